@@ -0,0 +1,323 @@
+//! Command line arguments.
+//!
+//! The commands mirror the API's services, e.g. `ledger categories list`
+//! calls `CategoriesList`. Every command takes the global [`Connection`]
+//! options and `--output`, which may come before or after the subcommand.
+
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use lib_domain as domain;
+
+/// The server URL used when neither `--url` nor `LEDGER_URL` is set.
+pub const DEFAULT_URL: &str = "http://127.0.0.1:50059";
+
+/// Command line client for the Personal Ledger gRPC API.
+#[derive(Debug, Parser)]
+#[command(name = "ledger", version, about)]
+pub struct Cli {
+    #[command(flatten)]
+    pub connection: Connection,
+
+    /// How to write the results
+    #[arg(long, short, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// How to reach the server, and the token to send it. Shared with the
+/// terminal UI, which takes the same options.
+///
+/// The server is reached over `--socket`, or `--standalone`, when given,
+/// otherwise at `--url`. TLS is used for an `https://` URL or when any of
+/// the TLS files is given.
+#[derive(Debug, Clone, Args)]
+pub struct Connection {
+    /// The URL of the server to connect to
+    #[arg(long, global = true, env = "LEDGER_URL", default_value = DEFAULT_URL)]
+    pub url: String,
+
+    /// Connect to the server's Unix socket at this path rather than the URL
+    #[arg(
+        long,
+        global = true,
+        env = "LEDGER_SOCKET",
+        conflicts_with = "standalone"
+    )]
+    pub socket: Option<PathBuf>,
+
+    /// Connect to a standalone server's Unix socket in the data directory
+    #[arg(long, global = true)]
+    pub standalone: bool,
+
+    /// The API key or JWT to send as a bearer token with every request
+    #[arg(long, global = true, env = "LEDGER_TOKEN", hide_env_values = true)]
+    pub token: Option<String>,
+
+    /// PEM file of the authority the server's certificate is signed by,
+    /// rather than the public web authorities
+    #[arg(long, global = true, env = "LEDGER_TLS_CA")]
+    pub tls_ca: Option<PathBuf>,
+
+    /// PEM file of the client's certificate chain, for mutual TLS
+    #[arg(long, global = true, env = "LEDGER_TLS_CERT", requires = "tls_key")]
+    pub tls_cert: Option<PathBuf>,
+
+    /// PEM file of the client's private key, for mutual TLS
+    #[arg(long, global = true, env = "LEDGER_TLS_KEY", requires = "tls_cert")]
+    pub tls_key: Option<PathBuf>,
+}
+
+impl Connection {
+    /// Whether to connect over TLS.
+    pub fn is_tls(&self) -> bool {
+        self.url.starts_with("https://")
+            || self.tls_ca.is_some()
+            || self.tls_cert.is_some()
+            || self.tls_key.is_some()
+    }
+}
+
+/// How results are written to standard output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned columns for reading
+    #[default]
+    Table,
+
+    /// Pretty printed JSON for scripts
+    Json,
+}
+
+/// The top level commands.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// List and create categories
+    #[command(subcommand)]
+    Categories(CategoriesCommand),
+
+    /// List and add transactions
+    #[command(subcommand, name = "tx", visible_alias = "transactions")]
+    Transactions(TransactionsCommand),
+
+    /// Check on the server and its ledger
+    #[command(subcommand)]
+    Utilities(UtilitiesCommand),
+}
+
+/// The `categories` subcommands.
+#[derive(Debug, Subcommand)]
+pub enum CategoriesCommand {
+    /// List categories
+    List(CategoriesList),
+
+    /// Create a category
+    Create(CategoryCreate),
+}
+
+/// Arguments of `categories list`.
+#[derive(Debug, Args)]
+pub struct CategoriesList {
+    /// Only list categories of this type, e.g. `expense`
+    #[arg(long = "type")]
+    pub category_type: Option<domain::CategoryTypes>,
+
+    /// Only list active categories
+    #[arg(long, conflicts_with = "inactive")]
+    pub active: bool,
+
+    /// Only list inactive categories
+    #[arg(long)]
+    pub inactive: bool,
+
+    /// The most categories to list, all of them if not set
+    #[arg(long)]
+    pub limit: Option<usize>,
+}
+
+/// Arguments of `categories create`.
+#[derive(Debug, Args)]
+pub struct CategoryCreate {
+    /// The category name, e.g. `Groceries`
+    #[arg(long)]
+    pub name: String,
+
+    /// The category type, e.g. `expense`
+    #[arg(long = "type")]
+    pub category_type: domain::CategoryTypes,
+
+    /// The unique category code, the name in capitals if not set
+    #[arg(long)]
+    pub code: Option<String>,
+
+    /// A description of the category, as markdown
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// The ID of the parent category
+    #[arg(long)]
+    pub parent: Option<String>,
+}
+
+/// The `tx` subcommands.
+#[derive(Debug, Subcommand)]
+pub enum TransactionsCommand {
+    /// List transactions, newest first
+    List(TransactionsList),
+
+    /// Add a transaction
+    Add(TransactionAdd),
+}
+
+/// Arguments of `tx list`.
+#[derive(Debug, Args)]
+pub struct TransactionsList {
+    /// Only list drafts, oldest first
+    #[arg(long)]
+    pub drafts: bool,
+
+    /// The most transactions to list, all of them if not set
+    #[arg(long)]
+    pub limit: Option<usize>,
+}
+
+/// Arguments of `tx add`.
+#[derive(Debug, Args)]
+pub struct TransactionAdd {
+    /// The transaction date as YYYY-MM-DD, today if not set
+    #[arg(long)]
+    pub date: Option<chrono::NaiveDate>,
+
+    /// Who the money was paid to or received from
+    #[arg(long)]
+    pub payee: String,
+
+    /// The amount, negative for money spent, e.g. `-42.50`
+    #[arg(long, allow_hyphen_values = true)]
+    pub amount: domain::Money,
+
+    /// The ID of the category to classify it under
+    #[arg(long)]
+    pub category: Option<String>,
+
+    /// The ID of the account to post it to
+    #[arg(long)]
+    pub account: Option<String>,
+
+    /// A note about the transaction, as markdown
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// Save it as a draft, left out of balances until posted
+    #[arg(long)]
+    pub draft: bool,
+}
+
+/// The `utilities` subcommands.
+#[derive(Debug, Subcommand)]
+pub enum UtilitiesCommand {
+    /// Export the ledger, read it back into an empty database and compare
+    /// record counts and balances
+    RoundTrip,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_global_options_after_the_subcommand() {
+        let cli = Cli::try_parse_from([
+            "ledger",
+            "tx",
+            "add",
+            "--payee",
+            "Fresh Market",
+            "--amount",
+            "-42.50",
+            "--output",
+            "json",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.output, OutputFormat::Json);
+        let Command::Transactions(TransactionsCommand::Add(add)) = cli.command else {
+            panic!("expected tx add");
+        };
+        assert_eq!(add.amount.amount(), -4_250);
+        assert!(add.date.is_none());
+    }
+
+    #[test]
+    fn parses_connection_options() {
+        let cli = Cli::try_parse_from([
+            "ledger",
+            "utilities",
+            "round-trip",
+            "--socket",
+            "/run/ledger.sock",
+            "--token",
+            "a-long-random-key",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.connection.url, DEFAULT_URL);
+        assert_eq!(
+            cli.connection.socket.as_deref(),
+            Some(std::path::Path::new("/run/ledger.sock"))
+        );
+        assert_eq!(cli.connection.token.as_deref(), Some("a-long-random-key"));
+        assert!(!cli.connection.is_tls());
+
+        let tls = Cli::try_parse_from([
+            "ledger",
+            "utilities",
+            "round-trip",
+            "--url",
+            "https://ledger.example.com:50059",
+        ])
+        .unwrap();
+        assert!(tls.connection.is_tls());
+    }
+
+    #[test]
+    fn refuses_a_client_certificate_without_its_key() {
+        let parsed = Cli::try_parse_from([
+            "ledger",
+            "utilities",
+            "round-trip",
+            "--tls-cert",
+            "client.pem",
+        ]);
+
+        assert!(parsed.is_err());
+    }
+
+    #[test]
+    fn parses_utilities_round_trip() {
+        let cli = Cli::try_parse_from(["ledger", "utilities", "round-trip"]).unwrap();
+
+        assert!(matches!(
+            cli.command,
+            Command::Utilities(UtilitiesCommand::RoundTrip)
+        ));
+    }
+
+    #[test]
+    fn refuses_an_unknown_category_type() {
+        let parsed = Cli::try_parse_from([
+            "ledger",
+            "categories",
+            "create",
+            "--name",
+            "Groceries",
+            "--type",
+            "food",
+        ]);
+
+        assert!(parsed.is_err());
+    }
+}
@@ -0,0 +1,436 @@
+//! Command execution.
+//!
+//! Each command makes its requests through one [`LedgerClient`](rpc::LedgerClient)
+//! and returns what to print, so commands can be run against any server,
+//! such as a test server, without touching standard output.
+
+use futures_util::{StreamExt, TryStreamExt};
+
+use lib_rpc as rpc;
+
+use crate::cli::{
+    CategoriesCommand, CategoriesList, CategoryCreate, Cli, Command, Connection, TransactionAdd,
+    TransactionsCommand, TransactionsList, UtilitiesCommand,
+};
+use crate::output::{self, CategoryRow, RoundTripRow, TransactionRow};
+use crate::{ClientError, ClientResult, OutputFormat};
+
+/// Connects to the server the arguments name and runs their command.
+///
+/// # Errors
+///
+/// Returns the errors of [`connect`] and [`execute`].
+pub async fn run(cli: Cli) -> ClientResult<String> {
+    let client = connect(&cli.connection).await?;
+
+    execute(&client, cli.command, cli.output).await
+}
+
+/// Connects to the server `connection` names, with a client that sends its
+/// token with every request.
+///
+/// # Errors
+///
+/// Returns `ClientError::Connect` if the server cannot be reached,
+/// `ClientError::Tls` if a TLS file cannot be read, or
+/// `ClientError::Invalid` if the token cannot be sent or there is no Unix
+/// socket to connect to.
+pub async fn connect(connection: &Connection) -> ClientResult<rpc::LedgerClient> {
+    let token = connection
+        .token
+        .as_deref()
+        .map(rpc::BearerToken::new)
+        .transpose()
+        .map_err(|_| ClientError::Invalid("the token has characters that cannot be sent".into()))?;
+
+    let socket = if connection.standalone {
+        Some(
+            lib_config::LedgerConfig::standalone_socket_path().ok_or_else(|| {
+                ClientError::Invalid("there is no data directory for a standalone server".into())
+            })?,
+        )
+    } else {
+        connection.socket.clone()
+    };
+
+    let client = if let Some(socket) = socket {
+        connect_unix(socket).await?
+    } else if connection.is_tls() {
+        let identity = connection
+            .tls_cert
+            .as_deref()
+            .zip(connection.tls_key.as_deref());
+        let tls = rpc::load_client_tls(connection.tls_ca.as_deref(), identity)
+            .map_err(ClientError::Tls)?;
+        rpc::LedgerClient::connect_tls(connection.url.clone(), tls).await?
+    } else {
+        rpc::LedgerClient::connect(connection.url.clone()).await?
+    };
+
+    Ok(client.with_token(token))
+}
+
+#[cfg(unix)]
+async fn connect_unix(socket: std::path::PathBuf) -> ClientResult<rpc::LedgerClient> {
+    Ok(rpc::LedgerClient::connect_unix(socket).await?)
+}
+
+#[cfg(not(unix))]
+async fn connect_unix(_socket: std::path::PathBuf) -> ClientResult<rpc::LedgerClient> {
+    Err(ClientError::Invalid(
+        "Unix sockets are not supported on this platform".into(),
+    ))
+}
+
+/// Runs a command with `client` and returns its output in `format`.
+///
+/// # Errors
+///
+/// Returns `ClientError::Status` if the server refuses a request, such as a
+/// category whose code is taken, or `ClientError::Invalid` if an argument
+/// cannot be sent.
+pub async fn execute(
+    client: &rpc::LedgerClient,
+    command: Command,
+    format: OutputFormat,
+) -> ClientResult<String> {
+    match command {
+        Command::Categories(CategoriesCommand::List(args)) => {
+            output::categories(&list_categories(client, args).await?, format)
+        }
+        Command::Categories(CategoriesCommand::Create(args)) => {
+            output::categories(&[create_category(client, args).await?], format)
+        }
+        Command::Transactions(TransactionsCommand::List(args)) => {
+            output::transactions(&list_transactions(client, args).await?, format)
+        }
+        Command::Transactions(TransactionsCommand::Add(args)) => {
+            output::transactions(&[add_transaction(client, args).await?], format)
+        }
+        Command::Utilities(UtilitiesCommand::RoundTrip) => {
+            output::round_trip(&round_trip(client).await?, format)
+        }
+    }
+}
+
+async fn list_categories(
+    client: &rpc::LedgerClient,
+    args: CategoriesList,
+) -> ClientResult<Vec<CategoryRow>> {
+    let is_active = match (args.active, args.inactive) {
+        (true, _) => Some(true),
+        (_, true) => Some(false),
+        _ => None,
+    };
+    let request = rpc::CategoriesListRequest {
+        limit: page_size(args.limit),
+        category_type: args
+            .category_type
+            .map(|category_type| category_type.to_rpc_i32()),
+        is_active,
+        ..Default::default()
+    };
+
+    let categories = client
+        .iter_categories(request)
+        .take(args.limit.unwrap_or(usize::MAX));
+
+    Ok(categories.map_ok(CategoryRow::from).try_collect().await?)
+}
+
+async fn create_category(
+    client: &rpc::LedgerClient,
+    args: CategoryCreate,
+) -> ClientResult<CategoryRow> {
+    let code = args.code.unwrap_or_else(|| default_code(&args.name));
+    let category = rpc::Category {
+        code,
+        name: args.name,
+        description: args.description,
+        category_type: args.category_type.to_rpc_i32(),
+        is_active: true,
+        parent_id: args.parent,
+        ..Default::default()
+    };
+
+    let response = client
+        .categories()
+        .category_create(rpc::CategoryCreateRequest {
+            category: Some(category),
+            validate_only: false,
+        })
+        .await?
+        .into_inner();
+
+    response.category.map(CategoryRow::from).ok_or_else(|| {
+        ClientError::Invalid("the server did not return the created category".to_string())
+    })
+}
+
+async fn list_transactions(
+    client: &rpc::LedgerClient,
+    args: TransactionsList,
+) -> ClientResult<Vec<TransactionRow>> {
+    let request = rpc::TransactionsListRequest {
+        limit: page_size(args.limit),
+        drafts_only: args.drafts,
+        ..Default::default()
+    };
+
+    let transactions = client
+        .iter_transactions(request)
+        .take(args.limit.unwrap_or(usize::MAX));
+
+    Ok(transactions
+        .map_ok(TransactionRow::from)
+        .try_collect()
+        .await?)
+}
+
+async fn add_transaction(
+    client: &rpc::LedgerClient,
+    args: TransactionAdd,
+) -> ClientResult<TransactionRow> {
+    if args.amount.currency() != lib_domain::Currency::default() {
+        return Err(ClientError::Invalid(format!(
+            "amounts must be in {}, the ledger's currency",
+            lib_domain::Currency::default().code()
+        )));
+    }
+
+    let date = args
+        .date
+        .unwrap_or_else(|| chrono::Local::now().date_naive());
+    let transaction = rpc::Transaction {
+        transaction_date: date.format("%Y-%m-%d").to_string(),
+        payee: args.payee,
+        description: args.description,
+        amount: args.amount.amount(),
+        category_id: args.category,
+        account_id: args.account,
+        is_draft: args.draft,
+        ..Default::default()
+    };
+
+    let response = client
+        .transactions()
+        .transaction_create(rpc::TransactionCreateRequest {
+            transaction: Some(transaction),
+            validate_only: false,
+        })
+        .await?
+        .into_inner();
+
+    response
+        .transaction
+        .map(TransactionRow::from)
+        .ok_or_else(|| {
+            ClientError::Invalid("the server did not return the added transaction".to_string())
+        })
+}
+
+async fn round_trip(client: &rpc::LedgerClient) -> ClientResult<Vec<RoundTripRow>> {
+    let response = client
+        .utilities()
+        .round_trip_export(rpc::RoundTripExportRequest {})
+        .await?
+        .into_inner();
+
+    Ok(response
+        .checks
+        .into_iter()
+        .map(RoundTripRow::from)
+        .collect())
+}
+
+/// The page size to request, no bigger than the rows wanted. Zero leaves it
+/// to the server.
+fn page_size(limit: Option<usize>) -> i32 {
+    limit.map_or(0, |limit| i32::try_from(limit).unwrap_or(i32::MAX))
+}
+
+/// The code a category gets when none is given: its name in capitals, with
+/// spaces as underscores, e.g. `DINING_OUT`.
+fn default_code(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join("_")
+        .to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use lib_testkit::TestServer;
+
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn ledger(server: &TestServer, args: &[&str]) -> ClientResult<String> {
+        let cli =
+            Cli::try_parse_from(std::iter::once("ledger").chain(args.iter().copied())).unwrap();
+
+        execute(&server.client(), cli.command, cli.output).await
+    }
+
+    #[tokio::test]
+    async fn creates_and_lists_categories() -> Result<()> {
+        let server = TestServer::start().await?;
+
+        let created = ledger(
+            &server,
+            &[
+                "categories",
+                "create",
+                "--name",
+                "Dining Out",
+                "--type",
+                "expense",
+            ],
+        )
+        .await?;
+        ledger(
+            &server,
+            &[
+                "categories",
+                "create",
+                "--name",
+                "Salary",
+                "--type",
+                "income",
+                "--code",
+                "PAY",
+            ],
+        )
+        .await?;
+        let expenses = ledger(
+            &server,
+            &["categories", "list", "--type", "expense", "-o", "json"],
+        )
+        .await?;
+
+        assert!(created.contains("DINING_OUT"));
+        let expenses: serde_json::Value = serde_json::from_str(&expenses)?;
+        assert_eq!(expenses.as_array().map(Vec::len), Some(1));
+        assert_eq!(expenses[0]["name"], "Dining Out");
+        assert_eq!(expenses[0]["category_type"], "expense");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn adds_and_lists_transactions() -> Result<()> {
+        let server = TestServer::start().await?;
+
+        for payee in ["Fresh Market", "Corner Cafe", "Metro Transit"] {
+            let args = [
+                "tx",
+                "add",
+                "--date",
+                "2026-10-01",
+                "--payee",
+                payee,
+                "--amount",
+                "-12.50",
+            ];
+            ledger(&server, &args).await?;
+        }
+        let listed = ledger(
+            &server,
+            &["transactions", "list", "--limit", "2", "--output", "json"],
+        )
+        .await?;
+
+        let listed: serde_json::Value = serde_json::from_str(&listed)?;
+        assert_eq!(listed.as_array().map(Vec::len), Some(2));
+        assert_eq!(listed[0]["amount"], -1_250);
+        assert_eq!(listed[0]["date"], "2026-10-01");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_what_the_server_refuses() -> Result<()> {
+        let server = TestServer::start().await?;
+
+        let refused = ledger(
+            &server,
+            &["tx", "add", "--payee", "Nobody", "--amount", "0"],
+        )
+        .await;
+
+        assert!(
+            matches!(refused, Err(ClientError::Status(status)) if status.code() == tonic::Code::InvalidArgument)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn round_trip_compares_the_ledger_with_its_export() -> Result<()> {
+        let server = TestServer::start().await?;
+        ledger(
+            &server,
+            &[
+                "tx",
+                "add",
+                "--date",
+                "2026-10-01",
+                "--payee",
+                "Fresh Market",
+                "--amount",
+                "-12.50",
+            ],
+        )
+        .await?;
+
+        let checks = ledger(&server, &["utilities", "round-trip", "-o", "json"]).await?;
+
+        let checks: serde_json::Value = serde_json::from_str(&checks)?;
+        assert_eq!(checks[2]["check"], "transaction_count");
+        assert_eq!(checks[2]["exported"], 1);
+        assert_eq!(checks[3]["imported"], -1_250);
+        assert!(
+            checks
+                .as_array()
+                .unwrap()
+                .iter()
+                .all(|check| check["matches"] == true)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn runs_commands_over_the_connection_options() -> Result<()> {
+        let server = TestServer::start().await?;
+        let url = server.url().expect("a TCP server");
+
+        let list = ["ledger", "categories", "list", "--url", &url, "-o", "json"];
+        assert_eq!(run(Cli::try_parse_from(list)?).await?, "[]");
+
+        // The server knows no API keys, so one that is sent is refused
+        let cli = Cli::try_parse_from(list.into_iter().chain(["--token", "unknown-key"]))?;
+        let Err(ClientError::Status(status)) = run(cli).await else {
+            panic!("expected the token to be refused");
+        };
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        let cli = Cli::try_parse_from([
+            "ledger",
+            "categories",
+            "list",
+            "--socket",
+            "/nonexistent/personal-ledger.sock",
+        ])?;
+        assert!(matches!(run(cli).await, Err(ClientError::Connect(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_code_is_the_name_in_capitals() {
+        assert_eq!(default_code("  Dining   out "), "DINING_OUT");
+    }
+}
@@ -0,0 +1,31 @@
+//! Client errors.
+//!
+//! `ClientError` wraps whatever stops a command, so `main` can print it and
+//! exit with a failure code.
+
+/// Result type alias used by the client.
+pub type ClientResult<T> = std::result::Result<T, ClientError>;
+
+/// Errors produced while running a client command.
+#[derive(thiserror::Error, Debug)]
+pub enum ClientError {
+    /// The server could not be connected to.
+    #[error("Could not connect to the server: {0}")]
+    Connect(#[from] tonic::transport::Error),
+
+    /// The TLS files could not be read.
+    #[error("Could not read the TLS files: {0}")]
+    Tls(std::io::Error),
+
+    /// The server refused or failed the request.
+    #[error("{}: {}", .0.code(), .0.message())]
+    Status(#[from] tonic::Status),
+
+    /// An argument could not be turned into a request.
+    #[error("Invalid argument: {0}")]
+    Invalid(String),
+
+    /// The output could not be written as JSON.
+    #[error("Could not write JSON output: {0}")]
+    Json(#[from] serde_json::Error),
+}
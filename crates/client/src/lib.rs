@@ -0,0 +1,34 @@
+//! # Personal Ledger Client
+//!
+//! A command line client for the Personal Ledger gRPC API, installed as the
+//! `ledger` binary. It lists and creates categories, lists and adds
+//! transactions, and checks an export of the ledger reads back whole,
+//! writing results as a table or as JSON:
+//!
+//! ```text
+//! ledger categories list --type expense
+//! ledger categories create --name Groceries --type expense
+//! ledger tx add --payee "Fresh Market" --amount -42.50 --category <ID>
+//! ledger tx list --limit 20 --output json
+//! ledger utilities round-trip
+//! ```
+//!
+//! The server URL is taken from `--url`, then the `LEDGER_URL` environment
+//! variable, and defaults to the server's default local address. A server
+//! on a Unix socket is reached with `--socket` (`LEDGER_SOCKET`), or
+//! `--standalone` for a standalone server's socket. An API key or JWT is
+//! sent with `--token` (`LEDGER_TOKEN`), and `--tls-ca`, `--tls-cert` and
+//! `--tls-key` set up TLS, which an `https://` URL also turns on.
+
+mod cli;
+mod commands;
+mod error;
+mod output;
+
+pub use cli::{
+    CategoriesCommand, CategoriesList, CategoryCreate, Cli, Command, Connection, DEFAULT_URL,
+    OutputFormat, TransactionAdd, TransactionsCommand, TransactionsList, UtilitiesCommand,
+};
+pub use commands::{connect, execute, run};
+pub use error::{ClientError, ClientResult};
+pub use output::{CategoryRow, RoundTripRow, TransactionRow};
@@ -0,0 +1,19 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use client::Cli;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match client::run(Cli::parse()).await {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Output formatting.
+//!
+//! Results are turned into rows first, which are then written as aligned
+//! columns or as JSON, so both formats always hold the same fields. JSON
+//! amounts stay in cents as the API has them, the table shows dollars.
+
+use serde::Serialize;
+
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{ClientResult, OutputFormat};
+
+/// A category as it is written out.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CategoryRow {
+    /// The category's public ID.
+    pub id: String,
+    /// The unique category code.
+    pub code: String,
+    /// The category name.
+    pub name: String,
+    /// The category type, e.g. `expense`.
+    pub category_type: String,
+    /// Whether the category is available for use.
+    pub is_active: bool,
+    /// The parent category's ID, if it has one.
+    pub parent_id: Option<String>,
+    /// The description, as markdown.
+    pub description: Option<String>,
+}
+
+impl From<rpc::Category> for CategoryRow {
+    fn from(category: rpc::Category) -> Self {
+        let category_type = domain::CategoryTypes::from_rpc_i32(category.category_type)
+            .map(|category_type| category_type.as_str().to_string())
+            .unwrap_or_else(|_| "unknown".to_string());
+
+        Self {
+            id: category.id,
+            code: category.code,
+            name: category.name,
+            category_type,
+            is_active: category.is_active,
+            parent_id: category.parent_id,
+            description: category.description,
+        }
+    }
+}
+
+/// A transaction as it is written out.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TransactionRow {
+    /// The transaction's public ID.
+    pub id: String,
+    /// The transaction date as YYYY-MM-DD.
+    pub date: String,
+    /// Who the money was paid to or received from.
+    pub payee: String,
+    /// The amount in cents, negative for money spent.
+    pub amount: i64,
+    /// The category's ID, if it is classified.
+    pub category_id: Option<String>,
+    /// The account's ID, if it is posted to one.
+    pub account_id: Option<String>,
+    /// Whether it is a draft.
+    pub is_draft: bool,
+    /// The note, as markdown.
+    pub description: Option<String>,
+}
+
+impl From<rpc::Transaction> for TransactionRow {
+    fn from(transaction: rpc::Transaction) -> Self {
+        Self {
+            id: transaction.id,
+            date: transaction.transaction_date,
+            payee: transaction.payee,
+            amount: transaction.amount,
+            category_id: transaction.category_id,
+            account_id: transaction.account_id,
+            is_draft: transaction.is_draft,
+            description: transaction.description,
+        }
+    }
+}
+
+/// A figure compared by a round trip of the ledger's export.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RoundTripRow {
+    /// What was compared, e.g. `transaction_count`.
+    pub check: String,
+    /// The ID of the account a balance is for.
+    pub record_id: Option<String>,
+    /// The figure in the ledger.
+    pub exported: i64,
+    /// The figure read back from the export.
+    pub imported: i64,
+    /// Whether the two figures are the same.
+    pub matches: bool,
+}
+
+impl From<rpc::RoundTripCheck> for RoundTripRow {
+    fn from(check: rpc::RoundTripCheck) -> Self {
+        let name = match check.check() {
+            rpc::RoundTripChecks::CategoryCount => "category_count",
+            rpc::RoundTripChecks::AccountCount => "account_count",
+            rpc::RoundTripChecks::TransactionCount => "transaction_count",
+            rpc::RoundTripChecks::TransactionTotal => "transaction_total",
+            rpc::RoundTripChecks::AccountBalance => "account_balance",
+            rpc::RoundTripChecks::Unspecified => "unknown",
+        };
+
+        Self {
+            check: name.to_string(),
+            record_id: check.record_id,
+            exported: check.exported,
+            imported: check.imported,
+            matches: check.exported == check.imported,
+        }
+    }
+}
+
+/// Writes categories in the given format.
+///
+/// # Errors
+///
+/// Returns `ClientError::Json` if the JSON cannot be written.
+pub fn categories(rows: &[CategoryRow], format: OutputFormat) -> ClientResult<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(rows)?),
+        OutputFormat::Table => Ok(table(
+            &["ID", "CODE", "NAME", "TYPE", "ACTIVE"],
+            rows.iter().map(|row| {
+                vec![
+                    row.id.clone(),
+                    row.code.clone(),
+                    row.name.clone(),
+                    row.category_type.clone(),
+                    yes_no(row.is_active),
+                ]
+            }),
+        )),
+    }
+}
+
+/// Writes transactions in the given format.
+///
+/// # Errors
+///
+/// Returns `ClientError::Json` if the JSON cannot be written.
+pub fn transactions(rows: &[TransactionRow], format: OutputFormat) -> ClientResult<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(rows)?),
+        OutputFormat::Table => Ok(table(
+            &["ID", "DATE", "PAYEE", "AMOUNT", "DRAFT"],
+            rows.iter().map(|row| {
+                vec![
+                    row.id.clone(),
+                    row.date.clone(),
+                    row.payee.clone(),
+                    domain::Money::new(row.amount, domain::Currency::default()).to_string(),
+                    yes_no(row.is_draft),
+                ]
+            }),
+        )),
+    }
+}
+
+/// Writes round trip checks in the given format.
+///
+/// # Errors
+///
+/// Returns `ClientError::Json` if the JSON cannot be written.
+pub fn round_trip(rows: &[RoundTripRow], format: OutputFormat) -> ClientResult<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(rows)?),
+        OutputFormat::Table => Ok(table(
+            &["CHECK", "RECORD", "EXPORTED", "IMPORTED", "MATCHES"],
+            rows.iter().map(|row| {
+                vec![
+                    row.check.clone(),
+                    row.record_id.clone().unwrap_or_default(),
+                    row.exported.to_string(),
+                    row.imported.to_string(),
+                    yes_no(row.matches),
+                ]
+            }),
+        )),
+    }
+}
+
+fn yes_no(value: bool) -> String {
+    if value { "yes" } else { "no" }.to_string()
+}
+
+/// Lays out rows under their headers in columns as wide as their widest
+/// cell, separated by two spaces.
+fn table(headers: &[&str], rows: impl Iterator<Item = Vec<String>>) -> String {
+    let rows: Vec<Vec<String>> = rows.collect();
+
+    let mut widths: Vec<usize> = headers
+        .iter()
+        .map(|header| header.chars().count())
+        .collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header = headers.iter().map(|header| header.to_string()).collect();
+    std::iter::once(header)
+        .chain(rows)
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect();
+            cells.join("  ").trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn groceries() -> CategoryRow {
+        CategoryRow {
+            id: "cat_1".to_string(),
+            code: "GROCERIES".to_string(),
+            name: "Groceries".to_string(),
+            category_type: "expense".to_string(),
+            is_active: true,
+            parent_id: None,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn table_aligns_columns() {
+        let output = categories(&[groceries()], OutputFormat::Table).unwrap();
+
+        assert_eq!(
+            output,
+            "ID     CODE       NAME       TYPE     ACTIVE\n\
+             cat_1  GROCERIES  Groceries  expense  yes"
+        );
+    }
+
+    #[test]
+    fn json_keeps_amounts_in_cents() {
+        let row = TransactionRow {
+            id: "tx_1".to_string(),
+            date: "2026-10-18".to_string(),
+            payee: "Fresh Market".to_string(),
+            amount: -4_250,
+            category_id: None,
+            account_id: None,
+            is_draft: false,
+            description: None,
+        };
+
+        let table = transactions(std::slice::from_ref(&row), OutputFormat::Table).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&transactions(&[row], OutputFormat::Json).unwrap()).unwrap();
+
+        assert!(table.contains("-$42.50"));
+        assert_eq!(json[0]["amount"], -4_250);
+    }
+}
@@ -0,0 +1,81 @@
+//! # Auth Configuration Types and Defaults
+//!
+//! This module defines [`AuthConfig`], the credentials the server accepts as
+//! bearer tokens, read from the `[Auth]` section of a config file.
+//!
+//! Authentication is off until an API key file or JWT secret is set, and
+//! every request is then trusted as an admin. Once it is on, requests over
+//! TCP without a valid token are refused. Requests over a Unix socket, which
+//! only the user the server runs as can connect to, are still trusted as
+//! that user when they have no token.
+//!
+//! The API key file lists one key per line, naming who it authenticates,
+//! their role and the SHA-256 digest of the key in hex, so the keys
+//! themselves are never written down. Blank lines and lines starting with
+//! `#` are skipped:
+//!
+//! ```text
+//! # subject  role       sha256(key)
+//! ian        admin      9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08
+//! sam        read-only  60303ae22b998861bce3b28f33eec1be758a213c86c93c076dbe9f558c11c752
+//! ```
+
+use std::path::PathBuf;
+
+/// The credentials the server accepts as bearer tokens.
+///
+/// # Example
+///
+/// ```rust
+/// use lib_config::AuthConfig;
+///
+/// let config = AuthConfig {
+///     jwt_secret: Some("a long random string".to_string()),
+///     ..AuthConfig::default()
+/// };
+/// assert!(config.is_enabled());
+/// assert!(!AuthConfig::default().is_enabled());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct AuthConfig {
+    /// File listing the API keys accepted, see the [module docs](self) for
+    /// its format.
+    pub api_keys_file: Option<PathBuf>,
+
+    /// Secret JWTs are signed with using HS256. JWTs are not accepted when
+    /// unset.
+    pub jwt_secret: Option<String>,
+}
+
+impl AuthConfig {
+    /// Whether requests must authenticate, once an API key file or JWT
+    /// secret is set.
+    pub fn is_enabled(&self) -> bool {
+        self.api_keys_file.is_some() || self.jwt_secret.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_auth_config_is_off() {
+        let config = AuthConfig::default();
+
+        assert_eq!(config.api_keys_file, None);
+        assert_eq!(config.jwt_secret, None);
+        assert!(!config.is_enabled());
+    }
+
+    #[test]
+    fn api_key_file_turns_auth_on() {
+        let config = AuthConfig {
+            api_keys_file: Some(PathBuf::from("/etc/personal-ledger/api-keys")),
+            ..AuthConfig::default()
+        };
+
+        assert!(config.is_enabled());
+    }
+}
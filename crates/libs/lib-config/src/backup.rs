@@ -0,0 +1,106 @@
+//! # Backup Configuration Types and Defaults
+//!
+//! This module defines [`BackupConfig`], where database backups are written
+//! and how many are kept, read from the `[Backup]` section of a config file.
+//!
+//! Backups are off until a directory is set. With a directory the server
+//! backs the database up on a schedule, and clients can ask for a backup with
+//! the `Backup` RPC. Scheduled backups are thinned out by the retention
+//! policy, keeping the newest backup of each recent day and week.
+
+use std::path::PathBuf;
+
+/// Default hours between scheduled backups.
+pub const DEFAULT_BACKUP_INTERVAL_HOURS: u64 = 24;
+
+/// Default number of days a daily backup is kept for.
+pub const DEFAULT_KEEP_DAILY: u32 = 7;
+
+/// Default number of weeks a weekly backup is kept for.
+pub const DEFAULT_KEEP_WEEKLY: u32 = 4;
+
+/// Where database backups are written, how often and how many are kept.
+///
+/// # Example
+///
+/// ```rust
+/// use lib_config::BackupConfig;
+///
+/// let config = BackupConfig {
+///     directory: Some("/var/backups/personal-ledger".into()),
+///     ..BackupConfig::default()
+/// };
+/// assert!(config.is_scheduled());
+/// assert_eq!(config.interval(), Some(std::time::Duration::from_secs(24 * 60 * 60)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct BackupConfig {
+    /// Directory backups are written to. Scheduled backups and the `Backup`
+    /// RPC are turned off when this is not set.
+    #[serde(default)]
+    pub directory: Option<PathBuf>,
+
+    /// Hours between scheduled backups, `0` to only back up when asked to
+    /// with the `Backup` RPC.
+    pub interval_hours: u64,
+
+    /// The number of most recent days to keep the newest backup of.
+    pub keep_daily: u32,
+
+    /// The number of most recent weeks, starting on Monday, to keep the
+    /// newest backup of.
+    pub keep_weekly: u32,
+}
+
+impl Default for BackupConfig {
+    /// Backups are off, and once a directory is set run daily, keeping a
+    /// week of daily backups and a month of weekly ones.
+    fn default() -> Self {
+        Self {
+            directory: None,
+            interval_hours: DEFAULT_BACKUP_INTERVAL_HOURS,
+            keep_daily: DEFAULT_KEEP_DAILY,
+            keep_weekly: DEFAULT_KEEP_WEEKLY,
+        }
+    }
+}
+
+impl BackupConfig {
+    /// Time between scheduled backups, or `None` when they are off.
+    pub fn interval(&self) -> Option<std::time::Duration> {
+        (self.interval_hours > 0)
+            .then(|| std::time::Duration::from_secs(self.interval_hours * 60 * 60))
+    }
+
+    /// Whether the server backs the database up on a schedule.
+    pub fn is_scheduled(&self) -> bool {
+        self.directory.is_some() && self.interval().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_backup_config_is_off() {
+        let config = BackupConfig::default();
+
+        assert!(config.directory.is_none());
+        assert!(!config.is_scheduled());
+        assert_eq!(config.keep_daily, DEFAULT_KEEP_DAILY);
+        assert_eq!(config.keep_weekly, DEFAULT_KEEP_WEEKLY);
+    }
+
+    #[test]
+    fn zero_interval_turns_schedule_off() {
+        let config = BackupConfig {
+            directory: Some(PathBuf::from("backups")),
+            interval_hours: 0,
+            ..BackupConfig::default()
+        };
+
+        assert_eq!(config.interval(), None);
+        assert!(!config.is_scheduled());
+    }
+}
@@ -0,0 +1,181 @@
+//! # Database Configuration Types and Defaults
+//!
+//! This module defines [`DatabaseConfig`], the SQLite connection settings applied
+//! to every connection the database pool opens. They trade durability against
+//! write speed, and are read from the `[Database]` section of a config file.
+//!
+//! The defaults are SQLite's own, apart from foreign keys which are always
+//! enforced by default, so a database behaves the same with or without a
+//! `[Database]` section.
+
+/// SQLite journal mode, how a transaction is made atomic on disk.
+///
+/// See <https://www.sqlite.org/pragma.html#pragma_journal_mode>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum JournalModes {
+    /// A rollback journal deleted at the end of each transaction, SQLite's default.
+    #[default]
+    Delete,
+
+    /// A rollback journal truncated to zero length instead of deleted.
+    Truncate,
+
+    /// A rollback journal whose header is zeroed instead of deleted.
+    Persist,
+
+    /// A rollback journal kept in memory, lost if the process crashes.
+    Memory,
+
+    /// A write-ahead log. Readers do not block the writer, which suits a
+    /// server with concurrent requests.
+    Wal,
+
+    /// No journal, a crash mid-transaction can corrupt the database.
+    Off,
+}
+
+impl std::fmt::Display for JournalModes {
+    /// Formats the journal mode as it is written in a `PRAGMA` statement.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mode = match self {
+            JournalModes::Delete => "delete",
+            JournalModes::Truncate => "truncate",
+            JournalModes::Persist => "persist",
+            JournalModes::Memory => "memory",
+            JournalModes::Wal => "wal",
+            JournalModes::Off => "off",
+        };
+        write!(f, "{}", mode)
+    }
+}
+
+/// SQLite synchronous mode, how often writes are flushed to disk.
+///
+/// See <https://www.sqlite.org/pragma.html#pragma_synchronous>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SynchronousModes {
+    /// Never flush, fastest but a power loss can corrupt the database.
+    Off,
+
+    /// Flush at critical moments. Safe with the `wal` journal mode, where a
+    /// power loss can only lose the last transactions.
+    Normal,
+
+    /// Flush on every commit, SQLite's default.
+    #[default]
+    Full,
+
+    /// As `full`, and also flush the directory after deleting the journal.
+    Extra,
+}
+
+impl std::fmt::Display for SynchronousModes {
+    /// Formats the synchronous mode as it is written in a `PRAGMA` statement.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mode = match self {
+            SynchronousModes::Off => "off",
+            SynchronousModes::Normal => "normal",
+            SynchronousModes::Full => "full",
+            SynchronousModes::Extra => "extra",
+        };
+        write!(f, "{}", mode)
+    }
+}
+
+/// Default journal mode, SQLite's rollback journal.
+pub const DEFAULT_JOURNAL_MODE: JournalModes = JournalModes::Delete;
+
+/// Default synchronous mode, a flush on every commit.
+pub const DEFAULT_SYNCHRONOUS: SynchronousModes = SynchronousModes::Full;
+
+/// Default for whether foreign key constraints are enforced.
+pub const DEFAULT_FOREIGN_KEYS: bool = true;
+
+/// Default time to wait for a locked database, in milliseconds.
+pub const DEFAULT_BUSY_TIMEOUT_MS: u64 = 5_000;
+
+/// Default page cache size. Negative values are in KiB, so this is about
+/// 2 MB, positive values are a number of pages.
+pub const DEFAULT_CACHE_SIZE: i64 = -2_000;
+
+/// SQLite settings applied to every database connection.
+///
+/// # Example
+///
+/// ```rust
+/// use lib_config::{DatabaseConfig, JournalModes, SynchronousModes};
+///
+/// // Faster writes for a server, safe against application crashes
+/// let config = DatabaseConfig {
+///     journal_mode: JournalModes::Wal,
+///     synchronous: SynchronousModes::Normal,
+///     ..DatabaseConfig::default()
+/// };
+/// assert!(config.foreign_keys);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct DatabaseConfig {
+    /// How transactions are journalled, see [`JournalModes`].
+    pub journal_mode: JournalModes,
+
+    /// How often writes are flushed to disk, see [`SynchronousModes`].
+    pub synchronous: SynchronousModes,
+
+    /// Whether foreign key constraints are enforced. The ledger relies on
+    /// them to keep categories, accounts and transactions consistent, so turn
+    /// them off only to repair a database by hand.
+    pub foreign_keys: bool,
+
+    /// How long a connection waits for a lock held by another connection
+    /// before failing with "database is locked", in milliseconds.
+    pub busy_timeout_ms: u64,
+
+    /// Page cache size per connection. Negative values are in KiB, positive
+    /// values a number of pages.
+    pub cache_size: i64,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            journal_mode: DEFAULT_JOURNAL_MODE,
+            synchronous: DEFAULT_SYNCHRONOUS,
+            foreign_keys: DEFAULT_FOREIGN_KEYS,
+            busy_timeout_ms: DEFAULT_BUSY_TIMEOUT_MS,
+            cache_size: DEFAULT_CACHE_SIZE,
+        }
+    }
+}
+
+impl DatabaseConfig {
+    /// How long a connection waits for a lock held by another connection.
+    pub fn busy_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.busy_timeout_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_database_config_matches_sqlite() {
+        let config = DatabaseConfig::default();
+
+        assert_eq!(config.journal_mode, JournalModes::Delete);
+        assert_eq!(config.synchronous, SynchronousModes::Full);
+        assert!(config.foreign_keys);
+        assert_eq!(config.busy_timeout(), std::time::Duration::from_secs(5));
+        assert_eq!(config.cache_size, -2_000);
+    }
+
+    #[test]
+    fn modes_display_as_pragma_values() {
+        assert_eq!(JournalModes::Wal.to_string(), "wal");
+        assert_eq!(JournalModes::Delete.to_string(), "delete");
+        assert_eq!(SynchronousModes::Normal.to_string(), "normal");
+        assert_eq!(SynchronousModes::Extra.to_string(), "extra");
+    }
+}
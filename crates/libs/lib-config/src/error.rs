@@ -40,9 +40,9 @@ pub enum ConfigError {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::net::SocketAddr;
     use std::fs::File;
     use std::io::Write;
+    use std::net::SocketAddr;
 
     #[test]
     fn validation_variant_formats_as_expected() {
@@ -76,4 +76,4 @@ mod tests {
         let s = err.to_string();
         assert!(s.starts_with("Configuration parsing error:"));
     }
-}
\ No newline at end of file
+}
@@ -62,6 +62,35 @@ const ENV_PREFIX: &str = "PERSONAL_LEDGER";
 pub struct LedgerConfig {
     #[serde(alias = "Telemetry")]
     pub telemetry: telemetry::TelemetryConfig,
+
+    #[serde(alias = "Server")]
+    pub server: super::ServerConfig,
+
+    #[serde(alias = "Database")]
+    pub database: super::DatabaseConfig,
+
+    #[serde(alias = "Backup")]
+    pub backup: super::BackupConfig,
+
+    /// Every limit is off unless set, so the section may be left out.
+    #[serde(alias = "Quota", default)]
+    pub quota: super::QuotaConfig,
+
+    /// Sandbox mode is off unless enabled, so the section may be left out.
+    #[serde(alias = "Sandbox", default)]
+    pub sandbox: super::SandboxConfig,
+
+    /// Bill reminders are off unless enabled, so the section may be left out.
+    #[serde(alias = "Notifications", default)]
+    pub notifications: super::NotificationsConfig,
+
+    /// Webhooks are off until an endpoint is set, so the section may be left out.
+    #[serde(alias = "Webhooks", default)]
+    pub webhooks: super::WebhooksConfig,
+
+    /// Authentication is off until a credential is set, so the section may be left out.
+    #[serde(alias = "Auth", default)]
+    pub auth: super::AuthConfig,
 }
 
 impl LedgerConfig {
@@ -117,18 +146,50 @@ impl LedgerConfig {
         //-- 01. Build Defaults
         let default_telemetry_level = telemetry::TelemetryConfig::default().telemetry_level();
 
-        let mut config_builder = Config::builder().set_default(
-            "telemetry.telemetry_level",
-            default_telemetry_level.to_string(),
-        )?;
+        let mut config_builder = Config::builder()
+            .set_default(
+                "telemetry.telemetry_level",
+                default_telemetry_level.to_string(),
+            )?
+            .set_default(
+                "telemetry.response_metadata",
+                telemetry::TelemetryConfig::default().response_metadata(),
+            )?
+            .set_default("server.address", super::server::DEFAULT_SERVER_ADDRESS)?
+            .set_default("server.port", super::server::DEFAULT_SERVER_PORT)?
+            .set_default("server.database_path", super::server::DEFAULT_DATABASE_PATH)?
+            .set_default(
+                "server.seed_categories",
+                super::server::DEFAULT_SEED_CATEGORIES,
+            )?
+            .set_default(
+                "database.journal_mode",
+                super::database::DEFAULT_JOURNAL_MODE.to_string(),
+            )?
+            .set_default(
+                "database.synchronous",
+                super::database::DEFAULT_SYNCHRONOUS.to_string(),
+            )?
+            .set_default(
+                "database.foreign_keys",
+                super::database::DEFAULT_FOREIGN_KEYS,
+            )?
+            .set_default(
+                "database.busy_timeout_ms",
+                super::database::DEFAULT_BUSY_TIMEOUT_MS,
+            )?
+            .set_default("database.cache_size", super::database::DEFAULT_CACHE_SIZE)?
+            .set_default(
+                "backup.interval_hours",
+                super::backup::DEFAULT_BACKUP_INTERVAL_HOURS,
+            )?
+            .set_default("backup.keep_daily", super::backup::DEFAULT_KEEP_DAILY)?
+            .set_default("backup.keep_weekly", super::backup::DEFAULT_KEEP_WEEKLY)?;
 
         //-- helper: read INI file and normalise section headers to lowercase
         let normalise_ini = |p: &Path| -> super::ConfigResult<String> {
             let content = std::fs::read_to_string(p).map_err(|e| {
-                super::ConfigError::Validation(format!(
-                    "Could not read config file {:?}: {}",
-                    p, e
-                ))
+                super::ConfigError::Validation(format!("Could not read config file {:?}: {}", p, e))
             })?;
 
             let normalised = content
@@ -152,25 +213,22 @@ impl LedgerConfig {
         //-- 02. System config directory (lowest precedence after defaults)
         if let Some(system_config) = Self::get_system_config_path().filter(|p| p.exists()) {
             let normalised = normalise_ini(&system_config)?;
-            config_builder = config_builder.add_source(
-                config::File::from_str(&normalised, config::FileFormat::Ini),
-            );
+            config_builder = config_builder
+                .add_source(config::File::from_str(&normalised, config::FileFormat::Ini));
         }
 
         //-- 03. User config directory
         if let Some(user_config) = Self::get_user_config_path().filter(|p| p.exists()) {
             let normalised = normalise_ini(&user_config)?;
-            config_builder = config_builder.add_source(
-                config::File::from_str(&normalised, config::FileFormat::Ini),
-            );
+            config_builder = config_builder
+                .add_source(config::File::from_str(&normalised, config::FileFormat::Ini));
         }
 
         //-- 04. Executable directory
         if let Some(exec_config) = Self::get_executable_config_path().filter(|p| p.exists()) {
             let normalised = normalise_ini(&exec_config)?;
-            config_builder = config_builder.add_source(
-                config::File::from_str(&normalised, config::FileFormat::Ini),
-            );
+            config_builder = config_builder
+                .add_source(config::File::from_str(&normalised, config::FileFormat::Ini));
         }
 
         //-- 05. Current working directory
@@ -181,17 +239,15 @@ impl LedgerConfig {
         };
         if let Some(cwd_config) = cwd_config.filter(|p| p.exists()) {
             let normalised = normalise_ini(&cwd_config)?;
-            config_builder = config_builder.add_source(
-                config::File::from_str(&normalised, config::FileFormat::Ini),
-            );
+            config_builder = config_builder
+                .add_source(config::File::from_str(&normalised, config::FileFormat::Ini));
         }
 
         //-- 06. Explicit config file
         if let Some(explicit_config) = config_file.filter(|p| p.exists()) {
             let normalised = normalise_ini(explicit_config)?;
-            config_builder = config_builder.add_source(
-                config::File::from_str(&normalised, config::FileFormat::Ini),
-            );
+            config_builder = config_builder
+                .add_source(config::File::from_str(&normalised, config::FileFormat::Ini));
         }
 
         //-- 07. Environment variables (highest precedence)
@@ -303,6 +359,112 @@ impl LedgerConfig {
     pub fn telemetry_config(&self) -> &lib_telemetry::TelemetryConfig {
         &self.telemetry
     }
+
+    /// Get the server configuration.
+    pub fn server_config(&self) -> &super::ServerConfig {
+        &self.server
+    }
+
+    /// Get the database connection settings.
+    pub fn database_config(&self) -> &super::DatabaseConfig {
+        &self.database
+    }
+
+    /// Get the backup directory, schedule and retention.
+    pub fn backup_config(&self) -> &super::BackupConfig {
+        &self.backup
+    }
+
+    /// Get the soft limits on what the ledger may hold.
+    pub fn quota_config(&self) -> &super::QuotaConfig {
+        &self.quota
+    }
+
+    /// Get whether the server runs as a public demo, and its reset schedule.
+    pub fn sandbox_config(&self) -> &super::SandboxConfig {
+        &self.sandbox
+    }
+
+    /// Get which bills are reminded about, and the channels reminders are
+    /// sent through.
+    pub fn notifications_config(&self) -> &super::NotificationsConfig {
+        &self.notifications
+    }
+
+    /// Get the endpoints changes to the ledger are posted to, and how
+    /// deliveries are retried.
+    pub fn webhooks_config(&self) -> &super::WebhooksConfig {
+        &self.webhooks
+    }
+
+    /// Get the API keys and JWT secret accepted as bearer tokens.
+    pub fn auth_config(&self) -> &super::AuthConfig {
+        &self.auth
+    }
+
+    /// Get the directory the application keeps its data in when running
+    /// standalone.
+    ///
+    /// - **Linux**: `~/.local/share/personal-ledger` (or `$XDG_DATA_HOME`)
+    /// - **macOS**: `~/Library/Application Support/personal-ledger`
+    /// - **Windows**: `%APPDATA%\personal-ledger`
+    pub fn data_directory() -> Option<PathBuf> {
+        dirs::data_dir().map(|data_dir| data_dir.join(APPLICATION_NAME))
+    }
+
+    /// Get the Unix socket a standalone server listens on when none is
+    /// configured, `personal-ledger.sock` in the
+    /// [data directory](Self::data_directory).
+    pub fn standalone_socket_path() -> Option<PathBuf> {
+        Self::data_directory().map(|data_directory| standalone_socket_in(&data_directory))
+    }
+
+    /// Adapts the configuration for running as the backend of a desktop app,
+    /// with everything kept in the [data directory](Self::data_directory).
+    ///
+    /// Settings left at their defaults are changed, values set by a config
+    /// file or the environment are kept:
+    ///
+    /// - the database is `personal_ledger.db` in the data directory
+    /// - the server listens on the Unix socket `personal-ledger.sock` in the
+    ///   data directory, trusting the local user, rather than a TCP port
+    /// - backups are written to `backups` in the data directory, turning
+    ///   scheduled backups on
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Validation`](super::ConfigError::Validation) if
+    /// the platform has no data directory.
+    pub fn standalone(self) -> super::ConfigResult<Self> {
+        let data_directory = Self::data_directory().ok_or_else(|| {
+            super::ConfigError::Validation(
+                "Could not find a data directory for standalone mode".to_string(),
+            )
+        })?;
+
+        Ok(self.standalone_in(&data_directory))
+    }
+
+    /// [`standalone`](Self::standalone), keeping data in `data_directory`.
+    fn standalone_in(mut self, data_directory: &Path) -> Self {
+        if self.server.database_path == Path::new(super::server::DEFAULT_DATABASE_PATH) {
+            self.server.database_path = data_directory.join(super::server::DEFAULT_DATABASE_PATH);
+        }
+        if self.server.socket_path.is_none() {
+            self.server.socket_path = Some(standalone_socket_in(data_directory));
+        }
+        if self.backup.directory.is_none() {
+            self.backup.directory = Some(data_directory.join("backups"));
+        }
+
+        self
+    }
+}
+
+/// The Unix socket a standalone server keeping data in `data_directory`
+/// listens on.
+fn standalone_socket_in(data_directory: &Path) -> PathBuf {
+    data_directory.join(format!("{}.sock", APPLICATION_NAME))
 }
 
 #[cfg(test)]
@@ -382,6 +544,15 @@ mod tests {
             config.telemetry.telemetry_level(),
             telemetry::TelemetryConfig::default().telemetry_level()
         );
+        assert!(!config.telemetry.response_metadata());
+        assert_eq!(config.server, crate::ServerConfig::default());
+        assert_eq!(config.database, crate::DatabaseConfig::default());
+        assert_eq!(config.backup, crate::BackupConfig::default());
+        assert_eq!(config.quota, crate::QuotaConfig::default());
+        assert_eq!(config.sandbox, crate::SandboxConfig::default());
+        assert_eq!(config.notifications, crate::NotificationsConfig::default());
+        assert_eq!(config.webhooks, crate::WebhooksConfig::default());
+        assert_eq!(config.auth, crate::AuthConfig::default());
 
         // Restore original directory
         std::env::set_current_dir(original_cwd).unwrap();
@@ -393,8 +564,7 @@ mod tests {
         let config_file = temp_dir.path().join("test.conf");
 
         // Create a config file with custom telemetry level (INI format)
-        let config_content = 
-        r#"
+        let config_content = r#"
         [telemetry]
         telemetry_level = "debug"
         "#;
@@ -409,6 +579,290 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_with_response_metadata_enabled() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("test.conf");
+
+        let config_content = r#"
+        [telemetry]
+        telemetry_level = "info"
+        response_metadata = true
+        "#;
+        fs::write(&config_file, config_content).unwrap();
+
+        let config = LedgerConfig::parse(Some(&config_file)).unwrap();
+        assert!(config.telemetry.response_metadata());
+    }
+
+    #[test]
+    fn parse_with_otlp_export() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("test.conf");
+
+        let config_content = r#"
+        [telemetry]
+        otlp_endpoint = "http://tempo:4318"
+        service_name = "ledger-staging"
+        sampling_ratio = 0.25
+        "#;
+        fs::write(&config_file, config_content).unwrap();
+
+        let config = LedgerConfig::parse(Some(&config_file)).unwrap();
+        assert_eq!(
+            config.telemetry.otlp_traces_endpoint().as_deref(),
+            Some("http://tempo:4318/v1/traces")
+        );
+        assert_eq!(config.telemetry.service_name(), "ledger-staging");
+        assert_eq!(config.telemetry.sampling_ratio(), 0.25);
+    }
+
+    #[test]
+    fn parse_with_server_section() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("test.conf");
+
+        let config_content = r#"
+        [Server]
+        address = "0.0.0.0"
+        port = 8080
+        database_path = "/var/lib/personal-ledger/ledger.db"
+        "#;
+        fs::write(&config_file, config_content).unwrap();
+
+        let config = LedgerConfig::parse(Some(&config_file)).unwrap();
+        let server = config.server_config();
+        assert_eq!(
+            server.socket_address().unwrap(),
+            "0.0.0.0:8080".parse().unwrap()
+        );
+        assert_eq!(
+            server.database_path,
+            PathBuf::from("/var/lib/personal-ledger/ledger.db")
+        );
+        assert!(server.receipt_maildir.is_none());
+    }
+
+    #[test]
+    fn parse_with_database_section() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("test.conf");
+
+        let config_content = r#"
+        [Database]
+        journal_mode = "wal"
+        synchronous = "normal"
+        busy_timeout_ms = 10000
+        "#;
+        fs::write(&config_file, config_content).unwrap();
+
+        let config = LedgerConfig::parse(Some(&config_file)).unwrap();
+        let database = config.database_config();
+        assert_eq!(database.journal_mode, crate::JournalModes::Wal);
+        assert_eq!(database.synchronous, crate::SynchronousModes::Normal);
+        assert_eq!(database.busy_timeout_ms, 10_000);
+        assert!(database.foreign_keys);
+        assert_eq!(
+            database.cache_size,
+            crate::DatabaseConfig::default().cache_size
+        );
+    }
+
+    #[test]
+    fn parse_with_backup_section() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("test.conf");
+
+        let config_content = r#"
+        [Backup]
+        directory = "/var/backups/personal-ledger"
+        keep_daily = 14
+        "#;
+        fs::write(&config_file, config_content).unwrap();
+
+        let config = LedgerConfig::parse(Some(&config_file)).unwrap();
+        let backup = config.backup_config();
+        assert_eq!(
+            backup.directory,
+            Some(PathBuf::from("/var/backups/personal-ledger"))
+        );
+        assert_eq!(backup.keep_daily, 14);
+        assert_eq!(
+            backup.keep_weekly,
+            crate::BackupConfig::default().keep_weekly
+        );
+        assert!(backup.is_scheduled());
+    }
+
+    #[test]
+    fn parse_with_quota_section() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("test.conf");
+
+        let config_content = r#"
+        [Quota]
+        max_transactions = 50000
+        max_attachment_bytes = 1073741824
+        "#;
+        fs::write(&config_file, config_content).unwrap();
+
+        let config = LedgerConfig::parse(Some(&config_file)).unwrap();
+        let quota = config.quota_config();
+        assert_eq!(quota.max_transactions, Some(50_000));
+        assert_eq!(quota.max_attachment_bytes, Some(1_073_741_824));
+        assert_eq!(quota.max_categories, None);
+    }
+
+    #[test]
+    fn parse_with_sandbox_section() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("test.conf");
+
+        let config_content = r#"
+        [Sandbox]
+        enabled = true
+        reset_interval_minutes = 30
+        "#;
+        fs::write(&config_file, config_content).unwrap();
+
+        let config = LedgerConfig::parse(Some(&config_file)).unwrap();
+        let sandbox = config.sandbox_config();
+        assert!(sandbox.enabled);
+        assert_eq!(
+            sandbox.reset_interval(),
+            Some(std::time::Duration::from_secs(30 * 60))
+        );
+    }
+
+    #[test]
+    fn parse_with_notifications_section() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("test.conf");
+
+        let config_content = r#"
+        [Notifications]
+        enabled = true
+        days_ahead = 5
+        webhook_url = "https://hooks.example.com/ledger"
+        smtp_host = "smtp.example.com"
+        email_from = "ledger@example.com"
+        email_to = "ian@example.com"
+        "#;
+        fs::write(&config_file, config_content).unwrap();
+
+        let config = LedgerConfig::parse(Some(&config_file)).unwrap();
+        let notifications = config.notifications_config();
+        assert!(notifications.enabled);
+        assert_eq!(notifications.days_ahead, 5);
+        assert_eq!(
+            notifications.webhook_url.as_deref(),
+            Some("https://hooks.example.com/ledger")
+        );
+        assert_eq!(
+            notifications.smtp_port,
+            crate::NotificationsConfig::default().smtp_port
+        );
+        assert!(notifications.is_email_configured());
+        assert!(notifications.log);
+    }
+
+    #[test]
+    fn parse_with_webhooks_section() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("test.conf");
+
+        let config_content = r#"
+        [Webhooks]
+        endpoints = "https://hooks.example.com/ledger, https://budget.example.com/events"
+        secret = "correct horse battery staple"
+        events = "category.*"
+        max_attempts = 3
+        "#;
+        fs::write(&config_file, config_content).unwrap();
+
+        let config = LedgerConfig::parse(Some(&config_file)).unwrap();
+        let webhooks = config.webhooks_config();
+        assert_eq!(
+            webhooks.endpoints(),
+            [
+                "https://hooks.example.com/ledger",
+                "https://budget.example.com/events"
+            ]
+        );
+        assert_eq!(
+            webhooks.secret.as_deref(),
+            Some("correct horse battery staple")
+        );
+        assert!(webhooks.sends("category.updated"));
+        assert!(!webhooks.sends("transaction.updated"));
+        assert_eq!(webhooks.max_attempts, 3);
+        assert_eq!(
+            webhooks.retry_backoff_seconds,
+            crate::WebhooksConfig::default().retry_backoff_seconds
+        );
+    }
+
+    #[test]
+    fn parse_with_auth_section() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("test.conf");
+
+        let config_content = r#"
+        [Auth]
+        api_keys_file = "/etc/personal-ledger/api-keys"
+        jwt_secret = "correct horse battery staple"
+        "#;
+        fs::write(&config_file, config_content).unwrap();
+
+        let config = LedgerConfig::parse(Some(&config_file)).unwrap();
+        let auth = config.auth_config();
+        assert!(auth.is_enabled());
+        assert_eq!(
+            auth.api_keys_file.as_deref(),
+            Some(Path::new("/etc/personal-ledger/api-keys"))
+        );
+        assert_eq!(
+            auth.jwt_secret.as_deref(),
+            Some("correct horse battery staple")
+        );
+    }
+
+    #[test]
+    fn standalone_keeps_data_in_data_directory() {
+        let data_directory = Path::new("/home/ian/.local/share/personal-ledger");
+
+        let config = LedgerConfig::default().standalone_in(data_directory);
+
+        assert_eq!(
+            config.server.database_path,
+            data_directory.join("personal_ledger.db")
+        );
+        assert_eq!(
+            config.server.socket_path,
+            Some(data_directory.join("personal-ledger.sock"))
+        );
+        assert_eq!(
+            config.backup.directory,
+            Some(data_directory.join("backups"))
+        );
+        assert!(config.backup.is_scheduled());
+    }
+
+    #[test]
+    fn standalone_keeps_configured_values() {
+        let mut config = LedgerConfig::default();
+        config.server.database_path = PathBuf::from("/srv/ledger.db");
+        config.server.socket_path = Some(PathBuf::from("/run/ledger.sock"));
+        config.backup.directory = Some(PathBuf::from("/srv/backups"));
+
+        let standalone = config
+            .clone()
+            .standalone_in(Path::new("/home/ian/.local/share/personal-ledger"));
+
+        assert_eq!(standalone.server, config.server);
+        assert_eq!(standalone.backup, config.backup);
+    }
+
     #[test]
     fn parse_with_nonexistent_explicit_file_returns_error() {
         let temp_dir = TempDir::new().unwrap();
@@ -424,8 +878,7 @@ mod tests {
         let config_file = temp_dir.path().join("test.conf");
 
         // Create config with [telemetry]
-        let config_content = 
-        r#"
+        let config_content = r#"
         [telemetry]
         telemetry_level = "info"
         "#;
@@ -449,9 +902,8 @@ mod tests {
         let config_file = temp_dir.path().join("invalid.conf");
 
         // Create invalid INI content
-        let config_content = 
-        r#"
-        [telemetry]
+        let config_content = r#"
+        [telemetry
         telemetry_level = "debug"
         "#; // Missing closing bracket
         fs::write(&config_file, config_content).unwrap();
@@ -466,8 +918,7 @@ mod tests {
         let config_file = temp_dir.path().join("invalid_level.conf");
 
         // Create config with invalid telemetry level
-        let config_content = 
-        r#"
+        let config_content = r#"
         [telemetry]
         telemetry_level = "invalid"
         "#;
@@ -487,8 +938,7 @@ mod tests {
         let config_dir = temp_dir.path().join("config");
         fs::create_dir(&config_dir).unwrap();
         let cwd_config_file = config_dir.join("personal-ledger.conf");
-        let cwd_content = 
-        r#"
+        let cwd_content = r#"
         [Telemetry]
         telemetry_level = "warn"
         "#;
@@ -496,8 +946,7 @@ mod tests {
 
         // Create explicit config with debug
         let explicit_file = temp_dir.path().join("explicit.conf");
-        let explicit_content = 
-        r#"
+        let explicit_content = r#"
         [Telemetry]
         telemetry_level = "debug"
         "#;
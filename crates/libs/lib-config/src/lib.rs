@@ -9,24 +9,60 @@
 //!
 //! - [`error`] - Configuration error types
 //! - [`ledger`] - Top-level application configuration
-//! - [`server`] - Server networking, TLS, and database path configuration
+//! - [`server`] - Server networking and database path configuration
+//! - [`database`] - SQLite journal, sync, locking and cache settings
+//! - [`backup`] - Backup directory, schedule and retention
+//! - [`quota`] - Soft limits on what a ledger may hold
+//! - [`sandbox`] - Public demo mode, reset to demo data on a schedule
+//! - [`notifications`] - Bill reminders and the channels they are sent through
+//! - [`webhooks`] - Endpoints changes to the ledger are posted to
+//! - [`auth`] - API keys and JWT secret accepted as bearer tokens
 //!
 //! ## Database Configuration
 //!
 //! Database configuration is handled through the `ServerConfig.database_path` field,
 //! which specifies the SQLite database file path. The `ServerConfig::database_url()`
 //! method constructs the appropriate SQLx connection URL for SQLite databases.
+//! `DatabaseConfig` holds the SQLite `PRAGMA` settings, such as the journal
+//! mode, applied to every connection the pool opens.
 
+mod auth;
+mod backup;
+mod database;
 mod error;
 mod ledger;
-
+mod notifications;
+mod quota;
+mod sandbox;
+mod server;
+mod webhooks;
 
 /// Configuration loading and validation errors.
-pub use error::{ConfigResult, ConfigError};
+pub use error::{ConfigError, ConfigResult};
 
 // /// The top-level application configuration type.
 pub use ledger::LedgerConfig;
 
-// mod server;
-// /// Server-specific configuration values and defaults.
-// pub use server::ServerConfig;
+/// Server-specific configuration values and defaults.
+pub use server::ServerConfig;
+
+/// SQLite connection settings applied to every database connection.
+pub use database::{DatabaseConfig, JournalModes, SynchronousModes};
+
+/// Database backup directory, schedule and retention.
+pub use backup::BackupConfig;
+
+/// Soft limits on the transactions, attachments and categories a ledger holds.
+pub use quota::QuotaConfig;
+
+/// Public demo mode, seeding demo data and resetting it on a schedule.
+pub use sandbox::SandboxConfig;
+
+/// Bill reminders, sent through the log, a webhook or email.
+pub use notifications::NotificationsConfig;
+
+/// Webhook endpoints, the events posted to them and how deliveries are retried.
+pub use webhooks::WebhooksConfig;
+
+/// API keys and the JWT secret the server accepts as bearer tokens.
+pub use auth::AuthConfig;
@@ -0,0 +1,144 @@
+//! # Notifications Configuration Types and Defaults
+//!
+//! This module defines [`NotificationsConfig`], which bills are reminded
+//! about and where the reminders are sent, read from the `[Notifications]`
+//! section of a config file.
+//!
+//! Reminders are off unless enabled. Once enabled the server checks its
+//! recurring transactions on a schedule and sends a reminder for each one due
+//! within `days_ahead` days, through every channel that is set up: the log,
+//! a webhook the reminder is posted to as JSON, and email sent over SMTP.
+
+/// Default number of days before a bill is due to remind about it.
+pub const DEFAULT_DAYS_AHEAD: u32 = 3;
+
+/// Default minutes between checks for bills to remind about.
+pub const DEFAULT_NOTIFICATION_INTERVAL_MINUTES: u64 = 60;
+
+/// Default SMTP submission port, upgraded to TLS with `STARTTLS`.
+pub const DEFAULT_SMTP_PORT: u16 = 587;
+
+/// Which bills are reminded about, how often they are checked for and the
+/// channels reminders are sent through.
+///
+/// # Example
+///
+/// ```rust
+/// use lib_config::NotificationsConfig;
+///
+/// let config = NotificationsConfig {
+///     enabled: true,
+///     webhook_url: Some("https://hooks.example.com/ledger".to_string()),
+///     ..NotificationsConfig::default()
+/// };
+/// assert_eq!(config.interval(), Some(std::time::Duration::from_secs(60 * 60)));
+/// assert!(config.log);
+/// assert!(!config.is_email_configured());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct NotificationsConfig {
+    /// Whether bill reminders are sent.
+    pub enabled: bool,
+
+    /// How many days before a bill is due to remind about it, `0` to remind
+    /// on the day.
+    pub days_ahead: u32,
+
+    /// Minutes between checks for bills to remind about, `0` to only check
+    /// on startup.
+    pub interval_minutes: u64,
+
+    /// Whether reminders are written to the server log.
+    pub log: bool,
+
+    /// URL reminders are posted to as JSON. Only used with the
+    /// `webhook-notifications` feature.
+    pub webhook_url: Option<String>,
+
+    /// SMTP server reminders are emailed through. Email is sent once the
+    /// server, `email_from` and `email_to` are all set. Only used with the
+    /// `email-notifications` feature.
+    pub smtp_host: Option<String>,
+
+    /// Port of the SMTP server.
+    pub smtp_port: u16,
+
+    /// User name to log in to the SMTP server with, if it needs one.
+    pub smtp_username: Option<String>,
+
+    /// Password to log in to the SMTP server with.
+    pub smtp_password: Option<String>,
+
+    /// Address reminders are emailed from.
+    pub email_from: Option<String>,
+
+    /// Address reminders are emailed to.
+    pub email_to: Option<String>,
+}
+
+impl Default for NotificationsConfig {
+    /// Reminders are off, and once enabled are logged an hour at a time for
+    /// bills due in the next three days.
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            days_ahead: DEFAULT_DAYS_AHEAD,
+            interval_minutes: DEFAULT_NOTIFICATION_INTERVAL_MINUTES,
+            log: true,
+            webhook_url: None,
+            smtp_host: None,
+            smtp_port: DEFAULT_SMTP_PORT,
+            smtp_username: None,
+            smtp_password: None,
+            email_from: None,
+            email_to: None,
+        }
+    }
+}
+
+impl NotificationsConfig {
+    /// Time between checks for bills to remind about, or `None` when
+    /// reminders are off or only checked on startup.
+    pub fn interval(&self) -> Option<std::time::Duration> {
+        (self.enabled && self.interval_minutes > 0)
+            .then(|| std::time::Duration::from_secs(self.interval_minutes * 60))
+    }
+
+    /// Whether there is enough set up to send reminders by email.
+    pub fn is_email_configured(&self) -> bool {
+        self.smtp_host.is_some() && self.email_from.is_some() && self.email_to.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_notifications_config_is_off() {
+        let config = NotificationsConfig::default();
+
+        assert!(!config.enabled);
+        assert_eq!(config.days_ahead, DEFAULT_DAYS_AHEAD);
+        assert_eq!(config.interval(), None);
+        assert!(config.webhook_url.is_none());
+        assert!(!config.is_email_configured());
+    }
+
+    #[test]
+    fn email_needs_a_server_and_both_addresses() {
+        let config = NotificationsConfig {
+            smtp_host: Some("smtp.example.com".to_string()),
+            email_from: Some("ledger@example.com".to_string()),
+            ..NotificationsConfig::default()
+        };
+        assert!(!config.is_email_configured());
+
+        let config = NotificationsConfig {
+            email_to: Some("ian@example.com".to_string()),
+            ..config
+        };
+        assert!(config.is_email_configured());
+    }
+}
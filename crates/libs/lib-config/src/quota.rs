@@ -0,0 +1,74 @@
+//! # Quota Configuration Types and Defaults
+//!
+//! This module defines [`QuotaConfig`], soft limits on what one ledger may
+//! hold, read from the `[Quota]` section of a config file. They are useful
+//! when hosting the server for several family members, so one ledger cannot
+//! fill the disk.
+//!
+//! Every limit is off until it is set. The server checks a limit before
+//! adding records and refuses the request once it would be passed. The
+//! limits are soft: requests running at the same moment are checked against
+//! the same count, so a ledger can end up a few records over.
+
+/// Soft limits on what one ledger may hold.
+///
+/// # Example
+///
+/// ```rust
+/// use lib_config::QuotaConfig;
+///
+/// let config = QuotaConfig {
+///     max_transactions: Some(100_000),
+///     ..QuotaConfig::default()
+/// };
+/// assert!(config.allows(config.max_transactions, 99_999, 1));
+/// assert!(!config.allows(config.max_transactions, 99_999, 2));
+/// assert!(config.allows(config.max_categories, 1_000_000, 1));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct QuotaConfig {
+    /// The most transactions a ledger may hold, drafts included.
+    #[serde(default)]
+    pub max_transactions: Option<u64>,
+
+    /// The most bytes of receipt attachments a ledger may store.
+    #[serde(default)]
+    pub max_attachment_bytes: Option<u64>,
+
+    /// The most categories a ledger may hold, not counting soft-deleted ones.
+    #[serde(default)]
+    pub max_categories: Option<u64>,
+}
+
+impl QuotaConfig {
+    /// Whether adding `adding` to `used` stays within `limit`, always true
+    /// when the limit is off.
+    pub fn allows(&self, limit: Option<u64>, used: u64, adding: u64) -> bool {
+        limit.is_none_or(|limit| used.saturating_add(adding) <= limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_quota_config_is_unlimited() {
+        let config = QuotaConfig::default();
+
+        assert_eq!(config.max_transactions, None);
+        assert!(config.allows(config.max_transactions, u64::MAX, 1));
+    }
+
+    #[test]
+    fn allows_up_to_the_limit() {
+        let config = QuotaConfig {
+            max_categories: Some(10),
+            ..QuotaConfig::default()
+        };
+
+        assert!(config.allows(config.max_categories, 9, 1));
+        assert!(!config.allows(config.max_categories, 10, 1));
+        assert!(!config.allows(config.max_categories, 0, 11));
+    }
+}
@@ -0,0 +1,85 @@
+//! # Sandbox Configuration Types and Defaults
+//!
+//! This module defines [`SandboxConfig`], which turns the server into a
+//! public demo, read from the `[Sandbox]` section of a config file.
+//!
+//! In sandbox mode the server seeds its database with demo data on startup
+//! and resets it on a schedule, throwing away whatever visitors changed, and
+//! refuses the admin RPCs, such as deletes and backups, so visitors cannot
+//! get in each other's way between resets. Sandbox mode is off unless it is
+//! enabled, and must only be used with a database that is meant to be thrown
+//! away.
+
+/// Default minutes between resets of a sandbox database.
+pub const DEFAULT_RESET_INTERVAL_MINUTES: u64 = 60;
+
+/// Whether the server runs as a public demo, and how often it is reset.
+///
+/// # Example
+///
+/// ```rust
+/// use lib_config::SandboxConfig;
+///
+/// let config = SandboxConfig {
+///     enabled: true,
+///     ..SandboxConfig::default()
+/// };
+/// assert_eq!(config.reset_interval(), Some(std::time::Duration::from_secs(60 * 60)));
+/// assert_eq!(SandboxConfig::default().reset_interval(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct SandboxConfig {
+    /// Whether sandbox mode is on. Wipes the database on startup.
+    pub enabled: bool,
+
+    /// Minutes between resets of the database to the demo data, `0` to only
+    /// reset on startup.
+    pub reset_interval_minutes: u64,
+}
+
+impl Default for SandboxConfig {
+    /// Sandbox mode is off, and once enabled resets the database hourly.
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            reset_interval_minutes: DEFAULT_RESET_INTERVAL_MINUTES,
+        }
+    }
+}
+
+impl SandboxConfig {
+    /// Time between scheduled resets, or `None` when sandbox mode is off or
+    /// only resets on startup.
+    pub fn reset_interval(&self) -> Option<std::time::Duration> {
+        (self.enabled && self.reset_interval_minutes > 0)
+            .then(|| std::time::Duration::from_secs(self.reset_interval_minutes * 60))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_sandbox_config_is_off() {
+        let config = SandboxConfig::default();
+
+        assert!(!config.enabled);
+        assert_eq!(
+            config.reset_interval_minutes,
+            DEFAULT_RESET_INTERVAL_MINUTES
+        );
+        assert_eq!(config.reset_interval(), None);
+    }
+
+    #[test]
+    fn zero_interval_only_resets_on_startup() {
+        let config = SandboxConfig {
+            enabled: true,
+            reset_interval_minutes: 0,
+        };
+
+        assert_eq!(config.reset_interval(), None);
+    }
+}
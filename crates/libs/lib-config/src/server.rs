@@ -0,0 +1,360 @@
+//! # Server Configuration Types and Defaults
+//!
+//! This module defines [`ServerConfig`] and related constants for configuring the
+//! Personal Ledger gRPC server. All values here represent program defaults and are
+//! typically overridden by the `[Server]` section of a config file or by environment
+//! variables prefixed with `PERSONAL_LEDGER`.
+//!
+//! The configuration is designed for SQLite-backed deployments and holds the bind
+//! address, port and database path.
+
+use std::path::{Path, PathBuf};
+
+use super::{ConfigError, ConfigResult};
+
+/// Default server bind address used when no value is provided by file or env.
+///
+/// Defaults to `"127.0.0.1"` (localhost) for security, ensuring the server only
+/// accepts local connections unless explicitly configured otherwise.
+pub const DEFAULT_SERVER_ADDRESS: &str = "127.0.0.1";
+
+/// Default server port used when no value is provided by file or env.
+///
+/// Defaults to `50059`, within the user port range (49152-65535) to avoid
+/// conflicts with well-known services. A port of `0` binds an ephemeral port
+/// chosen by the operating system.
+pub const DEFAULT_SERVER_PORT: u16 = 50059;
+
+/// Default SQLite database path.
+///
+/// Defaults to `"personal_ledger.db"` in the current working directory. This can
+/// be overridden to use a different filename or absolute path. For in-memory
+/// databases, set this to `":memory:"` in the configuration.
+pub const DEFAULT_DATABASE_PATH: &str = "personal_ledger.db";
+
+/// Whether a ledger without categories is given the standard chart of
+/// categories when the server starts.
+///
+/// Defaults to `true`, so a new ledger starts with categories to choose from.
+pub const DEFAULT_SEED_CATEGORIES: bool = true;
+
+/// Database path that selects an in-memory SQLite database.
+pub const IN_MEMORY_DATABASE_PATH: &str = ":memory:";
+
+/// Server-specific configuration values for the Personal Ledger server.
+///
+/// `ServerConfig` holds the host/port the gRPC server will bind to and the SQLite
+/// database path. Fields are intentionally simple to allow easy deserialisation
+/// from config files or environment sources.
+///
+/// # Example
+///
+/// ```rust
+/// use lib_config::ServerConfig;
+///
+/// let config = ServerConfig::default();
+/// assert_eq!(config.address, "127.0.0.1");
+/// assert_eq!(config.port, 50059);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct ServerConfig {
+    /// The IP address to bind the server to.
+    /// Defaults to "127.0.0.1".
+    pub address: String,
+
+    /// The port number to bind the server on, `0` for an ephemeral port.
+    pub port: u16,
+
+    /// Path to the SQLite database file, or `":memory:"` for an in-memory
+    /// database. Defaults to "personal_ledger.db" in the current directory.
+    pub database_path: PathBuf,
+
+    /// Load the standard chart of categories into a ledger that has no
+    /// categories yet, such as on the first start. Defaults to `true`.
+    pub seed_categories: bool,
+
+    /// Maildir that forwarded receipt emails are delivered to. The receipt
+    /// inbox is only started when this is set and the server was built with
+    /// the `receipt-email` feature.
+    #[serde(default)]
+    pub receipt_maildir: Option<PathBuf>,
+
+    /// Unix domain socket to listen on instead of the address and port.
+    /// Only the user the server runs as can connect, and that user is
+    /// recorded as the actor of changes that do not name one.
+    #[serde(default)]
+    pub socket_path: Option<PathBuf>,
+
+    /// PEM certificate chain the server presents, turning on TLS for the
+    /// address and port. Set together with `tls_key`.
+    #[serde(default)]
+    pub tls_cert: Option<PathBuf>,
+
+    /// PEM private key of `tls_cert`.
+    #[serde(default)]
+    pub tls_key: Option<PathBuf>,
+
+    /// PEM certificate of the authority client certificates must be signed
+    /// by. When set, clients without a valid certificate are turned away.
+    #[serde(default)]
+    pub client_ca: Option<PathBuf>,
+
+    /// Port of the REST/JSON gateway, on the same address as the gRPC
+    /// server. The gateway is only started when this is set and the server
+    /// was built with the `rest-gateway` feature.
+    #[serde(default)]
+    pub gateway_port: Option<u16>,
+}
+
+impl Default for ServerConfig {
+    /// Creates a default server configuration suitable for local development.
+    ///
+    /// Binds to `127.0.0.1:50059` (localhost only) with the SQLite database at
+    /// `personal_ledger.db`, seeded with the standard chart of categories, with
+    /// no receipt inbox, no Unix socket, no TLS and no REST gateway.
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_SERVER_ADDRESS.to_string(),
+            port: DEFAULT_SERVER_PORT,
+            database_path: PathBuf::from(DEFAULT_DATABASE_PATH),
+            seed_categories: DEFAULT_SEED_CATEGORIES,
+            receipt_maildir: None,
+            socket_path: None,
+            tls_cert: None,
+            tls_key: None,
+            client_ca: None,
+            gateway_port: None,
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Creates a configuration for an in-memory database on an ephemeral
+    /// localhost port, for running the server in tests. The database starts
+    /// empty, without the standard chart of categories.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_config::ServerConfig;
+    ///
+    /// let config = ServerConfig::ephemeral();
+    /// assert_eq!(config.port, 0);
+    /// assert_eq!(config.database_url().unwrap(), "sqlite::memory:");
+    /// assert!(!config.seed_categories);
+    /// ```
+    pub fn ephemeral() -> Self {
+        Self {
+            port: 0,
+            database_path: PathBuf::from(IN_MEMORY_DATABASE_PATH),
+            seed_categories: false,
+            ..Self::default()
+        }
+    }
+
+    /// Build and return the bind `SocketAddr` for the server.
+    ///
+    /// Combines the configured `address` and `port` fields into a socket address
+    /// that can be used to bind the gRPC server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidServerAddress`] if the address is not a
+    /// valid IP address.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_config::ServerConfig;
+    ///
+    /// let config = ServerConfig::default();
+    /// let addr = config.socket_address().expect("default config should parse");
+    /// assert_eq!(addr.port(), 50059);
+    /// ```
+    pub fn socket_address(&self) -> ConfigResult<std::net::SocketAddr> {
+        let address = format!("{}:{}", self.address, self.port).parse()?;
+        Ok(address)
+    }
+
+    /// Whether the database is held in memory and lost when the server stops.
+    pub fn is_in_memory(&self) -> bool {
+        self.database_path.as_os_str() == IN_MEMORY_DATABASE_PATH
+    }
+
+    /// The certificate and private key the server presents, or `None` when
+    /// TLS is off.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Validation`] when only one of `tls_cert` and
+    /// `tls_key` is set, or `client_ca` is set without them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_config::ServerConfig;
+    ///
+    /// let mut config = ServerConfig::default();
+    /// assert_eq!(config.tls_identity().unwrap(), None);
+    ///
+    /// config.tls_cert = Some("server.pem".into());
+    /// assert!(config.tls_identity().is_err());
+    ///
+    /// config.tls_key = Some("server.key".into());
+    /// assert!(config.tls_identity().unwrap().is_some());
+    /// ```
+    pub fn tls_identity(&self) -> ConfigResult<Option<(&Path, &Path)>> {
+        match (&self.tls_cert, &self.tls_key) {
+            (Some(cert), Some(key)) => Ok(Some((cert, key))),
+            (None, None) if self.client_ca.is_some() => Err(ConfigError::Validation(
+                "client_ca needs tls_cert and tls_key to be set".to_string(),
+            )),
+            (None, None) => Ok(None),
+            _ => Err(ConfigError::Validation(
+                "tls_cert and tls_key must be set together".to_string(),
+            )),
+        }
+    }
+
+    /// Constructs a SQLite connection URL from the configured database path.
+    ///
+    /// File databases are opened in read-write-create mode, so the database
+    /// is created on first start.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Validation`] when the configured database path is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_config::ServerConfig;
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let mut config = ServerConfig::default();
+    /// assert_eq!(config.database_url().unwrap(), "sqlite://personal_ledger.db?mode=rwc");
+    ///
+    /// config.database_path = PathBuf::from(":memory:");
+    /// assert_eq!(config.database_url().unwrap(), "sqlite::memory:");
+    /// ```
+    pub fn database_url(&self) -> ConfigResult<String> {
+        if self.is_in_memory() {
+            return Ok("sqlite::memory:".to_string());
+        }
+
+        let path = self.database_path.to_string_lossy();
+        if path.trim().is_empty() {
+            return Err(ConfigError::Validation(
+                "database path cannot be empty".to_string(),
+            ));
+        }
+
+        Ok(format!("sqlite://{}?mode=rwc", path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_server_config_values() {
+        let config = ServerConfig::default();
+        assert_eq!(config.address, DEFAULT_SERVER_ADDRESS);
+        assert_eq!(config.port, DEFAULT_SERVER_PORT);
+        assert_eq!(config.database_path, PathBuf::from(DEFAULT_DATABASE_PATH));
+        assert!(config.seed_categories);
+        assert!(config.receipt_maildir.is_none());
+        assert!(config.socket_path.is_none());
+        assert_eq!(config.tls_identity().unwrap(), None);
+        assert!(!config.is_in_memory());
+    }
+
+    #[test]
+    fn socket_address_parses_address_and_port() {
+        let config = ServerConfig {
+            address: "0.0.0.0".to_string(),
+            port: 8080,
+            ..ServerConfig::default()
+        };
+
+        let addr = config.socket_address().expect("address should parse");
+        assert_eq!(addr, "0.0.0.0:8080".parse().unwrap());
+    }
+
+    #[test]
+    fn socket_address_fails_for_invalid_address() {
+        let config = ServerConfig {
+            address: "not_an_ip".to_string(),
+            ..ServerConfig::default()
+        };
+
+        assert!(matches!(
+            config.socket_address(),
+            Err(ConfigError::InvalidServerAddress(_))
+        ));
+    }
+
+    #[test]
+    fn tls_identity_needs_cert_and_key() {
+        let config = ServerConfig {
+            tls_cert: Some(PathBuf::from("server.pem")),
+            tls_key: Some(PathBuf::from("server.key")),
+            client_ca: Some(PathBuf::from("clients.pem")),
+            ..ServerConfig::default()
+        };
+        assert_eq!(
+            config.tls_identity().unwrap(),
+            Some((Path::new("server.pem"), Path::new("server.key")))
+        );
+
+        let key_only = ServerConfig {
+            tls_key: Some(PathBuf::from("server.key")),
+            ..ServerConfig::default()
+        };
+        assert!(matches!(
+            key_only.tls_identity(),
+            Err(ConfigError::Validation(_))
+        ));
+
+        let client_ca_only = ServerConfig {
+            client_ca: Some(PathBuf::from("clients.pem")),
+            ..ServerConfig::default()
+        };
+        assert!(matches!(
+            client_ca_only.tls_identity(),
+            Err(ConfigError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn database_url_uses_database_path() {
+        let config = ServerConfig {
+            database_path: PathBuf::from("/var/lib/ledger/custom.db"),
+            ..ServerConfig::default()
+        };
+        assert_eq!(
+            config.database_url().unwrap(),
+            "sqlite:///var/lib/ledger/custom.db?mode=rwc"
+        );
+
+        assert_eq!(
+            ServerConfig::ephemeral().database_url().unwrap(),
+            "sqlite::memory:"
+        );
+        assert!(ServerConfig::ephemeral().is_in_memory());
+    }
+
+    #[test]
+    fn database_url_returns_error_for_empty_path() {
+        let config = ServerConfig {
+            database_path: PathBuf::from(""),
+            ..ServerConfig::default()
+        };
+
+        match config.database_url() {
+            Err(ConfigError::Validation(msg)) => assert_eq!(msg, "database path cannot be empty"),
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! # Webhooks Configuration Types and Defaults
+//!
+//! This module defines [`WebhooksConfig`], the endpoints changes to the
+//! ledger are posted to and how deliveries are signed and retried, read from
+//! the `[Webhooks]` section of a config file.
+//!
+//! Webhooks are off until an endpoint is set. Lists, of endpoints and of the
+//! events to send, are written comma separated, as INI has no lists:
+//!
+//! ```ini
+//! [Webhooks]
+//! endpoints = "https://hooks.example.com/ledger, https://budget.example.com/events"
+//! secret = "a long random string"
+//! events = "category.*, transaction.created"
+//! ```
+
+/// Default events posted to webhooks, every event.
+pub const DEFAULT_WEBHOOK_EVENTS: &str = "*";
+
+/// Default number of times a delivery is tried before it is given up on.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+/// Default seconds to wait before the first retry, doubled for each one after.
+pub const DEFAULT_RETRY_BACKOFF_SECONDS: u64 = 2;
+
+/// Default seconds to wait for an endpoint to answer.
+pub const DEFAULT_WEBHOOK_TIMEOUT_SECONDS: u64 = 10;
+
+/// Longest wait between retries, however many there have been.
+pub const MAX_RETRY_BACKOFF: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// The endpoints changes to the ledger are posted to, the events posted and
+/// how failed deliveries are retried.
+///
+/// # Example
+///
+/// ```rust
+/// use lib_config::WebhooksConfig;
+///
+/// let config = WebhooksConfig {
+///     endpoints: Some("https://hooks.example.com/ledger".to_string()),
+///     events: "category.*".to_string(),
+///     ..WebhooksConfig::default()
+/// };
+/// assert!(config.is_enabled());
+/// assert!(config.sends("category.created"));
+/// assert!(!config.sends("transaction.created"));
+/// assert_eq!(config.retry_delay(3), std::time::Duration::from_secs(8));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct WebhooksConfig {
+    /// URLs events are posted to, comma separated. Webhooks are off when
+    /// unset. Only used with the `webhooks` feature.
+    pub endpoints: Option<String>,
+
+    /// Secret deliveries are signed with, shared with the endpoints so they
+    /// can check a delivery came from the ledger. Required once endpoints
+    /// are set.
+    pub secret: Option<String>,
+
+    /// Events posted, comma separated. A name ending in `.*` matches every
+    /// event of a record, such as `category.*`, and `*` matches every event.
+    pub events: String,
+
+    /// Times a delivery is tried before it is given up on.
+    pub max_attempts: u32,
+
+    /// Seconds to wait before the first retry, doubled for each one after.
+    pub retry_backoff_seconds: u64,
+
+    /// Seconds to wait for an endpoint to answer before the attempt fails.
+    pub timeout_seconds: u64,
+}
+
+impl Default for WebhooksConfig {
+    /// Webhooks are off, and once an endpoint is set every event is posted,
+    /// tried up to five times.
+    fn default() -> Self {
+        Self {
+            endpoints: None,
+            secret: None,
+            events: DEFAULT_WEBHOOK_EVENTS.to_string(),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_backoff_seconds: DEFAULT_RETRY_BACKOFF_SECONDS,
+            timeout_seconds: DEFAULT_WEBHOOK_TIMEOUT_SECONDS,
+        }
+    }
+}
+
+impl WebhooksConfig {
+    /// The URLs events are posted to.
+    pub fn endpoints(&self) -> Vec<&str> {
+        split_list(self.endpoints.as_deref().unwrap_or_default())
+    }
+
+    /// Whether any endpoint is set.
+    pub fn is_enabled(&self) -> bool {
+        !self.endpoints().is_empty()
+    }
+
+    /// Whether the event called `name` is posted.
+    pub fn sends(&self, name: &str) -> bool {
+        split_list(&self.events)
+            .into_iter()
+            .any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => name.starts_with(prefix),
+                None => name == pattern,
+            })
+    }
+
+    /// Time to wait before trying a delivery again after `attempt` failed
+    /// attempts, doubling each time up to [`MAX_RETRY_BACKOFF`].
+    pub fn retry_delay(&self, attempt: u32) -> std::time::Duration {
+        let factor = 2u64.saturating_pow(attempt.saturating_sub(1));
+        std::time::Duration::from_secs(self.retry_backoff_seconds.saturating_mul(factor))
+            .min(MAX_RETRY_BACKOFF)
+    }
+
+    /// Time to wait for an endpoint to answer.
+    pub fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.timeout_seconds)
+    }
+}
+
+/// Splits a comma separated list, dropping blank entries.
+fn split_list(list: &str) -> Vec<&str> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_webhooks_config_is_off() {
+        let config = WebhooksConfig::default();
+
+        assert!(!config.is_enabled());
+        assert!(config.sends("transaction.deleted"));
+        assert_eq!(config.max_attempts, DEFAULT_MAX_ATTEMPTS);
+    }
+
+    #[test]
+    fn endpoints_and_events_are_comma_separated() {
+        let config = WebhooksConfig {
+            endpoints: Some(" https://a.example.com ,, https://b.example.com".to_string()),
+            events: "category.*, transaction.created".to_string(),
+            ..WebhooksConfig::default()
+        };
+
+        assert_eq!(
+            config.endpoints(),
+            ["https://a.example.com", "https://b.example.com"]
+        );
+        assert!(config.sends("category.restored"));
+        assert!(config.sends("transaction.created"));
+        assert!(!config.sends("transaction.deleted"));
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_cap() {
+        let config = WebhooksConfig::default();
+
+        assert_eq!(config.retry_delay(1), std::time::Duration::from_secs(2));
+        assert_eq!(config.retry_delay(2), std::time::Duration::from_secs(4));
+        assert_eq!(config.retry_delay(40), MAX_RETRY_BACKOFF);
+    }
+}
@@ -0,0 +1,18 @@
+-- ./migrations/0001_categories.sql
+--
+-- Financial categories used to classify transactions and accounts.
+-- Row ids are UUID v7 strings, timestamps are RFC 3339 strings.
+
+CREATE TABLE IF NOT EXISTS categories (
+    id              TEXT PRIMARY KEY NOT NULL,
+    code            TEXT NOT NULL UNIQUE,
+    name            TEXT NOT NULL UNIQUE,
+    description     TEXT,
+    url_slug        TEXT UNIQUE,
+    category_type   TEXT NOT NULL,
+    color           TEXT,
+    icon            TEXT,
+    is_active       BOOLEAN NOT NULL DEFAULT 1,
+    created_on      TEXT NOT NULL,
+    updated_on      TEXT NOT NULL
+);
@@ -0,0 +1,25 @@
+-- ./migrations/0002_journal.sql
+--
+-- Double-entry journal. Each journal entry owns two or more splits, every split
+-- debits (positive amount) or credits (negative amount) a category in minor
+-- units. The splits of an entry must sum to zero; the invariant is checked by
+-- lib-database inside the transaction that writes the splits.
+
+CREATE TABLE IF NOT EXISTS journal_entries (
+    id              TEXT PRIMARY KEY NOT NULL,
+    entry_date      TEXT NOT NULL,
+    description     TEXT,
+    created_on      TEXT NOT NULL,
+    updated_on      TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS journal_splits (
+    id              TEXT PRIMARY KEY NOT NULL,
+    entry_id        TEXT NOT NULL REFERENCES journal_entries (id) ON DELETE CASCADE,
+    category_id     TEXT NOT NULL REFERENCES categories (id),
+    amount          INTEGER NOT NULL CHECK (amount <> 0),
+    memo            TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_journal_splits_entry_id ON journal_splits (entry_id);
+CREATE INDEX IF NOT EXISTS idx_journal_splits_category_id ON journal_splits (category_id);
@@ -0,0 +1,19 @@
+-- ./migrations/0003_transactions.sql
+--
+-- Financial transactions recorded against the ledger. Each transaction is a
+-- single signed amount in minor units (negative for money spent, positive for
+-- money received), optionally classified by a category.
+
+CREATE TABLE IF NOT EXISTS transactions (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    transaction_date    TEXT NOT NULL,
+    payee               TEXT NOT NULL,
+    description         TEXT,
+    amount              INTEGER NOT NULL CHECK (amount <> 0),
+    category_id         TEXT REFERENCES categories (id),
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_transactions_transaction_date ON transactions (transaction_date);
+CREATE INDEX IF NOT EXISTS idx_transactions_category_id ON transactions (category_id);
@@ -0,0 +1,19 @@
+-- ./migrations/0004_accounts.sql
+--
+-- Accounts that money is held in or owed on (e.g. a bank account or credit
+-- card). An account's balance is its opening balance plus the sum of the
+-- transactions posted to it, all in minor units.
+
+CREATE TABLE IF NOT EXISTS accounts (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    name                TEXT NOT NULL UNIQUE,
+    description         TEXT,
+    opening_balance     INTEGER NOT NULL DEFAULT 0,
+    is_active           BOOLEAN NOT NULL DEFAULT 1,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL
+);
+
+ALTER TABLE transactions ADD COLUMN account_id TEXT REFERENCES accounts (id);
+
+CREATE INDEX IF NOT EXISTS idx_transactions_account_id ON transactions (account_id);
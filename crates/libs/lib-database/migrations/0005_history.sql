@@ -0,0 +1,201 @@
+-- ./migrations/0005_history.sql
+--
+-- Row version history for categories, accounts and transactions, used to
+-- reproduce the ledger exactly as it was recorded at a past point in time.
+--
+-- Each history row is one version of a record, valid from `valid_from` until
+-- `valid_to` (NULL while it is the current version). Triggers keep the history
+-- in step with every insert, update and delete, so it cannot be bypassed by
+-- application code. History timestamps use SQLite's clock in the fixed format
+-- YYYY-MM-DDTHH:MM:SS.SSSZ so they compare correctly as text.
+
+-- ----------------------------- [ CATEGORIES ] --------------------------------
+
+CREATE TABLE IF NOT EXISTS categories_history (
+    history_id      INTEGER PRIMARY KEY AUTOINCREMENT,
+    id              TEXT NOT NULL,
+    code            TEXT NOT NULL,
+    name            TEXT NOT NULL,
+    description     TEXT,
+    url_slug        TEXT,
+    category_type   TEXT NOT NULL,
+    color           TEXT,
+    icon            TEXT,
+    is_active       BOOLEAN NOT NULL,
+    created_on      TEXT NOT NULL,
+    updated_on      TEXT NOT NULL,
+    valid_from      TEXT NOT NULL,
+    valid_to        TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_categories_history_id ON categories_history (id, valid_from);
+
+INSERT INTO categories_history (
+    id, code, name, description, url_slug, category_type, color, icon, is_active,
+    created_on, updated_on, valid_from
+)
+SELECT
+    id, code, name, description, url_slug, category_type, color, icon, is_active,
+    created_on, updated_on, strftime('%Y-%m-%dT%H:%M:%fZ', updated_on)
+FROM categories;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_insert AFTER INSERT ON categories
+BEGIN
+    INSERT INTO categories_history (
+        id, code, name, description, url_slug, category_type, color, icon, is_active,
+        created_on, updated_on, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.code, NEW.name, NEW.description, NEW.url_slug, NEW.category_type,
+        NEW.color, NEW.icon, NEW.is_active, NEW.created_on, NEW.updated_on,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_update AFTER UPDATE ON categories
+BEGIN
+    UPDATE categories_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO categories_history (
+        id, code, name, description, url_slug, category_type, color, icon, is_active,
+        created_on, updated_on, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.code, NEW.name, NEW.description, NEW.url_slug, NEW.category_type,
+        NEW.color, NEW.icon, NEW.is_active, NEW.created_on, NEW.updated_on,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_delete AFTER DELETE ON categories
+BEGIN
+    UPDATE categories_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+END;
+
+-- ------------------------------ [ ACCOUNTS ] ---------------------------------
+
+CREATE TABLE IF NOT EXISTS accounts_history (
+    history_id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    id                  TEXT NOT NULL,
+    name                TEXT NOT NULL,
+    description         TEXT,
+    opening_balance     INTEGER NOT NULL,
+    is_active           BOOLEAN NOT NULL,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL,
+    valid_from          TEXT NOT NULL,
+    valid_to            TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_accounts_history_id ON accounts_history (id, valid_from);
+
+INSERT INTO accounts_history (
+    id, name, description, opening_balance, is_active, created_on, updated_on, valid_from
+)
+SELECT
+    id, name, description, opening_balance, is_active, created_on, updated_on,
+    strftime('%Y-%m-%dT%H:%M:%fZ', updated_on)
+FROM accounts;
+
+CREATE TRIGGER IF NOT EXISTS accounts_history_insert AFTER INSERT ON accounts
+BEGIN
+    INSERT INTO accounts_history (
+        id, name, description, opening_balance, is_active, created_on, updated_on, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.name, NEW.description, NEW.opening_balance, NEW.is_active,
+        NEW.created_on, NEW.updated_on, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS accounts_history_update AFTER UPDATE ON accounts
+BEGIN
+    UPDATE accounts_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO accounts_history (
+        id, name, description, opening_balance, is_active, created_on, updated_on, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.name, NEW.description, NEW.opening_balance, NEW.is_active,
+        NEW.created_on, NEW.updated_on, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS accounts_history_delete AFTER DELETE ON accounts
+BEGIN
+    UPDATE accounts_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+END;
+
+-- ---------------------------- [ TRANSACTIONS ] -------------------------------
+
+CREATE TABLE IF NOT EXISTS transactions_history (
+    history_id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    id                  TEXT NOT NULL,
+    transaction_date    TEXT NOT NULL,
+    payee               TEXT NOT NULL,
+    description         TEXT,
+    amount              INTEGER NOT NULL,
+    category_id         TEXT,
+    account_id          TEXT,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL,
+    valid_from          TEXT NOT NULL,
+    valid_to            TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_transactions_history_id ON transactions_history (id, valid_from);
+CREATE INDEX IF NOT EXISTS idx_transactions_history_account_id ON transactions_history (account_id, valid_from);
+
+INSERT INTO transactions_history (
+    id, transaction_date, payee, description, amount, category_id, account_id,
+    created_on, updated_on, valid_from
+)
+SELECT
+    id, transaction_date, payee, description, amount, category_id, account_id,
+    created_on, updated_on, strftime('%Y-%m-%dT%H:%M:%fZ', updated_on)
+FROM transactions;
+
+CREATE TRIGGER IF NOT EXISTS transactions_history_insert AFTER INSERT ON transactions
+BEGIN
+    INSERT INTO transactions_history (
+        id, transaction_date, payee, description, amount, category_id, account_id,
+        created_on, updated_on, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.transaction_date, NEW.payee, NEW.description, NEW.amount,
+        NEW.category_id, NEW.account_id, NEW.created_on, NEW.updated_on,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_history_update AFTER UPDATE ON transactions
+BEGIN
+    UPDATE transactions_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO transactions_history (
+        id, transaction_date, payee, description, amount, category_id, account_id,
+        created_on, updated_on, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.transaction_date, NEW.payee, NEW.description, NEW.amount,
+        NEW.category_id, NEW.account_id, NEW.created_on, NEW.updated_on,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_history_delete AFTER DELETE ON transactions
+BEGIN
+    UPDATE transactions_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+END;
@@ -0,0 +1,17 @@
+-- ./migrations/0006_period_closings.sql
+--
+-- Period closing audit trail. Closing a period locks every transaction dated
+-- on or before `closed_through` against insert, update and delete. Reopening
+-- moves the lock date back (or clears it when `closed_through` is NULL) and
+-- must give a reason. Rows are never updated or deleted, the latest row is the
+-- current lock and earlier rows are the history of who changed it and why.
+
+CREATE TABLE IF NOT EXISTS period_closings (
+    id              TEXT PRIMARY KEY NOT NULL,
+    action          TEXT NOT NULL CHECK (action IN ('close', 'reopen')),
+    closed_through  TEXT,
+    reason          TEXT,
+    created_on      TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_period_closings_created_on ON period_closings (created_on);
@@ -0,0 +1,46 @@
+-- ./migrations/0007_category_hierarchy.sql
+--
+-- Parent/child hierarchy for categories. A category with no parent is a root.
+-- Deleting a category that still has children is rejected by the foreign key,
+-- the children must be moved or deleted first. Cycles are rejected by the
+-- application before a parent is set.
+
+ALTER TABLE categories ADD COLUMN parent_id TEXT REFERENCES categories (id);
+
+CREATE INDEX IF NOT EXISTS idx_categories_parent_id ON categories (parent_id);
+
+-- Keep the parent in the row version history
+ALTER TABLE categories_history ADD COLUMN parent_id TEXT;
+
+DROP TRIGGER IF EXISTS categories_history_insert;
+DROP TRIGGER IF EXISTS categories_history_update;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_insert AFTER INSERT ON categories
+BEGIN
+    INSERT INTO categories_history (
+        id, code, name, description, url_slug, category_type, color, icon, is_active,
+        created_on, updated_on, parent_id, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.code, NEW.name, NEW.description, NEW.url_slug, NEW.category_type,
+        NEW.color, NEW.icon, NEW.is_active, NEW.created_on, NEW.updated_on, NEW.parent_id,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_update AFTER UPDATE ON categories
+BEGIN
+    UPDATE categories_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO categories_history (
+        id, code, name, description, url_slug, category_type, color, icon, is_active,
+        created_on, updated_on, parent_id, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.code, NEW.name, NEW.description, NEW.url_slug, NEW.category_type,
+        NEW.color, NEW.icon, NEW.is_active, NEW.created_on, NEW.updated_on, NEW.parent_id,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
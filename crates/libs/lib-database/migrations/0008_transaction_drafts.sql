@@ -0,0 +1,47 @@
+-- ./migrations/0008_transaction_drafts.sql
+--
+-- Draft transactions. A draft can be created and edited freely without
+-- affecting account balances or reports, and is posted to the ledger later,
+-- e.g. preparing a batch of cash expenses before confirming them. Existing
+-- transactions are all posted.
+
+ALTER TABLE transactions ADD COLUMN is_draft BOOLEAN NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS idx_transactions_is_draft ON transactions (is_draft);
+
+-- Keep the draft flag in the row version history, so balances as recorded
+-- also leave out transactions that were still drafts at the time
+ALTER TABLE transactions_history ADD COLUMN is_draft BOOLEAN NOT NULL DEFAULT 0;
+
+DROP TRIGGER IF EXISTS transactions_history_insert;
+DROP TRIGGER IF EXISTS transactions_history_update;
+
+CREATE TRIGGER IF NOT EXISTS transactions_history_insert AFTER INSERT ON transactions
+BEGIN
+    INSERT INTO transactions_history (
+        id, transaction_date, payee, description, amount, category_id, account_id,
+        created_on, updated_on, is_draft, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.transaction_date, NEW.payee, NEW.description, NEW.amount,
+        NEW.category_id, NEW.account_id, NEW.created_on, NEW.updated_on, NEW.is_draft,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_history_update AFTER UPDATE ON transactions
+BEGIN
+    UPDATE transactions_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO transactions_history (
+        id, transaction_date, payee, description, amount, category_id, account_id,
+        created_on, updated_on, is_draft, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.transaction_date, NEW.payee, NEW.description, NEW.amount,
+        NEW.category_id, NEW.account_id, NEW.created_on, NEW.updated_on, NEW.is_draft,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
@@ -0,0 +1,12 @@
+-- ./migrations/0009_transaction_imports.sql
+--
+-- Transactions created by importing a bank export. Each imported transaction
+-- keeps a hash of its date, amount and payee, so importing an overlapping
+-- export again skips the rows already in the ledger. Deleting the transaction
+-- forgets the hash, allowing the row to be imported again.
+
+CREATE TABLE IF NOT EXISTS transaction_imports (
+    transaction_id  TEXT PRIMARY KEY NOT NULL REFERENCES transactions (id) ON DELETE CASCADE,
+    import_hash     TEXT NOT NULL UNIQUE,
+    imported_on     TEXT NOT NULL
+);
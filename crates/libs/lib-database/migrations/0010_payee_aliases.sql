@@ -0,0 +1,18 @@
+-- ./migrations/0010_payee_aliases.sql
+--
+-- Payee aliases map the raw payee strings in bank exports, such as
+-- "AMZN Mktp US*1234", to a clean payee name. An exact alias matches the whole
+-- string ignoring case and spacing, a regex alias matches a pattern anywhere
+-- in it. Aliases are applied when transactions are imported.
+
+CREATE TABLE IF NOT EXISTS payee_aliases (
+    id              TEXT PRIMARY KEY NOT NULL,
+    pattern         TEXT NOT NULL,
+    match_kind      TEXT NOT NULL CHECK (match_kind IN ('exact', 'regex')),
+    payee           TEXT NOT NULL,
+    created_on      TEXT NOT NULL,
+    updated_on      TEXT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_payee_aliases_pattern
+    ON payee_aliases (match_kind, pattern COLLATE NOCASE);
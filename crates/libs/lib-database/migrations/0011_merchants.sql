@@ -0,0 +1,14 @@
+-- ./migrations/0011_merchants.sql
+--
+-- Merchant metadata looked up for a payee by an enrichment provider, such as
+-- the merchant's clean name, the code of the category it usually belongs in
+-- and a logo. One row per payee, replaced when the payee is looked up again.
+
+CREATE TABLE IF NOT EXISTS merchants (
+    payee           TEXT PRIMARY KEY NOT NULL COLLATE NOCASE,
+    name            TEXT NOT NULL,
+    category_hint   TEXT,
+    logo_url        TEXT,
+    source          TEXT NOT NULL,
+    updated_on      TEXT NOT NULL
+);
@@ -0,0 +1,51 @@
+-- ./migrations/0012_transaction_locations.sql
+--
+-- Where a transaction was made, for mobile clients that record it. Latitude
+-- and longitude are WGS 84 decimal degrees and are either both set or both
+-- NULL. The place name is free text, e.g. the shop or suburb.
+
+ALTER TABLE transactions ADD COLUMN latitude REAL;
+ALTER TABLE transactions ADD COLUMN longitude REAL;
+ALTER TABLE transactions ADD COLUMN place_name TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_transactions_location ON transactions (latitude, longitude);
+
+-- Keep the location in the row version history
+ALTER TABLE transactions_history ADD COLUMN latitude REAL;
+ALTER TABLE transactions_history ADD COLUMN longitude REAL;
+ALTER TABLE transactions_history ADD COLUMN place_name TEXT;
+
+DROP TRIGGER IF EXISTS transactions_history_insert;
+DROP TRIGGER IF EXISTS transactions_history_update;
+
+CREATE TRIGGER IF NOT EXISTS transactions_history_insert AFTER INSERT ON transactions
+BEGIN
+    INSERT INTO transactions_history (
+        id, transaction_date, payee, description, amount, category_id, account_id,
+        created_on, updated_on, is_draft, latitude, longitude, place_name, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.transaction_date, NEW.payee, NEW.description, NEW.amount,
+        NEW.category_id, NEW.account_id, NEW.created_on, NEW.updated_on, NEW.is_draft,
+        NEW.latitude, NEW.longitude, NEW.place_name,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_history_update AFTER UPDATE ON transactions
+BEGIN
+    UPDATE transactions_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO transactions_history (
+        id, transaction_date, payee, description, amount, category_id, account_id,
+        created_on, updated_on, is_draft, latitude, longitude, place_name, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.transaction_date, NEW.payee, NEW.description, NEW.amount,
+        NEW.category_id, NEW.account_id, NEW.created_on, NEW.updated_on, NEW.is_draft,
+        NEW.latitude, NEW.longitude, NEW.place_name,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
@@ -0,0 +1,24 @@
+-- ./migrations/0013_exchange_rates.sql
+--
+-- Daily exchange rates, so amounts in accounts held in different currencies
+-- can be converted and added up at a chosen valuation date. A rate is the
+-- price of one unit of the base currency in the quote currency, held as a
+-- whole number of hundred millionths to avoid floating point rounding. There
+-- is at most one rate per currency pair per day.
+
+CREATE TABLE IF NOT EXISTS exchange_rates (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    base_currency       TEXT NOT NULL,
+    quote_currency      TEXT NOT NULL,
+    rate_date           TEXT NOT NULL,
+    rate                INTEGER NOT NULL CHECK (rate > 0),
+    source              TEXT,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL,
+    CHECK (base_currency <> quote_currency),
+    UNIQUE (base_currency, quote_currency, rate_date)
+);
+
+-- Accounts are held in a single currency, defaulting to Australian dollars.
+-- The opening balance and the transactions posted to the account are in it.
+ALTER TABLE accounts ADD COLUMN currency TEXT NOT NULL DEFAULT 'AUD';
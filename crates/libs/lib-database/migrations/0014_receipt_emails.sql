@@ -0,0 +1,31 @@
+-- ./migrations/0014_receipt_emails.sql
+--
+-- Receipts forwarded by email. Each email is recorded once, by its
+-- Message-ID, so a mailbox can be read again without duplicating drafts. When
+-- an amount is found in the email a draft transaction is created for review
+-- and linked to it. The email's attachments, such as a PDF tax invoice, are
+-- kept with it.
+
+CREATE TABLE IF NOT EXISTS receipt_emails (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    message_id          TEXT NOT NULL UNIQUE,
+    sender              TEXT,
+    subject             TEXT,
+    received_on         TEXT NOT NULL,
+    transaction_id      TEXT REFERENCES transactions (id) ON DELETE SET NULL,
+    created_on          TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_receipt_emails_transaction_id ON receipt_emails (transaction_id);
+
+CREATE TABLE IF NOT EXISTS receipt_attachments (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    receipt_email_id    TEXT NOT NULL REFERENCES receipt_emails (id) ON DELETE CASCADE,
+    file_name           TEXT NOT NULL,
+    content_type        TEXT NOT NULL,
+    content             BLOB NOT NULL,
+    created_on          TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_receipt_attachments_receipt_email_id
+    ON receipt_attachments (receipt_email_id);
@@ -0,0 +1,15 @@
+-- ./migrations/0015_budgets.sql
+--
+-- Budgets set how much may be spent in a category each month or each
+-- financial year. A category has at most one budget. Spending in its
+-- subcategories counts towards it, so a budget on "Food" also covers
+-- "Groceries". Amounts are in minor units (cents) and must be positive.
+
+CREATE TABLE IF NOT EXISTS budgets (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    category_id         TEXT NOT NULL UNIQUE REFERENCES categories (id) ON DELETE CASCADE,
+    period              TEXT NOT NULL CHECK (period IN ('monthly', 'annual')),
+    amount              INTEGER NOT NULL CHECK (amount > 0),
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL
+);
@@ -0,0 +1,54 @@
+-- ./migrations/0016_transaction_return_windows.sql
+--
+-- The last day a purchase can be returned and the day its warranty expires,
+-- so the ledger can give a reminder before either passes. Both are optional
+-- dates and neither may be before the transaction date.
+
+ALTER TABLE transactions ADD COLUMN return_by TEXT;
+ALTER TABLE transactions ADD COLUMN warranty_expires TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_transactions_return_by
+    ON transactions (return_by) WHERE return_by IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_transactions_warranty_expires
+    ON transactions (warranty_expires) WHERE warranty_expires IS NOT NULL;
+
+-- Keep the return and warranty dates in the row version history
+ALTER TABLE transactions_history ADD COLUMN return_by TEXT;
+ALTER TABLE transactions_history ADD COLUMN warranty_expires TEXT;
+
+DROP TRIGGER IF EXISTS transactions_history_insert;
+DROP TRIGGER IF EXISTS transactions_history_update;
+
+CREATE TRIGGER IF NOT EXISTS transactions_history_insert AFTER INSERT ON transactions
+BEGIN
+    INSERT INTO transactions_history (
+        id, transaction_date, payee, description, amount, category_id, account_id,
+        created_on, updated_on, is_draft, latitude, longitude, place_name, return_by,
+        warranty_expires, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.transaction_date, NEW.payee, NEW.description, NEW.amount,
+        NEW.category_id, NEW.account_id, NEW.created_on, NEW.updated_on, NEW.is_draft,
+        NEW.latitude, NEW.longitude, NEW.place_name, NEW.return_by, NEW.warranty_expires,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_history_update AFTER UPDATE ON transactions
+BEGIN
+    UPDATE transactions_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO transactions_history (
+        id, transaction_date, payee, description, amount, category_id, account_id,
+        created_on, updated_on, is_draft, latitude, longitude, place_name, return_by,
+        warranty_expires, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.transaction_date, NEW.payee, NEW.description, NEW.amount,
+        NEW.category_id, NEW.account_id, NEW.created_on, NEW.updated_on, NEW.is_draft,
+        NEW.latitude, NEW.longitude, NEW.place_name, NEW.return_by, NEW.warranty_expires,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
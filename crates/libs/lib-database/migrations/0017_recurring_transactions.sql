@@ -0,0 +1,32 @@
+-- ./migrations/0017_recurring_transactions.sql
+--
+-- Recurring transactions are templates for bills and income that repeat,
+-- such as rent, subscriptions or a salary. A scheduler turns each template
+-- into a transaction on its next due date and moves the due date on by the
+-- template's frequency. Templates that auto post create posted transactions,
+-- the rest create drafts to be reviewed. Month based frequencies count from
+-- the start date, so a template starting on the 31st falls on the last day
+-- of shorter months without drifting earlier.
+
+CREATE TABLE IF NOT EXISTS recurring_transactions (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    payee               TEXT NOT NULL,
+    description         TEXT,
+    amount              INTEGER NOT NULL CHECK (amount <> 0),
+    category_id         TEXT REFERENCES categories (id) ON DELETE SET NULL,
+    account_id          TEXT REFERENCES accounts (id) ON DELETE SET NULL,
+    frequency           TEXT NOT NULL CHECK (
+                            frequency IN ('weekly', 'fortnightly', 'monthly', 'quarterly', 'annually')
+                        ),
+    starts_on           TEXT NOT NULL,
+    next_due            TEXT NOT NULL,
+    ends_on             TEXT,
+    auto_post           INTEGER NOT NULL DEFAULT 0,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL,
+    CHECK (next_due >= starts_on),
+    CHECK (ends_on IS NULL OR ends_on >= starts_on)
+);
+
+CREATE INDEX IF NOT EXISTS idx_recurring_transactions_next_due
+    ON recurring_transactions (next_due);
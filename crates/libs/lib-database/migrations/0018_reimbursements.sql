@@ -0,0 +1,38 @@
+-- ./migrations/0018_reimbursements.sql
+--
+-- Reimbursables are expenses someone else will pay back, such as a work
+-- purchase made on a personal card or a trip logged for mileage. Each one
+-- records when a claim was submitted and the transaction that paid it back.
+-- A reimbursable is outstanding until that transaction is linked.
+--
+-- The mileage log records trips by distance in metres and a rate in cents
+-- per kilometre. The amount is worked out from them and rounded to the
+-- nearest cent, rather than stored. A trip becomes reimbursable when a
+-- reimbursable is added for it.
+
+CREATE TABLE IF NOT EXISTS mileage_logs (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    trip_date           TEXT NOT NULL,
+    purpose             TEXT NOT NULL,
+    distance_metres     INTEGER NOT NULL CHECK (distance_metres > 0),
+    rate_cents_per_km   INTEGER NOT NULL CHECK (rate_cents_per_km > 0),
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_mileage_logs_trip_date
+    ON mileage_logs (trip_date);
+
+CREATE TABLE IF NOT EXISTS reimbursables (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    transaction_id      TEXT UNIQUE REFERENCES transactions (id) ON DELETE CASCADE,
+    mileage_log_id      TEXT UNIQUE REFERENCES mileage_logs (id) ON DELETE CASCADE,
+    claimed_on          TEXT,
+    reimbursed_by       TEXT REFERENCES transactions (id) ON DELETE SET NULL,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL,
+    CHECK ((transaction_id IS NULL) <> (mileage_log_id IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_reimbursables_outstanding
+    ON reimbursables (reimbursed_by) WHERE reimbursed_by IS NULL;
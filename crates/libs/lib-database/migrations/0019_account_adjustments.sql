@@ -0,0 +1,8 @@
+-- ./migrations/0019_account_adjustments.sql
+--
+-- The category balancing entries are posted to when an account's recorded
+-- balance is adjusted to match a counted one, such as the cash in a wallet.
+-- Accounts without one cannot be adjusted.
+
+ALTER TABLE accounts ADD COLUMN adjustments_category_id TEXT
+    REFERENCES categories (id) ON DELETE SET NULL;
@@ -0,0 +1,73 @@
+-- ./migrations/0020_search.sql
+--
+-- Full-text search indexes over categories and transactions, using SQLite
+-- FTS5. Each index keeps its own copy of the searchable text alongside the
+-- row id, rather than pointing at the table's rowid, which VACUUM may change
+-- for tables with a TEXT primary key. Triggers keep the indexes in step with
+-- every insert, update and delete, so they cannot drift from the tables.
+--
+-- The unicode61 tokeniser folds case and, with remove_diacritics, accents, so
+-- "cafe" matches "Café".
+
+-- ----------------------------- [ CATEGORIES ] --------------------------------
+
+CREATE VIRTUAL TABLE IF NOT EXISTS categories_fts USING fts5 (
+    id UNINDEXED,
+    code,
+    name,
+    description,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO categories_fts (id, code, name, description)
+SELECT id, code, name, COALESCE(description, '') FROM categories;
+
+CREATE TRIGGER IF NOT EXISTS categories_fts_insert AFTER INSERT ON categories
+BEGIN
+    INSERT INTO categories_fts (id, code, name, description)
+    VALUES (NEW.id, NEW.code, NEW.name, COALESCE(NEW.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_fts_update AFTER UPDATE OF code, name, description ON categories
+BEGIN
+    DELETE FROM categories_fts WHERE id = OLD.id;
+    INSERT INTO categories_fts (id, code, name, description)
+    VALUES (NEW.id, NEW.code, NEW.name, COALESCE(NEW.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_fts_delete AFTER DELETE ON categories
+BEGIN
+    DELETE FROM categories_fts WHERE id = OLD.id;
+END;
+
+-- ---------------------------- [ TRANSACTIONS ] -------------------------------
+
+CREATE VIRTUAL TABLE IF NOT EXISTS transactions_fts USING fts5 (
+    id UNINDEXED,
+    payee,
+    description,
+    place_name,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO transactions_fts (id, payee, description, place_name)
+SELECT id, payee, COALESCE(description, ''), COALESCE(place_name, '') FROM transactions;
+
+CREATE TRIGGER IF NOT EXISTS transactions_fts_insert AFTER INSERT ON transactions
+BEGIN
+    INSERT INTO transactions_fts (id, payee, description, place_name)
+    VALUES (NEW.id, NEW.payee, COALESCE(NEW.description, ''), COALESCE(NEW.place_name, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_fts_update
+AFTER UPDATE OF payee, description, place_name ON transactions
+BEGIN
+    DELETE FROM transactions_fts WHERE id = OLD.id;
+    INSERT INTO transactions_fts (id, payee, description, place_name)
+    VALUES (NEW.id, NEW.payee, COALESCE(NEW.description, ''), COALESCE(NEW.place_name, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_fts_delete AFTER DELETE ON transactions
+BEGIN
+    DELETE FROM transactions_fts WHERE id = OLD.id;
+END;
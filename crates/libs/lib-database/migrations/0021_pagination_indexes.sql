@@ -0,0 +1,12 @@
+-- ./migrations/0021_pagination_indexes.sql
+--
+-- Indexes for keyset pagination. Paged lists are read newest first by
+-- (created_on, id), starting after the last row of the previous page, so
+-- these indexes let a page be found without scanning or sorting the table.
+
+CREATE INDEX IF NOT EXISTS idx_categories_created_on_id ON categories (created_on, id);
+CREATE INDEX IF NOT EXISTS idx_transactions_created_on_id ON transactions (created_on, id);
+CREATE INDEX IF NOT EXISTS idx_accounts_created_on_id ON accounts (created_on, id);
+CREATE INDEX IF NOT EXISTS idx_budgets_created_on_id ON budgets (created_on, id);
+CREATE INDEX IF NOT EXISTS idx_recurring_transactions_created_on_id
+    ON recurring_transactions (created_on, id);
@@ -0,0 +1,20 @@
+-- ./migrations/0022_balance_snapshots.sql
+--
+-- Balances recorded by hand for accounts whose transactions are not kept in
+-- the ledger, such as a pension fund or crypto wallet, read off a statement
+-- or app every so often. There is at most one snapshot per account per day.
+--
+-- Once an account has a snapshot, reports value it from its snapshots alone,
+-- interpolating between them, instead of from its opening balance and
+-- transactions.
+
+CREATE TABLE IF NOT EXISTS balance_snapshots (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    account_id          TEXT NOT NULL REFERENCES accounts (id) ON DELETE CASCADE,
+    snapshot_date       TEXT NOT NULL,
+    balance             INTEGER NOT NULL,
+    note                TEXT,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL,
+    UNIQUE (account_id, snapshot_date)
+);
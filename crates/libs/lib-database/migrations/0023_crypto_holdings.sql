@@ -0,0 +1,35 @@
+-- ./migrations/0023_crypto_holdings.sql
+--
+-- Crypto assets divide into as many as eighteen decimal places, more than
+-- fit in an INTEGER column, so quantities and prices are stored as exact
+-- decimal TEXT (e.g. '1.234567890123456789') and parsed by the domain types.
+--
+-- Asset prices are daily, like exchange rates, with at most one price per
+-- asset and currency per day. A holding is valued at the latest price on or
+-- before the valuation date, then converted with the exchange rates if the
+-- price is in another currency.
+--
+-- Holdings record how much of each asset an account, such as a wallet,
+-- holds. There is at most one holding per asset per account.
+
+CREATE TABLE IF NOT EXISTS asset_prices (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    asset               TEXT NOT NULL,
+    currency            TEXT NOT NULL,
+    price_date          TEXT NOT NULL,
+    price               TEXT NOT NULL,
+    source              TEXT,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL,
+    UNIQUE (asset, currency, price_date)
+);
+
+CREATE TABLE IF NOT EXISTS crypto_holdings (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    account_id          TEXT NOT NULL REFERENCES accounts (id) ON DELETE CASCADE,
+    asset               TEXT NOT NULL,
+    quantity            TEXT NOT NULL,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL,
+    UNIQUE (account_id, asset)
+);
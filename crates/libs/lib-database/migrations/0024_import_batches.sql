@@ -0,0 +1,26 @@
+-- ./migrations/0024_import_batches.sql
+--
+-- Each import of a bank export is recorded as a batch, with a SHA-256 hash
+-- of the file's contents, so importing the same file again is recognised and
+-- skipped unless forced. Every transaction the import created records its
+-- batch, so a bad import can be found and rolled back as a whole.
+--
+-- The file hash is not unique, a forced import of the same file is a batch of
+-- its own. Transactions imported before batches were recorded have none.
+
+CREATE TABLE IF NOT EXISTS import_batches (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    file_hash           TEXT NOT NULL,
+    file_name           TEXT,
+    file_size           INTEGER NOT NULL CHECK (file_size >= 0),
+    account_id          TEXT REFERENCES accounts (id) ON DELETE SET NULL,
+    inserted_count      INTEGER NOT NULL DEFAULT 0,
+    skipped_count       INTEGER NOT NULL DEFAULT 0,
+    imported_on         TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_import_batches_file_hash ON import_batches (file_hash, imported_on);
+
+ALTER TABLE transaction_imports ADD COLUMN batch_id TEXT REFERENCES import_batches (id);
+
+CREATE INDEX IF NOT EXISTS idx_transaction_imports_batch_id ON transaction_imports (batch_id);
@@ -0,0 +1,32 @@
+-- ./migrations/0025_category_soft_delete.sql
+--
+-- Soft deletion of categories. A soft-deleted category keeps its row, with
+-- the time it was deleted in `deleted_on`, so it can be restored, and is left
+-- out of category lookups and lists unless asked for. Its code, name and slug
+-- stay taken until it is purged (deleted for good).
+--
+-- In the row version history a soft delete ends the category's last version,
+-- the same as a delete, and a restore starts a new one.
+
+ALTER TABLE categories ADD COLUMN deleted_on TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_categories_deleted_on ON categories (deleted_on);
+
+DROP TRIGGER IF EXISTS categories_history_update;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_update AFTER UPDATE ON categories
+BEGIN
+    UPDATE categories_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO categories_history (
+        id, code, name, description, url_slug, category_type, color, icon, is_active,
+        created_on, updated_on, parent_id, valid_from
+    )
+    SELECT
+        NEW.id, NEW.code, NEW.name, NEW.description, NEW.url_slug, NEW.category_type,
+        NEW.color, NEW.icon, NEW.is_active, NEW.created_on, NEW.updated_on, NEW.parent_id,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE NEW.deleted_on IS NULL;
+END;
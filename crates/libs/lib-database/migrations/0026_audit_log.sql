@@ -0,0 +1,1108 @@
+-- ./migrations/0026_audit_log.sql
+--
+-- Audit log of every insert, update and delete, with the row before and
+-- after the change as JSON. Rows are written by triggers, so they are part of
+-- the same database transaction as the change they record, and are never
+-- updated. When columns are added to an audited table its triggers must be
+-- recreated to snapshot them, which the audit log tests check.
+--
+-- The actor is read from `audit_actor`, which holds at most one row, written
+-- at the start of a database transaction and deleted before it commits. As
+-- SQLite allows one writer at a time the row always belongs to the
+-- transaction making the change. Changes made without an actor are logged
+-- with a NULL actor.
+
+CREATE TABLE IF NOT EXISTS audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    entity TEXT NOT NULL,
+    entity_id TEXT NOT NULL,
+    action TEXT NOT NULL CHECK (action IN ('insert', 'update', 'delete')),
+    actor TEXT,
+    old_values TEXT,
+    new_values TEXT,
+    changed_on TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_entity ON audit_log (entity, entity_id, id);
+
+CREATE TABLE IF NOT EXISTS audit_actor (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    actor TEXT NOT NULL
+);
+
+-- accounts
+
+CREATE TRIGGER IF NOT EXISTS accounts_audit_insert AFTER INSERT ON accounts
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'accounts', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'name', NEW.name, 'description', NEW.description,
+            'opening_balance', NEW.opening_balance, 'is_active', NEW.is_active,
+            'created_on', NEW.created_on, 'updated_on', NEW.updated_on,
+            'currency', NEW.currency,
+            'adjustments_category_id', NEW.adjustments_category_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS accounts_audit_update AFTER UPDATE ON accounts
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'accounts', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'name', OLD.name, 'description', OLD.description,
+            'opening_balance', OLD.opening_balance, 'is_active', OLD.is_active,
+            'created_on', OLD.created_on, 'updated_on', OLD.updated_on,
+            'currency', OLD.currency,
+            'adjustments_category_id', OLD.adjustments_category_id
+        ),
+        json_object(
+            'id', NEW.id, 'name', NEW.name, 'description', NEW.description,
+            'opening_balance', NEW.opening_balance, 'is_active', NEW.is_active,
+            'created_on', NEW.created_on, 'updated_on', NEW.updated_on,
+            'currency', NEW.currency,
+            'adjustments_category_id', NEW.adjustments_category_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS accounts_audit_delete AFTER DELETE ON accounts
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'accounts', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'name', OLD.name, 'description', OLD.description,
+            'opening_balance', OLD.opening_balance, 'is_active', OLD.is_active,
+            'created_on', OLD.created_on, 'updated_on', OLD.updated_on,
+            'currency', OLD.currency,
+            'adjustments_category_id', OLD.adjustments_category_id
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- asset_prices
+
+CREATE TRIGGER IF NOT EXISTS asset_prices_audit_insert AFTER INSERT ON asset_prices
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'asset_prices', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'asset', NEW.asset, 'currency', NEW.currency,
+            'price_date', NEW.price_date, 'price', NEW.price, 'source', NEW.source,
+            'created_on', NEW.created_on, 'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS asset_prices_audit_update AFTER UPDATE ON asset_prices
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'asset_prices', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'asset', OLD.asset, 'currency', OLD.currency,
+            'price_date', OLD.price_date, 'price', OLD.price, 'source', OLD.source,
+            'created_on', OLD.created_on, 'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'asset', NEW.asset, 'currency', NEW.currency,
+            'price_date', NEW.price_date, 'price', NEW.price, 'source', NEW.source,
+            'created_on', NEW.created_on, 'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS asset_prices_audit_delete AFTER DELETE ON asset_prices
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'asset_prices', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'asset', OLD.asset, 'currency', OLD.currency,
+            'price_date', OLD.price_date, 'price', OLD.price, 'source', OLD.source,
+            'created_on', OLD.created_on, 'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- balance_snapshots
+
+CREATE TRIGGER IF NOT EXISTS balance_snapshots_audit_insert AFTER INSERT ON balance_snapshots
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'balance_snapshots', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'account_id', NEW.account_id,
+            'snapshot_date', NEW.snapshot_date, 'balance', NEW.balance,
+            'note', NEW.note, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS balance_snapshots_audit_update AFTER UPDATE ON balance_snapshots
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'balance_snapshots', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'account_id', OLD.account_id,
+            'snapshot_date', OLD.snapshot_date, 'balance', OLD.balance,
+            'note', OLD.note, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'account_id', NEW.account_id,
+            'snapshot_date', NEW.snapshot_date, 'balance', NEW.balance,
+            'note', NEW.note, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS balance_snapshots_audit_delete AFTER DELETE ON balance_snapshots
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'balance_snapshots', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'account_id', OLD.account_id,
+            'snapshot_date', OLD.snapshot_date, 'balance', OLD.balance,
+            'note', OLD.note, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- budgets
+
+CREATE TRIGGER IF NOT EXISTS budgets_audit_insert AFTER INSERT ON budgets
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'budgets', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'category_id', NEW.category_id, 'period', NEW.period,
+            'amount', NEW.amount, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS budgets_audit_update AFTER UPDATE ON budgets
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'budgets', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'category_id', OLD.category_id, 'period', OLD.period,
+            'amount', OLD.amount, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'category_id', NEW.category_id, 'period', NEW.period,
+            'amount', NEW.amount, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS budgets_audit_delete AFTER DELETE ON budgets
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'budgets', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'category_id', OLD.category_id, 'period', OLD.period,
+            'amount', OLD.amount, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- categories
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_insert AFTER INSERT ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'code', NEW.code, 'name', NEW.name,
+            'description', NEW.description, 'url_slug', NEW.url_slug,
+            'category_type', NEW.category_type, 'color', NEW.color, 'icon', NEW.icon,
+            'is_active', NEW.is_active, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'parent_id', NEW.parent_id,
+            'deleted_on', NEW.deleted_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_update AFTER UPDATE ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'code', OLD.code, 'name', OLD.name,
+            'description', OLD.description, 'url_slug', OLD.url_slug,
+            'category_type', OLD.category_type, 'color', OLD.color, 'icon', OLD.icon,
+            'is_active', OLD.is_active, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'parent_id', OLD.parent_id,
+            'deleted_on', OLD.deleted_on
+        ),
+        json_object(
+            'id', NEW.id, 'code', NEW.code, 'name', NEW.name,
+            'description', NEW.description, 'url_slug', NEW.url_slug,
+            'category_type', NEW.category_type, 'color', NEW.color, 'icon', NEW.icon,
+            'is_active', NEW.is_active, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'parent_id', NEW.parent_id,
+            'deleted_on', NEW.deleted_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_delete AFTER DELETE ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'code', OLD.code, 'name', OLD.name,
+            'description', OLD.description, 'url_slug', OLD.url_slug,
+            'category_type', OLD.category_type, 'color', OLD.color, 'icon', OLD.icon,
+            'is_active', OLD.is_active, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'parent_id', OLD.parent_id,
+            'deleted_on', OLD.deleted_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- crypto_holdings
+
+CREATE TRIGGER IF NOT EXISTS crypto_holdings_audit_insert AFTER INSERT ON crypto_holdings
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'crypto_holdings', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'account_id', NEW.account_id, 'asset', NEW.asset,
+            'quantity', NEW.quantity, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS crypto_holdings_audit_update AFTER UPDATE ON crypto_holdings
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'crypto_holdings', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'account_id', OLD.account_id, 'asset', OLD.asset,
+            'quantity', OLD.quantity, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'account_id', NEW.account_id, 'asset', NEW.asset,
+            'quantity', NEW.quantity, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS crypto_holdings_audit_delete AFTER DELETE ON crypto_holdings
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'crypto_holdings', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'account_id', OLD.account_id, 'asset', OLD.asset,
+            'quantity', OLD.quantity, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- exchange_rates
+
+CREATE TRIGGER IF NOT EXISTS exchange_rates_audit_insert AFTER INSERT ON exchange_rates
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'exchange_rates', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'base_currency', NEW.base_currency,
+            'quote_currency', NEW.quote_currency, 'rate_date', NEW.rate_date,
+            'rate', NEW.rate, 'source', NEW.source, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS exchange_rates_audit_update AFTER UPDATE ON exchange_rates
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'exchange_rates', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'base_currency', OLD.base_currency,
+            'quote_currency', OLD.quote_currency, 'rate_date', OLD.rate_date,
+            'rate', OLD.rate, 'source', OLD.source, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'base_currency', NEW.base_currency,
+            'quote_currency', NEW.quote_currency, 'rate_date', NEW.rate_date,
+            'rate', NEW.rate, 'source', NEW.source, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS exchange_rates_audit_delete AFTER DELETE ON exchange_rates
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'exchange_rates', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'base_currency', OLD.base_currency,
+            'quote_currency', OLD.quote_currency, 'rate_date', OLD.rate_date,
+            'rate', OLD.rate, 'source', OLD.source, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- import_batches
+
+CREATE TRIGGER IF NOT EXISTS import_batches_audit_insert AFTER INSERT ON import_batches
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'import_batches', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'file_hash', NEW.file_hash, 'file_name', NEW.file_name,
+            'file_size', NEW.file_size, 'account_id', NEW.account_id,
+            'inserted_count', NEW.inserted_count, 'skipped_count', NEW.skipped_count,
+            'imported_on', NEW.imported_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS import_batches_audit_update AFTER UPDATE ON import_batches
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'import_batches', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'file_hash', OLD.file_hash, 'file_name', OLD.file_name,
+            'file_size', OLD.file_size, 'account_id', OLD.account_id,
+            'inserted_count', OLD.inserted_count, 'skipped_count', OLD.skipped_count,
+            'imported_on', OLD.imported_on
+        ),
+        json_object(
+            'id', NEW.id, 'file_hash', NEW.file_hash, 'file_name', NEW.file_name,
+            'file_size', NEW.file_size, 'account_id', NEW.account_id,
+            'inserted_count', NEW.inserted_count, 'skipped_count', NEW.skipped_count,
+            'imported_on', NEW.imported_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS import_batches_audit_delete AFTER DELETE ON import_batches
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'import_batches', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'file_hash', OLD.file_hash, 'file_name', OLD.file_name,
+            'file_size', OLD.file_size, 'account_id', OLD.account_id,
+            'inserted_count', OLD.inserted_count, 'skipped_count', OLD.skipped_count,
+            'imported_on', OLD.imported_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- journal_entries
+
+CREATE TRIGGER IF NOT EXISTS journal_entries_audit_insert AFTER INSERT ON journal_entries
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'journal_entries', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'entry_date', NEW.entry_date,
+            'description', NEW.description, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS journal_entries_audit_update AFTER UPDATE ON journal_entries
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'journal_entries', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'entry_date', OLD.entry_date,
+            'description', OLD.description, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'entry_date', NEW.entry_date,
+            'description', NEW.description, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS journal_entries_audit_delete AFTER DELETE ON journal_entries
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'journal_entries', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'entry_date', OLD.entry_date,
+            'description', OLD.description, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- journal_splits
+
+CREATE TRIGGER IF NOT EXISTS journal_splits_audit_insert AFTER INSERT ON journal_splits
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'journal_splits', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'entry_id', NEW.entry_id, 'category_id', NEW.category_id,
+            'amount', NEW.amount, 'memo', NEW.memo
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS journal_splits_audit_update AFTER UPDATE ON journal_splits
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'journal_splits', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'entry_id', OLD.entry_id, 'category_id', OLD.category_id,
+            'amount', OLD.amount, 'memo', OLD.memo
+        ),
+        json_object(
+            'id', NEW.id, 'entry_id', NEW.entry_id, 'category_id', NEW.category_id,
+            'amount', NEW.amount, 'memo', NEW.memo
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS journal_splits_audit_delete AFTER DELETE ON journal_splits
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'journal_splits', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'entry_id', OLD.entry_id, 'category_id', OLD.category_id,
+            'amount', OLD.amount, 'memo', OLD.memo
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- merchants, keyed by payee
+
+CREATE TRIGGER IF NOT EXISTS merchants_audit_insert AFTER INSERT ON merchants
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'merchants', NEW.payee, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'payee', NEW.payee, 'name', NEW.name, 'category_hint', NEW.category_hint,
+            'logo_url', NEW.logo_url, 'source', NEW.source,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS merchants_audit_update AFTER UPDATE ON merchants
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'merchants', NEW.payee, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'payee', OLD.payee, 'name', OLD.name, 'category_hint', OLD.category_hint,
+            'logo_url', OLD.logo_url, 'source', OLD.source,
+            'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'payee', NEW.payee, 'name', NEW.name, 'category_hint', NEW.category_hint,
+            'logo_url', NEW.logo_url, 'source', NEW.source,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS merchants_audit_delete AFTER DELETE ON merchants
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'merchants', OLD.payee, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'payee', OLD.payee, 'name', OLD.name, 'category_hint', OLD.category_hint,
+            'logo_url', OLD.logo_url, 'source', OLD.source,
+            'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- mileage_logs
+
+CREATE TRIGGER IF NOT EXISTS mileage_logs_audit_insert AFTER INSERT ON mileage_logs
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'mileage_logs', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'trip_date', NEW.trip_date, 'purpose', NEW.purpose,
+            'distance_metres', NEW.distance_metres,
+            'rate_cents_per_km', NEW.rate_cents_per_km, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS mileage_logs_audit_update AFTER UPDATE ON mileage_logs
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'mileage_logs', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'trip_date', OLD.trip_date, 'purpose', OLD.purpose,
+            'distance_metres', OLD.distance_metres,
+            'rate_cents_per_km', OLD.rate_cents_per_km, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'trip_date', NEW.trip_date, 'purpose', NEW.purpose,
+            'distance_metres', NEW.distance_metres,
+            'rate_cents_per_km', NEW.rate_cents_per_km, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS mileage_logs_audit_delete AFTER DELETE ON mileage_logs
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'mileage_logs', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'trip_date', OLD.trip_date, 'purpose', OLD.purpose,
+            'distance_metres', OLD.distance_metres,
+            'rate_cents_per_km', OLD.rate_cents_per_km, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- payee_aliases
+
+CREATE TRIGGER IF NOT EXISTS payee_aliases_audit_insert AFTER INSERT ON payee_aliases
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'payee_aliases', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'pattern', NEW.pattern, 'match_kind', NEW.match_kind,
+            'payee', NEW.payee, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS payee_aliases_audit_update AFTER UPDATE ON payee_aliases
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'payee_aliases', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'pattern', OLD.pattern, 'match_kind', OLD.match_kind,
+            'payee', OLD.payee, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'pattern', NEW.pattern, 'match_kind', NEW.match_kind,
+            'payee', NEW.payee, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS payee_aliases_audit_delete AFTER DELETE ON payee_aliases
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'payee_aliases', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'pattern', OLD.pattern, 'match_kind', OLD.match_kind,
+            'payee', OLD.payee, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- period_closings
+
+CREATE TRIGGER IF NOT EXISTS period_closings_audit_insert AFTER INSERT ON period_closings
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'period_closings', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'action', NEW.action, 'closed_through', NEW.closed_through,
+            'reason', NEW.reason, 'created_on', NEW.created_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS period_closings_audit_update AFTER UPDATE ON period_closings
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'period_closings', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'action', OLD.action, 'closed_through', OLD.closed_through,
+            'reason', OLD.reason, 'created_on', OLD.created_on
+        ),
+        json_object(
+            'id', NEW.id, 'action', NEW.action, 'closed_through', NEW.closed_through,
+            'reason', NEW.reason, 'created_on', NEW.created_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS period_closings_audit_delete AFTER DELETE ON period_closings
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'period_closings', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'action', OLD.action, 'closed_through', OLD.closed_through,
+            'reason', OLD.reason, 'created_on', OLD.created_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- receipt_attachments
+-- Attachment contents are left out of the snapshots, JSON cannot hold BLOBs
+
+CREATE TRIGGER IF NOT EXISTS receipt_attachments_audit_insert AFTER INSERT ON receipt_attachments
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'receipt_attachments', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'receipt_email_id', NEW.receipt_email_id,
+            'file_name', NEW.file_name, 'content_type', NEW.content_type,
+            'created_on', NEW.created_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS receipt_attachments_audit_update AFTER UPDATE ON receipt_attachments
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'receipt_attachments', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'receipt_email_id', OLD.receipt_email_id,
+            'file_name', OLD.file_name, 'content_type', OLD.content_type,
+            'created_on', OLD.created_on
+        ),
+        json_object(
+            'id', NEW.id, 'receipt_email_id', NEW.receipt_email_id,
+            'file_name', NEW.file_name, 'content_type', NEW.content_type,
+            'created_on', NEW.created_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS receipt_attachments_audit_delete AFTER DELETE ON receipt_attachments
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'receipt_attachments', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'receipt_email_id', OLD.receipt_email_id,
+            'file_name', OLD.file_name, 'content_type', OLD.content_type,
+            'created_on', OLD.created_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- receipt_emails
+
+CREATE TRIGGER IF NOT EXISTS receipt_emails_audit_insert AFTER INSERT ON receipt_emails
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'receipt_emails', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'message_id', NEW.message_id, 'sender', NEW.sender,
+            'subject', NEW.subject, 'received_on', NEW.received_on,
+            'transaction_id', NEW.transaction_id, 'created_on', NEW.created_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS receipt_emails_audit_update AFTER UPDATE ON receipt_emails
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'receipt_emails', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'message_id', OLD.message_id, 'sender', OLD.sender,
+            'subject', OLD.subject, 'received_on', OLD.received_on,
+            'transaction_id', OLD.transaction_id, 'created_on', OLD.created_on
+        ),
+        json_object(
+            'id', NEW.id, 'message_id', NEW.message_id, 'sender', NEW.sender,
+            'subject', NEW.subject, 'received_on', NEW.received_on,
+            'transaction_id', NEW.transaction_id, 'created_on', NEW.created_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS receipt_emails_audit_delete AFTER DELETE ON receipt_emails
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'receipt_emails', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'message_id', OLD.message_id, 'sender', OLD.sender,
+            'subject', OLD.subject, 'received_on', OLD.received_on,
+            'transaction_id', OLD.transaction_id, 'created_on', OLD.created_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- recurring_transactions
+
+CREATE TRIGGER IF NOT EXISTS recurring_transactions_audit_insert AFTER INSERT ON recurring_transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'recurring_transactions', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'payee', NEW.payee, 'description', NEW.description,
+            'amount', NEW.amount, 'category_id', NEW.category_id,
+            'account_id', NEW.account_id, 'frequency', NEW.frequency,
+            'starts_on', NEW.starts_on, 'next_due', NEW.next_due,
+            'ends_on', NEW.ends_on, 'auto_post', NEW.auto_post,
+            'created_on', NEW.created_on, 'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS recurring_transactions_audit_update AFTER UPDATE ON recurring_transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'recurring_transactions', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'payee', OLD.payee, 'description', OLD.description,
+            'amount', OLD.amount, 'category_id', OLD.category_id,
+            'account_id', OLD.account_id, 'frequency', OLD.frequency,
+            'starts_on', OLD.starts_on, 'next_due', OLD.next_due,
+            'ends_on', OLD.ends_on, 'auto_post', OLD.auto_post,
+            'created_on', OLD.created_on, 'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'payee', NEW.payee, 'description', NEW.description,
+            'amount', NEW.amount, 'category_id', NEW.category_id,
+            'account_id', NEW.account_id, 'frequency', NEW.frequency,
+            'starts_on', NEW.starts_on, 'next_due', NEW.next_due,
+            'ends_on', NEW.ends_on, 'auto_post', NEW.auto_post,
+            'created_on', NEW.created_on, 'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS recurring_transactions_audit_delete AFTER DELETE ON recurring_transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'recurring_transactions', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'payee', OLD.payee, 'description', OLD.description,
+            'amount', OLD.amount, 'category_id', OLD.category_id,
+            'account_id', OLD.account_id, 'frequency', OLD.frequency,
+            'starts_on', OLD.starts_on, 'next_due', OLD.next_due,
+            'ends_on', OLD.ends_on, 'auto_post', OLD.auto_post,
+            'created_on', OLD.created_on, 'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- reimbursables
+
+CREATE TRIGGER IF NOT EXISTS reimbursables_audit_insert AFTER INSERT ON reimbursables
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'reimbursables', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'transaction_id', NEW.transaction_id,
+            'mileage_log_id', NEW.mileage_log_id, 'claimed_on', NEW.claimed_on,
+            'reimbursed_by', NEW.reimbursed_by, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS reimbursables_audit_update AFTER UPDATE ON reimbursables
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'reimbursables', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'transaction_id', OLD.transaction_id,
+            'mileage_log_id', OLD.mileage_log_id, 'claimed_on', OLD.claimed_on,
+            'reimbursed_by', OLD.reimbursed_by, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'transaction_id', NEW.transaction_id,
+            'mileage_log_id', NEW.mileage_log_id, 'claimed_on', NEW.claimed_on,
+            'reimbursed_by', NEW.reimbursed_by, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS reimbursables_audit_delete AFTER DELETE ON reimbursables
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'reimbursables', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'transaction_id', OLD.transaction_id,
+            'mileage_log_id', OLD.mileage_log_id, 'claimed_on', OLD.claimed_on,
+            'reimbursed_by', OLD.reimbursed_by, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- transaction_imports, keyed by the imported transaction
+
+CREATE TRIGGER IF NOT EXISTS transaction_imports_audit_insert AFTER INSERT ON transaction_imports
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transaction_imports', NEW.transaction_id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'transaction_id', NEW.transaction_id, 'import_hash', NEW.import_hash,
+            'imported_on', NEW.imported_on, 'batch_id', NEW.batch_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transaction_imports_audit_update AFTER UPDATE ON transaction_imports
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transaction_imports', NEW.transaction_id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'transaction_id', OLD.transaction_id, 'import_hash', OLD.import_hash,
+            'imported_on', OLD.imported_on, 'batch_id', OLD.batch_id
+        ),
+        json_object(
+            'transaction_id', NEW.transaction_id, 'import_hash', NEW.import_hash,
+            'imported_on', NEW.imported_on, 'batch_id', NEW.batch_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transaction_imports_audit_delete AFTER DELETE ON transaction_imports
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transaction_imports', OLD.transaction_id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'transaction_id', OLD.transaction_id, 'import_hash', OLD.import_hash,
+            'imported_on', OLD.imported_on, 'batch_id', OLD.batch_id
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- transactions
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_insert AFTER INSERT ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'transaction_date', NEW.transaction_date,
+            'payee', NEW.payee, 'description', NEW.description, 'amount', NEW.amount,
+            'category_id', NEW.category_id, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'account_id', NEW.account_id,
+            'is_draft', NEW.is_draft, 'latitude', NEW.latitude,
+            'longitude', NEW.longitude, 'place_name', NEW.place_name,
+            'return_by', NEW.return_by, 'warranty_expires', NEW.warranty_expires
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_update AFTER UPDATE ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'transaction_date', OLD.transaction_date,
+            'payee', OLD.payee, 'description', OLD.description, 'amount', OLD.amount,
+            'category_id', OLD.category_id, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'account_id', OLD.account_id,
+            'is_draft', OLD.is_draft, 'latitude', OLD.latitude,
+            'longitude', OLD.longitude, 'place_name', OLD.place_name,
+            'return_by', OLD.return_by, 'warranty_expires', OLD.warranty_expires
+        ),
+        json_object(
+            'id', NEW.id, 'transaction_date', NEW.transaction_date,
+            'payee', NEW.payee, 'description', NEW.description, 'amount', NEW.amount,
+            'category_id', NEW.category_id, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'account_id', NEW.account_id,
+            'is_draft', NEW.is_draft, 'latitude', NEW.latitude,
+            'longitude', NEW.longitude, 'place_name', NEW.place_name,
+            'return_by', NEW.return_by, 'warranty_expires', NEW.warranty_expires
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_delete AFTER DELETE ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'transaction_date', OLD.transaction_date,
+            'payee', OLD.payee, 'description', OLD.description, 'amount', OLD.amount,
+            'category_id', OLD.category_id, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'account_id', OLD.account_id,
+            'is_draft', OLD.is_draft, 'latitude', OLD.latitude,
+            'longitude', OLD.longitude, 'place_name', OLD.place_name,
+            'return_by', OLD.return_by, 'warranty_expires', OLD.warranty_expires
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
@@ -0,0 +1,285 @@
+-- ./migrations/0027_users.sql
+--
+-- People sharing one server. Categories and transactions may be owned by a
+-- user, and their lookups, updates and deletes are scoped to the owner when
+-- a user is given. Rows recorded before users existed, or without one, have
+-- no owner and are only seen unscoped.
+--
+-- Category codes, names and slugs stay unique across every user, as SQLite
+-- cannot change a column's UNIQUE constraint without rebuilding the table.
+
+CREATE TABLE IF NOT EXISTS users (
+    id              TEXT PRIMARY KEY NOT NULL,
+    username        TEXT NOT NULL UNIQUE,
+    display_name    TEXT,
+    is_active       BOOLEAN NOT NULL DEFAULT 1,
+    created_on      TEXT NOT NULL,
+    updated_on      TEXT NOT NULL
+);
+
+ALTER TABLE categories ADD COLUMN user_id TEXT REFERENCES users (id);
+ALTER TABLE transactions ADD COLUMN user_id TEXT REFERENCES users (id);
+
+CREATE INDEX IF NOT EXISTS idx_categories_user_id ON categories (user_id);
+CREATE INDEX IF NOT EXISTS idx_transactions_user_id ON transactions (user_id);
+
+-- Keep the owner in the row version history, so past categories are scoped too
+ALTER TABLE categories_history ADD COLUMN user_id TEXT;
+ALTER TABLE transactions_history ADD COLUMN user_id TEXT;
+
+DROP TRIGGER IF EXISTS categories_history_insert;
+DROP TRIGGER IF EXISTS categories_history_update;
+DROP TRIGGER IF EXISTS transactions_history_insert;
+DROP TRIGGER IF EXISTS transactions_history_update;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_insert AFTER INSERT ON categories
+BEGIN
+    INSERT INTO categories_history (
+        id, code, name, description, url_slug, category_type, color, icon, is_active,
+        created_on, updated_on, parent_id, user_id, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.code, NEW.name, NEW.description, NEW.url_slug, NEW.category_type,
+        NEW.color, NEW.icon, NEW.is_active, NEW.created_on, NEW.updated_on, NEW.parent_id,
+        NEW.user_id, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_update AFTER UPDATE ON categories
+BEGIN
+    UPDATE categories_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO categories_history (
+        id, code, name, description, url_slug, category_type, color, icon, is_active,
+        created_on, updated_on, parent_id, user_id, valid_from
+    )
+    SELECT
+        NEW.id, NEW.code, NEW.name, NEW.description, NEW.url_slug, NEW.category_type,
+        NEW.color, NEW.icon, NEW.is_active, NEW.created_on, NEW.updated_on, NEW.parent_id,
+        NEW.user_id, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE NEW.deleted_on IS NULL;
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_history_insert AFTER INSERT ON transactions
+BEGIN
+    INSERT INTO transactions_history (
+        id, transaction_date, payee, description, amount, category_id, account_id,
+        created_on, updated_on, is_draft, latitude, longitude, place_name, return_by,
+        warranty_expires, user_id, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.transaction_date, NEW.payee, NEW.description, NEW.amount,
+        NEW.category_id, NEW.account_id, NEW.created_on, NEW.updated_on, NEW.is_draft,
+        NEW.latitude, NEW.longitude, NEW.place_name, NEW.return_by, NEW.warranty_expires,
+        NEW.user_id, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_history_update AFTER UPDATE ON transactions
+BEGIN
+    UPDATE transactions_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO transactions_history (
+        id, transaction_date, payee, description, amount, category_id, account_id,
+        created_on, updated_on, is_draft, latitude, longitude, place_name, return_by,
+        warranty_expires, user_id, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.transaction_date, NEW.payee, NEW.description, NEW.amount,
+        NEW.category_id, NEW.account_id, NEW.created_on, NEW.updated_on, NEW.is_draft,
+        NEW.latitude, NEW.longitude, NEW.place_name, NEW.return_by, NEW.warranty_expires,
+        NEW.user_id, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- Snapshot the owner in the audit log, and audit the users themselves
+
+DROP TRIGGER IF EXISTS categories_audit_insert;
+DROP TRIGGER IF EXISTS categories_audit_update;
+DROP TRIGGER IF EXISTS categories_audit_delete;
+DROP TRIGGER IF EXISTS transactions_audit_insert;
+DROP TRIGGER IF EXISTS transactions_audit_update;
+DROP TRIGGER IF EXISTS transactions_audit_delete;
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_insert AFTER INSERT ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'code', NEW.code, 'name', NEW.name,
+            'description', NEW.description, 'url_slug', NEW.url_slug,
+            'category_type', NEW.category_type, 'color', NEW.color, 'icon', NEW.icon,
+            'is_active', NEW.is_active, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'parent_id', NEW.parent_id,
+            'deleted_on', NEW.deleted_on, 'user_id', NEW.user_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_update AFTER UPDATE ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'code', OLD.code, 'name', OLD.name,
+            'description', OLD.description, 'url_slug', OLD.url_slug,
+            'category_type', OLD.category_type, 'color', OLD.color, 'icon', OLD.icon,
+            'is_active', OLD.is_active, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'parent_id', OLD.parent_id,
+            'deleted_on', OLD.deleted_on, 'user_id', OLD.user_id
+        ),
+        json_object(
+            'id', NEW.id, 'code', NEW.code, 'name', NEW.name,
+            'description', NEW.description, 'url_slug', NEW.url_slug,
+            'category_type', NEW.category_type, 'color', NEW.color, 'icon', NEW.icon,
+            'is_active', NEW.is_active, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'parent_id', NEW.parent_id,
+            'deleted_on', NEW.deleted_on, 'user_id', NEW.user_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_delete AFTER DELETE ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'code', OLD.code, 'name', OLD.name,
+            'description', OLD.description, 'url_slug', OLD.url_slug,
+            'category_type', OLD.category_type, 'color', OLD.color, 'icon', OLD.icon,
+            'is_active', OLD.is_active, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'parent_id', OLD.parent_id,
+            'deleted_on', OLD.deleted_on, 'user_id', OLD.user_id
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_insert AFTER INSERT ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'transaction_date', NEW.transaction_date,
+            'payee', NEW.payee, 'description', NEW.description, 'amount', NEW.amount,
+            'category_id', NEW.category_id, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'account_id', NEW.account_id,
+            'is_draft', NEW.is_draft, 'latitude', NEW.latitude,
+            'longitude', NEW.longitude, 'place_name', NEW.place_name,
+            'return_by', NEW.return_by, 'warranty_expires', NEW.warranty_expires,
+            'user_id', NEW.user_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_update AFTER UPDATE ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'transaction_date', OLD.transaction_date,
+            'payee', OLD.payee, 'description', OLD.description, 'amount', OLD.amount,
+            'category_id', OLD.category_id, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'account_id', OLD.account_id,
+            'is_draft', OLD.is_draft, 'latitude', OLD.latitude,
+            'longitude', OLD.longitude, 'place_name', OLD.place_name,
+            'return_by', OLD.return_by, 'warranty_expires', OLD.warranty_expires,
+            'user_id', OLD.user_id
+        ),
+        json_object(
+            'id', NEW.id, 'transaction_date', NEW.transaction_date,
+            'payee', NEW.payee, 'description', NEW.description, 'amount', NEW.amount,
+            'category_id', NEW.category_id, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'account_id', NEW.account_id,
+            'is_draft', NEW.is_draft, 'latitude', NEW.latitude,
+            'longitude', NEW.longitude, 'place_name', NEW.place_name,
+            'return_by', NEW.return_by, 'warranty_expires', NEW.warranty_expires,
+            'user_id', NEW.user_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_delete AFTER DELETE ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'transaction_date', OLD.transaction_date,
+            'payee', OLD.payee, 'description', OLD.description, 'amount', OLD.amount,
+            'category_id', OLD.category_id, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'account_id', OLD.account_id,
+            'is_draft', OLD.is_draft, 'latitude', OLD.latitude,
+            'longitude', OLD.longitude, 'place_name', OLD.place_name,
+            'return_by', OLD.return_by, 'warranty_expires', OLD.warranty_expires,
+            'user_id', OLD.user_id
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS users_audit_insert AFTER INSERT ON users
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'users', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'username', NEW.username, 'display_name', NEW.display_name,
+            'is_active', NEW.is_active, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS users_audit_update AFTER UPDATE ON users
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'users', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'username', OLD.username, 'display_name', OLD.display_name,
+            'is_active', OLD.is_active, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'username', NEW.username, 'display_name', NEW.display_name,
+            'is_active', NEW.is_active, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS users_audit_delete AFTER DELETE ON users
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'users', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'username', OLD.username, 'display_name', OLD.display_name,
+            'is_active', OLD.is_active, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
@@ -0,0 +1,13 @@
+-- ./migrations/0028_public_id_key.sql
+--
+-- The key public IDs are encrypted with. Records are named outside the
+-- ledger by their RowID encrypted under this key, so the key is made once,
+-- when the database is, and travels with it in backups. Changing it would
+-- change every public ID already handed out.
+
+CREATE TABLE IF NOT EXISTS public_id_key (
+    id      INTEGER PRIMARY KEY NOT NULL CHECK (id = 1),
+    key     BLOB NOT NULL CHECK (length(key) = 16)
+);
+
+INSERT INTO public_id_key (id, key) VALUES (1, randomblob(16));
@@ -0,0 +1,21 @@
+-- ./migrations/0029_tags.sql
+--
+-- Tags cross-cut transactions beyond their single category, e.g. "#travel"
+-- across flights, hotels and meals. Tag names are lowercase without the
+-- leading "#", and unique across every user like category codes. A
+-- transaction may have many tags and a tag many transactions, linked through
+-- transaction_tags, whose rows go with the transaction or tag they link.
+
+CREATE TABLE IF NOT EXISTS tags (
+    id              TEXT PRIMARY KEY NOT NULL,
+    name            TEXT NOT NULL UNIQUE,
+    created_on      TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS transaction_tags (
+    transaction_id  TEXT NOT NULL REFERENCES transactions (id) ON DELETE CASCADE,
+    tag_id          TEXT NOT NULL REFERENCES tags (id) ON DELETE CASCADE,
+    PRIMARY KEY (transaction_id, tag_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_transaction_tags_tag_id ON transaction_tags (tag_id);
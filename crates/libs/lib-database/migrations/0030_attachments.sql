@@ -0,0 +1,33 @@
+-- ./migrations/0030_attachments.sql
+--
+-- Receipts and invoices attached to transactions, as images or PDFs. The
+-- bytes are stored once per SHA-256 hash of their content in
+-- attachment_contents, so the same receipt attached twice, or to two
+-- transactions, takes its space once. Attachments go with their transaction,
+-- and content is removed once no attachment refers to it.
+
+CREATE TABLE IF NOT EXISTS attachment_contents (
+    sha256              TEXT PRIMARY KEY NOT NULL,
+    content             BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS attachments (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    transaction_id      TEXT NOT NULL REFERENCES transactions (id) ON DELETE CASCADE,
+    file_name           TEXT NOT NULL,
+    content_type        TEXT NOT NULL,
+    size_bytes          INTEGER NOT NULL CHECK (size_bytes > 0),
+    sha256              TEXT NOT NULL REFERENCES attachment_contents (sha256),
+    created_on          TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_attachments_transaction_id ON attachments (transaction_id);
+CREATE INDEX IF NOT EXISTS idx_attachments_sha256 ON attachments (sha256);
+
+-- Also runs for the attachments removed with their transaction
+CREATE TRIGGER IF NOT EXISTS attachments_delete_orphaned_content AFTER DELETE ON attachments
+BEGIN
+    DELETE FROM attachment_contents
+    WHERE sha256 = OLD.sha256
+        AND NOT EXISTS (SELECT 1 FROM attachments WHERE sha256 = OLD.sha256);
+END;
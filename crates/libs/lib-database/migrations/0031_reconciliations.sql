@@ -0,0 +1,106 @@
+-- ./migrations/0031_reconciliations.sql
+--
+-- Reconciling accounts against their statements. A transaction is uncleared
+-- until it is ticked off as cleared, having shown up on the statement, and is
+-- reconciled once a reconciliation includes it. A reconciliation records the
+-- statement's date and its starting and ending balances, in minor units, and
+-- its transactions must account for the difference between them.
+--
+-- Reconciled transactions point to the reconciliation that included them, so
+-- undoing a reconciliation can set them back to cleared.
+
+CREATE TABLE IF NOT EXISTS reconciliations (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    account_id          TEXT NOT NULL REFERENCES accounts (id) ON DELETE CASCADE,
+    statement_date      TEXT NOT NULL,
+    starting_balance    INTEGER NOT NULL,
+    ending_balance      INTEGER NOT NULL,
+    created_on          TEXT NOT NULL,
+    UNIQUE (account_id, statement_date)
+);
+
+ALTER TABLE transactions ADD COLUMN cleared_status TEXT NOT NULL DEFAULT 'uncleared'
+    CHECK (cleared_status IN ('uncleared', 'cleared', 'reconciled'));
+ALTER TABLE transactions ADD COLUMN reconciliation_id TEXT REFERENCES reconciliations (id);
+
+CREATE INDEX IF NOT EXISTS idx_transactions_reconciliation_id
+    ON transactions (reconciliation_id) WHERE reconciliation_id IS NOT NULL;
+
+-- Snapshot the cleared status and reconciliation in the audit log
+
+DROP TRIGGER IF EXISTS transactions_audit_insert;
+DROP TRIGGER IF EXISTS transactions_audit_update;
+DROP TRIGGER IF EXISTS transactions_audit_delete;
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_insert AFTER INSERT ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'transaction_date', NEW.transaction_date,
+            'payee', NEW.payee, 'description', NEW.description, 'amount', NEW.amount,
+            'category_id', NEW.category_id, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'account_id', NEW.account_id,
+            'is_draft', NEW.is_draft, 'latitude', NEW.latitude,
+            'longitude', NEW.longitude, 'place_name', NEW.place_name,
+            'return_by', NEW.return_by, 'warranty_expires', NEW.warranty_expires,
+            'user_id', NEW.user_id, 'cleared_status', NEW.cleared_status,
+            'reconciliation_id', NEW.reconciliation_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_update AFTER UPDATE ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'transaction_date', OLD.transaction_date,
+            'payee', OLD.payee, 'description', OLD.description, 'amount', OLD.amount,
+            'category_id', OLD.category_id, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'account_id', OLD.account_id,
+            'is_draft', OLD.is_draft, 'latitude', OLD.latitude,
+            'longitude', OLD.longitude, 'place_name', OLD.place_name,
+            'return_by', OLD.return_by, 'warranty_expires', OLD.warranty_expires,
+            'user_id', OLD.user_id, 'cleared_status', OLD.cleared_status,
+            'reconciliation_id', OLD.reconciliation_id
+        ),
+        json_object(
+            'id', NEW.id, 'transaction_date', NEW.transaction_date,
+            'payee', NEW.payee, 'description', NEW.description, 'amount', NEW.amount,
+            'category_id', NEW.category_id, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'account_id', NEW.account_id,
+            'is_draft', NEW.is_draft, 'latitude', NEW.latitude,
+            'longitude', NEW.longitude, 'place_name', NEW.place_name,
+            'return_by', NEW.return_by, 'warranty_expires', NEW.warranty_expires,
+            'user_id', NEW.user_id, 'cleared_status', NEW.cleared_status,
+            'reconciliation_id', NEW.reconciliation_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_delete AFTER DELETE ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'transaction_date', OLD.transaction_date,
+            'payee', OLD.payee, 'description', OLD.description, 'amount', OLD.amount,
+            'category_id', OLD.category_id, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'account_id', OLD.account_id,
+            'is_draft', OLD.is_draft, 'latitude', OLD.latitude,
+            'longitude', OLD.longitude, 'place_name', OLD.place_name,
+            'return_by', OLD.return_by, 'warranty_expires', OLD.warranty_expires,
+            'user_id', OLD.user_id, 'cleared_status', OLD.cleared_status,
+            'reconciliation_id', OLD.reconciliation_id
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
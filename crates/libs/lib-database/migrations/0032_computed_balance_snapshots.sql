@@ -0,0 +1,74 @@
+-- ./migrations/0032_computed_balance_snapshots.sql
+--
+-- Balance snapshots are either recorded by hand, for accounts kept outside
+-- the ledger, or computed from an account's opening balance and posted
+-- transactions at the end of a period, so net worth history has a fixed
+-- balance for every account at each period end.
+--
+-- Only manual snapshots change how an account is valued. Computed snapshots
+-- are never recorded over a manual one.
+
+ALTER TABLE balance_snapshots ADD COLUMN source TEXT NOT NULL DEFAULT 'manual'
+    CHECK (source IN ('manual', 'computed'));
+
+CREATE INDEX IF NOT EXISTS idx_balance_snapshots_manual
+    ON balance_snapshots (account_id, snapshot_date) WHERE source = 'manual';
+
+-- Snapshot the source in the audit log
+
+DROP TRIGGER IF EXISTS balance_snapshots_audit_insert;
+DROP TRIGGER IF EXISTS balance_snapshots_audit_update;
+DROP TRIGGER IF EXISTS balance_snapshots_audit_delete;
+
+CREATE TRIGGER IF NOT EXISTS balance_snapshots_audit_insert AFTER INSERT ON balance_snapshots
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'balance_snapshots', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'account_id', NEW.account_id,
+            'snapshot_date', NEW.snapshot_date, 'balance', NEW.balance,
+            'note', NEW.note, 'source', NEW.source,
+            'created_on', NEW.created_on, 'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS balance_snapshots_audit_update AFTER UPDATE ON balance_snapshots
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'balance_snapshots', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'account_id', OLD.account_id,
+            'snapshot_date', OLD.snapshot_date, 'balance', OLD.balance,
+            'note', OLD.note, 'source', OLD.source,
+            'created_on', OLD.created_on, 'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'account_id', NEW.account_id,
+            'snapshot_date', NEW.snapshot_date, 'balance', NEW.balance,
+            'note', NEW.note, 'source', NEW.source,
+            'created_on', NEW.created_on, 'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS balance_snapshots_audit_delete AFTER DELETE ON balance_snapshots
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'balance_snapshots', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'account_id', OLD.account_id,
+            'snapshot_date', OLD.snapshot_date, 'balance', OLD.balance,
+            'note', OLD.note, 'source', OLD.source,
+            'created_on', OLD.created_on, 'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
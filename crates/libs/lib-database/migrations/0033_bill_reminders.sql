@@ -0,0 +1,15 @@
+-- ./migrations/0033_bill_reminders.sql
+--
+-- Reminders sent for recurring transactions coming up, one per template and
+-- due date, so each occurrence is only reminded about once however often
+-- the server checks. A reminder is recorded once it has been sent through
+-- every notification channel, and goes with its template when the template
+-- is deleted.
+
+CREATE TABLE IF NOT EXISTS bill_reminders (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    recurring_id        TEXT NOT NULL REFERENCES recurring_transactions (id) ON DELETE CASCADE,
+    due_on              TEXT NOT NULL,
+    sent_on             TEXT NOT NULL,
+    UNIQUE (recurring_id, due_on)
+);
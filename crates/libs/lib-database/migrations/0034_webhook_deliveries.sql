@@ -0,0 +1,28 @@
+-- ./migrations/0034_webhook_deliveries.sql
+--
+-- A log of data events posted to webhooks, one row per event and endpoint.
+-- A delivery is pending while it is being tried, delivered once the endpoint
+-- accepts it and failed once every attempt has been used up. The response
+-- status, or the error when there was no response, is kept from the latest
+-- attempt, so a failing endpoint can be looked into.
+--
+-- The payload is kept exactly as it was signed and posted.
+
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    event_id            TEXT NOT NULL,
+    event_name          TEXT NOT NULL,
+    endpoint            TEXT NOT NULL,
+    payload             TEXT NOT NULL,
+    status              TEXT NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'delivered', 'failed')),
+    attempts            INTEGER NOT NULL DEFAULT 0,
+    response_status     INTEGER,
+    last_error          TEXT,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL,
+    UNIQUE (event_id, endpoint)
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_created_on
+    ON webhook_deliveries (created_on);
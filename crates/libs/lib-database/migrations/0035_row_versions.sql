@@ -0,0 +1,189 @@
+-- ./migrations/0035_row_versions.sql
+--
+-- Row versions for optimistic concurrency. Categories and transactions count
+-- the updates made to them, starting at 1, and an update only applies to the
+-- version it was read at, so two clients editing the same row cannot
+-- silently overwrite each other.
+
+ALTER TABLE categories ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE transactions ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+
+-- Keep the version in the category history, so past categories carry it too
+
+ALTER TABLE categories_history ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+
+DROP TRIGGER IF EXISTS categories_history_insert;
+DROP TRIGGER IF EXISTS categories_history_update;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_insert AFTER INSERT ON categories
+BEGIN
+    INSERT INTO categories_history (
+        id, code, name, description, url_slug, category_type, color, icon, is_active,
+        created_on, updated_on, parent_id, user_id, version, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.code, NEW.name, NEW.description, NEW.url_slug, NEW.category_type,
+        NEW.color, NEW.icon, NEW.is_active, NEW.created_on, NEW.updated_on, NEW.parent_id,
+        NEW.user_id, NEW.version, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_update AFTER UPDATE ON categories
+BEGIN
+    UPDATE categories_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO categories_history (
+        id, code, name, description, url_slug, category_type, color, icon, is_active,
+        created_on, updated_on, parent_id, user_id, version, valid_from
+    )
+    SELECT
+        NEW.id, NEW.code, NEW.name, NEW.description, NEW.url_slug, NEW.category_type,
+        NEW.color, NEW.icon, NEW.is_active, NEW.created_on, NEW.updated_on, NEW.parent_id,
+        NEW.user_id, NEW.version, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE NEW.deleted_on IS NULL;
+END;
+
+-- Snapshot the versions in the audit log
+
+DROP TRIGGER IF EXISTS categories_audit_insert;
+DROP TRIGGER IF EXISTS categories_audit_update;
+DROP TRIGGER IF EXISTS categories_audit_delete;
+DROP TRIGGER IF EXISTS transactions_audit_insert;
+DROP TRIGGER IF EXISTS transactions_audit_update;
+DROP TRIGGER IF EXISTS transactions_audit_delete;
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_insert AFTER INSERT ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'code', NEW.code, 'name', NEW.name,
+            'description', NEW.description, 'url_slug', NEW.url_slug,
+            'category_type', NEW.category_type, 'color', NEW.color, 'icon', NEW.icon,
+            'is_active', NEW.is_active, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'parent_id', NEW.parent_id,
+            'deleted_on', NEW.deleted_on, 'user_id', NEW.user_id, 'version', NEW.version
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_update AFTER UPDATE ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'code', OLD.code, 'name', OLD.name,
+            'description', OLD.description, 'url_slug', OLD.url_slug,
+            'category_type', OLD.category_type, 'color', OLD.color, 'icon', OLD.icon,
+            'is_active', OLD.is_active, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'parent_id', OLD.parent_id,
+            'deleted_on', OLD.deleted_on, 'user_id', OLD.user_id, 'version', OLD.version
+        ),
+        json_object(
+            'id', NEW.id, 'code', NEW.code, 'name', NEW.name,
+            'description', NEW.description, 'url_slug', NEW.url_slug,
+            'category_type', NEW.category_type, 'color', NEW.color, 'icon', NEW.icon,
+            'is_active', NEW.is_active, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'parent_id', NEW.parent_id,
+            'deleted_on', NEW.deleted_on, 'user_id', NEW.user_id, 'version', NEW.version
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_delete AFTER DELETE ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'code', OLD.code, 'name', OLD.name,
+            'description', OLD.description, 'url_slug', OLD.url_slug,
+            'category_type', OLD.category_type, 'color', OLD.color, 'icon', OLD.icon,
+            'is_active', OLD.is_active, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'parent_id', OLD.parent_id,
+            'deleted_on', OLD.deleted_on, 'user_id', OLD.user_id, 'version', OLD.version
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_insert AFTER INSERT ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'transaction_date', NEW.transaction_date,
+            'payee', NEW.payee, 'description', NEW.description, 'amount', NEW.amount,
+            'category_id', NEW.category_id, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'account_id', NEW.account_id,
+            'is_draft', NEW.is_draft, 'latitude', NEW.latitude,
+            'longitude', NEW.longitude, 'place_name', NEW.place_name,
+            'return_by', NEW.return_by, 'warranty_expires', NEW.warranty_expires,
+            'user_id', NEW.user_id, 'cleared_status', NEW.cleared_status,
+            'reconciliation_id', NEW.reconciliation_id, 'version', NEW.version
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_update AFTER UPDATE ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'transaction_date', OLD.transaction_date,
+            'payee', OLD.payee, 'description', OLD.description, 'amount', OLD.amount,
+            'category_id', OLD.category_id, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'account_id', OLD.account_id,
+            'is_draft', OLD.is_draft, 'latitude', OLD.latitude,
+            'longitude', OLD.longitude, 'place_name', OLD.place_name,
+            'return_by', OLD.return_by, 'warranty_expires', OLD.warranty_expires,
+            'user_id', OLD.user_id, 'cleared_status', OLD.cleared_status,
+            'reconciliation_id', OLD.reconciliation_id, 'version', OLD.version
+        ),
+        json_object(
+            'id', NEW.id, 'transaction_date', NEW.transaction_date,
+            'payee', NEW.payee, 'description', NEW.description, 'amount', NEW.amount,
+            'category_id', NEW.category_id, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'account_id', NEW.account_id,
+            'is_draft', NEW.is_draft, 'latitude', NEW.latitude,
+            'longitude', NEW.longitude, 'place_name', NEW.place_name,
+            'return_by', NEW.return_by, 'warranty_expires', NEW.warranty_expires,
+            'user_id', NEW.user_id, 'cleared_status', NEW.cleared_status,
+            'reconciliation_id', NEW.reconciliation_id, 'version', NEW.version
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS transactions_audit_delete AFTER DELETE ON transactions
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'transactions', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'transaction_date', OLD.transaction_date,
+            'payee', OLD.payee, 'description', OLD.description, 'amount', OLD.amount,
+            'category_id', OLD.category_id, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'account_id', OLD.account_id,
+            'is_draft', OLD.is_draft, 'latitude', OLD.latitude,
+            'longitude', OLD.longitude, 'place_name', OLD.place_name,
+            'return_by', OLD.return_by, 'warranty_expires', OLD.warranty_expires,
+            'user_id', OLD.user_id, 'cleared_status', OLD.cleared_status,
+            'reconciliation_id', OLD.reconciliation_id, 'version', OLD.version
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
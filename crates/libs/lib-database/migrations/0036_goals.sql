@@ -0,0 +1,79 @@
+-- ./migrations/0036_goals.sql
+--
+-- Savings goals, such as an emergency fund, tracked inside the ledger. A
+-- goal has a target amount in minor units (cents), an optional date to reach
+-- it by, and is linked to either the account the money is saved in or the
+-- category money set aside is classified under. Progress is worked out from
+-- the linked account's balance or the spending in the category, so nothing
+-- extra is recorded as money is saved. A goal goes with its account or
+-- category when that is deleted.
+
+CREATE TABLE IF NOT EXISTS goals (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    name                TEXT NOT NULL CHECK (name <> ''),
+    target_amount       INTEGER NOT NULL CHECK (target_amount > 0),
+    target_date         TEXT,
+    account_id          TEXT REFERENCES accounts (id) ON DELETE CASCADE,
+    category_id         TEXT REFERENCES categories (id) ON DELETE CASCADE,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL,
+    CHECK ((account_id IS NULL) <> (category_id IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_goals_account_id ON goals (account_id);
+CREATE INDEX IF NOT EXISTS idx_goals_category_id ON goals (category_id);
+
+-- goals
+
+CREATE TRIGGER IF NOT EXISTS goals_audit_insert AFTER INSERT ON goals
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'goals', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'name', NEW.name, 'target_amount', NEW.target_amount,
+            'target_date', NEW.target_date, 'account_id', NEW.account_id,
+            'category_id', NEW.category_id, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS goals_audit_update AFTER UPDATE ON goals
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'goals', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'name', OLD.name, 'target_amount', OLD.target_amount,
+            'target_date', OLD.target_date, 'account_id', OLD.account_id,
+            'category_id', OLD.category_id, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        json_object(
+            'id', NEW.id, 'name', NEW.name, 'target_amount', NEW.target_amount,
+            'target_date', NEW.target_date, 'account_id', NEW.account_id,
+            'category_id', NEW.category_id, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS goals_audit_delete AFTER DELETE ON goals
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'goals', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'name', OLD.name, 'target_amount', OLD.target_amount,
+            'target_date', OLD.target_date, 'account_id', OLD.account_id,
+            'category_id', OLD.category_id, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
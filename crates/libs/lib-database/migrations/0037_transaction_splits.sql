@@ -0,0 +1,20 @@
+-- ./migrations/0037_transaction_splits.sql
+--
+-- Splits of a transaction across categories, such as a supermarket receipt
+-- that is part groceries and part homewares. A split transaction has two or
+-- more splits whose amounts, in signed minor units (cents) like the
+-- transaction's, sum to the transaction amount, which is checked before they
+-- are written. Splits go with their transaction, and a category cannot be
+-- deleted while splits use it.
+
+CREATE TABLE IF NOT EXISTS transaction_splits (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    transaction_id      TEXT NOT NULL REFERENCES transactions (id) ON DELETE CASCADE,
+    category_id         TEXT NOT NULL REFERENCES categories (id),
+    amount              INTEGER NOT NULL CHECK (amount <> 0),
+    memo                TEXT,
+    position            INTEGER NOT NULL,
+    UNIQUE (transaction_id, position)
+);
+
+CREATE INDEX IF NOT EXISTS idx_transaction_splits_category_id ON transaction_splits (category_id);
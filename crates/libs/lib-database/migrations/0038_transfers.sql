@@ -0,0 +1,15 @@
+-- ./migrations/0038_transfers.sql
+--
+-- Transfers of money between two accounts, such as moving savings out of an
+-- everyday account. A transfer is recorded as a pair of transactions, one
+-- taking the amount out of the from account and one paying it into the to
+-- account, linked here so changes to either are copied to the other.
+-- Deleting either transaction removes the link.
+
+CREATE TABLE IF NOT EXISTS transfers (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    from_transaction_id TEXT NOT NULL UNIQUE REFERENCES transactions (id) ON DELETE CASCADE,
+    to_transaction_id   TEXT NOT NULL UNIQUE REFERENCES transactions (id) ON DELETE CASCADE,
+    created_on          TEXT NOT NULL,
+    CHECK (from_transaction_id <> to_transaction_id)
+);
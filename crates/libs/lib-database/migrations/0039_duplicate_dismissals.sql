@@ -0,0 +1,21 @@
+-- ./migrations/0039_duplicate_dismissals.sql
+--
+-- Probable duplicate transactions, such as one typed in by hand and then
+-- imported, are found by matching amounts on close dates with similar
+-- payees. A pair that is not a duplicate is dismissed, recorded here with
+-- the lower transaction id first, so it is not reported again. Dismissals go
+-- with either transaction.
+
+CREATE TABLE IF NOT EXISTS duplicate_dismissals (
+    first_transaction_id    TEXT NOT NULL REFERENCES transactions (id) ON DELETE CASCADE,
+    second_transaction_id   TEXT NOT NULL REFERENCES transactions (id) ON DELETE CASCADE,
+    dismissed_on            TEXT NOT NULL,
+    PRIMARY KEY (first_transaction_id, second_transaction_id),
+    CHECK (first_transaction_id < second_transaction_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_duplicate_dismissals_second_transaction_id
+    ON duplicate_dismissals (second_transaction_id);
+
+-- Candidate pairs are matched on amount, then date
+CREATE INDEX IF NOT EXISTS idx_transactions_amount_date ON transactions (amount, transaction_date);
@@ -0,0 +1,19 @@
+-- ./migrations/0040_balance_assertions.sql
+--
+-- Balances an account should have at the end of a day, as reported by the
+-- bank, to check the ledger against. An assertion is compared with the
+-- balance computed from the account's opening balance and posted
+-- transactions up to its date, so a difference points at a missed or
+-- doubled import before that day. Assertions never change a balance, and
+-- there is at most one per account per day.
+
+CREATE TABLE IF NOT EXISTS balance_assertions (
+    id                  TEXT PRIMARY KEY NOT NULL,
+    account_id          TEXT NOT NULL REFERENCES accounts (id) ON DELETE CASCADE,
+    assertion_date      TEXT NOT NULL,
+    balance             INTEGER NOT NULL,
+    note                TEXT,
+    created_on          TEXT NOT NULL,
+    updated_on          TEXT NOT NULL,
+    UNIQUE (account_id, assertion_date)
+);
@@ -0,0 +1,204 @@
+-- ./migrations/0041_category_uniqueness_per_user.sql
+--
+-- Category codes, names and slugs are unique per user rather than across
+-- the whole server, so two users can each file under `EXP.001 Groceries`.
+-- Rows without an owner stay unique among themselves. SQLite cannot change a
+-- column's UNIQUE constraint, so the table is rebuilt and its indexes and
+-- triggers are created again.
+--
+-- The rebuild runs in the one transaction SQLx applies the migration in, so
+-- it is applied and recorded together or not at all. Dropping the old table
+-- must not cascade to the rows referring to it, so it needs foreign keys
+-- off, which cannot be changed inside a transaction.
+-- `DatabasePool::migrate` turns them off for the migrations and back to the
+-- configured setting afterwards, and the migration refuses to run on a
+-- ledger with categories while they are on. The references are checked
+-- before the transaction commits.
+
+CREATE TEMP TABLE categories_rebuild_checks (
+    is_passed       BOOLEAN NOT NULL,
+    CONSTRAINT foreign_keys_off_for_rebuild CHECK (is_passed)
+);
+
+INSERT INTO categories_rebuild_checks (is_passed)
+SELECT NOT (SELECT foreign_keys FROM pragma_foreign_keys)
+    OR NOT EXISTS (SELECT 1 FROM categories);
+
+CREATE TABLE categories_rebuilt (
+    id              TEXT PRIMARY KEY NOT NULL,
+    code            TEXT NOT NULL,
+    name            TEXT NOT NULL,
+    description     TEXT,
+    url_slug        TEXT,
+    category_type   TEXT NOT NULL,
+    color           TEXT,
+    icon            TEXT,
+    is_active       BOOLEAN NOT NULL DEFAULT 1,
+    created_on      TEXT NOT NULL,
+    updated_on      TEXT NOT NULL,
+    parent_id       TEXT REFERENCES categories (id),
+    deleted_on      TEXT,
+    user_id         TEXT REFERENCES users (id),
+    version         INTEGER NOT NULL DEFAULT 1
+);
+
+INSERT INTO categories_rebuilt (
+    id, code, name, description, url_slug, category_type, color, icon, is_active,
+    created_on, updated_on, parent_id, deleted_on, user_id, version
+)
+SELECT
+    id, code, name, description, url_slug, category_type, color, icon, is_active,
+    created_on, updated_on, parent_id, deleted_on, user_id, version
+FROM categories;
+
+DROP TABLE categories;
+ALTER TABLE categories_rebuilt RENAME TO categories;
+
+-- Unique per owner, and among the rows without one
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_categories_user_code
+    ON categories (user_id, code) WHERE user_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_categories_user_name
+    ON categories (user_id, name) WHERE user_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_categories_user_url_slug
+    ON categories (user_id, url_slug) WHERE user_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_categories_unowned_code
+    ON categories (code) WHERE user_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_categories_unowned_name
+    ON categories (name) WHERE user_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_categories_unowned_url_slug
+    ON categories (url_slug) WHERE user_id IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_categories_created_on_id ON categories (created_on, id);
+CREATE INDEX IF NOT EXISTS idx_categories_deleted_on ON categories (deleted_on);
+CREATE INDEX IF NOT EXISTS idx_categories_parent_id ON categories (parent_id);
+CREATE INDEX IF NOT EXISTS idx_categories_user_id ON categories (user_id);
+
+CREATE TRIGGER IF NOT EXISTS categories_fts_insert AFTER INSERT ON categories
+BEGIN
+    INSERT INTO categories_fts (id, code, name, description)
+    VALUES (NEW.id, NEW.code, NEW.name, COALESCE(NEW.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_fts_update AFTER UPDATE OF code, name, description ON categories
+BEGIN
+    DELETE FROM categories_fts WHERE id = OLD.id;
+    INSERT INTO categories_fts (id, code, name, description)
+    VALUES (NEW.id, NEW.code, NEW.name, COALESCE(NEW.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_fts_delete AFTER DELETE ON categories
+BEGIN
+    DELETE FROM categories_fts WHERE id = OLD.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_insert AFTER INSERT ON categories
+BEGIN
+    INSERT INTO categories_history (
+        id, code, name, description, url_slug, category_type, color, icon, is_active,
+        created_on, updated_on, parent_id, user_id, version, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.code, NEW.name, NEW.description, NEW.url_slug, NEW.category_type,
+        NEW.color, NEW.icon, NEW.is_active, NEW.created_on, NEW.updated_on, NEW.parent_id,
+        NEW.user_id, NEW.version, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_update AFTER UPDATE ON categories
+BEGIN
+    UPDATE categories_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO categories_history (
+        id, code, name, description, url_slug, category_type, color, icon, is_active,
+        created_on, updated_on, parent_id, user_id, version, valid_from
+    )
+    SELECT
+        NEW.id, NEW.code, NEW.name, NEW.description, NEW.url_slug, NEW.category_type,
+        NEW.color, NEW.icon, NEW.is_active, NEW.created_on, NEW.updated_on, NEW.parent_id,
+        NEW.user_id, NEW.version, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE NEW.deleted_on IS NULL;
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_history_delete AFTER DELETE ON categories
+BEGIN
+    UPDATE categories_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_insert AFTER INSERT ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'code', NEW.code, 'name', NEW.name,
+            'description', NEW.description, 'url_slug', NEW.url_slug,
+            'category_type', NEW.category_type, 'color', NEW.color, 'icon', NEW.icon,
+            'is_active', NEW.is_active, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'parent_id', NEW.parent_id,
+            'deleted_on', NEW.deleted_on, 'user_id', NEW.user_id, 'version', NEW.version
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_update AFTER UPDATE ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'code', OLD.code, 'name', OLD.name,
+            'description', OLD.description, 'url_slug', OLD.url_slug,
+            'category_type', OLD.category_type, 'color', OLD.color, 'icon', OLD.icon,
+            'is_active', OLD.is_active, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'parent_id', OLD.parent_id,
+            'deleted_on', OLD.deleted_on, 'user_id', OLD.user_id, 'version', OLD.version
+        ),
+        json_object(
+            'id', NEW.id, 'code', NEW.code, 'name', NEW.name,
+            'description', NEW.description, 'url_slug', NEW.url_slug,
+            'category_type', NEW.category_type, 'color', NEW.color, 'icon', NEW.icon,
+            'is_active', NEW.is_active, 'created_on', NEW.created_on,
+            'updated_on', NEW.updated_on, 'parent_id', NEW.parent_id,
+            'deleted_on', NEW.deleted_on, 'user_id', NEW.user_id, 'version', NEW.version
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS categories_audit_delete AFTER DELETE ON categories
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'categories', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'code', OLD.code, 'name', OLD.name,
+            'description', OLD.description, 'url_slug', OLD.url_slug,
+            'category_type', OLD.category_type, 'color', OLD.color, 'icon', OLD.icon,
+            'is_active', OLD.is_active, 'created_on', OLD.created_on,
+            'updated_on', OLD.updated_on, 'parent_id', OLD.parent_id,
+            'deleted_on', OLD.deleted_on, 'user_id', OLD.user_id, 'version', OLD.version
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- Every row referring to a category still has it
+
+CREATE TEMP TABLE category_reference_checks (
+    is_passed       BOOLEAN NOT NULL,
+    CONSTRAINT category_references_kept CHECK (is_passed)
+);
+
+INSERT INTO category_reference_checks (is_passed)
+SELECT NOT EXISTS (SELECT 1 FROM pragma_foreign_key_check);
+
+DROP TABLE categories_rebuild_checks;
+DROP TABLE category_reference_checks;
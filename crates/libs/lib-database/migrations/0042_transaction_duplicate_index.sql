@@ -0,0 +1,10 @@
+-- ./migrations/0042_transaction_duplicate_index.sql
+--
+-- Probable duplicates are only looked for within an account, so candidate
+-- pairs are matched on account, then amount, then a window of dates. The
+-- index this replaces left out the account.
+
+DROP INDEX IF EXISTS idx_transactions_amount_date;
+
+CREATE INDEX IF NOT EXISTS idx_transactions_account_amount_date
+    ON transactions (account_id, amount, transaction_date);
@@ -0,0 +1,164 @@
+-- ./migrations/0043_account_owners.sql
+--
+-- Accounts may be owned by a user, as categories and transactions are, and
+-- their lookups, updates and deletes are scoped to the owner. Budgets and
+-- goals belong to the owner of their category or account. Accounts recorded
+-- before they had owners, or without one, are only seen unscoped.
+--
+-- Account names are unique per user, and among the accounts without one, so
+-- the table is rebuilt as categories were in 0041, with foreign keys off and
+-- the references checked before the transaction commits.
+
+CREATE TEMP TABLE accounts_rebuild_checks (
+    is_passed       BOOLEAN NOT NULL,
+    CONSTRAINT foreign_keys_off_for_rebuild CHECK (is_passed)
+);
+
+INSERT INTO accounts_rebuild_checks (is_passed)
+SELECT NOT (SELECT foreign_keys FROM pragma_foreign_keys)
+    OR NOT EXISTS (SELECT 1 FROM accounts);
+
+CREATE TABLE accounts_rebuilt (
+    id                      TEXT PRIMARY KEY NOT NULL,
+    name                    TEXT NOT NULL,
+    description             TEXT,
+    opening_balance         INTEGER NOT NULL DEFAULT 0,
+    is_active               BOOLEAN NOT NULL DEFAULT 1,
+    created_on              TEXT NOT NULL,
+    updated_on              TEXT NOT NULL,
+    currency                TEXT NOT NULL DEFAULT 'AUD',
+    adjustments_category_id TEXT REFERENCES categories (id) ON DELETE SET NULL,
+    user_id                 TEXT REFERENCES users (id)
+);
+
+INSERT INTO accounts_rebuilt (
+    id, name, description, opening_balance, is_active, created_on, updated_on, currency,
+    adjustments_category_id
+)
+SELECT
+    id, name, description, opening_balance, is_active, created_on, updated_on, currency,
+    adjustments_category_id
+FROM accounts;
+
+DROP TABLE accounts;
+ALTER TABLE accounts_rebuilt RENAME TO accounts;
+
+-- Unique per owner, and among the rows without one
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_accounts_user_name
+    ON accounts (user_id, name) WHERE user_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_accounts_unowned_name
+    ON accounts (name) WHERE user_id IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_accounts_created_on_id ON accounts (created_on, id);
+CREATE INDEX IF NOT EXISTS idx_accounts_user_id ON accounts (user_id);
+
+CREATE TRIGGER IF NOT EXISTS accounts_history_insert AFTER INSERT ON accounts
+BEGIN
+    INSERT INTO accounts_history (
+        id, name, description, opening_balance, is_active, created_on, updated_on, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.name, NEW.description, NEW.opening_balance, NEW.is_active,
+        NEW.created_on, NEW.updated_on, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS accounts_history_update AFTER UPDATE ON accounts
+BEGIN
+    UPDATE accounts_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+
+    INSERT INTO accounts_history (
+        id, name, description, opening_balance, is_active, created_on, updated_on, valid_from
+    )
+    VALUES (
+        NEW.id, NEW.name, NEW.description, NEW.opening_balance, NEW.is_active,
+        NEW.created_on, NEW.updated_on, strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS accounts_history_delete AFTER DELETE ON accounts
+BEGIN
+    UPDATE accounts_history
+    SET valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = OLD.id AND valid_to IS NULL;
+END;
+
+-- The owner is kept in the audit log, so the history of a deleted account is
+-- scoped too
+
+CREATE TRIGGER IF NOT EXISTS accounts_audit_insert AFTER INSERT ON accounts
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'accounts', NEW.id, 'insert', (SELECT actor FROM audit_actor WHERE id = 1),
+        NULL,
+        json_object(
+            'id', NEW.id, 'name', NEW.name, 'description', NEW.description,
+            'opening_balance', NEW.opening_balance, 'is_active', NEW.is_active,
+            'created_on', NEW.created_on, 'updated_on', NEW.updated_on,
+            'currency', NEW.currency,
+            'adjustments_category_id', NEW.adjustments_category_id,
+            'user_id', NEW.user_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS accounts_audit_update AFTER UPDATE ON accounts
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'accounts', NEW.id, 'update', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'name', OLD.name, 'description', OLD.description,
+            'opening_balance', OLD.opening_balance, 'is_active', OLD.is_active,
+            'created_on', OLD.created_on, 'updated_on', OLD.updated_on,
+            'currency', OLD.currency,
+            'adjustments_category_id', OLD.adjustments_category_id,
+            'user_id', OLD.user_id
+        ),
+        json_object(
+            'id', NEW.id, 'name', NEW.name, 'description', NEW.description,
+            'opening_balance', NEW.opening_balance, 'is_active', NEW.is_active,
+            'created_on', NEW.created_on, 'updated_on', NEW.updated_on,
+            'currency', NEW.currency,
+            'adjustments_category_id', NEW.adjustments_category_id,
+            'user_id', NEW.user_id
+        ),
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS accounts_audit_delete AFTER DELETE ON accounts
+BEGIN
+    INSERT INTO audit_log (entity, entity_id, action, actor, old_values, new_values, changed_on)
+    VALUES (
+        'accounts', OLD.id, 'delete', (SELECT actor FROM audit_actor WHERE id = 1),
+        json_object(
+            'id', OLD.id, 'name', OLD.name, 'description', OLD.description,
+            'opening_balance', OLD.opening_balance, 'is_active', OLD.is_active,
+            'created_on', OLD.created_on, 'updated_on', OLD.updated_on,
+            'currency', OLD.currency,
+            'adjustments_category_id', OLD.adjustments_category_id,
+            'user_id', OLD.user_id
+        ),
+        NULL,
+        strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
+END;
+
+-- Every row referring to an account still has it
+
+CREATE TEMP TABLE account_reference_checks (
+    is_passed       BOOLEAN NOT NULL,
+    CONSTRAINT account_references_kept CHECK (is_passed)
+);
+
+INSERT INTO account_reference_checks (is_passed)
+SELECT NOT EXISTS (SELECT 1 FROM pragma_foreign_key_check);
+
+DROP TABLE accounts_rebuild_checks;
+DROP TABLE account_reference_checks;
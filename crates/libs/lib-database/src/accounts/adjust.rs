@@ -0,0 +1,262 @@
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+/// The payee of the balancing entries created by
+/// [`Accounts::adjust_balance`](database::Accounts::adjust_balance).
+const ADJUSTMENT_PAYEE: &str = "Balance adjustment";
+
+impl database::Accounts {
+    /// Adjusts an account's balance on a date to a counted amount, such as
+    /// the cash in a wallet, by posting a balancing entry for the difference.
+    ///
+    /// The entry is posted to the account's adjustments category, with a
+    /// description recording the balance before and after and the optional
+    /// note, so the adjustment can be audited later. The entry belongs to the
+    /// account's owner. The balance is read and the entry written in a single
+    /// database transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the account
+    /// * `counted` - The actual balance on `on`, in minor units (cents)
+    /// * `on` - The date of the count, and of the balancing entry
+    /// * `note` - Optional reason for the adjustment, added to the description
+    /// * `scope` - The user whose accounts are adjusted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the balancing entry, or `None` if the balance already matched.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The account does not exist or is outside `scope` (`DatabaseError::NotFound`)
+    /// - The account has no adjustments category (`DatabaseError::Validation`)
+    /// - `on` is in a closed period (`DatabaseError::PeriodClosed`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Accounts, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, wallet_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let today = chrono::Utc::now().date_naive();
+    ///
+    /// let adjusted =
+    ///     Accounts::adjust_balance(wallet_id, 4_260, today, None, UserScope::All, pool).await?;
+    /// if let Some(entry) = adjusted {
+    ///     println!("Adjusted the wallet by {}", entry.amount);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Adjust account balance", skip(conn), err)]
+    pub fn adjust_balance<'c, A>(
+        id: domain::RowID,
+        counted: i64,
+        on: chrono::NaiveDate,
+        note: Option<String>,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<database::Transactions>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            match Self::post_adjustment(id, counted, on, note, scope, &mut tx).await {
+                Ok(adjustment) => {
+                    tx.commit().await?;
+
+                    if adjustment.is_some() {
+                        tracing::info!("Adjusted balance of account {} to {}", id, counted);
+                    }
+
+                    Ok(adjustment)
+                }
+                Err(error) => {
+                    tx.rollback().await?;
+                    Err(error)
+                }
+            }
+        }
+    }
+
+    /// Posts the balancing entry within the caller's database transaction.
+    async fn post_adjustment(
+        id: domain::RowID,
+        counted: i64,
+        on: chrono::NaiveDate,
+        note: Option<String>,
+        scope: database::UserScope,
+        tx: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<database::Transactions>> {
+        let account = Self::fetch_by_id(id, &mut *tx)
+            .await?
+            .filter(|account| scope.includes(account.user_id))
+            .ok_or_else(|| {
+                database::DatabaseError::NotFound(format!("Account with id {} not found", id))
+            })?;
+
+        let category_id = account.adjustments_category_id.ok_or_else(|| {
+            database::DatabaseError::Validation(format!(
+                "Account {} has no adjustments category to balance against",
+                id
+            ))
+        })?;
+
+        let balance = Self::balance(id, Some(on), &mut *tx).await?;
+        let difference = counted.checked_sub(balance).ok_or_else(|| {
+            database::DatabaseError::Validation(format!(
+                "Adjustment to account {} is too large",
+                id
+            ))
+        })?;
+
+        if difference == 0 {
+            return Ok(None);
+        }
+
+        let from = domain::Money::new(balance, account.currency);
+        let to = domain::Money::new(counted, account.currency);
+        let mut description = format!("Balance adjusted from {from} to {to}");
+        if let Some(note) = note
+            .as_deref()
+            .map(str::trim)
+            .filter(|note| !note.is_empty())
+        {
+            description.push_str(": ");
+            description.push_str(note);
+        }
+
+        let mut adjustment = database::Transactions::new(on, ADJUSTMENT_PAYEE, difference);
+        adjustment.description = Some(description);
+        adjustment.category_id = Some(category_id);
+        adjustment.account_id = Some(id);
+        adjustment.user_id = account.user_id;
+
+        Ok(Some(adjustment.insert(&mut *tx).await?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::insert::tests::Result;
+
+    fn date(day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    async fn wallet(pool: &sqlx::Pool<sqlx::Sqlite>) -> Result<database::Accounts> {
+        let mut category = database::Categories::mock();
+        category.name = "Cash adjustments".to_string();
+        category.url_slug = None;
+        let category = category.insert(pool).await?;
+
+        let mut wallet = database::Accounts::new("Wallet");
+        wallet.opening_balance = 5_000;
+        wallet.adjustments_category_id = Some(category.id);
+
+        Ok(wallet.insert(pool).await?)
+    }
+
+    #[sqlx::test]
+    async fn adjust_balance_posts_difference(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let wallet = wallet(&pool).await?;
+        let mut coffee = database::Transactions::new(date(3), "Corner Cafe", -550);
+        coffee.account_id = Some(wallet.id);
+        coffee.insert(&pool).await?;
+
+        let note = Some("Counted after the markets".to_string());
+        let adjustment = database::Accounts::adjust_balance(
+            wallet.id,
+            4_260,
+            date(10),
+            note,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?
+        .expect("balance differs");
+
+        assert_eq!(adjustment.amount, -190);
+        assert_eq!(adjustment.category_id, wallet.adjustments_category_id);
+        assert_eq!(
+            adjustment.description.as_deref(),
+            Some("Balance adjusted from $44.50 to $42.60: Counted after the markets")
+        );
+        assert_eq!(
+            database::Accounts::balance(wallet.id, Some(date(10)), &pool).await?,
+            4_260
+        );
+
+        let again = database::Accounts::adjust_balance(
+            wallet.id,
+            4_260,
+            date(10),
+            None,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        assert!(again.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn adjust_balance_requires_adjustments_category(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+
+        let result = database::Accounts::adjust_balance(
+            account.id,
+            0,
+            date(10),
+            None,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn adjust_balance_in_closed_period_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let wallet = wallet(&pool).await?;
+        database::PeriodClosings::close(date(31), None, &pool).await?;
+
+        let result = database::Accounts::adjust_balance(
+            wallet.id,
+            0,
+            date(10),
+            None,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::PeriodClosed(_))
+        ));
+        assert_eq!(
+            database::Accounts::balance(wallet.id, None, &pool).await?,
+            5_000
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,399 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Accounts {
+    /// Computes the balance of an account as of a date.
+    ///
+    /// The balance is the account's opening balance plus the sum of every
+    /// transaction posted to it on or before `as_of`. When `as_of` is `None`
+    /// all transactions are included, giving the current balance. Draft
+    /// transactions are never included.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the account
+    /// * `as_of` - Optional last transaction date to include
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the balance in minor units, or a `DatabaseError` if the query fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The account with the given ID does not exist (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Accounts;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, account_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let end_of_year = chrono::NaiveDate::from_ymd_opt(2025, 6, 30);
+    /// let balance = Accounts::balance(account_id, end_of_year, pool).await?;
+    /// println!("Balance at 30 June: {balance}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Compute account balance",
+        skip(conn),
+        fields(id = %id, as_of = ?as_of),
+        err
+    )]
+    pub fn balance<'c, A>(
+        id: domain::RowID,
+        as_of: Option<chrono::NaiveDate>,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<i64>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let balance = sqlx::query_scalar!(
+                r#"
+                    SELECT
+                        accounts.opening_balance + COALESCE((
+                            SELECT SUM(transactions.amount)
+                            FROM transactions
+                            WHERE transactions.account_id = accounts.id
+                                AND transactions.is_draft = 0
+                                AND (?1 IS NULL OR transactions.transaction_date <= ?1)
+                        ), 0) AS "balance!: i64"
+                    FROM accounts
+                    WHERE accounts.id = ?2
+                "#,
+                as_of,
+                id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("accounts.balance")
+            .await?;
+
+            balance.ok_or_else(|| {
+                database::DatabaseError::NotFound(format!("Account with id {} not found", id))
+            })
+        }
+    }
+
+    /// Computes the balance of an account as it was recorded at the end of a
+    /// date.
+    ///
+    /// Unlike [`balance`](Self::balance), which applies today's data to a past
+    /// date, this reads the account and transaction history so the result is
+    /// the balance a report would have shown on `as_of`. Transactions entered,
+    /// edited or deleted after `as_of` do not change it, making year-end
+    /// figures reproducible. The day ends at midnight UTC.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the account
+    /// * `as_of` - The last transaction date and day of history to include
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the balance in minor units, or a `DatabaseError` if the query fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The account did not exist at the end of `as_of` (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Accounts;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, account_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let end_of_year = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+    /// let balance = Accounts::balance_as_recorded(account_id, end_of_year, pool).await?;
+    /// println!("Balance reported at 30 June: {balance}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Compute account balance as recorded",
+        skip(conn),
+        fields(id = %id, as_of = %as_of),
+        err
+    )]
+    pub fn balance_as_recorded<'c, A>(
+        id: domain::RowID,
+        as_of: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<i64>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let cutoff = database::history::end_of_day(as_of);
+
+            let balance = sqlx::query_scalar!(
+                r#"
+                    SELECT
+                        accounts_history.opening_balance + COALESCE((
+                            SELECT SUM(transactions_history.amount)
+                            FROM transactions_history
+                            WHERE transactions_history.account_id = accounts_history.id
+                                AND transactions_history.is_draft = 0
+                                AND transactions_history.transaction_date <= ?1
+                                AND transactions_history.valid_from < ?2
+                                AND (transactions_history.valid_to IS NULL
+                                    OR transactions_history.valid_to >= ?2)
+                        ), 0) AS "balance!: i64"
+                    FROM accounts_history
+                    WHERE accounts_history.id = ?3
+                        AND accounts_history.valid_from < ?2
+                        AND (accounts_history.valid_to IS NULL OR accounts_history.valid_to >= ?2)
+                "#,
+                as_of,
+                cutoff,
+                id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("accounts.balance_as_recorded")
+            .await?;
+
+            balance.ok_or_else(|| {
+                database::DatabaseError::NotFound(format!(
+                    "Account with id {} not found as of {}",
+                    id, as_of
+                ))
+            })
+        }
+    }
+
+    /// Adds up the balances of every account as of a valuation date, each
+    /// converted to one currency at the exchange rate on that date.
+    ///
+    /// Each account's balance is computed as by [`balance`](Self::balance) in
+    /// the account's own currency, then converted with
+    /// [`ExchangeRates::convert`](database::ExchangeRates::convert).
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - The currency to report the total in
+    /// * `on` - The valuation date, and the last transaction date to include
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - An account's currency has no exchange rate on or before `on` (`DatabaseError::NotFound`)
+    /// - The total is out of range (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Accounts;
+    /// use lib_domain::Currency;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let end_of_year = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+    /// let total = Accounts::total_balance(Currency::AUD, end_of_year, pool).await?;
+    /// println!("Net worth at 30 June: {total}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Compute total balance of accounts", skip(conn), err)]
+    pub fn total_balance<'c, A>(
+        currency: domain::Currency,
+        on: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<domain::Money>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let balances = sqlx::query!(
+                r#"
+                    SELECT
+                        accounts.currency AS "currency!: domain::Currency",
+                        accounts.opening_balance + COALESCE((
+                            SELECT SUM(transactions.amount)
+                            FROM transactions
+                            WHERE transactions.account_id = accounts.id
+                                AND transactions.is_draft = 0
+                                AND transactions.transaction_date <= ?
+                        ), 0) AS "balance!: i64"
+                    FROM accounts
+                "#,
+                on
+            )
+            .fetch_all(&mut *conn)
+            .observe("accounts.total_balance")
+            .await?;
+
+            let mut total = domain::Money::zero(currency);
+            for row in balances {
+                let balance = domain::Money::new(row.balance, row.currency);
+                let converted =
+                    database::ExchangeRates::convert(balance, currency, on, &mut *conn).await?;
+
+                total = total
+                    .checked_add(converted)
+                    .map_err(|error| database::DatabaseError::Validation(error.to_string()))?;
+            }
+
+            Ok(total)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::insert::tests::Result;
+
+    async fn post(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+        account_id: domain::RowID,
+        date: chrono::NaiveDate,
+        amount: i64,
+    ) -> Result<()> {
+        let mut transaction = database::Transactions::new(date, "Payee", amount);
+        transaction.account_id = Some(account_id);
+        transaction.insert(pool).await?;
+
+        Ok(())
+    }
+
+    fn date(day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn balance_sums_opening_balance_and_transactions(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut account = database::Accounts::new("Everyday");
+        account.opening_balance = 100_000;
+        let account = account.insert(&pool).await?;
+        let other = database::Accounts::mock().insert(&pool).await?;
+
+        post(&pool, account.id, date(1), -2_500).await?;
+        post(&pool, account.id, date(10), 50_000).await?;
+        post(&pool, account.id, date(20), -7_500).await?;
+        post(&pool, other.id, date(5), -99_999).await?;
+
+        let current = database::Accounts::balance(account.id, None, &pool).await?;
+        let as_of_tenth = database::Accounts::balance(account.id, Some(date(10)), &pool).await?;
+        let before_any =
+            database::Accounts::balance(account.id, Some(date(1).pred_opt().unwrap()), &pool)
+                .await?;
+
+        assert_eq!(current, 140_000);
+        assert_eq!(as_of_tenth, 147_500);
+        assert_eq!(before_any, 100_000);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn total_balance_converts_each_currency(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut savings = database::Accounts::new("Savings");
+        savings.opening_balance = 100_000;
+        let savings = savings.insert(&pool).await?;
+        let mut travel = database::Accounts::new("Travel");
+        travel.opening_balance = 50_000;
+        travel.currency = domain::Currency::USD;
+        let travel = travel.insert(&pool).await?;
+
+        post(&pool, savings.id, date(1), -10_000).await?;
+        post(&pool, travel.id, date(1), -10_000).await?;
+        post(&pool, travel.id, date(20), -10_000).await?;
+
+        let rate =
+            domain::ExchangeRate::parse(domain::Currency::AUD, domain::Currency::USD, "0.8")?;
+        database::ExchangeRates::new(rate, date(1))
+            .insert(&pool)
+            .await?;
+
+        let total =
+            database::Accounts::total_balance(domain::Currency::AUD, date(10), &pool).await?;
+        let missing =
+            database::Accounts::total_balance(domain::Currency::EUR, date(10), &pool).await;
+
+        assert_eq!(total, domain::Money::new(140_000, domain::Currency::AUD));
+        assert!(matches!(missing, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn balance_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let result = database::Accounts::balance(domain::RowID::new(), None, &pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn balance_as_recorded_ignores_later_edits(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let today = chrono::Utc::now().date_naive();
+        let year_end = today - chrono::Days::new(5);
+        let mut account = database::Accounts::new("Everyday");
+        account.opening_balance = 100_000;
+        let account = account.insert(&pool).await?;
+
+        let mut edited = database::Transactions::new(year_end, "Payee", -2_500);
+        edited.account_id = Some(account.id);
+        let mut edited = edited.insert(&pool).await?;
+        let mut deleted = database::Transactions::new(year_end, "Payee", -1_000);
+        deleted.account_id = Some(account.id);
+        let deleted = deleted.insert(&pool).await?;
+        for (table, id) in [
+            ("accounts_history", account.id),
+            ("transactions_history", edited.id),
+            ("transactions_history", deleted.id),
+        ] {
+            database::history::backdate(table, id, 10, &pool).await?;
+        }
+
+        // Edits made after year end: a late entry, a changed amount and a delete
+        post(&pool, account.id, year_end, -7_500).await?;
+        edited.amount = -3_000;
+        edited.update(database::UserScope::All, &pool).await?;
+        database::Transactions::delete_by_id(deleted.id, database::UserScope::All, &pool).await?;
+
+        let as_recorded =
+            database::Accounts::balance_as_recorded(account.id, year_end, &pool).await?;
+        let as_of = database::Accounts::balance(account.id, Some(year_end), &pool).await?;
+        let recorded_today =
+            database::Accounts::balance_as_recorded(account.id, today, &pool).await?;
+
+        assert_eq!(as_recorded, 96_500);
+        assert_eq!(as_of, 89_500);
+        assert_eq!(recorded_today, as_of);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn balance_as_recorded_before_account_existed(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let yesterday = chrono::Utc::now().date_naive() - chrono::Days::new(1);
+
+        let result = database::Accounts::balance_as_recorded(account.id, yesterday, &pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
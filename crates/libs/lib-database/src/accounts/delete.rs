@@ -0,0 +1,134 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Accounts {
+    /// Deletes an account from the database by its ID.
+    ///
+    /// Accounts that still have transactions posted to them cannot be deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the account to delete
+    /// * `scope` - The user whose accounts are deleted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the deletion was successful, or a `DatabaseError` if it fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The account with the given ID does not exist or is outside `scope`
+    ///   (`DatabaseError::NotFound`)
+    /// - Transactions are still posted to the account (foreign key violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Accounts, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, account_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// Accounts::delete_by_id(account_id, UserScope::All, pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Delete account from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let user_id = scope.user_id();
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    DELETE FROM accounts
+                    WHERE id = ?1 AND (?2 IS NULL OR user_id = ?2)
+                "#,
+                id,
+                user_id
+            )
+            .execute(&mut *conn)
+            .observe("accounts.delete_by_id")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Account with id {} not found",
+                    id
+                )));
+            }
+
+            tracing::info!("Deleted account {} from database", id);
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn delete_by_id_removes_account(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+
+        database::Accounts::delete_by_id(account.id, database::UserScope::All, &pool).await?;
+
+        assert!(
+            database::Accounts::find_by_id(account.id, database::UserScope::All, &pool)
+                .await?
+                .is_none()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn delete_with_transactions_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let mut transaction = database::Transactions::mock();
+        transaction.account_id = Some(account.id);
+        transaction.insert(&pool).await?;
+
+        let result =
+            database::Accounts::delete_by_id(account.id, database::UserScope::All, &pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+        assert!(
+            database::Accounts::find_by_id(account.id, database::UserScope::All, &pool)
+                .await?
+                .is_some()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn delete_by_id_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let result =
+            database::Accounts::delete_by_id(domain::RowID::new(), database::UserScope::All, &pool)
+                .await;
+
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
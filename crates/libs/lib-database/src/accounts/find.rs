@@ -0,0 +1,323 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Accounts {
+    /// Finds an account by its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the account to find
+    /// * `scope` - The user whose accounts are searched
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Accounts)` if the account exists in `scope`, or `None` if
+    /// not found. Returns a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Accounts, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, account_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// if let Some(account) = Accounts::find_by_id(account_id, UserScope::All, pool).await? {
+    ///     println!("Found account: {}", account.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find account by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let account = Self::fetch_by_id(id, &mut conn).await?;
+
+            Ok(account.filter(|account| scope.includes(account.user_id)))
+        }
+    }
+
+    /// Retrieves a page of accounts, ordered by name.
+    ///
+    /// The next page starts after the name of the cursor's account, so a page
+    /// read with a cursor is not shifted by accounts created in the meantime.
+    /// See the [`pagination`](crate::pagination) module.
+    ///
+    /// # Arguments
+    ///
+    /// * `after` - The cursor of the previous page, or `None` for the first page
+    /// * `page_size` - Maximum number of records to return, 50 if zero or negative
+    /// * `scope` - The user whose accounts are listed
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the page of accounts, with the total number of accounts and the
+    /// cursor of the next page, or a `DatabaseError` if the query fails, or
+    /// `DatabaseError::Validation` if the cursor's account has been deleted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Accounts, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let page = Accounts::find_all_with_pagination(None, 20, UserScope::All, pool).await?;
+    /// println!("Showing {} of {} accounts", page.items.len(), page.total_count);
+    ///
+    /// if let Some(cursor) = page.next_cursor {
+    ///     let next = Accounts::find_all_with_pagination(Some(cursor), 20, UserScope::All, pool).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find accounts with pagination",
+        skip(conn),
+        fields(after = ?after, page_size = %page_size),
+        err
+    )]
+    pub fn find_all_with_pagination<'c, A>(
+        after: Option<database::PageCursor>,
+        page_size: i32,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<database::Page<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let page_size = database::pagination::page_size_or_default(page_size);
+            let limit = page_size + 1;
+            let after_id = after.map(|after| after.id);
+
+            let mut conn = conn.acquire().await?;
+
+            let after_name = match after_id {
+                Some(id) => Some(
+                    sqlx::query_scalar!("SELECT name FROM accounts WHERE id = ?", id)
+                        .fetch_optional(&mut *conn)
+                        .observe("accounts.find_all_with_pagination")
+                        .await?
+                        .ok_or_else(|| {
+                            database::DatabaseError::Validation(
+                                "The page token's account has been deleted, start again from the first page"
+                                    .to_string(),
+                            )
+                        })?,
+                ),
+                None => None,
+            };
+
+            let total_count: i32 = sqlx::query_scalar(
+                "SELECT COUNT(*) as count FROM accounts WHERE ?1 IS NULL OR user_id = ?1",
+            )
+            .bind(scope.user_id())
+            .fetch_one(&mut *conn)
+            .observe_one("accounts.find_all_with_pagination")
+            .await?;
+
+            let user_id = scope.user_id();
+            let accounts = sqlx::query_as!(
+                database::Accounts,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        name,
+                        description,
+                        opening_balance     AS "opening_balance!: i64",
+                        currency            AS "currency!: domain::Currency",
+                        adjustments_category_id AS "adjustments_category_id?: domain::RowID",
+                        is_active           AS "is_active!: bool",
+                        user_id             AS "user_id?: domain::RowID",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM accounts
+                    WHERE (?1 IS NULL OR (name, id) > (?1, ?2))
+                        AND (?4 IS NULL OR user_id = ?4)
+                    ORDER BY name ASC, id ASC
+                    LIMIT ?3
+                "#,
+                after_name,
+                after_id,
+                limit,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("accounts.find_all_with_pagination")
+            .await?;
+
+            let page = database::Page::from_rows(accounts, page_size, total_count, |account| {
+                database::PageCursor::new(account.created_on, account.id)
+            });
+
+            tracing::info!(
+                "Retrieved {} of {} accounts from database",
+                page.items.len(),
+                total_count
+            );
+
+            Ok(page)
+        }
+    }
+
+    /// Reads an account back through the given connection.
+    ///
+    /// Used by [`find_by_id`](Self::find_by_id) and by writes that return the
+    /// stored row through the connection they already hold.
+    pub(super) async fn fetch_by_id(
+        id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let account = sqlx::query_as!(
+            database::Accounts,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    name,
+                    description,
+                    opening_balance     AS "opening_balance!: i64",
+                    currency            AS "currency!: domain::Currency",
+                    adjustments_category_id AS "adjustments_category_id?: domain::RowID",
+                    is_active           AS "is_active!: bool",
+                    user_id             AS "user_id?: domain::RowID",
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM accounts
+                WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("accounts.fetch_by_id")
+        .await?;
+
+        Ok(account)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn find_by_id_returns_account(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+
+        let found = database::Accounts::find_by_id(account.id, database::UserScope::All, &pool)
+            .await?
+            .expect("account should exist");
+
+        assert_eq!(found, account);
+        assert!(
+            database::Accounts::find_by_id(domain::RowID::new(), database::UserScope::All, &pool)
+                .await?
+                .is_none()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_all_with_pagination_pages_by_name(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        for name in ["Visa", "Everyday", "Savings"] {
+            database::Accounts::new(name).insert(&pool).await?;
+        }
+        let names = |accounts: &[database::Accounts]| {
+            accounts
+                .iter()
+                .map(|account| account.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let first =
+            database::Accounts::find_all_with_pagination(None, 2, database::UserScope::All, &pool)
+                .await?;
+        // An account created before the cursor does not shift the next page
+        database::Accounts::new("Cash").insert(&pool).await?;
+        let second = database::Accounts::find_all_with_pagination(
+            first.next_cursor,
+            2,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(first.total_count, 3);
+        assert_eq!(names(&first.items), ["Everyday", "Savings"]);
+        assert_eq!(names(&second.items), ["Visa"]);
+        assert_eq!(second.next_cursor, None);
+
+        // A cursor whose account has gone cannot say where the next page starts
+        let savings = first.items[1].id;
+        database::Accounts::delete_by_id(savings, database::UserScope::All, &pool).await?;
+        let gone = database::Accounts::find_all_with_pagination(
+            first.next_cursor,
+            2,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+        assert!(matches!(gone, Err(database::DatabaseError::Validation(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn accounts_are_limited_to_the_scope(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let owner = database::Users::mock().insert(&pool).await?;
+        let other = database::Users::mock().insert(&pool).await?;
+        let mut account = database::Accounts::new("Everyday");
+        account.user_id = Some(owner.id);
+        let account = account.insert(&pool).await?;
+        // Names are unique per owner, so the other user can have an Everyday too
+        let mut theirs = database::Accounts::new("Everyday");
+        theirs.user_id = Some(other.id);
+        theirs.insert(&pool).await?;
+
+        let as_owner =
+            database::Accounts::find_by_id(account.id, database::UserScope::User(owner.id), &pool)
+                .await?;
+        let as_other =
+            database::Accounts::find_by_id(account.id, database::UserScope::User(other.id), &pool)
+                .await?;
+        let page = database::Accounts::find_all_with_pagination(
+            None,
+            10,
+            database::UserScope::User(other.id),
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(as_owner, Some(account.clone()));
+        assert!(as_other.is_none());
+        assert_eq!(page.total_count, 1);
+        assert!(page.items.iter().all(|item| item.user_id == Some(other.id)));
+
+        let deleted = database::Accounts::delete_by_id(
+            account.id,
+            database::UserScope::User(other.id),
+            &pool,
+        )
+        .await;
+        assert!(matches!(deleted, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::Accounts {
+    /// Inserts a new account into the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the inserted account as read back from the database, or a
+    /// `DatabaseError` if the insertion fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The name is empty (`DatabaseError::Validation`)
+    /// - An account with the same id, or the same name and owner, already exists
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Accounts;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut account = Accounts::new("Everyday Savings");
+    /// account.opening_balance = 150_000;
+    ///
+    /// let inserted = account.insert(pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert new account into database",
+        skip(self, conn),
+        fields(id = %self.id, name = %self.name),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO accounts (
+                        id, name, description, opening_balance, currency, adjustments_category_id,
+                        is_active, user_id, created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.name,
+                self.description,
+                self.opening_balance,
+                self.currency,
+                self.adjustments_category_id,
+                self.is_active,
+                self.user_id,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("accounts.insert")
+            .await?;
+
+            let inserted = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Account with id {} not found after insert",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("New account inserted into the database.");
+
+            Ok(inserted)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    // Bring module into test scope
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn insert_account_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::mock();
+
+        let inserted = account.insert(&pool).await?;
+
+        assert_eq!(inserted, account);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_duplicate_name_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let existing = database::Accounts::mock().insert(&pool).await?;
+
+        let result = database::Accounts::new(existing.name).insert(&pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+//! # Accounts Database Module
+//!
+//! Provides storage for the accounts money is held in or owed on, such as a
+//! bank account or credit card. Transactions are posted to an account, and an
+//! account's balance is its opening balance plus the sum of its transactions.
+//! When a counted balance, such as the cash in a wallet, differs from the
+//! recorded one, a balancing entry is posted to the account's adjustments
+//! category.
+//!
+//! As with [`Transactions`](crate::Transactions), the query functions return
+//! `impl Future + Send` so they can run inside
+//! [`with_transaction`](crate::with_transaction) from a gRPC handler.
+
+mod adjust;
+mod balance;
+mod delete;
+mod find;
+mod insert;
+mod model;
+mod update;
+
+/// Database row model representing a persisted account.
+pub use model::Accounts;
@@ -0,0 +1,112 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// An account that transactions are posted to.
+///
+/// The opening balance is in signed minor units (cents) of the account's
+/// currency, positive for money held and negative for money owed.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct Accounts {
+    pub id: domain::RowID,
+    pub name: String,
+    pub description: Option<String>,
+    pub opening_balance: i64,
+    pub currency: domain::Currency,
+
+    /// The category balancing entries are posted to when the balance is
+    /// adjusted to a counted amount.
+    pub adjustments_category_id: Option<domain::RowID>,
+
+    pub is_active: bool,
+
+    /// The user who owns the account, or `None` for accounts recorded
+    /// before they had owners.
+    pub user_id: Option<domain::RowID>,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::Accounts {
+    /// Creates a new active account with a zero opening balance, in the
+    /// default currency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::Accounts;
+    ///
+    /// let account = Accounts::new("Everyday Savings");
+    ///
+    /// assert_eq!(account.opening_balance, 0);
+    /// assert!(account.is_active);
+    /// ```
+    pub fn new(name: impl Into<String>) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            name: name.into(),
+            description: None,
+            opening_balance: 0,
+            currency: domain::Currency::default(),
+            adjustments_category_id: None,
+            is_active: true,
+            user_id: None,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Checks the account fields before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the name is empty or only
+    /// whitespace.
+    pub fn validate(&self) -> DatabaseResult<()> {
+        if self.name.trim().is_empty() {
+            return Err(DatabaseError::Validation(format!(
+                "Account {} must have a name",
+                self.id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Generates a mock account with a unique name and random opening balance.
+    #[cfg(test)]
+    pub fn mock() -> Self {
+        use fake::Fake;
+        use fake::faker::company::en::CompanyName;
+
+        let name: String = CompanyName().fake();
+
+        let mut account = Self::new(format!("{name} {}", domain::RowID::new()));
+        account.opening_balance = (-100_000..1_000_000).fake();
+        account
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_accepts_account() {
+        assert_eq!(
+            database::Accounts::new("Everyday Savings").validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_rejects_empty_name() {
+        let account = database::Accounts::new("  ");
+
+        assert!(matches!(
+            account.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+}
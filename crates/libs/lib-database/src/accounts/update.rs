@@ -0,0 +1,144 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::Accounts {
+    /// Updates an existing account in the database.
+    ///
+    /// All editable fields of the account identified by `id` are replaced and
+    /// the `updated_on` timestamp is set to now. The `created_on` timestamp and
+    /// the owner are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The user whose accounts are updated
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated account as read back from the database, or a
+    /// `DatabaseError` if the update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The account does not exist or is outside `scope` (`DatabaseError::NotFound`)
+    /// - The name is empty (`DatabaseError::Validation`)
+    /// - Another account of the same owner already has the same name
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Accounts, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, account_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut account = Accounts::find_by_id(account_id, UserScope::All, pool).await?.unwrap();
+    /// account.is_active = false;
+    ///
+    /// let updated = account.update(UserScope::All, pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Update account in database",
+        skip(self, conn),
+        fields(id = %self.id, name = %self.name),
+        err
+    )]
+    pub fn update<'a, 'c, A>(
+        &'a self,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let updated_on = chrono::Utc::now();
+            let user_id = scope.user_id();
+
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    UPDATE accounts
+                    SET name = ?, description = ?, opening_balance = ?, currency = ?,
+                        adjustments_category_id = ?, is_active = ?, updated_on = ?
+                    WHERE id = ? AND (? IS NULL OR user_id = ?)
+                "#,
+                self.name,
+                self.description,
+                self.opening_balance,
+                self.currency,
+                self.adjustments_category_id,
+                self.is_active,
+                updated_on,
+                self.id,
+                user_id,
+                user_id
+            )
+            .execute(&mut *conn)
+            .observe("accounts.update")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Account with id {} not found",
+                    self.id
+                )));
+            }
+
+            let updated = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Account with id {} not found after update",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("Account updated in the database.");
+
+            Ok(updated)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn update_account_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Accounts::mock().insert(&pool).await?;
+
+        let mut account = inserted.clone();
+        account.name = "Everyday Savings".to_string();
+        account.is_active = false;
+
+        let updated = account.update(database::UserScope::All, &pool).await?;
+
+        assert_eq!(updated.name, "Everyday Savings");
+        assert!(!updated.is_active);
+        assert_eq!(updated.created_on, inserted.created_on);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let result = database::Accounts::mock()
+            .update(database::UserScope::All, &pool)
+            .await;
+
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Attachments {
+    /// Deletes an attachment from the database by its ID. Its content is
+    /// deleted too, unless another attachment shares it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the attachment to delete
+    /// * `scope` - The user whose transactions' attachments may be deleted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The attachment with the given ID does not exist or is outside `scope` (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Delete attachment from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let user_id = scope.user_id();
+
+            // The attachments_delete_orphaned_content trigger removes the
+            // content once nothing refers to it
+            let rows_affected = sqlx::query!(
+                r#"
+                    DELETE FROM attachments
+                    WHERE id = ?1
+                        AND (?2 IS NULL OR transaction_id IN (SELECT id FROM transactions WHERE user_id = ?2))
+                "#,
+                id,
+                user_id
+            )
+            .execute(&mut *conn)
+            .observe("attachments.delete_by_id")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Attachment with id {} not found",
+                    id
+                )));
+            }
+
+            tracing::info!("Deleted attachment {} from database", id);
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attachments::insert::tests::{PDF, Result};
+
+    async fn contents(pool: &sqlx::SqlitePool) -> Result<i64> {
+        Ok(
+            sqlx::query_scalar("SELECT COUNT(*) FROM attachment_contents")
+                .fetch_one(pool)
+                .await?,
+        )
+    }
+
+    #[sqlx::test]
+    async fn delete_by_id_keeps_shared_content(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let transaction = database::Transactions::mock().insert(&pool).await?;
+        let first =
+            database::Attachments::new(transaction.id, "invoice.pdf", "application/pdf", PDF)
+                .insert(PDF, &pool)
+                .await?;
+        let second = database::Attachments::new(transaction.id, "copy.pdf", "application/pdf", PDF)
+            .insert(PDF, &pool)
+            .await?;
+
+        database::Attachments::delete_by_id(first.id, database::UserScope::All, &pool).await?;
+        assert_eq!(contents(&pool).await?, 1);
+
+        database::Attachments::delete_by_id(second.id, database::UserScope::All, &pool).await?;
+        assert_eq!(contents(&pool).await?, 0);
+        assert!(matches!(
+            database::Attachments::delete_by_id(second.id, database::UserScope::All, &pool).await,
+            Err(database::DatabaseError::NotFound(_))
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn deleting_the_transaction_deletes_its_attachments(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let transaction = database::Transactions::mock().insert(&pool).await?;
+        database::Attachments::new(transaction.id, "invoice.pdf", "application/pdf", PDF)
+            .insert(PDF, &pool)
+            .await?;
+
+        database::Transactions::delete_by_id(transaction.id, database::UserScope::All, &pool)
+            .await?;
+
+        assert!(
+            database::Attachments::find_by_transaction(
+                transaction.id,
+                database::UserScope::All,
+                &pool
+            )
+            .await?
+            .is_empty()
+        );
+        assert_eq!(contents(&pool).await?, 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Attachments {
+    /// Finds an attachment by its unique identifier, without its content.
+    /// Attachments belong to the owner of their transaction.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Attachments)` if the attachment exists in `scope`, or
+    /// `None` if not found.
+    #[tracing::instrument(
+        name = "Find attachment by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let user_id = scope.user_id();
+
+            let attachment = sqlx::query_as!(
+                database::Attachments,
+                r#"
+                    SELECT
+                        id              AS "id!: domain::RowID",
+                        transaction_id  AS "transaction_id!: domain::RowID",
+                        file_name,
+                        content_type,
+                        size_bytes      AS "size_bytes!: i64",
+                        sha256,
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>"
+                    FROM attachments
+                    WHERE id = ?1
+                        AND (?2 IS NULL OR transaction_id IN (SELECT id FROM transactions WHERE user_id = ?2))
+                "#,
+                id,
+                user_id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("attachments.find_by_id")
+            .await?;
+
+            Ok(attachment)
+        }
+    }
+
+    /// Finds the attachments of a transaction, oldest first, without their
+    /// content. A transaction outside `scope` has none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Attachments, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, transaction_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// for attachment in Attachments::find_by_transaction(transaction_id, UserScope::All, pool).await? {
+    ///     println!("{} ({} bytes)", attachment.file_name, attachment.size_bytes);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find attachments of transaction",
+        skip(conn),
+        fields(transaction_id = %transaction_id),
+        err
+    )]
+    pub fn find_by_transaction<'c, A>(
+        transaction_id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let user_id = scope.user_id();
+
+            let attachments = sqlx::query_as!(
+                database::Attachments,
+                r#"
+                    SELECT
+                        id              AS "id!: domain::RowID",
+                        transaction_id  AS "transaction_id!: domain::RowID",
+                        file_name,
+                        content_type,
+                        size_bytes      AS "size_bytes!: i64",
+                        sha256,
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>"
+                    FROM attachments
+                    WHERE transaction_id = ?1
+                        AND (?2 IS NULL OR transaction_id IN (SELECT id FROM transactions WHERE user_id = ?2))
+                    ORDER BY created_on, id
+                "#,
+                transaction_id,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("attachments.find_by_transaction")
+            .await?;
+
+            Ok(attachments)
+        }
+    }
+
+    /// Reads the content of an attachment.
+    ///
+    /// # Returns
+    ///
+    /// Returns the file's bytes, or `None` if the attachment does not exist
+    /// in `scope`.
+    #[tracing::instrument(
+        name = "Find attachment content",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_content<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Vec<u8>>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let user_id = scope.user_id();
+
+            let content = sqlx::query_scalar!(
+                r#"
+                    SELECT attachment_contents.content
+                    FROM attachments
+                    JOIN attachment_contents ON attachment_contents.sha256 = attachments.sha256
+                    WHERE attachments.id = ?1
+                        AND (?2 IS NULL OR attachments.transaction_id IN (
+                            SELECT id FROM transactions WHERE user_id = ?2
+                        ))
+                "#,
+                id,
+                user_id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("attachments.find_content")
+            .await?;
+
+            Ok(content)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attachments::insert::tests::{PDF, Result};
+
+    #[sqlx::test]
+    async fn find_returns_the_attachment_and_its_content(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let transaction = database::Transactions::mock().insert(&pool).await?;
+        let attachment =
+            database::Attachments::new(transaction.id, "invoice.pdf", "application/pdf", PDF)
+                .insert(PDF, &pool)
+                .await?;
+
+        let found =
+            database::Attachments::find_by_id(attachment.id, database::UserScope::All, &pool)
+                .await?;
+        let listed = database::Attachments::find_by_transaction(
+            transaction.id,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        let content =
+            database::Attachments::find_content(attachment.id, database::UserScope::All, &pool)
+                .await?;
+        let missing = database::Attachments::find_content(
+            domain::RowID::new(),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(found.as_ref(), Some(&attachment));
+        assert_eq!(listed, [attachment]);
+        assert_eq!(content.as_deref(), Some(PDF));
+        assert!(missing.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn attachments_are_limited_to_the_scope(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let owner = database::Users::mock().insert(&pool).await?;
+        let other = database::Users::mock().insert(&pool).await?;
+        let mut transaction = database::Transactions::mock();
+        transaction.user_id = Some(owner.id);
+        let transaction = transaction.insert(&pool).await?;
+        let attachment =
+            database::Attachments::new(transaction.id, "invoice.pdf", "application/pdf", PDF)
+                .insert(PDF, &pool)
+                .await?;
+        let (as_owner, as_other) = (
+            database::UserScope::User(owner.id),
+            database::UserScope::User(other.id),
+        );
+
+        let found = database::Attachments::find_by_id(attachment.id, as_owner, &pool).await?;
+        let hidden = database::Attachments::find_by_id(attachment.id, as_other, &pool).await?;
+        let listed =
+            database::Attachments::find_by_transaction(transaction.id, as_other, &pool).await?;
+        let content = database::Attachments::find_content(attachment.id, as_other, &pool).await?;
+        let deleted = database::Attachments::delete_by_id(attachment.id, as_other, &pool).await;
+
+        assert_eq!(found, Some(attachment));
+        assert!(hidden.is_none());
+        assert!(listed.is_empty());
+        assert!(content.is_none());
+        assert!(matches!(deleted, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
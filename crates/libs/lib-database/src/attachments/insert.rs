@@ -0,0 +1,179 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+
+impl database::Attachments {
+    /// Stores the attachment with its content.
+    ///
+    /// The content is stored once per hash, so content already stored for
+    /// another attachment is shared with it rather than written again.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The file's bytes, as passed to [`Attachments::new`](database::Attachments::new)
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored attachment, or a `DatabaseError` if it could not be
+    /// stored.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The attachment or its content is invalid (`DatabaseError::Validation`)
+    /// - The transaction does not exist (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Attachments;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, transaction_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let content = std::fs::read("invoice.pdf")?;
+    ///
+    /// let attachment = Attachments::new(transaction_id, "invoice.pdf", "application/pdf", &content)
+    ///     .insert(&content, pool)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert new attachment into database",
+        skip(self, content, conn),
+        fields(id = %self.id, transaction_id = %self.transaction_id, size_bytes = self.size_bytes),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        content: &'a [u8],
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate(content)?;
+            if self.size_bytes != content.len() as i64 {
+                return Err(DatabaseError::Validation(format!(
+                    "Attachment is {} bytes, not the {} bytes it was created for",
+                    content.len(),
+                    self.size_bytes
+                )));
+            }
+
+            let mut tx = conn.begin().await?;
+
+            let transaction_exists = sqlx::query_scalar!(
+                r#"
+                    SELECT EXISTS (SELECT 1 FROM transactions WHERE id = ?) AS "exists!: bool"
+                "#,
+                self.transaction_id
+            )
+            .fetch_one(&mut *tx)
+            .observe_one("attachments.insert")
+            .await?;
+            if !transaction_exists {
+                return Err(DatabaseError::NotFound(format!(
+                    "Transaction with id {} not found",
+                    self.transaction_id
+                )));
+            }
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO attachment_contents (sha256, content)
+                    VALUES (?, ?)
+                    ON CONFLICT (sha256) DO NOTHING
+                "#,
+                self.sha256,
+                content
+            )
+            .execute(&mut *tx)
+            .observe("attachments.insert")
+            .await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO attachments (id, transaction_id, file_name, content_type, size_bytes, sha256, created_on)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.transaction_id,
+                self.file_name,
+                self.content_type,
+                self.size_bytes,
+                self.sha256,
+                self.created_on
+            )
+            .execute(&mut *tx)
+            .observe("attachments.insert")
+            .await?;
+
+            tx.commit().await?;
+
+            tracing::info!("New attachment inserted into the database.");
+
+            Ok(self.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    pub const PDF: &[u8] = b"%PDF-1.7 tax invoice";
+
+    #[sqlx::test]
+    async fn insert_shares_content_with_the_same_hash(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let transaction = database::Transactions::mock().insert(&pool).await?;
+
+        let first =
+            database::Attachments::new(transaction.id, "invoice.pdf", "application/pdf", PDF)
+                .insert(PDF, &pool)
+                .await?;
+        let second = database::Attachments::new(transaction.id, "copy.pdf", "application/pdf", PDF)
+            .insert(PDF, &pool)
+            .await?;
+
+        let contents: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM attachment_contents")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(first.sha256, second.sha256);
+        assert_eq!(contents, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_rejects_unknown_transaction_and_mismatched_content(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let transaction = database::Transactions::mock().insert(&pool).await?;
+        let unknown = database::Attachments::new(
+            lib_domain::RowID::new(),
+            "invoice.pdf",
+            "application/pdf",
+            PDF,
+        );
+        let attachment =
+            database::Attachments::new(transaction.id, "invoice.pdf", "application/pdf", PDF);
+
+        let unknown = unknown.insert(PDF, &pool).await;
+        let mismatched = attachment.insert(b"%PDF-1.7", &pool).await;
+
+        assert!(matches!(unknown, Err(DatabaseError::NotFound(_))));
+        assert!(matches!(mismatched, Err(DatabaseError::Validation(_))));
+
+        Ok(())
+    }
+}
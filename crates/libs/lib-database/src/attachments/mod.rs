@@ -0,0 +1,23 @@
+//! # Attachments Database Module
+//!
+//! Stores receipts and invoices, as images or PDFs, attached to transactions.
+//! Content is stored in the database as a blob, once per SHA-256 hash, so the
+//! same file attached twice takes its space once, and is removed when the
+//! last attachment referring to it goes. Attachments are removed with their
+//! transaction.
+//!
+//! Files are checked before they are stored: they must be no larger than
+//! [`MAX_ATTACHMENT_BYTES`], of one of the [`ATTACHMENT_CONTENT_TYPES`], and
+//! start the way files of that type do, so a renamed executable is not kept
+//! as a "PDF".
+
+mod delete;
+mod find;
+mod insert;
+mod model;
+
+/// Database row model representing an attachment, without its content.
+pub use model::Attachments;
+
+/// The largest attachment and the content types that may be stored.
+pub use model::{ATTACHMENT_CONTENT_TYPES, MAX_ATTACHMENT_BYTES};
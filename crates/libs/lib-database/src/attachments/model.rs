@@ -0,0 +1,193 @@
+use sha2::Digest;
+
+use crate::{DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// The largest attachment that may be stored, 10 MiB.
+pub const MAX_ATTACHMENT_BYTES: usize = 10 * 1024 * 1024;
+
+/// The content types attachments may have: photos of receipts and PDF
+/// invoices.
+pub const ATTACHMENT_CONTENT_TYPES: &[&str] = &[
+    "application/pdf",
+    "image/jpeg",
+    "image/png",
+    "image/webp",
+    "image/heic",
+];
+
+/// A file attached to a transaction. The content is read on its own, with
+/// [`Attachments::find_content`].
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct Attachments {
+    pub id: domain::RowID,
+    pub transaction_id: domain::RowID,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+
+    /// Lowercase hex SHA-256 hash of the content.
+    pub sha256: String,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl Attachments {
+    /// Creates a new, unsaved attachment for `content`, computing its size and
+    /// hash. The file name is trimmed and the content type lowercased.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::Attachments;
+    /// use lib_domain::RowID;
+    ///
+    /// let content = b"%PDF-1.7 tax invoice";
+    /// let attachment = Attachments::new(RowID::new(), " invoice.pdf ", "application/PDF", content);
+    ///
+    /// assert_eq!(attachment.file_name, "invoice.pdf");
+    /// assert_eq!(attachment.size_bytes, 20);
+    /// assert!(attachment.validate(content).is_ok());
+    /// ```
+    pub fn new(
+        transaction_id: domain::RowID,
+        file_name: impl Into<String>,
+        content_type: impl Into<String>,
+        content: &[u8],
+    ) -> Self {
+        let digest = sha2::Sha256::digest(content);
+
+        Self {
+            id: domain::RowID::new(),
+            transaction_id,
+            file_name: file_name.into().trim().to_string(),
+            content_type: content_type.into().trim().to_lowercase(),
+            size_bytes: content.len() as i64,
+            sha256: digest.iter().map(|byte| format!("{byte:02x}")).collect(),
+            created_on: chrono::Utc::now(),
+        }
+    }
+
+    /// Checks the attachment and its content before they are written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the file name is blank, the
+    /// content is empty or larger than [`MAX_ATTACHMENT_BYTES`], the content
+    /// type is not one of [`ATTACHMENT_CONTENT_TYPES`], or the content does
+    /// not start the way files of that type do.
+    pub fn validate(&self, content: &[u8]) -> DatabaseResult<()> {
+        if self.file_name.is_empty() {
+            return Err(DatabaseError::Validation(
+                "Attachment file name cannot be empty".to_string(),
+            ));
+        }
+
+        if content.is_empty() {
+            return Err(DatabaseError::Validation(
+                "Attachment cannot be empty".to_string(),
+            ));
+        }
+
+        if content.len() > MAX_ATTACHMENT_BYTES {
+            return Err(DatabaseError::Validation(format!(
+                "Attachment is {} bytes, larger than the {MAX_ATTACHMENT_BYTES} bytes allowed",
+                content.len()
+            )));
+        }
+
+        if !ATTACHMENT_CONTENT_TYPES.contains(&self.content_type.as_str()) {
+            return Err(DatabaseError::Validation(format!(
+                "Attachment content type '{}' is not one of {}",
+                self.content_type,
+                ATTACHMENT_CONTENT_TYPES.join(", ")
+            )));
+        }
+
+        if !Self::has_signature(&self.content_type, content) {
+            return Err(DatabaseError::Validation(format!(
+                "Attachment '{}' is not a {} file",
+                self.file_name, self.content_type
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Whether `content` starts with the magic bytes of `content_type`.
+    fn has_signature(content_type: &str, content: &[u8]) -> bool {
+        match content_type {
+            "application/pdf" => content.starts_with(b"%PDF-"),
+            "image/jpeg" => content.starts_with(&[0xFF, 0xD8, 0xFF]),
+            "image/png" => content.starts_with(b"\x89PNG\r\n\x1a\n"),
+            "image/webp" => {
+                content.len() >= 12 && content.starts_with(b"RIFF") && &content[8..12] == b"WEBP"
+            }
+            "image/heic" => {
+                content.len() >= 12
+                    && &content[4..8] == b"ftyp"
+                    && matches!(&content[8..12], b"heic" | b"heix" | b"mif1" | b"msf1")
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(content_type: &str, content: &[u8]) -> Attachments {
+        Attachments::new(domain::RowID::new(), "receipt", content_type, content)
+    }
+
+    #[test]
+    fn new_hashes_the_content() {
+        let first = attachment("application/pdf", b"%PDF-1.4");
+        let second = attachment("application/pdf", b"%PDF-1.4");
+
+        assert_eq!(first.sha256.len(), 64);
+        assert_eq!(first.sha256, second.sha256);
+    }
+
+    #[test]
+    fn validate_checks_the_content_matches_its_type() {
+        let png = b"\x89PNG\r\n\x1a\n....";
+        let webp = b"RIFF\x00\x00\x00\x00WEBPVP8 ";
+
+        assert!(attachment("image/png", png).validate(png).is_ok());
+        assert!(attachment("image/webp", webp).validate(webp).is_ok());
+        assert!(
+            attachment("image/jpeg", &[0xFF, 0xD8, 0xFF, 0xE0])
+                .validate(&[0xFF, 0xD8, 0xFF, 0xE0])
+                .is_ok()
+        );
+        assert!(matches!(
+            attachment("image/jpeg", png).validate(png),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            attachment("text/html", b"<html>").validate(b"<html>"),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_empty_and_oversized_content() {
+        let mut oversized = b"%PDF-".to_vec();
+        oversized.resize(MAX_ATTACHMENT_BYTES + 1, b' ');
+        let unnamed = Attachments::new(domain::RowID::new(), " ", "application/pdf", b"%PDF-1.4");
+
+        assert!(matches!(
+            attachment("application/pdf", b"").validate(b""),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            attachment("application/pdf", &oversized).validate(&oversized),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            unnamed.validate(b"%PDF-1.4"),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+}
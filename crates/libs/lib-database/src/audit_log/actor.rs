@@ -0,0 +1,76 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+tokio::task_local! {
+    /// The actor set by [`AuditLog::with_actor`](database::AuditLog::with_actor)
+    /// for the task being run.
+    static ACTOR: String;
+}
+
+impl database::AuditLog {
+    /// Runs `future` with `actor` recorded against every change it makes
+    /// through [`with_transaction`](crate::with_transaction).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{AuditLog, Categories, EventBus, UserScope, with_transaction};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, bus: &EventBus, category: Categories) -> Result<(), Box<dyn std::error::Error>> {
+    /// AuditLog::with_actor("ian", async {
+    ///     with_transaction(pool, bus, false, async |conn| category.insert(conn).await).await
+    /// })
+    /// .await?;
+    ///
+    /// let id = category.id.to_string();
+    /// let history = AuditLog::find_by_entity("categories", &id, UserScope::All, pool).await?;
+    /// assert_eq!(history[0].actor.as_deref(), Some("ian"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_actor<F: Future>(actor: impl Into<String>, future: F) -> F::Output {
+        ACTOR.scope(actor.into(), future).await
+    }
+
+    /// Returns the actor set for the current task, if any.
+    pub fn current_actor() -> Option<String> {
+        ACTOR.try_with(Clone::clone).ok()
+    }
+}
+
+/// Records the current task's actor for the audit triggers, at the start of
+/// a database transaction.
+pub(crate) async fn begin_actor(conn: &mut sqlx::SqliteConnection) -> DatabaseResult<()> {
+    let Some(actor) = database::AuditLog::current_actor() else {
+        return Ok(());
+    };
+
+    sqlx::query!(
+        r#"
+            INSERT INTO audit_actor (id, actor)
+            VALUES (1, ?)
+            ON CONFLICT (id) DO UPDATE SET actor = excluded.actor
+        "#,
+        actor
+    )
+    .execute(&mut *conn)
+    .observe("audit_log.begin_actor")
+    .await?;
+
+    Ok(())
+}
+
+/// Clears the actor before a database transaction commits, so it is never
+/// recorded against changes made by the next one.
+pub(crate) async fn end_actor(conn: &mut sqlx::SqliteConnection) -> DatabaseResult<()> {
+    if database::AuditLog::current_actor().is_none() {
+        return Ok(());
+    }
+
+    sqlx::query!("DELETE FROM audit_actor")
+        .execute(&mut *conn)
+        .observe("audit_log.end_actor")
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,301 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::AuditLog {
+    /// Retrieves the audit history of a single row, newest first.
+    ///
+    /// A row is in a user's scope when its snapshots name the user as its
+    /// owner, or, for rows without an owner of their own such as budgets,
+    /// refer to a category, account or transaction the user owns.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The audited table, one of [`AuditLog::ENTITIES`](database::AuditLog::ENTITIES)
+    /// * `entity_id` - The primary key of the row
+    /// * `scope` - The user whose rows' history is searched
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns every change recorded for the row, including its deletion, or
+    /// an empty list if none were recorded or the row is outside `scope`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The entity is not audited (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{AuditLog, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, id: lib_domain::RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let id = id.to_string();
+    /// for entry in AuditLog::find_by_entity("transactions", &id, UserScope::All, pool).await? {
+    ///     println!("{:?} by {:?} on {}", entry.action, entry.actor, entry.changed_on);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find audit log of entity", skip(conn), err)]
+    pub fn find_by_entity<'a, 'c, A>(
+        entity: &'a str,
+        entity_id: &'a str,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            if !Self::is_audited(entity) {
+                return Err(database::DatabaseError::Validation(format!(
+                    "Changes to '{}' are not audited",
+                    entity
+                )));
+            }
+
+            let mut conn = conn.acquire().await?;
+
+            if let Some(user_id) = scope.user_id() {
+                let is_owned = sqlx::query_scalar!(
+                    r#"
+                        SELECT EXISTS (
+                            SELECT 1
+                            FROM audit_log,
+                                json_each(COALESCE(audit_log.new_values, audit_log.old_values)) AS snapshot
+                            WHERE audit_log.entity = ?1 AND audit_log.entity_id = ?2
+                                AND CASE
+                                    WHEN json_type(COALESCE(audit_log.new_values, audit_log.old_values), '$.user_id') IS NOT NULL
+                                        THEN snapshot.key = 'user_id' AND snapshot.value = ?3
+                                    WHEN snapshot.key = 'category_id'
+                                        THEN snapshot.value IN (SELECT id FROM categories WHERE user_id = ?3)
+                                    WHEN snapshot.key = 'account_id'
+                                        THEN snapshot.value IN (SELECT id FROM accounts WHERE user_id = ?3)
+                                    WHEN snapshot.key = 'transaction_id'
+                                        THEN snapshot.value IN (SELECT id FROM transactions WHERE user_id = ?3)
+                                    ELSE FALSE
+                                END
+                        ) AS "is_owned!: bool"
+                    "#,
+                    entity,
+                    entity_id,
+                    user_id
+                )
+                .fetch_one(&mut *conn)
+                .observe_one("audit_log.find_by_entity")
+                .await?;
+
+                if !is_owned {
+                    return Ok(Vec::new());
+                }
+            }
+
+            let entries = sqlx::query_as!(
+                database::AuditLog,
+                r#"
+                    SELECT
+                        id              AS "id!: i64",
+                        entity,
+                        entity_id,
+                        action          AS "action!: database::AuditActions",
+                        actor,
+                        old_values,
+                        new_values,
+                        changed_on      AS "changed_on!: chrono::DateTime<chrono::Utc>"
+                    FROM audit_log
+                    WHERE entity = ? AND entity_id = ?
+                    ORDER BY id DESC
+                "#,
+                entity,
+                entity_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("audit_log.find_by_entity")
+            .await?;
+
+            Ok(entries)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::SqlitePool;
+
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    async fn category_history(
+        category: &database::Categories,
+        pool: &SqlitePool,
+    ) -> Result<Vec<database::AuditLog>> {
+        Ok(database::AuditLog::find_by_entity(
+            "categories",
+            &category.id.to_string(),
+            database::UserScope::All,
+            pool,
+        )
+        .await?)
+    }
+
+    #[sqlx::test]
+    async fn records_insert_update_and_delete(pool: SqlitePool) -> Result<()> {
+        let mut category = database::Categories::mock();
+        category.name = "Groceries".to_string();
+        let category = category.insert(&pool).await?;
+
+        let mut renamed = category.clone();
+        renamed.name = "Food".to_string();
+        renamed.update(database::UserScope::All, &pool).await?;
+        database::Categories::delete_by_id(category.id, database::UserScope::All, &pool).await?;
+
+        let history = category_history(&category, &pool).await?;
+        let actions: Vec<_> = history.iter().map(|entry| entry.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                database::AuditActions::Delete,
+                database::AuditActions::Update,
+                database::AuditActions::Insert
+            ]
+        );
+
+        let old: serde_json::Value =
+            serde_json::from_str(history[1].old_values.as_deref().unwrap())?;
+        let new: serde_json::Value =
+            serde_json::from_str(history[1].new_values.as_deref().unwrap())?;
+        assert_eq!(old["name"], "Groceries");
+        assert_eq!(new["name"], "Food");
+        assert_eq!(new["id"], category.id.to_string());
+
+        assert!(history[0].new_values.is_none());
+        assert!(history[2].old_values.is_none());
+        assert!(history.iter().all(|entry| entry.actor.is_none()));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn records_actor_of_committed_changes_only(pool: SqlitePool) -> Result<()> {
+        let category = database::Categories::mock();
+        let discarded = database::Categories::mock();
+
+        database::AuditLog::with_actor("ian", async {
+            database::with_transaction(&pool, &database::EventBus::new(), false, async |conn| {
+                category.insert(conn).await
+            })
+            .await?;
+            database::with_transaction(&pool, &database::EventBus::new(), true, async |conn| {
+                discarded.insert(conn).await
+            })
+            .await
+        })
+        .await?;
+
+        let history = category_history(&category, &pool).await?;
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].actor.as_deref(), Some("ian"));
+        assert!(category_history(&discarded, &pool).await?.is_empty());
+
+        // The actor does not outlive its transaction
+        let actors: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM audit_actor")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(actors, 0);
+        database::Categories::delete_by_id(category.id, database::UserScope::All, &pool).await?;
+        assert_eq!(category_history(&category, &pool).await?[0].actor, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn history_is_limited_to_the_scope(pool: SqlitePool) -> Result<()> {
+        let owner = database::Users::mock().insert(&pool).await?;
+        let other = database::Users::mock().insert(&pool).await?;
+        let mut category = database::Categories::mock();
+        category.user_id = Some(owner.id);
+        let category = category.insert(&pool).await?;
+        let budget = database::Budgets::new(category.id, database::BudgetPeriods::Monthly, 50_000)
+            .insert(&pool)
+            .await?;
+        let history = async |entity, id: lib_domain::RowID, scope| {
+            database::AuditLog::find_by_entity(entity, &id.to_string(), scope, &pool).await
+        };
+
+        let owned = history(
+            "categories",
+            category.id,
+            database::UserScope::User(owner.id),
+        )
+        .await?;
+        let not_owned = history(
+            "categories",
+            category.id,
+            database::UserScope::User(other.id),
+        )
+        .await?;
+        assert_eq!(owned.len(), 1);
+        assert!(not_owned.is_empty());
+
+        // A budget belongs to the owner of its category
+        let owned = history("budgets", budget.id, database::UserScope::User(owner.id)).await?;
+        let not_owned = history("budgets", budget.id, database::UserScope::User(other.id)).await?;
+        assert_eq!(owned.len(), 1);
+        assert!(not_owned.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn rejects_entities_that_are_not_audited(pool: SqlitePool) -> Result<()> {
+        let result =
+            database::AuditLog::find_by_entity("audit_log", "1", database::UserScope::All, &pool)
+                .await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        Ok(())
+    }
+
+    /// Columns added to an audited table must be added to its triggers too.
+    #[sqlx::test]
+    async fn triggers_snapshot_every_column(pool: SqlitePool) -> Result<()> {
+        for entity in database::AuditLog::ENTITIES {
+            let columns: Vec<String> =
+                sqlx::query_scalar("SELECT name FROM pragma_table_info(?) WHERE type <> 'BLOB'")
+                    .bind(entity)
+                    .fetch_all(&pool)
+                    .await?;
+            assert!(!columns.is_empty(), "{entity} is not a table");
+
+            for action in ["insert", "update", "delete"] {
+                let trigger: Option<String> = sqlx::query_scalar(
+                    "SELECT sql FROM sqlite_master WHERE type = 'trigger' AND name = ?",
+                )
+                .bind(format!("{entity}_audit_{action}"))
+                .fetch_optional(&pool)
+                .await?;
+                let trigger =
+                    trigger.unwrap_or_else(|| panic!("{entity} has no {action} audit trigger"));
+                let row = if action == "delete" { "OLD" } else { "NEW" };
+
+                for column in &columns {
+                    assert!(
+                        trigger.contains(&format!("'{column}', {row}.{column}")),
+                        "{entity}_audit_{action} does not snapshot {column}"
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+//! # Audit Log Database Module
+//!
+//! Records every insert, update and delete made to the ledger, with the row
+//! before and after the change as JSON and the actor who made it.
+//!
+//! Entries are written by database triggers, so they are committed or rolled
+//! back with the change they record and no write can skip them. The actor is
+//! set for a task with [`AuditLog::with_actor`], and recorded against every
+//! change that task makes through [`with_transaction`](crate::with_transaction).
+//! Changes made any other way are logged without an actor.
+
+mod actor;
+mod find;
+mod model;
+
+/// Database row model for a single audit log entry.
+pub use model::AuditLog;
+
+/// Whether an audit log entry records an insert, update or delete.
+pub use model::AuditActions;
+
+pub(crate) use actor::{begin_actor, end_actor};
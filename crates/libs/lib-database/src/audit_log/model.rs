@@ -0,0 +1,61 @@
+/// Whether an audit log entry records an insert, update or delete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, serde::Deserialize, serde::Serialize)]
+#[sqlx(rename_all = "lowercase")]
+pub enum AuditActions {
+    /// The row was inserted, only `new_values` is set.
+    Insert,
+    /// The row was updated, both `old_values` and `new_values` are set.
+    Update,
+    /// The row was deleted, only `old_values` is set.
+    Delete,
+}
+
+/// A single change to a ledger row, as recorded in the audit log.
+///
+/// `entity` is the table that was changed and `entity_id` the primary key of
+/// the row, e.g. `"categories"` and the category ID. The snapshots are JSON
+/// objects of the row's columns, as stored in the database.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct AuditLog {
+    pub id: i64,
+    pub entity: String,
+    pub entity_id: String,
+    pub action: AuditActions,
+    pub actor: Option<String>,
+    pub old_values: Option<String>,
+    pub new_values: Option<String>,
+    pub changed_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl AuditLog {
+    /// The tables whose changes are recorded in the audit log.
+    pub const ENTITIES: &'static [&'static str] = &[
+        "accounts",
+        "asset_prices",
+        "balance_snapshots",
+        "budgets",
+        "categories",
+        "crypto_holdings",
+        "exchange_rates",
+        "goals",
+        "import_batches",
+        "journal_entries",
+        "journal_splits",
+        "merchants",
+        "mileage_logs",
+        "payee_aliases",
+        "period_closings",
+        "receipt_attachments",
+        "receipt_emails",
+        "recurring_transactions",
+        "reimbursables",
+        "transaction_imports",
+        "transactions",
+        "users",
+    ];
+
+    /// Whether changes to `entity` are recorded in the audit log.
+    pub fn is_audited(entity: &str) -> bool {
+        Self::ENTITIES.contains(&entity)
+    }
+}
@@ -0,0 +1,484 @@
+//! # Backup Module
+//!
+//! Copies a live database to a backup file, and restores a database from one,
+//! without stopping the server.
+//!
+//! A backup is written with SQLite's `VACUUM INTO`, which reads the database
+//! in a single read transaction. The backup is a consistent snapshot even
+//! while other connections keep writing, and is a plain, compacted SQLite
+//! file that can be opened or copied like any other.
+//!
+//! A restore replaces every row in the database with the rows in the backup,
+//! in one transaction, so other connections see either the old data or the
+//! restored data and never a mix. The backup must be at the same migration
+//! version as the database, as the rows are copied table by table. Triggers
+//! are dropped while the rows are copied and then recreated, so the restore
+//! is not itself written to the audit log or the history tables.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_database::DatabaseBackup;
+//!
+//! # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+//! let backup = DatabaseBackup::backup_to(pool, "backups/ledger-2025-07-01.db".into()).await?;
+//! println!("Backed up {} bytes to {}", backup.size_bytes, backup.path.display());
+//!
+//! DatabaseBackup::restore_from(pool, backup.path).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::query_metrics::Observe;
+use std::path::{Path, PathBuf};
+
+use crate::{DatabaseError, DatabasePool, DatabaseResult};
+
+/// Schema name the backup file is attached as while it is restored.
+const BACKUP_SCHEMA: &str = "backup";
+
+/// A backup file written by [`DatabaseBackup::backup_to`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct DatabaseBackup {
+    /// Where the backup was written.
+    pub path: PathBuf,
+
+    /// Size of the backup file in bytes.
+    pub size_bytes: u64,
+
+    /// The newest migration applied to the backed up database, `0` when none
+    /// have been applied.
+    pub migration_version: i64,
+
+    /// When the backup was taken.
+    pub created_on: chrono::DateTime<chrono::Utc>,
+}
+
+/// Quotes a table or trigger name for use in a statement.
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// The path as a SQLite URI opened in `mode`. Files named in `VACUUM INTO`
+/// and `ATTACH` are opened with the flags of the main database, which would
+/// make them in-memory when it is, unless the mode is given.
+fn sqlite_uri(path: &Path, mode: &str) -> DatabaseResult<String> {
+    let path = path.to_str().ok_or_else(|| {
+        DatabaseError::Validation(format!("Backup path {} is not valid UTF-8", path.display()))
+    })?;
+    let path = path
+        .replace('%', "%25")
+        .replace('?', "%3f")
+        .replace('#', "%23");
+
+    Ok(format!("file:{path}?mode={mode}"))
+}
+
+/// The successfully applied migration versions in `schema`, in order.
+async fn applied_versions(
+    conn: &mut sqlx::SqliteConnection,
+    schema: &str,
+) -> DatabaseResult<Vec<i64>> {
+    let has_table: bool = sqlx::query_scalar(&format!(
+        "SELECT EXISTS (SELECT 1 FROM {schema}.sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations')"
+    ))
+    .fetch_one(&mut *conn)
+    .observe_one("backup.applied_versions")
+    .await?;
+
+    if !has_table {
+        return Ok(Vec::new());
+    }
+
+    let versions = sqlx::query_scalar(&format!(
+        "SELECT version FROM {schema}._sqlx_migrations WHERE success = TRUE ORDER BY version"
+    ))
+    .fetch_all(&mut *conn)
+    .observe("backup.applied_versions")
+    .await?;
+
+    Ok(versions)
+}
+
+impl DatabaseBackup {
+    /// Writes a consistent snapshot of the database to a new file at `path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool or connection to back up. The backup
+    ///   cannot be taken inside a transaction.
+    /// * `path` - The file to write, which must not already exist
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the file already exists, or a
+    /// `DatabaseError` if the backup cannot be written.
+    #[tracing::instrument(name = "Back up database", skip(conn, path), fields(path = %path.display()), err)]
+    pub fn backup_to<'c, A>(
+        conn: A,
+        path: PathBuf,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            if path.exists() {
+                return Err(DatabaseError::Validation(format!(
+                    "Backup file {} already exists",
+                    path.display()
+                )));
+            }
+
+            let mut conn = conn.acquire().await?;
+
+            let migration_version = applied_versions(&mut conn, "main")
+                .await?
+                .last()
+                .copied()
+                .unwrap_or_default();
+
+            sqlx::query("VACUUM INTO ?")
+                .bind(sqlite_uri(&path, "rwc")?)
+                .execute(&mut *conn)
+                .observe("backup.backup_to")
+                .await?;
+
+            let size_bytes = tokio::fs::metadata(&path)
+                .await
+                .map_err(|e| DatabaseError::Other(format!("Cannot read backup file: {e}")))?
+                .len();
+
+            tracing::info!(size_bytes, migration_version, "Database backed up");
+
+            Ok(Self {
+                path,
+                size_bytes,
+                migration_version,
+                created_on: chrono::Utc::now(),
+            })
+        }
+    }
+
+    /// Replaces every row in the database with the rows in the backup at
+    /// `path`, in a single transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool or connection to restore into. The
+    ///   restore cannot run inside a transaction.
+    /// * `path` - A backup written by [`backup_to`](Self::backup_to)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::NotFound`] if there is no file at `path`,
+    /// [`DatabaseError::Validation`] if the backup is at a different
+    /// migration version than the database, or a `DatabaseError` if the
+    /// backup cannot be read. On error the database is left unchanged.
+    #[tracing::instrument(name = "Restore database", skip(conn, path), fields(path = %path.display()), err)]
+    pub fn restore_from<'c, A>(
+        conn: A,
+        path: PathBuf,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            // Attaching a missing file would create an empty database
+            if !path.is_file() {
+                return Err(DatabaseError::NotFound(format!(
+                    "Backup file {}",
+                    path.display()
+                )));
+            }
+
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query(&format!("ATTACH DATABASE ? AS {BACKUP_SCHEMA}"))
+                .bind(sqlite_uri(&path, "ro")?)
+                .execute(&mut *conn)
+                .observe("backup.restore_from")
+                .await?;
+
+            let restored = Self::copy_backup(&mut conn).await;
+
+            // Detach whatever happened, the connection goes back to the pool
+            sqlx::query(&format!("DETACH DATABASE {BACKUP_SCHEMA}"))
+                .execute(&mut *conn)
+                .observe("backup.restore_from")
+                .await?;
+
+            restored
+        }
+    }
+
+    /// Copies every row from the attached backup into the main database.
+    async fn copy_backup(conn: &mut sqlx::SqliteConnection) -> DatabaseResult<()> {
+        let database_versions = applied_versions(conn, "main").await?;
+        let backup_versions = applied_versions(conn, BACKUP_SCHEMA).await?;
+
+        if database_versions != backup_versions {
+            return Err(DatabaseError::Validation(format!(
+                "Backup is at migration version {}, the database is at {}",
+                backup_versions.last().copied().unwrap_or_default(),
+                database_versions.last().copied().unwrap_or_default()
+            )));
+        }
+
+        // Shadow tables belong to their virtual table and are filled by it,
+        // and the migrations table was checked above
+        let tables: Vec<(String, String)> = sqlx::query_as(
+            r#"
+                SELECT name, type
+                FROM pragma_table_list
+                WHERE schema = 'main'
+                    AND type IN ('table', 'virtual')
+                    AND name NOT LIKE 'sqlite_%'
+                    AND name != '_sqlx_migrations'
+                ORDER BY name
+            "#,
+        )
+        .fetch_all(&mut *conn)
+        .observe("backup.copy_backup")
+        .await?;
+
+        let triggers: Vec<(String, String)> = sqlx::query_as(
+            "SELECT name, sql FROM main.sqlite_master WHERE type = 'trigger' ORDER BY name",
+        )
+        .fetch_all(&mut *conn)
+        .observe("backup.copy_backup")
+        .await?;
+
+        let mut tx = sqlx::Connection::begin(&mut *conn).await?;
+
+        // Rows are copied in name order, so references are checked at commit
+        sqlx::query("PRAGMA defer_foreign_keys = ON")
+            .execute(&mut *tx)
+            .observe("backup.copy_backup")
+            .await?;
+
+        for (name, _) in &triggers {
+            sqlx::query(&format!("DROP TRIGGER main.{}", quote(name)))
+                .execute(&mut *tx)
+                .observe("backup.copy_backup")
+                .await?;
+        }
+
+        for (name, kind) in &tables {
+            let columns: Vec<String> =
+                sqlx::query_scalar("SELECT name FROM pragma_table_info(?, 'main') ORDER BY cid")
+                    .bind(name)
+                    .fetch_all(&mut *tx)
+                    .observe("backup.copy_backup")
+                    .await?;
+
+            let mut columns: Vec<String> = columns.iter().map(|column| quote(column)).collect();
+            // Full text search rows are matched to their source rows by rowid
+            if kind == "virtual" {
+                columns.insert(0, "rowid".to_string());
+            }
+            let columns = columns.join(", ");
+            let table = quote(name);
+
+            sqlx::query(&format!("DELETE FROM main.{table}"))
+                .execute(&mut *tx)
+                .observe("backup.copy_backup")
+                .await?;
+            sqlx::query(&format!(
+                "INSERT INTO main.{table} ({columns}) SELECT {columns} FROM {BACKUP_SCHEMA}.{table}"
+            ))
+            .execute(&mut *tx)
+            .observe("backup.copy_backup")
+            .await?;
+        }
+
+        // Carry the autoincrement counters over, so deleted ids are not reused
+        let has_sequence: bool = sqlx::query_scalar(&format!(
+            "SELECT EXISTS (SELECT 1 FROM {BACKUP_SCHEMA}.sqlite_master WHERE name = 'sqlite_sequence')"
+        ))
+        .fetch_one(&mut *tx)
+        .observe_one("backup.copy_backup")
+        .await?;
+        if has_sequence {
+            sqlx::query("DELETE FROM main.sqlite_sequence")
+                .execute(&mut *tx)
+                .observe("backup.copy_backup")
+                .await?;
+            sqlx::query(&format!(
+                "INSERT INTO main.sqlite_sequence (name, seq) SELECT name, seq FROM {BACKUP_SCHEMA}.sqlite_sequence"
+            ))
+            .execute(&mut *tx)
+            .observe("backup.copy_backup")
+            .await?;
+        }
+
+        for (_, sql) in &triggers {
+            sqlx::query(sql)
+                .execute(&mut *tx)
+                .observe("backup.copy_backup")
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        tracing::info!(tables = tables.len(), "Database restored from backup");
+
+        Ok(())
+    }
+}
+
+impl DatabasePool {
+    /// Writes a consistent snapshot of the database to a new file at `path`.
+    ///
+    /// See [`DatabaseBackup::backup_to`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Connection`] if the pool is not connected, or
+    /// an error from [`DatabaseBackup::backup_to`].
+    pub async fn backup_to(&self, path: impl AsRef<Path>) -> DatabaseResult<DatabaseBackup> {
+        DatabaseBackup::backup_to(self.get_pool()?, path.as_ref().to_path_buf()).await
+    }
+
+    /// Replaces every row in the database with the rows in a backup.
+    ///
+    /// See [`DatabaseBackup::restore_from`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Connection`] if the pool is not connected, or
+    /// an error from [`DatabaseBackup::restore_from`].
+    pub async fn restore_from(&self, path: impl AsRef<Path>) -> DatabaseResult<()> {
+        DatabaseBackup::restore_from(self.get_pool()?, path.as_ref().to_path_buf()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    /// A migrated in-memory database.
+    async fn database() -> Result<DatabasePool> {
+        let db = DatabasePool::new("sqlite::memory:").connect().await?;
+        db.migrate().await?;
+        Ok(db)
+    }
+
+    /// A path in a new temporary directory, removed by the caller.
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ledger-backup-{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    async fn insert_category(pool: &sqlx::SqlitePool, code: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO categories (id, code, name, category_type, is_active, created_on, updated_on)
+                VALUES (?, ?, ?, 'expense', TRUE, datetime('now'), datetime('now'))
+            "#,
+        )
+            .bind(uuid::Uuid::now_v7().to_string())
+            .bind(code)
+            .bind(format!("Category {code}"))
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn category_codes(pool: &sqlx::SqlitePool) -> Result<Vec<String>> {
+        Ok(
+            sqlx::query_scalar("SELECT code FROM categories ORDER BY code")
+                .fetch_all(pool)
+                .await?,
+        )
+    }
+
+    #[tokio::test]
+    async fn backup_and_restore_round_trip() -> Result<()> {
+        let db = database().await?;
+        let pool = db.get_pool()?;
+        let path = temp_path("ledger.db");
+
+        insert_category(pool, "FOOD").await?;
+        let backup = db.backup_to(&path).await?;
+
+        assert_eq!(backup.path, path);
+        assert!(backup.size_bytes > 0);
+        assert_eq!(backup.size_bytes, std::fs::metadata(&path)?.len());
+        assert!(backup.migration_version > 0);
+
+        insert_category(pool, "RENT").await?;
+        assert_eq!(category_codes(pool).await?, ["FOOD", "RENT"]);
+
+        db.restore_from(&path).await?;
+        assert_eq!(category_codes(pool).await?, ["FOOD"]);
+
+        // Triggers are back, so search and the audit log keep working
+        insert_category(pool, "TRAVEL").await?;
+        let found: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM categories_fts WHERE categories_fts MATCH 'travel'",
+        )
+        .fetch_one(pool)
+        .await?;
+        assert_eq!(found, 1);
+
+        std::fs::remove_dir_all(path.parent().unwrap())?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn backup_to_existing_file_fails() -> Result<()> {
+        let db = database().await?;
+        let path = temp_path("ledger.db");
+        std::fs::write(&path, b"not a backup")?;
+
+        let result = db.backup_to(&path).await;
+
+        assert!(matches!(result, Err(DatabaseError::Validation(_))));
+        assert_eq!(std::fs::read(&path)?, b"not a backup");
+
+        std::fs::remove_dir_all(path.parent().unwrap())?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn restore_from_missing_file_fails() -> Result<()> {
+        let db = database().await?;
+        let path = temp_path("missing.db");
+
+        let result = db.restore_from(&path).await;
+
+        assert!(matches!(result, Err(DatabaseError::NotFound(_))));
+        assert!(!path.exists());
+
+        std::fs::remove_dir_all(path.parent().unwrap())?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn restore_from_other_migration_version_fails() -> Result<()> {
+        let db = database().await?;
+        let pool = db.get_pool()?;
+        insert_category(pool, "FOOD").await?;
+
+        // A backup of a database that was never migrated
+        let empty = DatabasePool::new("sqlite::memory:").connect().await?;
+        let path = temp_path("empty.db");
+        empty.backup_to(&path).await?;
+
+        let result = db.restore_from(&path).await;
+
+        assert!(matches!(result, Err(DatabaseError::Validation(_))));
+        assert_eq!(category_codes(pool).await?, ["FOOD"]);
+
+        // The backup was detached, so the connection can attach another
+        db.restore_from(&path).await.unwrap_err();
+
+        std::fs::remove_dir_all(path.parent().unwrap())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+/// A balance assertion compared with the balance computed from the ledger
+/// at the end of its day.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct BalanceAssertionCheck {
+    /// The balance asserted.
+    pub assertion: database::BalanceAssertions,
+
+    /// The account's opening balance plus its posted transactions up to and
+    /// including the assertion date.
+    pub computed_balance: i64,
+}
+
+impl BalanceAssertionCheck {
+    /// Returns the computed balance less the asserted balance, positive when
+    /// the ledger holds more than the bank reports. A doubled or missed
+    /// import shows up as the amount of the transaction, or its negative.
+    pub fn difference(&self) -> i64 {
+        self.computed_balance - self.assertion.balance
+    }
+
+    /// Returns whether the ledger agrees with the asserted balance.
+    pub fn holds(&self) -> bool {
+        self.difference() == 0
+    }
+}
+
+impl database::BalanceAssertions {
+    /// Compares every balance assertion, or those of one account, with the
+    /// balance computed from the ledger at the end of its day.
+    ///
+    /// Draft transactions are left out, as they are from balances.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The account whose assertions to check, or `None` for
+    ///   every account
+    /// * `scope` - The user whose accounts are checked
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the checks of the assertions by date, oldest first. The first
+    /// that does not hold for an account is the earliest day its ledger is
+    /// known to be wrong.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{BalanceAssertions, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for check in BalanceAssertions::check(None, UserScope::All, pool).await? {
+    ///     if !check.holds() {
+    ///         println!("{} is out by {}", check.assertion.assertion_date, check.difference());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Check balance assertions", skip(conn), err)]
+    pub fn check<'c, A>(
+        account_id: Option<domain::RowID>,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<BalanceAssertionCheck>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let user_id = scope.user_id();
+            let mut conn = conn.acquire().await?;
+
+            let rows = sqlx::query!(
+                r#"
+                    SELECT
+                        balance_assertions.id               AS "id!: domain::RowID",
+                        balance_assertions.account_id       AS "account_id!: domain::RowID",
+                        balance_assertions.assertion_date   AS "assertion_date!: chrono::NaiveDate",
+                        balance_assertions.balance          AS "balance!: i64",
+                        balance_assertions.note,
+                        balance_assertions.created_on       AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        balance_assertions.updated_on       AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        accounts.opening_balance + COALESCE((
+                            SELECT SUM(transactions.amount)
+                            FROM transactions
+                            WHERE transactions.account_id = accounts.id
+                                AND transactions.is_draft = 0
+                                AND transactions.transaction_date <= balance_assertions.assertion_date
+                        ), 0)                               AS "computed_balance!: i64"
+                    FROM balance_assertions
+                    JOIN accounts ON accounts.id = balance_assertions.account_id
+                    WHERE (?1 IS NULL OR balance_assertions.account_id = ?1)
+                        AND (?2 IS NULL OR accounts.user_id = ?2)
+                    ORDER BY balance_assertions.assertion_date ASC, accounts.name ASC
+                "#,
+                account_id,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("balance_assertions.check")
+            .await?;
+
+            Ok(rows
+                .into_iter()
+                .map(|row| BalanceAssertionCheck {
+                    assertion: database::BalanceAssertions {
+                        id: row.id,
+                        account_id: row.account_id,
+                        assertion_date: row.assertion_date,
+                        balance: row.balance,
+                        note: row.note,
+                        created_on: row.created_on,
+                        updated_on: row.updated_on,
+                    },
+                    computed_balance: row.computed_balance,
+                })
+                .collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance_assertions::insert::tests::{Result, date};
+
+    #[sqlx::test]
+    async fn check_reports_where_the_ledger_diverges(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut everyday = database::Accounts::new("Everyday");
+        everyday.opening_balance = 10_000;
+        let everyday = everyday.insert(&pool).await?;
+        let savings = database::Accounts::new("Savings").insert(&pool).await?;
+        // The 20 March grocery shop was imported twice
+        for (day, amount) in [
+            (date(3, 1), 250_000),
+            (date(3, 20), -15_000),
+            (date(3, 20), -15_000),
+        ] {
+            let mut transaction = database::Transactions::new(day, "Corner Store", amount);
+            transaction.account_id = Some(everyday.id);
+            transaction.insert(&pool).await?;
+        }
+        database::BalanceAssertions::new(everyday.id, date(3, 10), 260_000)
+            .insert(&pool)
+            .await?;
+        database::BalanceAssertions::new(everyday.id, date(3, 31), 245_000)
+            .insert(&pool)
+            .await?;
+        database::BalanceAssertions::new(savings.id, date(3, 31), 0)
+            .insert(&pool)
+            .await?;
+
+        let checks =
+            database::BalanceAssertions::check(None, database::UserScope::All, &pool).await?;
+        let found: Vec<_> = checks
+            .iter()
+            .map(|check| {
+                (
+                    check.assertion.assertion_date,
+                    check.computed_balance,
+                    check.holds(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            found,
+            [
+                (date(3, 10), 260_000, true),
+                (date(3, 31), 230_000, false),
+                (date(3, 31), 0, true)
+            ]
+        );
+        assert_eq!(checks[1].difference(), -15_000);
+
+        let savings_checks =
+            database::BalanceAssertions::check(Some(savings.id), database::UserScope::All, &pool)
+                .await?;
+        assert_eq!(savings_checks.len(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::BalanceAssertions {
+    /// Finds every balance asserted for an account, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The ID of the account
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::BalanceAssertions;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, everyday: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// for assertion in BalanceAssertions::find_by_account(everyday, pool).await? {
+    ///     println!("{}: {}", assertion.assertion_date, assertion.balance);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find balance assertions for account", skip(conn), err)]
+    pub fn find_by_account<'c, A>(
+        account_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let assertions = sqlx::query_as!(
+                database::BalanceAssertions,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        account_id          AS "account_id!: domain::RowID",
+                        assertion_date      AS "assertion_date!: chrono::NaiveDate",
+                        balance             AS "balance!: i64",
+                        note,
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM balance_assertions
+                    WHERE account_id = ?
+                    ORDER BY assertion_date ASC
+                "#,
+                account_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("balance_assertions.find_by_account")
+            .await?;
+
+            Ok(assertions)
+        }
+    }
+
+    /// Reads the assertion for an account and day back through the given
+    /// connection.
+    pub(super) async fn fetch_by_day(
+        account_id: domain::RowID,
+        assertion_date: chrono::NaiveDate,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let assertion = sqlx::query_as!(
+            database::BalanceAssertions,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    account_id          AS "account_id!: domain::RowID",
+                    assertion_date      AS "assertion_date!: chrono::NaiveDate",
+                    balance             AS "balance!: i64",
+                    note,
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM balance_assertions
+                WHERE account_id = ? AND assertion_date = ?
+            "#,
+            account_id,
+            assertion_date
+        )
+        .fetch_optional(conn)
+        .observe("balance_assertions.fetch_by_day")
+        .await?;
+
+        Ok(assertion)
+    }
+}
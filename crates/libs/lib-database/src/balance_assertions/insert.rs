@@ -0,0 +1,135 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::BalanceAssertions {
+    /// Stores the assertion for its account and day, replacing any assertion
+    /// the account already has for that day.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored assertion. When an existing assertion was replaced,
+    /// it keeps its id and creation time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The account does not exist (foreign key violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::BalanceAssertions;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, everyday: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let statement_date = chrono::NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+    ///
+    /// let mut assertion = BalanceAssertions::new(everyday, statement_date, 245_000);
+    /// assertion.note = Some("March statement".to_string());
+    /// assertion.insert(pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert balance assertion into database",
+        skip(self, conn),
+        fields(account_id = %self.account_id, assertion_date = %self.assertion_date),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO balance_assertions (
+                        id, account_id, assertion_date, balance, note, created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                    ON CONFLICT (account_id, assertion_date) DO UPDATE SET
+                        balance = excluded.balance,
+                        note = excluded.note,
+                        updated_on = excluded.updated_on
+                "#,
+                self.id,
+                self.account_id,
+                self.assertion_date,
+                self.balance,
+                self.note,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("balance_assertions.insert")
+            .await?;
+
+            let stored = Self::fetch_by_day(self.account_id, self.assertion_date, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Balance assertion for account {} on {} not found after insert",
+                        self.account_id, self.assertion_date
+                    ))
+                })?;
+
+            tracing::info!(
+                "Asserted balance {} on {}",
+                self.balance,
+                self.assertion_date
+            );
+
+            Ok(stored)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    pub(crate) fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn insert_replaces_assertion_for_same_day(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::new("Everyday").insert(&pool).await?;
+        let first = database::BalanceAssertions::new(account.id, date(3, 31), 245_000)
+            .insert(&pool)
+            .await?;
+
+        let mut correction = database::BalanceAssertions::new(account.id, date(3, 31), 254_000);
+        correction.note = Some("Typo in the first one".to_string());
+        let replaced = correction.insert(&pool).await?;
+
+        assert_eq!(replaced.id, first.id);
+        assert_eq!(replaced.created_on, first.created_on);
+        assert_eq!(replaced.balance, 254_000);
+        assert_eq!(
+            database::BalanceAssertions::find_by_account(account.id, &pool).await?,
+            [replaced]
+        );
+
+        let unknown = database::BalanceAssertions::new(lib_domain::RowID::new(), date(3, 31), 0)
+            .insert(&pool)
+            .await;
+        assert!(unknown.is_err());
+
+        Ok(())
+    }
+}
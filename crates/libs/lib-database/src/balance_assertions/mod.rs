@@ -0,0 +1,27 @@
+//! # Balance Assertions Database Module
+//!
+//! Checks the ledger against the balances a bank reports. An assertion
+//! records that an account had a balance at the end of a day, read off a
+//! statement or the bank's website. There is at most one assertion per
+//! account per day, and asserting a balance for a day that already has one
+//! replaces it.
+//!
+//! Nothing is changed to make an assertion hold. [`BalanceAssertions::check`]
+//! compares each assertion with the balance computed from the account's
+//! opening balance and posted transactions up to its date, so a difference
+//! points at a missed or doubled import somewhere before that day. Unlike
+//! [`BalanceSnapshots`](crate::BalanceSnapshots), assertions never change how
+//! an account is valued.
+
+mod check;
+mod delete;
+mod find;
+mod insert;
+mod model;
+
+/// Database row model representing a balance asserted for an account on a
+/// day.
+pub use model::BalanceAssertions;
+
+/// An assertion compared with the balance computed from the ledger.
+pub use check::BalanceAssertionCheck;
@@ -0,0 +1,51 @@
+use crate as database;
+use lib_domain as domain;
+
+/// The balance an account should have at the end of a day, as reported by
+/// the bank.
+///
+/// `balance` is in minor units of the account's currency.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct BalanceAssertions {
+    pub id: domain::RowID,
+    pub account_id: domain::RowID,
+    pub assertion_date: chrono::NaiveDate,
+    pub balance: i64,
+
+    /// Where the balance was read, e.g. "March statement".
+    pub note: Option<String>,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::BalanceAssertions {
+    /// Creates a new, unsaved assertion of an account's balance at the end
+    /// of a day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::BalanceAssertions;
+    /// use lib_domain::RowID;
+    ///
+    /// let everyday = RowID::new();
+    /// let statement_date = chrono::NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+    /// let assertion = BalanceAssertions::new(everyday, statement_date, 245_000);
+    ///
+    /// assert_eq!(assertion.balance, 245_000);
+    /// assert_eq!(assertion.note, None);
+    /// ```
+    pub fn new(account_id: domain::RowID, assertion_date: chrono::NaiveDate, balance: i64) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            account_id,
+            assertion_date,
+            balance,
+            note: None,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+}
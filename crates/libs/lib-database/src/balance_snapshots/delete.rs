@@ -0,0 +1,64 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::BalanceSnapshots {
+    /// Deletes a balance snapshot from the database by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the snapshot to delete
+    /// * `scope` - The user whose accounts' snapshots are deleted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The snapshot with the given ID does not exist, or its account is
+    ///   outside `scope` (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Delete balance snapshot from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let user_id = scope.user_id();
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    DELETE FROM balance_snapshots
+                    WHERE id = ?1
+                        AND (?2 IS NULL OR account_id IN (SELECT id FROM accounts WHERE user_id = ?2))
+                "#,
+                id,
+                user_id
+            )
+            .execute(&mut *conn)
+            .observe("balance_snapshots.delete_by_id")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Balance snapshot with id {} not found",
+                    id
+                )));
+            }
+
+            tracing::info!("Deleted balance snapshot {} from database", id);
+
+            Ok(())
+        }
+    }
+}
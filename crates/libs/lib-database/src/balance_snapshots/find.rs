@@ -0,0 +1,134 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::BalanceSnapshots {
+    /// Finds every snapshot of an account's balance, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The ID of the account
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::BalanceSnapshots;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, super_fund: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// for snapshot in BalanceSnapshots::find_by_account(super_fund, pool).await? {
+    ///     println!("{}: {}", snapshot.snapshot_date, snapshot.balance);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find balance snapshots for account", skip(conn), err)]
+    pub fn find_by_account<'c, A>(
+        account_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let snapshots = sqlx::query_as!(
+                database::BalanceSnapshots,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        account_id          AS "account_id!: domain::RowID",
+                        snapshot_date       AS "snapshot_date!: chrono::NaiveDate",
+                        balance             AS "balance!: i64",
+                        note,
+                        source              AS "source!: database::SnapshotSources",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM balance_snapshots
+                    WHERE account_id = ?
+                    ORDER BY snapshot_date ASC
+                "#,
+                account_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("balance_snapshots.find_by_account")
+            .await?;
+
+            Ok(snapshots)
+        }
+    }
+
+    /// Reads the snapshot for an account and day back through the given
+    /// connection.
+    pub(super) async fn fetch_by_day(
+        account_id: domain::RowID,
+        snapshot_date: chrono::NaiveDate,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let snapshot = sqlx::query_as!(
+            database::BalanceSnapshots,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    account_id          AS "account_id!: domain::RowID",
+                    snapshot_date       AS "snapshot_date!: chrono::NaiveDate",
+                    balance             AS "balance!: i64",
+                    note,
+                    source              AS "source!: database::SnapshotSources",
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM balance_snapshots
+                WHERE account_id = ? AND snapshot_date = ?
+            "#,
+            account_id,
+            snapshot_date
+        )
+        .fetch_optional(conn)
+        .observe("balance_snapshots.fetch_by_day")
+        .await?;
+
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance_snapshots::insert::tests::{Result, date};
+
+    #[sqlx::test]
+    async fn find_by_account_returns_oldest_first(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let super_fund = database::Accounts::new("Super").insert(&pool).await?;
+        let wallet = database::Accounts::new("Crypto Wallet")
+            .insert(&pool)
+            .await?;
+        for (month, balance) in [(9, 1_080_000), (3, 950_000), (6, 1_000_000)] {
+            let last_day = date(month + 1, 1).pred_opt().unwrap();
+            database::BalanceSnapshots::new(super_fund.id, last_day, balance)
+                .insert(&pool)
+                .await?;
+        }
+        database::BalanceSnapshots::new(wallet.id, date(6, 30), 200_000)
+            .insert(&pool)
+            .await?;
+
+        let snapshots = database::BalanceSnapshots::find_by_account(super_fund.id, &pool).await?;
+
+        let found: Vec<_> = snapshots
+            .iter()
+            .map(|s| (s.snapshot_date, s.balance))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (date(3, 31), 950_000),
+                (date(6, 30), 1_000_000),
+                (date(9, 30), 1_080_000)
+            ]
+        );
+
+        Ok(())
+    }
+}
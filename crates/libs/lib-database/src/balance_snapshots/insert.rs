@@ -0,0 +1,139 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::BalanceSnapshots {
+    /// Stores the snapshot for its account and day, replacing any snapshot the
+    /// account already has for that day.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored snapshot. When an existing snapshot was replaced, it
+    /// keeps its id and creation time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The account does not exist (foreign key violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::BalanceSnapshots;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, super_fund: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let statement_date = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+    ///
+    /// let mut snapshot = BalanceSnapshots::new(super_fund, statement_date, 18_450_000);
+    /// snapshot.note = Some("Annual statement".to_string());
+    /// snapshot.insert(pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert balance snapshot into database",
+        skip(self, conn),
+        fields(account_id = %self.account_id, snapshot_date = %self.snapshot_date),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO balance_snapshots (
+                        id, account_id, snapshot_date, balance, note, source, created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                    ON CONFLICT (account_id, snapshot_date) DO UPDATE SET
+                        balance = excluded.balance,
+                        note = excluded.note,
+                        source = excluded.source,
+                        updated_on = excluded.updated_on
+                "#,
+                self.id,
+                self.account_id,
+                self.snapshot_date,
+                self.balance,
+                self.note,
+                self.source,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("balance_snapshots.insert")
+            .await?;
+
+            let stored = Self::fetch_by_day(self.account_id, self.snapshot_date, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Balance snapshot for account {} on {} not found after insert",
+                        self.account_id, self.snapshot_date
+                    ))
+                })?;
+
+            tracing::info!("Stored balance {} on {}", self.balance, self.snapshot_date);
+
+            Ok(stored)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use lib_domain as domain;
+
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    pub(crate) fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn insert_replaces_snapshot_for_same_day(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::new("Super").insert(&pool).await?;
+        let first = database::BalanceSnapshots::new(account.id, date(6, 30), 1_000_000)
+            .insert(&pool)
+            .await?;
+
+        let mut correction = database::BalanceSnapshots::new(account.id, date(6, 30), 1_050_000);
+        correction.note = Some("Amended statement".to_string());
+        let replaced = correction.insert(&pool).await?;
+
+        assert_eq!(replaced.id, first.id);
+        assert_eq!(replaced.balance, 1_050_000);
+        assert_eq!(replaced.note.as_deref(), Some("Amended statement"));
+
+        let next = database::BalanceSnapshots::new(account.id, date(9, 30), 1_080_000)
+            .insert(&pool)
+            .await?;
+        assert_ne!(next.id, first.id);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_for_unknown_account_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let snapshot = database::BalanceSnapshots::new(domain::RowID::new(), date(6, 30), 1_000);
+
+        assert!(snapshot.insert(&pool).await.is_err());
+
+        Ok(())
+    }
+}
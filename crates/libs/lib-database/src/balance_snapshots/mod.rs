@@ -0,0 +1,29 @@
+//! # Balance Snapshots Database Module
+//!
+//! Stores balances recorded by hand for accounts whose transactions are not
+//! kept in the ledger, such as a superannuation fund or crypto wallet. The
+//! balance is read off a statement every so often and recorded with the date
+//! it was read. There is at most one snapshot per account per day, and
+//! recording a balance for a day that already has one replaces it.
+//!
+//! Once an account has a manual snapshot, [`Reports::net_worth`](crate::Reports::net_worth)
+//! values it from its snapshots: straight-line between the snapshots either
+//! side of a day, the latest snapshot after the last one, and nothing before
+//! the first.
+//!
+//! Closing a period also records the balance of every other account at its
+//! end, computed from its opening balance and posted transactions. Computed
+//! snapshots fix the account's balance at each period end for
+//! [`Reports::net_worth_history`](crate::Reports::net_worth_history), but do
+//! not change how the account is valued, and reopening the period discards
+//! them.
+
+mod delete;
+mod find;
+mod insert;
+mod model;
+mod record;
+
+/// Database row model representing a balance recorded on a day, and where
+/// the balance came from.
+pub use model::{BalanceSnapshots, SnapshotSources};
@@ -0,0 +1,68 @@
+use crate as database;
+use lib_domain as domain;
+
+/// The balance of an account as recorded on a day.
+///
+/// `balance` is in minor units of the account's currency.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct BalanceSnapshots {
+    pub id: domain::RowID,
+    pub account_id: domain::RowID,
+    pub snapshot_date: chrono::NaiveDate,
+    pub balance: i64,
+
+    /// Where the balance came from, e.g. "June quarterly statement".
+    pub note: Option<String>,
+    pub source: SnapshotSources,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+/// Where a snapshot's balance came from.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, sqlx::Type, serde::Deserialize, serde::Serialize,
+)]
+#[sqlx(rename_all = "lowercase")]
+pub enum SnapshotSources {
+    /// Read off a statement and recorded by hand. The account is valued from
+    /// its manual snapshots instead of its transactions.
+    #[default]
+    Manual,
+    /// Worked out from the account's opening balance and posted transactions
+    /// at the end of a period.
+    Computed,
+}
+
+impl database::BalanceSnapshots {
+    /// Creates a new, unsaved snapshot of an account's balance on a day,
+    /// recorded by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{BalanceSnapshots, SnapshotSources};
+    /// use lib_domain::RowID;
+    ///
+    /// let super_fund = RowID::new();
+    /// let statement_date = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+    /// let snapshot = BalanceSnapshots::new(super_fund, statement_date, 18_450_000);
+    ///
+    /// assert_eq!(snapshot.balance, 18_450_000);
+    /// assert_eq!(snapshot.note, None);
+    /// assert_eq!(snapshot.source, SnapshotSources::Manual);
+    /// ```
+    pub fn new(account_id: domain::RowID, snapshot_date: chrono::NaiveDate, balance: i64) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            account_id,
+            snapshot_date,
+            balance,
+            note: None,
+            source: SnapshotSources::Manual,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::BalanceSnapshots {
+    /// Records the balance of every account at the end of a period, computed
+    /// from its opening balance and posted transactions up to `on`.
+    ///
+    /// Accounts valued from manual snapshots are skipped, and a manual
+    /// snapshot is never recorded over. Recording the same day again updates
+    /// its computed snapshots, so it can be rerun after a period is reopened
+    /// and closed again.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - The last day of the period
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the computed snapshots recorded on `on`, or a `DatabaseError`
+    /// if they could not be recorded.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::BalanceSnapshots;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let end_of_year = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+    ///
+    /// for snapshot in BalanceSnapshots::record_period_end(end_of_year, pool).await? {
+    ///     println!("{}: {}", snapshot.account_id, snapshot.balance);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Record period end balance snapshots", skip(conn), err)]
+    pub fn record_period_end<'c, A>(
+        on: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            let balances = sqlx::query!(
+                r#"
+                    SELECT
+                        accounts.id         AS "account_id!: domain::RowID",
+                        accounts.opening_balance + COALESCE((
+                            SELECT SUM(transactions.amount)
+                            FROM transactions
+                            WHERE transactions.account_id = accounts.id
+                                AND transactions.is_draft = 0
+                                AND transactions.transaction_date <= ?1
+                        ), 0)               AS "balance!: i64"
+                    FROM accounts
+                    WHERE NOT EXISTS (
+                        SELECT 1 FROM balance_snapshots
+                        WHERE balance_snapshots.account_id = accounts.id
+                            AND balance_snapshots.source = 'manual'
+                    )
+                    ORDER BY accounts.name ASC
+                "#,
+                on
+            )
+            .fetch_all(&mut *tx)
+            .observe("balance_snapshots.record_period_end")
+            .await?;
+
+            let mut recorded = Vec::with_capacity(balances.len());
+
+            for row in balances {
+                let mut snapshot = Self::new(row.account_id, on, row.balance);
+                snapshot.source = database::SnapshotSources::Computed;
+
+                sqlx::query!(
+                    r#"
+                        INSERT INTO balance_snapshots (
+                            id, account_id, snapshot_date, balance, note, source, created_on, updated_on
+                        )
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                        ON CONFLICT (account_id, snapshot_date) DO UPDATE SET
+                            balance = excluded.balance,
+                            updated_on = excluded.updated_on
+                        WHERE balance_snapshots.source = 'computed'
+                    "#,
+                    snapshot.id,
+                    snapshot.account_id,
+                    snapshot.snapshot_date,
+                    snapshot.balance,
+                    snapshot.note,
+                    snapshot.source,
+                    snapshot.created_on,
+                    snapshot.updated_on
+                )
+                .execute(&mut *tx)
+                .observe("balance_snapshots.record_period_end")
+                .await?;
+
+                if let Some(stored) = Self::fetch_by_day(row.account_id, on, &mut tx).await? {
+                    recorded.push(stored);
+                }
+            }
+
+            tx.commit().await?;
+
+            tracing::info!("Recorded {} period end balances on {}", recorded.len(), on);
+
+            Ok(recorded)
+        }
+    }
+
+    /// Deletes the computed snapshots dated after `after`, or every computed
+    /// snapshot when it is `None`, as their periods have been reopened.
+    pub(crate) async fn discard_computed_after(
+        after: Option<chrono::NaiveDate>,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<u64> {
+        let discarded = sqlx::query!(
+            r#"
+                DELETE FROM balance_snapshots
+                WHERE source = 'computed'
+                    AND (?1 IS NULL OR snapshot_date > ?1)
+            "#,
+            after
+        )
+        .execute(conn)
+        .observe("balance_snapshots.discard_computed_after")
+        .await?
+        .rows_affected();
+
+        Ok(discarded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance_snapshots::insert::tests::{Result, date};
+
+    #[sqlx::test]
+    async fn record_period_end_computes_balances(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut everyday = database::Accounts::new("Everyday");
+        everyday.opening_balance = 10_000;
+        let everyday = everyday.insert(&pool).await?;
+        for (day, amount) in [
+            (date(6, 1), 250_000),
+            (date(6, 20), -180_000),
+            (date(7, 2), -4_500),
+        ] {
+            let mut transaction = database::Transactions::new(day, "Corner Store", amount);
+            transaction.account_id = Some(everyday.id);
+            transaction.insert(&pool).await?;
+        }
+        let super_fund = database::Accounts::new("Super").insert(&pool).await?;
+        database::BalanceSnapshots::new(super_fund.id, date(6, 30), 1_000_000)
+            .insert(&pool)
+            .await?;
+
+        let recorded = database::BalanceSnapshots::record_period_end(date(6, 30), &pool).await?;
+
+        let found: Vec<_> = recorded
+            .iter()
+            .map(|s| (s.account_id, s.balance, s.source))
+            .collect();
+        assert_eq!(
+            found,
+            [(everyday.id, 80_000, database::SnapshotSources::Computed)]
+        );
+
+        let snapshots = database::BalanceSnapshots::find_by_account(super_fund.id, &pool).await?;
+        let found: Vec<_> = snapshots.iter().map(|s| (s.balance, s.source)).collect();
+        assert_eq!(found, [(1_000_000, database::SnapshotSources::Manual)]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn record_period_end_updates_computed_snapshots(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let everyday = database::Accounts::new("Everyday").insert(&pool).await?;
+        let first = database::BalanceSnapshots::record_period_end(date(6, 30), &pool).await?;
+        let mut transaction = database::Transactions::new(date(6, 15), "Corner Store", -2_500);
+        transaction.account_id = Some(everyday.id);
+        transaction.insert(&pool).await?;
+
+        let again = database::BalanceSnapshots::record_period_end(date(6, 30), &pool).await?;
+
+        assert_eq!(again.len(), 1);
+        assert_eq!(again[0].id, first[0].id);
+        assert_eq!(again[0].balance, -2_500);
+
+        let mut conn = pool.acquire().await?;
+        assert_eq!(
+            database::BalanceSnapshots::discard_computed_after(Some(date(5, 31)), &mut conn)
+                .await?,
+            1
+        );
+        assert!(
+            database::BalanceSnapshots::find_by_account(everyday.id, &pool)
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+}
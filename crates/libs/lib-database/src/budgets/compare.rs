@@ -0,0 +1,235 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+/// Budgeted and actual spending for one budget over a period.
+///
+/// Amounts are in minor units (cents). Spending is positive: it is the
+/// negated sum of the posted transactions in the budget's category and its
+/// subcategories, so refunds reduce it.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct BudgetComparison {
+    pub budget: database::Budgets,
+
+    /// First day of the period compared.
+    pub start: chrono::NaiveDate,
+
+    /// Last day of the period compared.
+    pub end: chrono::NaiveDate,
+
+    /// The budget scaled to the period.
+    pub budgeted: i64,
+
+    /// The amount spent in the period.
+    pub actual: i64,
+}
+
+impl BudgetComparison {
+    /// Returns what is left to spend, negative when over budget.
+    pub fn remaining(&self) -> i64 {
+        self.budgeted - self.actual
+    }
+
+    /// Returns `true` when more was spent than budgeted.
+    pub fn is_over_budget(&self) -> bool {
+        self.actual > self.budgeted
+    }
+}
+
+impl database::Budgets {
+    /// Compares every budget with the spending in the month or financial year
+    /// containing a date, ordered by category name.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Whether to compare over a month or a financial year
+    /// * `on` - A date in the period to compare
+    /// * `scope` - The user whose categories' budgets are compared
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{BudgetPeriods, Budgets, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let today = chrono::Utc::now().date_naive();
+    ///
+    /// let scope = UserScope::All;
+    /// for comparison in Budgets::compare(BudgetPeriods::Monthly, today, scope, pool).await? {
+    ///     println!("{} left of {}", comparison.remaining(), comparison.budgeted);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Compare budgets with spending", skip(conn), err)]
+    pub fn compare<'c, A>(
+        period: database::BudgetPeriods,
+        on: chrono::NaiveDate,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::BudgetComparison>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let (start, end) = period.range(on);
+            let user_id = scope.user_id();
+
+            let rows = sqlx::query!(
+                r#"
+                    WITH RECURSIVE tree (budget_id, category_id) AS (
+                        SELECT id, category_id FROM budgets
+                        UNION
+                        SELECT tree.budget_id, c.id
+                        FROM categories c JOIN tree ON c.parent_id = tree.category_id
+                    )
+                    SELECT
+                        b.id                AS "id!: domain::RowID",
+                        b.category_id       AS "category_id!: domain::RowID",
+                        b.period            AS "period!: database::BudgetPeriods",
+                        b.amount            AS "amount!: i64",
+                        b.created_on        AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        b.updated_on        AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        -COALESCE((
+                            SELECT SUM(t.amount)
+                            FROM transactions t JOIN tree ON tree.category_id = t.category_id
+                            WHERE tree.budget_id = b.id
+                                AND t.is_draft = 0
+                                AND t.transaction_date BETWEEN ?1 AND ?2
+                                AND (?3 IS NULL OR t.user_id = ?3)
+                        ), 0)               AS "actual!: i64"
+                    FROM budgets b JOIN categories c ON c.id = b.category_id
+                    WHERE ?3 IS NULL OR c.user_id = ?3
+                    ORDER BY c.name ASC
+                "#,
+                start,
+                end,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("budgets.compare")
+            .await?;
+
+            let comparisons = rows
+                .into_iter()
+                .map(|row| {
+                    let budget = database::Budgets {
+                        id: row.id,
+                        category_id: row.category_id,
+                        period: row.period,
+                        amount: row.amount,
+                        created_on: row.created_on,
+                        updated_on: row.updated_on,
+                    };
+
+                    database::BudgetComparison {
+                        budgeted: budget.amount_for(period),
+                        budget,
+                        start,
+                        end,
+                        actual: row.actual,
+                    }
+                })
+                .collect();
+
+            Ok(comparisons)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budgets::insert::tests::Result;
+
+    fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    async fn spend(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+        category_id: domain::RowID,
+        on: chrono::NaiveDate,
+        amount: i64,
+    ) -> Result<()> {
+        let mut transaction = database::Transactions::new(on, "Payee", amount);
+        transaction.category_id = Some(category_id);
+        transaction.insert(pool).await?;
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn compare_counts_subcategories_in_period(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        // Mock names and slugs are a word or two, so fix them to avoid collisions
+        let mut food = database::Categories::mock();
+        food.name = "Food".to_string();
+        food.url_slug = None;
+        let food = food.insert(&pool).await?;
+        let mut groceries = database::Categories::mock();
+        groceries.name = "Groceries".to_string();
+        groceries.url_slug = None;
+        groceries.parent_id = Some(food.id);
+        let groceries = groceries.insert(&pool).await?;
+        let budget = database::Budgets::new(food.id, database::BudgetPeriods::Monthly, 50_000)
+            .insert(&pool)
+            .await?;
+
+        spend(&pool, food.id, date(3, 1), -10_000).await?;
+        spend(&pool, groceries.id, date(3, 31), -45_000).await?;
+        spend(&pool, groceries.id, date(3, 15), 2_000).await?;
+        spend(&pool, groceries.id, date(4, 1), -99_000).await?;
+
+        let mut draft = database::Transactions::new(date(3, 20), "Payee", -99_000);
+        draft.category_id = Some(food.id);
+        draft.is_draft = true;
+        draft.insert(&pool).await?;
+
+        let comparisons = database::Budgets::compare(
+            database::BudgetPeriods::Monthly,
+            date(3, 14),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(comparisons.len(), 1);
+        let comparison = &comparisons[0];
+        assert_eq!(comparison.budget, budget);
+        assert_eq!(
+            (comparison.start, comparison.end),
+            (date(3, 1), date(3, 31))
+        );
+        assert_eq!(comparison.budgeted, 50_000);
+        assert_eq!(comparison.actual, 53_000);
+        assert_eq!(comparison.remaining(), -3_000);
+        assert!(comparison.is_over_budget());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn compare_scales_budget_to_period(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        database::Budgets::new(category.id, database::BudgetPeriods::Monthly, 10_000)
+            .insert(&pool)
+            .await?;
+        spend(&pool, category.id, date(1, 10), -30_000).await?;
+
+        let comparisons = database::Budgets::compare(
+            database::BudgetPeriods::Annual,
+            date(3, 14),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(comparisons[0].budgeted, 120_000);
+        assert_eq!(comparisons[0].actual, 30_000);
+        assert_eq!(comparisons[0].remaining(), 90_000);
+
+        Ok(())
+    }
+}
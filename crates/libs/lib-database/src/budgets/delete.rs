@@ -0,0 +1,111 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Budgets {
+    /// Deletes a budget from the database by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the budget to delete
+    /// * `scope` - The user whose categories' budgets are deleted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The budget with the given ID does not exist, or its category is
+    ///   outside `scope` (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Delete budget from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let user_id = scope.user_id();
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    DELETE FROM budgets
+                    WHERE id = ?1
+                        AND (?2 IS NULL OR category_id IN (SELECT id FROM categories WHERE user_id = ?2))
+                "#,
+                id,
+                user_id
+            )
+            .execute(&mut *conn)
+            .observe("budgets.delete_by_id")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Budget with id {} not found",
+                    id
+                )));
+            }
+
+            tracing::info!("Deleted budget {} from database", id);
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budgets::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn delete_by_id_removes_budget(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        let budget = database::Budgets::mock(category.id).insert(&pool).await?;
+
+        database::Budgets::delete_by_id(budget.id, database::UserScope::All, &pool).await?;
+
+        assert!(
+            database::Budgets::find_by_id(budget.id, database::UserScope::All, &pool)
+                .await?
+                .is_none()
+        );
+        assert!(matches!(
+            database::Budgets::delete_by_id(budget.id, database::UserScope::All, &pool).await,
+            Err(database::DatabaseError::NotFound(_))
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn deleting_category_deletes_budget(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        let target = database::Categories::mock().insert(&pool).await?;
+        let budget = database::Budgets::mock(category.id).insert(&pool).await?;
+        let scope = database::UserScope::All;
+
+        // Only when reassigning, not as a side effect of a plain delete
+        let refused = database::Categories::delete_by_id(category.id, scope, &pool).await;
+        assert!(matches!(refused, Err(database::DatabaseError::InUse(_))));
+        database::Categories::delete_with_reassign(category.id, target.id, scope, &pool).await?;
+
+        assert!(
+            database::Budgets::find_by_id(budget.id, database::UserScope::All, &pool)
+                .await?
+                .is_none()
+        );
+
+        Ok(())
+    }
+}
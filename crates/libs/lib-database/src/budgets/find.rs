@@ -0,0 +1,302 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Budgets {
+    /// Finds a budget by its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the budget to find
+    /// * `scope` - The user whose categories' budgets are searched
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Budgets)` if the budget exists and its category is in
+    /// `scope`, or `None` if not found.
+    #[tracing::instrument(
+        name = "Find budget by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let user_id = scope.user_id();
+            let mut conn = conn.acquire().await?;
+
+            let budget = sqlx::query_as!(
+                database::Budgets,
+                r#"
+                    SELECT
+                        b.id                AS "id!: domain::RowID",
+                        b.category_id       AS "category_id!: domain::RowID",
+                        b.period            AS "period!: database::BudgetPeriods",
+                        b.amount            AS "amount!: i64",
+                        b.created_on        AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        b.updated_on        AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM budgets b JOIN categories c ON c.id = b.category_id
+                    WHERE b.id = ?1 AND (?2 IS NULL OR c.user_id = ?2)
+                "#,
+                id,
+                user_id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("budgets.find_by_id")
+            .await?;
+
+            Ok(budget)
+        }
+    }
+
+    /// Finds the budget for a category, if it has one.
+    ///
+    /// # Arguments
+    ///
+    /// * `category_id` - The category the budget is for
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(
+        name = "Find budget by category",
+        skip(conn),
+        fields(category_id = %category_id),
+        err
+    )]
+    pub fn find_by_category<'c, A>(
+        category_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let budget = sqlx::query_as!(
+                database::Budgets,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        category_id         AS "category_id!: domain::RowID",
+                        period              AS "period!: database::BudgetPeriods",
+                        amount              AS "amount!: i64",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM budgets
+                    WHERE category_id = ?
+                "#,
+                category_id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("budgets.find_by_category")
+            .await?;
+
+            Ok(budget)
+        }
+    }
+
+    /// Retrieves a page of budgets, ordered by category name.
+    ///
+    /// The next page starts after the category name of the cursor's budget.
+    /// See the [`pagination`](crate::pagination) module for how pages are
+    /// read with a cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `after` - The cursor of the previous page, or `None` for the first page
+    /// * `page_size` - Maximum number of records to return, 50 if zero or negative
+    /// * `scope` - The user whose categories' budgets are listed
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the page of budgets, with the total number of budgets and the
+    /// cursor of the next page, or `DatabaseError::Validation` if the
+    /// cursor's budget has been deleted.
+    #[tracing::instrument(
+        name = "Find budgets with pagination",
+        skip(conn),
+        fields(after = ?after, page_size = %page_size),
+        err
+    )]
+    pub fn find_all_with_pagination<'c, A>(
+        after: Option<database::PageCursor>,
+        page_size: i32,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<database::Page<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let page_size = database::pagination::page_size_or_default(page_size);
+            let limit = page_size + 1;
+            let after_id = after.map(|after| after.id);
+
+            let mut conn = conn.acquire().await?;
+
+            let after_name = match after_id {
+                Some(id) => Some(
+                    sqlx::query_scalar!(
+                        r#"
+                            SELECT c.name
+                            FROM budgets b JOIN categories c ON c.id = b.category_id
+                            WHERE b.id = ?
+                        "#,
+                        id
+                    )
+                    .fetch_optional(&mut *conn)
+                    .observe("budgets.find_all_with_pagination")
+                    .await?
+                    .ok_or_else(|| {
+                        database::DatabaseError::Validation(
+                            "The page token's budget has been deleted, start again from the first page"
+                                .to_string(),
+                        )
+                    })?,
+                ),
+                None => None,
+            };
+
+            let total_count: i32 = sqlx::query_scalar(
+                r#"
+                    SELECT COUNT(*) as count
+                    FROM budgets b JOIN categories c ON c.id = b.category_id
+                    WHERE ?1 IS NULL OR c.user_id = ?1
+                "#,
+            )
+            .bind(scope.user_id())
+            .fetch_one(&mut *conn)
+            .observe_one("budgets.find_all_with_pagination")
+            .await?;
+
+            let user_id = scope.user_id();
+            let budgets = sqlx::query_as!(
+                database::Budgets,
+                r#"
+                    SELECT
+                        b.id                AS "id!: domain::RowID",
+                        b.category_id       AS "category_id!: domain::RowID",
+                        b.period            AS "period!: database::BudgetPeriods",
+                        b.amount            AS "amount!: i64",
+                        b.created_on        AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        b.updated_on        AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM budgets b JOIN categories c ON c.id = b.category_id
+                    WHERE (?1 IS NULL OR (c.name, b.id) > (?1, ?2))
+                        AND (?4 IS NULL OR c.user_id = ?4)
+                    ORDER BY c.name ASC, b.id ASC
+                    LIMIT ?3
+                "#,
+                after_name,
+                after_id,
+                limit,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("budgets.find_all_with_pagination")
+            .await?;
+
+            let page = database::Page::from_rows(budgets, page_size, total_count, |budget| {
+                database::PageCursor::new(budget.created_on, budget.id)
+            });
+
+            tracing::info!(
+                "Retrieved {} of {} budgets from database",
+                page.items.len(),
+                total_count
+            );
+
+            Ok(page)
+        }
+    }
+
+    /// Reads a budget back through the given connection.
+    pub(super) async fn fetch_by_id(
+        id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let budget = sqlx::query_as!(
+            database::Budgets,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    category_id         AS "category_id!: domain::RowID",
+                    period              AS "period!: database::BudgetPeriods",
+                    amount              AS "amount!: i64",
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM budgets
+                WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("budgets.fetch_by_id")
+        .await?;
+
+        Ok(budget)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budgets::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn find_by_category_returns_budget(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        let budget = database::Budgets::mock(category.id).insert(&pool).await?;
+
+        assert_eq!(
+            database::Budgets::find_by_category(category.id, &pool).await?,
+            Some(budget)
+        );
+        assert!(
+            database::Budgets::find_by_category(domain::RowID::new(), &pool)
+                .await?
+                .is_none()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_all_with_pagination_orders_by_category_name(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut created = Vec::new();
+        for name in ["Transport", "Groceries", "Rent"] {
+            let mut category = database::Categories::mock();
+            category.name = name.to_string();
+            category.url_slug = None;
+            let category = category.insert(&pool).await?;
+            created.push(database::Budgets::mock(category.id).insert(&pool).await?);
+        }
+
+        let first =
+            database::Budgets::find_all_with_pagination(None, 2, database::UserScope::All, &pool)
+                .await?;
+        let second = database::Budgets::find_all_with_pagination(
+            first.next_cursor,
+            2,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(first.total_count, 3);
+        assert_eq!(first.items, [created[1].clone(), created[2].clone()]);
+        assert_eq!(second.items, [created[0].clone()]);
+        assert_eq!(second.next_cursor, None);
+
+        Ok(())
+    }
+}
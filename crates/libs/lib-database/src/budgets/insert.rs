@@ -0,0 +1,132 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::Budgets {
+    /// Inserts a new budget into the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the inserted budget as read back from the database, or a
+    /// `DatabaseError` if the insertion fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The amount is not positive (`DatabaseError::Validation`)
+    /// - The category does not exist (foreign key violation)
+    /// - The category already has a budget (unique constraint violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{BudgetPeriods, Budgets};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, groceries_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let budget = Budgets::new(groceries_id, BudgetPeriods::Monthly, 80_000);
+    ///
+    /// let inserted = budget.insert(pool).await?;
+    /// assert_eq!(inserted.amount, 80_000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert new budget into database",
+        skip(self, conn),
+        fields(id = %self.id, category_id = %self.category_id, amount = self.amount),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO budgets (id, category_id, period, amount, created_on, updated_on)
+                    VALUES (?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.category_id,
+                self.period,
+                self.amount,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("budgets.insert")
+            .await?;
+
+            let inserted = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Budget with id {} not found after insert",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("New budget inserted into the database.");
+
+            Ok(inserted)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use lib_domain as domain;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn insert_budget_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        let budget = database::Budgets::mock(category.id);
+
+        let inserted = budget.insert(&pool).await?;
+
+        assert_eq!(inserted, budget);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_second_budget_for_category_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        database::Budgets::mock(category.id).insert(&pool).await?;
+
+        let result = database::Budgets::mock(category.id).insert(&pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_unknown_category_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let result = database::Budgets::mock(domain::RowID::new())
+            .insert(&pool)
+            .await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+//! # Budgets Database Module
+//!
+//! Sets how much may be spent in a category each month or each financial
+//! year, and compares that with what was actually spent. A category has at
+//! most one budget, and spending in its subcategories counts towards it.
+//!
+//! Budgets are compared over a calendar month or an Australian financial year
+//! (1 July to 30 June). A monthly budget compared over a year is multiplied by
+//! twelve, and an annual budget compared over a month is divided by twelve.
+//! Draft transactions are not counted as spending.
+//!
+//! `Budgets::variance` compares the same figures as a tree of categories,
+//! rolling spending in subcategories up into their parents and flagging the
+//! categories over budget.
+
+mod compare;
+mod delete;
+mod find;
+mod insert;
+mod model;
+mod update;
+mod variance;
+
+/// Database row model representing a category budget.
+pub use model::Budgets;
+
+/// Whether a budget is set per month or per financial year.
+pub use model::BudgetPeriods;
+
+/// Budgeted and actual spending for one budget over a period.
+pub use compare::BudgetComparison;
+
+/// Budgeted and actual spending for a category and its subcategories.
+pub use variance::BudgetVariance;
@@ -0,0 +1,183 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Whether a budget is set per month or per financial year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, serde::Deserialize, serde::Serialize)]
+#[sqlx(rename_all = "lowercase")]
+pub enum BudgetPeriods {
+    /// A calendar month.
+    Monthly,
+    /// An Australian financial year, 1 July to 30 June.
+    Annual,
+}
+
+impl BudgetPeriods {
+    /// Returns the first and last day of the period containing a date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::BudgetPeriods;
+    ///
+    /// let date = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(BudgetPeriods::Monthly.range(date(2025, 2, 14)), (date(2025, 2, 1), date(2025, 2, 28)));
+    /// assert_eq!(BudgetPeriods::Annual.range(date(2025, 2, 14)), (date(2024, 7, 1), date(2025, 6, 30)));
+    /// ```
+    pub fn range(&self, on: chrono::NaiveDate) -> (chrono::NaiveDate, chrono::NaiveDate) {
+        use chrono::Datelike;
+
+        let (start, months) = match self {
+            BudgetPeriods::Monthly => (on.with_day(1), 1),
+            BudgetPeriods::Annual => {
+                let year = if on.month() >= 7 {
+                    on.year()
+                } else {
+                    on.year() - 1
+                };
+                (chrono::NaiveDate::from_ymd_opt(year, 7, 1), 12)
+            }
+        };
+        let start = start.expect("first day of month is valid");
+        let end = start + chrono::Months::new(months) - chrono::Days::new(1);
+
+        (start, end)
+    }
+
+    /// Returns the number of months in the period.
+    pub fn months(&self) -> i64 {
+        match self {
+            BudgetPeriods::Monthly => 1,
+            BudgetPeriods::Annual => 12,
+        }
+    }
+}
+
+/// A budget for spending in a category, in positive minor units (cents) per
+/// period.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct Budgets {
+    pub id: domain::RowID,
+    pub category_id: domain::RowID,
+    pub period: BudgetPeriods,
+    pub amount: i64,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::Budgets {
+    /// Creates a new, unsaved budget for a category.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{BudgetPeriods, Budgets};
+    /// use lib_domain::RowID;
+    ///
+    /// let budget = Budgets::new(RowID::new(), BudgetPeriods::Monthly, 80_000);
+    ///
+    /// assert_eq!(budget.amount_for(BudgetPeriods::Annual), 960_000);
+    /// ```
+    pub fn new(category_id: domain::RowID, period: BudgetPeriods, amount: i64) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            category_id,
+            period,
+            amount,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Returns the budgeted amount over another period, scaled by the number
+    /// of months and rounded to the nearest cent.
+    pub fn amount_for(&self, period: BudgetPeriods) -> i64 {
+        let (from, to) = (self.period.months(), period.months());
+
+        (self.amount * to * 2 + from) / (from * 2)
+    }
+
+    /// Checks the budget fields before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the amount is not positive, or
+    /// too large to scale to a financial year.
+    pub fn validate(&self) -> DatabaseResult<()> {
+        if self.amount <= 0 {
+            return Err(DatabaseError::Validation(format!(
+                "Budget {} amount must be positive",
+                self.id
+            )));
+        }
+
+        if self
+            .amount
+            .checked_mul(BudgetPeriods::Annual.months() * 2)
+            .is_none()
+        {
+            return Err(DatabaseError::Validation(format!(
+                "Budget {} amount is too large",
+                self.id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Generates a mock monthly budget for a category.
+    #[cfg(test)]
+    pub fn mock(category_id: domain::RowID) -> Self {
+        use fake::Fake;
+
+        Self::new(category_id, BudgetPeriods::Monthly, (1..1_000_000).fake())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn range_covers_month_or_financial_year() {
+        assert_eq!(
+            BudgetPeriods::Monthly.range(date(2024, 12, 31)),
+            (date(2024, 12, 1), date(2024, 12, 31))
+        );
+        assert_eq!(
+            BudgetPeriods::Annual.range(date(2025, 7, 1)),
+            (date(2025, 7, 1), date(2026, 6, 30))
+        );
+        assert_eq!(
+            BudgetPeriods::Annual.range(date(2025, 6, 30)),
+            (date(2024, 7, 1), date(2025, 6, 30))
+        );
+    }
+
+    #[test]
+    fn amount_for_scales_and_rounds() {
+        let annual = database::Budgets::new(domain::RowID::new(), BudgetPeriods::Annual, 100_006);
+
+        assert_eq!(annual.amount_for(BudgetPeriods::Monthly), 8_334);
+        assert_eq!(annual.amount_for(BudgetPeriods::Annual), 100_006);
+    }
+
+    #[test]
+    fn validate_rejects_non_positive_amounts() {
+        for amount in [0, -100, i64::MAX] {
+            let budget =
+                database::Budgets::new(domain::RowID::new(), BudgetPeriods::Monthly, amount);
+
+            assert!(
+                matches!(budget.validate(), Err(DatabaseError::Validation(_))),
+                "{amount}"
+            );
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::Budgets {
+    /// Updates an existing budget in the database.
+    ///
+    /// The category, period and amount of the budget identified by `id` are
+    /// replaced and the `updated_on` timestamp is set to now.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The user whose categories' budgets are updated
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated budget as read back from the database, or a
+    /// `DatabaseError` if the update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The budget does not exist, or its category is outside `scope`
+    ///   (`DatabaseError::NotFound`)
+    /// - The amount is not positive (`DatabaseError::Validation`)
+    /// - The new category already has a budget
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Update budget in database",
+        skip(self, conn),
+        fields(id = %self.id, amount = self.amount),
+        err
+    )]
+    pub fn update<'a, 'c, A>(
+        &'a self,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let updated_on = chrono::Utc::now();
+            let user_id = scope.user_id();
+
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    UPDATE budgets
+                    SET category_id = ?, period = ?, amount = ?, updated_on = ?
+                    WHERE id = ?
+                        AND (? IS NULL OR category_id IN (SELECT id FROM categories WHERE user_id = ?))
+                "#,
+                self.category_id,
+                self.period,
+                self.amount,
+                updated_on,
+                self.id,
+                user_id,
+                user_id
+            )
+            .execute(&mut *conn)
+            .observe("budgets.update")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Budget with id {} not found",
+                    self.id
+                )));
+            }
+
+            let updated = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Budget with id {} not found after update",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("Budget updated in the database.");
+
+            Ok(updated)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budgets::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn update_budget_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        let inserted = database::Budgets::mock(category.id).insert(&pool).await?;
+
+        let mut budget = inserted.clone();
+        budget.period = database::BudgetPeriods::Annual;
+        budget.amount = 1_200_000;
+
+        let updated = budget.update(database::UserScope::All, &pool).await?;
+
+        assert_eq!(updated.period, database::BudgetPeriods::Annual);
+        assert_eq!(updated.amount, 1_200_000);
+        assert_eq!(updated.created_on, inserted.created_on);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+
+        let result = database::Budgets::mock(category.id)
+            .update(database::UserScope::All, &pool)
+            .await;
+
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+/// Budgeted and actual spending for a category and its subcategories over a
+/// period, as a node of the tree returned by
+/// [`Budgets::variance`](database::Budgets::variance).
+///
+/// Amounts are in minor units (cents), with spending positive as for a
+/// [`BudgetComparison`](database::BudgetComparison).
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct BudgetVariance {
+    pub category_id: domain::RowID,
+    pub category_name: String,
+
+    /// The category's own budget, `None` if it has none.
+    pub budget: Option<database::Budgets>,
+
+    /// The category's budget scaled to the period or, when it has none, the
+    /// sum of its subcategories' budgeted amounts.
+    pub budgeted: i64,
+
+    /// The amount spent in the category and every subcategory in the period.
+    pub actual: i64,
+
+    /// Subcategories with a budget or spending, ordered by name.
+    pub children: Vec<BudgetVariance>,
+}
+
+impl BudgetVariance {
+    /// Returns what is left to spend, negative when over budget.
+    pub fn remaining(&self) -> i64 {
+        self.budgeted - self.actual
+    }
+
+    /// Returns `true` when the category or one of its subcategories has a
+    /// budget.
+    pub fn is_budgeted(&self) -> bool {
+        self.budget.is_some() || self.children.iter().any(Self::is_budgeted)
+    }
+
+    /// Returns `true` when the category is budgeted and more was spent than
+    /// budgeted. Spending in a category without a budget anywhere below it
+    /// is never over budget.
+    pub fn is_over_budget(&self) -> bool {
+        self.is_budgeted() && self.actual > self.budgeted
+    }
+}
+
+/// A category as read for the tree.
+#[derive(sqlx::FromRow)]
+struct CategoryRow {
+    id: domain::RowID,
+    parent_id: Option<domain::RowID>,
+    name: String,
+}
+
+/// The categories, spending and budgets the tree is built from.
+struct Ledger {
+    children: HashMap<Option<domain::RowID>, Vec<CategoryRow>>,
+    spent: HashMap<domain::RowID, i64>,
+    budgets: HashMap<domain::RowID, database::Budgets>,
+    period: database::BudgetPeriods,
+}
+
+impl database::Budgets {
+    /// Compares budgets with spending in the month or financial year
+    /// containing a date, as a tree of categories.
+    ///
+    /// Spending in subcategories rolls up into their parents, and a parent
+    /// without a budget of its own is budgeted the sum of its subcategories'
+    /// budgets, so every level of the tree can be checked against a budget.
+    /// Categories with neither a budget nor spending anywhere below them are
+    /// left out, and deleted categories are skipped, with their
+    /// subcategories shown at the top of the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Whether to compare over a month or a financial year
+    /// * `on` - A date in the period to compare
+    /// * `scope` - The user whose categories are compared
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{BudgetPeriods, BudgetVariance, Budgets, UserScope};
+    ///
+    /// fn print(node: &BudgetVariance, depth: usize) {
+    ///     let flag = if node.is_over_budget() { " over budget" } else { "" };
+    ///     println!("{:depth$}{} {} of {}{flag}", "", node.category_name, node.actual, node.budgeted);
+    ///     for child in &node.children {
+    ///         print(child, depth + 2);
+    ///     }
+    /// }
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let today = chrono::Utc::now().date_naive();
+    /// let scope = UserScope::All;
+    /// for root in Budgets::variance(BudgetPeriods::Monthly, today, scope, pool).await? {
+    ///     print(&root, 0);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Budget variance tree", skip(conn), err)]
+    pub fn variance<'c, A>(
+        period: database::BudgetPeriods,
+        on: chrono::NaiveDate,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<BudgetVariance>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let (start, end) = period.range(on);
+
+            let categories = sqlx::query_as::<_, CategoryRow>(
+                r#"
+                    SELECT c.id, p.id AS parent_id, c.name
+                    FROM categories c
+                    LEFT JOIN categories p ON p.id = c.parent_id AND p.deleted_on IS NULL
+                    WHERE c.deleted_on IS NULL AND (?1 IS NULL OR c.user_id = ?1)
+                    ORDER BY c.name, c.id
+                "#,
+            )
+            .bind(scope.user_id())
+            .fetch_all(&mut *conn)
+            .observe("budgets.variance")
+            .await?;
+
+            let spent = sqlx::query_as::<_, (domain::RowID, i64)>(
+                r#"
+                    SELECT category_id, -SUM(amount)
+                    FROM transactions
+                    WHERE category_id IS NOT NULL
+                        AND is_draft = 0
+                        AND transaction_date BETWEEN ?1 AND ?2
+                        AND (?3 IS NULL OR user_id = ?3)
+                    GROUP BY category_id
+                "#,
+            )
+            .bind(start)
+            .bind(end)
+            .bind(scope.user_id())
+            .fetch_all(&mut *conn)
+            .observe("budgets.variance")
+            .await?;
+
+            let budgets = sqlx::query_as::<_, database::Budgets>(
+                r#"
+                    SELECT b.id, b.category_id, b.period, b.amount, b.created_on, b.updated_on
+                    FROM budgets b JOIN categories c ON c.id = b.category_id
+                    WHERE ?1 IS NULL OR c.user_id = ?1
+                "#,
+            )
+            .bind(scope.user_id())
+            .fetch_all(&mut *conn)
+            .observe("budgets.variance")
+            .await?;
+
+            let mut children: HashMap<_, Vec<CategoryRow>> = HashMap::new();
+            for category in categories {
+                children
+                    .entry(category.parent_id)
+                    .or_default()
+                    .push(category);
+            }
+
+            let mut ledger = Ledger {
+                children,
+                spent: spent.into_iter().collect(),
+                budgets: budgets
+                    .into_iter()
+                    .map(|budget| (budget.category_id, budget))
+                    .collect(),
+                period,
+            };
+
+            Ok(ledger.nodes(None))
+        }
+    }
+}
+
+impl Ledger {
+    /// The nodes for the children of `parent`, or the roots for `None`,
+    /// leaving out those with neither a budget nor spending.
+    ///
+    /// Each category is taken out of the ledger as it is visited, so a
+    /// category is only ever placed once.
+    fn nodes(&mut self, parent: Option<domain::RowID>) -> Vec<BudgetVariance> {
+        let categories = self.children.remove(&parent).unwrap_or_default();
+
+        categories
+            .into_iter()
+            .filter_map(|category| {
+                let children = self.nodes(Some(category.id));
+                let budget = self.budgets.remove(&category.id);
+
+                let actual = self.spent.get(&category.id).copied().unwrap_or_default()
+                    + children.iter().map(|child| child.actual).sum::<i64>();
+                let budgeted = match &budget {
+                    Some(budget) => budget.amount_for(self.period),
+                    None => children.iter().map(|child| child.budgeted).sum(),
+                };
+
+                let node = BudgetVariance {
+                    category_id: category.id,
+                    category_name: category.name,
+                    budget,
+                    budgeted,
+                    actual,
+                    children,
+                };
+
+                (node.is_budgeted() || node.actual != 0).then_some(node)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budgets::insert::tests::Result;
+
+    fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    async fn category(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+        name: &str,
+        parent_id: Option<domain::RowID>,
+    ) -> Result<database::Categories> {
+        // Mock names and slugs are a word or two, so fix them to avoid collisions
+        let mut category = database::Categories::mock();
+        category.name = name.to_string();
+        category.url_slug = None;
+        category.parent_id = parent_id;
+
+        Ok(category.insert(pool).await?)
+    }
+
+    async fn spend(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+        category_id: domain::RowID,
+        amount: i64,
+    ) -> Result<()> {
+        let mut transaction = database::Transactions::new(date(3, 10), "Payee", amount);
+        transaction.category_id = Some(category_id);
+        transaction.insert(pool).await?;
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn variance_rolls_children_up_into_parents(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let food = category(&pool, "Food", None).await?;
+        let groceries = category(&pool, "Groceries", Some(food.id)).await?;
+        let dining = category(&pool, "Dining", Some(food.id)).await?;
+        category(&pool, "Unused", Some(food.id)).await?;
+        database::Budgets::new(groceries.id, database::BudgetPeriods::Monthly, 40_000)
+            .insert(&pool)
+            .await?;
+        database::Budgets::new(dining.id, database::BudgetPeriods::Monthly, 10_000)
+            .insert(&pool)
+            .await?;
+
+        spend(&pool, groceries.id, -30_000).await?;
+        spend(&pool, dining.id, -15_000).await?;
+        spend(&pool, food.id, -1_000).await?;
+
+        let tree = database::Budgets::variance(
+            database::BudgetPeriods::Monthly,
+            date(3, 1),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(tree.len(), 1);
+        let food = &tree[0];
+        assert!(food.budget.is_none());
+        assert_eq!((food.budgeted, food.actual), (50_000, 46_000));
+        assert!(!food.is_over_budget());
+
+        let children: Vec<(&str, i64, bool)> = food
+            .children
+            .iter()
+            .map(|child| {
+                (
+                    child.category_name.as_str(),
+                    child.remaining(),
+                    child.is_over_budget(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            children,
+            [("Dining", -5_000, true), ("Groceries", 10_000, false)]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn unbudgeted_spending_is_shown_but_never_over(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let gifts = category(&pool, "Gifts", None).await?;
+        category(&pool, "Quiet", None).await?;
+        spend(&pool, gifts.id, -2_500).await?;
+
+        let tree = database::Budgets::variance(
+            database::BudgetPeriods::Annual,
+            date(3, 1),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(tree.len(), 1);
+        assert_eq!((tree[0].budgeted, tree[0].actual), (0, 2_500));
+        assert!(!tree[0].is_over_budget());
+
+        Ok(())
+    }
+}
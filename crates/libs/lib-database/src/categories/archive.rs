@@ -0,0 +1,236 @@
+//! # Category Archival
+//!
+//! Retiring a category that is no longer wanted. Archiving deactivates the
+//! category, like [`Categories::update_active_status`](database::Categories::update_active_status),
+//! but first counts the transactions classified under it since a given day,
+//! whether directly or through one of their splits. A category still in use
+//! is only archived when forced, so one that imports are still landing in is
+//! not retired by accident.
+
+use crate::query_metrics::Observe;
+
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Categories {
+    /// Counts the transactions classified under a category on or after a
+    /// day, directly or through a split. Drafts are counted, as they have been
+    /// classified even though they are not yet in balances.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category
+    /// * `since` - The first day to count transactions from
+    /// * `scope` - Whose transactions are counted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let since = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+    /// let count = Categories::count_usage_since(id, since, UserScope::All, pool).await?;
+    /// println!("Used by {count} transactions this year");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Count category usage", skip(conn), err)]
+    pub fn count_usage_since<'c, A>(
+        id: domain::RowID,
+        since: chrono::NaiveDate,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<i64>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let user_id = scope.user_id();
+            let count = sqlx::query_scalar!(
+                r#"
+                    SELECT COUNT(*) AS "count!: i64"
+                    FROM transactions
+                    WHERE transactions.transaction_date >= ?2
+                      AND (?3 IS NULL OR transactions.user_id = ?3)
+                      AND (
+                        transactions.category_id = ?1
+                        OR EXISTS (
+                            SELECT 1
+                            FROM transaction_splits
+                            WHERE transaction_splits.transaction_id = transactions.id
+                              AND transaction_splits.category_id = ?1
+                        )
+                      )
+                "#,
+                id,
+                since,
+                user_id
+            )
+            .fetch_one(&mut *conn)
+            .observe_one("categories.count_usage_since")
+            .await?;
+
+            Ok(count)
+        }
+    }
+
+    /// Archives a category by deactivating it, refusing if it has been used
+    /// since `since` unless `force` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category to archive
+    /// * `since` - The first day a use of the category counts as recent
+    /// * `force` - Archive the category even if it has been used recently
+    /// * `scope` - The user whose categories are archived
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the archived category and the number of transactions that
+    /// used it since `since`, which is only above zero when forced.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The category does not exist or is outside `scope` (`DatabaseError::NotFound`)
+    /// - The category has been used since `since` and `force` is not set
+    ///   (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let since = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+    /// let (archived, _) = Categories::archive(id, since, false, UserScope::All, pool).await?;
+    /// assert!(!archived.is_active);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Archive category", skip(conn), err)]
+    pub fn archive<'c, A>(
+        id: domain::RowID,
+        since: chrono::NaiveDate,
+        force: bool,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<(Self, i64)>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            let recent = Self::count_usage_since(id, since, scope, &mut *tx).await?;
+            if recent > 0 && !force {
+                return Err(database::DatabaseError::Validation(format!(
+                    "Category {} is used by {} transactions since {}, force the archive to retire it anyway",
+                    id, recent, since
+                )));
+            }
+
+            let archived = Self::update_active_status(id, false, scope, &mut *tx).await?;
+
+            tx.commit().await?;
+
+            tracing::info!(
+                "Archived category {} ({}) with {} recent transactions",
+                archived.id,
+                archived.code,
+                recent
+            );
+
+            Ok((archived, recent))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn archive_refuses_recently_used_categories_unless_forced(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut categories = Vec::new();
+        for code in ["GRO", "HOM", "OLD"] {
+            let category = database::CategoriesBuilder::new()
+                .with_code(code)
+                .with_name(code)
+                .with_category_type(domain::CategoryTypes::Expense)
+                .with_is_active(true)
+                .build()?
+                .insert(&pool)
+                .await?;
+            categories.push(category);
+        }
+        let [groceries, homewares, old] = categories.as_slice() else {
+            unreachable!()
+        };
+
+        let mut shop = database::Transactions::new(date(5, 3), "Supermarket", -12_000);
+        shop.category_id = Some(groceries.id);
+        let shop = shop.insert(&pool).await?;
+        database::TransactionSplits::set_for_transaction(
+            &shop,
+            &[
+                database::TransactionSplits::new(shop.id, groceries.id, -9_000, None),
+                database::TransactionSplits::new(shop.id, homewares.id, -3_000, None),
+            ],
+            &pool,
+        )
+        .await?;
+        let mut earlier = database::Transactions::new(date(1, 10), "Hardware", -5_000);
+        earlier.category_id = Some(old.id);
+        earlier.insert(&pool).await?;
+
+        let since = date(3, 1);
+        let scope = database::UserScope::All;
+        assert_eq!(
+            database::Categories::count_usage_since(homewares.id, since, scope, &pool).await?,
+            1
+        );
+        assert_eq!(
+            database::Categories::count_usage_since(old.id, since, scope, &pool).await?,
+            0
+        );
+
+        let refused = database::Categories::archive(homewares.id, since, false, scope, &pool).await;
+        assert!(matches!(
+            refused,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        let unchanged = database::Categories::find_by_id(homewares.id, scope, &pool)
+            .await?
+            .unwrap();
+        assert!(unchanged.is_active);
+
+        let (forced, recent) =
+            database::Categories::archive(homewares.id, since, true, scope, &pool).await?;
+        assert!(!forced.is_active);
+        assert_eq!(recent, 1);
+
+        let (archived, recent) =
+            database::Categories::archive(old.id, since, false, scope, &pool).await?;
+        assert!(!archived.is_active);
+        assert_eq!(recent, 0);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
-use crate::database::{self, DatabaseResult};
-use crate::domain;
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
 
 /// Delete operations for Category database records.
 ///
@@ -14,7 +15,8 @@ impl database::Categories {
     ///
     /// # Arguments
     ///
-    /// * `pool` - The database connection pool
+    /// * `scope` - The user whose categories are deleted
+    /// * `conn` - The database pool, connection or transaction to run on
     ///
     /// # Returns
     ///
@@ -23,23 +25,28 @@ impl database::Categories {
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The category no longer exists in the database
+    /// - The category no longer exists in the database, or is outside `scope`
     /// - Database connection fails
-    /// - The deletion violates foreign key constraints
+    /// - Transactions, splits, recurring transactions, account adjustments,
+    ///   budgets, goals or other categories still refer to it
+    ///   (`DatabaseError::InUse`)
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
+    /// use lib_database::{Categories, CategoriesBuilder, UserScope};
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     /// // Create and insert a category
-    /// let mut category = Category::mock();
+    /// let mut category = CategoriesBuilder::new()
+    ///     .with_code("FOOD.001")
+    ///     .with_name("Groceries")
+    ///     .with_category_type(lib_domain::CategoryTypes::Expense)
+    ///     .build()?;
     /// let inserted = category.insert(pool).await?;
     ///
     /// // Delete the category using the instance method
-    /// inserted.delete(pool).await?;
+    /// inserted.delete(UserScope::All, pool).await?;
     ///
     /// // The category is now deleted
     /// # Ok(())
@@ -47,20 +54,33 @@ impl database::Categories {
     /// ```
     #[tracing::instrument(
         name = "Delete category instance from database",
-        skip(pool),
+        skip(conn),
         fields(id = %self.id, code = %self.code),
         err
     )]
-    pub async fn delete(&self, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<()> {
+    pub async fn delete<'c, A>(&self, scope: database::UserScope, conn: A) -> DatabaseResult<()>
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite>,
+    {
+        let mut conn = conn.acquire().await?;
+
+        Self::ensure_unused(self.id, scope, &mut conn).await?;
+
+        let user_id = scope.user_id();
         let delete_query = sqlx::query!(
             r#"
                 DELETE FROM categories
-                WHERE id = ?
+                WHERE id = ?1 AND (?2 IS NULL OR user_id = ?2)
             "#,
-            self.id
+            self.id,
+            user_id
         );
 
-        let rows_affected = delete_query.execute(pool).await?.rows_affected();
+        let rows_affected = delete_query
+            .execute(&mut *conn)
+            .observe("categories.delete")
+            .await?
+            .rows_affected();
 
         if rows_affected == 0 {
             return Err(database::DatabaseError::NotFound(format!(
@@ -71,6 +91,8 @@ impl database::Categories {
 
         tracing::info!("Deleted category {} ({}) from database", self.id, self.code);
 
+        database::events::publish(database::DataChanges::CategoryDeleted(self.id));
+
         Ok(())
     }
 
@@ -82,7 +104,8 @@ impl database::Categories {
     /// # Arguments
     ///
     /// * `id` - The ID of the category to delete
-    /// * `pool` - The database connection pool
+    /// * `scope` - The user whose categories are deleted
+    /// * `conn` - The database pool, connection or transaction to run on
     ///
     /// # Returns
     ///
@@ -91,59 +114,78 @@ impl database::Categories {
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The category with the given ID does not exist
+    /// - The category with the given ID does not exist, or is outside `scope`
     /// - Database connection fails
-    /// - The deletion violates foreign key constraints
+    /// - Transactions, splits, recurring transactions, account adjustments,
+    ///   budgets, goals or other categories still refer to it
+    ///   (`DatabaseError::InUse`)
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
-    /// use personal_ledger_backend::domain::RowID;
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::RowID;
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     /// // Assuming we have a category ID
     /// let category_id = RowID::new();
     ///
     /// // Delete the category
-    /// Category::delete(category_id, pool).await?;
+    /// Categories::delete_by_id(category_id, UserScope::All, pool).await?;
     ///
     /// // Verify it's gone (this would fail)
-    /// // let result = Category::find_by_id(category_id, pool).await?;
+    /// // let result = Categories::find_by_id(category_id, pool).await?;
     /// # Ok(())
     /// # }
     /// ```
     #[tracing::instrument(
         name = "Delete category from database",
-        skip(pool),
+        skip(conn),
         fields(id = %id),
         err
     )]
-    pub async fn delete_by_id(
+    pub fn delete_by_id<'c, A>(
         id: domain::RowID,
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<()> {
-        let delete_query = sqlx::query!(
-            r#"
-                DELETE FROM categories
-                WHERE id = ?
-            "#,
-            id
-        );
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            Self::ensure_unused(id, scope, &mut conn).await?;
+
+            let user_id = scope.user_id();
+            let delete_query = sqlx::query!(
+                r#"
+                    DELETE FROM categories
+                    WHERE id = ?1 AND (?2 IS NULL OR user_id = ?2)
+                "#,
+                id,
+                user_id
+            );
 
-        let rows_affected = delete_query.execute(pool).await?.rows_affected();
+            let rows_affected = delete_query
+                .execute(&mut *conn)
+                .observe("categories.delete_by_id")
+                .await?
+                .rows_affected();
 
-        if rows_affected == 0 {
-            return Err(database::DatabaseError::NotFound(format!(
-                "Category with id {} not found",
-                id
-            )));
-        }
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Category with id {} not found",
+                    id
+                )));
+            }
 
-        tracing::info!("Deleted category {} from database", id);
+            tracing::info!("Deleted category {} from database", id);
 
-        Ok(())
+            database::events::publish(database::DataChanges::CategoryDeleted(id));
+
+            Ok(())
+        }
     }
 
     /// Deletes multiple categories from the database by their IDs.
@@ -155,7 +197,8 @@ impl database::Categories {
     /// # Arguments
     ///
     /// * `ids` - A slice of category IDs to delete
-    /// * `pool` - The database connection pool
+    /// * `scope` - The user whose categories are deleted
+    /// * `conn` - The database pool, connection or transaction to run on
     ///
     /// # Returns
     ///
@@ -164,26 +207,37 @@ impl database::Categories {
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - Any category with the given IDs does not exist
+    /// - Any category with the given IDs does not exist, or is outside `scope`
     /// - Database connection fails
     /// - Transaction fails to commit
-    /// - Any deletion violates foreign key constraints
+    /// - Any category is still referred to by transactions, splits or other
+    ///   categories (`DatabaseError::InUse`)
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
-    /// use personal_ledger_backend::domain::RowID;
+    /// use lib_database::{Categories, CategoriesBuilder, UserScope};
+    /// use lib_domain::RowID;
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     /// // Create some categories first
-    /// let categories = vec![Category::mock(), Category::mock()];
-    /// let inserted = Category::insert_many(&categories, pool).await?;
+    /// let categories = vec![
+    ///     CategoriesBuilder::new()
+    ///         .with_code("FOOD.001")
+    ///         .with_name("Groceries")
+    ///         .with_category_type(lib_domain::CategoryTypes::Expense)
+    ///         .build()?,
+    ///     CategoriesBuilder::new()
+    ///         .with_code("FOOD.002")
+    ///         .with_name("Dining Out")
+    ///         .with_category_type(lib_domain::CategoryTypes::Expense)
+    ///         .build()?,
+    /// ];
+    /// let inserted = Categories::insert_many(&categories, pool).await?;
     /// let ids: Vec<RowID> = inserted.iter().map(|c| c.id).collect();
     ///
     /// // Delete them all
-    /// Category::delete_many_by_id(&ids, pool).await?;
+    /// Categories::delete_many_by_id(&ids, UserScope::All, pool).await?;
     ///
     /// // All categories are now deleted
     /// # Ok(())
@@ -191,46 +245,66 @@ impl database::Categories {
     /// ```
     #[tracing::instrument(
         name = "Bulk delete categories from database",
-        skip(ids, pool),
+        skip(ids, conn),
         fields(count = ids.len()),
         err
     )]
-    pub async fn delete_many_by_id(
-        ids: &[domain::RowID],
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<()> {
-        if ids.is_empty() {
-            return Ok(());
-        }
-
-        // Use a transaction for atomicity
-        let mut tx = pool.begin().await?;
-
-        for &id in ids {
-            let delete_query = sqlx::query!(
-                r#"
-                    DELETE FROM categories
-                    WHERE id = ?
-                "#,
-                id
-            );
-
-            let rows_affected = delete_query.execute(&mut *tx).await?.rows_affected();
+    pub fn delete_many_by_id<'a, 'c, A>(
+        ids: &'a [domain::RowID],
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            if ids.is_empty() {
+                return Ok(());
+            }
 
-            if rows_affected == 0 {
-                return Err(database::DatabaseError::NotFound(format!(
-                    "Category with id {} not found",
-                    id
-                )));
+            // Use a transaction for atomicity
+            let mut tx = conn.begin().await?;
+
+            for &id in ids {
+                Self::ensure_unused(id, scope, &mut tx).await?;
+
+                let user_id = scope.user_id();
+                let delete_query = sqlx::query!(
+                    r#"
+                        DELETE FROM categories
+                        WHERE id = ?1 AND (?2 IS NULL OR user_id = ?2)
+                    "#,
+                    id,
+                    user_id
+                );
+
+                let rows_affected = delete_query
+                    .execute(&mut *tx)
+                    .observe("categories.delete_many_by_id")
+                    .await?
+                    .rows_affected();
+
+                if rows_affected == 0 {
+                    return Err(database::DatabaseError::NotFound(format!(
+                        "Category with id {} not found",
+                        id
+                    )));
+                }
+
+                database::events::publish(database::DataChanges::CategoryDeleted(id));
             }
-        }
 
-        // Commit the transaction
-        tx.commit().await?;
+            // Commit the transaction
+            tx.commit().await?;
 
-        tracing::info!("Successfully deleted {} categories from database", ids.len());
+            tracing::info!(
+                "Successfully deleted {} categories from database",
+                ids.len()
+            );
 
-        Ok(())
+            Ok(())
+        }
     }
 
     /// Deletes all inactive categories from the database.
@@ -240,7 +314,8 @@ impl database::Categories {
     ///
     /// # Arguments
     ///
-    /// * `pool` - The database connection pool
+    /// * `scope` - The user whose categories are deleted
+    /// * `conn` - The database pool, connection or transaction to run on
     ///
     /// # Returns
     ///
@@ -250,44 +325,70 @@ impl database::Categories {
     ///
     /// This function will return an error if:
     /// - Database connection fails
-    /// - The deletion violates foreign key constraints
+    /// - Any inactive category is still referred to by transactions, splits,
+    ///   budgets, goals or active categories (`DatabaseError::InUse`), in
+    ///   which case none are deleted
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
+    /// use lib_database::{Categories, UserScope};
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     /// // Deactivate some categories first
     /// // ... deactivation logic ...
     ///
     /// // Clean up all inactive categories
-    /// let deleted_count = Category::delete_inactive(pool).await?;
+    /// let deleted_count = Categories::delete_inactive(UserScope::All, pool).await?;
     /// println!("Deleted {} inactive categories", deleted_count);
     /// # Ok(())
     /// # }
     /// ```
-    #[tracing::instrument(
-        name = "Delete all inactive categories from database",
-        skip(pool),
-        err
-    )]
-    pub async fn delete_inactive(
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<u64> {
-        let delete_query = sqlx::query!(
+    #[tracing::instrument(name = "Delete all inactive categories from database", skip(conn), err)]
+    pub async fn delete_inactive<'c, A>(scope: database::UserScope, conn: A) -> DatabaseResult<u64>
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite>,
+    {
+        let mut tx = conn.begin().await?;
+
+        // Deepest first, so an inactive category is not refused for the
+        // inactive categories under it
+        let user_id = scope.user_id();
+        let ids = sqlx::query_scalar!(
             r#"
-                DELETE FROM categories
-                WHERE is_active = false
-            "#
-        );
+                WITH RECURSIVE depths (id, depth) AS (
+                    SELECT id, 0 FROM categories WHERE parent_id IS NULL
+                    UNION ALL
+                    SELECT categories.id, depths.depth + 1
+                    FROM categories JOIN depths ON categories.parent_id = depths.id
+                )
+                SELECT categories.id AS "id!: domain::RowID"
+                FROM categories JOIN depths ON depths.id = categories.id
+                WHERE categories.is_active = false AND (?1 IS NULL OR categories.user_id = ?1)
+                ORDER BY depths.depth DESC, categories.id
+            "#,
+            user_id
+        )
+        .fetch_all(&mut *tx)
+        .observe("categories.delete_inactive")
+        .await?;
+
+        for &id in &ids {
+            Self::ensure_unused(id, scope, &mut tx).await?;
 
-        let rows_affected = delete_query.execute(pool).await?.rows_affected();
+            sqlx::query!("DELETE FROM categories WHERE id = ?", id)
+                .execute(&mut *tx)
+                .observe("categories.delete_inactive")
+                .await?;
 
-        tracing::info!("Deleted {} inactive categories from database", rows_affected);
+            database::events::publish(database::DataChanges::CategoryDeleted(id));
+        }
 
-        Ok(rows_affected)
+        tx.commit().await?;
+
+        tracing::info!("Deleted {} inactive categories from database", ids.len());
+
+        Ok(ids.len() as u64)
     }
 
     /// Deletes a category by its code.
@@ -298,7 +399,8 @@ impl database::Categories {
     /// # Arguments
     ///
     /// * `code` - The code of the category to delete
-    /// * `pool` - The database connection pool
+    /// * `scope` - The user whose categories are deleted
+    /// * `conn` - The database pool, connection or transaction to run on
     ///
     /// # Returns
     ///
@@ -307,59 +409,397 @@ impl database::Categories {
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - No category with the given code exists
+    /// - No category with the given code exists within `scope`
     /// - Database connection fails
-    /// - The deletion violates foreign key constraints
+    /// - Transactions, splits, budgets, goals or other categories still refer
+    ///   to it (`DatabaseError::InUse`)
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
+    /// use lib_database::{Categories, UserScope};
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     /// // Delete category by code
-    /// Category::delete_by_code("FOOD.001", pool).await?;
+    /// Categories::delete_by_code("FOOD.001", UserScope::All, pool).await?;
     /// # Ok(())
     /// # }
     /// ```
     #[tracing::instrument(
         name = "Delete category by code from database",
-        skip(pool),
+        skip(conn),
         fields(code = %code),
         err
     )]
-    pub async fn delete_by_code(
+    pub async fn delete_by_code<'c, A>(
         code: &str,
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<()> {
-        let delete_query = sqlx::query!(
+        scope: database::UserScope,
+        conn: A,
+    ) -> DatabaseResult<()>
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite>,
+    {
+        let mut tx = conn.begin().await?;
+
+        // Codes are unique per user, so unscoped there may be one for each
+        let user_id = scope.user_id();
+        let ids = sqlx::query_scalar!(
             r#"
-                DELETE FROM categories
-                WHERE code = ?
+                SELECT id AS "id!: domain::RowID"
+                FROM categories
+                WHERE code = ?1 AND (?2 IS NULL OR user_id = ?2)
             "#,
-            code
-        );
-
-        let rows_affected = delete_query.execute(pool).await?.rows_affected();
+            code,
+            user_id
+        )
+        .fetch_all(&mut *tx)
+        .observe("categories.delete_by_code")
+        .await?;
 
-        if rows_affected == 0 {
+        if ids.is_empty() {
             return Err(database::DatabaseError::NotFound(format!(
                 "Category with code '{}' not found",
                 code
             )));
         }
 
+        for &id in &ids {
+            Self::ensure_unused(id, scope, &mut tx).await?;
+
+            sqlx::query!("DELETE FROM categories WHERE id = ?", id)
+                .execute(&mut *tx)
+                .observe("categories.delete_by_code")
+                .await?;
+
+            database::events::publish(database::DataChanges::CategoryDeleted(id));
+        }
+
+        tx.commit().await?;
+
         tracing::info!("Deleted category with code '{}' from database", code);
 
         Ok(())
     }
+
+    /// Deletes a category for good, first moving everything classified under
+    /// it to another category.
+    ///
+    /// Transactions, transaction splits, journal splits, recurring
+    /// transactions, account adjustments and the categories placed under it
+    /// are all moved to `target_id`, so nothing is left without a category.
+    /// Budgets and goals for the deleted category are deleted with it, as the
+    /// target may have its own.
+    ///
+    /// Transactions are moved with
+    /// [`Transactions::reassign`](database::Transactions::reassign), so only
+    /// those in `scope` are moved, none in a closed period, and each move is
+    /// published. The categories moved from under it are published as
+    /// updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category to delete
+    /// * `target_id` - The ID of the category to move its uses to
+    /// * `scope` - The user whose categories are deleted and reassigned to
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of rows moved to the target category.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - Either category does not exist, is outside `scope` or the target is
+    ///   soft-deleted (`DatabaseError::NotFound`)
+    /// - The target is the category itself or placed under it
+    ///   (`DatabaseError::Validation`)
+    /// - A transaction or split to move is dated in a closed period
+    ///   (`DatabaseError::PeriodClosed`)
+    /// - Transactions of users outside `scope` still refer to it
+    ///   (`DatabaseError::InUse`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, dining: RowID, food: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let moved = Categories::delete_with_reassign(dining, food, UserScope::All, pool).await?;
+    /// println!("Moved {moved} rows to the food category");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Delete category with reassign", skip(conn), err)]
+    pub fn delete_with_reassign<'c, A>(
+        id: domain::RowID,
+        target_id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<u64>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            if id == target_id {
+                return Err(database::DatabaseError::Validation(format!(
+                    "Category {} cannot be reassigned to itself",
+                    id
+                )));
+            }
+
+            Self::fetch_including_deleted(id, &mut tx)
+                .await?
+                .filter(|category| scope.includes(category.user_id))
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!("Category with id {} not found", id))
+                })?;
+            Self::fetch_including_deleted(target_id, &mut tx)
+                .await?
+                .filter(|category| {
+                    category.deleted_on.is_none() && scope.includes(category.user_id)
+                })
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Category with id {} not found",
+                        target_id
+                    ))
+                })?;
+
+            let under = sqlx::query_scalar!(
+                r#"
+                    WITH RECURSIVE descendants (id) AS (
+                        SELECT id FROM categories WHERE parent_id = ?1
+                        UNION ALL
+                        SELECT categories.id
+                        FROM categories
+                        JOIN descendants ON categories.parent_id = descendants.id
+                    )
+                    SELECT COUNT(*) AS "count!: i64" FROM descendants WHERE id = ?2
+                "#,
+                id,
+                target_id
+            )
+            .fetch_one(&mut *tx)
+            .observe_one("categories.delete_with_reassign")
+            .await?;
+
+            if under > 0 {
+                return Err(database::DatabaseError::Validation(format!(
+                    "Category {} is placed under category {}, reassign to a category outside it",
+                    target_id, id
+                )));
+            }
+
+            let now = chrono::Utc::now();
+            let user_id = scope.user_id();
+
+            // Moved as any edit is, so closed periods are kept and the
+            // changes published
+            let filter = database::TransactionFilter {
+                category_id: Some(id),
+                ..Default::default()
+            };
+            let mut reassigned =
+                database::Transactions::reassign(&filter, target_id, scope, &mut *tx).await?;
+
+            // Splits are locked with the transaction or journal entry they
+            // belong to
+            let earliest = sqlx::query_scalar!(
+                r#"
+                    SELECT MIN(split_date) AS "earliest?: chrono::NaiveDate"
+                    FROM (
+                        SELECT transactions.transaction_date AS split_date
+                        FROM transaction_splits
+                        JOIN transactions ON transactions.id = transaction_splits.transaction_id
+                        WHERE transaction_splits.category_id = ?1
+                            AND NOT transactions.is_draft
+                            AND (?2 IS NULL OR transactions.user_id = ?2)
+                        UNION ALL
+                        SELECT journal_entries.entry_date AS split_date
+                        FROM journal_splits
+                        JOIN journal_entries ON journal_entries.id = journal_splits.entry_id
+                        WHERE journal_splits.category_id = ?1
+                    )
+                "#,
+                id,
+                user_id
+            )
+            .fetch_one(&mut *tx)
+            .observe_one("categories.delete_with_reassign")
+            .await?;
+            if let Some(earliest) = earliest {
+                database::PeriodClosings::ensure_open(earliest, &mut tx).await?;
+            }
+
+            reassigned += sqlx::query!(
+                r#"
+                    UPDATE transaction_splits
+                    SET category_id = ?2
+                    WHERE category_id = ?1
+                        AND transaction_id IN (
+                            SELECT id FROM transactions WHERE ?3 IS NULL OR user_id = ?3
+                        )
+                "#,
+                id,
+                target_id,
+                user_id
+            )
+            .execute(&mut *tx)
+            .observe("categories.delete_with_reassign")
+            .await?
+            .rows_affected();
+
+            reassigned += sqlx::query!(
+                "UPDATE journal_splits SET category_id = ?2 WHERE category_id = ?1",
+                id,
+                target_id
+            )
+            .execute(&mut *tx)
+            .observe("categories.delete_with_reassign")
+            .await?
+            .rows_affected();
+
+            reassigned += sqlx::query!(
+                "UPDATE recurring_transactions SET category_id = ?2, updated_on = ?3 WHERE category_id = ?1",
+                id,
+                target_id,
+                now
+            )
+            .execute(&mut *tx)
+            .observe("categories.delete_with_reassign")
+            .await?
+            .rows_affected();
+
+            reassigned += sqlx::query!(
+                "UPDATE accounts SET adjustments_category_id = ?2, updated_on = ?3 WHERE adjustments_category_id = ?1",
+                id,
+                target_id,
+                now
+            )
+            .execute(&mut *tx)
+            .observe("categories.delete_with_reassign")
+            .await?
+            .rows_affected();
+
+            let children = sqlx::query_scalar!(
+                r#"
+                    UPDATE categories
+                    SET parent_id = ?2, updated_on = ?3, version = version + 1
+                    WHERE parent_id = ?1 AND (?4 IS NULL OR user_id = ?4)
+                    RETURNING id AS "id!: domain::RowID"
+                "#,
+                id,
+                target_id,
+                now,
+                user_id
+            )
+            .fetch_all(&mut *tx)
+            .observe("categories.delete_with_reassign")
+            .await?;
+            reassigned += children.len() as u64;
+            for child in children {
+                if let Some(child) = Self::fetch_including_deleted(child, &mut tx).await? {
+                    database::events::publish(database::DataChanges::CategoryUpdated(child));
+                }
+            }
+
+            // The target may have budgets and goals of its own
+            sqlx::query!("DELETE FROM budgets WHERE category_id = ?", id)
+                .execute(&mut *tx)
+                .observe("categories.delete_with_reassign")
+                .await?;
+            sqlx::query!("DELETE FROM goals WHERE category_id = ?", id)
+                .execute(&mut *tx)
+                .observe("categories.delete_with_reassign")
+                .await?;
+
+            Self::delete_by_id(id, scope, &mut *tx).await?;
+
+            tx.commit().await?;
+
+            tracing::info!(
+                "Deleted category {} after reassigning {} rows to {}",
+                id,
+                reassigned,
+                target_id
+            );
+
+            Ok(reassigned)
+        }
+    }
+
+    /// Refuses to delete a category in `scope` that anything still refers to,
+    /// whether or not the connection enforces foreign keys, rather than have
+    /// its budgets and goals deleted or its recurring transactions and
+    /// account adjustments left without a category. A category outside `scope` is left for the
+    /// delete to report as not found.
+    pub(super) async fn ensure_unused(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<()> {
+        let user_id = scope.user_id();
+        let usage = sqlx::query!(
+            r#"
+                SELECT
+                    (SELECT COUNT(*) FROM transactions WHERE category_id = ?1)          AS "transactions!: i64",
+                    (SELECT COUNT(*) FROM transaction_splits WHERE category_id = ?1)    AS "transaction_splits!: i64",
+                    (SELECT COUNT(*) FROM journal_splits WHERE category_id = ?1)        AS "journal_splits!: i64",
+                    (SELECT COUNT(*) FROM recurring_transactions WHERE category_id = ?1) AS "recurring_transactions!: i64",
+                    (SELECT COUNT(*) FROM accounts WHERE adjustments_category_id = ?1)  AS "accounts!: i64",
+                    (SELECT COUNT(*) FROM budgets WHERE category_id = ?1)               AS "budgets!: i64",
+                    (SELECT COUNT(*) FROM goals WHERE category_id = ?1)                 AS "goals!: i64",
+                    (SELECT COUNT(*) FROM categories AS children WHERE parent_id = ?1)  AS "categories!: i64"
+                FROM categories
+                WHERE id = ?1 AND (?2 IS NULL OR user_id = ?2)
+            "#,
+            id,
+            user_id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("categories.ensure_unused")
+        .await?;
+
+        let Some(usage) = usage else {
+            return Ok(());
+        };
+
+        let uses: Vec<String> = [
+            (usage.transactions, "transactions"),
+            (usage.transaction_splits, "transaction splits"),
+            (usage.journal_splits, "journal splits"),
+            (usage.recurring_transactions, "recurring transactions"),
+            (usage.accounts, "account adjustments"),
+            (usage.budgets, "budgets"),
+            (usage.goals, "goals"),
+            (usage.categories, "categories under it"),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, uses)| format!("{count} {uses}"))
+        .collect();
+
+        if uses.is_empty() {
+            return Ok(());
+        }
+
+        Err(database::DatabaseError::InUse(format!(
+            "Category {} is used by {}, delete it with a category to reassign them to",
+            id,
+            uses.join(", ")
+        )))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{RowID, UrlSlug};
+    use lib_domain::{RowID, UrlSlug};
     use sqlx::SqlitePool;
 
     /// Helper function to create a test category
@@ -394,7 +834,8 @@ mod tests {
         assert!(insert_result.is_ok()); // Should succeed (update existing)
 
         // Delete the category
-        let result = database::Categories::delete_by_id(category.id, &pool).await;
+        let result =
+            database::Categories::delete_by_id(category.id, database::UserScope::All, &pool).await;
         assert!(result.is_ok());
 
         // Verify it's gone by trying to insert with same ID (should succeed as new insert)
@@ -406,11 +847,17 @@ mod tests {
     async fn test_delete_nonexistent_category(pool: SqlitePool) {
         // Try to delete a category that doesn't exist
         let fake_id = RowID::new();
-        let result = database::Categories::delete_by_id(fake_id, &pool).await;
+        let result =
+            database::Categories::delete_by_id(fake_id, database::UserScope::All, &pool).await;
 
         // Should return NotFound error
         assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
-        assert!(result.unwrap_err().to_string().contains(&fake_id.to_string()));
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains(&fake_id.to_string())
+        );
     }
 
     #[sqlx::test]
@@ -420,7 +867,8 @@ mod tests {
         let ids: Vec<RowID> = categories.iter().map(|c| c.id).collect();
 
         // Delete them all
-        let result = database::Categories::delete_many_by_id(&ids, &pool).await;
+        let result =
+            database::Categories::delete_many_by_id(&ids, database::UserScope::All, &pool).await;
         assert!(result.is_ok());
 
         // Verify they're all gone by trying to re-insert them
@@ -441,9 +889,15 @@ mod tests {
         ids.push(fake_id);
 
         // Try to delete - should fail due to nonexistent category
-        let result = database::Categories::delete_many_by_id(&ids, &pool).await;
+        let result =
+            database::Categories::delete_many_by_id(&ids, database::UserScope::All, &pool).await;
         assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
-        assert!(result.unwrap_err().to_string().contains(&fake_id.to_string()));
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains(&fake_id.to_string())
+        );
 
         // The real category should still exist (transaction rolled back)
         // Verify by trying to insert duplicate (should fail as update)
@@ -458,7 +912,8 @@ mod tests {
     #[sqlx::test]
     async fn test_delete_many_empty_list(pool: SqlitePool) {
         // Delete with empty list should succeed
-        let result = database::Categories::delete_many_by_id(&[], &pool).await;
+        let result =
+            database::Categories::delete_many_by_id(&[], database::UserScope::All, &pool).await;
         assert!(result.is_ok());
     }
 
@@ -473,7 +928,9 @@ mod tests {
             category.description = Some(format!("Active description {}", i));
             category.url_slug = Some(UrlSlug::from(format!("active-category-{}", i)));
             category.is_active = true; // Ensure active
-            database::Categories::insert(&category, &pool).await.unwrap();
+            database::Categories::insert(&category, &pool)
+                .await
+                .unwrap();
             active_categories.push(category);
         }
 
@@ -486,12 +943,16 @@ mod tests {
             category.description = Some(format!("Inactive description {}", i));
             category.url_slug = Some(UrlSlug::from(format!("inactive-category-{}", i)));
             category.is_active = false; // Inactive
-            database::Categories::insert(&category, &pool).await.unwrap();
+            database::Categories::insert(&category, &pool)
+                .await
+                .unwrap();
             inactive_categories.push(category);
         }
 
         // Delete inactive categories
-        let deleted_count = database::Categories::delete_inactive(&pool).await.unwrap();
+        let deleted_count = database::Categories::delete_inactive(database::UserScope::All, &pool)
+            .await
+            .unwrap();
         assert_eq!(deleted_count, 3);
 
         // Verify inactive categories are gone by trying to re-insert them
@@ -518,12 +979,16 @@ mod tests {
         for _ in 0..2 {
             let mut category = database::Categories::mock();
             category.is_active = true; // Ensure category is active
-            database::Categories::insert(&category, &pool).await.unwrap();
+            database::Categories::insert(&category, &pool)
+                .await
+                .unwrap();
             active_categories.push(category);
         }
 
         // Delete inactive categories - should delete 0
-        let deleted_count = database::Categories::delete_inactive(&pool).await.unwrap();
+        let deleted_count = database::Categories::delete_inactive(database::UserScope::All, &pool)
+            .await
+            .unwrap();
         assert_eq!(deleted_count, 0);
 
         // Active categories should still exist
@@ -536,7 +1001,9 @@ mod tests {
         let category = create_test_category(&pool).await;
 
         // Delete by code
-        let result = database::Categories::delete_by_code(&category.code, &pool).await;
+        let result =
+            database::Categories::delete_by_code(&category.code, database::UserScope::All, &pool)
+                .await;
         assert!(result.is_ok());
 
         // Verify it's gone by trying to re-insert
@@ -548,7 +1015,8 @@ mod tests {
     async fn test_delete_by_code_nonexistent_category(pool: SqlitePool) {
         // Try to delete by a code that doesn't exist
         let fake_code = "NONEXISTENT.CODE";
-        let result = database::Categories::delete_by_code(fake_code, &pool).await;
+        let result =
+            database::Categories::delete_by_code(fake_code, database::UserScope::All, &pool).await;
 
         // Should return NotFound error
         assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
@@ -562,15 +1030,21 @@ mod tests {
         category.code = category.code.to_uppercase();
 
         // Update it in the database
-        database::Categories::update(&category, &pool).await.unwrap();
+        database::Categories::update(&category, database::UserScope::All, &pool)
+            .await
+            .unwrap();
 
         // Try to delete with lowercase version - should fail
         let lowercase_code = category.code.to_lowercase();
-        let result = database::Categories::delete_by_code(&lowercase_code, &pool).await;
+        let result =
+            database::Categories::delete_by_code(&lowercase_code, database::UserScope::All, &pool)
+                .await;
         assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
 
         // Delete with correct case should work
-        let result = database::Categories::delete_by_code(&category.code, &pool).await;
+        let result =
+            database::Categories::delete_by_code(&category.code, database::UserScope::All, &pool)
+                .await;
         assert!(result.is_ok());
     }
 
@@ -580,11 +1054,13 @@ mod tests {
         let category = create_test_category(&pool).await;
 
         // Delete using the instance method
-        let result = category.delete(&pool).await;
+        let result = category.delete(database::UserScope::All, &pool).await;
         assert!(result.is_ok());
 
         // Verify it's gone by trying to find it
-        let found = database::Categories::find_by_id(category.id, &pool).await.unwrap();
+        let found = database::Categories::find_by_id(category.id, database::UserScope::All, &pool)
+            .await
+            .unwrap();
         assert!(found.is_none());
     }
 
@@ -594,11 +1070,16 @@ mod tests {
         let category = database::Categories::mock();
 
         // Try to delete the non-existent category
-        let result = category.delete(&pool).await;
+        let result = category.delete(database::UserScope::All, &pool).await;
 
         // Should return NotFound error
-        assert!(matches!(result, Err(crate::database::DatabaseError::NotFound(_))));
-        assert!(result.unwrap_err().to_string().contains(&category.id.to_string()));
+        assert!(matches!(result, Err(crate::DatabaseError::NotFound(_))));
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains(&category.id.to_string())
+        );
     }
 
     #[sqlx::test]
@@ -608,14 +1089,255 @@ mod tests {
 
         // Update the category
         category.name = "Updated Name".to_string();
-        let updated = database::Categories::update(&category, &pool).await.unwrap();
+        let updated = database::Categories::update(&category, database::UserScope::All, &pool)
+            .await
+            .unwrap();
 
         // Delete using the updated instance
-        let result = updated.delete(&pool).await;
+        let result = updated.delete(database::UserScope::All, &pool).await;
         assert!(result.is_ok());
 
         // Verify it's gone
-        let found = database::Categories::find_by_id(category.id, &pool).await.unwrap();
+        let found = database::Categories::find_by_id(category.id, database::UserScope::All, &pool)
+            .await
+            .unwrap();
         assert!(found.is_none());
     }
-}
\ No newline at end of file
+    /// Helper function to insert a category with a code and optional parent
+    async fn insert_category(
+        code: &str,
+        parent_id: Option<RowID>,
+        pool: &SqlitePool,
+    ) -> database::Categories {
+        let mut category = database::Categories::mock();
+        category.code = code.to_string();
+        category.url_slug = None;
+        category.parent_id = parent_id;
+        category.insert(pool).await.unwrap()
+    }
+
+    /// Helper function to insert a transaction classified under a category
+    async fn insert_transaction(category_id: RowID, pool: &SqlitePool) -> database::Transactions {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+        let mut transaction = database::Transactions::new(date, "Noodle Bar", -2_400);
+        transaction.category_id = Some(category_id);
+        transaction.insert(pool).await.unwrap()
+    }
+
+    #[sqlx::test]
+    async fn test_delete_category_in_use_is_refused(pool: SqlitePool) {
+        let category = insert_category("DINING", None, &pool).await;
+        let transaction = insert_transaction(category.id, &pool).await;
+
+        let result =
+            database::Categories::delete_by_id(category.id, database::UserScope::All, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::InUse(_))));
+        assert!(result.unwrap_err().to_string().contains("1 transactions"));
+
+        let result = database::Categories::delete_many_by_id(
+            &[category.id],
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+        assert!(matches!(result, Err(database::DatabaseError::InUse(_))));
+
+        let result =
+            database::Categories::delete_by_code("DINING", database::UserScope::All, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::InUse(_))));
+
+        let kept =
+            database::Transactions::find_by_id(transaction.id, database::UserScope::All, &pool)
+                .await
+                .unwrap();
+        assert_eq!(kept.unwrap().category_id, Some(category.id));
+    }
+
+    #[sqlx::test]
+    async fn test_delete_inactive_refuses_categories_in_use(pool: SqlitePool) {
+        let bus = database::EventBus::new();
+        let mut events = bus.subscribe();
+        let mut dining = insert_category("DINING", None, &pool).await;
+        let mut takeaway = insert_category("TAKEAWAY", Some(dining.id), &pool).await;
+        let mut fuel = insert_category("FUEL", None, &pool).await;
+        for category in [&mut dining, &mut takeaway, &mut fuel] {
+            category.is_active = false;
+            category
+                .update(database::UserScope::All, &pool)
+                .await
+                .unwrap();
+        }
+        insert_transaction(fuel.id, &pool).await;
+
+        let result = database::with_transaction(&pool, &bus, false, async |conn| {
+            database::Categories::delete_inactive(database::UserScope::All, conn).await
+        })
+        .await;
+        assert!(matches!(result, Err(database::DatabaseError::InUse(_))));
+        assert!(events.try_recv().is_err());
+
+        database::Categories::delete_with_reassign(
+            fuel.id,
+            insert_category("CAR", None, &pool).await.id,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
+        // The category under another inactive one goes first
+        let deleted = database::with_transaction(&pool, &bus, false, async |conn| {
+            database::Categories::delete_inactive(database::UserScope::All, conn).await
+        })
+        .await
+        .unwrap();
+        assert_eq!(deleted, 2);
+
+        let published: Vec<_> = std::iter::from_fn(|| events.try_recv().ok())
+            .map(|event| event.change)
+            .collect();
+        assert_eq!(
+            published,
+            [
+                database::DataChanges::CategoryDeleted(takeaway.id),
+                database::DataChanges::CategoryDeleted(dining.id),
+            ]
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_delete_with_reassign_moves_uses_to_target(pool: SqlitePool) {
+        let food = insert_category("FOOD", None, &pool).await;
+        let dining = insert_category("DINING", None, &pool).await;
+        let takeaway = insert_category("TAKEAWAY", Some(dining.id), &pool).await;
+        let transaction = insert_transaction(dining.id, &pool).await;
+        let splits = [
+            database::TransactionSplits::new(transaction.id, dining.id, -1_400, None),
+            database::TransactionSplits::new(transaction.id, food.id, -1_000, None),
+        ];
+        database::TransactionSplits::set_for_transaction(&transaction, &splits, &pool)
+            .await
+            .unwrap();
+        let scope = database::UserScope::All;
+
+        let result =
+            database::Categories::delete_with_reassign(dining.id, dining.id, scope, &pool).await;
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        let result =
+            database::Categories::delete_with_reassign(dining.id, takeaway.id, scope, &pool).await;
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        let result =
+            database::Categories::delete_with_reassign(dining.id, RowID::new(), scope, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        // The transaction, its dining split and the takeaway category
+        let reassigned =
+            database::Categories::delete_with_reassign(dining.id, food.id, scope, &pool)
+                .await
+                .unwrap();
+        assert_eq!(reassigned, 3);
+
+        assert!(
+            database::Categories::find_by_id(dining.id, scope, &pool)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let moved = database::Transactions::find_by_id(transaction.id, scope, &pool)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(moved.category_id, Some(food.id));
+        let splits = database::TransactionSplits::find_for_transaction(transaction.id, &pool)
+            .await
+            .unwrap();
+        assert!(splits.iter().all(|split| split.category_id == food.id));
+        let takeaway = database::Categories::find_by_id(takeaway.id, scope, &pool)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(takeaway.parent_id, Some(food.id));
+    }
+
+    #[sqlx::test]
+    async fn test_delete_with_reassign_refuses_a_closed_period(pool: SqlitePool) {
+        let food = insert_category("FOOD", None, &pool).await;
+        let dining = insert_category("DINING", None, &pool).await;
+        let snacks = insert_category("SNACKS", None, &pool).await;
+        insert_transaction(dining.id, &pool).await;
+        let split = insert_transaction(food.id, &pool).await;
+        let splits = [
+            database::TransactionSplits::new(split.id, food.id, -1_400, None),
+            database::TransactionSplits::new(split.id, snacks.id, -1_000, None),
+        ];
+        database::TransactionSplits::set_for_transaction(&split, &splits, &pool)
+            .await
+            .unwrap();
+        let closed_through = chrono::NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
+        database::PeriodClosings::close(closed_through, None, &pool)
+            .await
+            .unwrap();
+        let scope = database::UserScope::All;
+
+        // Whether used by the transaction itself or one of its splits
+        for category in [&dining, &snacks] {
+            let result =
+                database::Categories::delete_with_reassign(category.id, food.id, scope, &pool)
+                    .await;
+            assert!(matches!(
+                result,
+                Err(database::DatabaseError::PeriodClosed(_))
+            ));
+            assert!(
+                database::Categories::find_by_id(category.id, scope, &pool)
+                    .await
+                    .unwrap()
+                    .is_some()
+            );
+        }
+        let splits = database::TransactionSplits::find_for_transaction(split.id, &pool)
+            .await
+            .unwrap();
+        assert!(splits.iter().any(|split| split.category_id == snacks.id));
+    }
+
+    #[sqlx::test]
+    async fn test_delete_category_used_by_a_recurring_transaction(pool: SqlitePool) {
+        let food = insert_category("FOOD", None, &pool).await;
+        let dining = insert_category("DINING", None, &pool).await;
+        let mut template = database::RecurringTransactions::new(
+            "Meal Kit",
+            -8_900,
+            database::RecurringFrequencies::Weekly,
+            chrono::NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+        );
+        template.category_id = Some(dining.id);
+        let template = template.insert(&pool).await.unwrap();
+        let scope = database::UserScope::All;
+
+        let result = database::Categories::delete_by_id(dining.id, scope, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::InUse(_))));
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("1 recurring transactions")
+        );
+
+        let reassigned =
+            database::Categories::delete_with_reassign(dining.id, food.id, scope, &pool)
+                .await
+                .unwrap();
+        assert_eq!(reassigned, 1);
+        let moved = database::RecurringTransactions::find_by_id(template.id, &pool)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(moved.category_id, Some(food.id));
+    }
+}
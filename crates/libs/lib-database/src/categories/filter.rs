@@ -0,0 +1,298 @@
+//! # Category Filters
+//!
+//! Builds the SQL for [`Categories::find_with_filters`](crate::Categories::find_with_filters)
+//! with `sqlx::QueryBuilder`, so only the conditions that are set end up in the
+//! query. Values are always bound as parameters. Sort columns are chosen from
+//! [`CategorySortColumns`], never taken from the caller as text, so nothing
+//! the caller sends is written into the SQL itself.
+
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Selects and orders the categories listed by
+/// [`Categories::find_with_filters`](crate::Categories::find_with_filters).
+/// Every field that is set must match, the default lists every category
+/// newest first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CategoryFilter {
+    /// Category type to match.
+    pub category_type: Option<domain::CategoryTypes>,
+
+    /// Active status to match.
+    pub is_active: Option<bool>,
+
+    /// First day of creation to include.
+    pub created_from: Option<chrono::NaiveDate>,
+
+    /// Last day of creation to include.
+    pub created_to: Option<chrono::NaiveDate>,
+
+    /// Text the name must contain, ignoring ASCII case.
+    pub name_contains: Option<String>,
+
+    /// Column to sort by.
+    pub sort_by: CategorySortColumns,
+
+    /// Whether to sort in descending order, defaulting to descending for
+    /// dates and ascending for text.
+    pub sort_desc: Option<bool>,
+
+    /// Whether to include soft-deleted categories.
+    pub include_deleted: bool,
+}
+
+/// The columns categories can be sorted by. Ties are broken by ID, in the
+/// same direction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CategorySortColumns {
+    /// When the category was created.
+    #[default]
+    CreatedOn,
+    /// When the category was last updated.
+    UpdatedOn,
+    /// The category name.
+    Name,
+    /// The category code.
+    Code,
+}
+
+impl CategorySortColumns {
+    /// Returns the column name, as accepted by `from_str`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CategorySortColumns::CreatedOn => "created_on",
+            CategorySortColumns::UpdatedOn => "updated_on",
+            CategorySortColumns::Name => "name",
+            CategorySortColumns::Code => "code",
+        }
+    }
+
+    /// Whether the column is sorted in descending order unless asked
+    /// otherwise.
+    fn descending_by_default(&self) -> bool {
+        matches!(
+            self,
+            CategorySortColumns::CreatedOn | CategorySortColumns::UpdatedOn
+        )
+    }
+}
+
+impl std::fmt::Display for CategorySortColumns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for CategorySortColumns {
+    type Err = DatabaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "created_on" => Ok(CategorySortColumns::CreatedOn),
+            "updated_on" => Ok(CategorySortColumns::UpdatedOn),
+            "name" => Ok(CategorySortColumns::Name),
+            "code" => Ok(CategorySortColumns::Code),
+            _ => Err(DatabaseError::Validation(format!(
+                "Cannot sort categories by '{}', expected created_on, updated_on, name or code",
+                s
+            ))),
+        }
+    }
+}
+
+impl CategoryFilter {
+    /// Checks the filter can match any category.
+    pub(super) fn validate(&self) -> DatabaseResult<()> {
+        if let (Some(from), Some(to)) = (self.created_from, self.created_to)
+            && from > to
+        {
+            return Err(DatabaseError::Validation(format!(
+                "Created from date {} is after created to date {}",
+                from, to
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Whether the filter sorts in descending order.
+    pub fn is_descending(&self) -> bool {
+        self.sort_desc
+            .unwrap_or_else(|| self.sort_by.descending_by_default())
+    }
+
+    /// Whether a category matches the filter, for categories read from
+    /// history rather than queried.
+    pub fn matches(&self, category: &database::Categories) -> bool {
+        let created = category.created_on.date_naive();
+
+        self.category_type
+            .as_ref()
+            .is_none_or(|category_type| &category.category_type == category_type)
+            && self
+                .is_active
+                .is_none_or(|is_active| category.is_active == is_active)
+            && self.created_from.is_none_or(|from| created >= from)
+            && self.created_to.is_none_or(|to| created <= to)
+            && self.name_contains.as_deref().is_none_or(|text| {
+                category
+                    .name
+                    .to_ascii_lowercase()
+                    .contains(&text.to_ascii_lowercase())
+            })
+    }
+
+    /// Appends the `WHERE` clause of the filter, limited to the categories in
+    /// `scope`, and of the page after `after` when given, to a query over the
+    /// categories table.
+    pub(super) fn push_where(
+        &self,
+        query: &mut sqlx::QueryBuilder<'_, sqlx::Sqlite>,
+        scope: database::UserScope,
+        after: Option<database::PageCursor>,
+    ) {
+        query.push(" WHERE 1 = 1");
+
+        if !self.include_deleted {
+            query.push(" AND deleted_on IS NULL");
+        }
+
+        if let Some(user_id) = scope.user_id() {
+            query.push(" AND user_id = ").push_bind(user_id);
+        }
+
+        if let Some(category_type) = &self.category_type {
+            query
+                .push(" AND category_type = ")
+                .push_bind(category_type.clone());
+        }
+
+        if let Some(is_active) = self.is_active {
+            query.push(" AND is_active = ").push_bind(is_active);
+        }
+
+        if let Some(from) = self.created_from {
+            query.push(" AND date(created_on) >= ").push_bind(from);
+        }
+
+        if let Some(to) = self.created_to {
+            query.push(" AND date(created_on) <= ").push_bind(to);
+        }
+
+        if let Some(text) = &self.name_contains {
+            query
+                .push(r" AND name LIKE ")
+                .push_bind(format!("%{}%", escape_like(text)))
+                .push(r" ESCAPE '\'");
+        }
+
+        if let Some(after) = after {
+            let column = self.sort_by.as_str();
+            let comparison = if self.is_descending() { " < " } else { " > " };
+
+            query.push(format_args!(" AND ({column}, id){comparison}("));
+            match self.sort_by {
+                CategorySortColumns::CreatedOn => {
+                    query.push_bind(after.created_on);
+                }
+                // The cursor only holds the creation time, so the sort value
+                // is read back from the last row of the previous page. If that
+                // row has since been deleted the list ends there.
+                _ => {
+                    query
+                        .push(format_args!("(SELECT {column} FROM categories WHERE id = "))
+                        .push_bind(after.id)
+                        .push(")");
+                }
+            }
+            query.push(", ").push_bind(after.id).push(")");
+        }
+    }
+
+    /// Appends the `ORDER BY` clause of the filter.
+    pub(super) fn push_order_by(&self, query: &mut sqlx::QueryBuilder<'_, sqlx::Sqlite>) {
+        let direction = if self.is_descending() { "DESC" } else { "ASC" };
+
+        query.push(format_args!(
+            " ORDER BY {} {direction}, id {direction}",
+            self.sort_by.as_str()
+        ));
+    }
+}
+
+/// Escapes the `LIKE` wildcards in text to be matched literally, using `\`
+/// as the escape character.
+fn escape_like(text: &str) -> String {
+    text.replace('\\', r"\\")
+        .replace('%', r"\%")
+        .replace('_', r"\_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_columns_parse_and_reject_unknown() {
+        assert_eq!(
+            " Name ".parse::<CategorySortColumns>().unwrap(),
+            CategorySortColumns::Name
+        );
+        assert_eq!(
+            "updated_on".parse::<CategorySortColumns>().unwrap(),
+            CategorySortColumns::UpdatedOn
+        );
+
+        for column in ["", "id; DROP TABLE categories", "description"] {
+            assert!(matches!(
+                column.parse::<CategorySortColumns>(),
+                Err(DatabaseError::Validation(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn sort_direction_defaults_by_column() {
+        let mut filter = CategoryFilter::default();
+        assert!(filter.is_descending());
+
+        filter.sort_by = CategorySortColumns::Name;
+        assert!(!filter.is_descending());
+
+        filter.sort_desc = Some(true);
+        assert!(filter.is_descending());
+    }
+
+    #[test]
+    fn push_where_includes_only_set_conditions() {
+        let filter = CategoryFilter {
+            is_active: Some(true),
+            name_contains: Some("50%_off".to_string()),
+            sort_by: CategorySortColumns::Name,
+            ..Default::default()
+        };
+        let mut query = sqlx::QueryBuilder::new("SELECT id FROM categories");
+        filter.push_where(&mut query, database::UserScope::All, None);
+        filter.push_order_by(&mut query);
+
+        assert_eq!(
+            query.sql(),
+            r"SELECT id FROM categories WHERE 1 = 1 AND deleted_on IS NULL AND is_active = ? AND name LIKE ? ESCAPE '\' ORDER BY name ASC, id ASC"
+        );
+        assert_eq!(escape_like("50%_off\\"), r"50\%\_off\\");
+    }
+
+    #[test]
+    fn validate_rejects_reversed_dates() {
+        let filter = CategoryFilter {
+            created_from: chrono::NaiveDate::from_ymd_opt(2025, 3, 2),
+            created_to: chrono::NaiveDate::from_ymd_opt(2025, 3, 1),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            filter.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+}
@@ -1,5 +1,7 @@
-use crate::database::{self, DatabaseResult};
-use crate::domain;
+use crate::query_metrics::{Observe, StreamObservation};
+use crate::{self as database, DatabaseResult};
+use futures_util::stream::{BoxStream, StreamExt, TryStreamExt};
+use lib_domain as domain;
 
 /// Read operations for Category database records.
 ///
@@ -13,24 +15,24 @@ impl database::Categories {
     /// # Arguments
     ///
     /// * `id` - The ID of the category to find
+    /// * `scope` - The user whose categories are searched
     /// * `pool` - The database connection pool
     ///
     /// # Returns
     ///
-    /// Returns `Some(Category)` if the category exists, or `None` if not found.
+    /// Returns `Some(Categories)` if the category exists, or `None` if not found.
     /// Returns a `DatabaseError` if the query fails.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
-    /// use personal_ledger_backend::domain::RowID;
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::RowID;
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     /// let category_id = RowID::new();
     ///
-    /// if let Some(category) = Category::find_by_id(category_id, pool).await? {
+    /// if let Some(category) = Categories::find_by_id(category_id, UserScope::All, pool).await? {
     ///     println!("Found category: {}", category.name);
     /// } else {
     ///     println!("Category not found");
@@ -46,8 +48,10 @@ impl database::Categories {
     )]
     pub async fn find_by_id(
         id: domain::RowID,
+        scope: database::UserScope,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<Option<Self>> {
+        let user_id = scope.user_id();
         let category = sqlx::query_as!(
             database::Categories,
             r#"
@@ -61,19 +65,71 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
                 FROM categories
-                WHERE id = ?
+                WHERE id = ?1 AND deleted_on IS NULL AND (?2 IS NULL OR user_id = ?2)
             "#,
-            id
+            id,
+            user_id
         )
         .fetch_optional(pool)
+        .observe("categories.find_by_id")
         .await?;
 
         Ok(category)
     }
 
+    /// Finds a category by its public ID, the ID it is known by outside the
+    /// ledger.
+    ///
+    /// # Arguments
+    ///
+    /// * `public_id` - The public ID of the category to find
+    /// * `scope` - The user whose categories are searched
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Categories)` if the category exists, or `None` if not
+    /// found, including when the public ID was not made by this ledger.
+    /// Returns a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let public_id = "2b5yH0MzQk1xXr7aV9cDeF".parse()?;
+    ///
+    /// if let Some(category) = Categories::find_by_public_id(public_id, UserScope::All, pool).await? {
+    ///     println!("Found category: {}", category.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find category by public ID",
+        skip(pool),
+        fields(public_id = %public_id),
+        err
+    )]
+    pub async fn find_by_public_id(
+        public_id: domain::PublicID,
+        scope: database::UserScope,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Option<Self>> {
+        match database::public_ids::row_id(public_id, &mut *pool.acquire().await?).await? {
+            Some(id) => Self::find_by_id(id, scope, pool).await,
+            None => Ok(None),
+        }
+    }
+
     /// Finds a category by its code.
     ///
     /// This function retrieves a single category record from the database by its unique code.
@@ -82,21 +138,21 @@ impl database::Categories {
     /// # Arguments
     ///
     /// * `code` - The code of the category to find
+    /// * `scope` - The user whose categories are searched
     /// * `pool` - The database connection pool
     ///
     /// # Returns
     ///
-    /// Returns `Some(Category)` if the category exists, or `None` if not found.
+    /// Returns `Some(Categories)` if the category exists, or `None` if not found.
     /// Returns a `DatabaseError` if the query fails.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
+    /// use lib_database::{Categories, UserScope};
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// if let Some(category) = Category::find_by_code("FOOD.001", pool).await? {
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// if let Some(category) = Categories::find_by_code("FOOD.001", UserScope::All, pool).await? {
     ///     println!("Found category: {}", category.name);
     /// } else {
     ///     println!("Category not found");
@@ -112,8 +168,10 @@ impl database::Categories {
     )]
     pub async fn find_by_code(
         code: &str,
+        scope: database::UserScope,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<Option<Self>> {
+        let user_id = scope.user_id();
         let category = sqlx::query_as!(
             database::Categories,
             r#"
@@ -127,14 +185,20 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
                 FROM categories
-                WHERE code = ?
+                WHERE code = ?1 AND deleted_on IS NULL AND (?2 IS NULL OR user_id = ?2)
             "#,
-            code
+            code,
+            user_id
         )
         .fetch_optional(pool)
+        .observe("categories.find_by_code")
         .await?;
 
         Ok(category)
@@ -148,23 +212,23 @@ impl database::Categories {
     /// # Arguments
     ///
     /// * `slug` - The URL slug of the category to find
+    /// * `scope` - The user whose categories are searched
     /// * `pool` - The database connection pool
     ///
     /// # Returns
     ///
-    /// Returns `Some(Category)` if the category exists, or `None` if not found.
+    /// Returns `Some(Categories)` if the category exists, or `None` if not found.
     /// Returns a `DatabaseError` if the query fails.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
-    /// use personal_ledger_backend::domain::UrlSlug;
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::UrlSlug;
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     /// let slug = UrlSlug::parse("groceries")?;
-    /// if let Some(category) = Category::find_by_url_slug(&slug, pool).await? {
+    /// if let Some(category) = Categories::find_by_url_slug(&slug, UserScope::All, pool).await? {
     ///     println!("Found category: {}", category.name);
     /// } else {
     ///     println!("Category not found");
@@ -180,8 +244,10 @@ impl database::Categories {
     )]
     pub async fn find_by_url_slug(
         slug: &domain::UrlSlug,
+        scope: database::UserScope,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<Option<Self>> {
+        let user_id = scope.user_id();
         let category = sqlx::query_as!(
             database::Categories,
             r#"
@@ -195,14 +261,20 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
                 FROM categories
-                WHERE url_slug = ?
+                WHERE url_slug = ?1 AND deleted_on IS NULL AND (?2 IS NULL OR user_id = ?2)
             "#,
-            slug
+            slug,
+            user_id
         )
         .fetch_optional(pool)
+        .observe("categories.find_by_url_slug")
         .await?;
 
         Ok(category)
@@ -215,6 +287,7 @@ impl database::Categories {
     ///
     /// # Arguments
     ///
+    /// * `scope` - The user whose categories are listed
     /// * `pool` - The database connection pool
     ///
     /// # Returns
@@ -224,11 +297,10 @@ impl database::Categories {
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
+    /// use lib_database::{Categories, UserScope};
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// let all_categories = Category::find_all(pool).await?;
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let all_categories = Categories::find_all(UserScope::All, pool).await?;
     /// println!("Found {} categories", all_categories.len());
     ///
     /// for category in all_categories {
@@ -237,14 +309,12 @@ impl database::Categories {
     /// # Ok(())
     /// # }
     /// ```
-    #[tracing::instrument(
-        name = "Find all categories",
-        skip(pool),
-        err
-    )]
+    #[tracing::instrument(name = "Find all categories", skip(pool), err)]
     pub async fn find_all(
+        scope: database::UserScope,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<Vec<Self>> {
+        let user_id = scope.user_id();
         let categories = sqlx::query_as!(
             database::Categories,
             r#"
@@ -258,13 +328,20 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
                 FROM categories
+                WHERE deleted_on IS NULL AND (?1 IS NULL OR user_id = ?1)
                 ORDER BY created_on DESC
-            "#
+            "#,
+            user_id
         )
         .fetch_all(pool)
+        .observe("categories.find_all")
         .await?;
 
         tracing::info!("Retrieved {} categories from database", categories.len());
@@ -272,6 +349,63 @@ impl database::Categories {
         Ok(categories)
     }
 
+    /// Streams every category, newest first, in the same order as
+    /// [`find_all`](Self::find_all).
+    ///
+    /// Rows are read from a database cursor as the stream is polled instead of
+    /// being collected into a `Vec`, so large tables can be sent to a client
+    /// page by page. The stream holds a pool connection until it is dropped or
+    /// exhausted.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The user whose categories are streamed
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of categories, each item a `DatabaseError` if reading
+    /// that row fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures_util::TryStreamExt;
+    /// use lib_database::{Categories, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut categories = Categories::stream_all(UserScope::All, pool);
+    /// while let Some(category) = categories.try_next().await? {
+    ///     println!("- {} ({})", category.name, category.code);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_all(
+        scope: database::UserScope,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> BoxStream<'_, DatabaseResult<Self>> {
+        let mut observation = StreamObservation::new("categories.stream_all");
+
+        // Bound by value, as the arguments of `query_as!` are borrowed and
+        // would not live as long as the stream
+        sqlx::query_as::<_, Self>(
+            r#"
+                SELECT
+                    id, code, name, description, url_slug, category_type, color,
+                    icon, is_active, parent_id, user_id, created_on, updated_on, deleted_on, version
+                FROM categories
+                WHERE deleted_on IS NULL AND (?1 IS NULL OR user_id = ?1)
+                ORDER BY created_on DESC
+            "#,
+        )
+        .bind(scope.user_id())
+        .fetch(pool)
+        .inspect_ok(move |_| observation.row())
+        .map_err(database::DatabaseError::from)
+        .boxed()
+    }
+
     /// Retrieves all active categories from the database.
     ///
     /// This function returns only categories that are marked as active (is_active = true),
@@ -280,6 +414,7 @@ impl database::Categories {
     ///
     /// # Arguments
     ///
+    /// * `scope` - The user whose categories are listed
     /// * `pool` - The database connection pool
     ///
     /// # Returns
@@ -289,11 +424,10 @@ impl database::Categories {
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
+    /// use lib_database::{Categories, UserScope};
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// let active_categories = Category::find_all_active(pool).await?;
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let active_categories = Categories::find_all_active(UserScope::All, pool).await?;
     /// println!("Found {} active categories", active_categories.len());
     ///
     /// for category in active_categories {
@@ -302,14 +436,12 @@ impl database::Categories {
     /// # Ok(())
     /// # }
     /// ```
-    #[tracing::instrument(
-        name = "Find all active categories",
-        skip(pool),
-        err
-    )]
+    #[tracing::instrument(name = "Find all active categories", skip(pool), err)]
     pub async fn find_all_active(
+        scope: database::UserScope,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<Vec<Self>> {
+        let user_id = scope.user_id();
         let categories = sqlx::query_as!(
             database::Categories,
             r#"
@@ -323,17 +455,26 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
                 FROM categories
-                WHERE is_active = true
+                WHERE is_active = true AND deleted_on IS NULL AND (?1 IS NULL OR user_id = ?1)
                 ORDER BY created_on DESC
-            "#
+            "#,
+            user_id
         )
         .fetch_all(pool)
+        .observe("categories.find_all_active")
         .await?;
 
-        tracing::info!("Retrieved {} active categories from database", categories.len());
+        tracing::info!(
+            "Retrieved {} active categories from database",
+            categories.len()
+        );
 
         Ok(categories)
     }
@@ -347,6 +488,7 @@ impl database::Categories {
     /// # Arguments
     ///
     /// * `category_type` - The type of categories to retrieve
+    /// * `scope` - The user whose categories are listed
     /// * `pool` - The database connection pool
     ///
     /// # Returns
@@ -356,15 +498,14 @@ impl database::Categories {
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
-    /// use personal_ledger_backend::domain::CategoryTypes;
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::CategoryTypes;
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// let expense_categories = Category::find_by_type(CategoryTypes::Expense, pool).await?;
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let expense_categories = Categories::find_by_type(CategoryTypes::Expense, UserScope::All, pool).await?;
     /// println!("Found {} expense categories", expense_categories.len());
     ///
-    /// let income_categories = Category::find_by_type(CategoryTypes::Income, pool).await?;
+    /// let income_categories = Categories::find_by_type(CategoryTypes::Income, UserScope::All, pool).await?;
     /// println!("Found {} income categories", income_categories.len());
     /// # Ok(())
     /// # }
@@ -377,8 +518,10 @@ impl database::Categories {
     )]
     pub async fn find_by_type(
         category_type: domain::CategoryTypes,
+        scope: database::UserScope,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<Vec<Self>> {
+        let user_id = scope.user_id();
         let categories = sqlx::query_as!(
             database::Categories,
             r#"
@@ -392,18 +535,28 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
                 FROM categories
-                WHERE category_type = ?
+                WHERE category_type = ?1 AND deleted_on IS NULL AND (?2 IS NULL OR user_id = ?2)
                 ORDER BY created_on DESC
             "#,
-            category_type
+            category_type,
+            user_id
         )
         .fetch_all(pool)
+        .observe("categories.find_by_type")
         .await?;
 
-        tracing::info!("Retrieved {} categories of type {} from database", categories.len(), category_type);
+        tracing::info!(
+            "Retrieved {} categories of type {} from database",
+            categories.len(),
+            category_type
+        );
 
         Ok(categories)
     }
@@ -417,6 +570,7 @@ impl database::Categories {
     /// # Arguments
     ///
     /// * `category_type` - The type of categories to retrieve
+    /// * `scope` - The user whose categories are listed
     /// * `pool` - The database connection pool
     ///
     /// # Returns
@@ -426,12 +580,11 @@ impl database::Categories {
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
-    /// use personal_ledger_backend::domain::CategoryTypes;
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::CategoryTypes;
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// let active_expense_categories = Category::find_active_by_type(CategoryTypes::Expense, pool).await?;
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let active_expense_categories = Categories::find_active_by_type(CategoryTypes::Expense, UserScope::All, pool).await?;
     /// println!("Found {} active expense categories", active_expense_categories.len());
     /// # Ok(())
     /// # }
@@ -444,8 +597,10 @@ impl database::Categories {
     )]
     pub async fn find_active_by_type(
         category_type: domain::CategoryTypes,
+        scope: database::UserScope,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<Vec<Self>> {
+        let user_id = scope.user_id();
         let categories = sqlx::query_as!(
             database::Categories,
             r#"
@@ -459,64 +614,78 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
                 FROM categories
-                WHERE category_type = ? AND is_active = true
+                WHERE category_type = ?1 AND is_active = true AND deleted_on IS NULL
+                    AND (?2 IS NULL OR user_id = ?2)
                 ORDER BY created_on DESC
             "#,
-            category_type
+            category_type,
+            user_id
         )
         .fetch_all(pool)
+        .observe("categories.find_active_by_type")
         .await?;
 
-        tracing::info!("Retrieved {} active categories of type {} from database", categories.len(), category_type);
+        tracing::info!(
+            "Retrieved {} active categories of type {} from database",
+            categories.len(),
+            category_type
+        );
 
         Ok(categories)
     }
 
-    /// Retrieves categories with flexible filtering, sorting, and pagination.
+    /// Retrieves a page of categories matching a filter, in the filter's
+    /// sort order.
     ///
-    /// This function provides comprehensive category listing with support for:
-    /// - Filtering by category type and active status
-    /// - Sorting by any field in ascending or descending order
-    /// - Pagination with offset and limit
+    /// This function provides category listing with support for:
+    /// - Filtering by any combination of category type, active status, the
+    ///   day created and text in the name
+    /// - Sorting by creation or update time, name or code, see [`CategoryFilter`](database::CategoryFilter)
+    /// - Cursor pagination, see the [`pagination`](crate::pagination) module
     ///
     /// # Arguments
     ///
-    /// * `category_type_filter` - Optional filter by category type
-    /// * `is_active_filter` - Optional filter by active status
-    /// * `sort_by` - Optional field to sort by (defaults to "created_on")
-    /// * `sort_desc` - Whether to sort in descending order (defaults to true)
-    /// * `offset` - Number of records to skip (for pagination)
-    /// * `limit` - Maximum number of records to return
+    /// * `filter` - The categories to include, and their order
+    /// * `after` - The cursor of the previous page, or `None` for the first page
+    /// * `page_size` - Maximum number of records to return, 50 if zero or negative
+    /// * `scope` - The user whose categories are listed
     /// * `pool` - The database connection pool
     ///
     /// # Returns
     ///
-    /// Returns a tuple of (categories, total_count) where total_count is the total
-    /// number of categories matching the filters (before pagination).
+    /// Returns the page of categories, with the total number of categories
+    /// matching the filter (across every page) and the cursor of the next page.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if the filter's created from date is
+    /// after its created to date.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
-    /// use personal_ledger_backend::domain::CategoryTypes;
-    ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// // Get first 10 active expense categories, sorted by name
-    /// let (categories, total) = Category::find_with_filters(
-    ///     Some(CategoryTypes::Expense),
-    ///     Some(true),
-    ///     Some("name"),
-    ///     Some(false), // ascending
-    ///     0,
-    ///     10,
-    ///     pool
-    /// ).await?;
-    ///
-    /// println!("Found {} of {} total categories", categories.len(), total);
+    /// use lib_database::{Categories, CategoryFilter, CategorySortColumns, UserScope};
+    /// use lib_domain::CategoryTypes;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// // Get the first 10 active expense categories with "food" in the name, by name
+    /// let filter = CategoryFilter {
+    ///     category_type: Some(CategoryTypes::Expense),
+    ///     is_active: Some(true),
+    ///     name_contains: Some("food".to_string()),
+    ///     sort_by: CategorySortColumns::Name,
+    ///     ..Default::default()
+    /// };
+    /// let page = Categories::find_with_filters(&filter, None, 10, UserScope::All, pool).await?;
+    ///
+    /// println!("Found {} of {} total categories", page.items.len(), page.total_count);
     /// # Ok(())
     /// # }
     /// ```
@@ -524,177 +693,101 @@ impl database::Categories {
         name = "Find categories with filters",
         skip(pool),
         fields(
-            category_type = ?category_type_filter,
-            is_active = ?is_active_filter,
-            sort_by = ?sort_by,
-            sort_desc = ?sort_desc,
-            offset = %offset,
-            limit = %limit
+            filter = ?filter,
+            after = ?after,
+            page_size = %page_size
         ),
         err
     )]
     pub async fn find_with_filters(
-        category_type_filter: Option<domain::CategoryTypes>,
-        is_active_filter: Option<bool>,
-        sort_by: Option<&str>,
-        sort_desc: Option<bool>,
-        offset: i32,
-        limit: i32,
+        filter: &database::CategoryFilter,
+        after: Option<database::PageCursor>,
+        page_size: i32,
+        scope: database::UserScope,
         pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<(Vec<Self>, i32)> {
-        // For now, implement a simpler version that handles the most common cases
-        // TODO: Implement full dynamic filtering when needed
-
-        let (categories, total_count) = match (category_type_filter, is_active_filter) {
-            (Some(category_type), Some(_is_active)) => {
-                Self::find_active_by_type_with_pagination(category_type, offset, limit, pool).await?
-            }
-            (Some(category_type), None) => {
-                Self::find_by_type_with_pagination(category_type, offset, limit, pool).await?
-            }
-            (None, Some(is_active)) => {
-                if is_active {
-                    Self::find_all_active_with_pagination(offset, limit, pool).await?
-                } else {
-                    Self::find_all_inactive_with_pagination(offset, limit, pool).await?
-                }
-            }
-            (None, None) => {
-                Self::find_all_with_pagination(offset, limit, pool).await?
-            }
-        };
-
-        Ok((categories, total_count))
-    }
-
-    /// Helper method to find all categories with pagination
-    async fn find_all_with_pagination(
-        offset: i32,
-        limit: i32,
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<(Vec<Self>, i32)> {
-        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories")
+    ) -> DatabaseResult<database::Page<Self>> {
+        filter.validate()?;
+        let page_size = database::pagination::page_size_or_default(page_size);
+
+        let mut count = sqlx::QueryBuilder::new("SELECT COUNT(*) FROM categories");
+        filter.push_where(&mut count, scope, None);
+        let total_count: i32 = count
+            .build_query_scalar()
             .fetch_one(pool)
+            .observe_one("categories.find_with_filters")
             .await?;
 
-        let categories = sqlx::query_as!(
-            database::Categories,
+        let mut query = sqlx::QueryBuilder::new(
             r#"
                 SELECT
-                    id              AS "id!: domain::RowID",
-                    code,
-                    name,
-                    description,
-                    url_slug        AS "url_slug?: domain::UrlSlug",
-                    category_type   AS "category_type!: domain::CategoryTypes",
-                    color           AS "color?: domain::HexColor",
-                    icon,
-                    is_active       AS "is_active!: bool",
-                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    id, code, name, description, url_slug, category_type, color,
+                    icon, is_active, parent_id, user_id, created_on, updated_on, deleted_on, version
                 FROM categories
-                ORDER BY created_on DESC
-                LIMIT ? OFFSET ?
             "#,
-            limit,
-            offset
-        )
-        .fetch_all(pool)
-        .await?;
-
-        Ok((categories, total_count))
-    }
-
-    /// Helper method to find all active categories with pagination
-    async fn find_all_active_with_pagination(
-        offset: i32,
-        limit: i32,
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<(Vec<Self>, i32)> {
-        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE is_active = true")
-            .fetch_one(pool)
+        );
+        filter.push_where(&mut query, scope, after);
+        filter.push_order_by(&mut query);
+        query.push(" LIMIT ").push_bind(page_size + 1);
+
+        let categories = query
+            .build_query_as::<Self>()
+            .fetch_all(pool)
+            .observe("categories.find_with_filters")
             .await?;
 
-        let categories = sqlx::query_as!(
-            database::Categories,
-            r#"
-                SELECT
-                    id              AS "id!: domain::RowID",
-                    code,
-                    name,
-                    description,
-                    url_slug        AS "url_slug?: domain::UrlSlug",
-                    category_type   AS "category_type!: domain::CategoryTypes",
-                    color           AS "color?: domain::HexColor",
-                    icon,
-                    is_active       AS "is_active!: bool",
-                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
-                FROM categories
-                WHERE is_active = true
-                ORDER BY created_on DESC
-                LIMIT ? OFFSET ?
-            "#,
-            limit,
-            offset
-        )
-        .fetch_all(pool)
-        .await?;
-
-        Ok((categories, total_count))
+        Ok(database::Page::from_rows(
+            categories,
+            page_size,
+            total_count,
+            |category| database::PageCursor::new(category.created_on, category.id),
+        ))
     }
 
-    /// Helper method to find all inactive categories with pagination
-    async fn find_all_inactive_with_pagination(
-        offset: i32,
-        limit: i32,
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<(Vec<Self>, i32)> {
-        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE is_active = false")
-            .fetch_one(pool)
-            .await?;
-
-        let categories = sqlx::query_as!(
-            database::Categories,
-            r#"
-                SELECT
-                    id              AS "id!: domain::RowID",
-                    code,
-                    name,
-                    description,
-                    url_slug        AS "url_slug?: domain::UrlSlug",
-                    category_type   AS "category_type!: domain::CategoryTypes",
-                    color           AS "color?: domain::HexColor",
-                    icon,
-                    is_active       AS "is_active!: bool",
-                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
-                FROM categories
-                WHERE is_active = false
-                ORDER BY created_on DESC
-                LIMIT ? OFFSET ?
-            "#,
-            limit,
-            offset
-        )
-        .fetch_all(pool)
-        .await?;
-
-        Ok((categories, total_count))
-    }
-
-    /// Helper method to find categories by type with pagination
-    async fn find_by_type_with_pagination(
-        category_type: domain::CategoryTypes,
-        offset: i32,
-        limit: i32,
+    /// Retrieves all categories as they were recorded at the end of a date.
+    ///
+    /// Reads the category history rather than the current table, so a report
+    /// for a past date returns the same categories (names, codes, active status)
+    /// it did on that date, even if they have since been edited or deleted.
+    /// Categories created after `as_of` are not included. The day ends at
+    /// midnight UTC.
+    ///
+    /// # Arguments
+    ///
+    /// * `as_of` - The last day of history to include
+    /// * `scope` - The user whose categories are listed
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the categories as they were recorded, ordered by creation date
+    /// (newest first), or a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let end_of_year = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+    /// let categories = Categories::find_all_as_of(end_of_year, UserScope::All, pool).await?;
+    /// println!("{} categories at 30 June", categories.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find all categories as of date",
+        skip(pool),
+        fields(as_of = %as_of),
+        err
+    )]
+    pub async fn find_all_as_of(
+        as_of: chrono::NaiveDate,
+        scope: database::UserScope,
         pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<(Vec<Self>, i32)> {
-        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE category_type = ?")
-            .bind(&category_type)
-            .fetch_one(pool)
-            .await?;
+    ) -> DatabaseResult<Vec<Self>> {
+        let cutoff = database::history::end_of_day(as_of);
 
+        let user_id = scope.user_id();
         let categories = sqlx::query_as!(
             database::Categories,
             r#"
@@ -708,63 +801,32 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
-                FROM categories
-                WHERE category_type = ?
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    NULL            AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
+                FROM categories_history
+                WHERE valid_from < ?1
+                    AND (valid_to IS NULL OR valid_to >= ?1)
+                    AND (?2 IS NULL OR user_id = ?2)
                 ORDER BY created_on DESC
-                LIMIT ? OFFSET ?
             "#,
-            category_type,
-            limit,
-            offset
+            cutoff,
+            user_id
         )
         .fetch_all(pool)
+        .observe("categories.find_all_as_of")
         .await?;
 
-        Ok((categories, total_count))
-    }
-
-    /// Helper method to find active categories by type with pagination
-    async fn find_active_by_type_with_pagination(
-        category_type: domain::CategoryTypes,
-        offset: i32,
-        limit: i32,
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<(Vec<Self>, i32)> {
-        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE category_type = ? AND is_active = true")
-            .bind(&category_type)
-            .fetch_one(pool)
-            .await?;
-
-        let categories = sqlx::query_as!(
-            database::Categories,
-            r#"
-                SELECT
-                    id              AS "id!: domain::RowID",
-                    code,
-                    name,
-                    description,
-                    url_slug        AS "url_slug?: domain::UrlSlug",
-                    category_type   AS "category_type!: domain::CategoryTypes",
-                    color           AS "color?: domain::HexColor",
-                    icon,
-                    is_active       AS "is_active!: bool",
-                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
-                FROM categories
-                WHERE category_type = ? AND is_active = true
-                ORDER BY created_on DESC
-                LIMIT ? OFFSET ?
-            "#,
-            category_type,
-            limit,
-            offset
-        )
-        .fetch_all(pool)
-        .await?;
+        tracing::info!(
+            "Retrieved {} categories as of {} from history",
+            categories.len(),
+            as_of
+        );
 
-        Ok((categories, total_count))
+        Ok(categories)
     }
 }
 
@@ -790,7 +852,11 @@ mod tests {
             category.name = format!("Test Category {}", i);
             category.description = Some(format!("Description for category {}", i));
             category.url_slug = Some(domain::UrlSlug::from(format!("test-category-{}", i)));
-            category.category_type = if i % 2 == 0 { domain::CategoryTypes::Expense } else { domain::CategoryTypes::Income };
+            category.category_type = if i % 2 == 0 {
+                domain::CategoryTypes::Expense
+            } else {
+                domain::CategoryTypes::Income
+            };
             category.is_active = i % 3 != 0; // Every 3rd category is inactive
             database::Categories::insert(&category, pool).await.unwrap();
             categories.push(category);
@@ -804,7 +870,9 @@ mod tests {
         let category = create_test_category(&pool).await;
 
         // Find it by ID
-        let found = database::Categories::find_by_id(category.id, &pool).await.unwrap();
+        let found = database::Categories::find_by_id(category.id, database::UserScope::All, &pool)
+            .await
+            .unwrap();
 
         // Verify it's the same category
         assert!(found.is_some());
@@ -824,7 +892,9 @@ mod tests {
     async fn test_find_by_id_nonexistent_category(pool: SqlitePool) {
         // Try to find a category that doesn't exist
         let fake_id = domain::RowID::new();
-        let result = database::Categories::find_by_id(fake_id, &pool).await.unwrap();
+        let result = database::Categories::find_by_id(fake_id, database::UserScope::All, &pool)
+            .await
+            .unwrap();
 
         // Should return None
         assert!(result.is_none());
@@ -836,7 +906,10 @@ mod tests {
         let category = create_test_category(&pool).await;
 
         // Find it by code
-        let found = database::Categories::find_by_code(&category.code, &pool).await.unwrap();
+        let found =
+            database::Categories::find_by_code(&category.code, database::UserScope::All, &pool)
+                .await
+                .unwrap();
 
         // Verify it's the same category
         assert!(found.is_some());
@@ -848,7 +921,10 @@ mod tests {
     #[sqlx::test]
     async fn test_find_by_code_nonexistent_category(pool: SqlitePool) {
         // Try to find a category that doesn't exist
-        let result = database::Categories::find_by_code("NONEXISTENT.CODE", &pool).await.unwrap();
+        let result =
+            database::Categories::find_by_code("NONEXISTENT.CODE", database::UserScope::All, &pool)
+                .await
+                .unwrap();
 
         // Should return None
         assert!(result.is_none());
@@ -861,15 +937,23 @@ mod tests {
         category.code = category.code.to_uppercase();
 
         // Update it in the database
-        database::Categories::update(&category, &pool).await.unwrap();
+        database::Categories::update(&category, database::UserScope::All, &pool)
+            .await
+            .unwrap();
 
         // Try to find with lowercase version - should fail
         let lowercase_code = category.code.to_lowercase();
-        let result = database::Categories::find_by_code(&lowercase_code, &pool).await.unwrap();
+        let result =
+            database::Categories::find_by_code(&lowercase_code, database::UserScope::All, &pool)
+                .await
+                .unwrap();
         assert!(result.is_none());
 
         // Find with correct case should work
-        let result = database::Categories::find_by_code(&category.code, &pool).await.unwrap();
+        let result =
+            database::Categories::find_by_code(&category.code, database::UserScope::All, &pool)
+                .await
+                .unwrap();
         assert!(result.is_some());
         assert_eq!(result.unwrap().id, category.id);
     }
@@ -881,7 +965,10 @@ mod tests {
 
         // Find it by URL slug (assuming it has one)
         if let Some(ref slug) = category.url_slug {
-            let found = database::Categories::find_by_url_slug(slug, &pool).await.unwrap();
+            let found =
+                database::Categories::find_by_url_slug(slug, database::UserScope::All, &pool)
+                    .await
+                    .unwrap();
 
             // Verify it's the same category
             assert!(found.is_some());
@@ -894,9 +981,17 @@ mod tests {
                 url_slug: Some(domain::UrlSlug::from("test-slug")),
                 ..category
             };
-            database::Categories::update(&category_with_slug, &pool).await.unwrap();
-
-            let found = database::Categories::find_by_url_slug(&domain::UrlSlug::from("test-slug"), &pool).await.unwrap();
+            database::Categories::update(&category_with_slug, database::UserScope::All, &pool)
+                .await
+                .unwrap();
+
+            let found = database::Categories::find_by_url_slug(
+                &domain::UrlSlug::from("test-slug"),
+                database::UserScope::All,
+                &pool,
+            )
+            .await
+            .unwrap();
             assert!(found.is_some());
             assert_eq!(found.unwrap().id, category.id);
         }
@@ -906,7 +1001,10 @@ mod tests {
     async fn test_find_by_url_slug_nonexistent_category(pool: SqlitePool) {
         // Try to find a category with a slug that doesn't exist
         let fake_slug = domain::UrlSlug::from("nonexistent-slug");
-        let result = database::Categories::find_by_url_slug(&fake_slug, &pool).await.unwrap();
+        let result =
+            database::Categories::find_by_url_slug(&fake_slug, database::UserScope::All, &pool)
+                .await
+                .unwrap();
 
         // Should return None
         assert!(result.is_none());
@@ -918,7 +1016,9 @@ mod tests {
         let test_categories = create_test_categories(5, &pool).await;
 
         // Find all categories
-        let all_categories = database::Categories::find_all(&pool).await.unwrap();
+        let all_categories = database::Categories::find_all(database::UserScope::All, &pool)
+            .await
+            .unwrap();
 
         // Should have at least our test categories
         assert!(all_categories.len() >= test_categories.len());
@@ -926,14 +1026,20 @@ mod tests {
         // Verify our test categories are in the results
         for test_cat in &test_categories {
             let found = all_categories.iter().find(|c| c.id == test_cat.id);
-            assert!(found.is_some(), "Test category {} not found in results", test_cat.id);
+            assert!(
+                found.is_some(),
+                "Test category {} not found in results",
+                test_cat.id
+            );
         }
     }
 
     #[sqlx::test]
     async fn test_find_all_empty_database(pool: SqlitePool) {
         // Find all categories in empty database
-        let all_categories = database::Categories::find_all(&pool).await.unwrap();
+        let all_categories = database::Categories::find_all(database::UserScope::All, &pool)
+            .await
+            .unwrap();
 
         // Should return empty vector
         assert!(all_categories.is_empty());
@@ -945,7 +1051,10 @@ mod tests {
         let test_categories = create_test_categories(9, &pool).await; // 3 inactive, 6 active
 
         // Find all active categories
-        let active_categories = database::Categories::find_all_active(&pool).await.unwrap();
+        let active_categories =
+            database::Categories::find_all_active(database::UserScope::All, &pool)
+                .await
+                .unwrap();
 
         // Should have exactly the active ones
         let expected_active_count = test_categories.iter().filter(|c| c.is_active).count();
@@ -960,7 +1069,11 @@ mod tests {
         for test_cat in &test_categories {
             if !test_cat.is_active {
                 let found_in_active = active_categories.iter().find(|c| c.id == test_cat.id);
-                assert!(found_in_active.is_none(), "Inactive category {} found in active results", test_cat.id);
+                assert!(
+                    found_in_active.is_none(),
+                    "Inactive category {} found in active results",
+                    test_cat.id
+                );
             }
         }
     }
@@ -980,15 +1093,24 @@ mod tests {
                 color: domain::HexColor::mock_with_option(),
                 icon: Some("test-icon".to_string()),
                 is_active: false, // Inactive
+                parent_id: None,
+                user_id: None,
                 created_on: chrono::Utc::now(),
                 updated_on: chrono::Utc::now(),
+                deleted_on: None,
+                version: 1,
             };
-            database::Categories::insert(&category, &pool).await.unwrap();
+            database::Categories::insert(&category, &pool)
+                .await
+                .unwrap();
             inactive_categories.push(category);
         }
 
         // Find all active categories
-        let active_categories = database::Categories::find_all_active(&pool).await.unwrap();
+        let active_categories =
+            database::Categories::find_all_active(database::UserScope::All, &pool)
+                .await
+                .unwrap();
 
         // Should return empty vector
         assert!(active_categories.is_empty());
@@ -1000,7 +1122,13 @@ mod tests {
         let test_categories = create_test_categories(10, &pool).await;
 
         // Find expense categories
-        let expense_categories = database::Categories::find_by_type(domain::CategoryTypes::Expense, &pool).await.unwrap();
+        let expense_categories = database::Categories::find_by_type(
+            domain::CategoryTypes::Expense,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
 
         // Verify all returned categories are expenses
         for category in &expense_categories {
@@ -1008,7 +1136,8 @@ mod tests {
         }
 
         // Verify count matches expected
-        let expected_expense_count = test_categories.iter()
+        let expected_expense_count = test_categories
+            .iter()
             .filter(|c| c.category_type == domain::CategoryTypes::Expense)
             .count();
         assert_eq!(expense_categories.len(), expected_expense_count);
@@ -1020,7 +1149,13 @@ mod tests {
         let test_categories = create_test_categories(10, &pool).await;
 
         // Find income categories
-        let income_categories = database::Categories::find_by_type(domain::CategoryTypes::Income, &pool).await.unwrap();
+        let income_categories = database::Categories::find_by_type(
+            domain::CategoryTypes::Income,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
 
         // Verify all returned categories are income
         for category in &income_categories {
@@ -1028,7 +1163,8 @@ mod tests {
         }
 
         // Verify count matches expected
-        let expected_income_count = test_categories.iter()
+        let expected_income_count = test_categories
+            .iter()
             .filter(|c| c.category_type == domain::CategoryTypes::Income)
             .count();
         assert_eq!(income_categories.len(), expected_income_count);
@@ -1048,14 +1184,26 @@ mod tests {
                 color: domain::HexColor::mock_with_option(),
                 icon: Some("test-icon".to_string()),
                 is_active: true,
+                parent_id: None,
+                user_id: None,
                 created_on: chrono::Utc::now(),
                 updated_on: chrono::Utc::now(),
+                deleted_on: None,
+                version: 1,
             };
-            database::Categories::insert(&category, &pool).await.unwrap();
+            database::Categories::insert(&category, &pool)
+                .await
+                .unwrap();
         }
 
         // Try to find income categories
-        let income_categories = database::Categories::find_by_type(domain::CategoryTypes::Income, &pool).await.unwrap();
+        let income_categories = database::Categories::find_by_type(
+            domain::CategoryTypes::Income,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
 
         // Should return empty vector
         assert!(income_categories.is_empty());
@@ -1067,7 +1215,13 @@ mod tests {
         let test_categories = create_test_categories(12, &pool).await; // 4 inactive, 8 active
 
         // Find active expense categories
-        let active_expense_categories = database::Categories::find_active_by_type(domain::CategoryTypes::Expense, &pool).await.unwrap();
+        let active_expense_categories = database::Categories::find_active_by_type(
+            domain::CategoryTypes::Expense,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
 
         // Verify all returned categories are active expenses
         for category in &active_expense_categories {
@@ -1076,13 +1230,23 @@ mod tests {
         }
 
         // Verify count matches expected
-        let expected_active_expense_count = test_categories.iter()
+        let expected_active_expense_count = test_categories
+            .iter()
             .filter(|c| c.is_active && c.category_type == domain::CategoryTypes::Expense)
             .count();
-        assert_eq!(active_expense_categories.len(), expected_active_expense_count);
+        assert_eq!(
+            active_expense_categories.len(),
+            expected_active_expense_count
+        );
 
         // Find active income categories
-        let active_income_categories = database::Categories::find_active_by_type(domain::CategoryTypes::Income, &pool).await.unwrap();
+        let active_income_categories = database::Categories::find_active_by_type(
+            domain::CategoryTypes::Income,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
 
         // Verify all returned categories are active income
         for category in &active_income_categories {
@@ -1091,7 +1255,8 @@ mod tests {
         }
 
         // Verify count matches expected
-        let expected_active_income_count = test_categories.iter()
+        let expected_active_income_count = test_categories
+            .iter()
             .filter(|c| c.is_active && c.category_type == domain::CategoryTypes::Income)
             .count();
         assert_eq!(active_income_categories.len(), expected_active_income_count);
@@ -1111,16 +1276,365 @@ mod tests {
                 color: domain::HexColor::mock_with_option(),
                 icon: Some("test-icon".to_string()),
                 is_active: false, // Inactive
+                parent_id: None,
+                user_id: None,
                 created_on: chrono::Utc::now(),
                 updated_on: chrono::Utc::now(),
+                deleted_on: None,
+                version: 1,
             };
-            database::Categories::insert(&category, &pool).await.unwrap();
+            database::Categories::insert(&category, &pool)
+                .await
+                .unwrap();
         }
 
         // Try to find active income categories
-        let active_income_categories = database::Categories::find_active_by_type(domain::CategoryTypes::Income, &pool).await.unwrap();
+        let active_income_categories = database::Categories::find_active_by_type(
+            domain::CategoryTypes::Income,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
 
         // Should return empty vector
         assert!(active_income_categories.is_empty());
     }
-}
\ No newline at end of file
+
+    #[sqlx::test]
+    async fn test_find_with_filters_pages_newest_first(pool: SqlitePool) {
+        let test_categories = create_test_categories(12, &pool).await; // 4 inactive, 8 active
+        let codes = |page: &database::Page<database::Categories>| {
+            page.items
+                .iter()
+                .map(|category| category.code.clone())
+                .collect::<Vec<_>>()
+        };
+
+        // Find active expense categories, three at a time
+        let filter = |is_active| database::CategoryFilter {
+            category_type: Some(domain::CategoryTypes::Expense),
+            is_active: Some(is_active),
+            ..Default::default()
+        };
+        let first = database::Categories::find_with_filters(
+            &filter(true),
+            None,
+            3,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
+        let second = database::Categories::find_with_filters(
+            &filter(true),
+            first.next_cursor,
+            3,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(first.total_count, 4);
+        assert_eq!(codes(&first), ["TEST.010", "TEST.008", "TEST.004"]);
+        assert_eq!(codes(&second), ["TEST.002"]);
+        assert_eq!(second.next_cursor, None);
+
+        // Inactive categories of a type are not mixed up with active ones
+        let inactive = database::Categories::find_with_filters(
+            &filter(false),
+            None,
+            0,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
+        assert_eq!(codes(&inactive), ["TEST.006", "TEST.000"]);
+
+        let all = database::Categories::find_with_filters(
+            &Default::default(),
+            None,
+            0,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
+        assert_eq!(all.total_count as usize, test_categories.len());
+    }
+
+    #[sqlx::test]
+    async fn test_find_with_filters_combines_filters_and_sorts(pool: SqlitePool) {
+        let created = |day| {
+            chrono::NaiveDate::from_ymd_opt(2025, 3, day)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap()
+                .and_utc()
+        };
+        for (code, name, day, is_active) in [
+            ("FOOD.01", "Groceries", 1, true),
+            ("FOOD.02", "Dining out", 5, true),
+            ("FOOD.03", "Fast food", 9, true),
+            ("FOOD.04", "Food delivery", 12, true),
+            ("FOOD.05", "Old food budget", 6, false),
+            ("HOME.01", "Home maintenance", 7, true),
+        ] {
+            let mut category = database::Categories::mock();
+            category.code = code.to_string();
+            category.name = name.to_string();
+            category.url_slug = None;
+            category.category_type = domain::CategoryTypes::Expense;
+            category.is_active = is_active;
+            category.created_on = created(day);
+            category.updated_on = created(day);
+            category.insert(&pool).await.unwrap();
+        }
+        let names = |page: &database::Page<database::Categories>| {
+            page.items
+                .iter()
+                .map(|category| category.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        // Active, with "food" in the name, created in the first ten days, by name
+        let mut filter = database::CategoryFilter {
+            is_active: Some(true),
+            created_from: chrono::NaiveDate::from_ymd_opt(2025, 3, 1),
+            created_to: chrono::NaiveDate::from_ymd_opt(2025, 3, 10),
+            name_contains: Some("FOOD".to_string()),
+            sort_by: database::CategorySortColumns::Name,
+            ..Default::default()
+        };
+        let page = database::Categories::find_with_filters(
+            &filter,
+            None,
+            0,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.total_count, 1);
+        assert_eq!(names(&page), ["Fast food"]);
+
+        // Every expense by code, newest code first, two at a time
+        filter = database::CategoryFilter {
+            sort_by: database::CategorySortColumns::Code,
+            sort_desc: Some(true),
+            ..Default::default()
+        };
+        let mut codes = Vec::new();
+        let mut after = None;
+        loop {
+            let page = database::Categories::find_with_filters(
+                &filter,
+                after,
+                2,
+                database::UserScope::All,
+                &pool,
+            )
+            .await
+            .unwrap();
+            codes.extend(page.items.into_iter().map(|category| category.code));
+            after = page.next_cursor;
+            if after.is_none() {
+                break;
+            }
+        }
+        assert_eq!(
+            codes,
+            [
+                "HOME.01", "FOOD.05", "FOOD.04", "FOOD.03", "FOOD.02", "FOOD.01"
+            ]
+        );
+
+        // Name search matches wildcards literally
+        filter = database::CategoryFilter {
+            name_contains: Some("%".to_string()),
+            ..Default::default()
+        };
+        let page = database::Categories::find_with_filters(
+            &filter,
+            None,
+            0,
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.total_count, 0);
+
+        filter = database::CategoryFilter {
+            created_from: chrono::NaiveDate::from_ymd_opt(2025, 3, 10),
+            created_to: chrono::NaiveDate::from_ymd_opt(2025, 3, 1),
+            ..Default::default()
+        };
+        let result = database::Categories::find_with_filters(
+            &filter,
+            None,
+            0,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+    }
+
+    #[sqlx::test]
+    async fn test_stream_all_matches_find_all(pool: SqlitePool) {
+        create_test_categories(5, &pool).await;
+
+        let streamed: Vec<_> = database::Categories::stream_all(database::UserScope::All, &pool)
+            .try_collect()
+            .await
+            .unwrap();
+        let found = database::Categories::find_all(database::UserScope::All, &pool)
+            .await
+            .unwrap();
+
+        assert_eq!(streamed.len(), 5);
+        assert_eq!(streamed, found);
+    }
+
+    fn days_ago(days: u64) -> chrono::NaiveDate {
+        chrono::Utc::now().date_naive() - chrono::Days::new(days)
+    }
+
+    #[sqlx::test]
+    async fn test_find_all_as_of_ignores_later_edits(pool: SqlitePool) {
+        let mut category = create_test_category(&pool).await;
+        let original_name = category.name.clone();
+        database::history::backdate("categories_history", category.id, 10, &pool)
+            .await
+            .unwrap();
+
+        category.name = "Renamed Category".to_string();
+        category
+            .update(database::UserScope::All, &pool)
+            .await
+            .unwrap();
+        let later = create_test_category(&pool).await;
+
+        let as_recorded =
+            database::Categories::find_all_as_of(days_ago(5), database::UserScope::All, &pool)
+                .await
+                .unwrap();
+        let today =
+            database::Categories::find_all_as_of(days_ago(0), database::UserScope::All, &pool)
+                .await
+                .unwrap();
+
+        assert_eq!(as_recorded.len(), 1);
+        assert_eq!(as_recorded[0].name, original_name);
+        assert_eq!(today.len(), 2);
+        assert!(
+            today
+                .iter()
+                .any(|c| c.id == category.id && c.name == "Renamed Category")
+        );
+        assert!(today.iter().any(|c| c.id == later.id));
+    }
+
+    #[sqlx::test]
+    async fn test_find_all_as_of_includes_deleted_categories(pool: SqlitePool) {
+        let category = create_test_category(&pool).await;
+        database::history::backdate("categories_history", category.id, 10, &pool)
+            .await
+            .unwrap();
+
+        category
+            .delete(database::UserScope::All, &pool)
+            .await
+            .unwrap();
+
+        let as_recorded =
+            database::Categories::find_all_as_of(days_ago(5), database::UserScope::All, &pool)
+                .await
+                .unwrap();
+        let today =
+            database::Categories::find_all_as_of(days_ago(0), database::UserScope::All, &pool)
+                .await
+                .unwrap();
+        let before_created =
+            database::Categories::find_all_as_of(days_ago(11), database::UserScope::All, &pool)
+                .await
+                .unwrap();
+
+        assert_eq!(as_recorded.len(), 1);
+        assert_eq!(as_recorded[0].id, category.id);
+        assert!(today.is_empty());
+        assert!(before_created.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_user_scope_hides_other_users_categories(pool: SqlitePool) {
+        let owner = database::Users::mock().insert(&pool).await.unwrap();
+        let other = database::Users::mock().insert(&pool).await.unwrap();
+        let mut category = database::Categories::mock();
+        category.user_id = Some(owner.id);
+        database::Categories::insert(&category, &pool)
+            .await
+            .unwrap();
+
+        let as_owner = database::Categories::find_by_id(
+            category.id,
+            database::UserScope::User(owner.id),
+            &pool,
+        )
+        .await
+        .unwrap();
+        let as_other = database::Categories::find_by_id(
+            category.id,
+            database::UserScope::User(other.id),
+            &pool,
+        )
+        .await
+        .unwrap();
+        let all_for_other =
+            database::Categories::find_all(database::UserScope::User(other.id), &pool)
+                .await
+                .unwrap();
+
+        assert_eq!(as_owner.map(|c| c.id), Some(category.id));
+        assert!(as_other.is_none());
+        assert!(all_for_other.is_empty());
+
+        category.name = "Not Yours".to_string();
+        let updated = category
+            .update(database::UserScope::User(other.id), &pool)
+            .await;
+        assert!(matches!(updated, Err(database::DatabaseError::NotFound(_))));
+        let deleted = category
+            .delete(database::UserScope::User(other.id), &pool)
+            .await;
+        assert!(matches!(deleted, Err(database::DatabaseError::NotFound(_))));
+    }
+
+    #[sqlx::test]
+    async fn test_find_by_public_id(pool: SqlitePool) {
+        let category = create_test_category(&pool).await;
+        let key = database::public_id_key(&pool).await.unwrap();
+
+        let found = database::Categories::find_by_public_id(
+            key.encode(category.id),
+            database::UserScope::All,
+            &pool,
+        )
+        .await
+        .unwrap();
+        let other_key = domain::PublicIdKey::new([9; 16]).encode(category.id);
+        let not_ours =
+            database::Categories::find_by_public_id(other_key, database::UserScope::All, &pool)
+                .await
+                .unwrap();
+
+        assert_eq!(found.map(|c| c.id), Some(category.id));
+        assert!(not_ours.is_none());
+    }
+}
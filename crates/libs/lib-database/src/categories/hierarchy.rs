@@ -0,0 +1,458 @@
+//! # Category Hierarchy
+//!
+//! Parent/child queries for categories. A category with no `parent_id` is a
+//! root, and any category can have children, so the hierarchy is a forest of
+//! trees, e.g. `Food > Groceries > Fruit & Veg`.
+//!
+//! Writes call [`Categories::ensure_valid_parent`](database::Categories) before a
+//! parent is set, so a category can never be placed under itself or one of its
+//! own descendants. Walks up the hierarchy are still capped at [`MAX_DEPTH`] in
+//! case a cycle was written to the database outside the application.
+
+use crate::query_metrics::Observe;
+use std::collections::BTreeMap;
+
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+/// Deepest level followed when walking up the hierarchy.
+pub const MAX_DEPTH: i64 = 64;
+
+/// A category and every category below it, as returned by
+/// [`Categories::find_tree`](database::Categories::find_tree).
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CategoryTree {
+    /// The category at this node.
+    pub category: database::Categories,
+
+    /// Direct children of the category, ordered by name.
+    pub children: Vec<CategoryTree>,
+}
+
+impl database::Categories {
+    /// Finds the direct children of a category, ordered by name.
+    ///
+    /// Returns an empty list when the category has no children in `scope` or
+    /// does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let food_id = RowID::new();
+    ///
+    /// for child in Categories::find_children(food_id, UserScope::All, pool).await? {
+    ///     println!("{}", child.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find category children",
+        skip(pool),
+        fields(id = %id),
+        err
+    )]
+    pub async fn find_children(
+        id: domain::RowID,
+        scope: database::UserScope,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let user_id = scope.user_id();
+        let children = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
+                FROM categories
+                WHERE parent_id = ?1 AND deleted_on IS NULL AND (?2 IS NULL OR user_id = ?2)
+                ORDER BY name
+            "#,
+            id,
+            user_id
+        )
+        .fetch_all(pool)
+        .observe("categories.find_children")
+        .await?;
+
+        Ok(children)
+    }
+
+    /// Finds the ancestors of a category, nearest first.
+    ///
+    /// The first entry is the parent and the last is the root of the tree. A
+    /// root category, or one that does not exist in `scope`, has no ancestors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let fruit_id = RowID::new();
+    ///
+    /// // Build a breadcrumb such as "Food > Groceries"
+    /// let ancestors = Categories::find_ancestors(fruit_id, UserScope::All, pool).await?;
+    /// let path: Vec<_> = ancestors.iter().rev().map(|c| c.name.as_str()).collect();
+    /// println!("{}", path.join(" > "));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find category ancestors",
+        skip(pool),
+        fields(id = %id),
+        err
+    )]
+    pub async fn find_ancestors(
+        id: domain::RowID,
+        scope: database::UserScope,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let max_depth = MAX_DEPTH;
+
+        let user_id = scope.user_id();
+        let ancestors = sqlx::query_as!(
+            database::Categories,
+            r#"
+                WITH RECURSIVE chain (id, depth) AS (
+                    SELECT parent_id, 1 FROM categories
+                    WHERE id = ?1 AND parent_id IS NOT NULL AND (?3 IS NULL OR user_id = ?3)
+                    UNION ALL
+                    SELECT c.parent_id, chain.depth + 1
+                    FROM categories c JOIN chain ON c.id = chain.id
+                    WHERE c.parent_id IS NOT NULL AND chain.depth < ?2
+                )
+                SELECT
+                    c.id            AS "id!: domain::RowID",
+                    c.code          AS "code!",
+                    c.name          AS "name!",
+                    c.description,
+                    c.url_slug      AS "url_slug?: domain::UrlSlug",
+                    c.category_type AS "category_type!: domain::CategoryTypes",
+                    c.color         AS "color?: domain::HexColor",
+                    c.icon,
+                    c.is_active     AS "is_active!: bool",
+                    c.parent_id     AS "parent_id?: domain::RowID",
+                    c.user_id       AS "user_id?: domain::RowID",
+                    c.created_on    AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    c.updated_on    AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    c.deleted_on    AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    c.version       AS "version!: i64"
+                FROM chain JOIN categories c ON c.id = chain.id
+                WHERE ?3 IS NULL OR c.user_id = ?3
+                ORDER BY chain.depth
+            "#,
+            id,
+            max_depth,
+            user_id
+        )
+        .fetch_all(pool)
+        .observe("categories.find_ancestors")
+        .await?;
+
+        Ok(ancestors)
+    }
+
+    /// Finds every category arranged as a forest of trees, one per root
+    /// category, from the categories in `scope`. Roots and the children at
+    /// each level are ordered by name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, CategoryTree, UserScope};
+    ///
+    /// fn print(node: &CategoryTree, depth: usize) {
+    ///     println!("{}{}", "  ".repeat(depth), node.category.name);
+    ///     node.children.iter().for_each(|child| print(child, depth + 1));
+    /// }
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for root in Categories::find_tree(UserScope::All, pool).await? {
+    ///     print(&root, 0);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find category tree", skip(pool), err)]
+    pub async fn find_tree(
+        scope: database::UserScope,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<CategoryTree>> {
+        let user_id = scope.user_id();
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
+                FROM categories
+                WHERE deleted_on IS NULL AND (?1 IS NULL OR user_id = ?1)
+                ORDER BY name
+            "#,
+            user_id
+        )
+        .fetch_all(pool)
+        .observe("categories.find_tree")
+        .await?;
+
+        let mut by_parent: BTreeMap<Option<domain::RowID>, Vec<Self>> = BTreeMap::new();
+        for category in categories {
+            by_parent
+                .entry(category.parent_id)
+                .or_default()
+                .push(category);
+        }
+
+        Ok(CategoryTree::build(None, &mut by_parent))
+    }
+
+    /// Checks the category's parent exists and that setting it would not
+    /// create a cycle, i.e. the parent is not the category itself or one of
+    /// its descendants.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` when the parent does not exist or
+    /// would create a cycle.
+    pub(crate) async fn ensure_valid_parent(
+        &self,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<()> {
+        let Some(parent_id) = self.parent_id else {
+            return Ok(());
+        };
+
+        if parent_id == self.id {
+            return Err(database::DatabaseError::Validation(format!(
+                "Category {} cannot be its own parent",
+                self.id
+            )));
+        }
+
+        let max_depth = MAX_DEPTH;
+
+        // The parent followed by each of its ancestors
+        let chain = sqlx::query_scalar!(
+            r#"
+                WITH RECURSIVE chain (id, depth) AS (
+                    SELECT id, 0 FROM categories WHERE id = ?1 AND deleted_on IS NULL
+                    UNION ALL
+                    SELECT c.parent_id, chain.depth + 1
+                    FROM categories c JOIN chain ON c.id = chain.id
+                    WHERE c.parent_id IS NOT NULL AND chain.depth < ?2
+                )
+                SELECT id AS "id!: domain::RowID" FROM chain ORDER BY depth
+            "#,
+            parent_id,
+            max_depth
+        )
+        .fetch_all(&mut *conn)
+        .observe("categories.ensure_valid_parent")
+        .await?;
+
+        if chain.is_empty() {
+            return Err(database::DatabaseError::Validation(format!(
+                "Parent category {} not found",
+                parent_id
+            )));
+        }
+
+        if chain.contains(&self.id) {
+            return Err(database::DatabaseError::Validation(format!(
+                "Category {} cannot be placed under its own descendant {}",
+                self.id, parent_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl CategoryTree {
+    /// Builds the trees below `parent`, taking each category out of
+    /// `by_parent` as it is placed so a cycle can never be visited twice.
+    fn build(
+        parent: Option<domain::RowID>,
+        by_parent: &mut BTreeMap<Option<domain::RowID>, Vec<database::Categories>>,
+    ) -> Vec<Self> {
+        by_parent
+            .remove(&parent)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|category| {
+                let children = Self::build(Some(category.id), by_parent);
+                Self { category, children }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::insert::tests::Result;
+    use sqlx::SqlitePool;
+
+    async fn insert(
+        name: &str,
+        parent_id: Option<domain::RowID>,
+        pool: &SqlitePool,
+    ) -> database::Categories {
+        database::CategoriesBuilder::new()
+            .with_code(name.to_uppercase())
+            .with_name(name)
+            .with_category_type(domain::CategoryTypes::Expense)
+            .with_parent_id_opt(parent_id)
+            .build()
+            .unwrap()
+            .insert(pool)
+            .await
+            .unwrap()
+    }
+
+    fn names(categories: &[database::Categories]) -> Vec<&str> {
+        categories.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[sqlx::test]
+    async fn find_children_and_ancestors(pool: SqlitePool) -> Result<()> {
+        let food = insert("Food", None, &pool).await;
+        let groceries = insert("Groceries", Some(food.id), &pool).await;
+        let dining = insert("Dining", Some(food.id), &pool).await;
+        let fruit = insert("Fruit", Some(groceries.id), &pool).await;
+
+        let children =
+            database::Categories::find_children(food.id, database::UserScope::All, &pool).await?;
+        let ancestors =
+            database::Categories::find_ancestors(fruit.id, database::UserScope::All, &pool).await?;
+
+        assert_eq!(names(&children), vec!["Dining", "Groceries"]);
+        assert_eq!(names(&ancestors), vec!["Groceries", "Food"]);
+        assert!(
+            database::Categories::find_children(dining.id, database::UserScope::All, &pool)
+                .await?
+                .is_empty()
+        );
+        assert!(
+            database::Categories::find_ancestors(food.id, database::UserScope::All, &pool)
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_tree_nests_children_under_roots(pool: SqlitePool) -> Result<()> {
+        let food = insert("Food", None, &pool).await;
+        let groceries = insert("Groceries", Some(food.id), &pool).await;
+        insert("Fruit", Some(groceries.id), &pool).await;
+        insert("Transport", None, &pool).await;
+
+        let tree = database::Categories::find_tree(database::UserScope::All, &pool).await?;
+
+        let roots: Vec<_> = tree
+            .iter()
+            .map(|node| node.category.name.as_str())
+            .collect();
+        assert_eq!(roots, vec!["Food", "Transport"]);
+        assert_eq!(tree[0].children[0].category.name, "Groceries");
+        assert_eq!(tree[0].children[0].children[0].category.name, "Fruit");
+        assert!(tree[1].children.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_rejects_missing_parent(pool: SqlitePool) -> Result<()> {
+        let category = database::CategoriesBuilder::new()
+            .with_code("ORPHAN")
+            .with_name("Orphan")
+            .with_category_type(domain::CategoryTypes::Expense)
+            .with_parent_id(domain::RowID::new())
+            .build()?;
+
+        let result = category.insert(&pool).await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_rejects_cycles(pool: SqlitePool) -> Result<()> {
+        let food = insert("Food", None, &pool).await;
+        let groceries = insert("Groceries", Some(food.id), &pool).await;
+        let fruit = insert("Fruit", Some(groceries.id), &pool).await;
+
+        let own_parent = database::Categories {
+            parent_id: Some(food.id),
+            ..food.clone()
+        };
+        let under_grandchild = database::Categories {
+            parent_id: Some(fruit.id),
+            ..food.clone()
+        };
+
+        let own_parent = own_parent.update(database::UserScope::All, &pool).await;
+        let under_grandchild = under_grandchild
+            .update(database::UserScope::All, &pool)
+            .await;
+
+        assert!(matches!(
+            own_parent,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            under_grandchild,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        // Moving a leaf to another branch is fine
+        let moved = database::Categories {
+            parent_id: Some(food.id),
+            ..fruit
+        }
+        .update(database::UserScope::All, &pool)
+        .await?;
+        assert_eq!(moved.parent_id, Some(food.id));
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
-use crate::database::{self, DatabaseResult};
-use crate::domain;
-
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
 
 impl database::Categories {
     /// Inserts a new category into the database.
@@ -12,7 +12,7 @@ impl database::Categories {
     ///
     /// # Arguments
     ///
-    /// * `pool` - The database connection pool
+    /// * `conn` - The database pool, connection or transaction to run on
     ///
     /// # Returns
     ///
@@ -30,9 +30,9 @@ impl database::Categories {
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
-    /// use personal_ledger_backend::domain::CategoryTypes;
+    /// use lib_database::Categories;
+    /// use lib_database::DatabasePool;
+    /// use lib_domain::CategoryTypes;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// // Create a database connection
@@ -42,8 +42,8 @@ impl database::Categories {
     /// let pool = db.get_pool()?;
     ///
     /// // Create a new category
-    /// let category = Category {
-    ///     id: personal_ledger_backend::domain::RowID::new(),
+    /// let category = Categories {
+    ///     id: lib_domain::RowID::new(),
     ///     code: "FOOD.001".to_string(),
     ///     name: "Groceries".to_string(),
     ///     description: Some("Food and beverage expenses".to_string()),
@@ -52,8 +52,12 @@ impl database::Categories {
     ///     color: Some("#FF5733".parse()?),
     ///     icon: Some("shopping-cart".to_string()),
     ///     is_active: true,
+    ///     parent_id: None,
+    ///     user_id: None,
     ///     created_on: chrono::Utc::now(),
     ///     updated_on: chrono::Utc::now(),
+    ///     deleted_on: None,
+    ///     version: 1,
     /// };
     ///
     /// // Insert into database
@@ -69,9 +73,9 @@ impl database::Categories {
     /// Using the builder pattern for cleaner construction:
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::{Category, CategoriesBuilder};
-    /// use use lib_database::DatabasePool;
-    /// use personal_ledger_backend::domain::CategoryTypes;
+    /// use lib_database::{Categories, CategoriesBuilder};
+    /// use lib_database::DatabasePool;
+    /// use lib_domain::CategoryTypes;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// // Create a database connection
@@ -93,7 +97,7 @@ impl database::Categories {
     /// ```
     #[tracing::instrument(
         name = "Insert new Category into database: ",
-        skip(self, pool),
+        skip(self, conn),
         fields(
             id = % self.id,
             code = % self.code,
@@ -108,59 +112,84 @@ impl database::Categories {
             updated_on = % self.updated_on,
         ),
     )]
-    pub async fn insert(&self, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<Self> {
-        // 1) INSERT: SQLite uses `?` placeholders and does not reliably support
-        // `RETURNING *` for compile-time checked macros. Execute the insert first.
-        let insert_query = sqlx::query!(
-            r#"
-                INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, created_on, updated_on)
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-            "#,
-            self.id,
-            self.code,
-            self.name,
-            self.description,
-            self.url_slug,
-            self.category_type,
-            self.color,
-            self.icon,
-            self.is_active,
-            self.created_on,
-            self.updated_on
-        );
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            self.ensure_valid_parent(&mut conn).await?;
+
+            // 1) INSERT: SQLite uses `?` placeholders and does not reliably support
+            // `RETURNING *` for compile-time checked macros. Execute the insert first.
+            let insert_query = sqlx::query!(
+                r#"
+                    INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, created_on, updated_on, parent_id, user_id)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.code,
+                self.name,
+                self.description,
+                self.url_slug,
+                self.category_type,
+                self.color,
+                self.icon,
+                self.is_active,
+                self.created_on,
+                self.updated_on,
+                self.parent_id,
+                self.user_id
+            );
 
-        insert_query.execute(pool).await?;
+            insert_query
+                .execute(&mut *conn)
+                .observe("categories.insert")
+                .await?;
 
-        tracing::info!("New Category inserted into the database.");
+            tracing::info!("New Category inserted into the database.");
 
-        // 2) SELECT: Read back the inserted row with explicit type annotations
-        // for UUID and chrono types to avoid NULL/mapping issues in SQLite.
-        let category = sqlx::query_as!(
-            database::Categories,
-            r#"
-                SELECT
-                    id              AS "id!: domain::RowID",
-                    code,
-                    name,
-                    description,
-                    url_slug        AS "url_slug?: domain::UrlSlug",
-                    category_type   AS "category_type!: domain::CategoryTypes",
-                    color           AS "color?: domain::HexColor",
-                    icon,
-                    is_active       AS "is_active!: bool",
-                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
-                FROM categories
-                WHERE id = ?
-            "#,
-            self.id
-        )
-        .fetch_one(pool)
-        .await?;
+            // 2) SELECT: Read back the inserted row with explicit type annotations
+            // for UUID and chrono types to avoid NULL/mapping issues in SQLite.
+            let category = sqlx::query_as!(
+                database::Categories,
+                r#"
+                    SELECT
+                        id              AS "id!: domain::RowID",
+                        code,
+                        name,
+                        description,
+                        url_slug        AS "url_slug?: domain::UrlSlug",
+                        category_type   AS "category_type!: domain::CategoryTypes",
+                        color           AS "color?: domain::HexColor",
+                        icon,
+                        is_active       AS "is_active!: bool",
+                        parent_id       AS "parent_id?: domain::RowID",
+                        user_id         AS "user_id?: domain::RowID",
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                        version
+                    FROM categories
+                    WHERE id = ?
+                "#,
+                self.id
+            )
+            .fetch_one(&mut *conn)
+            .observe_one("categories.insert")
+            .await?;
+
+            tracing::debug!("Newly created Category retrived from the database.");
 
-        tracing::debug!("Newly created Category retrived from the database.");
+            database::events::publish(database::DataChanges::CategoryInserted(category.clone()));
 
-        Ok(category)
+            Ok(category)
+        }
     }
 
     /// Inserts multiple categories into the database in a single transaction.
@@ -172,7 +201,7 @@ impl database::Categories {
     /// # Arguments
     ///
     /// * `categories` - A slice of categories to insert
-    /// * `pool` - The database connection pool
+    /// * `conn` - The database pool, connection or transaction to run on
     ///
     /// # Returns
     ///
@@ -189,8 +218,8 @@ impl database::Categories {
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
+    /// use lib_database::{Categories, CategoriesBuilder};
+    /// use lib_database::DatabasePool;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// // Create a database connection
@@ -200,88 +229,121 @@ impl database::Categories {
     /// let pool = db.get_pool()?;
     ///
     /// let categories = vec![
-    ///     Category::mock(),
-    ///     Category::mock(),
+    ///     CategoriesBuilder::new()
+    ///         .with_code("FOOD.001")
+    ///         .with_name("Groceries")
+    ///         .with_category_type(lib_domain::CategoryTypes::Expense)
+    ///         .build()?,
+    ///     CategoriesBuilder::new()
+    ///         .with_code("FOOD.002")
+    ///         .with_name("Dining Out")
+    ///         .with_category_type(lib_domain::CategoryTypes::Expense)
+    ///         .build()?,
     /// ];
     ///
-    /// let inserted = Category::insert_many(&categories, pool).await?;
+    /// let inserted = Categories::insert_many(&categories, pool).await?;
     /// assert_eq!(inserted.len(), 2);
     /// # Ok(())
     /// # }
     /// ```
     #[tracing::instrument(
         name = "Bulk insert categories into database",
-        skip(categories, pool),
+        skip(categories, conn),
         fields(count = categories.len())
     )]
-    pub async fn insert_many(
-        categories: &[Self],
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<Vec<Self>> {
-        if categories.is_empty() {
-            return Ok(Vec::new());
-        }
-
-        // Use a transaction for atomicity
-        let mut tx = pool.begin().await?;
-
-        let mut inserted_categories = Vec::with_capacity(categories.len());
-
-        for category in categories {
-            // Insert each category
-            let insert_query = sqlx::query!(
-                r#"
-                    INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, created_on, updated_on)
-                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-                "#,
-                category.id,
-                category.code,
-                category.name,
-                category.description,
-                category.url_slug,
-                category.category_type,
-                category.color,
-                category.icon,
-                category.is_active,
-                category.created_on,
-                category.updated_on
+    pub fn insert_many<'a, 'c, A>(
+        categories: &'a [Self],
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            if categories.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            // Use a transaction for atomicity
+            let mut tx = conn.begin().await?;
+
+            let mut inserted_categories = Vec::with_capacity(categories.len());
+
+            for category in categories {
+                category.ensure_valid_parent(&mut tx).await?;
+
+                // Insert each category
+                let insert_query = sqlx::query!(
+                    r#"
+                        INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, created_on, updated_on, parent_id, user_id)
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    "#,
+                    category.id,
+                    category.code,
+                    category.name,
+                    category.description,
+                    category.url_slug,
+                    category.category_type,
+                    category.color,
+                    category.icon,
+                    category.is_active,
+                    category.created_on,
+                    category.updated_on,
+                    category.parent_id,
+                    category.user_id
+                );
+
+                insert_query
+                    .execute(&mut *tx)
+                    .observe("categories.insert_many")
+                    .await?;
+
+                // Read back the inserted category
+                let inserted = sqlx::query_as!(
+                    database::Categories,
+                    r#"
+                        SELECT
+                            id              AS "id!: domain::RowID",
+                            code,
+                            name,
+                            description,
+                            url_slug        AS "url_slug?: domain::UrlSlug",
+                            category_type   AS "category_type!: domain::CategoryTypes",
+                            color           AS "color?: domain::HexColor",
+                            icon,
+                            is_active       AS "is_active!: bool",
+                            parent_id       AS "parent_id?: domain::RowID",
+                            user_id         AS "user_id?: domain::RowID",
+                            created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                            updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                            deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                            version
+                        FROM categories
+                        WHERE id = ?
+                    "#,
+                    category.id
+                )
+                .fetch_one(&mut *tx)
+                .observe_one("categories.insert_many")
+                .await?;
+
+                database::events::publish(database::DataChanges::CategoryInserted(
+                    inserted.clone(),
+                ));
+
+                inserted_categories.push(inserted);
+            }
+
+            // Commit the transaction
+            tx.commit().await?;
+
+            tracing::info!(
+                "Successfully inserted {} categories into database",
+                inserted_categories.len()
             );
 
-            insert_query.execute(&mut *tx).await?;
-
-            // Read back the inserted category
-            let inserted = sqlx::query_as!(
-                database::Categories,
-                r#"
-                    SELECT
-                        id              AS "id!: domain::RowID",
-                        code,
-                        name,
-                        description,
-                        url_slug        AS "url_slug?: domain::UrlSlug",
-                        category_type   AS "category_type!: domain::CategoryTypes",
-                        color           AS "color?: domain::HexColor",
-                        icon,
-                        is_active       AS "is_active!: bool",
-                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
-                    FROM categories
-                    WHERE id = ?
-                "#,
-                category.id
-            )
-            .fetch_one(&mut *tx)
-            .await?;
-
-            inserted_categories.push(inserted);
+            Ok(inserted_categories)
         }
-
-        // Commit the transaction
-        tx.commit().await?;
-
-        tracing::info!("Successfully inserted {} categories into database", inserted_categories.len());
-
-        Ok(inserted_categories)
     }
 
     /// Inserts a category or updates it if it already exists (upsert).
@@ -290,13 +352,13 @@ impl database::Categories {
     /// `id` already exists, it updates the existing record instead. This is useful
     /// for idempotent operations or data synchronization.
     ///
-    /// Note: This function updates all fields except `id` and `created_on` when
+    /// Note: This function updates all fields except `id`, `user_id` and `created_on` when
     /// performing an update.
     ///
     /// # Arguments
     ///
     /// * `category` - The category to insert or update
-    /// * `pool` - The database connection pool
+    /// * `conn` - The database pool, connection or transaction to run on
     ///
     /// # Returns
     ///
@@ -311,8 +373,8 @@ impl database::Categories {
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
+    /// use lib_database::{Categories, CategoriesBuilder};
+    /// use lib_database::DatabasePool;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// // Create a database connection
@@ -321,13 +383,17 @@ impl database::Categories {
     ///     .await?;
     /// let pool = db.get_pool()?;
     ///
-    /// let category = Category::mock();
+    /// let category = CategoriesBuilder::new()
+    ///     .with_code("FOOD.001")
+    ///     .with_name("Groceries")
+    ///     .with_category_type(lib_domain::CategoryTypes::Expense)
+    ///     .build()?;
     ///
     /// // First call inserts
-    /// let result1 = Category::insert_or_update(&category, pool).await?;
+    /// let result1 = Categories::insert_or_update(&category, pool).await?;
     ///
     /// // Second call with same ID updates
-    /// let result2 = Category::insert_or_update(&category, pool).await?;
+    /// let result2 = Categories::insert_or_update(&category, pool).await?;
     ///
     /// assert_eq!(result1.id, result2.id);
     /// # Ok(())
@@ -335,18 +401,22 @@ impl database::Categories {
     /// ```
     #[tracing::instrument(
         name = "Insert or update category in database",
-        skip(category, pool),
+        skip(category, conn),
         fields(id = %category.id, code = %category.code)
     )]
-    pub async fn insert_or_update(
-        category: &Self,
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<Self> {
+    pub async fn insert_or_update<'c, A>(category: &Self, conn: A) -> DatabaseResult<Self>
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite>,
+    {
+        let mut conn = conn.acquire().await?;
+
+        category.ensure_valid_parent(&mut conn).await?;
+
         // Use SQLite's UPSERT syntax (INSERT ... ON CONFLICT)
         let upsert_query = sqlx::query!(
             r#"
-                INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, created_on, updated_on)
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, created_on, updated_on, parent_id, user_id)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 ON CONFLICT(id) DO UPDATE SET
                     code = excluded.code,
                     name = excluded.name,
@@ -356,7 +426,8 @@ impl database::Categories {
                     color = excluded.color,
                     icon = excluded.icon,
                     is_active = excluded.is_active,
-                    updated_on = excluded.updated_on
+                    updated_on = excluded.updated_on,
+                    parent_id = excluded.parent_id
                 WHERE id = excluded.id
             "#,
             category.id,
@@ -369,10 +440,15 @@ impl database::Categories {
             category.icon,
             category.is_active,
             category.created_on,
-            category.updated_on
+            category.updated_on,
+            category.parent_id,
+            category.user_id
         );
 
-        upsert_query.execute(pool).await?;
+        upsert_query
+            .execute(&mut *conn)
+            .observe("categories.insert_or_update")
+            .await?;
 
         // Read back the inserted/updated category
         let result = sqlx::query_as!(
@@ -388,14 +464,19 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
                 FROM categories
                 WHERE id = ?
             "#,
             category.id
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *conn)
+        .observe_one("categories.insert_or_update")
         .await?;
 
         tracing::info!("Category upserted successfully: {}", result.id);
@@ -455,11 +536,7 @@ pub mod tests {
         use fake::faker::lorem::en::Word;
 
         let has_icon: bool = Boolean(60).fake(); // 60% chance of having icon
-        if has_icon {
-            Some(Word().fake())
-        } else {
-            None
-        }
+        if has_icon { Some(Word().fake()) } else { None }
     }
 
     #[cfg(test)]
@@ -482,8 +559,12 @@ pub mod tests {
             color: domain::HexColor::mock_with_option(),
             icon: generate_fake_icon(),
             is_active: Boolean(85).fake(), // 85% chance of active
+            parent_id: None,
+            user_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
+            version: 1,
         }
     }
 
@@ -521,8 +602,12 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            parent_id: None,
+            user_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
+            version: 1,
         };
 
         let inserted = category.insert(&pool).await?;
@@ -542,7 +627,8 @@ pub mod tests {
     async fn insert_category_with_all_fields(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
         let fake_code = generate_fake_code();
         let fake_name = generate_fake_name();
-        let fake_description = generate_fake_description().unwrap_or_else(|| "Test description".to_string());
+        let fake_description =
+            generate_fake_description().unwrap_or_else(|| "Test description".to_string());
         let fake_icon = generate_fake_icon().unwrap_or_else(|| "test-icon".to_string());
 
         let color = domain::HexColor::parse("#FF5733")?;
@@ -558,13 +644,20 @@ pub mod tests {
             color: Some(color.clone()),
             icon: Some(fake_icon.clone()),
             is_active: false,
+            parent_id: None,
+            user_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
+            version: 1,
         };
 
         let inserted = category.insert(&pool).await?;
 
-        assert_eq!(inserted.description.as_deref(), Some(fake_description.as_str()));
+        assert_eq!(
+            inserted.description.as_deref(),
+            Some(fake_description.as_str())
+        );
         assert_eq!(inserted.url_slug.as_ref(), Some(&slug));
         assert_eq!(inserted.color.as_ref(), Some(&color));
         assert_eq!(inserted.icon.as_deref(), Some(fake_icon.as_str()));
@@ -589,8 +682,12 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            parent_id: None,
+            user_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
+            version: 1,
         };
 
         let category2 = database::Categories {
@@ -603,8 +700,12 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            parent_id: None,
+            user_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
+            version: 1,
         };
 
         // First insert should succeed
@@ -633,8 +734,12 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            parent_id: None,
+            user_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
+            version: 1,
         };
 
         let category2 = database::Categories {
@@ -647,8 +752,12 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            parent_id: None,
+            user_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
+            version: 1,
         };
 
         // First insert should succeed
@@ -685,8 +794,12 @@ pub mod tests {
             color: Some(valid_color),
             icon: None,
             is_active: true,
+            parent_id: None,
+            user_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
+            version: 1,
         };
 
         // This should succeed since our domain type validates the color
@@ -744,9 +857,12 @@ pub mod tests {
 
         // Verify neither category was inserted due to transaction rollback
         let code_prefix = format!("{}%", duplicate_code.split('.').next().unwrap_or(""));
-        let count_query = sqlx::query!("SELECT COUNT(*) as count FROM categories WHERE code LIKE ?", code_prefix)
-            .fetch_one(&pool)
-            .await?;
+        let count_query = sqlx::query!(
+            "SELECT COUNT(*) as count FROM categories WHERE code LIKE ?",
+            code_prefix
+        )
+        .fetch_one(&pool)
+        .await?;
 
         assert_eq!(count_query.count, 0);
 
@@ -804,6 +920,8 @@ pub mod tests {
         // Update
         let updated_category = database::Categories {
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
+            version: 1,
             ..category
         };
 
@@ -815,7 +933,9 @@ pub mod tests {
     }
 
     #[sqlx::test]
-    async fn insert_or_update_fails_on_constraint_violation(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+    async fn insert_or_update_fails_on_constraint_violation(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
         let duplicate_code = generate_fake_code();
         let fake_name1 = generate_fake_name();
         let fake_name2 = generate_fake_name();
@@ -831,8 +951,12 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            parent_id: None,
+            user_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
+            version: 1,
         };
 
         database::Categories::insert_or_update(&category1, &pool).await?;
@@ -840,7 +964,7 @@ pub mod tests {
         // Try to upsert a different category with same code (but different ID)
         let category2 = database::Categories {
             id: domain::RowID::new(), // Different ID
-            code: duplicate_code, // Same code - should fail
+            code: duplicate_code,     // Same code - should fail
             name: fake_name2,
             description: None,
             url_slug: None,
@@ -848,8 +972,12 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            parent_id: None,
+            user_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
+            version: 1,
         };
 
         let result = database::Categories::insert_or_update(&category2, &pool).await;
@@ -871,4 +999,4 @@ pub mod tests {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -4,18 +4,48 @@
 //! category records in the persistence layer. The module exposes the
 //! database representation of a category alongside utilities for creating
 //! and inserting records during tests or data seeding.
+//!
+//! Categories can be soft-deleted, which hides them from lookups and lists
+//! until they are restored, or deleted for good. A category still used by
+//! transactions, splits or other categories is only deleted for good with
+//! `Categories::delete_with_reassign`, which moves its uses to another
+//! category first.
+//!
+//! `Categories::statistics` summarises the categories and how much they are
+//! used in one query, for dashboards. `Categories::archive` deactivates a
+//! category that is no longer wanted, refusing one still in recent use
+//! unless forced.
 
 // #![allow(unused)] // For development only
 
+mod archive;
 mod builder;
-mod model;
-mod insert;
-mod update;
 mod delete;
+mod filter;
 mod find;
+mod hierarchy;
+mod insert;
+mod model;
+mod partial;
+mod search;
+mod soft_delete;
+mod statistics;
+mod update;
 
 /// Database row model representing a persisted category.
 pub use model::Categories;
 
-/// Fluent builder for constructing `Category` instances in tests and fixtures.
-pub use builder::CategoriesBuilder;
\ No newline at end of file
+/// Fluent builder for constructing `Categories` instances in tests and fixtures.
+pub use builder::CategoriesBuilder;
+
+/// A category with its descendants, returned by `Categories::find_tree`.
+pub use hierarchy::CategoryTree;
+
+/// Filter and sort order for `Categories::find_with_filters`.
+pub use filter::{CategoryFilter, CategorySortColumns};
+
+/// Columns written by `Categories::update_partial`.
+pub use partial::CategoryFields;
+
+/// A summary of categories and their use, returned by `Categories::statistics`.
+pub use statistics::{CategoryStatistics, CategoryTypeCount, CategoryUsage};
@@ -1,5 +1,5 @@
-use crate::{database, domain};
-
+use crate as database;
+use lib_domain as domain;
 
 // TODO: Move code into a domain type
 #[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
@@ -13,27 +13,42 @@ pub struct Categories {
     pub color: Option<domain::HexColor>,
     pub icon: Option<String>,
     pub is_active: bool,
+    pub parent_id: Option<domain::RowID>,
+
+    /// The user who owns the category, or `None` for categories recorded
+    /// before there were users.
+    pub user_id: Option<domain::RowID>,
     pub created_on: chrono::DateTime<chrono::Utc>,
     pub updated_on: chrono::DateTime<chrono::Utc>,
+
+    /// When the category was soft-deleted, or `None` if it is not deleted.
+    /// Set by `Categories::delete_soft` and cleared by `Categories::restore`,
+    /// never written by insert or update.
+    pub deleted_on: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Number of times the category has been written, starting at 1. An
+    /// update only applies to the version it was read at, see
+    /// [`DatabaseError::Conflict`](database::DatabaseError::Conflict).
+    pub version: i64,
 }
 
 impl database::Categories {
-    /// Generates a mock `Category` instance with randomized test data.
+    /// Generates a mock `Categories` instance with randomized test data.
     ///
     /// This function creates realistic test data for categories, using the `fake` crate
     /// to randomise optional fields and text content. Useful for unit and integration tests.
     ///
     /// # Examples
     ///
-    /// ```rust
-    /// use use lib_database::categories::model::Category;
+    /// ```rust,ignore
+    /// use lib_database::Categories;
     ///
-    /// let mock_category = Category::mock();
+    /// let mock_category = Categories::mock();
     /// assert!(!mock_category.name.is_empty());
     /// ```
     #[cfg(test)]
     pub fn mock() -> Self {
-        use crate::database::categories::CategoriesBuilder;
+        use crate::categories::CategoriesBuilder;
 
         CategoriesBuilder::new()
             .with_id(domain::RowID::mock())
@@ -71,8 +86,13 @@ impl database::Categories {
         use fake::Fake;
         use fake::faker::lorem::en::Words;
 
+        use rand::Rng;
+
+        // Names and slugs are unique, so a random word keeps two mocks apart
         let words: Vec<String> = Words(1..3).fake();
-        words.join(" ")
+        let mut rng = rand::rng();
+        let suffix: String = (0..6).map(|_| rng.random_range('a'..='z')).collect();
+        format!("{} {suffix}", words.join(" "))
     }
 
     #[cfg(test)]
@@ -102,11 +122,7 @@ impl database::Categories {
         use fake::faker::lorem::en::Word;
 
         let is_some: bool = Boolean(50).fake(); // 50% chance of Some
-        if is_some {
-            Some(Word().fake())
-        } else {
-            None
-        }
+        if is_some { Some(Word().fake()) } else { None }
     }
 
     #[cfg(test)]
@@ -159,7 +175,10 @@ mod tests {
             }
         }
 
-        assert!(has_some_description && has_none_description, "Description should randomize");
+        assert!(
+            has_some_description && has_none_description,
+            "Description should randomize"
+        );
         assert!(has_some_icon && has_none_icon, "Icon should randomize");
         assert!(has_inactive, "is_active should sometimes be false");
     }
@@ -189,9 +208,9 @@ mod tests {
         let mut has_none = false;
         for _ in 0..20 {
             let desc = Categories::generate_mock_description();
-            if desc.is_some() {
+            if let Some(desc) = &desc {
                 has_some = true;
-                assert!(!desc.as_ref().unwrap().is_empty());
+                assert!(!desc.is_empty());
             } else {
                 has_none = true;
             }
@@ -213,10 +232,10 @@ mod tests {
         let mut has_none = false;
         for _ in 0..20 {
             let icon = Categories::generate_mock_icon();
-            if icon.is_some() {
+            if let Some(icon) = &icon {
                 has_some = true;
-                assert!(!icon.as_ref().unwrap().is_empty());
-                assert!(icon.as_ref().unwrap().chars().all(|c| c.is_alphabetic()));
+                assert!(!icon.is_empty());
+                assert!(icon.chars().all(|c| c.is_alphabetic()));
             } else {
                 has_none = true;
             }
@@ -254,4 +273,4 @@ mod tests {
         let deserialized: Categories = serde_json::from_str(&json).unwrap();
         assert_eq!(cat1, deserialized);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,302 @@
+//! # Partial Category Updates
+//!
+//! Builds an UPDATE for [`Categories::update_partial`](crate::Categories::update_partial)
+//! with `sqlx::QueryBuilder`, setting only the columns asked for, so a client
+//! changing one field does not write back stale values for the others. The
+//! columns are chosen from [`CategoryFields`], never taken from the caller as
+//! text, and values are always bound as parameters.
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+
+/// The client editable columns of a category, updated one by one with
+/// [`Categories::update_partial`](crate::Categories::update_partial).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CategoryFields {
+    /// The category code.
+    Code,
+    /// The category name.
+    Name,
+    /// The markdown description.
+    Description,
+    /// The URL slug.
+    UrlSlug,
+    /// The category type.
+    CategoryType,
+    /// The hex colour.
+    Color,
+    /// The icon name.
+    Icon,
+    /// Whether the category is active.
+    IsActive,
+    /// The parent category.
+    ParentId,
+}
+
+impl CategoryFields {
+    /// Returns the column name, as accepted by `from_str`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CategoryFields::Code => "code",
+            CategoryFields::Name => "name",
+            CategoryFields::Description => "description",
+            CategoryFields::UrlSlug => "url_slug",
+            CategoryFields::CategoryType => "category_type",
+            CategoryFields::Color => "color",
+            CategoryFields::Icon => "icon",
+            CategoryFields::IsActive => "is_active",
+            CategoryFields::ParentId => "parent_id",
+        }
+    }
+}
+
+impl std::fmt::Display for CategoryFields {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for CategoryFields {
+    type Err = DatabaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "code" => Ok(CategoryFields::Code),
+            "name" => Ok(CategoryFields::Name),
+            "description" => Ok(CategoryFields::Description),
+            "url_slug" => Ok(CategoryFields::UrlSlug),
+            "category_type" => Ok(CategoryFields::CategoryType),
+            "color" => Ok(CategoryFields::Color),
+            "icon" => Ok(CategoryFields::Icon),
+            "is_active" => Ok(CategoryFields::IsActive),
+            "parent_id" => Ok(CategoryFields::ParentId),
+            _ => Err(DatabaseError::Validation(format!(
+                "Cannot update category field '{}', expected code, name, description, url_slug, \
+                 category_type, color, icon, is_active or parent_id",
+                s
+            ))),
+        }
+    }
+}
+
+impl database::Categories {
+    /// Updates only the given `fields` of an existing category, leaving its
+    /// other columns as they are stored.
+    ///
+    /// The values are taken from `self`, and `updated_on` is always written.
+    /// As with [`update`](Self::update) the category must still be at the
+    /// `version` it was read at, and the version is then incremented. Fields
+    /// named more than once are written once.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - The columns to update
+    /// * `scope` - The user whose categories can be updated
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated category as read back from the database.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - `fields` is empty (`DatabaseError::Validation`)
+    /// - The parent is updated to one that does not exist or would create a
+    ///   cycle (`DatabaseError::Validation`)
+    /// - The category does not exist, is soft-deleted, or is outside `scope`
+    ///   (`DatabaseError::NotFound`)
+    /// - The category was changed since it was read (`DatabaseError::Conflict`)
+    /// - The updated columns violate database constraints
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, CategoryFields, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut category = Categories::find_by_id(id, UserScope::All, pool).await?.unwrap();
+    /// category.name = "Groceries".to_string();
+    ///
+    /// let updated = category.update_partial(&[CategoryFields::Name], UserScope::All, pool).await?;
+    /// assert_eq!(updated.name, "Groceries");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Partially update category in database",
+        skip(self, conn),
+        fields(id = % self.id),
+        err
+    )]
+    pub fn update_partial<'a, 'c, A>(
+        &'a self,
+        fields: &'a [CategoryFields],
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            if fields.is_empty() {
+                return Err(DatabaseError::Validation(format!(
+                    "No fields given to update category {}",
+                    self.id
+                )));
+            }
+
+            let mut conn = conn.acquire().await?;
+
+            if fields.contains(&CategoryFields::ParentId) {
+                self.ensure_valid_parent(&mut conn).await?;
+            }
+
+            let mut query = sqlx::QueryBuilder::new("UPDATE categories SET ");
+            let mut columns = query.separated(", ");
+            let mut written = std::collections::HashSet::new();
+            for field in fields.iter().filter(|field| written.insert(**field)) {
+                columns.push(field.as_str()).push_unseparated(" = ");
+                match field {
+                    CategoryFields::Code => columns.push_bind_unseparated(self.code.clone()),
+                    CategoryFields::Name => columns.push_bind_unseparated(self.name.clone()),
+                    CategoryFields::Description => {
+                        columns.push_bind_unseparated(self.description.clone())
+                    }
+                    CategoryFields::UrlSlug => columns.push_bind_unseparated(self.url_slug.clone()),
+                    CategoryFields::CategoryType => {
+                        columns.push_bind_unseparated(self.category_type.clone())
+                    }
+                    CategoryFields::Color => columns.push_bind_unseparated(self.color.clone()),
+                    CategoryFields::Icon => columns.push_bind_unseparated(self.icon.clone()),
+                    CategoryFields::IsActive => columns.push_bind_unseparated(self.is_active),
+                    CategoryFields::ParentId => columns.push_bind_unseparated(self.parent_id),
+                };
+            }
+            columns
+                .push("updated_on = ")
+                .push_bind_unseparated(self.updated_on);
+            columns.push("version = version + 1");
+
+            query
+                .push(" WHERE id = ")
+                .push_bind(self.id)
+                .push(" AND deleted_on IS NULL AND version = ")
+                .push_bind(self.version);
+            if let Some(user_id) = scope.user_id() {
+                query.push(" AND user_id = ").push_bind(user_id);
+            }
+
+            let rows_affected = query
+                .build()
+                .execute(&mut *conn)
+                .observe("categories.update_partial")
+                .await?
+                .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(self.update_error(scope, &mut conn).await);
+            }
+
+            tracing::info!(
+                "Updated {} fields of category {} in database",
+                written.len(),
+                self.id
+            );
+
+            let updated = Self::fetch_including_deleted(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    DatabaseError::NotFound(format!(
+                        "Category with id {} not found after update",
+                        self.id
+                    ))
+                })?;
+
+            database::events::publish(database::DataChanges::CategoryUpdated(updated.clone()));
+
+            Ok(updated)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[test]
+    fn fields_parse_from_their_column_names() {
+        assert_eq!(
+            "url_slug".parse::<CategoryFields>().unwrap(),
+            CategoryFields::UrlSlug
+        );
+        assert_eq!(
+            " Name ".parse::<CategoryFields>().unwrap(),
+            CategoryFields::Name
+        );
+        assert_eq!(CategoryFields::IsActive.to_string(), "is_active");
+        assert!(matches!(
+            "id".parse::<CategoryFields>(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[sqlx::test]
+    async fn update_partial_writes_only_the_given_fields(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let inserted = database::Categories::mock().insert(&pool).await?;
+        let changed = database::Categories {
+            name: "Groceries".to_string(),
+            code: "CHANGED.BUT.UNMASKED".to_string(),
+            updated_on: chrono::Utc::now(),
+            ..inserted.clone()
+        };
+
+        let updated = changed
+            .update_partial(
+                &[CategoryFields::Name, CategoryFields::Name],
+                database::UserScope::All,
+                &pool,
+            )
+            .await?;
+
+        assert_eq!(updated.name, "Groceries");
+        assert_eq!(updated.code, inserted.code);
+        assert_eq!(updated.version, inserted.version + 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_partial_checks_the_version(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Categories::mock().insert(&pool).await?;
+        let renamed = database::Categories {
+            name: "Groceries".to_string(),
+            ..inserted.clone()
+        };
+        renamed
+            .update_partial(&[CategoryFields::Name], database::UserScope::All, &pool)
+            .await?;
+
+        let stale = database::Categories {
+            is_active: !inserted.is_active,
+            ..inserted.clone()
+        };
+        let result = stale
+            .update_partial(&[CategoryFields::IsActive], database::UserScope::All, &pool)
+            .await;
+        let none = inserted
+            .update_partial(&[], database::UserScope::All, &pool)
+            .await;
+
+        assert!(matches!(result, Err(DatabaseError::Conflict(_))));
+        assert!(matches!(none, Err(DatabaseError::Validation(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Categories {
+    /// Searches categories by code, name and description, best match first.
+    ///
+    /// Matches in the name rank above matches in the code, which rank above
+    /// matches in the description. See the [`search`](crate::search) module
+    /// for the search syntax, which supports prefixes (`gro*`) and phrases
+    /// (`"eating out"`).
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The search text
+    /// * `limit` - The most categories to return, 20 if `None`
+    /// * `scope` - The user whose categories are searched
+    /// * `pool` - The database connection pool
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The search text has no words in it (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for category in Categories::search("gro*", None, UserScope::All, pool).await? {
+    ///     println!("{}: {}", category.code, category.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Search categories", skip(pool), err)]
+    pub async fn search(
+        text: &str,
+        limit: Option<u32>,
+        scope: database::UserScope,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let expression = database::search::match_expression(text)?;
+        let limit = limit.unwrap_or(database::search::DEFAULT_SEARCH_LIMIT);
+
+        let user_id = scope.user_id();
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    c.id            AS "id!: domain::RowID",
+                    c.code,
+                    c.name,
+                    c.description,
+                    c.url_slug      AS "url_slug?: domain::UrlSlug",
+                    c.category_type AS "category_type!: domain::CategoryTypes",
+                    c.color         AS "color?: domain::HexColor",
+                    c.icon,
+                    c.is_active     AS "is_active!: bool",
+                    c.parent_id     AS "parent_id?: domain::RowID",
+                    c.user_id       AS "user_id?: domain::RowID",
+                    c.created_on    AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    c.updated_on    AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    c.deleted_on    AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    c.version
+                FROM categories_fts f JOIN categories c ON c.id = f.id
+                WHERE categories_fts MATCH ?1 AND c.deleted_on IS NULL
+                    AND (?3 IS NULL OR c.user_id = ?3)
+                ORDER BY bm25(categories_fts, 0.0, 5.0, 10.0, 1.0), c.name
+                LIMIT ?2
+            "#,
+            expression,
+            limit,
+            user_id
+        )
+        .fetch_all(pool)
+        .observe("categories.search")
+        .await?;
+
+        tracing::debug!("Found {} categories matching '{}'", categories.len(), text);
+
+        Ok(categories)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::insert::tests::Result;
+
+    async fn category(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+        code: &str,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<database::Categories> {
+        let mut category = database::Categories::mock();
+        category.code = code.to_string();
+        category.name = name.to_string();
+        category.description = description.map(str::to_string);
+        category.url_slug = None;
+
+        Ok(category.insert(pool).await?)
+    }
+
+    fn names(categories: &[database::Categories]) -> Vec<&str> {
+        categories
+            .iter()
+            .map(|category| category.name.as_str())
+            .collect()
+    }
+
+    #[sqlx::test]
+    async fn search_ranks_name_above_description(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        category(&pool, "FOOD.001", "Groceries", Some("Supermarket shopping")).await?;
+        category(
+            &pool,
+            "FOOD.002",
+            "Eating out",
+            Some("Cafés, restaurants and takeaway groceries"),
+        )
+        .await?;
+        category(&pool, "HOME.001", "Rent", None).await?;
+
+        let ranked =
+            database::Categories::search("groceries", None, database::UserScope::All, &pool)
+                .await?;
+        let prefix =
+            database::Categories::search("gro*", Some(1), database::UserScope::All, &pool).await?;
+        let phrase =
+            database::Categories::search(r#""eating out""#, None, database::UserScope::All, &pool)
+                .await?;
+        let accents =
+            database::Categories::search("cafes", None, database::UserScope::All, &pool).await?;
+
+        assert_eq!(names(&ranked), ["Groceries", "Eating out"]);
+        assert_eq!(names(&prefix), ["Groceries"]);
+        assert_eq!(names(&phrase), ["Eating out"]);
+        assert_eq!(names(&accents), ["Eating out"]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn search_follows_updates_and_deletes(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut category = category(&pool, "TRAVEL.001", "Holidays", None).await?;
+        category.name = "Travel".to_string();
+        let category = category.update(database::UserScope::All, &pool).await?;
+
+        assert!(
+            database::Categories::search("holidays", None, database::UserScope::All, &pool)
+                .await?
+                .is_empty()
+        );
+        assert_eq!(
+            names(
+                &database::Categories::search("travel", None, database::UserScope::All, &pool)
+                    .await?
+            ),
+            ["Travel"]
+        );
+
+        category.delete(database::UserScope::All, &pool).await?;
+        assert!(
+            database::Categories::search("travel", None, database::UserScope::All, &pool)
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,434 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+/// Soft delete operations for Category database records.
+///
+/// A soft-deleted category keeps its row with `deleted_on` set, and is left
+/// out of lookups and lists until it is restored. Transactions and budgets
+/// classified under it keep their category. Purging deletes it for good.
+impl database::Categories {
+    /// Soft-deletes a category, so it can be restored later.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category to delete
+    /// * `scope` - The user whose categories are deleted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the deleted category, with `deleted_on` set.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The category does not exist, is outside `scope` or is already deleted
+    ///   (`DatabaseError::NotFound`)
+    /// - Categories that are not deleted are placed under it (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let deleted = Categories::delete_soft(id, UserScope::All, pool).await?;
+    /// assert!(Categories::find_by_id(id, UserScope::All, pool).await?.is_none());
+    ///
+    /// let restored = Categories::restore(deleted.id, UserScope::All, pool).await?;
+    /// assert_eq!(restored.deleted_on, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Soft delete category", skip(conn), err)]
+    pub fn delete_soft<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            // Checked before writing, so a rejected delete holds no write lock
+            Self::fetch_including_deleted(id, &mut tx)
+                .await?
+                .filter(|category| {
+                    category.deleted_on.is_none() && scope.includes(category.user_id)
+                })
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!("Category with id {} not found", id))
+                })?;
+
+            let children = sqlx::query_scalar!(
+                r#"
+                    SELECT COUNT(*) AS "count!: i64"
+                    FROM categories
+                    WHERE parent_id = ? AND deleted_on IS NULL
+                "#,
+                id
+            )
+            .fetch_one(&mut *tx)
+            .observe_one("categories.delete_soft")
+            .await?;
+
+            if children > 0 {
+                return Err(database::DatabaseError::Validation(format!(
+                    "Category {} has {} categories under it, move or delete them first",
+                    id, children
+                )));
+            }
+
+            let now = chrono::Utc::now();
+            sqlx::query!(
+                r#"
+                    UPDATE categories
+                    SET deleted_on = ?1, updated_on = ?1, version = version + 1
+                    WHERE id = ?2
+                "#,
+                now,
+                id
+            )
+            .execute(&mut *tx)
+            .observe("categories.delete_soft")
+            .await?;
+
+            let deleted = Self::fetch_including_deleted(id, &mut tx)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Category with id {} not found after delete",
+                        id
+                    ))
+                })?;
+
+            database::events::publish(database::DataChanges::CategoryDeleted(deleted.id));
+
+            tx.commit().await?;
+
+            tracing::info!("Soft deleted category {} ({})", deleted.id, deleted.code);
+
+            Ok(deleted)
+        }
+    }
+
+    /// Restores a soft-deleted category.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the deleted category
+    /// * `scope` - The user whose categories are restored
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the restored category.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No deleted category within `scope` has the ID (`DatabaseError::NotFound`)
+    /// - Its parent is deleted, and must be restored first (`DatabaseError::Validation`)
+    /// - Database connection fails
+    #[tracing::instrument(name = "Restore soft deleted category", skip(conn), err)]
+    pub fn restore<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            let category = Self::fetch_including_deleted(id, &mut tx)
+                .await?
+                .filter(|category| {
+                    category.deleted_on.is_some() && scope.includes(category.user_id)
+                })
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Deleted category with id {} not found",
+                        id
+                    ))
+                })?;
+
+            category.ensure_valid_parent(&mut tx).await?;
+
+            let now = chrono::Utc::now();
+            sqlx::query!(
+                r#"
+                    UPDATE categories
+                    SET deleted_on = NULL, updated_on = ?, version = version + 1
+                    WHERE id = ?
+                "#,
+                now,
+                id
+            )
+            .execute(&mut *tx)
+            .observe("categories.restore")
+            .await?;
+
+            let restored = Self::fetch_including_deleted(id, &mut tx)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Category with id {} not found after restore",
+                        id
+                    ))
+                })?;
+
+            database::events::publish(database::DataChanges::CategoryRestored(restored.clone()));
+
+            tx.commit().await?;
+
+            tracing::info!("Restored category {} ({})", restored.id, restored.code);
+
+            Ok(restored)
+        }
+    }
+
+    /// Permanently deletes a soft-deleted category.
+    ///
+    /// Only a category that was soft-deleted first can be purged, use
+    /// [`delete_by_id`](Self::delete_by_id) to delete any category outright.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the deleted category
+    /// * `scope` - The user whose categories are purged
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No deleted category within `scope` has the ID (`DatabaseError::NotFound`)
+    /// - Transactions, splits, recurring transactions, account adjustments,
+    ///   budgets, goals or deleted categories still refer to it
+    ///   (`DatabaseError::InUse`)
+    /// - Database connection fails
+    #[tracing::instrument(name = "Purge soft deleted category", skip(conn), err)]
+    pub fn purge<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            Self::ensure_unused(id, scope, &mut conn).await?;
+
+            let user_id = scope.user_id();
+            let rows_affected = sqlx::query!(
+                r#"
+                    DELETE FROM categories
+                    WHERE id = ?1 AND deleted_on IS NOT NULL AND (?2 IS NULL OR user_id = ?2)
+                "#,
+                id,
+                user_id
+            )
+            .execute(&mut *conn)
+            .observe("categories.purge")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Deleted category with id {} not found",
+                    id
+                )));
+            }
+
+            tracing::info!("Purged category {}", id);
+
+            Ok(())
+        }
+    }
+
+    /// Reads a category by ID whether or not it is soft-deleted.
+    pub(super) async fn fetch_including_deleted(
+        id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let category = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    user_id         AS "user_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                    version
+                FROM categories
+                WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("categories.fetch_including_deleted")
+        .await?;
+
+        Ok(category)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::insert::tests::Result;
+    use sqlx::SqlitePool;
+
+    async fn insert(
+        code: &str,
+        parent_id: Option<domain::RowID>,
+        pool: &SqlitePool,
+    ) -> Result<database::Categories> {
+        let mut category = database::Categories::mock();
+        category.code = code.to_string();
+        category.name = code.to_string();
+        category.url_slug = None;
+        category.parent_id = parent_id;
+
+        Ok(category.insert(pool).await?)
+    }
+
+    #[sqlx::test]
+    async fn delete_soft_hides_category_until_restored(pool: SqlitePool) -> Result<()> {
+        let category = insert("FOOD", None, &pool).await?;
+
+        let deleted =
+            database::Categories::delete_soft(category.id, database::UserScope::All, &pool).await?;
+
+        assert!(deleted.deleted_on.is_some());
+        assert_eq!(
+            database::Categories::find_by_id(category.id, database::UserScope::All, &pool).await?,
+            None
+        );
+        assert_eq!(
+            database::Categories::find_by_code("FOOD", database::UserScope::All, &pool).await?,
+            None
+        );
+        assert!(
+            database::Categories::find_all(database::UserScope::All, &pool)
+                .await?
+                .is_empty()
+        );
+
+        let with_deleted = database::CategoryFilter {
+            include_deleted: true,
+            ..Default::default()
+        };
+        let listed = database::Categories::find_with_filters(
+            &with_deleted,
+            None,
+            0,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        assert_eq!(listed.items, vec![deleted]);
+
+        let again =
+            database::Categories::delete_soft(category.id, database::UserScope::All, &pool).await;
+        assert!(matches!(again, Err(database::DatabaseError::NotFound(_))));
+
+        let restored =
+            database::Categories::restore(category.id, database::UserScope::All, &pool).await?;
+        assert_eq!(restored.deleted_on, None);
+        assert_eq!(
+            database::Categories::find_by_id(category.id, database::UserScope::All, &pool).await?,
+            Some(restored)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn delete_soft_keeps_parents_of_categories_in_use(pool: SqlitePool) -> Result<()> {
+        let food = insert("FOOD", None, &pool).await?;
+        let groceries = insert("GROCERIES", Some(food.id), &pool).await?;
+
+        let result =
+            database::Categories::delete_soft(food.id, database::UserScope::All, &pool).await;
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        // Once the child is deleted the parent can be, but must be restored first
+        database::Categories::delete_soft(groceries.id, database::UserScope::All, &pool).await?;
+        database::Categories::delete_soft(food.id, database::UserScope::All, &pool).await?;
+
+        let result =
+            database::Categories::restore(groceries.id, database::UserScope::All, &pool).await;
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        database::Categories::restore(food.id, database::UserScope::All, &pool).await?;
+        database::Categories::restore(groceries.id, database::UserScope::All, &pool).await?;
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn purge_only_deletes_soft_deleted_categories(pool: SqlitePool) -> Result<()> {
+        let category = insert("FOOD", None, &pool).await?;
+
+        let result =
+            database::Categories::purge(category.id, database::UserScope::All, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        database::Categories::delete_soft(category.id, database::UserScope::All, &pool).await?;
+        database::Categories::purge(category.id, database::UserScope::All, &pool).await?;
+
+        let result =
+            database::Categories::restore(category.id, database::UserScope::All, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn soft_deleted_category_is_left_out_of_history(pool: SqlitePool) -> Result<()> {
+        let category = insert("FOOD", None, &pool).await?;
+        let today = chrono::Utc::now().date_naive();
+
+        database::Categories::delete_soft(category.id, database::UserScope::All, &pool).await?;
+        assert!(
+            database::Categories::find_all_as_of(today, database::UserScope::All, &pool)
+                .await?
+                .is_empty()
+        );
+
+        database::Categories::restore(category.id, database::UserScope::All, &pool).await?;
+        assert_eq!(
+            database::Categories::find_all_as_of(today, database::UserScope::All, &pool)
+                .await?
+                .len(),
+            1
+        );
+
+        Ok(())
+    }
+}
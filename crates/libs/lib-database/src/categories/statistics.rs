@@ -0,0 +1,294 @@
+//! # Category Statistics
+//!
+//! A summary of the categories in a scope and how much they are used, for
+//! dashboards that would otherwise list every category and transaction to
+//! count them. One aggregate query returns each category with its number of
+//! transactions and the date it was last used, and the counts by type and
+//! activity are worked out from those rows.
+//!
+//! Soft-deleted categories are left out. Drafts count as uses, as they have
+//! been classified even though they are not yet in balances.
+
+use crate::query_metrics::Observe;
+
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+/// How many transactions are classified under a category, and when it was
+/// last used.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct CategoryUsage {
+    /// The category.
+    pub category_id: domain::RowID,
+
+    /// Transactions classified under the category, drafts included.
+    pub transaction_count: i64,
+
+    /// The date of the latest transaction classified under the category,
+    /// `None` if it has never been used.
+    pub last_used_on: Option<chrono::NaiveDate>,
+}
+
+/// The number of categories of one type.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CategoryTypeCount {
+    /// The category type.
+    pub category_type: domain::CategoryTypes,
+
+    /// Categories of the type, active or not.
+    pub count: i64,
+}
+
+/// A summary of the categories in a scope, returned by
+/// [`Categories::statistics`](database::Categories::statistics).
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CategoryStatistics {
+    /// Every category, active or not.
+    pub total_count: i64,
+
+    /// Categories available for use.
+    pub active_count: i64,
+
+    /// Categories hidden from use.
+    pub inactive_count: i64,
+
+    /// Categories by type, one entry for every type in the order of
+    /// [`CategoryTypes::all`](domain::CategoryTypes::all), with a count of
+    /// zero for types without categories.
+    pub type_counts: Vec<CategoryTypeCount>,
+
+    /// Every category, most used first.
+    pub usage: Vec<CategoryUsage>,
+
+    /// The categories used most recently, latest first, up to the number
+    /// asked for. Categories never used are left out.
+    pub recently_used: Vec<CategoryUsage>,
+}
+
+/// A category with its usage, as read by the aggregate query.
+struct UsageRow {
+    category_id: domain::RowID,
+    category_type: domain::CategoryTypes,
+    is_active: bool,
+    transaction_count: i64,
+    last_used_on: Option<chrono::NaiveDate>,
+}
+
+impl database::Categories {
+    /// Summarises the categories in `scope` and how much they are used, in one
+    /// query, see the [module documentation](self).
+    ///
+    /// # Arguments
+    ///
+    /// * `recent_limit` - The most recently used categories to return
+    /// * `scope` - Whose categories and transactions are counted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Categories, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let statistics = Categories::statistics(5, UserScope::All, pool).await?;
+    /// println!("{} of {} categories active", statistics.active_count, statistics.total_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError::Sqlx` if the categories cannot be read.
+    #[tracing::instrument(name = "Category statistics", skip(conn), err)]
+    pub fn statistics<'c, A>(
+        recent_limit: usize,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<CategoryStatistics>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let user_id = scope.user_id();
+            let rows = sqlx::query_as!(
+                UsageRow,
+                r#"
+                    SELECT
+                        categories.id                       AS "category_id!: domain::RowID",
+                        categories.category_type            AS "category_type!: domain::CategoryTypes",
+                        categories.is_active                AS "is_active!: bool",
+                        COUNT(transactions.id)              AS "transaction_count!: i64",
+                        MAX(transactions.transaction_date)  AS "last_used_on?: chrono::NaiveDate"
+                    FROM categories
+                    LEFT JOIN transactions
+                        ON transactions.category_id = categories.id
+                        AND (?1 IS NULL OR transactions.user_id = ?1)
+                    WHERE categories.deleted_on IS NULL
+                      AND (?1 IS NULL OR categories.user_id = ?1)
+                    GROUP BY categories.id
+                    ORDER BY COUNT(transactions.id) DESC, categories.name, categories.id
+                "#,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("categories.statistics")
+            .await?;
+
+            let total_count = rows.len() as i64;
+            let active_count = rows.iter().filter(|row| row.is_active).count() as i64;
+            let type_counts = domain::CategoryTypes::all()
+                .iter()
+                .map(|category_type| CategoryTypeCount {
+                    category_type: category_type.clone(),
+                    count: rows
+                        .iter()
+                        .filter(|row| &row.category_type == category_type)
+                        .count() as i64,
+                })
+                .collect();
+
+            let usage: Vec<CategoryUsage> = rows
+                .into_iter()
+                .map(|row| CategoryUsage {
+                    category_id: row.category_id,
+                    transaction_count: row.transaction_count,
+                    last_used_on: row.last_used_on,
+                })
+                .collect();
+
+            let mut recently_used: Vec<CategoryUsage> = usage
+                .iter()
+                .filter(|usage| usage.last_used_on.is_some())
+                .cloned()
+                .collect();
+            // Stable, so categories used on the same day stay most used first
+            recently_used.sort_by_key(|usage| std::cmp::Reverse(usage.last_used_on));
+            recently_used.truncate(recent_limit);
+
+            Ok(CategoryStatistics {
+                total_count,
+                active_count,
+                inactive_count: total_count - active_count,
+                type_counts,
+                usage,
+                recently_used,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn category(
+        code: &str,
+        category_type: domain::CategoryTypes,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<database::Categories> {
+        Ok(database::CategoriesBuilder::new()
+            .with_code(code)
+            .with_name(code)
+            .with_category_type(category_type)
+            .build()?
+            .insert(pool)
+            .await?)
+    }
+
+    async fn classify(
+        category: &database::Categories,
+        day: u32,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<()> {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 4, day).unwrap();
+        let mut transaction = database::Transactions::new(date, "Fresh Market", -1_000);
+        transaction.category_id = Some(category.id);
+        transaction.insert(pool).await?;
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn statistics_count_categories_and_their_use(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let groceries = category("GRO", domain::CategoryTypes::Expense, &pool).await?;
+        let dining = category("DIN", domain::CategoryTypes::Expense, &pool).await?;
+        let salary = category("PAY", domain::CategoryTypes::Income, &pool).await?;
+        database::Categories::update_active_status(
+            salary.id,
+            false,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        let deleted = category("OLD", domain::CategoryTypes::Expense, &pool).await?;
+        database::Categories::delete_soft(deleted.id, database::UserScope::All, &pool).await?;
+
+        for day in [1, 2, 3] {
+            classify(&groceries, day, &pool).await?;
+        }
+        classify(&dining, 20, &pool).await?;
+
+        let statistics =
+            database::Categories::statistics(5, database::UserScope::All, &pool).await?;
+
+        assert_eq!(
+            (
+                statistics.total_count,
+                statistics.active_count,
+                statistics.inactive_count
+            ),
+            (3, 2, 1)
+        );
+        let expense = statistics
+            .type_counts
+            .iter()
+            .find(|count| count.category_type == domain::CategoryTypes::Expense)
+            .unwrap();
+        assert_eq!(expense.count, 2);
+        assert_eq!(
+            statistics.type_counts.len(),
+            domain::CategoryTypes::all().len()
+        );
+
+        let counts: Vec<(domain::RowID, i64)> = statistics
+            .usage
+            .iter()
+            .map(|usage| (usage.category_id, usage.transaction_count))
+            .collect();
+        assert_eq!(counts, [(groceries.id, 3), (dining.id, 1), (salary.id, 0)]);
+
+        let recent: Vec<domain::RowID> = statistics
+            .recently_used
+            .iter()
+            .map(|usage| usage.category_id)
+            .collect();
+        assert_eq!(recent, [dining.id, groceries.id]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn recently_used_is_limited(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        for (day, code) in [(1, "A"), (2, "B"), (3, "C")] {
+            let category = category(code, domain::CategoryTypes::Expense, &pool).await?;
+            classify(&category, day, &pool).await?;
+        }
+
+        let statistics =
+            database::Categories::statistics(2, database::UserScope::All, &pool).await?;
+
+        assert_eq!(statistics.recently_used.len(), 2);
+        assert_eq!(
+            statistics.recently_used[0].last_used_on,
+            chrono::NaiveDate::from_ymd_opt(2025, 4, 3)
+        );
+
+        Ok(())
+    }
+}
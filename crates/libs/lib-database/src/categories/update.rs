@@ -1,5 +1,6 @@
-use crate::database::{self, DatabaseResult};
-use crate::domain;
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
 
 /// Update operations for Category database records.
 ///
@@ -11,9 +12,16 @@ impl database::Categories {
     /// This function updates all fields of the category record identified by the `id` field.
     /// The operation is atomic and will either succeed completely or fail without side effects.
     ///
+    /// The update only applies if the category is still at the `version` it
+    /// was read at, and the version is then incremented, so a client cannot
+    /// overwrite a change it has not seen.
+    ///
+    /// The owner of the category is never changed.
+    ///
     /// # Arguments
     ///
-    /// * `pool` - The database connection pool
+    /// * `scope` - The user whose categories can be updated
+    /// * `conn` - The database pool, connection or transaction to run on
     ///
     /// # Returns
     ///
@@ -23,7 +31,10 @@ impl database::Categories {
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The category with the given ID does not exist
+    /// - The category with the given ID does not exist, is soft-deleted, or
+    ///   is outside `scope`
+    /// - The category was changed since it was read, as its `version` is not
+    ///   the stored one (`DatabaseError::Conflict`)
     /// - The updated category violates database constraints (duplicate code, name, or url_slug)
     /// - The category_type is invalid
     /// - The color format is invalid
@@ -32,29 +43,32 @@ impl database::Categories {
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
-    /// use personal_ledger_backend::domain::CategoryTypes;
+    /// use lib_database::{Categories, CategoriesBuilder, UserScope};
+    /// use lib_domain::CategoryTypes;
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     /// // First create a category
-    /// let mut category = Category::mock();
+    /// let mut category = CategoriesBuilder::new()
+    ///     .with_code("FOOD.001")
+    ///     .with_name("Groceries")
+    ///     .with_category_type(lib_domain::CategoryTypes::Expense)
+    ///     .build()?;
     /// let inserted = category.insert(pool).await?;
     ///
     /// // Update the category
-    /// let updated_category = Category {
+    /// let updated_category = Categories {
     ///     name: "Updated Category Name".to_string(),
     ///     ..inserted
     /// };
     ///
-    /// let result = updated_category.update(pool).await?;
+    /// let result = updated_category.update(UserScope::All, pool).await?;
     /// assert_eq!(result.name, "Updated Category Name");
     /// # Ok(())
     /// # }
     /// ```
     #[tracing::instrument(
         name = "Update category in database",
-        skip(self, pool),
+        skip(self, conn),
         fields(
             id = % self.id,
             code = % self.code,
@@ -62,63 +76,90 @@ impl database::Categories {
         ),
         err
     )]
-    pub async fn update(&self, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<Self> {
-        // Update the category record
-        let update_query = sqlx::query!(
-            r#"
-                UPDATE categories
-                SET code = ?, name = ?, description = ?, url_slug = ?, category_type = ?,
-                    color = ?, icon = ?, is_active = ?, updated_on = ?
-                WHERE id = ?
-            "#,
-            self.code,
-            self.name,
-            self.description,
-            self.url_slug,
-            self.category_type,
-            self.color,
-            self.icon,
-            self.is_active,
-            self.updated_on,
-            self.id
-        );
+    pub fn update<'a, 'c, A>(
+        &'a self,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            self.ensure_valid_parent(&mut conn).await?;
+
+            // Update the category record
+            let user_id = scope.user_id();
+            let update_query = sqlx::query!(
+                r#"
+                    UPDATE categories
+                    SET code = ?, name = ?, description = ?, url_slug = ?, category_type = ?,
+                        color = ?, icon = ?, is_active = ?, updated_on = ?, parent_id = ?,
+                        version = version + 1
+                    WHERE id = ? AND deleted_on IS NULL AND (?12 IS NULL OR user_id = ?12) AND version = ?13
+                "#,
+                self.code,
+                self.name,
+                self.description,
+                self.url_slug,
+                self.category_type,
+                self.color,
+                self.icon,
+                self.is_active,
+                self.updated_on,
+                self.parent_id,
+                self.id,
+                user_id,
+                self.version
+            );
 
-        let rows_affected = update_query.execute(pool).await?.rows_affected();
+            let rows_affected = update_query
+                .execute(&mut *conn)
+                .observe("categories.update")
+                .await?
+                .rows_affected();
 
-        if rows_affected == 0 {
-            return Err(database::DatabaseError::NotFound(format!(
-                "Category with id {} not found",
-                self.id
-            )));
-        }
+            if rows_affected == 0 {
+                return Err(self.update_error(scope, &mut conn).await);
+            }
 
-        tracing::info!("Updated category {} in database", self.id);
+            tracing::info!("Updated category {} in database", self.id);
 
-        // Read back the updated category
-        let updated = sqlx::query_as!(
-            database::Categories,
-            r#"
-                SELECT
-                    id              AS "id!: domain::RowID",
-                    code,
-                    name,
-                    description,
-                    url_slug        AS "url_slug?: domain::UrlSlug",
-                    category_type   AS "category_type!: domain::CategoryTypes",
-                    color           AS "color?: domain::HexColor",
-                    icon,
-                    is_active       AS "is_active!: bool",
-                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
-                FROM categories
-                WHERE id = ?
-            "#,
-            self.id
-        )
-        .fetch_one(pool)
-        .await?;
+            // Read back the updated category
+            let updated = sqlx::query_as!(
+                database::Categories,
+                r#"
+                    SELECT
+                        id              AS "id!: domain::RowID",
+                        code,
+                        name,
+                        description,
+                        url_slug        AS "url_slug?: domain::UrlSlug",
+                        category_type   AS "category_type!: domain::CategoryTypes",
+                        color           AS "color?: domain::HexColor",
+                        icon,
+                        is_active       AS "is_active!: bool",
+                        parent_id       AS "parent_id?: domain::RowID",
+                        user_id         AS "user_id?: domain::RowID",
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                        version
+                    FROM categories
+                    WHERE id = ?
+                "#,
+                self.id
+            )
+            .fetch_one(&mut *conn)
+            .observe_one("categories.update")
+            .await?;
+
+            database::events::publish(database::DataChanges::CategoryUpdated(updated.clone()));
 
-        Ok(updated)
+            Ok(updated)
+        }
     }
 
     /// Updates multiple categories in the database in a single transaction.
@@ -130,7 +171,8 @@ impl database::Categories {
     /// # Arguments
     ///
     /// * `categories` - A slice of categories to update
-    /// * `pool` - The database connection pool
+    /// * `scope` - The user whose categories can be updated
+    /// * `conn` - The database pool, connection or transaction to run on
     ///
     /// # Returns
     ///
@@ -140,7 +182,8 @@ impl database::Categories {
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - Any category with the given ID does not exist
+    /// - Any category with the given ID does not exist, or is outside `scope`
+    /// - Any category was changed since it was read (`DatabaseError::Conflict`)
     /// - Any updated category violates database constraints
     /// - Database connection fails
     /// - Transaction fails to commit
@@ -148,54 +191,72 @@ impl database::Categories {
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
+    /// use lib_database::{Categories, CategoriesBuilder, UserScope};
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     /// // Create some categories first
-    /// let categories = vec![Category::mock(), Category::mock()];
-    /// let inserted = Category::insert_many(&categories, pool).await?;
+    /// let categories = vec![
+    ///     CategoriesBuilder::new()
+    ///         .with_code("FOOD.001")
+    ///         .with_name("Groceries")
+    ///         .with_category_type(lib_domain::CategoryTypes::Expense)
+    ///         .build()?,
+    ///     CategoriesBuilder::new()
+    ///         .with_code("FOOD.002")
+    ///         .with_name("Dining Out")
+    ///         .with_category_type(lib_domain::CategoryTypes::Expense)
+    ///         .build()?,
+    /// ];
+    /// let inserted = Categories::insert_many(&categories, pool).await?;
     ///
     /// // Update them
     /// let updates = inserted.into_iter()
-    ///     .map(|cat| Category {
+    ///     .map(|cat| Categories {
     ///         name: format!("Updated {}", cat.name),
     ///         ..cat
     ///     })
     ///     .collect::<Vec<_>>();
     ///
-    /// let updated = Category::update_many(&updates, pool).await?;
+    /// let updated = Categories::update_many(&updates, UserScope::All, pool).await?;
     /// assert_eq!(updated.len(), 2);
     /// # Ok(())
     /// # }
     /// ```
     #[tracing::instrument(
         name = "Bulk update categories in database",
-        skip(categories, pool),
+        skip(categories, conn),
         fields(count = categories.len()),
         err
     )]
-    pub async fn update_many(
+    pub async fn update_many<'c, A>(
         categories: &[Self],
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<Vec<Self>> {
+        scope: database::UserScope,
+        conn: A,
+    ) -> DatabaseResult<Vec<Self>>
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite>,
+    {
         if categories.is_empty() {
             return Ok(Vec::new());
         }
 
         // Use a transaction for atomicity
-        let mut tx = pool.begin().await?;
+        let mut tx = conn.begin().await?;
 
         let mut updated_categories = Vec::with_capacity(categories.len());
 
         for category in categories {
+            category.ensure_valid_parent(&mut tx).await?;
+
             // Update each category
+            let user_id = scope.user_id();
             let update_query = sqlx::query!(
                 r#"
                     UPDATE categories
                     SET code = ?, name = ?, description = ?, url_slug = ?, category_type = ?,
-                        color = ?, icon = ?, is_active = ?, updated_on = ?
-                    WHERE id = ?
+                        color = ?, icon = ?, is_active = ?, updated_on = ?, parent_id = ?,
+                        version = version + 1
+                    WHERE id = ? AND deleted_on IS NULL AND (?12 IS NULL OR user_id = ?12) AND version = ?13
                 "#,
                 category.code,
                 category.name,
@@ -206,16 +267,20 @@ impl database::Categories {
                 category.icon,
                 category.is_active,
                 category.updated_on,
-                category.id
+                category.parent_id,
+                category.id,
+                user_id,
+                category.version
             );
 
-            let rows_affected = update_query.execute(&mut *tx).await?.rows_affected();
+            let rows_affected = update_query
+                .execute(&mut *tx)
+                .observe("categories.update_many")
+                .await?
+                .rows_affected();
 
             if rows_affected == 0 {
-                return Err(database::DatabaseError::NotFound(format!(
-                    "Category with id {} not found",
-                    category.id
-                )));
+                return Err(category.update_error(scope, &mut tx).await);
             }
 
             // Read back the updated category
@@ -232,23 +297,33 @@ impl database::Categories {
                         color           AS "color?: domain::HexColor",
                         icon,
                         is_active       AS "is_active!: bool",
+                        parent_id       AS "parent_id?: domain::RowID",
+                        user_id         AS "user_id?: domain::RowID",
                         created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                        version
                     FROM categories
                     WHERE id = ?
                 "#,
                 category.id
             )
             .fetch_one(&mut *tx)
+            .observe_one("categories.update_many")
             .await?;
 
+            database::events::publish(database::DataChanges::CategoryUpdated(updated.clone()));
+
             updated_categories.push(updated);
         }
 
         // Commit the transaction
         tx.commit().await?;
 
-        tracing::info!("Successfully updated {} categories in database", updated_categories.len());
+        tracing::info!(
+            "Successfully updated {} categories in database",
+            updated_categories.len()
+        );
 
         Ok(updated_categories)
     }
@@ -262,7 +337,8 @@ impl database::Categories {
     ///
     /// * `id` - The ID of the category to update
     /// * `is_active` - The new active status
-    /// * `pool` - The database connection pool
+    /// * `scope` - The user whose categories can be updated
+    /// * `conn` - The database pool, connection or transaction to run on
     ///
     /// # Returns
     ///
@@ -271,88 +347,141 @@ impl database::Categories {
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The category with the given ID does not exist
+    /// - The category with the given ID does not exist, or is outside `scope`
     /// - Database connection fails
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use use lib_database::categories::Category;
-    /// use use lib_database::DatabasePool;
-    /// use personal_ledger_backend::domain::RowID;
+    /// use lib_database::{Categories, UserScope};
+    /// use lib_domain::RowID;
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     /// // Assuming we have a category ID
     /// let category_id = RowID::new();
     ///
     /// // Deactivate the category
-    /// let updated = Category::update_active_status(category_id, false, pool).await?;
+    /// let updated = Categories::update_active_status(category_id, false, UserScope::All, pool).await?;
     /// assert!(!updated.is_active);
     ///
     /// // Reactivate the category
-    /// let updated = Category::update_active_status(category_id, true, pool).await?;
+    /// let updated = Categories::update_active_status(category_id, true, UserScope::All, pool).await?;
     /// assert!(updated.is_active);
     /// # Ok(())
     /// # }
     /// ```
     #[tracing::instrument(
         name = "Update category active status",
-        skip(pool),
+        skip(conn),
         fields(id = %id, is_active = %is_active),
         err
     )]
-    pub async fn update_active_status(
+    pub fn update_active_status<'c, A>(
         id: domain::RowID,
         is_active: bool,
-        pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<Self> {
-        // Update only the active status and updated_on timestamp
-        let update_query = sqlx::query!(
-            r#"
-                UPDATE categories
-                SET is_active = ?, updated_on = strftime('%Y-%m-%dT%H:%M:%fZ','now')
-                WHERE id = ?
-            "#,
-            is_active,
-            id
-        );
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            // Update only the active status and updated_on timestamp
+            let user_id = scope.user_id();
+            let update_query = sqlx::query!(
+                r#"
+                    UPDATE categories
+                    SET is_active = ?1, updated_on = strftime('%Y-%m-%dT%H:%M:%fZ','now'), version = version + 1
+                    WHERE id = ?2 AND deleted_on IS NULL AND (?3 IS NULL OR user_id = ?3)
+                "#,
+                is_active,
+                id,
+                user_id
+            );
+
+            let rows_affected = update_query
+                .execute(&mut *conn)
+                .observe("categories.update_active_status")
+                .await?
+                .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Category with id {} not found",
+                    id
+                )));
+            }
 
-        let rows_affected = update_query.execute(pool).await?.rows_affected();
+            tracing::info!("Updated active status for category {} to {}", id, is_active);
 
-        if rows_affected == 0 {
-            return Err(database::DatabaseError::NotFound(format!(
-                "Category with id {} not found",
+            // Read back the updated category
+            let updated = sqlx::query_as!(
+                database::Categories,
+                r#"
+                    SELECT
+                        id              AS "id!: domain::RowID",
+                        code,
+                        name,
+                        description,
+                        url_slug        AS "url_slug?: domain::UrlSlug",
+                        category_type   AS "category_type!: domain::CategoryTypes",
+                        color           AS "color?: domain::HexColor",
+                        icon,
+                        is_active       AS "is_active!: bool",
+                        parent_id       AS "parent_id?: domain::RowID",
+                        user_id         AS "user_id?: domain::RowID",
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>",
+                        version
+                    FROM categories
+                    WHERE id = ?
+                "#,
                 id
-            )));
-        }
+            )
+            .fetch_one(&mut *conn)
+            .observe_one("categories.update_active_status")
+            .await?;
 
-        tracing::info!("Updated active status for category {} to {}", id, is_active);
+            database::events::publish(database::DataChanges::CategoryUpdated(updated.clone()));
 
-        // Read back the updated category
-        let updated = sqlx::query_as!(
-            database::Categories,
+            Ok(updated)
+        }
+    }
+
+    /// The error for an update that changed no row: a conflict if the
+    /// category is there at another version, otherwise not found.
+    pub(super) async fn update_error(
+        &self,
+        scope: database::UserScope,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> database::DatabaseError {
+        let user_id = scope.user_id();
+        let current = sqlx::query_scalar!(
             r#"
-                SELECT
-                    id              AS "id!: domain::RowID",
-                    code,
-                    name,
-                    description,
-                    url_slug        AS "url_slug?: domain::UrlSlug",
-                    category_type   AS "category_type!: domain::CategoryTypes",
-                    color           AS "color?: domain::HexColor",
-                    icon,
-                    is_active       AS "is_active!: bool",
-                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                SELECT version
                 FROM categories
-                WHERE id = ?
+                WHERE id = ?1 AND deleted_on IS NULL AND (?2 IS NULL OR user_id = ?2)
             "#,
-            id
+            self.id,
+            user_id
         )
-        .fetch_one(pool)
-        .await?;
-
-        Ok(updated)
+        .fetch_optional(conn)
+        .observe("categories.update")
+        .await;
+
+        match current {
+            Ok(Some(version)) => database::DatabaseError::Conflict(format!(
+                "Category {} was changed since it was read, it is at version {version}, not {}",
+                self.id, self.version
+            )),
+            Ok(None) => {
+                database::DatabaseError::NotFound(format!("Category with id {} not found", self.id))
+            }
+            Err(error) => error.into(),
+        }
     }
 }
 
@@ -384,7 +513,9 @@ pub mod tests {
             ..inserted
         };
 
-        let result = updated_category.update(&pool).await?;
+        let result = updated_category
+            .update(database::UserScope::All, &pool)
+            .await?;
 
         // Verify the update
         assert_eq!(result.id, inserted.id);
@@ -401,7 +532,7 @@ pub mod tests {
     async fn update_nonexistent_category_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
         let fake_category = database::Categories::mock();
 
-        let result = fake_category.update(&pool).await;
+        let result = fake_category.update(database::UserScope::All, &pool).await;
 
         assert!(result.is_err());
         // The error should be a NotFound error
@@ -411,7 +542,37 @@ pub mod tests {
     }
 
     #[sqlx::test]
-    async fn update_category_with_duplicate_name_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+    async fn update_of_a_stale_version_conflicts(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Categories::mock().insert(&pool).await?;
+        let first = database::Categories {
+            name: "First Edit".to_string(),
+            ..inserted.clone()
+        };
+        let second = database::Categories {
+            name: "Second Edit".to_string(),
+            ..inserted.clone()
+        };
+
+        let updated = first.update(database::UserScope::All, &pool).await?;
+        let result = second.update(database::UserScope::All, &pool).await;
+
+        assert_eq!(inserted.version, 1);
+        assert_eq!(updated.version, 2);
+        assert!(matches!(result, Err(database::DatabaseError::Conflict(_))));
+        let stored =
+            database::Categories::find_by_id(inserted.id, database::UserScope::All, &pool).await?;
+        assert_eq!(
+            stored.map(|category| category.name),
+            Some("First Edit".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_category_with_duplicate_name_fails(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
         // Insert two categories
         let category1 = database::Categories::mock();
         let inserted1 = category1.insert(&pool).await?;
@@ -426,7 +587,9 @@ pub mod tests {
             ..inserted2
         };
 
-        let result = updated_category2.update(&pool).await;
+        let result = updated_category2
+            .update(database::UserScope::All, &pool)
+            .await;
         assert!(result.is_err());
 
         Ok(())
@@ -450,12 +613,18 @@ pub mod tests {
             })
             .collect::<Vec<_>>();
 
-        let updated = database::Categories::update_many(&updates, &pool).await?;
+        let updated =
+            database::Categories::update_many(&updates, database::UserScope::All, &pool).await?;
 
         assert_eq!(updated.len(), 2);
         for cat in updated.iter() {
             assert!(cat.name.starts_with("Updated "));
-            assert!(cat.description.as_ref().unwrap().starts_with("Updated description"));
+            assert!(
+                cat.description
+                    .as_ref()
+                    .unwrap()
+                    .starts_with("Updated description")
+            );
         }
 
         Ok(())
@@ -465,7 +634,8 @@ pub mod tests {
     async fn update_many_empty_list(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
         let categories: Vec<database::Categories> = vec![];
 
-        let result = database::Categories::update_many(&categories, &pool).await?;
+        let result =
+            database::Categories::update_many(&categories, database::UserScope::All, &pool).await?;
 
         assert!(result.is_empty());
 
@@ -473,7 +643,9 @@ pub mod tests {
     }
 
     #[sqlx::test]
-    async fn update_many_with_nonexistent_category_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+    async fn update_many_with_nonexistent_category_fails(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
         // Insert one category
         let category = database::Categories::mock();
         let inserted = category.insert(&pool).await?;
@@ -493,7 +665,8 @@ pub mod tests {
             },
         ];
 
-        let result = database::Categories::update_many(&updates, &pool).await;
+        let result =
+            database::Categories::update_many(&updates, database::UserScope::All, &pool).await;
         assert!(result.is_err());
 
         Ok(())
@@ -508,13 +681,25 @@ pub mod tests {
         assert!(inserted.is_active);
 
         // Deactivate it
-        let deactivated = database::Categories::update_active_status(inserted.id, false, &pool).await?;
+        let deactivated = database::Categories::update_active_status(
+            inserted.id,
+            false,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
         assert_eq!(deactivated.id, inserted.id);
         assert!(!deactivated.is_active);
         assert_ne!(deactivated.updated_on, inserted.updated_on);
 
         // Reactivate it
-        let reactivated = database::Categories::update_active_status(inserted.id, true, &pool).await?;
+        let reactivated = database::Categories::update_active_status(
+            inserted.id,
+            true,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
         assert_eq!(reactivated.id, inserted.id);
         assert!(reactivated.is_active);
         assert_ne!(reactivated.updated_on, deactivated.updated_on);
@@ -523,10 +708,18 @@ pub mod tests {
     }
 
     #[sqlx::test]
-    async fn update_active_status_nonexistent_category_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+    async fn update_active_status_nonexistent_category_fails(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
         let fake_id = domain::RowID::new();
 
-        let result = database::Categories::update_active_status(fake_id, false, &pool).await;
+        let result = database::Categories::update_active_status(
+            fake_id,
+            false,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
 
         assert!(result.is_err());
         assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
@@ -549,7 +742,9 @@ pub mod tests {
             ..inserted
         };
 
-        let result = updated_category.update(&pool).await?;
+        let result = updated_category
+            .update(database::UserScope::All, &pool)
+            .await?;
 
         // Created timestamp should be preserved
         assert_eq!(result.created_on, original_created_on);
@@ -558,4 +753,4 @@ pub mod tests {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,213 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::AssetPrices {
+    /// Finds the price to value an asset at on a valuation date.
+    ///
+    /// The latest price on or before `on` is used, in any currency. When
+    /// prices in several currencies were stored on that day, the one in
+    /// `currency` is preferred, so no conversion is needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - The asset to price
+    /// * `currency` - The preferred currency of the price
+    /// * `on` - The valuation date
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the price, or `None` if the asset has no price on or before `on`.
+    #[tracing::instrument(name = "Find asset price", skip(conn), err)]
+    pub fn find_price<'c, A>(
+        asset: domain::CryptoAsset,
+        currency: domain::Currency,
+        on: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let price = sqlx::query_as!(
+                database::AssetPrices,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        asset               AS "asset!: domain::CryptoAsset",
+                        currency            AS "currency!: domain::Currency",
+                        price_date          AS "price_date!: chrono::NaiveDate",
+                        price,
+                        source,
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM asset_prices
+                    WHERE asset = ?1 AND price_date <= ?3
+                    ORDER BY price_date DESC, currency = ?2 DESC
+                    LIMIT 1
+                "#,
+                asset,
+                currency,
+                on
+            )
+            .fetch_optional(&mut *conn)
+            .observe("crypto.find_price")
+            .await?;
+
+            Ok(price)
+        }
+    }
+
+    /// Reads the price for an asset, currency and day back through the given
+    /// connection.
+    pub(super) async fn fetch_by_day(
+        asset: domain::CryptoAsset,
+        currency: domain::Currency,
+        price_date: chrono::NaiveDate,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let price = sqlx::query_as!(
+            database::AssetPrices,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    asset               AS "asset!: domain::CryptoAsset",
+                    currency            AS "currency!: domain::Currency",
+                    price_date          AS "price_date!: chrono::NaiveDate",
+                    price,
+                    source,
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM asset_prices
+                WHERE asset = ? AND currency = ? AND price_date = ?
+            "#,
+            asset,
+            currency,
+            price_date
+        )
+        .fetch_optional(conn)
+        .observe("crypto.fetch_by_day")
+        .await?;
+
+        Ok(price)
+    }
+}
+
+impl database::CryptoHoldings {
+    /// Finds every asset held in an account, ordered by ticker.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The ID of the account
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(name = "Find crypto holdings for account", skip(conn), err)]
+    pub fn find_by_account<'c, A>(
+        account_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let holdings = sqlx::query_as!(
+                database::CryptoHoldings,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        account_id          AS "account_id!: domain::RowID",
+                        asset               AS "asset!: domain::CryptoAsset",
+                        quantity,
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM crypto_holdings
+                    WHERE account_id = ?
+                    ORDER BY asset ASC
+                "#,
+                account_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("crypto.find_by_account")
+            .await?;
+
+            Ok(holdings)
+        }
+    }
+
+    /// Reads the holding of an asset in an account back through the given
+    /// connection.
+    pub(super) async fn fetch_by_asset(
+        account_id: domain::RowID,
+        asset: domain::CryptoAsset,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let holding = sqlx::query_as!(
+            database::CryptoHoldings,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    account_id          AS "account_id!: domain::RowID",
+                    asset               AS "asset!: domain::CryptoAsset",
+                    quantity,
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM crypto_holdings
+                WHERE account_id = ? AND asset = ?
+            "#,
+            account_id,
+            asset
+        )
+        .fetch_optional(conn)
+        .observe("crypto.fetch_by_asset")
+        .await?;
+
+        Ok(holding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::insert::tests::{Result, date, price};
+
+    #[sqlx::test]
+    async fn find_price_uses_latest_and_prefers_currency(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let (eth, aud, usd) = (
+            domain::CryptoAsset::ETH,
+            domain::Currency::AUD,
+            domain::Currency::USD,
+        );
+        price(eth, aud, "5000", 10).insert(&pool).await?;
+        price(eth, usd, "3300", 12).insert(&pool).await?;
+        price(eth, aud, "5100", 12).insert(&pool).await?;
+
+        let found =
+            |currency, day| database::AssetPrices::find_price(eth, currency, date(day), &pool);
+
+        assert_eq!(
+            found(aud, 11).await?.map(|p| p.price),
+            Some("5000".to_string())
+        );
+        assert_eq!(
+            found(usd, 11).await?.map(|p| p.price),
+            Some("5000".to_string())
+        );
+        assert_eq!(
+            found(usd, 14).await?.map(|p| p.price),
+            Some("3300".to_string())
+        );
+        assert_eq!(
+            found(aud, 14).await?.map(|p| p.price),
+            Some("5100".to_string())
+        );
+        assert_eq!(found(aud, 9).await?, None);
+
+        Ok(())
+    }
+}
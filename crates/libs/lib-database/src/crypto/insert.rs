@@ -0,0 +1,257 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::AssetPrices {
+    /// Stores the price for its asset, currency and day, replacing any price
+    /// already stored for them.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored price. When an existing price was replaced, it keeps
+    /// its id and creation time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The price is not a positive decimal (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::AssetPrices;
+    /// use lib_domain::{AssetPrice, CryptoAsset, Currency};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let price = AssetPrice::parse(CryptoAsset::BTC, Currency::USD, "98250.12")?;
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    ///
+    /// let mut row = AssetPrices::new(price, date);
+    /// row.source = Some("Exchange close".to_string());
+    /// row.insert(pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert asset price into database",
+        skip(self, conn),
+        fields(asset = %self.asset, currency = %self.currency, price_date = %self.price_date),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            // Store the price as the domain type writes it, so equal prices compare equal
+            let price = self.asset_price()?.format_decimal();
+
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO asset_prices (
+                        id, asset, currency, price_date, price, source, created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                    ON CONFLICT (asset, currency, price_date) DO UPDATE SET
+                        price = excluded.price,
+                        source = excluded.source,
+                        updated_on = excluded.updated_on
+                "#,
+                self.id,
+                self.asset,
+                self.currency,
+                self.price_date,
+                price,
+                self.source,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("crypto.insert")
+            .await?;
+
+            let stored = Self::fetch_by_day(self.asset, self.currency, self.price_date, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Price of {} in {} on {} not found after insert",
+                        self.asset, self.currency, self.price_date
+                    ))
+                })?;
+
+            tracing::info!("Stored price {} on {}", price, self.price_date);
+
+            Ok(stored)
+        }
+    }
+}
+
+impl database::CryptoHoldings {
+    /// Stores the holding for its account and asset, replacing the quantity
+    /// of any holding the account already has of the asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored holding. When an existing holding was replaced, it
+    /// keeps its id and creation time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The quantity is not a valid amount of the asset (`DatabaseError::Validation`)
+    /// - The account does not exist (foreign key violation)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Insert crypto holding into database",
+        skip(self, conn),
+        fields(account_id = %self.account_id, asset = %self.asset),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let quantity = self.amount()?.format_decimal();
+
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO crypto_holdings (
+                        id, account_id, asset, quantity, created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?)
+                    ON CONFLICT (account_id, asset) DO UPDATE SET
+                        quantity = excluded.quantity,
+                        updated_on = excluded.updated_on
+                "#,
+                self.id,
+                self.account_id,
+                self.asset,
+                quantity,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("crypto.insert")
+            .await?;
+
+            let stored = Self::fetch_by_asset(self.account_id, self.asset, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Holding of {} in account {} not found after insert",
+                        self.asset, self.account_id
+                    ))
+                })?;
+
+            tracing::info!("Stored holding of {} {}", quantity, self.asset);
+
+            Ok(stored)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use lib_domain as domain;
+
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    pub(crate) fn date(day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    pub(crate) fn price(
+        asset: domain::CryptoAsset,
+        currency: domain::Currency,
+        price: &str,
+        day: u32,
+    ) -> database::AssetPrices {
+        database::AssetPrices::new(
+            domain::AssetPrice::parse(asset, currency, price).unwrap(),
+            date(day),
+        )
+    }
+
+    #[sqlx::test]
+    async fn insert_replaces_price_for_same_day(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let first = price(domain::CryptoAsset::ETH, domain::Currency::AUD, "5000", 14)
+            .insert(&pool)
+            .await?;
+
+        let mut replacement = price(
+            domain::CryptoAsset::ETH,
+            domain::Currency::AUD,
+            "5123.450000000000000001",
+            14,
+        );
+        replacement.source = Some("Exchange close".to_string());
+        let replaced = replacement.insert(&pool).await?;
+
+        assert_eq!(replaced.id, first.id);
+        assert_eq!(replaced.price, "5123.450000000000000001");
+        assert_eq!(replaced.source.as_deref(), Some("Exchange close"));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_keeps_every_wei(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let wallet = database::Accounts::new("Wallet").insert(&pool).await?;
+        let amount =
+            domain::CryptoAmount::parse("123456.123456789012345678", domain::CryptoAsset::ETH)?;
+
+        let first = database::CryptoHoldings::new(wallet.id, amount)
+            .insert(&pool)
+            .await?;
+        assert_eq!(first.amount()?, amount);
+
+        let topped_up =
+            amount.checked_add(domain::CryptoAmount::new(1, domain::CryptoAsset::ETH))?;
+        let replaced = database::CryptoHoldings::new(wallet.id, topped_up)
+            .insert(&pool)
+            .await?;
+
+        assert_eq!(replaced.id, first.id);
+        assert_eq!(replaced.quantity, "123456.123456789012345679");
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_rejects_invalid_text(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut row = price(domain::CryptoAsset::BTC, domain::Currency::AUD, "1", 14);
+        row.price = "1e6".to_string();
+
+        let result = row.insert(&pool).await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+//! # Crypto Database Module
+//!
+//! Tracks crypto wallets without losing precision. Each account can hold
+//! several assets, recorded as [`CryptoHoldings`], and assets are priced
+//! daily in [`AssetPrices`]. Quantities and prices run to eighteen decimal
+//! places, so both are stored as exact decimal text and read back through
+//! [`CryptoAmount`](lib_domain::CryptoAmount) and
+//! [`AssetPrice`](lib_domain::AssetPrice).
+//!
+//! A holding is valued at the latest price on or before the valuation date.
+//! When that price is in another currency, the value is converted with the
+//! [`ExchangeRates`](crate::ExchangeRates) for the day, so a wallet priced in
+//! US dollars can be reported in Australian dollars.
+
+mod find;
+mod insert;
+mod model;
+mod value;
+
+/// Database row model representing the daily price of a crypto asset.
+pub use model::AssetPrices;
+
+/// Database row model representing the quantity of an asset an account holds.
+pub use model::CryptoHoldings;
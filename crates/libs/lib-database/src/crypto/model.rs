@@ -0,0 +1,145 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// The price of a crypto asset in a currency on a day.
+///
+/// `price` is the exact decimal price of one whole asset, as written by
+/// [`domain::AssetPrice::format_decimal`].
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct AssetPrices {
+    pub id: domain::RowID,
+    pub asset: domain::CryptoAsset,
+    pub currency: domain::Currency,
+    pub price_date: chrono::NaiveDate,
+    pub price: String,
+
+    /// Where the price came from, e.g. the name of an exchange.
+    pub source: Option<String>,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+/// The quantity of a crypto asset held in an account.
+///
+/// `quantity` is the exact decimal number of whole assets, as written by
+/// [`domain::CryptoAmount::format_decimal`].
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct CryptoHoldings {
+    pub id: domain::RowID,
+    pub account_id: domain::RowID,
+    pub asset: domain::CryptoAsset,
+    pub quantity: String,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::AssetPrices {
+    /// Creates a new, unsaved price for a day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::AssetPrices;
+    /// use lib_domain::{AssetPrice, CryptoAsset, Currency};
+    ///
+    /// let price = AssetPrice::parse(CryptoAsset::ETH, Currency::AUD, "5123.45").unwrap();
+    /// let row = AssetPrices::new(price, chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap());
+    ///
+    /// assert_eq!(row.price, "5123.45");
+    /// assert_eq!(row.asset_price(), Ok(price));
+    /// ```
+    pub fn new(price: domain::AssetPrice, price_date: chrono::NaiveDate) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            asset: price.asset(),
+            currency: price.currency(),
+            price_date,
+            price: price.format_decimal(),
+            source: None,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Returns the stored price as a domain asset price.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the price is not a positive
+    /// decimal with at most eighteen decimal places.
+    pub fn asset_price(&self) -> DatabaseResult<domain::AssetPrice> {
+        domain::AssetPrice::parse(self.asset, self.currency, &self.price)
+            .map_err(|error| DatabaseError::Validation(error.to_string()))
+    }
+}
+
+impl database::CryptoHoldings {
+    /// Creates a new, unsaved holding of an amount in an account.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::CryptoHoldings;
+    /// use lib_domain::{CryptoAmount, CryptoAsset, RowID};
+    ///
+    /// let amount = CryptoAmount::parse("1.234567890123456789", CryptoAsset::ETH).unwrap();
+    /// let holding = CryptoHoldings::new(RowID::new(), amount);
+    ///
+    /// assert_eq!(holding.quantity, "1.234567890123456789");
+    /// assert_eq!(holding.amount(), Ok(amount));
+    /// ```
+    pub fn new(account_id: domain::RowID, amount: domain::CryptoAmount) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            account_id,
+            asset: amount.asset(),
+            quantity: amount.format_decimal(),
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Returns the stored quantity as a domain crypto amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the quantity is not a decimal
+    /// with at most as many decimal places as the asset.
+    pub fn amount(&self) -> DatabaseResult<domain::CryptoAmount> {
+        domain::CryptoAmount::parse(&self.quantity, self.asset)
+            .map_err(|error| DatabaseError::Validation(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stored_text_must_parse() {
+        let price =
+            domain::AssetPrice::parse(domain::CryptoAsset::BTC, domain::Currency::AUD, "150000")
+                .unwrap();
+        let mut row =
+            AssetPrices::new(price, chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap());
+        row.price = "0".to_string();
+        assert!(matches!(
+            row.asset_price(),
+            Err(DatabaseError::Validation(_))
+        ));
+
+        let mut holding = CryptoHoldings::new(
+            domain::RowID::new(),
+            domain::CryptoAmount::zero(domain::CryptoAsset::BTC),
+        );
+        holding.quantity = "0.000000001".to_string();
+        assert!(matches!(
+            holding.amount(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+impl database::AssetPrices {
+    /// Values an amount of a crypto asset in a currency on a valuation date.
+    ///
+    /// The amount is valued at the price found by
+    /// [`find_price`](Self::find_price) and rounded to the minor unit of the
+    /// price's currency. A price in another currency is then converted at the
+    /// day's [`ExchangeRates`](database::ExchangeRates).
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The amount to value
+    /// * `to` - The currency to value it in
+    /// * `on` - The valuation date
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The asset has no price on or before `on` (`DatabaseError::NotFound`)
+    /// - The price's currency has no exchange rate to `to` on or before `on` (`DatabaseError::NotFound`)
+    /// - The value is out of range (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::AssetPrices;
+    /// use lib_domain::{CryptoAmount, CryptoAsset, Currency};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let end_of_year = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+    /// let stack = CryptoAmount::parse("0.05 BTC", CryptoAsset::BTC)?;
+    ///
+    /// let in_aud = AssetPrices::value(stack, Currency::AUD, end_of_year, pool).await?;
+    /// println!("Worth {in_aud} at 30 June");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Value crypto amount", skip(conn), err)]
+    pub fn value<'c, A>(
+        amount: domain::CryptoAmount,
+        to: domain::Currency,
+        on: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<domain::Money>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let price = Self::find_price(amount.asset(), to, on, &mut *conn)
+                .await?
+                .ok_or_else(|| {
+                    DatabaseError::NotFound(format!(
+                        "No price for {} on or before {on}",
+                        amount.asset()
+                    ))
+                })?
+                .asset_price()?;
+
+            let value = amount
+                .value(&price)
+                .map_err(|error| DatabaseError::Validation(error.to_string()))?;
+
+            database::ExchangeRates::convert(value, to, on, &mut *conn).await
+        }
+    }
+}
+
+impl database::CryptoHoldings {
+    /// Values every asset held in an account in a currency on a valuation
+    /// date, as [`AssetPrices::value`](database::AssetPrices::value) does for
+    /// one amount, and adds them up.
+    ///
+    /// Holdings are valued at their current quantity.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The ID of the account
+    /// * `to` - The currency to value the holdings in
+    /// * `on` - The valuation date
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A held asset has no price on or before `on` (`DatabaseError::NotFound`)
+    /// - A price's currency has no exchange rate to `to` on or before `on` (`DatabaseError::NotFound`)
+    /// - A stored quantity or price is invalid, or the total is out of range (`DatabaseError::Validation`)
+    /// - Database connection fails
+    #[tracing::instrument(name = "Value crypto holdings", skip(conn), err)]
+    pub fn value_account<'c, A>(
+        account_id: domain::RowID,
+        to: domain::Currency,
+        on: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<domain::Money>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let mut total = domain::Money::zero(to);
+            for holding in Self::find_by_account(account_id, &mut *conn).await? {
+                let value =
+                    database::AssetPrices::value(holding.amount()?, to, on, &mut *conn).await?;
+                total = total
+                    .checked_add(value)
+                    .map_err(|error| DatabaseError::Validation(error.to_string()))?;
+            }
+
+            Ok(total)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::insert::tests::{Result, date, price};
+
+    #[sqlx::test]
+    async fn value_account_converts_through_exchange_rates(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let (aud, usd) = (domain::Currency::AUD, domain::Currency::USD);
+        let wallet = database::Accounts::new("Wallet").insert(&pool).await?;
+        let eth =
+            domain::CryptoAmount::parse("2.000000000000000001 ETH", domain::CryptoAsset::BTC)?;
+        let usdc = domain::CryptoAmount::parse("1000000 USDC", domain::CryptoAsset::BTC)?;
+        database::CryptoHoldings::new(wallet.id, eth)
+            .insert(&pool)
+            .await?;
+        database::CryptoHoldings::new(wallet.id, usdc)
+            .insert(&pool)
+            .await?;
+
+        // Ether is priced in dollars, the stablecoin in Australian dollars at a long-tail price
+        price(domain::CryptoAsset::ETH, usd, "3250.5", 10)
+            .insert(&pool)
+            .await?;
+        price(domain::CryptoAsset::USDC, aud, "0.000000123456", 10)
+            .insert(&pool)
+            .await?;
+        let rate = domain::ExchangeRate::parse(aud, usd, "0.5")?;
+        database::ExchangeRates::new(rate, date(1))
+            .insert(&pool)
+            .await?;
+
+        let value =
+            database::CryptoHoldings::value_account(wallet.id, aud, date(14), &pool).await?;
+
+        // 6,501.00 USD is 13,002.00 AUD, plus 0.12 AUD of the stablecoin
+        assert_eq!(value, domain::Money::new(1_300_212, aud));
+
+        let unpriced =
+            database::CryptoHoldings::value_account(wallet.id, aud, date(9), &pool).await;
+        assert!(matches!(unpriced, Err(DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
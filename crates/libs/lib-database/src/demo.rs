@@ -0,0 +1,240 @@
+//! # Demo Data Module
+//!
+//! Seeds a small, believable ledger for a public demo of the server, and
+//! resets a database back to it, so visitors can change whatever they like
+//! and the next reset puts it right.
+//!
+//! The demo ledger holds two accounts, a handful of income and expense
+//! categories with a monthly grocery budget, and about two months of
+//! transactions dated back from the day it is seeded, so reports always have
+//! something recent to show.
+//!
+//! [`DemoData::reset`] empties every table except the migrations and the
+//! public ID key, then seeds the demo ledger, all in one transaction. Nothing
+//! is kept, including the audit log, so it must only be run against a
+//! database that is meant to be thrown away.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_database::DemoData;
+//!
+//! # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+//! let seeded = DemoData::reset(pool).await?;
+//! println!("Demo ledger seeded with {} transactions", seeded.transactions);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Tables a reset leaves alone: the applied migrations, and the key public
+/// IDs are made with, so IDs handed out before a reset stay well formed.
+const KEPT_TABLES: &[&str] = &["_sqlx_migrations", "public_id_key"];
+
+/// Demo categories as code, name and type.
+const CATEGORIES: &[(&str, &str, domain::CategoryTypes)] = &[
+    ("INC.001", "Salary", domain::CategoryTypes::Income),
+    ("EXP.001", "Groceries", domain::CategoryTypes::Expense),
+    ("EXP.002", "Dining Out", domain::CategoryTypes::Expense),
+    ("EXP.003", "Transport", domain::CategoryTypes::Expense),
+    ("EXP.004", "Utilities", domain::CategoryTypes::Expense),
+];
+
+/// Demo transactions repeated every `every_days` over the seeded period, as
+/// payee, amount in cents, index into [`CATEGORIES`] and the first day back
+/// from today.
+const RECURRING: &[(&str, i64, usize, i64, i64)] = &[
+    ("Acme Pty Ltd", 385_000, 0, 14, 3),
+    ("Fresh Market", -14_250, 1, 7, 1),
+    ("Corner Cafe", -1_850, 2, 4, 0),
+    ("Thai Garden", -6_400, 2, 11, 5),
+    ("Metro Transit", -5_000, 3, 7, 2),
+    ("City Power", -21_730, 4, 30, 12),
+];
+
+/// Days back from today the demo transactions start.
+const SEEDED_DAYS: i64 = 60;
+
+/// What was seeded into the demo ledger.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DemoData {
+    /// Accounts seeded.
+    pub accounts: usize,
+
+    /// Categories seeded.
+    pub categories: usize,
+
+    /// Transactions seeded.
+    pub transactions: usize,
+}
+
+impl DemoData {
+    /// Empties the database and seeds the demo ledger, in one transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The database pool to reset
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError::Sqlx` if a table cannot be emptied or a row
+    /// inserted. Nothing is changed when any step fails.
+    #[tracing::instrument(name = "Reset demo data", skip(pool), err)]
+    pub async fn reset(pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<Self> {
+        let mut tx = pool.begin().await?;
+
+        // Tables are emptied in name order, whatever references them
+        sqlx::query("PRAGMA defer_foreign_keys = ON")
+            .execute(&mut *tx)
+            .observe("demo.reset")
+            .await?;
+
+        let tables: Vec<String> = sqlx::query_scalar(
+            r#"
+                SELECT name
+                FROM pragma_table_list
+                WHERE schema = 'main'
+                    AND type = 'table'
+                    AND name NOT LIKE 'sqlite_%'
+                ORDER BY name
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .observe("demo.reset")
+        .await?;
+
+        // The audit log and history tables are emptied last, as emptying the
+        // others writes to them
+        let (logs, records): (Vec<_>, Vec<_>) = tables
+            .into_iter()
+            .filter(|table| !KEPT_TABLES.contains(&table.as_str()))
+            .partition(|table| table == "audit_log" || table.ends_with("_history"));
+
+        for table in records.iter().chain(&logs) {
+            sqlx::query(&format!("DELETE FROM \"{table}\""))
+                .execute(&mut *tx)
+                .observe("demo.reset")
+                .await?;
+        }
+
+        let seeded = Self::seed(chrono::Utc::now().date_naive(), &mut tx).await?;
+
+        tx.commit().await?;
+
+        tracing::info!(
+            "Reset the demo ledger to {} accounts, {} categories and {} transactions",
+            seeded.accounts,
+            seeded.categories,
+            seeded.transactions
+        );
+
+        Ok(seeded)
+    }
+
+    /// Seeds the demo ledger into an empty database, with transactions dated
+    /// up to `today`.
+    ///
+    /// # Arguments
+    ///
+    /// * `today` - The date of the newest demo transactions
+    /// * `conn` - The database connection or transaction to seed
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError::Sqlx` if a row cannot be inserted, such as a
+    /// demo category whose code is already taken.
+    pub async fn seed(
+        today: chrono::NaiveDate,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Self> {
+        let mut everyday = database::Accounts::new("Everyday Account");
+        everyday.opening_balance = 250_000;
+        everyday.insert(&mut *conn).await?;
+
+        let mut credit_card = database::Accounts::new("Credit Card");
+        credit_card.opening_balance = -45_000;
+        credit_card.insert(&mut *conn).await?;
+
+        let mut categories = Vec::with_capacity(CATEGORIES.len());
+        for (code, name, category_type) in CATEGORIES {
+            let category = database::CategoriesBuilder::new()
+                .with_code(*code)
+                .with_name(*name)
+                .with_category_type(category_type.clone())
+                .build()
+                .map_err(|e| DatabaseError::Validation(e.to_string()))?;
+            categories.push(category.insert(&mut *conn).await?);
+        }
+
+        database::Budgets::new(categories[1].id, database::BudgetPeriods::Monthly, 60_000)
+            .insert(&mut *conn)
+            .await?;
+
+        let mut transactions = 0;
+        for (payee, amount, category, every_days, first_day_back) in RECURRING {
+            let mut days_back = *first_day_back;
+            while days_back < SEEDED_DAYS {
+                let mut transaction = database::Transactions::new(
+                    today - chrono::Duration::days(days_back),
+                    *payee,
+                    *amount,
+                );
+                transaction.category_id = Some(categories[*category].id);
+                // Eating out goes on the credit card, everything else the everyday account
+                transaction.account_id = Some(if *category == 2 {
+                    credit_card.id
+                } else {
+                    everyday.id
+                });
+                transaction.insert(&mut *conn).await?;
+
+                transactions += 1;
+                days_back += every_days;
+            }
+        }
+
+        Ok(Self {
+            accounts: 2,
+            categories: categories.len(),
+            transactions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test]
+    async fn reset_replaces_everything_with_the_demo_ledger(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let visitor = database::Categories::mock();
+        visitor.insert(&pool).await?;
+        let key = database::public_id_key(&pool).await?;
+
+        let seeded = DemoData::reset(&pool).await?;
+        let usage = database::Usage::find(&pool).await?;
+
+        assert_eq!(seeded.categories, CATEGORIES.len());
+        assert_eq!(usage.categories, CATEGORIES.len() as i64);
+        assert_eq!(usage.transactions, seeded.transactions as i64);
+        assert!(
+            database::Categories::find_by_id(visitor.id, database::UserScope::All, &pool)
+                .await?
+                .is_none()
+        );
+        assert!(database::public_id_key(&pool).await? == key);
+
+        // A second reset starts from scratch again rather than adding to it
+        DemoData::reset(&pool).await?;
+        assert_eq!(database::Usage::find(&pool).await?, usage);
+
+        Ok(())
+    }
+}
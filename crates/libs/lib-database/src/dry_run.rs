@@ -0,0 +1,192 @@
+//! # Dry Run Module
+//!
+//! Runs mutating database operations inside a transaction that is either
+//! committed or rolled back, so callers can offer a "validate only" (dry-run)
+//! mode without duplicating any write logic.
+//!
+//! A dry run executes the exact same statements as a real write, so database
+//! constraints (unique codes, foreign keys, journal balancing) are checked and
+//! the would-be result is read back, but nothing is persisted.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_database::{Categories, CategoriesBuilder, EventBus, with_transaction};
+//!
+//! # async fn example(pool: &sqlx::SqlitePool, bus: &EventBus) -> Result<(), Box<dyn std::error::Error>> {
+//! let category = CategoriesBuilder::new()
+//!     .with_code("FOOD.001")
+//!     .with_name("Groceries")
+//!     .with_category_type(lib_domain::CategoryTypes::Expense)
+//!     .build()?;
+//!
+//! // Validate the insert and see the would-be row without saving it
+//! let preview =
+//!     with_transaction(pool, bus, true, async |conn| category.insert(conn).await).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{DatabaseResult, EventBus, audit_log, events};
+
+/// Runs `operation` inside a new transaction on `pool`.
+///
+/// When `validate_only` is `false` the transaction is committed once the
+/// operation succeeds. When `validate_only` is `true` the transaction is always
+/// rolled back, and the operation's result is returned as a preview of what
+/// would have been written. If the operation fails the transaction is rolled
+/// back in both modes.
+///
+/// Once the commit returns, the changes the operation wrote are published on
+/// `events`. Nothing is published for a transaction that is rolled back.
+///
+/// Changes are recorded in the audit log against the actor set by
+/// [`AuditLog::with_actor`](crate::AuditLog::with_actor), if any.
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `events` - The bus to publish the committed changes on
+/// * `validate_only` - Roll back instead of committing (dry-run)
+/// * `operation` - The database work to run on the transaction's connection
+///
+/// # Errors
+///
+/// Returns any error from `operation`, or a `DatabaseError::Sqlx` if the
+/// transaction cannot be started, committed or rolled back.
+#[tracing::instrument(
+    name = "Run database operation in transaction",
+    skip(pool, events, operation),
+    err
+)]
+pub async fn with_transaction<T, F>(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    events: &EventBus,
+    validate_only: bool,
+    operation: F,
+) -> DatabaseResult<T>
+where
+    F: AsyncFnOnce(&mut sqlx::SqliteConnection) -> DatabaseResult<T>,
+{
+    let mut tx = pool.begin().await?;
+    audit_log::begin_actor(&mut tx).await?;
+
+    let (result, pending) = events::collect(operation(&mut tx)).await;
+    let result = result?;
+
+    if validate_only {
+        tx.rollback().await?;
+        tracing::debug!("Validate only, transaction rolled back.");
+    } else {
+        audit_log::end_actor(&mut tx).await?;
+        tx.commit().await?;
+        events.send(pending);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as database;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    async fn count_categories(pool: &sqlx::Pool<sqlx::Sqlite>) -> Result<i64> {
+        Ok(sqlx::query_scalar("SELECT COUNT(*) FROM categories")
+            .fetch_one(pool)
+            .await?)
+    }
+
+    #[sqlx::test]
+    async fn validate_only_returns_result_without_persisting(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let category = database::Categories::mock();
+
+        let preview = with_transaction(&pool, &EventBus::new(), true, async |conn| {
+            category.insert(conn).await
+        })
+        .await?;
+
+        assert_eq!(preview.id, category.id);
+        assert_eq!(preview.code, category.code);
+        assert_eq!(count_categories(&pool).await?, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn commit_persists_result(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock();
+
+        let inserted = with_transaction(&pool, &EventBus::new(), false, async |conn| {
+            category.insert(conn).await
+        })
+        .await?;
+
+        assert_eq!(inserted.id, category.id);
+        assert_eq!(count_categories(&pool).await?, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn validate_only_surfaces_constraint_errors(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let existing = database::Categories::mock().insert(&pool).await?;
+        let mut duplicate = database::Categories::mock();
+        duplicate.code = existing.code.clone();
+
+        let result = with_transaction(&pool, &EventBus::new(), true, async |conn| {
+            duplicate.insert(conn).await
+        })
+        .await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+        assert_eq!(count_categories(&pool).await?, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn validate_only_delete_leaves_row(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let existing = database::Categories::mock().insert(&pool).await?;
+
+        with_transaction(&pool, &EventBus::new(), true, async |conn| {
+            database::Categories::delete_by_id(existing.id, database::UserScope::All, conn).await
+        })
+        .await?;
+
+        assert_eq!(count_categories(&pool).await?, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn validate_only_journal_entry_checks_balance(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let debit = database::Categories::mock().insert(&pool).await?.id;
+        let credit = database::Categories::mock().insert(&pool).await?.id;
+        let entry = database::JournalEntries::mock(debit, credit);
+
+        let preview = with_transaction(&pool, &EventBus::new(), true, async |conn| {
+            entry.insert(conn).await
+        })
+        .await?;
+
+        assert_eq!(preview.splits.len(), 2);
+        assert!(
+            database::JournalEntries::find_by_id(entry.id, &pool)
+                .await?
+                .is_none()
+        );
+
+        Ok(())
+    }
+}
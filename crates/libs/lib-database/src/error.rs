@@ -13,6 +13,9 @@
 //! - `Config`: Configuration errors during DB initialization
 //! - `Validation`: Domain validation errors (constraint violations, etc.)
 //! - `NotFound`: Resource not found errors
+//! - `PeriodClosed`: Writes to transactions dated in a closed period
+//! - `Conflict`: Updates to rows changed since they were read
+//! - `InUse`: Deletes of rows other rows still refer to
 //! - `Other`: Catch-all for miscellaneous DB errors
 //!
 //! ## Usage
@@ -21,14 +24,13 @@
 //!
 //! Example:
 //! ```rust
+//! use lib_database::DatabaseError;
+//!
 //! fn do_db_work() -> Result<(), DatabaseError> {
 //!     // ...
+//!     Ok(())
 //! }
 //! ```
-//!
-//! ## Integration
-//!
-//! Errors are convertible to `LedgerError` for unified error handling across the backend.
 
 /// Result type alias used across database modules.
 ///
@@ -38,8 +40,11 @@
 /// Example:
 ///
 /// ```rust
-/// fn get_categories() -> DatabaseResult<Vec<Category>> {
+/// use lib_database::{Categories, DatabaseResult};
+///
+/// fn get_categories() -> DatabaseResult<Vec<Categories>> {
 ///     // ...
+///     Ok(Vec::new())
 /// }
 /// ```
 pub type DatabaseResult<T> = std::result::Result<T, DatabaseError>;
@@ -63,7 +68,7 @@ pub enum DatabaseError {
 
     /// Wrap config errors that occur during database initialization
     #[error("Config error: {0}")]
-    Config(#[from] crate::config::ConfigError),
+    Config(#[from] lib_config::ConfigError),
 
     /// Validation errors originating from the DB layer (e.g. constraint violations)
     #[error("Validation: {0}")]
@@ -73,6 +78,18 @@ pub enum DatabaseError {
     #[error("Not found: {0}")]
     NotFound(String),
 
+    /// Writes rejected because the transaction date is in a closed period
+    #[error("Period closed: {0}")]
+    PeriodClosed(String),
+
+    /// Updates rejected because the row was changed since it was read
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// Deletes rejected because other rows still refer to the row
+    #[error("In use: {0}")]
+    InUse(String),
+
     /// Generic catch-all for other database related errors
     #[error("Other database error: {0}")]
     Other(String),
@@ -119,7 +136,7 @@ mod tests {
         assert!(matches!(db_err, DatabaseError::Migration(_)));
 
         // Test Config variant (via From)
-        let config_err = crate::config::ConfigError::Validation("config error".to_string());
+        let config_err = lib_config::ConfigError::Validation("config error".to_string());
         let db_err: DatabaseError = config_err.into();
         assert!(matches!(db_err, DatabaseError::Config(_)));
 
@@ -139,15 +156,22 @@ mod tests {
     #[test]
     fn test_database_error_display() {
         let conn_err = DatabaseError::Connection("test connection".to_string());
-        assert_eq!(format!("{}", conn_err), "Error connecting to the database: test connection");
+        assert_eq!(
+            format!("{}", conn_err),
+            "Error connecting to the database: test connection"
+        );
 
         let sqlx_err = DatabaseError::Sqlx(sqlx::Error::RowNotFound);
         assert!(format!("{}", sqlx_err).contains("Database error:"));
 
-        let migrate_err = DatabaseError::Migration(sqlx::migrate::MigrateError::Execute(sqlx::Error::RowNotFound));
+        let migrate_err = DatabaseError::Migration(sqlx::migrate::MigrateError::Execute(
+            sqlx::Error::RowNotFound,
+        ));
         assert!(format!("{}", migrate_err).contains("Database migration error:"));
 
-        let config_err = DatabaseError::Config(crate::config::ConfigError::Validation("test config".to_string()));
+        let config_err = DatabaseError::Config(lib_config::ConfigError::Validation(
+            "test config".to_string(),
+        ));
         assert!(format!("{}", config_err).contains("Config error:"));
 
         let val_err = DatabaseError::Validation("test validation".to_string());
@@ -156,6 +180,15 @@ mod tests {
         let not_found_err = DatabaseError::NotFound("test record".to_string());
         assert_eq!(format!("{}", not_found_err), "Not found: test record");
 
+        let in_use_err = DatabaseError::InUse("test category".to_string());
+        assert_eq!(format!("{}", in_use_err), "In use: test category");
+
+        let closed_err = DatabaseError::PeriodClosed("2025-03-14".to_string());
+        assert_eq!(format!("{}", closed_err), "Period closed: 2025-03-14");
+
+        let conflict_err = DatabaseError::Conflict("version 2".to_string());
+        assert_eq!(format!("{}", conflict_err), "Conflict: version 2");
+
         let other_err = DatabaseError::Other("test other".to_string());
         assert_eq!(format!("{}", other_err), "Other database error: test other");
     }
@@ -180,30 +213,20 @@ mod tests {
         let db_err: DatabaseError = migrate_err.into();
         assert!(matches!(db_err, DatabaseError::Migration(_)));
 
-        // Test From<crate::config::ConfigError>
-        let config_err = crate::config::ConfigError::Validation("test".to_string());
+        // Test From<lib_config::ConfigError>
+        let config_err = lib_config::ConfigError::Validation("test".to_string());
         let db_err: DatabaseError = config_err.into();
         assert!(matches!(db_err, DatabaseError::Config(_)));
     }
 
-    #[test]
-    fn test_database_error_to_ledger_error_conversion() {
-        use crate::LedgerError;
-
-        let conn_err = DatabaseError::Connection("test".to_string());
-        let ledger_err: LedgerError = conn_err.into();
-        assert!(matches!(ledger_err, LedgerError::Database(_)));
-
-        let val_err = DatabaseError::Validation("test".to_string());
-        let ledger_err: LedgerError = val_err.into();
-        assert!(matches!(ledger_err, LedgerError::Database(_)));
-    }
-
     #[test]
     fn test_database_error_edge_cases() {
         // Test with empty strings
         let empty_conn = DatabaseError::Connection("".to_string());
-        assert_eq!(format!("{}", empty_conn), "Error connecting to the database: ");
+        assert_eq!(
+            format!("{}", empty_conn),
+            "Error connecting to the database: "
+        );
 
         // Test with special characters
         let special = DatabaseError::Validation("test\nwith\ttabs".to_string());
@@ -214,4 +237,3 @@ mod tests {
         assert_eq!(format!("{}", unicode), "Other database error: 测试错误");
     }
 }
-
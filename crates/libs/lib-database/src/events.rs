@@ -0,0 +1,359 @@
+//! # Data Events Module
+//!
+//! A bus announcing changes to the ledger's data, so the server can fan them
+//! out, to webhooks, caches and streaming subscribers, without polling the
+//! database. The server keeps one [`EventBus`] for its ledger, and hands it
+//! to everything that writes or listens.
+//!
+//! Each [`DataEvent`] carries a typed [`DataChanges`], holding the row as it
+//! was written or, for deletes, the ID of the row that went:
+//!
+//! | Change | Name |
+//! |--------|------|
+//! | [`CategoryInserted`](DataChanges::CategoryInserted) | `category.created` |
+//! | [`CategoryUpdated`](DataChanges::CategoryUpdated) | `category.updated` |
+//! | [`CategoryDeleted`](DataChanges::CategoryDeleted) | `category.deleted` |
+//! | [`CategoryRestored`](DataChanges::CategoryRestored) | `category.restored` |
+//! | [`TransactionInserted`](DataChanges::TransactionInserted) | `transaction.created` |
+//! | [`TransactionUpdated`](DataChanges::TransactionUpdated) | `transaction.updated` |
+//! | [`TransactionDeleted`](DataChanges::TransactionDeleted) | `transaction.deleted` |
+//!
+//! ## After Commit
+//!
+//! Events are only published for changes that are committed. The changes an
+//! operation run by [`with_transaction`](crate::with_transaction) writes are
+//! held by its task, and published on the bus it was given once the commit
+//! has returned. They are dropped if the operation fails, is only validated,
+//! or the commit fails. [`EventBus::publish_committed`] does the same for an
+//! operation that commits its own transactions. Changes written outside
+//! either are not published.
+//!
+//! Rolling back to a savepoint, inside an operation that still commits, does
+//! not drop the events of the changes it undid.
+//!
+//! Nothing waits for subscribers: with none listening an event is dropped,
+//! and a subscriber that falls more than [`EVENT_CAPACITY`] events behind
+//! misses the oldest.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_database::{DataChanges, EventBus};
+//!
+//! # async fn example(bus: EventBus) {
+//! let mut events = bus.subscribe();
+//!
+//! while let Ok(event) = events.recv().await {
+//!     if let DataChanges::CategoryUpdated(category) = &event.change {
+//!         println!("{} renamed {}", category.id, category.name);
+//!     }
+//! }
+//! # }
+//! ```
+
+use std::cell::RefCell;
+
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+/// Events buffered for each subscriber before the oldest are dropped.
+pub const EVENT_CAPACITY: usize = 1_024;
+
+tokio::task_local! {
+    /// Events of the changes written by the task being run, waiting for its
+    /// transaction to commit.
+    static PENDING: RefCell<Vec<DataEvent>>;
+}
+
+/// A change to the ledger's data.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum DataChanges {
+    /// A category was inserted.
+    CategoryInserted(database::Categories),
+    /// A category was updated, including its active status.
+    CategoryUpdated(database::Categories),
+    /// A category was deleted, or soft deleted.
+    CategoryDeleted(domain::RowID),
+    /// A soft deleted category was restored.
+    CategoryRestored(database::Categories),
+    /// A transaction was inserted.
+    TransactionInserted(database::Transactions),
+    /// A transaction was updated.
+    TransactionUpdated(database::Transactions),
+    /// A transaction was deleted.
+    TransactionDeleted(domain::RowID),
+}
+
+impl DataChanges {
+    /// The name of the change, as `<record>.<change>`, such as
+    /// `category.created`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CategoryInserted(_) => "category.created",
+            Self::CategoryUpdated(_) => "category.updated",
+            Self::CategoryDeleted(_) => "category.deleted",
+            Self::CategoryRestored(_) => "category.restored",
+            Self::TransactionInserted(_) => "transaction.created",
+            Self::TransactionUpdated(_) => "transaction.updated",
+            Self::TransactionDeleted(_) => "transaction.deleted",
+        }
+    }
+
+    /// The ID of the row that changed.
+    pub fn id(&self) -> domain::RowID {
+        match self {
+            Self::CategoryInserted(category)
+            | Self::CategoryUpdated(category)
+            | Self::CategoryRestored(category) => category.id,
+            Self::TransactionInserted(transaction) | Self::TransactionUpdated(transaction) => {
+                transaction.id
+            }
+            Self::CategoryDeleted(id) | Self::TransactionDeleted(id) => *id,
+        }
+    }
+}
+
+/// A committed change to the ledger's data.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct DataEvent {
+    /// Unique ID of the event, so a receiver can tell repeats apart.
+    pub event_id: domain::RowID,
+
+    /// What changed.
+    pub change: DataChanges,
+
+    /// When it was written.
+    pub occurred_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl DataEvent {
+    /// The name of the change, see [`DataChanges::name`].
+    pub fn name(&self) -> &'static str {
+        self.change.name()
+    }
+
+    /// The ID of the row that changed.
+    pub fn id(&self) -> domain::RowID {
+        self.change.id()
+    }
+}
+
+/// The bus a ledger's events are published on.
+///
+/// Clones share the bus. It closes, ending every subscription, once the last
+/// clone is dropped.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: tokio::sync::broadcast::Sender<DataEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    /// Creates a bus with nobody subscribed.
+    pub fn new() -> Self {
+        Self {
+            sender: tokio::sync::broadcast::channel(EVENT_CAPACITY).0,
+        }
+    }
+
+    /// Subscribes to the events published from now on.
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<DataEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Runs `operation`, which commits the database transactions it writes
+    /// in itself, and publishes the changes it wrote once it succeeds.
+    ///
+    /// Nothing is published if it fails, even for a transaction it committed
+    /// before failing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{EventBus, RecurringTransactions};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, bus: &EventBus, template_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let today = chrono::Utc::now().date_naive();
+    /// bus.publish_committed(RecurringTransactions::materialise(template_id, today, pool))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn publish_committed<T>(
+        &self,
+        operation: impl Future<Output = DatabaseResult<T>>,
+    ) -> DatabaseResult<T> {
+        let (result, pending) = collect(operation).await;
+        let result = result?;
+
+        self.send(pending);
+
+        Ok(result)
+    }
+
+    /// Sends events to every subscriber.
+    pub(crate) fn send(&self, events: Vec<DataEvent>) {
+        for event in events {
+            // Sending only fails when nobody is subscribed, which is fine
+            let _ = self.sender.send(event);
+        }
+    }
+}
+
+/// Runs `future`, returning its output with the events of the changes it
+/// wrote, for the caller to publish once they are committed.
+pub(crate) async fn collect<F: Future>(future: F) -> (F::Output, Vec<DataEvent>) {
+    PENDING
+        .scope(RefCell::new(Vec::new()), async move {
+            let output = future.await;
+            (output, PENDING.with(RefCell::take))
+        })
+        .await
+}
+
+/// Holds a change written by the current task until it is committed, see
+/// [After Commit](self#after-commit).
+pub(crate) fn publish(change: DataChanges) {
+    let event = DataEvent {
+        event_id: domain::RowID::new(),
+        change,
+        occurred_on: chrono::Utc::now(),
+    };
+
+    // Outside an operation that publishes its changes there is nobody to
+    let _ = PENDING.try_with(|pending| pending.borrow_mut().push(event));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert(
+        payee: &str,
+    ) -> impl AsyncFnOnce(&mut sqlx::SqliteConnection) -> DatabaseResult<database::Transactions>
+    {
+        let transaction =
+            database::Transactions::new(chrono::Utc::now().date_naive(), payee, -1_250);
+        async move |conn| transaction.insert(conn).await
+    }
+
+    fn received(events: &mut tokio::sync::broadcast::Receiver<DataEvent>) -> Vec<DataChanges> {
+        std::iter::from_fn(|| events.try_recv().ok())
+            .map(|event| event.change)
+            .collect()
+    }
+
+    #[sqlx::test]
+    async fn transaction_changes_are_published(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<()> {
+        let bus = EventBus::new();
+        let mut events = bus.subscribe();
+        let scope = database::UserScope::All;
+
+        let transaction =
+            database::with_transaction(&pool, &bus, false, insert("Corner Store")).await?;
+        let mut renamed = transaction.clone();
+        renamed.payee = "Corner Store Pty Ltd".to_string();
+        let updated = database::with_transaction(&pool, &bus, false, async |conn| {
+            renamed.update(scope, conn).await
+        })
+        .await?;
+        database::with_transaction(&pool, &bus, false, async |conn| {
+            database::Transactions::delete_by_id(transaction.id, scope, conn).await
+        })
+        .await?;
+
+        assert_eq!(
+            received(&mut events),
+            [
+                DataChanges::TransactionInserted(transaction.clone()),
+                DataChanges::TransactionUpdated(updated),
+                DataChanges::TransactionDeleted(transaction.id),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn changes_wait_for_the_commit(pool: sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<()> {
+        let bus = EventBus::new();
+        let mut events = bus.subscribe();
+
+        let transaction = database::with_transaction(&pool, &bus, false, async |conn| {
+            let transaction = insert("Corner Store")(&mut *conn).await?;
+            assert_eq!(received(&mut events), []);
+            Ok(transaction)
+        })
+        .await?;
+
+        assert_eq!(
+            received(&mut events),
+            [DataChanges::TransactionInserted(transaction)]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn changes_not_committed_are_not_published(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<()> {
+        let bus = EventBus::new();
+        let mut events = bus.subscribe();
+
+        database::with_transaction(&pool, &bus, true, insert("Corner Store")).await?;
+        let failed = database::with_transaction(&pool, &bus, false, async |conn| {
+            insert("Landlord")(&mut *conn).await?;
+            Err::<(), _>(database::DatabaseError::Validation("rent".to_string()))
+        })
+        .await;
+        let direct = insert("Bakery")(&mut *pool.acquire().await?).await?;
+
+        assert!(failed.is_err());
+        assert_eq!(received(&mut events), []);
+        assert!(
+            database::Transactions::find_by_id(direct.id, database::UserScope::All, &pool)
+                .await?
+                .is_some()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn each_bus_only_publishes_its_own_changes(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<()> {
+        let bus = EventBus::new();
+        let other = EventBus::new();
+        let mut events = bus.subscribe();
+        let mut others = other.subscribe();
+
+        let transaction = bus
+            .publish_committed(
+                database::Transactions::new(
+                    chrono::Utc::now().date_naive(),
+                    "Corner Store",
+                    -1_250,
+                )
+                .insert(&pool),
+            )
+            .await?;
+
+        assert_eq!(
+            received(&mut events),
+            [DataChanges::TransactionInserted(transaction)]
+        );
+        assert_eq!(received(&mut others), []);
+
+        Ok(())
+    }
+}
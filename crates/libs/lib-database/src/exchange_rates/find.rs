@@ -0,0 +1,286 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+impl database::ExchangeRates {
+    /// Finds every stored rate for a currency pair, oldest first.
+    ///
+    /// Only rates stored in the given direction are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The currency the rates convert from
+    /// * `quote` - The currency the rates convert to
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(name = "Find exchange rates for pair", skip(conn), err)]
+    pub fn find_by_pair<'c, A>(
+        base: domain::Currency,
+        quote: domain::Currency,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let rates = sqlx::query_as!(
+                database::ExchangeRates,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        base_currency       AS "base_currency!: domain::Currency",
+                        quote_currency      AS "quote_currency!: domain::Currency",
+                        rate_date           AS "rate_date!: chrono::NaiveDate",
+                        rate                AS "rate!: i64",
+                        source,
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM exchange_rates
+                    WHERE base_currency = ? AND quote_currency = ?
+                    ORDER BY rate_date ASC
+                "#,
+                base,
+                quote
+            )
+            .fetch_all(&mut *conn)
+            .observe("exchange_rates.find_by_pair")
+            .await?;
+
+            Ok(rates)
+        }
+    }
+
+    /// Finds the rate to convert between two currencies on a valuation date.
+    ///
+    /// The latest rate on or before `on` is used, stored in either direction.
+    /// A rate stored the other way round is inverted. When both directions
+    /// have a rate on the same day, the one stored in the requested direction
+    /// is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The currency to convert from
+    /// * `to` - The currency to convert to
+    /// * `on` - The valuation date
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the rate, or `None` if the pair has no rate on or before `on`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - Both currencies are the same (`DatabaseError::Validation`)
+    /// - Database connection fails
+    #[tracing::instrument(name = "Find exchange rate", skip(conn), err)]
+    pub fn find_rate<'c, A>(
+        from: domain::Currency,
+        to: domain::Currency,
+        on: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<domain::ExchangeRate>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            if from == to {
+                return Err(DatabaseError::Validation(format!(
+                    "No exchange rate is needed from {from} to itself"
+                )));
+            }
+
+            let mut conn = conn.acquire().await?;
+
+            let row = sqlx::query_as!(
+                database::ExchangeRates,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        base_currency       AS "base_currency!: domain::Currency",
+                        quote_currency      AS "quote_currency!: domain::Currency",
+                        rate_date           AS "rate_date!: chrono::NaiveDate",
+                        rate                AS "rate!: i64",
+                        source,
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM exchange_rates
+                    WHERE ((base_currency = ?1 AND quote_currency = ?2)
+                            OR (base_currency = ?2 AND quote_currency = ?1))
+                        AND rate_date <= ?3
+                    ORDER BY rate_date DESC, base_currency = ?1 DESC
+                    LIMIT 1
+                "#,
+                from,
+                to,
+                on
+            )
+            .fetch_optional(&mut *conn)
+            .observe("exchange_rates.find_rate")
+            .await?;
+
+            let Some(row) = row else {
+                return Ok(None);
+            };
+
+            let rate = row.exchange_rate()?;
+            if rate.from() == from {
+                return Ok(Some(rate));
+            }
+
+            rate.inverse()
+                .map(Some)
+                .map_err(|error| DatabaseError::Validation(error.to_string()))
+        }
+    }
+
+    /// Converts an amount to another currency at the rate on a valuation date.
+    ///
+    /// Amounts already in `to` are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `money` - The amount to convert
+    /// * `to` - The currency to convert to
+    /// * `on` - The valuation date
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - There is no rate for the pair on or before `on` (`DatabaseError::NotFound`)
+    /// - The converted amount is out of range (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::ExchangeRates;
+    /// use lib_domain::{Currency, Money};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let end_of_year = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+    /// let holiday_fund = Money::new(250_000, Currency::USD);
+    ///
+    /// let in_aud = ExchangeRates::convert(holiday_fund, Currency::AUD, end_of_year, pool).await?;
+    /// println!("Worth {in_aud} at 30 June");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Convert money", skip(conn), err)]
+    pub fn convert<'c, A>(
+        money: domain::Money,
+        to: domain::Currency,
+        on: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<domain::Money>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            if money.currency() == to {
+                return Ok(money);
+            }
+
+            let rate = Self::find_rate(money.currency(), to, on, conn)
+                .await?
+                .ok_or_else(|| {
+                    DatabaseError::NotFound(format!(
+                        "No exchange rate from {} to {to} on or before {on}",
+                        money.currency()
+                    ))
+                })?;
+
+            money
+                .convert(&rate)
+                .map_err(|error| DatabaseError::Validation(error.to_string()))
+        }
+    }
+
+    /// Reads the rate for a currency pair and day back through the given
+    /// connection.
+    pub(super) async fn fetch_by_day(
+        base: domain::Currency,
+        quote: domain::Currency,
+        rate_date: chrono::NaiveDate,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let rate = sqlx::query_as!(
+            database::ExchangeRates,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    base_currency       AS "base_currency!: domain::Currency",
+                    quote_currency      AS "quote_currency!: domain::Currency",
+                    rate_date           AS "rate_date!: chrono::NaiveDate",
+                    rate                AS "rate!: i64",
+                    source,
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM exchange_rates
+                WHERE base_currency = ? AND quote_currency = ? AND rate_date = ?
+            "#,
+            base,
+            quote,
+            rate_date
+        )
+        .fetch_optional(&mut *conn)
+        .observe("exchange_rates.fetch_by_day")
+        .await?;
+
+        Ok(rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange_rates::insert::tests::{Result, rate};
+    use domain::Currency::{AUD, EUR, USD};
+
+    fn date(day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn find_rate_uses_latest_on_or_before(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        rate(AUD, USD, "0.60", 10).insert(&pool).await?;
+        rate(AUD, USD, "0.65", 14).insert(&pool).await?;
+        rate(USD, AUD, "1.25", 14).insert(&pool).await?;
+        rate(USD, AUD, "1.50", 20).insert(&pool).await?;
+
+        let friday = database::ExchangeRates::find_rate(AUD, USD, date(16), &pool).await?;
+        let inverted = database::ExchangeRates::find_rate(AUD, USD, date(21), &pool).await?;
+        let before_any = database::ExchangeRates::find_rate(AUD, USD, date(9), &pool).await?;
+        let history = database::ExchangeRates::find_by_pair(AUD, USD, &pool).await?;
+
+        assert_eq!(friday.map(|rate| rate.rate()), Some(65_000_000));
+        assert_eq!(inverted.map(|rate| rate.rate()), Some(66_666_667));
+        assert_eq!(before_any, None);
+        assert_eq!(history.len(), 2);
+        assert!(
+            database::ExchangeRates::find_rate(AUD, AUD, date(16), &pool)
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn convert_uses_stored_rate(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        rate(EUR, AUD, "1.6", 1).insert(&pool).await?;
+        let amount = domain::Money::new(-1_000, EUR);
+
+        let converted = database::ExchangeRates::convert(amount, AUD, date(2), &pool).await?;
+        let unchanged = database::ExchangeRates::convert(amount, EUR, date(2), &pool).await?;
+        let missing = database::ExchangeRates::convert(amount, USD, date(2), &pool).await;
+
+        assert_eq!(converted, domain::Money::new(-1_600, AUD));
+        assert_eq!(unchanged, amount);
+        assert!(matches!(missing, Err(DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
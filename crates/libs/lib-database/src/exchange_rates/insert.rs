@@ -0,0 +1,162 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::ExchangeRates {
+    /// Stores the rate for its currency pair and day, replacing any rate the
+    /// pair already has for that day.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored rate. When an existing rate was replaced, it keeps
+    /// its id and creation time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The rate is not positive or converts a currency to itself (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::ExchangeRates;
+    /// use lib_domain::{Currency, ExchangeRate};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let rate = ExchangeRate::parse(Currency::AUD, Currency::USD, "0.6543")?;
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    ///
+    /// let mut row = ExchangeRates::new(rate, date);
+    /// row.source = Some("RBA".to_string());
+    /// row.insert(pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert exchange rate into database",
+        skip(self, conn),
+        fields(
+            base = %self.base_currency,
+            quote = %self.quote_currency,
+            rate_date = %self.rate_date
+        ),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO exchange_rates (
+                        id, base_currency, quote_currency, rate_date, rate, source, created_on,
+                        updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                    ON CONFLICT (base_currency, quote_currency, rate_date) DO UPDATE SET
+                        rate = excluded.rate,
+                        source = excluded.source,
+                        updated_on = excluded.updated_on
+                "#,
+                self.id,
+                self.base_currency,
+                self.quote_currency,
+                self.rate_date,
+                self.rate,
+                self.source,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("exchange_rates.insert")
+            .await?;
+
+            let stored = Self::fetch_by_day(
+                self.base_currency,
+                self.quote_currency,
+                self.rate_date,
+                &mut conn,
+            )
+            .await?
+            .ok_or_else(|| {
+                database::DatabaseError::NotFound(format!(
+                    "Exchange rate {} to {} on {} not found after insert",
+                    self.base_currency, self.quote_currency, self.rate_date
+                ))
+            })?;
+
+            tracing::info!("Stored exchange rate {} on {}", self.rate, self.rate_date);
+
+            Ok(stored)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use lib_domain as domain;
+
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    pub(crate) fn rate(
+        from: domain::Currency,
+        to: domain::Currency,
+        rate: &str,
+        day: u32,
+    ) -> database::ExchangeRates {
+        let rate = domain::ExchangeRate::parse(from, to, rate).unwrap();
+        database::ExchangeRates::new(rate, chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap())
+    }
+
+    #[sqlx::test]
+    async fn insert_replaces_rate_for_same_day(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let first = rate(domain::Currency::AUD, domain::Currency::USD, "0.65", 14)
+            .insert(&pool)
+            .await?;
+
+        let mut replacement = rate(domain::Currency::AUD, domain::Currency::USD, "0.66", 14);
+        replacement.source = Some("RBA".to_string());
+        let replaced = replacement.insert(&pool).await?;
+
+        assert_eq!(replaced.id, first.id);
+        assert_eq!(replaced.rate, 66_000_000);
+        assert_eq!(replaced.source.as_deref(), Some("RBA"));
+
+        let other_day = rate(domain::Currency::AUD, domain::Currency::USD, "0.67", 15)
+            .insert(&pool)
+            .await?;
+        assert_ne!(other_day.id, first.id);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_rejects_invalid_rate(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut row = rate(domain::Currency::AUD, domain::Currency::USD, "0.65", 14);
+        row.rate = -1;
+
+        let result = row.insert(&pool).await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        Ok(())
+    }
+}
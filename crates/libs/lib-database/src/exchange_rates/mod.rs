@@ -0,0 +1,17 @@
+//! # Exchange Rates Database Module
+//!
+//! Stores daily exchange rates between currencies, so accounts held in
+//! different currencies can be added up at a chosen valuation date. There is
+//! at most one rate per currency pair per day, and storing a rate for a day
+//! that already has one replaces it.
+//!
+//! Rates are looked up in either direction: a stored AUD to USD rate also
+//! gives the USD to AUD rate. The rate used for a date is the latest one on or
+//! before it, so weekends and public holidays use the last trading day.
+
+mod find;
+mod insert;
+mod model;
+
+/// Database row model representing a stored daily exchange rate.
+pub use model::ExchangeRates;
@@ -0,0 +1,94 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// The exchange rate between two currencies on a day.
+///
+/// `rate` is the price of one unit of the base currency in the quote
+/// currency, in hundred millionths, as held by [`domain::ExchangeRate`].
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct ExchangeRates {
+    pub id: domain::RowID,
+    pub base_currency: domain::Currency,
+    pub quote_currency: domain::Currency,
+    pub rate_date: chrono::NaiveDate,
+    pub rate: i64,
+
+    /// Where the rate came from, e.g. the name of a rates feed.
+    pub source: Option<String>,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::ExchangeRates {
+    /// Creates a new, unsaved rate for a day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::ExchangeRates;
+    /// use lib_domain::{Currency, ExchangeRate};
+    ///
+    /// let rate = ExchangeRate::parse(Currency::AUD, Currency::USD, "0.6543").unwrap();
+    /// let row = ExchangeRates::new(rate, chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap());
+    ///
+    /// assert_eq!(row.rate, 65_430_000);
+    /// assert_eq!(row.exchange_rate(), Ok(rate));
+    /// ```
+    pub fn new(rate: domain::ExchangeRate, rate_date: chrono::NaiveDate) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            base_currency: rate.from(),
+            quote_currency: rate.to(),
+            rate_date,
+            rate: rate.rate(),
+            source: None,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Returns the stored rate as a domain exchange rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the rate is not positive or
+    /// the currencies are the same.
+    pub fn exchange_rate(&self) -> DatabaseResult<domain::ExchangeRate> {
+        domain::ExchangeRate::new(self.base_currency, self.quote_currency, self.rate)
+            .map_err(|error| DatabaseError::Validation(error.to_string()))
+    }
+
+    /// Checks the rate before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the rate is not positive or
+    /// the currencies are the same.
+    pub fn validate(&self) -> DatabaseResult<()> {
+        self.exchange_rate().map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_rejects_invalid_rates() {
+        let rate =
+            domain::ExchangeRate::parse(domain::Currency::AUD, domain::Currency::USD, "0.65")
+                .unwrap();
+        let mut row =
+            ExchangeRates::new(rate, chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap());
+        assert_eq!(row.validate(), Ok(()));
+
+        row.rate = 0;
+        assert!(matches!(row.validate(), Err(DatabaseError::Validation(_))));
+
+        row.rate = rate.rate();
+        row.quote_currency = domain::Currency::AUD;
+        assert!(matches!(row.validate(), Err(DatabaseError::Validation(_))));
+    }
+}
@@ -0,0 +1,532 @@
+use std::collections::BTreeSet;
+
+use futures_util::TryStreamExt;
+
+use crate::{self as database, DatabaseResult, ExportFormats, LedgerExport};
+use lib_domain as domain;
+
+/// Namespaces of the elements in a GnuCash XML book.
+const NAMESPACES: &[(&str, &str)] = &[
+    ("gnc", "http://www.gnucash.org/XML/gnc"),
+    ("act", "http://www.gnucash.org/XML/act"),
+    ("book", "http://www.gnucash.org/XML/book"),
+    ("cd", "http://www.gnucash.org/XML/cd"),
+    ("cmdty", "http://www.gnucash.org/XML/cmdty"),
+    ("slot", "http://www.gnucash.org/XML/slot"),
+    ("split", "http://www.gnucash.org/XML/split"),
+    ("trn", "http://www.gnucash.org/XML/trn"),
+    ("ts", "http://www.gnucash.org/XML/ts"),
+];
+
+/// Time of day GnuCash posts transactions at, so the date reads the same in
+/// every timezone.
+const POSTED_TIME: &str = "10:59:00 +0000";
+
+/// Exports the ledger as a GnuCash XML book, see the
+/// [module documentation](super).
+#[derive(Debug, Clone, Default)]
+pub struct GnuCashExport {
+    /// The first transaction date exported, or `None` for no lower bound.
+    pub from: Option<chrono::NaiveDate>,
+
+    /// The last transaction date exported, or `None` for no upper bound.
+    pub to: Option<chrono::NaiveDate>,
+
+    /// Whose categories, accounts and transactions are exported.
+    pub scope: database::UserScope,
+}
+
+/// A GnuCash account, made from a category, a ledger account or one of the
+/// accounts the export adds.
+struct BookAccount {
+    guid: String,
+    name: String,
+    account_type: &'static str,
+    currency: domain::Currency,
+    code: Option<String>,
+    description: Option<String>,
+    parent: Option<String>,
+    hidden: bool,
+}
+
+/// A split of a GnuCash transaction.
+struct BookSplit {
+    account: String,
+    amount: i64,
+    reconciled: char,
+}
+
+/// A GnuCash transaction, with splits summing to zero.
+struct BookTransaction {
+    guid: String,
+    currency: domain::Currency,
+    date: chrono::NaiveDate,
+    entered: chrono::DateTime<chrono::Utc>,
+    description: String,
+    notes: Option<String>,
+    splits: [BookSplit; 2],
+}
+
+impl GnuCashExport {
+    /// Exports every posted transaction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the transactions exported to those dated from `from` to `to`,
+    /// inclusive. Either bound may be left open.
+    pub fn with_dates(
+        mut self,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Self {
+        self.from = from;
+        self.to = to;
+        self
+    }
+
+    /// Limits the categories, accounts and transactions exported to `scope`.
+    pub fn with_scope(mut self, scope: database::UserScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Writes the book as an uncompressed GnuCash XML file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError::Sqlx` if the ledger cannot be read.
+    #[tracing::instrument(name = "Export GnuCash book", skip(pool), err)]
+    pub async fn write(&self, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<String> {
+        let records = LedgerExport::new(ExportFormats::Csv)
+            .with_dates(self.from, self.to)
+            .with_scope(self.scope);
+        let categories: Vec<database::Categories> = records.categories(pool).try_collect().await?;
+        let accounts: Vec<database::Accounts> = records.accounts(pool).try_collect().await?;
+        let transactions: Vec<database::Transactions> =
+            records.transactions(pool).try_collect().await?;
+
+        let root = guid(domain::RowID::new());
+        let mut book_accounts = vec![BookAccount::root(&root)];
+
+        for category in &categories {
+            book_accounts.push(BookAccount {
+                guid: guid(category.id),
+                name: category.name.clone(),
+                account_type: account_type(&category.category_type),
+                currency: domain::Currency::default(),
+                code: Some(category.code.clone()),
+                description: category.description.clone(),
+                parent: Some(category.parent_id.map(guid).unwrap_or_else(|| root.clone())),
+                hidden: !category.is_active || category.deleted_on.is_some(),
+            });
+        }
+
+        for account in &accounts {
+            book_accounts.push(BookAccount {
+                guid: guid(account.id),
+                name: account.name.clone(),
+                account_type: if account.opening_balance < 0 {
+                    "CREDIT"
+                } else {
+                    "BANK"
+                },
+                currency: account.currency,
+                code: None,
+                description: account.description.clone(),
+                parent: Some(root.clone()),
+                hidden: !account.is_active,
+            });
+        }
+
+        let mut book_transactions = Vec::new();
+        let mut extra = ExtraAccounts::new(&root);
+
+        for account in accounts
+            .iter()
+            .filter(|account| account.opening_balance != 0)
+        {
+            let equity = extra.opening_balances(account.currency);
+            book_transactions.push(BookTransaction {
+                guid: guid(domain::RowID::new()),
+                currency: account.currency,
+                date: account.created_on.date_naive(),
+                entered: account.created_on,
+                description: "Opening Balance".to_string(),
+                notes: None,
+                splits: [
+                    BookSplit::new(guid(account.id), account.opening_balance, 'y'),
+                    BookSplit::new(equity, -account.opening_balance, 'n'),
+                ],
+            });
+        }
+
+        for transaction in transactions
+            .iter()
+            .filter(|transaction| !transaction.is_draft)
+        {
+            let account = transaction
+                .account_id
+                .and_then(|id| accounts.iter().find(|account| account.id == id));
+            let currency = account.map(|account| account.currency).unwrap_or_default();
+
+            let account_guid = match account {
+                Some(account) => guid(account.id),
+                None => extra.imbalance(currency),
+            };
+            let category_guid = match transaction.category_id {
+                Some(id) if categories.iter().any(|category| category.id == id) => guid(id),
+                _ => extra.imbalance(currency),
+            };
+
+            book_transactions.push(BookTransaction {
+                guid: guid(transaction.id),
+                currency,
+                date: transaction.transaction_date,
+                entered: transaction.created_on,
+                description: transaction.payee.clone(),
+                notes: transaction.description.clone(),
+                splits: [
+                    BookSplit::new(
+                        account_guid,
+                        transaction.amount,
+                        reconciled(transaction.cleared_status),
+                    ),
+                    BookSplit::new(category_guid, -transaction.amount, 'n'),
+                ],
+            });
+        }
+
+        book_accounts.extend(extra.accounts);
+        book_transactions.sort_by_key(|transaction| (transaction.date, transaction.entered));
+
+        Ok(book_xml(&book_accounts, &book_transactions))
+    }
+}
+
+/// The GnuCash account type of a category of `category_type`.
+fn account_type(category_type: &domain::CategoryTypes) -> &'static str {
+    match category_type {
+        domain::CategoryTypes::Asset => "ASSET",
+        domain::CategoryTypes::Equity => "EQUITY",
+        domain::CategoryTypes::Expense => "EXPENSE",
+        domain::CategoryTypes::Income => "INCOME",
+        domain::CategoryTypes::Liability => "LIABILITY",
+    }
+}
+
+/// The accounts GnuCash books keep for amounts with nowhere else to go,
+/// added to the book only if something is posted to them.
+struct ExtraAccounts<'r> {
+    root: &'r str,
+    accounts: Vec<BookAccount>,
+}
+
+impl<'r> ExtraAccounts<'r> {
+    fn new(root: &'r str) -> Self {
+        Self {
+            root,
+            accounts: Vec::new(),
+        }
+    }
+
+    /// The equity account opening balances in `currency` are posted against.
+    fn opening_balances(&mut self, currency: domain::Currency) -> String {
+        self.account(
+            format!("Opening Balances-{}", currency.code()),
+            "EQUITY",
+            currency,
+        )
+    }
+
+    /// The account the other side of a transaction without an account or
+    /// category in `currency` is posted to.
+    fn imbalance(&mut self, currency: domain::Currency) -> String {
+        self.account(format!("Imbalance-{}", currency.code()), "BANK", currency)
+    }
+
+    fn account(
+        &mut self,
+        name: String,
+        account_type: &'static str,
+        currency: domain::Currency,
+    ) -> String {
+        if let Some(account) = self.accounts.iter().find(|account| account.name == name) {
+            return account.guid.clone();
+        }
+
+        let account = BookAccount {
+            guid: guid(domain::RowID::new()),
+            name,
+            account_type,
+            currency,
+            code: None,
+            description: None,
+            parent: Some(self.root.to_string()),
+            hidden: false,
+        };
+        let guid = account.guid.clone();
+        self.accounts.push(account);
+
+        guid
+    }
+}
+
+impl BookAccount {
+    /// The root account every top level account is under.
+    fn root(guid: &str) -> Self {
+        Self {
+            guid: guid.to_string(),
+            name: "Root Account".to_string(),
+            account_type: "ROOT",
+            currency: domain::Currency::default(),
+            code: None,
+            description: None,
+            parent: None,
+            hidden: false,
+        }
+    }
+}
+
+impl BookSplit {
+    fn new(account: String, amount: i64, reconciled: char) -> Self {
+        Self {
+            account,
+            amount,
+            reconciled,
+        }
+    }
+}
+
+/// The GnuCash reconciled state of a transaction with `status`.
+fn reconciled(status: database::ClearedStatus) -> char {
+    match status {
+        database::ClearedStatus::Uncleared => 'n',
+        database::ClearedStatus::Cleared => 'c',
+        database::ClearedStatus::Reconciled => 'y',
+    }
+}
+
+/// A row ID as a GnuCash GUID, 32 hex digits.
+fn guid(id: domain::RowID) -> String {
+    id.into_uuid().simple().to_string()
+}
+
+/// Writes the book, accounts before the transactions posted to them.
+fn book_xml(accounts: &[BookAccount], transactions: &[BookTransaction]) -> String {
+    let currencies: BTreeSet<domain::Currency> =
+        accounts.iter().map(|account| account.currency).collect();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\" ?>\n<gnc-v2");
+    for (prefix, namespace) in NAMESPACES {
+        xml.push_str(&format!("\n     xmlns:{prefix}=\"{namespace}\""));
+    }
+    xml.push_str(
+        ">\n<gnc:count-data cd:type=\"book\">1</gnc:count-data>\n<gnc:book version=\"2.0.0\">\n",
+    );
+    xml.push_str(&format!(
+        "<book:id type=\"guid\">{}</book:id>\n",
+        guid(domain::RowID::new())
+    ));
+    xml.push_str(&format!(
+        "<gnc:count-data cd:type=\"commodity\">{}</gnc:count-data>\n",
+        currencies.len()
+    ));
+    xml.push_str(&format!(
+        "<gnc:count-data cd:type=\"account\">{}</gnc:count-data>\n",
+        accounts.len()
+    ));
+    xml.push_str(&format!(
+        "<gnc:count-data cd:type=\"transaction\">{}</gnc:count-data>\n",
+        transactions.len()
+    ));
+
+    for currency in &currencies {
+        xml.push_str("<gnc:commodity version=\"2.0.0\">\n");
+        xml.push_str(&commodity("cmdty", *currency));
+        xml.push_str("</gnc:commodity>\n");
+    }
+
+    for account in accounts {
+        xml.push_str("<gnc:account version=\"2.0.0\">\n");
+        xml.push_str(&format!(
+            "  <act:name>{}</act:name>\n",
+            escape(&account.name)
+        ));
+        xml.push_str(&format!(
+            "  <act:id type=\"guid\">{}</act:id>\n",
+            account.guid
+        ));
+        xml.push_str(&format!(
+            "  <act:type>{}</act:type>\n",
+            account.account_type
+        ));
+        if account.parent.is_some() {
+            xml.push_str("  <act:commodity>\n");
+            xml.push_str(&commodity("    cmdty", account.currency));
+            xml.push_str("  </act:commodity>\n");
+            xml.push_str(&format!(
+                "  <act:commodity-scu>{}</act:commodity-scu>\n",
+                account.currency.minor_per_major()
+            ));
+        }
+        if let Some(code) = &account.code {
+            xml.push_str(&format!("  <act:code>{}</act:code>\n", escape(code)));
+        }
+        if let Some(description) = &account.description {
+            xml.push_str(&format!(
+                "  <act:description>{}</act:description>\n",
+                escape(description)
+            ));
+        }
+        if account.hidden {
+            xml.push_str("  <act:slots>\n    <slot>\n      <slot:key>hidden</slot:key>\n");
+            xml.push_str("      <slot:value type=\"string\">true</slot:value>\n    </slot>\n  </act:slots>\n");
+        }
+        if let Some(parent) = &account.parent {
+            xml.push_str(&format!(
+                "  <act:parent type=\"guid\">{parent}</act:parent>\n"
+            ));
+        }
+        xml.push_str("</gnc:account>\n");
+    }
+
+    for transaction in transactions {
+        let scu = transaction.currency.minor_per_major();
+
+        xml.push_str("<gnc:transaction version=\"2.0.0\">\n");
+        xml.push_str(&format!(
+            "  <trn:id type=\"guid\">{}</trn:id>\n",
+            transaction.guid
+        ));
+        xml.push_str("  <trn:currency>\n");
+        xml.push_str(&commodity("    cmdty", transaction.currency));
+        xml.push_str("  </trn:currency>\n");
+        xml.push_str(&format!(
+            "  <trn:date-posted>\n    <ts:date>{} {POSTED_TIME}</ts:date>\n  </trn:date-posted>\n",
+            transaction.date
+        ));
+        xml.push_str(&format!(
+            "  <trn:date-entered>\n    <ts:date>{}</ts:date>\n  </trn:date-entered>\n",
+            transaction.entered.format("%Y-%m-%d %H:%M:%S %z")
+        ));
+        xml.push_str(&format!(
+            "  <trn:description>{}</trn:description>\n",
+            escape(&transaction.description)
+        ));
+        if let Some(notes) = &transaction.notes {
+            xml.push_str("  <trn:slots>\n    <slot>\n      <slot:key>notes</slot:key>\n");
+            xml.push_str(&format!(
+                "      <slot:value type=\"string\">{}</slot:value>\n    </slot>\n  </trn:slots>\n",
+                escape(notes)
+            ));
+        }
+        xml.push_str("  <trn:splits>\n");
+        for split in &transaction.splits {
+            xml.push_str("    <trn:split>\n");
+            xml.push_str(&format!(
+                "      <split:id type=\"guid\">{}</split:id>\n",
+                guid(domain::RowID::new())
+            ));
+            xml.push_str(&format!(
+                "      <split:reconciled-state>{}</split:reconciled-state>\n",
+                split.reconciled
+            ));
+            xml.push_str(&format!(
+                "      <split:value>{}/{scu}</split:value>\n",
+                split.amount
+            ));
+            xml.push_str(&format!(
+                "      <split:quantity>{}/{scu}</split:quantity>\n",
+                split.amount
+            ));
+            xml.push_str(&format!(
+                "      <split:account type=\"guid\">{}</split:account>\n",
+                split.account
+            ));
+            xml.push_str("    </trn:split>\n");
+        }
+        xml.push_str("  </trn:splits>\n</gnc:transaction>\n");
+    }
+
+    xml.push_str("</gnc:book>\n</gnc-v2>\n");
+    xml
+}
+
+/// The elements naming `currency` as a commodity, under `prefix`.
+fn commodity(prefix: &str, currency: domain::Currency) -> String {
+    let indent = prefix.trim_end_matches("cmdty");
+    format!(
+        "{indent}<cmdty:space>CURRENCY</cmdty:space>\n{indent}<cmdty:id>{}</cmdty:id>\n",
+        currency.code()
+    )
+}
+
+/// Escapes `text` for an XML element.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test]
+    async fn book_posts_each_transaction_between_its_account_and_category(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let food = database::CategoriesBuilder::new()
+            .with_code("FOD")
+            .with_name("Food & Drink")
+            .with_category_type(domain::CategoryTypes::Expense)
+            .build()?
+            .insert(&pool)
+            .await?;
+        let mut everyday = database::Accounts::new("Everyday");
+        everyday.opening_balance = 100_000;
+        let everyday = everyday.insert(&pool).await?;
+
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        let mut groceries = database::Transactions::new(date, "Fresh Market", -4_200);
+        groceries.category_id = Some(food.id);
+        groceries.account_id = Some(everyday.id);
+        groceries.insert(&pool).await?;
+        database::Transactions::new(date, "Unfiled", -100)
+            .insert(&pool)
+            .await?;
+
+        let xml = GnuCashExport::new().write(&pool).await?;
+
+        assert!(xml.contains("<act:name>Food &amp; Drink</act:name>"));
+        assert!(xml.contains(&format!(
+            "<act:id type=\"guid\">{}</act:id>\n  <act:type>EXPENSE</act:type>",
+            guid(food.id)
+        )));
+        assert!(xml.contains("<act:type>BANK</act:type>"));
+        assert!(xml.contains("<gnc:count-data cd:type=\"transaction\">3</gnc:count-data>"));
+        assert!(xml.contains("<act:name>Imbalance-AUD</act:name>"));
+        assert!(xml.contains("<act:name>Opening Balances-AUD</act:name>"));
+        assert!(xml.contains("<ts:date>2025-03-14 10:59:00 +0000</ts:date>"));
+        assert!(xml.contains(&format!(
+            "<split:value>4200/100</split:value>\n      <split:quantity>4200/100</split:quantity>\n      <split:account type=\"guid\">{}</split:account>",
+            guid(food.id)
+        )));
+
+        Ok(())
+    }
+
+    #[test]
+    fn category_types_map_to_gnucash_account_types() {
+        assert_eq!(account_type(&domain::CategoryTypes::Asset), "ASSET");
+        assert_eq!(account_type(&domain::CategoryTypes::Liability), "LIABILITY");
+        assert_eq!(account_type(&domain::CategoryTypes::Equity), "EQUITY");
+        assert_eq!(account_type(&domain::CategoryTypes::Income), "INCOME");
+        assert_eq!(account_type(&domain::CategoryTypes::Expense), "EXPENSE");
+    }
+}
@@ -0,0 +1,380 @@
+use futures_util::stream::{self, BoxStream, StreamExt, TryStreamExt};
+
+use crate::query_metrics::StreamObservation;
+use crate::{self as database, DatabaseError, DatabaseResult, ExportEntities, ExportFormats};
+use lib_domain as domain;
+
+/// Exports the records of a ledger, see the [module documentation](super).
+#[derive(Debug, Clone, Default)]
+pub struct LedgerExport {
+    /// The format records are written in.
+    pub format: ExportFormats,
+
+    /// The first transaction date exported, or `None` for no lower bound.
+    pub from: Option<chrono::NaiveDate>,
+
+    /// The last transaction date exported, or `None` for no upper bound.
+    pub to: Option<chrono::NaiveDate>,
+
+    /// Whose categories, accounts and transactions are exported.
+    pub scope: database::UserScope,
+
+    /// The key to write row IDs as public IDs with, or `None` to write them
+    /// as stored.
+    pub public_ids: Option<domain::PublicIdKey>,
+}
+
+impl LedgerExport {
+    /// Exports every record in `format`, with row IDs as stored.
+    pub fn new(format: ExportFormats) -> Self {
+        Self {
+            format,
+            ..Self::default()
+        }
+    }
+
+    /// Limits the transactions exported to those dated from `from` to `to`,
+    /// inclusive. Either bound may be left open.
+    pub fn with_dates(
+        mut self,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Self {
+        self.from = from;
+        self.to = to;
+        self
+    }
+
+    /// Limits the categories, accounts and transactions exported to `scope`.
+    pub fn with_scope(mut self, scope: database::UserScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Writes row IDs as the public IDs `key` encrypts them to, as the ledger
+    /// is known by outside the server.
+    pub fn with_public_ids(mut self, key: domain::PublicIdKey) -> Self {
+        self.public_ids = Some(key);
+        self
+    }
+
+    /// Streams the records of `entity`, each as one CSV record or JSON line
+    /// ending in a newline. A CSV export starts with its header row, even
+    /// when there are no records.
+    ///
+    /// The stream reads rows from the pool as it is polled, so a large ledger
+    /// is never held in memory.
+    ///
+    /// # Errors
+    ///
+    /// Yields a `DatabaseError::Sqlx` if the rows cannot be read, or a
+    /// `DatabaseError::Other` if a record cannot be written.
+    pub fn stream<'p>(
+        &self,
+        entity: ExportEntities,
+        pool: &'p sqlx::Pool<sqlx::Sqlite>,
+    ) -> BoxStream<'p, DatabaseResult<String>> {
+        match entity {
+            ExportEntities::Categories => self.records(entity, self.categories(pool)),
+            ExportEntities::Accounts => self.records(entity, self.accounts(pool)),
+            ExportEntities::Transactions => self.records(entity, self.transactions(pool)),
+        }
+    }
+
+    /// Every category in scope, deleted or not, oldest first.
+    pub(super) fn categories<'p>(
+        &self,
+        pool: &'p sqlx::Pool<sqlx::Sqlite>,
+    ) -> BoxStream<'p, DatabaseResult<database::Categories>> {
+        let mut observation = StreamObservation::new("export.categories");
+
+        sqlx::query_as::<_, database::Categories>(
+            r#"
+                SELECT
+                    id, code, name, description, url_slug, category_type, color,
+                    icon, is_active, parent_id, user_id, created_on, updated_on, deleted_on, version
+                FROM categories
+                WHERE ?1 IS NULL OR user_id = ?1
+                ORDER BY created_on, id
+            "#,
+        )
+        .bind(self.scope.user_id())
+        .fetch(pool)
+        .inspect_ok(move |_| observation.row())
+        .map_err(DatabaseError::from)
+        .boxed()
+    }
+
+    /// Every account in scope, oldest first.
+    pub(super) fn accounts<'p>(
+        &self,
+        pool: &'p sqlx::Pool<sqlx::Sqlite>,
+    ) -> BoxStream<'p, DatabaseResult<database::Accounts>> {
+        let mut observation = StreamObservation::new("export.accounts");
+
+        sqlx::query_as::<_, database::Accounts>(
+            r#"
+                SELECT
+                    id, name, description, opening_balance, currency,
+                    adjustments_category_id, is_active, user_id, created_on, updated_on
+                FROM accounts
+                WHERE ?1 IS NULL OR user_id = ?1
+                ORDER BY created_on, id
+            "#,
+        )
+        .bind(self.scope.user_id())
+        .fetch(pool)
+        .inspect_ok(move |_| observation.row())
+        .map_err(DatabaseError::from)
+        .boxed()
+    }
+
+    /// Every transaction in scope and in the date range, oldest first.
+    pub(super) fn transactions<'p>(
+        &self,
+        pool: &'p sqlx::Pool<sqlx::Sqlite>,
+    ) -> BoxStream<'p, DatabaseResult<database::Transactions>> {
+        let mut observation = StreamObservation::new("export.transactions");
+
+        sqlx::query_as::<_, database::Transactions>(
+            r#"
+                SELECT
+                    id, transaction_date, payee, description, amount, category_id,
+                    account_id, is_draft, latitude, longitude, place_name, return_by,
+                    warranty_expires, cleared_status, user_id, created_on, updated_on, version
+                FROM transactions
+                WHERE (?1 IS NULL OR user_id = ?1)
+                  AND (?2 IS NULL OR transaction_date >= ?2)
+                  AND (?3 IS NULL OR transaction_date <= ?3)
+                ORDER BY transaction_date, created_on, id
+            "#,
+        )
+        .bind(self.scope.user_id())
+        .bind(self.from)
+        .bind(self.to)
+        .fetch(pool)
+        .inspect_ok(move |_| observation.row())
+        .map_err(DatabaseError::from)
+        .boxed()
+    }
+
+    /// Writes each of `rows` as a record, after the header row of a CSV
+    /// export.
+    fn records<'p, T>(
+        &self,
+        entity: ExportEntities,
+        rows: BoxStream<'p, DatabaseResult<T>>,
+    ) -> BoxStream<'p, DatabaseResult<String>>
+    where
+        T: serde::Serialize + Send + 'p,
+    {
+        let header = match self.format {
+            ExportFormats::Csv => Some(csv_record(entity.columns().iter().copied())),
+            ExportFormats::JsonLines => None,
+        };
+
+        let export = self.clone();
+        let records = rows.and_then(move |row| std::future::ready(export.record(entity, &row)));
+
+        stream::iter(header).chain(records).boxed()
+    }
+
+    /// Writes `row` as a record of `entity`.
+    fn record<T: serde::Serialize>(
+        &self,
+        entity: ExportEntities,
+        row: &T,
+    ) -> DatabaseResult<String> {
+        let serde_json::Value::Object(mut fields) =
+            serde_json::to_value(row).map_err(export_error)?
+        else {
+            return Err(DatabaseError::Other(format!(
+                "Cannot export {entity:?}, not a record"
+            )));
+        };
+
+        if let Some(key) = &self.public_ids {
+            for (column, value) in fields.iter_mut() {
+                let id = value
+                    .as_str()
+                    .and_then(|text| text.parse::<domain::RowID>().ok());
+                if let Some(id) = id.filter(|_| column == "id" || column.ends_with("_id")) {
+                    *value = key.encode(id).to_string().into();
+                }
+            }
+        }
+
+        match self.format {
+            ExportFormats::JsonLines => Ok(format!("{}\n", serde_json::Value::Object(fields))),
+            ExportFormats::Csv => {
+                csv_record(
+                    entity
+                        .columns()
+                        .iter()
+                        .map(|column| match fields.get(*column) {
+                            None | Some(serde_json::Value::Null) => String::new(),
+                            Some(serde_json::Value::String(text)) => text.clone(),
+                            Some(value) => value.to_string(),
+                        }),
+                )
+            }
+        }
+    }
+}
+
+/// Writes `fields` as one CSV record, quoted where needed.
+fn csv_record<I>(fields: I) -> DatabaseResult<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(fields).map_err(export_error)?;
+    let bytes = writer.into_inner().map_err(export_error)?;
+
+    String::from_utf8(bytes).map_err(export_error)
+}
+
+fn export_error(error: impl std::fmt::Display) -> DatabaseError {
+    DatabaseError::Other(format!("Cannot write export: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn lines(
+        export: &LedgerExport,
+        entity: ExportEntities,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<Vec<String>> {
+        Ok(export.stream(entity, pool).try_collect().await?)
+    }
+
+    #[sqlx::test]
+    async fn csv_export_writes_a_header_and_quotes_fields(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut account = database::Accounts::new("Savings, joint");
+        account.opening_balance = 150_000;
+        account.insert(&pool).await?;
+
+        let records = lines(
+            &LedgerExport::new(ExportFormats::Csv),
+            ExportEntities::Accounts,
+            &pool,
+        )
+        .await?;
+        let file = records.concat();
+        let mut reader = csv::Reader::from_reader(file.as_bytes());
+        let headers = reader.headers()?.clone();
+        let rows = reader
+            .records()
+            .collect::<core::result::Result<Vec<_>, _>>()?;
+
+        assert_eq!(
+            headers.iter().collect::<Vec<_>>(),
+            ExportEntities::Accounts.columns()
+        );
+        assert_eq!(rows.len(), 1);
+        assert_eq!(&rows[0][1], "Savings, joint");
+        assert_eq!(&rows[0][3], "150000");
+        assert_eq!(&rows[0][2], "");
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn json_lines_export_limits_transactions_to_the_dates(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let date = |day| chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        for day in [1, 15, 31] {
+            database::Transactions::new(date(day), "Fresh Market", -4_200)
+                .insert(&pool)
+                .await?;
+        }
+
+        let export =
+            LedgerExport::new(ExportFormats::JsonLines).with_dates(Some(date(2)), Some(date(31)));
+        let records = export_records(&export, &pool).await?;
+
+        let dates: Vec<&str> = records
+            .iter()
+            .map(|record| record["transaction_date"].as_str().unwrap())
+            .collect();
+        assert_eq!(dates, ["2025-03-15", "2025-03-31"]);
+        assert_eq!(records[0]["amount"], -4_200);
+
+        Ok(())
+    }
+
+    async fn export_records(
+        export: &LedgerExport,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<Vec<serde_json::Value>> {
+        lines(export, ExportEntities::Transactions, pool)
+            .await?
+            .iter()
+            .map(|line| Ok(serde_json::from_str(line)?))
+            .collect()
+    }
+
+    #[sqlx::test]
+    async fn public_ids_replace_row_ids(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let key = database::public_id_key(&pool).await?;
+        let category = database::Categories::mock().insert(&pool).await?;
+        let mut transaction = database::Transactions::new(
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+            "Cafe",
+            -450,
+        );
+        transaction.category_id = Some(category.id);
+        transaction.insert(&pool).await?;
+
+        let export = LedgerExport::new(ExportFormats::JsonLines).with_public_ids(key.clone());
+        let records = export_records(&export, &pool).await?;
+
+        assert_eq!(records[0]["id"], key.encode(transaction.id).to_string());
+        assert_eq!(
+            records[0]["category_id"],
+            key.encode(category.id).to_string()
+        );
+        assert_eq!(records[0]["payee"], "Cafe");
+
+        Ok(())
+    }
+
+    #[test]
+    fn columns_cover_every_field() -> Result<()> {
+        let fields = |value: serde_json::Value| -> Vec<String> {
+            value.as_object().unwrap().keys().cloned().collect()
+        };
+        let sorted = |entity: ExportEntities| {
+            let mut columns: Vec<String> = entity
+                .columns()
+                .iter()
+                .map(|column| column.to_string())
+                .collect();
+            columns.sort();
+            columns
+        };
+
+        assert_eq!(
+            fields(serde_json::to_value(database::Categories::mock())?),
+            sorted(ExportEntities::Categories)
+        );
+        assert_eq!(
+            fields(serde_json::to_value(database::Accounts::mock())?),
+            sorted(ExportEntities::Accounts)
+        );
+        assert_eq!(
+            fields(serde_json::to_value(database::Transactions::mock())?),
+            sorted(ExportEntities::Transactions)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+//! # Export Database Module
+//!
+//! Gets records out of the ledger, for spreadsheets or for moving to another
+//! program. A [`LedgerExport`] streams the categories, accounts or
+//! transactions of the ledger, one [`ExportEntities`] at a time, as CSV with a
+//! header row or as JSON Lines, see [`ExportFormats`].
+//!
+//! Every column of a record is exported, by its name in the database, so an
+//! export can be read back without losing anything. Categories include those
+//! that were soft-deleted, as older transactions may still refer to them,
+//! with `deleted_on` telling them apart. Transactions can be limited to a
+//! range of dates, while categories and accounts are always exported whole.
+//!
+//! Row IDs are written as stored unless the export is given the ledger's
+//! [public ID key](crate::public_id_key), in which case every ID column is
+//! written as the public ID the record is known by outside the server.
+//!
+//! ## GnuCash
+//!
+//! [`GnuCashExport`] writes the whole ledger as an uncompressed GnuCash XML
+//! book, for moving to GnuCash or checking balances against it. Categories
+//! become accounts of the GnuCash type matching their category type, e.g.
+//! `EXPENSE` or `INCOME`, under their parent category, and ledger accounts
+//! become `BANK` accounts, or `CREDIT` accounts if they opened owing money.
+//! Each posted transaction is split between its account and its category,
+//! and each opening balance between its account and an `Opening Balances`
+//! equity account. A transaction without an account or category has that
+//! side posted to an `Imbalance` account, as GnuCash does. Drafts are left
+//! out, as they are of every balance.
+//!
+//! Categories are in the ledger's default currency, so a transaction in an
+//! account of another currency is posted to its category at face value, and
+//! needs a price set in GnuCash to balance.
+//!
+//! ## Plain text accounting
+//!
+//! [`PlainTextExport`] writes the ledger as a Beancount or hledger journal,
+//! see [`PlainTextFormats`], posting transactions the same way. A category is
+//! the journal account named by its code under the root account of its
+//! type, so `FOD.GRO` of type expense is `Expenses:FOD:GRO`, and a ledger
+//! account is under `Assets`, or `Liabilities` if it opened owing money.
+//! Transactions without a category are posted to `Expenses:Uncategorised` or
+//! `Income:Uncategorised`, and without an account from `Assets:Unassigned`.
+//!
+//! ## Round trip
+//!
+//! [`RoundTrip`] checks an export holds the whole ledger, by exporting every
+//! record as JSON Lines, reading the lines back into an empty in-memory
+//! database and comparing the two, see [`RoundTripChecks`]. It compares the
+//! number of categories, accounts and transactions, the sum of every
+//! transaction and the balance of each account. Owners are not carried
+//! across, as users are not exported. The export is held in memory while it
+//! is read back, so this is for occasionally checking backups rather than
+//! for every export.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use futures_util::TryStreamExt;
+//! use lib_database::{ExportEntities, ExportFormats, LedgerExport};
+//!
+//! # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+//! let from = chrono::NaiveDate::from_ymd_opt(2025, 7, 1);
+//! let export = LedgerExport::new(ExportFormats::Csv).with_dates(from, None);
+//!
+//! let mut records = export.stream(ExportEntities::Transactions, pool);
+//! while let Some(record) = records.try_next().await? {
+//!     print!("{record}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod gnucash;
+mod ledger;
+mod model;
+mod plain_text;
+mod round_trip;
+
+/// Writes a ledger as a GnuCash book.
+pub use gnucash::GnuCashExport;
+
+/// Streams the records of a ledger.
+pub use ledger::LedgerExport;
+
+/// The format and kinds of records exported.
+pub use model::{ExportEntities, ExportFormats};
+
+/// Writes a ledger as a Beancount or hledger journal.
+pub use plain_text::{PlainTextExport, PlainTextFormats};
+
+/// Checks an export reads back into the same ledger.
+pub use round_trip::{RoundTrip, RoundTripCheck, RoundTripChecks};
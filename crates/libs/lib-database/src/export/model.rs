@@ -0,0 +1,81 @@
+/// The format records are exported in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum ExportFormats {
+    /// Comma separated values, with a header row naming the columns, for
+    /// spreadsheets.
+    #[default]
+    Csv,
+
+    /// One JSON object per line, keyed by column, for migration scripts.
+    JsonLines,
+}
+
+/// The kinds of record that can be exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum ExportEntities {
+    Categories,
+    Accounts,
+    Transactions,
+}
+
+impl ExportEntities {
+    /// Every kind of record, in the order a full export writes them, so the
+    /// records a transaction refers to come before it.
+    pub const ALL: [ExportEntities; 3] = [Self::Categories, Self::Accounts, Self::Transactions];
+
+    /// The columns exported for the records, in the order CSV exports write
+    /// them.
+    pub fn columns(&self) -> &'static [&'static str] {
+        match self {
+            Self::Categories => &[
+                "id",
+                "code",
+                "name",
+                "description",
+                "url_slug",
+                "category_type",
+                "color",
+                "icon",
+                "is_active",
+                "parent_id",
+                "user_id",
+                "created_on",
+                "updated_on",
+                "deleted_on",
+                "version",
+            ],
+            Self::Accounts => &[
+                "id",
+                "name",
+                "description",
+                "opening_balance",
+                "currency",
+                "adjustments_category_id",
+                "is_active",
+                "user_id",
+                "created_on",
+                "updated_on",
+            ],
+            Self::Transactions => &[
+                "id",
+                "transaction_date",
+                "payee",
+                "description",
+                "amount",
+                "category_id",
+                "account_id",
+                "is_draft",
+                "latitude",
+                "longitude",
+                "place_name",
+                "return_by",
+                "warranty_expires",
+                "cleared_status",
+                "user_id",
+                "created_on",
+                "updated_on",
+                "version",
+            ],
+        }
+    }
+}
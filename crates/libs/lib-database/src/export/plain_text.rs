@@ -0,0 +1,381 @@
+use futures_util::TryStreamExt;
+
+use crate::{self as database, DatabaseResult, ExportFormats, LedgerExport};
+use lib_domain as domain;
+
+/// The equity account opening balances are posted against.
+const OPENING_BALANCES: &str = "Equity:Opening-Balances";
+
+/// The account transactions without an account are posted from.
+const UNASSIGNED: &str = "Assets:Unassigned";
+
+/// The plain text accounting syntax a ledger is written in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum PlainTextFormats {
+    /// Beancount, with an `open` directive for every account, dated the
+    /// first entry.
+    #[default]
+    Beancount,
+
+    /// hledger journal syntax, with `account` directives for every account.
+    Hledger,
+}
+
+/// Exports the ledger as a plain text accounting journal, see the
+/// [module documentation](super).
+#[derive(Debug, Clone, Default)]
+pub struct PlainTextExport {
+    /// The syntax the journal is written in.
+    pub format: PlainTextFormats,
+
+    /// The first transaction date exported, or `None` for no lower bound.
+    pub from: Option<chrono::NaiveDate>,
+
+    /// The last transaction date exported, or `None` for no upper bound.
+    pub to: Option<chrono::NaiveDate>,
+
+    /// Whose categories, accounts and transactions are exported.
+    pub scope: database::UserScope,
+}
+
+/// A journal transaction, posted from one account to another.
+struct Entry {
+    date: chrono::NaiveDate,
+    cleared_status: database::ClearedStatus,
+    payee: String,
+    narration: Option<String>,
+    currency: domain::Currency,
+    postings: [(String, i64); 2],
+}
+
+impl PlainTextExport {
+    /// Exports every posted transaction in `format`.
+    pub fn new(format: PlainTextFormats) -> Self {
+        Self {
+            format,
+            ..Self::default()
+        }
+    }
+
+    /// Limits the transactions exported to those dated from `from` to `to`,
+    /// inclusive. Either bound may be left open.
+    pub fn with_dates(
+        mut self,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Self {
+        self.from = from;
+        self.to = to;
+        self
+    }
+
+    /// Limits the categories, accounts and transactions exported to `scope`.
+    pub fn with_scope(mut self, scope: database::UserScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Writes the journal.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError::Sqlx` if the ledger cannot be read.
+    #[tracing::instrument(name = "Export plain text journal", skip(pool), err)]
+    pub async fn write(&self, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<String> {
+        let records = LedgerExport::new(ExportFormats::Csv)
+            .with_dates(self.from, self.to)
+            .with_scope(self.scope);
+        let categories: Vec<database::Categories> = records.categories(pool).try_collect().await?;
+        let accounts: Vec<database::Accounts> = records.accounts(pool).try_collect().await?;
+        let transactions: Vec<database::Transactions> =
+            records.transactions(pool).try_collect().await?;
+
+        let mut entries = Vec::new();
+
+        for account in accounts
+            .iter()
+            .filter(|account| account.opening_balance != 0)
+        {
+            entries.push(Entry {
+                date: account.created_on.date_naive(),
+                cleared_status: database::ClearedStatus::Reconciled,
+                payee: "Opening Balance".to_string(),
+                narration: None,
+                currency: account.currency,
+                postings: [
+                    (account_name(account), account.opening_balance),
+                    (OPENING_BALANCES.to_string(), -account.opening_balance),
+                ],
+            });
+        }
+
+        for transaction in transactions
+            .iter()
+            .filter(|transaction| !transaction.is_draft)
+        {
+            let account = transaction
+                .account_id
+                .and_then(|id| accounts.iter().find(|account| account.id == id));
+            let category = transaction
+                .category_id
+                .and_then(|id| categories.iter().find(|category| category.id == id));
+
+            let other = match category {
+                Some(category) => category_name(category),
+                None if transaction.amount > 0 => "Income:Uncategorised".to_string(),
+                None => "Expenses:Uncategorised".to_string(),
+            };
+
+            entries.push(Entry {
+                date: transaction.transaction_date,
+                cleared_status: transaction.cleared_status,
+                payee: transaction.payee.clone(),
+                narration: transaction.description.clone(),
+                currency: account.map(|account| account.currency).unwrap_or_default(),
+                postings: [
+                    (
+                        account
+                            .map(account_name)
+                            .unwrap_or_else(|| UNASSIGNED.to_string()),
+                        transaction.amount,
+                    ),
+                    (other, -transaction.amount),
+                ],
+            });
+        }
+
+        entries.sort_by_key(|entry| entry.date);
+
+        Ok(self.journal(&entries))
+    }
+
+    /// Writes the declarations of every account posted to, then the entries.
+    fn journal(&self, entries: &[Entry]) -> String {
+        let mut opened: Vec<&str> = entries
+            .iter()
+            .flat_map(|entry| entry.postings.iter().map(|(account, _)| account.as_str()))
+            .collect();
+        opened.sort();
+        opened.dedup();
+
+        let mut journal = match self.format {
+            PlainTextFormats::Beancount => format!(
+                "option \"title\" \"Personal Ledger\"\noption \"operating_currency\" \"{}\"\n",
+                domain::Currency::default().code()
+            ),
+            PlainTextFormats::Hledger => "; Personal Ledger\n".to_string(),
+        };
+
+        let first = entries.first().map(|entry| entry.date);
+        if !opened.is_empty() {
+            journal.push('\n');
+        }
+        for account in &opened {
+            match (self.format, first) {
+                (PlainTextFormats::Beancount, Some(first)) => {
+                    journal.push_str(&format!("{first} open {account}\n"))
+                }
+                _ => journal.push_str(&format!("account {account}\n")),
+            }
+        }
+
+        for entry in entries {
+            journal.push('\n');
+            journal.push_str(&self.header(entry));
+            for (account, amount) in &entry.postings {
+                journal.push_str(&format!(
+                    "  {account}  {} {}\n",
+                    decimal(*amount, entry.currency),
+                    entry.currency.code()
+                ));
+            }
+        }
+
+        journal
+    }
+
+    /// The first line of an entry, with its date, status, payee and narration.
+    fn header(&self, entry: &Entry) -> String {
+        match self.format {
+            PlainTextFormats::Beancount => {
+                let narration = entry.narration.as_deref().unwrap_or_default();
+                format!(
+                    "{} * \"{}\" \"{}\"\n",
+                    entry.date,
+                    quote(&entry.payee),
+                    quote(narration)
+                )
+            }
+            PlainTextFormats::Hledger => {
+                let status = match entry.cleared_status {
+                    database::ClearedStatus::Uncleared => "",
+                    database::ClearedStatus::Cleared => " !",
+                    database::ClearedStatus::Reconciled => " *",
+                };
+                let payee = entry.payee.replace('|', "/");
+                match &entry.narration {
+                    Some(narration) => format!(
+                        "{}{status} {payee} | {}\n",
+                        entry.date,
+                        narration.replace('\n', " ")
+                    ),
+                    None => format!("{}{status} {payee}\n", entry.date),
+                }
+            }
+        }
+    }
+}
+
+/// The journal account of a category, under the root account of its type,
+/// with a component for each part of its code, e.g. `Expenses:FOD:GRO`.
+fn category_name(category: &database::Categories) -> String {
+    let root = match category.category_type {
+        domain::CategoryTypes::Asset => "Assets",
+        domain::CategoryTypes::Equity => "Equity",
+        domain::CategoryTypes::Expense => "Expenses",
+        domain::CategoryTypes::Income => "Income",
+        domain::CategoryTypes::Liability => "Liabilities",
+    };
+
+    std::iter::once(root.to_string())
+        .chain(category.code.split('.').map(component))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// The journal account of a ledger account, a liability if it opened owing
+/// money and an asset otherwise.
+fn account_name(account: &database::Accounts) -> String {
+    let root = if account.opening_balance < 0 {
+        "Liabilities"
+    } else {
+        "Assets"
+    };
+    format!("{root}:{}", component(&account.name))
+}
+
+/// `name` as an account name component, which must start with a capital
+/// letter or digit and hold only letters, digits and hyphens.
+fn component(name: &str) -> String {
+    let words: Vec<String> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    match words.join("-") {
+        joined if joined.is_empty() => "X".to_string(),
+        joined => joined,
+    }
+}
+
+/// `amount` minor units as a decimal number of major units, e.g. `-42.00`.
+fn decimal(amount: i64, currency: domain::Currency) -> String {
+    let sign = if amount < 0 { "-" } else { "" };
+    let per_major = currency.minor_per_major().unsigned_abs();
+    let minor = amount.unsigned_abs();
+
+    match currency.decimal_places() as usize {
+        0 => format!("{sign}{minor}"),
+        places => format!("{sign}{}.{:0places$}", minor / per_major, minor % per_major),
+    }
+}
+
+/// Escapes `text` for a Beancount string.
+fn quote(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// A ledger with a categorised groceries transaction from an account.
+    async fn ledger(pool: &sqlx::SqlitePool) -> Result<()> {
+        let groceries = database::CategoriesBuilder::new()
+            .with_code("FOD.GRO")
+            .with_name("Groceries")
+            .with_category_type(domain::CategoryTypes::Expense)
+            .build()?
+            .insert(pool)
+            .await?;
+        let everyday = database::Accounts::new("Everyday account")
+            .insert(pool)
+            .await?;
+
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        let mut transaction = database::Transactions::new(date, "Fresh \"Market\"", -4_205);
+        transaction.description = Some("Weekly shop".to_string());
+        transaction.category_id = Some(groceries.id);
+        transaction.account_id = Some(everyday.id);
+        transaction.insert(pool).await?;
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn beancount_opens_accounts_named_from_category_codes(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        ledger(&pool).await?;
+
+        let journal = PlainTextExport::new(PlainTextFormats::Beancount)
+            .write(&pool)
+            .await?;
+
+        assert!(journal.contains("2025-03-14 open Expenses:FOD:GRO\n"));
+        assert!(journal.contains("2025-03-14 open Assets:Everyday-Account\n"));
+        assert!(journal.contains(
+            "2025-03-14 * \"Fresh \\\"Market\\\"\" \"Weekly shop\"\n  Assets:Everyday-Account  -42.05 AUD\n  Expenses:FOD:GRO  42.05 AUD\n"
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn hledger_declares_accounts_and_marks_status(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        ledger(&pool).await?;
+        database::Transactions::new(
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 20).unwrap(),
+            "Refund",
+            1_000,
+        )
+        .insert(&pool)
+        .await?;
+
+        let journal = PlainTextExport::new(PlainTextFormats::Hledger)
+            .write(&pool)
+            .await?;
+
+        assert!(journal.contains("account Expenses:FOD:GRO\n"));
+        assert!(journal.contains("2025-03-14 Fresh \"Market\" | Weekly shop\n"));
+        assert!(journal.contains("2025-03-20 Refund\n  Assets:Unassigned  10.00 AUD\n  Income:Uncategorised  -10.00 AUD\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn components_are_valid_account_names() {
+        assert_eq!(component("Everyday account"), "Everyday-Account");
+        assert_eq!(component("visa (joint)"), "Visa-Joint");
+        assert_eq!(component("GRO"), "GRO");
+        assert_eq!(component("--"), "X");
+    }
+
+    #[test]
+    fn decimal_writes_minor_units_as_major() {
+        assert_eq!(decimal(-4_205, domain::Currency::AUD), "-42.05");
+        assert_eq!(decimal(7, domain::Currency::AUD), "0.07");
+        assert_eq!(decimal(1_500, domain::Currency::JPY), "1500");
+    }
+}
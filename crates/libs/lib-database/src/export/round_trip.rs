@@ -0,0 +1,364 @@
+use futures_util::TryStreamExt;
+
+use crate::query_metrics::Observe;
+use crate::{
+    self as database, DatabaseError, DatabaseResult, ExportEntities, ExportFormats, LedgerExport,
+};
+use lib_domain as domain;
+
+/// The figures a [`RoundTrip`] compares, see the
+/// [module documentation](super).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum RoundTripChecks {
+    /// The number of categories, deleted or not.
+    CategoryCount,
+    /// The number of accounts.
+    AccountCount,
+    /// The number of transactions, drafts included.
+    TransactionCount,
+    /// The sum of every transaction amount, in minor units.
+    TransactionTotal,
+    /// The balance of one account, in minor units.
+    AccountBalance,
+}
+
+/// One figure, worked out from the ledger and from the copy imported from
+/// its export.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct RoundTripCheck {
+    /// What was compared.
+    pub check: RoundTripChecks,
+
+    /// The account an [`AccountBalance`](RoundTripChecks::AccountBalance)
+    /// check is for, `None` for checks of the whole ledger.
+    pub record_id: Option<domain::RowID>,
+
+    /// The figure in the ledger.
+    pub exported: i64,
+
+    /// The figure in the copy imported from the export.
+    pub imported: i64,
+}
+
+impl RoundTripCheck {
+    fn new(
+        check: RoundTripChecks,
+        record_id: Option<domain::RowID>,
+        exported: i64,
+        imported: i64,
+    ) -> Self {
+        Self {
+            check,
+            record_id,
+            exported,
+            imported,
+        }
+    }
+
+    /// Returns `true` when the copy has the same figure as the ledger.
+    pub fn matches(&self) -> bool {
+        self.exported == self.imported
+    }
+}
+
+/// The result of exporting the whole ledger and importing it into an empty
+/// database.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct RoundTrip {
+    /// Every figure compared, the counts and totals first, then the balance
+    /// of each account.
+    pub checks: Vec<RoundTripCheck>,
+
+    /// When the round trip ran (UTC).
+    pub checked_on: chrono::DateTime<chrono::Utc>,
+}
+
+/// The figures of the whole ledger, in the order they are reported.
+const LEDGER_FIGURES: [(RoundTripChecks, &str); 4] = [
+    (
+        RoundTripChecks::CategoryCount,
+        "SELECT COUNT(*) FROM categories",
+    ),
+    (
+        RoundTripChecks::AccountCount,
+        "SELECT COUNT(*) FROM accounts",
+    ),
+    (
+        RoundTripChecks::TransactionCount,
+        "SELECT COUNT(*) FROM transactions",
+    ),
+    (
+        RoundTripChecks::TransactionTotal,
+        "SELECT COALESCE(SUM(amount), 0) FROM transactions",
+    ),
+];
+
+impl RoundTrip {
+    /// Exports every category, account and transaction as JSON Lines, reads
+    /// the lines back into an empty in-memory database and compares the two.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The pool of the ledger to check
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError::Sqlx` if either database cannot be read or
+    /// written, or a `DatabaseError::Other` if an exported line cannot be
+    /// read back. A copy that differs is not an error, it is reported in the
+    /// checks.
+    #[tracing::instrument(name = "Round trip ledger export", skip(pool), err)]
+    pub async fn run(pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<Self> {
+        let export = LedgerExport::new(ExportFormats::JsonLines);
+        let categories: Vec<database::Categories> =
+            read_back(&export, ExportEntities::Categories, pool).await?;
+        let accounts: Vec<database::Accounts> =
+            read_back(&export, ExportEntities::Accounts, pool).await?;
+        let transactions: Vec<database::Transactions> =
+            read_back(&export, ExportEntities::Transactions, pool).await?;
+
+        // Each connection to an in-memory database opens a new one, so the
+        // copy is kept on a single connection that is never closed early
+        let copy = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect("sqlite::memory:")
+            .await?;
+        database::migrations::MIGRATOR.run(&copy).await?;
+        import(&copy, &categories, &accounts, &transactions).await?;
+
+        let mut checks = Vec::new();
+        for (check, query) in LEDGER_FIGURES {
+            let exported: i64 = sqlx::query_scalar(query)
+                .fetch_one(pool)
+                .observe_one("export.round_trip")
+                .await?;
+            let imported: i64 = sqlx::query_scalar(query)
+                .fetch_one(&copy)
+                .observe_one("export.round_trip")
+                .await?;
+            checks.push(RoundTripCheck::new(check, None, exported, imported));
+        }
+
+        for account in &accounts {
+            let exported = database::Accounts::balance(account.id, None, pool).await?;
+            let imported = match database::Accounts::balance(account.id, None, &copy).await {
+                Err(DatabaseError::NotFound(_)) => 0,
+                balance => balance?,
+            };
+            checks.push(RoundTripCheck::new(
+                RoundTripChecks::AccountBalance,
+                Some(account.id),
+                exported,
+                imported,
+            ));
+        }
+
+        copy.close().await;
+
+        let differences = checks.iter().filter(|check| !check.matches()).count();
+        tracing::info!("Round tripped the ledger export with {differences} differences");
+
+        Ok(Self {
+            checks,
+            checked_on: chrono::Utc::now(),
+        })
+    }
+
+    /// Returns `true` when every figure in the copy matches the ledger.
+    pub fn is_intact(&self) -> bool {
+        self.checks.iter().all(RoundTripCheck::matches)
+    }
+}
+
+/// Exports the records of `entity` and reads each line back into a row.
+async fn read_back<T: serde::de::DeserializeOwned>(
+    export: &LedgerExport,
+    entity: ExportEntities,
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+) -> DatabaseResult<Vec<T>> {
+    export
+        .stream(entity, pool)
+        .and_then(|line| {
+            std::future::ready(serde_json::from_str(&line).map_err(|error| {
+                DatabaseError::Other(format!("Cannot read back {entity:?} export: {error}"))
+            }))
+        })
+        .try_collect()
+        .await
+}
+
+/// Inserts the rows into the copy as exported, in one transaction.
+///
+/// Rows are written directly rather than through their `insert` methods,
+/// which would refuse a reconciled transaction or a child category read
+/// before its parent, and owners are cleared as users are not exported.
+async fn import(
+    copy: &sqlx::Pool<sqlx::Sqlite>,
+    categories: &[database::Categories],
+    accounts: &[database::Accounts],
+    transactions: &[database::Transactions],
+) -> DatabaseResult<()> {
+    let mut tx = copy.begin().await?;
+
+    // Parents are checked at commit, once every category is in
+    sqlx::query("PRAGMA defer_foreign_keys = ON")
+        .execute(&mut *tx)
+        .await?;
+
+    for category in categories {
+        sqlx::query(
+            r#"
+                INSERT INTO categories (
+                    id, code, name, description, url_slug, category_type, color, icon,
+                    is_active, parent_id, created_on, updated_on, deleted_on, version
+                )
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(category.id)
+        .bind(&category.code)
+        .bind(&category.name)
+        .bind(&category.description)
+        .bind(&category.url_slug)
+        .bind(&category.category_type)
+        .bind(&category.color)
+        .bind(&category.icon)
+        .bind(category.is_active)
+        .bind(category.parent_id)
+        .bind(category.created_on)
+        .bind(category.updated_on)
+        .bind(category.deleted_on)
+        .bind(category.version)
+        .execute(&mut *tx)
+        .observe("export.round_trip")
+        .await?;
+    }
+
+    for account in accounts {
+        sqlx::query(
+            r#"
+                INSERT INTO accounts (
+                    id, name, description, opening_balance, currency, adjustments_category_id,
+                    is_active, created_on, updated_on
+                )
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(account.id)
+        .bind(&account.name)
+        .bind(&account.description)
+        .bind(account.opening_balance)
+        .bind(account.currency)
+        .bind(account.adjustments_category_id)
+        .bind(account.is_active)
+        .bind(account.created_on)
+        .bind(account.updated_on)
+        .execute(&mut *tx)
+        .observe("export.round_trip")
+        .await?;
+    }
+
+    for transaction in transactions {
+        sqlx::query(
+            r#"
+                INSERT INTO transactions (
+                    id, transaction_date, payee, description, amount, category_id,
+                    account_id, is_draft, latitude, longitude, place_name, return_by,
+                    warranty_expires, cleared_status, created_on, updated_on, version
+                )
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(transaction.id)
+        .bind(transaction.transaction_date)
+        .bind(&transaction.payee)
+        .bind(&transaction.description)
+        .bind(transaction.amount)
+        .bind(transaction.category_id)
+        .bind(transaction.account_id)
+        .bind(transaction.is_draft)
+        .bind(transaction.latitude)
+        .bind(transaction.longitude)
+        .bind(&transaction.place_name)
+        .bind(transaction.return_by)
+        .bind(transaction.warranty_expires)
+        .bind(transaction.cleared_status)
+        .bind(transaction.created_on)
+        .bind(transaction.updated_on)
+        .bind(transaction.version)
+        .execute(&mut *tx)
+        .observe("export.round_trip")
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test]
+    async fn round_trip_matches_the_ledger(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let parent = database::Categories::mock().insert(&pool).await?;
+        let mut child = database::Categories::mock();
+        child.parent_id = Some(parent.id);
+        child.category_type = parent.category_type;
+        child.insert(&pool).await?;
+        let deleted = database::Categories::mock().insert(&pool).await?;
+        database::Categories::delete_soft(deleted.id, database::UserScope::All, &pool).await?;
+
+        let mut account = database::Accounts::new("Everyday");
+        account.opening_balance = 10_000;
+        let account = account.insert(&pool).await?;
+        for amount in [-4_205, 1_500] {
+            let mut transaction = database::Transactions::new(
+                chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap(),
+                "Fresh Market",
+                amount,
+            );
+            transaction.account_id = Some(account.id);
+            transaction.category_id = Some(child.id);
+            transaction.insert(&pool).await?;
+        }
+
+        let round_trip = RoundTrip::run(&pool).await?;
+
+        assert!(round_trip.is_intact(), "{:?}", round_trip.checks);
+        assert_eq!(round_trip.checks.len(), LEDGER_FIGURES.len() + 1);
+        assert_eq!(round_trip.checks[0].imported, 3);
+        assert_eq!(
+            round_trip.checks[4],
+            RoundTripCheck::new(
+                RoundTripChecks::AccountBalance,
+                Some(account.id),
+                7_295,
+                7_295
+            )
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn round_trip_of_an_empty_ledger_is_intact(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let round_trip = RoundTrip::run(&pool).await?;
+
+        assert!(round_trip.is_intact());
+        assert!(round_trip.checks.iter().all(|check| check.exported == 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn a_check_matches_when_the_figures_are_equal() {
+        assert!(RoundTripCheck::new(RoundTripChecks::AccountCount, None, 2, 2).matches());
+        assert!(!RoundTripCheck::new(RoundTripChecks::TransactionTotal, None, -100, 0).matches());
+    }
+}
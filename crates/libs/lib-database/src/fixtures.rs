@@ -0,0 +1,471 @@
+//! # Fixtures Module
+//!
+//! Dumps selected rows to a small JSON file, and loads them back into
+//! another database, so integration tests and bug reproductions can share
+//! an exact database state as text.
+//!
+//! A [`FixtureSelection`] names the tables to dump, either every row or only
+//! the rows with the given IDs. The [`Fixture`] lists the tables parents
+//! first, so a table comes after the tables its foreign keys reference, and
+//! each row is an object of its columns as stored. BLOB columns are written
+//! as `{"blob": "<hex>"}`.
+//!
+//! [`Fixture::load`] inserts every row in one transaction, with foreign keys
+//! checked at commit, so a fixture missing a referenced row is not loaded at
+//! all. The rows keep their IDs, so the database they are loaded into must
+//! not have them already. Loading goes through the triggers like any insert,
+//! so the rows are written to the audit log and the history tables.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_database::{Fixture, FixtureSelection};
+//!
+//! # async fn example(pool: &sqlx::SqlitePool, test_pool: &sqlx::SqlitePool, transaction_id: lib_domain::RowID) -> Result<(), Box<dyn std::error::Error>> {
+//! let fixture = FixtureSelection::new()
+//!     .with_table("categories")
+//!     .with_rows("transactions", [transaction_id])
+//!     .dump(pool)
+//!     .await?;
+//! std::fs::write("tests/fixtures/bug-123.json", fixture.to_json()?)?;
+//!
+//! let fixture = Fixture::from_json(&std::fs::read_to_string("tests/fixtures/bug-123.json")?)?;
+//! fixture.load(test_pool).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+
+use sqlx::{Column, Row, TypeInfo, ValueRef};
+
+use crate::query_metrics::Observe;
+use crate::{DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Key of the object a BLOB value is written as.
+const BLOB_KEY: &str = "blob";
+
+/// The rows dumped from one table.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct FixtureTable {
+    /// The table's name.
+    pub name: String,
+
+    /// Each row as an object of its columns.
+    pub rows: Vec<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Rows from a set of tables, parents first, see the
+/// [module documentation](self).
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Fixture {
+    pub tables: Vec<FixtureTable>,
+}
+
+/// The tables and rows to dump into a [`Fixture`].
+#[derive(Debug, Clone, Default)]
+pub struct FixtureSelection {
+    /// Tables by name, with the IDs of the rows to dump or `None` for all.
+    tables: BTreeMap<String, Option<Vec<domain::RowID>>>,
+}
+
+/// Quotes a table or column name for use in a statement.
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+impl FixtureSelection {
+    /// An empty selection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Dumps every row of `table`.
+    pub fn with_table(mut self, table: impl Into<String>) -> Self {
+        self.tables.insert(table.into(), None);
+        self
+    }
+
+    /// Dumps the rows of `table` with these IDs, as well as any already
+    /// selected from it.
+    pub fn with_rows(
+        mut self,
+        table: impl Into<String>,
+        ids: impl IntoIterator<Item = domain::RowID>,
+    ) -> Self {
+        let selected = self
+            .tables
+            .entry(table.into())
+            .or_insert_with(|| Some(Vec::new()));
+        if let Some(selected) = selected {
+            selected.extend(ids);
+        }
+        self
+    }
+
+    /// Reads the selected rows into a fixture, tables parents first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if a selected table does not exist,
+    /// or a `DatabaseError::Sqlx` if reading the rows fails.
+    #[tracing::instrument(name = "Dump database fixture", skip(self, pool), fields(tables = self.tables.len()), err)]
+    pub async fn dump(&self, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<Fixture> {
+        let mut conn = pool.acquire().await?;
+
+        let existing: Vec<String> = sqlx::query_scalar(
+            r#"
+                SELECT name
+                FROM pragma_table_list
+                WHERE schema = 'main'
+                    AND type = 'table'
+                    AND name NOT LIKE 'sqlite_%'
+                    AND name != '_sqlx_migrations'
+            "#,
+        )
+        .fetch_all(&mut *conn)
+        .observe("fixtures.dump")
+        .await?;
+
+        if let Some(unknown) = self.tables.keys().find(|table| !existing.contains(table)) {
+            return Err(DatabaseError::Validation(format!(
+                "Table {unknown} does not exist"
+            )));
+        }
+
+        let mut tables = Vec::new();
+        for name in parents_first(self.tables.keys(), &mut conn).await? {
+            let mut query =
+                sqlx::QueryBuilder::<sqlx::Sqlite>::new(format!("SELECT * FROM {}", quote(&name)));
+            if let Some(ids) = &self.tables[&name] {
+                query.push(" WHERE id IN (");
+                let mut separated = query.separated(", ");
+                for id in ids {
+                    separated.push_bind(*id);
+                }
+                // `IN ()` is valid SQLite and matches nothing
+                separated.push_unseparated(")");
+            }
+            query.push(" ORDER BY rowid");
+
+            let rows = query
+                .build()
+                .fetch_all(&mut *conn)
+                .observe("fixtures.dump")
+                .await?
+                .iter()
+                .map(row_to_json)
+                .collect::<DatabaseResult<_>>()?;
+
+            tables.push(FixtureTable { name, rows });
+        }
+
+        Ok(Fixture { tables })
+    }
+}
+
+/// `tables` ordered so each comes after the tables its foreign keys
+/// reference, among those given. References to itself are left to the
+/// deferred foreign key check on load.
+async fn parents_first<'a>(
+    tables: impl Iterator<Item = &'a String>,
+    conn: &mut sqlx::SqliteConnection,
+) -> DatabaseResult<Vec<String>> {
+    let mut parents = BTreeMap::new();
+    for table in tables {
+        let references: Vec<String> =
+            sqlx::query_scalar(r#"SELECT DISTINCT "table" FROM pragma_foreign_key_list(?)"#)
+                .bind(table)
+                .fetch_all(&mut *conn)
+                .observe("fixtures.dump")
+                .await?;
+        parents.insert(table.clone(), references);
+    }
+
+    let mut ordered: Vec<String> = Vec::with_capacity(parents.len());
+    while ordered.len() < parents.len() {
+        let ready = parents
+            .iter()
+            .filter(|(table, _)| !ordered.contains(table))
+            .find(|(table, references)| {
+                references.iter().all(|parent| {
+                    parent == *table || ordered.contains(parent) || !parents.contains_key(parent)
+                })
+            })
+            .map(|(table, _)| table.clone());
+
+        match ready {
+            Some(table) => ordered.push(table),
+            // A reference cycle, the remaining tables are left in name order
+            None => ordered.extend(
+                parents
+                    .keys()
+                    .filter(|table| !ordered.contains(table))
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            ),
+        }
+    }
+
+    Ok(ordered)
+}
+
+/// A row as an object of its columns.
+fn row_to_json(
+    row: &sqlx::sqlite::SqliteRow,
+) -> DatabaseResult<serde_json::Map<String, serde_json::Value>> {
+    let mut object = serde_json::Map::new();
+
+    for column in row.columns() {
+        let index = column.ordinal();
+        let raw = row.try_get_raw(index)?;
+
+        let value = if raw.is_null() {
+            serde_json::Value::Null
+        } else {
+            match raw.type_info().name() {
+                "INTEGER" => row.try_get::<i64, _>(index)?.into(),
+                "REAL" => row.try_get::<f64, _>(index)?.into(),
+                "BLOB" => {
+                    let bytes: Vec<u8> = row.try_get(index)?;
+                    let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+                    serde_json::json!({ BLOB_KEY: hex })
+                }
+                _ => row.try_get::<String, _>(index)?.into(),
+            }
+        };
+
+        object.insert(column.name().to_string(), value);
+    }
+
+    Ok(object)
+}
+
+/// The bytes of a BLOB written by [`row_to_json`].
+fn blob_from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+impl Fixture {
+    /// Reads a fixture written by [`to_json`](Self::to_json).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if `json` is not a fixture.
+    pub fn from_json(json: &str) -> DatabaseResult<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| DatabaseError::Validation(format!("Fixture is not valid: {e}")))
+    }
+
+    /// The fixture as pretty printed JSON, one column per line so changes to
+    /// a fixture read well in a diff.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Other` if the fixture cannot be serialised.
+    pub fn to_json(&self) -> DatabaseResult<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| DatabaseError::Other(format!("Fixture cannot be written: {e}")))
+    }
+
+    /// Inserts every row of the fixture, in one transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if a value cannot be stored, such
+    /// as a nested array, or a `DatabaseError::Sqlx` if a row is rejected by
+    /// the database, including a row that already exists or a reference to a
+    /// row that is not in the fixture or the database. Nothing is inserted
+    /// when any row fails.
+    #[tracing::instrument(name = "Load database fixture", skip(self, pool), fields(tables = self.tables.len()), err)]
+    pub async fn load(&self, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<()> {
+        let mut tx = pool.begin().await?;
+
+        // Rows referencing others in the same table may come before them
+        sqlx::query("PRAGMA defer_foreign_keys = ON")
+            .execute(&mut *tx)
+            .observe("fixtures.load")
+            .await?;
+
+        for table in &self.tables {
+            for row in &table.rows {
+                let columns: Vec<String> = row.keys().map(|column| quote(column)).collect();
+                let mut query = sqlx::QueryBuilder::<sqlx::Sqlite>::new(format!(
+                    "INSERT INTO {} ({}) VALUES (",
+                    quote(&table.name),
+                    columns.join(", ")
+                ));
+
+                let mut separated = query.separated(", ");
+                for (column, value) in row {
+                    match value {
+                        serde_json::Value::Null => separated.push_bind(None::<String>),
+                        serde_json::Value::Bool(value) => separated.push_bind(*value),
+                        serde_json::Value::Number(number) => match number.as_i64() {
+                            Some(integer) => separated.push_bind(integer),
+                            None => separated.push_bind(number.as_f64()),
+                        },
+                        serde_json::Value::String(text) => separated.push_bind(text.clone()),
+                        serde_json::Value::Object(object) => {
+                            let bytes = object
+                                .get(BLOB_KEY)
+                                .and_then(serde_json::Value::as_str)
+                                .and_then(blob_from_hex)
+                                .ok_or_else(|| {
+                                    DatabaseError::Validation(format!(
+                                        "Column {}.{column} is an object that is not a hex blob",
+                                        table.name
+                                    ))
+                                })?;
+                            separated.push_bind(bytes)
+                        }
+                        serde_json::Value::Array(_) => {
+                            return Err(DatabaseError::Validation(format!(
+                                "Column {}.{column} is an array, which cannot be stored",
+                                table.name
+                            )));
+                        }
+                    };
+                }
+                separated.push_unseparated(")");
+
+                query
+                    .build()
+                    .execute(&mut *tx)
+                    .observe("fixtures.load")
+                    .await?;
+            }
+        }
+
+        tx.commit().await?;
+
+        tracing::info!(
+            "Loaded {} rows from fixture",
+            self.tables
+                .iter()
+                .map(|table| table.rows.len())
+                .sum::<usize>()
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as database;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// A migrated in-memory database.
+    async fn database() -> Result<database::DatabasePool> {
+        let db = database::DatabasePool::new("sqlite::memory:")
+            .connect()
+            .await?;
+        db.migrate().await?;
+        Ok(db)
+    }
+
+    #[sqlx::test]
+    async fn dump_and_load_round_trip(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let parent = database::Categories::mock();
+        parent.insert(&pool).await?;
+        let mut child = database::Categories::mock();
+        child.parent_id = Some(parent.id);
+        child.insert(&pool).await?;
+        let mut transaction = database::Transactions::mock();
+        transaction.category_id = Some(child.id);
+        let transaction = transaction.insert(&pool).await?;
+        database::Transactions::mock().insert(&pool).await?;
+
+        let fixture = FixtureSelection::new()
+            .with_rows("transactions", [transaction.id])
+            .with_table("categories")
+            .dump(&pool)
+            .await?;
+
+        let names: Vec<_> = fixture
+            .tables
+            .iter()
+            .map(|table| table.name.as_str())
+            .collect();
+        assert_eq!(names, ["categories", "transactions"]);
+        assert_eq!(fixture.tables[1].rows.len(), 1);
+
+        let fixture = Fixture::from_json(&fixture.to_json()?)?;
+        let other = database().await?;
+        let other = other.get_pool()?;
+        fixture.load(other).await?;
+
+        let loaded =
+            database::Transactions::find_by_id(transaction.id, database::UserScope::All, other)
+                .await?;
+        assert_eq!(loaded, Some(transaction));
+        let child =
+            database::Categories::find_by_id(child.id, database::UserScope::All, other).await?;
+        assert_eq!(child.and_then(|child| child.parent_id), Some(parent.id));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn load_is_all_or_nothing(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut transaction = database::Transactions::mock();
+        transaction.category_id = None;
+        let transaction = transaction.insert(&pool).await?;
+        let mut fixture = FixtureSelection::new()
+            .with_table("transactions")
+            .dump(&pool)
+            .await?;
+
+        // The transaction is already in the database
+        let duplicate = fixture.load(&pool).await;
+        assert!(matches!(duplicate, Err(DatabaseError::Sqlx(_))));
+
+        // A reference to a category in neither the fixture nor the database
+        database::Transactions::delete_by_id(transaction.id, database::UserScope::All, &pool)
+            .await?;
+        fixture.tables[0].rows[0].insert(
+            "category_id".to_string(),
+            domain::RowID::new().to_string().into(),
+        );
+        let dangling = fixture.load(&pool).await;
+        assert!(dangling.is_err());
+        assert_eq!(
+            database::Transactions::find_by_id(transaction.id, database::UserScope::All, &pool)
+                .await?,
+            None
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn dump_rejects_unknown_tables(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let unknown = FixtureSelection::new()
+            .with_table("widgets")
+            .dump(&pool)
+            .await;
+
+        assert!(matches!(unknown, Err(DatabaseError::Validation(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn blobs_round_trip_through_hex() {
+        assert_eq!(blob_from_hex("00ff10"), Some(vec![0x00, 0xff, 0x10]));
+        assert_eq!(blob_from_hex("0f0"), None);
+        assert_eq!(blob_from_hex("zz"), None);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Goals {
+    /// Deletes a goal from the database by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the goal to delete
+    /// * `scope` - The user whose goals are deleted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The goal with the given ID does not exist or is outside `scope`
+    ///   (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Delete goal from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let user_id = scope.user_id();
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    DELETE FROM goals
+                    WHERE id = ?1
+                        AND (?2 IS NULL OR account_id IN (SELECT id FROM accounts WHERE user_id = ?2)
+                            OR category_id IN (SELECT id FROM categories WHERE user_id = ?2))
+                "#,
+                id,
+                user_id
+            )
+            .execute(&mut *conn)
+            .observe("goals.delete_by_id")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Goal with id {} not found",
+                    id
+                )));
+            }
+
+            tracing::info!("Deleted goal {} from database", id);
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goals::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn delete_by_id_removes_goal(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let goal = database::Goals::mock(account.id).insert(&pool).await?;
+
+        database::Goals::delete_by_id(goal.id, database::UserScope::All, &pool).await?;
+
+        assert!(
+            database::Goals::find_by_id(goal.id, database::UserScope::All, &pool)
+                .await?
+                .is_none()
+        );
+        assert!(matches!(
+            database::Goals::delete_by_id(goal.id, database::UserScope::All, &pool).await,
+            Err(database::DatabaseError::NotFound(_))
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn deleting_category_deletes_goal(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        let target = database::Categories::mock().insert(&pool).await?;
+        let goal = database::Goals::for_category("Holiday", 300_000, category.id)
+            .insert(&pool)
+            .await?;
+        let scope = database::UserScope::All;
+
+        // Only when reassigning, not as a side effect of a plain delete
+        let refused = database::Categories::delete_by_id(category.id, scope, &pool).await;
+        assert!(matches!(refused, Err(database::DatabaseError::InUse(_))));
+        database::Categories::delete_with_reassign(category.id, target.id, scope, &pool).await?;
+
+        assert!(
+            database::Goals::find_by_id(goal.id, database::UserScope::All, &pool)
+                .await?
+                .is_none()
+        );
+
+        Ok(())
+    }
+}
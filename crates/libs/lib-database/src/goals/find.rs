@@ -0,0 +1,173 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Goals {
+    /// Finds a goal by its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the goal to find
+    /// * `scope` - The user whose goals are searched
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Goals)` if the goal exists in `scope`, or `None` if not
+    /// found.
+    #[tracing::instrument(
+        name = "Find goal by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let user_id = scope.user_id();
+            let mut conn = conn.acquire().await?;
+
+            let goal = sqlx::query_as!(
+                database::Goals,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        name                AS "name!: String",
+                        target_amount       AS "target_amount!: i64",
+                        target_date         AS "target_date?: chrono::NaiveDate",
+                        account_id          AS "account_id?: domain::RowID",
+                        category_id         AS "category_id?: domain::RowID",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM goals
+                    WHERE id = ?1
+                        AND (?2 IS NULL OR account_id IN (SELECT id FROM accounts WHERE user_id = ?2)
+                            OR category_id IN (SELECT id FROM categories WHERE user_id = ?2))
+                "#,
+                id,
+                user_id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("goals.find_by_id")
+            .await?;
+
+            Ok(goal)
+        }
+    }
+
+    /// Retrieves every goal in `scope`, those due soonest first and those
+    /// without a target date last, then by name.
+    ///
+    /// A goal belongs to the owner of the account or category it tracks.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The user whose goals are listed
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(name = "Find all goals", skip(conn), err)]
+    pub fn find_all<'c, A>(
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let user_id = scope.user_id();
+            let mut conn = conn.acquire().await?;
+
+            let goals = sqlx::query_as!(
+                database::Goals,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        name                AS "name!: String",
+                        target_amount       AS "target_amount!: i64",
+                        target_date         AS "target_date?: chrono::NaiveDate",
+                        account_id          AS "account_id?: domain::RowID",
+                        category_id         AS "category_id?: domain::RowID",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM goals
+                    WHERE ?1 IS NULL OR account_id IN (SELECT id FROM accounts WHERE user_id = ?1)
+                        OR category_id IN (SELECT id FROM categories WHERE user_id = ?1)
+                    ORDER BY target_date IS NULL, target_date, name, id
+                "#,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("goals.find_all")
+            .await?;
+
+            tracing::info!("Retrieved {} goals from database", goals.len());
+
+            Ok(goals)
+        }
+    }
+
+    /// Reads a goal back through the given connection.
+    pub(super) async fn fetch_by_id(
+        id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let goal = sqlx::query_as!(
+            database::Goals,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    name                AS "name!: String",
+                    target_amount       AS "target_amount!: i64",
+                    target_date         AS "target_date?: chrono::NaiveDate",
+                    account_id          AS "account_id?: domain::RowID",
+                    category_id         AS "category_id?: domain::RowID",
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM goals
+                WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("goals.fetch_by_id")
+        .await?;
+
+        Ok(goal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goals::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn find_all_orders_by_target_date(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let mut created = Vec::new();
+        for (name, target_date) in [
+            ("Car", None),
+            ("Holiday", Some((2026, 1, 15))),
+            ("Laptop", Some((2025, 11, 1))),
+        ] {
+            let mut goal = database::Goals::for_account(name, 100_000, account.id);
+            goal.target_date =
+                target_date.and_then(|(y, m, d)| chrono::NaiveDate::from_ymd_opt(y, m, d));
+            created.push(goal.insert(&pool).await?);
+        }
+
+        let goals = database::Goals::find_all(database::UserScope::All, &pool).await?;
+
+        assert_eq!(
+            goals,
+            [created[2].clone(), created[1].clone(), created[0].clone()]
+        );
+
+        Ok(())
+    }
+}
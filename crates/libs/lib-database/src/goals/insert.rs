@@ -0,0 +1,126 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::Goals {
+    /// Inserts a new goal into the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the inserted goal as read back from the database, or a
+    /// `DatabaseError` if the insertion fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The goal fails validation (`DatabaseError::Validation`)
+    /// - The account or category does not exist (foreign key violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Goals;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, savings_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut goal = Goals::for_account("Emergency fund", 1_000_000, savings_id);
+    /// goal.target_date = chrono::NaiveDate::from_ymd_opt(2026, 6, 30);
+    ///
+    /// let inserted = goal.insert(pool).await?;
+    /// assert_eq!(inserted.target_amount, 1_000_000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert new goal into database",
+        skip(self, conn),
+        fields(id = %self.id, target_amount = self.target_amount),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO goals (
+                        id, name, target_amount, target_date, account_id, category_id,
+                        created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.name,
+                self.target_amount,
+                self.target_date,
+                self.account_id,
+                self.category_id,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("goals.insert")
+            .await?;
+
+            let inserted = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Goal with id {} not found after insert",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("New goal inserted into the database.");
+
+            Ok(inserted)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use lib_domain as domain;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn insert_goal_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let mut goal = database::Goals::mock(account.id);
+        goal.target_date = chrono::NaiveDate::from_ymd_opt(2026, 6, 30);
+
+        let inserted = goal.insert(&pool).await?;
+
+        assert_eq!(inserted, goal);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_unknown_category_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let goal = database::Goals::for_category("Holiday", 300_000, domain::RowID::new());
+
+        let result = goal.insert(&pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+
+        Ok(())
+    }
+}
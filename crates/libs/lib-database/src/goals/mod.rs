@@ -0,0 +1,26 @@
+//! # Goals Database Module
+//!
+//! Savings goals, such as an emergency fund, tracked inside the ledger. A
+//! goal has a target amount, an optional date to reach it by, and is linked
+//! to either the account the money is saved in or the category the money
+//! set aside is classified under.
+//!
+//! Nothing extra is recorded as money is saved. Progress is worked out as of
+//! a date from the linked account's balance, or from the posted spending in
+//! the linked category and its subcategories, so moving $500 into savings
+//! under an "Emergency fund" category counts as $500 saved. With a target
+//! date, progress also gives the monthly amount still needed to reach the
+//! goal in time.
+
+mod delete;
+mod find;
+mod insert;
+mod model;
+mod progress;
+mod update;
+
+/// Database row model representing a savings goal.
+pub use model::Goals;
+
+/// How far a goal is towards its target on a date.
+pub use progress::GoalProgress;
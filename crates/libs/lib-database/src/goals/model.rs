@@ -0,0 +1,157 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// A savings goal, with its target in positive minor units (cents), linked
+/// to exactly one account or category that progress is measured from.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct Goals {
+    pub id: domain::RowID,
+    pub name: String,
+    pub target_amount: i64,
+
+    /// The date to reach the target by, `None` for a goal without a deadline.
+    pub target_date: Option<chrono::NaiveDate>,
+
+    /// The account the money is saved in, its balance being the amount saved.
+    pub account_id: Option<domain::RowID>,
+
+    /// The category money set aside is classified under, its spending being
+    /// the amount saved.
+    pub category_id: Option<domain::RowID>,
+
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::Goals {
+    /// Creates a new, unsaved goal measured by the balance of an account.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::Goals;
+    /// use lib_domain::RowID;
+    ///
+    /// let goal = Goals::for_account("Emergency fund", 1_000_000, RowID::new());
+    ///
+    /// assert!(goal.validate().is_ok());
+    /// ```
+    pub fn for_account(
+        name: impl Into<String>,
+        target_amount: i64,
+        account_id: domain::RowID,
+    ) -> Self {
+        let mut goal = Self::new(name, target_amount);
+        goal.account_id = Some(account_id);
+        goal
+    }
+
+    /// Creates a new, unsaved goal measured by the spending in a category.
+    pub fn for_category(
+        name: impl Into<String>,
+        target_amount: i64,
+        category_id: domain::RowID,
+    ) -> Self {
+        let mut goal = Self::new(name, target_amount);
+        goal.category_id = Some(category_id);
+        goal
+    }
+
+    /// Creates a new, unsaved goal that is not yet linked. Its account or
+    /// category must be set before it is saved.
+    pub fn new(name: impl Into<String>, target_amount: i64) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            name: name.into(),
+            target_amount,
+            target_date: None,
+            account_id: None,
+            category_id: None,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Checks the goal fields before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the name is blank, the target
+    /// amount is not positive, or the goal is not linked to exactly one
+    /// account or category.
+    pub fn validate(&self) -> DatabaseResult<()> {
+        if self.name.trim().is_empty() {
+            return Err(DatabaseError::Validation(format!(
+                "Goal {} name must not be empty",
+                self.id
+            )));
+        }
+
+        if self.target_amount <= 0 {
+            return Err(DatabaseError::Validation(format!(
+                "Goal {} target amount must be positive",
+                self.id
+            )));
+        }
+
+        if self.account_id.is_some() == self.category_id.is_some() {
+            return Err(DatabaseError::Validation(format!(
+                "Goal {} must be linked to either an account or a category",
+                self.id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Generates a mock goal for an account.
+    #[cfg(test)]
+    pub fn mock(account_id: domain::RowID) -> Self {
+        use fake::Fake;
+        use fake::faker::lorem::en::Words;
+
+        let words: Vec<String> = Words(1..3).fake();
+
+        Self::for_account(words.join(" "), (1..10_000_000).fake(), account_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_requires_one_link() {
+        let mut goal = database::Goals::for_account("Holiday", 300_000, domain::RowID::new());
+        assert!(goal.validate().is_ok());
+
+        goal.category_id = Some(domain::RowID::new());
+        assert!(matches!(goal.validate(), Err(DatabaseError::Validation(_))));
+
+        goal.account_id = None;
+        assert!(goal.validate().is_ok());
+
+        goal.category_id = None;
+        assert!(matches!(goal.validate(), Err(DatabaseError::Validation(_))));
+    }
+
+    #[test]
+    fn validate_rejects_blank_names_and_non_positive_targets() {
+        let blank = database::Goals::for_account(" ", 100, domain::RowID::new());
+        assert!(matches!(
+            blank.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+
+        for amount in [0, -100] {
+            let goal = database::Goals::for_account("Car", amount, domain::RowID::new());
+
+            assert!(
+                matches!(goal.validate(), Err(DatabaseError::Validation(_))),
+                "{amount}"
+            );
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+/// How far a goal is towards its target on a date.
+///
+/// Amounts are in minor units (cents). The amount saved is the balance of
+/// the goal's account or, for a goal linked to a category, the negated sum
+/// of the posted transactions in the category and its subcategories, so
+/// money moved into savings counts as saved and withdrawals reduce it.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct GoalProgress {
+    pub goal: database::Goals,
+
+    /// The date progress is measured on.
+    pub on: chrono::NaiveDate,
+
+    /// The amount saved on that date.
+    pub saved: i64,
+}
+
+impl GoalProgress {
+    /// Returns what is left to save, zero once the target is reached.
+    pub fn remaining(&self) -> i64 {
+        (self.goal.target_amount - self.saved).max(0)
+    }
+
+    /// Returns the amount saved as a percentage of the target, from zero,
+    /// and over 100 once more than the target is saved.
+    pub fn percent_complete(&self) -> f64 {
+        (self.saved.max(0) as f64 / self.goal.target_amount as f64) * 100.0
+    }
+
+    /// Returns `true` once the amount saved reaches the target.
+    pub fn is_reached(&self) -> bool {
+        self.saved >= self.goal.target_amount
+    }
+
+    /// Returns the amount to save each month, rounded up to the cent, to
+    /// reach the target by its date. A part month left counts as a whole
+    /// one, and a goal due or overdue needs everything left in one month.
+    ///
+    /// Returns `None` for a goal without a target date, or once it is
+    /// reached.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{GoalProgress, Goals};
+    /// use lib_domain::RowID;
+    ///
+    /// let date = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// let mut goal = Goals::for_account("Holiday", 300_000, RowID::new());
+    /// goal.target_date = Some(date(2025, 12, 1));
+    /// let progress = GoalProgress { goal, on: date(2025, 6, 1), saved: 60_000 };
+    ///
+    /// assert_eq!(progress.monthly_required(), Some(40_000));
+    /// ```
+    pub fn monthly_required(&self) -> Option<i64> {
+        let target_date = self.goal.target_date?;
+        if self.is_reached() {
+            return None;
+        }
+
+        let months = months_until(self.on, target_date).max(1);
+        let remaining = self.remaining();
+
+        Some((remaining + months - 1) / months)
+    }
+}
+
+/// The months from `from` until `to`, counting a part month as a whole one.
+/// Zero when `to` is not after `from`.
+fn months_until(from: chrono::NaiveDate, to: chrono::NaiveDate) -> i64 {
+    use chrono::Datelike;
+
+    if to <= from {
+        return 0;
+    }
+
+    let mut months =
+        i64::from(to.year() - from.year()) * 12 + i64::from(to.month()) - i64::from(from.month());
+    if to.day() > from.day() {
+        months += 1;
+    }
+
+    months
+}
+
+impl database::Goals {
+    /// Measures how far the goal is towards its target on a date.
+    ///
+    /// Only posted transactions on or before `on` are counted. See
+    /// [`GoalProgress`] for how the amount saved is worked out.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - The date to measure progress on
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The goal's account does not exist (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Goals, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let today = chrono::Utc::now().date_naive();
+    ///
+    /// for goal in Goals::find_all(UserScope::All, pool).await? {
+    ///     let progress = goal.progress(today, pool).await?;
+    ///     println!("{}: {:.0}% saved", progress.goal.name, progress.percent_complete());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Measure goal progress",
+        skip(self, conn),
+        fields(id = %self.id, on = %on),
+        err
+    )]
+    pub fn progress<'a, 'c, A>(
+        &'a self,
+        on: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<GoalProgress>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let saved = match (self.account_id, self.category_id) {
+                (Some(account_id), _) => {
+                    database::Accounts::balance(account_id, Some(on), &mut *conn).await?
+                }
+                (None, Some(category_id)) => {
+                    Self::saved_in_category(category_id, on, &mut conn).await?
+                }
+                (None, None) => 0,
+            };
+
+            Ok(GoalProgress {
+                goal: self.clone(),
+                on,
+                saved,
+            })
+        }
+    }
+
+    /// The negated sum of the posted transactions in a category and its
+    /// subcategories on or before a date.
+    async fn saved_in_category(
+        category_id: domain::RowID,
+        on: chrono::NaiveDate,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<i64> {
+        let saved = sqlx::query_scalar!(
+            r#"
+                WITH RECURSIVE tree (id) AS (
+                    SELECT ?1
+                    UNION
+                    SELECT c.id FROM categories c JOIN tree ON c.parent_id = tree.id
+                )
+                SELECT -COALESCE(SUM(t.amount), 0) AS "saved!: i64"
+                FROM transactions t JOIN tree ON tree.id = t.category_id
+                WHERE t.is_draft = 0 AND t.transaction_date <= ?2
+            "#,
+            category_id,
+            on
+        )
+        .fetch_one(&mut *conn)
+        .observe_one("goals.progress")
+        .await?;
+
+        Ok(saved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goals::insert::tests::Result;
+
+    fn date(year: i32, month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn progress(
+        target_date: Option<chrono::NaiveDate>,
+        on: chrono::NaiveDate,
+        saved: i64,
+    ) -> GoalProgress {
+        let mut goal =
+            database::Goals::for_account("Emergency fund", 100_000, domain::RowID::new());
+        goal.target_date = target_date;
+
+        GoalProgress { goal, on, saved }
+    }
+
+    #[test]
+    fn progress_reports_remaining_and_percent() {
+        let halfway = progress(None, date(2025, 3, 1), 50_000);
+        assert_eq!(
+            (halfway.remaining(), halfway.percent_complete()),
+            (50_000, 50.0)
+        );
+        assert!(!halfway.is_reached());
+        assert_eq!(halfway.monthly_required(), None);
+
+        let overdrawn = progress(None, date(2025, 3, 1), -5_000);
+        assert_eq!(
+            (overdrawn.remaining(), overdrawn.percent_complete()),
+            (105_000, 0.0)
+        );
+
+        let reached = progress(Some(date(2025, 6, 1)), date(2025, 3, 1), 120_000);
+        assert_eq!(reached.remaining(), 0);
+        assert!(reached.is_reached());
+        assert_eq!(reached.monthly_required(), None);
+    }
+
+    #[test]
+    fn monthly_required_rounds_part_months_and_cents_up() {
+        // 14 March to 1 June is two months and a part month
+        assert_eq!(
+            progress(Some(date(2025, 6, 1)), date(2025, 3, 14), 0).monthly_required(),
+            Some(33_334)
+        );
+        assert_eq!(
+            progress(Some(date(2025, 6, 15)), date(2025, 3, 14), 0).monthly_required(),
+            Some(25_000)
+        );
+        assert_eq!(
+            progress(Some(date(2025, 6, 14)), date(2025, 3, 14), 1).monthly_required(),
+            Some(33_333)
+        );
+        assert_eq!(
+            progress(Some(date(2025, 2, 1)), date(2025, 3, 14), 40_000).monthly_required(),
+            Some(60_000)
+        );
+    }
+
+    #[sqlx::test]
+    async fn progress_of_an_account_goal_is_its_balance(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut account = database::Accounts::new("Savings");
+        account.opening_balance = 20_000;
+        let account = account.insert(&pool).await?;
+        for (on, amount) in [(date(2025, 3, 1), 30_000), (date(2025, 4, 1), 30_000)] {
+            let mut transaction = database::Transactions::new(on, "Transfer", amount);
+            transaction.account_id = Some(account.id);
+            transaction.insert(&pool).await?;
+        }
+        let goal = database::Goals::for_account("Emergency fund", 100_000, account.id)
+            .insert(&pool)
+            .await?;
+
+        let progress = goal.progress(date(2025, 3, 31), &pool).await?;
+
+        assert_eq!(progress.saved, 50_000);
+        assert_eq!(progress.remaining(), 50_000);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn progress_of_a_category_goal_counts_subcategories(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let savings = database::Categories::mock().insert(&pool).await?;
+        let mut holiday = database::Categories::mock();
+        holiday.parent_id = Some(savings.id);
+        holiday.category_type = savings.category_type.clone();
+        let holiday = holiday.insert(&pool).await?;
+
+        for (category_id, amount) in [
+            (savings.id, -40_000),
+            (holiday.id, -25_000),
+            (holiday.id, 5_000),
+        ] {
+            let mut transaction = database::Transactions::new(date(2025, 3, 1), "Transfer", amount);
+            transaction.category_id = Some(category_id);
+            transaction.insert(&pool).await?;
+        }
+        let mut draft = database::Transactions::new(date(2025, 3, 1), "Transfer", -99_000);
+        draft.category_id = Some(savings.id);
+        draft.is_draft = true;
+        draft.insert(&pool).await?;
+
+        let goal = database::Goals::for_category("Holiday", 300_000, savings.id)
+            .insert(&pool)
+            .await?;
+
+        let progress = goal.progress(date(2025, 3, 31), &pool).await?;
+
+        assert_eq!(progress.saved, 60_000);
+        assert_eq!(progress.percent_complete(), 20.0);
+
+        Ok(())
+    }
+}
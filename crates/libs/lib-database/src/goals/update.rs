@@ -0,0 +1,137 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::Goals {
+    /// Updates an existing goal in the database.
+    ///
+    /// The name, target and link of the goal identified by `id` are replaced
+    /// and the `updated_on` timestamp is set to now.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The user whose goals are updated
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated goal as read back from the database, or a
+    /// `DatabaseError` if the update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The goal does not exist or is outside `scope` (`DatabaseError::NotFound`)
+    /// - The goal fails validation (`DatabaseError::Validation`)
+    /// - The account or category does not exist (foreign key violation)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Update goal in database",
+        skip(self, conn),
+        fields(id = %self.id, target_amount = self.target_amount),
+        err
+    )]
+    pub fn update<'a, 'c, A>(
+        &'a self,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let updated_on = chrono::Utc::now();
+            let user_id = scope.user_id();
+
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    UPDATE goals
+                    SET name = ?, target_amount = ?, target_date = ?, account_id = ?,
+                        category_id = ?, updated_on = ?
+                    WHERE id = ?
+                        AND (? IS NULL OR account_id IN (SELECT id FROM accounts WHERE user_id = ?)
+                            OR category_id IN (SELECT id FROM categories WHERE user_id = ?))
+                "#,
+                self.name,
+                self.target_amount,
+                self.target_date,
+                self.account_id,
+                self.category_id,
+                updated_on,
+                self.id,
+                user_id,
+                user_id,
+                user_id
+            )
+            .execute(&mut *conn)
+            .observe("goals.update")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Goal with id {} not found",
+                    self.id
+                )));
+            }
+
+            let updated = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Goal with id {} not found after update",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("Goal updated in the database.");
+
+            Ok(updated)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goals::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn update_goal_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let inserted = database::Goals::mock(account.id).insert(&pool).await?;
+        let category = database::Categories::mock().insert(&pool).await?;
+
+        let mut goal = inserted.clone();
+        goal.name = "New car".to_string();
+        goal.target_amount = 2_500_000;
+        goal.account_id = None;
+        goal.category_id = Some(category.id);
+
+        let updated = goal.update(database::UserScope::All, &pool).await?;
+
+        assert_eq!(updated.name, "New car");
+        assert_eq!(updated.category_id, Some(category.id));
+        assert_eq!(updated.account_id, None);
+        assert_eq!(updated.created_on, inserted.created_on);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+
+        let result = database::Goals::mock(account.id)
+            .update(database::UserScope::All, &pool)
+            .await;
+
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! # History Module
+//!
+//! Helpers for querying the `*_history` tables kept by the `0005_history`
+//! migration triggers. Every insert, update and delete of a category, account
+//! or transaction closes the current history row and/or opens a new one, so the
+//! ledger can be read back exactly as it was recorded at a past point in time,
+//! even after later edits.
+//!
+//! History timestamps are written by SQLite in the fixed UTC format
+//! `YYYY-MM-DDTHH:MM:SS.SSSZ`, which compares correctly as text. Cutoffs
+//! passed to history queries must be formatted the same way.
+
+/// Timestamp format used by the history triggers.
+const HISTORY_TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3fZ";
+
+/// Returns the history cutoff for the end of `as_of` (UTC).
+///
+/// A history row was current at the end of the day when it became valid before
+/// the cutoff and was not replaced until the cutoff or later:
+///
+/// ```sql
+/// valid_from < ?cutoff AND (valid_to IS NULL OR valid_to >= ?cutoff)
+/// ```
+pub(crate) fn end_of_day(as_of: chrono::NaiveDate) -> String {
+    let next_day = as_of.succ_opt().unwrap_or(chrono::NaiveDate::MAX);
+
+    next_day
+        .and_time(chrono::NaiveTime::MIN)
+        .and_utc()
+        .format(HISTORY_TIMESTAMP_FORMAT)
+        .to_string()
+}
+
+/// Backdates every history row of the record `id` in `table` by `days`, so
+/// tests can simulate edits made on earlier dates.
+#[cfg(test)]
+pub(crate) async fn backdate(
+    table: &str,
+    id: lib_domain::RowID,
+    days: i64,
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+) -> crate::DatabaseResult<()> {
+    let modifier = format!("-{days} days");
+    let sql = format!(
+        "UPDATE {table}
+         SET valid_from = strftime('%Y-%m-%dT%H:%M:%fZ', valid_from, ?1),
+             valid_to = strftime('%Y-%m-%dT%H:%M:%fZ', valid_to, ?1)
+         WHERE id = ?2"
+    );
+
+    sqlx::query(&sql)
+        .bind(modifier)
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn end_of_day_is_start_of_next_day() {
+        let as_of = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+
+        assert_eq!(end_of_day(as_of), "2025-07-01T00:00:00.000Z");
+    }
+
+    #[test]
+    fn end_of_day_matches_sqlite_format() {
+        // Same width as strftime('%Y-%m-%dT%H:%M:%fZ') so text comparison holds
+        let as_of = chrono::NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+
+        assert_eq!(end_of_day(as_of).len(), "2025-01-01T00:00:00.000Z".len());
+    }
+}
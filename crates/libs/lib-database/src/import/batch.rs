@@ -0,0 +1,181 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::ImportBatches {
+    /// Finds an import batch by its ID.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(ImportBatches)` if the batch exists, or `None` if not found.
+    #[tracing::instrument(name = "Find import batch by id", skip(conn), err)]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let batch = sqlx::query_as!(
+                database::ImportBatches,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        file_hash,
+                        file_name,
+                        file_size           AS "file_size!: i64",
+                        account_id          AS "account_id?: domain::RowID",
+                        inserted_count      AS "inserted_count!: i64",
+                        skipped_count       AS "skipped_count!: i64",
+                        imported_on         AS "imported_on!: chrono::DateTime<chrono::Utc>"
+                    FROM import_batches
+                    WHERE id = ?
+                "#,
+                id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("import.find_by_id")
+            .await?;
+
+            Ok(batch)
+        }
+    }
+
+    /// Finds the latest import of a file, by the hash of its contents.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::ImportBatches;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, export: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    /// let batch = ImportBatches::new(export, Some("march.csv"), None);
+    ///
+    /// if let Some(previous) = ImportBatches::find_latest_by_file_hash(&batch.file_hash, pool).await? {
+    ///     println!("Already imported on {}", previous.imported_on);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find latest import batch by file hash", skip(conn), err)]
+    pub fn find_latest_by_file_hash<'a, 'c, A>(
+        file_hash: &'a str,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let batch = sqlx::query_as!(
+                database::ImportBatches,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        file_hash,
+                        file_name,
+                        file_size           AS "file_size!: i64",
+                        account_id          AS "account_id?: domain::RowID",
+                        inserted_count      AS "inserted_count!: i64",
+                        skipped_count       AS "skipped_count!: i64",
+                        imported_on         AS "imported_on!: chrono::DateTime<chrono::Utc>"
+                    FROM import_batches
+                    WHERE file_hash = ?
+                    ORDER BY imported_on DESC
+                    LIMIT 1
+                "#,
+                file_hash
+            )
+            .fetch_optional(&mut *conn)
+            .observe("import.find_latest_by_file_hash")
+            .await?;
+
+            Ok(batch)
+        }
+    }
+
+    /// Finds the transactions created by an import batch that have not since
+    /// been deleted, in the order they were imported.
+    #[tracing::instrument(name = "Find transactions of import batch", skip(conn), err)]
+    pub fn find_transactions<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::Transactions>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let transactions = sqlx::query_as!(
+                database::Transactions,
+                r#"
+                    SELECT
+                        t.id                AS "id!: domain::RowID",
+                        t.transaction_date  AS "transaction_date!: chrono::NaiveDate",
+                        t.payee,
+                        t.description,
+                        t.amount            AS "amount!: i64",
+                        t.category_id       AS "category_id?: domain::RowID",
+                        t.account_id        AS "account_id?: domain::RowID",
+                        t.is_draft          AS "is_draft!: bool",
+                        t.latitude          AS "latitude?: f64",
+                        t.longitude         AS "longitude?: f64",
+                        t.place_name,
+                        t.return_by         AS "return_by?: chrono::NaiveDate",
+                        t.warranty_expires  AS "warranty_expires?: chrono::NaiveDate",
+                        t.cleared_status    AS "cleared_status!: database::ClearedStatus",
+                        t.user_id           AS "user_id?: domain::RowID",
+                        t.created_on        AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        t.updated_on        AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        t.version
+                    FROM transaction_imports i
+                    JOIN transactions t ON t.id = i.transaction_id
+                    WHERE i.batch_id = ?
+                    ORDER BY i.rowid
+                "#,
+                id
+            )
+            .fetch_all(&mut *conn)
+            .observe("import.find_transactions")
+            .await?;
+
+            Ok(transactions)
+        }
+    }
+
+    /// Writes the batch, with its counts, within the caller's database
+    /// transaction.
+    pub(super) async fn insert(&self, conn: &mut sqlx::SqliteConnection) -> DatabaseResult<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO import_batches (
+                    id, file_hash, file_name, file_size, account_id,
+                    inserted_count, skipped_count, imported_on
+                )
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                ON CONFLICT (id) DO UPDATE SET
+                    inserted_count = excluded.inserted_count,
+                    skipped_count = excluded.skipped_count
+            "#,
+            self.id,
+            self.file_hash,
+            self.file_name,
+            self.file_size,
+            self.account_id,
+            self.inserted_count,
+            self.skipped_count,
+            self.imported_on
+        )
+        .execute(&mut *conn)
+        .observe("import.insert")
+        .await?;
+
+        Ok(())
+    }
+}
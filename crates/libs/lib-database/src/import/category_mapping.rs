@@ -0,0 +1,383 @@
+//! # Category Mapping
+//!
+//! Import files such as QIF carry their own category names, e.g.
+//! `"Food:Groceries"`, which need to be matched to ledger categories. A
+//! [`CategoryMapping`] maps each file category to a ledger category code,
+//! either explicitly or by deriving a code from the name (`FOOD.GROCERIES`).
+//!
+//! File categories that map to no ledger category are handled by the mapping
+//! mode. In interactive mode they are reported back, so the user can choose a
+//! ledger category for each before importing. In auto-create mode a category
+//! is created for each, with a parent for every level of the name.
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+use std::collections::HashMap;
+
+/// What to do with import file categories that map to no ledger category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize, serde::Serialize)]
+pub enum CategoryMappingModes {
+    /// Refuse to import until every file category is mapped, so the user can
+    /// be asked about each of them.
+    #[default]
+    Interactive,
+    /// Create a ledger category for each unmapped file category.
+    AutoCreate,
+}
+
+/// Maps import file categories to ledger category codes.
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CategoryMapping {
+    pub mode: CategoryMappingModes,
+
+    /// Ledger category code chosen for a file category, by file category.
+    pub codes: HashMap<String, String>,
+}
+
+impl CategoryMapping {
+    /// Creates a mapping with no explicit codes.
+    pub fn new(mode: CategoryMappingModes) -> Self {
+        Self {
+            mode,
+            codes: HashMap::new(),
+        }
+    }
+
+    /// Maps a file category to a ledger category code.
+    pub fn with_code(mut self, category: impl Into<String>, code: impl Into<String>) -> Self {
+        self.codes.insert(category.into(), code.into());
+        self
+    }
+
+    /// Derives a ledger category code from a file category, e.g.
+    /// `"Food:Dining Out"` becomes `"FOOD.DININGOUT"`.
+    pub fn derive_code(category: &str) -> String {
+        category
+            .split(':')
+            .map(|level| {
+                level
+                    .chars()
+                    .filter(char::is_ascii_alphanumeric)
+                    .collect::<String>()
+                    .to_ascii_uppercase()
+            })
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Returns the file categories in `rows` that map to no ledger category, in
+    /// the order they first appear. These are the categories to ask the user
+    /// about in interactive mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if an explicitly mapped code is not
+    /// a ledger category.
+    #[tracing::instrument(name = "Find unmapped import categories", skip(self, rows, conn), err)]
+    pub fn unmapped<'a, 'c, A>(
+        &'a self,
+        rows: &'a [database::ImportRow],
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<String>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let existing = existing_codes(&mut conn).await?;
+
+            let mut unmapped = Vec::new();
+            for category in distinct_categories(rows) {
+                if self.resolve(category, &existing)?.is_none() {
+                    unmapped.push(category.to_string());
+                }
+            }
+
+            Ok(unmapped)
+        }
+    }
+
+    /// Sets the ledger category of every row that has a file category.
+    ///
+    /// In auto-create mode, the unmapped categories are created first, all in
+    /// one database transaction. A new category is an income category if the
+    /// first row using it is money received, otherwise an expense category.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The parsed rows, updated in place
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the categories created, parents before children.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - In interactive mode, a file category is not mapped
+    ///   (`DatabaseError::Validation`, listing every unmapped category)
+    /// - An explicitly mapped code is not a ledger category (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{CategoryMapping, CategoryMappingModes, ImportBatches, ImportSummary, QifFormat};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, qif: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut rows = QifFormat::default().parse(qif)?;
+    ///
+    /// let mapping = CategoryMapping::new(CategoryMappingModes::AutoCreate)
+    ///     .with_code("Food:Groceries", "GROC");
+    /// let created = mapping.apply(&mut rows, pool).await?;
+    ///
+    /// let batch = ImportBatches::new(qif, Some("export.qif"), None);
+    /// let summary = ImportSummary::import(&batch, &rows, false, pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Map import categories",
+        skip(self, rows, conn),
+        fields(mode = ?self.mode, count = rows.len()),
+        err
+    )]
+    pub fn apply<'a, 'c, A>(
+        &'a self,
+        rows: &'a mut [database::ImportRow],
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::Categories>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+            let mut existing = existing_codes(&mut tx).await?;
+            let mut resolved = HashMap::new();
+            let mut unmapped = Vec::new();
+            let mut created = Vec::new();
+
+            let categories: Vec<String> = distinct_categories(rows).map(str::to_string).collect();
+
+            for category in &categories {
+                if let Some(id) = self.resolve(category, &existing)? {
+                    resolved.insert(category.clone(), id);
+                    continue;
+                }
+
+                if self.mode == CategoryMappingModes::Interactive {
+                    unmapped.push(category.as_str());
+                    continue;
+                }
+
+                let received = rows
+                    .iter()
+                    .find(|row| row.category.as_ref() == Some(category))
+                    .is_some_and(|row| row.amount > 0);
+                let category_type = if received {
+                    domain::CategoryTypes::Income
+                } else {
+                    domain::CategoryTypes::Expense
+                };
+
+                // Create each missing level of the name, parents first
+                let mut parent_id = None;
+                let levels: Vec<&str> = category.split(':').collect();
+                for depth in 1..=levels.len() {
+                    let code = Self::derive_code(&levels[..depth].join(":"));
+                    if let Some(&id) = existing.get(&code) {
+                        parent_id = Some(id);
+                        continue;
+                    }
+
+                    let new_category = database::CategoriesBuilder::new()
+                        .with_code(code.clone())
+                        .with_name(levels[depth - 1].trim())
+                        .with_category_type(category_type.clone())
+                        .with_parent_id_opt(parent_id)
+                        .build()
+                        .map_err(|error| DatabaseError::Validation(error.to_string()))?
+                        .insert(&mut *tx)
+                        .await?;
+
+                    existing.insert(code, new_category.id);
+                    parent_id = Some(new_category.id);
+                    created.push(new_category);
+                }
+
+                if let Some(id) = parent_id {
+                    resolved.insert(category.clone(), id);
+                }
+            }
+
+            if !unmapped.is_empty() {
+                return Err(DatabaseError::Validation(format!(
+                    "Import categories are not mapped to a ledger category: {}",
+                    unmapped.join(", ")
+                )));
+            }
+
+            tx.commit().await?;
+
+            for row in rows.iter_mut() {
+                if let Some(category) = &row.category {
+                    row.category_id = resolved.get(category).copied();
+                }
+            }
+
+            tracing::info!(
+                "Mapped {} import categories, created {}",
+                resolved.len(),
+                created.len()
+            );
+
+            Ok(created)
+        }
+    }
+
+    /// Finds the ledger category for a file category, by its explicit code or
+    /// else its derived code.
+    fn resolve(
+        &self,
+        category: &str,
+        existing: &HashMap<String, domain::RowID>,
+    ) -> DatabaseResult<Option<domain::RowID>> {
+        match self.codes.get(category) {
+            Some(code) => existing.get(code).copied().map(Some).ok_or_else(|| {
+                DatabaseError::Validation(format!(
+                    "Import category '{category}' is mapped to unknown category code '{code}'"
+                ))
+            }),
+            None => Ok(existing.get(&Self::derive_code(category)).copied()),
+        }
+    }
+}
+
+/// The distinct file categories in `rows`, in the order they first appear.
+fn distinct_categories(rows: &[database::ImportRow]) -> impl Iterator<Item = &str> {
+    let mut seen = std::collections::HashSet::new();
+
+    rows.iter()
+        .filter_map(|row| row.category.as_deref())
+        .filter(move |category| seen.insert(*category))
+}
+
+/// Loads the id of every ledger category by its code.
+async fn existing_codes(
+    conn: &mut sqlx::SqliteConnection,
+) -> DatabaseResult<HashMap<String, domain::RowID>> {
+    let rows = sqlx::query!(
+        r#"
+            SELECT code, id AS "id!: domain::RowID"
+            FROM categories
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .observe("import.existing_codes")
+    .await?;
+
+    Ok(rows.into_iter().map(|row| (row.code, row.id)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::insert::tests::Result;
+
+    const QIF: &str = "\
+!Type:Bank
+D3/14'25
+T-85.20
+PWoolworths
+LFood:Groceries
+^
+D3/15'25
+T-12.50
+PCorner Cafe
+LFood:Dining Out
+^
+D3/16'25
+T2500
+PEmployer
+LSalary
+^
+";
+
+    fn rows() -> Vec<database::ImportRow> {
+        database::QifFormat::default()
+            .parse(QIF.as_bytes())
+            .unwrap()
+    }
+
+    #[test]
+    fn derive_code_keeps_levels() {
+        assert_eq!(
+            CategoryMapping::derive_code("Food:Dining Out"),
+            "FOOD.DININGOUT"
+        );
+        assert_eq!(CategoryMapping::derive_code("Salary"), "SALARY");
+    }
+
+    #[sqlx::test]
+    async fn interactive_mode_reports_unmapped_categories(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut groceries = database::Categories::mock();
+        groceries.code = "GROC".to_string();
+        groceries.insert(&pool).await?;
+
+        let mapping = CategoryMapping::new(CategoryMappingModes::Interactive)
+            .with_code("Food:Groceries", "GROC");
+        let mut rows = rows();
+
+        assert_eq!(
+            mapping.unmapped(&rows, &pool).await?,
+            vec!["Food:Dining Out", "Salary"]
+        );
+        let result = mapping.apply(&mut rows, &pool).await;
+        assert!(
+            matches!(result, Err(DatabaseError::Validation(message)) if message.ends_with("Food:Dining Out, Salary"))
+        );
+
+        let unknown = CategoryMapping::default()
+            .with_code("Salary", "NOPE")
+            .unmapped(&rows, &pool)
+            .await;
+        assert!(matches!(unknown, Err(DatabaseError::Validation(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn auto_create_mode_builds_hierarchy(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mapping = CategoryMapping::new(CategoryMappingModes::AutoCreate);
+        let mut rows = rows();
+
+        let created = mapping.apply(&mut rows, &pool).await?;
+        let codes: Vec<&str> = created
+            .iter()
+            .map(|category| category.code.as_str())
+            .collect();
+
+        assert_eq!(
+            codes,
+            vec!["FOOD", "FOOD.GROCERIES", "FOOD.DININGOUT", "SALARY"]
+        );
+        assert_eq!(created[1].parent_id, Some(created[0].id));
+        assert_eq!(created[3].category_type, domain::CategoryTypes::Income);
+        assert_eq!(rows[1].category_id, Some(created[2].id));
+
+        let batch = database::ImportBatches::new(QIF.as_bytes(), None, None);
+        let summary = database::ImportSummary::import(&batch, &rows, false, &pool).await?;
+        assert_eq!(summary.inserted[0].category_id, Some(created[1].id));
+
+        let again = mapping.apply(&mut rows, &pool).await?;
+        assert!(again.is_empty());
+
+        Ok(())
+    }
+}
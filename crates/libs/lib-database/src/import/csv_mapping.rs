@@ -0,0 +1,396 @@
+//! # CSV Mapping
+//!
+//! Every bank lays out its CSV export differently: the columns are named or
+//! ordered differently, dates are written day first or month first, and some
+//! put money in and out in separate columns. A [`CsvMapping`] describes one
+//! layout, so the same parser handles them all.
+
+use crate::{DatabaseError, DatabaseResult, ImportRow};
+
+/// A column in a CSV file, by header name or by zero based position.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum CsvColumn {
+    /// Column with this header, ignoring ASCII case and surrounding whitespace.
+    Name(String),
+
+    /// Column at this position, counting from zero.
+    Index(usize),
+}
+
+/// Where the amount of each row is found.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum CsvAmount {
+    /// A single signed column, negative for money spent.
+    Signed(CsvColumn),
+
+    /// Separate columns for money spent and money received, one of which is
+    /// blank on each row. Amounts in either column may be written unsigned.
+    DebitCredit { debit: CsvColumn, credit: CsvColumn },
+}
+
+/// Describes the layout of a bank's CSV export.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct CsvMapping {
+    /// Whether the first record is a header row.
+    pub has_headers: bool,
+
+    /// Field delimiter, usually `b','`.
+    pub delimiter: u8,
+
+    /// The transaction date column.
+    pub date: CsvColumn,
+
+    /// `chrono` format of the date column, e.g. `"%d/%m/%Y"`.
+    pub date_format: String,
+
+    /// The payee column.
+    pub payee: CsvColumn,
+
+    /// The description column, if the export has one.
+    pub description: Option<CsvColumn>,
+
+    /// The amount column or columns.
+    pub amount: CsvAmount,
+}
+
+/// A mapping with each column resolved to its position.
+struct ResolvedColumns {
+    date: usize,
+    payee: usize,
+    description: Option<usize>,
+    amount: ResolvedAmount,
+}
+
+enum ResolvedAmount {
+    Signed(usize),
+    DebitCredit { debit: usize, credit: usize },
+}
+
+impl CsvMapping {
+    /// Creates a mapping for a comma separated export with a header row and
+    /// ISO 8601 (`YYYY-MM-DD`) dates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{CsvAmount, CsvColumn, CsvMapping};
+    ///
+    /// let mapping = CsvMapping::new(
+    ///     CsvColumn::Name("Date".to_string()),
+    ///     CsvColumn::Name("Payee".to_string()),
+    ///     CsvAmount::Signed(CsvColumn::Name("Amount".to_string())),
+    /// )
+    /// .with_date_format("%d/%m/%Y");
+    ///
+    /// let csv = "Date,Payee,Amount\n14/03/2025,Corner Cafe,-12.50\n";
+    /// let rows = mapping.parse(csv.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(rows[0].amount, -1_250);
+    /// ```
+    pub fn new(date: CsvColumn, payee: CsvColumn, amount: CsvAmount) -> Self {
+        Self {
+            has_headers: true,
+            delimiter: b',',
+            date,
+            date_format: "%Y-%m-%d".to_string(),
+            payee,
+            description: None,
+            amount,
+        }
+    }
+
+    /// Sets the `chrono` format of the date column.
+    pub fn with_date_format(mut self, date_format: impl Into<String>) -> Self {
+        self.date_format = date_format.into();
+        self
+    }
+
+    /// Sets the description column.
+    pub fn with_description(mut self, description: CsvColumn) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the field delimiter.
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether the first record is a header row. Columns must be mapped
+    /// by position when there is no header row.
+    pub fn with_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Parses a CSV export into import rows, in file order. Records with every
+    /// field blank are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation`, naming the line, if the file is not
+    /// valid CSV, a mapped column is missing, or a date, payee or amount cannot
+    /// be read. Nothing is returned for a file with any bad row, so a partly
+    /// understood export is never imported.
+    pub fn parse<R: std::io::Read>(&self, reader: R) -> DatabaseResult<Vec<ImportRow>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(self.has_headers)
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+
+        let headers = if self.has_headers {
+            Some(reader.headers().map_err(csv_error)?.clone())
+        } else {
+            None
+        };
+        let columns = self.resolve(headers.as_ref())?;
+
+        let mut rows = Vec::new();
+
+        for record in reader.records() {
+            let record = record.map_err(csv_error)?;
+            if record.iter().all(str::is_empty) {
+                continue;
+            }
+
+            let line = record.position().map_or(0, csv::Position::line);
+            rows.push(self.parse_record(&record, line, &columns)?);
+        }
+
+        Ok(rows)
+    }
+
+    fn resolve(&self, headers: Option<&csv::StringRecord>) -> DatabaseResult<ResolvedColumns> {
+        let position = |column: &CsvColumn| match column {
+            CsvColumn::Index(index) => Ok(*index),
+            CsvColumn::Name(name) => headers
+                .ok_or_else(|| {
+                    DatabaseError::Validation(format!(
+                        "Column '{name}' must be mapped by position, the file has no header row"
+                    ))
+                })?
+                .iter()
+                .position(|header| header.trim().eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| {
+                    DatabaseError::Validation(format!("Column '{name}' not found in header row"))
+                }),
+        };
+
+        Ok(ResolvedColumns {
+            date: position(&self.date)?,
+            payee: position(&self.payee)?,
+            description: self.description.as_ref().map(position).transpose()?,
+            amount: match &self.amount {
+                CsvAmount::Signed(column) => ResolvedAmount::Signed(position(column)?),
+                CsvAmount::DebitCredit { debit, credit } => ResolvedAmount::DebitCredit {
+                    debit: position(debit)?,
+                    credit: position(credit)?,
+                },
+            },
+        })
+    }
+
+    fn parse_record(
+        &self,
+        record: &csv::StringRecord,
+        line: u64,
+        columns: &ResolvedColumns,
+    ) -> DatabaseResult<ImportRow> {
+        let invalid =
+            |message: String| DatabaseError::Validation(format!("Line {line}: {message}"));
+        let field = |index: usize| {
+            record
+                .get(index)
+                .ok_or_else(|| invalid(format!("missing column {}", index + 1)))
+        };
+
+        let date = field(columns.date)?;
+        let transaction_date =
+            chrono::NaiveDate::parse_from_str(date, &self.date_format).map_err(|_| {
+                invalid(format!(
+                    "invalid date '{date}', expected format '{}'",
+                    self.date_format
+                ))
+            })?;
+
+        let payee = field(columns.payee)?;
+        if payee.is_empty() {
+            return Err(invalid("payee is blank".to_string()));
+        }
+
+        let description = match columns.description {
+            Some(index) => Some(field(index)?)
+                .filter(|text| !text.is_empty())
+                .map(str::to_string),
+            None => None,
+        };
+
+        let amount = match columns.amount {
+            ResolvedAmount::Signed(index) => {
+                let text = field(index)?;
+                parse_amount(text).ok_or_else(|| invalid(format!("invalid amount '{text}'")))?
+            }
+            ResolvedAmount::DebitCredit { debit, credit } => {
+                let (debit, credit) = (field(debit)?, field(credit)?);
+                match (debit.is_empty(), credit.is_empty()) {
+                    (false, true) => -parse_amount(debit)
+                        .ok_or_else(|| invalid(format!("invalid debit '{debit}'")))?
+                        .abs(),
+                    (true, false) => parse_amount(credit)
+                        .ok_or_else(|| invalid(format!("invalid credit '{credit}'")))?
+                        .abs(),
+                    _ => {
+                        return Err(invalid(
+                            "exactly one of debit and credit must be given".to_string(),
+                        ));
+                    }
+                }
+            }
+        };
+
+        Ok(ImportRow {
+            line,
+            transaction_date,
+            payee: payee.to_string(),
+            description,
+            amount,
+            category: None,
+            category_id: None,
+        })
+    }
+}
+
+/// Parses an amount as written in bank exports into minor units (cents).
+///
+/// Accepts a leading sign or accounting style parentheses for negatives,
+/// a leading `$`, thousands separators and up to two decimal places, e.g.
+/// `-1,234.5`, `$12.50` or `(12.50)`.
+pub(super) fn parse_amount(text: &str) -> Option<i64> {
+    let text = text.trim();
+    let (negative, text) = match text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+        Some(inner) => (true, inner.trim()),
+        None => match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        },
+    };
+
+    let text = text.strip_prefix('$').unwrap_or(text).replace(',', "");
+    let (units, fraction) = text.split_once('.').unwrap_or((&text, ""));
+
+    if units.is_empty() && fraction.is_empty()
+        || !units.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+        || fraction.len() > 2
+    {
+        return None;
+    }
+
+    let units: i64 = if units.is_empty() {
+        0
+    } else {
+        units.parse().ok()?
+    };
+    let cents: i64 = format!("{fraction:0<2}").parse().ok()?;
+    let amount = units.checked_mul(100)?.checked_add(cents)?;
+
+    Some(if negative { -amount } else { amount })
+}
+
+fn csv_error(error: csv::Error) -> DatabaseError {
+    match error.position() {
+        Some(position) => DatabaseError::Validation(format!("Line {}: {error}", position.line())),
+        None => DatabaseError::Validation(format!("Invalid CSV: {error}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn named(name: &str) -> CsvColumn {
+        CsvColumn::Name(name.to_string())
+    }
+
+    #[test]
+    fn parse_amount_accepts_bank_formats() {
+        assert_eq!(parse_amount("-12.50"), Some(-1_250));
+        assert_eq!(parse_amount("$1,234.5"), Some(123_450));
+        assert_eq!(parse_amount("(12.50)"), Some(-1_250));
+        assert_eq!(parse_amount("+7"), Some(700));
+        assert_eq!(parse_amount(".99"), Some(99));
+        assert_eq!(parse_amount("12.345"), None);
+        assert_eq!(parse_amount("twelve"), None);
+        assert_eq!(parse_amount(""), None);
+    }
+
+    #[test]
+    fn parse_maps_named_columns() {
+        let mapping = CsvMapping::new(
+            named("Date"),
+            named("Payee"),
+            CsvAmount::Signed(named("Amount")),
+        )
+        .with_date_format("%d/%m/%Y")
+        .with_description(named("Memo"));
+        let csv = "Amount, date ,Payee,Memo\n-12.50,14/03/2025,Corner Cafe,EFTPOS\n\n2500.00,15/03/2025,Employer,\n";
+
+        let rows = mapping.parse(csv.as_bytes()).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].line, 2);
+        assert_eq!(rows[0].payee, "Corner Cafe");
+        assert_eq!(rows[0].amount, -1_250);
+        assert_eq!(rows[0].description.as_deref(), Some("EFTPOS"));
+        assert_eq!(
+            rows[1].transaction_date,
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 15).unwrap()
+        );
+        assert_eq!(rows[1].description, None);
+    }
+
+    #[test]
+    fn parse_maps_debit_and_credit_columns_by_position() {
+        let amount = CsvAmount::DebitCredit {
+            debit: CsvColumn::Index(2),
+            credit: CsvColumn::Index(3),
+        };
+        let mapping = CsvMapping::new(CsvColumn::Index(0), CsvColumn::Index(1), amount)
+            .with_headers(false)
+            .with_delimiter(b';');
+        let csv = "2025-03-14;Corner Cafe;12.50;\n2025-03-15;Employer;;2500\n";
+
+        let rows = mapping.parse(csv.as_bytes()).unwrap();
+
+        assert_eq!(
+            rows.iter().map(|row| row.amount).collect::<Vec<_>>(),
+            vec![-1_250, 250_000]
+        );
+    }
+
+    #[test]
+    fn parse_rejects_bad_rows_with_line_number() {
+        let mapping = CsvMapping::new(
+            named("Date"),
+            named("Payee"),
+            CsvAmount::Signed(named("Amount")),
+        );
+
+        let bad_date =
+            mapping.parse("Date,Payee,Amount\n2025-03-14,Cafe,-1\n14/03/2025,Cafe,-1\n".as_bytes());
+        let missing = mapping.parse("Date,Description,Amount\n".as_bytes());
+        let by_name = mapping
+            .clone()
+            .with_headers(false)
+            .parse("2025-03-14,Cafe,-1\n".as_bytes());
+
+        assert!(
+            matches!(bad_date, Err(DatabaseError::Validation(message)) if message.starts_with("Line 3:"))
+        );
+        assert!(matches!(missing, Err(DatabaseError::Validation(_))));
+        assert!(matches!(by_name, Err(DatabaseError::Validation(_))));
+    }
+}
@@ -0,0 +1,363 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::ImportSummary {
+    /// Imports the parsed rows of a file as transactions, skipping
+    /// duplicates, and records the import as a batch.
+    ///
+    /// If the same file (see [`database::ImportBatches::new`]) was imported
+    /// before, nothing is written and every row is skipped, unless `force` is
+    /// set. A forced import is recorded as a batch of its own, and still skips
+    /// duplicate rows.
+    ///
+    /// A row is a duplicate if a transaction with the same date, amount and
+    /// payee (see [`database::ImportRow::import_hash`]) was imported before, or
+    /// appears earlier in `rows`. Every other row is inserted in a single
+    /// database transaction, recording the batch it was imported in: either
+    /// the whole import is written or, if any row fails, none of it is.
+    ///
+    /// Payees are cleaned up with the stored payee aliases (see
+    /// [`database::PayeeNormaliser`]). Duplicates are still recognised by the
+    /// raw payee, so adding an alias does not cause rows to be imported twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch` - The file being imported, and the account to post to
+    /// * `rows` - The rows parsed from the file, e.g. by [`database::CsvMapping::parse`]
+    /// * `force` - Whether to import the file even if it was imported before
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns a summary of the inserted transactions and skipped rows, with
+    /// the batch recorded and any earlier import of the file, or a
+    /// `DatabaseError` if the import fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A row is not a valid transaction (`DatabaseError::Validation`)
+    /// - A row is dated in a closed period (`DatabaseError::PeriodClosed`)
+    /// - The account does not exist (foreign key violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{CsvAmount, CsvColumn, CsvMapping, ImportBatches, ImportSummary};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mapping = CsvMapping::new(
+    ///     CsvColumn::Name("Date".to_string()),
+    ///     CsvColumn::Name("Payee".to_string()),
+    ///     CsvAmount::Signed(CsvColumn::Name("Amount".to_string())),
+    /// );
+    /// let export = "Date,Payee,Amount\n2025-03-14,Corner Cafe,-12.50\n";
+    /// let rows = mapping.parse(export.as_bytes())?;
+    /// let batch = ImportBatches::new(export.as_bytes(), Some("march.csv"), None);
+    ///
+    /// let summary = ImportSummary::import(&batch, &rows, false, pool).await?;
+    /// if let Some(previous) = summary.previous_batch {
+    ///     println!("The file was already imported on {}", previous.imported_on);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Import transactions",
+        skip(batch, rows, conn),
+        fields(batch_id = %batch.id, file_hash = %batch.file_hash, count = rows.len()),
+        err
+    )]
+    pub fn import<'a, 'c, A>(
+        batch: &'a database::ImportBatches,
+        rows: &'a [database::ImportRow],
+        force: bool,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            let previous_batch =
+                database::ImportBatches::find_latest_by_file_hash(&batch.file_hash, &mut *tx)
+                    .await?;
+
+            if let Some(previous) = &previous_batch
+                && !force
+            {
+                tracing::info!(
+                    "File was already imported in batch {}, skipping",
+                    previous.id
+                );
+
+                return Ok(Self {
+                    batch: None,
+                    previous_batch,
+                    inserted: Vec::new(),
+                    skipped: rows.to_vec(),
+                });
+            }
+
+            let mut batch = batch.clone();
+            batch.insert(&mut tx).await?;
+
+            let normaliser = database::PayeeNormaliser::load(&mut *tx).await?;
+
+            let mut seen = std::collections::HashSet::new();
+            let mut summary = Self {
+                previous_batch,
+                ..Self::default()
+            };
+
+            for row in rows {
+                let import_hash = row.import_hash();
+
+                let already_imported = sqlx::query_scalar!(
+                    r#"
+                        SELECT EXISTS (
+                            SELECT 1 FROM transaction_imports WHERE import_hash = ?
+                        ) AS "exists!: bool"
+                    "#,
+                    import_hash
+                )
+                .fetch_one(&mut *tx)
+                .observe_one("import.import")
+                .await?;
+
+                if already_imported || !seen.insert(import_hash.clone()) {
+                    summary.skipped.push(row.clone());
+                    continue;
+                }
+
+                let mut transaction = row.to_transaction(batch.account_id);
+                transaction.payee = normaliser.normalise(&row.payee).to_string();
+                let transaction = transaction.insert(&mut *tx).await?;
+
+                sqlx::query!(
+                    r#"
+                        INSERT INTO transaction_imports (transaction_id, import_hash, imported_on, batch_id)
+                        VALUES (?, ?, ?, ?)
+                    "#,
+                    transaction.id,
+                    import_hash,
+                    batch.imported_on,
+                    batch.id
+                )
+                .execute(&mut *tx)
+                .observe("import.import")
+                .await?;
+
+                summary.inserted.push(transaction);
+            }
+
+            batch.inserted_count = summary.inserted.len() as i64;
+            batch.skipped_count = summary.skipped.len() as i64;
+            batch.insert(&mut tx).await?;
+            summary.batch = Some(batch);
+
+            tx.commit().await?;
+
+            tracing::info!(
+                "Imported {} transactions, skipped {} duplicates",
+                summary.inserted.len(),
+                summary.skipped.len()
+            );
+
+            Ok(summary)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use lib_domain as domain;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    pub fn file(
+        csv: &str,
+        account_id: Option<domain::RowID>,
+    ) -> (database::ImportBatches, Vec<database::ImportRow>) {
+        let mapping = database::CsvMapping::new(
+            database::CsvColumn::Name("Date".to_string()),
+            database::CsvColumn::Name("Payee".to_string()),
+            database::CsvAmount::Signed(database::CsvColumn::Name("Amount".to_string())),
+        );
+        let contents = format!("Date,Payee,Amount\n{csv}");
+
+        (
+            database::ImportBatches::new(contents.as_bytes(), Some("export.csv"), account_id),
+            mapping.parse(contents.as_bytes()).unwrap(),
+        )
+    }
+
+    #[sqlx::test]
+    async fn import_skips_rows_already_imported(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::new("Everyday").insert(&pool).await?;
+        let (march_file, march) = file(
+            "2025-03-14,Corner Cafe,-12.50\n2025-03-15,Employer,2500\n",
+            Some(account.id),
+        );
+        let (overlapping_file, overlapping) = file(
+            "2025-03-15,EMPLOYER,2500\n2025-03-16,Bakery,-6\n2025-03-16,Bakery,-6\n",
+            Some(account.id),
+        );
+
+        let first = database::ImportSummary::import(&march_file, &march, false, &pool).await?;
+        let second =
+            database::ImportSummary::import(&overlapping_file, &overlapping, false, &pool).await?;
+
+        assert_eq!(first.inserted.len(), 2);
+        assert!(first.skipped.is_empty());
+        assert_eq!(second.inserted.len(), 1);
+        assert_eq!(second.inserted[0].payee, "Bakery");
+        assert_eq!(
+            second
+                .skipped
+                .iter()
+                .map(|row| row.line)
+                .collect::<Vec<_>>(),
+            vec![2, 4]
+        );
+        assert_eq!(second.previous_batch, None);
+        assert_eq!(
+            database::Accounts::balance(account.id, None, &pool).await?,
+            248_150
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn import_records_batch_of_each_transaction(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let (batch, rows) = file(
+            "2025-03-14,Corner Cafe,-12.50\n2025-03-15,Employer,2500\n2025-03-15,Employer,2500\n",
+            None,
+        );
+
+        let summary = database::ImportSummary::import(&batch, &rows, false, &pool).await?;
+        let recorded = database::ImportBatches::find_by_id(batch.id, &pool)
+            .await?
+            .expect("batch recorded");
+
+        assert_eq!(summary.batch.as_ref(), Some(&recorded));
+        assert_eq!((recorded.inserted_count, recorded.skipped_count), (2, 1));
+        assert_eq!(recorded.file_name.as_deref(), Some("export.csv"));
+        assert_eq!(
+            database::ImportBatches::find_transactions(batch.id, &pool).await?,
+            summary.inserted
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn import_of_same_file_is_skipped_unless_forced(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let csv = "2025-03-14,Corner Cafe,-12.50\n2025-03-15,Employer,2500\n";
+        let (first_batch, rows) = file(csv, None);
+        let first = database::ImportSummary::import(&first_batch, &rows, false, &pool).await?;
+        database::Transactions::delete_by_id(first.inserted[0].id, database::UserScope::All, &pool)
+            .await?;
+
+        // The same export downloaded again is a new batch of the same file
+        let (again_batch, rows) = file(csv, None);
+        let again = database::ImportSummary::import(&again_batch, &rows, false, &pool).await?;
+
+        assert_eq!(again.batch, None);
+        assert_eq!(again.previous_batch, first.batch);
+        assert!(again.inserted.is_empty());
+        assert_eq!(again.skipped.len(), 2);
+        assert_eq!(
+            database::ImportBatches::find_by_id(again_batch.id, &pool).await?,
+            None
+        );
+
+        // Forced, only the deleted row is imported again
+        let forced = database::ImportSummary::import(&again_batch, &rows, true, &pool).await?;
+
+        assert_eq!(
+            forced.batch.as_ref().map(|batch| batch.id),
+            Some(again_batch.id)
+        );
+        assert_eq!(forced.previous_batch, first.batch);
+        assert_eq!(forced.inserted.len(), 1);
+        assert_eq!(forced.inserted[0].payee, "Corner Cafe");
+        assert_eq!(
+            database::ImportBatches::find_latest_by_file_hash(&first_batch.file_hash, &pool)
+                .await?,
+            forced.batch
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn import_is_all_or_nothing(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        database::PeriodClosings::close(
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap(),
+            None,
+            &pool,
+        )
+        .await?;
+        let (batch, rows) = file(
+            "2025-03-15,Employer,2500\n2025-03-14,Corner Cafe,-12.50\n",
+            None,
+        );
+
+        let result = database::ImportSummary::import(&batch, &rows, false, &pool).await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::PeriodClosed(_))
+        ));
+        assert_eq!(
+            database::Transactions::find_all_with_pagination(
+                None,
+                10,
+                database::UserScope::All,
+                &pool
+            )
+            .await?
+            .total_count,
+            0
+        );
+        assert_eq!(
+            database::ImportBatches::find_by_id(batch.id, &pool).await?,
+            None
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn deleted_transactions_can_be_imported_again(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let (first_batch, rows) = file("2025-03-14,Corner Cafe,-12.50\n", None);
+        let (second_batch, overlapping) = file(
+            "2025-03-14,Corner Cafe,-12.50\n2025-03-15,Bakery,-6\n",
+            None,
+        );
+
+        let first = database::ImportSummary::import(&first_batch, &rows, false, &pool).await?;
+        database::Transactions::delete_by_id(first.inserted[0].id, database::UserScope::All, &pool)
+            .await?;
+        let second =
+            database::ImportSummary::import(&second_batch, &overlapping, false, &pool).await?;
+
+        assert_eq!(second.inserted.len(), 2);
+
+        Ok(())
+    }
+}
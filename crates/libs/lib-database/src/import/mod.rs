@@ -0,0 +1,36 @@
+//! # Import Database Module
+//!
+//! Brings transactions in from bank exports. A file is first parsed into
+//! [`ImportRow`]s by a format specific reader, e.g. a [`CsvMapping`] that says
+//! which columns of a bank's CSV export hold the date, payee and amount, or a
+//! [`QifFormat`] for files exported from Quicken and GnuCash. Categories named
+//! in the file are matched to ledger categories by a [`CategoryMapping`]. The
+//! rows are then written in a single database transaction, skipping any row
+//! whose date, amount and payee were already imported, so overlapping exports
+//! can be imported without creating duplicates. Raw payees are cleaned up
+//! with the stored [payee aliases](crate::PayeeAliases) as they are written.
+//!
+//! Each import is recorded as an [`ImportBatches`] row with a hash of the
+//! file, so the same file is not imported twice, and each imported
+//! transaction records the batch it came from. A batch can be rolled back,
+//! deleting the transactions it created.
+
+mod batch;
+mod category_mapping;
+mod csv_mapping;
+mod insert;
+mod model;
+mod qif;
+mod rollback;
+
+/// Mapping of import file categories to ledger categories.
+pub use category_mapping::{CategoryMapping, CategoryMappingModes};
+
+/// Column mapping for parsing bank CSV exports.
+pub use csv_mapping::{CsvAmount, CsvColumn, CsvMapping};
+
+/// Reader for Quicken Interchange Format files.
+pub use qif::{QifDateOrders, QifFormat};
+
+/// A parsed row, the record of an imported file and the summary of an import.
+pub use model::{ImportBatches, ImportRow, ImportSummary};
@@ -0,0 +1,196 @@
+use crate as database;
+use sha2::Digest;
+
+/// A transaction parsed from an import file, before it is written.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ImportRow {
+    /// Line in the import file the row was read from, for reporting.
+    pub line: u64,
+    pub transaction_date: chrono::NaiveDate,
+    pub payee: String,
+    pub description: Option<String>,
+
+    /// Signed amount in minor units (cents), negative for money spent.
+    pub amount: i64,
+
+    /// Category as written in the import file, e.g. `"Food:Groceries"`.
+    pub category: Option<String>,
+
+    /// Ledger category the row is classified under, set by a
+    /// [`CategoryMapping`](database::CategoryMapping).
+    pub category_id: Option<lib_domain::RowID>,
+}
+
+/// A record of one import of a bank export file.
+///
+/// The file is recognised by a SHA-256 hash of its contents, so the same
+/// export is recognised whatever it was named when downloaded.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ImportBatches {
+    pub id: lib_domain::RowID,
+
+    /// Hex encoded SHA-256 hash of the file's contents.
+    pub file_hash: String,
+
+    /// Name of the file as uploaded, for display only.
+    pub file_name: Option<String>,
+
+    /// Size of the file in bytes.
+    pub file_size: i64,
+
+    /// The account the transactions were posted to, if any.
+    pub account_id: Option<lib_domain::RowID>,
+
+    /// Number of transactions the import created.
+    pub inserted_count: i64,
+
+    /// Number of rows left out as duplicates.
+    pub skipped_count: i64,
+
+    pub imported_on: chrono::DateTime<chrono::Utc>,
+}
+
+/// The outcome of an import.
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ImportSummary {
+    /// The batch the import was recorded as, or `None` if the file was
+    /// skipped because it was imported before.
+    pub batch: Option<ImportBatches>,
+
+    /// The latest earlier import of the same file, if any.
+    pub previous_batch: Option<ImportBatches>,
+
+    /// Transactions created by the import, in file order.
+    pub inserted: Vec<database::Transactions>,
+
+    /// Rows left out because the same date, amount and payee were already
+    /// imported, or appear earlier in the same file.
+    pub skipped: Vec<ImportRow>,
+}
+
+impl ImportRow {
+    /// Returns the hash used to recognise a row that was already imported.
+    ///
+    /// The hash covers the date, amount and payee. The payee is compared
+    /// ignoring case and runs of whitespace, which banks are not consistent
+    /// about between exports. The description is left out for the same reason.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::ImportRow;
+    ///
+    /// let row = |payee: &str| ImportRow {
+    ///     line: 2,
+    ///     transaction_date: chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap(),
+    ///     payee: payee.to_string(),
+    ///     description: None,
+    ///     amount: -1_250,
+    ///     category: None,
+    ///     category_id: None,
+    /// };
+    ///
+    /// assert_eq!(row("CORNER  CAFE").import_hash(), row("Corner Cafe").import_hash());
+    /// ```
+    pub fn import_hash(&self) -> String {
+        let payee = self
+            .payee
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let digest = sha2::Sha256::digest(format!(
+            "{}|{}|{}",
+            self.transaction_date, self.amount, payee
+        ));
+
+        digest.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// Converts the row into a new transaction, optionally posted to an
+    /// account, classified under the row's mapped category.
+    pub fn to_transaction(&self, account_id: Option<lib_domain::RowID>) -> database::Transactions {
+        let mut transaction =
+            database::Transactions::new(self.transaction_date, self.payee.trim(), self.amount);
+        transaction.description = self.description.clone();
+        transaction.account_id = account_id;
+        transaction.category_id = self.category_id;
+
+        transaction
+    }
+}
+
+impl ImportBatches {
+    /// Creates the batch for importing a file's contents, computing the
+    /// file's hash. Counts are filled in as the file is imported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::ImportBatches;
+    ///
+    /// let export = "Date,Payee,Amount\n2025-03-14,Corner Cafe,-12.50\n";
+    /// let batch = ImportBatches::new(export.as_bytes(), Some("march.csv"), None);
+    ///
+    /// assert_eq!(batch.file_hash, ImportBatches::new(export.as_bytes(), None, None).file_hash);
+    /// assert_eq!(batch.file_size, 48);
+    /// ```
+    pub fn new(
+        contents: &[u8],
+        file_name: Option<&str>,
+        account_id: Option<lib_domain::RowID>,
+    ) -> Self {
+        let digest = sha2::Sha256::digest(contents);
+
+        Self {
+            id: lib_domain::RowID::new(),
+            file_hash: digest.iter().map(|byte| format!("{byte:02x}")).collect(),
+            file_name: file_name.map(str::to_string),
+            file_size: contents.len() as i64,
+            account_id,
+            inserted_count: 0,
+            skipped_count: 0,
+            imported_on: chrono::Utc::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(payee: &str, amount: i64) -> ImportRow {
+        ImportRow {
+            line: 2,
+            transaction_date: chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap(),
+            payee: payee.to_string(),
+            description: Some("EFTPOS".to_string()),
+            amount,
+            category: None,
+            category_id: None,
+        }
+    }
+
+    #[test]
+    fn import_hash_ignores_payee_case_and_spacing() {
+        let hash = row("Corner Cafe", -1_250).import_hash();
+
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, row("  corner   CAFE ", -1_250).import_hash());
+        assert_ne!(hash, row("Corner Cafe", -1_200).import_hash());
+        assert_ne!(hash, row("Corner Bakery", -1_250).import_hash());
+    }
+
+    #[test]
+    fn to_transaction_keeps_row_fields() {
+        let account_id = lib_domain::RowID::new();
+        let transaction = row(" Corner Cafe ", -1_250).to_transaction(Some(account_id));
+
+        assert_eq!(transaction.payee, "Corner Cafe");
+        assert_eq!(transaction.amount, -1_250);
+        assert_eq!(transaction.description.as_deref(), Some("EFTPOS"));
+        assert_eq!(transaction.account_id, Some(account_id));
+        assert!(!transaction.is_draft);
+    }
+}
@@ -0,0 +1,303 @@
+//! # QIF
+//!
+//! Parses Quicken Interchange Format files, as exported by Quicken, GnuCash
+//! and many older bank sites. A QIF file is a list of records, one field per
+//! line, each line starting with a code letter and each record ending with `^`:
+//!
+//! ```text
+//! !Type:Bank
+//! D03/14'25
+//! T-12.50
+//! PCorner Cafe
+//! MFlat white
+//! LFood:Dining Out
+//! ^
+//! ```
+//!
+//! Only bank, cash, credit card and other asset or liability accounts are
+//! read. Account lists, category lists, classes and memorised transactions are
+//! skipped. Split lines are not read, each transaction is imported with its
+//! total and its main category.
+
+use super::csv_mapping::parse_amount;
+use crate::{DatabaseError, DatabaseResult, ImportRow};
+
+/// The order of the month and day in QIF dates, which depends on the locale
+/// of the program that wrote the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize, serde::Serialize)]
+pub enum QifDateOrders {
+    /// `MM/DD/YYYY`, as written by US versions of Quicken.
+    #[default]
+    MonthFirst,
+    /// `DD/MM/YYYY`, as written with Australian and UK locales.
+    DayFirst,
+}
+
+/// Reads transactions from QIF files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize, serde::Serialize)]
+pub struct QifFormat {
+    pub date_order: QifDateOrders,
+}
+
+/// The kind of records in the current section of the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sections {
+    Transactions,
+    Skipped,
+}
+
+/// A transaction record being read.
+#[derive(Default)]
+struct Record {
+    line: u64,
+    date: Option<String>,
+    amount: Option<String>,
+    payee: Option<String>,
+    memo: Option<String>,
+    category: Option<String>,
+}
+
+impl QifFormat {
+    /// Creates a QIF reader for dates in the given order.
+    pub fn new(date_order: QifDateOrders) -> Self {
+        Self { date_order }
+    }
+
+    /// Parses a QIF file into import rows, in file order.
+    ///
+    /// Transfers, written as a category in brackets such as `[Savings]`, are
+    /// imported uncategorised. A record without a payee uses its memo instead.
+    /// Records with a zero amount, such as an empty opening balance, are
+    /// skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation`, naming the line, if the file is an
+    /// investment account, or a record is missing its date, amount or payee or
+    /// they cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{QifDateOrders, QifFormat};
+    ///
+    /// let qif = "!Type:Bank\nD14/03'25\nT-12.50\nPCorner Cafe\nLFood:Dining Out\n^\n";
+    /// let rows = QifFormat::new(QifDateOrders::DayFirst).parse(qif.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(rows[0].amount, -1_250);
+    /// assert_eq!(rows[0].category.as_deref(), Some("Food:Dining Out"));
+    /// ```
+    pub fn parse<R: std::io::Read>(&self, reader: R) -> DatabaseResult<Vec<ImportRow>> {
+        let reader = std::io::BufReader::new(reader);
+        let mut section = Sections::Transactions;
+        let mut record = Record::default();
+        let mut rows = Vec::new();
+
+        for (index, line) in std::io::BufRead::lines(reader).enumerate() {
+            let line_number = index as u64 + 1;
+            let line = line.map_err(|error| {
+                DatabaseError::Validation(format!("Line {line_number}: {error}"))
+            })?;
+            let line = line.trim_end();
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('!') {
+                section = Self::section(header, line_number)?;
+                record = Record::default();
+                continue;
+            }
+
+            if section == Sections::Skipped {
+                continue;
+            }
+
+            let (code, value) = line.split_at(line.chars().next().map_or(0, char::len_utf8));
+            let value = Some(value.trim().to_string()).filter(|value| !value.is_empty());
+
+            if record.line == 0 {
+                record.line = line_number;
+            }
+
+            match code {
+                "D" => record.date = value,
+                "T" => record.amount = value,
+                "U" => record.amount = record.amount.take().or(value),
+                "P" => record.payee = value,
+                "M" => record.memo = value,
+                "L" => record.category = value,
+                "^" => {
+                    if let Some(row) = self.parse_record(std::mem::take(&mut record))? {
+                        rows.push(row);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(rows)
+    }
+
+    fn section(header: &str, line: u64) -> DatabaseResult<Sections> {
+        let header = header.trim().to_ascii_lowercase();
+
+        match header.strip_prefix("type:").map(str::trim) {
+            Some("bank" | "cash" | "ccard" | "oth a" | "oth l") => Ok(Sections::Transactions),
+            Some("invst") => Err(DatabaseError::Validation(format!(
+                "Line {line}: QIF investment accounts are not supported"
+            ))),
+            _ => Ok(Sections::Skipped),
+        }
+    }
+
+    fn parse_record(&self, record: Record) -> DatabaseResult<Option<ImportRow>> {
+        let line = record.line;
+        let invalid =
+            |message: String| DatabaseError::Validation(format!("Line {line}: {message}"));
+
+        let date = record
+            .date
+            .ok_or_else(|| invalid("record has no date".to_string()))?;
+        let transaction_date = self
+            .parse_date(&date)
+            .ok_or_else(|| invalid(format!("invalid date '{date}'")))?;
+
+        let amount = record
+            .amount
+            .ok_or_else(|| invalid("record has no amount".to_string()))?;
+        let amount =
+            parse_amount(&amount).ok_or_else(|| invalid(format!("invalid amount '{amount}'")))?;
+        if amount == 0 {
+            return Ok(None);
+        }
+
+        let (payee, description) = match (record.payee, record.memo) {
+            (Some(payee), memo) => (payee, memo),
+            (None, Some(memo)) => (memo, None),
+            (None, None) => return Err(invalid("record has no payee or memo".to_string())),
+        };
+
+        // Drop the class after a slash, and leave transfers uncategorised
+        let category = record
+            .category
+            .map(|category| {
+                category
+                    .split('/')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string()
+            })
+            .filter(|category| !category.is_empty() && !category.starts_with('['));
+
+        Ok(Some(ImportRow {
+            line,
+            transaction_date,
+            payee,
+            description,
+            amount,
+            category,
+            category_id: None,
+        }))
+    }
+
+    /// Parses a QIF date, e.g. `3/14/2025`, `03/14'25` or `14-03-2025`. Two
+    /// digit years after an apostrophe are in the 2000s, other two digit years
+    /// are read as 1970 to 2069.
+    fn parse_date(&self, text: &str) -> Option<chrono::NaiveDate> {
+        let apostrophe = text.contains('\'');
+        let normalised = text.replace(['\'', '-', '.'], "/").replace(' ', "");
+        let mut parts = normalised.split('/');
+        let (first, second, year) = (parts.next()?, parts.next()?, parts.next()?);
+        if parts.next().is_some() {
+            return None;
+        }
+
+        let (month, day) = match self.date_order {
+            QifDateOrders::MonthFirst => (first, second),
+            QifDateOrders::DayFirst => (second, first),
+        };
+
+        let year: i32 = year.parse().ok()?;
+        let year = match year {
+            0..=99 if apostrophe => 2000 + year,
+            0..=69 => 2000 + year,
+            70..=99 => 1900 + year,
+            _ => year,
+        };
+
+        chrono::NaiveDate::from_ymd_opt(year, month.parse().ok()?, day.parse().ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QIF: &str = "\
+!Account
+NEveryday
+TBank
+^
+!Type:Bank
+D3/1'25
+T0.00
+POpening Balance
+L[Everyday]
+^
+D3/14'25
+T-1,250.00
+PRent
+LHousing:Rent/Personal
+N1001
+^
+D03/15/2025
+U2500
+MSalary
+L[Savings]
+^
+";
+
+    #[test]
+    fn parse_reads_bank_records() {
+        let rows = QifFormat::default().parse(QIF.as_bytes()).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].line, 11);
+        assert_eq!(
+            rows[0].transaction_date,
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap()
+        );
+        assert_eq!(rows[0].amount, -125_000);
+        assert_eq!(rows[0].category.as_deref(), Some("Housing:Rent"));
+        assert_eq!(rows[1].payee, "Salary");
+        assert_eq!(rows[1].amount, 250_000);
+        assert_eq!(rows[1].category, None);
+    }
+
+    #[test]
+    fn parse_date_handles_orders_and_two_digit_years() {
+        let month_first = QifFormat::new(QifDateOrders::MonthFirst);
+        let day_first = QifFormat::new(QifDateOrders::DayFirst);
+        let date = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d);
+
+        assert_eq!(month_first.parse_date("3/14'25"), date(2025, 3, 14));
+        assert_eq!(month_first.parse_date("12/31/99"), date(1999, 12, 31));
+        assert_eq!(day_first.parse_date("14-03-2025"), date(2025, 3, 14));
+        assert_eq!(day_first.parse_date("14/ 3'05"), date(2005, 3, 14));
+        assert_eq!(month_first.parse_date("14/03/2025"), None);
+    }
+
+    #[test]
+    fn parse_rejects_investment_and_incomplete_records() {
+        let investment = QifFormat::default().parse("!Type:Invst\nD3/14'25\n^\n".as_bytes());
+        let no_amount = QifFormat::default().parse("!Type:Bank\nD3/14'25\nPCafe\n^\n".as_bytes());
+
+        assert!(matches!(investment, Err(DatabaseError::Validation(_))));
+        assert!(
+            matches!(no_amount, Err(DatabaseError::Validation(message)) if message.starts_with("Line 2:"))
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::ImportBatches {
+    /// Rolls back an import, deleting every transaction it created that is
+    /// still in the ledger, all or nothing.
+    ///
+    /// The batch record is deleted too, so the same file can be imported
+    /// again. Transactions edited since they were imported are only deleted
+    /// when `force` is set. Transactions claimed as reimbursable, recorded as
+    /// a repayment or matched to a receipt are never deleted, those links must
+    /// be removed first. A batch is in a user's scope when they own every
+    /// transaction still in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the import batch
+    /// * `force` - Delete transactions that were edited since the import
+    /// * `scope` - The user whose imports may be rolled back
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the deleted transactions, in the order they were imported.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The batch does not exist or is outside `scope` (`DatabaseError::NotFound`)
+    /// - Any of the transactions were edited and `force` is not set, or are
+    ///   linked to a reimbursement or receipt (`DatabaseError::Validation`)
+    /// - Any of the transactions are in a closed period (`DatabaseError::PeriodClosed`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{ImportBatches, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, batch_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let deleted = ImportBatches::rollback(batch_id, false, UserScope::All, pool).await?;
+    /// println!("Rolled back {} transactions", deleted.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Roll back import batch", skip(conn), err)]
+    pub fn rollback<'c, A>(
+        id: domain::RowID,
+        force: bool,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::Transactions>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            let not_found = || {
+                database::DatabaseError::NotFound(format!("Import batch with id {} not found", id))
+            };
+            Self::find_by_id(id, &mut *tx)
+                .await?
+                .ok_or_else(not_found)?;
+
+            let transactions = Self::find_transactions(id, &mut *tx).await?;
+            if !transactions
+                .iter()
+                .all(|transaction| scope.includes(transaction.user_id))
+            {
+                return Err(not_found());
+            }
+
+            let linked = sqlx::query_scalar!(
+                r#"
+                    SELECT COUNT(*) AS "count!: i64"
+                    FROM transaction_imports i
+                    WHERE i.batch_id = ?
+                        AND (
+                            EXISTS (
+                                SELECT 1 FROM reimbursables r
+                                WHERE r.transaction_id = i.transaction_id
+                                    OR r.reimbursed_by = i.transaction_id
+                            )
+                            OR EXISTS (
+                                SELECT 1 FROM receipt_emails e
+                                WHERE e.transaction_id = i.transaction_id
+                            )
+                        )
+                "#,
+                id
+            )
+            .fetch_one(&mut *tx)
+            .observe_one("import.rollback")
+            .await?;
+
+            if linked > 0 {
+                return Err(database::DatabaseError::Validation(format!(
+                    "{} transactions of import batch {} are linked to reimbursements or receipts, unlink them first",
+                    linked, id
+                )));
+            }
+
+            // Imported transactions are created with matching timestamps
+            let edited = transactions
+                .iter()
+                .filter(|transaction| transaction.updated_on > transaction.created_on)
+                .count();
+
+            if edited > 0 && !force {
+                return Err(database::DatabaseError::Validation(format!(
+                    "{} transactions of import batch {} were edited since they were imported, force the rollback to delete them",
+                    edited, id
+                )));
+            }
+
+            for transaction in &transactions {
+                // Already deleted as the other transaction of a transfer
+                if database::Transactions::find_by_id(transaction.id, scope, &mut *tx)
+                    .await?
+                    .is_none()
+                {
+                    continue;
+                }
+                database::Transactions::delete_by_id(transaction.id, scope, &mut *tx).await?;
+            }
+
+            // Rows of transactions deleted earlier went with them, clear any
+            // left before the batch itself
+            sqlx::query!("DELETE FROM transaction_imports WHERE batch_id = ?", id)
+                .execute(&mut *tx)
+                .observe("import.rollback")
+                .await?;
+            sqlx::query!("DELETE FROM import_batches WHERE id = ?", id)
+                .execute(&mut *tx)
+                .observe("import.rollback")
+                .await?;
+
+            tx.commit().await?;
+
+            tracing::info!(
+                "Rolled back import batch {}, deleting {} transactions ({} edited)",
+                id,
+                transactions.len(),
+                edited
+            );
+
+            Ok(transactions)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::insert::tests::{Result, file};
+
+    const CSV: &str = "2025-03-14,Corner Cafe,-12.50\n2025-03-15,Employer,2500\n";
+
+    async fn count_transactions(pool: &sqlx::SqlitePool) -> Result<i64> {
+        Ok(sqlx::query_scalar("SELECT COUNT(*) FROM transactions")
+            .fetch_one(pool)
+            .await?)
+    }
+
+    #[sqlx::test]
+    async fn rollback_deletes_transactions_and_batch(pool: sqlx::SqlitePool) -> Result<()> {
+        let kept = database::Transactions::mock().insert(&pool).await?;
+        let (batch, rows) = file(CSV, None);
+        let summary = database::ImportSummary::import(&batch, &rows, false, &pool).await?;
+
+        let deleted =
+            database::ImportBatches::rollback(batch.id, false, database::UserScope::All, &pool)
+                .await?;
+
+        assert_eq!(deleted, summary.inserted);
+        assert_eq!(count_transactions(&pool).await?, 1);
+        assert!(
+            database::Transactions::find_by_id(kept.id, database::UserScope::All, &pool)
+                .await?
+                .is_some()
+        );
+        assert_eq!(
+            database::ImportBatches::find_by_id(batch.id, &pool).await?,
+            None
+        );
+
+        // The same file is not recognised as imported any more
+        let again = database::ImportSummary::import(&batch, &rows, false, &pool).await?;
+        assert_eq!(again.inserted.len(), 2);
+
+        let missing = database::ImportBatches::rollback(
+            domain::RowID::new(),
+            false,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+        assert!(matches!(missing, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn rollback_of_edited_transactions_must_be_forced(pool: sqlx::SqlitePool) -> Result<()> {
+        let (batch, rows) = file(CSV, None);
+        let summary = database::ImportSummary::import(&batch, &rows, false, &pool).await?;
+        let mut edited = summary.inserted[0].clone();
+        edited.description = Some("Flat white".to_string());
+        edited.update(database::UserScope::All, &pool).await?;
+
+        let result =
+            database::ImportBatches::rollback(batch.id, false, database::UserScope::All, &pool)
+                .await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        assert_eq!(count_transactions(&pool).await?, 2);
+
+        let deleted =
+            database::ImportBatches::rollback(batch.id, true, database::UserScope::All, &pool)
+                .await?;
+
+        assert_eq!(deleted.len(), 2);
+        assert_eq!(count_transactions(&pool).await?, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn rollback_keeps_reimbursable_and_locked_transactions(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let (batch, rows) = file(CSV, None);
+        let summary = database::ImportSummary::import(&batch, &rows, false, &pool).await?;
+        let claim = database::Reimbursables::for_transaction(summary.inserted[0].id)
+            .insert(&pool)
+            .await?;
+
+        let result =
+            database::ImportBatches::rollback(batch.id, true, database::UserScope::All, &pool)
+                .await;
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        database::Reimbursables::delete_by_id(claim.id, &pool).await?;
+        database::PeriodClosings::close(
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+            None,
+            &pool,
+        )
+        .await?;
+
+        let result =
+            database::ImportBatches::rollback(batch.id, true, database::UserScope::All, &pool)
+                .await;
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::PeriodClosed(_))
+        ));
+        assert_eq!(count_transactions(&pool).await?, 2);
+        assert!(
+            database::ImportBatches::find_by_id(batch.id, &pool)
+                .await?
+                .is_some()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::JournalEntries {
+    /// Deletes this journal entry and all of its splits from the database.
+    ///
+    /// This is a convenience wrapper around [`delete_by_id`](Self::delete_by_id).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The journal entry no longer exists in the database
+    /// - Database connection or transaction commit fails
+    #[tracing::instrument(
+        name = "Delete journal entry instance from database",
+        skip(self, conn),
+        fields(id = %self.id),
+        err
+    )]
+    pub async fn delete<'c, A>(&self, conn: A) -> DatabaseResult<()>
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite>,
+    {
+        let mut conn = conn.acquire().await?;
+
+        Self::delete_by_id(self.id, &mut *conn).await
+    }
+
+    /// Deletes a journal entry and all of its splits by entry ID.
+    ///
+    /// The splits and the entry are removed in a single transaction, so an entry
+    /// is never left with a partial set of splits.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the journal entry to delete
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the entry was deleted, or a `DatabaseError` if it fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No journal entry with the given ID exists (`DatabaseError::NotFound`)
+    /// - Database connection or transaction commit fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::JournalEntries;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, entry_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// JournalEntries::delete_by_id(entry_id, pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Delete journal entry from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub async fn delete_by_id<'c, A>(id: domain::RowID, conn: A) -> DatabaseResult<()>
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite>,
+    {
+        let mut tx = conn.begin().await?;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM journal_splits
+                WHERE entry_id = ?
+            "#,
+            id
+        )
+        .execute(&mut *tx)
+        .observe("journal.delete_by_id")
+        .await?;
+
+        let rows_affected = sqlx::query!(
+            r#"
+                DELETE FROM journal_entries
+                WHERE id = ?
+            "#,
+            id
+        )
+        .execute(&mut *tx)
+        .observe("journal.delete_by_id")
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(database::DatabaseError::NotFound(format!(
+                "Journal entry with id {} not found",
+                id
+            )));
+        }
+
+        tx.commit().await?;
+
+        tracing::info!("Deleted journal entry {} from database", id);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::journal::insert::tests::{Result, insert_categories};
+
+    #[sqlx::test]
+    async fn delete_removes_entry_and_splits(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let (debit, credit) = insert_categories(&pool).await?;
+        let entry = database::JournalEntries::mock(debit, credit)
+            .insert(&pool)
+            .await?;
+        let other = database::JournalEntries::mock(debit, credit)
+            .insert(&pool)
+            .await?;
+
+        entry.delete(&pool).await?;
+
+        assert!(
+            database::JournalEntries::find_by_id(entry.id, &pool)
+                .await?
+                .is_none()
+        );
+        assert!(
+            database::JournalEntries::find_by_id(other.id, &pool)
+                .await?
+                .is_some()
+        );
+
+        let split_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM journal_splits")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(split_count, 2);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn delete_by_id_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let result = database::JournalEntries::delete_by_id(domain::RowID::new(), &pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
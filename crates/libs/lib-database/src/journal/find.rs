@@ -0,0 +1,253 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::JournalEntries {
+    /// Finds a journal entry, including its splits, by its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the journal entry to find
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(JournalEntries)` if the entry exists, or `None` if not found.
+    /// Returns a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::JournalEntries;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let entry_id = RowID::new();
+    ///
+    /// match JournalEntries::find_by_id(entry_id, pool).await? {
+    ///     Some(entry) => println!("Found entry with {} splits", entry.splits.len()),
+    ///     None => println!("Journal entry not found"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find journal entry by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub async fn find_by_id<'c, A>(id: domain::RowID, conn: A) -> DatabaseResult<Option<Self>>
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite>,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let entry = sqlx::query!(
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    entry_date      AS "entry_date!: chrono::NaiveDate",
+                    description,
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM journal_entries
+                WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("journal.find_by_id")
+        .await?;
+
+        let Some(entry) = entry else {
+            return Ok(None);
+        };
+
+        let splits = Self::find_splits(entry.id, &mut conn).await?;
+
+        Ok(Some(Self {
+            id: entry.id,
+            entry_date: entry.entry_date,
+            description: entry.description,
+            splits,
+            created_on: entry.created_on,
+            updated_on: entry.updated_on,
+        }))
+    }
+
+    /// Retrieves all journal entries that post to the given category.
+    ///
+    /// Each returned entry includes all of its splits, not only the split for
+    /// the requested category. Entries are ordered by entry date, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `category_id` - The category to find postings for
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of journal entries (empty if there are none), or a
+    /// `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::JournalEntries;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, groceries: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let entries = JournalEntries::find_by_category(groceries, pool).await?;
+    /// println!("Groceries has {} journal entries", entries.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find journal entries by category",
+        skip(conn),
+        fields(category_id = %category_id),
+        err
+    )]
+    pub async fn find_by_category<'c, A>(
+        category_id: domain::RowID,
+        conn: A,
+    ) -> DatabaseResult<Vec<Self>>
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite>,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let entries = sqlx::query!(
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    entry_date      AS "entry_date!: chrono::NaiveDate",
+                    description,
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM journal_entries
+                WHERE id IN (SELECT entry_id FROM journal_splits WHERE category_id = ?)
+                ORDER BY entry_date ASC, created_on ASC
+            "#,
+            category_id
+        )
+        .fetch_all(&mut *conn)
+        .observe("journal.find_by_category")
+        .await?;
+
+        let mut journal_entries = Vec::with_capacity(entries.len());
+
+        for entry in entries {
+            let splits = Self::find_splits(entry.id, &mut conn).await?;
+
+            journal_entries.push(Self {
+                id: entry.id,
+                entry_date: entry.entry_date,
+                description: entry.description,
+                splits,
+                created_on: entry.created_on,
+                updated_on: entry.updated_on,
+            });
+        }
+
+        Ok(journal_entries)
+    }
+
+    /// Retrieves the splits belonging to a journal entry, ordered by amount so
+    /// debits are listed before credits.
+    async fn find_splits(
+        entry_id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Vec<database::JournalSplits>> {
+        let splits = sqlx::query_as!(
+            database::JournalSplits,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    entry_id        AS "entry_id!: domain::RowID",
+                    category_id     AS "category_id!: domain::RowID",
+                    amount          AS "amount!: i64",
+                    memo
+                FROM journal_splits
+                WHERE entry_id = ?
+                ORDER BY amount DESC
+            "#,
+            entry_id
+        )
+        .fetch_all(&mut *conn)
+        .observe("journal.find_splits")
+        .await?;
+
+        Ok(splits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::journal::insert::tests::{Result, insert_categories};
+
+    #[sqlx::test]
+    async fn find_by_id_returns_entry_with_splits(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let (debit, credit) = insert_categories(&pool).await?;
+        let entry = database::JournalEntries::mock(debit, credit)
+            .insert(&pool)
+            .await?;
+
+        let found = database::JournalEntries::find_by_id(entry.id, &pool)
+            .await?
+            .expect("journal entry should exist");
+
+        assert_eq!(found, entry);
+        assert_eq!(found.splits[0].category_id, debit);
+        assert_eq!(found.splits[1].category_id, credit);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let found = database::JournalEntries::find_by_id(domain::RowID::new(), &pool).await?;
+
+        assert!(found.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_by_category_returns_matching_entries(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let (groceries, credit_card) = insert_categories(&pool).await?;
+        let salary = database::Categories::mock().insert(&pool).await?.id;
+
+        database::JournalEntries::mock(groceries, credit_card)
+            .insert(&pool)
+            .await?;
+        database::JournalEntries::mock(groceries, credit_card)
+            .insert(&pool)
+            .await?;
+        database::JournalEntries::mock(credit_card, salary)
+            .insert(&pool)
+            .await?;
+
+        let groceries_entries =
+            database::JournalEntries::find_by_category(groceries, &pool).await?;
+        let credit_card_entries =
+            database::JournalEntries::find_by_category(credit_card, &pool).await?;
+        let unused_entries =
+            database::JournalEntries::find_by_category(domain::RowID::new(), &pool).await?;
+
+        assert_eq!(groceries_entries.len(), 2);
+        assert_eq!(credit_card_entries.len(), 3);
+        assert!(unused_entries.is_empty());
+        assert!(
+            groceries_entries
+                .iter()
+                .all(|entry| entry.splits.len() == 2)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,299 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::JournalEntries {
+    /// Inserts a new journal entry and its splits into the database.
+    ///
+    /// The entry header and every split are written inside a single transaction.
+    /// The double-entry invariant is checked twice: once in memory with
+    /// [`validate`](Self::validate) before anything is written, and again against
+    /// the rows written in the transaction before it is committed. If either
+    /// check fails nothing is persisted.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the inserted journal entry with its splits as read back from the
+    /// database, or a `DatabaseError` if the insertion fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The splits do not balance or fewer than two splits are given (`DatabaseError::Validation`)
+    /// - A split references a category that does not exist (foreign key violation)
+    /// - An entry or split with the same id already exists
+    /// - Database connection or transaction commit fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::JournalEntries;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, groceries: RowID, credit_card: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// let entry = JournalEntries::new(date, Some("Weekly shop".to_string()))
+    ///     .with_split(groceries, 8_560, None)
+    ///     .with_split(credit_card, -8_560, None);
+    ///
+    /// let inserted = entry.insert(pool).await?;
+    /// assert_eq!(inserted.splits.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert new journal entry into database",
+        skip(self, conn),
+        fields(
+            id = %self.id,
+            entry_date = %self.entry_date,
+            splits = self.splits.len(),
+        ),
+        err
+    )]
+    pub async fn insert<'c, A>(&self, conn: A) -> DatabaseResult<Self>
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite>,
+    {
+        self.validate()?;
+
+        let mut tx = conn.begin().await?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO journal_entries (id, entry_date, description, created_on, updated_on)
+                VALUES (?, ?, ?, ?, ?)
+            "#,
+            self.id,
+            self.entry_date,
+            self.description,
+            self.created_on,
+            self.updated_on
+        )
+        .execute(&mut *tx)
+        .observe("journal.insert")
+        .await?;
+
+        self.insert_splits(&mut tx).await?;
+        Self::ensure_balanced(self.id, &mut tx).await?;
+
+        let inserted = Self::find_by_id(self.id, &mut *tx).await?.ok_or_else(|| {
+            database::DatabaseError::NotFound(format!(
+                "Journal entry with id {} not found after insert",
+                self.id
+            ))
+        })?;
+
+        tx.commit().await?;
+
+        tracing::info!("New journal entry inserted into the database.");
+
+        Ok(inserted)
+    }
+
+    /// Writes the splits of this entry using the given connection.
+    ///
+    /// Callers are expected to run this inside a transaction and follow it with
+    /// [`ensure_balanced`](Self::ensure_balanced) before committing.
+    pub(super) async fn insert_splits(
+        &self,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<()> {
+        for split in &self.splits {
+            sqlx::query!(
+                r#"
+                    INSERT INTO journal_splits (id, entry_id, category_id, amount, memo)
+                    VALUES (?, ?, ?, ?, ?)
+                "#,
+                split.id,
+                self.id,
+                split.category_id,
+                split.amount,
+                split.memo
+            )
+            .execute(&mut *conn)
+            .observe("journal.insert_splits")
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the splits persisted for an entry sum to zero.
+    ///
+    /// This reads the splits back through the same connection, so when called
+    /// inside a transaction it sees the uncommitted rows and an error leaves the
+    /// transaction to roll back when dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if fewer than two splits exist for the
+    /// entry or their amounts do not sum to zero.
+    pub(super) async fn ensure_balanced(
+        entry_id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<()> {
+        let totals = sqlx::query!(
+            r#"
+                SELECT
+                    COUNT(*)                    AS "split_count!: i64",
+                    COALESCE(SUM(amount), 0)    AS "total!: i64"
+                FROM journal_splits
+                WHERE entry_id = ?
+            "#,
+            entry_id
+        )
+        .fetch_one(&mut *conn)
+        .observe_one("journal.ensure_balanced")
+        .await?;
+
+        if totals.split_count < 2 {
+            return Err(database::DatabaseError::Validation(format!(
+                "Journal entry {} must have at least two splits, found {}",
+                entry_id, totals.split_count
+            )));
+        }
+
+        if totals.total != 0 {
+            return Err(database::DatabaseError::Validation(format!(
+                "Journal entry {} is unbalanced, splits sum to {} instead of zero",
+                entry_id, totals.total
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    // Bring module into test scope
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    /// Inserts two mock categories to post between.
+    pub(crate) async fn insert_categories(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<(domain::RowID, domain::RowID)> {
+        let debit = database::Categories::mock().insert(pool).await?;
+        let credit = database::Categories::mock().insert(pool).await?;
+
+        Ok((debit.id, credit.id))
+    }
+
+    async fn count_rows(pool: &sqlx::Pool<sqlx::Sqlite>) -> Result<(i64, i64)> {
+        let entries: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM journal_entries")
+            .fetch_one(pool)
+            .await?;
+        let splits: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM journal_splits")
+            .fetch_one(pool)
+            .await?;
+
+        Ok((entries, splits))
+    }
+
+    #[sqlx::test]
+    async fn insert_balanced_entry_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let (debit, credit) = insert_categories(&pool).await?;
+        let entry = database::JournalEntries::mock(debit, credit);
+
+        let inserted = entry.insert(&pool).await?;
+
+        assert_eq!(inserted.id, entry.id);
+        assert_eq!(inserted.entry_date, entry.entry_date);
+        assert_eq!(inserted.description, entry.description);
+        assert_eq!(inserted.splits.len(), 2);
+        assert!(inserted.is_balanced());
+        assert_eq!(count_rows(&pool).await?, (1, 2));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_multi_split_entry_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let (groceries, credit_card) = insert_categories(&pool).await?;
+        let household = database::Categories::mock().insert(&pool).await?.id;
+
+        let entry = database::JournalEntries::new(chrono::Utc::now().date_naive(), None)
+            .with_split(groceries, 6_000, Some("food".to_string()))
+            .with_split(household, 2_560, Some("cleaning".to_string()))
+            .with_split(credit_card, -8_560, None);
+
+        let inserted = entry.insert(&pool).await?;
+
+        assert_eq!(inserted.splits.len(), 3);
+        assert!(inserted.is_balanced());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_unbalanced_entry_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let (debit, credit) = insert_categories(&pool).await?;
+        let entry = database::JournalEntries::new(chrono::Utc::now().date_naive(), None)
+            .with_split(debit, 1_000, None)
+            .with_split(credit, -900, None);
+
+        let result = entry.insert(&pool).await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        assert_eq!(count_rows(&pool).await?, (0, 0));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_with_unknown_category_rolls_back(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let (debit, _) = insert_categories(&pool).await?;
+        let entry = database::JournalEntries::mock(debit, domain::RowID::new());
+
+        let result = entry.insert(&pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+        assert_eq!(count_rows(&pool).await?, (0, 0));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn ensure_balanced_checks_uncommitted_rows(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let (debit, credit) = insert_categories(&pool).await?;
+        let mut entry = database::JournalEntries::mock(debit, credit);
+        // Bypass the in-memory check so only the database check can catch it.
+        entry.splits[1].amount += 1;
+
+        let mut tx = pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO journal_entries (id, entry_date, description, created_on, updated_on) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(entry.id)
+        .bind(entry.entry_date)
+        .bind(&entry.description)
+        .bind(entry.created_on)
+        .bind(entry.updated_on)
+        .execute(&mut *tx)
+        .await?;
+        entry.insert_splits(&mut tx).await?;
+
+        let result = database::JournalEntries::ensure_balanced(entry.id, &mut tx).await;
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        tx.rollback().await?;
+        assert_eq!(count_rows(&pool).await?, (0, 0));
+
+        Ok(())
+    }
+}
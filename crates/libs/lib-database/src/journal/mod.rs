@@ -0,0 +1,21 @@
+//! # Journal Database Module
+//!
+//! Provides double-entry journal storage. A journal entry records a single
+//! financial event as two or more splits, each debiting (positive amount) or
+//! crediting (negative amount) a category in minor units.
+//!
+//! The core invariant is that the splits of an entry sum to zero. It is
+//! checked in memory before any write and again against the written rows
+//! inside the same transaction, so an unbalanced entry is never committed.
+
+mod delete;
+mod find;
+mod insert;
+mod model;
+mod update;
+
+/// Database model for a journal entry and its splits.
+pub use model::JournalEntries;
+
+/// Database row model for a single debit or credit within a journal entry.
+pub use model::JournalSplits;
@@ -0,0 +1,257 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// A double-entry journal entry and the splits that balance it.
+///
+/// Each entry records a single financial event (e.g. paying for groceries with
+/// a credit card) as two or more [`JournalSplits`]. Every split debits or
+/// credits one category, and the split amounts must sum to zero.
+#[derive(Debug, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct JournalEntries {
+    pub id: domain::RowID,
+    pub entry_date: chrono::NaiveDate,
+    pub description: Option<String>,
+    pub splits: Vec<JournalSplits>,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+/// One leg of a [`JournalEntries`] posting.
+///
+/// Amounts are signed minor units (cents): positive amounts debit the
+/// category, negative amounts credit it.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct JournalSplits {
+    pub id: domain::RowID,
+    pub entry_id: domain::RowID,
+    pub category_id: domain::RowID,
+    pub amount: i64,
+    pub memo: Option<String>,
+}
+
+impl database::JournalEntries {
+    /// Creates a new journal entry with no splits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::JournalEntries;
+    /// use lib_domain::RowID;
+    ///
+    /// let groceries = RowID::new();
+    /// let credit_card = RowID::new();
+    ///
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// let entry = JournalEntries::new(date, Some("Groceries".to_string()))
+    ///     .with_split(groceries, 4_250, None)
+    ///     .with_split(credit_card, -4_250, None);
+    ///
+    /// assert!(entry.is_balanced());
+    /// ```
+    pub fn new(entry_date: chrono::NaiveDate, description: Option<String>) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            entry_date,
+            description,
+            splits: Vec::new(),
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Adds a split debiting (positive `amount`) or crediting (negative
+    /// `amount`) the given category.
+    #[must_use]
+    pub fn with_split(
+        mut self,
+        category_id: domain::RowID,
+        amount: i64,
+        memo: Option<String>,
+    ) -> Self {
+        self.splits.push(database::JournalSplits {
+            id: domain::RowID::new(),
+            entry_id: self.id,
+            category_id,
+            amount,
+            memo,
+        });
+        self
+    }
+
+    /// Returns the sum of all split amounts, or `None` if the sum overflows.
+    pub fn splits_total(&self) -> Option<i64> {
+        self.splits
+            .iter()
+            .try_fold(0_i64, |total, split| total.checked_add(split.amount))
+    }
+
+    /// Returns `true` when the splits sum to zero.
+    pub fn is_balanced(&self) -> bool {
+        self.splits_total() == Some(0)
+    }
+
+    /// Checks the double-entry invariants before the entry is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if:
+    /// - The entry has fewer than two splits
+    /// - Any split has a zero amount
+    /// - Any split belongs to a different entry
+    /// - The split amounts overflow or do not sum to zero
+    pub fn validate(&self) -> DatabaseResult<()> {
+        if self.splits.len() < 2 {
+            return Err(DatabaseError::Validation(format!(
+                "Journal entry {} must have at least two splits, found {}",
+                self.id,
+                self.splits.len()
+            )));
+        }
+
+        if let Some(split) = self.splits.iter().find(|split| split.amount == 0) {
+            return Err(DatabaseError::Validation(format!(
+                "Journal split {} has a zero amount",
+                split.id
+            )));
+        }
+
+        if let Some(split) = self.splits.iter().find(|split| split.entry_id != self.id) {
+            return Err(DatabaseError::Validation(format!(
+                "Journal split {} belongs to entry {}, not {}",
+                split.id, split.entry_id, self.id
+            )));
+        }
+
+        match self.splits_total() {
+            Some(0) => Ok(()),
+            Some(total) => Err(DatabaseError::Validation(format!(
+                "Journal entry {} is unbalanced, splits sum to {} instead of zero",
+                self.id, total
+            ))),
+            None => Err(DatabaseError::Validation(format!(
+                "Journal entry {} split amounts overflow",
+                self.id
+            ))),
+        }
+    }
+
+    /// Generates a balanced mock journal entry posting between two categories.
+    ///
+    /// The categories are not inserted, so tests that write the entry must
+    /// insert categories with matching ids first.
+    #[cfg(test)]
+    pub fn mock(debit_category: domain::RowID, credit_category: domain::RowID) -> Self {
+        use fake::Fake;
+        use fake::faker::lorem::en::Sentence;
+
+        let amount: i64 = (1..1_000_000).fake();
+        let description: String = Sentence(2..6).fake();
+
+        Self::new(chrono::Utc::now().date_naive(), Some(description))
+            .with_split(debit_category, amount, None)
+            .with_split(credit_category, -amount, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry() -> database::JournalEntries {
+        database::JournalEntries::new(
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap(),
+            Some("Groceries".to_string()),
+        )
+    }
+
+    #[test]
+    fn with_split_links_split_to_entry() {
+        let entry = entry().with_split(domain::RowID::new(), 100, Some("milk".to_string()));
+
+        assert_eq!(entry.splits.len(), 1);
+        assert_eq!(entry.splits[0].entry_id, entry.id);
+        assert_eq!(entry.splits[0].memo.as_deref(), Some("milk"));
+    }
+
+    #[test]
+    fn validate_accepts_balanced_splits() {
+        let entry = entry()
+            .with_split(domain::RowID::new(), 3_000, None)
+            .with_split(domain::RowID::new(), 1_250, None)
+            .with_split(domain::RowID::new(), -4_250, None);
+
+        assert!(entry.is_balanced());
+        assert_eq!(entry.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_unbalanced_splits() {
+        let entry = entry()
+            .with_split(domain::RowID::new(), 4_250, None)
+            .with_split(domain::RowID::new(), -4_000, None);
+
+        assert!(!entry.is_balanced());
+        assert_eq!(entry.splits_total(), Some(250));
+        assert!(matches!(
+            entry.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_single_split() {
+        let entry = entry().with_split(domain::RowID::new(), 0, None);
+
+        assert!(matches!(
+            entry.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_zero_amount_split() {
+        let entry = entry()
+            .with_split(domain::RowID::new(), 0, None)
+            .with_split(domain::RowID::new(), 0, None);
+
+        assert!(matches!(
+            entry.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_split_from_another_entry() {
+        let mut entry = entry()
+            .with_split(domain::RowID::new(), 100, None)
+            .with_split(domain::RowID::new(), -100, None);
+        entry.splits[1].entry_id = domain::RowID::new();
+
+        assert!(matches!(
+            entry.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_overflowing_splits() {
+        let entry = entry()
+            .with_split(domain::RowID::new(), i64::MAX, None)
+            .with_split(domain::RowID::new(), 1, None);
+
+        assert_eq!(entry.splits_total(), None);
+        assert!(matches!(
+            entry.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn mock_is_balanced() {
+        let entry = database::JournalEntries::mock(domain::RowID::new(), domain::RowID::new());
+
+        assert_eq!(entry.validate(), Ok(()));
+    }
+}
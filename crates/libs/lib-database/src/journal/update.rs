@@ -0,0 +1,218 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::JournalEntries {
+    /// Updates a journal entry and replaces its splits.
+    ///
+    /// The entry header is updated, the existing splits are removed, and the
+    /// splits on `self` are written in their place, all inside one transaction.
+    /// As with [`insert`](Self::insert), the splits must balance both before the
+    /// write and once written, otherwise the transaction is rolled back and the
+    /// stored entry is left unchanged. The `updated_on` timestamp is set to now.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated journal entry as read back from the database, or a
+    /// `DatabaseError` if the update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The journal entry does not exist (`DatabaseError::NotFound`)
+    /// - The splits do not balance or fewer than two splits are given (`DatabaseError::Validation`)
+    /// - A split references a category that does not exist (foreign key violation)
+    /// - Database connection or transaction commit fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::JournalEntries;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, entry_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut entry = JournalEntries::find_by_id(entry_id, pool).await?.unwrap();
+    ///
+    /// // Correct the amount on both legs so the entry stays balanced
+    /// entry.splits[0].amount = 9_000;
+    /// entry.splits[1].amount = -9_000;
+    ///
+    /// let updated = entry.update(pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Update journal entry in database",
+        skip(self, conn),
+        fields(
+            id = %self.id,
+            entry_date = %self.entry_date,
+            splits = self.splits.len(),
+        ),
+        err
+    )]
+    pub async fn update<'c, A>(&self, conn: A) -> DatabaseResult<Self>
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite>,
+    {
+        self.validate()?;
+
+        let updated_on = chrono::Utc::now();
+
+        let mut tx = conn.begin().await?;
+
+        let rows_affected = sqlx::query!(
+            r#"
+                UPDATE journal_entries
+                SET entry_date = ?, description = ?, updated_on = ?
+                WHERE id = ?
+            "#,
+            self.entry_date,
+            self.description,
+            updated_on,
+            self.id
+        )
+        .execute(&mut *tx)
+        .observe("journal.update")
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(database::DatabaseError::NotFound(format!(
+                "Journal entry with id {} not found",
+                self.id
+            )));
+        }
+
+        sqlx::query!(
+            r#"
+                DELETE FROM journal_splits
+                WHERE entry_id = ?
+            "#,
+            self.id
+        )
+        .execute(&mut *tx)
+        .observe("journal.update")
+        .await?;
+
+        self.insert_splits(&mut tx).await?;
+        Self::ensure_balanced(self.id, &mut tx).await?;
+
+        let updated = Self::find_by_id(self.id, &mut *tx).await?.ok_or_else(|| {
+            database::DatabaseError::NotFound(format!(
+                "Journal entry with id {} not found after update",
+                self.id
+            ))
+        })?;
+
+        tx.commit().await?;
+
+        tracing::info!("Journal entry updated in the database.");
+
+        Ok(updated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::journal::insert::tests::{Result, insert_categories};
+    use lib_domain as domain;
+
+    #[sqlx::test]
+    async fn update_replaces_splits(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let (groceries, credit_card) = insert_categories(&pool).await?;
+        let household = database::Categories::mock().insert(&pool).await?.id;
+        let inserted = database::JournalEntries::mock(groceries, credit_card)
+            .insert(&pool)
+            .await?;
+
+        let mut entry = inserted.clone();
+        entry.description = Some("Split the weekly shop".to_string());
+        entry.splits.clear();
+        let entry = entry
+            .with_split(groceries, 7_000, None)
+            .with_split(household, 1_500, None)
+            .with_split(credit_card, -8_500, None);
+
+        let updated = entry.update(&pool).await?;
+
+        assert_eq!(
+            updated.description.as_deref(),
+            Some("Split the weekly shop")
+        );
+        assert_eq!(updated.splits.len(), 3);
+        assert!(updated.is_balanced());
+        assert!(updated.updated_on >= inserted.updated_on);
+
+        let split_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM journal_splits")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(split_count, 3);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_unbalanced_leaves_entry_unchanged(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let (debit, credit) = insert_categories(&pool).await?;
+        let inserted = database::JournalEntries::mock(debit, credit)
+            .insert(&pool)
+            .await?;
+
+        let mut entry = inserted.clone();
+        entry.description = Some("Unbalanced".to_string());
+        entry.splits[0].amount += 100;
+
+        let result = entry.update(&pool).await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        let stored = database::JournalEntries::find_by_id(inserted.id, &pool)
+            .await?
+            .expect("journal entry should still exist");
+        assert_eq!(stored, inserted);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_with_unknown_category_rolls_back(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let (debit, credit) = insert_categories(&pool).await?;
+        let inserted = database::JournalEntries::mock(debit, credit)
+            .insert(&pool)
+            .await?;
+
+        let mut entry = inserted.clone();
+        entry.splits[1].category_id = domain::RowID::new();
+
+        let result = entry.update(&pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+        let stored = database::JournalEntries::find_by_id(inserted.id, &pool)
+            .await?
+            .expect("journal entry should still exist");
+        assert_eq!(stored, inserted);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let (debit, credit) = insert_categories(&pool).await?;
+        let entry = database::JournalEntries::mock(debit, credit);
+
+        let result = entry.update(&pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
@@ -8,9 +8,74 @@
 //! The database layer is designed following domain-driven design principles, with
 //! separate modules for different concerns:
 //!
-//! - Connection management and pooling ([`DatabasePool`])
+//! - Connection management and pooling ([`DatabasePool`]), with embedded
+//!   schema migrations ([`MigrationStatus`]), and fixtures of selected rows
+//!   for tests ([`Fixture`])
 //! - Standardized error types ([`DatabaseError`], [`DatabaseResult`])
-//! - Domain models like financial categories ([`Category`])
+//! - Users sharing one server ([`Users`]), each owning their own categories
+//!   and transactions, read and changed within a [`UserScope`]
+//! - Domain models like financial categories ([`Categories`]) arranged in a
+//!   parent/child hierarchy ([`CategoryTree`]), double-entry
+//!   journal entries ([`JournalEntries`]), transactions ([`Transactions`]),
+//!   optionally split across categories ([`TransactionSplits`]), and the
+//!   accounts they are posted to ([`Accounts`]), each in its own currency
+//!   with daily exchange rates between them ([`ExchangeRates`]), and
+//!   transfers between accounts ([`Transfers`])
+//! - Balances recorded by hand for accounts kept outside the ledger, such as
+//!   a pension fund ([`BalanceSnapshots`]), and balances read off a statement
+//!   to check the ledger against ([`BalanceAssertions`])
+//! - Crypto wallet holdings ([`CryptoHoldings`]) to eighteen decimal places,
+//!   valued at daily asset prices ([`AssetPrices`])
+//! - Return window and warranty reminders for purchases ([`ExpiryRule`])
+//! - Tags that cross-cut transactions beyond their category ([`Tags`]),
+//!   suggested as they are typed ([`TagSuggestion`])
+//! - Monthly and annual budgets per category ([`Budgets`]), compared with
+//!   actual spending over a month or financial year ([`BudgetComparison`]),
+//!   or as a tree of categories with spending rolled up ([`BudgetVariance`])
+//! - Savings goals ([`Goals`]) linked to an account or category, with their
+//!   progress towards a target amount and date ([`GoalProgress`])
+//! - Reimbursable expenses and a mileage log ([`Reimbursables`], [`MileageLogs`]),
+//!   reported on until they are paid back ([`OutstandingReimbursement`])
+//! - Aggregate reports ([`Reports`]): spending by category, income against
+//!   expenses by month, net worth over time with a breakdown per account
+//!   ([`NetWorthHistoryPoint`]) and account balances
+//! - Recurring transaction templates ([`RecurringTransactions`]) that create
+//!   posted or draft transactions as they fall due, reminded about once
+//!   before they do ([`BillReminder`])
+//! - Importing bank exports ([`CsvMapping`], [`QifFormat`], [`ImportSummary`]) without
+//!   duplicating transactions that were already imported, cleaning up raw
+//!   payees with payee aliases ([`PayeeAliases`], [`PayeeNormaliser`])
+//! - Exporting categories, accounts and transactions as CSV or JSON Lines
+//!   ([`LedgerExport`]), or the whole ledger as a GnuCash book
+//!   ([`GnuCashExport`]) or a Beancount or hledger journal ([`PlainTextExport`])
+//! - Merchant enrichment from a pluggable [`MerchantProvider`], stored per payee
+//!   ([`Merchants`]) and used to suggest categories ([`CategorySuggestion`])
+//! - Receipt images and PDF invoices attached to transactions ([`Attachments`]),
+//!   stored once per content hash
+//! - Receipts forwarded by email ([`ReceiptEmails`]), read for their merchant
+//!   and total ([`ReceiptDetails`]) and turned into draft transactions
+//! - Reconciling accounts against their statements ([`Reconciliations`]),
+//!   after ticking transactions off as cleared ([`ClearedStatus`])
+//! - Period closing ([`PeriodClosings`]), which locks transactions in finished
+//!   months and tax years against modification
+//! - Full-text search over categories and transactions, ranked, with prefix
+//!   and phrase matching
+//! - An audit log of every insert, update and delete ([`AuditLog`]), with the
+//!   actor who made it and the row before and after
+//! - Row version history, kept by database triggers, so categories and account
+//!   balances can be read back exactly as they were recorded at a past date
+//! - What a ledger holds ([`Usage`]), for quotas and usage reporting
+//! - A demo ledger for public sandbox servers ([`DemoData`]), reset on a
+//!   schedule, and a standard chart of categories for a new ledger, with
+//!   optional demo transactions ([`SeedData`])
+//! - Consistency checks across the whole ledger ([`LedgerVerification`]),
+//!   from journal balances to the search index, and a round trip of the
+//!   export through an empty database ([`RoundTrip`])
+//! - Typed events announcing changes to categories and transactions on an
+//!   [`EventBus`] once they are committed ([`DataEvent`], [`DataChanges`]),
+//!   with a log of their webhook deliveries ([`WebhookDeliveries`])
+//! - Latency and row count metrics for every query, labelled by the operation
+//!   that ran it ([`describe_query_metrics`])
 //!
 //! ## Architecture
 //!
@@ -18,16 +83,46 @@
 //!   connection pools with centralized error handling and lifecycle management.
 //! - **Error Handling**: All database operations return [`DatabaseResult<T>`] for
 //!   consistent error propagation using [`DatabaseError`] variants.
-//! - **Domain Models**: Structs like [`Category`] represent business entities with
+//! - **Domain Models**: Structs like [`Categories`] represent business entities with
 //!   validation and conversion logic.
 //!
+//! ## Database Backend
+//!
+//! Only SQLite is supported. Queries are checked against the SQLite schema at
+//! compile time with SQLx's `query!` macros, and the schema leans on SQLite
+//! features: triggers written in SQLite's dialect keep the audit log and row
+//! history, dates are stored as text and compared with `strftime`, and search
+//! uses FTS5 virtual tables. [`DatabasePool::connect`] rejects any other URL.
+//!
+//! A PostgreSQL backend behind a `postgres` cargo feature has been declined.
+//! It would need a second set of migrations and queries alongside these,
+//! rather than a change to the types the functions are generic over, and
+//! every change to the schema would then be made twice. Sharing a ledger
+//! between devices means sharing the SQLite file, through the server.
+//!
+//! ## Models Rather Than Repositories
+//!
+//! Each model's functions are called directly, such as
+//! [`Categories::find_by_id`]. A `Repository<T>` trait over categories,
+//! transactions and accounts has been declined. The handlers that would use
+//! it scope every lookup to a user, write several models in one
+//! [`with_transaction`], and publish events as they go, none of which a
+//! generic insert, find, page, update and delete would carry, so each one
+//! would still reach past the trait to the models.
+//!
+//! For the same reason there are no in-memory `HashMap` stand-ins for
+//! testing. Tests run against an in-memory SQLite database with the real
+//! migrations, through `#[sqlx::test]` here and `lib-testkit` for the server,
+//! which needs no database file and checks the foreign keys, triggers and
+//! scoping that a stand-in would skip.
+//!
 //! ## Usage
 //!
 //! ```rust,no_run
-//! use personal_ledger_backend::database::{DatabasePool, DatabaseResult};
+//! use lib_database::{DatabasePool, DatabaseResult};
 //!
 //! async fn initialize_db() -> DatabaseResult<()> {
-//!     let pool = DatabasePool::connect("sqlite::memory:").await?;
+//!     let pool = DatabasePool::new("sqlite::memory:").connect().await?;
 //!     // Use pool for database operations...
 //!     Ok(())
 //! }
@@ -52,10 +147,11 @@ pub use error::DatabaseError;
 /// # Examples
 ///
 /// ```rust
-/// use personal_ledger_backend::database::DatabaseResult;
+/// use lib_database::{Categories, DatabaseResult};
 ///
-/// fn get_user(id: i32) -> DatabaseResult<User> {
+/// fn get_category(code: &str) -> DatabaseResult<Categories> {
 ///     // Database operation that may fail...
+///     # unimplemented!("{code}")
 /// }
 /// ```
 pub use error::DatabaseResult;
@@ -64,12 +160,37 @@ mod pool;
 /// Database connection pool wrapper.
 ///
 /// Provides a lightweight abstraction over SQLx connection pools with centralized
-/// error handling, lifecycle management, and graceful shutdown. Supports both
-/// SQLite and PostgreSQL through the `sqlx::any` driver.
+/// error handling, lifecycle management, and graceful shutdown. Only SQLite
+/// URLs are accepted.
 ///
 /// See [`pool`] module for detailed API documentation and examples.
 pub use pool::DatabasePool;
 
+mod migrations;
+/// Embedded schema migrations.
+///
+/// Applied with [`DatabasePool::migrate`], and reported on by
+/// [`DatabasePool::migration_status`].
+///
+/// See [`migrations`] module for details.
+pub use migrations::MigrationStatus;
+
+mod backup;
+/// Online backup and restore.
+///
+/// Written with [`DatabasePool::backup_to`], and read back with
+/// [`DatabasePool::restore_from`].
+///
+/// See [`backup`] module for details.
+pub use backup::DatabaseBackup;
+
+mod fixtures;
+/// Fixtures of selected rows, dumped to JSON and loaded into another
+/// database, for tests and bug reproductions.
+///
+/// See [`fixtures`] module for details.
+pub use fixtures::{Fixture, FixtureSelection, FixtureTable};
+
 mod categories;
 /// Financial category domain model.
 ///
@@ -79,4 +200,309 @@ mod categories;
 ///
 /// See [`categories`] module for implementation details.
 pub use categories::Categories;
-pub use categories::CategoriesBuilder;
\ No newline at end of file
+pub use categories::CategoriesBuilder;
+pub use categories::CategoryFields;
+pub use categories::CategoryTree;
+pub use categories::{CategoryFilter, CategorySortColumns};
+pub use categories::{CategoryStatistics, CategoryTypeCount, CategoryUsage};
+mod journal;
+/// Double-entry journal entry model.
+///
+/// Records financial events as balanced sets of splits that debit and credit
+/// categories. The splits of every entry must sum to zero, which is enforced
+/// inside the transaction that writes them.
+///
+/// See [`journal`] module for implementation details.
+pub use journal::JournalEntries;
+pub use journal::JournalSplits;
+
+mod transactions;
+pub use transactions::{BulkEditPreview, TransactionChanges, TransactionFilter};
+pub use transactions::{CategorySuggestion, SuggestionSources};
+/// Financial transaction model.
+///
+/// Records a single signed amount against a payee on a date, optionally
+/// classified by a category, posted to an account and located on a map.
+/// Purchases can carry return and warranty dates for expiry reminders, and
+/// a transaction can be split across categories.
+///
+/// See [`transactions`] module for implementation details.
+pub use transactions::{ClearedStatus, TransactionSplits, Transactions, Transfers};
+pub use transactions::{DUPLICATE_PAYEE_SIMILARITY, DuplicatePair};
+pub use transactions::{ExpiryKinds, ExpiryReminder, ExpiryRule};
+pub use transactions::{MapBounds, MapCluster};
+
+mod import;
+pub use import::{CategoryMapping, CategoryMappingModes, QifDateOrders, QifFormat};
+/// Bank export import.
+///
+/// Parses bank CSV exports with a configurable column mapping, and QIF files
+/// with their categories mapped to ledger categories, and imports them as
+/// transactions, skipping rows and files that were already imported.
+///
+/// See [`import`] module for implementation details.
+pub use import::{CsvAmount, CsvColumn, CsvMapping, ImportBatches, ImportRow, ImportSummary};
+
+mod export;
+/// Ledger export.
+///
+/// Streams categories, accounts and transactions as CSV or JSON Lines, for
+/// spreadsheets and for moving the ledger to another program, and writes the
+/// ledger as a GnuCash book or a Beancount or hledger journal. A round trip
+/// reads an export back into an empty database to check nothing was lost.
+///
+/// See [`export`] module for implementation details.
+pub use export::{
+    ExportEntities, ExportFormats, GnuCashExport, LedgerExport, PlainTextExport, PlainTextFormats,
+};
+pub use export::{RoundTrip, RoundTripCheck, RoundTripChecks};
+
+mod payees;
+pub use payees::{LocalMerchantDataset, MerchantInfo, MerchantProvider, Merchants};
+/// Payee alias model.
+///
+/// Maps raw payee strings from bank exports to clean payees, exactly or by
+/// regular expression, and applies them on import. Payees can be enriched with
+/// merchant metadata from a pluggable provider.
+///
+/// See [`payees`] module for implementation details.
+pub use payees::{PayeeAliases, PayeeMatchKinds, PayeeNormaliser};
+
+mod accounts;
+/// Account model.
+///
+/// The accounts money is held in or owed on. Transactions are posted to an
+/// account and its balance is computed from them, or adjusted to a counted
+/// amount with a balancing entry.
+///
+/// See [`accounts`] module for implementation details.
+pub use accounts::Accounts;
+
+mod users;
+/// User model.
+///
+/// The people sharing one server. Categories and transactions are owned by
+/// a user, and their find, update and delete functions are limited to one
+/// user's rows by a [`UserScope`].
+///
+/// See [`users`] module for implementation details.
+pub use users::{UserScope, Users};
+
+mod crypto;
+/// Crypto holding and price models.
+///
+/// Quantities of crypto assets held in an account and daily asset prices,
+/// stored as exact decimals, valued in any currency through the exchange
+/// rates.
+///
+/// See [`crypto`] module for implementation details.
+pub use crypto::{AssetPrices, CryptoHoldings};
+
+mod balance_snapshots;
+/// Balance snapshot model.
+///
+/// Balances recorded by hand for accounts whose transactions are not kept in
+/// the ledger, such as a superannuation fund, valued between snapshots by
+/// net worth reports, and balances computed for every other account when a
+/// period is closed.
+///
+/// See [`balance_snapshots`] module for implementation details.
+pub use balance_snapshots::{BalanceSnapshots, SnapshotSources};
+
+mod balance_assertions;
+/// Balance assertion model.
+///
+/// Balances a bank reports for an account on a day, compared with the
+/// balance computed from the ledger to find missed or doubled imports.
+///
+/// See [`balance_assertions`] module for implementation details.
+pub use balance_assertions::{BalanceAssertionCheck, BalanceAssertions};
+
+mod attachments;
+/// Attachment model.
+///
+/// Receipt images and PDF invoices attached to transactions, stored once per
+/// content hash and checked for their size and type.
+///
+/// See [`attachments`] module for implementation details.
+pub use attachments::{ATTACHMENT_CONTENT_TYPES, Attachments, MAX_ATTACHMENT_BYTES};
+
+mod tags;
+/// Tag model.
+///
+/// Lowercase tags that cross-cut transactions beyond their single category,
+/// linked many-to-many and suggested by how often they are used.
+///
+/// See [`tags`] module for implementation details.
+pub use tags::{TagSuggestion, Tags};
+
+mod budgets;
+/// Budget model.
+///
+/// Monthly or annual spending limits per category, compared with the posted
+/// transactions in the category and its subcategories.
+///
+/// See [`budgets`] module for implementation details.
+pub use budgets::{BudgetComparison, BudgetPeriods, BudgetVariance, Budgets};
+
+mod goals;
+/// Savings goal model.
+///
+/// A target amount and optional date, with progress measured from the
+/// balance of a linked account or the spending in a linked category.
+///
+/// See [`goals`] module for implementation details.
+pub use goals::{GoalProgress, Goals};
+
+mod recurring;
+/// Recurring transaction model.
+///
+/// Templates for bills and income that repeat, materialised into
+/// transactions on each due date by a scheduler, and reminded about before
+/// they fall due.
+///
+/// See [`recurring`] module for implementation details.
+pub use recurring::{BillReminder, RecurringFrequencies, RecurringTransactions};
+
+mod reports;
+/// Aggregate report queries.
+///
+/// Spending by category, monthly income and expenses, and net worth over
+/// time, totalled by the database.
+///
+/// See [`reports`] module for implementation details.
+pub use reports::{AccountBalance, CategorySpending, MonthlyCashFlow, NetWorthPoint, Reports};
+pub use reports::{AccountWorth, NetWorthHistoryPoint, ValuationSources};
+
+mod reimbursements;
+/// Reimbursement models.
+///
+/// Expenses that someone else will pay back, from transactions or trips in
+/// the mileage log, tracked from claim to repayment.
+///
+/// See [`reimbursements`] module for implementation details.
+pub use reimbursements::{
+    MileageLogs, OutstandingReimbursement, Reimbursables, ReimbursementSources,
+};
+
+mod exchange_rates;
+/// Exchange rate model.
+///
+/// Daily rates between currencies, used to convert amounts so accounts held
+/// in different currencies can be added up at a valuation date.
+///
+/// See [`exchange_rates`] module for implementation details.
+pub use exchange_rates::ExchangeRates;
+
+mod receipts;
+pub use receipts::ReceiptDetails;
+/// Receipt email models.
+///
+/// Receipts forwarded by email, stored with their attachments and turned
+/// into draft transactions for review.
+///
+/// See [`receipts`] module for implementation details.
+pub use receipts::{InboundAttachment, InboundReceipt, ReceiptAttachments, ReceiptEmails};
+
+mod reconciliations;
+/// Reconciliation model.
+///
+/// A statement's date and balances per account, with the transactions on it
+/// marked reconciled all at once, and undone latest first.
+///
+/// See [`reconciliations`] module for implementation details.
+pub use reconciliations::Reconciliations;
+
+mod periods;
+/// Period closing model.
+///
+/// Locks transactions on or before a closing date against modification. Every
+/// close and reopen is kept as an audit trail.
+///
+/// See [`periods`] module for implementation details.
+pub use periods::PeriodActions;
+pub use periods::PeriodClosings;
+
+mod audit_log;
+/// Audit log model.
+///
+/// Every insert, update and delete, recorded by database triggers within the
+/// same transaction, with JSON snapshots of the row and the actor.
+///
+/// See [`audit_log`] module for details.
+pub use audit_log::{AuditActions, AuditLog};
+
+mod pagination;
+/// Keyset (cursor) pagination.
+///
+/// Paged lists are read newest first, each page starting after the cursor of
+/// the last row of the previous one, passed to clients as an opaque token.
+///
+/// See [`pagination`] module for details.
+pub use pagination::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE, Page, PageCursor};
+
+mod dry_run;
+/// Run a mutating operation in a transaction that is committed, or rolled
+/// back when `validate_only` is set.
+///
+/// See [`dry_run`] module for details.
+pub use dry_run::with_transaction;
+
+mod query_metrics;
+/// Query latency and row count metrics, labelled by operation.
+///
+/// Every query records its latency and the rows it returned or affected
+/// through the `metrics` facade, under a static label naming the operation.
+///
+/// See [`query_metrics`] module for details.
+pub use query_metrics::{
+    QUERY_DURATION_SECONDS, QUERY_ERRORS_TOTAL, QUERY_ROWS, describe_query_metrics,
+};
+
+mod public_ids;
+/// The key the public IDs of records are encrypted with.
+///
+/// See [`public_ids`] module for details.
+pub use public_ids::public_id_key;
+
+mod usage;
+/// What a ledger holds: transactions, categories and attachment storage.
+///
+/// See [`usage`] module for details.
+pub use usage::Usage;
+
+mod demo;
+/// Demo data for a public sandbox server, and resetting a database to it.
+///
+/// See [`demo`] module for details.
+pub use demo::DemoData;
+
+mod seed;
+/// The standard chart of categories for a new ledger, and demo transactions
+/// for trying the server out.
+///
+/// See [`seed`] module for details.
+pub use seed::{DEMO_DAYS, SeedData};
+
+mod verify;
+/// Consistency checks across the whole ledger, reported as findings.
+///
+/// See [`verify`] module for details.
+pub use verify::{LedgerChecks, LedgerFinding, LedgerVerification};
+
+mod events;
+/// A bus announcing inserts, updates and deletes once they are committed,
+/// for reacting to changes without polling.
+///
+/// See [`events`] module for details.
+pub use events::{DataChanges, DataEvent, EVENT_CAPACITY, EventBus};
+
+mod webhook_deliveries;
+/// A log of data events posted to webhooks, and how each delivery went.
+///
+/// See [`webhook_deliveries`] module for details.
+pub use webhook_deliveries::{WebhookDeliveries, WebhookDeliveryStatuses};
+
+mod history;
+
+mod search;
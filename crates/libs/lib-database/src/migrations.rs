@@ -0,0 +1,329 @@
+//! # Migrations Module
+//!
+//! Runs the SQL migrations in `migrations/`, which are embedded in the
+//! library when it is compiled, so a server always brings its database up to
+//! the schema it was built against.
+//!
+//! Applied migrations are recorded by SQLx in the `_sqlx_migrations` table
+//! with a checksum of the SQL that was run. [`MigrationStatus`] compares the
+//! embedded migrations with that table, to report what is pending and
+//! whether an applied migration was changed after it ran.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_database::DatabasePool;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let db = DatabasePool::new("sqlite:ledger.db").connect().await?;
+//!
+//! for version in db.migrate().await? {
+//!     println!("Applied migration {version}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::query_metrics::Observe;
+use crate::{DatabasePool, DatabaseResult};
+
+/// The migrations embedded from `migrations/`.
+pub(crate) static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
+/// The state of one embedded migration in a database.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct MigrationStatus {
+    /// The migration's version, the number its file name starts with.
+    pub version: i64,
+
+    /// The migration's description, from the rest of its file name.
+    pub description: String,
+
+    /// When the migration was applied, or `None` if it is pending.
+    pub applied_on: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Whether the migration's SQL was changed after it was applied. The
+    /// database no longer matches the migration and must be rebuilt or
+    /// repaired by hand.
+    pub is_modified: bool,
+}
+
+impl MigrationStatus {
+    /// Whether the migration has been applied.
+    pub fn is_applied(&self) -> bool {
+        self.applied_on.is_some()
+    }
+
+    /// Returns the state of every embedded migration, in version order.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError` if the database cannot be read.
+    #[tracing::instrument(name = "Find migration status", skip(conn), err)]
+    pub fn find_all<'c, A>(conn: A) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            // SQLx creates the table when the first migration runs
+            let has_table = sqlx::query_scalar!(
+                r#"
+                    SELECT EXISTS (
+                        SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations'
+                    ) AS "exists!: bool"
+                "#
+            )
+            .fetch_one(&mut *conn)
+            .observe_one("migrations.find_all")
+            .await?;
+
+            let applied = if has_table {
+                sqlx::query!(
+                    r#"
+                        SELECT
+                            version         AS "version!: i64",
+                            checksum        AS "checksum!: Vec<u8>",
+                            installed_on    AS "installed_on!: chrono::DateTime<chrono::Utc>"
+                        FROM _sqlx_migrations
+                        WHERE success = TRUE
+                    "#
+                )
+                .fetch_all(&mut *conn)
+                .observe("migrations.find_all")
+                .await?
+                .into_iter()
+                .map(|row| (row.version, (row.checksum, row.installed_on)))
+                .collect()
+            } else {
+                std::collections::HashMap::new()
+            };
+
+            Ok(MIGRATOR
+                .iter()
+                .filter(|migration| !migration.migration_type.is_down_migration())
+                .map(|migration| {
+                    let applied = applied.get(&migration.version);
+
+                    Self {
+                        version: migration.version,
+                        description: migration.description.to_string(),
+                        applied_on: applied.map(|(_, installed_on)| *installed_on),
+                        is_modified: applied.is_some_and(|(checksum, _)| {
+                            checksum.as_slice() != &*migration.checksum
+                        }),
+                    }
+                })
+                .collect())
+        }
+    }
+}
+
+impl DatabasePool {
+    /// Applies every pending embedded migration, in version order.
+    ///
+    /// # Returns
+    ///
+    /// Returns the versions that were applied, empty when the database was
+    /// already up to date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Connection`](crate::DatabaseError::Connection)
+    /// if the pool is not connected, or
+    /// [`DatabaseError::Migration`](crate::DatabaseError::Migration) if a
+    /// migration fails or an applied migration was modified. A failed
+    /// migration is rolled back, leaving the ones before it applied.
+    ///
+    /// The migrations run with foreign keys off, then the connection is set
+    /// back to the configured
+    /// [`foreign_keys`](lib_config::DatabaseConfig::foreign_keys).
+    #[tracing::instrument(name = "Run database migrations", skip(self), err)]
+    pub async fn migrate(&self) -> DatabaseResult<Vec<i64>> {
+        let pool = self.get_pool()?;
+
+        let pending: Vec<i64> = MigrationStatus::find_all(pool)
+            .await?
+            .into_iter()
+            .filter(|status| !status.is_applied())
+            .map(|status| status.version)
+            .collect();
+
+        // A migration that rebuilds a table needs foreign keys off, so that
+        // dropping the old table does not cascade, and they cannot be changed
+        // inside the transaction each migration runs in
+        let mut conn = pool.acquire().await?;
+        sqlx::query("PRAGMA foreign_keys = OFF")
+            .execute(&mut *conn)
+            .await?;
+        let migrated = MIGRATOR.run(&mut *conn).await;
+        sqlx::query(&self.foreign_keys_pragma())
+            .execute(&mut *conn)
+            .await?;
+        migrated?;
+
+        for version in &pending {
+            tracing::info!(version, "Applied database migration");
+        }
+
+        Ok(pending)
+    }
+
+    /// Returns the state of every embedded migration, in version order.
+    ///
+    /// See [`MigrationStatus::find_all`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Connection`](crate::DatabaseError::Connection)
+    /// if the pool is not connected, or a `DatabaseError` if the database
+    /// cannot be read.
+    pub async fn migration_status(&self) -> DatabaseResult<Vec<MigrationStatus>> {
+        MigrationStatus::find_all(self.get_pool()?).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[tokio::test]
+    async fn migrate_applies_pending_migrations_once() -> Result<()> {
+        let db = DatabasePool::new("sqlite::memory:").connect().await?;
+
+        let before = db.migration_status().await?;
+        assert!(!before.is_empty());
+        assert!(before.iter().all(|status| !status.is_applied()));
+        assert!(
+            before
+                .windows(2)
+                .all(|pair| pair[0].version < pair[1].version)
+        );
+
+        let applied = db.migrate().await?;
+        assert_eq!(
+            applied,
+            before
+                .iter()
+                .map(|status| status.version)
+                .collect::<Vec<_>>()
+        );
+        assert!(db.migrate().await?.is_empty());
+
+        let after = db.migration_status().await?;
+        assert!(
+            after
+                .iter()
+                .all(|status| status.is_applied() && !status.is_modified)
+        );
+        assert_eq!(after[0].description, "categories");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn migration_status_reports_modified_migrations() -> Result<()> {
+        let db = DatabasePool::new("sqlite::memory:").connect().await?;
+        db.migrate().await?;
+
+        sqlx::query("UPDATE _sqlx_migrations SET checksum = x'00' WHERE version = 1")
+            .execute(db.get_pool()?)
+            .await?;
+
+        let status = db.migration_status().await?;
+        assert!(status[0].is_modified);
+        assert!(status[1..].iter().all(|status| !status.is_modified));
+        assert!(matches!(
+            db.migrate().await,
+            Err(crate::DatabaseError::Migration(_))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rebuilding_tables_keeps_the_rows_referring_to_them() -> Result<()> {
+        let db = DatabasePool::new("sqlite::memory:").connect().await?;
+        let pool = db.get_pool()?;
+        let mut before_rebuild = sqlx::migrate::Migrator::DEFAULT;
+        before_rebuild.migrations = MIGRATOR
+            .iter()
+            .filter(|migration| migration.version < 41)
+            .cloned()
+            .collect::<Vec<_>>()
+            .into();
+        before_rebuild.run(pool).await?;
+        sqlx::query(
+            "INSERT INTO categories (id, code, name, category_type, created_on, updated_on)
+             VALUES ('c1', 'EXP.001', 'Groceries', 'expense', '2025-01-01', '2025-01-01')",
+        )
+        .execute(pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO budgets (id, category_id, period, amount, created_on, updated_on)
+             VALUES ('b1', 'c1', 'monthly', 50000, '2025-01-01', '2025-01-01')",
+        )
+        .execute(pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO accounts (id, name, created_on, updated_on)
+             VALUES ('a1', 'Everyday', '2025-01-01', '2025-01-01')",
+        )
+        .execute(pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO transactions (
+                id, transaction_date, payee, amount, category_id, account_id, created_on, updated_on
+             )
+             VALUES ('t1', '2025-01-02', 'Corner Store', -1250, 'c1', 'a1', '2025-01-02', '2025-01-02')",
+        )
+        .execute(pool)
+        .await?;
+
+        // Run as SQLx would, the old table cannot be dropped with foreign keys on
+        let refused = MIGRATOR.run(pool).await;
+        assert!(
+            refused
+                .unwrap_err()
+                .to_string()
+                .contains("foreign_keys_off_for_rebuild")
+        );
+
+        db.migrate().await?;
+
+        let budgets: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM budgets")
+            .fetch_one(pool)
+            .await?;
+        assert_eq!(budgets, 1);
+        let posted: Option<String> =
+            sqlx::query_scalar("SELECT account_id FROM transactions WHERE id = 't1'")
+                .fetch_one(pool)
+                .await?;
+        assert_eq!(posted.as_deref(), Some("a1"));
+        let foreign_keys: i64 = sqlx::query_scalar("PRAGMA foreign_keys")
+            .fetch_one(pool)
+            .await?;
+        assert_eq!(foreign_keys, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn migrate_requires_connection() {
+        let db = DatabasePool::new("sqlite::memory:");
+
+        assert!(matches!(
+            db.migrate().await,
+            Err(crate::DatabaseError::Connection(_))
+        ));
+    }
+}
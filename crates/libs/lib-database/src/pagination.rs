@@ -0,0 +1,196 @@
+//! # Pagination Module
+//!
+//! Keyset (cursor) pagination for the `find_all_with_pagination` style
+//! queries. Paged lists are ordered newest first by `created_on` and then
+//! `id`, and each page ends with a [`PageCursor`] naming its last row. The
+//! next page is read with `(created_on, id) < (cursor)`, which an index on
+//! those columns answers directly, so reading a late page costs the same as
+//! the first and rows inserted while a client pages through a list neither
+//! repeat nor shift rows onto a page already read, as they would with OFFSET.
+//!
+//! Lists ordered by name, such as accounts and budgets, page by the name and
+//! `id` instead. Their cursor names the last row, and the next page starts
+//! after that row's name, so a cursor whose row has been deleted since is
+//! refused rather than guessed at.
+//!
+//! Cursors are passed to clients as opaque page tokens with
+//! [`PageCursor::encode`] and read back with [`PageCursor::decode`].
+
+use crate::{DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Page size used when the caller does not ask for one.
+pub const DEFAULT_PAGE_SIZE: u32 = 50;
+
+/// Largest page size a caller may ask for, larger requests are capped.
+pub const MAX_PAGE_SIZE: u32 = 500;
+
+/// The position of the last row of a page, which the next page starts after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PageCursor {
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub id: domain::RowID,
+}
+
+/// One page of a paged list, newest first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+
+    /// Where the next page starts, or `None` if this is the last page.
+    pub next_cursor: Option<PageCursor>,
+
+    /// The number of rows in the whole list, across every page.
+    pub total_count: i32,
+}
+
+impl PageCursor {
+    /// Creates a cursor at the row with the given creation time and id.
+    pub fn new(created_on: chrono::DateTime<chrono::Utc>, id: domain::RowID) -> Self {
+        Self { created_on, id }
+    }
+
+    /// Encodes the cursor as an opaque page token.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::PageCursor;
+    /// use lib_domain::RowID;
+    ///
+    /// let cursor = PageCursor::new(chrono::Utc::now(), RowID::new());
+    ///
+    /// assert_eq!(PageCursor::decode(&cursor.encode()), Ok(cursor));
+    /// ```
+    pub fn encode(&self) -> String {
+        let nanos = self.created_on.timestamp_nanos_opt().unwrap_or_default();
+
+        format!("{:016x}{:032x}", nanos as u64, self.id.as_uuid().as_u128())
+    }
+
+    /// Reads back a page token made by [`encode`](Self::encode).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the token was not made by
+    /// [`encode`](Self::encode).
+    pub fn decode(token: &str) -> DatabaseResult<Self> {
+        let invalid = || DatabaseError::Validation(format!("Invalid page token '{}'", token));
+
+        if token.len() != 48 || !token.is_ascii() {
+            return Err(invalid());
+        }
+
+        let nanos = u64::from_str_radix(&token[..16], 16).map_err(|_| invalid())?;
+        let id = u128::from_str_radix(&token[16..], 16).map_err(|_| invalid())?;
+        let id = domain::RowID::try_from(uuid::Uuid::from_u128(id)).map_err(|_| invalid())?;
+
+        Ok(Self::new(
+            chrono::DateTime::from_timestamp_nanos(nanos as i64),
+            id,
+        ))
+    }
+}
+
+impl<T> Page<T> {
+    /// Builds a page from rows read newest first after the requested cursor,
+    /// with one row more than the page size asked for if there is a next
+    /// page.
+    pub(crate) fn from_rows(
+        mut rows: Vec<T>,
+        page_size: u32,
+        total_count: i32,
+        cursor_of: impl Fn(&T) -> PageCursor,
+    ) -> Self {
+        let next_cursor = if rows.len() > page_size as usize {
+            rows.truncate(page_size as usize);
+            rows.last().map(&cursor_of)
+        } else {
+            None
+        };
+
+        Self {
+            items: rows,
+            next_cursor,
+            total_count,
+        }
+    }
+
+    /// Pages a list held in memory, such as rows read from history, the same
+    /// way the database pages a table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{Page, PageCursor};
+    /// use lib_domain::RowID;
+    ///
+    /// let now = chrono::Utc::now();
+    /// let ids = [RowID::new(), RowID::new(), RowID::new()];
+    /// let cursor_of = |id: &RowID| PageCursor::new(now, *id);
+    ///
+    /// let first = Page::from_all(ids.to_vec(), None, 2, cursor_of);
+    /// assert_eq!(first.items, [ids[2], ids[1]]);
+    ///
+    /// let second = Page::from_all(ids.to_vec(), first.next_cursor, 2, cursor_of);
+    /// assert_eq!((second.items, second.next_cursor), (vec![ids[0]], None));
+    /// ```
+    pub fn from_all(
+        mut items: Vec<T>,
+        after: Option<PageCursor>,
+        page_size: i32,
+        cursor_of: impl Fn(&T) -> PageCursor,
+    ) -> Self {
+        let total_count = items.len() as i32;
+        let page_size = page_size_or_default(page_size);
+
+        items.sort_by_key(|item| std::cmp::Reverse(cursor_of(item)));
+        let rows = items
+            .into_iter()
+            .filter(|item| after.is_none_or(|after| cursor_of(item) < after))
+            .take(page_size as usize + 1)
+            .collect();
+
+        Self::from_rows(rows, page_size, total_count, cursor_of)
+    }
+}
+
+/// Applies the default page size to zero or negative requests, and caps
+/// larger ones.
+pub(crate) fn page_size_or_default(requested: i32) -> u32 {
+    match u32::try_from(requested) {
+        Ok(0) | Err(_) => DEFAULT_PAGE_SIZE,
+        Ok(size) => size.min(MAX_PAGE_SIZE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_rejects_tokens_not_from_encode() {
+        let token = PageCursor::new(chrono::Utc::now(), domain::RowID::new()).encode();
+
+        for invalid in [
+            "",
+            "not a token",
+            &token[1..],
+            &token.replace(&token[..1], "g"),
+            "é".repeat(24).as_str(),
+        ] {
+            assert!(matches!(
+                PageCursor::decode(invalid),
+                Err(DatabaseError::Validation(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn page_size_applies_default_and_cap() {
+        assert_eq!(page_size_or_default(-1), DEFAULT_PAGE_SIZE);
+        assert_eq!(page_size_or_default(0), DEFAULT_PAGE_SIZE);
+        assert_eq!(page_size_or_default(10), 10);
+        assert_eq!(page_size_or_default(i32::MAX), MAX_PAGE_SIZE);
+    }
+}
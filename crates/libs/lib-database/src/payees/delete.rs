@@ -0,0 +1,71 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::PayeeAliases {
+    /// Deletes a payee alias from the database by its ID. Transactions already
+    /// imported with the alias keep their clean payee.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the alias to delete
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The alias with the given ID does not exist (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::PayeeAliases;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, alias_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// PayeeAliases::delete_by_id(alias_id, pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Delete payee alias from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    DELETE FROM payee_aliases
+                    WHERE id = ?
+                "#,
+                id
+            )
+            .execute(&mut *conn)
+            .observe("payees.delete_by_id")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Payee alias with id {} not found",
+                    id
+                )));
+            }
+
+            tracing::info!("Deleted payee alias {} from database", id);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::PayeeAliases {
+    /// Retrieves every payee alias, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::PayeeAliases;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for alias in PayeeAliases::find_all(pool).await? {
+    ///     println!("{} -> {}", alias.pattern, alias.payee);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find all payee aliases", skip(conn), err)]
+    pub fn find_all<'c, A>(conn: A) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let aliases = sqlx::query_as!(
+                database::PayeeAliases,
+                r#"
+                    SELECT
+                        id              AS "id!: lib_domain::RowID",
+                        pattern,
+                        match_kind      AS "match_kind!: database::PayeeMatchKinds",
+                        payee,
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM payee_aliases
+                    ORDER BY created_on, id
+                "#
+            )
+            .fetch_all(&mut *conn)
+            .observe("payees.find_all")
+            .await?;
+
+            Ok(aliases)
+        }
+    }
+
+    /// Finds a payee alias by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the alias to find
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(PayeeAliases)` if the alias exists, or `None` if not found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::PayeeAliases;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, alias_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// if let Some(alias) = PayeeAliases::find_by_id(alias_id, pool).await? {
+    ///     println!("{} -> {}", alias.pattern, alias.payee);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find payee alias by ID", skip(conn), fields(id = %id), err)]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let alias = sqlx::query_as!(
+                database::PayeeAliases,
+                r#"
+                    SELECT
+                        id              AS "id!: lib_domain::RowID",
+                        pattern,
+                        match_kind      AS "match_kind!: database::PayeeMatchKinds",
+                        payee,
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM payee_aliases
+                    WHERE id = ?
+                "#,
+                id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("payees.find_by_id")
+            .await?;
+
+            Ok(alias)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payees::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn find_by_id_finds_only_that_alias(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let alias =
+            database::PayeeAliases::new("AMZN Mktp", database::PayeeMatchKinds::Exact, "Amazon")
+                .insert(&pool)
+                .await?;
+
+        let found = database::PayeeAliases::find_by_id(alias.id, &pool).await?;
+        let missing = database::PayeeAliases::find_by_id(domain::RowID::new(), &pool).await?;
+
+        assert_eq!(found.map(|found| found.payee), Some("Amazon".to_string()));
+        assert!(missing.is_none());
+
+        Ok(())
+    }
+}
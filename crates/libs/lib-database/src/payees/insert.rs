@@ -0,0 +1,207 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::PayeeAliases {
+    /// Inserts a new payee alias into the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the inserted alias, or a `DatabaseError` if the insertion fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The pattern or payee is blank, or the regex is invalid (`DatabaseError::Validation`)
+    /// - An alias with the same kind and pattern already exists (unique violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{PayeeAliases, PayeeMatchKinds};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let alias = PayeeAliases::new(r"^AMZN Mktp", PayeeMatchKinds::Regex, "Amazon")
+    ///     .insert(pool)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert new payee alias into database",
+        skip(self, conn),
+        fields(id = %self.id, pattern = %self.pattern),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO payee_aliases (id, pattern, match_kind, payee, created_on, updated_on)
+                    VALUES (?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.pattern,
+                self.match_kind,
+                self.payee,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("payees.insert")
+            .await?;
+
+            tracing::info!("Inserted payee alias {} into database", self.id);
+
+            Ok(self.clone())
+        }
+    }
+
+    /// Creates an exact alias from an example transaction, mapping its raw
+    /// payee to `payee`, so later imports of the same raw payee are cleaned up.
+    ///
+    /// The example transaction itself is left unchanged. Existing transactions
+    /// can be renamed with a bulk edit.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - The transaction whose payee is the raw string
+    /// * `payee` - The clean payee to map it to
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transaction does not exist (`DatabaseError::NotFound`)
+    /// - The payee is blank, or already the transaction's payee (`DatabaseError::Validation`)
+    /// - An exact alias for the raw payee already exists (unique violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::PayeeAliases;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, transaction_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let alias = PayeeAliases::from_transaction(transaction_id, "Amazon", pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Create payee alias from transaction",
+        skip(payee, conn),
+        fields(transaction_id = %transaction_id),
+        err
+    )]
+    pub fn from_transaction<'a, 'c, A>(
+        transaction_id: domain::RowID,
+        payee: &'a str,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let transaction = database::Transactions::find_by_id(
+                transaction_id,
+                database::UserScope::All,
+                &mut *conn,
+            )
+            .await?
+            .ok_or_else(|| {
+                database::DatabaseError::NotFound(format!(
+                    "Transaction with id {} not found",
+                    transaction_id
+                ))
+            })?;
+
+            if transaction.payee.trim().eq_ignore_ascii_case(payee.trim()) {
+                return Err(database::DatabaseError::Validation(format!(
+                    "Transaction payee is already '{}'",
+                    transaction.payee
+                )));
+            }
+
+            Self::new(transaction.payee, database::PayeeMatchKinds::Exact, payee)
+                .insert(&mut *conn)
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn insert_rejects_duplicate_patterns(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        database::PayeeAliases::new("AMZN Mktp", database::PayeeMatchKinds::Exact, "Amazon")
+            .insert(&pool)
+            .await?;
+
+        let duplicate =
+            database::PayeeAliases::new("amzn mktp", database::PayeeMatchKinds::Exact, "Amazon")
+                .insert(&pool)
+                .await;
+        let regex =
+            database::PayeeAliases::new("amzn mktp", database::PayeeMatchKinds::Regex, "Amazon")
+                .insert(&pool)
+                .await;
+
+        assert!(matches!(duplicate, Err(database::DatabaseError::Sqlx(_))));
+        assert!(regex.is_ok());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn from_transaction_aliases_raw_payee(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        let transaction = database::Transactions::new(date, "AMZN Mktp US*1234", -4_999)
+            .insert(&pool)
+            .await?;
+
+        let alias =
+            database::PayeeAliases::from_transaction(transaction.id, "Amazon", &pool).await?;
+        let unchanged =
+            database::PayeeAliases::from_transaction(transaction.id, "amzn mktp us*1234", &pool)
+                .await;
+        let missing =
+            database::PayeeAliases::from_transaction(domain::RowID::new(), "Amazon", &pool).await;
+
+        assert_eq!(alias.pattern, "AMZN Mktp US*1234");
+        assert_eq!(alias.match_kind, database::PayeeMatchKinds::Exact);
+        assert_eq!(alias.payee, "Amazon");
+        assert!(matches!(
+            unchanged,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        assert!(matches!(missing, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+//! # Merchants
+//!
+//! Stores the merchant metadata found for each payee, so a provider is only
+//! asked about a payee once and the categorisation suggester can use the
+//! category hint without a lookup.
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+/// Merchant metadata stored for a payee.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct Merchants {
+    /// The payee the metadata was looked up for, unique ignoring case.
+    pub payee: String,
+    pub name: String,
+    pub category_hint: Option<String>,
+    pub logo_url: Option<String>,
+
+    /// Name of the provider the metadata came from.
+    pub source: String,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::Merchants {
+    /// Finds the merchant metadata stored for a payee, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// * `payee` - The payee to look up
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(name = "Find merchant by payee", skip(conn), err)]
+    pub fn find_by_payee<'a, 'c, A>(
+        payee: &'a str,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let merchant = sqlx::query_as!(
+                database::Merchants,
+                r#"
+                    SELECT
+                        payee,
+                        name,
+                        category_hint,
+                        logo_url,
+                        source,
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM merchants
+                    WHERE payee = ?
+                "#,
+                payee
+            )
+            .fetch_optional(&mut *conn)
+            .observe("payees.find_by_payee")
+            .await?;
+
+            Ok(merchant)
+        }
+    }
+
+    /// Looks up the merchant behind each payee that has no stored metadata,
+    /// and stores what the provider finds. Payees already looked up, or that
+    /// the provider does not recognise, are left alone.
+    ///
+    /// This is an optional step after an import, e.g. for the payees of
+    /// [`ImportSummary::inserted`](database::ImportSummary::inserted).
+    ///
+    /// # Arguments
+    ///
+    /// * `payees` - The payees to enrich, duplicates are looked up once
+    /// * `provider` - The merchant provider to ask
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly stored merchants.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{LocalMerchantDataset, Merchants};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, dataset: LocalMerchantDataset) -> Result<(), Box<dyn std::error::Error>> {
+    /// let payees = vec!["AMZN Mktp US*1234".to_string()];
+    /// let stored = Merchants::enrich(&payees, &dataset, pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Enrich payees with merchant metadata",
+        skip(payees, provider, conn),
+        fields(count = payees.len(), provider = provider.name()),
+        err
+    )]
+    pub fn enrich<'a, 'c, A>(
+        payees: &'a [String],
+        provider: &'a dyn database::MerchantProvider,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let mut seen = std::collections::HashSet::new();
+            let mut stored = Vec::new();
+
+            for payee in payees {
+                let payee = payee.trim();
+                if payee.is_empty() || !seen.insert(payee.to_lowercase()) {
+                    continue;
+                }
+
+                if Self::find_by_payee(payee, &mut *conn).await?.is_some() {
+                    continue;
+                }
+
+                let Some(info) = provider.lookup(payee) else {
+                    continue;
+                };
+
+                let merchant = Self {
+                    payee: payee.to_string(),
+                    name: info.name,
+                    category_hint: info.category_hint,
+                    logo_url: info.logo_url,
+                    source: provider.name().to_string(),
+                    updated_on: chrono::Utc::now(),
+                };
+
+                sqlx::query!(
+                    r#"
+                        INSERT INTO merchants (payee, name, category_hint, logo_url, source, updated_on)
+                        VALUES (?, ?, ?, ?, ?, ?)
+                        ON CONFLICT (payee) DO UPDATE SET
+                            name = excluded.name,
+                            category_hint = excluded.category_hint,
+                            logo_url = excluded.logo_url,
+                            source = excluded.source,
+                            updated_on = excluded.updated_on
+                    "#,
+                    merchant.payee,
+                    merchant.name,
+                    merchant.category_hint,
+                    merchant.logo_url,
+                    merchant.source,
+                    merchant.updated_on
+                )
+                .execute(&mut *conn)
+                .observe("payees.enrich")
+                .await?;
+
+                stored.push(merchant);
+            }
+
+            tracing::info!("Stored merchant metadata for {} payees", stored.len());
+
+            Ok(stored)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payees::insert::tests::Result;
+
+    fn dataset() -> database::LocalMerchantDataset {
+        database::LocalMerchantDataset::new(vec![(
+            "amazon".to_string(),
+            database::MerchantInfo {
+                name: "Amazon".to_string(),
+                category_hint: Some("SHOP".to_string()),
+                logo_url: None,
+            },
+        )])
+    }
+
+    #[sqlx::test]
+    async fn enrich_stores_each_recognised_payee_once(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let payees = ["Amazon", "AMAZON", "Corner Cafe"].map(String::from);
+
+        let first = database::Merchants::enrich(&payees, &dataset(), &pool).await?;
+        let second = database::Merchants::enrich(&payees, &dataset(), &pool).await?;
+
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].source, "local");
+        assert!(second.is_empty());
+        assert_eq!(
+            database::Merchants::find_by_payee("amazon", &pool)
+                .await?
+                .map(|merchant| merchant.name),
+            Some("Amazon".to_string())
+        );
+        assert!(
+            database::Merchants::find_by_payee("Corner Cafe", &pool)
+                .await?
+                .is_none()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+//! # Payees Database Module
+//!
+//! Cleans up the payee strings banks put in their exports. A payee alias maps
+//! a raw string such as "AMZN Mktp US*1234" to a clean payee such as "Amazon",
+//! either exactly or by regular expression. The [`PayeeNormaliser`] loads every
+//! alias and is applied to each row when transactions are imported. Aliases are
+//! created, read, updated and deleted through the `PayeesService` RPCs.
+//!
+//! Payees can also be enriched with merchant metadata from a pluggable
+//! [`MerchantProvider`], starting with a [`LocalMerchantDataset`]. The metadata
+//! is stored per payee in [`Merchants`] and its category hint is used to
+//! suggest categories for payees that have never been categorised.
+
+mod delete;
+mod find;
+mod insert;
+mod merchants;
+mod model;
+mod normaliser;
+mod provider;
+mod update;
+
+/// Database row model representing a persisted payee alias.
+pub use model::{PayeeAliases, PayeeMatchKinds};
+
+/// Maps raw payee strings to clean payees using the stored aliases.
+pub use normaliser::PayeeNormaliser;
+
+/// Pluggable lookup of merchant metadata, and the local dataset provider.
+pub use provider::{LocalMerchantDataset, MerchantInfo, MerchantProvider};
+
+/// Database row model representing merchant metadata stored for a payee.
+pub use merchants::Merchants;
@@ -0,0 +1,120 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// How a payee alias pattern is matched against a raw payee string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, serde::Deserialize, serde::Serialize)]
+#[sqlx(rename_all = "lowercase")]
+pub enum PayeeMatchKinds {
+    /// The whole raw payee, ignoring case and runs of whitespace.
+    Exact,
+    /// A regular expression found anywhere in the raw payee, ignoring case.
+    Regex,
+}
+
+/// Maps raw payee strings from bank exports to a clean payee.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct PayeeAliases {
+    pub id: domain::RowID,
+    pub pattern: String,
+    pub match_kind: PayeeMatchKinds,
+    pub payee: String,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::PayeeAliases {
+    /// Creates a new, unsaved payee alias. The pattern and payee are trimmed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{PayeeAliases, PayeeMatchKinds};
+    ///
+    /// let alias = PayeeAliases::new(r"^AMZN Mktp", PayeeMatchKinds::Regex, " Amazon ");
+    ///
+    /// assert_eq!(alias.payee, "Amazon");
+    /// assert!(alias.validate().is_ok());
+    /// ```
+    pub fn new(
+        pattern: impl Into<String>,
+        match_kind: PayeeMatchKinds,
+        payee: impl Into<String>,
+    ) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            pattern: pattern.into().trim().to_string(),
+            match_kind,
+            payee: payee.into().trim().to_string(),
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Checks the alias fields before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the pattern or payee is blank,
+    /// or a regex pattern does not compile.
+    pub fn validate(&self) -> DatabaseResult<()> {
+        if self.pattern.trim().is_empty() {
+            return Err(DatabaseError::Validation(
+                "Payee alias pattern cannot be empty".to_string(),
+            ));
+        }
+
+        if self.payee.trim().is_empty() {
+            return Err(DatabaseError::Validation(
+                "Payee alias payee cannot be empty".to_string(),
+            ));
+        }
+
+        if self.match_kind == PayeeMatchKinds::Regex {
+            self.regex()?;
+        }
+
+        Ok(())
+    }
+
+    /// Compiles the pattern as a case insensitive regular expression.
+    pub(crate) fn regex(&self) -> DatabaseResult<regex::Regex> {
+        regex::RegexBuilder::new(&self.pattern)
+            .case_insensitive(true)
+            .build()
+            .map_err(|error| {
+                DatabaseError::Validation(format!(
+                    "Invalid payee alias pattern '{}': {error}",
+                    self.pattern
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_rejects_blank_fields_and_bad_regex() {
+        let blank_pattern = database::PayeeAliases::new(" ", PayeeMatchKinds::Exact, "Amazon");
+        let blank_payee = database::PayeeAliases::new("AMZN", PayeeMatchKinds::Exact, "");
+        let bad_regex = database::PayeeAliases::new("AMZN (Mktp", PayeeMatchKinds::Regex, "Amazon");
+        let exact = database::PayeeAliases::new("AMZN (Mktp", PayeeMatchKinds::Exact, "Amazon");
+
+        assert!(matches!(
+            blank_pattern.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            blank_payee.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            bad_regex.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(exact.validate().is_ok());
+    }
+}
@@ -0,0 +1,141 @@
+//! # Payee Normaliser
+//!
+//! Applies the stored payee aliases to raw payee strings. Exact aliases are
+//! tried first, then regex aliases in the order they were created, so a
+//! specific exact alias always wins over a broad pattern.
+
+use crate::{self as database, DatabaseResult};
+
+/// The payee aliases, loaded and compiled ready to apply.
+#[derive(Debug, Clone, Default)]
+pub struct PayeeNormaliser {
+    exact: std::collections::HashMap<String, String>,
+    regex: Vec<(regex::Regex, String)>,
+}
+
+/// Lowercases and collapses whitespace, the form exact aliases are matched in.
+fn exact_key(raw: &str) -> String {
+    raw.split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl PayeeNormaliser {
+    /// Builds a normaliser from a list of aliases.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if a regex alias does not compile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{PayeeAliases, PayeeMatchKinds, PayeeNormaliser};
+    ///
+    /// let normaliser = PayeeNormaliser::new(vec![
+    ///     PayeeAliases::new(r"^AMZN Mktp", PayeeMatchKinds::Regex, "Amazon"),
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(normaliser.normalise("AMZN Mktp US*1234"), "Amazon");
+    /// assert_eq!(normaliser.normalise("Corner Cafe"), "Corner Cafe");
+    /// ```
+    pub fn new(aliases: Vec<database::PayeeAliases>) -> DatabaseResult<Self> {
+        let mut normaliser = Self::default();
+
+        for alias in aliases {
+            match alias.match_kind {
+                database::PayeeMatchKinds::Exact => {
+                    normaliser
+                        .exact
+                        .entry(exact_key(&alias.pattern))
+                        .or_insert(alias.payee);
+                }
+                database::PayeeMatchKinds::Regex => {
+                    normaliser.regex.push((alias.regex()?, alias.payee));
+                }
+            }
+        }
+
+        Ok(normaliser)
+    }
+
+    /// Loads every stored alias into a normaliser.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(name = "Load payee normaliser", skip(conn), err)]
+    pub fn load<'c, A>(conn: A) -> impl Future<Output = DatabaseResult<Self>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let aliases = database::PayeeAliases::find_all(&mut *conn).await?;
+
+            Self::new(aliases)
+        }
+    }
+
+    /// Returns the clean payee for a raw payee string, or the raw string
+    /// trimmed if no alias matches.
+    pub fn normalise<'a>(&'a self, raw: &'a str) -> &'a str {
+        if let Some(payee) = self.exact.get(&exact_key(raw)) {
+            return payee;
+        }
+
+        self.regex
+            .iter()
+            .find(|(regex, _)| regex.is_match(raw))
+            .map_or(raw.trim(), |(_, payee)| payee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payees::insert::tests::Result;
+
+    #[test]
+    fn exact_aliases_win_over_regex() {
+        let normaliser = PayeeNormaliser::new(vec![
+            database::PayeeAliases::new("AMZN", database::PayeeMatchKinds::Regex, "Amazon"),
+            database::PayeeAliases::new(
+                "amzn  prime",
+                database::PayeeMatchKinds::Exact,
+                "Prime Video",
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(normaliser.normalise("AMZN PRIME"), "Prime Video");
+        assert_eq!(normaliser.normalise("amzn mktp us*1234"), "Amazon");
+        assert_eq!(normaliser.normalise("  Corner Cafe "), "Corner Cafe");
+    }
+
+    #[sqlx::test]
+    async fn load_applies_aliases_on_import(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        database::PayeeAliases::new(r"^AMZN Mktp", database::PayeeMatchKinds::Regex, "Amazon")
+            .insert(&pool)
+            .await?;
+        let mapping = database::CsvMapping::new(
+            database::CsvColumn::Index(0),
+            database::CsvColumn::Index(1),
+            database::CsvAmount::Signed(database::CsvColumn::Index(2)),
+        )
+        .with_headers(false);
+        let export = "2025-03-14,AMZN Mktp US*1234,-49.99\n";
+        let rows = mapping.parse(export.as_bytes())?;
+        let batch = database::ImportBatches::new(export.as_bytes(), None, None);
+
+        let first = database::ImportSummary::import(&batch, &rows, false, &pool).await?;
+        let again = database::ImportSummary::import(&batch, &rows, true, &pool).await?;
+
+        assert_eq!(first.inserted[0].payee, "Amazon");
+        assert_eq!(again.skipped.len(), 1);
+
+        Ok(())
+    }
+}
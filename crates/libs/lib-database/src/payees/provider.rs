@@ -0,0 +1,168 @@
+//! # Merchant Providers
+//!
+//! A merchant provider looks up metadata for a payee: the merchant's clean
+//! name, a hint of the category it belongs in and a logo. Providers are
+//! pluggable through the [`MerchantProvider`] trait. The
+//! [`LocalMerchantDataset`] is a provider backed by a list of known merchants,
+//! e.g. loaded from a CSV file shipped with the app, and needs no network.
+
+use crate::{DatabaseError, DatabaseResult};
+
+/// Metadata a provider knows about a merchant.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct MerchantInfo {
+    /// The merchant's clean name, e.g. "Amazon".
+    pub name: String,
+
+    /// Code of the category the merchant's transactions usually belong in.
+    pub category_hint: Option<String>,
+
+    /// URL of the merchant's logo.
+    pub logo_url: Option<String>,
+}
+
+/// Looks up merchant metadata for a payee.
+pub trait MerchantProvider: Send + Sync {
+    /// A short name for the provider, stored with each lookup, e.g. `"local"`.
+    fn name(&self) -> &str;
+
+    /// Returns what the provider knows about the merchant behind a payee, or
+    /// `None` if the payee is not recognised.
+    fn lookup(&self, payee: &str) -> Option<MerchantInfo>;
+}
+
+/// A merchant provider backed by a fixed list of known merchants.
+///
+/// Each entry has a pattern that is matched as a case insensitive substring of
+/// the payee. When several patterns match, the longest wins, so a specific
+/// entry such as "amazon prime" is preferred over "amazon".
+#[derive(Debug, Clone, Default)]
+pub struct LocalMerchantDataset {
+    entries: Vec<(String, MerchantInfo)>,
+}
+
+/// A row of a local merchant dataset CSV file.
+#[derive(serde::Deserialize)]
+struct DatasetRecord {
+    pattern: String,
+    name: String,
+    category_hint: Option<String>,
+    logo_url: Option<String>,
+}
+
+impl LocalMerchantDataset {
+    /// Creates a dataset from pattern and merchant pairs. Blank patterns are
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{LocalMerchantDataset, MerchantInfo, MerchantProvider};
+    ///
+    /// let dataset = LocalMerchantDataset::new(vec![(
+    ///     "amzn mktp".to_string(),
+    ///     MerchantInfo { name: "Amazon".to_string(), category_hint: Some("SHOP".to_string()), logo_url: None },
+    /// )]);
+    ///
+    /// assert_eq!(dataset.lookup("AMZN Mktp US*1234").unwrap().name, "Amazon");
+    /// assert!(dataset.lookup("Corner Cafe").is_none());
+    /// ```
+    pub fn new(entries: Vec<(String, MerchantInfo)>) -> Self {
+        let entries = entries
+            .into_iter()
+            .map(|(pattern, info)| (pattern.trim().to_lowercase(), info))
+            .filter(|(pattern, _)| !pattern.is_empty())
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Loads a dataset from CSV with a header row of `pattern`, `name`,
+    /// `category_hint` and `logo_url`. The last two may be blank.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if the file is not valid CSV or a
+    /// row is missing its pattern or name.
+    pub fn from_csv<R: std::io::Read>(reader: R) -> DatabaseResult<Self> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let mut entries = Vec::new();
+
+        for record in reader.deserialize::<DatasetRecord>() {
+            let record = record.map_err(|error| {
+                DatabaseError::Validation(format!("Invalid merchant dataset: {error}"))
+            })?;
+
+            if record.name.is_empty() {
+                return Err(DatabaseError::Validation(format!(
+                    "Merchant dataset pattern '{}' has no name",
+                    record.pattern
+                )));
+            }
+
+            let blank_to_none = |text: Option<String>| text.filter(|text| !text.is_empty());
+            entries.push((
+                record.pattern,
+                MerchantInfo {
+                    name: record.name,
+                    category_hint: blank_to_none(record.category_hint),
+                    logo_url: blank_to_none(record.logo_url),
+                },
+            ));
+        }
+
+        Ok(Self::new(entries))
+    }
+}
+
+impl MerchantProvider for LocalMerchantDataset {
+    fn name(&self) -> &str {
+        "local"
+    }
+
+    fn lookup(&self, payee: &str) -> Option<MerchantInfo> {
+        let payee = payee.to_lowercase();
+
+        self.entries
+            .iter()
+            .filter(|(pattern, _)| payee.contains(pattern.as_str()))
+            .max_by_key(|(pattern, _)| pattern.len())
+            .map(|(_, info)| info.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATASET: &str = "\
+pattern,name,category_hint,logo_url
+amazon,Amazon,SHOP,https://example.com/amazon.png
+amazon prime,Prime Video,ENT,
+woolworths,Woolworths,GROC,
+";
+
+    #[test]
+    fn from_csv_prefers_longest_pattern() {
+        let dataset = LocalMerchantDataset::from_csv(DATASET.as_bytes()).unwrap();
+
+        let prime = dataset.lookup("AMAZON PRIME AU").unwrap();
+        let shop = dataset.lookup("Amazon").unwrap();
+
+        assert_eq!(prime.name, "Prime Video");
+        assert_eq!(prime.logo_url, None);
+        assert_eq!(shop.category_hint.as_deref(), Some("SHOP"));
+        assert!(dataset.lookup("Corner Cafe").is_none());
+    }
+
+    #[test]
+    fn from_csv_rejects_rows_without_name() {
+        let result = LocalMerchantDataset::from_csv(
+            "pattern,name,category_hint,logo_url\namazon,,,\n".as_bytes(),
+        );
+
+        assert!(matches!(result, Err(DatabaseError::Validation(_))));
+    }
+}
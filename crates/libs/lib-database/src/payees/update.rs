@@ -0,0 +1,143 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::PayeeAliases {
+    /// Updates a payee alias's pattern, match kind and payee. Transactions
+    /// already imported keep the payee they were cleaned up to.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated alias, with a new `updated_on`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The pattern or payee is blank, or the regex is invalid (`DatabaseError::Validation`)
+    /// - The alias does not exist (`DatabaseError::NotFound`)
+    /// - Another alias with the same kind and pattern exists (unique violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::PayeeAliases;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, mut alias: PayeeAliases) -> Result<(), Box<dyn std::error::Error>> {
+    /// alias.payee = "Amazon Australia".to_string();
+    /// let alias = alias.update(pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Update payee alias in database",
+        skip(self, conn),
+        fields(id = %self.id, pattern = %self.pattern),
+        err
+    )]
+    pub fn update<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut alias = Self {
+                pattern: self.pattern.trim().to_string(),
+                payee: self.payee.trim().to_string(),
+                updated_on: chrono::Utc::now(),
+                ..self.clone()
+            };
+            alias.validate()?;
+
+            let mut conn = conn.acquire().await?;
+
+            let updated = sqlx::query_scalar!(
+                r#"
+                    UPDATE payee_aliases
+                    SET pattern = ?, match_kind = ?, payee = ?, updated_on = ?
+                    WHERE id = ?
+                    RETURNING created_on AS "created_on!: chrono::DateTime<chrono::Utc>"
+                "#,
+                alias.pattern,
+                alias.match_kind,
+                alias.payee,
+                alias.updated_on,
+                alias.id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("payees.update")
+            .await?;
+
+            alias.created_on = updated.ok_or_else(|| {
+                database::DatabaseError::NotFound(format!(
+                    "Payee alias with id {} not found",
+                    self.id
+                ))
+            })?;
+
+            tracing::info!("Updated payee alias {} in database", alias.id);
+
+            Ok(alias)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payees::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn update_changes_the_alias(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let alias =
+            database::PayeeAliases::new("AMZN Mktp", database::PayeeMatchKinds::Exact, "Amazon")
+                .insert(&pool)
+                .await?;
+
+        let updated = database::PayeeAliases {
+            pattern: "^AMZN".to_string(),
+            match_kind: database::PayeeMatchKinds::Regex,
+            payee: " Amazon Australia ".to_string(),
+            ..alias.clone()
+        }
+        .update(&pool)
+        .await?;
+
+        assert_eq!(updated.payee, "Amazon Australia");
+        assert_eq!(updated.created_on, alias.created_on);
+        assert_eq!(
+            database::PayeeAliases::find_by_id(alias.id, &pool).await?,
+            Some(updated)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_rejects_missing_and_invalid_aliases(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let missing =
+            database::PayeeAliases::new("AMZN", database::PayeeMatchKinds::Exact, "Amazon")
+                .update(&pool)
+                .await;
+        let invalid =
+            database::PayeeAliases::new("AMZN (", database::PayeeMatchKinds::Regex, "Amazon")
+                .update(&pool)
+                .await;
+
+        assert!(matches!(missing, Err(database::DatabaseError::NotFound(_))));
+        assert!(matches!(
+            invalid,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        Ok(())
+    }
+}
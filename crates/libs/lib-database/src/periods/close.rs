@@ -0,0 +1,341 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::PeriodClosings {
+    /// Closes the ledger through a date.
+    ///
+    /// Transactions dated on or before `closed_through` can no longer be
+    /// inserted, updated or deleted until the period is reopened. The close is
+    /// recorded in the audit trail, along with the balance of every account
+    /// on `closed_through` (see [`BalanceSnapshots::record_period_end`](database::BalanceSnapshots::record_period_end)).
+    ///
+    /// # Arguments
+    ///
+    /// * `closed_through` - The last date of the closed period
+    /// * `reason` - Optional note recorded with the close
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the recorded closing, or a `DatabaseError` if it fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The ledger is already closed through `closed_through` or later
+    ///   (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::PeriodClosings;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let end_of_year = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+    /// PeriodClosings::close(end_of_year, Some("FY2025 lodged".to_string()), pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Close ledger period",
+        skip(reason, conn),
+        fields(closed_through = %closed_through),
+        err
+    )]
+    pub fn close<'c, A>(
+        closed_through: chrono::NaiveDate,
+        reason: Option<String>,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let closing = Self::new(database::PeriodActions::Close, Some(closed_through), reason);
+
+            let mut tx = conn.begin().await?;
+
+            let recorded = closing.record(&mut tx).await?;
+            database::BalanceSnapshots::record_period_end(closed_through, &mut *tx).await?;
+
+            tx.commit().await?;
+
+            Ok(recorded)
+        }
+    }
+
+    /// Reopens a closed period as an admin override.
+    ///
+    /// Moves the lock date back to `closed_through`, or removes the lock when
+    /// it is `None`. A reason is required and is recorded in the audit trail
+    /// along with the new lock date. Balances computed at the end of the
+    /// reopened periods are discarded, as their transactions can change.
+    ///
+    /// # Arguments
+    ///
+    /// * `closed_through` - The new, earlier lock date, or `None` to reopen everything
+    /// * `reason` - Why the period is being reopened
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the recorded reopening, or a `DatabaseError` if it fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The reason is blank (`DatabaseError::Validation`)
+    /// - No period is closed, or `closed_through` is not earlier than the
+    ///   current lock date (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::PeriodClosings;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let end_of_march = chrono::NaiveDate::from_ymd_opt(2025, 3, 31);
+    /// PeriodClosings::reopen(end_of_march, "Amended Q4 invoice".to_string(), pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Reopen ledger period",
+        skip(conn),
+        fields(closed_through = ?closed_through),
+        err
+    )]
+    pub fn reopen<'c, A>(
+        closed_through: Option<chrono::NaiveDate>,
+        reason: String,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let reopening = Self::new(
+                database::PeriodActions::Reopen,
+                closed_through,
+                Some(reason),
+            );
+
+            let mut tx = conn.begin().await?;
+
+            let recorded = reopening.record(&mut tx).await?;
+            database::BalanceSnapshots::discard_computed_after(closed_through, &mut tx).await?;
+
+            tx.commit().await?;
+
+            Ok(recorded)
+        }
+    }
+
+    /// Validates the row against the current lock date and inserts it.
+    async fn record(&self, conn: &mut sqlx::SqliteConnection) -> DatabaseResult<Self> {
+        let current = Self::fetch_closed_through(&mut *conn).await?;
+        self.validate(current)?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO period_closings (id, action, closed_through, reason, created_on)
+                VALUES (?, ?, ?, ?, ?)
+            "#,
+            self.id,
+            self.action,
+            self.closed_through,
+            self.reason,
+            self.created_on
+        )
+        .execute(&mut *conn)
+        .observe("periods.record")
+        .await?;
+
+        let recorded = Self::fetch_by_id(self.id, &mut *conn)
+            .await?
+            .ok_or_else(|| {
+                database::DatabaseError::NotFound(format!(
+                    "Period closing with id {} not found after insert",
+                    self.id
+                ))
+            })?;
+
+        tracing::info!(
+            action = ?recorded.action,
+            closed_through = ?recorded.closed_through,
+            "Ledger period lock changed"
+        );
+
+        Ok(recorded)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    pub fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn close_and_reopen_record_audit_trail(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        database::PeriodClosings::close(date(3, 31), None, &pool).await?;
+        database::PeriodClosings::close(date(6, 30), Some("FY2025 lodged".to_string()), &pool)
+            .await?;
+        let reopened = database::PeriodClosings::reopen(
+            Some(date(3, 31)),
+            "Amended Q4 invoice".to_string(),
+            &pool,
+        )
+        .await?;
+
+        let history = database::PeriodClosings::find_all(&pool).await?;
+
+        assert_eq!(reopened.action, database::PeriodActions::Reopen);
+        assert_eq!(reopened.reason.as_deref(), Some("Amended Q4 invoice"));
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0], reopened);
+        assert_eq!(history[1].reason.as_deref(), Some("FY2025 lodged"));
+        assert_eq!(
+            database::PeriodClosings::closed_through(&pool).await?,
+            Some(date(3, 31))
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn reopen_everything_clears_lock(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        database::PeriodClosings::close(date(6, 30), None, &pool).await?;
+        database::PeriodClosings::reopen(None, "Restating the year".to_string(), &pool).await?;
+
+        assert_eq!(database::PeriodClosings::closed_through(&pool).await?, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn invalid_changes_are_not_recorded(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let reopen_nothing =
+            database::PeriodClosings::reopen(None, "Nothing closed".to_string(), &pool).await;
+        database::PeriodClosings::close(date(6, 30), None, &pool).await?;
+        let close_earlier = database::PeriodClosings::close(date(3, 31), None, &pool).await;
+        let reopen_without_reason =
+            database::PeriodClosings::reopen(None, String::new(), &pool).await;
+
+        assert!(matches!(
+            reopen_nothing,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            close_earlier,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            reopen_without_reason,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        assert_eq!(database::PeriodClosings::find_all(&pool).await?.len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn closing_locks_transactions_until_reopened(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let locked = database::Transactions::new(date(6, 1), "Corner Cafe", -1_250)
+            .insert(&pool)
+            .await?;
+        let open = database::Transactions::new(date(7, 1), "Corner Cafe", -1_250)
+            .insert(&pool)
+            .await?;
+        database::PeriodClosings::close(date(6, 30), None, &pool).await?;
+
+        let insert = database::Transactions::new(date(6, 30), "Late Invoice", -5_000)
+            .insert(&pool)
+            .await;
+        let update = database::Transactions {
+            amount: -1_450,
+            ..locked.clone()
+        }
+        .update(database::UserScope::All, &pool)
+        .await;
+        let move_into_closed = database::Transactions {
+            transaction_date: date(6, 15),
+            ..open.clone()
+        }
+        .update(database::UserScope::All, &pool)
+        .await;
+        let delete =
+            database::Transactions::delete_by_id(locked.id, database::UserScope::All, &pool).await;
+
+        assert!(matches!(
+            insert,
+            Err(database::DatabaseError::PeriodClosed(_))
+        ));
+        assert!(matches!(
+            update,
+            Err(database::DatabaseError::PeriodClosed(_))
+        ));
+        assert!(matches!(
+            move_into_closed,
+            Err(database::DatabaseError::PeriodClosed(_))
+        ));
+        assert!(matches!(
+            delete,
+            Err(database::DatabaseError::PeriodClosed(_))
+        ));
+
+        database::PeriodClosings::reopen(Some(date(5, 31)), "Refund received".to_string(), &pool)
+            .await?;
+        database::Transactions::delete_by_id(locked.id, database::UserScope::All, &pool).await?;
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn closing_records_period_end_balances(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut everyday = database::Accounts::new("Everyday");
+        everyday.opening_balance = 5_000;
+        let everyday = everyday.insert(&pool).await?;
+        database::PeriodClosings::close(date(3, 31), None, &pool).await?;
+        database::PeriodClosings::close(date(6, 30), None, &pool).await?;
+
+        let snapshots = database::BalanceSnapshots::find_by_account(everyday.id, &pool).await?;
+        let found: Vec<_> = snapshots
+            .iter()
+            .map(|s| (s.snapshot_date, s.balance, s.source))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (date(3, 31), 5_000, database::SnapshotSources::Computed),
+                (date(6, 30), 5_000, database::SnapshotSources::Computed),
+            ]
+        );
+
+        database::PeriodClosings::reopen(
+            Some(date(3, 31)),
+            "Amended Q4 invoice".to_string(),
+            &pool,
+        )
+        .await?;
+        let snapshots = database::BalanceSnapshots::find_by_account(everyday.id, &pool).await?;
+        assert_eq!(
+            snapshots
+                .iter()
+                .map(|s| s.snapshot_date)
+                .collect::<Vec<_>>(),
+            [date(3, 31)]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::PeriodClosings {
+    /// Returns the date the ledger is currently closed through.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(date)` when transactions on or before `date` are locked,
+    /// or `None` when no period is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::PeriodClosings;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// if let Some(date) = PeriodClosings::closed_through(pool).await? {
+    ///     println!("Ledger closed through {date}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find ledger closing date", skip(conn), err)]
+    pub fn closed_through<'c, A>(
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<chrono::NaiveDate>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            Self::fetch_closed_through(&mut conn).await
+        }
+    }
+
+    /// Retrieves the full audit trail of closes and reopens, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns every period closing row, or a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::PeriodClosings;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for closing in PeriodClosings::find_all(pool).await? {
+    ///     println!("{:?} through {:?}: {:?}", closing.action, closing.closed_through, closing.reason);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find all period closings", skip(conn), err)]
+    pub fn find_all<'c, A>(conn: A) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let closings = sqlx::query_as!(
+                database::PeriodClosings,
+                r#"
+                    SELECT
+                        id              AS "id!: domain::RowID",
+                        action          AS "action!: database::PeriodActions",
+                        closed_through  AS "closed_through?: chrono::NaiveDate",
+                        reason,
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>"
+                    FROM period_closings
+                    ORDER BY created_on DESC, id DESC
+                "#
+            )
+            .fetch_all(&mut *conn)
+            .observe("periods.find_all")
+            .await?;
+
+            Ok(closings)
+        }
+    }
+
+    /// Returns an error when `date` falls in a closed period.
+    ///
+    /// Called by transaction writes on the same connection, so the check and
+    /// the write happen in the same database transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::PeriodClosed` if `date` is on or before the
+    /// current closing date.
+    pub(crate) async fn ensure_open(
+        date: chrono::NaiveDate,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<()> {
+        match Self::fetch_closed_through(conn).await? {
+            Some(closed_through) if date <= closed_through => {
+                Err(database::DatabaseError::PeriodClosed(format!(
+                    "{date} is on or before the closing date {closed_through}"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reads the current closing date on a concrete connection.
+    pub(super) async fn fetch_closed_through(
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<chrono::NaiveDate>> {
+        let closed_through = sqlx::query_scalar!(
+            r#"
+                SELECT closed_through AS "closed_through?: chrono::NaiveDate"
+                FROM period_closings
+                ORDER BY created_on DESC, id DESC
+                LIMIT 1
+            "#
+        )
+        .fetch_optional(&mut *conn)
+        .observe("periods.fetch_closed_through")
+        .await?;
+
+        Ok(closed_through.flatten())
+    }
+
+    /// Fetches a period closing by ID on a concrete connection.
+    pub(super) async fn fetch_by_id(
+        id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let closing = sqlx::query_as!(
+            database::PeriodClosings,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    action          AS "action!: database::PeriodActions",
+                    closed_through  AS "closed_through?: chrono::NaiveDate",
+                    reason,
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>"
+                FROM period_closings
+                WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("periods.fetch_by_id")
+        .await?;
+
+        Ok(closing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::periods::close::tests::{Result, date};
+
+    #[sqlx::test]
+    async fn closed_through_is_none_when_never_closed(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        assert_eq!(database::PeriodClosings::closed_through(&pool).await?, None);
+        assert!(database::PeriodClosings::find_all(&pool).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn ensure_open_checks_closing_date(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        database::PeriodClosings::close(date(6, 30), None, &pool).await?;
+        let mut conn = pool.acquire().await?;
+
+        let closed = database::PeriodClosings::ensure_open(date(6, 30), &mut conn).await;
+        let open = database::PeriodClosings::ensure_open(date(7, 1), &mut conn).await;
+
+        assert!(matches!(
+            closed,
+            Err(database::DatabaseError::PeriodClosed(_))
+        ));
+        assert_eq!(open, Ok(()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+//! # Period Closing Database Module
+//!
+//! Locks finished months and tax years against modification. Closing a period
+//! records a lock date, and every transaction dated on or before it can no
+//! longer be inserted, updated or deleted. Reopening is the admin override: it
+//! moves the lock date back (or clears it) and must give a reason.
+//!
+//! Every close and reopen is stored as a new row and rows are never changed,
+//! so the table doubles as the audit trail. The latest row is the current
+//! lock. The lock is checked inside the same database transaction as each
+//! transaction insert, update and delete.
+
+mod close;
+mod find;
+mod model;
+
+/// Database row model for a single close or reopen of the ledger.
+pub use model::PeriodClosings;
+
+/// Whether a period closing row closed or reopened the ledger.
+pub use model::PeriodActions;
@@ -0,0 +1,170 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Whether a period closing row closed or reopened the ledger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, serde::Deserialize, serde::Serialize)]
+#[sqlx(rename_all = "lowercase")]
+pub enum PeriodActions {
+    /// Locked transactions on or before the closing date.
+    Close,
+    /// Moved the lock date back, or cleared it, as an admin override.
+    Reopen,
+}
+
+/// A single close or reopen of the ledger, kept as an audit trail.
+///
+/// `closed_through` is the lock date after the action. It is `None` when a
+/// reopen removed the lock entirely.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct PeriodClosings {
+    pub id: domain::RowID,
+    pub action: PeriodActions,
+    pub closed_through: Option<chrono::NaiveDate>,
+    pub reason: Option<String>,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::PeriodClosings {
+    /// Creates a new, unsaved period closing row.
+    ///
+    /// Blank reasons are stored as `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{PeriodActions, PeriodClosings};
+    ///
+    /// let end_of_year = chrono::NaiveDate::from_ymd_opt(2025, 6, 30);
+    /// let closing = PeriodClosings::new(PeriodActions::Close, end_of_year, Some("  ".to_string()));
+    ///
+    /// assert_eq!(closing.closed_through, end_of_year);
+    /// assert!(closing.reason.is_none());
+    /// ```
+    pub fn new(
+        action: PeriodActions,
+        closed_through: Option<chrono::NaiveDate>,
+        reason: Option<String>,
+    ) -> Self {
+        Self {
+            id: domain::RowID::new(),
+            action,
+            closed_through,
+            reason: reason
+                .map(|reason| reason.trim().to_string())
+                .filter(|reason| !reason.is_empty()),
+            created_on: chrono::Utc::now(),
+        }
+    }
+
+    /// Checks the action against the current lock date before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if:
+    /// - A close has no date, or does not move the lock date forward
+    /// - A reopen has no reason
+    /// - A reopen is made when nothing is closed, or does not move the lock
+    ///   date back
+    pub fn validate(&self, current: Option<chrono::NaiveDate>) -> DatabaseResult<()> {
+        match self.action {
+            PeriodActions::Close => {
+                let Some(closed_through) = self.closed_through else {
+                    return Err(DatabaseError::Validation(
+                        "Closing a period requires a closing date".to_string(),
+                    ));
+                };
+
+                if let Some(current) = current
+                    && closed_through <= current
+                {
+                    return Err(DatabaseError::Validation(format!(
+                        "Period is already closed through {current}"
+                    )));
+                }
+            }
+            PeriodActions::Reopen => {
+                if self.reason.is_none() {
+                    return Err(DatabaseError::Validation(
+                        "A reason is required to reopen a closed period".to_string(),
+                    ));
+                }
+
+                let Some(current) = current else {
+                    return Err(DatabaseError::Validation("No period is closed".to_string()));
+                };
+
+                if let Some(closed_through) = self.closed_through
+                    && closed_through >= current
+                {
+                    return Err(DatabaseError::Validation(format!(
+                        "Reopening must move the closing date back from {current}"
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> Option<chrono::NaiveDate> {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day)
+    }
+
+    fn reason() -> Option<String> {
+        Some("Correcting a miscoded invoice".to_string())
+    }
+
+    #[test]
+    fn validate_close_moves_lock_forward() {
+        let closing = PeriodClosings::new(PeriodActions::Close, date(6, 30), None);
+
+        assert_eq!(closing.validate(None), Ok(()));
+        assert_eq!(closing.validate(date(3, 31)), Ok(()));
+        assert!(matches!(
+            closing.validate(date(6, 30)),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_close_requires_date() {
+        let closing = PeriodClosings::new(PeriodActions::Close, None, None);
+
+        assert!(matches!(
+            closing.validate(None),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_reopen_moves_lock_back() {
+        let partial = PeriodClosings::new(PeriodActions::Reopen, date(3, 31), reason());
+        let full = PeriodClosings::new(PeriodActions::Reopen, None, reason());
+
+        assert_eq!(partial.validate(date(6, 30)), Ok(()));
+        assert_eq!(full.validate(date(6, 30)), Ok(()));
+        assert!(matches!(
+            partial.validate(date(3, 31)),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            full.validate(None),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_reopen_requires_reason() {
+        let reopen = PeriodClosings::new(PeriodActions::Reopen, None, Some(" ".to_string()));
+
+        assert!(matches!(
+            reopen.validate(date(6, 30)),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+//! # Public IDs Module
+//!
+//! Reads the key that RowIDs are encrypted with to make the public IDs
+//! records are known by outside the ledger, see
+//! [`PublicID`](lib_domain::PublicID). The key is made by the
+//! `0028_public_id_key` migration and never changes.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_database::Categories;
+//!
+//! # async fn example(pool: &sqlx::SqlitePool, category: Categories) -> Result<(), Box<dyn std::error::Error>> {
+//! let key = lib_database::public_id_key(pool).await?;
+//! println!("/categories/{}", key.encode(category.id));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::query_metrics::Observe;
+use crate::{DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Reads the ledger's public ID key.
+///
+/// # Arguments
+///
+/// * `conn` - The database pool, connection or transaction to run on
+///
+/// # Errors
+///
+/// Returns a `DatabaseError::Sqlx` if the key cannot be read, or a
+/// `DatabaseError::Other` if the stored key is not 16 bytes.
+#[tracing::instrument(name = "Read public ID key", skip(conn), err)]
+pub fn public_id_key<'c, A>(
+    conn: A,
+) -> impl Future<Output = DatabaseResult<domain::PublicIdKey>> + Send + 'c
+where
+    A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+{
+    async move {
+        let mut conn = conn.acquire().await?;
+
+        let key =
+            sqlx::query_scalar!(r#"SELECT key AS "key!: Vec<u8>" FROM public_id_key WHERE id = 1"#)
+                .fetch_one(&mut *conn)
+                .observe_one("public_ids.public_id_key")
+                .await?;
+
+        domain::PublicIdKey::from_slice(&key).map_err(|e| DatabaseError::Other(e.to_string()))
+    }
+}
+
+/// The RowID `public_id` stands for in this ledger, or `None` if it was not
+/// made with the ledger's key.
+pub(crate) async fn row_id(
+    public_id: domain::PublicID,
+    conn: &mut sqlx::SqliteConnection,
+) -> DatabaseResult<Option<domain::RowID>> {
+    Ok(public_id_key(conn).await?.decode(public_id).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test]
+    async fn key_is_kept_for_the_database(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let key = public_id_key(&pool).await?;
+        let id = domain::RowID::new();
+
+        assert_eq!(public_id_key(&pool).await?, key);
+        assert_ne!(key, domain::PublicIdKey::new([0; 16]));
+        assert_eq!(
+            row_id(key.encode(id), &mut *pool.acquire().await?).await?,
+            Some(id)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+//! # Query Metrics Module
+//!
+//! Records how long every database query takes and how many rows it returns
+//! or changes, labelled with a static operation name such as
+//! `"categories.find_by_id"`, so a release that makes one operation slower,
+//! or makes it read far more rows than it used to, shows up against that
+//! operation rather than in an overall average.
+//!
+//! Metrics are recorded through the [`metrics`] facade and go to whichever
+//! recorder the binary installs. Without one they cost next to nothing and
+//! are dropped. Each query records:
+//!
+//! - [`QUERY_DURATION_SECONDS`], a histogram of the query's latency
+//! - [`QUERY_ROWS`], a histogram of the rows returned or affected
+//! - [`QUERY_ERRORS_TOTAL`], a counter of queries that failed
+//!
+//! all with an `operation` label. Operations that run more than one statement
+//! record each of them under the operation's label.
+//!
+//! Queries are tagged by calling [`Observe::observe`] on the future returned
+//! by `fetch_all`, `fetch_optional` or `execute` before awaiting it, and
+//! [`Observe::observe_one`] on `fetch_one`:
+//!
+//! ```rust,ignore
+//! let category = sqlx::query_as!(Categories, "SELECT ... WHERE id = ?", id)
+//!     .fetch_optional(&mut *conn)
+//!     .observe("categories.find_by_id")
+//!     .await?;
+//! ```
+
+use std::time::Instant;
+
+/// Histogram of query latency in seconds, labelled by `operation`.
+pub const QUERY_DURATION_SECONDS: &str = "database_query_duration_seconds";
+
+/// Histogram of the rows a query returned or affected, labelled by `operation`.
+pub const QUERY_ROWS: &str = "database_query_rows";
+
+/// Counter of queries that returned an error, labelled by `operation`.
+pub const QUERY_ERRORS_TOTAL: &str = "database_query_errors_total";
+
+/// Describes the query metrics to the installed recorder, giving them units
+/// and help text. Call once, after installing the recorder.
+pub fn describe_query_metrics() {
+    metrics::describe_histogram!(
+        QUERY_DURATION_SECONDS,
+        metrics::Unit::Seconds,
+        "Database query latency by operation"
+    );
+    metrics::describe_histogram!(
+        QUERY_ROWS,
+        metrics::Unit::Count,
+        "Rows returned or affected by a database query, by operation"
+    );
+    metrics::describe_counter!(
+        QUERY_ERRORS_TOTAL,
+        metrics::Unit::Count,
+        "Database queries that failed, by operation"
+    );
+}
+
+/// The number of rows a query result stands for.
+pub(crate) trait RowCount {
+    fn row_count(&self) -> u64;
+}
+
+impl<T> RowCount for Vec<T> {
+    fn row_count(&self) -> u64 {
+        self.len() as u64
+    }
+}
+
+impl<T> RowCount for Option<T> {
+    fn row_count(&self) -> u64 {
+        u64::from(self.is_some())
+    }
+}
+
+impl RowCount for sqlx::sqlite::SqliteQueryResult {
+    fn row_count(&self) -> u64 {
+        self.rows_affected()
+    }
+}
+
+/// Tags a query future with its operation label and records its latency
+/// and row count when it completes.
+pub(crate) trait Observe<T>: Future<Output = Result<T, sqlx::Error>> + Send + Sized {
+    /// Records the query under `operation`, counting rows with [`RowCount`].
+    fn observe(self, operation: &'static str) -> impl Future<Output = Result<T, sqlx::Error>> + Send
+    where
+        T: RowCount,
+    {
+        timed(operation, self, RowCount::row_count)
+    }
+
+    /// Records a `fetch_one` query under `operation`, as one row.
+    fn observe_one(
+        self,
+        operation: &'static str,
+    ) -> impl Future<Output = Result<T, sqlx::Error>> + Send {
+        timed(operation, self, |_| 1)
+    }
+}
+
+impl<T, F> Observe<T> for F where F: Future<Output = Result<T, sqlx::Error>> + Send {}
+
+async fn timed<T, F>(
+    operation: &'static str,
+    query: F,
+    rows: fn(&T) -> u64,
+) -> Result<T, sqlx::Error>
+where
+    F: Future<Output = Result<T, sqlx::Error>>,
+{
+    let started = Instant::now();
+    let result = query.await;
+
+    metrics::histogram!(QUERY_DURATION_SECONDS, "operation" => operation).record(started.elapsed());
+    match &result {
+        Ok(value) => {
+            metrics::histogram!(QUERY_ROWS, "operation" => operation).record(rows(value) as f64)
+        }
+        Err(_) => metrics::counter!(QUERY_ERRORS_TOTAL, "operation" => operation).increment(1),
+    }
+
+    result
+}
+
+/// Records a streamed query under `operation` when the stream is dropped,
+/// with the time it was open and the rows read from it.
+pub(crate) struct StreamObservation {
+    operation: &'static str,
+    started: Instant,
+    rows: u64,
+}
+
+impl StreamObservation {
+    pub(crate) fn new(operation: &'static str) -> Self {
+        Self {
+            operation,
+            started: Instant::now(),
+            rows: 0,
+        }
+    }
+
+    /// Counts a row read from the stream.
+    pub(crate) fn row(&mut self) {
+        self.rows += 1;
+    }
+}
+
+impl Drop for StreamObservation {
+    fn drop(&mut self) {
+        metrics::histogram!(QUERY_DURATION_SECONDS, "operation" => self.operation)
+            .record(self.started.elapsed());
+        metrics::histogram!(QUERY_ROWS, "operation" => self.operation).record(self.rows as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as database;
+    use metrics_util::CompositeKey;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    type Snapshot = Vec<(
+        CompositeKey,
+        Option<metrics::Unit>,
+        Option<metrics::SharedString>,
+        DebugValue,
+    )>;
+
+    /// Finds the metric called `name` recorded under `operation`.
+    fn recorded<'s>(snapshot: &'s Snapshot, name: &str, operation: &str) -> Option<&'s DebugValue> {
+        snapshot.iter().find_map(|(key, _, _, value)| {
+            let key = key.key();
+            let labelled = key
+                .labels()
+                .any(|label| label.key() == "operation" && label.value() == operation);
+            (key.name() == name && labelled).then_some(value)
+        })
+    }
+
+    /// Runs `query` with `recorder` installed for each poll.
+    async fn with_recorder<F: Future>(recorder: &DebuggingRecorder, query: F) -> F::Output {
+        let mut query = std::pin::pin!(query);
+        std::future::poll_fn(|cx| {
+            metrics::with_local_recorder(recorder, || query.as_mut().poll(cx))
+        })
+        .await
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_records_latency_and_rows(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock();
+        category.insert(&pool).await?;
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let query = database::Categories::find_by_id(category.id, database::UserScope::All, &pool);
+        with_recorder(&recorder, query).await?;
+
+        // Taking a snapshot drains the histograms, so take one for both
+        let snapshot = snapshotter.snapshot().into_vec();
+        let durations = recorded(&snapshot, QUERY_DURATION_SECONDS, "categories.find_by_id");
+        let rows = recorded(&snapshot, QUERY_ROWS, "categories.find_by_id");
+        assert!(matches!(durations, Some(DebugValue::Histogram(values)) if values.len() == 1));
+        assert!(
+            matches!(rows, Some(DebugValue::Histogram(values)) if values[0].into_inner() == 1.0)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_query_counts_an_error() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+
+        let query = async { Err::<Vec<()>, _>(sqlx::Error::RowNotFound) }.observe("tests.failing");
+        let result = with_recorder(&recorder, query).await;
+
+        assert!(matches!(result, Err(sqlx::Error::RowNotFound)));
+        let snapshot = snapshotter.snapshot().into_vec();
+        let errors = recorded(&snapshot, QUERY_ERRORS_TOTAL, "tests.failing");
+        assert!(matches!(errors, Some(&DebugValue::Counter(1))));
+    }
+}
@@ -0,0 +1,295 @@
+//! # Receipt Details
+//!
+//! Finds the merchant and total in a receipt email. Receipts are laid out in
+//! many different ways, so this reads the email the way a person skims it:
+//! the merchant is usually named in the subject, and the total is the largest
+//! amount on a line that mentions a total or a payment, ignoring subtotals,
+//! GST and savings lines.
+
+use lib_domain as domain;
+use std::sync::LazyLock;
+
+/// An amount written with a currency code or symbol, or with cents, e.g.
+/// `$12.50`, `AUD 1,234.00`, `£8` or `12.50 USD`.
+static AMOUNT: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(
+        r"(?i)(?P<code>\b[a-z]{3}\s?)?(?P<symbol>[$€£¥₹]\s?)?(?P<number>\d{1,3}(?:,\d{3})+(?:\.\d{2})?|\d+(?:\.\d{2})?)\b(?P<suffix>\s?[a-z]{3}\b)?",
+    )
+    .expect("amount pattern compiles")
+});
+
+/// Subjects naming the merchant, e.g. "Your receipt from Corner Cafe #1234"
+/// or "Your Uber receipt".
+static SUBJECT_PAYEE: LazyLock<[regex::Regex; 2]> = LazyLock::new(|| {
+    [
+        regex::Regex::new(
+            r"(?i)\b(?:receipt|order|invoice|purchase|payment)\s+(?:from|with|at)\s+(?P<payee>[^#|(\-–:]+)",
+        )
+        .expect("subject payee pattern compiles"),
+        regex::Regex::new(r"(?i)^your\s+(?P<payee>.+?)\s+(?:tax\s+)?(?:receipt|order|invoice)\b")
+            .expect("subject payee pattern compiles"),
+    ]
+});
+
+/// The "From:" line of a forwarded message, e.g.
+/// `From: Corner Cafe <receipts@cornercafe.com.au>`.
+static FORWARDED_FROM: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(
+        r#"(?im)^\s*>?\s*from:\s*"?(?P<name>[^"<\r\n]*?)"?\s*(?:<(?P<address>[^>\s]+)>)?\s*$"#,
+    )
+    .expect("forwarded from pattern compiles")
+});
+
+/// Words on a line that mark the amount on it as the total paid.
+const TOTAL_WORDS: [&str; 5] = [
+    "total",
+    "amount paid",
+    "amount charged",
+    "amount due",
+    "charged",
+];
+
+/// Words on a line that mark the amount on it as part of the total.
+const PART_WORDS: [&str; 5] = ["subtotal", "sub-total", "sub total", "saving", "discount"];
+
+/// The merchant and amount found in a receipt email.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReceiptDetails {
+    /// The merchant, or `"Receipt"` if none was found.
+    pub payee: String,
+
+    /// The subject, without any forwarding or reply prefixes.
+    pub description: Option<String>,
+
+    /// The total, as money spent, if one was found.
+    pub amount: Option<domain::Money>,
+}
+
+impl ReceiptDetails {
+    /// Reads the merchant and total from a receipt email's subject and text.
+    ///
+    /// Amounts without a currency code or unique symbol are read in
+    /// `currency`. The total is returned as a negative amount, as it is money
+    /// spent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::ReceiptDetails;
+    /// use lib_domain::{Currency, Money};
+    ///
+    /// let details = ReceiptDetails::extract(
+    ///     Some("Fwd: Your receipt from Corner Cafe #4521"),
+    ///     "Flat white  $5.50\nBanana bread  $6.00\nGST  $1.05\nTotal  $11.50",
+    ///     Currency::AUD,
+    /// );
+    ///
+    /// assert_eq!(details.payee, "Corner Cafe");
+    /// assert_eq!(details.amount, Some(Money::new(-1_150, Currency::AUD)));
+    /// ```
+    pub fn extract(subject: Option<&str>, body: &str, currency: domain::Currency) -> Self {
+        let description = subject
+            .map(clean_subject)
+            .filter(|subject| !subject.is_empty());
+
+        let payee = description
+            .as_deref()
+            .and_then(payee_from_subject)
+            .or_else(|| payee_from_forwarded(body))
+            .unwrap_or_else(|| "Receipt".to_string());
+
+        let amount = subject
+            .into_iter()
+            .chain(body.lines())
+            .filter(|line| is_total_line(line))
+            .flat_map(|line| amounts(line, currency))
+            .max_by_key(|amount| amount.amount().abs())
+            .or_else(|| {
+                body.lines()
+                    .filter(|line| !is_part_line(line))
+                    .flat_map(|line| amounts(line, currency))
+                    .max_by_key(|amount| amount.amount().abs())
+            })
+            .and_then(|amount| {
+                if amount.is_negative() {
+                    Some(amount)
+                } else {
+                    amount.checked_neg().ok()
+                }
+            })
+            .filter(|amount| !amount.is_zero());
+
+        Self {
+            payee,
+            description,
+            amount,
+        }
+    }
+}
+
+/// Removes "Fwd:", "Fw:" and "Re:" prefixes, however many there are.
+fn clean_subject(subject: &str) -> String {
+    let mut subject = subject.trim();
+
+    while let Some((prefix, rest)) = subject.split_once(':') {
+        if !["fwd", "fw", "re"].contains(&prefix.trim().to_lowercase().as_str()) {
+            break;
+        }
+        subject = rest.trim_start();
+    }
+
+    subject.trim().to_string()
+}
+
+fn payee_from_subject(subject: &str) -> Option<String> {
+    SUBJECT_PAYEE
+        .iter()
+        .find_map(|pattern| pattern.captures(subject))
+        .map(|captures| captures["payee"].trim().to_string())
+        .filter(|payee| !payee.is_empty())
+}
+
+/// Takes the sender of a forwarded message, by name or else by the domain of
+/// their address.
+fn payee_from_forwarded(body: &str) -> Option<String> {
+    let captures = FORWARDED_FROM.captures(body)?;
+
+    let name = captures["name"].trim();
+    if !name.is_empty() && !name.contains('@') {
+        return Some(name.to_string());
+    }
+
+    let address = captures
+        .name("address")
+        .map_or(name, |address| address.as_str());
+    let domain = address.rsplit_once('@')?.1;
+    let domain = domain.strip_prefix("mail.").unwrap_or(domain);
+
+    domain
+        .split('.')
+        .next()
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            let mut chars = name.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+}
+
+fn is_part_line(line: &str) -> bool {
+    let line = line.to_lowercase();
+
+    PART_WORDS.iter().any(|word| line.contains(word))
+        || ((line.contains("gst") || line.contains("tax")) && !line.contains("incl"))
+}
+
+fn is_total_line(line: &str) -> bool {
+    let lower = line.to_lowercase();
+
+    TOTAL_WORDS.iter().any(|word| lower.contains(word)) && !is_part_line(line)
+}
+
+/// Reads every amount written with a currency or with cents on a line. Plain
+/// whole numbers, such as order numbers and dates, are skipped.
+fn amounts(line: &str, currency: domain::Currency) -> Vec<domain::Money> {
+    AMOUNT
+        .captures_iter(line)
+        .filter_map(|captures| {
+            let code = captures
+                .name("code")
+                .map(|code| code.as_str().trim())
+                .filter(|code| code.parse::<domain::Currency>().is_ok());
+            let suffix = captures
+                .name("suffix")
+                .map(|suffix| suffix.as_str().trim())
+                .filter(|suffix| suffix.parse::<domain::Currency>().is_ok());
+            let symbol = captures.name("symbol").map(|symbol| symbol.as_str());
+            let number = &captures["number"];
+
+            if code.is_none() && suffix.is_none() && symbol.is_none() && !number.contains('.') {
+                return None;
+            }
+
+            let text = format!(
+                "{}{}{}{}",
+                code.unwrap_or_default(),
+                symbol.unwrap_or_default(),
+                number,
+                suffix
+                    .map(|suffix| format!(" {suffix}"))
+                    .unwrap_or_default()
+            );
+
+            domain::Money::parse(text, currency).ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::{Currency, Money};
+
+    #[test]
+    fn extract_prefers_total_over_larger_subtotal_lines() {
+        let body = "Subtotal $120.00\nDiscount $20.00\nTotal (incl. GST) $100.00\nGST $9.09";
+
+        let details =
+            ReceiptDetails::extract(Some("Invoice from Hardware Store"), body, Currency::AUD);
+
+        assert_eq!(details.payee, "Hardware Store");
+        assert_eq!(details.amount, Some(Money::new(-10_000, Currency::AUD)));
+    }
+
+    #[test]
+    fn extract_falls_back_to_largest_amount() {
+        let body = "Order 123456 placed 14/03/2025\nWidget x2 $8.00\nShipping $4.95";
+
+        let details = ReceiptDetails::extract(Some("Your Acme order"), body, Currency::AUD);
+
+        assert_eq!(details.payee, "Acme");
+        assert_eq!(details.amount, Some(Money::new(-800, Currency::AUD)));
+    }
+
+    #[test]
+    fn extract_reads_currency_codes_and_symbols() {
+        let details = ReceiptDetails::extract(None, "Amount charged: USD 42.10", Currency::AUD);
+        assert_eq!(details.amount, Some(Money::new(-4_210, Currency::USD)));
+
+        let details = ReceiptDetails::extract(None, "Total £8", Currency::AUD);
+        assert_eq!(details.amount, Some(Money::new(-800, Currency::GBP)));
+
+        let details = ReceiptDetails::extract(None, "Total paid 1,250.00 NZD", Currency::AUD);
+        assert_eq!(details.amount, Some(Money::new(-125_000, Currency::NZD)));
+    }
+
+    #[test]
+    fn extract_without_amount_or_payee() {
+        let details =
+            ReceiptDetails::extract(Some("Re: Fwd: thanks"), "See attached", Currency::AUD);
+
+        assert_eq!(details.payee, "Receipt");
+        assert_eq!(details.description.as_deref(), Some("thanks"));
+        assert_eq!(details.amount, None);
+    }
+
+    #[test]
+    fn extract_takes_payee_from_forwarded_sender() {
+        let body = "---------- Forwarded message ---------\n\
+            From: <no-reply@mail.cornercafe.com.au>\n\
+            Subject: Thanks for visiting\n\
+            Total $7.20";
+
+        let details =
+            ReceiptDetails::extract(Some("Fwd: Thanks for visiting"), body, Currency::AUD);
+
+        assert_eq!(details.payee, "Cornercafe");
+        assert_eq!(details.amount, Some(Money::new(-720, Currency::AUD)));
+
+        let details =
+            ReceiptDetails::extract(None, "From: \"Corner Cafe\" <hi@cafe.com>", Currency::AUD);
+        assert_eq!(details.payee, "Corner Cafe");
+    }
+}
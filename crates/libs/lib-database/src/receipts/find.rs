@@ -0,0 +1,170 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::ReceiptEmails {
+    /// Finds the receipt emails no draft transaction could be created for, or
+    /// whose draft has since been deleted, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(name = "Find unmatched receipt emails", skip(conn), err)]
+    pub fn find_unmatched<'c, A>(
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let emails = sqlx::query_as!(
+                database::ReceiptEmails,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        message_id,
+                        sender,
+                        subject,
+                        received_on         AS "received_on!: chrono::DateTime<chrono::Utc>",
+                        transaction_id      AS "transaction_id?: domain::RowID",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>"
+                    FROM receipt_emails
+                    WHERE transaction_id IS NULL
+                    ORDER BY received_on ASC
+                "#
+            )
+            .fetch_all(&mut *conn)
+            .observe("receipts.find_unmatched")
+            .await?;
+
+            Ok(emails)
+        }
+    }
+
+    /// Finds the receipt email a transaction was created from.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - The transaction to find the receipt for
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(name = "Find receipt email by transaction", skip(conn), err)]
+    pub fn find_by_transaction<'c, A>(
+        transaction_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let email = sqlx::query_as!(
+                database::ReceiptEmails,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        message_id,
+                        sender,
+                        subject,
+                        received_on         AS "received_on!: chrono::DateTime<chrono::Utc>",
+                        transaction_id      AS "transaction_id?: domain::RowID",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>"
+                    FROM receipt_emails
+                    WHERE transaction_id = ?
+                "#,
+                transaction_id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("receipts.find_by_transaction")
+            .await?;
+
+            Ok(email)
+        }
+    }
+}
+
+impl database::ReceiptAttachments {
+    /// Finds the attachments of a receipt email, in the order they were stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `receipt_email_id` - The receipt email the attachments belong to
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(name = "Find receipt attachments", skip(conn), err)]
+    pub fn find_by_email<'c, A>(
+        receipt_email_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let attachments = sqlx::query_as!(
+                database::ReceiptAttachments,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        receipt_email_id    AS "receipt_email_id!: domain::RowID",
+                        file_name,
+                        content_type,
+                        content,
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>"
+                    FROM receipt_attachments
+                    WHERE receipt_email_id = ?
+                    ORDER BY rowid ASC
+                "#,
+                receipt_email_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("receipts.find_by_email")
+            .await?;
+
+            Ok(attachments)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::receipts::insert::tests::{Result, receipt};
+
+    #[sqlx::test]
+    async fn find_unmatched_includes_deleted_drafts(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let matched = database::ReceiptEmails::ingest(
+            &receipt("1@cafe", "Receipt from Corner Cafe", "Total $11.50"),
+            &pool,
+        )
+        .await?
+        .unwrap();
+        let unmatched =
+            database::ReceiptEmails::ingest(&receipt("2@shop", "Hello", "No amount"), &pool)
+                .await?
+                .unwrap();
+
+        assert_eq!(
+            database::ReceiptEmails::find_unmatched(&pool).await?,
+            vec![unmatched.clone()]
+        );
+
+        let transaction_id = matched.transaction_id.unwrap();
+        let found = database::ReceiptEmails::find_by_transaction(transaction_id, &pool).await?;
+        assert_eq!(found.map(|email| email.id), Some(matched.id));
+
+        sqlx::query("DELETE FROM transactions WHERE id = ?")
+            .bind(transaction_id)
+            .execute(&pool)
+            .await?;
+
+        assert_eq!(
+            database::ReceiptEmails::find_unmatched(&pool).await?.len(),
+            2
+        );
+
+        Ok(())
+    }
+}
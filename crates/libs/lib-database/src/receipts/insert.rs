@@ -0,0 +1,354 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+impl database::ReceiptEmails {
+    /// Stores a receipt email and its attachments, with a draft transaction
+    /// for the amount found in it.
+    ///
+    /// The draft is dated the day the email was received and its payee is
+    /// cleaned up with the payee aliases. An amount in another currency is
+    /// converted to Australian dollars at the rate for that day and noted in
+    /// the description. If no amount is found, or there is no rate to convert
+    /// it, the email is stored without a draft. Everything is written in one
+    /// database transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `receipt` - The email as read from the mailbox
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored email, or `None` if an email with the same
+    /// Message-ID was already ingested.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The Message-ID is empty (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{InboundReceipt, ReceiptEmails};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let receipt = InboundReceipt {
+    ///     message_id: "4521@cornercafe.com.au".to_string(),
+    ///     sender: Some("me@example.com".to_string()),
+    ///     subject: Some("Fwd: Your receipt from Corner Cafe".to_string()),
+    ///     received_on: chrono::Utc::now(),
+    ///     body: "Total $11.50".to_string(),
+    ///     attachments: Vec::new(),
+    /// };
+    ///
+    /// if let Some(stored) = ReceiptEmails::ingest(&receipt, pool).await? {
+    ///     println!("Draft transaction {:?}", stored.transaction_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Ingest receipt email",
+        skip(receipt, conn),
+        fields(message_id = %receipt.message_id),
+        err
+    )]
+    pub fn ingest<'a, 'c, A>(
+        receipt: &'a database::InboundReceipt,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let message_id = receipt.message_id.trim();
+            if message_id.is_empty() {
+                return Err(DatabaseError::Validation(
+                    "Receipt email must have a Message-ID".to_string(),
+                ));
+            }
+
+            let mut tx = conn.begin().await?;
+
+            let already_ingested = sqlx::query_scalar!(
+                r#"
+                    SELECT EXISTS (
+                        SELECT 1 FROM receipt_emails WHERE message_id = ?
+                    ) AS "exists!: bool"
+                "#,
+                message_id
+            )
+            .fetch_one(&mut *tx)
+            .observe_one("receipts.ingest")
+            .await?;
+
+            if already_ingested {
+                tx.rollback().await?;
+                tracing::info!("Receipt email already ingested, skipping.");
+                return Ok(None);
+            }
+
+            let details = database::ReceiptDetails::extract(
+                receipt.subject.as_deref(),
+                &receipt.body,
+                domain::Currency::default(),
+            );
+            let received_on = receipt.received_on.date_naive();
+
+            let transaction_id = match details.amount {
+                Some(amount) => {
+                    let converted = match database::ExchangeRates::convert(
+                        amount,
+                        domain::Currency::default(),
+                        received_on,
+                        &mut *tx,
+                    )
+                    .await
+                    {
+                        Ok(converted) => Some(converted),
+                        Err(DatabaseError::NotFound(reason)) => {
+                            tracing::warn!("Receipt stored without a draft: {reason}");
+                            None
+                        }
+                        Err(error) => return Err(error),
+                    };
+
+                    match converted {
+                        Some(converted) => {
+                            let normaliser = database::PayeeNormaliser::load(&mut *tx).await?;
+
+                            let mut draft = database::Transactions::new(
+                                received_on,
+                                normaliser.normalise(&details.payee),
+                                converted.amount(),
+                            );
+                            draft.is_draft = true;
+                            draft.description = if amount.currency() == converted.currency() {
+                                details.description.clone()
+                            } else {
+                                Some(match &details.description {
+                                    Some(description) => {
+                                        format!("{description} ({})", amount.format_with_code())
+                                    }
+                                    None => amount.format_with_code(),
+                                })
+                            };
+
+                            Some(draft.insert(&mut *tx).await?.id)
+                        }
+                        None => None,
+                    }
+                }
+                None => None,
+            };
+
+            let now = chrono::Utc::now();
+            let email = Self {
+                id: domain::RowID::new(),
+                message_id: message_id.to_string(),
+                sender: receipt.sender.clone(),
+                subject: receipt.subject.clone(),
+                received_on: receipt.received_on,
+                transaction_id,
+                created_on: now,
+            };
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO receipt_emails (
+                        id, message_id, sender, subject, received_on, transaction_id, created_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+                email.id,
+                email.message_id,
+                email.sender,
+                email.subject,
+                email.received_on,
+                email.transaction_id,
+                email.created_on
+            )
+            .execute(&mut *tx)
+            .observe("receipts.ingest")
+            .await?;
+
+            for attachment in &receipt.attachments {
+                let id = domain::RowID::new();
+
+                sqlx::query!(
+                    r#"
+                        INSERT INTO receipt_attachments (
+                            id, receipt_email_id, file_name, content_type, content, created_on
+                        )
+                        VALUES (?, ?, ?, ?, ?, ?)
+                    "#,
+                    id,
+                    email.id,
+                    attachment.file_name,
+                    attachment.content_type,
+                    attachment.content,
+                    now
+                )
+                .execute(&mut *tx)
+                .observe("receipts.ingest")
+                .await?;
+            }
+
+            tx.commit().await?;
+
+            tracing::info!(
+                "Receipt email ingested with {} attachments.",
+                receipt.attachments.len()
+            );
+
+            Ok(Some(email))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    pub(crate) fn receipt(message_id: &str, subject: &str, body: &str) -> database::InboundReceipt {
+        database::InboundReceipt {
+            message_id: message_id.to_string(),
+            sender: Some("me@example.com".to_string()),
+            subject: Some(subject.to_string()),
+            received_on: chrono::DateTime::parse_from_rfc3339("2025-03-14T09:30:00+00:00")
+                .unwrap()
+                .to_utc(),
+            body: body.to_string(),
+            attachments: vec![database::InboundAttachment {
+                file_name: "receipt.pdf".to_string(),
+                content_type: "application/pdf".to_string(),
+                content: b"%PDF-1.4".to_vec(),
+            }],
+        }
+    }
+
+    #[sqlx::test]
+    async fn ingest_creates_draft_transaction(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        database::PayeeAliases::new("Corner Cafe", database::PayeeMatchKinds::Exact, "Cafe")
+            .insert(&pool)
+            .await?;
+        let receipt = receipt(
+            "1@cafe",
+            "Fwd: Your receipt from Corner Cafe",
+            "Total $11.50",
+        );
+
+        let stored = database::ReceiptEmails::ingest(&receipt, &pool)
+            .await?
+            .unwrap();
+
+        let draft = database::Transactions::find_by_id(
+            stored.transaction_id.unwrap(),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?
+        .unwrap();
+        assert!(draft.is_draft);
+        assert_eq!(draft.payee, "Cafe");
+        assert_eq!(draft.amount, -1_150);
+        assert_eq!(draft.transaction_date, receipt.received_on.date_naive());
+
+        let attachments = database::ReceiptAttachments::find_by_email(stored.id, &pool).await?;
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].content, b"%PDF-1.4");
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn ingest_skips_duplicate_message_id(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let receipt = receipt("1@cafe", "Receipt from Corner Cafe", "Total $11.50");
+
+        assert!(
+            database::ReceiptEmails::ingest(&receipt, &pool)
+                .await?
+                .is_some()
+        );
+        assert!(
+            database::ReceiptEmails::ingest(&receipt, &pool)
+                .await?
+                .is_none()
+        );
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM transactions")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(count, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn ingest_without_amount_or_rate_stores_email_only(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let no_amount = receipt("1@shop", "Your order", "Thanks for shopping");
+        let no_rate = receipt("2@shop", "Your order", "Total USD 20.00");
+
+        let first = database::ReceiptEmails::ingest(&no_amount, &pool)
+            .await?
+            .unwrap();
+        let second = database::ReceiptEmails::ingest(&no_rate, &pool)
+            .await?
+            .unwrap();
+
+        assert_eq!(first.transaction_id, None);
+        assert_eq!(second.transaction_id, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn ingest_converts_foreign_amounts(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let rate =
+            domain::ExchangeRate::parse(domain::Currency::AUD, domain::Currency::USD, "0.5")?;
+        database::ExchangeRates::new(rate, chrono::NaiveDate::from_ymd_opt(2025, 3, 13).unwrap())
+            .insert(&pool)
+            .await?;
+        let receipt = receipt("1@shop", "Invoice from Acme", "Amount charged: USD 20.00");
+
+        let stored = database::ReceiptEmails::ingest(&receipt, &pool)
+            .await?
+            .unwrap();
+
+        let draft = database::Transactions::find_by_id(
+            stored.transaction_id.unwrap(),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?
+        .unwrap();
+        assert_eq!(draft.amount, -4_000);
+        assert_eq!(
+            draft.description.as_deref(),
+            Some("Invoice from Acme (-20.00 USD)")
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn ingest_rejects_missing_message_id(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let receipt = receipt(" ", "Receipt from Corner Cafe", "Total $11.50");
+
+        let result = database::ReceiptEmails::ingest(&receipt, &pool).await;
+
+        assert!(matches!(result, Err(DatabaseError::Validation(_))));
+
+        Ok(())
+    }
+}
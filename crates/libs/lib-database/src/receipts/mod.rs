@@ -0,0 +1,23 @@
+//! # Receipts Database Module
+//!
+//! Turns receipts forwarded by email into draft transactions, so "forward the
+//! receipt" is enough to capture a purchase. The mail itself is read by the
+//! server, which hands each email over as an [`InboundReceipt`]. The merchant
+//! and total are found in its subject and text ([`ReceiptDetails`]), a draft
+//! transaction is created for review, and the email and its attachments are
+//! stored with a link to the draft.
+//!
+//! Emails are recorded by Message-ID and ingesting the same email again does
+//! nothing. An email with no amount that can be read is still stored, without
+//! a draft, so it can be matched by hand.
+
+mod extract;
+mod find;
+mod insert;
+mod model;
+
+/// An email as read from the mailbox, and the stored email and attachments.
+pub use model::{InboundAttachment, InboundReceipt, ReceiptAttachments, ReceiptEmails};
+
+/// The merchant and amount found in a receipt email.
+pub use extract::ReceiptDetails;
@@ -0,0 +1,48 @@
+use lib_domain as domain;
+
+/// A receipt email as read from a mailbox, before it is stored.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct InboundReceipt {
+    /// The email's Message-ID header, used to ingest it only once.
+    pub message_id: String,
+    pub sender: Option<String>,
+    pub subject: Option<String>,
+    pub received_on: chrono::DateTime<chrono::Utc>,
+
+    /// The plain text body, or the HTML body converted to text.
+    pub body: String,
+    pub attachments: Vec<InboundAttachment>,
+}
+
+/// A file attached to a receipt email.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct InboundAttachment {
+    pub file_name: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+/// A stored receipt email.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct ReceiptEmails {
+    pub id: domain::RowID,
+    pub message_id: String,
+    pub sender: Option<String>,
+    pub subject: Option<String>,
+    pub received_on: chrono::DateTime<chrono::Utc>,
+
+    /// The draft transaction created from the email, if an amount was found.
+    pub transaction_id: Option<domain::RowID>,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+}
+
+/// A stored attachment of a receipt email.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct ReceiptAttachments {
+    pub id: domain::RowID,
+    pub receipt_email_id: domain::RowID,
+    pub file_name: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+}
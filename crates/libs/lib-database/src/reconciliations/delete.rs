@@ -0,0 +1,149 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Reconciliations {
+    /// Undoes a reconciliation, setting its transactions back to cleared.
+    ///
+    /// Only the account's latest reconciliation can be undone, as the next
+    /// statement starts where it ended.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the reconciliation to undo
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns how many transactions were set back to cleared, or a
+    /// `DatabaseError` if it fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The reconciliation does not exist (`DatabaseError::NotFound`)
+    /// - A later statement of the account is reconciled (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Reconciliations;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, reconciliation_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let cleared = Reconciliations::delete_by_id(reconciliation_id, pool).await?;
+    /// println!("{cleared} transactions set back to cleared");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Undo reconciliation",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<u64>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            let reconciliation = Self::find_by_id(id, &mut *tx).await?.ok_or_else(|| {
+                DatabaseError::NotFound(format!("Reconciliation with id {id} not found"))
+            })?;
+            let latest = Self::fetch_latest(reconciliation.account_id, &mut tx).await?;
+            if latest.is_some_and(|latest| latest.id != id) {
+                return Err(DatabaseError::Validation(format!(
+                    "A statement after {} is reconciled, undo it first",
+                    reconciliation.statement_date
+                )));
+            }
+
+            let updated_on = chrono::Utc::now();
+            let cleared = sqlx::query!(
+                r#"
+                    UPDATE transactions
+                    SET cleared_status = 'cleared', reconciliation_id = NULL, updated_on = ?,
+                        version = version + 1
+                    WHERE reconciliation_id = ?
+                "#,
+                updated_on,
+                id
+            )
+            .execute(&mut *tx)
+            .observe("reconciliations.delete_by_id")
+            .await?
+            .rows_affected();
+
+            sqlx::query!(
+                r#"
+                    DELETE FROM reconciliations
+                    WHERE id = ?
+                "#,
+                id
+            )
+            .execute(&mut *tx)
+            .observe("reconciliations.delete_by_id")
+            .await?;
+
+            tx.commit().await?;
+
+            tracing::info!("Undid reconciliation {id}, {cleared} transactions set back to cleared");
+
+            Ok(cleared)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reconciliations::reconcile::tests::{Result, day, posted};
+
+    #[sqlx::test]
+    async fn delete_by_id_sets_transactions_back_to_cleared(pool: sqlx::SqlitePool) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let first = posted(account.id, day(1), -1_000, &pool).await?;
+        let second = posted(account.id, day(20), -2_000, &pool).await?;
+        let march = database::Reconciliations::new(account.id, day(15), 10_000, 9_000)
+            .reconcile(&[first.id], database::UserScope::All, &pool)
+            .await?;
+        let april = database::Reconciliations::new(account.id, day(31), 9_000, 7_000)
+            .reconcile(&[second.id], database::UserScope::All, &pool)
+            .await?;
+
+        let out_of_order = database::Reconciliations::delete_by_id(march.id, &pool).await;
+        assert!(matches!(out_of_order, Err(DatabaseError::Validation(_))));
+
+        assert_eq!(
+            database::Reconciliations::delete_by_id(april.id, &pool).await?,
+            1
+        );
+        let second =
+            database::Transactions::find_by_id(second.id, database::UserScope::All, &pool).await?;
+        assert_eq!(
+            second.map(|t| t.cleared_status),
+            Some(database::ClearedStatus::Cleared)
+        );
+        assert_eq!(
+            database::Reconciliations::find_by_account(account.id, &pool).await?,
+            vec![march]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn delete_by_id_not_found(pool: sqlx::SqlitePool) -> Result<()> {
+        let result = database::Reconciliations::delete_by_id(domain::RowID::new(), &pool).await;
+
+        assert!(matches!(result, Err(DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Reconciliations {
+    /// Finds a reconciliation by its unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Reconciliations)` if the reconciliation exists, or `None`
+    /// if not found.
+    #[tracing::instrument(
+        name = "Find reconciliation by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let reconciliation = sqlx::query_as!(
+                database::Reconciliations,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        account_id          AS "account_id!: domain::RowID",
+                        statement_date      AS "statement_date!: chrono::NaiveDate",
+                        starting_balance    AS "starting_balance!: i64",
+                        ending_balance      AS "ending_balance!: i64",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>"
+                    FROM reconciliations
+                    WHERE id = ?
+                "#,
+                id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("reconciliations.find_by_id")
+            .await?;
+
+            Ok(reconciliation)
+        }
+    }
+
+    /// Finds the reconciliations of an account, latest statement first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Reconciliations;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, account_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// for reconciliation in Reconciliations::find_by_account(account_id, pool).await? {
+    ///     println!("{}: {}", reconciliation.statement_date, reconciliation.ending_balance);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find reconciliations of account",
+        skip(conn),
+        fields(account_id = %account_id),
+        err
+    )]
+    pub fn find_by_account<'c, A>(
+        account_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let reconciliations = sqlx::query_as!(
+                database::Reconciliations,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        account_id          AS "account_id!: domain::RowID",
+                        statement_date      AS "statement_date!: chrono::NaiveDate",
+                        starting_balance    AS "starting_balance!: i64",
+                        ending_balance      AS "ending_balance!: i64",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>"
+                    FROM reconciliations
+                    WHERE account_id = ?
+                    ORDER BY statement_date DESC
+                "#,
+                account_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("reconciliations.find_by_account")
+            .await?;
+
+            Ok(reconciliations)
+        }
+    }
+
+    /// Fetches the account's latest reconciliation, the one the next
+    /// statement follows on from.
+    pub(crate) async fn fetch_latest(
+        account_id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let latest = sqlx::query_as!(
+            database::Reconciliations,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    account_id          AS "account_id!: domain::RowID",
+                    statement_date      AS "statement_date!: chrono::NaiveDate",
+                    starting_balance    AS "starting_balance!: i64",
+                    ending_balance      AS "ending_balance!: i64",
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>"
+                FROM reconciliations
+                WHERE account_id = ?
+                ORDER BY statement_date DESC
+                LIMIT 1
+            "#,
+            account_id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("reconciliations.fetch_latest")
+        .await?;
+
+        Ok(latest)
+    }
+}
@@ -0,0 +1,22 @@
+//! # Reconciliations Database Module
+//!
+//! Reconciles accounts against their bank or card statements. A
+//! reconciliation records a statement's date and its starting and ending
+//! balances, and marks the transactions on it reconciled, all at once. The
+//! transactions must be posted to the account, dated on or before the
+//! statement, and add up to the difference between its balances.
+//!
+//! Each statement starts where the account's previous reconciliation ended,
+//! so the reconciliations of an account form an unbroken chain. Only the
+//! latest can be undone, which sets its transactions back to cleared.
+//!
+//! Transactions are ticked off as cleared beforehand with
+//! [`Transactions::mark_cleared`](crate::Transactions::mark_cleared).
+
+mod delete;
+mod find;
+mod model;
+mod reconcile;
+
+/// Database row model representing a reconciled statement.
+pub use model::Reconciliations;
@@ -0,0 +1,52 @@
+use crate as database;
+use lib_domain as domain;
+
+/// An account reconciled against one of its statements.
+///
+/// Balances are in minor units (cents), as on the statement.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct Reconciliations {
+    pub id: domain::RowID,
+    pub account_id: domain::RowID,
+    pub statement_date: chrono::NaiveDate,
+    pub starting_balance: i64,
+    pub ending_balance: i64,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::Reconciliations {
+    /// Creates a new, unsaved reconciliation of a statement.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::Reconciliations;
+    /// use lib_domain::RowID;
+    ///
+    /// let statement_date = chrono::NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+    /// let reconciliation = Reconciliations::new(RowID::new(), statement_date, 120_000, 95_500);
+    ///
+    /// assert_eq!(reconciliation.difference(), -24_500);
+    /// ```
+    pub fn new(
+        account_id: domain::RowID,
+        statement_date: chrono::NaiveDate,
+        starting_balance: i64,
+        ending_balance: i64,
+    ) -> Self {
+        Self {
+            id: domain::RowID::new(),
+            account_id,
+            statement_date,
+            starting_balance,
+            ending_balance,
+            created_on: chrono::Utc::now(),
+        }
+    }
+
+    /// How much the statement's transactions must add up to, its ending
+    /// balance less its starting balance.
+    pub fn difference(&self) -> i64 {
+        self.ending_balance - self.starting_balance
+    }
+}
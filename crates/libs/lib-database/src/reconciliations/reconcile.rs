@@ -0,0 +1,336 @@
+use std::collections::BTreeSet;
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Reconciliations {
+    /// Reconciles the account against a statement, marking the statement's
+    /// transactions reconciled.
+    ///
+    /// The reconciliation is recorded and its transactions marked in a single
+    /// database transaction: either the whole statement is reconciled or,
+    /// if anything about it does not check out, nothing is changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_ids` - The IDs of the transactions on the statement
+    /// * `scope` - The user whose transactions are reconciled
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the recorded reconciliation, or a `DatabaseError` if the
+    /// statement could not be reconciled.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The account, or a transaction, does not exist or is outside `scope`
+    ///   (`DatabaseError::NotFound`)
+    /// - The statement is not after the account's latest reconciliation, or
+    ///   does not start at its ending balance (`DatabaseError::Validation`)
+    /// - A transaction is not posted to the account, is dated after the
+    ///   statement, or is already reconciled (`DatabaseError::Validation`)
+    /// - The transactions do not add up to the difference between the
+    ///   statement's balances (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Reconciliations, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, account_id: RowID, on_statement: Vec<RowID>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let statement_date = chrono::NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+    ///
+    /// Reconciliations::new(account_id, statement_date, 120_000, 95_500)
+    ///     .reconcile(&on_statement, UserScope::All, pool)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Reconcile account statement",
+        skip(self, transaction_ids, conn),
+        fields(
+            id = %self.id,
+            account_id = %self.account_id,
+            statement_date = %self.statement_date,
+            count = transaction_ids.len(),
+        ),
+        err
+    )]
+    pub fn reconcile<'a, 'c, A>(
+        &'a self,
+        transaction_ids: &'a [domain::RowID],
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            if let Err(error) = self.record(transaction_ids, scope, &mut tx).await {
+                // Roll back now rather than on drop, so the write lock is
+                // released before the caller sees the error
+                tx.rollback().await?;
+                return Err(error);
+            }
+
+            tx.commit().await?;
+
+            tracing::info!(
+                "Reconciled {} transactions against the statement of {}",
+                transaction_ids.len(),
+                self.statement_date
+            );
+
+            Ok(self.clone())
+        }
+    }
+
+    /// Checks the statement and its transactions, then records the
+    /// reconciliation within the caller's database transaction.
+    async fn record(
+        &self,
+        transaction_ids: &[domain::RowID],
+        scope: database::UserScope,
+        tx: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<()> {
+        if database::Accounts::find_by_id(self.account_id, scope, &mut *tx)
+            .await?
+            .is_none()
+        {
+            return Err(DatabaseError::NotFound(format!(
+                "Account with id {} not found",
+                self.account_id
+            )));
+        }
+
+        if let Some(latest) = Self::fetch_latest(self.account_id, &mut *tx).await? {
+            if self.statement_date <= latest.statement_date {
+                return Err(DatabaseError::Validation(format!(
+                    "Account is already reconciled through {}",
+                    latest.statement_date
+                )));
+            }
+            if self.starting_balance != latest.ending_balance {
+                return Err(DatabaseError::Validation(format!(
+                    "Statement must start at {}, where the statement of {} ended",
+                    latest.ending_balance, latest.statement_date
+                )));
+            }
+        }
+
+        let ids: BTreeSet<_> = transaction_ids.iter().copied().collect();
+        let mut total = 0;
+        for &id in &ids {
+            let transaction = database::Transactions::find_by_id(id, scope, &mut *tx)
+                .await?
+                .ok_or_else(|| {
+                    DatabaseError::NotFound(format!("Transaction with id {id} not found"))
+                })?;
+
+            if transaction.account_id != Some(self.account_id) || transaction.is_draft {
+                return Err(DatabaseError::Validation(format!(
+                    "Transaction {id} is not posted to the account"
+                )));
+            }
+            if transaction.transaction_date > self.statement_date {
+                return Err(DatabaseError::Validation(format!(
+                    "Transaction {id} is dated after the statement"
+                )));
+            }
+            if transaction.cleared_status == database::ClearedStatus::Reconciled {
+                return Err(DatabaseError::Validation(format!(
+                    "Transaction {id} is already reconciled"
+                )));
+            }
+
+            total += transaction.amount;
+        }
+
+        if total != self.difference() {
+            return Err(DatabaseError::Validation(format!(
+                "Statement does not balance: its transactions add up to {total}, not {}",
+                self.difference()
+            )));
+        }
+
+        sqlx::query!(
+            r#"
+                INSERT INTO reconciliations (id, account_id, statement_date, starting_balance, ending_balance, created_on)
+                VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+            self.id,
+            self.account_id,
+            self.statement_date,
+            self.starting_balance,
+            self.ending_balance,
+            self.created_on
+        )
+        .execute(&mut *tx)
+        .observe("reconciliations.reconcile")
+        .await?;
+
+        for id in ids {
+            sqlx::query!(
+                r#"
+                    UPDATE transactions
+                    SET cleared_status = 'reconciled', reconciliation_id = ?, updated_on = ?,
+                        version = version + 1
+                    WHERE id = ?
+                "#,
+                self.id,
+                self.created_on,
+                id
+            )
+            .execute(&mut *tx)
+            .observe("reconciliations.reconcile")
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    pub fn day(day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    /// Posts a transaction to the account.
+    pub async fn posted(
+        account_id: domain::RowID,
+        date: chrono::NaiveDate,
+        amount: i64,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<database::Transactions> {
+        let mut transaction = database::Transactions::new(date, "Corner Store", amount);
+        transaction.account_id = Some(account_id);
+
+        Ok(transaction.insert(pool).await?)
+    }
+
+    #[sqlx::test]
+    async fn reconcile_marks_the_statement_reconciled(pool: sqlx::SqlitePool) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let wages = posted(account.id, day(1), 250_000, &pool).await?;
+        let rent = posted(account.id, day(3), -180_000, &pool).await?;
+        let later = posted(account.id, day(20), -4_500, &pool).await?;
+
+        let reconciliation = database::Reconciliations::new(account.id, day(15), 10_000, 80_000)
+            .reconcile(&[wages.id, rent.id], database::UserScope::All, &pool)
+            .await?;
+
+        for (transaction, status) in [
+            (wages, database::ClearedStatus::Reconciled),
+            (rent, database::ClearedStatus::Reconciled),
+            (later, database::ClearedStatus::Uncleared),
+        ] {
+            let found =
+                database::Transactions::find_by_id(transaction.id, database::UserScope::All, &pool)
+                    .await?;
+            assert_eq!(found.map(|t| t.cleared_status), Some(status));
+        }
+        assert_eq!(
+            database::Reconciliations::find_by_account(account.id, &pool).await?,
+            vec![reconciliation]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn reconcile_refuses_a_statement_that_does_not_balance(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let wages = posted(account.id, day(1), 250_000, &pool).await?;
+
+        let result = database::Reconciliations::new(account.id, day(15), 10_000, 250_000)
+            .reconcile(&[wages.id], database::UserScope::All, &pool)
+            .await;
+
+        assert!(matches!(result, Err(DatabaseError::Validation(_))));
+        assert!(
+            database::Reconciliations::find_by_account(account.id, &pool)
+                .await?
+                .is_empty()
+        );
+        let wages =
+            database::Transactions::find_by_id(wages.id, database::UserScope::All, &pool).await?;
+        assert_eq!(
+            wages.map(|t| t.cleared_status),
+            Some(database::ClearedStatus::Uncleared)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn reconcile_follows_on_from_the_previous_statement(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let first = posted(account.id, day(1), -1_000, &pool).await?;
+        let second = posted(account.id, day(20), -2_000, &pool).await?;
+        database::Reconciliations::new(account.id, day(15), 10_000, 9_000)
+            .reconcile(&[first.id], database::UserScope::All, &pool)
+            .await?;
+
+        let gap = database::Reconciliations::new(account.id, day(31), 10_000, 8_000)
+            .reconcile(&[second.id], database::UserScope::All, &pool)
+            .await;
+        let again = database::Reconciliations::new(account.id, day(31), 9_000, 8_000)
+            .reconcile(&[first.id], database::UserScope::All, &pool)
+            .await;
+        assert!(matches!(gap, Err(DatabaseError::Validation(_))));
+        assert!(matches!(again, Err(DatabaseError::Validation(_))));
+
+        database::Reconciliations::new(account.id, day(31), 9_000, 7_000)
+            .reconcile(&[second.id], database::UserScope::All, &pool)
+            .await?;
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn reconciled_transactions_are_locked(pool: sqlx::SqlitePool) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let wages = posted(account.id, day(1), 250_000, &pool).await?;
+        database::Reconciliations::new(account.id, day(15), 0, 250_000)
+            .reconcile(&[wages.id], database::UserScope::All, &pool)
+            .await?;
+        let wages = database::Transactions::find_by_id(wages.id, database::UserScope::All, &pool)
+            .await?
+            .unwrap();
+
+        let mut changed = wages.clone();
+        changed.amount = 260_000;
+        let update = changed.update(database::UserScope::All, &pool).await;
+        let delete =
+            database::Transactions::delete_by_id(wages.id, database::UserScope::All, &pool).await;
+        assert!(matches!(update, Err(DatabaseError::Validation(_))));
+        assert!(matches!(delete, Err(DatabaseError::Validation(_))));
+
+        // Anything but the amount, date and account can still be changed
+        let mut described = wages;
+        described.payee = "Acme Pty Ltd".to_string();
+        described.update(database::UserScope::All, &pool).await?;
+
+        Ok(())
+    }
+}
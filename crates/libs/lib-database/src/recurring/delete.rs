@@ -0,0 +1,86 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::RecurringTransactions {
+    /// Deletes a recurring transaction template from the database by its ID.
+    /// Transactions already created from the template are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the template to delete
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The template with the given ID does not exist (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Delete recurring transaction from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    DELETE FROM recurring_transactions
+                    WHERE id = ?
+                "#,
+                id
+            )
+            .execute(&mut *conn)
+            .observe("recurring.delete_by_id")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Recurring transaction with id {} not found",
+                    id
+                )));
+            }
+
+            tracing::info!("Deleted recurring transaction {} from database", id);
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recurring::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn delete_by_id_removes_template(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let template = database::RecurringTransactions::mock()
+            .insert(&pool)
+            .await?;
+
+        database::RecurringTransactions::delete_by_id(template.id, &pool).await?;
+
+        assert!(
+            database::RecurringTransactions::find_by_id(template.id, &pool)
+                .await?
+                .is_none()
+        );
+        assert!(matches!(
+            database::RecurringTransactions::delete_by_id(template.id, &pool).await,
+            Err(database::DatabaseError::NotFound(_))
+        ));
+
+        Ok(())
+    }
+}
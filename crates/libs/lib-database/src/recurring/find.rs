@@ -0,0 +1,246 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::RecurringTransactions {
+    /// Finds a recurring transaction template by its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the template to find
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(RecurringTransactions)` if the template exists, or `None`
+    /// if not found.
+    #[tracing::instrument(
+        name = "Find recurring transaction by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            Self::fetch_by_id(id, &mut conn).await
+        }
+    }
+
+    /// Finds the templates with an occurrence due on or before a date, most
+    /// overdue first. Templates whose schedule has ended are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - The day to find due templates for
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(name = "Find due recurring transactions", skip(conn), err)]
+    pub fn find_due<'c, A>(
+        on: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let templates = sqlx::query_as!(
+                database::RecurringTransactions,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        payee               AS "payee!",
+                        description,
+                        amount              AS "amount!: i64",
+                        category_id         AS "category_id?: domain::RowID",
+                        account_id          AS "account_id?: domain::RowID",
+                        frequency           AS "frequency!: database::RecurringFrequencies",
+                        starts_on           AS "starts_on!: chrono::NaiveDate",
+                        next_due            AS "next_due!: chrono::NaiveDate",
+                        ends_on             AS "ends_on?: chrono::NaiveDate",
+                        auto_post           AS "auto_post!: bool",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM recurring_transactions
+                    WHERE next_due <= ?1 AND (ends_on IS NULL OR next_due <= ends_on)
+                    ORDER BY next_due ASC, payee ASC
+                "#,
+                on
+            )
+            .fetch_all(&mut *conn)
+            .observe("recurring.find_due")
+            .await?;
+
+            Ok(templates)
+        }
+    }
+
+    /// Retrieves a page of templates, newest first.
+    ///
+    /// See the [`pagination`](crate::pagination) module for how pages are
+    /// read with a cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `after` - The cursor of the previous page, or `None` for the first page
+    /// * `page_size` - Maximum number of records to return, 50 if zero or negative
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the page of templates, with the total number of templates and
+    /// the cursor of the next page.
+    #[tracing::instrument(
+        name = "Find recurring transactions with pagination",
+        skip(conn),
+        fields(after = ?after, page_size = %page_size),
+        err
+    )]
+    pub fn find_all_with_pagination<'c, A>(
+        after: Option<database::PageCursor>,
+        page_size: i32,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<database::Page<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let page_size = database::pagination::page_size_or_default(page_size);
+            let limit = page_size + 1;
+            let (after_created_on, after_id) =
+                after.map(|after| (after.created_on, after.id)).unzip();
+
+            let mut conn = conn.acquire().await?;
+
+            let total_count: i32 =
+                sqlx::query_scalar("SELECT COUNT(*) as count FROM recurring_transactions")
+                    .fetch_one(&mut *conn)
+                    .observe_one("recurring.find_all_with_pagination")
+                    .await?;
+
+            let templates = sqlx::query_as!(
+                database::RecurringTransactions,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        payee               AS "payee!",
+                        description,
+                        amount              AS "amount!: i64",
+                        category_id         AS "category_id?: domain::RowID",
+                        account_id          AS "account_id?: domain::RowID",
+                        frequency           AS "frequency!: database::RecurringFrequencies",
+                        starts_on           AS "starts_on!: chrono::NaiveDate",
+                        next_due            AS "next_due!: chrono::NaiveDate",
+                        ends_on             AS "ends_on?: chrono::NaiveDate",
+                        auto_post           AS "auto_post!: bool",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM recurring_transactions
+                    WHERE ?1 IS NULL OR (created_on, id) < (?1, ?2)
+                    ORDER BY created_on DESC, id DESC
+                    LIMIT ?3
+                "#,
+                after_created_on,
+                after_id,
+                limit
+            )
+            .fetch_all(&mut *conn)
+            .observe("recurring.find_all_with_pagination")
+            .await?;
+
+            let page = database::Page::from_rows(templates, page_size, total_count, |template| {
+                database::PageCursor::new(template.created_on, template.id)
+            });
+
+            tracing::info!(
+                "Retrieved {} of {} recurring transactions from database",
+                page.items.len(),
+                total_count
+            );
+
+            Ok(page)
+        }
+    }
+
+    /// Reads a template back through the given connection.
+    pub(super) async fn fetch_by_id(
+        id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let template = sqlx::query_as!(
+            database::RecurringTransactions,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    payee               AS "payee!",
+                    description,
+                    amount              AS "amount!: i64",
+                    category_id         AS "category_id?: domain::RowID",
+                    account_id          AS "account_id?: domain::RowID",
+                    frequency           AS "frequency!: database::RecurringFrequencies",
+                    starts_on           AS "starts_on!: chrono::NaiveDate",
+                    next_due            AS "next_due!: chrono::NaiveDate",
+                    ends_on             AS "ends_on?: chrono::NaiveDate",
+                    auto_post           AS "auto_post!: bool",
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM recurring_transactions
+                WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("recurring.fetch_by_id")
+        .await?;
+
+        Ok(template)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recurring::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn find_due_skips_future_and_ended_templates(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let date = |month, day| chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap();
+        let monthly = database::RecurringFrequencies::Monthly;
+
+        let rent = database::RecurringTransactions::new("Landlord", -200_000, monthly, date(3, 1));
+        let rent = rent.insert(&pool).await?;
+        database::RecurringTransactions::new("Insurer", -9_000, monthly, date(3, 20))
+            .insert(&pool)
+            .await?;
+        let mut ended = database::RecurringTransactions::new("Gym", -2_500, monthly, date(1, 1));
+        ended.next_due = date(3, 1);
+        ended.ends_on = Some(date(2, 28));
+        ended.insert(&pool).await?;
+
+        let due = database::RecurringTransactions::find_due(date(3, 14), &pool).await?;
+
+        assert_eq!(due, vec![rent]);
+
+        let page =
+            database::RecurringTransactions::find_all_with_pagination(None, 2, &pool).await?;
+        assert_eq!(page.total_count, 3);
+        let payees: Vec<_> = page
+            .items
+            .iter()
+            .map(|template| template.payee.as_str())
+            .collect();
+        assert_eq!(payees, ["Gym", "Insurer"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::RecurringTransactions {
+    /// Inserts a new recurring transaction template into the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the inserted template as read back from the database, or a
+    /// `DatabaseError` if the insertion fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The payee is empty, the amount is zero or the dates are out of order
+    ///   (`DatabaseError::Validation`)
+    /// - The category or account does not exist (foreign key violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{RecurringFrequencies, RecurringTransactions};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let starts_on = chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    /// let mut rent = RecurringTransactions::new("Landlord", -200_000, RecurringFrequencies::Monthly, starts_on);
+    /// rent.auto_post = true;
+    ///
+    /// let inserted = rent.insert(pool).await?;
+    /// assert_eq!(inserted.next_due, starts_on);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert new recurring transaction into database",
+        skip(self, conn),
+        fields(id = %self.id, next_due = %self.next_due, amount = self.amount),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO recurring_transactions (
+                        id, payee, description, amount, category_id, account_id, frequency,
+                        starts_on, next_due, ends_on, auto_post, created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.payee,
+                self.description,
+                self.amount,
+                self.category_id,
+                self.account_id,
+                self.frequency,
+                self.starts_on,
+                self.next_due,
+                self.ends_on,
+                self.auto_post,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("recurring.insert")
+            .await?;
+
+            let inserted = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Recurring transaction with id {} not found after insert",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("New recurring transaction inserted into the database.");
+
+            Ok(inserted)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use lib_domain as domain;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn insert_recurring_transaction_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let mut template = database::RecurringTransactions::mock();
+        template.account_id = Some(account.id);
+        template.ends_on = Some(template.starts_on + chrono::Months::new(12));
+
+        let inserted = template.insert(&pool).await?;
+
+        assert_eq!(inserted, template);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_unknown_category_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut template = database::RecurringTransactions::mock();
+        template.category_id = Some(domain::RowID::new());
+
+        let result = template.insert(&pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+
+        Ok(())
+    }
+}
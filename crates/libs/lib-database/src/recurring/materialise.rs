@@ -0,0 +1,233 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::RecurringTransactions {
+    /// Creates a transaction for every occurrence of a template due on or
+    /// before `on`, and moves the template's next due date past them.
+    ///
+    /// The template is read again inside a single database transaction, so a
+    /// stale copy or two schedulers running at once cannot create the same
+    /// occurrence twice. Either every due occurrence is created or, if any of
+    /// them fails, none are and the due date is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the template to materialise
+    /// * `on` - The day to create due occurrences up to, inclusive
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the created transactions, oldest first. The list is empty if
+    /// nothing was due.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The template does not exist (`DatabaseError::NotFound`)
+    /// - An auto posted occurrence is in a closed period (`DatabaseError::PeriodClosed`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{RecurringFrequencies, RecurringTransactions};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let today = chrono::Utc::now().date_naive();
+    ///
+    /// for template in RecurringTransactions::find_due(today, pool).await? {
+    ///     let created = RecurringTransactions::materialise(template.id, today, pool).await?;
+    ///     println!("{}: {} transactions", template.payee, created.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Materialise recurring transaction", skip(conn), err)]
+    pub fn materialise<'c, A>(
+        id: domain::RowID,
+        on: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::Transactions>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            match Self::materialise_occurrences(id, on, &mut tx).await {
+                Ok(created) => {
+                    tx.commit().await?;
+
+                    if !created.is_empty() {
+                        tracing::info!(
+                            "Created {} transactions from recurring transaction {}",
+                            created.len(),
+                            id
+                        );
+                    }
+
+                    Ok(created)
+                }
+                Err(error) => {
+                    // Roll back now rather than on drop, so the write lock
+                    // is released before the caller sees the error
+                    tx.rollback().await?;
+                    Err(error)
+                }
+            }
+        }
+    }
+
+    /// Creates the due occurrences within the caller's database transaction.
+    async fn materialise_occurrences(
+        id: domain::RowID,
+        on: chrono::NaiveDate,
+        tx: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Vec<database::Transactions>> {
+        let mut template = Self::fetch_by_id(id, &mut *tx).await?.ok_or_else(|| {
+            database::DatabaseError::NotFound(format!(
+                "Recurring transaction with id {} not found",
+                id
+            ))
+        })?;
+
+        let mut created = Vec::new();
+        while template.is_due(on) {
+            created.push(template.occurrence().insert(&mut *tx).await?);
+            template.next_due = template
+                .frequency
+                .next_after(template.starts_on, template.next_due);
+        }
+
+        if created.is_empty() {
+            return Ok(created);
+        }
+
+        let updated_on = chrono::Utc::now();
+        sqlx::query!(
+            r#"
+                UPDATE recurring_transactions
+                SET next_due = ?, updated_on = ?
+                WHERE id = ?
+            "#,
+            template.next_due,
+            updated_on,
+            id
+        )
+        .execute(&mut *tx)
+        .observe("recurring.materialise_occurrences")
+        .await?;
+
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recurring::insert::tests::Result;
+
+    fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    async fn count_transactions(pool: &sqlx::Pool<sqlx::Sqlite>) -> Result<i64> {
+        Ok(sqlx::query_scalar("SELECT COUNT(*) FROM transactions")
+            .fetch_one(pool)
+            .await?)
+    }
+
+    #[sqlx::test]
+    async fn materialise_catches_up_missed_occurrences(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut rent = database::RecurringTransactions::new(
+            "Landlord",
+            -200_000,
+            database::RecurringFrequencies::Monthly,
+            date(1, 31),
+        );
+        rent.auto_post = true;
+        let rent = rent.insert(&pool).await?;
+
+        let created =
+            database::RecurringTransactions::materialise(rent.id, date(3, 31), &pool).await?;
+
+        let dates: Vec<_> = created
+            .iter()
+            .map(|transaction| transaction.transaction_date)
+            .collect();
+        assert_eq!(dates, [date(1, 31), date(2, 28), date(3, 31)]);
+        assert!(
+            created
+                .iter()
+                .all(|transaction| !transaction.is_draft && transaction.amount == -200_000)
+        );
+
+        let rent = database::RecurringTransactions::find_by_id(rent.id, &pool)
+            .await?
+            .unwrap();
+        assert_eq!(rent.next_due, date(4, 30));
+
+        let again =
+            database::RecurringTransactions::materialise(rent.id, date(3, 31), &pool).await?;
+        assert!(again.is_empty());
+        assert_eq!(count_transactions(&pool).await?, 3);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn materialise_creates_drafts_until_schedule_ends(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut gym = database::RecurringTransactions::new(
+            "Gym",
+            -2_500,
+            database::RecurringFrequencies::Weekly,
+            date(3, 1),
+        );
+        gym.ends_on = Some(date(3, 10));
+        let gym = gym.insert(&pool).await?;
+
+        let created =
+            database::RecurringTransactions::materialise(gym.id, date(3, 31), &pool).await?;
+
+        assert_eq!(created.len(), 2);
+        assert!(created.iter().all(|transaction| transaction.is_draft));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn materialise_rolls_back_when_period_closed(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut salary = database::RecurringTransactions::new(
+            "Employer",
+            450_000,
+            database::RecurringFrequencies::Fortnightly,
+            date(2, 20),
+        );
+        salary.auto_post = true;
+        let salary = salary.insert(&pool).await?;
+        database::PeriodClosings::close(date(2, 28), None, &pool).await?;
+
+        let result =
+            database::RecurringTransactions::materialise(salary.id, date(3, 14), &pool).await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::PeriodClosed(_))
+        ));
+        assert_eq!(count_transactions(&pool).await?, 0);
+        let salary = database::RecurringTransactions::find_by_id(salary.id, &pool)
+            .await?
+            .unwrap();
+        assert_eq!(salary.next_due, date(2, 20));
+
+        Ok(())
+    }
+}
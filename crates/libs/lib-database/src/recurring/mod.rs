@@ -0,0 +1,30 @@
+//! # Recurring Transactions Database Module
+//!
+//! Templates for transactions that repeat, such as rent, subscriptions or a
+//! salary. Each template has a frequency and the date it is next due. When a
+//! template falls due it is materialised: a transaction is created for the
+//! due date, posted if the template auto posts or as a draft for review
+//! otherwise, and the due date moves on by the frequency.
+//!
+//! Occurrences missed while nothing was running are caught up the next time
+//! the template is materialised, one transaction per missed due date.
+//!
+//! Occurrences coming up within a few days can be found to remind about,
+//! each only once.
+
+mod delete;
+mod find;
+mod insert;
+mod materialise;
+mod model;
+mod reminders;
+mod update;
+
+/// Database row model representing a recurring transaction template.
+pub use model::RecurringTransactions;
+
+/// How often a recurring transaction repeats.
+pub use model::RecurringFrequencies;
+
+/// An occurrence coming up, to remind about.
+pub use reminders::BillReminder;
@@ -0,0 +1,285 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// How often a recurring transaction repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, serde::Deserialize, serde::Serialize)]
+#[sqlx(rename_all = "lowercase")]
+pub enum RecurringFrequencies {
+    /// Every 7 days.
+    Weekly,
+    /// Every 14 days.
+    Fortnightly,
+    /// Every calendar month.
+    Monthly,
+    /// Every three calendar months.
+    Quarterly,
+    /// Every year.
+    Annually,
+}
+
+impl RecurringFrequencies {
+    /// Returns the occurrence after `due` of a schedule that began on
+    /// `starts_on`.
+    ///
+    /// Month based frequencies count whole periods from `starts_on`, so a
+    /// schedule starting on the 31st falls on the last day of shorter months
+    /// and returns to the 31st after them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::RecurringFrequencies;
+    ///
+    /// let date = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    /// let starts_on = date(2025, 1, 31);
+    ///
+    /// let february = RecurringFrequencies::Monthly.next_after(starts_on, starts_on);
+    /// assert_eq!(february, date(2025, 2, 28));
+    /// assert_eq!(RecurringFrequencies::Monthly.next_after(starts_on, february), date(2025, 3, 31));
+    /// ```
+    pub fn next_after(
+        &self,
+        starts_on: chrono::NaiveDate,
+        due: chrono::NaiveDate,
+    ) -> chrono::NaiveDate {
+        use chrono::Datelike;
+
+        let months = match self {
+            RecurringFrequencies::Weekly => return due + chrono::Days::new(7),
+            RecurringFrequencies::Fortnightly => return due + chrono::Days::new(14),
+            RecurringFrequencies::Monthly => 1,
+            RecurringFrequencies::Quarterly => 3,
+            RecurringFrequencies::Annually => 12,
+        };
+
+        let elapsed =
+            (due.year() - starts_on.year()) * 12 + due.month() as i32 - starts_on.month() as i32;
+        let periods = elapsed.max(0) as u32 / months + 1;
+
+        starts_on + chrono::Months::new(periods * months)
+    }
+}
+
+/// A template for a transaction that repeats, such as rent or a salary.
+///
+/// The amount is in signed minor units (cents), as for a transaction. Each
+/// time the template is due a transaction is created from it, posted when
+/// `auto_post` is set or as a draft otherwise, and `next_due` moves on.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct RecurringTransactions {
+    pub id: domain::RowID,
+    pub payee: String,
+    pub description: Option<String>,
+    pub amount: i64,
+    pub category_id: Option<domain::RowID>,
+    pub account_id: Option<domain::RowID>,
+    pub frequency: RecurringFrequencies,
+
+    /// The first occurrence, which month based frequencies count from.
+    pub starts_on: chrono::NaiveDate,
+
+    /// The next occurrence a transaction will be created for.
+    pub next_due: chrono::NaiveDate,
+
+    /// The last day an occurrence may fall on, if the schedule ends.
+    pub ends_on: Option<chrono::NaiveDate>,
+
+    pub auto_post: bool,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::RecurringTransactions {
+    /// Creates a new, unsaved template that is first due on `starts_on`. It
+    /// creates drafts and never ends until changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{RecurringFrequencies, RecurringTransactions};
+    ///
+    /// let starts_on = chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    /// let rent = RecurringTransactions::new("Landlord", -200_000, RecurringFrequencies::Monthly, starts_on);
+    ///
+    /// assert_eq!(rent.next_due, starts_on);
+    /// assert!(!rent.auto_post);
+    /// ```
+    pub fn new(
+        payee: impl Into<String>,
+        amount: i64,
+        frequency: RecurringFrequencies,
+        starts_on: chrono::NaiveDate,
+    ) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            payee: payee.into(),
+            description: None,
+            amount,
+            category_id: None,
+            account_id: None,
+            frequency,
+            starts_on,
+            next_due: starts_on,
+            ends_on: None,
+            auto_post: false,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Returns whether an occurrence is due on or before `on`.
+    pub fn is_due(&self, on: chrono::NaiveDate) -> bool {
+        self.next_due <= on && self.ends_on.is_none_or(|ends_on| self.next_due <= ends_on)
+    }
+
+    /// Builds the transaction for the next occurrence, without saving it.
+    pub fn occurrence(&self) -> database::Transactions {
+        let mut transaction =
+            database::Transactions::new(self.next_due, self.payee.clone(), self.amount);
+        transaction.description = self.description.clone();
+        transaction.category_id = self.category_id;
+        transaction.account_id = self.account_id;
+        transaction.is_draft = !self.auto_post;
+
+        transaction
+    }
+
+    /// Checks the template fields before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the payee is empty, the
+    /// amount is zero, or the next due or end date is before the start.
+    pub fn validate(&self) -> DatabaseResult<()> {
+        if self.payee.trim().is_empty() {
+            return Err(DatabaseError::Validation(format!(
+                "Recurring transaction {} payee must not be empty",
+                self.id
+            )));
+        }
+
+        if self.amount == 0 {
+            return Err(DatabaseError::Validation(format!(
+                "Recurring transaction {} amount must not be zero",
+                self.id
+            )));
+        }
+
+        if self.next_due < self.starts_on {
+            return Err(DatabaseError::Validation(format!(
+                "Recurring transaction {} is next due before it starts",
+                self.id
+            )));
+        }
+
+        if self.ends_on.is_some_and(|ends_on| ends_on < self.starts_on) {
+            return Err(DatabaseError::Validation(format!(
+                "Recurring transaction {} ends before it starts",
+                self.id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Generates a mock monthly template starting on a random date in 2025.
+    #[cfg(test)]
+    pub fn mock() -> Self {
+        use fake::Fake;
+        use fake::faker::company::en::CompanyName;
+
+        let starts_on = chrono::NaiveDate::from_ymd_opt(2025, (1..=12).fake(), (1..=28).fake())
+            .expect("mock start date is valid");
+
+        Self::new(
+            CompanyName().fake::<String>(),
+            (-1_000_000..-1).fake(),
+            RecurringFrequencies::Monthly,
+            starts_on,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn next_after_steps_by_frequency() {
+        let starts_on = date(2024, 2, 29);
+
+        assert_eq!(
+            RecurringFrequencies::Weekly.next_after(starts_on, starts_on),
+            date(2024, 3, 7)
+        );
+        assert_eq!(
+            RecurringFrequencies::Fortnightly.next_after(starts_on, starts_on),
+            date(2024, 3, 14)
+        );
+        assert_eq!(
+            RecurringFrequencies::Quarterly.next_after(starts_on, date(2024, 11, 29)),
+            date(2025, 2, 28)
+        );
+        assert_eq!(
+            RecurringFrequencies::Annually.next_after(starts_on, date(2025, 2, 28)),
+            date(2026, 2, 28)
+        );
+        assert_eq!(
+            RecurringFrequencies::Annually.next_after(starts_on, date(2027, 2, 28)),
+            date(2028, 2, 29)
+        );
+    }
+
+    #[test]
+    fn is_due_until_schedule_ends() {
+        let mut template = database::RecurringTransactions::new(
+            "Gym",
+            -2_500,
+            RecurringFrequencies::Weekly,
+            date(2025, 3, 1),
+        );
+
+        assert!(!template.is_due(date(2025, 2, 28)));
+        assert!(template.is_due(date(2025, 3, 1)));
+
+        template.ends_on = Some(date(2025, 3, 1));
+        template.next_due = date(2025, 3, 8);
+        assert!(!template.is_due(date(2025, 3, 31)));
+    }
+
+    #[test]
+    fn validate_rejects_invalid_templates() {
+        let valid = database::RecurringTransactions::mock();
+        assert!(valid.validate().is_ok());
+
+        let empty_payee = database::RecurringTransactions {
+            payee: " ".to_string(),
+            ..valid.clone()
+        };
+        let zero_amount = database::RecurringTransactions {
+            amount: 0,
+            ..valid.clone()
+        };
+        let due_early = database::RecurringTransactions {
+            next_due: valid.starts_on - chrono::Days::new(1),
+            ..valid.clone()
+        };
+        let ends_early = database::RecurringTransactions {
+            ends_on: Some(valid.starts_on - chrono::Days::new(1)),
+            ..valid.clone()
+        };
+
+        for template in [empty_payee, zero_amount, due_early, ends_early] {
+            assert!(matches!(
+                template.validate(),
+                Err(DatabaseError::Validation(_))
+            ));
+        }
+    }
+}
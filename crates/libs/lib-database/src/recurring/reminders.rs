@@ -0,0 +1,228 @@
+//! # Bill Reminders
+//!
+//! Finds recurring transactions coming up within a number of days that have
+//! not been reminded about yet. Once a reminder has been sent it is marked
+//! sent, so each occurrence is only reminded about once.
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+/// An occurrence of a recurring transaction coming up, to remind about.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct BillReminder {
+    pub template: database::RecurringTransactions,
+
+    /// The day the occurrence is due.
+    pub due_on: chrono::NaiveDate,
+
+    /// Days from the reminder date to `due_on`, zero on the day itself.
+    pub days_left: i64,
+}
+
+impl database::RecurringTransactions {
+    /// Finds the templates next due between `on` and `days_ahead` days after
+    /// it that have not been reminded about, soonest first. Templates whose
+    /// schedule has ended are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - The day reminders are sent
+    /// * `days_ahead` - How many days after `on` to remind about
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::RecurringTransactions;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let today = chrono::Utc::now().date_naive();
+    ///
+    /// for reminder in RecurringTransactions::find_reminders(today, 3, pool).await? {
+    ///     println!("{} due in {} days", reminder.template.payee, reminder.days_left);
+    ///     reminder.mark_sent(pool).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find bill reminders", skip(conn), err)]
+    pub fn find_reminders<'c, A>(
+        on: chrono::NaiveDate,
+        days_ahead: u32,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::BillReminder>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let until = on + chrono::Days::new(days_ahead.into());
+
+            let templates = sqlx::query_as!(
+                database::RecurringTransactions,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        payee               AS "payee!",
+                        description,
+                        amount              AS "amount!: i64",
+                        category_id         AS "category_id?: domain::RowID",
+                        account_id          AS "account_id?: domain::RowID",
+                        frequency           AS "frequency!: database::RecurringFrequencies",
+                        starts_on           AS "starts_on!: chrono::NaiveDate",
+                        next_due            AS "next_due!: chrono::NaiveDate",
+                        ends_on             AS "ends_on?: chrono::NaiveDate",
+                        auto_post           AS "auto_post!: bool",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM recurring_transactions
+                    WHERE next_due BETWEEN ?1 AND ?2
+                        AND (ends_on IS NULL OR next_due <= ends_on)
+                        AND NOT EXISTS (
+                            SELECT 1 FROM bill_reminders
+                            WHERE bill_reminders.recurring_id = recurring_transactions.id
+                                AND bill_reminders.due_on = recurring_transactions.next_due
+                        )
+                    ORDER BY next_due ASC, payee ASC
+                "#,
+                on,
+                until
+            )
+            .fetch_all(&mut *conn)
+            .observe("recurring.find_reminders")
+            .await?;
+
+            Ok(templates
+                .into_iter()
+                .map(|template| database::BillReminder {
+                    due_on: template.next_due,
+                    days_left: (template.next_due - on).num_days(),
+                    template,
+                })
+                .collect())
+        }
+    }
+}
+
+impl BillReminder {
+    /// Records that the reminder has been sent, so it is not found again.
+    /// Marking a reminder sent twice does nothing.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The template no longer exists (foreign key violation)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Mark bill reminder sent",
+        skip(self, conn),
+        fields(recurring_id = %self.template.id, due_on = %self.due_on),
+        err
+    )]
+    pub fn mark_sent<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let id = domain::RowID::new();
+            let sent_on = chrono::Utc::now();
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO bill_reminders (id, recurring_id, due_on, sent_on)
+                    VALUES (?, ?, ?, ?)
+                    ON CONFLICT (recurring_id, due_on) DO NOTHING
+                "#,
+                id,
+                self.template.id,
+                self.due_on,
+                sent_on
+            )
+            .execute(&mut *conn)
+            .observe("recurring.mark_sent")
+            .await?;
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn find_reminders_within_days_ahead(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let monthly = database::RecurringFrequencies::Monthly;
+        let rent = database::RecurringTransactions::new("Landlord", -200_000, monthly, date(3, 4))
+            .insert(&pool)
+            .await?;
+        database::RecurringTransactions::new("Internet", -8_000, monthly, date(3, 1))
+            .insert(&pool)
+            .await?;
+        database::RecurringTransactions::new("Insurance", -12_000, monthly, date(3, 10))
+            .insert(&pool)
+            .await?;
+        let mut ended = database::RecurringTransactions::new("Gym", -6_000, monthly, date(1, 3));
+        ended.next_due = date(3, 3);
+        ended.ends_on = Some(date(2, 28));
+        ended.insert(&pool).await?;
+
+        let reminders =
+            database::RecurringTransactions::find_reminders(date(3, 1), 3, &pool).await?;
+
+        let found: Vec<_> = reminders
+            .iter()
+            .map(|r| (r.template.payee.as_str(), r.due_on, r.days_left))
+            .collect();
+        assert_eq!(
+            found,
+            [("Internet", date(3, 1), 0), ("Landlord", date(3, 4), 3)]
+        );
+        assert_eq!(reminders[1].template, rent);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn sent_reminders_are_not_found_again(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let monthly = database::RecurringFrequencies::Monthly;
+        let rent = database::RecurringTransactions::new("Landlord", -200_000, monthly, date(3, 4))
+            .insert(&pool)
+            .await?;
+
+        let reminders =
+            database::RecurringTransactions::find_reminders(date(3, 1), 3, &pool).await?;
+        reminders[0].mark_sent(&pool).await?;
+        reminders[0].mark_sent(&pool).await?;
+        assert!(
+            database::RecurringTransactions::find_reminders(date(3, 2), 3, &pool)
+                .await?
+                .is_empty()
+        );
+
+        // The next occurrence is reminded about once the template moves on
+        database::RecurringTransactions::materialise(rent.id, date(3, 4), &pool).await?;
+        let reminders =
+            database::RecurringTransactions::find_reminders(date(4, 1), 3, &pool).await?;
+        assert_eq!(
+            reminders.iter().map(|r| r.due_on).collect::<Vec<_>>(),
+            [date(4, 4)]
+        );
+
+        Ok(())
+    }
+}
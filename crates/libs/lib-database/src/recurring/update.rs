@@ -0,0 +1,125 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::RecurringTransactions {
+    /// Updates an existing recurring transaction template in the database.
+    ///
+    /// Every field of the template identified by `id` except its creation
+    /// time is replaced, and the `updated_on` timestamp is set to now.
+    /// Transactions already created from the template are not changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated template as read back from the database, or a
+    /// `DatabaseError` if the update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The template does not exist (`DatabaseError::NotFound`)
+    /// - The payee is empty, the amount is zero or the dates are out of order
+    ///   (`DatabaseError::Validation`)
+    /// - The category or account does not exist (foreign key violation)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Update recurring transaction in database",
+        skip(self, conn),
+        fields(id = %self.id, next_due = %self.next_due, amount = self.amount),
+        err
+    )]
+    pub fn update<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let updated_on = chrono::Utc::now();
+
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    UPDATE recurring_transactions
+                    SET payee = ?, description = ?, amount = ?, category_id = ?, account_id = ?,
+                        frequency = ?, starts_on = ?, next_due = ?, ends_on = ?, auto_post = ?,
+                        updated_on = ?
+                    WHERE id = ?
+                "#,
+                self.payee,
+                self.description,
+                self.amount,
+                self.category_id,
+                self.account_id,
+                self.frequency,
+                self.starts_on,
+                self.next_due,
+                self.ends_on,
+                self.auto_post,
+                updated_on,
+                self.id
+            )
+            .execute(&mut *conn)
+            .observe("recurring.update")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Recurring transaction with id {} not found",
+                    self.id
+                )));
+            }
+
+            let updated = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Recurring transaction with id {} not found after update",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("Recurring transaction updated in the database.");
+
+            Ok(updated)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recurring::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn update_changes_fields(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut template = database::RecurringTransactions::mock()
+            .insert(&pool)
+            .await?;
+        template.frequency = database::RecurringFrequencies::Fortnightly;
+        template.auto_post = true;
+
+        let updated = template.update(&pool).await?;
+
+        assert_eq!(
+            updated.frequency,
+            database::RecurringFrequencies::Fortnightly
+        );
+        assert!(updated.auto_post);
+        assert!(updated.updated_on >= template.updated_on);
+
+        let missing = database::RecurringTransactions::mock().update(&pool).await;
+        assert!(matches!(missing, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
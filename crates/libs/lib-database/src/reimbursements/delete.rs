@@ -0,0 +1,144 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Reimbursables {
+    /// Deletes a reimbursable from the database by its ID.
+    /// The transaction or trip it was for is kept, and is no longer
+    /// expected to be paid back.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the reimbursable to delete
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The reimbursable with the given ID does not exist (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Delete reimbursable from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    DELETE FROM reimbursables
+                    WHERE id = ?
+                "#,
+                id
+            )
+            .execute(&mut *conn)
+            .observe("reimbursements.delete_by_id")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Reimbursable with id {} not found",
+                    id
+                )));
+            }
+
+            tracing::info!("Deleted reimbursable {} from database", id);
+
+            Ok(())
+        }
+    }
+}
+
+impl database::MileageLogs {
+    /// Deletes a mileage log from the database by its ID.
+    /// The trip's reimbursable, if it has one, is deleted with it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the mileage log to delete
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The mileage log with the given ID does not exist (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Delete mileage log from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    DELETE FROM mileage_logs
+                    WHERE id = ?
+                "#,
+                id
+            )
+            .execute(&mut *conn)
+            .observe("reimbursements.delete_by_id")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Mileage log with id {} not found",
+                    id
+                )));
+            }
+
+            tracing::info!("Deleted mileage log {} from database", id);
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reimbursements::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn delete_mileage_log_removes_reimbursable(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let trip = database::MileageLogs::mock().insert(&pool).await?;
+        let reimbursable = database::Reimbursables::for_mileage_log(trip.id)
+            .insert(&pool)
+            .await?;
+
+        database::MileageLogs::delete_by_id(trip.id, &pool).await?;
+
+        assert!(
+            database::Reimbursables::find_by_id(reimbursable.id, &pool)
+                .await?
+                .is_none()
+        );
+        assert!(matches!(
+            database::Reimbursables::delete_by_id(reimbursable.id, &pool).await,
+            Err(database::DatabaseError::NotFound(_))
+        ));
+
+        Ok(())
+    }
+}
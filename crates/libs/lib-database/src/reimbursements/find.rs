@@ -0,0 +1,301 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Reimbursables {
+    /// Finds a reimbursable by its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the reimbursable to find
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(
+        name = "Find reimbursable by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            Self::fetch_by_id(id, &mut conn).await
+        }
+    }
+
+    /// Finds the reimbursable for a transaction, if it is to be paid back.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - The transaction the reimbursable is for
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(
+        name = "Find reimbursable by transaction",
+        skip(conn),
+        fields(transaction_id = %transaction_id),
+        err
+    )]
+    pub fn find_by_transaction<'c, A>(
+        transaction_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let reimbursable = sqlx::query_as!(
+                database::Reimbursables,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        transaction_id      AS "transaction_id?: domain::RowID",
+                        mileage_log_id      AS "mileage_log_id?: domain::RowID",
+                        claimed_on          AS "claimed_on?: chrono::NaiveDate",
+                        reimbursed_by       AS "reimbursed_by?: domain::RowID",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM reimbursables
+                    WHERE transaction_id = ?
+                "#,
+                transaction_id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("reimbursements.find_by_transaction")
+            .await?;
+
+            Ok(reimbursable)
+        }
+    }
+
+    /// Finds the reimbursable for a trip, if it is to be paid back.
+    ///
+    /// # Arguments
+    ///
+    /// * `mileage_log_id` - The mileage log the reimbursable is for
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(
+        name = "Find reimbursable by mileage log",
+        skip(conn),
+        fields(mileage_log_id = %mileage_log_id),
+        err
+    )]
+    pub fn find_by_mileage_log<'c, A>(
+        mileage_log_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let reimbursable = sqlx::query_as!(
+                database::Reimbursables,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        transaction_id      AS "transaction_id?: domain::RowID",
+                        mileage_log_id      AS "mileage_log_id?: domain::RowID",
+                        claimed_on          AS "claimed_on?: chrono::NaiveDate",
+                        reimbursed_by       AS "reimbursed_by?: domain::RowID",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM reimbursables
+                    WHERE mileage_log_id = ?
+                "#,
+                mileage_log_id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("reimbursements.find_by_mileage_log")
+            .await?;
+
+            Ok(reimbursable)
+        }
+    }
+
+    /// Reads a reimbursable back through the given connection.
+    pub(super) async fn fetch_by_id(
+        id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let reimbursable = sqlx::query_as!(
+            database::Reimbursables,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    transaction_id      AS "transaction_id?: domain::RowID",
+                    mileage_log_id      AS "mileage_log_id?: domain::RowID",
+                    claimed_on          AS "claimed_on?: chrono::NaiveDate",
+                    reimbursed_by       AS "reimbursed_by?: domain::RowID",
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM reimbursables
+                WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("reimbursements.fetch_by_id")
+        .await?;
+
+        Ok(reimbursable)
+    }
+}
+
+impl database::MileageLogs {
+    /// Finds a trip in the mileage log by its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the mileage log to find
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(
+        name = "Find mileage log by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            Self::fetch_by_id(id, &mut conn).await
+        }
+    }
+
+    /// Finds the trips made between two dates, inclusive, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The first trip date to include
+    /// * `to` - The last trip date to include
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(name = "Find mileage logs between dates", skip(conn), err)]
+    pub fn find_between<'c, A>(
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let trips = sqlx::query_as!(
+                database::MileageLogs,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        trip_date           AS "trip_date!: chrono::NaiveDate",
+                        purpose             AS "purpose!",
+                        distance_metres     AS "distance_metres!: i64",
+                        rate_cents_per_km   AS "rate_cents_per_km!: i64",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM mileage_logs
+                    WHERE trip_date BETWEEN ? AND ?
+                    ORDER BY trip_date ASC, created_on ASC
+                "#,
+                from,
+                to
+            )
+            .fetch_all(&mut *conn)
+            .observe("reimbursements.find_between")
+            .await?;
+
+            Ok(trips)
+        }
+    }
+
+    /// Reads a trip back through the given connection.
+    pub(super) async fn fetch_by_id(
+        id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let trip = sqlx::query_as!(
+            database::MileageLogs,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    trip_date           AS "trip_date!: chrono::NaiveDate",
+                    purpose             AS "purpose!",
+                    distance_metres     AS "distance_metres!: i64",
+                    rate_cents_per_km   AS "rate_cents_per_km!: i64",
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM mileage_logs
+                WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("reimbursements.fetch_by_id")
+        .await?;
+
+        Ok(trip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reimbursements::insert::tests::{Result, reimbursable_purchase};
+
+    #[sqlx::test]
+    async fn find_by_source_returns_reimbursable(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let purchase = reimbursable_purchase(&pool, "Officeworks", -4_200).await?;
+        let trip = database::MileageLogs::mock().insert(&pool).await?;
+        let mileage = database::Reimbursables::for_mileage_log(trip.id)
+            .insert(&pool)
+            .await?;
+
+        let transaction_id = purchase.transaction_id.unwrap();
+        assert_eq!(
+            database::Reimbursables::find_by_transaction(transaction_id, &pool).await?,
+            Some(purchase)
+        );
+        assert_eq!(
+            database::Reimbursables::find_by_mileage_log(trip.id, &pool).await?,
+            Some(mileage)
+        );
+        assert!(
+            database::Reimbursables::find_by_transaction(trip.id, &pool)
+                .await?
+                .is_none()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_between_returns_trips_in_range(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let date = |day| chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        for day in [20, 5, 12] {
+            database::MileageLogs::new(date(day), "Site visit", 10_000, 88)
+                .insert(&pool)
+                .await?;
+        }
+
+        let trips = database::MileageLogs::find_between(date(1), date(15), &pool).await?;
+
+        let dates: Vec<_> = trips.iter().map(|trip| trip.trip_date).collect();
+        assert_eq!(dates, [date(5), date(12)]);
+
+        Ok(())
+    }
+}
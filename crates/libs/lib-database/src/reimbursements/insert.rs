@@ -0,0 +1,233 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::Reimbursables {
+    /// Inserts a new reimbursable into the database, marking its transaction
+    /// or trip as an expense to be paid back.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the inserted reimbursable as read back from the database, or a
+    /// `DatabaseError` if the insertion fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - Neither or both of the transaction and trip are set (`DatabaseError::Validation`)
+    /// - The reimbursing transaction is not money received (`DatabaseError::Validation`)
+    /// - The transaction or trip does not exist (foreign key violation)
+    /// - The transaction or trip is already reimbursable (unique constraint violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Reimbursables, Transactions};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// let lunch = Transactions::new(date, "Client lunch", -8_500).insert(pool).await?;
+    ///
+    /// let reimbursable = Reimbursables::for_transaction(lunch.id).insert(pool).await?;
+    /// assert!(reimbursable.is_outstanding());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert new reimbursable into database",
+        skip(self, conn),
+        fields(id = %self.id),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let mut conn = conn.acquire().await?;
+
+            self.ensure_reimbursing(&mut conn).await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO reimbursables (
+                        id, transaction_id, mileage_log_id, claimed_on, reimbursed_by,
+                        created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.transaction_id,
+                self.mileage_log_id,
+                self.claimed_on,
+                self.reimbursed_by,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("reimbursements.insert")
+            .await?;
+
+            let inserted = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Reimbursable with id {} not found after insert",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("New reimbursable inserted into the database.");
+
+            Ok(inserted)
+        }
+    }
+}
+
+impl database::MileageLogs {
+    /// Inserts a new trip into the mileage log.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the inserted trip as read back from the database, or a
+    /// `DatabaseError` if the insertion fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The purpose is empty or the distance or rate is not positive
+    ///   (`DatabaseError::Validation`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Insert new mileage log into database",
+        skip(self, conn),
+        fields(id = %self.id, trip_date = %self.trip_date, distance_metres = self.distance_metres),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO mileage_logs (
+                        id, trip_date, purpose, distance_metres, rate_cents_per_km,
+                        created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.trip_date,
+                self.purpose,
+                self.distance_metres,
+                self.rate_cents_per_km,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("reimbursements.insert")
+            .await?;
+
+            let inserted = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Mileage log with id {} not found after insert",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("New mileage log inserted into the database.");
+
+            Ok(inserted)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use lib_domain as domain;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    /// Inserts a purchase of `amount` cents and marks it reimbursable.
+    pub async fn reimbursable_purchase(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+        payee: &str,
+        amount: i64,
+    ) -> Result<database::Reimbursables> {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        let purchase = database::Transactions::new(date, payee, amount)
+            .insert(pool)
+            .await?;
+
+        Ok(database::Reimbursables::for_transaction(purchase.id)
+            .insert(pool)
+            .await?)
+    }
+
+    #[sqlx::test]
+    async fn insert_reimbursable_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let trip = database::MileageLogs::mock().insert(&pool).await?;
+        let reimbursable = database::Reimbursables::for_mileage_log(trip.id);
+
+        let inserted = reimbursable.insert(&pool).await?;
+
+        assert_eq!(inserted, reimbursable);
+
+        let again = database::Reimbursables::for_mileage_log(trip.id)
+            .insert(&pool)
+            .await;
+        assert!(matches!(again, Err(database::DatabaseError::Sqlx(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_unknown_transaction_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let result = database::Reimbursables::for_transaction(domain::RowID::new())
+            .insert(&pool)
+            .await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_mileage_log_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let trip = database::MileageLogs::mock();
+
+        let inserted = trip.insert(&pool).await?;
+
+        assert_eq!(inserted, trip);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+//! # Reimbursements Database Module
+//!
+//! Tracks expenses that someone else will pay back: purchases made on a
+//! personal card for work, and trips recorded in the mileage log. Each
+//! reimbursable records when its claim was submitted and the transaction
+//! that paid it back, and is outstanding until then.
+//!
+//! Mileage amounts are worked out from the distance and a rate in cents per
+//! kilometre, such as the ATO cents per kilometre rate, rounded to the
+//! nearest cent.
+
+mod delete;
+mod find;
+mod insert;
+mod model;
+mod outstanding;
+mod update;
+
+/// Database row model representing an expense to be paid back.
+pub use model::Reimbursables;
+
+/// Database row model representing a trip in the mileage log.
+pub use model::MileageLogs;
+
+/// What a reimbursable expense was recorded as.
+pub use model::ReimbursementSources;
+
+/// An expense that has not been paid back yet.
+pub use model::OutstandingReimbursement;
@@ -0,0 +1,319 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// An expense that someone else will pay back, such as a work purchase on a
+/// personal card, or a trip in the mileage log.
+///
+/// Exactly one of `transaction_id` and `mileage_log_id` is set. The
+/// reimbursable is outstanding until `reimbursed_by` links the transaction
+/// that paid it back.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct Reimbursables {
+    pub id: domain::RowID,
+    pub transaction_id: Option<domain::RowID>,
+    pub mileage_log_id: Option<domain::RowID>,
+
+    /// The day the claim was submitted, if it has been.
+    pub claimed_on: Option<chrono::NaiveDate>,
+
+    /// The transaction, money received, that paid the expense back.
+    pub reimbursed_by: Option<domain::RowID>,
+
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+/// A trip recorded for mileage, by distance and a rate per kilometre.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct MileageLogs {
+    pub id: domain::RowID,
+    pub trip_date: chrono::NaiveDate,
+
+    /// Why the trip was made, e.g. "Client visit, Toowoomba".
+    pub purpose: String,
+
+    pub distance_metres: i64,
+
+    /// The rate paid per kilometre in minor units (cents), e.g. the ATO
+    /// cents per kilometre rate.
+    pub rate_cents_per_km: i64,
+
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+/// What a reimbursable expense was recorded as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, serde::Deserialize, serde::Serialize)]
+#[sqlx(rename_all = "lowercase")]
+pub enum ReimbursementSources {
+    /// A transaction, such as a purchase on a personal card.
+    Transaction,
+    /// A trip in the mileage log.
+    Mileage,
+}
+
+/// An expense that has not been paid back yet.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct OutstandingReimbursement {
+    pub reimbursable_id: domain::RowID,
+    pub source: ReimbursementSources,
+
+    /// The transaction or mileage log the expense was recorded as.
+    pub source_id: domain::RowID,
+
+    /// The transaction date or trip date.
+    pub incurred_on: chrono::NaiveDate,
+
+    /// The payee of a transaction, or the purpose of a trip.
+    pub description: String,
+
+    /// The amount owed, in positive minor units (cents).
+    pub amount: i64,
+
+    pub claimed_on: Option<chrono::NaiveDate>,
+}
+
+impl database::Reimbursables {
+    /// Creates a new, unsaved reimbursable for a transaction.
+    pub fn for_transaction(transaction_id: domain::RowID) -> Self {
+        Self::new(Some(transaction_id), None)
+    }
+
+    /// Creates a new, unsaved reimbursable for a trip in the mileage log.
+    pub fn for_mileage_log(mileage_log_id: domain::RowID) -> Self {
+        Self::new(None, Some(mileage_log_id))
+    }
+
+    fn new(transaction_id: Option<domain::RowID>, mileage_log_id: Option<domain::RowID>) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            transaction_id,
+            mileage_log_id,
+            claimed_on: None,
+            reimbursed_by: None,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Returns whether the expense has not been paid back yet.
+    pub fn is_outstanding(&self) -> bool {
+        self.reimbursed_by.is_none()
+    }
+
+    /// Checks the reimbursable fields before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] unless exactly one of the
+    /// transaction and mileage log is set, or if the expense is paid back by
+    /// its own transaction.
+    pub fn validate(&self) -> DatabaseResult<()> {
+        if self.transaction_id.is_some() == self.mileage_log_id.is_some() {
+            return Err(DatabaseError::Validation(format!(
+                "Reimbursable {} must be for either a transaction or a mileage log",
+                self.id
+            )));
+        }
+
+        if self.reimbursed_by.is_some() && self.reimbursed_by == self.transaction_id {
+            return Err(DatabaseError::Validation(format!(
+                "Reimbursable {} cannot be paid back by its own transaction",
+                self.id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the transaction paying the expense back is money received.
+    pub(super) async fn ensure_reimbursing(
+        &self,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<()> {
+        let Some(reimbursed_by) = self.reimbursed_by else {
+            return Ok(());
+        };
+
+        let transaction =
+            database::Transactions::find_by_id(reimbursed_by, database::UserScope::All, &mut *conn)
+                .await?
+                .ok_or_else(|| {
+                    DatabaseError::NotFound(format!(
+                        "Transaction with id {} not found",
+                        reimbursed_by
+                    ))
+                })?;
+
+        if transaction.amount <= 0 {
+            return Err(DatabaseError::Validation(format!(
+                "Reimbursable {} must be paid back by money received, not transaction {}",
+                self.id, reimbursed_by
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl database::MileageLogs {
+    /// Creates a new, unsaved trip.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::MileageLogs;
+    ///
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// let trip = MileageLogs::new(date, "Client visit", 125_400, 88);
+    ///
+    /// assert_eq!(trip.amount(), 11_035);
+    /// ```
+    pub fn new(
+        trip_date: chrono::NaiveDate,
+        purpose: impl Into<String>,
+        distance_metres: i64,
+        rate_cents_per_km: i64,
+    ) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            trip_date,
+            purpose: purpose.into(),
+            distance_metres,
+            rate_cents_per_km,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Returns the amount for the trip in minor units (cents), the distance
+    /// in kilometres times the rate, rounded to the nearest cent.
+    pub fn amount(&self) -> i64 {
+        (self.distance_metres * self.rate_cents_per_km + 500) / 1_000
+    }
+
+    /// Checks the trip fields before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the purpose is empty, the
+    /// distance or rate is not positive, or the amount is too large.
+    pub fn validate(&self) -> DatabaseResult<()> {
+        if self.purpose.trim().is_empty() {
+            return Err(DatabaseError::Validation(format!(
+                "Mileage log {} purpose must not be empty",
+                self.id
+            )));
+        }
+
+        if self.distance_metres <= 0 || self.rate_cents_per_km <= 0 {
+            return Err(DatabaseError::Validation(format!(
+                "Mileage log {} distance and rate must be positive",
+                self.id
+            )));
+        }
+
+        if self
+            .distance_metres
+            .checked_mul(self.rate_cents_per_km)
+            .is_none_or(|total| total > i64::MAX - 500)
+        {
+            return Err(DatabaseError::Validation(format!(
+                "Mileage log {} amount is too large",
+                self.id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Generates a mock trip of up to 500 km today at 88 cents per km.
+    #[cfg(test)]
+    pub fn mock() -> Self {
+        use fake::Fake;
+        use fake::faker::lorem::en::Sentence;
+
+        Self::new(
+            chrono::Utc::now().date_naive(),
+            Sentence(2..5).fake::<String>(),
+            (100..500_000).fake(),
+            88,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amount_rounds_to_nearest_cent() {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+
+        assert_eq!(
+            database::MileageLogs::new(date, "Trip", 1_000, 88).amount(),
+            88
+        );
+        assert_eq!(
+            database::MileageLogs::new(date, "Trip", 5_682, 88).amount(),
+            500
+        );
+        assert_eq!(
+            database::MileageLogs::new(date, "Trip", 5_676, 88).amount(),
+            499
+        );
+    }
+
+    #[test]
+    fn validate_rejects_invalid_trips() {
+        let valid = database::MileageLogs::mock();
+        assert!(valid.validate().is_ok());
+
+        let invalid = [
+            database::MileageLogs {
+                purpose: String::new(),
+                ..valid.clone()
+            },
+            database::MileageLogs {
+                distance_metres: 0,
+                ..valid.clone()
+            },
+            database::MileageLogs {
+                rate_cents_per_km: -88,
+                ..valid.clone()
+            },
+            database::MileageLogs {
+                distance_metres: i64::MAX,
+                ..valid.clone()
+            },
+        ];
+
+        for trip in invalid {
+            assert!(matches!(trip.validate(), Err(DatabaseError::Validation(_))));
+        }
+    }
+
+    #[test]
+    fn validate_requires_one_source() {
+        let id = domain::RowID::new();
+        let mut reimbursable = database::Reimbursables::for_transaction(id);
+        assert!(reimbursable.validate().is_ok());
+
+        reimbursable.reimbursed_by = Some(id);
+        assert!(matches!(
+            reimbursable.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+
+        reimbursable.reimbursed_by = None;
+        reimbursable.mileage_log_id = Some(domain::RowID::new());
+        assert!(matches!(
+            reimbursable.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+}
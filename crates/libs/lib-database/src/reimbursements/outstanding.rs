@@ -0,0 +1,148 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Reimbursables {
+    /// Lists the expenses that have not been paid back, oldest first, with
+    /// the amount owed for each.
+    ///
+    /// A transaction is owed the negation of its amount, and a trip the
+    /// amount worked out from its distance and rate. Expenses that have been
+    /// claimed are included until a reimbursing transaction is linked.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Reimbursables;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let outstanding = Reimbursables::find_outstanding(pool).await?;
+    /// let unclaimed: i64 = outstanding
+    ///     .iter()
+    ///     .filter(|item| item.claimed_on.is_none())
+    ///     .map(|item| item.amount)
+    ///     .sum();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find outstanding reimbursements", skip(conn), err)]
+    pub fn find_outstanding<'c, A>(
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::OutstandingReimbursement>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let outstanding = sqlx::query_as!(
+                database::OutstandingReimbursement,
+                r#"
+                    SELECT
+                        reimbursable_id     AS "reimbursable_id!: domain::RowID",
+                        source              AS "source!: database::ReimbursementSources",
+                        source_id           AS "source_id!: domain::RowID",
+                        incurred_on         AS "incurred_on!: chrono::NaiveDate",
+                        description         AS "description!",
+                        amount              AS "amount!: i64",
+                        claimed_on          AS "claimed_on?: chrono::NaiveDate"
+                    FROM (
+                        SELECT
+                            r.id                AS reimbursable_id,
+                            'transaction'       AS source,
+                            t.id                AS source_id,
+                            t.transaction_date  AS incurred_on,
+                            t.payee             AS description,
+                            -t.amount           AS amount,
+                            r.claimed_on        AS claimed_on
+                        FROM reimbursables r JOIN transactions t ON t.id = r.transaction_id
+                        WHERE r.reimbursed_by IS NULL
+                        UNION ALL
+                        SELECT
+                            r.id,
+                            'mileage',
+                            m.id,
+                            m.trip_date,
+                            m.purpose,
+                            (m.distance_metres * m.rate_cents_per_km + 500) / 1000,
+                            r.claimed_on
+                        FROM reimbursables r JOIN mileage_logs m ON m.id = r.mileage_log_id
+                        WHERE r.reimbursed_by IS NULL
+                    )
+                    ORDER BY incurred_on ASC, description ASC
+                "#
+            )
+            .fetch_all(&mut *conn)
+            .observe("reimbursements.find_outstanding")
+            .await?;
+
+            Ok(outstanding)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reimbursements::insert::tests::{Result, reimbursable_purchase};
+
+    #[sqlx::test]
+    async fn find_outstanding_lists_unpaid_expenses(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let date = |day| chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        let mut repaid = reimbursable_purchase(&pool, "Bunnings", -12_000).await?;
+        reimbursable_purchase(&pool, "Officeworks", -4_200).await?;
+        let trip = database::MileageLogs::new(date(2), "Client visit", 125_400, 88)
+            .insert(&pool)
+            .await?;
+        let mut mileage = database::Reimbursables::for_mileage_log(trip.id);
+        mileage.claimed_on = Some(date(10));
+        mileage.insert(&pool).await?;
+        database::MileageLogs::new(date(3), "Personal", 5_000, 88)
+            .insert(&pool)
+            .await?;
+
+        let repayment = database::Transactions::new(date(20), "Employer", 12_000)
+            .insert(&pool)
+            .await?;
+        repaid.reimbursed_by = Some(repayment.id);
+        repaid.update(&pool).await?;
+
+        let outstanding = database::Reimbursables::find_outstanding(&pool).await?;
+
+        let found: Vec<_> = outstanding
+            .iter()
+            .map(|item| {
+                (
+                    item.source,
+                    item.description.as_str(),
+                    item.amount,
+                    item.claimed_on,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    database::ReimbursementSources::Mileage,
+                    "Client visit",
+                    11_035,
+                    Some(date(10))
+                ),
+                (
+                    database::ReimbursementSources::Transaction,
+                    "Officeworks",
+                    4_200,
+                    None
+                ),
+            ]
+        );
+        assert_eq!(outstanding[0].source_id, trip.id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::Reimbursables {
+    /// Updates the claim date and reimbursing transaction of a reimbursable,
+    /// and sets the `updated_on` timestamp to now. The transaction or trip it
+    /// is for cannot be changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated reimbursable as read back from the database, or a
+    /// `DatabaseError` if the update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The reimbursable does not exist (`DatabaseError::NotFound`)
+    /// - The reimbursing transaction does not exist (`DatabaseError::NotFound`)
+    /// - The reimbursing transaction is not money received (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Reimbursables, Transactions};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, lunch_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 28).unwrap();
+    /// let mut reimbursable = Reimbursables::find_by_transaction(lunch_id, pool).await?.unwrap();
+    /// let repayment = Transactions::new(date, "Employer", 8_500).insert(pool).await?;
+    ///
+    /// reimbursable.reimbursed_by = Some(repayment.id);
+    /// assert!(!reimbursable.update(pool).await?.is_outstanding());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Update reimbursable in database",
+        skip(self, conn),
+        fields(id = %self.id),
+        err
+    )]
+    pub fn update<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let updated_on = chrono::Utc::now();
+
+            let mut conn = conn.acquire().await?;
+
+            self.ensure_reimbursing(&mut conn).await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    UPDATE reimbursables
+                    SET claimed_on = ?, reimbursed_by = ?, updated_on = ?
+                    WHERE id = ?
+                "#,
+                self.claimed_on,
+                self.reimbursed_by,
+                updated_on,
+                self.id
+            )
+            .execute(&mut *conn)
+            .observe("reimbursements.update")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Reimbursable with id {} not found",
+                    self.id
+                )));
+            }
+
+            let updated = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Reimbursable with id {} not found after update",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("Reimbursable updated in the database.");
+
+            Ok(updated)
+        }
+    }
+}
+
+impl database::MileageLogs {
+    /// Updates an existing trip in the mileage log.
+    ///
+    /// The date, purpose, distance and rate of the trip identified by `id`
+    /// are replaced and the `updated_on` timestamp is set to now.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The trip does not exist (`DatabaseError::NotFound`)
+    /// - The purpose is empty or the distance or rate is not positive
+    ///   (`DatabaseError::Validation`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Update mileage log in database",
+        skip(self, conn),
+        fields(id = %self.id, distance_metres = self.distance_metres),
+        err
+    )]
+    pub fn update<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let updated_on = chrono::Utc::now();
+
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    UPDATE mileage_logs
+                    SET trip_date = ?, purpose = ?, distance_metres = ?, rate_cents_per_km = ?,
+                        updated_on = ?
+                    WHERE id = ?
+                "#,
+                self.trip_date,
+                self.purpose,
+                self.distance_metres,
+                self.rate_cents_per_km,
+                updated_on,
+                self.id
+            )
+            .execute(&mut *conn)
+            .observe("reimbursements.update")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Mileage log with id {} not found",
+                    self.id
+                )));
+            }
+
+            let updated = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Mileage log with id {} not found after update",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("Mileage log updated in the database.");
+
+            Ok(updated)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reimbursements::insert::tests::{Result, reimbursable_purchase};
+
+    #[sqlx::test]
+    async fn update_records_claim_and_repayment(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 28).unwrap();
+        let mut reimbursable = reimbursable_purchase(&pool, "Officeworks", -4_200).await?;
+        let repayment = database::Transactions::new(date, "Employer", 4_200)
+            .insert(&pool)
+            .await?;
+        let spending = database::Transactions::new(date, "Cafe", -600)
+            .insert(&pool)
+            .await?;
+
+        reimbursable.claimed_on = Some(date);
+        reimbursable.reimbursed_by = Some(spending.id);
+        let result = reimbursable.update(&pool).await;
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        reimbursable.reimbursed_by = Some(repayment.id);
+        let updated = reimbursable.update(&pool).await?;
+
+        assert_eq!(updated.claimed_on, Some(date));
+        assert!(!updated.is_outstanding());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_mileage_log_changes_fields(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut trip = database::MileageLogs::mock().insert(&pool).await?;
+        trip.distance_metres = 42_000;
+
+        let updated = trip.update(&pool).await?;
+
+        assert_eq!(updated.distance_metres, 42_000);
+
+        let missing = database::MileageLogs::mock().update(&pool).await;
+        assert!(matches!(missing, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Reports {
+    /// Works out the balance of every active account at the end of a day,
+    /// ordered by account name.
+    ///
+    /// A balance is the account's opening balance plus its posted
+    /// transactions up to the day. Accounts with manual
+    /// [`BalanceSnapshots`](database::BalanceSnapshots) take the balance of
+    /// the latest snapshot on or before the day instead, or nothing before
+    /// the first. Balances stay in each account's own currency.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - The day to report balances at the end of
+    /// * `scope` - The user whose accounts are reported
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the database connection fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Reports, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let today = chrono::Local::now().date_naive();
+    ///
+    /// for account in Reports::account_balances(today, UserScope::All, pool).await? {
+    ///     println!("{}: {} {}", account.account_name, account.balance, account.currency);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Report account balances", skip(conn), err)]
+    pub fn account_balances<'c, A>(
+        on: chrono::NaiveDate,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::AccountBalance>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let user_id = scope.user_id();
+
+            let balances = sqlx::query_as!(
+                database::AccountBalance,
+                r#"
+                    SELECT
+                        accounts.id         AS "account_id!: domain::RowID",
+                        accounts.name       AS "account_name!: String",
+                        accounts.currency   AS "currency!: domain::Currency",
+                        CASE
+                            WHEN EXISTS (
+                                SELECT 1 FROM balance_snapshots
+                                WHERE balance_snapshots.account_id = accounts.id
+                                    AND balance_snapshots.source = 'manual'
+                            ) THEN COALESCE((
+                                SELECT balance_snapshots.balance
+                                FROM balance_snapshots
+                                WHERE balance_snapshots.account_id = accounts.id
+                                    AND balance_snapshots.source = 'manual'
+                                    AND balance_snapshots.snapshot_date <= ?1
+                                ORDER BY balance_snapshots.snapshot_date DESC
+                                LIMIT 1
+                            ), 0)
+                            ELSE accounts.opening_balance + COALESCE((
+                                SELECT SUM(transactions.amount)
+                                FROM transactions
+                                WHERE transactions.account_id = accounts.id
+                                    AND transactions.is_draft = 0
+                                    AND transactions.transaction_date <= ?1
+                            ), 0)
+                        END AS "balance!: i64"
+                    FROM accounts
+                    WHERE accounts.is_active = 1 AND (?2 IS NULL OR accounts.user_id = ?2)
+                    ORDER BY accounts.name ASC
+                "#,
+                on,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("reports.account_balances")
+            .await?;
+
+            Ok(balances)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn account_balances_report_active_accounts_on_a_day(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut savings = database::Accounts::new("Savings");
+        savings.opening_balance = 100_000;
+        let savings = savings.insert(&pool).await?;
+        let transactions = [
+            (date(1, 10), -5_000, false),
+            (date(1, 12), -1_000, true),
+            (date(2, 1), 20_000, false),
+        ];
+        for (day, amount, is_draft) in transactions {
+            let mut transaction = database::Transactions::new(day, "Payee", amount);
+            transaction.account_id = Some(savings.id);
+            transaction.is_draft = is_draft;
+            transaction.insert(&pool).await?;
+        }
+
+        let super_fund = database::Accounts::new("Super").insert(&pool).await?;
+        database::BalanceSnapshots::new(super_fund.id, date(1, 1), 80_000)
+            .insert(&pool)
+            .await?;
+        database::BalanceSnapshots::new(super_fund.id, date(2, 1), 90_000)
+            .insert(&pool)
+            .await?;
+
+        let mut closed = database::Accounts::new("Closed");
+        closed.is_active = false;
+        closed.insert(&pool).await?;
+
+        let balances =
+            database::Reports::account_balances(date(1, 31), database::UserScope::All, &pool)
+                .await?;
+
+        let found: Vec<_> = balances
+            .iter()
+            .map(|account| (account.account_name.as_str(), account.balance))
+            .collect();
+        assert_eq!(found, [("Savings", 95_000), ("Super", 80_000)]);
+        assert_eq!(balances[0].account_id, savings.id);
+        assert_eq!(balances[0].currency, domain::Currency::default());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn account_balances_are_limited_to_the_scope(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let owner = database::Users::mock().insert(&pool).await?;
+        let other = database::Users::mock().insert(&pool).await?;
+        let mut account = database::Accounts::new("Everyday");
+        account.user_id = Some(owner.id);
+        let account = account.insert(&pool).await?;
+        database::Accounts::new("Shared").insert(&pool).await?;
+
+        let as_owner = database::Reports::account_balances(
+            date(1, 31),
+            database::UserScope::User(owner.id),
+            &pool,
+        )
+        .await?;
+        let as_other = database::Reports::account_balances(
+            date(1, 31),
+            database::UserScope::User(other.id),
+            &pool,
+        )
+        .await?;
+        let unscoped =
+            database::Reports::account_balances(date(1, 31), database::UserScope::All, &pool)
+                .await?;
+
+        let ids: Vec<_> = as_owner.iter().map(|balance| balance.account_id).collect();
+        assert_eq!(ids, [account.id]);
+        assert!(as_other.is_empty());
+        assert_eq!(unscoped.len(), 2);
+
+        Ok(())
+    }
+}
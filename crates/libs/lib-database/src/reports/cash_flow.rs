@@ -0,0 +1,139 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::Reports {
+    /// Totals the income and expenses in each month between two dates,
+    /// inclusive, oldest month first.
+    ///
+    /// Every month in the range is returned, with zero totals for months
+    /// without transactions, so the result can be charted directly. The
+    /// first and last months only include transactions within the range.
+    /// Months are bucketed by SQLite's `date(..., 'start of month')`, and
+    /// [`MonthlyCashFlow::net`](database::MonthlyCashFlow::net) gives each
+    /// month's net cash flow.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The first transaction date to include
+    /// * `to` - The last transaction date to include
+    /// * `scope` - The user whose transactions are totalled
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - `to` is before `from` (`DatabaseError::Validation`)
+    /// - Database connection fails
+    #[doc(alias = "monthly_cash_flow")]
+    #[tracing::instrument(name = "Report income and expenses by month", skip(conn), err)]
+    pub fn income_vs_expense<'c, A>(
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::MonthlyCashFlow>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            use chrono::Datelike;
+
+            Self::validate_range(from, to)?;
+
+            let mut conn = conn.acquire().await?;
+            let user_id = scope.user_id();
+
+            let totals = sqlx::query_as!(
+                database::MonthlyCashFlow,
+                r#"
+                    SELECT
+                        date(transaction_date, 'start of month')                    AS "month!: chrono::NaiveDate",
+                        COALESCE(SUM(CASE WHEN amount > 0 THEN amount END), 0)      AS "income!: i64",
+                        COALESCE(-SUM(CASE WHEN amount < 0 THEN amount END), 0)     AS "expense!: i64"
+                    FROM transactions
+                    WHERE is_draft = 0 AND transaction_date BETWEEN ?1 AND ?2
+                        AND (?3 IS NULL OR user_id = ?3)
+                    GROUP BY 1
+                    ORDER BY 1 ASC
+                "#,
+                from,
+                to,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("reports.income_vs_expense")
+            .await?;
+
+            let mut totals = totals.into_iter().peekable();
+            let mut months = Vec::new();
+            let mut month = from.with_day(1).expect("first day of month is valid");
+
+            while month <= to {
+                match totals.next_if(|total| total.month == month) {
+                    Some(total) => months.push(total),
+                    None => months.push(database::MonthlyCashFlow {
+                        month,
+                        income: 0,
+                        expense: 0,
+                    }),
+                }
+                month = month + chrono::Months::new(1);
+            }
+
+            Ok(months)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn income_vs_expense_fills_empty_months(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        for (day, amount) in [
+            (date(1, 20), -1_000),
+            (date(1, 25), 5_000),
+            (date(3, 3), -2_000),
+            (date(3, 20), -500),
+        ] {
+            database::Transactions::new(day, "Payee", amount)
+                .insert(&pool)
+                .await?;
+        }
+        let mut draft = database::Transactions::new(date(2, 2), "Payee", -9_000);
+        draft.is_draft = true;
+        draft.insert(&pool).await?;
+
+        let months = database::Reports::income_vs_expense(
+            date(1, 22),
+            date(3, 10),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        let found: Vec<_> = months
+            .iter()
+            .map(|month| (month.month, month.income, month.expense))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (date(1, 1), 5_000, 0),
+                (date(2, 1), 0, 0),
+                (date(3, 1), 0, 2_000)
+            ]
+        );
+        assert_eq!(months[2].net(), -2_000);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,324 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Reports {
+    /// Works out net worth at the end of each month between two dates, and
+    /// on the last date itself, with what each account was worth.
+    ///
+    /// Accounts are valued as in [`Reports::net_worth`](database::Reports::net_worth),
+    /// except that an account with a balance computed when a period ending
+    /// on the day was closed takes that balance. Each account's worth is
+    /// converted into `currency` at the day's exchange rates, so the accounts
+    /// of a point add up to its net worth.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - The currency to report net worth in
+    /// * `from` - A day in the first month to report on
+    /// * `to` - The last day to report on
+    /// * `scope` - The user whose accounts are valued
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - `to` is before `from` (`DatabaseError::Validation`)
+    /// - An account's currency has no exchange rate on or before a day (`DatabaseError::NotFound`)
+    /// - A total is out of range (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Reports, UserScope};
+    /// use lib_domain::Currency;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let from = chrono::NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+    /// let to = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+    ///
+    /// for point in Reports::net_worth_history(Currency::AUD, from, to, UserScope::All, pool).await? {
+    ///     for account in &point.accounts {
+    ///         println!("{} {}: {}", point.on, account.account_name, account.worth);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Report net worth history", skip(conn), err)]
+    pub fn net_worth_history<'c, A>(
+        currency: domain::Currency,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::NetWorthHistoryPoint>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            Self::validate_range(from, to)?;
+
+            let mut conn = conn.acquire().await?;
+            let user_id = scope.user_id();
+
+            let balances = sqlx::query!(
+                r#"
+                    WITH RECURSIVE month_ends (on_date) AS (
+                        SELECT MIN(date(?1, 'start of month', '+1 month', '-1 day'), ?2)
+                        UNION ALL
+                        SELECT MIN(date(on_date, '+1 day', '+1 month', '-1 day'), ?2)
+                        FROM month_ends
+                        WHERE on_date < ?2
+                    ),
+                    valuations AS (
+                        SELECT
+                            month_ends.on_date,
+                            accounts.id,
+                            accounts.name,
+                            accounts.currency,
+                            accounts.opening_balance,
+                            CASE
+                                WHEN EXISTS (
+                                    SELECT 1 FROM balance_snapshots
+                                    WHERE balance_snapshots.account_id = accounts.id
+                                        AND balance_snapshots.source = 'manual'
+                                ) THEN 'manual'
+                                WHEN EXISTS (
+                                    SELECT 1 FROM balance_snapshots
+                                    WHERE balance_snapshots.account_id = accounts.id
+                                        AND balance_snapshots.source = 'computed'
+                                        AND balance_snapshots.snapshot_date = month_ends.on_date
+                                ) THEN 'computed'
+                                ELSE 'transactions'
+                            END AS valued_from
+                        FROM month_ends CROSS JOIN accounts
+                        WHERE ?3 IS NULL OR accounts.user_id = ?3
+                    )
+                    SELECT
+                        valuations.on_date          AS "on_date!: chrono::NaiveDate",
+                        valuations.id               AS "account_id!: domain::RowID",
+                        valuations.name             AS "account_name!: String",
+                        valuations.currency         AS "currency!: domain::Currency",
+                        valuations.valued_from      AS "valued_from!: database::ValuationSources",
+                        CASE valuations.valued_from
+                            WHEN 'manual' THEN COALESCE((
+                                SELECT CASE
+                                    WHEN next.snapshot_date IS NULL THEN prev.balance
+                                    ELSE prev.balance + CAST(ROUND(
+                                        (next.balance - prev.balance)
+                                        * (julianday(valuations.on_date) - julianday(prev.snapshot_date))
+                                        / (julianday(next.snapshot_date) - julianday(prev.snapshot_date))
+                                    ) AS INTEGER)
+                                END
+                                FROM balance_snapshots AS prev
+                                LEFT JOIN balance_snapshots AS next
+                                    ON next.account_id = prev.account_id
+                                    AND next.source = 'manual'
+                                    AND next.snapshot_date = (
+                                        SELECT MIN(later.snapshot_date)
+                                        FROM balance_snapshots AS later
+                                        WHERE later.account_id = prev.account_id
+                                            AND later.source = 'manual'
+                                            AND later.snapshot_date > valuations.on_date
+                                    )
+                                WHERE prev.account_id = valuations.id
+                                    AND prev.source = 'manual'
+                                    AND prev.snapshot_date <= valuations.on_date
+                                ORDER BY prev.snapshot_date DESC
+                                LIMIT 1
+                            ), 0)
+                            WHEN 'computed' THEN (
+                                SELECT balance_snapshots.balance
+                                FROM balance_snapshots
+                                WHERE balance_snapshots.account_id = valuations.id
+                                    AND balance_snapshots.snapshot_date = valuations.on_date
+                            )
+                            ELSE valuations.opening_balance + COALESCE((
+                                SELECT SUM(transactions.amount)
+                                FROM transactions
+                                WHERE transactions.account_id = valuations.id
+                                    AND transactions.is_draft = 0
+                                    AND transactions.transaction_date <= valuations.on_date
+                            ), 0)
+                        END                         AS "balance!: i64"
+                    FROM valuations
+                    ORDER BY valuations.on_date ASC, valuations.name ASC
+                "#,
+                from,
+                to,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("reports.net_worth_history")
+            .await?;
+
+            let mut points: Vec<database::NetWorthHistoryPoint> = Vec::new();
+            for row in balances {
+                let balance = domain::Money::new(row.balance, row.currency);
+                let worth =
+                    database::ExchangeRates::convert(balance, currency, row.on_date, &mut *conn)
+                        .await?;
+
+                let point = match points.last_mut() {
+                    Some(point) if point.on == row.on_date => point,
+                    _ => {
+                        points.push(database::NetWorthHistoryPoint {
+                            on: row.on_date,
+                            net_worth: domain::Money::new(0, currency),
+                            accounts: Vec::new(),
+                        });
+                        points.last_mut().expect("a point was just pushed")
+                    }
+                };
+
+                point.net_worth = point
+                    .net_worth
+                    .checked_add(worth)
+                    .map_err(|error| database::DatabaseError::Validation(error.to_string()))?;
+                point.accounts.push(database::AccountWorth {
+                    account_id: row.account_id,
+                    account_name: row.account_name,
+                    worth,
+                    valued_from: row.valued_from,
+                });
+            }
+
+            Ok(points)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn net_worth_history_breaks_down_each_account(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut everyday = database::Accounts::new("Everyday");
+        everyday.opening_balance = 10_000;
+        let everyday = everyday.insert(&pool).await?;
+        let mut wages = database::Transactions::new(date(1, 15), "Acme Pty Ltd", 250_000);
+        wages.account_id = Some(everyday.id);
+        wages.insert(&pool).await?;
+        let super_fund = database::Accounts::new("Super").insert(&pool).await?;
+        database::BalanceSnapshots::new(super_fund.id, date(1, 1), 100_000)
+            .insert(&pool)
+            .await?;
+        database::BalanceSnapshots::new(super_fund.id, date(3, 1), 159_000)
+            .insert(&pool)
+            .await?;
+
+        let points = database::Reports::net_worth_history(
+            domain::Currency::AUD,
+            date(1, 1),
+            date(2, 28),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        let found: Vec<_> = points
+            .iter()
+            .map(|point| {
+                let accounts: Vec<_> = point
+                    .accounts
+                    .iter()
+                    .map(|account| {
+                        (
+                            account.account_name.as_str(),
+                            account.worth.amount(),
+                            account.valued_from,
+                        )
+                    })
+                    .collect();
+                (point.on, point.net_worth.amount(), accounts)
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    date(1, 31),
+                    390_000,
+                    vec![
+                        (
+                            "Everyday",
+                            260_000,
+                            database::ValuationSources::Transactions
+                        ),
+                        ("Super", 130_000, database::ValuationSources::Manual),
+                    ]
+                ),
+                (
+                    date(2, 28),
+                    418_000,
+                    vec![
+                        (
+                            "Everyday",
+                            260_000,
+                            database::ValuationSources::Transactions
+                        ),
+                        ("Super", 158_000, database::ValuationSources::Manual),
+                    ]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn net_worth_history_takes_closed_period_balances(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let everyday = database::Accounts::new("Everyday").insert(&pool).await?;
+        let mut rent = database::Transactions::new(date(1, 10), "Landlord", -180_000);
+        rent.account_id = Some(everyday.id);
+        rent.insert(&pool).await?;
+        database::PeriodClosings::close(date(1, 31), None, &pool).await?;
+
+        let points = database::Reports::net_worth_history(
+            domain::Currency::AUD,
+            date(1, 1),
+            date(2, 28),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        let found: Vec<_> = points
+            .iter()
+            .map(|point| {
+                (
+                    point.on,
+                    point.net_worth.amount(),
+                    point.accounts[0].valued_from,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (date(1, 31), -180_000, database::ValuationSources::Computed),
+                (
+                    date(2, 28),
+                    -180_000,
+                    database::ValuationSources::Transactions
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+}
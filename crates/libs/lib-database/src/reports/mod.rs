@@ -0,0 +1,40 @@
+//! # Reports Database Module
+//!
+//! Aggregate reports over the ledger, worked out by SQL aggregate queries so
+//! only the totals leave the database:
+//!
+//! - Spending by category over a date range
+//! - Income and expenses for each month of a date range, the monthly cash
+//!   flow, with the net of each month
+//! - Net worth at the end of each month of a date range, valuing accounts
+//!   kept outside the ledger from their balance snapshots
+//! - Net worth history with each account's part in it, taking the balances
+//!   recorded when periods were closed
+//! - The balance of every active account on a day
+//!
+//! Draft transactions are left out of every report.
+
+mod balances;
+mod cash_flow;
+mod history;
+mod model;
+mod net_worth;
+mod spending;
+
+/// Namespace for the aggregate report queries.
+pub use model::Reports;
+
+/// Spending in one category over a date range.
+pub use model::CategorySpending;
+
+/// Income and expenses for one month.
+pub use model::MonthlyCashFlow;
+
+/// Net worth on one day.
+pub use model::NetWorthPoint;
+
+/// Net worth on one day broken down by account, and how each was valued.
+pub use model::{AccountWorth, NetWorthHistoryPoint, ValuationSources};
+
+/// The balance of one active account on a day.
+pub use model::AccountBalance;
@@ -0,0 +1,109 @@
+use crate::{DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Namespace for the aggregate report queries. Reports are read only, so
+/// there is nothing to construct.
+#[derive(Debug, Clone, Copy)]
+pub struct Reports;
+
+/// Spending in one category over a date range.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CategorySpending {
+    /// The category, or `None` for uncategorised transactions.
+    pub category_id: Option<domain::RowID>,
+    pub category_name: Option<String>,
+
+    /// Money spent in minor units (cents), positive. Refunds reduce it.
+    pub spent: i64,
+
+    pub transaction_count: i64,
+}
+
+/// Income and expenses for one month.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MonthlyCashFlow {
+    /// The first day of the month.
+    pub month: chrono::NaiveDate,
+
+    /// Money received in minor units (cents), positive.
+    pub income: i64,
+
+    /// Money spent in minor units (cents), positive.
+    pub expense: i64,
+}
+
+impl MonthlyCashFlow {
+    /// Returns income less expenses, negative when more was spent.
+    pub fn net(&self) -> i64 {
+        self.income - self.expense
+    }
+}
+
+/// Net worth on one day: the balance of every account, converted into one
+/// currency at that day's exchange rates.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct NetWorthPoint {
+    pub on: chrono::NaiveDate,
+    pub net_worth: domain::Money,
+}
+
+/// Net worth on one day with what each account contributed to it, for
+/// charting net worth over time.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct NetWorthHistoryPoint {
+    pub on: chrono::NaiveDate,
+    pub net_worth: domain::Money,
+
+    /// Every account's worth on the day, ordered by account name.
+    pub accounts: Vec<AccountWorth>,
+}
+
+/// What one account was worth on a day.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct AccountWorth {
+    pub account_id: domain::RowID,
+    pub account_name: String,
+
+    /// The account's balance, converted into the report's currency.
+    pub worth: domain::Money,
+    pub valued_from: ValuationSources,
+}
+
+/// How an account's worth on a day was worked out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, serde::Deserialize, serde::Serialize)]
+#[sqlx(rename_all = "lowercase")]
+pub enum ValuationSources {
+    /// Its opening balance and posted transactions up to the day.
+    Transactions,
+    /// Interpolated between its manual balance snapshots.
+    Manual,
+    /// The balance computed when the period ending on the day was closed.
+    Computed,
+}
+
+/// The balance of one active account on a day.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct AccountBalance {
+    pub account_id: domain::RowID,
+    pub account_name: String,
+    pub currency: domain::Currency,
+
+    /// Balance in minor units of the account's currency.
+    pub balance: i64,
+}
+
+impl Reports {
+    /// Checks that a report's date range is not backwards.
+    pub(super) fn validate_range(
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> DatabaseResult<()> {
+        if to < from {
+            return Err(DatabaseError::Validation(format!(
+                "Report end {to} is before its start {from}"
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,269 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Reports {
+    /// Works out net worth at the end of each month between two dates, and
+    /// on the last date itself.
+    ///
+    /// Net worth is the sum of every account's balance, the opening balance
+    /// plus its posted transactions up to the day. Accounts with manual
+    /// [`BalanceSnapshots`](database::BalanceSnapshots) are valued from them
+    /// instead, interpolated in a straight line between the snapshots either
+    /// side of the day and rounded to the nearest cent. After the last
+    /// snapshot its balance is carried forward, and before the first the
+    /// account is worth nothing. Balances are summed per currency by the
+    /// database and then converted into `currency` at each day's exchange
+    /// rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency` - The currency to report net worth in
+    /// * `from` - A day in the first month to report on
+    /// * `to` - The last day to report on
+    /// * `scope` - The user whose accounts are valued
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - `to` is before `from` (`DatabaseError::Validation`)
+    /// - An account's currency has no exchange rate on or before a day (`DatabaseError::NotFound`)
+    /// - A total is out of range (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Reports, UserScope};
+    /// use lib_domain::Currency;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let from = chrono::NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+    /// let to = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+    ///
+    /// for point in Reports::net_worth(Currency::AUD, from, to, UserScope::All, pool).await? {
+    ///     println!("{}: {}", point.on, point.net_worth);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Report net worth over time", skip(conn), err)]
+    pub fn net_worth<'c, A>(
+        currency: domain::Currency,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::NetWorthPoint>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            Self::validate_range(from, to)?;
+
+            let mut conn = conn.acquire().await?;
+            let user_id = scope.user_id();
+
+            let balances = sqlx::query!(
+                r#"
+                    WITH RECURSIVE month_ends (on_date) AS (
+                        SELECT MIN(date(?1, 'start of month', '+1 month', '-1 day'), ?2)
+                        UNION ALL
+                        SELECT MIN(date(on_date, '+1 day', '+1 month', '-1 day'), ?2)
+                        FROM month_ends
+                        WHERE on_date < ?2
+                    )
+                    SELECT
+                        month_ends.on_date  AS "on_date!: chrono::NaiveDate",
+                        accounts.currency   AS "currency!: domain::Currency",
+                        SUM(CASE
+                            WHEN EXISTS (
+                                SELECT 1 FROM balance_snapshots
+                                WHERE balance_snapshots.account_id = accounts.id
+                                    AND balance_snapshots.source = 'manual'
+                            ) THEN COALESCE((
+                                SELECT CASE
+                                    WHEN next.snapshot_date IS NULL THEN prev.balance
+                                    ELSE prev.balance + CAST(ROUND(
+                                        (next.balance - prev.balance)
+                                        * (julianday(month_ends.on_date) - julianday(prev.snapshot_date))
+                                        / (julianday(next.snapshot_date) - julianday(prev.snapshot_date))
+                                    ) AS INTEGER)
+                                END
+                                FROM balance_snapshots AS prev
+                                LEFT JOIN balance_snapshots AS next
+                                    ON next.account_id = prev.account_id
+                                    AND next.source = 'manual'
+                                    AND next.snapshot_date = (
+                                        SELECT MIN(later.snapshot_date)
+                                        FROM balance_snapshots AS later
+                                        WHERE later.account_id = prev.account_id
+                                            AND later.source = 'manual'
+                                            AND later.snapshot_date > month_ends.on_date
+                                    )
+                                WHERE prev.account_id = accounts.id
+                                    AND prev.source = 'manual'
+                                    AND prev.snapshot_date <= month_ends.on_date
+                                ORDER BY prev.snapshot_date DESC
+                                LIMIT 1
+                            ), 0)
+                            ELSE accounts.opening_balance + COALESCE((
+                                SELECT SUM(transactions.amount)
+                                FROM transactions
+                                WHERE transactions.account_id = accounts.id
+                                    AND transactions.is_draft = 0
+                                    AND transactions.transaction_date <= month_ends.on_date
+                            ), 0)
+                        END) AS "balance!: i64"
+                    FROM month_ends CROSS JOIN accounts
+                    WHERE ?3 IS NULL OR accounts.user_id = ?3
+                    GROUP BY month_ends.on_date, accounts.currency
+                    ORDER BY month_ends.on_date ASC
+                "#,
+                from,
+                to,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("reports.net_worth")
+            .await?;
+
+            let mut points: Vec<database::NetWorthPoint> = Vec::new();
+            for row in balances {
+                let balance = domain::Money::new(row.balance, row.currency);
+                let converted =
+                    database::ExchangeRates::convert(balance, currency, row.on_date, &mut *conn)
+                        .await?;
+
+                match points.last_mut() {
+                    Some(point) if point.on == row.on_date => {
+                        point.net_worth =
+                            point.net_worth.checked_add(converted).map_err(|error| {
+                                database::DatabaseError::Validation(error.to_string())
+                            })?;
+                    }
+                    _ => points.push(database::NetWorthPoint {
+                        on: row.on_date,
+                        net_worth: converted,
+                    }),
+                }
+            }
+
+            Ok(points)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn net_worth_reports_each_month_end(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut savings = database::Accounts::new("Savings");
+        savings.opening_balance = 100_000;
+        let savings = savings.insert(&pool).await?;
+        let mut travel = database::Accounts::new("Travel");
+        travel.opening_balance = 10_000;
+        travel.currency = domain::Currency::USD;
+        travel.insert(&pool).await?;
+        let rate =
+            domain::ExchangeRate::parse(domain::Currency::AUD, domain::Currency::USD, "0.5")?;
+        database::ExchangeRates::new(rate, date(1, 1))
+            .insert(&pool)
+            .await?;
+
+        for (day, amount) in [
+            (date(1, 31), -5_000),
+            (date(2, 28), -5_000),
+            (date(3, 10), 20_000),
+        ] {
+            let mut transaction = database::Transactions::new(day, "Payee", amount);
+            transaction.account_id = Some(savings.id);
+            transaction.insert(&pool).await?;
+        }
+
+        let points = database::Reports::net_worth(
+            domain::Currency::AUD,
+            date(1, 15),
+            date(3, 5),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        let found: Vec<_> = points
+            .iter()
+            .map(|point| (point.on, point.net_worth.amount()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (date(1, 31), 115_000),
+                (date(2, 28), 110_000),
+                (date(3, 5), 110_000)
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn net_worth_interpolates_balance_snapshots(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        // Opening balances and transactions are ignored once an account has snapshots
+        let mut super_fund = database::Accounts::new("Super");
+        super_fund.opening_balance = 1_000;
+        let super_fund = super_fund.insert(&pool).await?;
+        let mut fees = database::Transactions::new(date(1, 20), "Fund fees", -500);
+        fees.account_id = Some(super_fund.id);
+        fees.insert(&pool).await?;
+        database::BalanceSnapshots::new(super_fund.id, date(1, 1), 100_000)
+            .insert(&pool)
+            .await?;
+        database::BalanceSnapshots::new(super_fund.id, date(3, 1), 159_000)
+            .insert(&pool)
+            .await?;
+
+        let wallet = database::Accounts::new("Crypto Wallet")
+            .insert(&pool)
+            .await?;
+        database::BalanceSnapshots::new(wallet.id, date(2, 15), 50_000)
+            .insert(&pool)
+            .await?;
+
+        let points = database::Reports::net_worth(
+            domain::Currency::AUD,
+            date(1, 15),
+            date(3, 5),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        // 30 and 58 of the 59 days between the super snapshots, then carried forward
+        let found: Vec<_> = points
+            .iter()
+            .map(|point| (point.on, point.net_worth.amount()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (date(1, 31), 130_000),
+                (date(2, 28), 208_000),
+                (date(3, 5), 209_000)
+            ]
+        );
+
+        Ok(())
+    }
+}
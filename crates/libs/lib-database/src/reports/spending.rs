@@ -0,0 +1,175 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Reports {
+    /// Totals the spending in each category between two dates, inclusive,
+    /// largest first.
+    ///
+    /// Spending is the negated sum of a category's posted transactions, so a
+    /// refund reduces it. Categories whose transactions were money received
+    /// on balance, such as a salary, are left out. Subcategories are reported
+    /// on their own rather than rolled into their parent.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The first transaction date to include
+    /// * `to` - The last transaction date to include
+    /// * `scope` - The user whose spending is reported
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - `to` is before `from` (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Reports, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let from = chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    /// let to = chrono::NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+    ///
+    /// for category in Reports::spending_by_category(from, to, UserScope::All, pool).await? {
+    ///     println!("{:?}: {}", category.category_name, category.spent);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Report spending by category", skip(conn), err)]
+    pub fn spending_by_category<'c, A>(
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<database::CategorySpending>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            Self::validate_range(from, to)?;
+
+            let mut conn = conn.acquire().await?;
+            let user_id = scope.user_id();
+
+            let spending = sqlx::query_as!(
+                database::CategorySpending,
+                r#"
+                    SELECT
+                        t.category_id       AS "category_id?: domain::RowID",
+                        c.name              AS "category_name?",
+                        -SUM(t.amount)      AS "spent!: i64",
+                        COUNT(*)            AS "transaction_count!: i64"
+                    FROM transactions t LEFT JOIN categories c ON c.id = t.category_id
+                    WHERE t.is_draft = 0 AND t.transaction_date BETWEEN ?1 AND ?2
+                        AND (?3 IS NULL OR t.user_id = ?3)
+                    GROUP BY t.category_id
+                    HAVING SUM(t.amount) < 0
+                    ORDER BY SUM(t.amount) ASC, c.name ASC
+                "#,
+                from,
+                to,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("reports.spending_by_category")
+            .await?;
+
+            Ok(spending)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn post(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+        date: chrono::NaiveDate,
+        category_id: Option<domain::RowID>,
+        amount: i64,
+    ) -> Result<()> {
+        let mut transaction = database::Transactions::new(date, "Payee", amount);
+        transaction.category_id = category_id;
+        transaction.insert(pool).await?;
+
+        Ok(())
+    }
+
+    /// Inserts a category with a fixed name, as mock names can collide.
+    async fn category(pool: &sqlx::Pool<sqlx::Sqlite>, name: &str) -> Result<database::Categories> {
+        let mut category = database::Categories::mock();
+        category.name = name.to_string();
+        category.url_slug = None;
+
+        Ok(category.insert(pool).await?)
+    }
+
+    #[sqlx::test]
+    async fn spending_by_category_nets_refunds(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let date = |day| chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        let groceries = category(&pool, "Groceries").await?;
+        let fuel = category(&pool, "Fuel").await?;
+        let salary = category(&pool, "Salary").await?;
+
+        post(&pool, date(1), Some(groceries.id), -12_000).await?;
+        post(&pool, date(8), Some(groceries.id), -8_000).await?;
+        post(&pool, date(9), Some(groceries.id), 1_500).await?;
+        post(&pool, date(10), Some(fuel.id), -7_000).await?;
+        post(&pool, date(12), None, -25_000).await?;
+        post(&pool, date(14), Some(salary.id), 400_000).await?;
+        post(&pool, date(31).succ_opt().unwrap(), Some(fuel.id), -6_000).await?;
+
+        let spending = database::Reports::spending_by_category(
+            date(1),
+            date(31),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        let found: Vec<_> = spending
+            .iter()
+            .map(|category| {
+                (
+                    category.category_id,
+                    category.spent,
+                    category.transaction_count,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (None, 25_000, 1),
+                (Some(groceries.id), 18_500, 3),
+                (Some(fuel.id), 7_000, 1)
+            ]
+        );
+        assert_eq!(
+            spending[1].category_name.as_deref(),
+            Some(groceries.name.as_str())
+        );
+
+        let backwards = database::Reports::spending_by_category(
+            date(31),
+            date(1),
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+        assert!(matches!(
+            backwards,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+//! # Search Module
+//!
+//! Helpers for the full-text indexes kept by the `0020_search` migration
+//! triggers, searched by [`Categories::search`](crate::Categories::search) and
+//! [`Transactions::search`](crate::Transactions::search).
+//!
+//! Search text typed by a user is never passed to FTS5 as is, since stray
+//! quotes or operators would be a syntax error. Instead it is read as:
+//!
+//! - `word`: a word, matched in any indexed column
+//! - `word*`: any word starting with `word`
+//! - `"two words"`: the words next to each other, in order
+//!
+//! Every term must match. Case and accents are ignored, so `cafe` finds
+//! "Café".
+
+use crate::{DatabaseError, DatabaseResult};
+
+/// The most results a search returns when no limit is given.
+pub(crate) const DEFAULT_SEARCH_LIMIT: u32 = 20;
+
+/// Converts search text into an FTS5 match expression.
+///
+/// Each term is quoted so any punctuation in it is searched for literally
+/// instead of read as query syntax. An unclosed phrase runs to the end of the
+/// text.
+///
+/// # Errors
+///
+/// Returns [`DatabaseError::Validation`] if the text has no terms to search
+/// for.
+pub(crate) fn match_expression(text: &str) -> DatabaseResult<String> {
+    let mut terms = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(&next) = chars.peek() {
+        if next.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        if next == '"' {
+            chars.next();
+            let phrase: String = chars.by_ref().take_while(|&c| c != '"').collect();
+            if !phrase.trim().is_empty() {
+                terms.push(quote(phrase.trim()));
+            }
+            continue;
+        }
+
+        let mut word = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() || c == '"' {
+                break;
+            }
+            word.push(c);
+            chars.next();
+        }
+
+        let stem = word.trim_end_matches('*');
+        if stem.is_empty() {
+            continue;
+        }
+
+        if stem.len() < word.len() {
+            terms.push(format!("{}*", quote(stem)));
+        } else {
+            terms.push(quote(stem));
+        }
+    }
+
+    if terms.is_empty() {
+        return Err(DatabaseError::Validation(format!(
+            "Search '{}' has no words to search for",
+            text
+        )));
+    }
+
+    Ok(terms.join(" "))
+}
+
+/// Quotes a term as an FTS5 string, doubling any quotes inside it.
+fn quote(term: &str) -> String {
+    format!("\"{}\"", term.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_expression_quotes_words_prefixes_and_phrases() {
+        assert_eq!(match_expression("coffee").unwrap(), r#""coffee""#);
+        assert_eq!(match_expression("  gro*  ").unwrap(), r#""gro"*"#);
+        assert_eq!(
+            match_expression(r#"woolies "corner cafe" fuel*"#).unwrap(),
+            r#""woolies" "corner cafe" "fuel"*"#
+        );
+    }
+
+    #[test]
+    fn match_expression_escapes_query_syntax() {
+        assert_eq!(
+            match_expression("AND OR NOT").unwrap(),
+            r#""AND" "OR" "NOT""#
+        );
+        assert_eq!(
+            match_expression("col:value (x)").unwrap(),
+            r#""col:value" "(x)""#
+        );
+        assert_eq!(
+            match_expression(r#""unclosed phrase"#).unwrap(),
+            r#""unclosed phrase""#
+        );
+    }
+
+    #[test]
+    fn match_expression_rejects_empty_search() {
+        for text in ["", "   ", "*", r#""""#, r#"" " **"#] {
+            assert!(matches!(
+                match_expression(text),
+                Err(DatabaseError::Validation(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,349 @@
+//! # Seed Data Module
+//!
+//! Gives a new ledger something to start from: a standard chart of
+//! categories, and for trying the server out, a few months of demo
+//! transactions.
+//!
+//! The chart is embedded in the binary from `seed/categories.json`, as a
+//! list of top level categories, such as Income, Housing and Food, each with
+//! its subcategories:
+//!
+//! ```json
+//! [
+//!     {
+//!         "code": "FOD",
+//!         "name": "Food",
+//!         "category_type": "Expense",
+//!         "icon": "shopping-cart",
+//!         "children": [
+//!             { "code": "FOD.GRO", "name": "Groceries", "category_type": "Expense" }
+//!         ]
+//!     }
+//! ]
+//! ```
+//!
+//! [`SeedData::categories`] loads it into a ledger without categories, on its
+//! first start, and does nothing to a ledger that has some.
+//! [`SeedData::demo`] adds two accounts and [`DEMO_DAYS`] of transactions,
+//! filed under the chart's categories, to a ledger without transactions.
+//! Unlike [`DemoData::reset`](database::DemoData::reset), neither removes
+//! anything.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_database::SeedData;
+//!
+//! # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+//! let seeded = SeedData::categories(pool).await?;
+//! println!("Seeded {} categories", seeded.categories);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// The standard chart of categories.
+const CHART: &str = include_str!("../seed/categories.json");
+
+/// Days back from today the demo transactions start.
+pub const DEMO_DAYS: i64 = 90;
+
+/// Demo transactions repeated every `every_days` over [`DEMO_DAYS`], as
+/// payee, amount in cents, category code, days between them, the first day
+/// back from today, and whether they are paid by credit card.
+const DEMO_TRANSACTIONS: &[(&str, i64, &str, i64, i64, bool)] = &[
+    ("Acme Pty Ltd", 385_000, "INC.SAL", 14, 3, false),
+    ("Harbour Real Estate", -240_000, "HOU.RNT", 14, 6, false),
+    ("Fresh Market", -14_250, "FOD.GRO", 7, 1, false),
+    ("Corner Grocer", -3_680, "FOD.GRO", 5, 3, true),
+    ("Thai Garden", -6_400, "FOD.DIN", 11, 5, true),
+    ("Corner Cafe", -1_850, "FOD.TKA", 3, 0, true),
+    ("Metro Transit", -5_000, "TRN.PUB", 7, 2, false),
+    ("Coastal Fuel", -7_900, "TRN.FUE", 10, 4, true),
+    ("City Power", -21_730, "UTL.ELE", 30, 12, false),
+    ("Fastnet Broadband", -8_900, "UTL.NET", 30, 18, false),
+    ("StreamBox", -1_799, "PER.SUB", 30, 9, true),
+    ("Chemist Corner", -2_495, "HLT.PHA", 21, 8, true),
+];
+
+/// What was seeded into the ledger.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SeedData {
+    /// Accounts seeded.
+    pub accounts: usize,
+
+    /// Categories seeded.
+    pub categories: usize,
+
+    /// Transactions seeded.
+    pub transactions: usize,
+}
+
+/// A category of the chart, with its subcategories.
+#[derive(Debug, serde::Deserialize)]
+struct ChartEntry {
+    code: String,
+    name: String,
+    category_type: domain::CategoryTypes,
+    #[serde(default)]
+    icon: Option<String>,
+    #[serde(default)]
+    children: Vec<ChartEntry>,
+}
+
+impl SeedData {
+    /// Loads the standard chart of categories, in one transaction, if the
+    /// ledger has no categories, deleted or not.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError::Sqlx` if a category cannot be inserted.
+    /// Nothing is seeded when any category fails.
+    #[tracing::instrument(name = "Seed chart of categories", skip(pool), err)]
+    pub async fn categories(pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<Self> {
+        let mut tx = pool.begin().await?;
+
+        let seeded = Self::seed_categories(&mut tx).await?;
+
+        tx.commit().await?;
+
+        if seeded.categories > 0 {
+            tracing::info!(
+                "Seeded the standard chart of {} categories",
+                seeded.categories
+            );
+        }
+
+        Ok(seeded)
+    }
+
+    /// Seeds demo accounts and transactions dated up to `today`, in one
+    /// transaction, if the ledger has no transactions. The chart of categories
+    /// is loaded first if the ledger has no categories. Demo transactions
+    /// whose category the ledger does not have are left uncategorised.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError::Sqlx` if a row cannot be inserted. Nothing is
+    /// seeded when any row fails.
+    #[tracing::instrument(name = "Seed demo transactions", skip(pool), err)]
+    pub async fn demo(
+        today: chrono::NaiveDate,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Self> {
+        let mut tx = pool.begin().await?;
+
+        let transactions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM transactions")
+            .fetch_one(&mut *tx)
+            .observe_one("seed.demo")
+            .await?;
+        if transactions > 0 {
+            tracing::info!("Not seeding demo transactions, the ledger already has {transactions}");
+            return Ok(Self::default());
+        }
+
+        let mut seeded = Self::seed_categories(&mut tx).await?;
+
+        let mut everyday = database::Accounts::new("Everyday Account");
+        everyday.opening_balance = 420_000;
+        everyday.insert(&mut *tx).await?;
+
+        let credit_card = database::Accounts::new("Credit Card");
+        credit_card.insert(&mut *tx).await?;
+        seeded.accounts = 2;
+
+        let codes: std::collections::HashMap<String, domain::RowID> =
+            sqlx::query_as("SELECT code, id FROM categories WHERE deleted_on IS NULL")
+                .fetch_all(&mut *tx)
+                .observe("seed.demo")
+                .await?
+                .into_iter()
+                .collect();
+
+        for (payee, amount, code, every_days, first_day_back, on_credit) in DEMO_TRANSACTIONS {
+            let mut days_back = *first_day_back;
+            while days_back < DEMO_DAYS {
+                let date = today - chrono::Duration::days(days_back);
+                let mut transaction =
+                    database::Transactions::new(date, *payee, vary(*amount, days_back));
+                transaction.category_id = codes.get(*code).copied();
+                transaction.account_id = Some(if *on_credit {
+                    credit_card.id
+                } else {
+                    everyday.id
+                });
+                transaction.insert(&mut *tx).await?;
+
+                seeded.transactions += 1;
+                days_back += every_days;
+            }
+        }
+
+        tx.commit().await?;
+
+        tracing::info!(
+            "Seeded {} demo transactions over the last {DEMO_DAYS} days",
+            seeded.transactions
+        );
+
+        Ok(seeded)
+    }
+
+    /// Inserts the chart of categories if the ledger has none.
+    async fn seed_categories(conn: &mut sqlx::SqliteConnection) -> DatabaseResult<Self> {
+        let existing: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM categories")
+            .fetch_one(&mut *conn)
+            .observe_one("seed.categories")
+            .await?;
+        if existing > 0 {
+            return Ok(Self::default());
+        }
+
+        let mut categories = 0;
+        for entry in chart()? {
+            let parent = entry.build(None)?.insert(&mut *conn).await?;
+            categories += 1;
+
+            for child in &entry.children {
+                child.build(Some(parent.id))?.insert(&mut *conn).await?;
+                categories += 1;
+            }
+        }
+
+        Ok(Self {
+            categories,
+            ..Self::default()
+        })
+    }
+}
+
+impl ChartEntry {
+    /// The category of the entry, under `parent_id`.
+    fn build(&self, parent_id: Option<domain::RowID>) -> DatabaseResult<database::Categories> {
+        database::CategoriesBuilder::new()
+            .with_code(&self.code)
+            .with_name(&self.name)
+            .with_category_type(self.category_type.clone())
+            .with_icon_opt(self.icon.clone())
+            .with_parent_id_opt(parent_id)
+            .build()
+            .map_err(|e| DatabaseError::Validation(e.to_string()))
+    }
+}
+
+/// Parses the embedded chart of categories.
+fn chart() -> DatabaseResult<Vec<ChartEntry>> {
+    serde_json::from_str(CHART)
+        .map_err(|e| DatabaseError::Other(format!("Invalid chart of categories: {e}")))
+}
+
+/// Varies a spending amount by up to 15% either way, the same way for the
+/// same day, so demo spending is not identical week to week. Income, rent and
+/// bills, due every fortnight or longer, are the same each time.
+fn vary(amount: i64, days_back: i64) -> i64 {
+    if amount > 0 || amount.abs() >= 100_000 {
+        return amount;
+    }
+
+    let percent = 85 + (days_back * 7_919) % 31;
+    amount * percent / 100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[test]
+    fn chart_parses_with_unique_codes_and_names() -> Result<()> {
+        let chart = chart()?;
+        let entries: Vec<&ChartEntry> = chart
+            .iter()
+            .flat_map(|entry| std::iter::once(entry).chain(&entry.children))
+            .collect();
+
+        let codes: std::collections::HashSet<&str> =
+            entries.iter().map(|entry| entry.code.as_str()).collect();
+        let names: std::collections::HashSet<&str> =
+            entries.iter().map(|entry| entry.name.as_str()).collect();
+
+        assert_eq!(codes.len(), entries.len());
+        assert_eq!(names.len(), entries.len());
+        assert!(
+            DEMO_TRANSACTIONS
+                .iter()
+                .all(|(_, _, code, ..)| codes.contains(code))
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn categories_are_seeded_only_into_a_ledger_without_any(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let seeded = SeedData::categories(&pool).await?;
+        let again = SeedData::categories(&pool).await?;
+
+        let groceries =
+            database::Categories::find_by_code("FOD.GRO", database::UserScope::All, &pool)
+                .await?
+                .unwrap();
+        let food = database::Categories::find_by_code("FOD", database::UserScope::All, &pool)
+            .await?
+            .unwrap();
+
+        assert_eq!(
+            seeded.categories as i64,
+            database::Usage::find(&pool).await?.categories
+        );
+        assert_eq!(again, SeedData::default());
+        assert_eq!(groceries.parent_id, Some(food.id));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn demo_files_transactions_under_the_chart(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+
+        let seeded = SeedData::demo(today, &pool).await?;
+        let again = SeedData::demo(today, &pool).await?;
+
+        let uncategorised: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM transactions WHERE category_id IS NULL")
+                .fetch_one(&pool)
+                .await?;
+        let oldest: chrono::NaiveDate =
+            sqlx::query_scalar("SELECT MIN(transaction_date) FROM transactions")
+                .fetch_one(&pool)
+                .await?;
+
+        assert_eq!(seeded.accounts, 2);
+        assert!(seeded.categories > 0);
+        assert_eq!(
+            seeded.transactions as i64,
+            database::Usage::find(&pool).await?.transactions
+        );
+        assert_eq!(uncategorised, 0);
+        assert!(oldest > today - chrono::Duration::days(DEMO_DAYS));
+        assert_eq!(again, SeedData::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn vary_keeps_income_and_bills_fixed() {
+        assert_eq!(vary(385_000, 3), 385_000);
+        assert_eq!(vary(-240_000, 6), -240_000);
+
+        for days_back in 0..DEMO_DAYS {
+            let varied = vary(-10_000, days_back);
+            assert!((-11_500..=-8_500).contains(&varied), "{varied}");
+        }
+    }
+}
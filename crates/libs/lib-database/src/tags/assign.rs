@@ -0,0 +1,180 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Tags {
+    /// Replaces the tags of a transaction, creating any tag that does not
+    /// exist yet.
+    ///
+    /// Names are cleaned up with [`Tags::normalise_name`](database::Tags::normalise_name),
+    /// so `#Travel` and `travel` are the same tag. An empty set removes every
+    /// tag from the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - The transaction to tag
+    /// * `names` - Every tag the transaction should have
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction's tag names, sorted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A tag name is invalid (`DatabaseError::Validation`)
+    /// - The transaction does not exist (foreign key violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Tags;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, transaction_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let tags = Tags::set_for_transaction(transaction_id, &["#Travel", "work"], pool).await?;
+    ///
+    /// assert_eq!(tags, ["travel", "work"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Set tags of transaction",
+        skip(names, conn),
+        fields(transaction_id = %transaction_id),
+        err
+    )]
+    pub fn set_for_transaction<'a, 'c, A, S>(
+        transaction_id: domain::RowID,
+        names: &'a [S],
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<String>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        S: AsRef<str> + Sync,
+        'c: 'a,
+    {
+        async move {
+            let names = Self::normalise_names(names)?;
+
+            let mut tx = conn.begin().await?;
+
+            sqlx::query!(
+                r#"
+                    DELETE FROM transaction_tags
+                    WHERE transaction_id = ?
+                "#,
+                transaction_id
+            )
+            .execute(&mut *tx)
+            .observe("tags.set_for_transaction")
+            .await?;
+
+            for name in &names {
+                let new_id = domain::RowID::new();
+                let now = chrono::Utc::now();
+
+                // Updating the name to itself on conflict returns the id of
+                // the tag that already exists
+                let tag_id = sqlx::query_scalar!(
+                    r#"
+                        INSERT INTO tags (id, name, created_on)
+                        VALUES (?1, ?2, ?3)
+                        ON CONFLICT (name) DO UPDATE SET name = excluded.name
+                        RETURNING id AS "id!: domain::RowID"
+                    "#,
+                    new_id,
+                    name,
+                    now
+                )
+                .fetch_one(&mut *tx)
+                .observe_one("tags.set_for_transaction")
+                .await?;
+
+                sqlx::query!(
+                    r#"
+                        INSERT INTO transaction_tags (transaction_id, tag_id)
+                        VALUES (?, ?)
+                    "#,
+                    transaction_id,
+                    tag_id
+                )
+                .execute(&mut *tx)
+                .observe("tags.set_for_transaction")
+                .await?;
+            }
+
+            tx.commit().await?;
+
+            Ok(names)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn set_for_transaction_replaces_tags(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let transaction = database::Transactions::mock().insert(&pool).await?;
+
+        database::Tags::set_for_transaction(transaction.id, &["travel", "food"], &pool).await?;
+        let tags = database::Tags::set_for_transaction(transaction.id, &["#Work", "travel"], &pool)
+            .await?;
+
+        assert_eq!(tags, ["travel", "work"]);
+        assert_eq!(
+            database::Tags::find_for_transaction(transaction.id, &pool).await?,
+            tags
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn set_for_transaction_shares_tags_between_transactions(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let first = database::Transactions::mock().insert(&pool).await?;
+        let second = database::Transactions::mock().insert(&pool).await?;
+
+        database::Tags::set_for_transaction(first.id, &["travel"], &pool).await?;
+        database::Tags::set_for_transaction(second.id, &["TRAVEL"], &pool).await?;
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tags")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(count, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn set_for_transaction_rejects_invalid_names(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let transaction = database::Transactions::mock().insert(&pool).await?;
+        database::Tags::set_for_transaction(transaction.id, &["travel"], &pool).await?;
+
+        let result =
+            database::Tags::set_for_transaction(transaction.id, &["road trip"], &pool).await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        assert_eq!(
+            database::Tags::find_for_transaction(transaction.id, &pool).await?,
+            ["travel"]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Tags {
+    /// Finds the tag names of a transaction, sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - The transaction whose tags are read
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the tag names, empty if the transaction has none or does not
+    /// exist.
+    #[tracing::instrument(
+        name = "Find tags of transaction",
+        skip(conn),
+        fields(transaction_id = %transaction_id),
+        err
+    )]
+    pub fn find_for_transaction<'c, A>(
+        transaction_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<String>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let names = sqlx::query_scalar!(
+                r#"
+                    SELECT tags.name
+                    FROM transaction_tags
+                    JOIN tags ON tags.id = transaction_tags.tag_id
+                    WHERE transaction_tags.transaction_id = ?
+                    ORDER BY tags.name
+                "#,
+                transaction_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("tags.find_for_transaction")
+            .await?;
+
+            Ok(names)
+        }
+    }
+
+    /// Finds the tag names of many transactions in one query, such as a page
+    /// of a list.
+    ///
+    /// # Returns
+    ///
+    /// Returns the sorted tag names by transaction. Transactions without tags
+    /// are left out of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Tags, Transactions, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let drafts = Transactions::find_drafts(UserScope::All, pool).await?;
+    /// let ids: Vec<_> = drafts.iter().map(|draft| draft.id).collect();
+    ///
+    /// let mut tags = Tags::find_for_transactions(&ids, pool).await?;
+    /// for draft in drafts {
+    ///     println!("{}: {:?}", draft.payee, tags.remove(&draft.id).unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find tags of transactions",
+        skip(transaction_ids, conn),
+        fields(count = transaction_ids.len()),
+        err
+    )]
+    pub fn find_for_transactions<'a, 'c, A>(
+        transaction_ids: &'a [domain::RowID],
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<BTreeMap<domain::RowID, Vec<String>>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            if transaction_ids.is_empty() {
+                return Ok(BTreeMap::new());
+            }
+
+            let mut conn = conn.acquire().await?;
+
+            // The ids are passed as one JSON array, as SQLite cannot bind a list
+            let ids = serde_json::to_string(
+                &transaction_ids
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+            )
+            .map_err(|e| DatabaseError::Other(format!("Transaction ids cannot be written: {e}")))?;
+            let rows = sqlx::query!(
+                r#"
+                    SELECT
+                        transaction_tags.transaction_id AS "transaction_id!: domain::RowID",
+                        tags.name
+                    FROM transaction_tags
+                    JOIN tags ON tags.id = transaction_tags.tag_id
+                    WHERE transaction_tags.transaction_id IN (SELECT value FROM json_each(?))
+                    ORDER BY tags.name
+                "#,
+                ids
+            )
+            .fetch_all(&mut *conn)
+            .observe("tags.find_for_transactions")
+            .await?;
+
+            let mut tags: BTreeMap<domain::RowID, Vec<String>> = BTreeMap::new();
+            for row in rows {
+                tags.entry(row.transaction_id).or_default().push(row.name);
+            }
+
+            Ok(tags)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tags::assign::tests::Result;
+
+    #[sqlx::test]
+    async fn find_for_transactions_groups_tags_by_transaction(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let tagged = database::Transactions::mock().insert(&pool).await?;
+        let untagged = database::Transactions::mock().insert(&pool).await?;
+        database::Tags::set_for_transaction(tagged.id, &["work", "travel"], &pool).await?;
+
+        let tags = database::Tags::find_for_transactions(&[tagged.id, untagged.id], &pool).await?;
+
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[&tagged.id], ["travel", "work"]);
+
+        Ok(())
+    }
+}
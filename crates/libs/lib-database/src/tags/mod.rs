@@ -0,0 +1,22 @@
+//! # Tags Database Module
+//!
+//! Tags cross-cut transactions beyond their single category, so "#travel"
+//! can gather flights, hotels and meals that are each categorised on their
+//! own. A transaction may have many tags and a tag many transactions, linked
+//! through the `transaction_tags` junction table.
+//!
+//! Tag names are lowercase without the leading `#`, as quick entry parses
+//! them, and are created the first time a transaction is given them. A
+//! transaction's tags are replaced as a set, and suggested as they are typed
+//! by how many transactions already use them.
+
+mod assign;
+mod find;
+mod model;
+mod suggest;
+
+/// Database row model representing a tag.
+pub use model::Tags;
+
+/// A tag suggested for a prefix, with how many transactions use it.
+pub use suggest::TagSuggestion;
@@ -0,0 +1,98 @@
+use crate::{DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Longest tag name, in characters.
+const MAX_NAME_LENGTH: usize = 50;
+
+/// A tag that transactions can be given, by its lowercase name.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct Tags {
+    pub id: domain::RowID,
+    pub name: String,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl Tags {
+    /// Cleans up a tag name as typed: trimmed, without a leading `#`, and
+    /// lowercased.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the name is blank, contains
+    /// whitespace, or is longer than 50 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::Tags;
+    ///
+    /// assert_eq!(Tags::normalise_name(" #Travel ").unwrap(), "travel");
+    /// assert!(Tags::normalise_name("road trip").is_err());
+    /// ```
+    pub fn normalise_name(name: &str) -> DatabaseResult<String> {
+        let trimmed = name.trim();
+        let name = trimmed.strip_prefix('#').unwrap_or(trimmed).to_lowercase();
+
+        if name.is_empty() {
+            return Err(DatabaseError::Validation(
+                "Tag name cannot be empty".to_string(),
+            ));
+        }
+
+        if name.chars().any(char::is_whitespace) {
+            return Err(DatabaseError::Validation(format!(
+                "Tag name '{name}' cannot contain spaces"
+            )));
+        }
+
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(DatabaseError::Validation(format!(
+                "Tag name '{name}' is longer than {MAX_NAME_LENGTH} characters"
+            )));
+        }
+
+        Ok(name)
+    }
+
+    /// Cleans up a set of tag names, sorted with duplicates removed.
+    pub(crate) fn normalise_names<S: AsRef<str>>(names: &[S]) -> DatabaseResult<Vec<String>> {
+        let mut names = names
+            .iter()
+            .map(|name| Self::normalise_name(name.as_ref()))
+            .collect::<DatabaseResult<Vec<_>>>()?;
+        names.sort();
+        names.dedup();
+
+        Ok(names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalise_names_sorts_and_deduplicates() {
+        let names = Tags::normalise_names(&["Travel", "#food", "travel"]).unwrap();
+
+        assert_eq!(names, ["food", "travel"]);
+    }
+
+    #[test]
+    fn normalise_name_rejects_blank_spaced_and_long_names() {
+        let long = "a".repeat(MAX_NAME_LENGTH + 1);
+
+        assert!(matches!(
+            Tags::normalise_name(" # "),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            Tags::normalise_name("road trip"),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            Tags::normalise_name(&long),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+/// A tag suggested for a prefix, with how many transactions use it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct TagSuggestion {
+    pub name: String,
+    pub transaction_count: i64,
+}
+
+impl database::Tags {
+    /// Suggests tags starting with `prefix`, the most used first, to
+    /// complete a tag as it is typed.
+    ///
+    /// The prefix is cleaned up like a tag name, so `#Tr` suggests
+    /// `travel`, and an empty prefix suggests the most used tags. Only
+    /// transactions in `scope` are counted, and tags no transaction in
+    /// `scope` uses are not suggested.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The start of the tag typed so far
+    /// * `limit` - The most suggestions to return
+    /// * `scope` - The user whose transactions are counted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Tags, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for suggestion in Tags::suggest("#tr", 5, UserScope::All, pool).await? {
+    ///     println!("#{} ({} transactions)", suggestion.name, suggestion.transaction_count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Suggest tags", skip(conn), err)]
+    pub fn suggest<'a, 'c, A>(
+        prefix: &'a str,
+        limit: u32,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<TagSuggestion>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let trimmed = prefix.trim();
+            let prefix = trimmed.strip_prefix('#').unwrap_or(trimmed).to_lowercase();
+            let pattern = format!(
+                "{}%",
+                prefix
+                    .replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_")
+            );
+            let user_id = scope.user_id();
+
+            let suggestions = sqlx::query_as!(
+                TagSuggestion,
+                r#"
+                    SELECT
+                        tags.name,
+                        COUNT(*) AS "transaction_count!: i64"
+                    FROM tags
+                    JOIN transaction_tags ON transaction_tags.tag_id = tags.id
+                    JOIN transactions ON transactions.id = transaction_tags.transaction_id
+                    WHERE tags.name LIKE ?1 ESCAPE '\'
+                        AND (?2 IS NULL OR transactions.user_id = ?2)
+                    GROUP BY tags.id
+                    ORDER BY COUNT(*) DESC, tags.name
+                    LIMIT ?3
+                "#,
+                pattern,
+                user_id,
+                limit
+            )
+            .fetch_all(&mut *conn)
+            .observe("tags.suggest")
+            .await?;
+
+            Ok(suggestions)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tags::assign::tests::Result;
+    use lib_domain as domain;
+
+    async fn tagged(
+        names: &[&str],
+        user_id: Option<domain::RowID>,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<()> {
+        let mut transaction = database::Transactions::mock();
+        transaction.user_id = user_id;
+        let transaction = transaction.insert(pool).await?;
+        database::Tags::set_for_transaction(transaction.id, names, pool).await?;
+
+        Ok(())
+    }
+
+    fn names(suggestions: &[TagSuggestion]) -> Vec<&str> {
+        suggestions
+            .iter()
+            .map(|suggestion| suggestion.name.as_str())
+            .collect()
+    }
+
+    #[sqlx::test]
+    async fn suggest_orders_matching_tags_by_use(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        tagged(&["train", "food"], None, &pool).await?;
+        tagged(&["travel", "food"], None, &pool).await?;
+        tagged(&["travel"], None, &pool).await?;
+
+        let suggestions =
+            database::Tags::suggest("#TR", 10, database::UserScope::All, &pool).await?;
+        let limited = database::Tags::suggest("", 1, database::UserScope::All, &pool).await?;
+
+        assert_eq!(names(&suggestions), ["travel", "train"]);
+        assert_eq!(suggestions[0].transaction_count, 2);
+        assert_eq!(names(&limited), ["food"]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn suggest_counts_only_transactions_in_scope(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let user = database::Users::new("ian").insert(&pool).await?;
+        tagged(&["travel"], Some(user.id), &pool).await?;
+        tagged(&["tax"], None, &pool).await?;
+
+        let suggestions =
+            database::Tags::suggest("t", 10, database::UserScope::User(user.id), &pool).await?;
+
+        assert_eq!(names(&suggestions), ["travel"]);
+
+        Ok(())
+    }
+}
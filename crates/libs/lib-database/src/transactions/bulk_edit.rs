@@ -0,0 +1,716 @@
+//! # Bulk Edit
+//!
+//! Applies the same change to every transaction matching a filter, for
+//! cleanup jobs such as renaming a payee after an import. An edit is previewed
+//! first, giving the number of matching transactions and a sample showing the
+//! change, and is then applied in chunks. Each chunk is its own database
+//! transaction, so a large edit never holds the write lock for long.
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Selects the transactions a bulk edit applies to. Every field that is set
+/// must match, and at least one must be set.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransactionFilter {
+    /// Payee to match, ignoring ASCII case.
+    pub payee: Option<String>,
+
+    /// Payee pattern to match, ignoring ASCII case, where `*` matches any
+    /// run of characters, e.g. `AMZN*`.
+    pub payee_pattern: Option<String>,
+
+    /// First transaction date to include.
+    pub from_date: Option<chrono::NaiveDate>,
+
+    /// Last transaction date to include.
+    pub to_date: Option<chrono::NaiveDate>,
+
+    /// Category the transactions are classified under.
+    pub category_id: Option<domain::RowID>,
+
+    /// Account the transactions are posted to.
+    pub account_id: Option<domain::RowID>,
+}
+
+/// The changes a bulk edit makes to each matching transaction.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransactionChanges {
+    /// New payee for every transaction.
+    pub payee: Option<String>,
+
+    /// Number of days to move each transaction date, negative for earlier.
+    pub shift_days: i64,
+
+    /// Tags to give every transaction, keeping the tags it already has.
+    /// Names are cleaned up with
+    /// [`Tags::normalise_name`](database::Tags::normalise_name).
+    pub add_tags: Vec<String>,
+}
+
+/// The result of previewing a bulk edit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkEditPreview {
+    /// Number of transactions the edit applies to.
+    pub count: usize,
+
+    /// The first matching transactions, oldest first, as they would be after
+    /// the edit.
+    pub sample: Vec<database::Transactions>,
+
+    /// The tag names of each sampled transaction after the edit, sorted, in
+    /// the order of `sample`.
+    pub sample_tags: Vec<Vec<String>>,
+}
+
+impl TransactionFilter {
+    pub(super) fn validate(&self) -> DatabaseResult<()> {
+        if *self == Self::default() {
+            return Err(DatabaseError::Validation(
+                "Bulk edit filter must select by at least one field".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// The payee pattern as a `LIKE` pattern escaped with `\`, so only `*`
+    /// is a wildcard.
+    fn payee_like(&self) -> Option<String> {
+        self.payee_pattern.as_ref().map(|pattern| {
+            pattern
+                .chars()
+                .fold(String::with_capacity(pattern.len()), |mut like, c| {
+                    match c {
+                        '*' => like.push('%'),
+                        '%' | '_' | '\\' => {
+                            like.push('\\');
+                            like.push(c);
+                        }
+                        c => like.push(c),
+                    }
+                    like
+                })
+        })
+    }
+}
+
+impl TransactionChanges {
+    fn validate(&self) -> DatabaseResult<()> {
+        if *self == Self::default() {
+            return Err(DatabaseError::Validation(
+                "Bulk edit has no changes".to_string(),
+            ));
+        }
+
+        database::Tags::normalise_names(&self.add_tags)?;
+
+        Ok(())
+    }
+
+    /// The tag names a transaction tagged with `tags` has after the changes,
+    /// sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if a tag name is not valid.
+    pub fn apply_tags(&self, mut tags: Vec<String>) -> DatabaseResult<Vec<String>> {
+        tags.extend(self.add_tags.iter().cloned());
+
+        database::Tags::normalise_names(&tags)
+    }
+
+    /// Applies the changes to a transaction, without writing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if the shifted date is out of range
+    /// or the changed transaction is not valid.
+    pub fn apply(
+        &self,
+        mut transaction: database::Transactions,
+    ) -> DatabaseResult<database::Transactions> {
+        if let Some(payee) = &self.payee {
+            transaction.payee = payee.clone();
+        }
+
+        if self.shift_days != 0 {
+            transaction.transaction_date = chrono::Duration::try_days(self.shift_days)
+                .and_then(|shift| transaction.transaction_date.checked_add_signed(shift))
+                .ok_or_else(|| {
+                    DatabaseError::Validation(format!(
+                        "Transaction {} date cannot be shifted by {} days",
+                        transaction.id, self.shift_days
+                    ))
+                })?;
+        }
+
+        transaction.validate()?;
+
+        Ok(transaction)
+    }
+}
+
+impl database::Transactions {
+    /// Previews a bulk edit, returning how many transactions it applies to and
+    /// a sample of up to `sample_size` of them with the changes applied. Only
+    /// transactions in `scope` are matched. Nothing is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if the filter or changes are empty,
+    /// a tag name is not valid, or a sampled transaction would be invalid
+    /// after the change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{TransactionChanges, TransactionFilter, Transactions, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let filter = TransactionFilter { payee: Some("CORNER CAFE PTY".to_string()), ..Default::default() };
+    /// let changes = TransactionChanges { payee: Some("Corner Cafe".to_string()), ..Default::default() };
+    ///
+    /// let preview = Transactions::bulk_edit_preview(&filter, &changes, 5, UserScope::All, pool).await?;
+    /// println!("{} transactions will be renamed", preview.count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Preview transaction bulk edit", skip(conn), err)]
+    pub fn bulk_edit_preview<'a, 'c, A>(
+        filter: &'a TransactionFilter,
+        changes: &'a TransactionChanges,
+        sample_size: usize,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<BulkEditPreview>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            filter.validate()?;
+            changes.validate()?;
+
+            let mut conn = conn.acquire().await?;
+
+            let ids = Self::fetch_matching_ids(filter, scope, &mut conn).await?;
+
+            let mut sample = Vec::with_capacity(sample_size.min(ids.len()));
+            let mut sample_tags = Vec::with_capacity(sample.capacity());
+            for &id in ids.iter().take(sample_size) {
+                if let Some(transaction) = Self::fetch_by_id(id, &mut conn).await? {
+                    sample.push(changes.apply(transaction)?);
+                    let tags = database::Tags::find_for_transaction(id, &mut *conn).await?;
+                    sample_tags.push(changes.apply_tags(tags)?);
+                }
+            }
+
+            Ok(BulkEditPreview {
+                count: ids.len(),
+                sample,
+                sample_tags,
+            })
+        }
+    }
+
+    /// Applies a bulk edit to every matching transaction, `chunk_size`
+    /// transactions at a time. Only transactions in `scope` are matched.
+    ///
+    /// The matching transactions are selected once, before any are changed.
+    /// Each chunk is written in its own database transaction, so if a chunk
+    /// fails, for example because a shifted date falls in a closed period, the
+    /// chunks before it stay applied and the error is returned.
+    ///
+    /// A transfer transaction's date is copied to the other transaction of
+    /// its transfer, so when both match, the other is not shifted again.
+    /// Tags are only added to the transactions that match.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of transactions updated.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The filter or changes are empty (`DatabaseError::Validation`)
+    /// - A changed transaction or tag name is not valid (`DatabaseError::Validation`)
+    /// - A current or shifted date is in a closed period (`DatabaseError::PeriodClosed`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{TransactionChanges, TransactionFilter, Transactions, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// // Imported a month of statements a day late
+    /// let filter = TransactionFilter {
+    ///     from_date: chrono::NaiveDate::from_ymd_opt(2025, 3, 2),
+    ///     to_date: chrono::NaiveDate::from_ymd_opt(2025, 4, 1),
+    ///     ..Default::default()
+    /// };
+    /// let changes = TransactionChanges { shift_days: -1, ..Default::default() };
+    ///
+    /// let updated = Transactions::bulk_edit(&filter, &changes, 500, UserScope::All, pool).await?;
+    /// println!("Moved {updated} transactions");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Apply transaction bulk edit", skip(pool), err)]
+    pub fn bulk_edit<'a>(
+        filter: &'a TransactionFilter,
+        changes: &'a TransactionChanges,
+        chunk_size: usize,
+        scope: database::UserScope,
+        pool: &'a sqlx::Pool<sqlx::Sqlite>,
+    ) -> impl Future<Output = DatabaseResult<u64>> + Send + 'a {
+        async move {
+            filter.validate()?;
+            changes.validate()?;
+
+            let ids = {
+                let mut conn = pool.acquire().await?;
+                Self::fetch_matching_ids(filter, scope, &mut conn).await?
+            };
+
+            let mut updated = 0;
+            // Transfer transactions already moved with the other of the pair
+            let mut shifted = std::collections::HashSet::new();
+            let unshifted = TransactionChanges {
+                shift_days: 0,
+                ..changes.clone()
+            };
+
+            for chunk in ids.chunks(chunk_size.max(1)) {
+                let mut tx = pool.begin().await?;
+
+                for &id in chunk {
+                    // Deleted since the edit started
+                    let Some(transaction) = Self::fetch_by_id(id, &mut tx).await? else {
+                        continue;
+                    };
+
+                    let changes = if shifted.remove(&id) {
+                        &unshifted
+                    } else {
+                        changes
+                    };
+                    changes.apply(transaction)?.update(scope, &mut *tx).await?;
+                    if !changes.add_tags.is_empty() {
+                        let tags = database::Tags::find_for_transaction(id, &mut *tx).await?;
+                        database::Tags::set_for_transaction(
+                            id,
+                            &changes.apply_tags(tags)?,
+                            &mut *tx,
+                        )
+                        .await?;
+                    }
+                    if let Some(transfer) =
+                        database::Transfers::find_by_transaction(id, &mut *tx).await?
+                    {
+                        shifted.insert(transfer.counterpart_of(id));
+                    }
+                    updated += 1;
+                }
+
+                tx.commit().await?;
+
+                tracing::debug!(
+                    "Bulk edit updated {} of {} transactions",
+                    updated,
+                    ids.len()
+                );
+            }
+
+            tracing::info!("Bulk edit updated {} transactions", updated);
+
+            Ok(updated)
+        }
+    }
+
+    /// Selects the IDs of the transactions in `scope` matching a filter,
+    /// oldest first.
+    pub(super) async fn fetch_matching_ids(
+        filter: &TransactionFilter,
+        scope: database::UserScope,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Vec<domain::RowID>> {
+        let user_id = scope.user_id();
+        let payee_like = filter.payee_like();
+        let ids = sqlx::query_scalar!(
+            r#"
+                SELECT id AS "id!: domain::RowID"
+                FROM transactions
+                WHERE (?1 IS NULL OR payee = ?1 COLLATE NOCASE)
+                    AND (?2 IS NULL OR transaction_date >= ?2)
+                    AND (?3 IS NULL OR transaction_date <= ?3)
+                    AND (?4 IS NULL OR category_id = ?4)
+                    AND (?5 IS NULL OR account_id = ?5)
+                    AND (?6 IS NULL OR user_id = ?6)
+                    AND (?7 IS NULL OR payee LIKE ?7 ESCAPE '\')
+                ORDER BY transaction_date, created_on
+            "#,
+            filter.payee,
+            filter.from_date,
+            filter.to_date,
+            filter.category_id,
+            filter.account_id,
+            user_id,
+            payee_like
+        )
+        .fetch_all(&mut *conn)
+        .observe("transactions.fetch_matching_ids")
+        .await?;
+
+        Ok(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::Result;
+
+    fn date(day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    async fn insert(
+        day: u32,
+        payee: &str,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<database::Transactions> {
+        Ok(database::Transactions::new(date(day), payee, -1_250)
+            .insert(pool)
+            .await?)
+    }
+
+    fn rename(from: &str, to: &str) -> (TransactionFilter, TransactionChanges) {
+        let filter = TransactionFilter {
+            payee: Some(from.to_string()),
+            ..Default::default()
+        };
+        let changes = TransactionChanges {
+            payee: Some(to.to_string()),
+            ..Default::default()
+        };
+
+        (filter, changes)
+    }
+
+    #[test]
+    fn apply_renames_and_shifts() {
+        let changes = TransactionChanges {
+            payee: Some("Corner Cafe".to_string()),
+            shift_days: -2,
+            ..Default::default()
+        };
+
+        let changed = changes
+            .apply(database::Transactions::new(
+                date(14),
+                "CORNER CAFE PTY",
+                -1_250,
+            ))
+            .unwrap();
+
+        assert_eq!(changed.payee, "Corner Cafe");
+        assert_eq!(changed.transaction_date, date(12));
+    }
+
+    #[test]
+    fn apply_tags_adds_to_the_tags_kept() {
+        let changes = TransactionChanges {
+            add_tags: vec!["#Coffee".to_string(), "work".to_string()],
+            ..Default::default()
+        };
+
+        let tags = changes
+            .apply_tags(vec!["work".to_string(), "travel".to_string()])
+            .unwrap();
+
+        assert_eq!(tags, ["coffee", "travel", "work"]);
+    }
+
+    #[test]
+    fn payee_pattern_only_wildcards_stars() {
+        let filter = TransactionFilter {
+            payee_pattern: Some("AMZN*50%_off\\".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(filter.payee_like().as_deref(), Some("AMZN%50\\%\\_off\\\\"));
+    }
+
+    #[sqlx::test]
+    async fn preview_counts_and_samples_without_writing(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let first = insert(1, "CORNER CAFE PTY", &pool).await?;
+        database::Tags::set_for_transaction(first.id, &["work"], &pool).await?;
+        for day in 2..=3 {
+            insert(day, "CORNER CAFE PTY", &pool).await?;
+        }
+        insert(4, "Bakery", &pool).await?;
+        let (filter, mut changes) = rename("corner cafe pty", "Corner Cafe");
+        changes.add_tags = vec!["#Coffee".to_string()];
+
+        let preview = database::Transactions::bulk_edit_preview(
+            &filter,
+            &changes,
+            2,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        let unchanged = database::Transactions::bulk_edit_preview(
+            &filter,
+            &changes,
+            0,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(preview.count, 3);
+        assert_eq!(preview.sample.len(), 2);
+        assert!(preview.sample.iter().all(|t| t.payee == "Corner Cafe"));
+        assert_eq!(
+            preview.sample_tags,
+            [vec!["coffee", "work"], vec!["coffee"]]
+        );
+        assert_eq!(unchanged.count, 3);
+        assert_eq!(
+            database::Tags::find_for_transaction(first.id, &pool).await?,
+            ["work"]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn bulk_edit_applies_in_chunks(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        for day in 1..=5 {
+            insert(day, "CORNER CAFE PTY", &pool).await?;
+        }
+        let bakery = insert(6, "Bakery", &pool).await?;
+        let (filter, mut changes) = rename("CORNER CAFE PTY", "Corner Cafe");
+        changes.add_tags = vec!["coffee".to_string()];
+
+        let updated = database::Transactions::bulk_edit(
+            &filter,
+            &changes,
+            2,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        // Previewing a rename without tags shows the tags that were written
+        let (filter, unchanged) = rename("Corner Cafe", "Corner Cafe");
+        let renamed = database::Transactions::bulk_edit_preview(
+            &filter,
+            &unchanged,
+            10,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        assert_eq!(updated, 5);
+        assert_eq!(renamed.count, 5);
+        assert!(renamed.sample_tags.iter().all(|tags| tags == &["coffee"]));
+        assert!(
+            database::Tags::find_for_transaction(bakery.id, &pool)
+                .await?
+                .is_empty()
+        );
+        assert_eq!(
+            database::Transactions::find_by_id(bakery.id, database::UserScope::All, &pool).await?,
+            Some(bakery)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn bulk_edit_rejects_empty_filter_and_changes(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let (filter, changes) = rename("Corner Cafe", "Cafe");
+
+        let no_filter = database::Transactions::bulk_edit(
+            &TransactionFilter::default(),
+            &changes,
+            10,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+        let no_changes = database::Transactions::bulk_edit(
+            &filter,
+            &TransactionChanges::default(),
+            10,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+
+        assert!(matches!(no_filter, Err(DatabaseError::Validation(_))));
+        assert!(matches!(no_changes, Err(DatabaseError::Validation(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn bulk_edit_keeps_completed_chunks_when_one_fails(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        insert(20, "Corner Cafe", &pool).await?;
+        database::PeriodClosings::close(date(20), None, &pool).await?;
+        for day in [1, 2] {
+            let mut draft = database::Transactions::new(date(day), "Corner Cafe", -1_250);
+            draft.is_draft = true;
+            draft.insert(&pool).await?;
+        }
+
+        // The drafts can move, the posted transaction on the 20th is locked
+        let filter = TransactionFilter {
+            payee: Some("Corner Cafe".to_string()),
+            ..Default::default()
+        };
+        let changes = TransactionChanges {
+            shift_days: 1,
+            ..Default::default()
+        };
+        let result = database::Transactions::bulk_edit(
+            &filter,
+            &changes,
+            1,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+
+        let moved = TransactionFilter {
+            from_date: Some(date(2)),
+            to_date: Some(date(3)),
+            ..filter
+        };
+        let preview = database::Transactions::bulk_edit_preview(
+            &moved,
+            &changes,
+            0,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        assert!(matches!(result, Err(DatabaseError::PeriodClosed(_))));
+        assert_eq!(preview.count, 2);
+
+        Ok(())
+    }
+
+    async fn transfer(
+        pool: &sqlx::SqlitePool,
+    ) -> Result<(database::Transactions, database::Transactions)> {
+        let everyday = database::Accounts::new("Everyday").insert(pool).await?;
+        let savings = database::Accounts::new("Savings").insert(pool).await?;
+        let (_, from, to) = database::Transfers::create(
+            date(14),
+            50_000,
+            everyday.id,
+            savings.id,
+            None,
+            None,
+            pool,
+        )
+        .await?;
+
+        Ok((from, to))
+    }
+
+    #[sqlx::test]
+    async fn bulk_edit_of_one_transfer_transaction_moves_the_other(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let (from, to) = transfer(&pool).await?;
+        let filter = TransactionFilter {
+            payee: Some(from.payee.clone()),
+            ..Default::default()
+        };
+        let changes = TransactionChanges {
+            payee: Some("Savings top up".to_string()),
+            shift_days: 2,
+            ..Default::default()
+        };
+
+        let updated = database::Transactions::bulk_edit(
+            &filter,
+            &changes,
+            10,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        let from = database::Transactions::find_by_id(from.id, database::UserScope::All, &pool)
+            .await?
+            .unwrap();
+        let to = database::Transactions::find_by_id(to.id, database::UserScope::All, &pool)
+            .await?
+            .unwrap();
+        assert_eq!(updated, 1);
+        assert_eq!(
+            (from.payee.as_str(), from.transaction_date),
+            ("Savings top up", date(16))
+        );
+        assert_eq!(
+            (to.payee.as_str(), to.transaction_date),
+            ("Transfer from Everyday", date(16))
+        );
+        assert_eq!((from.amount, to.amount), (-50_000, 50_000));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn bulk_edit_of_both_transfer_transactions_moves_them_once(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let (from, to) = transfer(&pool).await?;
+        let filter = TransactionFilter {
+            from_date: Some(date(14)),
+            to_date: Some(date(14)),
+            ..Default::default()
+        };
+        let changes = TransactionChanges {
+            shift_days: 2,
+            ..Default::default()
+        };
+
+        let updated = database::Transactions::bulk_edit(
+            &filter,
+            &changes,
+            1,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        let from = database::Transactions::find_by_id(from.id, database::UserScope::All, &pool)
+            .await?
+            .unwrap();
+        let to = database::Transactions::find_by_id(to.id, database::UserScope::All, &pool)
+            .await?
+            .unwrap();
+        assert_eq!(updated, 2);
+        assert_eq!(
+            (from.transaction_date, to.transaction_date),
+            (date(16), date(16))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Transactions {
+    /// Ticks off a batch of transactions as cleared against a statement, or
+    /// sets them back to uncleared.
+    ///
+    /// The batch is marked in a single database transaction: either every
+    /// transaction is marked or, if any of them fails, none are. Reconciled
+    /// transactions are left to their reconciliation, see
+    /// [`Reconciliations`](database::Reconciliations).
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the transactions to mark
+    /// * `cleared` - Whether they are marked cleared, or uncleared
+    /// * `scope` - The user whose transactions are marked
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the marked transactions in the order of `ids`, or a
+    /// `DatabaseError` if the batch could not be marked.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A transaction does not exist or is outside `scope` (`DatabaseError::NotFound`)
+    /// - A transaction is a draft or already reconciled (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{ClearedStatus, Transactions, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, transaction_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let marked = Transactions::mark_cleared(&[transaction_id], true, UserScope::All, pool).await?;
+    /// assert_eq!(marked[0].cleared_status, ClearedStatus::Cleared);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Mark transactions cleared",
+        skip(ids, conn),
+        fields(count = ids.len()),
+        err
+    )]
+    pub fn mark_cleared<'a, 'c, A>(
+        ids: &'a [domain::RowID],
+        cleared: bool,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+            let status = if cleared {
+                database::ClearedStatus::Cleared
+            } else {
+                database::ClearedStatus::Uncleared
+            };
+            let updated_on = chrono::Utc::now();
+
+            let mut marked = Vec::with_capacity(ids.len());
+
+            for &id in ids {
+                match Self::mark_one(id, status, scope, updated_on, &mut tx).await {
+                    Ok(transaction) => marked.push(transaction),
+                    Err(error) => {
+                        // Roll back now rather than on drop, so the write lock
+                        // is released before the caller sees the error
+                        tx.rollback().await?;
+                        return Err(error);
+                    }
+                }
+            }
+
+            tx.commit().await?;
+
+            tracing::info!("Marked {} transactions {:?}", marked.len(), status);
+
+            Ok(marked)
+        }
+    }
+
+    /// Marks a single transaction within the batch's database transaction.
+    async fn mark_one(
+        id: domain::RowID,
+        status: database::ClearedStatus,
+        scope: database::UserScope,
+        updated_on: chrono::DateTime<chrono::Utc>,
+        tx: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Self> {
+        let transaction = Self::fetch_by_id(id, &mut *tx)
+            .await?
+            .filter(|transaction| scope.includes(transaction.user_id))
+            .ok_or_else(|| {
+                database::DatabaseError::NotFound(format!("Transaction with id {} not found", id))
+            })?;
+
+        if transaction.is_draft {
+            return Err(database::DatabaseError::Validation(format!(
+                "Transaction {} is a draft, post it before clearing it",
+                id
+            )));
+        }
+        if transaction.cleared_status == database::ClearedStatus::Reconciled {
+            return Err(database::DatabaseError::Validation(format!(
+                "Transaction {} is already reconciled",
+                id
+            )));
+        }
+
+        sqlx::query!(
+            r#"
+                UPDATE transactions
+                SET cleared_status = ?, updated_on = ?, version = version + 1
+                WHERE id = ?
+            "#,
+            status,
+            updated_on,
+            id
+        )
+        .execute(&mut *tx)
+        .observe("transactions.mark_cleared")
+        .await?;
+
+        Self::fetch_by_id(id, &mut *tx).await?.ok_or_else(|| {
+            database::DatabaseError::NotFound(format!(
+                "Transaction with id {} not found after marking it",
+                id
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn mark_cleared_marks_every_transaction(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let first = database::Transactions::mock().insert(&pool).await?;
+        let second = database::Transactions::mock().insert(&pool).await?;
+
+        let marked = database::Transactions::mark_cleared(
+            &[first.id, second.id],
+            true,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        assert!(
+            marked
+                .iter()
+                .all(|transaction| transaction.cleared_status == database::ClearedStatus::Cleared)
+        );
+
+        database::Transactions::mark_cleared(&[second.id], false, database::UserScope::All, &pool)
+            .await?;
+        let second =
+            database::Transactions::find_by_id(second.id, database::UserScope::All, &pool).await?;
+        assert_eq!(
+            second.map(|t| t.cleared_status),
+            Some(database::ClearedStatus::Uncleared)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn mark_cleared_rolls_back_when_any_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let posted = database::Transactions::mock().insert(&pool).await?;
+        let mut draft = database::Transactions::mock();
+        draft.is_draft = true;
+        let draft = draft.insert(&pool).await?;
+
+        let result = database::Transactions::mark_cleared(
+            &[posted.id, draft.id],
+            true,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        let posted =
+            database::Transactions::find_by_id(posted.id, database::UserScope::All, &pool).await?;
+        assert_eq!(
+            posted.map(|t| t.cleared_status),
+            Some(database::ClearedStatus::Uncleared)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Transactions {
+    /// Deletes a transaction from the database by its ID.
+    ///
+    /// If the transaction is one of a [`Transfers`](database::Transfers)
+    /// pair, the other transaction of the pair is deleted with it, all or
+    /// nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the transaction to delete
+    /// * `scope` - The user whose transactions are deleted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the deletion was successful, or a `DatabaseError` if it fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transaction with the given ID does not exist or is outside `scope`
+    ///   (`DatabaseError::NotFound`)
+    /// - The transaction is reconciled (`DatabaseError::Validation`)
+    /// - The transaction date is in a closed period (`DatabaseError::PeriodClosed`)
+    /// - The other transaction of its transfer cannot be deleted for any of
+    ///   the reasons above
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Transactions, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, transaction_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// Transactions::delete_by_id(transaction_id, UserScope::All, pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Delete transaction from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            // Found first, as deleting either transaction deletes the transfer
+            let transfer = database::Transfers::find_by_transaction(id, &mut *tx).await?;
+            delete_row(id, scope, &mut tx).await?;
+            if let Some(transfer) = transfer {
+                delete_row(transfer.counterpart_of(id), scope, &mut tx).await?;
+            }
+
+            tx.commit().await?;
+
+            Ok(())
+        }
+    }
+}
+
+/// Deletes a single transaction, for [`delete_by_id`](database::Transactions::delete_by_id).
+async fn delete_row(
+    id: domain::RowID,
+    scope: database::UserScope,
+    conn: &mut sqlx::SqliteConnection,
+) -> DatabaseResult<()> {
+    let existing = database::Transactions::fetch_by_id(id, &mut *conn)
+        .await?
+        .filter(|existing| scope.includes(existing.user_id))
+        .ok_or_else(|| {
+            database::DatabaseError::NotFound(format!("Transaction with id {} not found", id))
+        })?;
+    if existing.cleared_status == database::ClearedStatus::Reconciled {
+        return Err(database::DatabaseError::Validation(format!(
+            "Transaction {id} is reconciled, undo its reconciliation before deleting it"
+        )));
+    }
+    if !existing.is_draft {
+        database::PeriodClosings::ensure_open(existing.transaction_date, &mut *conn).await?;
+    }
+
+    sqlx::query!(
+        r#"
+            DELETE FROM transactions
+            WHERE id = ?
+        "#,
+        id
+    )
+    .execute(&mut *conn)
+    .observe("transactions.delete_by_id")
+    .await?;
+
+    tracing::info!("Deleted transaction {} from database", id);
+
+    database::events::publish(database::DataChanges::TransactionDeleted(id));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::{Result, count_transactions};
+
+    #[sqlx::test]
+    async fn delete_by_id_removes_transaction(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let kept = database::Transactions::mock().insert(&pool).await?;
+        let removed = database::Transactions::mock().insert(&pool).await?;
+
+        database::Transactions::delete_by_id(removed.id, database::UserScope::All, &pool).await?;
+
+        assert_eq!(count_transactions(&pool).await?, 1);
+        assert!(
+            database::Transactions::find_by_id(kept.id, database::UserScope::All, &pool)
+                .await?
+                .is_some()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn delete_by_id_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let result = database::Transactions::delete_by_id(
+            domain::RowID::new(),
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
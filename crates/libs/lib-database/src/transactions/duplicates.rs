@@ -0,0 +1,574 @@
+use std::collections::BTreeSet;
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Lowest payee similarity, from 0 to 1, for two transactions of the same
+/// amount on close dates to be reported as probable duplicates.
+pub const DUPLICATE_PAYEE_SIMILARITY: f64 = 0.5;
+
+/// Two transactions that are probably the same money movement recorded
+/// twice, such as a transaction typed in by hand and then imported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicatePair {
+    /// The transaction recorded first.
+    pub original: database::Transactions,
+    /// The transaction recorded later, probably a duplicate of the original.
+    pub duplicate: database::Transactions,
+    /// How alike the two payees are, from 0 for nothing in common to 1 for
+    /// the same payee.
+    pub similarity: f64,
+    /// Number of days between the two transaction dates.
+    pub days_apart: i64,
+}
+
+/// Returns the trigrams of a payee, as `pg_trgm` makes them: each word is
+/// lowercased, padded with two spaces in front and one behind, and cut into
+/// every run of three characters. Punctuation separates words.
+fn trigrams(payee: &str) -> BTreeSet<String> {
+    payee
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .flat_map(|word| {
+            let padded: Vec<char> = format!("  {} ", word.to_lowercase()).chars().collect();
+            padded
+                .windows(3)
+                .map(|window| window.iter().collect())
+                .collect::<Vec<String>>()
+        })
+        .collect()
+}
+
+/// Returns how alike two payees are, as the share of their trigrams they
+/// have in common, from 0 to 1. Case and punctuation are ignored, so
+/// "CORNER CAFE" and "Corner Cafe." are the same payee.
+pub(crate) fn payee_similarity(first: &str, second: &str) -> f64 {
+    let (first, second) = (trigrams(first), trigrams(second));
+    let union = first.union(&second).count();
+    if union == 0 {
+        return 0.0;
+    }
+
+    first.intersection(&second).count() as f64 / union as f64
+}
+
+/// Orders the ids of a pair of transactions as they are stored in a
+/// dismissal, the lower id first.
+fn ordered(first: domain::RowID, second: domain::RowID) -> (domain::RowID, domain::RowID) {
+    if first.to_string() < second.to_string() {
+        (first, second)
+    } else {
+        (second, first)
+    }
+}
+
+impl database::Transactions {
+    /// Finds probable duplicate transactions: pairs in the same account with
+    /// the same amount, dated no more than `window_days` apart, whose payees are at least
+    /// [`DUPLICATE_PAYEE_SIMILARITY`] alike. Pairs dismissed with
+    /// [`dismiss_duplicate`](Self::dismiss_duplicate) are left out.
+    ///
+    /// Drafts are included, so an imported draft can be matched with the
+    /// transaction already typed in.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_days` - The most days apart the two transactions may be dated
+    /// * `scope` - The user whose transactions are searched
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the pairs, the most alike payees first, then the most recent.
+    #[tracing::instrument(name = "Find duplicate transactions", skip(conn), err)]
+    pub fn find_duplicates<'c, A>(
+        window_days: u32,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<DuplicatePair>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let user_id = scope.user_id();
+            let candidates = sqlx::query!(
+                r#"
+                    SELECT
+                        first.id                        AS "first_id!: domain::RowID",
+                        first.transaction_date          AS "first_transaction_date!: chrono::NaiveDate",
+                        first.payee                     AS "first_payee!: String",
+                        first.description               AS "first_description?: String",
+                        first.amount                    AS "first_amount!: i64",
+                        first.category_id               AS "first_category_id?: domain::RowID",
+                        first.account_id                AS "first_account_id?: domain::RowID",
+                        first.is_draft                  AS "first_is_draft!: bool",
+                        first.latitude                  AS "first_latitude?: f64",
+                        first.longitude                 AS "first_longitude?: f64",
+                        first.place_name                AS "first_place_name?: String",
+                        first.return_by                 AS "first_return_by?: chrono::NaiveDate",
+                        first.warranty_expires          AS "first_warranty_expires?: chrono::NaiveDate",
+                        first.cleared_status            AS "first_cleared_status!: database::ClearedStatus",
+                        first.user_id                   AS "first_user_id?: domain::RowID",
+                        first.created_on                AS "first_created_on!: chrono::DateTime<chrono::Utc>",
+                        first.updated_on                AS "first_updated_on!: chrono::DateTime<chrono::Utc>",
+                        first.version                   AS "first_version!: i64",
+                        second.id                       AS "second_id!: domain::RowID",
+                        second.transaction_date         AS "second_transaction_date!: chrono::NaiveDate",
+                        second.payee                    AS "second_payee!: String",
+                        second.description              AS "second_description?: String",
+                        second.amount                   AS "second_amount!: i64",
+                        second.category_id              AS "second_category_id?: domain::RowID",
+                        second.account_id               AS "second_account_id?: domain::RowID",
+                        second.is_draft                 AS "second_is_draft!: bool",
+                        second.latitude                 AS "second_latitude?: f64",
+                        second.longitude                AS "second_longitude?: f64",
+                        second.place_name               AS "second_place_name?: String",
+                        second.return_by                AS "second_return_by?: chrono::NaiveDate",
+                        second.warranty_expires         AS "second_warranty_expires?: chrono::NaiveDate",
+                        second.cleared_status           AS "second_cleared_status!: database::ClearedStatus",
+                        second.user_id                  AS "second_user_id?: domain::RowID",
+                        second.created_on               AS "second_created_on!: chrono::DateTime<chrono::Utc>",
+                        second.updated_on               AS "second_updated_on!: chrono::DateTime<chrono::Utc>",
+                        second.version                  AS "second_version!: i64"
+                    FROM transactions AS first
+                    JOIN transactions AS second
+                        ON second.account_id IS first.account_id
+                        AND second.amount = first.amount
+                        AND second.transaction_date BETWEEN first.transaction_date
+                            AND date(first.transaction_date, '+' || ?1 || ' days')
+                        AND (second.transaction_date > first.transaction_date OR second.id > first.id)
+                    WHERE (?2 IS NULL OR (first.user_id = ?2 AND second.user_id = ?2))
+                        AND NOT EXISTS (
+                            SELECT 1 FROM duplicate_dismissals
+                            WHERE duplicate_dismissals.first_transaction_id = min(first.id, second.id)
+                                AND duplicate_dismissals.second_transaction_id = max(first.id, second.id)
+                        )
+                "#,
+                window_days,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("transactions.find_duplicates")
+            .await?;
+
+            let mut pairs = Vec::new();
+            for candidate in candidates {
+                let similarity = payee_similarity(&candidate.first_payee, &candidate.second_payee);
+                if similarity < DUPLICATE_PAYEE_SIMILARITY {
+                    continue;
+                }
+
+                let first = Self {
+                    id: candidate.first_id,
+                    transaction_date: candidate.first_transaction_date,
+                    payee: candidate.first_payee,
+                    description: candidate.first_description,
+                    amount: candidate.first_amount,
+                    category_id: candidate.first_category_id,
+                    account_id: candidate.first_account_id,
+                    is_draft: candidate.first_is_draft,
+                    latitude: candidate.first_latitude,
+                    longitude: candidate.first_longitude,
+                    place_name: candidate.first_place_name,
+                    return_by: candidate.first_return_by,
+                    warranty_expires: candidate.first_warranty_expires,
+                    cleared_status: candidate.first_cleared_status,
+                    user_id: candidate.first_user_id,
+                    created_on: candidate.first_created_on,
+                    updated_on: candidate.first_updated_on,
+                    version: candidate.first_version,
+                };
+                let second = Self {
+                    id: candidate.second_id,
+                    transaction_date: candidate.second_transaction_date,
+                    payee: candidate.second_payee,
+                    description: candidate.second_description,
+                    amount: candidate.second_amount,
+                    category_id: candidate.second_category_id,
+                    account_id: candidate.second_account_id,
+                    is_draft: candidate.second_is_draft,
+                    latitude: candidate.second_latitude,
+                    longitude: candidate.second_longitude,
+                    place_name: candidate.second_place_name,
+                    return_by: candidate.second_return_by,
+                    warranty_expires: candidate.second_warranty_expires,
+                    cleared_status: candidate.second_cleared_status,
+                    user_id: candidate.second_user_id,
+                    created_on: candidate.second_created_on,
+                    updated_on: candidate.second_updated_on,
+                    version: candidate.second_version,
+                };
+                let recorded =
+                    |transaction: &Self| (transaction.created_on, transaction.id.to_string());
+                let (original, duplicate) = if recorded(&first) <= recorded(&second) {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+                pairs.push(DuplicatePair {
+                    days_apart: (duplicate.transaction_date - original.transaction_date)
+                        .num_days()
+                        .abs(),
+                    original,
+                    duplicate,
+                    similarity,
+                });
+            }
+
+            pairs.sort_by(|a, b| {
+                b.similarity.total_cmp(&a.similarity).then(
+                    b.duplicate
+                        .transaction_date
+                        .cmp(&a.duplicate.transaction_date),
+                )
+            });
+
+            Ok(pairs)
+        }
+    }
+
+    /// Marks two transactions as not duplicates of each other, so
+    /// [`find_duplicates`](Self::find_duplicates) no longer reports them.
+    /// Dismissing a pair again does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - One of the transactions
+    /// * `other_id` - The other transaction
+    /// * `scope` - The user whose transactions are dismissed
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The two ids are the same (`DatabaseError::Validation`)
+    /// - Either transaction does not exist or is outside `scope` (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Dismiss duplicate transactions",
+        skip(conn),
+        fields(id = %id, other_id = %other_id),
+        err
+    )]
+    pub fn dismiss_duplicate<'c, A>(
+        id: domain::RowID,
+        other_id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            if id == other_id {
+                return Err(DatabaseError::Validation(format!(
+                    "Transaction {id} cannot be dismissed as a duplicate of itself"
+                )));
+            }
+
+            let mut conn = conn.acquire().await?;
+            for id in [id, other_id] {
+                Self::fetch_by_id(id, &mut conn)
+                    .await?
+                    .filter(|transaction| scope.includes(transaction.user_id))
+                    .ok_or_else(|| {
+                        DatabaseError::NotFound(format!("Transaction with id {id} not found"))
+                    })?;
+            }
+
+            let (first_id, second_id) = ordered(id, other_id);
+            let dismissed_on = chrono::Utc::now();
+            sqlx::query!(
+                r#"
+                    INSERT OR IGNORE INTO duplicate_dismissals (first_transaction_id, second_transaction_id, dismissed_on)
+                    VALUES (?, ?, ?)
+                "#,
+                first_id,
+                second_id,
+                dismissed_on
+            )
+            .execute(&mut *conn)
+            .observe("transactions.dismiss_duplicate")
+            .await?;
+
+            Ok(())
+        }
+    }
+
+    /// Merges a duplicate transaction into the one kept, all or nothing.
+    ///
+    /// Fields left empty on the kept transaction are filled in from the
+    /// duplicate, such as a category or a location, and it gains the
+    /// duplicate's tags, attachments, receipt emails and reimbursements. The
+    /// duplicate is then deleted, along with its splits. The kept
+    /// transaction's amount, date and payee are not changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `keep_id` - The transaction to keep
+    /// * `duplicate_id` - The duplicate to merge into it and delete
+    /// * `scope` - The user whose transactions are merged
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the kept transaction as merged.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The two ids are the same (`DatabaseError::Validation`)
+    /// - Either transaction does not exist or is outside `scope` (`DatabaseError::NotFound`)
+    /// - The duplicate is part of a transfer (`DatabaseError::Validation`)
+    /// - The kept transaction cannot be updated, or the duplicate cannot be
+    ///   deleted, such as when it is reconciled
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Merge duplicate transactions",
+        skip(conn),
+        fields(keep_id = %keep_id, duplicate_id = %duplicate_id),
+        err
+    )]
+    pub fn merge_duplicate<'c, A>(
+        keep_id: domain::RowID,
+        duplicate_id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            if keep_id == duplicate_id {
+                return Err(DatabaseError::Validation(format!(
+                    "Transaction {keep_id} cannot be merged into itself"
+                )));
+            }
+
+            let mut tx = conn.begin().await?;
+
+            let not_found =
+                |id| DatabaseError::NotFound(format!("Transaction with id {id} not found"));
+            let kept = Self::find_by_id(keep_id, scope, &mut *tx)
+                .await?
+                .ok_or_else(|| not_found(keep_id))?;
+            let duplicate = Self::find_by_id(duplicate_id, scope, &mut *tx)
+                .await?
+                .ok_or_else(|| not_found(duplicate_id))?;
+            if database::Transfers::find_by_transaction(duplicate_id, &mut *tx)
+                .await?
+                .is_some()
+            {
+                return Err(DatabaseError::Validation(format!(
+                    "Transaction {duplicate_id} is part of a transfer, delete the transfer instead"
+                )));
+            }
+
+            let mut merged = kept.clone();
+            merged.description = merged.description.or(duplicate.description);
+            merged.category_id = merged.category_id.or(duplicate.category_id);
+            merged.account_id = merged.account_id.or(duplicate.account_id);
+            if merged.latitude.is_none() && merged.longitude.is_none() {
+                (merged.latitude, merged.longitude) = (duplicate.latitude, duplicate.longitude);
+            }
+            merged.place_name = merged.place_name.or(duplicate.place_name);
+            merged.return_by = merged.return_by.or(duplicate.return_by);
+            merged.warranty_expires = merged.warranty_expires.or(duplicate.warranty_expires);
+            if merged != kept {
+                merged.update(scope, &mut *tx).await?;
+            }
+
+            let mut tags = database::Tags::find_for_transaction(keep_id, &mut *tx).await?;
+            tags.extend(database::Tags::find_for_transaction(duplicate_id, &mut *tx).await?);
+            database::Tags::set_for_transaction(keep_id, &tags, &mut *tx).await?;
+
+            sqlx::query!(
+                r#"
+                    UPDATE attachments SET transaction_id = ?1 WHERE transaction_id = ?2
+                "#,
+                keep_id,
+                duplicate_id
+            )
+            .execute(&mut *tx)
+            .observe("transactions.merge_duplicate")
+            .await?;
+
+            sqlx::query!(
+                r#"
+                    UPDATE receipt_emails SET transaction_id = ?1 WHERE transaction_id = ?2
+                "#,
+                keep_id,
+                duplicate_id
+            )
+            .execute(&mut *tx)
+            .observe("transactions.merge_duplicate")
+            .await?;
+
+            // A transaction has one reimbursable, so the duplicate's is only
+            // moved when the kept transaction has none
+            sqlx::query!(
+                r#"
+                    UPDATE reimbursables SET transaction_id = ?1
+                    WHERE transaction_id = ?2
+                        AND NOT EXISTS (SELECT 1 FROM reimbursables WHERE transaction_id = ?1)
+                "#,
+                keep_id,
+                duplicate_id
+            )
+            .execute(&mut *tx)
+            .observe("transactions.merge_duplicate")
+            .await?;
+
+            sqlx::query!(
+                r#"
+                    UPDATE reimbursables SET reimbursed_by = ?1 WHERE reimbursed_by = ?2
+                "#,
+                keep_id,
+                duplicate_id
+            )
+            .execute(&mut *tx)
+            .observe("transactions.merge_duplicate")
+            .await?;
+
+            Self::delete_by_id(duplicate_id, scope, &mut *tx).await?;
+
+            let merged = Self::fetch_by_id(keep_id, &mut tx).await?.ok_or_else(|| {
+                DatabaseError::NotFound(format!(
+                    "Transaction with id {keep_id} not found after merge"
+                ))
+            })?;
+
+            tx.commit().await?;
+
+            tracing::info!("Duplicate transaction merged in the database.");
+
+            Ok(merged)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::Result;
+
+    fn date(day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    #[test]
+    fn payee_similarity_ignores_case_and_punctuation() {
+        assert_eq!(payee_similarity("CORNER CAFE", "Corner Cafe."), 1.0);
+        assert!(
+            payee_similarity("Corner Cafe", "CORNER CAFE PTY LTD") >= DUPLICATE_PAYEE_SIMILARITY
+        );
+        assert!(payee_similarity("Corner Cafe", "Fresh Market") < DUPLICATE_PAYEE_SIMILARITY);
+        assert_eq!(payee_similarity("", "..."), 0.0);
+    }
+
+    #[sqlx::test]
+    async fn find_duplicates_matches_amount_dates_and_payee(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let typed = database::Transactions::new(date(14), "Corner Cafe", -1_250)
+            .insert(&pool)
+            .await?;
+        let imported = database::Transactions::new(date(15), "CORNER CAFE PTY LTD", -1_250)
+            .insert(&pool)
+            .await?;
+        // Too far apart, a different amount, a different payee, and a
+        // different account
+        database::Transactions::new(date(25), "Corner Cafe", -1_250)
+            .insert(&pool)
+            .await?;
+        database::Transactions::new(date(14), "Corner Cafe", -1_450)
+            .insert(&pool)
+            .await?;
+        database::Transactions::new(date(14), "Fresh Market", -1_250)
+            .insert(&pool)
+            .await?;
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let mut elsewhere = database::Transactions::new(date(14), "Corner Cafe", -1_250);
+        elsewhere.account_id = Some(account.id);
+        elsewhere.insert(&pool).await?;
+
+        let pairs =
+            database::Transactions::find_duplicates(3, database::UserScope::All, &pool).await?;
+
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].original.id, typed.id);
+        assert_eq!(pairs[0].duplicate.id, imported.id);
+        assert_eq!(pairs[0].days_apart, 1);
+
+        database::Transactions::dismiss_duplicate(
+            imported.id,
+            typed.id,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        database::Transactions::dismiss_duplicate(
+            typed.id,
+            imported.id,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert!(
+            database::Transactions::find_duplicates(3, database::UserScope::All, &pool)
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn merge_duplicate_fills_in_the_kept_transaction(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let kept = database::Transactions::new(date(14), "Corner Cafe", -1_250)
+            .insert(&pool)
+            .await?;
+        let mut duplicate = database::Transactions::new(date(15), "CORNER CAFE PTY LTD", -1_250);
+        duplicate.place_name = Some("West End".to_string());
+        let duplicate = duplicate.insert(&pool).await?;
+        database::Tags::set_for_transaction(kept.id, &["coffee"], &pool).await?;
+        database::Tags::set_for_transaction(duplicate.id, &["work"], &pool).await?;
+
+        let merged = database::Transactions::merge_duplicate(
+            kept.id,
+            duplicate.id,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(merged.payee, "Corner Cafe");
+        assert_eq!(merged.place_name.as_deref(), Some("West End"));
+        assert_eq!(
+            database::Tags::find_for_transaction(kept.id, &pool).await?,
+            ["coffee", "work"]
+        );
+        assert!(
+            database::Transactions::find_by_id(duplicate.id, database::UserScope::All, &pool)
+                .await?
+                .is_none()
+        );
+
+        let itself = database::Transactions::merge_duplicate(
+            kept.id,
+            kept.id,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+        assert!(matches!(itself, Err(DatabaseError::Validation(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,326 @@
+//! # Return Windows and Warranties
+//!
+//! Finds purchases whose return window closes, or whose warranty expires,
+//! within a range of days. [`ExpiryRule`] gives each kind its own notice
+//! period, so a return a week away and a warranty a month away can both be
+//! reminded about on the same day.
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// Whether a reminder is for a return window or a warranty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, serde::Deserialize, serde::Serialize)]
+#[sqlx(rename_all = "lowercase")]
+pub enum ExpiryKinds {
+    /// The last day the purchase can be returned.
+    Return,
+    /// The day the purchase's warranty expires.
+    Warranty,
+}
+
+/// A purchase whose return window or warranty ends soon.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ExpiryReminder {
+    pub transaction: database::Transactions,
+    pub kind: ExpiryKinds,
+
+    /// The return by or warranty expiry date.
+    pub due_on: chrono::NaiveDate,
+
+    /// Days from the reminder date to `due_on`, zero on the day itself.
+    pub days_left: i64,
+}
+
+/// How many days ahead to remind about closing return windows and expiring
+/// warranties.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use lib_database::{ExpiryRule, UserScope};
+///
+/// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+/// let today = chrono::Utc::now().date_naive();
+///
+/// for reminder in ExpiryRule::default().reminders(today, UserScope::All, pool).await? {
+///     println!("{} {:?} in {} days", reminder.transaction.payee, reminder.kind, reminder.days_left);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct ExpiryRule {
+    pub return_notice_days: u32,
+    pub warranty_notice_days: u32,
+}
+
+/// A week's notice for returns and a month's for warranties.
+impl Default for ExpiryRule {
+    fn default() -> Self {
+        Self {
+            return_notice_days: 7,
+            warranty_notice_days: 30,
+        }
+    }
+}
+
+impl ExpiryRule {
+    /// Finds the return windows and warranties ending from `on` to the end of
+    /// their notice period, soonest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `on` - The day the reminders are for
+    /// * `scope` - The user whose purchases are reminded of
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(name = "Find expiry reminders", skip(conn), err)]
+    pub fn reminders<'c, A>(
+        self,
+        on: chrono::NaiveDate,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<ExpiryReminder>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            database::Transactions::fetch_expiring(
+                on,
+                on + chrono::Days::new(self.return_notice_days.into()),
+                on + chrono::Days::new(self.warranty_notice_days.into()),
+                scope,
+                &mut conn,
+            )
+            .await
+        }
+    }
+}
+
+impl database::Transactions {
+    /// Finds the purchases whose return window closes or warranty expires
+    /// between two dates, inclusive, soonest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The first due date to include, and the day `days_left` counts from
+    /// * `to` - The last due date to include
+    /// * `scope` - The user whose purchases are listed
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - `to` is before `from` (`DatabaseError::Validation`)
+    /// - Database connection fails
+    #[tracing::instrument(name = "Find expiring purchases", skip(conn), err)]
+    pub fn find_expiring<'c, A>(
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<ExpiryReminder>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            if to < from {
+                return Err(DatabaseError::Validation(format!(
+                    "Expiry report end {to} is before its start {from}"
+                )));
+            }
+
+            let mut conn = conn.acquire().await?;
+
+            Self::fetch_expiring(from, to, to, scope, &mut conn).await
+        }
+    }
+
+    /// Fetches return windows ending from `from` to `return_to` and
+    /// warranties ending from `from` to `warranty_to`, of purchases in `scope`.
+    async fn fetch_expiring(
+        from: chrono::NaiveDate,
+        return_to: chrono::NaiveDate,
+        warranty_to: chrono::NaiveDate,
+        scope: database::UserScope,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Vec<ExpiryReminder>> {
+        let user_id = scope.user_id();
+        let rows = sqlx::query!(
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    transaction_date    AS "transaction_date!: chrono::NaiveDate",
+                    payee               AS "payee!",
+                    description,
+                    amount              AS "amount!: i64",
+                    category_id         AS "category_id?: domain::RowID",
+                    account_id          AS "account_id?: domain::RowID",
+                    is_draft            AS "is_draft!: bool",
+                    latitude            AS "latitude?: f64",
+                    longitude           AS "longitude?: f64",
+                    place_name,
+                    return_by           AS "return_by?: chrono::NaiveDate",
+                    warranty_expires    AS "warranty_expires?: chrono::NaiveDate",
+                    cleared_status      AS "cleared_status!: database::ClearedStatus",
+                    user_id             AS "user_id?: domain::RowID",
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    version             AS "version!: i64",
+                    kind                AS "kind!: ExpiryKinds",
+                    due_on              AS "due_on!: chrono::NaiveDate"
+                FROM (
+                    SELECT *, 'return' AS kind, return_by AS due_on
+                    FROM transactions
+                    WHERE return_by BETWEEN ?1 AND ?2 AND (?4 IS NULL OR user_id = ?4)
+                    UNION ALL
+                    SELECT *, 'warranty' AS kind, warranty_expires AS due_on
+                    FROM transactions
+                    WHERE warranty_expires BETWEEN ?1 AND ?3 AND (?4 IS NULL OR user_id = ?4)
+                )
+                ORDER BY due_on, kind, payee
+            "#,
+            from,
+            return_to,
+            warranty_to,
+            user_id
+        )
+        .fetch_all(&mut *conn)
+        .observe("transactions.fetch_expiring")
+        .await?;
+
+        let reminders = rows
+            .into_iter()
+            .map(|row| ExpiryReminder {
+                kind: row.kind,
+                due_on: row.due_on,
+                days_left: (row.due_on - from).num_days(),
+                transaction: database::Transactions {
+                    id: row.id,
+                    transaction_date: row.transaction_date,
+                    payee: row.payee,
+                    description: row.description,
+                    amount: row.amount,
+                    category_id: row.category_id,
+                    account_id: row.account_id,
+                    is_draft: row.is_draft,
+                    latitude: row.latitude,
+                    longitude: row.longitude,
+                    place_name: row.place_name,
+                    return_by: row.return_by,
+                    warranty_expires: row.warranty_expires,
+                    cleared_status: row.cleared_status,
+                    user_id: row.user_id,
+                    created_on: row.created_on,
+                    updated_on: row.updated_on,
+                    version: row.version,
+                },
+            })
+            .collect();
+
+        Ok(reminders)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::Result;
+
+    fn day(day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    async fn purchase(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+        payee: &str,
+        return_by: Option<chrono::NaiveDate>,
+        warranty_expires: Option<chrono::NaiveDate>,
+    ) -> Result<database::Transactions> {
+        let mut transaction = database::Transactions::new(day(1), payee, -49_900);
+        transaction.return_by = return_by;
+        transaction.warranty_expires = warranty_expires;
+
+        Ok(transaction.insert(pool).await?)
+    }
+
+    #[sqlx::test]
+    async fn find_expiring_lists_both_kinds_soonest_first(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let kettle = purchase(&pool, "Kettle", Some(day(12)), Some(day(11))).await?;
+        purchase(&pool, "Toaster", Some(day(9)), None).await?;
+        purchase(&pool, "Blender", Some(day(20)), None).await?;
+
+        let reminders = database::Transactions::find_expiring(
+            day(10),
+            day(15),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        let found: Vec<_> = reminders
+            .iter()
+            .map(|reminder| {
+                (
+                    reminder.transaction.payee.as_str(),
+                    reminder.kind,
+                    reminder.days_left,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("Kettle", ExpiryKinds::Warranty, 1),
+                ("Kettle", ExpiryKinds::Return, 2)
+            ]
+        );
+        assert_eq!(reminders[0].transaction, kettle);
+
+        let backwards = database::Transactions::find_expiring(
+            day(15),
+            day(10),
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+        assert!(matches!(backwards, Err(DatabaseError::Validation(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn rule_uses_notice_period_per_kind(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        purchase(&pool, "Kettle", Some(day(20)), Some(day(20))).await?;
+        let rule = ExpiryRule {
+            return_notice_days: 5,
+            warranty_notice_days: 14,
+        };
+
+        let reminders = rule
+            .reminders(day(10), database::UserScope::All, &pool)
+            .await?;
+
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(reminders[0].kind, ExpiryKinds::Warranty);
+        assert_eq!(reminders[0].days_left, 10);
+
+        assert_eq!(
+            rule.reminders(day(15), database::UserScope::All, &pool)
+                .await?
+                .len(),
+            2
+        );
+        assert!(
+            rule.reminders(day(21), database::UserScope::All, &pool)
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,614 @@
+use crate::query_metrics::{Observe, StreamObservation};
+use crate::{self as database, DatabaseResult};
+use futures_util::stream::{BoxStream, StreamExt, TryStreamExt};
+use lib_domain as domain;
+
+impl database::Transactions {
+    /// Finds a transaction by its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the transaction to find
+    /// * `scope` - The user whose transactions are searched
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Transactions)` if the transaction exists in `scope`, or
+    /// `None` if not found. Returns a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Transactions, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let transaction_id = RowID::new();
+    ///
+    /// match Transactions::find_by_id(transaction_id, UserScope::All, pool).await? {
+    ///     Some(transaction) => println!("Found transaction with {}", transaction.payee),
+    ///     None => println!("Transaction not found"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find transaction by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let transaction = Self::fetch_by_id(id, &mut conn).await?;
+
+            Ok(transaction.filter(|transaction| scope.includes(transaction.user_id)))
+        }
+    }
+
+    /// Finds a transaction by its public ID, the ID it is known by outside
+    /// the ledger.
+    ///
+    /// # Arguments
+    ///
+    /// * `public_id` - The public ID of the transaction to find
+    /// * `scope` - The user whose transactions are searched
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Transactions)` if found, or `None` if not, including
+    /// when the public ID was not made by this ledger.
+    #[tracing::instrument(
+        name = "Find transaction by public ID",
+        skip(conn),
+        fields(public_id = %public_id),
+        err
+    )]
+    pub fn find_by_public_id<'c, A>(
+        public_id: domain::PublicID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            match database::public_ids::row_id(public_id, &mut conn).await? {
+                Some(id) => Self::find_by_id(id, scope, &mut *conn).await,
+                None => Ok(None),
+            }
+        }
+    }
+
+    /// Retrieves a page of transactions, newest entered first.
+    ///
+    /// Transactions are ordered by when they were entered rather than by
+    /// transaction date, so a page read with a cursor is not shifted by
+    /// transactions entered in the meantime, even back dated ones. See the
+    /// [`pagination`](crate::pagination) module.
+    ///
+    /// # Arguments
+    ///
+    /// * `after` - The cursor of the previous page, or `None` for the first page
+    /// * `page_size` - Maximum number of records to return, 50 if zero or negative
+    /// * `scope` - The user whose transactions are listed
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the page of transactions, with the total number of transactions
+    /// and the cursor of the next page, or a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Transactions, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let page = Transactions::find_all_with_pagination(None, 50, UserScope::All, pool).await?;
+    /// println!("Showing {} of {} transactions", page.items.len(), page.total_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find transactions with pagination",
+        skip(conn),
+        fields(after = ?after, page_size = %page_size),
+        err
+    )]
+    pub fn find_all_with_pagination<'c, A>(
+        after: Option<database::PageCursor>,
+        page_size: i32,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<database::Page<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let page_size = database::pagination::page_size_or_default(page_size);
+            let limit = page_size + 1;
+            let (after_created_on, after_id) =
+                after.map(|after| (after.created_on, after.id)).unzip();
+
+            let mut conn = conn.acquire().await?;
+
+            let total_count: i32 = sqlx::query_scalar(
+                "SELECT COUNT(*) as count FROM transactions WHERE ?1 IS NULL OR user_id = ?1",
+            )
+            .bind(scope.user_id())
+            .fetch_one(&mut *conn)
+            .observe_one("transactions.find_all_with_pagination")
+            .await?;
+
+            let user_id = scope.user_id();
+            let transactions = sqlx::query_as!(
+                database::Transactions,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        transaction_date    AS "transaction_date!: chrono::NaiveDate",
+                        payee,
+                        description,
+                        amount              AS "amount!: i64",
+                        category_id         AS "category_id?: domain::RowID",
+                        account_id          AS "account_id?: domain::RowID",
+                        is_draft            AS "is_draft!: bool",
+                        latitude            AS "latitude?: f64",
+                        longitude           AS "longitude?: f64",
+                        place_name,
+                        return_by           AS "return_by?: chrono::NaiveDate",
+                        warranty_expires    AS "warranty_expires?: chrono::NaiveDate",
+                        cleared_status      AS "cleared_status!: database::ClearedStatus",
+                        user_id             AS "user_id?: domain::RowID",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        version
+                    FROM transactions
+                    WHERE (?1 IS NULL OR (created_on, id) < (?1, ?2))
+                        AND (?4 IS NULL OR user_id = ?4)
+                    ORDER BY created_on DESC, id DESC
+                    LIMIT ?3
+                "#,
+                after_created_on,
+                after_id,
+                limit,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("transactions.find_all_with_pagination")
+            .await?;
+
+            let page =
+                database::Page::from_rows(transactions, page_size, total_count, |transaction| {
+                    database::PageCursor::new(transaction.created_on, transaction.id)
+                });
+
+            tracing::info!(
+                "Retrieved {} of {} transactions from database",
+                page.items.len(),
+                total_count
+            );
+
+            Ok(page)
+        }
+    }
+
+    /// Streams every transaction in `scope`, newest transaction date first.
+    ///
+    /// Rows are read from a database cursor as the stream is polled, so only
+    /// the rows the consumer has not yet taken are ever held in memory, and a
+    /// slow consumer holds the cursor back rather than buffering the table.
+    /// The stream holds a pool connection until it is dropped or exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures_util::TryStreamExt;
+    /// use lib_database::{Transactions, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut transactions = Transactions::stream_all(UserScope::All, pool);
+    /// while let Some(transaction) = transactions.try_next().await? {
+    ///     println!("{} {}", transaction.transaction_date, transaction.payee);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_all(
+        scope: database::UserScope,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> BoxStream<'_, DatabaseResult<Self>> {
+        let mut observation = StreamObservation::new("transactions.stream_all");
+
+        // Bound by value, as the arguments of `query_as!` are borrowed and
+        // would not live as long as the stream
+        sqlx::query_as::<_, Self>(
+            r#"
+                SELECT
+                    id, transaction_date, payee, description, amount, category_id,
+                    account_id, is_draft, latitude, longitude, place_name, return_by,
+                    warranty_expires, cleared_status, user_id, created_on, updated_on, version
+                FROM transactions
+                WHERE ?1 IS NULL OR user_id = ?1
+                ORDER BY transaction_date DESC, created_on DESC
+            "#,
+        )
+        .bind(scope.user_id())
+        .fetch(pool)
+        .inspect_ok(move |_| observation.row())
+        .map_err(database::DatabaseError::from)
+        .boxed()
+    }
+
+    /// Retrieves every draft transaction in `scope`, oldest transaction date
+    /// first, ready to be reviewed and posted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Transactions, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let drafts = Transactions::find_drafts(UserScope::All, pool).await?;
+    /// let ids: Vec<_> = drafts.iter().map(|draft| draft.id).collect();
+    ///
+    /// Transactions::post_drafts(&ids, UserScope::All, pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find draft transactions", skip(conn), err)]
+    pub fn find_drafts<'c, A>(
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let user_id = scope.user_id();
+            let drafts = sqlx::query_as!(
+                database::Transactions,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        transaction_date    AS "transaction_date!: chrono::NaiveDate",
+                        payee,
+                        description,
+                        amount              AS "amount!: i64",
+                        category_id         AS "category_id?: domain::RowID",
+                        account_id          AS "account_id?: domain::RowID",
+                        is_draft            AS "is_draft!: bool",
+                        latitude            AS "latitude?: f64",
+                        longitude           AS "longitude?: f64",
+                        place_name,
+                        return_by           AS "return_by?: chrono::NaiveDate",
+                        warranty_expires    AS "warranty_expires?: chrono::NaiveDate",
+                        cleared_status      AS "cleared_status!: database::ClearedStatus",
+                        user_id             AS "user_id?: domain::RowID",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        version
+                    FROM transactions
+                    WHERE is_draft = 1 AND (?1 IS NULL OR user_id = ?1)
+                    ORDER BY transaction_date, created_on
+                "#,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("transactions.find_drafts")
+            .await?;
+
+            Ok(drafts)
+        }
+    }
+
+    /// Retrieves every transaction in `scope` with the tag `name`, newest
+    /// transaction date first.
+    ///
+    /// The name is cleaned up like a tag name, so `#Travel` finds the
+    /// transactions tagged `travel`. An unknown tag finds nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`](database::DatabaseError::Validation)
+    /// if the name is not a valid tag name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Transactions, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let trip = Transactions::find_by_tag("#travel", UserScope::All, pool).await?;
+    /// let spent: i64 = trip.iter().map(|transaction| transaction.amount).sum();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find transactions by tag", skip(conn), err)]
+    pub fn find_by_tag<'a, 'c, A>(
+        name: &'a str,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let name = database::Tags::normalise_name(name)?;
+
+            let mut conn = conn.acquire().await?;
+
+            let user_id = scope.user_id();
+            let transactions = sqlx::query_as!(
+                database::Transactions,
+                r#"
+                    SELECT
+                        transactions.id                 AS "id!: domain::RowID",
+                        transactions.transaction_date   AS "transaction_date!: chrono::NaiveDate",
+                        transactions.payee,
+                        transactions.description,
+                        transactions.amount             AS "amount!: i64",
+                        transactions.category_id        AS "category_id?: domain::RowID",
+                        transactions.account_id         AS "account_id?: domain::RowID",
+                        transactions.is_draft           AS "is_draft!: bool",
+                        transactions.latitude           AS "latitude?: f64",
+                        transactions.longitude          AS "longitude?: f64",
+                        transactions.place_name,
+                        transactions.return_by          AS "return_by?: chrono::NaiveDate",
+                        transactions.warranty_expires   AS "warranty_expires?: chrono::NaiveDate",
+                        transactions.cleared_status     AS "cleared_status!: database::ClearedStatus",
+                        transactions.user_id            AS "user_id?: domain::RowID",
+                        transactions.created_on         AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        transactions.updated_on         AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        transactions.version
+                    FROM transactions
+                    JOIN transaction_tags ON transaction_tags.transaction_id = transactions.id
+                    JOIN tags ON tags.id = transaction_tags.tag_id
+                    WHERE tags.name = ?1 AND (?2 IS NULL OR transactions.user_id = ?2)
+                    ORDER BY transactions.transaction_date DESC, transactions.created_on DESC
+                "#,
+                name,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("transactions.find_by_tag")
+            .await?;
+
+            Ok(transactions)
+        }
+    }
+
+    /// Reads a transaction back through the given connection.
+    ///
+    /// Used by [`find_by_id`](Self::find_by_id) and by writes that return the
+    /// stored row through the connection they already hold.
+    pub(super) async fn fetch_by_id(
+        id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let transaction = sqlx::query_as!(
+            database::Transactions,
+            r#"
+                SELECT
+                    id                  AS "id!: domain::RowID",
+                    transaction_date    AS "transaction_date!: chrono::NaiveDate",
+                    payee,
+                    description,
+                    amount              AS "amount!: i64",
+                    category_id         AS "category_id?: domain::RowID",
+                    account_id          AS "account_id?: domain::RowID",
+                    is_draft            AS "is_draft!: bool",
+                    latitude            AS "latitude?: f64",
+                    longitude           AS "longitude?: f64",
+                    place_name,
+                    return_by           AS "return_by?: chrono::NaiveDate",
+                    warranty_expires    AS "warranty_expires?: chrono::NaiveDate",
+                    cleared_status      AS "cleared_status!: database::ClearedStatus",
+                    user_id             AS "user_id?: domain::RowID",
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    version
+                FROM transactions
+                WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("transactions.fetch_by_id")
+        .await?;
+
+        Ok(transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn find_by_tag_returns_tagged_transactions(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let tagged = database::Transactions::mock().insert(&pool).await?;
+        let other = database::Transactions::mock().insert(&pool).await?;
+        database::Tags::set_for_transaction(tagged.id, &["travel"], &pool).await?;
+        database::Tags::set_for_transaction(other.id, &["food"], &pool).await?;
+
+        let found =
+            database::Transactions::find_by_tag("#Travel", database::UserScope::All, &pool).await?;
+        let unknown =
+            database::Transactions::find_by_tag("work", database::UserScope::All, &pool).await?;
+
+        assert_eq!(found, [tagged]);
+        assert!(unknown.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_returns_transaction(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let transaction = database::Transactions::mock().insert(&pool).await?;
+
+        let found =
+            database::Transactions::find_by_id(transaction.id, database::UserScope::All, &pool)
+                .await?
+                .expect("transaction should exist");
+
+        assert_eq!(found, transaction);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let found = database::Transactions::find_by_id(
+            domain::RowID::new(),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert!(found.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_all_with_pagination_pages_newest_entered_first(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let mut entered = Vec::new();
+        for _ in 0..5 {
+            entered.push(database::Transactions::mock().insert(&pool).await?.id);
+        }
+
+        let first_page = database::Transactions::find_all_with_pagination(
+            None,
+            3,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        // A transaction entered while paging is not on a later page
+        database::Transactions::mock().insert(&pool).await?;
+        let second_page = database::Transactions::find_all_with_pagination(
+            first_page.next_cursor,
+            3,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        let ids = |page: &database::Page<database::Transactions>| {
+            page.items
+                .iter()
+                .map(|transaction| transaction.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(first_page.total_count, 5);
+        assert_eq!(ids(&first_page), [entered[4], entered[3], entered[2]]);
+        assert_eq!(ids(&second_page), [entered[1], entered[0]]);
+        assert_eq!(second_page.next_cursor, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn stream_all_yields_every_transaction_newest_first(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let date = |day| chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        for day in [3, 1, 2] {
+            database::Transactions::new(date(day), "Payee", -100)
+                .insert(&pool)
+                .await?;
+        }
+
+        let streamed: Vec<_> = database::Transactions::stream_all(database::UserScope::All, &pool)
+            .try_collect()
+            .await?;
+
+        let dates: Vec<_> = streamed.iter().map(|t| t.transaction_date).collect();
+        assert_eq!(dates, vec![date(3), date(2), date(1)]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_is_limited_to_the_scope(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let owner = database::Users::mock().insert(&pool).await?;
+        let other = database::Users::mock().insert(&pool).await?;
+        let mut transaction = database::Transactions::mock();
+        transaction.user_id = Some(owner.id);
+        let transaction = transaction.insert(&pool).await?;
+
+        let as_owner = database::Transactions::find_by_id(
+            transaction.id,
+            database::UserScope::User(owner.id),
+            &pool,
+        )
+        .await?;
+        let as_other = database::Transactions::find_by_id(
+            transaction.id,
+            database::UserScope::User(other.id),
+            &pool,
+        )
+        .await?;
+        let page = database::Transactions::find_all_with_pagination(
+            None,
+            10,
+            database::UserScope::User(other.id),
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(as_owner, Some(transaction.clone()));
+        assert!(as_other.is_none());
+        assert_eq!(page.total_count, 0);
+
+        let deleted = database::Transactions::delete_by_id(
+            transaction.id,
+            database::UserScope::User(other.id),
+            &pool,
+        )
+        .await;
+        assert!(matches!(deleted, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_by_public_id_decodes_with_the_ledgers_key(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let transaction = database::Transactions::mock().insert(&pool).await?;
+        let key = database::public_id_key(&pool).await?;
+
+        let found = database::Transactions::find_by_public_id(
+            key.encode(transaction.id),
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert_eq!(found, Some(transaction));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::Transactions {
+    /// Inserts a new transaction into the database.
+    ///
+    /// The transaction is validated before anything is written and is read back
+    /// after the insert, so the returned value reflects what was stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the inserted transaction as read back from the database, or a
+    /// `DatabaseError` if the insertion fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The payee is empty or the amount is zero (`DatabaseError::Validation`)
+    /// - The transaction is marked reconciled (`DatabaseError::Validation`)
+    /// - The transaction date is in a closed period (`DatabaseError::PeriodClosed`)
+    /// - The category or account does not exist (foreign key violation)
+    /// - A transaction with the same id already exists
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Transactions;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// let transaction = Transactions::new(date, "Corner Cafe", -1_250);
+    ///
+    /// let inserted = transaction.insert(pool).await?;
+    /// assert_eq!(inserted.amount, -1_250);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert new transaction into database",
+        skip(self, conn),
+        fields(
+            id = %self.id,
+            transaction_date = %self.transaction_date,
+            amount = self.amount,
+        ),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+            if self.cleared_status == database::ClearedStatus::Reconciled {
+                return Err(database::DatabaseError::Validation(
+                    "A new transaction cannot already be reconciled".to_string(),
+                ));
+            }
+
+            let mut conn = conn.acquire().await?;
+
+            if !self.is_draft {
+                database::PeriodClosings::ensure_open(self.transaction_date, &mut conn).await?;
+            }
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO transactions (
+                        id, transaction_date, payee, description, amount, category_id,
+                        account_id, is_draft, latitude, longitude, place_name, return_by,
+                        warranty_expires, cleared_status, user_id, created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.transaction_date,
+                self.payee,
+                self.description,
+                self.amount,
+                self.category_id,
+                self.account_id,
+                self.is_draft,
+                self.latitude,
+                self.longitude,
+                self.place_name,
+                self.return_by,
+                self.warranty_expires,
+                self.cleared_status,
+                self.user_id,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("transactions.insert")
+            .await?;
+
+            let inserted = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "Transaction with id {} not found after insert",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("New transaction inserted into the database.");
+
+            database::events::publish(database::DataChanges::TransactionInserted(inserted.clone()));
+
+            Ok(inserted)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    // Bring module into test scope
+    use super::*;
+    use lib_domain as domain;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    pub(crate) async fn count_transactions(pool: &sqlx::Pool<sqlx::Sqlite>) -> Result<i64> {
+        Ok(sqlx::query_scalar("SELECT COUNT(*) FROM transactions")
+            .fetch_one(pool)
+            .await?)
+    }
+
+    #[sqlx::test]
+    async fn insert_transaction_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let transaction = database::Transactions::mock();
+
+        let inserted = transaction.insert(&pool).await?;
+
+        assert_eq!(inserted, transaction);
+        assert_eq!(count_transactions(&pool).await?, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_categorised_transaction_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        let mut transaction = database::Transactions::mock();
+        transaction.category_id = Some(category.id);
+
+        let inserted = transaction.insert(&pool).await?;
+
+        assert_eq!(inserted.category_id, Some(category.id));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_posted_to_account_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::mock().insert(&pool).await?;
+        let mut transaction = database::Transactions::mock();
+        transaction.account_id = Some(account.id);
+
+        let inserted = transaction.insert(&pool).await?;
+
+        assert_eq!(inserted.account_id, Some(account.id));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_with_unknown_category_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut transaction = database::Transactions::mock();
+        transaction.category_id = Some(domain::RowID::new());
+
+        let result = transaction.insert(&pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+        assert_eq!(count_transactions(&pool).await?, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_zero_amount_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut transaction = database::Transactions::mock();
+        transaction.amount = 0;
+
+        let result = transaction.insert(&pool).await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        assert_eq!(count_transactions(&pool).await?, 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,421 @@
+//! # Transaction Locations
+//!
+//! Finds transactions by where they were made. Proximity search narrows the
+//! candidates with a latitude and longitude bounding box in SQL, using the
+//! location index, and then checks the great circle distance of each.
+//!
+//! For maps, transactions within an area are grouped into clusters on a grid
+//! of cells a given number of degrees wide, so a client can draw one marker
+//! per cell instead of one per transaction.
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+
+/// Mean radius of the Earth in metres.
+const EARTH_RADIUS_METRES: f64 = 6_371_000.0;
+
+/// Length of one degree of latitude in metres, near enough everywhere.
+const METRES_PER_DEGREE: f64 = 111_320.0;
+
+/// An area of the map, in WGS 84 decimal degrees. Areas crossing the
+/// antimeridian are not supported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MapBounds {
+    pub min_latitude: f64,
+    pub min_longitude: f64,
+    pub max_latitude: f64,
+    pub max_longitude: f64,
+}
+
+/// The transactions within one cell of the map grid.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MapCluster {
+    /// Mean latitude of the transactions in the cell.
+    pub latitude: f64,
+
+    /// Mean longitude of the transactions in the cell.
+    pub longitude: f64,
+
+    /// Number of transactions in the cell.
+    pub count: usize,
+
+    /// Sum of the transaction amounts in minor units (cents).
+    pub total_amount: i64,
+
+    /// The place name used most often in the cell, if any.
+    pub place_name: Option<String>,
+}
+
+/// Great circle distance between two points in metres, by the haversine formula.
+fn haversine_metres(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lat2) = (from.0.to_radians(), to.0.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (to.1 - from.1).to_radians();
+
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_METRES * a.sqrt().asin()
+}
+
+impl MapBounds {
+    fn validate(&self) -> DatabaseResult<()> {
+        let latitudes = -90.0..=90.0;
+        let longitudes = -180.0..=180.0;
+
+        if !latitudes.contains(&self.min_latitude)
+            || !latitudes.contains(&self.max_latitude)
+            || !longitudes.contains(&self.min_longitude)
+            || !longitudes.contains(&self.max_longitude)
+            || self.min_latitude > self.max_latitude
+            || self.min_longitude > self.max_longitude
+        {
+            return Err(DatabaseError::Validation(format!(
+                "Invalid map bounds {self:?}"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl database::Transactions {
+    /// Returns the distance in metres from the transaction's location to a
+    /// point, or `None` if the transaction has no location.
+    pub fn distance_metres(&self, latitude: f64, longitude: f64) -> Option<f64> {
+        Some(haversine_metres(
+            (self.latitude?, self.longitude?),
+            (latitude, longitude),
+        ))
+    }
+
+    /// Finds the transactions made within a radius of a point, nearest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Latitude of the point in decimal degrees
+    /// * `longitude` - Longitude of the point in decimal degrees
+    /// * `radius_metres` - The search radius in metres
+    /// * `scope` - The user whose transactions are searched
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if the point is out of range or the
+    /// radius is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Transactions, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for transaction in Transactions::find_near(-27.4698, 153.0251, 500.0, UserScope::All, pool).await? {
+    ///     println!("{} at {:?}", transaction.payee, transaction.place_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find transactions near a point", skip(conn), err)]
+    pub fn find_near<'c, A>(
+        latitude: f64,
+        longitude: f64,
+        radius_metres: f64,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let valid = (-90.0..=90.0).contains(&latitude)
+                && (-180.0..=180.0).contains(&longitude)
+                && radius_metres > 0.0
+                && radius_metres.is_finite();
+            if !valid {
+                return Err(DatabaseError::Validation(format!(
+                    "Invalid proximity search of {radius_metres} metres around ({latitude}, {longitude})"
+                )));
+            }
+
+            let d_lat = radius_metres / METRES_PER_DEGREE;
+            let d_lon = radius_metres / (METRES_PER_DEGREE * latitude.to_radians().cos());
+
+            // Search every longitude near the poles or across the antimeridian
+            let (min_lon, max_lon) =
+                if !d_lon.is_finite() || longitude - d_lon < -180.0 || longitude + d_lon > 180.0 {
+                    (-180.0, 180.0)
+                } else {
+                    (longitude - d_lon, longitude + d_lon)
+                };
+
+            let bounds = MapBounds {
+                min_latitude: (latitude - d_lat).max(-90.0),
+                min_longitude: min_lon,
+                max_latitude: (latitude + d_lat).min(90.0),
+                max_longitude: max_lon,
+            };
+
+            let mut conn = conn.acquire().await?;
+            let candidates = Self::fetch_within(bounds, scope, &mut conn).await?;
+
+            let mut nearby: Vec<(f64, Self)> = candidates
+                .into_iter()
+                .filter_map(|transaction| {
+                    let distance = transaction.distance_metres(latitude, longitude)?;
+                    (distance <= radius_metres).then_some((distance, transaction))
+                })
+                .collect();
+            nearby.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            Ok(nearby
+                .into_iter()
+                .map(|(_, transaction)| transaction)
+                .collect())
+        }
+    }
+
+    /// Groups the posted transactions within an area of the map into clusters,
+    /// one per grid cell of `cell_degrees` square. Drafts are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - The area of the map
+    /// * `cell_degrees` - Width and height of a grid cell in degrees
+    /// * `scope` - The user whose transactions are clustered
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the clusters ordered by latitude then longitude of their cell.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` if the bounds are out of range or
+    /// inverted, or the cell size is not positive.
+    #[tracing::instrument(name = "Cluster transactions for map", skip(conn), err)]
+    pub fn map_clusters<'c, A>(
+        bounds: MapBounds,
+        cell_degrees: f64,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<MapCluster>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            bounds.validate()?;
+            if !(cell_degrees > 0.0 && cell_degrees.is_finite()) {
+                return Err(DatabaseError::Validation(format!(
+                    "Invalid map cell size {cell_degrees} degrees"
+                )));
+            }
+
+            let mut conn = conn.acquire().await?;
+            let transactions = Self::fetch_within(bounds, scope, &mut conn).await?;
+
+            struct Cell {
+                latitude_sum: f64,
+                longitude_sum: f64,
+                count: usize,
+                total_amount: i64,
+                place_names: std::collections::HashMap<String, usize>,
+            }
+
+            let mut cells = std::collections::BTreeMap::<(i64, i64), Cell>::new();
+
+            for transaction in transactions
+                .into_iter()
+                .filter(|transaction| !transaction.is_draft)
+            {
+                let (Some(latitude), Some(longitude)) =
+                    (transaction.latitude, transaction.longitude)
+                else {
+                    continue;
+                };
+
+                let key = (
+                    (latitude / cell_degrees).floor() as i64,
+                    (longitude / cell_degrees).floor() as i64,
+                );
+                let cell = cells.entry(key).or_insert_with(|| Cell {
+                    latitude_sum: 0.0,
+                    longitude_sum: 0.0,
+                    count: 0,
+                    total_amount: 0,
+                    place_names: Default::default(),
+                });
+
+                cell.latitude_sum += latitude;
+                cell.longitude_sum += longitude;
+                cell.count += 1;
+                cell.total_amount += transaction.amount;
+                if let Some(place_name) = transaction.place_name {
+                    *cell.place_names.entry(place_name).or_default() += 1;
+                }
+            }
+
+            Ok(cells
+                .into_values()
+                .map(|cell| MapCluster {
+                    latitude: cell.latitude_sum / cell.count as f64,
+                    longitude: cell.longitude_sum / cell.count as f64,
+                    count: cell.count,
+                    total_amount: cell.total_amount,
+                    place_name: cell
+                        .place_names
+                        .into_iter()
+                        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+                        .map(|(place_name, _)| place_name),
+                })
+                .collect())
+        }
+    }
+
+    /// Fetches every transaction in `scope` with a location inside the bounds.
+    async fn fetch_within(
+        bounds: MapBounds,
+        scope: database::UserScope,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Vec<Self>> {
+        let user_id = scope.user_id();
+        let transactions = sqlx::query_as!(
+            database::Transactions,
+            r#"
+                SELECT
+                    id                  AS "id!: lib_domain::RowID",
+                    transaction_date    AS "transaction_date!: chrono::NaiveDate",
+                    payee,
+                    description,
+                    amount              AS "amount!: i64",
+                    category_id         AS "category_id?: lib_domain::RowID",
+                    account_id          AS "account_id?: lib_domain::RowID",
+                    is_draft            AS "is_draft!: bool",
+                    latitude            AS "latitude?: f64",
+                    longitude           AS "longitude?: f64",
+                    place_name,
+                    return_by           AS "return_by?: chrono::NaiveDate",
+                    warranty_expires    AS "warranty_expires?: chrono::NaiveDate",
+                    cleared_status      AS "cleared_status!: database::ClearedStatus",
+                    user_id             AS "user_id?: lib_domain::RowID",
+                    created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    version
+                FROM transactions
+                WHERE latitude BETWEEN ?1 AND ?2
+                    AND longitude BETWEEN ?3 AND ?4
+                    AND (?5 IS NULL OR user_id = ?5)
+            "#,
+            bounds.min_latitude,
+            bounds.max_latitude,
+            bounds.min_longitude,
+            bounds.max_longitude,
+            user_id
+        )
+        .fetch_all(&mut *conn)
+        .observe("transactions.fetch_within")
+        .await?;
+
+        Ok(transactions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::Result;
+
+    async fn located(
+        payee: &str,
+        amount: i64,
+        location: (f64, f64),
+        place_name: Option<&str>,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<database::Transactions> {
+        let mut transaction = database::Transactions::mock();
+        transaction.payee = payee.to_string();
+        transaction.amount = amount;
+        transaction.latitude = Some(location.0);
+        transaction.longitude = Some(location.1);
+        transaction.place_name = place_name.map(str::to_string);
+
+        Ok(transaction.insert(pool).await?)
+    }
+
+    #[test]
+    fn haversine_matches_known_distance() {
+        // Brisbane to Sydney is about 732 km
+        let distance = haversine_metres((-27.4698, 153.0251), (-33.8688, 151.2093));
+
+        assert!((distance - 732_000.0).abs() < 5_000.0, "{distance}");
+    }
+
+    #[sqlx::test]
+    async fn find_near_returns_nearest_first(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let far = located("Cafe 300m", -450, (-27.4725, 153.0251), None, &pool).await?;
+        let near = located("Cafe 100m", -500, (-27.4707, 153.0251), None, &pool).await?;
+        located("Sydney", -900, (-33.8688, 151.2093), None, &pool).await?;
+        database::Transactions::mock().insert(&pool).await?;
+
+        let found = database::Transactions::find_near(
+            -27.4698,
+            153.0251,
+            500.0,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        let invalid = database::Transactions::find_near(
+            -27.4698,
+            153.0251,
+            0.0,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+
+        assert_eq!(
+            found.iter().map(|t| t.id).collect::<Vec<_>>(),
+            vec![near.id, far.id]
+        );
+        assert!(matches!(invalid, Err(DatabaseError::Validation(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn map_clusters_groups_by_cell(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        located("Bakery", -600, (-27.47, 153.02), Some("West End"), &pool).await?;
+        located("Cafe", -400, (-27.48, 153.03), Some("West End"), &pool).await?;
+        located("Pub", -3_000, (-27.49, 153.04), Some("South Bank"), &pool).await?;
+        located("Surf Shop", -8_000, (-28.00, 153.43), None, &pool).await?;
+
+        let bounds = MapBounds {
+            min_latitude: -29.0,
+            min_longitude: 152.0,
+            max_latitude: -27.0,
+            max_longitude: 154.0,
+        };
+        let clusters =
+            database::Transactions::map_clusters(bounds, 0.1, database::UserScope::All, &pool)
+                .await?;
+
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].count, 1);
+        assert_eq!(clusters[1].count, 3);
+        assert_eq!(clusters[1].total_amount, -4_000);
+        assert_eq!(clusters[1].place_name.as_deref(), Some("West End"));
+        assert!((clusters[1].latitude + 27.48).abs() < 1e-9);
+
+        let inverted = MapBounds {
+            min_latitude: -27.0,
+            max_latitude: -29.0,
+            ..bounds
+        };
+        let result =
+            database::Transactions::map_clusters(inverted, 0.1, database::UserScope::All, &pool)
+                .await;
+        assert!(matches!(result, Err(DatabaseError::Validation(_))));
+
+        Ok(())
+    }
+}
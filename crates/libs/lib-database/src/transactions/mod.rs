@@ -0,0 +1,77 @@
+//! # Transactions Database Module
+//!
+//! Provides storage for financial transactions. A transaction records a single
+//! signed amount in minor units against a payee on a given date, optionally
+//! classified by a category and posted to an account. Negative amounts are money spent and positive
+//! amounts are money received.
+//!
+//! Transactions can also be saved as drafts, which are left out of balances
+//! and reports until they are posted in bulk.
+//!
+//! A transaction can record where it was made, so transactions can be found
+//! near a point and clustered for display on a map.
+//!
+//! A purchase can record when its return window closes and its warranty
+//! expires, so reminders can be given before either passes.
+//!
+//! Transactions can be found by full-text search over their payee,
+//! description and place name.
+//!
+//! Every transaction matching a filter can be edited in bulk, or moved to
+//! another category in one go.
+//!
+//! Transactions are ticked off as cleared against an account's statement in
+//! batches, then reconciled with it. See [`Reconciliations`](crate::Reconciliations).
+//!
+//! A transaction can be split across categories, its splits summing to its
+//! amount, such as a supermarket receipt that is part groceries and part
+//! homewares.
+//!
+//! Money moved between two accounts is recorded as a transfer, a linked pair
+//! of transactions kept in step when either is updated or deleted.
+//!
+//! Probable duplicates, such as a transaction typed in by hand and then
+//! imported, are found by amount, date and payee similarity, then merged or
+//! dismissed.
+
+mod bulk_edit;
+mod cleared;
+mod delete;
+mod duplicates;
+mod expiry;
+mod find;
+mod insert;
+mod location;
+mod model;
+mod post;
+mod reassign;
+mod search;
+mod splits;
+mod suggest;
+mod transfers;
+mod update;
+
+/// Database row model representing a persisted transaction, and how far it
+/// has been reconciled.
+pub use model::{ClearedStatus, Transactions};
+
+/// Filter, changes and preview for editing many transactions at once.
+pub use bulk_edit::{BulkEditPreview, TransactionChanges, TransactionFilter};
+
+/// Category suggested for a transaction from its payee.
+pub use suggest::{CategorySuggestion, SuggestionSources};
+
+/// Map area and clustered points for transactions with a location.
+pub use location::{MapBounds, MapCluster};
+
+/// Return window and warranty reminders.
+pub use expiry::{ExpiryKinds, ExpiryReminder, ExpiryRule};
+
+/// One part of a transaction split across categories.
+pub use splits::TransactionSplits;
+
+/// A transfer between accounts, linking a transaction in each.
+pub use transfers::Transfers;
+
+/// Probable duplicate transactions and how alike their payees must be.
+pub use duplicates::{DUPLICATE_PAYEE_SIMILARITY, DuplicatePair};
@@ -0,0 +1,298 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// A financial transaction recorded in the ledger.
+///
+/// Amounts are signed minor units (cents): negative for money spent, positive
+/// for money received. A draft transaction is left out of balances and reports
+/// and is not checked against closed periods until it is posted.
+///
+/// A transaction may record where it was made, as a latitude and longitude in
+/// WGS 84 decimal degrees and a free text place name.
+///
+/// A purchase may record the last day it can be returned and the day its
+/// warranty expires, for reminders before either passes.
+///
+/// A transaction is uncleared until it is ticked off against a statement,
+/// and reconciled once a [`Reconciliations`](database::Reconciliations)
+/// includes it. Only the reconciliation methods change the cleared status of
+/// a stored transaction.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct Transactions {
+    pub id: domain::RowID,
+    pub transaction_date: chrono::NaiveDate,
+    pub payee: String,
+    pub description: Option<String>,
+    pub amount: i64,
+    pub category_id: Option<domain::RowID>,
+    pub account_id: Option<domain::RowID>,
+    pub is_draft: bool,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub place_name: Option<String>,
+    pub return_by: Option<chrono::NaiveDate>,
+    pub warranty_expires: Option<chrono::NaiveDate>,
+    pub cleared_status: ClearedStatus,
+
+    /// The user who owns the transaction, or `None` for transactions
+    /// recorded before there were users.
+    pub user_id: Option<domain::RowID>,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+
+    /// Number of times the transaction has been written, starting at 1. An
+    /// update only applies to the version it was read at, see
+    /// [`DatabaseError::Conflict`](DatabaseError::Conflict).
+    pub version: i64,
+}
+
+/// How far a transaction has been checked against the account's statements.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, sqlx::Type, serde::Deserialize, serde::Serialize,
+)]
+#[sqlx(rename_all = "lowercase")]
+pub enum ClearedStatus {
+    /// Not yet seen on a statement.
+    #[default]
+    Uncleared,
+    /// Seen on a statement, waiting to be reconciled.
+    Cleared,
+    /// Included in a reconciliation, and locked against changes to its
+    /// amount, date and account until the reconciliation is undone.
+    Reconciled,
+}
+
+impl database::Transactions {
+    /// Creates a new uncategorised transaction with no description, not posted
+    /// to an account. The transaction is posted, not a draft.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::Transactions;
+    ///
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// let transaction = Transactions::new(date, "Corner Cafe", -1_250);
+    ///
+    /// assert_eq!(transaction.payee, "Corner Cafe");
+    /// assert!(transaction.category_id.is_none());
+    /// ```
+    pub fn new(transaction_date: chrono::NaiveDate, payee: impl Into<String>, amount: i64) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            transaction_date,
+            payee: payee.into(),
+            description: None,
+            amount,
+            category_id: None,
+            account_id: None,
+            is_draft: false,
+            latitude: None,
+            longitude: None,
+            place_name: None,
+            return_by: None,
+            warranty_expires: None,
+            cleared_status: ClearedStatus::Uncleared,
+            user_id: None,
+            created_on: now,
+            updated_on: now,
+            version: 1,
+        }
+    }
+
+    /// Checks the transaction fields before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if:
+    /// - The payee is empty or only whitespace
+    /// - The amount is zero
+    /// - The description is not valid [`Markdown`](domain::Markdown)
+    /// - Only one of latitude and longitude is set, or either is out of range
+    /// - The return by or warranty expiry date is before the transaction date
+    pub fn validate(&self) -> DatabaseResult<()> {
+        if self.payee.trim().is_empty() {
+            return Err(DatabaseError::Validation(format!(
+                "Transaction {} must have a payee",
+                self.id
+            )));
+        }
+
+        if self.amount == 0 {
+            return Err(DatabaseError::Validation(format!(
+                "Transaction {} has a zero amount",
+                self.id
+            )));
+        }
+
+        if let Some(description) = &self.description
+            && let Err(e) = domain::Markdown::parse(description.as_str())
+        {
+            return Err(DatabaseError::Validation(format!(
+                "Transaction {} description: {e}",
+                self.id
+            )));
+        }
+
+        match (self.latitude, self.longitude) {
+            (None, None) => {}
+            (Some(latitude), Some(longitude)) => {
+                if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+                    return Err(DatabaseError::Validation(format!(
+                        "Transaction {} location ({latitude}, {longitude}) is out of range",
+                        self.id
+                    )));
+                }
+            }
+            _ => {
+                return Err(DatabaseError::Validation(format!(
+                    "Transaction {} location needs both a latitude and a longitude",
+                    self.id
+                )));
+            }
+        }
+
+        for (field, date) in [
+            ("return by", self.return_by),
+            ("warranty expiry", self.warranty_expires),
+        ] {
+            if let Some(date) = date
+                && date < self.transaction_date
+            {
+                return Err(DatabaseError::Validation(format!(
+                    "Transaction {} {field} date {date} is before the transaction date {}",
+                    self.id, self.transaction_date
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Generates a mock uncategorised transaction with randomised test data.
+    #[cfg(test)]
+    pub fn mock() -> Self {
+        use fake::Fake;
+        use fake::faker::company::en::CompanyName;
+        use fake::faker::lorem::en::Sentence;
+
+        let amount: i64 = (1..1_000_000).fake();
+        let spent: bool = fake::Faker.fake();
+        let payee: String = CompanyName().fake();
+
+        let mut transaction = Self::new(
+            chrono::Utc::now().date_naive(),
+            payee,
+            if spent { -amount } else { amount },
+        );
+        transaction.description = Some(Sentence(2..6).fake());
+        transaction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transaction() -> database::Transactions {
+        database::Transactions::new(
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap(),
+            "Corner Cafe",
+            -1_250,
+        )
+    }
+
+    #[test]
+    fn new_sets_matching_timestamps() {
+        let transaction = transaction();
+
+        assert_eq!(transaction.created_on, transaction.updated_on);
+        assert!(transaction.description.is_none());
+        assert!(!transaction.is_draft);
+    }
+
+    #[test]
+    fn validate_accepts_transaction() {
+        assert_eq!(transaction().validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_empty_payee() {
+        let mut transaction = transaction();
+        transaction.payee = "   ".to_string();
+
+        assert!(matches!(
+            transaction.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_zero_amount() {
+        let mut transaction = transaction();
+        transaction.amount = 0;
+
+        assert!(matches!(
+            transaction.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_unsafe_markdown_description() {
+        let mut transaction = transaction();
+        transaction.description = Some("**Lunch** with [Sam](mailto:sam@example.com)".to_string());
+        assert_eq!(transaction.validate(), Ok(()));
+
+        transaction.description = Some("[Refund](javascript:alert(1))".to_string());
+        assert!(matches!(
+            transaction.validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_checks_location() {
+        let located = |latitude, longitude| database::Transactions {
+            latitude,
+            longitude,
+            ..transaction()
+        };
+
+        assert_eq!(located(Some(-27.4698), Some(153.0251)).validate(), Ok(()));
+        assert!(matches!(
+            located(Some(-27.4698), None).validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            located(Some(91.0), Some(0.0)).validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            located(Some(0.0), Some(f64::NAN)).validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_return_dates_before_purchase() {
+        let day = |day| chrono::NaiveDate::from_ymd_opt(2025, 3, day);
+        let with = |return_by, warranty_expires| database::Transactions {
+            return_by,
+            warranty_expires,
+            ..transaction()
+        };
+
+        assert_eq!(with(day(14), day(28)).validate(), Ok(()));
+        assert!(matches!(
+            with(day(13), None).validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+        assert!(matches!(
+            with(None, day(1)).validate(),
+            Err(DatabaseError::Validation(_))
+        ));
+    }
+}
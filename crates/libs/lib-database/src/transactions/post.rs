@@ -0,0 +1,243 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Transactions {
+    /// Posts a batch of draft transactions to the ledger, so they count towards
+    /// balances and reports.
+    ///
+    /// The batch is posted in a single database transaction: either every
+    /// draft is posted or, if any of them fails, none are.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the draft transactions to post
+    /// * `scope` - The user whose transactions are posted
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the posted transactions in the order of `ids`, or a
+    /// `DatabaseError` if the batch could not be posted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A transaction does not exist or is outside `scope` (`DatabaseError::NotFound`)
+    /// - A transaction is already posted (`DatabaseError::Validation`)
+    /// - A transaction date is in a closed period (`DatabaseError::PeriodClosed`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Transactions, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// let mut transaction = Transactions::new(date, "Corner Cafe", -1_250);
+    /// transaction.is_draft = true;
+    /// let draft = transaction.insert(pool).await?;
+    ///
+    /// let posted = Transactions::post_drafts(&[draft.id], UserScope::All, pool).await?;
+    /// assert!(!posted[0].is_draft);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Post draft transactions",
+        skip(ids, conn),
+        fields(count = ids.len()),
+        err
+    )]
+    pub fn post_drafts<'a, 'c, A>(
+        ids: &'a [domain::RowID],
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+            let updated_on = chrono::Utc::now();
+
+            let mut posted = Vec::with_capacity(ids.len());
+
+            for &id in ids {
+                match Self::post_draft(id, scope, updated_on, &mut tx).await {
+                    Ok(transaction) => posted.push(transaction),
+                    Err(error) => {
+                        // Roll back now rather than on drop, so the write lock
+                        // is released before the caller sees the error
+                        tx.rollback().await?;
+                        return Err(error);
+                    }
+                }
+            }
+
+            tx.commit().await?;
+
+            tracing::info!("Posted {} draft transactions", posted.len());
+
+            Ok(posted)
+        }
+    }
+
+    /// Posts a single draft within the batch's database transaction.
+    async fn post_draft(
+        id: domain::RowID,
+        scope: database::UserScope,
+        updated_on: chrono::DateTime<chrono::Utc>,
+        tx: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Self> {
+        let draft = Self::fetch_by_id(id, &mut *tx)
+            .await?
+            .filter(|draft| scope.includes(draft.user_id))
+            .ok_or_else(|| {
+                database::DatabaseError::NotFound(format!("Transaction with id {} not found", id))
+            })?;
+
+        if !draft.is_draft {
+            return Err(database::DatabaseError::Validation(format!(
+                "Transaction {} is already posted",
+                id
+            )));
+        }
+
+        draft.validate()?;
+        database::PeriodClosings::ensure_open(draft.transaction_date, &mut *tx).await?;
+
+        sqlx::query!(
+            r#"
+                UPDATE transactions
+                SET is_draft = 0, updated_on = ?, version = version + 1
+                WHERE id = ?
+            "#,
+            updated_on,
+            id
+        )
+        .execute(&mut *tx)
+        .observe("transactions.post_draft")
+        .await?;
+
+        Self::fetch_by_id(id, &mut *tx).await?.ok_or_else(|| {
+            database::DatabaseError::NotFound(format!(
+                "Transaction with id {} not found after posting",
+                id
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::Result;
+
+    async fn draft(
+        amount: i64,
+        account_id: domain::RowID,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<database::Transactions> {
+        let mut transaction = database::Transactions::mock();
+        transaction.amount = amount;
+        transaction.account_id = Some(account_id);
+        transaction.is_draft = true;
+
+        Ok(transaction.insert(pool).await?)
+    }
+
+    #[sqlx::test]
+    async fn drafts_do_not_count_until_posted(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::new("Wallet").insert(&pool).await?;
+        let coffee = draft(-450, account.id, &pool).await?;
+        let lunch = draft(-1_800, account.id, &pool).await?;
+
+        assert_eq!(
+            database::Accounts::balance(account.id, None, &pool).await?,
+            0
+        );
+        assert_eq!(
+            database::Transactions::find_drafts(database::UserScope::All, &pool)
+                .await?
+                .len(),
+            2
+        );
+
+        let posted = database::Transactions::post_drafts(
+            &[coffee.id, lunch.id],
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+
+        assert!(posted.iter().all(|transaction| !transaction.is_draft));
+        assert_eq!(
+            database::Accounts::balance(account.id, None, &pool).await?,
+            -2_250
+        );
+        assert!(
+            database::Transactions::find_drafts(database::UserScope::All, &pool)
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn post_drafts_is_all_or_nothing(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let account = database::Accounts::new("Wallet").insert(&pool).await?;
+        let coffee = draft(-450, account.id, &pool).await?;
+        let posted = database::Transactions::mock().insert(&pool).await?;
+
+        let already_posted = database::Transactions::post_drafts(
+            &[coffee.id, posted.id],
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+        let missing = database::Transactions::post_drafts(
+            &[coffee.id, domain::RowID::new()],
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+
+        assert!(matches!(
+            already_posted,
+            Err(database::DatabaseError::Validation(_))
+        ));
+        assert!(matches!(missing, Err(database::DatabaseError::NotFound(_))));
+        assert_eq!(
+            database::Transactions::find_drafts(database::UserScope::All, &pool).await?,
+            vec![coffee]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn drafts_in_closed_period_cannot_be_posted(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let closed_through = chrono::NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        database::PeriodClosings::close(closed_through, None, &pool).await?;
+
+        let mut transaction = database::Transactions::new(closed_through, "Late Invoice", -5_000);
+        transaction.is_draft = true;
+        let draft = transaction.insert(&pool).await?;
+
+        let result =
+            database::Transactions::post_drafts(&[draft.id], database::UserScope::All, &pool).await;
+
+        assert!(matches!(
+            result,
+            Err(database::DatabaseError::PeriodClosed(_))
+        ));
+
+        Ok(())
+    }
+}
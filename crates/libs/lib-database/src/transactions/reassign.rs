@@ -0,0 +1,238 @@
+//! # Reassign
+//!
+//! Moves every transaction matching a [`TransactionFilter`] to another
+//! category, for when a category is split up or was used by mistake.
+//!
+//! Unlike a bulk edit, a reassignment is all or nothing: every matching
+//! transaction is moved in one database transaction, so a failure, such as
+//! a transaction in a closed period, leaves them all where they were.
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult, TransactionFilter};
+use lib_domain as domain;
+
+impl database::Transactions {
+    /// Moves every transaction in `scope` matching `filter` to the category
+    /// `category_id`, atomically.
+    ///
+    /// Transactions already in the category are matched but left alone.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of transactions moved.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The filter is empty (`DatabaseError::Validation`)
+    /// - The category does not exist in `scope` (`DatabaseError::NotFound`)
+    /// - A matching transaction is in a closed period (`DatabaseError::PeriodClosed`),
+    ///   in which case none are moved
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{TransactionFilter, Transactions, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, dining_out: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let filter = TransactionFilter { payee_pattern: Some("*cafe*".to_string()), ..Default::default() };
+    ///
+    /// let moved = Transactions::reassign(&filter, dining_out, UserScope::All, pool).await?;
+    /// println!("Moved {moved} cafe transactions to Dining Out");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Reassign transactions", skip(conn), err)]
+    pub fn reassign<'a, 'c, A>(
+        filter: &'a TransactionFilter,
+        category_id: domain::RowID,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<u64>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            filter.validate()?;
+
+            let mut tx = conn.begin().await?;
+
+            let user_id = scope.user_id();
+            let category_exists = sqlx::query_scalar!(
+                r#"
+                    SELECT EXISTS (
+                        SELECT 1
+                        FROM categories
+                        WHERE id = ?1 AND deleted_on IS NULL AND (?2 IS NULL OR user_id = ?2)
+                    ) AS "exists!: bool"
+                "#,
+                category_id,
+                user_id
+            )
+            .fetch_one(&mut *tx)
+            .observe_one("transactions.reassign")
+            .await?;
+            if !category_exists {
+                return Err(DatabaseError::NotFound(format!(
+                    "Category with id {category_id} not found"
+                )));
+            }
+
+            let ids = Self::fetch_matching_ids(filter, scope, &mut tx).await?;
+
+            let mut moved = 0;
+            for id in ids {
+                let Some(mut transaction) = Self::fetch_by_id(id, &mut tx).await? else {
+                    continue;
+                };
+                if transaction.category_id == Some(category_id) {
+                    continue;
+                }
+
+                transaction.category_id = Some(category_id);
+                transaction.update(scope, &mut *tx).await?;
+                moved += 1;
+            }
+
+            tx.commit().await?;
+
+            tracing::info!(
+                "Reassigned {} transactions to category {}",
+                moved,
+                category_id
+            );
+
+            Ok(moved)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::Result;
+
+    fn date(day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    async fn category(code: &str, pool: &sqlx::SqlitePool) -> Result<domain::RowID> {
+        let category = database::CategoriesBuilder::new()
+            .with_code(code)
+            .with_name(code)
+            .with_category_type(domain::CategoryTypes::Expense)
+            .build()?;
+
+        Ok(category.insert(pool).await?.id)
+    }
+
+    async fn insert(
+        day: u32,
+        payee: &str,
+        category_id: domain::RowID,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<domain::RowID> {
+        let mut transaction = database::Transactions::new(date(day), payee, -1_250);
+        transaction.category_id = Some(category_id);
+
+        Ok(transaction.insert(pool).await?.id)
+    }
+
+    async fn category_of(
+        id: domain::RowID,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<Option<domain::RowID>> {
+        let transaction =
+            database::Transactions::find_by_id(id, database::UserScope::All, pool).await?;
+
+        Ok(transaction.and_then(|transaction| transaction.category_id))
+    }
+
+    #[sqlx::test]
+    async fn reassign_moves_matching_transactions(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let groceries = category("GROCERIES", &pool).await?;
+        let dining = category("DINING", &pool).await?;
+        let cafe = insert(2, "Corner Cafe", groceries, &pool).await?;
+        let late_cafe = insert(20, "Corner Cafe", groceries, &pool).await?;
+        let market = insert(3, "Fresh Market", groceries, &pool).await?;
+        let already = insert(4, "Cafe 100% Organic", dining, &pool).await?;
+
+        let filter = TransactionFilter {
+            payee_pattern: Some("*CAFE*".to_string()),
+            to_date: Some(date(10)),
+            ..Default::default()
+        };
+        let moved =
+            database::Transactions::reassign(&filter, dining, database::UserScope::All, &pool)
+                .await?;
+
+        assert_eq!(moved, 1);
+        assert_eq!(category_of(cafe, &pool).await?, Some(dining));
+        assert_eq!(category_of(late_cafe, &pool).await?, Some(groceries));
+        assert_eq!(category_of(market, &pool).await?, Some(groceries));
+        assert_eq!(category_of(already, &pool).await?, Some(dining));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn reassign_is_all_or_nothing(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let groceries = category("GROCERIES", &pool).await?;
+        let dining = category("DINING", &pool).await?;
+        // The draft is moved first and could move on its own, the posted
+        // transaction after it is locked by the closed period
+        let mut draft = database::Transactions::new(date(1), "Corner Cafe", -1_250);
+        draft.category_id = Some(groceries);
+        draft.is_draft = true;
+        let draft = draft.insert(&pool).await?.id;
+        insert(2, "Corner Cafe", groceries, &pool).await?;
+        database::PeriodClosings::close(date(20), None, &pool).await?;
+
+        let filter = TransactionFilter {
+            category_id: Some(groceries),
+            ..Default::default()
+        };
+        let result =
+            database::Transactions::reassign(&filter, dining, database::UserScope::All, &pool)
+                .await;
+
+        assert!(matches!(result, Err(DatabaseError::PeriodClosed(_))));
+        assert_eq!(category_of(draft, &pool).await?, Some(groceries));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn reassign_rejects_empty_filter_and_unknown_category(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let groceries = category("GROCERIES", &pool).await?;
+        let filter = TransactionFilter {
+            category_id: Some(groceries),
+            ..Default::default()
+        };
+
+        let no_filter = database::Transactions::reassign(
+            &TransactionFilter::default(),
+            groceries,
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+        let unknown = database::Transactions::reassign(
+            &filter,
+            domain::RowID::new(),
+            database::UserScope::All,
+            &pool,
+        )
+        .await;
+
+        assert!(matches!(no_filter, Err(DatabaseError::Validation(_))));
+        assert!(matches!(unknown, Err(DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
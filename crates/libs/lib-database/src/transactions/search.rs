@@ -0,0 +1,194 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Transactions {
+    /// Searches transactions by payee, description and place name, best
+    /// match first.
+    ///
+    /// Matches in the payee rank above matches in the description, which
+    /// rank above matches in the place name. Equally good matches are
+    /// returned newest first. Drafts are included. See the
+    /// [`search`](crate::search) module for the search syntax, which supports
+    /// prefixes (`wool*`) and phrases (`"corner cafe"`).
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The search text
+    /// * `limit` - The most transactions to return, 20 if `None`
+    /// * `scope` - The user whose transactions are searched
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The search text has no words in it (`DatabaseError::Validation`)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Transactions, UserScope};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for transaction in Transactions::search(r#""corner cafe""#, Some(10), UserScope::All, pool).await? {
+    ///     println!("{} {}: {}", transaction.transaction_date, transaction.payee, transaction.amount);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Search transactions", skip(conn), err)]
+    pub fn search<'c, A>(
+        text: &'c str,
+        limit: Option<u32>,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let expression = database::search::match_expression(text)?;
+            let limit = limit.unwrap_or(database::search::DEFAULT_SEARCH_LIMIT);
+
+            let mut conn = conn.acquire().await?;
+
+            let user_id = scope.user_id();
+            let transactions = sqlx::query_as!(
+                database::Transactions,
+                r#"
+                    SELECT
+                        t.id                AS "id!: domain::RowID",
+                        t.transaction_date  AS "transaction_date!: chrono::NaiveDate",
+                        t.payee,
+                        t.description,
+                        t.amount            AS "amount!: i64",
+                        t.category_id       AS "category_id?: domain::RowID",
+                        t.account_id        AS "account_id?: domain::RowID",
+                        t.is_draft          AS "is_draft!: bool",
+                        t.latitude          AS "latitude?: f64",
+                        t.longitude         AS "longitude?: f64",
+                        t.place_name,
+                        t.return_by         AS "return_by?: chrono::NaiveDate",
+                        t.warranty_expires  AS "warranty_expires?: chrono::NaiveDate",
+                        t.cleared_status    AS "cleared_status!: database::ClearedStatus",
+                        t.user_id           AS "user_id?: domain::RowID",
+                        t.created_on        AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        t.updated_on        AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        t.version
+                    FROM transactions_fts f JOIN transactions t ON t.id = f.id
+                    WHERE transactions_fts MATCH ?1 AND (?3 IS NULL OR t.user_id = ?3)
+                    ORDER BY bm25(transactions_fts, 0.0, 10.0, 2.0, 1.0), t.transaction_date DESC
+                    LIMIT ?2
+                "#,
+                expression,
+                limit,
+                user_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("transactions.search")
+            .await?;
+
+            tracing::debug!(
+                "Found {} transactions matching '{}'",
+                transactions.len(),
+                text
+            );
+
+            Ok(transactions)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::Result;
+
+    fn date(day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    fn payees(transactions: &[database::Transactions]) -> Vec<&str> {
+        transactions
+            .iter()
+            .map(|transaction| transaction.payee.as_str())
+            .collect()
+    }
+
+    #[sqlx::test]
+    async fn search_matches_payee_description_and_place(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        database::Transactions::new(date(1), "Woolworths", -8_450)
+            .insert(&pool)
+            .await?;
+        let mut lunch = database::Transactions::new(date(2), "Corner Cafe", -1_850);
+        lunch.description = Some("Lunch with Woolworths team".to_string());
+        lunch.insert(&pool).await?;
+        let mut fuel = database::Transactions::new(date(3), "Ampol", -6_000);
+        fuel.place_name = Some("Corner of Main Street".to_string());
+        fuel.insert(&pool).await?;
+
+        let ranked =
+            database::Transactions::search("woolworths", None, database::UserScope::All, &pool)
+                .await?;
+        let prefix =
+            database::Transactions::search("wool*", Some(1), database::UserScope::All, &pool)
+                .await?;
+        let phrase = database::Transactions::search(
+            r#""corner cafe""#,
+            None,
+            database::UserScope::All,
+            &pool,
+        )
+        .await?;
+        let newest_first =
+            database::Transactions::search("corner", None, database::UserScope::All, &pool).await?;
+        let no_words =
+            database::Transactions::search(r#""  ""#, None, database::UserScope::All, &pool).await;
+
+        assert_eq!(payees(&ranked), ["Woolworths", "Corner Cafe"]);
+        assert_eq!(payees(&prefix), ["Woolworths"]);
+        assert_eq!(payees(&phrase), ["Corner Cafe"]);
+        assert_eq!(payees(&newest_first), ["Corner Cafe", "Ampol"]);
+        assert!(matches!(
+            no_words,
+            Err(database::DatabaseError::Validation(_))
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn search_follows_updates_and_deletes(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut transaction = database::Transactions::new(date(1), "Coles", -2_000)
+            .insert(&pool)
+            .await?;
+        transaction.payee = "Aldi".to_string();
+        let transaction = transaction.update(database::UserScope::All, &pool).await?;
+
+        assert!(
+            database::Transactions::search("coles", None, database::UserScope::All, &pool)
+                .await?
+                .is_empty()
+        );
+        assert_eq!(
+            payees(
+                &database::Transactions::search("aldi", None, database::UserScope::All, &pool)
+                    .await?
+            ),
+            ["Aldi"]
+        );
+
+        database::Transactions::delete_by_id(transaction.id, database::UserScope::All, &pool)
+            .await?;
+        assert!(
+            database::Transactions::search("aldi", None, database::UserScope::All, &pool)
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,412 @@
+use std::collections::BTreeMap;
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// One part of a split transaction, classifying part of its amount under a
+/// category.
+///
+/// Amounts are signed minor units (cents) with the same sign convention as
+/// the transaction, so the splits of a purchase are negative.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct TransactionSplits {
+    pub id: domain::RowID,
+    pub transaction_id: domain::RowID,
+    pub category_id: domain::RowID,
+    pub amount: i64,
+    pub memo: Option<String>,
+}
+
+impl database::TransactionSplits {
+    /// Creates a new, unsaved split of a transaction.
+    pub fn new(
+        transaction_id: domain::RowID,
+        category_id: domain::RowID,
+        amount: i64,
+        memo: Option<String>,
+    ) -> Self {
+        Self {
+            id: domain::RowID::new(),
+            transaction_id,
+            category_id,
+            amount,
+            memo,
+        }
+    }
+
+    /// Checks that `splits` split `transaction`. No splits at all is an
+    /// unsplit transaction and always valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if:
+    /// - There is only one split
+    /// - Any split has a zero amount, or belongs to another transaction
+    /// - The split amounts overflow or do not sum to the transaction amount
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{TransactionSplits, Transactions};
+    /// use lib_domain::RowID;
+    ///
+    /// let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// let receipt = Transactions::new(date, "Supermarket", -12_000);
+    /// let splits = [
+    ///     TransactionSplits::new(receipt.id, RowID::new(), -9_000, Some("Groceries".to_string())),
+    ///     TransactionSplits::new(receipt.id, RowID::new(), -3_000, Some("Light globes".to_string())),
+    /// ];
+    ///
+    /// assert!(TransactionSplits::validate(&receipt, &splits).is_ok());
+    /// assert!(TransactionSplits::validate(&receipt, &splits[..1]).is_err());
+    /// ```
+    pub fn validate(transaction: &database::Transactions, splits: &[Self]) -> DatabaseResult<()> {
+        if splits.is_empty() {
+            return Ok(());
+        }
+
+        if splits.len() < 2 {
+            return Err(DatabaseError::Validation(format!(
+                "Transaction {} must be split at least two ways, found one split",
+                transaction.id
+            )));
+        }
+
+        if let Some(split) = splits.iter().find(|split| split.amount == 0) {
+            return Err(DatabaseError::Validation(format!(
+                "Transaction split {} has a zero amount",
+                split.id
+            )));
+        }
+
+        if let Some(split) = splits
+            .iter()
+            .find(|split| split.transaction_id != transaction.id)
+        {
+            return Err(DatabaseError::Validation(format!(
+                "Transaction split {} belongs to transaction {}, not {}",
+                split.id, split.transaction_id, transaction.id
+            )));
+        }
+
+        match splits
+            .iter()
+            .try_fold(0_i64, |total, split| total.checked_add(split.amount))
+        {
+            Some(total) if total == transaction.amount => Ok(()),
+            Some(total) => Err(DatabaseError::Validation(format!(
+                "Transaction {} splits sum to {} instead of its amount {}",
+                transaction.id, total, transaction.amount
+            ))),
+            None => Err(DatabaseError::Validation(format!(
+                "Transaction {} split amounts overflow",
+                transaction.id
+            ))),
+        }
+    }
+
+    /// Replaces the splits of a transaction, in the order given. An empty
+    /// set makes the transaction unsplit.
+    ///
+    /// The splits are checked against the transaction as passed in, so call
+    /// this after writing a change to its amount, in the same database
+    /// transaction, for the two to be checked together.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction` - The transaction to split
+    /// * `splits` - Every split the transaction should have
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction's splits as written.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The splits do not split the transaction (`DatabaseError::Validation`)
+    /// - The transaction or a category does not exist (foreign key violation)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Set splits of transaction",
+        skip(transaction, splits, conn),
+        fields(transaction_id = %transaction.id, count = splits.len()),
+        err
+    )]
+    pub fn set_for_transaction<'a, 'c, A>(
+        transaction: &'a database::Transactions,
+        splits: &'a [Self],
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            Self::validate(transaction, splits)?;
+
+            let mut tx = conn.begin().await?;
+
+            sqlx::query!(
+                r#"
+                    DELETE FROM transaction_splits
+                    WHERE transaction_id = ?
+                "#,
+                transaction.id
+            )
+            .execute(&mut *tx)
+            .observe("transaction_splits.set_for_transaction")
+            .await?;
+
+            for (position, split) in (0_i64..).zip(splits) {
+                sqlx::query!(
+                    r#"
+                        INSERT INTO transaction_splits (id, transaction_id, category_id, amount, memo, position)
+                        VALUES (?, ?, ?, ?, ?, ?)
+                    "#,
+                    split.id,
+                    split.transaction_id,
+                    split.category_id,
+                    split.amount,
+                    split.memo,
+                    position
+                )
+                .execute(&mut *tx)
+                .observe("transaction_splits.set_for_transaction")
+                .await?;
+            }
+
+            tx.commit().await?;
+
+            Ok(splits.to_vec())
+        }
+    }
+
+    /// Finds the splits of a transaction, in order. An unsplit transaction
+    /// has none.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - The transaction whose splits to find
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(
+        name = "Find splits of transaction",
+        skip(conn),
+        fields(transaction_id = %transaction_id),
+        err
+    )]
+    pub fn find_for_transaction<'c, A>(
+        transaction_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let splits = sqlx::query_as!(
+                database::TransactionSplits,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        transaction_id      AS "transaction_id!: domain::RowID",
+                        category_id         AS "category_id!: domain::RowID",
+                        amount              AS "amount!: i64",
+                        memo
+                    FROM transaction_splits
+                    WHERE transaction_id = ?
+                    ORDER BY position
+                "#,
+                transaction_id
+            )
+            .fetch_all(&mut *conn)
+            .observe("transaction_splits.find_for_transaction")
+            .await?;
+
+            Ok(splits)
+        }
+    }
+
+    /// Finds the splits of many transactions in one query, keyed by
+    /// transaction. Unsplit transactions are left out of the map.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_ids` - The transactions whose splits to find
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(
+        name = "Find splits of transactions",
+        skip(transaction_ids, conn),
+        fields(count = transaction_ids.len()),
+        err
+    )]
+    pub fn find_for_transactions<'a, 'c, A>(
+        transaction_ids: &'a [domain::RowID],
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<BTreeMap<domain::RowID, Vec<Self>>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            if transaction_ids.is_empty() {
+                return Ok(BTreeMap::new());
+            }
+
+            let mut conn = conn.acquire().await?;
+
+            // The ids are passed as one JSON array, as SQLite cannot bind a list
+            let ids = serde_json::to_string(
+                &transaction_ids
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+            )
+            .map_err(|e| DatabaseError::Other(format!("Transaction ids cannot be written: {e}")))?;
+            let rows = sqlx::query_as!(
+                database::TransactionSplits,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        transaction_id      AS "transaction_id!: domain::RowID",
+                        category_id         AS "category_id!: domain::RowID",
+                        amount              AS "amount!: i64",
+                        memo
+                    FROM transaction_splits
+                    WHERE transaction_id IN (SELECT value FROM json_each(?))
+                    ORDER BY transaction_id, position
+                "#,
+                ids
+            )
+            .fetch_all(&mut *conn)
+            .observe("transaction_splits.find_for_transactions")
+            .await?;
+
+            let mut splits: BTreeMap<domain::RowID, Vec<Self>> = BTreeMap::new();
+            for split in rows {
+                splits.entry(split.transaction_id).or_default().push(split);
+            }
+
+            Ok(splits)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn receipt(
+        pool: &sqlx::SqlitePool,
+    ) -> Result<(database::Transactions, [domain::RowID; 2])> {
+        // Mock names and slugs are a word or two, so fix them to avoid collisions
+        let mut ids = [domain::RowID::new(); 2];
+        for (id, name) in ids.iter_mut().zip(["Groceries", "Homewares"]) {
+            let mut category = database::Categories::mock();
+            category.name = name.to_string();
+            category.url_slug = None;
+            *id = category.insert(pool).await?.id;
+        }
+
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        let transaction = database::Transactions::new(date, "Supermarket", -12_000)
+            .insert(pool)
+            .await?;
+
+        Ok((transaction, ids))
+    }
+
+    #[test]
+    fn validate_rejects_splits_that_do_not_sum_to_the_amount() {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        let transaction = database::Transactions::new(date, "Supermarket", -12_000);
+        let split = |amount| {
+            database::TransactionSplits::new(transaction.id, domain::RowID::new(), amount, None)
+        };
+
+        assert!(database::TransactionSplits::validate(&transaction, &[]).is_ok());
+        for splits in [
+            vec![split(-9_000), split(-2_000)],
+            vec![split(-12_000), split(0)],
+            vec![split(-12_000)],
+            vec![split(i64::MIN), split(-1)],
+        ] {
+            let result = database::TransactionSplits::validate(&transaction, &splits);
+
+            assert!(
+                matches!(result, Err(DatabaseError::Validation(_))),
+                "{splits:?}"
+            );
+        }
+
+        let other = database::TransactionSplits::new(
+            domain::RowID::new(),
+            domain::RowID::new(),
+            -3_000,
+            None,
+        );
+        let result = database::TransactionSplits::validate(&transaction, &[split(-9_000), other]);
+        assert!(matches!(result, Err(DatabaseError::Validation(_))));
+    }
+
+    #[sqlx::test]
+    async fn set_for_transaction_replaces_splits_in_order(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let (transaction, [groceries, homewares]) = receipt(&pool).await?;
+        let splits = [
+            database::TransactionSplits::new(
+                transaction.id,
+                homewares,
+                -3_000,
+                Some("Light globes".to_string()),
+            ),
+            database::TransactionSplits::new(transaction.id, groceries, -9_000, None),
+        ];
+
+        database::TransactionSplits::set_for_transaction(&transaction, &splits, &pool).await?;
+        assert_eq!(
+            database::TransactionSplits::find_for_transaction(transaction.id, &pool).await?,
+            splits
+        );
+
+        let found =
+            database::TransactionSplits::find_for_transactions(&[transaction.id], &pool).await?;
+        assert_eq!(found[&transaction.id], splits);
+
+        database::TransactionSplits::set_for_transaction(&transaction, &[], &pool).await?;
+        assert!(
+            database::TransactionSplits::find_for_transaction(transaction.id, &pool)
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn splits_go_with_their_transaction(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let (transaction, [groceries, homewares]) = receipt(&pool).await?;
+        let splits = [
+            database::TransactionSplits::new(transaction.id, groceries, -9_000, None),
+            database::TransactionSplits::new(transaction.id, homewares, -3_000, None),
+        ];
+        database::TransactionSplits::set_for_transaction(&transaction, &splits, &pool).await?;
+
+        database::Transactions::delete_by_id(transaction.id, database::UserScope::All, &pool)
+            .await?;
+
+        assert!(
+            database::TransactionSplits::find_for_transaction(transaction.id, &pool)
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+//! # Category Suggestions
+//!
+//! Suggests a category for a new transaction from its payee. The category most
+//! often used for the payee before is suggested first. A payee that has never
+//! been categorised falls back to the category hint of its merchant, if a
+//! merchant provider has enriched the payee.
+
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+/// Where a category suggestion came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum SuggestionSources {
+    /// Earlier transactions with the same payee.
+    History,
+    /// The category hint of the payee's merchant.
+    Merchant,
+}
+
+/// A suggested category for a transaction.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CategorySuggestion {
+    pub category_id: domain::RowID,
+    pub source: SuggestionSources,
+}
+
+impl database::Transactions {
+    /// Suggests a category for a transaction with the given payee.
+    ///
+    /// # Arguments
+    ///
+    /// * `payee` - The payee, matched ignoring case
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the category most used for the payee before, ties going to the
+    /// most recently used, or else the active category whose code matches the
+    /// payee's merchant category hint. Soft-deleted categories are never
+    /// suggested. Returns `None` if neither is known.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Transactions;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// if let Some(suggestion) = Transactions::suggest_category("Corner Cafe", pool).await? {
+    ///     println!("Suggested {} from {:?}", suggestion.category_id, suggestion.source);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Suggest transaction category", skip(conn), err)]
+    pub fn suggest_category<'a, 'c, A>(
+        payee: &'a str,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<CategorySuggestion>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let payee = payee.trim();
+
+            let history = sqlx::query_scalar!(
+                r#"
+                    SELECT category_id AS "category_id!: domain::RowID"
+                    FROM transactions
+                    WHERE payee = ? COLLATE NOCASE AND category_id IS NOT NULL
+                        AND category_id NOT IN (SELECT id FROM categories WHERE deleted_on IS NOT NULL)
+                    GROUP BY category_id
+                    ORDER BY COUNT(*) DESC, MAX(transaction_date) DESC
+                    LIMIT 1
+                "#,
+                payee
+            )
+            .fetch_optional(&mut *conn)
+            .observe("transactions.suggest_category")
+            .await?;
+
+            if let Some(category_id) = history {
+                return Ok(Some(CategorySuggestion {
+                    category_id,
+                    source: SuggestionSources::History,
+                }));
+            }
+
+            let merchant = sqlx::query_scalar!(
+                r#"
+                    SELECT categories.id AS "id!: domain::RowID"
+                    FROM merchants
+                    JOIN categories ON categories.code = merchants.category_hint
+                    WHERE merchants.payee = ? AND categories.is_active = 1
+                        AND categories.deleted_on IS NULL
+                "#,
+                payee
+            )
+            .fetch_optional(&mut *conn)
+            .observe("transactions.suggest_category")
+            .await?;
+
+            Ok(merchant.map(|category_id| CategorySuggestion {
+                category_id,
+                source: SuggestionSources::Merchant,
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn history_wins_over_merchant_hint(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut shopping = database::Categories::mock();
+        shopping.code = "SHOP".to_string();
+        shopping.is_active = true;
+        let shopping = shopping.insert(&pool).await?;
+        let books = database::Categories::mock().insert(&pool).await?;
+
+        let dataset = database::LocalMerchantDataset::new(vec![(
+            "amazon".to_string(),
+            database::MerchantInfo {
+                name: "Amazon".to_string(),
+                category_hint: Some("SHOP".to_string()),
+                logo_url: None,
+            },
+        )]);
+        database::Merchants::enrich(&["Amazon".to_string()], &dataset, &pool).await?;
+
+        let from_merchant = database::Transactions::suggest_category("AMAZON", &pool).await?;
+        assert_eq!(
+            from_merchant,
+            Some(CategorySuggestion {
+                category_id: shopping.id,
+                source: SuggestionSources::Merchant
+            })
+        );
+
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        let mut transaction = database::Transactions::new(date, "Amazon", -2_999);
+        transaction.category_id = Some(books.id);
+        transaction.insert(&pool).await?;
+
+        let from_history = database::Transactions::suggest_category("amazon", &pool).await?;
+        assert_eq!(
+            from_history,
+            Some(CategorySuggestion {
+                category_id: books.id,
+                source: SuggestionSources::History
+            })
+        );
+        assert_eq!(
+            database::Transactions::suggest_category("Corner Cafe", &pool).await?,
+            None
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,383 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// A transfer of money between two accounts, recorded as a linked pair of
+/// transactions: one taking the amount out of the from account, with a
+/// negative amount, and one paying it into the to account.
+///
+/// The amount, date, description and draft flag of the pair are kept the
+/// same by [`Transactions::update`](database::Transactions::update) when
+/// either is updated, and
+/// [`Transactions::delete_by_id`](database::Transactions::delete_by_id)
+/// deletes both.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct Transfers {
+    pub id: domain::RowID,
+    pub from_transaction_id: domain::RowID,
+    pub to_transaction_id: domain::RowID,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::Transfers {
+    /// Returns the other transaction of the transfer, given either of its
+    /// transactions.
+    pub fn counterpart_of(&self, transaction_id: domain::RowID) -> domain::RowID {
+        if transaction_id == self.from_transaction_id {
+            self.to_transaction_id
+        } else {
+            self.from_transaction_id
+        }
+    }
+
+    /// Transfers `amount` from one account to another on `transaction_date`,
+    /// inserting a transaction in each account and the transfer linking
+    /// them, all or nothing.
+    ///
+    /// Each transaction is named after the other account, such as "Transfer
+    /// to Savings", and left uncategorised. Both are posted, not drafts.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_date` - The date the money moves
+    /// * `amount` - The amount moved, in minor units (cents), greater than zero
+    /// * `from_account_id` - The account the money leaves
+    /// * `to_account_id` - The account the money arrives in
+    /// * `description` - Optional markdown description given to both transactions
+    /// * `user_id` - The user who owns both transactions, `None` for no owner
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the transfer with its from and to transactions, as stored.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The amount is not greater than zero (`DatabaseError::Validation`)
+    /// - The from and to accounts are the same (`DatabaseError::Validation`)
+    /// - Either account does not exist or is not owned by `user_id`
+    ///   (`DatabaseError::NotFound`)
+    /// - The date is in a closed period (`DatabaseError::PeriodClosed`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Create transfer in database",
+        skip(description, conn),
+        fields(
+            transaction_date = %transaction_date,
+            amount = amount,
+            from_account_id = %from_account_id,
+            to_account_id = %to_account_id,
+        ),
+        err
+    )]
+    pub fn create<'c, A>(
+        transaction_date: chrono::NaiveDate,
+        amount: i64,
+        from_account_id: domain::RowID,
+        to_account_id: domain::RowID,
+        description: Option<String>,
+        user_id: Option<domain::RowID>,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<(Self, database::Transactions, database::Transactions)>>
+    + Send
+    + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            if amount <= 0 {
+                return Err(DatabaseError::Validation(format!(
+                    "Transfer amount must be greater than zero, found {amount}"
+                )));
+            }
+            if from_account_id == to_account_id {
+                return Err(DatabaseError::Validation(format!(
+                    "Cannot transfer from account {from_account_id} to itself"
+                )));
+            }
+
+            let mut tx = conn.begin().await?;
+
+            // Both accounts must be the owner's own
+            let scope = user_id.map_or(database::UserScope::All, database::UserScope::User);
+            let not_found = |id| DatabaseError::NotFound(format!("Account with id {id} not found"));
+            let from_account = database::Accounts::find_by_id(from_account_id, scope, &mut *tx)
+                .await?
+                .ok_or_else(|| not_found(from_account_id))?;
+            let to_account = database::Accounts::find_by_id(to_account_id, scope, &mut *tx)
+                .await?
+                .ok_or_else(|| not_found(to_account_id))?;
+
+            let mut from = database::Transactions::new(
+                transaction_date,
+                format!("Transfer to {}", to_account.name),
+                -amount,
+            );
+            from.account_id = Some(from_account.id);
+            from.description = description.clone();
+            from.user_id = user_id;
+            let from = from.insert(&mut *tx).await?;
+
+            let mut to = database::Transactions::new(
+                transaction_date,
+                format!("Transfer from {}", from_account.name),
+                amount,
+            );
+            to.account_id = Some(to_account.id);
+            to.description = description;
+            to.user_id = user_id;
+            let to = to.insert(&mut *tx).await?;
+
+            let transfer = Self {
+                id: domain::RowID::new(),
+                from_transaction_id: from.id,
+                to_transaction_id: to.id,
+                created_on: chrono::Utc::now(),
+            };
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO transfers (id, from_transaction_id, to_transaction_id, created_on)
+                    VALUES (?, ?, ?, ?)
+                "#,
+                transfer.id,
+                transfer.from_transaction_id,
+                transfer.to_transaction_id,
+                transfer.created_on
+            )
+            .execute(&mut *tx)
+            .observe("transfers.create")
+            .await?;
+
+            tx.commit().await?;
+
+            tracing::info!("Transfer created in the database.");
+
+            Ok((transfer, from, to))
+        }
+    }
+
+    /// Finds the transfer a transaction is part of, or `None` if the
+    /// transaction is not a transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - Either transaction of the transfer
+    /// * `conn` - The database pool, connection or transaction to run on
+    #[tracing::instrument(
+        name = "Find transfer by transaction",
+        skip(conn),
+        fields(transaction_id = %transaction_id),
+        err
+    )]
+    pub fn find_by_transaction<'c, A>(
+        transaction_id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let transfer = sqlx::query_as!(
+                database::Transfers,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        from_transaction_id AS "from_transaction_id!: domain::RowID",
+                        to_transaction_id   AS "to_transaction_id!: domain::RowID",
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>"
+                    FROM transfers
+                    WHERE from_transaction_id = ?1 OR to_transaction_id = ?1
+                "#,
+                transaction_id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("transfers.find_by_transaction")
+            .await?;
+
+            Ok(transfer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn date(day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    async fn accounts(pool: &sqlx::SqlitePool) -> Result<(database::Accounts, database::Accounts)> {
+        let everyday = database::Accounts::new("Everyday").insert(pool).await?;
+        let savings = database::Accounts::new("Savings").insert(pool).await?;
+
+        Ok((everyday, savings))
+    }
+
+    #[sqlx::test]
+    async fn create_links_a_transaction_in_each_account(pool: sqlx::SqlitePool) -> Result<()> {
+        let (everyday, savings) = accounts(&pool).await?;
+
+        let (transfer, from, to) = database::Transfers::create(
+            date(14),
+            50_000,
+            everyday.id,
+            savings.id,
+            None,
+            None,
+            &pool,
+        )
+        .await?;
+
+        assert_eq!((from.amount, from.account_id), (-50_000, Some(everyday.id)));
+        assert_eq!((to.amount, to.account_id), (50_000, Some(savings.id)));
+        assert_eq!(from.payee, "Transfer to Savings");
+        assert_eq!(to.payee, "Transfer from Everyday");
+        assert_eq!(transfer.counterpart_of(from.id), to.id);
+        assert_eq!(
+            database::Transfers::find_by_transaction(to.id, &pool).await?,
+            Some(transfer)
+        );
+        assert_eq!(
+            database::Accounts::balance(everyday.id, None, &pool).await?,
+            -50_000
+        );
+        assert_eq!(
+            database::Accounts::balance(savings.id, None, &pool).await?,
+            50_000
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn create_rejects_invalid_transfers(pool: sqlx::SqlitePool) -> Result<()> {
+        let (everyday, savings) = accounts(&pool).await?;
+
+        let zero =
+            database::Transfers::create(date(14), 0, everyday.id, savings.id, None, None, &pool)
+                .await;
+        let same =
+            database::Transfers::create(date(14), 500, everyday.id, everyday.id, None, None, &pool)
+                .await;
+        let missing = database::Transfers::create(
+            date(14),
+            500,
+            everyday.id,
+            domain::RowID::new(),
+            None,
+            None,
+            &pool,
+        )
+        .await;
+
+        assert!(matches!(zero, Err(DatabaseError::Validation(_))));
+        assert!(matches!(same, Err(DatabaseError::Validation(_))));
+        assert!(matches!(missing, Err(DatabaseError::NotFound(_))));
+
+        let count = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!: i64" FROM transactions"#)
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(count, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_and_delete_keep_both_transactions_together(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let (everyday, savings) = accounts(&pool).await?;
+        let (_, mut from, to) = database::Transfers::create(
+            date(14),
+            50_000,
+            everyday.id,
+            savings.id,
+            None,
+            None,
+            &pool,
+        )
+        .await?;
+
+        from.amount = -60_000;
+        from.transaction_date = date(15);
+        let from = from.update(database::UserScope::All, &pool).await?;
+        let synced = database::Transactions::find_by_id(to.id, database::UserScope::All, &pool)
+            .await?
+            .unwrap();
+
+        assert_eq!((synced.amount, synced.transaction_date), (60_000, date(15)));
+        assert_eq!(synced.version, to.version + 1);
+
+        // Updating the counterpart to match changes nothing more
+        synced.update(database::UserScope::All, &pool).await?;
+        assert_eq!(
+            database::Transactions::find_by_id(from.id, database::UserScope::All, &pool).await?,
+            Some(from.clone())
+        );
+
+        let unlinked = database::Transactions::new(date(14), "Corner Cafe", -1_250)
+            .insert(&pool)
+            .await?;
+
+        database::Transactions::delete_by_id(to.id, database::UserScope::All, &pool).await?;
+
+        assert!(
+            database::Transactions::find_by_id(from.id, database::UserScope::All, &pool)
+                .await?
+                .is_none()
+        );
+        assert!(
+            database::Transfers::find_by_transaction(from.id, &pool)
+                .await?
+                .is_none()
+        );
+        assert!(
+            database::Transactions::find_by_id(unlinked.id, database::UserScope::All, &pool)
+                .await?
+                .is_some()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_leaves_the_transfer_unchanged_when_the_counterpart_cannot_be(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let (everyday, savings) = accounts(&pool).await?;
+        let (_, mut from, to) = database::Transfers::create(
+            date(14),
+            50_000,
+            everyday.id,
+            savings.id,
+            None,
+            None,
+            &pool,
+        )
+        .await?;
+        sqlx::query!(
+            "UPDATE transactions SET cleared_status = 'reconciled' WHERE id = ?",
+            to.id
+        )
+        .execute(&pool)
+        .await?;
+
+        from.amount = -60_000;
+        let result = from.update(database::UserScope::All, &pool).await;
+
+        assert!(matches!(result, Err(DatabaseError::Validation(_))));
+        let from = database::Transactions::find_by_id(from.id, database::UserScope::All, &pool)
+            .await?
+            .unwrap();
+        assert_eq!(from.amount, -50_000);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,336 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Transactions {
+    /// Updates an existing transaction in the database.
+    ///
+    /// All editable fields of the transaction identified by `id` are replaced
+    /// and the `updated_on` timestamp is set to now. The `created_on`
+    /// timestamp, the owner and the cleared status are left unchanged.
+    ///
+    /// The update only applies if the transaction is still at the `version`
+    /// it was read at, and the version is then incremented, so a client
+    /// cannot overwrite a change it has not seen.
+    ///
+    /// If the transaction is one of a [`Transfers`](database::Transfers)
+    /// pair, the amount, negated, and the date, description and draft flag
+    /// are copied to the other transaction of the pair, all or nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The user whose transactions are updated
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated transaction as read back from the database, or a
+    /// `DatabaseError` if the update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transaction does not exist or is outside `scope` (`DatabaseError::NotFound`)
+    /// - The transaction was changed since it was read, as its `version` is
+    ///   not the stored one (`DatabaseError::Conflict`)
+    /// - The payee is empty or the amount is zero (`DatabaseError::Validation`)
+    /// - The transaction is reconciled and its amount, date, account or draft
+    ///   flag would change (`DatabaseError::Validation`)
+    /// - The current or new date is in a closed period (`DatabaseError::PeriodClosed`)
+    /// - The category or account does not exist (foreign key violation)
+    /// - The other transaction of its transfer cannot be updated for any of
+    ///   the reasons above
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{Transactions, UserScope};
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, transaction_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut transaction = Transactions::find_by_id(transaction_id, UserScope::All, pool).await?.unwrap();
+    /// transaction.amount = -1_450;
+    ///
+    /// let updated = transaction.update(UserScope::All, pool).await?;
+    /// assert_eq!(updated.amount, -1_450);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Update transaction in database",
+        skip(self, conn),
+        fields(
+            id = %self.id,
+            transaction_date = %self.transaction_date,
+            amount = self.amount,
+        ),
+        err
+    )]
+    pub fn update<'a, 'c, A>(
+        &'a self,
+        scope: database::UserScope,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut tx = conn.begin().await?;
+
+            let updated = self.update_row(scope, &mut tx).await?;
+            if let Some(transfer) =
+                database::Transfers::find_by_transaction(self.id, &mut *tx).await?
+            {
+                sync_counterpart(&updated, transfer.counterpart_of(self.id), scope, &mut tx)
+                    .await?;
+            }
+
+            tx.commit().await?;
+
+            Ok(updated)
+        }
+    }
+
+    /// Updates this transaction alone, for [`update`](Self::update).
+    async fn update_row(
+        &self,
+        scope: database::UserScope,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Self> {
+        self.validate()?;
+
+        let updated_on = chrono::Utc::now();
+
+        // Neither the current nor the new date may be in a closed period,
+        // unless the transaction was or stays a draft
+        let existing = Self::fetch_by_id(self.id, &mut *conn)
+            .await?
+            .filter(|existing| scope.includes(existing.user_id))
+            .ok_or_else(|| {
+                database::DatabaseError::NotFound(format!(
+                    "Transaction with id {} not found",
+                    self.id
+                ))
+            })?;
+        if existing.version != self.version {
+            return Err(conflict(self, existing.version));
+        }
+        if existing.cleared_status == database::ClearedStatus::Reconciled
+            && (
+                existing.amount,
+                existing.transaction_date,
+                existing.account_id,
+                existing.is_draft,
+            ) != (
+                self.amount,
+                self.transaction_date,
+                self.account_id,
+                self.is_draft,
+            )
+        {
+            return Err(database::DatabaseError::Validation(format!(
+                "Transaction {} is reconciled, undo its reconciliation to change its amount, date or account",
+                self.id
+            )));
+        }
+        if !existing.is_draft {
+            database::PeriodClosings::ensure_open(existing.transaction_date, &mut *conn).await?;
+        }
+        if !self.is_draft {
+            database::PeriodClosings::ensure_open(self.transaction_date, &mut *conn).await?;
+        }
+
+        let result = sqlx::query!(
+            r#"
+                UPDATE transactions
+                SET transaction_date = ?, payee = ?, description = ?, amount = ?,
+                    category_id = ?, account_id = ?, is_draft = ?, latitude = ?,
+                    longitude = ?, place_name = ?, return_by = ?, warranty_expires = ?,
+                    updated_on = ?, version = version + 1
+                WHERE id = ? AND version = ?
+            "#,
+            self.transaction_date,
+            self.payee,
+            self.description,
+            self.amount,
+            self.category_id,
+            self.account_id,
+            self.is_draft,
+            self.latitude,
+            self.longitude,
+            self.place_name,
+            self.return_by,
+            self.warranty_expires,
+            updated_on,
+            self.id,
+            self.version
+        )
+        .execute(&mut *conn)
+        .observe("transactions.update")
+        .await?;
+
+        // Changed by another connection since it was read above
+        if result.rows_affected() == 0 {
+            let current = Self::fetch_by_id(self.id, &mut *conn)
+                .await?
+                .map_or(self.version, |row| row.version);
+            return Err(conflict(self, current));
+        }
+
+        let updated = Self::fetch_by_id(self.id, &mut *conn)
+            .await?
+            .ok_or_else(|| {
+                database::DatabaseError::NotFound(format!(
+                    "Transaction with id {} not found after update",
+                    self.id
+                ))
+            })?;
+
+        tracing::info!("Transaction updated in the database.");
+
+        database::events::publish(database::DataChanges::TransactionUpdated(updated.clone()));
+
+        Ok(updated)
+    }
+}
+
+/// Copies the amount, negated, and the date, description and draft flag of
+/// an updated transfer transaction to the other transaction of its transfer,
+/// unless they already match.
+///
+/// The other transaction is updated at its stored version, and checked as
+/// any update is, so a reconciled one or one in a closed period is not
+/// changed.
+async fn sync_counterpart(
+    transaction: &database::Transactions,
+    counterpart_id: domain::RowID,
+    scope: database::UserScope,
+    conn: &mut sqlx::SqliteConnection,
+) -> DatabaseResult<()> {
+    let mut counterpart = database::Transactions::fetch_by_id(counterpart_id, conn)
+        .await?
+        .filter(|counterpart| scope.includes(counterpart.user_id))
+        .ok_or_else(|| {
+            database::DatabaseError::NotFound(format!(
+                "Transaction with id {counterpart_id} not found"
+            ))
+        })?;
+
+    let amount = transaction.amount.checked_neg().ok_or_else(|| {
+        database::DatabaseError::Validation(format!(
+            "Transfer amount {} cannot be negated",
+            transaction.amount
+        ))
+    })?;
+    if (
+        counterpart.amount,
+        counterpart.transaction_date,
+        &counterpart.description,
+        counterpart.is_draft,
+    ) == (
+        amount,
+        transaction.transaction_date,
+        &transaction.description,
+        transaction.is_draft,
+    ) {
+        return Ok(());
+    }
+
+    counterpart.amount = amount;
+    counterpart.transaction_date = transaction.transaction_date;
+    counterpart.description = transaction.description.clone();
+    counterpart.is_draft = transaction.is_draft;
+    counterpart.update_row(scope, conn).await?;
+
+    Ok(())
+}
+
+/// The error for an update of a transaction changed since it was read.
+fn conflict(transaction: &database::Transactions, current: i64) -> database::DatabaseError {
+    database::DatabaseError::Conflict(format!(
+        "Transaction {} was changed since it was read, it is at version {current}, not {}",
+        transaction.id, transaction.version
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::insert::tests::Result;
+
+    #[sqlx::test]
+    async fn update_transaction_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        let inserted = database::Transactions::mock().insert(&pool).await?;
+
+        let mut transaction = inserted.clone();
+        transaction.payee = "Corner Cafe".to_string();
+        transaction.amount = -1_450;
+        transaction.category_id = Some(category.id);
+
+        let updated = transaction.update(database::UserScope::All, &pool).await?;
+
+        assert_eq!(updated.payee, "Corner Cafe");
+        assert_eq!(updated.amount, -1_450);
+        assert_eq!(updated.category_id, Some(category.id));
+        assert_eq!(updated.created_on, inserted.created_on);
+        assert!(updated.updated_on >= inserted.updated_on);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_with_unknown_category_leaves_transaction_unchanged(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let inserted = database::Transactions::mock().insert(&pool).await?;
+
+        let mut transaction = inserted.clone();
+        transaction.category_id = Some(domain::RowID::new());
+
+        let result = transaction.update(database::UserScope::All, &pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+        let stored =
+            database::Transactions::find_by_id(inserted.id, database::UserScope::All, &pool)
+                .await?
+                .expect("transaction should still exist");
+        assert_eq!(stored, inserted);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_of_a_stale_version_conflicts(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Transactions::mock().insert(&pool).await?;
+        let mut first = inserted.clone();
+        first.amount = -1_450;
+        let mut second = inserted.clone();
+        second.amount = -2_000;
+
+        let updated = first.update(database::UserScope::All, &pool).await?;
+        let result = second.update(database::UserScope::All, &pool).await;
+
+        assert_eq!(updated.version, inserted.version + 1);
+        assert!(matches!(result, Err(database::DatabaseError::Conflict(_))));
+        let stored =
+            database::Transactions::find_by_id(inserted.id, database::UserScope::All, &pool)
+                .await?;
+        assert_eq!(stored.map(|transaction| transaction.amount), Some(-1_450));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let result = database::Transactions::mock()
+            .update(database::UserScope::All, &pool)
+            .await;
+
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
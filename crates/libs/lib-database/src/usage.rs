@@ -0,0 +1,116 @@
+//! # Usage Module
+//!
+//! Counts what a ledger holds, for the server to report with `GetUsage` and
+//! check against its configured quotas before adding more:
+//!
+//! - transactions, drafts included
+//! - categories that have not been soft-deleted, as deleted ones are out of
+//!   the way until they are restored
+//! - bytes of receipt attachments stored, from receipt emails and attached
+//!   to transactions, shared content counted once
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_database::Usage;
+//!
+//! # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+//! let usage = Usage::find(pool).await?;
+//! println!("{} transactions in {} categories", usage.transactions, usage.categories);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::DatabaseResult;
+use crate::query_metrics::Observe;
+
+/// What a ledger holds, counted across every user.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct Usage {
+    /// Transactions, drafts included.
+    pub transactions: i64,
+
+    /// Categories that have not been soft-deleted.
+    pub categories: i64,
+
+    /// Total size of the stored receipt email attachments and transaction
+    /// attachments, in bytes.
+    pub attachment_bytes: i64,
+}
+
+impl Usage {
+    /// Counts what the ledger holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError` if the database cannot be read.
+    #[tracing::instrument(name = "Find usage", skip(conn), err)]
+    pub fn find<'c, A>(conn: A) -> impl Future<Output = DatabaseResult<Self>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let usage = sqlx::query_as!(
+                Usage,
+                r#"
+                    SELECT
+                        (SELECT COUNT(*) FROM transactions)                             AS "transactions!: i64",
+                        (SELECT COUNT(*) FROM categories WHERE deleted_on IS NULL)      AS "categories!: i64",
+                        (SELECT COALESCE(SUM(length(content)), 0) FROM receipt_attachments)
+                            + (SELECT COALESCE(SUM(length(content)), 0) FROM attachment_contents)
+                                                                                        AS "attachment_bytes!: i64"
+                "#
+            )
+            .fetch_one(&mut *conn)
+            .observe_one("usage.find")
+            .await?;
+
+            Ok(usage)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as database;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test]
+    async fn find_counts_what_the_ledger_holds(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let kept = database::Categories::mock();
+        kept.insert(&pool).await?;
+        let deleted = database::Categories::mock();
+        deleted.insert(&pool).await?;
+        database::Categories::delete_soft(deleted.id, database::UserScope::All, &pool).await?;
+        database::Transactions::mock().insert(&pool).await?;
+        let receipt = database::InboundReceipt {
+            message_id: "usage@shop".to_string(),
+            sender: None,
+            subject: Some("Your order".to_string()),
+            received_on: chrono::Utc::now(),
+            body: "Thanks for shopping".to_string(),
+            attachments: vec![database::InboundAttachment {
+                file_name: "receipt.pdf".to_string(),
+                content_type: "application/pdf".to_string(),
+                content: b"%PDF-1.4".to_vec(),
+            }],
+        };
+        database::ReceiptEmails::ingest(&receipt, &pool).await?;
+
+        let usage = Usage::find(&pool).await?;
+
+        assert_eq!(usage.categories, 1);
+        assert_eq!(usage.transactions, 1);
+        assert_eq!(usage.attachment_bytes, b"%PDF-1.4".len() as i64);
+
+        Ok(())
+    }
+}
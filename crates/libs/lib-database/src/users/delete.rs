@@ -0,0 +1,116 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Users {
+    /// Deletes a user from the database by their ID.
+    ///
+    /// Users that still own categories or transactions cannot be deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the user to delete
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the deletion was successful, or a `DatabaseError` if it fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The user with the given ID does not exist (`DatabaseError::NotFound`)
+    /// - The user still owns categories or transactions (foreign key violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Users;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, user_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// Users::delete_by_id(user_id, pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Delete user from database",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn delete_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<()>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    DELETE FROM users
+                    WHERE id = ?
+                "#,
+                id
+            )
+            .execute(&mut *conn)
+            .observe("users.delete_by_id")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "User with id {} not found",
+                    id
+                )));
+            }
+
+            tracing::info!("Deleted user {} from database", id);
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test]
+    async fn delete_fails_while_user_owns_transactions(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let user = database::Users::mock().insert(&pool).await?;
+        let mut transaction = database::Transactions::new(
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+            "Grocer",
+            -1_000,
+        );
+        transaction.user_id = Some(user.id);
+        let transaction = transaction.insert(&pool).await?;
+
+        let owned = database::Users::delete_by_id(user.id, &pool).await;
+        assert!(matches!(owned, Err(database::DatabaseError::Sqlx(_))));
+
+        database::Transactions::delete_by_id(
+            transaction.id,
+            database::UserScope::User(user.id),
+            &pool,
+        )
+        .await?;
+        database::Users::delete_by_id(user.id, &pool).await?;
+        assert_eq!(database::Users::find_by_id(user.id, &pool).await?, None);
+
+        let missing = database::Users::delete_by_id(user.id, &pool).await;
+        assert!(matches!(missing, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+}
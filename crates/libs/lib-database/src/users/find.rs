@@ -0,0 +1,221 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::Users {
+    /// Finds a user by their unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the user to find
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Users)` if the user exists, or `None` if not found.
+    /// Returns a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Users;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, user_id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// if let Some(user) = Users::find_by_id(user_id, pool).await? {
+    ///     println!("Found user: {}", user.username);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find user by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            Self::fetch_by_id(id, &mut conn).await
+        }
+    }
+
+    /// Finds a user by their username, such as the subject of a bearer token.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - The username to find, matched exactly
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Users)` if the user exists, or `None` if not found.
+    /// Returns a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{UserScope, Users};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let scope = match Users::find_by_username("ian", pool).await? {
+    ///     Some(user) => UserScope::User(user.id),
+    ///     None => UserScope::All,
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find user by username",
+        skip(conn),
+        fields(username = %username),
+        err
+    )]
+    pub fn find_by_username<'a, 'c, A>(
+        username: &'a str,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let user = sqlx::query_as!(
+                database::Users,
+                r#"
+                    SELECT
+                        id              AS "id!: domain::RowID",
+                        username,
+                        display_name,
+                        is_active       AS "is_active!: bool",
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM users
+                    WHERE username = ?
+                "#,
+                username
+            )
+            .fetch_optional(&mut *conn)
+            .observe("users.find_by_username")
+            .await?;
+
+            Ok(user)
+        }
+    }
+
+    /// Retrieves every user, ordered by username.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Users;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for user in Users::find_all(pool).await? {
+    ///     println!("- {}", user.username);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find all users", skip(conn), err)]
+    pub fn find_all<'c, A>(conn: A) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let users = sqlx::query_as!(
+                database::Users,
+                r#"
+                    SELECT
+                        id              AS "id!: domain::RowID",
+                        username,
+                        display_name,
+                        is_active       AS "is_active!: bool",
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM users
+                    ORDER BY username ASC
+                "#
+            )
+            .fetch_all(&mut *conn)
+            .observe("users.find_all")
+            .await?;
+
+            Ok(users)
+        }
+    }
+
+    /// Reads a user back through the given connection.
+    ///
+    /// Used by [`find_by_id`](Self::find_by_id) and by writes that return the
+    /// stored row through the connection they already hold.
+    pub(super) async fn fetch_by_id(
+        id: domain::RowID,
+        conn: &mut sqlx::SqliteConnection,
+    ) -> DatabaseResult<Option<Self>> {
+        let user = sqlx::query_as!(
+            database::Users,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    username,
+                    display_name,
+                    is_active       AS "is_active!: bool",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                FROM users
+                WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&mut *conn)
+        .observe("users.fetch_by_id")
+        .await?;
+
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test]
+    async fn find_by_username_matches_exactly(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let user = database::Users::new("ian").insert(&pool).await?;
+        database::Users::new("sam").insert(&pool).await?;
+
+        let found = database::Users::find_by_username("ian", &pool).await?;
+        let missing = database::Users::find_by_username("Ian", &pool).await?;
+
+        assert_eq!(found, Some(user));
+        assert_eq!(missing, None);
+        let usernames: Vec<_> = database::Users::find_all(&pool)
+            .await?
+            .into_iter()
+            .map(|u| u.username)
+            .collect();
+        assert_eq!(usernames, ["ian", "sam"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::Users {
+    /// Inserts a new user into the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Returns
+    ///
+    /// Returns the inserted user as read back from the database, or a
+    /// `DatabaseError` if the insertion fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The username is empty or has spaces (`DatabaseError::Validation`)
+    /// - A user with the same id or username already exists
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::Users;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut user = Users::new("ian");
+    /// user.display_name = Some("Ian Teda".to_string());
+    ///
+    /// let inserted = user.insert(pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert new user into database",
+        skip(self, conn),
+        fields(id = %self.id, username = %self.username),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            self.validate()?;
+
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO users (
+                        id, username, display_name, is_active, created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.username,
+                self.display_name,
+                self.is_active,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("users.insert")
+            .await?;
+
+            let inserted = Self::fetch_by_id(self.id, &mut conn)
+                .await?
+                .ok_or_else(|| {
+                    database::DatabaseError::NotFound(format!(
+                        "User with id {} not found after insert",
+                        self.id
+                    ))
+                })?;
+
+            tracing::info!("New user inserted into the database.");
+
+            Ok(inserted)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test]
+    async fn insert_user_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let user = database::Users::mock();
+
+        let inserted = user.insert(&pool).await?;
+
+        assert_eq!(inserted, user);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_duplicate_username_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let existing = database::Users::mock().insert(&pool).await?;
+
+        let result = database::Users::new(existing.username).insert(&pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Sqlx(_))));
+
+        Ok(())
+    }
+}
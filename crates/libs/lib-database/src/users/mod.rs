@@ -0,0 +1,23 @@
+//! # Users Database Module
+//!
+//! Provides storage for the people sharing one server. Categories and
+//! transactions record the user that owns them, and their find, update and
+//! delete functions take a [`UserScope`] that limits them to one user's rows
+//! in SQL. [`UserScope::All`] is every row, for a server with one user and
+//! for administration.
+//!
+//! As with [`Accounts`](crate::Accounts), the query functions return
+//! `impl Future + Send` so they can run inside
+//! [`with_transaction`](crate::with_transaction) from a gRPC handler.
+
+mod delete;
+mod find;
+mod insert;
+mod model;
+mod scope;
+
+/// Database row model representing a persisted user.
+pub use model::Users;
+
+/// Whose categories and transactions an operation may see and change.
+pub use scope::UserScope;
@@ -0,0 +1,91 @@
+use crate::{self as database, DatabaseError, DatabaseResult};
+use lib_domain as domain;
+
+/// A person sharing the server, who may own categories and transactions.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct Users {
+    pub id: domain::RowID,
+
+    /// The unique name the user signs in as, such as the subject of their
+    /// bearer token.
+    pub username: String,
+
+    pub display_name: Option<String>,
+    pub is_active: bool,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::Users {
+    /// Creates a new active user with no display name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::Users;
+    ///
+    /// let user = Users::new("ian");
+    ///
+    /// assert_eq!(user.username, "ian");
+    /// assert!(user.is_active);
+    /// ```
+    pub fn new(username: impl Into<String>) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            username: username.into(),
+            display_name: None,
+            is_active: true,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+
+    /// Checks the user fields before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`] if the username is empty or has
+    /// whitespace in it.
+    pub fn validate(&self) -> DatabaseResult<()> {
+        if self.username.is_empty() || self.username.chars().any(char::is_whitespace) {
+            return Err(DatabaseError::Validation(format!(
+                "User {} must have a username without spaces",
+                self.id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Generates a mock user with a unique username.
+    #[cfg(test)]
+    pub fn mock() -> Self {
+        use fake::Fake;
+        use fake::faker::internet::en::Username;
+
+        let username: String = Username().fake();
+
+        Self::new(format!("{username}-{}", domain::RowID::new()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_accepts_user() {
+        assert_eq!(database::Users::new("ian").validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_blank_or_spaced_usernames() {
+        for username in ["", "ian teda"] {
+            let user = database::Users::new(username);
+
+            assert!(matches!(user.validate(), Err(DatabaseError::Validation(_))));
+        }
+    }
+}
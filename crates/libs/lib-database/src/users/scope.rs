@@ -0,0 +1,53 @@
+use lib_domain as domain;
+
+/// Whose categories and transactions an operation may see and change.
+///
+/// Scoped queries bind [`user_id`](Self::user_id) and match rows with
+/// `(? IS NULL OR user_id = ?)`, so the scope is enforced by the database
+/// rather than by filtering rows afterwards. A user's scope does not include
+/// rows without an owner.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UserScope {
+    /// Every row, whoever owns it. For a server with one user, and for
+    /// administration.
+    #[default]
+    All,
+
+    /// Only the rows owned by the user with this ID.
+    User(domain::RowID),
+}
+
+impl UserScope {
+    /// The owner to match, or `None` to match every row.
+    pub fn user_id(&self) -> Option<domain::RowID> {
+        match self {
+            Self::All => None,
+            Self::User(user_id) => Some(*user_id),
+        }
+    }
+
+    /// Whether a row owned by `owner` is in the scope.
+    pub fn includes(&self, owner: Option<domain::RowID>) -> bool {
+        match self {
+            Self::All => true,
+            Self::User(user_id) => owner == Some(*user_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_scope_includes_only_the_users_rows() {
+        let user_id = domain::RowID::new();
+        let scope = UserScope::User(user_id);
+
+        assert!(scope.includes(Some(user_id)));
+        assert!(!scope.includes(Some(domain::RowID::new())));
+        assert!(!scope.includes(None));
+        assert!(UserScope::All.includes(None));
+        assert_eq!(UserScope::All.user_id(), None);
+    }
+}
@@ -0,0 +1,470 @@
+//! # Ledger Verification Module
+//!
+//! Cross-checks the invariants the ledger relies on but the schema cannot
+//! enforce, for the server to report with `VerifyLedger`. Each check looks at
+//! every row, so a clean report means the whole ledger was found consistent:
+//!
+//! - [`JournalBalance`](LedgerChecks::JournalBalance): every journal entry
+//!   has at least two splits, and they sum to zero
+//! - [`AccountBalance`](LedgerChecks::AccountBalance): every account's
+//!   balance worked out from its transactions matches the balance replayed
+//!   from the history tables, which reports as recorded rely on
+//! - [`SearchIndex`](LedgerChecks::SearchIndex): every category and
+//!   transaction is in the full-text search index with its current text, and
+//!   the index holds nothing else
+//! - [`ReceiptAttachments`](LedgerChecks::ReceiptAttachments): no attachment
+//!   has lost its receipt email, and no receipt is matched to a transaction
+//!   that no longer exists
+//!
+//! The checks run in one read transaction, so they see the ledger as it was
+//! at one moment, and nothing is repaired. Findings describe what was found
+//! so it can be looked into, such as after restoring an old backup or editing
+//! the database by hand.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_database::LedgerVerification;
+//!
+//! # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+//! let verification = LedgerVerification::run(pool).await?;
+//! for finding in &verification.findings {
+//!     println!("{:?} {}: {}", finding.check, finding.record_id, finding.message);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use sqlx::Connection;
+
+use crate::DatabaseResult;
+use crate::query_metrics::Observe;
+use lib_domain as domain;
+
+/// The invariants a [`LedgerVerification`] checks, see the
+/// [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum LedgerChecks {
+    /// Journal entries have two or more splits that sum to zero.
+    JournalBalance,
+    /// Account balances match the balances replayed from history.
+    AccountBalance,
+    /// The full-text search index matches the categories and transactions.
+    SearchIndex,
+    /// Receipt attachments and emails do not point at missing rows.
+    ReceiptAttachments,
+}
+
+impl LedgerChecks {
+    /// Every check, in the order they run.
+    pub const ALL: [Self; 4] = [
+        Self::JournalBalance,
+        Self::AccountBalance,
+        Self::SearchIndex,
+        Self::ReceiptAttachments,
+    ];
+}
+
+/// One inconsistency found by a check.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct LedgerFinding {
+    /// The check that found it.
+    pub check: LedgerChecks,
+
+    /// The ID of the journal entry, account, category, transaction or
+    /// attachment at fault.
+    pub record_id: String,
+
+    /// What is wrong with it.
+    pub message: String,
+}
+
+impl LedgerFinding {
+    fn new(check: LedgerChecks, record_id: impl ToString, message: String) -> Self {
+        Self {
+            check,
+            record_id: record_id.to_string(),
+            message,
+        }
+    }
+}
+
+/// The findings of checking the whole ledger.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct LedgerVerification {
+    /// Every inconsistency found, grouped by check in the order of
+    /// [`LedgerChecks::ALL`].
+    pub findings: Vec<LedgerFinding>,
+
+    /// When the checks ran (UTC).
+    pub checked_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl LedgerVerification {
+    /// Runs every check against the ledger.
+    ///
+    /// # Arguments
+    ///
+    /// * `conn` - The database pool or connection to run on
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError` if the database cannot be read. An
+    /// inconsistent ledger is not an error, it is reported in the findings.
+    #[tracing::instrument(name = "Verify ledger", skip(conn), err)]
+    pub fn run<'c, A>(conn: A) -> impl Future<Output = DatabaseResult<Self>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let mut tx = conn.begin().await?;
+
+            let mut findings = journal_balance(&mut tx).await?;
+            findings.extend(account_balance(&mut tx).await?);
+            findings.extend(search_index(&mut tx).await?);
+            findings.extend(receipt_attachments(&mut tx).await?);
+
+            // Nothing was written, so there is nothing to commit
+            tx.rollback().await?;
+
+            tracing::info!("Verified the ledger with {} findings", findings.len());
+
+            Ok(Self {
+                findings,
+                checked_on: chrono::Utc::now(),
+            })
+        }
+    }
+
+    /// Returns `true` when no check found anything wrong.
+    pub fn is_consistent(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+/// Journal entries with fewer than two splits, or splits that do not sum to
+/// zero.
+async fn journal_balance(conn: &mut sqlx::SqliteConnection) -> DatabaseResult<Vec<LedgerFinding>> {
+    let entries = sqlx::query!(
+        r#"
+            SELECT
+                journal_entries.id              AS "id!: domain::RowID",
+                COUNT(journal_splits.id)        AS "splits!: i64",
+                COALESCE(SUM(journal_splits.amount), 0) AS "total!: i64"
+            FROM journal_entries
+            LEFT JOIN journal_splits ON journal_splits.entry_id = journal_entries.id
+            GROUP BY journal_entries.id
+            HAVING COUNT(journal_splits.id) < 2 OR COALESCE(SUM(journal_splits.amount), 0) <> 0
+            ORDER BY journal_entries.id
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .observe("verify.journal_balance")
+    .await?;
+
+    Ok(entries
+        .into_iter()
+        .map(|entry| {
+            let message = if entry.splits < 2 {
+                format!(
+                    "Journal entry has {} splits, it needs at least two",
+                    entry.splits
+                )
+            } else {
+                format!(
+                    "Journal entry is unbalanced, its splits sum to {} instead of zero",
+                    entry.total
+                )
+            };
+            LedgerFinding::new(LedgerChecks::JournalBalance, entry.id, message)
+        })
+        .collect())
+}
+
+/// Accounts whose balance from their transactions differs from the balance
+/// replayed from the current history rows.
+async fn account_balance(conn: &mut sqlx::SqliteConnection) -> DatabaseResult<Vec<LedgerFinding>> {
+    let accounts = sqlx::query!(
+        r#"
+            SELECT
+                accounts.id     AS "id!: domain::RowID",
+                accounts.opening_balance + COALESCE((
+                    SELECT SUM(transactions.amount)
+                    FROM transactions
+                    WHERE transactions.account_id = accounts.id
+                        AND transactions.is_draft = 0
+                ), 0) AS "balance!: i64",
+                (
+                    SELECT accounts_history.opening_balance
+                    FROM accounts_history
+                    WHERE accounts_history.id = accounts.id
+                        AND accounts_history.valid_to IS NULL
+                ) + COALESCE((
+                    SELECT SUM(transactions_history.amount)
+                    FROM transactions_history
+                    WHERE transactions_history.account_id = accounts.id
+                        AND transactions_history.is_draft = 0
+                        AND transactions_history.valid_to IS NULL
+                ), 0) AS "recorded: i64"
+            FROM accounts
+            ORDER BY accounts.id
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .observe("verify.account_balance")
+    .await?;
+
+    Ok(accounts
+        .into_iter()
+        .filter_map(|account| {
+            let message = match account.recorded {
+                None => {
+                    "Account has no current history, so its recorded balance cannot be replayed"
+                        .to_string()
+                }
+                Some(recorded) if recorded != account.balance => format!(
+                    "Account balance is {} but its history replays to {recorded}",
+                    account.balance
+                ),
+                Some(_) => return None,
+            };
+            Some(LedgerFinding::new(
+                LedgerChecks::AccountBalance,
+                account.id,
+                message,
+            ))
+        })
+        .collect())
+}
+
+/// Categories and transactions missing from the search index or indexed with
+/// old text, and index rows for neither.
+async fn search_index(conn: &mut sqlx::SqliteConnection) -> DatabaseResult<Vec<LedgerFinding>> {
+    // The index is read once into a materialised view, so SQLite can index it
+    // for the joins rather than scanning it for every row
+    let stale = sqlx::query!(
+        r#"
+            WITH indexed AS MATERIALIZED (
+                SELECT id, code || char(31) || name || char(31) || description AS text
+                FROM categories_fts
+            )
+            SELECT
+                categories.id           AS "id!: domain::RowID",
+                indexed.id IS NULL      AS "is_missing!: bool"
+            FROM categories
+            LEFT JOIN indexed ON indexed.id = categories.id
+            WHERE indexed.text IS NOT
+                categories.code || char(31) || categories.name || char(31) || COALESCE(categories.description, '')
+            ORDER BY categories.id
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .observe("verify.search_index")
+    .await?;
+
+    let mut findings: Vec<_> = stale
+        .into_iter()
+        .map(|category| {
+            let message = if category.is_missing {
+                "Category is missing from the search index"
+            } else {
+                "Category is in the search index with old text"
+            };
+            LedgerFinding::new(LedgerChecks::SearchIndex, category.id, message.to_string())
+        })
+        .collect();
+
+    let stale = sqlx::query!(
+        r#"
+            WITH indexed AS MATERIALIZED (
+                SELECT id, payee || char(31) || description || char(31) || place_name AS text
+                FROM transactions_fts
+            )
+            SELECT
+                transactions.id         AS "id!: domain::RowID",
+                indexed.id IS NULL      AS "is_missing!: bool"
+            FROM transactions
+            LEFT JOIN indexed ON indexed.id = transactions.id
+            WHERE indexed.text IS NOT
+                transactions.payee || char(31) || COALESCE(transactions.description, '')
+                    || char(31) || COALESCE(transactions.place_name, '')
+            ORDER BY transactions.id
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .observe("verify.search_index")
+    .await?;
+
+    findings.extend(stale.into_iter().map(|transaction| {
+        let message = if transaction.is_missing {
+            "Transaction is missing from the search index"
+        } else {
+            "Transaction is in the search index with old text"
+        };
+        LedgerFinding::new(
+            LedgerChecks::SearchIndex,
+            transaction.id,
+            message.to_string(),
+        )
+    }));
+
+    let orphans = sqlx::query!(
+        r#"
+            SELECT id AS "id!: String", 'Category' AS "kind!: String"
+            FROM categories_fts
+            WHERE NOT EXISTS (SELECT 1 FROM categories WHERE categories.id = categories_fts.id)
+            UNION ALL
+            SELECT id AS "id!: String", 'Transaction' AS "kind!: String"
+            FROM transactions_fts
+            WHERE NOT EXISTS (SELECT 1 FROM transactions WHERE transactions.id = transactions_fts.id)
+            ORDER BY 2, 1
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .observe("verify.search_index")
+    .await?;
+
+    findings.extend(orphans.into_iter().map(|orphan| {
+        let message = format!("{} is in the search index but not the ledger", orphan.kind);
+        LedgerFinding::new(LedgerChecks::SearchIndex, orphan.id, message)
+    }));
+
+    Ok(findings)
+}
+
+/// Attachments whose receipt email is gone, and receipts matched to a
+/// transaction that is gone.
+async fn receipt_attachments(
+    conn: &mut sqlx::SqliteConnection,
+) -> DatabaseResult<Vec<LedgerFinding>> {
+    let orphans = sqlx::query!(
+        r#"
+            SELECT
+                receipt_attachments.id  AS "id!: domain::RowID",
+                'Attachment'            AS "kind!: String"
+            FROM receipt_attachments
+            WHERE NOT EXISTS (
+                SELECT 1 FROM receipt_emails WHERE receipt_emails.id = receipt_attachments.receipt_email_id
+            )
+            UNION ALL
+            SELECT
+                receipt_emails.id       AS "id!: domain::RowID",
+                'Receipt'               AS "kind!: String"
+            FROM receipt_emails
+            WHERE receipt_emails.transaction_id IS NOT NULL
+                AND NOT EXISTS (
+                    SELECT 1 FROM transactions WHERE transactions.id = receipt_emails.transaction_id
+                )
+            ORDER BY 2, 1
+        "#
+    )
+    .fetch_all(&mut *conn)
+    .observe("verify.receipt_attachments")
+    .await?;
+
+    Ok(orphans
+        .into_iter()
+        .map(|orphan| {
+            let message = if orphan.kind == "Attachment" {
+                "Attachment belongs to a receipt email that does not exist"
+            } else {
+                "Receipt is matched to a transaction that does not exist"
+            };
+            LedgerFinding::new(
+                LedgerChecks::ReceiptAttachments,
+                orphan.id,
+                message.to_string(),
+            )
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as database;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test]
+    async fn run_finds_nothing_wrong_with_a_consistent_ledger(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        database::DemoData::reset(&pool).await?;
+
+        let verification = LedgerVerification::run(&pool).await?;
+
+        assert!(verification.is_consistent(), "{:?}", verification.findings);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn run_reports_every_broken_invariant(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut conn = pool.acquire().await?;
+        database::DemoData::seed(chrono::Utc::now().date_naive(), &mut conn).await?;
+
+        let category = database::Categories::mock();
+        category.insert(&mut *conn).await?;
+        let other = database::Categories::mock();
+        other.insert(&mut *conn).await?;
+        let entry = database::JournalEntries::new(chrono::Utc::now().date_naive(), None)
+            .with_split(category.id, 1_000, None)
+            .with_split(other.id, -1_000, None);
+        entry.insert(&mut *conn).await?;
+
+        // Break things behind the ledger's back, as restoring a bad backup or
+        // editing the database by hand might
+        sqlx::query("PRAGMA foreign_keys = OFF")
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("UPDATE journal_splits SET amount = 900 WHERE amount = 1000")
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("UPDATE accounts_history SET opening_balance = opening_balance + 1 WHERE name = 'Credit Card'")
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("DELETE FROM categories_fts WHERE id = ?")
+            .bind(category.id)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("INSERT INTO transactions_fts (id, payee, description, place_name) VALUES ('gone', 'Gone', '', '')")
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query(
+            "INSERT INTO receipt_attachments (id, receipt_email_id, file_name, content_type, content, created_on)
+            VALUES (?, 'no-such-email', 'receipt.pdf', 'application/pdf', x'00', ?)",
+        )
+        .bind(domain::RowID::new())
+        .bind(chrono::Utc::now())
+        .execute(&mut *conn)
+        .await?;
+        sqlx::query("PRAGMA foreign_keys = ON")
+            .execute(&mut *conn)
+            .await?;
+
+        let verification = LedgerVerification::run(&mut *conn).await?;
+        let checks: Vec<_> = verification
+            .findings
+            .iter()
+            .map(|finding| finding.check)
+            .collect();
+
+        assert_eq!(
+            checks,
+            vec![
+                LedgerChecks::JournalBalance,
+                LedgerChecks::AccountBalance,
+                LedgerChecks::SearchIndex,
+                LedgerChecks::SearchIndex,
+                LedgerChecks::ReceiptAttachments,
+            ]
+        );
+        assert_eq!(verification.findings[0].record_id, entry.id.to_string());
+        assert_eq!(verification.findings[2].record_id, category.id.to_string());
+        assert_eq!(verification.findings[3].record_id, "gone");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+use lib_domain as domain;
+
+impl database::WebhookDeliveries {
+    /// Finds a webhook delivery by its unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(WebhookDeliveries)` if the delivery exists, or `None` if
+    /// not found.
+    #[tracing::instrument(
+        name = "Find webhook delivery by ID",
+        skip(conn),
+        fields(id = %id),
+        err
+    )]
+    pub fn find_by_id<'c, A>(
+        id: domain::RowID,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Option<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let delivery = sqlx::query_as!(
+                database::WebhookDeliveries,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        event_id            AS "event_id!: domain::RowID",
+                        event_name          AS "event_name!",
+                        endpoint            AS "endpoint!",
+                        payload             AS "payload!",
+                        status              AS "status!: database::WebhookDeliveryStatuses",
+                        attempts            AS "attempts!: i64",
+                        response_status     AS "response_status?: i64",
+                        last_error,
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM webhook_deliveries
+                    WHERE id = ?
+                "#,
+                id
+            )
+            .fetch_optional(&mut *conn)
+            .observe("webhook_deliveries.find_by_id")
+            .await?;
+
+            Ok(delivery)
+        }
+    }
+
+    /// Finds the latest webhook deliveries, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The most deliveries to return
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::{WebhookDeliveries, WebhookDeliveryStatuses};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for delivery in WebhookDeliveries::find_recent(50, pool).await? {
+    ///     if delivery.status == WebhookDeliveryStatuses::Failed {
+    ///         println!("{} to {}: {:?}", delivery.event_name, delivery.endpoint, delivery.last_error);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find recent webhook deliveries", skip(conn), err)]
+    pub fn find_recent<'c, A>(
+        limit: u32,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Vec<Self>>> + Send + 'c
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            let deliveries = sqlx::query_as!(
+                database::WebhookDeliveries,
+                r#"
+                    SELECT
+                        id                  AS "id!: domain::RowID",
+                        event_id            AS "event_id!: domain::RowID",
+                        event_name          AS "event_name!",
+                        endpoint            AS "endpoint!",
+                        payload             AS "payload!",
+                        status              AS "status!: database::WebhookDeliveryStatuses",
+                        attempts            AS "attempts!: i64",
+                        response_status     AS "response_status?: i64",
+                        last_error,
+                        created_on          AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on          AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    FROM webhook_deliveries
+                    ORDER BY created_on DESC, id DESC
+                    LIMIT ?
+                "#,
+                limit
+            )
+            .fetch_all(&mut *conn)
+            .observe("webhook_deliveries.find_recent")
+            .await?;
+
+            Ok(deliveries)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test]
+    async fn find_recent_returns_newest_first(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut older = database::WebhookDeliveries::new(
+            domain::RowID::new(),
+            "category.created",
+            "https://hooks.example.com",
+            "{}",
+        );
+        older.created_on -= chrono::Duration::minutes(5);
+        older.insert(&pool).await?;
+        let newer = database::WebhookDeliveries::new(
+            domain::RowID::new(),
+            "category.deleted",
+            "https://hooks.example.com",
+            "{}",
+        )
+        .insert(&pool)
+        .await?;
+
+        let found = database::WebhookDeliveries::find_recent(1, &pool).await?;
+
+        assert_eq!(found, [newer]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use crate::query_metrics::Observe;
+use crate::{self as database, DatabaseResult};
+
+impl database::WebhookDeliveries {
+    /// Records a delivery before it is first tried.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The event has already been recorded for the endpoint (unique constraint violation)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use lib_database::WebhookDeliveries;
+    /// use lib_domain::RowID;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let delivery = WebhookDeliveries::new(RowID::new(), "category.created", "https://hooks.example.com/ledger", "{}")
+    ///     .insert(pool)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Insert webhook delivery",
+        skip(self, conn),
+        fields(id = %self.id, event_name = %self.event_name, endpoint = %self.endpoint),
+        err
+    )]
+    pub fn insert<'a, 'c, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO webhook_deliveries (
+                        id, event_id, event_name, endpoint, payload, status, attempts,
+                        response_status, last_error, created_on, updated_on
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+                self.id,
+                self.event_id,
+                self.event_name,
+                self.endpoint,
+                self.payload,
+                self.status,
+                self.attempts,
+                self.response_status,
+                self.last_error,
+                self.created_on,
+                self.updated_on
+            )
+            .execute(&mut *conn)
+            .observe("webhook_deliveries.insert")
+            .await?;
+
+            Self::find_by_id(self.id, &mut *conn).await?.ok_or_else(|| {
+                database::DatabaseError::NotFound(format!(
+                    "Webhook delivery with id {} not found",
+                    self.id
+                ))
+            })
+        }
+    }
+
+    /// Records an attempt at the delivery, counting it and keeping the
+    /// endpoint's response.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - Where the delivery has got to after the attempt
+    /// * `response_status` - The HTTP status the endpoint answered with, if it answered
+    /// * `last_error` - Why the attempt failed, if it did
+    /// * `conn` - The database pool, connection or transaction to run on
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The delivery does not exist (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Record webhook delivery attempt",
+        skip(self, last_error, conn),
+        fields(id = %self.id, status = ?status),
+        err
+    )]
+    pub fn record_attempt<'a, 'c, A>(
+        &'a self,
+        status: database::WebhookDeliveryStatuses,
+        response_status: Option<i64>,
+        last_error: Option<&'a str>,
+        conn: A,
+    ) -> impl Future<Output = DatabaseResult<Self>> + Send + 'a
+    where
+        A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'a,
+        'c: 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let updated_on = chrono::Utc::now();
+
+            let rows_affected = sqlx::query!(
+                r#"
+                    UPDATE webhook_deliveries
+                    SET status = ?, attempts = attempts + 1, response_status = ?, last_error = ?, updated_on = ?
+                    WHERE id = ?
+                "#,
+                status,
+                response_status,
+                last_error,
+                updated_on,
+                self.id
+            )
+            .execute(&mut *conn)
+            .observe("webhook_deliveries.record_attempt")
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Webhook delivery with id {} not found",
+                    self.id
+                )));
+            }
+
+            Self::find_by_id(self.id, &mut *conn).await?.ok_or_else(|| {
+                database::DatabaseError::NotFound(format!(
+                    "Webhook delivery with id {} not found",
+                    self.id
+                ))
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_domain as domain;
+
+    // Override with more flexible error
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test]
+    async fn record_attempt_counts_attempts(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let delivery = database::WebhookDeliveries::new(
+            domain::RowID::new(),
+            "category.created",
+            "https://hooks.example.com",
+            "{}",
+        )
+        .insert(&pool)
+        .await?;
+
+        let failed = delivery
+            .record_attempt(
+                database::WebhookDeliveryStatuses::Pending,
+                Some(503),
+                Some("503 Service Unavailable"),
+                &pool,
+            )
+            .await?;
+        assert_eq!((failed.attempts, failed.response_status), (1, Some(503)));
+
+        let delivered = delivery
+            .record_attempt(
+                database::WebhookDeliveryStatuses::Delivered,
+                Some(204),
+                None,
+                &pool,
+            )
+            .await?;
+        assert_eq!(
+            delivered.status,
+            database::WebhookDeliveryStatuses::Delivered
+        );
+        assert_eq!((delivered.attempts, delivered.last_error), (2, None));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_refuses_a_second_delivery_to_an_endpoint(
+        pool: sqlx::Pool<sqlx::Sqlite>,
+    ) -> Result<()> {
+        let event_id = domain::RowID::new();
+        database::WebhookDeliveries::new(
+            event_id,
+            "category.created",
+            "https://hooks.example.com",
+            "{}",
+        )
+        .insert(&pool)
+        .await?;
+
+        let again = database::WebhookDeliveries::new(
+            event_id,
+            "category.created",
+            "https://hooks.example.com",
+            "{}",
+        )
+        .insert(&pool)
+        .await;
+
+        assert!(again.is_err());
+        database::WebhookDeliveries::new(
+            event_id,
+            "category.created",
+            "https://other.example.com",
+            "{}",
+        )
+        .insert(&pool)
+        .await?;
+
+        Ok(())
+    }
+}
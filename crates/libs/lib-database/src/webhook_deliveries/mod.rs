@@ -0,0 +1,13 @@
+//! # Webhook Deliveries Database Module
+//!
+//! A log of [data events](crate::DataEvent) posted to webhooks, one delivery
+//! per event and endpoint. A delivery is recorded as pending before it is
+//! first tried, each attempt updates its status and the endpoint's response,
+//! and it ends up delivered or, once every attempt is used up, failed.
+
+mod find;
+mod insert;
+mod model;
+
+/// Database row model representing a delivery of an event to a webhook.
+pub use model::{WebhookDeliveries, WebhookDeliveryStatuses};
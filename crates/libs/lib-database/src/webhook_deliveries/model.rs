@@ -0,0 +1,76 @@
+use crate as database;
+use lib_domain as domain;
+
+/// Where a webhook delivery has got to.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, sqlx::Type, serde::Deserialize, serde::Serialize,
+)]
+#[sqlx(rename_all = "lowercase")]
+pub enum WebhookDeliveryStatuses {
+    /// Not yet accepted by the endpoint, and still being tried.
+    #[default]
+    Pending,
+    /// Accepted by the endpoint.
+    Delivered,
+    /// Not accepted by the endpoint after every attempt.
+    Failed,
+}
+
+/// An event posted, or to be posted, to a webhook endpoint.
+///
+/// `payload` is the JSON body exactly as it was signed. `response_status` and
+/// `last_error` are from the latest attempt: the HTTP status the endpoint
+/// answered with, and why the attempt failed.
+#[derive(Debug, sqlx::FromRow, serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+pub struct WebhookDeliveries {
+    pub id: domain::RowID,
+    pub event_id: domain::RowID,
+    pub event_name: String,
+    pub endpoint: String,
+    pub payload: String,
+    pub status: WebhookDeliveryStatuses,
+    pub attempts: i64,
+    pub response_status: Option<i64>,
+    pub last_error: Option<String>,
+    pub created_on: chrono::DateTime<chrono::Utc>,
+    pub updated_on: chrono::DateTime<chrono::Utc>,
+}
+
+impl database::WebhookDeliveries {
+    /// Creates a new, unsaved and untried delivery of an event's payload to
+    /// an endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_database::{WebhookDeliveries, WebhookDeliveryStatuses};
+    /// use lib_domain::RowID;
+    ///
+    /// let delivery = WebhookDeliveries::new(RowID::new(), "category.created", "https://hooks.example.com/ledger", "{}");
+    ///
+    /// assert_eq!(delivery.status, WebhookDeliveryStatuses::Pending);
+    /// assert_eq!(delivery.attempts, 0);
+    /// ```
+    pub fn new(
+        event_id: domain::RowID,
+        event_name: impl Into<String>,
+        endpoint: impl Into<String>,
+        payload: impl Into<String>,
+    ) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: domain::RowID::new(),
+            event_id,
+            event_name: event_name.into(),
+            endpoint: endpoint.into(),
+            payload: payload.into(),
+            status: WebhookDeliveryStatuses::Pending,
+            attempts: 0,
+            response_status: None,
+            last_error: None,
+            created_on: now,
+            updated_on: now,
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! # Asset Price Domain Type
+//!
+//! This module defines [`AssetPrice`], the price of one whole
+//! [`CryptoAsset`] in a [`Currency`]. Prices are held to eighteen decimal
+//! places in 128 bits, so long-tail tokens worth a tiny fraction of a cent
+//! keep their significant digits, where an [`ExchangeRate`](crate::ExchangeRate)
+//! would round them to zero.
+
+use crate::{CryptoAsset, Currency, MoneyError};
+
+/// The price of one whole `asset` in major units of `currency`, e.g.
+/// 1 ETH = 5,123.45 AUD.
+///
+/// # Examples
+///
+/// ```rust
+/// use lib_domain::{AssetPrice, CryptoAmount, CryptoAsset, Currency, Money};
+///
+/// let price = AssetPrice::parse(CryptoAsset::ETH, Currency::AUD, "5123.45")?;
+/// let holding = CryptoAmount::parse("0.5", CryptoAsset::ETH)?;
+///
+/// assert_eq!(holding.value(&price)?, Money::new(256_173, Currency::AUD));
+/// assert_eq!(price.to_string(), "1 ETH = 5123.45 AUD");
+/// # Ok::<(), lib_domain::MoneyError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct AssetPrice {
+    asset: CryptoAsset,
+    currency: Currency,
+    price: i128,
+}
+
+impl AssetPrice {
+    /// Number of decimal places a price is held to.
+    pub const DECIMAL_PLACES: u32 = 18;
+
+    /// A price of exactly one, in the units [`price`](Self::price) is held in.
+    pub const ONE: i128 = 10_i128.pow(Self::DECIMAL_PLACES);
+
+    /// Creates a price from units of 10^-18 of the currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidExchangeRate`] if the price is not
+    /// positive.
+    pub fn new(asset: CryptoAsset, currency: Currency, price: i128) -> Result<Self, MoneyError> {
+        if price <= 0 {
+            return Err(MoneyError::InvalidExchangeRate(format!(
+                "{asset} in {currency} at {price}e-{}",
+                Self::DECIMAL_PLACES
+            )));
+        }
+
+        Ok(Self {
+            asset,
+            currency,
+            price,
+        })
+    }
+
+    /// Parses a decimal price such as `"5123.45"` or `"0.000000000012"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidExchangeRate`] if the text is not a
+    /// positive decimal with at most eighteen decimal places.
+    pub fn parse<S: AsRef<str>>(
+        asset: CryptoAsset,
+        currency: Currency,
+        text: S,
+    ) -> Result<Self, MoneyError> {
+        let text = text.as_ref().trim();
+        let invalid =
+            || MoneyError::InvalidExchangeRate(format!("{asset} in {currency} at {text}"));
+
+        let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+        let places = Self::DECIMAL_PLACES as usize;
+        if whole.is_empty()
+            || fraction.len() > places
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let whole: i128 = whole.parse().map_err(|_| invalid())?;
+        let fraction: i128 = format!("{fraction:0<places$}")
+            .parse()
+            .map_err(|_| invalid())?;
+        let price = whole
+            .checked_mul(Self::ONE)
+            .and_then(|price| price.checked_add(fraction))
+            .ok_or_else(invalid)?;
+
+        Self::new(asset, currency, price)
+    }
+
+    /// Returns the asset priced.
+    #[must_use]
+    pub fn asset(&self) -> CryptoAsset {
+        self.asset
+    }
+
+    /// Returns the currency the price is in.
+    #[must_use]
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Returns the price in units of 10^-18 of the currency.
+    #[must_use]
+    pub fn price(&self) -> i128 {
+        self.price
+    }
+
+    /// Formats the price as a plain decimal without trailing zeros, e.g.
+    /// `5123.45`, as [`parse`](Self::parse) reads it.
+    #[must_use]
+    pub fn format_decimal(&self) -> String {
+        let places = Self::DECIMAL_PLACES as usize;
+        let fraction = format!("{:0places$}", self.price % Self::ONE);
+        let fraction = fraction.trim_end_matches('0');
+
+        match fraction {
+            "" => format!("{}", self.price / Self::ONE),
+            fraction => format!("{}.{fraction}", self.price / Self::ONE),
+        }
+    }
+}
+
+/// Formats the price as `1 ETH = 5123.45 AUD`, without trailing zeros.
+impl std::fmt::Display for AssetPrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "1 {} = {} {}",
+            self.asset,
+            self.format_decimal(),
+            self.currency
+        )
+    }
+}
+
+/// Multiplies two numbers and divides by a third, rounding halves up, through
+/// a 256 bit product so the multiplication cannot overflow.
+///
+/// Returns `None` if the quotient does not fit in 128 bits or `divisor` is
+/// zero.
+pub(crate) fn mul_div_round(a: u128, b: u128, divisor: u128) -> Option<u128> {
+    if divisor == 0 {
+        return None;
+    }
+
+    // Schoolbook multiplication in 64 bit halves
+    const MASK: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+    let low = (low_low & MASK) | (middle << 64);
+    let high = a_high * b_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+
+    if high >= divisor {
+        return None;
+    }
+
+    // Long division a bit at a time, the remainder always staying below the divisor
+    let (mut quotient, mut remainder) = (0_u128, high);
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+
+    if remainder >= divisor - remainder {
+        quotient.checked_add(1)
+    } else {
+        Some(quotient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_keeps_long_tail_precision() {
+        let price =
+            AssetPrice::parse(CryptoAsset::USDC, Currency::AUD, "0.000000000000000012").unwrap();
+
+        assert_eq!(price.price(), 12);
+        assert_eq!(price.format_decimal(), "0.000000000000000012");
+        assert_eq!(
+            AssetPrice::parse(CryptoAsset::BTC, Currency::USD, "98000")
+                .unwrap()
+                .to_string(),
+            "1 BTC = 98000 USD"
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_prices() {
+        for text in ["", "0", "-1", "0.0000000000000000001", "abc", "1.2.3"] {
+            assert!(
+                matches!(
+                    AssetPrice::parse(CryptoAsset::ETH, Currency::AUD, text),
+                    Err(MoneyError::InvalidExchangeRate(_))
+                ),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn mul_div_round_handles_256_bit_products() {
+        assert_eq!(mul_div_round(7, 3, 2), Some(11));
+        assert_eq!(mul_div_round(5, 1, 3), Some(2));
+        assert_eq!(
+            mul_div_round(u128::MAX, u128::MAX, u128::MAX),
+            Some(u128::MAX)
+        );
+        assert_eq!(mul_div_round(u128::MAX, 10, 100), Some(u128::MAX / 10 + 1));
+        assert_eq!(mul_div_round(u128::MAX, 2, 1), None);
+        assert_eq!(mul_div_round(1, 1, 0), None);
+    }
+}
@@ -30,15 +30,7 @@
 /// assert_eq!(category.as_str(), "asset");
 /// assert!(category.is_asset());
 /// ```
-#[derive(
-    Debug,
-    Clone,
-    Default,
-    PartialEq,
-    PartialOrd,
-    serde::Deserialize,
-    serde::Serialize,
-)]
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, serde::Deserialize, serde::Serialize)]
 pub enum CategoryTypes {
     /// Resources owned that have economic value (cash, investments, property).
     Asset,
@@ -118,7 +110,7 @@ impl CategoryTypes {
     pub fn as_str(&self) -> &'static str {
         match self {
             CategoryTypes::Asset => "asset",
-            CategoryTypes::Liability => "liability", 
+            CategoryTypes::Liability => "liability",
             CategoryTypes::Income => "income",
             CategoryTypes::Expense => "expense",
             CategoryTypes::Equity => "equity",
@@ -161,7 +153,7 @@ impl CategoryTypes {
     /// let random_type = CategoryTypes::mock();
     /// // random_type will be one of: Asset, Liability, Income, Expense, or Equity
     /// ```
-    #[cfg(test)]
+    #[cfg(any(test, feature = "mock"))]
     pub fn mock() -> Self {
         use fake::Fake;
 
@@ -311,7 +303,8 @@ impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for CategoryTypes {
     fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
         let s = <String as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
         use std::str::FromStr;
-        Ok(CategoryTypes::from_str(&s).map_err(|e| format!("Invalid category type in DB: {}", e))?)
+        Ok(CategoryTypes::from_str(&s)
+            .map_err(|e| format!("Invalid category type in DB: {}", e))?)
     }
 }
 
@@ -333,15 +326,15 @@ impl<'r> sqlx::Decode<'r, sqlx::Any> for CategoryTypes {
     fn decode(value: sqlx::any::AnyValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
         use std::str::FromStr;
         let s = <String as sqlx::Decode<sqlx::Any>>::decode(value)?;
-        Ok(CategoryTypes::from_str(&s).map_err(|e| format!("Invalid category type in database: {}", e))?)
+        Ok(CategoryTypes::from_str(&s)
+            .map_err(|e| format!("Invalid category type in database: {}", e))?)
     }
 }
 
 /// Newtype wrapper for `chrono::DateTime<chrono::Utc>` to enable SQLx trait implementations.
 ///
 /// This wrapper provides SQLx database integration for UTC timestamps, storing them
-/// as RFC 3339 formatted strings in the database for maximum compatibility across
-/// different database backends (SQLite and PostgreSQL).
+/// as RFC 3339 formatted strings, which SQLite's date functions can read.
 ///
 /// # Examples
 ///
@@ -412,29 +405,41 @@ mod tests {
     #[test]
     fn test_from_str_valid() {
         use std::str::FromStr;
-        
+
         assert_eq!(CategoryTypes::from_str("asset"), Ok(CategoryTypes::Asset));
-        assert_eq!(CategoryTypes::from_str("liability"), Ok(CategoryTypes::Liability));
+        assert_eq!(
+            CategoryTypes::from_str("liability"),
+            Ok(CategoryTypes::Liability)
+        );
         assert_eq!(CategoryTypes::from_str("income"), Ok(CategoryTypes::Income));
-        assert_eq!(CategoryTypes::from_str("expense"), Ok(CategoryTypes::Expense));
+        assert_eq!(
+            CategoryTypes::from_str("expense"),
+            Ok(CategoryTypes::Expense)
+        );
         assert_eq!(CategoryTypes::from_str("equity"), Ok(CategoryTypes::Equity));
     }
 
     #[test]
     fn test_from_str_case_insensitive() {
         use std::str::FromStr;
-        
+
         assert_eq!(CategoryTypes::from_str("ASSET"), Ok(CategoryTypes::Asset));
-        assert_eq!(CategoryTypes::from_str("LiAbIlItY"), Ok(CategoryTypes::Liability));
+        assert_eq!(
+            CategoryTypes::from_str("LiAbIlItY"),
+            Ok(CategoryTypes::Liability)
+        );
         assert_eq!(CategoryTypes::from_str("InCoMe"), Ok(CategoryTypes::Income));
-        assert_eq!(CategoryTypes::from_str("EXPENSE"), Ok(CategoryTypes::Expense));
+        assert_eq!(
+            CategoryTypes::from_str("EXPENSE"),
+            Ok(CategoryTypes::Expense)
+        );
         assert_eq!(CategoryTypes::from_str("equity"), Ok(CategoryTypes::Equity));
     }
 
     #[test]
     fn test_from_str_invalid() {
         use std::str::FromStr;
-        
+
         assert!(CategoryTypes::from_str("invalid").is_err());
         assert!(CategoryTypes::from_str("").is_err());
         assert!(CategoryTypes::from_str("assets").is_err()); // plural
@@ -467,12 +472,12 @@ mod tests {
             (CategoryTypes::Expense, "\"Expense\""),
             (CategoryTypes::Equity, "\"Equity\""),
         ];
-        
+
         for (category_type, expected_json) in test_cases {
             // Test serialization
             let serialized = serde_json::to_string(&category_type).unwrap();
             assert_eq!(serialized, expected_json);
-            
+
             // Test deserialization round-trip
             let deserialized: CategoryTypes = serde_json::from_str(&serialized).unwrap();
             assert_eq!(deserialized, category_type);
@@ -497,14 +502,14 @@ mod tests {
     fn test_mock() {
         // Test that mock() returns valid CategoryTypes
         let mock_type = CategoryTypes::mock();
-        
+
         // Should be one of the valid variants
         let all_types = CategoryTypes::all();
         assert!(all_types.contains(&mock_type));
-        
+
         // Should have a valid string representation
         assert!(!mock_type.as_str().is_empty());
-        
+
         // Should be parseable back from its string representation
         assert_eq!(CategoryTypes::from_str(mock_type.as_str()), Ok(mock_type));
     }
@@ -512,24 +517,54 @@ mod tests {
     #[test]
     fn test_to_rpc_i32() {
         // Test conversion to RPC i32 values
-        assert_eq!(CategoryTypes::Asset.to_rpc_i32(), lib_rpc::CategoryTypes::Asset as i32);
-        assert_eq!(CategoryTypes::Equity.to_rpc_i32(), lib_rpc::CategoryTypes::Equity as i32);
-        assert_eq!(CategoryTypes::Expense.to_rpc_i32(), lib_rpc::CategoryTypes::Expense as i32);
-        assert_eq!(CategoryTypes::Income.to_rpc_i32(), lib_rpc::CategoryTypes::Income as i32);
-        assert_eq!(CategoryTypes::Liability.to_rpc_i32(), lib_rpc::CategoryTypes::Liability as i32);
+        assert_eq!(
+            CategoryTypes::Asset.to_rpc_i32(),
+            lib_rpc::CategoryTypes::Asset as i32
+        );
+        assert_eq!(
+            CategoryTypes::Equity.to_rpc_i32(),
+            lib_rpc::CategoryTypes::Equity as i32
+        );
+        assert_eq!(
+            CategoryTypes::Expense.to_rpc_i32(),
+            lib_rpc::CategoryTypes::Expense as i32
+        );
+        assert_eq!(
+            CategoryTypes::Income.to_rpc_i32(),
+            lib_rpc::CategoryTypes::Income as i32
+        );
+        assert_eq!(
+            CategoryTypes::Liability.to_rpc_i32(),
+            lib_rpc::CategoryTypes::Liability as i32
+        );
     }
 
     #[test]
     fn test_from_rpc_i32() {
         // Test conversion from RPC i32 values
-        assert_eq!(CategoryTypes::from_rpc_i32(lib_rpc::CategoryTypes::Asset as i32), Ok(CategoryTypes::Asset));
-        assert_eq!(CategoryTypes::from_rpc_i32(lib_rpc::CategoryTypes::Equity as i32), Ok(CategoryTypes::Equity));
-        assert_eq!(CategoryTypes::from_rpc_i32(lib_rpc::CategoryTypes::Expense as i32), Ok(CategoryTypes::Expense));
-        assert_eq!(CategoryTypes::from_rpc_i32(lib_rpc::CategoryTypes::Income as i32), Ok(CategoryTypes::Income));
-        assert_eq!(CategoryTypes::from_rpc_i32(lib_rpc::CategoryTypes::Liability as i32), Ok(CategoryTypes::Liability));
+        assert_eq!(
+            CategoryTypes::from_rpc_i32(lib_rpc::CategoryTypes::Asset as i32),
+            Ok(CategoryTypes::Asset)
+        );
+        assert_eq!(
+            CategoryTypes::from_rpc_i32(lib_rpc::CategoryTypes::Equity as i32),
+            Ok(CategoryTypes::Equity)
+        );
+        assert_eq!(
+            CategoryTypes::from_rpc_i32(lib_rpc::CategoryTypes::Expense as i32),
+            Ok(CategoryTypes::Expense)
+        );
+        assert_eq!(
+            CategoryTypes::from_rpc_i32(lib_rpc::CategoryTypes::Income as i32),
+            Ok(CategoryTypes::Income)
+        );
+        assert_eq!(
+            CategoryTypes::from_rpc_i32(lib_rpc::CategoryTypes::Liability as i32),
+            Ok(CategoryTypes::Liability)
+        );
 
         // Invalid values should return an error
         assert!(CategoryTypes::from_rpc_i32(-1).is_err());
         assert!(CategoryTypes::from_rpc_i32(999).is_err());
     }
-}
\ No newline at end of file
+}
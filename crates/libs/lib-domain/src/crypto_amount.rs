@@ -0,0 +1,454 @@
+//! # Crypto Amount Domain Type
+//!
+//! This module defines [`CryptoAmount`], a quantity of a [`CryptoAsset`] held
+//! as a whole number of the asset's smallest units, such as wei. Eighteen
+//! decimal places do not fit in the 64 bits [`Money`] uses, so amounts are
+//! held in 128 bits instead, enough for any realistic holding to the last wei.
+//!
+//! Amounts are valued in a currency with an [`AssetPrice`], rounding once to
+//! the currency's minor unit.
+//!
+//! ## Example Usage
+//!
+//! ```rust
+//! use lib_domain::{CryptoAmount, CryptoAsset};
+//!
+//! let bought = CryptoAmount::parse("1.25 ETH", CryptoAsset::BTC)?;
+//! let gas = CryptoAmount::parse("0.000042", CryptoAsset::ETH)?;
+//!
+//! let held = bought.checked_sub(gas)?;
+//! assert_eq!(held.units(), 1_249_958_000_000_000_000);
+//! assert_eq!(held.to_string(), "1.249958 ETH");
+//! # Ok::<(), lib_domain::MoneyError>(())
+//! ```
+
+use crate::{AssetPrice, CryptoAsset, Money, MoneyError};
+
+/// A quantity of a crypto asset.
+///
+/// The quantity is a signed number of the asset's smallest units, negative
+/// for an amount sent. As with [`Money`], arithmetic is checked and refuses
+/// to mix assets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct CryptoAmount {
+    units: i128,
+    asset: CryptoAsset,
+}
+
+impl CryptoAmount {
+    /// Creates an amount from smallest units, e.g.
+    /// `CryptoAmount::new(1_000, CryptoAsset::BTC)` is 1,000 satoshis.
+    #[must_use]
+    pub fn new(units: i128, asset: CryptoAsset) -> Self {
+        Self { units, asset }
+    }
+
+    /// Creates a zero amount of an asset.
+    #[must_use]
+    pub fn zero(asset: CryptoAsset) -> Self {
+        Self::new(0, asset)
+    }
+
+    /// Parses a written amount such as `1.5`, `-0.25 ETH`, `BTC 0.001` or
+    /// `Ξ1,000`.
+    ///
+    /// A ticker before or after the number sets the asset, as does the symbol
+    /// of an asset other than `asset`. Otherwise `asset` is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError`] if the input is empty, is not a number, has more
+    /// decimal places than the asset allows, has a symbol that does not match
+    /// its ticker, or is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_domain::{CryptoAmount, CryptoAsset};
+    ///
+    /// let wei = CryptoAmount::parse("0.000000000000000001", CryptoAsset::ETH)?;
+    /// assert_eq!(wei, CryptoAmount::new(1, CryptoAsset::ETH));
+    ///
+    /// let sats = CryptoAmount::parse("-₿0.5", CryptoAsset::ETH)?;
+    /// assert_eq!(sats, CryptoAmount::new(-50_000_000, CryptoAsset::BTC));
+    /// # Ok::<(), lib_domain::MoneyError>(())
+    /// ```
+    pub fn parse<S: AsRef<str>>(input: S, asset: CryptoAsset) -> Result<Self, MoneyError> {
+        let input = input.as_ref().trim();
+        if input.is_empty() {
+            return Err(MoneyError::Empty);
+        }
+        let invalid = || MoneyError::InvalidAmount(input.to_string());
+
+        // Tickers are separated from the number by whitespace, e.g. "1.5 ETH"
+        let mut code = None;
+        let mut number = String::new();
+        for word in input.split_whitespace() {
+            match word.parse::<CryptoAsset>() {
+                Ok(found) if code.is_none() => code = Some(found),
+                Ok(_) => return Err(invalid()),
+                Err(_) => number.push_str(word),
+            }
+        }
+
+        let (negative, rest) = match number.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, number.strip_prefix('+').unwrap_or(&number)),
+        };
+
+        let symbol = CryptoAsset::all()
+            .iter()
+            .filter(|found| !found.symbol().is_empty())
+            .find(|found| rest.starts_with(found.symbol()));
+        let rest = symbol.map_or(rest, |found| &rest[found.symbol().len()..]);
+
+        let asset = match (code, symbol) {
+            (Some(code), Some(symbol)) if code != *symbol => return Err(invalid()),
+            (Some(code), _) => code,
+            (None, Some(symbol)) => *symbol,
+            (None, None) => asset,
+        };
+
+        let digits = rest.replace(',', "");
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits.as_str(), None),
+        };
+
+        let places = asset.decimal_places() as usize;
+        let is_digits = |text: &str| text.chars().all(|c| c.is_ascii_digit());
+        if whole.is_empty()
+            || !is_digits(whole)
+            || fraction.is_some_and(|fraction| {
+                fraction.is_empty() || fraction.len() > places || !is_digits(fraction)
+            })
+        {
+            return Err(invalid());
+        }
+
+        let whole: i128 = whole.parse().map_err(|_| MoneyError::Overflow)?;
+        let fraction: i128 = match fraction {
+            Some(fraction) => format!("{fraction:0<places$}")
+                .parse()
+                .map_err(|_| invalid())?,
+            None => 0,
+        };
+
+        let units = whole
+            .checked_mul(asset.units_per_whole())
+            .and_then(|units| match negative {
+                true => units.checked_neg()?.checked_sub(fraction),
+                false => units.checked_add(fraction),
+            })
+            .ok_or(MoneyError::Overflow)?;
+
+        Ok(Self::new(units, asset))
+    }
+
+    /// Returns the amount in the asset's smallest units.
+    #[must_use]
+    pub fn units(&self) -> i128 {
+        self.units
+    }
+
+    /// Returns the asset of the amount.
+    #[must_use]
+    pub fn asset(&self) -> CryptoAsset {
+        self.asset
+    }
+
+    /// Returns true if the amount is zero.
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.units == 0
+    }
+
+    /// Returns true if the amount was sent.
+    #[must_use]
+    pub fn is_negative(&self) -> bool {
+        self.units < 0
+    }
+
+    /// Adds two amounts of the same asset.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::AssetMismatch`] if the assets differ, or
+    /// [`MoneyError::Overflow`] if the sum is out of range.
+    pub fn checked_add(self, other: CryptoAmount) -> Result<Self, MoneyError> {
+        self.same_asset(other)?;
+        let units = self
+            .units
+            .checked_add(other.units)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Self::new(units, self.asset))
+    }
+
+    /// Subtracts an amount of the same asset.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::AssetMismatch`] if the assets differ, or
+    /// [`MoneyError::Overflow`] if the difference is out of range.
+    pub fn checked_sub(self, other: CryptoAmount) -> Result<Self, MoneyError> {
+        self.same_asset(other)?;
+        let units = self
+            .units
+            .checked_sub(other.units)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Self::new(units, self.asset))
+    }
+
+    /// Values the amount at a price, rounding half away from zero to the
+    /// minor unit of the price's currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::AssetMismatch`] if the price is for another
+    /// asset, or [`MoneyError::Overflow`] if the value is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_domain::{AssetPrice, CryptoAmount, CryptoAsset, Currency, Money};
+    ///
+    /// let price = AssetPrice::parse(CryptoAsset::ETH, Currency::USD, "3250.5")?;
+    /// let holding = CryptoAmount::parse("12.345678901234567891", CryptoAsset::ETH)?;
+    ///
+    /// assert_eq!(holding.value(&price)?, Money::new(4_012_963, Currency::USD));
+    /// # Ok::<(), lib_domain::MoneyError>(())
+    /// ```
+    pub fn value(self, price: &AssetPrice) -> Result<Money, MoneyError> {
+        if self.asset != price.asset() {
+            return Err(MoneyError::AssetMismatch(self.asset, price.asset()));
+        }
+
+        let currency = price.currency();
+        let numerator = self
+            .units
+            .unsigned_abs()
+            .checked_mul(currency.minor_per_major().unsigned_abs().into())
+            .ok_or(MoneyError::Overflow)?;
+        let denominator =
+            self.asset.units_per_whole().unsigned_abs() * AssetPrice::ONE.unsigned_abs();
+
+        let value =
+            crate::asset_price::mul_div_round(numerator, price.price().unsigned_abs(), denominator)
+                .and_then(|value| i64::try_from(value).ok())
+                .ok_or(MoneyError::Overflow)?;
+
+        Ok(Money::new(
+            if self.is_negative() { -value } else { value },
+            currency,
+        ))
+    }
+
+    fn same_asset(&self, other: CryptoAmount) -> Result<(), MoneyError> {
+        if self.asset != other.asset {
+            return Err(MoneyError::AssetMismatch(self.asset, other.asset));
+        }
+        Ok(())
+    }
+
+    /// Formats the amount as a plain decimal without grouping or trailing
+    /// zeros, e.g. `-1234.5`, as [`parse`](Self::parse) reads it.
+    #[must_use]
+    pub fn format_decimal(&self) -> String {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let (whole, fraction) = self.split_whole();
+
+        match fraction.as_str() {
+            "" => format!("{sign}{whole}"),
+            fraction => format!("{sign}{whole}.{fraction}"),
+        }
+    }
+
+    /// Splits the unsigned amount into whole assets and the decimal places
+    /// after the point, without trailing zeros.
+    fn split_whole(&self) -> (u128, String) {
+        let per_whole = self.asset.units_per_whole().unsigned_abs();
+        let units = self.units.unsigned_abs();
+        let places = self.asset.decimal_places() as usize;
+        let fraction = format!("{:0places$}", units % per_whole);
+
+        (
+            units / per_whole,
+            fraction.trim_end_matches('0').to_string(),
+        )
+    }
+
+    /// Generates a random amount for testing scenarios.
+    #[cfg(any(test, feature = "mock"))]
+    pub fn mock() -> Self {
+        use fake::Fake;
+
+        let asset = CryptoAsset::mock();
+        let units: i64 = (i64::MIN..i64::MAX).fake();
+
+        Self::new(i128::from(units) * asset.units_per_whole() / 1_000, asset)
+    }
+}
+
+/// Formats the amount with its ticker and without trailing zeros, e.g.
+/// `-1,234.5 ETH` or `0.00000001 BTC`. Crypto amounts often run to many
+/// decimal places, so unlike [`Money`] the ticker follows the number and only
+/// the significant decimal places are written.
+impl std::fmt::Display for CryptoAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let (whole, fraction) = self.split_whole();
+        let whole = whole.to_string();
+
+        let mut grouped = String::with_capacity(whole.len() + whole.len() / 3);
+        for (index, digit) in whole.chars().enumerate() {
+            if index > 0 && (whole.len() - index).is_multiple_of(3) {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+
+        match fraction.as_str() {
+            "" => write!(f, "{sign}{grouped} {}", self.asset),
+            fraction => write!(f, "{sign}{grouped}.{fraction} {}", self.asset),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Currency;
+
+    fn eth(units: i128) -> CryptoAmount {
+        CryptoAmount::new(units, CryptoAsset::ETH)
+    }
+
+    #[test]
+    fn parse_accepts_common_formats() {
+        let parse = |input| CryptoAmount::parse(input, CryptoAsset::ETH).unwrap();
+
+        assert_eq!(parse("1.5"), eth(1_500_000_000_000_000_000));
+        assert_eq!(parse("-0.25 ETH"), eth(-250_000_000_000_000_000));
+        assert_eq!(
+            parse("Ξ1,000"),
+            eth(1_000 * CryptoAsset::ETH.units_per_whole())
+        );
+        assert_eq!(
+            parse("btc 0.00000001"),
+            CryptoAmount::new(1, CryptoAsset::BTC)
+        );
+        assert_eq!(
+            parse("◎2"),
+            CryptoAmount::new(2_000_000_000, CryptoAsset::SOL)
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_amounts() {
+        let parse = |input| CryptoAmount::parse(input, CryptoAsset::BTC);
+
+        assert_eq!(parse(" "), Err(MoneyError::Empty));
+        assert!(matches!(
+            parse("0.000000001"),
+            Err(MoneyError::InvalidAmount(_))
+        ));
+        assert!(matches!(parse("Ξ1 BTC"), Err(MoneyError::InvalidAmount(_))));
+        assert!(matches!(
+            parse("1 BTC ETH"),
+            Err(MoneyError::InvalidAmount(_))
+        ));
+        assert!(matches!(parse("1."), Err(MoneyError::InvalidAmount(_))));
+        assert!(matches!(parse("abc"), Err(MoneyError::InvalidAmount(_))));
+        assert_eq!(parse(&"9".repeat(40)), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn display_writes_significant_decimals() {
+        assert_eq!(eth(1).to_string(), "0.000000000000000001 ETH");
+        assert_eq!(
+            eth(-1_234_500_000_000_000_000_000).to_string(),
+            "-1,234.5 ETH"
+        );
+        assert_eq!(
+            CryptoAmount::new(300_000_000, CryptoAsset::BTC).to_string(),
+            "3 BTC"
+        );
+        assert_eq!(
+            eth(-1_234_500_000_000_000_000_000).format_decimal(),
+            "-1234.5"
+        );
+        assert_eq!(
+            CryptoAmount::new(i128::MIN, CryptoAsset::ETH)
+                .format_decimal()
+                .len(),
+            41
+        );
+    }
+
+    #[test]
+    fn display_round_trips_through_parse() {
+        for _ in 0..100 {
+            let amount = CryptoAmount::mock();
+            assert_eq!(
+                CryptoAmount::parse(amount.to_string(), CryptoAsset::BTC).unwrap(),
+                amount
+            );
+            assert_eq!(
+                CryptoAmount::parse(amount.format_decimal(), amount.asset()).unwrap(),
+                amount
+            );
+        }
+    }
+
+    #[test]
+    fn arithmetic_is_checked() {
+        assert_eq!(eth(1_000).checked_add(eth(-250)), Ok(eth(750)));
+        assert_eq!(eth(1_000).checked_sub(eth(250)), Ok(eth(750)));
+        assert_eq!(
+            eth(i128::MAX).checked_add(eth(1)),
+            Err(MoneyError::Overflow)
+        );
+        assert_eq!(
+            eth(1).checked_add(CryptoAmount::new(1, CryptoAsset::BTC)),
+            Err(MoneyError::AssetMismatch(
+                CryptoAsset::ETH,
+                CryptoAsset::BTC
+            ))
+        );
+    }
+
+    #[test]
+    fn value_rounds_to_currency_minor_unit() {
+        let price = AssetPrice::parse(CryptoAsset::ETH, Currency::AUD, "5123.45").unwrap();
+        let long_tail =
+            AssetPrice::parse(CryptoAsset::USDC, Currency::JPY, "0.000000000123").unwrap();
+
+        assert_eq!(eth(1).value(&price), Ok(Money::zero(Currency::AUD)));
+        assert_eq!(
+            eth(-1_000 * CryptoAsset::ETH.units_per_whole()).value(&price),
+            Ok(Money::new(-512_345_000, Currency::AUD))
+        );
+        assert_eq!(
+            CryptoAmount::parse("5000000000000", CryptoAsset::USDC)
+                .unwrap()
+                .value(&long_tail),
+            Ok(Money::new(615, Currency::JPY))
+        );
+        assert_eq!(
+            CryptoAmount::new(1, CryptoAsset::BTC).value(&price),
+            Err(MoneyError::AssetMismatch(
+                CryptoAsset::BTC,
+                CryptoAsset::ETH
+            ))
+        );
+        assert_eq!(eth(i128::MAX).value(&price), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn serde_round_trip_preserves_value() {
+        let amount = eth(-1_250);
+        let json = serde_json::to_string(&amount).unwrap();
+        assert_eq!(json, r#"{"units":-1250,"asset":"ETH"}"#);
+        assert_eq!(serde_json::from_str::<CryptoAmount>(&json).unwrap(), amount);
+    }
+}
@@ -0,0 +1,181 @@
+//! # Crypto Asset Domain Type
+//!
+//! This module defines [`CryptoAsset`], the crypto currencies and tokens the
+//! ledger can track holdings of. Unlike a [`Currency`](crate::Currency), an
+//! asset can be divided into as many as eighteen decimal places, e.g. one wei
+//! is 0.000000000000000001 ETH, so amounts of it are held by
+//! [`CryptoAmount`](crate::CryptoAmount) in 128 bits rather than as
+//! [`Money`](crate::Money).
+
+/// A crypto currency or token, identified by its ticker.
+///
+/// Serialised and stored in the database as its uppercase ticker.
+///
+/// # Examples
+///
+/// ```rust
+/// use lib_domain::CryptoAsset;
+///
+/// let asset: CryptoAsset = "eth".parse()?;
+/// assert_eq!(asset, CryptoAsset::ETH);
+/// assert_eq!(asset.decimal_places(), 18);
+/// assert_eq!(CryptoAsset::BTC.symbol(), "₿");
+/// # Ok::<(), lib_domain::CryptoAssetError>(())
+/// ```
+// Variants are named by their ticker, as they are written everywhere else
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Deserialize, serde::Serialize,
+)]
+pub enum CryptoAsset {
+    /// Bitcoin, divisible into satoshis.
+    BTC,
+    /// Ether, divisible into wei.
+    ETH,
+    /// Solana, divisible into lamports.
+    SOL,
+    /// USD Coin, a stablecoin pegged to the United States dollar.
+    USDC,
+}
+
+/// Error type for [`CryptoAsset`] parsing operations.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CryptoAssetError {
+    /// The provided string is not a supported asset ticker.
+    #[error("Unknown crypto asset: {0}")]
+    UnknownAsset(String),
+}
+
+impl CryptoAsset {
+    /// Returns all supported assets, ordered by ticker.
+    pub fn all() -> &'static [CryptoAsset] {
+        &[
+            CryptoAsset::BTC,
+            CryptoAsset::ETH,
+            CryptoAsset::SOL,
+            CryptoAsset::USDC,
+        ]
+    }
+
+    /// Returns the uppercase ticker, e.g. `"ETH"`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            CryptoAsset::BTC => "BTC",
+            CryptoAsset::ETH => "ETH",
+            CryptoAsset::SOL => "SOL",
+            CryptoAsset::USDC => "USDC",
+        }
+    }
+
+    /// Returns the symbol the asset is written with, e.g. `"Ξ"`, or an empty
+    /// string for assets written by ticker only.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CryptoAsset::BTC => "₿",
+            CryptoAsset::ETH => "Ξ",
+            CryptoAsset::SOL => "◎",
+            CryptoAsset::USDC => "",
+        }
+    }
+
+    /// Returns the number of decimal places of the smallest unit, e.g. 8 for
+    /// satoshis and 18 for wei.
+    pub fn decimal_places(&self) -> u32 {
+        match self {
+            CryptoAsset::BTC => 8,
+            CryptoAsset::ETH => 18,
+            CryptoAsset::SOL => 9,
+            CryptoAsset::USDC => 6,
+        }
+    }
+
+    /// Returns the number of smallest units in one whole asset, e.g.
+    /// 100,000,000 satoshis in a bitcoin.
+    pub fn units_per_whole(&self) -> i128 {
+        10_i128.pow(self.decimal_places())
+    }
+
+    /// Returns a random asset for testing scenarios.
+    #[cfg(any(test, feature = "mock"))]
+    pub fn mock() -> Self {
+        use fake::Fake;
+
+        let all = Self::all();
+        all[(0..all.len()).fake::<usize>()]
+    }
+}
+
+impl std::fmt::Display for CryptoAsset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl std::str::FromStr for CryptoAsset {
+    type Err = CryptoAssetError;
+
+    /// Parses a ticker (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.trim();
+
+        Self::all()
+            .iter()
+            .find(|asset| asset.code().eq_ignore_ascii_case(code))
+            .copied()
+            .ok_or_else(|| CryptoAssetError::UnknownAsset(s.to_string()))
+    }
+}
+
+// SQLx trait implementations store the asset as its ticker in a TEXT column.
+impl sqlx::Type<sqlx::Sqlite> for CryptoAsset {
+    fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+        <String as sqlx::Type<sqlx::Sqlite>>::type_info()
+    }
+}
+
+impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for CryptoAsset {
+    fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        let raw = <String as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
+        Ok(raw
+            .parse::<CryptoAsset>()
+            .map_err(|e| format!("Invalid crypto asset in database: {}", e))?)
+    }
+}
+
+impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for CryptoAsset {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::Sqlite as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <String as sqlx::Encode<'q, sqlx::Sqlite>>::encode(self.code().to_string(), buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code_round_trips_for_all_assets() {
+        for asset in CryptoAsset::all() {
+            assert_eq!(
+                asset.code().to_lowercase().parse::<CryptoAsset>().unwrap(),
+                *asset
+            );
+            assert_eq!(asset.to_string(), asset.code());
+        }
+        assert_eq!(
+            "AUD".parse::<CryptoAsset>(),
+            Err(CryptoAssetError::UnknownAsset("AUD".to_string()))
+        );
+    }
+
+    #[test]
+    fn units_per_whole_follows_decimal_places() {
+        assert_eq!(CryptoAsset::BTC.units_per_whole(), 100_000_000);
+        assert_eq!(
+            CryptoAsset::ETH.units_per_whole(),
+            1_000_000_000_000_000_000
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! # Currency Domain Type
+//!
+//! This module defines [`Currency`], the ISO 4217 currencies the ledger can
+//! hold amounts in. Each currency knows its three letter code, the symbol it
+//! is written with and how many decimal places its minor unit has, which
+//! [`Money`](crate::Money) uses to parse and format amounts.
+
+/// An ISO 4217 currency.
+///
+/// Serialised and stored in the database as its uppercase three letter code.
+///
+/// # Examples
+///
+/// ```rust
+/// use lib_domain::Currency;
+///
+/// let currency: Currency = "aud".parse()?;
+/// assert_eq!(currency, Currency::AUD);
+/// assert_eq!(currency.symbol(), "$");
+/// assert_eq!(Currency::JPY.decimal_places(), 0);
+/// # Ok::<(), lib_domain::CurrencyError>(())
+/// ```
+// Variants are named by their ISO 4217 code, as they are written everywhere else
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+pub enum Currency {
+    /// Australian dollar, the default currency of the ledger.
+    #[default]
+    AUD,
+    /// Canadian dollar.
+    CAD,
+    /// Chinese yuan renminbi.
+    CNY,
+    /// Euro.
+    EUR,
+    /// Pound sterling.
+    GBP,
+    /// Hong Kong dollar.
+    HKD,
+    /// Indian rupee.
+    INR,
+    /// Japanese yen, which has no minor unit.
+    JPY,
+    /// New Zealand dollar.
+    NZD,
+    /// Singapore dollar.
+    SGD,
+    /// United States dollar.
+    USD,
+}
+
+/// Error type for [`Currency`] parsing operations.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CurrencyError {
+    /// The provided string is not a supported ISO 4217 currency code.
+    #[error("Unknown currency code: {0}")]
+    UnknownCurrency(String),
+}
+
+impl Currency {
+    /// Returns all supported currencies, ordered by code.
+    pub fn all() -> &'static [Currency] {
+        &[
+            Currency::AUD,
+            Currency::CAD,
+            Currency::CNY,
+            Currency::EUR,
+            Currency::GBP,
+            Currency::HKD,
+            Currency::INR,
+            Currency::JPY,
+            Currency::NZD,
+            Currency::SGD,
+            Currency::USD,
+        ]
+    }
+
+    /// Returns the uppercase ISO 4217 code, e.g. `"AUD"`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::AUD => "AUD",
+            Currency::CAD => "CAD",
+            Currency::CNY => "CNY",
+            Currency::EUR => "EUR",
+            Currency::GBP => "GBP",
+            Currency::HKD => "HKD",
+            Currency::INR => "INR",
+            Currency::JPY => "JPY",
+            Currency::NZD => "NZD",
+            Currency::SGD => "SGD",
+            Currency::USD => "USD",
+        }
+    }
+
+    /// Returns the symbol amounts are written with, e.g. `"$"`. Several
+    /// currencies share the dollar and yen symbols.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Currency::AUD
+            | Currency::CAD
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::USD => "$",
+            Currency::CNY | Currency::JPY => "¥",
+            Currency::EUR => "€",
+            Currency::GBP => "£",
+            Currency::INR => "₹",
+        }
+    }
+
+    /// Returns the number of decimal places of the minor unit, e.g. 2 for
+    /// cents and 0 for the yen.
+    pub fn decimal_places(&self) -> u32 {
+        match self {
+            Currency::JPY => 0,
+            _ => 2,
+        }
+    }
+
+    /// Returns the number of minor units in one major unit, e.g. 100.
+    pub fn minor_per_major(&self) -> i64 {
+        10_i64.pow(self.decimal_places())
+    }
+
+    /// Returns a random currency for testing scenarios.
+    #[cfg(any(test, feature = "mock"))]
+    pub fn mock() -> Self {
+        use fake::Fake;
+
+        let all = Self::all();
+        all[(0..all.len()).fake::<usize>()]
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl std::str::FromStr for Currency {
+    type Err = CurrencyError;
+
+    /// Parses an ISO 4217 code (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.trim();
+
+        Self::all()
+            .iter()
+            .find(|currency| currency.code().eq_ignore_ascii_case(code))
+            .copied()
+            .ok_or_else(|| CurrencyError::UnknownCurrency(s.to_string()))
+    }
+}
+
+// SQLx trait implementations store the currency as its code in a TEXT column.
+impl sqlx::Type<sqlx::Sqlite> for Currency {
+    fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+        <String as sqlx::Type<sqlx::Sqlite>>::type_info()
+    }
+}
+
+impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for Currency {
+    fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        let raw = <String as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
+        Ok(raw
+            .parse::<Currency>()
+            .map_err(|e| format!("Invalid currency in database: {}", e))?)
+    }
+}
+
+impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for Currency {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::Sqlite as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <String as sqlx::Encode<'q, sqlx::Sqlite>>::encode(self.code().to_string(), buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_ignores_case_and_whitespace() {
+        assert_eq!(" usd ".parse::<Currency>().unwrap(), Currency::USD);
+        assert_eq!("Eur".parse::<Currency>().unwrap(), Currency::EUR);
+    }
+
+    #[test]
+    fn from_str_rejects_unknown_codes() {
+        let err = "XYZ".parse::<Currency>().unwrap_err();
+        assert_eq!(err, CurrencyError::UnknownCurrency("XYZ".to_string()));
+    }
+
+    #[test]
+    fn code_round_trips_for_all_currencies() {
+        for currency in Currency::all() {
+            assert_eq!(currency.code().parse::<Currency>().unwrap(), *currency);
+            assert_eq!(currency.to_string(), currency.code());
+        }
+    }
+
+    #[test]
+    fn minor_per_major_follows_decimal_places() {
+        assert_eq!(Currency::AUD.minor_per_major(), 100);
+        assert_eq!(Currency::JPY.minor_per_major(), 1);
+    }
+
+    #[test]
+    fn serde_uses_code() {
+        let json = serde_json::to_string(&Currency::GBP).unwrap();
+        assert_eq!(json, "\"GBP\"");
+        assert_eq!(
+            serde_json::from_str::<Currency>(&json).unwrap(),
+            Currency::GBP
+        );
+    }
+
+    #[test]
+    fn mock_returns_supported_currency() {
+        assert!(Currency::all().contains(&Currency::mock()));
+    }
+}
@@ -0,0 +1,203 @@
+//! # Exchange Rate Domain Type
+//!
+//! This module defines [`ExchangeRate`], the price of one unit of a currency in
+//! another. Rates are held as a whole number of hundred millionths, so
+//! converting [`Money`](crate::Money) stays in integer arithmetic and only
+//! rounds once, to the minor unit of the target currency.
+
+use crate::{Currency, MoneyError};
+
+/// The price of one major unit of `from` in major units of `to`, e.g.
+/// 1 AUD = 0.6543 USD.
+///
+/// # Examples
+///
+/// ```rust
+/// use lib_domain::{Currency, ExchangeRate, Money};
+///
+/// let rate = ExchangeRate::parse(Currency::AUD, Currency::USD, "0.6543")?;
+/// let converted = Money::new(10_000, Currency::AUD).convert(&rate)?;
+///
+/// assert_eq!(converted, Money::new(6_543, Currency::USD));
+/// assert_eq!(rate.to_string(), "1 AUD = 0.6543 USD");
+/// # Ok::<(), lib_domain::MoneyError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ExchangeRate {
+    from: Currency,
+    to: Currency,
+    rate: i64,
+}
+
+impl ExchangeRate {
+    /// Number of decimal places a rate is held to.
+    pub const DECIMAL_PLACES: u32 = 8;
+
+    /// A rate of exactly one, in the units [`rate`](Self::rate) is held in.
+    pub const ONE: i64 = 10_i64.pow(Self::DECIMAL_PLACES);
+
+    /// Creates a rate from hundred millionths, e.g. `65_430_000` for 0.6543.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidExchangeRate`] if the rate is not positive
+    /// or both currencies are the same.
+    pub fn new(from: Currency, to: Currency, rate: i64) -> Result<Self, MoneyError> {
+        if rate <= 0 || from == to {
+            return Err(MoneyError::InvalidExchangeRate(format!(
+                "{from} to {to} at {rate}e-{}",
+                Self::DECIMAL_PLACES
+            )));
+        }
+
+        Ok(Self { from, to, rate })
+    }
+
+    /// Parses a decimal rate such as `"0.6543"` or `"1.5"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidExchangeRate`] if the text is not a
+    /// positive decimal with at most eight decimal places, or both currencies
+    /// are the same.
+    pub fn parse<S: AsRef<str>>(from: Currency, to: Currency, text: S) -> Result<Self, MoneyError> {
+        let text = text.as_ref().trim();
+        let invalid = || MoneyError::InvalidExchangeRate(format!("{from} to {to} at {text}"));
+
+        let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+        let places = Self::DECIMAL_PLACES as usize;
+        if whole.is_empty()
+            || fraction.len() > places
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let whole: i64 = whole.parse().map_err(|_| invalid())?;
+        let fraction: i64 = format!("{fraction:0<places$}")
+            .parse()
+            .map_err(|_| invalid())?;
+        let rate = whole
+            .checked_mul(Self::ONE)
+            .and_then(|rate| rate.checked_add(fraction))
+            .ok_or_else(invalid)?;
+
+        Self::new(from, to, rate)
+    }
+
+    /// Returns the currency converted from.
+    #[must_use]
+    pub fn from(&self) -> Currency {
+        self.from
+    }
+
+    /// Returns the currency converted to.
+    #[must_use]
+    pub fn to(&self) -> Currency {
+        self.to
+    }
+
+    /// Returns the rate in hundred millionths.
+    #[must_use]
+    pub fn rate(&self) -> i64 {
+        self.rate
+    }
+
+    /// Returns the rate in the other direction, rounded to eight decimal places.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidExchangeRate`] if the inverse is too small
+    /// or too large to hold.
+    pub fn inverse(&self) -> Result<Self, MoneyError> {
+        let one = i128::from(Self::ONE);
+        let inverse = round_div(one * one, i128::from(self.rate));
+        let inverse = i64::try_from(inverse)
+            .map_err(|_| MoneyError::InvalidExchangeRate(format!("inverse of {self}")))?;
+
+        Self::new(self.to, self.from, inverse)
+    }
+}
+
+/// Divides, rounding halves away from zero.
+pub(crate) fn round_div(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+
+    if remainder.abs() * 2 >= denominator.abs() {
+        quotient + numerator.signum() * denominator.signum()
+    } else {
+        quotient
+    }
+}
+
+/// Formats the rate as `1 AUD = 0.6543 USD`, without trailing zeros.
+impl std::fmt::Display for ExchangeRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let whole = self.rate / Self::ONE;
+        let places = Self::DECIMAL_PLACES as usize;
+        let fraction = format!("{:0places$}", self.rate % Self::ONE);
+        let fraction = fraction.trim_end_matches('0');
+
+        if fraction.is_empty() {
+            write!(f, "1 {} = {whole} {}", self.from, self.to)
+        } else {
+            write!(f, "1 {} = {whole}.{fraction} {}", self.from, self.to)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_decimal_rates() {
+        let rate = ExchangeRate::parse(Currency::AUD, Currency::JPY, "98.12345678").unwrap();
+
+        assert_eq!(rate.rate(), 9_812_345_678);
+        assert_eq!(rate.from(), Currency::AUD);
+        assert_eq!(rate.to(), Currency::JPY);
+        assert_eq!(
+            ExchangeRate::parse(Currency::USD, Currency::EUR, "2")
+                .unwrap()
+                .rate(),
+            200_000_000
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_rates() {
+        for text in ["", "0", "-1", "0.123456789", "abc", "1.2.3"] {
+            assert!(
+                matches!(
+                    ExchangeRate::parse(Currency::AUD, Currency::USD, text),
+                    Err(MoneyError::InvalidExchangeRate(_))
+                ),
+                "{text}"
+            );
+        }
+        assert!(ExchangeRate::parse(Currency::AUD, Currency::AUD, "1").is_err());
+    }
+
+    #[test]
+    fn inverse_swaps_currencies() {
+        let rate = ExchangeRate::parse(Currency::AUD, Currency::USD, "0.8").unwrap();
+        let inverse = rate.inverse().unwrap();
+
+        assert_eq!(inverse.from(), Currency::USD);
+        assert_eq!(inverse.to(), Currency::AUD);
+        assert_eq!(inverse.to_string(), "1 USD = 1.25 AUD");
+    }
+
+    #[test]
+    fn round_div_rounds_half_away_from_zero() {
+        assert_eq!(round_div(5, 2), 3);
+        assert_eq!(round_div(-5, 2), -3);
+        assert_eq!(round_div(4, 3), 1);
+        assert_eq!(round_div(-4, 3), -1);
+    }
+}
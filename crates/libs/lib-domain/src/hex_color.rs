@@ -121,7 +121,7 @@ impl HexColor {
     }
 
     /// Generates a random colour for testing scenarios.
-    #[cfg(test)]
+    #[cfg(any(test, feature = "mock"))]
     pub fn mock() -> Self {
         use fake::Fake;
         use fake::faker::color::en::HexColor as FakeHex;
@@ -131,17 +131,13 @@ impl HexColor {
     }
 
     // Generate a random option colour or None for testing scenarios.
-    #[cfg(test)]
+    #[cfg(any(test, feature = "mock"))]
     pub fn mock_with_option() -> Option<Self> {
         use fake::Fake;
         use fake::faker::boolean::en::Boolean;
 
         let is_some: bool = Boolean(50).fake(); // 50% chance of Some
-        if is_some {
-            Some(Self::mock())
-        } else {
-            None
-        }
+        if is_some { Some(Self::mock()) } else { None }
     }
 }
 
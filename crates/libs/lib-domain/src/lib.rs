@@ -13,6 +13,12 @@
 //! - [`RowID`] - Time-ordered UUID v7 identifiers for database rows
 //! - [`UrlSlug`] - URL-safe identifiers for web-friendly resource names
 //! - [`HexColor`] - Validated hexadecimal RGB colour representation
+//! - [`Markdown`] - Validated markdown descriptions, rendered to sanitised HTML
+//! - [`Money`] - Amounts in integer minor units with their [`Currency`]
+//! - [`ExchangeRate`] - The price of one currency in another, for converting [`Money`]
+//! - [`CryptoAmount`] - Quantities of a [`CryptoAsset`] to eighteen decimal places,
+//!   valued in a currency at an [`AssetPrice`]
+//! - [`QuickEntry`] - Transaction preview parsed from a line of quick entry text
 //!
 //! ## Design Principles
 //!
@@ -34,6 +40,14 @@
 mod row_id;
 pub use row_id::{RowID, RowIDError};
 
+/// Public identifier type, the form of a [`RowID`] shown outside the ledger.
+///
+/// [`PublicID`] is a RowID encrypted under the ledger's [`PublicIdKey`], so
+/// records can be named in responses, URLs and exports without giving away
+/// their RowIDs or when they were created.
+mod public_id;
+pub use public_id::{PUBLIC_ID_LENGTH, PublicID, PublicIdError, PublicIdKey};
+
 /// Category types used for classifying financial transactions.
 ///
 /// [`CategoryTypes`] represents the fundamental accounting categories
@@ -60,4 +74,65 @@ mod hex_color;
 /// providing convenient access to individual RGB channels. Useful for
 /// theming, categorisation, and any feature that requires precise colour
 /// handling across the application.
-pub use hex_color::{HexColor, HexColorError};
\ No newline at end of file
+pub use hex_color::{HexColor, HexColorError};
+
+mod markdown;
+/// Validated markdown for descriptions, rendered to sanitised HTML.
+///
+/// [`Markdown`] keeps descriptions short and to plain formatting and safe
+/// links, and renders any stored markdown to HTML that is safe to put in a
+/// web page, escaping raw HTML and dropping images and unsafe links.
+pub use markdown::{MAX_MARKDOWN_LENGTH, Markdown, MarkdownError};
+
+mod quick_entry;
+/// Transaction preview parsed from keyboard-speed quick entry text.
+///
+/// [`QuickEntry`] turns a line such as `12.50 coffee yesterday #food @visa`
+/// into an amount, payee, date, tags and account, so clients can show the
+/// parsed result for confirmation before a transaction is created.
+pub use quick_entry::{QuickEntry, QuickEntryError};
+
+mod currency;
+/// ISO 4217 currency codes with their symbols and minor units.
+///
+/// [`Currency`] is stored and serialised as its three letter code, and tells
+/// [`Money`] how many decimal places to parse and format.
+pub use currency::{Currency, CurrencyError};
+
+mod money;
+/// Money amounts held as integer minor units in a currency.
+///
+/// [`Money`] avoids floating point rounding by keeping amounts in minor units
+/// such as cents. Arithmetic is checked for overflow and refuses to mix
+/// currencies, and amounts can be parsed from text like `$1,234.56`.
+pub use money::{Money, MoneyError};
+
+mod exchange_rate;
+/// Exchange rates between two currencies.
+///
+/// [`ExchangeRate`] holds a rate to eight decimal places as an integer, so
+/// [`Money::convert`] can convert amounts without floating point rounding.
+pub use exchange_rate::ExchangeRate;
+
+mod crypto_asset;
+/// Crypto currencies and tokens with their tickers and smallest units.
+///
+/// [`CryptoAsset`] is stored and serialised as its ticker, and tells
+/// [`CryptoAmount`] how many decimal places, up to eighteen, to parse and
+/// format.
+pub use crypto_asset::{CryptoAsset, CryptoAssetError};
+
+mod crypto_amount;
+/// Quantities of a crypto asset held as 128 bit smallest units.
+///
+/// [`CryptoAmount`] keeps holdings such as ether exact to the last wei, which
+/// does not fit in the 64 bits [`Money`] uses.
+pub use crypto_amount::CryptoAmount;
+
+mod asset_price;
+/// Prices of crypto assets in a currency.
+///
+/// [`AssetPrice`] holds a price to eighteen decimal places, so long-tail
+/// tokens priced at a tiny fraction of a cent value [`CryptoAmount`]s
+/// without rounding to zero.
+pub use asset_price::AssetPrice;
@@ -0,0 +1,243 @@
+//! # Markdown Domain Type
+//!
+//! This module defines [`Markdown`], validated markdown for category and
+//! transaction descriptions, and renders it to HTML that is safe to put in a
+//! web page.
+//!
+//! Descriptions are stored as the markdown that was written, and only
+//! rendered on the way out, such as by the REST gateway. Validation keeps
+//! them short and to the plain features a description needs: emphasis, lists,
+//! quotes, code, strikethrough and links. Raw HTML and images are refused,
+//! and links must be `http`, `https` or `mailto`.
+//!
+//! Rendering sanitises as well, so descriptions stored before validation
+//! existed are safe too: raw HTML is escaped and shown as text, images are
+//! replaced by their alt text, and unsafe links by their text.
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// The most characters a markdown description may hold.
+pub const MAX_MARKDOWN_LENGTH: usize = 2_000;
+
+/// URL schemes links may use.
+const SAFE_LINK_SCHEMES: &[&str] = &["http:", "https:", "mailto:"];
+
+/// Markdown that passed validation, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Markdown(String);
+
+/// Errors that can occur when validating [`Markdown`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MarkdownError {
+    /// The markdown is longer than [`MAX_MARKDOWN_LENGTH`] characters.
+    #[error("Markdown is {0} characters, more than the {MAX_MARKDOWN_LENGTH} allowed")]
+    TooLong(usize),
+    /// The markdown contains raw HTML.
+    #[error("Markdown cannot contain raw HTML: {0}")]
+    RawHtml(String),
+    /// The markdown contains an image.
+    #[error("Markdown cannot contain images: {0}")]
+    Image(String),
+    /// A link does not use a safe scheme.
+    #[error("Markdown links must start with http:, https: or mailto: {0}")]
+    UnsafeLink(String),
+}
+
+/// The parser options of the features descriptions may use.
+fn options() -> Options {
+    Options::ENABLE_STRIKETHROUGH
+}
+
+/// Whether a link to `url` is safe to follow from a web page.
+fn is_safe_link(url: &str) -> bool {
+    let url = url.trim_start().to_ascii_lowercase();
+    SAFE_LINK_SCHEMES
+        .iter()
+        .any(|scheme| url.starts_with(scheme))
+}
+
+impl Markdown {
+    /// Validates markdown as a description.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MarkdownError`] if the markdown is too long, contains raw
+    /// HTML or an image, or links to a URL that is not `http`, `https` or
+    /// `mailto`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_domain::{Markdown, MarkdownError};
+    ///
+    /// let description = Markdown::parse("Weekly shop at the *markets*")?;
+    /// assert_eq!(description.to_html(), "<p>Weekly shop at the <em>markets</em></p>\n");
+    ///
+    /// assert!(matches!(Markdown::parse("<script>alert(1)</script>"), Err(MarkdownError::RawHtml(_))));
+    /// # Ok::<(), MarkdownError>(())
+    /// ```
+    pub fn parse<S: Into<String>>(input: S) -> Result<Self, MarkdownError> {
+        let input = input.into();
+
+        let length = input.chars().count();
+        if length > MAX_MARKDOWN_LENGTH {
+            return Err(MarkdownError::TooLong(length));
+        }
+
+        for event in Parser::new_ext(&input, options()) {
+            match event {
+                Event::Html(html) | Event::InlineHtml(html) => {
+                    return Err(MarkdownError::RawHtml(html.trim().to_string()));
+                }
+                Event::Start(Tag::Image { dest_url, .. }) => {
+                    return Err(MarkdownError::Image(dest_url.to_string()));
+                }
+                Event::Start(Tag::Link { dest_url, .. }) if !is_safe_link(&dest_url) => {
+                    return Err(MarkdownError::UnsafeLink(dest_url.to_string()));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self(input))
+    }
+
+    /// Returns the markdown as written.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consumes the value and returns the markdown as written.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Renders the markdown to HTML.
+    pub fn to_html(&self) -> String {
+        Self::sanitised_html(&self.0)
+    }
+
+    /// Renders markdown that may not have been validated to HTML that is
+    /// safe to put in a web page, see the [module documentation](self).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_domain::Markdown;
+    ///
+    /// let html = Markdown::sanitised_html("[Pay me](javascript:alert(1)) <b>now</b>");
+    /// assert_eq!(html, "<p>Pay me &lt;b&gt;now&lt;/b&gt;</p>\n");
+    /// ```
+    pub fn sanitised_html(markdown: &str) -> String {
+        // Whether each open link was kept, so its end is dropped with it
+        let mut links = Vec::new();
+
+        let events = Parser::new_ext(markdown, options()).filter_map(|event| match event {
+            Event::Html(html) | Event::InlineHtml(html) => Some(Event::Text(html)),
+            Event::Start(Tag::Image { .. }) | Event::End(TagEnd::Image) => None,
+            Event::Start(Tag::Link { ref dest_url, .. }) => {
+                let safe = is_safe_link(dest_url);
+                links.push(safe);
+                safe.then_some(event)
+            }
+            Event::End(TagEnd::Link) => links.pop().unwrap_or_default().then_some(event),
+            Event::Start(Tag::HtmlBlock) | Event::End(TagEnd::HtmlBlock) => None,
+            event => Some(event),
+        });
+
+        let mut html = String::with_capacity(markdown.len() * 3 / 2);
+        pulldown_cmark::html::push_html(&mut html, events);
+        html
+    }
+}
+
+impl std::fmt::Display for Markdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::str::FromStr for Markdown {
+    type Err = MarkdownError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<Markdown> for String {
+    fn from(value: Markdown) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<str> for Markdown {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_plain_features() {
+        let markdown = "Shared with **Sam**, see [the list](https://example.com/list)\n\n- milk\n- ~~bread~~\n\n> `code`";
+
+        let parsed = Markdown::parse(markdown).unwrap();
+
+        assert_eq!(parsed.as_str(), markdown);
+        let html = parsed.to_html();
+        assert!(html.contains("<strong>Sam</strong>"));
+        assert!(html.contains(r#"<a href="https://example.com/list">the list</a>"#));
+        assert!(html.contains("<del>bread</del>"));
+    }
+
+    #[test]
+    fn parse_refuses_what_descriptions_cannot_hold() {
+        let too_long = "a".repeat(MAX_MARKDOWN_LENGTH + 1);
+
+        assert_eq!(
+            Markdown::parse(too_long),
+            Err(MarkdownError::TooLong(MAX_MARKDOWN_LENGTH + 1))
+        );
+        assert!(Markdown::parse("a".repeat(MAX_MARKDOWN_LENGTH)).is_ok());
+        assert!(matches!(
+            Markdown::parse("Hi <img src=x onerror=alert(1)>"),
+            Err(MarkdownError::RawHtml(_))
+        ));
+        assert!(matches!(
+            Markdown::parse("![receipt](https://example.com/r.png)"),
+            Err(MarkdownError::Image(_))
+        ));
+        assert!(matches!(
+            Markdown::parse("[x](JavaScript:alert(1))"),
+            Err(MarkdownError::UnsafeLink(_))
+        ));
+        assert!(matches!(
+            Markdown::parse("[x](/relative)"),
+            Err(MarkdownError::UnsafeLink(_))
+        ));
+    }
+
+    #[test]
+    fn sanitised_html_escapes_html_and_drops_images() {
+        let html = Markdown::sanitised_html(
+            "<div onclick=\"steal()\">hi</div>\n\n![alt text](https://example.com/x.png)",
+        );
+
+        assert!(!html.contains("<div"));
+        assert!(html.contains("&lt;div onclick="));
+        assert!(!html.contains("<img"));
+        assert!(html.contains("alt text"));
+    }
+
+    #[test]
+    fn length_counts_characters_not_bytes() {
+        let emoji = "💸".repeat(MAX_MARKDOWN_LENGTH);
+
+        assert!(Markdown::parse(emoji).is_ok());
+    }
+}
@@ -0,0 +1,631 @@
+//! # Money Domain Type
+//!
+//! This module defines [`Money`], an amount held as a whole number of minor
+//! units (e.g. cents) together with its [`Currency`]. Keeping amounts as
+//! integers avoids the rounding errors of floating point, and carrying the
+//! currency stops amounts in different currencies being added together.
+//!
+//! ## Example Usage
+//!
+//! ```rust
+//! use lib_domain::{Currency, Money};
+//!
+//! let rent = Money::parse("-$1,234.56", Currency::AUD)?;
+//! let refund = Money::new(5_000, Currency::AUD);
+//!
+//! let total = rent.checked_add(refund)?;
+//! assert_eq!(total.amount(), -118_456);
+//! assert_eq!(total.to_string(), "-$1,184.56");
+//! assert_eq!(total.format_with_code(), "-1,184.56 AUD");
+//! # Ok::<(), lib_domain::MoneyError>(())
+//! ```
+
+use crate::{CryptoAsset, Currency, ExchangeRate};
+
+/// An amount of money in a single currency.
+///
+/// The amount is a signed number of minor units of the currency, negative for
+/// money spent. Arithmetic is checked: it fails on overflow or when the
+/// currencies differ, rather than wrapping or silently mixing currencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Money {
+    amount: i64,
+    currency: Currency,
+}
+
+/// Errors that can occur when parsing or calculating with [`Money`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum MoneyError {
+    /// The input was empty or whitespace only.
+    #[error("Money amount cannot be empty")]
+    Empty,
+
+    /// The input is not a valid amount, or has more decimal places than the
+    /// currency's minor unit.
+    #[error("Invalid money amount: {0}")]
+    InvalidAmount(String),
+
+    /// The input has a symbol shared by several currencies, none of which is
+    /// the expected currency.
+    #[error("Currency symbol is ambiguous, give a currency code: {0}")]
+    AmbiguousCurrency(String),
+
+    /// Two amounts in different currencies were combined.
+    #[error("Cannot combine {0} and {1} amounts")]
+    CurrencyMismatch(Currency, Currency),
+
+    /// Two amounts of different crypto assets were combined, or an amount
+    /// was valued at the price of another asset.
+    #[error("Cannot combine {0} and {1} amounts")]
+    AssetMismatch(CryptoAsset, CryptoAsset),
+
+    /// The result does not fit in 64 bits of minor units, or 128 bits of
+    /// crypto units.
+    #[error("Money amount is out of range")]
+    Overflow,
+
+    /// An exchange rate is not positive, or converts a currency to itself.
+    #[error("Invalid exchange rate: {0}")]
+    InvalidExchangeRate(String),
+}
+
+impl Money {
+    /// Creates an amount from minor units, e.g. `Money::new(1_250, Currency::AUD)`
+    /// is $12.50.
+    #[must_use]
+    pub fn new(amount: i64, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Creates a zero amount in a currency.
+    #[must_use]
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(0, currency)
+    }
+
+    /// Parses a written amount such as `$1,234.56`, `-12.50`, `(12.50)`,
+    /// `USD 20` or `€5`.
+    ///
+    /// A currency code before or after the number sets the currency. Without
+    /// one, `currency` is used, unless the symbol belongs to a single other
+    /// currency, e.g. `£` is always pounds. Negative amounts are written with
+    /// a leading `-` or in parentheses.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError`] if the input is empty, is not a number, has more
+    /// decimal places than the currency allows, has a symbol shared by other
+    /// currencies but not `currency`, or is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_domain::{Currency, Money};
+    ///
+    /// assert_eq!(Money::parse("$1,234.56", Currency::AUD)?, Money::new(123_456, Currency::AUD));
+    /// assert_eq!(Money::parse("(12.5)", Currency::AUD)?, Money::new(-1_250, Currency::AUD));
+    /// assert_eq!(Money::parse("20 usd", Currency::AUD)?, Money::new(2_000, Currency::USD));
+    /// assert_eq!(Money::parse("£3", Currency::AUD)?, Money::new(300, Currency::GBP));
+    /// # Ok::<(), lib_domain::MoneyError>(())
+    /// ```
+    pub fn parse<S: AsRef<str>>(input: S, currency: Currency) -> Result<Self, MoneyError> {
+        let input = input.as_ref().trim();
+        if input.is_empty() {
+            return Err(MoneyError::Empty);
+        }
+        let invalid = || MoneyError::InvalidAmount(input.to_string());
+
+        // Accounting style negatives, e.g. "(12.50)"
+        let (mut negative, mut rest) = match input
+            .strip_prefix('(')
+            .and_then(|inner| inner.strip_suffix(')'))
+        {
+            Some(inner) => (true, inner),
+            None => (false, input),
+        };
+
+        // Signs, codes and symbols may come in any order before the number
+        let mut signed = negative;
+        let mut code = None;
+        let mut symbol = None;
+        loop {
+            rest = rest.trim_start();
+
+            if !signed && let Some(unsigned) = rest.strip_prefix(['-', '+']) {
+                negative = rest.starts_with('-');
+                signed = true;
+                rest = unsigned;
+            } else if code.is_none()
+                && let Some((found, after)) = Self::strip_code_prefix(rest)
+            {
+                code = Some(found);
+                rest = after;
+            } else if symbol.is_none()
+                && let Some(found) = Currency::all()
+                    .iter()
+                    .map(Currency::symbol)
+                    .find(|found| rest.starts_with(found))
+            {
+                symbol = Some(found);
+                rest = &rest[found.len()..];
+            } else {
+                break;
+            }
+        }
+
+        // Codes may also follow the number, e.g. "12.50 AUD"
+        rest = rest.trim_end();
+        if code.is_none()
+            && let Some((before, found)) = Self::strip_code_suffix(rest)
+        {
+            code = Some(found);
+            rest = before.trim_end();
+        }
+
+        let currency = match (code, symbol) {
+            (Some(code), Some(symbol)) if code.symbol() != symbol => return Err(invalid()),
+            (Some(code), _) => code,
+            (None, Some(symbol)) if currency.symbol() != symbol => {
+                let mut matching = Currency::all()
+                    .iter()
+                    .filter(|other| other.symbol() == symbol);
+                match (matching.next(), matching.next()) {
+                    (Some(only), None) => *only,
+                    _ => return Err(MoneyError::AmbiguousCurrency(input.to_string())),
+                }
+            }
+            (None, _) => currency,
+        };
+
+        let digits = rest.replace(',', "");
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits.as_str(), None),
+        };
+
+        let places = currency.decimal_places() as usize;
+        let is_digits = |text: &str| text.chars().all(|c| c.is_ascii_digit());
+        if whole.is_empty()
+            || !is_digits(whole)
+            || fraction.is_some_and(|fraction| {
+                fraction.is_empty() || fraction.len() > places || !is_digits(fraction)
+            })
+        {
+            return Err(invalid());
+        }
+
+        let whole: i64 = whole.parse().map_err(|_| MoneyError::Overflow)?;
+        let fraction: i64 = match fraction {
+            // Right pad so "5" is read as 50 cents rather than 5 cents
+            Some(fraction) => format!("{fraction:0<places$}")
+                .parse()
+                .map_err(|_| invalid())?,
+            None => 0,
+        };
+
+        // Apply the sign before the fraction so the most negative amount fits
+        let amount = whole
+            .checked_mul(currency.minor_per_major())
+            .and_then(|amount| match negative {
+                true => amount.checked_neg()?.checked_sub(fraction),
+                false => amount.checked_add(fraction),
+            })
+            .ok_or(MoneyError::Overflow)?;
+
+        Ok(Self::new(amount, currency))
+    }
+
+    /// Splits a leading currency code, e.g. "AUD 12" or "aud12", from the rest.
+    fn strip_code_prefix(text: &str) -> Option<(Currency, &str)> {
+        let (code, rest) = (text.get(..3)?, text.get(3..)?);
+        if rest.starts_with(|c: char| c.is_alphabetic()) {
+            return None;
+        }
+
+        Some((code.parse().ok()?, rest))
+    }
+
+    /// Splits a trailing currency code, e.g. "12 AUD", from the rest.
+    fn strip_code_suffix(text: &str) -> Option<(&str, Currency)> {
+        let split = text.len().checked_sub(3)?;
+        let (rest, code) = (text.get(..split)?, text.get(split..)?);
+        if rest.ends_with(|c: char| c.is_alphabetic()) {
+            return None;
+        }
+
+        Some((rest, code.parse().ok()?))
+    }
+
+    /// Returns the amount in minor units of the currency.
+    #[must_use]
+    pub fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    /// Returns the currency of the amount.
+    #[must_use]
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Returns true if the amount is zero.
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// Returns true if the amount is money spent.
+    #[must_use]
+    pub fn is_negative(&self) -> bool {
+        self.amount < 0
+    }
+
+    /// Returns true if the amount is money received.
+    #[must_use]
+    pub fn is_positive(&self) -> bool {
+        self.amount > 0
+    }
+
+    /// Adds two amounts in the same currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the currencies differ, or
+    /// [`MoneyError::Overflow`] if the sum is out of range.
+    pub fn checked_add(self, other: Money) -> Result<Self, MoneyError> {
+        self.same_currency(other)?;
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Self::new(amount, self.currency))
+    }
+
+    /// Subtracts an amount in the same currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the currencies differ, or
+    /// [`MoneyError::Overflow`] if the difference is out of range.
+    pub fn checked_sub(self, other: Money) -> Result<Self, MoneyError> {
+        self.same_currency(other)?;
+        let amount = self
+            .amount
+            .checked_sub(other.amount)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Self::new(amount, self.currency))
+    }
+
+    /// Multiplies the amount by a whole number, e.g. a quantity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if the product is out of range.
+    pub fn checked_mul(self, factor: i64) -> Result<Self, MoneyError> {
+        let amount = self
+            .amount
+            .checked_mul(factor)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Self::new(amount, self.currency))
+    }
+
+    /// Flips the sign of the amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] for the most negative amount.
+    pub fn checked_neg(self) -> Result<Self, MoneyError> {
+        let amount = self.amount.checked_neg().ok_or(MoneyError::Overflow)?;
+        Ok(Self::new(amount, self.currency))
+    }
+
+    /// Adds up amounts that are all in `currency`. An empty list sums to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if any amount is in another
+    /// currency, or [`MoneyError::Overflow`] if the total is out of range.
+    pub fn checked_sum<I>(amounts: I, currency: Currency) -> Result<Self, MoneyError>
+    where
+        I: IntoIterator<Item = Money>,
+    {
+        amounts
+            .into_iter()
+            .try_fold(Self::zero(currency), |total, amount| {
+                total.checked_add(amount)
+            })
+    }
+
+    /// Converts the amount to another currency, rounding half away from zero
+    /// to the minor unit of the new currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the rate does not convert
+    /// from this amount's currency, or [`MoneyError::Overflow`] if the
+    /// converted amount is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_domain::{Currency, ExchangeRate, Money};
+    ///
+    /// let rate = ExchangeRate::parse(Currency::AUD, Currency::JPY, "98.5")?;
+    ///
+    /// assert_eq!(Money::new(1_250, Currency::AUD).convert(&rate)?, Money::new(1_231, Currency::JPY));
+    /// # Ok::<(), lib_domain::MoneyError>(())
+    /// ```
+    pub fn convert(self, rate: &ExchangeRate) -> Result<Self, MoneyError> {
+        if self.currency != rate.from() {
+            return Err(MoneyError::CurrencyMismatch(self.currency, rate.from()));
+        }
+
+        let numerator = i128::from(self.amount)
+            * i128::from(rate.rate())
+            * i128::from(rate.to().minor_per_major());
+        let denominator =
+            i128::from(self.currency.minor_per_major()) * i128::from(ExchangeRate::ONE);
+
+        let amount = crate::exchange_rate::round_div(numerator, denominator);
+        let amount = i64::try_from(amount).map_err(|_| MoneyError::Overflow)?;
+
+        Ok(Self::new(amount, rate.to()))
+    }
+
+    fn same_currency(&self, other: Money) -> Result<(), MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::CurrencyMismatch(self.currency, other.currency));
+        }
+        Ok(())
+    }
+
+    /// Formats the unsigned amount in major units with thousands separators,
+    /// e.g. `1,234.56`.
+    fn format_major(&self) -> String {
+        let per_major = self.currency.minor_per_major().unsigned_abs();
+        let minor = self.amount.unsigned_abs();
+        let whole = (minor / per_major).to_string();
+
+        let mut grouped = String::with_capacity(whole.len() + whole.len() / 3);
+        for (index, digit) in whole.chars().enumerate() {
+            if index > 0 && (whole.len() - index).is_multiple_of(3) {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+
+        match self.currency.decimal_places() as usize {
+            0 => grouped,
+            places => format!("{grouped}.{:0places$}", minor % per_major),
+        }
+    }
+
+    /// Formats the amount with its currency code instead of a symbol, e.g.
+    /// `-1,234.56 AUD`, for when the symbol would be ambiguous.
+    #[must_use]
+    pub fn format_with_code(&self) -> String {
+        let sign = if self.is_negative() { "-" } else { "" };
+        format!("{sign}{} {}", self.format_major(), self.currency.code())
+    }
+
+    /// Generates a random amount for testing scenarios.
+    #[cfg(any(test, feature = "mock"))]
+    pub fn mock() -> Self {
+        use fake::Fake;
+
+        Self::new((-1_000_000..1_000_000).fake(), Currency::mock())
+    }
+}
+
+/// Amounts in the same currency are ordered by value. Amounts in different
+/// currencies cannot be compared.
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (self.currency == other.currency).then(|| self.amount.cmp(&other.amount))
+    }
+}
+
+/// Formats the amount with its currency symbol, e.g. `-$1,234.56`.
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        write!(f, "{sign}{}{}", self.currency.symbol(), self.format_major())
+    }
+}
+
+impl std::str::FromStr for Money {
+    type Err = MoneyError;
+
+    /// Parses an amount, in the default currency unless another is written.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Money::parse(s, Currency::default())
+    }
+}
+
+// SQLx trait implementations store the amount as INTEGER minor units, as the
+// transaction amount columns are. The currency is not stored with it, so
+// decoded amounts are in the default currency. Where the currency can vary,
+// store it in its own column and rebuild the amount with `Money::new`.
+impl sqlx::Type<sqlx::Sqlite> for Money {
+    fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+        <i64 as sqlx::Type<sqlx::Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &sqlx::sqlite::SqliteTypeInfo) -> bool {
+        <i64 as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+    }
+}
+
+impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for Money {
+    fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        let amount = <i64 as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
+        Ok(Money::new(amount, Currency::default()))
+    }
+}
+
+impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for Money {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::Sqlite as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <i64 as sqlx::Encode<'q, sqlx::Sqlite>>::encode(self.amount, buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aud(amount: i64) -> Money {
+        Money::new(amount, Currency::AUD)
+    }
+
+    #[test]
+    fn parse_accepts_common_formats() {
+        let parse = |input| Money::parse(input, Currency::AUD).unwrap();
+
+        assert_eq!(parse("$1,234.56"), aud(123_456));
+        assert_eq!(parse("-$12.50"), aud(-1_250));
+        assert_eq!(parse("$-12.50"), aud(-1_250));
+        assert_eq!(parse("(12.5)"), aud(-1_250));
+        assert_eq!(parse("+7"), aud(700));
+        assert_eq!(parse("AUD 0.05"), aud(5));
+        assert_eq!(parse("-12 usd"), Money::new(-1_200, Currency::USD));
+    }
+
+    #[test]
+    fn parse_resolves_symbols() {
+        assert_eq!(
+            Money::parse("€5", Currency::AUD).unwrap(),
+            Money::new(500, Currency::EUR)
+        );
+        assert_eq!(
+            Money::parse("$5", Currency::USD).unwrap(),
+            Money::new(500, Currency::USD)
+        );
+        assert_eq!(
+            Money::parse("¥500", Currency::JPY).unwrap(),
+            Money::new(500, Currency::JPY)
+        );
+        assert!(matches!(
+            Money::parse("$5", Currency::EUR),
+            Err(MoneyError::AmbiguousCurrency(_))
+        ));
+        assert!(matches!(
+            Money::parse("£5 USD", Currency::AUD),
+            Err(MoneyError::InvalidAmount(_))
+        ));
+    }
+
+    #[test]
+    fn parse_rejects_invalid_amounts() {
+        let parse = |input| Money::parse(input, Currency::AUD);
+
+        assert_eq!(parse("  "), Err(MoneyError::Empty));
+        assert!(matches!(parse("12.345"), Err(MoneyError::InvalidAmount(_))));
+        assert!(matches!(parse("12."), Err(MoneyError::InvalidAmount(_))));
+        assert!(matches!(parse("1.2.3"), Err(MoneyError::InvalidAmount(_))));
+        assert!(matches!(parse("--5"), Err(MoneyError::InvalidAmount(_))));
+        assert!(matches!(parse("abc"), Err(MoneyError::InvalidAmount(_))));
+        assert!(matches!(
+            parse("¥1.5 JPY"),
+            Err(MoneyError::InvalidAmount(_))
+        ));
+        assert_eq!(parse("99999999999999999999"), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn display_groups_thousands() {
+        assert_eq!(aud(-123_456_789).to_string(), "-$1,234,567.89");
+        assert_eq!(aud(5).to_string(), "$0.05");
+        assert_eq!(
+            Money::new(1_234_567, Currency::JPY).to_string(),
+            "¥1,234,567"
+        );
+        assert_eq!(
+            Money::new(i64::MIN, Currency::USD).format_with_code(),
+            "-92,233,720,368,547,758.08 USD"
+        );
+        assert_eq!(
+            Money::parse("-92,233,720,368,547,758.08 USD", Currency::AUD),
+            Ok(Money::new(i64::MIN, Currency::USD))
+        );
+    }
+
+    #[test]
+    fn display_round_trips_through_parse() {
+        for _ in 0..100 {
+            let money = Money::mock();
+            assert_eq!(
+                Money::parse(money.format_with_code(), Currency::AUD).unwrap(),
+                money
+            );
+        }
+    }
+
+    #[test]
+    fn arithmetic_is_checked() {
+        assert_eq!(aud(1_000).checked_add(aud(-250)), Ok(aud(750)));
+        assert_eq!(aud(1_000).checked_sub(aud(250)), Ok(aud(750)));
+        assert_eq!(aud(250).checked_mul(3), Ok(aud(750)));
+        assert_eq!(aud(250).checked_neg(), Ok(aud(-250)));
+        assert_eq!(aud(i64::MAX).checked_add(aud(1)), Err(MoneyError::Overflow));
+        assert_eq!(aud(i64::MIN).checked_neg(), Err(MoneyError::Overflow));
+        assert_eq!(
+            aud(1).checked_add(Money::new(1, Currency::USD)),
+            Err(MoneyError::CurrencyMismatch(Currency::AUD, Currency::USD))
+        );
+    }
+
+    #[test]
+    fn convert_rounds_to_target_minor_unit() {
+        let to_usd = ExchangeRate::parse(Currency::AUD, Currency::USD, "0.6543").unwrap();
+        let to_aud = ExchangeRate::parse(Currency::JPY, Currency::AUD, "0.0102").unwrap();
+
+        assert_eq!(
+            aud(1_000).convert(&to_usd),
+            Ok(Money::new(654, Currency::USD))
+        );
+        assert_eq!(
+            aud(-1_001).convert(&to_usd),
+            Ok(Money::new(-655, Currency::USD))
+        );
+        assert_eq!(
+            Money::new(1_000, Currency::JPY).convert(&to_aud),
+            Ok(aud(1_020))
+        );
+        assert_eq!(
+            Money::new(1_000, Currency::USD).convert(&to_usd),
+            Err(MoneyError::CurrencyMismatch(Currency::USD, Currency::AUD))
+        );
+        let doubling = ExchangeRate::parse(Currency::AUD, Currency::USD, "2").unwrap();
+        assert_eq!(aud(i64::MAX).convert(&doubling), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn checked_sum_adds_same_currency() {
+        assert_eq!(
+            Money::checked_sum([aud(100), aud(-30)], Currency::AUD),
+            Ok(aud(70))
+        );
+        assert_eq!(
+            Money::checked_sum([], Currency::GBP),
+            Ok(Money::zero(Currency::GBP))
+        );
+        assert!(Money::checked_sum([Money::new(1, Currency::USD)], Currency::AUD).is_err());
+    }
+
+    #[test]
+    fn ordering_requires_same_currency() {
+        assert!(aud(100) > aud(-100));
+        assert_eq!(aud(100).partial_cmp(&Money::new(100, Currency::USD)), None);
+    }
+
+    #[test]
+    fn serde_round_trip_preserves_value() {
+        let money = aud(-1_250);
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(json, r#"{"amount":-1250,"currency":"AUD"}"#);
+        assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), money);
+    }
+}
@@ -0,0 +1,254 @@
+//! # PublicID Domain Type
+//!
+//! This module defines [`PublicID`], the identifier a record is known by
+//! outside the ledger, in RPC responses, URLs and exports, and
+//! [`PublicIdKey`], which maps between public IDs and [`RowID`]s.
+//!
+//! A [`RowID`] is a UUID v7, which starts with the time the row was created,
+//! so RowIDs handed out in a sequence give away when each record was made
+//! and in what order. A public ID is the RowID encrypted with a 128-bit
+//! block cipher under a key kept by the ledger, written as 22 base62
+//! characters:
+//!
+//! - **Short**: 22 characters rather than a UUID's 36
+//! - **URL-friendly**: only `0-9`, `A-Z` and `a-z`
+//! - **Non-sequential**: records created one after the other have unrelated
+//!   public IDs, and neither the time nor the RowID can be read from them
+//!   without the key
+//!
+//! The cipher is AES-128 applied to the RowID as a single block, a
+//! permutation of 128-bit values, so every RowID has exactly one public ID
+//! and back again. Nothing is stored per record, only the key, and a public
+//! ID that was not made with the same key decodes to an invalid RowID almost
+//! every time.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use lib_domain::{PublicID, PublicIdKey, RowID};
+//!
+//! let key = PublicIdKey::new([7; 16]);
+//! let id = RowID::new();
+//!
+//! let public_id = key.encode(id);
+//! assert_eq!(public_id.to_string().len(), 22);
+//!
+//! let parsed: PublicID = public_id.to_string().parse()?;
+//! assert_eq!(key.decode(parsed)?, id);
+//! # Ok::<(), lib_domain::PublicIdError>(())
+//! ```
+
+use aes::Aes128;
+use aes::cipher::{BlockDecrypt, BlockEncrypt, KeyInit};
+
+use crate::RowID;
+
+/// Characters of a public ID, in the order of their base62 digit values.
+const ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Length of every public ID, enough base62 digits for 128 bits.
+pub const PUBLIC_ID_LENGTH: usize = 22;
+
+/// The identifier a record is known by outside the ledger, see the
+/// [module documentation](self).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PublicID(u128);
+
+/// Errors that can occur when working with public IDs.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum PublicIdError {
+    /// The public ID is not 22 base62 characters.
+    #[error("Invalid public ID: {0}")]
+    InvalidFormat(String),
+
+    /// The public ID was not made with this key.
+    #[error("Unknown public ID: {0}")]
+    UnknownId(String),
+
+    /// The key is not 16 bytes.
+    #[error("Public ID key must be 16 bytes, got {0}")]
+    InvalidKeyLength(usize),
+}
+
+/// The key public IDs are encrypted with. Public IDs made with one key
+/// cannot be read with another, so a ledger keeps its key for good.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PublicIdKey([u8; 16]);
+
+impl PublicIdKey {
+    /// A key of these bytes.
+    pub const fn new(key: [u8; 16]) -> Self {
+        Self(key)
+    }
+
+    /// A key read from storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PublicIdError::InvalidKeyLength`] if `key` is not 16 bytes.
+    pub fn from_slice(key: &[u8]) -> Result<Self, PublicIdError> {
+        key.try_into()
+            .map(Self)
+            .map_err(|_| PublicIdError::InvalidKeyLength(key.len()))
+    }
+
+    /// The public ID of `id`.
+    pub fn encode(&self, id: RowID) -> PublicID {
+        let mut block = id.into_uuid().as_u128().to_be_bytes().into();
+        self.cipher().encrypt_block(&mut block);
+
+        PublicID(u128::from_be_bytes(block.into()))
+    }
+
+    /// The RowID `public_id` stands for.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PublicIdError::UnknownId`] if the public ID was not made
+    /// with this key.
+    pub fn decode(&self, public_id: PublicID) -> Result<RowID, PublicIdError> {
+        let mut block = public_id.0.to_be_bytes().into();
+        self.cipher().decrypt_block(&mut block);
+
+        let uuid = uuid::Uuid::from_u128(u128::from_be_bytes(block.into()));
+        RowID::try_from(uuid).map_err(|_| PublicIdError::UnknownId(public_id.to_string()))
+    }
+
+    /// The block cipher under this key.
+    fn cipher(&self) -> Aes128 {
+        Aes128::new(&self.0.into())
+    }
+}
+
+impl std::fmt::Debug for PublicIdKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PublicIdKey").field(&"[redacted]").finish()
+    }
+}
+
+impl std::str::FromStr for PublicID {
+    type Err = PublicIdError;
+
+    /// Parses the 22 base62 characters of a public ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PublicIdError::InvalidFormat`] if the string is not 22
+    /// base62 characters, or is beyond the largest 128-bit value.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || PublicIdError::InvalidFormat(s.to_string());
+        if s.len() != PUBLIC_ID_LENGTH {
+            return Err(invalid());
+        }
+
+        s.bytes()
+            .try_fold(0u128, |value, character| {
+                let digit = ALPHABET.iter().position(|&c| c == character)?;
+                value.checked_mul(62)?.checked_add(digit as u128)
+            })
+            .map(PublicID)
+            .ok_or_else(invalid)
+    }
+}
+
+impl std::fmt::Display for PublicID {
+    /// Formats the public ID as 22 base62 characters.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut digits = [b'0'; PUBLIC_ID_LENGTH];
+        let mut value = self.0;
+        for digit in digits.iter_mut().rev() {
+            *digit = ALPHABET[(value % 62) as usize];
+            value /= 62;
+        }
+
+        f.write_str(std::str::from_utf8(&digits).expect("The alphabet is ASCII"))
+    }
+}
+
+impl serde::Serialize for PublicID {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for PublicID {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_and_decode_round_trip() {
+        let key = PublicIdKey::new([42; 16]);
+
+        for _ in 0..100 {
+            let id = RowID::new();
+            let public_id = key.encode(id);
+
+            let parsed: PublicID = public_id.to_string().parse().unwrap();
+            assert_eq!(parsed, public_id);
+            assert_eq!(key.decode(parsed).unwrap(), id);
+        }
+    }
+
+    #[test]
+    fn public_ids_are_short_url_safe_and_unrelated() {
+        let key = PublicIdKey::new([42; 16]);
+        let first = key.encode(RowID::new()).to_string();
+        let second = key.encode(RowID::new()).to_string();
+
+        assert_eq!(first.len(), PUBLIC_ID_LENGTH);
+        assert!(first.bytes().all(|c| c.is_ascii_alphanumeric()));
+        // Consecutive RowIDs share their leading timestamp, public IDs do not
+        assert_ne!(first[..6], second[..6]);
+    }
+
+    #[test]
+    fn cipher_is_aes_128() {
+        // FIPS-197 appendix C.1
+        let key = PublicIdKey::new(std::array::from_fn(|i| i as u8));
+        let mut block = 0x0011_2233_4455_6677_8899_aabb_ccdd_eeff_u128
+            .to_be_bytes()
+            .into();
+
+        key.cipher().encrypt_block(&mut block);
+
+        assert_eq!(
+            u128::from_be_bytes(block.into()),
+            0x69c4_e0d8_6a7b_0430_d8cd_b780_70b4_c55a
+        );
+    }
+
+    #[test]
+    fn other_keys_cannot_decode() {
+        let id = RowID::new();
+        let public_id = PublicIdKey::new([1; 16]).encode(id);
+
+        // The wrong key decodes to something, a UUID v7 one time in 16, but
+        // never to the same RowID
+        assert_ne!(PublicIdKey::new([2; 16]).decode(public_id).ok(), Some(id));
+    }
+
+    #[test]
+    fn parse_rejects_invalid_public_ids() {
+        assert!("too-short".parse::<PublicID>().is_err());
+        assert!("0123456789abcdefghij-_".parse::<PublicID>().is_err());
+        // Beyond u128::MAX
+        assert!("zzzzzzzzzzzzzzzzzzzzzz".parse::<PublicID>().is_err());
+        assert!(RowID::new().to_string().parse::<PublicID>().is_err());
+    }
+
+    #[test]
+    fn key_from_slice_checks_length() {
+        assert!(PublicIdKey::from_slice(&[0; 16]).is_ok());
+        assert_eq!(
+            PublicIdKey::from_slice(&[0; 8]),
+            Err(PublicIdError::InvalidKeyLength(8))
+        );
+    }
+}
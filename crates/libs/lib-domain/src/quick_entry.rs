@@ -0,0 +1,545 @@
+//! # Quick Entry Domain Type
+//!
+//! This module defines [`QuickEntry`], the result of parsing a single line of
+//! free text typed at keyboard speed into the parts of a transaction. It lets
+//! a user type something like `12.50 coffee yesterday #food @visa` and get back
+//! a structured preview they can confirm before anything is saved.
+//!
+//! ## Syntax
+//!
+//! Tokens are separated by whitespace and may appear in any order:
+//!
+//! - **Amount**: The first number, e.g. `12.50`, `$1,234.56` or `+500`. Plain
+//!   amounts are money spent and are stored as negative minor units. Prefix
+//!   with `+` to record money received.
+//! - **Date**: `today`, `yesterday`, `tomorrow`, a weekday name (`mon`,
+//!   `friday`), an ISO date (`2025-01-31`) or a day/month date (`31/01` or
+//!   `31/01/2025`). Weekdays resolve to the most recent matching day. Defaults
+//!   to today when no date is given.
+//! - **Tags**: Words starting with `#`, e.g. `#food`. Any number are allowed.
+//! - **Account**: A single word starting with `@`, e.g. `@visa`.
+//! - **Payee**: Every remaining word, joined by single spaces.
+//!
+//! ## Example Usage
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use lib_domain::QuickEntry;
+//!
+//! let today = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+//! let entry = QuickEntry::parse_relative_to("12.50 coffee yesterday #food @visa", today)?;
+//!
+//! assert_eq!(entry.amount, -1250);
+//! assert_eq!(entry.payee, "coffee");
+//! assert_eq!(entry.date, NaiveDate::from_ymd_opt(2025, 3, 13).unwrap());
+//! assert_eq!(entry.tags, vec!["food".to_string()]);
+//! assert_eq!(entry.account.as_deref(), Some("visa"));
+//! # Ok::<(), lib_domain::QuickEntryError>(())
+//! ```
+
+use chrono::{Datelike, NaiveDate};
+
+/// Number of minor units (cents) in one major unit (dollar).
+const MINOR_UNITS: i64 = 100;
+
+/// Maximum number of decimal places accepted in an amount.
+const MAX_DECIMAL_PLACES: usize = 2;
+
+/// A transaction preview parsed from a single line of quick entry text.
+///
+/// The parsed values are not validated against the ledger, so the account and
+/// tags are the raw names the user typed. Callers are expected to resolve them
+/// and show the preview for confirmation before creating a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct QuickEntry {
+    /// Signed amount in minor units (cents). Negative for money spent,
+    /// positive for money received.
+    pub amount: i64,
+
+    /// The payee, built from all words that are not an amount, date, tag or account.
+    pub payee: String,
+
+    /// The date of the transaction.
+    pub date: NaiveDate,
+
+    /// Lowercase tag names without the leading `#`, in the order first typed.
+    pub tags: Vec<String>,
+
+    /// The account name without the leading `@`, if one was given.
+    pub account: Option<String>,
+}
+
+/// Errors that can occur when parsing quick entry text.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum QuickEntryError {
+    /// The input was empty or whitespace only.
+    #[error("Quick entry text cannot be empty")]
+    Empty,
+
+    /// No amount was found in the input.
+    #[error("Quick entry text must contain an amount")]
+    MissingAmount,
+
+    /// No payee words were left after removing the other tokens.
+    #[error("Quick entry text must contain a payee")]
+    MissingPayee,
+
+    /// A number was found but it is not a valid money amount.
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(String),
+
+    /// A token looked like a date but is not a real calendar date.
+    #[error("Invalid date: {0}")]
+    InvalidDate(String),
+
+    /// A `#` or `@` marker was typed without a name after it.
+    #[error("Tag or account name cannot be empty: {0}")]
+    EmptyName(String),
+
+    /// More than one `@account` token was given.
+    #[error("Only one account can be given, found: {0}")]
+    MultipleAccounts(String),
+}
+
+impl QuickEntry {
+    /// Parses quick entry text, resolving relative dates against today's local date.
+    ///
+    /// See the [module documentation](self) for the accepted syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`QuickEntryError`] if the text is empty, has no amount or
+    /// payee, or contains an invalid amount, date, tag or account.
+    pub fn parse<S: AsRef<str>>(input: S) -> Result<Self, QuickEntryError> {
+        Self::parse_relative_to(input, chrono::Local::now().date_naive())
+    }
+
+    /// Parses quick entry text, resolving relative dates against `today`.
+    ///
+    /// Passing the reference date in keeps parsing deterministic, which is
+    /// useful for tests and for clients in a different timezone to the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`QuickEntryError`] if the text is empty, has no amount or
+    /// payee, or contains an invalid amount, date, tag or account.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use lib_domain::QuickEntry;
+    ///
+    /// let today = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// let entry = QuickEntry::parse_relative_to("+2,500 salary 2025-03-01", today)?;
+    ///
+    /// assert_eq!(entry.amount, 250_000);
+    /// assert_eq!(entry.date, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+    /// # Ok::<(), lib_domain::QuickEntryError>(())
+    /// ```
+    pub fn parse_relative_to<S: AsRef<str>>(
+        input: S,
+        today: NaiveDate,
+    ) -> Result<Self, QuickEntryError> {
+        let input = input.as_ref().trim();
+        if input.is_empty() {
+            return Err(QuickEntryError::Empty);
+        }
+
+        let mut amount: Option<i64> = None;
+        let mut date: Option<NaiveDate> = None;
+        let mut tags: Vec<String> = Vec::new();
+        let mut account: Option<String> = None;
+        let mut payee_words: Vec<&str> = Vec::new();
+
+        for token in input.split_whitespace() {
+            if let Some(tag) = token.strip_prefix('#') {
+                let tag = Self::parse_name(tag, token)?.to_lowercase();
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+                continue;
+            }
+
+            if let Some(name) = token.strip_prefix('@') {
+                let name = Self::parse_name(name, token)?;
+                if account.is_some() {
+                    return Err(QuickEntryError::MultipleAccounts(token.to_string()));
+                }
+                account = Some(name.to_string());
+                continue;
+            }
+
+            if amount.is_none() && Self::looks_like_amount(token) {
+                amount = Some(Self::parse_amount(token)?);
+                continue;
+            }
+
+            if date.is_none()
+                && let Some(parsed) = Self::parse_date(token, today)?
+            {
+                date = Some(parsed);
+                continue;
+            }
+
+            payee_words.push(token);
+        }
+
+        let amount = amount.ok_or(QuickEntryError::MissingAmount)?;
+        if payee_words.is_empty() {
+            return Err(QuickEntryError::MissingPayee);
+        }
+
+        Ok(QuickEntry {
+            amount,
+            payee: payee_words.join(" "),
+            date: date.unwrap_or(today),
+            tags,
+            account,
+        })
+    }
+
+    /// Validates the name after a `#` or `@` marker.
+    fn parse_name<'a>(name: &'a str, token: &str) -> Result<&'a str, QuickEntryError> {
+        if name.is_empty() {
+            return Err(QuickEntryError::EmptyName(token.to_string()));
+        }
+        Ok(name)
+    }
+
+    /// Returns true if the token is made up only of amount characters and
+    /// contains at least one digit, e.g. `12.50`, `-$3` or `1,000`.
+    fn looks_like_amount(token: &str) -> bool {
+        let unsigned = token.trim_start_matches(['+', '-']);
+        let digits = unsigned.strip_prefix('$').unwrap_or(unsigned);
+
+        digits.starts_with(|c: char| c.is_ascii_digit())
+            && digits
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == ',' || c == '.')
+    }
+
+    /// Converts an amount token into signed minor units.
+    ///
+    /// Unsigned and `-` amounts are treated as money spent (negative), while
+    /// `+` amounts are treated as money received (positive).
+    fn parse_amount(token: &str) -> Result<i64, QuickEntryError> {
+        let invalid = || QuickEntryError::InvalidAmount(token.to_string());
+
+        let (is_income, unsigned) = match token.chars().next() {
+            Some('+') => (true, &token[1..]),
+            Some('-') => (false, &token[1..]),
+            _ => (false, token),
+        };
+        let digits = unsigned
+            .strip_prefix('$')
+            .unwrap_or(unsigned)
+            .replace(',', "");
+
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, fraction),
+            None => (digits.as_str(), ""),
+        };
+
+        if whole.is_empty() || fraction.len() > MAX_DECIMAL_PLACES || fraction.contains('.') {
+            return Err(invalid());
+        }
+
+        let whole: i64 = whole.parse().map_err(|_| invalid())?;
+        let fraction: i64 = if fraction.is_empty() {
+            0
+        } else {
+            // Right pad so "5" is read as 50 cents rather than 5 cents
+            format!("{:0<width$}", fraction, width = MAX_DECIMAL_PLACES)
+                .parse()
+                .map_err(|_| invalid())?
+        };
+
+        let minor = whole
+            .checked_mul(MINOR_UNITS)
+            .and_then(|value| value.checked_add(fraction))
+            .ok_or_else(invalid)?;
+
+        if minor == 0 {
+            return Err(invalid());
+        }
+
+        Ok(if is_income { minor } else { -minor })
+    }
+
+    /// Attempts to read the token as a date.
+    ///
+    /// Returns `Ok(None)` when the token is not date-like, so it can be used as
+    /// part of the payee, and an error when it is date-like but invalid.
+    fn parse_date(token: &str, today: NaiveDate) -> Result<Option<NaiveDate>, QuickEntryError> {
+        let lower = token.to_lowercase();
+        let invalid = || QuickEntryError::InvalidDate(token.to_string());
+
+        match lower.as_str() {
+            "today" => return Ok(Some(today)),
+            "yesterday" => return Ok(today.pred_opt()),
+            "tomorrow" => return Ok(today.succ_opt()),
+            _ => {}
+        }
+
+        if let Some(weekday) = Self::parse_weekday(&lower) {
+            let days_back =
+                (7 + today.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+            return Ok(today.checked_sub_days(chrono::Days::new(u64::from(days_back))));
+        }
+
+        // ISO 8601 calendar date, e.g. 2025-01-31
+        let iso_parts: Vec<&str> = token.split('-').collect();
+        if iso_parts.len() == 3
+            && iso_parts[0].len() == 4
+            && iso_parts
+                .iter()
+                .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()))
+        {
+            return NaiveDate::parse_from_str(token, "%Y-%m-%d")
+                .map(Some)
+                .map_err(|_| invalid());
+        }
+
+        // Day first date, e.g. 31/01 or 31/01/2025
+        let dmy_parts: Vec<&str> = token.split('/').collect();
+        if (dmy_parts.len() == 2 || dmy_parts.len() == 3)
+            && dmy_parts
+                .iter()
+                .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()))
+        {
+            let day: u32 = dmy_parts[0].parse().map_err(|_| invalid())?;
+            let month: u32 = dmy_parts[1].parse().map_err(|_| invalid())?;
+            let year: i32 = match dmy_parts.get(2) {
+                Some(year) => year.parse().map_err(|_| invalid())?,
+                None => today.year(),
+            };
+            return NaiveDate::from_ymd_opt(year, month, day)
+                .map(Some)
+                .ok_or_else(invalid);
+        }
+
+        Ok(None)
+    }
+
+    /// Maps full and three letter weekday names to a [`chrono::Weekday`].
+    fn parse_weekday(lower: &str) -> Option<chrono::Weekday> {
+        use chrono::Weekday;
+
+        match lower {
+            "mon" | "monday" => Some(Weekday::Mon),
+            "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+            "wed" | "wednesday" => Some(Weekday::Wed),
+            "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+            "fri" | "friday" => Some(Weekday::Fri),
+            "sat" | "saturday" => Some(Weekday::Sat),
+            "sun" | "sunday" => Some(Weekday::Sun),
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for QuickEntry {
+    type Err = QuickEntryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        QuickEntry::parse(s)
+    }
+}
+
+impl From<QuickEntry> for lib_rpc::TransactionPreview {
+    fn from(entry: QuickEntry) -> Self {
+        lib_rpc::TransactionPreview {
+            amount: entry.amount,
+            payee: entry.payee,
+            date: entry.date.format("%Y-%m-%d").to_string(),
+            tags: entry.tags,
+            account: entry.account,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Friday 14 March 2025, used as a fixed reference date.
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, 14).unwrap()
+    }
+
+    fn parse(input: &str) -> Result<QuickEntry, QuickEntryError> {
+        QuickEntry::parse_relative_to(input, today())
+    }
+
+    #[test]
+    fn parse_full_example() {
+        let entry = parse("12.50 coffee yesterday #food @visa").unwrap();
+        assert_eq!(entry.amount, -1250);
+        assert_eq!(entry.payee, "coffee");
+        assert_eq!(entry.date, NaiveDate::from_ymd_opt(2025, 3, 13).unwrap());
+        assert_eq!(entry.tags, vec!["food".to_string()]);
+        assert_eq!(entry.account.as_deref(), Some("visa"));
+    }
+
+    #[test]
+    fn parse_tokens_in_any_order() {
+        let entry = parse("@visa #food coffee yesterday 12.50").unwrap();
+        assert_eq!(entry, parse("12.50 coffee yesterday #food @visa").unwrap());
+    }
+
+    #[test]
+    fn parse_defaults_date_to_today() {
+        let entry = parse("4 bus fare").unwrap();
+        assert_eq!(entry.date, today());
+        assert_eq!(entry.payee, "bus fare");
+        assert!(entry.tags.is_empty());
+        assert!(entry.account.is_none());
+    }
+
+    #[test]
+    fn parse_amount_formats() {
+        assert_eq!(parse("12 x").unwrap().amount, -1200);
+        assert_eq!(parse("12.5 x").unwrap().amount, -1250);
+        assert_eq!(parse("$12.05 x").unwrap().amount, -1205);
+        assert_eq!(parse("1,234.56 x").unwrap().amount, -123_456);
+        assert_eq!(parse("-3 x").unwrap().amount, -300);
+        assert_eq!(parse("+$2,500 x").unwrap().amount, 250_000);
+    }
+
+    #[test]
+    fn parse_rejects_invalid_amounts() {
+        assert!(matches!(
+            parse("12.505 x"),
+            Err(QuickEntryError::InvalidAmount(_))
+        ));
+        assert!(matches!(
+            parse("1.2.3 x"),
+            Err(QuickEntryError::InvalidAmount(_))
+        ));
+        assert!(matches!(
+            parse("0 x"),
+            Err(QuickEntryError::InvalidAmount(_))
+        ));
+    }
+
+    #[test]
+    fn parse_only_first_amount_is_used() {
+        let entry = parse("5 coffee 2 go").unwrap();
+        assert_eq!(entry.amount, -500);
+        assert_eq!(entry.payee, "coffee 2 go");
+    }
+
+    #[test]
+    fn parse_payee_words_that_start_with_digits() {
+        let entry = parse("7.95 7-eleven").unwrap();
+        assert_eq!(entry.payee, "7-eleven");
+    }
+
+    #[test]
+    fn parse_relative_dates() {
+        assert_eq!(parse("1 x today").unwrap().date, today());
+        assert_eq!(
+            parse("1 x tomorrow").unwrap().date,
+            NaiveDate::from_ymd_opt(2025, 3, 15).unwrap()
+        );
+        assert_eq!(
+            parse("1 x YESTERDAY").unwrap().date,
+            NaiveDate::from_ymd_opt(2025, 3, 13).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_weekdays_resolve_to_most_recent() {
+        // The reference date is a Friday
+        assert_eq!(parse("1 x fri").unwrap().date, today());
+        assert_eq!(
+            parse("1 x monday").unwrap().date,
+            NaiveDate::from_ymd_opt(2025, 3, 10).unwrap()
+        );
+        assert_eq!(
+            parse("1 x sat").unwrap().date,
+            NaiveDate::from_ymd_opt(2025, 3, 8).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_absolute_dates() {
+        assert_eq!(
+            parse("1 x 2024-12-25").unwrap().date,
+            NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()
+        );
+        assert_eq!(
+            parse("1 x 25/12/2024").unwrap().date,
+            NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()
+        );
+        assert_eq!(
+            parse("1 x 1/2").unwrap().date,
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_dates() {
+        assert!(matches!(
+            parse("1 x 2025-13-01"),
+            Err(QuickEntryError::InvalidDate(_))
+        ));
+        assert!(matches!(
+            parse("1 x 31/02"),
+            Err(QuickEntryError::InvalidDate(_))
+        ));
+    }
+
+    #[test]
+    fn parse_only_first_date_is_used() {
+        let entry = parse("1 lunch today tomorrow").unwrap();
+        assert_eq!(entry.date, today());
+        assert_eq!(entry.payee, "lunch tomorrow");
+    }
+
+    #[test]
+    fn parse_tags_are_lowercased_and_deduplicated() {
+        let entry = parse("1 x #Food #travel #food").unwrap();
+        assert_eq!(entry.tags, vec!["food".to_string(), "travel".to_string()]);
+    }
+
+    #[test]
+    fn parse_rejects_empty_names() {
+        assert!(matches!(parse("1 x #"), Err(QuickEntryError::EmptyName(_))));
+        assert!(matches!(parse("1 x @"), Err(QuickEntryError::EmptyName(_))));
+    }
+
+    #[test]
+    fn parse_rejects_multiple_accounts() {
+        assert!(matches!(
+            parse("1 x @visa @cash"),
+            Err(QuickEntryError::MultipleAccounts(_))
+        ));
+    }
+
+    #[test]
+    fn parse_rejects_missing_parts() {
+        assert_eq!(parse("   "), Err(QuickEntryError::Empty));
+        assert_eq!(parse("coffee"), Err(QuickEntryError::MissingAmount));
+        assert_eq!(parse("12.50 #food"), Err(QuickEntryError::MissingPayee));
+    }
+
+    #[test]
+    fn from_str_uses_local_today() {
+        let entry: QuickEntry = "3 tea".parse().unwrap();
+        assert_eq!(entry.amount, -300);
+        assert_eq!(entry.date, chrono::Local::now().date_naive());
+    }
+
+    #[test]
+    fn into_rpc_preview() {
+        let entry = parse("12.50 coffee yesterday #food @visa").unwrap();
+        let preview: lib_rpc::TransactionPreview = entry.into();
+        assert_eq!(preview.amount, -1250);
+        assert_eq!(preview.payee, "coffee");
+        assert_eq!(preview.date, "2025-03-13");
+        assert_eq!(preview.tags, vec!["food".to_string()]);
+        assert_eq!(preview.account.as_deref(), Some("visa"));
+    }
+}
@@ -69,7 +69,7 @@
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 #[derive(
-    Debug, Copy, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Debug, Copy, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
 )]
 pub struct RowID(uuid::Uuid);
 
@@ -102,7 +102,8 @@ impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for RowID {
 impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for RowID {
     fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
         let s = <String as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
-        let uuid = uuid::Uuid::parse_str(&s).map_err(|e| format!("Invalid UUID string in DB: {}", e))?;
+        let uuid =
+            uuid::Uuid::parse_str(&s).map_err(|e| format!("Invalid UUID string in DB: {}", e))?;
         let row_id = RowID::try_from(uuid).map_err(|e| format!("Invalid RowID in DB: {}", e))?;
         Ok(row_id)
     }
@@ -144,7 +145,7 @@ impl TryFrom<uuid::Uuid> for RowID {
 
 impl std::str::FromStr for RowID {
     type Err = RowIDError;
-    
+
     /// Parse a RowID from a UUID string.
     ///
     /// This is the primary way to parse RowIDs from string input, including
@@ -511,7 +512,6 @@ impl RowID {
         self > other
     }
 
-
     /// Create a mock RowID with a random timestamp for testing.
     ///
     /// This generates a RowID with a random creation time between the Unix epoch
@@ -528,15 +528,14 @@ impl RowID {
     /// let mock_id = RowID::mock();
     /// assert_eq!(mock_id.as_uuid().get_version_num(), 7);
     /// ```
-    #[cfg(test)]
+    #[cfg(any(test, feature = "mock"))]
     pub fn mock() -> Self {
         use chrono::{DateTime, Utc};
-        use fake::faker::chrono::en::DateTimeAfter;
         use fake::Fake;
+        use fake::faker::chrono::en::DateTimeAfter;
 
         // Generate random DateTime after UNIX time epoch (00:00:00 UTC on 1 January 1970)
-        let random_datetime: DateTime<Utc> =
-            DateTimeAfter(chrono::DateTime::UNIX_EPOCH).fake();
+        let random_datetime: DateTime<Utc> = DateTimeAfter(chrono::DateTime::UNIX_EPOCH).fake();
 
         // Convert datetime to a UUID timestamp
         let random_uuid_timestamp: uuid::Timestamp = uuid::Timestamp::from_unix(
@@ -575,7 +574,7 @@ impl RowID {
     /// let id = RowID::mock_from_datetime(timestamp);
     /// assert_eq!(id.as_uuid().get_version_num(), 7);
     /// ```
-    #[cfg(test)]
+    #[cfg(any(test, feature = "mock"))]
     pub fn mock_from_datetime(date_time: chrono::DateTime<chrono::Utc>) -> Self {
         // Convert datetime to a UUID timestamp
         let uuid_timestamp: uuid::Timestamp = uuid::Timestamp::from_unix(
@@ -713,7 +712,7 @@ pub enum RowIDError {
     /// This occurs when parsing fails due to invalid UUID syntax.
     #[error("Invalid UUID format: {0}")]
     InvalidUuid(String),
-    
+
     /// The provided UUID is not version 7.
     ///
     /// RowIDs require UUID v7 for chronological ordering. This error is returned
@@ -919,7 +918,9 @@ mod tests {
     #[test]
     fn test_from_timestamp() {
         use chrono::{DateTime, Utc};
-        let timestamp = DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        let timestamp = DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
         let id = RowID::from_timestamp(timestamp);
         assert_eq!(id.0.get_version_num(), 7);
         // Note: We can't easily test the exact timestamp due to UUID v7 encoding
@@ -1,7 +1,7 @@
 // build.rs
 // Build script for personal-ledger-backend
 // Compiles protobuf files using tonic_prost_build to generate Rust gRPC code
-// 
+//
 // Note: Uncomment `out_dir` and `.file_descriptor` if you want tonic_prost_build
 // to build the code in the OUT_DIR (i.e. /target) instead of directly in src/rpc.
 // This will also require adjusting the module paths in src/rpc/mod.rs accordingly.
@@ -11,7 +11,7 @@ use std::{env, path::PathBuf};
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Get the cargo OUT_DIR environment variable, which is where the generated code will be placed
     let out_dir = PathBuf::from(env::var("OUT_DIR")?);
-    
+
     // Re-run the build script if any .proto files change
     println!("cargo:rerun-if-changed=proto/");
     // Re-run the build script if this file changes
@@ -26,12 +26,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build_server(true)
         .build_transport(true)
         .compile_well_known_types(false)
-        .file_descriptor_set_path(out_dir.join("utilities_descriptor.bin"))
+        // Served by reflection and read by the compatibility module to snapshot the API
+        .file_descriptor_set_path(out_dir.join("personal_ledger_descriptor.bin"))
         .compile_protos(
-          &[
-            "proto/personal-ledger/v001/utilities.proto", 
-            "proto/personal-ledger/v001/categories.proto"
-        ],
-          &["proto/", "/usr/include"])?;
+            &[
+                "proto/personal-ledger/v001/utilities.proto",
+                "proto/personal-ledger/v001/categories.proto",
+                "proto/personal-ledger/v001/transactions.proto",
+                "proto/personal-ledger/v001/accounts.proto",
+                "proto/personal-ledger/v001/periods.proto",
+                "proto/personal-ledger/v001/budgets.proto",
+                "proto/personal-ledger/v001/goals.proto",
+                "proto/personal-ledger/v001/reports.proto",
+                "proto/personal-ledger/v001/search.proto",
+                "proto/personal-ledger/v001/payees.proto",
+                "proto/personal-ledger/v001/attachments.proto",
+                "proto/personal-ledger/v001/reconciliations.proto",
+                "proto/personal-ledger/v001/audit.proto",
+                "proto/personal-ledger/v001/export.proto",
+            ],
+            &["proto/", "/usr/include"],
+        )?;
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,58 @@
+// -- ./src/accounts.rs --
+
+//! Accounts module - gRPC services and types for account operations.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the accounts service. It handles the accounts money is held in or owed on, such
+//! as bank accounts and credit cards, and the balances computed from their transactions.
+//!
+//! ## Services
+//!
+//! - **AccountsService**: Handles CRUD operations for accounts and returns computed
+//!   balances, optionally as of a past date, and adjusts balances to a counted amount.
+//!   Records balance snapshots for accounts whose transactions are not kept in the ledger,
+//!   and lists those computed when a period is closed.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `Account`: The main account struct with all fields
+//! - `BalanceSnapshot`: A balance recorded by hand, or computed at a period end, on a day
+//! - `SnapshotSources`: Enum of where a snapshot's balance came from
+//! - `BalanceAssertion`: A balance a bank reported for an account at the end of a day
+//! - `BalanceAssertionResult`: An assertion compared with the balance computed from the ledger
+//! - Request/Response types for all operations (Create, Get, Update, Delete, List, GetBalance,
+//!   AdjustBalance, SnapshotRecord, SnapshotsList, SnapshotDelete, AssertionRecord,
+//!   AssertionsList, AssertionDelete, AssertionsCheck)
+//! - `AccountsServiceClient`: gRPC client for connecting to accounts service
+//! - `AccountsService`: Server trait for implementing accounts service
+//! - `AccountsServiceServer`: Server implementation for accounts service
+
+// -------------------------- [ ACCOUNTS ] ---------------------------------
+
+/// gRPC client for the AccountsService.
+/// Provides methods for creating, reading, updating, deleting, and listing accounts,
+/// and for fetching an account's computed balance.
+pub use crate::generated::accounts::accounts_service_client::AccountsServiceClient;
+
+/// gRPC server trait and implementation for the AccountsService.
+/// Implement the `AccountsService` trait to handle incoming gRPC requests for accounts.
+pub use crate::generated::accounts::accounts_service_server::{
+    AccountsService, AccountsServiceServer,
+};
+
+/// Accounts-related message types.
+/// Includes structs for accounts, requests, and responses used in the AccountsService.
+/// These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::accounts::{
+    Account, AccountAdjustBalanceRequest, AccountAdjustBalanceResponse,
+    AccountAssertionDeleteRequest, AccountAssertionDeleteResponse, AccountAssertionRecordRequest,
+    AccountAssertionRecordResponse, AccountAssertionsCheckRequest, AccountAssertionsCheckResponse,
+    AccountAssertionsListRequest, AccountAssertionsListResponse, AccountCreateRequest,
+    AccountCreateResponse, AccountDeleteRequest, AccountDeleteResponse, AccountGetBalanceRequest,
+    AccountGetBalanceResponse, AccountGetRequest, AccountGetResponse, AccountSnapshotDeleteRequest,
+    AccountSnapshotDeleteResponse, AccountSnapshotRecordRequest, AccountSnapshotRecordResponse,
+    AccountSnapshotsListRequest, AccountSnapshotsListResponse, AccountUpdateRequest,
+    AccountUpdateResponse, AccountsListRequest, AccountsListResponse, BalanceAssertion,
+    BalanceAssertionResult, BalanceSnapshot, SnapshotSources,
+};
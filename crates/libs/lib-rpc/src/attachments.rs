@@ -0,0 +1,51 @@
+// -- ./src/attachments.rs --
+
+//! Attachments module - gRPC services and types for files attached to transactions.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the attachments service. Receipt images and PDF invoices are attached to
+//! transactions, and uploaded and downloaded as a stream of chunks.
+//!
+//! ## Services
+//!
+//! - **AttachmentsService**: Uploads, downloads, lists and deletes the files attached
+//!   to a transaction.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `Attachment`: A file attached to a transaction, without its content
+//! - `AttachmentUploadMetadata`: What a file being uploaded is, sent before its content
+//! - `AttachmentUploadPart`, `AttachmentDownloadPart`: The metadata or a chunk of content
+//!   carried by one message of an upload or download
+//! - Request/Response types for all operations (Upload, Download, List, Delete)
+//! - `AttachmentsServiceClient`: gRPC client for connecting to attachments service
+//! - `AttachmentsService`: Server trait for implementing attachments service
+//! - `AttachmentsServiceServer`: Server implementation for attachments service
+
+// ------------------------- [ ATTACHMENTS ] --------------------------------
+
+/// gRPC client for the AttachmentsService.
+/// Provides methods for uploading, downloading, listing and deleting attachments.
+pub use crate::generated::attachments::attachments_service_client::AttachmentsServiceClient;
+
+/// gRPC server trait and implementation for the AttachmentsService.
+/// Implement the `AttachmentsService` trait to handle incoming gRPC requests for attachments.
+pub use crate::generated::attachments::attachments_service_server::{
+    AttachmentsService, AttachmentsServiceServer,
+};
+
+/// The part carried by one message of an upload.
+pub use crate::generated::attachments::attachment_upload_request::Part as AttachmentUploadPart;
+
+/// The part carried by one message of a download.
+pub use crate::generated::attachments::attachment_download_response::Part as AttachmentDownloadPart;
+
+/// Attachments-related message types.
+/// Includes structs for attachments, requests, and responses used in the AttachmentsService.
+/// These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::attachments::{
+    Attachment, AttachmentDeleteRequest, AttachmentDeleteResponse, AttachmentDownloadRequest,
+    AttachmentDownloadResponse, AttachmentUploadMetadata, AttachmentUploadRequest,
+    AttachmentUploadResponse, AttachmentsListRequest, AttachmentsListResponse,
+};
@@ -0,0 +1,38 @@
+// -- ./src/audit.rs --
+
+//! Audit module - gRPC services and types for reading the audit log.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the audit service. The audit log records every insert, update and delete made
+//! to the ledger, with the record before and after the change and who made it.
+//!
+//! ## Services
+//!
+//! - **AuditService**: Returns the audit history of a single record.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `AuditEntry`: A single change recorded in the audit log
+//! - `AuditActions`: Whether an entry records an insert, update or delete
+//! - Request/Response types for all operations (History)
+//! - `AuditServiceClient`: gRPC client for connecting to audit service
+//! - `AuditService`: Server trait for implementing audit service
+//! - `AuditServiceServer`: Server implementation for audit service
+
+// ---------------------------- [ AUDIT ] -----------------------------------
+
+/// gRPC client for the AuditService.
+/// Provides methods for reading the audit history of records.
+pub use crate::generated::audit::audit_service_client::AuditServiceClient;
+
+/// gRPC server trait and implementation for the AuditService.
+/// Implement the `AuditService` trait to handle incoming gRPC requests for the audit log.
+pub use crate::generated::audit::audit_service_server::{AuditService, AuditServiceServer};
+
+/// Audit-related message types.
+/// Includes structs for audit entries, requests, and responses used in the AuditService.
+/// These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::audit::{
+    AuditActions, AuditEntry, AuditHistoryRequest, AuditHistoryResponse,
+};
@@ -0,0 +1,482 @@
+// -- ./src/auth.rs --
+
+//! Auth module - bearer token authentication for every RPC.
+//!
+//! Clients send a token in the `authorization` metadata as
+//! `Bearer <token>`. [`AuthInterceptor`] checks it on the server before the
+//! request reaches a service, and accepts either:
+//!
+//! - **API keys**: static keys, each naming the [`Principal`] it
+//!   authenticates and their [`Role`]. Only a SHA-256 digest of each key is
+//!   kept in memory
+//! - **JWTs**: tokens signed with a shared secret (HS256), with an `exp`
+//!   claim in the future, the principal in the `sub` claim and their role in
+//!   an optional `role` claim, read-only if it is left out
+//!
+//! The authenticated [`Principal`] is put in the request extensions, where
+//! a service reads it with [`principal`]. Requests without a valid token are
+//! answered `UNAUTHENTICATED`, and requests for an RPC the principal's role
+//! does not allow `PERMISSION_DENIED`, without reaching the service. See the
+//! [roles module](crate::Role) for the roles each RPC requires. Requests with
+//! no token at all can instead be trusted as a fixed principal, with
+//! [`AuthInterceptor::with_anonymous`], such as the local user on a Unix
+//! socket only they can connect to.
+//!
+//! [`BearerToken`] is the client side, attaching the token to every request.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_rpc::{AuthInterceptor, BearerToken, CategoriesServiceServer, Role};
+//!
+//! # async fn example(categories: impl lib_rpc::CategoriesService) -> Result<(), Box<dyn std::error::Error>> {
+//! let auth = AuthInterceptor::new()
+//!     .with_api_key("a-long-random-key", "ian", Role::Admin)
+//!     .with_jwt_secret(b"a-shared-secret");
+//! let server = CategoriesServiceServer::with_interceptor(categories, auth);
+//!
+//! let client = lib_rpc::LedgerClient::connect("http://127.0.0.1:50059").await?;
+//! let token = BearerToken::new("a-long-random-key")?;
+//! let categories = client.with_token(Some(token)).categories();
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+
+use crate::{Role, RpcMethod};
+use sha2::{Digest, Sha256};
+use tonic::metadata::{Ascii, MetadataValue, errors::InvalidMetadataValue};
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+/// Metadata key carrying the bearer token.
+pub const AUTHORIZATION_HEADER: &str = "authorization";
+
+/// Scheme prefixed to the token in the `authorization` metadata.
+const BEARER_SCHEME: &str = "Bearer ";
+
+/// Who a request was authenticated as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    /// The name an API key was given, or the `sub` claim of a JWT.
+    pub subject: String,
+
+    /// What the principal may do.
+    pub role: Role,
+}
+
+/// The principal a request was authenticated as, if it passed an
+/// [`AuthInterceptor`].
+pub fn principal<T>(request: &Request<T>) -> Option<&Principal> {
+    request.extensions().get::<Principal>()
+}
+
+/// The claims read from a JWT. `exp` is checked by the validation.
+#[derive(Debug, serde::Deserialize)]
+struct Claims {
+    sub: String,
+    #[serde(default)]
+    role: Role,
+}
+
+/// The shared secret JWTs are signed with, and how they are validated.
+#[derive(Debug)]
+struct JwtVerifier {
+    key: DecodingKey,
+    validation: Validation,
+}
+
+/// Server interceptor that authenticates every request with its bearer
+/// token, see the [module documentation](self).
+///
+/// Cloning is cheap, clones share the keys.
+#[derive(Debug, Clone, Default)]
+pub struct AuthInterceptor {
+    /// Principals by the SHA-256 digest of their API key.
+    api_keys: Arc<HashMap<[u8; 32], Principal>>,
+    jwt: Option<Arc<JwtVerifier>>,
+    /// Roles required by method name, overriding [`Role::required_for`].
+    method_roles: Arc<HashMap<String, Role>>,
+    /// Who requests without a bearer token are, refused when `None`.
+    anonymous: Option<Principal>,
+}
+
+impl AuthInterceptor {
+    /// An interceptor that accepts no tokens until keys are added.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accepts `api_key`, authenticating its requests as `subject` with
+    /// `role`.
+    pub fn with_api_key(
+        self,
+        api_key: impl AsRef<[u8]>,
+        subject: impl Into<String>,
+        role: Role,
+    ) -> Self {
+        self.with_api_key_digest(digest(api_key.as_ref()), subject, role)
+    }
+
+    /// Accepts the API key whose SHA-256 digest is `api_key_digest`, so the
+    /// key itself need not be kept, authenticating its requests as `subject`
+    /// with `role`.
+    pub fn with_api_key_digest(
+        mut self,
+        api_key_digest: [u8; 32],
+        subject: impl Into<String>,
+        role: Role,
+    ) -> Self {
+        let principal = Principal {
+            subject: subject.into(),
+            role,
+        };
+        Arc::make_mut(&mut self.api_keys).insert(api_key_digest, principal);
+        self
+    }
+
+    /// Accepts JWTs signed with `secret` using HS256, authenticating their
+    /// requests as the `sub` claim.
+    pub fn with_jwt_secret(mut self, secret: impl AsRef<[u8]>) -> Self {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_required_spec_claims(&["exp", "sub"]);
+
+        self.jwt = Some(Arc::new(JwtVerifier {
+            key: DecodingKey::from_secret(secret.as_ref()),
+            validation,
+        }));
+        self
+    }
+
+    /// Authenticates requests without a bearer token as `principal`, rather
+    /// than refusing them. Requests with a token that is not valid are still
+    /// refused.
+    pub fn with_anonymous(mut self, principal: Principal) -> Self {
+        self.anonymous = Some(principal);
+        self
+    }
+
+    /// Requires `role` for the RPC named `method`, e.g. `"CategoryDelete"`,
+    /// instead of its role in [`METHOD_ROLES`](crate::METHOD_ROLES).
+    pub fn with_method_role(mut self, method: impl Into<String>, role: Role) -> Self {
+        Arc::make_mut(&mut self.method_roles).insert(method.into(), role);
+        self
+    }
+
+    /// The role the RPC named `method` requires.
+    pub fn required_role(&self, method: &str) -> Role {
+        self.method_roles
+            .get(method)
+            .copied()
+            .unwrap_or_else(|| Role::required_for(method))
+    }
+
+    /// The principal `token` authenticates, checking the API keys first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UNAUTHENTICATED` if the token is not a known API key or a
+    /// valid JWT.
+    pub fn authenticate(&self, token: &str) -> Result<Principal, Status> {
+        if let Some(principal) = self.api_keys.get(&digest(token.as_bytes())) {
+            return Ok(principal.clone());
+        }
+
+        let jwt = self
+            .jwt
+            .as_ref()
+            .ok_or_else(|| Status::unauthenticated("Bearer token is not a known API key"))?;
+        let claims = jsonwebtoken::decode::<Claims>(token, &jwt.key, &jwt.validation)
+            .map_err(|e| Status::unauthenticated(format!("Bearer token is not valid: {e}")))?
+            .claims;
+
+        Ok(Principal {
+            subject: claims.sub,
+            role: claims.role,
+        })
+    }
+
+    /// Checks `principal` may call `method`. An unknown method, when the
+    /// request did not pass an [`RpcMethodLayer`](crate::RpcMethodLayer),
+    /// requires admin.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PERMISSION_DENIED` if the principal's role is below the one
+    /// the method requires.
+    pub fn authorize(
+        &self,
+        principal: &Principal,
+        method: Option<&RpcMethod>,
+    ) -> Result<(), Status> {
+        let name = method.map_or("this RPC", RpcMethod::name);
+        let required = method.map_or(Role::Admin, |method| self.required_role(method.name()));
+
+        if !principal.role.allows(required) {
+            return Err(Status::permission_denied(format!(
+                "{name} requires the {required} role, {} has {}",
+                principal.subject, principal.role
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let token = request
+            .metadata()
+            .get(AUTHORIZATION_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(bearer_token);
+
+        let principal = match (token, &self.anonymous) {
+            (Some(token), _) => self.authenticate(token)?,
+            (None, Some(anonymous)) => anonymous.clone(),
+            (None, None) => return Err(Status::unauthenticated("Request has no bearer token")),
+        };
+        self.authorize(&principal, request.extensions().get::<RpcMethod>())?;
+        request.extensions_mut().insert(principal);
+
+        Ok(request)
+    }
+}
+
+/// The token of an `authorization` value using the bearer scheme, whose name
+/// is case insensitive.
+fn bearer_token(value: &str) -> Option<&str> {
+    let scheme = value.get(..BEARER_SCHEME.len())?;
+    scheme
+        .eq_ignore_ascii_case(BEARER_SCHEME)
+        .then(|| value[BEARER_SCHEME.len()..].trim())
+        .filter(|token| !token.is_empty())
+}
+
+/// SHA-256 digest of an API key.
+fn digest(api_key: &[u8]) -> [u8; 32] {
+    Sha256::digest(api_key).into()
+}
+
+/// Client interceptor that attaches a bearer token to every request.
+#[derive(Clone)]
+pub struct BearerToken {
+    value: MetadataValue<Ascii>,
+}
+
+impl BearerToken {
+    /// Attaches `token`, an API key or JWT.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token has characters not allowed in metadata.
+    pub fn new(token: impl AsRef<str>) -> Result<Self, InvalidMetadataValue> {
+        let mut value: MetadataValue<Ascii> =
+            format!("{BEARER_SCHEME}{}", token.as_ref()).parse()?;
+        value.set_sensitive(true);
+
+        Ok(Self { value })
+    }
+}
+
+impl std::fmt::Debug for BearerToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BearerToken")
+            .field("value", &"[redacted]")
+            .finish()
+    }
+}
+
+impl Interceptor for BearerToken {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        request
+            .metadata_mut()
+            .insert(AUTHORIZATION_HEADER, self.value.clone());
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &[u8] = b"test-secret";
+
+    #[derive(serde::Serialize)]
+    struct TestClaims<'a> {
+        sub: &'a str,
+        exp: i64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        role: Option<&'a str>,
+    }
+
+    fn jwt(subject: &str, expires_in: i64) -> String {
+        jwt_with_role(subject, expires_in, None)
+    }
+
+    fn jwt_with_role(subject: &str, expires_in: i64, role: Option<&str>) -> String {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        let claims = TestClaims {
+            sub: subject,
+            exp: now + expires_in,
+            role,
+        };
+
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::new(Algorithm::HS256),
+            &claims,
+            &jsonwebtoken::EncodingKey::from_secret(SECRET),
+        )
+        .unwrap()
+    }
+
+    /// Sends a read-only request through the client interceptor, then the
+    /// server one.
+    fn round_trip(auth: &mut AuthInterceptor, token: &str) -> Result<Request<()>, Status> {
+        round_trip_to(auth, token, Some("CategoryGet"))
+    }
+
+    /// Sends a request for `method` through the client interceptor, then the
+    /// server one, as if it had passed an `RpcMethodLayer`.
+    fn round_trip_to(
+        auth: &mut AuthInterceptor,
+        token: &str,
+        method: Option<&str>,
+    ) -> Result<Request<()>, Status> {
+        let mut request = BearerToken::new(token).unwrap().call(Request::new(()))?;
+        if let Some(method) = method {
+            let path = format!("/personal_ledger.categories.v001.CategoriesService/{method}");
+            request.extensions_mut().insert(RpcMethod::from_path(&path));
+        }
+        auth.call(request)
+    }
+
+    #[test]
+    fn api_keys_authenticate_their_principal() {
+        let mut auth = AuthInterceptor::new()
+            .with_api_key("key-one", "ian", Role::Admin)
+            .with_api_key("key-two", "sam", Role::ReadOnly);
+
+        let request = round_trip(&mut auth, "key-two").unwrap();
+        assert_eq!(principal(&request).unwrap().subject, "sam");
+        assert_eq!(principal(&request).unwrap().role, Role::ReadOnly);
+
+        let status = round_trip(&mut auth, "key-three").unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn jwts_authenticate_their_subject_until_expiry() {
+        let mut auth = AuthInterceptor::new().with_jwt_secret(SECRET);
+
+        let request = round_trip(&mut auth, &jwt("ian", 60)).unwrap();
+        assert_eq!(principal(&request).unwrap().subject, "ian");
+
+        let expired = round_trip(&mut auth, &jwt("ian", -3_600)).unwrap_err();
+        assert_eq!(expired.code(), tonic::Code::Unauthenticated);
+
+        let mut other_secret = AuthInterceptor::new().with_jwt_secret(b"other-secret");
+        let forged = round_trip(&mut other_secret, &jwt("ian", 60)).unwrap_err();
+        assert_eq!(forged.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn requests_without_a_bearer_token_are_unauthenticated() {
+        let mut auth = AuthInterceptor::new().with_api_key("key-one", "ian", Role::Admin);
+
+        let missing = auth.call(Request::new(())).unwrap_err();
+        assert_eq!(missing.code(), tonic::Code::Unauthenticated);
+
+        let mut basic = Request::new(());
+        basic
+            .metadata_mut()
+            .insert(AUTHORIZATION_HEADER, "Basic key-one".parse().unwrap());
+        assert!(auth.call(basic).is_err());
+
+        assert_eq!(bearer_token("bearer key-one"), Some("key-one"));
+        assert_eq!(bearer_token("Bearer "), None);
+    }
+
+    #[test]
+    fn requests_without_a_token_are_the_anonymous_principal() {
+        let local = Principal {
+            subject: "ian".to_string(),
+            role: Role::Admin,
+        };
+        let mut auth = AuthInterceptor::new()
+            .with_api_key_digest(digest(b"key-one"), "sam", Role::ReadOnly)
+            .with_anonymous(local.clone());
+
+        let anonymous = auth.call(Request::new(())).unwrap();
+        assert_eq!(principal(&anonymous), Some(&local));
+
+        let keyed = round_trip(&mut auth, "key-one").unwrap();
+        assert_eq!(principal(&keyed).unwrap().subject, "sam");
+
+        let status = round_trip(&mut auth, "key-two").unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn roles_below_the_methods_are_permission_denied() {
+        let mut auth = AuthInterceptor::new()
+            .with_api_key("admin-key", "ian", Role::Admin)
+            .with_api_key("editor-key", "sam", Role::Editor)
+            .with_method_role("CategoryUpdate", Role::Admin);
+
+        assert!(round_trip_to(&mut auth, "editor-key", Some("CategoryCreate")).is_ok());
+        assert!(round_trip_to(&mut auth, "admin-key", Some("CategoryDelete")).is_ok());
+
+        let delete = round_trip_to(&mut auth, "editor-key", Some("CategoryDelete")).unwrap_err();
+        assert_eq!(delete.code(), tonic::Code::PermissionDenied);
+        let overridden =
+            round_trip_to(&mut auth, "editor-key", Some("CategoryUpdate")).unwrap_err();
+        assert_eq!(overridden.code(), tonic::Code::PermissionDenied);
+
+        // Without the method layer only admins get through
+        assert!(round_trip_to(&mut auth, "admin-key", None).is_ok());
+        let unknown = round_trip_to(&mut auth, "editor-key", None).unwrap_err();
+        assert_eq!(unknown.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[test]
+    fn jwts_carry_their_role_claim() {
+        let mut auth = AuthInterceptor::new().with_jwt_secret(SECRET);
+
+        let editor = round_trip_to(
+            &mut auth,
+            &jwt_with_role("ian", 60, Some("editor")),
+            Some("TransactionCreate"),
+        );
+        assert_eq!(principal(&editor.unwrap()).unwrap().role, Role::Editor);
+
+        let read_only =
+            round_trip_to(&mut auth, &jwt("ian", 60), Some("TransactionCreate")).unwrap_err();
+        assert_eq!(read_only.code(), tonic::Code::PermissionDenied);
+
+        let unknown_role =
+            round_trip(&mut auth, &jwt_with_role("ian", 60, Some("owner"))).unwrap_err();
+        assert_eq!(unknown_role.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn bearer_token_is_redacted_in_debug_output() {
+        let token = BearerToken::new("key-one").unwrap();
+
+        assert!(!format!("{token:?}").contains("key-one"));
+        assert!(
+            !format!(
+                "{:?}",
+                AuthInterceptor::new().with_api_key("key-one", "ian", Role::Admin)
+            )
+            .contains("key-one")
+        );
+    }
+}
@@ -0,0 +1,45 @@
+// -- ./src/budgets.rs --
+
+//! Budgets module - gRPC services and types for category budgets.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the budgets service. Budgets set how much may be spent in a category each month
+//! or financial year, and are compared with the actual spending.
+//!
+//! ## Services
+//!
+//! - **BudgetsService**: Handles CRUD operations for budgets and compares them with
+//!   actual spending over a month or financial year.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `Budget`: A monthly or annual spending budget for a category
+//! - `BudgetPeriods`: Whether a budget is per month or per financial year
+//! - `BudgetComparison`: Budgeted and actual spending for one budget over a period
+//! - `BudgetVariance`: Budgeted and actual spending for a category and its subcategories,
+//!   as a tree
+//! - Request/Response types for all operations (Create, Get, List, Update, Delete, Compare)
+//! - `BudgetsServiceClient`: gRPC client for connecting to budgets service
+//! - `BudgetsService`: Server trait for implementing budgets service
+//! - `BudgetsServiceServer`: Server implementation for budgets service
+
+// --------------------------- [ BUDGETS ] ----------------------------------
+
+/// gRPC client for the BudgetsService.
+/// Provides methods for managing budgets and comparing them with spending.
+pub use crate::generated::budgets::budgets_service_client::BudgetsServiceClient;
+
+/// gRPC server trait and implementation for the BudgetsService.
+/// Implement the `BudgetsService` trait to handle incoming gRPC requests for budgets.
+pub use crate::generated::budgets::budgets_service_server::{BudgetsService, BudgetsServiceServer};
+
+/// Budgets-related message types.
+/// Includes structs for budgets, comparisons, requests, and responses used in the
+/// BudgetsService. These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::budgets::{
+    Budget, BudgetComparison, BudgetCreateRequest, BudgetCreateResponse, BudgetDeleteRequest,
+    BudgetDeleteResponse, BudgetGetRequest, BudgetGetResponse, BudgetPeriods, BudgetUpdateRequest,
+    BudgetUpdateResponse, BudgetVariance, BudgetsCompareRequest, BudgetsCompareResponse,
+    BudgetsListRequest, BudgetsListResponse, BudgetsVarianceRequest, BudgetsVarianceResponse,
+};
@@ -9,14 +9,19 @@
 //! ## Services
 //!
 //! - **CategoriesService**: Handles CRUD operations for financial categories including
-//!   batch operations and activation/deactivation.
+//!   batch operations, activation/deactivation, archiving with a usage guard,
+//!   soft deletion and restoring, streaming large lists in pages and watching
+//!   for changes as they are made.
 //!
 //! ## Types
 //!
 //! Core message types include:
 //! - `Category`: The main category struct with all fields
 //! - `CategoryTypes`: Enum defining category types (Asset, Expense, etc.)
-//! - Request/Response types for all operations (Create, Get, Update, Delete, List, etc.)
+//! - `CategoryChangeKinds`: Enum defining the changes a watch reports (Created, Deleted, etc.)
+//! - `CategoryTypeCount`/`CategoryUsage`: Counts by type and use of each category, for
+//!   `GetCategoryStatistics`
+//! - Request/Response types for all operations (Create, Get, Update, Delete, List, Stream, etc.)
 //! - `CategoriesServiceClient`: gRPC client for connecting to categories service
 //! - `CategoriesService`: Server trait for implementing categories service
 //! - `CategoriesServiceServer`: Server implementation for categories service
@@ -38,28 +43,15 @@ pub use crate::generated::categories::categories_service_server::{
 /// Includes structs for categories, requests, and responses used in the CategoriesService.
 /// These are protobuf-generated types for serialization and deserialization.
 pub use crate::generated::categories::{
-    Category,
-    CategoryTypes,
-    CategoryCreateRequest,
-    CategoryCreateResponse,
-    CategoryGetRequest,
-    CategoryGetResponse,
-    CategoryGetByCodeRequest,
-    CategoryGetByCodeResponse,
-    CategoryGetBySlugRequest,
-    CategoryGetBySlugResponse,
-    CategoriesListRequest,
-    CategoriesListResponse,
-    CategoryUpdateRequest,
-    CategoryUpdateResponse,
-    CategoriesCreateBatchRequest,
-    CategoriesCreateBatchResponse,
-    CategoryDeleteRequest,
-    CategoryDeleteResponse,
-    CategoriesDeleteBatchRequest,
-    CategoriesDeleteBatchResponse,
-    CategoryActivateRequest,
-    CategoryActivateResponse,
-    CategoryDeactivateRequest,
-    CategoryDeactivateResponse,
+    CategoriesCreateBatchRequest, CategoriesCreateBatchResponse, CategoriesDeleteBatchRequest,
+    CategoriesDeleteBatchResponse, CategoriesListRequest, CategoriesListResponse,
+    CategoriesStreamRequest, CategoriesStreamResponse, CategoriesWatchRequest,
+    CategoriesWatchResponse, Category, CategoryActivateRequest, CategoryActivateResponse,
+    CategoryArchiveRequest, CategoryArchiveResponse, CategoryChangeKinds, CategoryCreateRequest,
+    CategoryCreateResponse, CategoryDeactivateRequest, CategoryDeactivateResponse,
+    CategoryDeleteRequest, CategoryDeleteResponse, CategoryGetByCodeRequest,
+    CategoryGetByCodeResponse, CategoryGetBySlugRequest, CategoryGetBySlugResponse,
+    CategoryGetRequest, CategoryGetResponse, CategoryRestoreRequest, CategoryRestoreResponse,
+    CategoryTypeCount, CategoryTypes, CategoryUpdateRequest, CategoryUpdateResponse, CategoryUsage,
+    GetCategoryStatisticsRequest, GetCategoryStatisticsResponse,
 };
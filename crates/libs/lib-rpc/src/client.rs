@@ -0,0 +1,442 @@
+// -- ./src/client.rs --
+
+//! Client module - one connection to the server, shared by every service client.
+//!
+//! [`LedgerClient`] connects once and hands out a client for each service over
+//! the same channel. It also follows page tokens for the list RPCs, returning a
+//! [`Stream`] of items so callers never write a pagination loop.
+//!
+//! Pages are fetched as the stream is read, one request at a time. The first
+//! error ends the stream.
+//!
+//! A client given a token with [`LedgerClient::with_token`] attaches it to
+//! every request of every service client, as a [`BearerToken`] does.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use futures_util::TryStreamExt;
+//! use lib_rpc::{LedgerClient, TransactionsListRequest};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = LedgerClient::connect("http://127.0.0.1:50059").await?;
+//!
+//! let categories: Vec<_> = client.list_all_categories().try_collect().await?;
+//!
+//! let drafts = client.iter_transactions(TransactionsListRequest {
+//!     drafts_only: true,
+//!     ..Default::default()
+//! });
+//! futures_util::pin_mut!(drafts);
+//! while let Some(draft) = drafts.try_next().await? {
+//!     println!("{} {}", draft.transaction_date, draft.payee);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use futures_util::{Stream, TryStreamExt, stream};
+use tonic::service::Interceptor;
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Request, Status};
+
+use crate::{
+    Account, AccountsListRequest, AccountsServiceClient, AttachmentsServiceClient,
+    AuditServiceClient, BearerToken, Budget, BudgetsListRequest, BudgetsServiceClient,
+    CategoriesListRequest, CategoriesServiceClient, Category, ExportServiceClient,
+    GoalsServiceClient, HealthClient, PayeesServiceClient, PeriodsServiceClient,
+    ReconciliationsServiceClient, ReportsServiceClient, SearchServiceClient, Transaction,
+    TransactionsListRequest, TransactionsServiceClient, UtilitiesServiceClient,
+};
+
+/// The channel the service clients of a [`LedgerClient`] send requests on,
+/// attaching its token if it has one.
+pub type LedgerChannel = InterceptedService<Channel, ClientToken>;
+
+/// Client interceptor that attaches a [`BearerToken`] to every request, or
+/// sends requests unchanged when there is none.
+#[derive(Debug, Clone, Default)]
+pub struct ClientToken(Option<BearerToken>);
+
+impl Interceptor for ClientToken {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        match &mut self.0 {
+            Some(token) => token.call(request),
+            None => Ok(request),
+        }
+    }
+}
+
+/// A connection to the Personal Ledger server, with a client for each service.
+///
+/// Cloning is cheap, clones share the connection.
+#[derive(Debug, Clone)]
+pub struct LedgerClient {
+    channel: Channel,
+    token: ClientToken,
+}
+
+impl LedgerClient {
+    /// Connects to the server at `url`, e.g. `"http://127.0.0.1:50059"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or the server cannot be reached.
+    pub async fn connect(url: impl Into<String>) -> Result<Self, tonic::transport::Error> {
+        let channel = Endpoint::from_shared(url.into())?.connect().await?;
+
+        Ok(Self::new(channel))
+    }
+
+    /// Connects to a server listening on the Unix domain socket at `path`,
+    /// as a standalone server does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket cannot be connected to.
+    #[cfg(unix)]
+    pub async fn connect_unix(
+        path: impl Into<std::path::PathBuf>,
+    ) -> Result<Self, tonic::transport::Error> {
+        let path = path.into();
+
+        // The URL is required but never resolved, the connector opens the socket
+        let channel = Endpoint::from_static("http://unix.personal-ledger")
+            .connect_with_connector(tower::service_fn(move |_uri| {
+                let path = path.clone();
+                async move {
+                    tokio::net::UnixStream::connect(path)
+                        .await
+                        .map(hyper_util::rt::TokioIo::new)
+                }
+            }))
+            .await?;
+
+        Ok(Self::new(channel))
+    }
+
+    /// Uses an existing channel, for a connection configured by the caller.
+    pub fn new(channel: Channel) -> Self {
+        Self {
+            channel,
+            token: ClientToken::default(),
+        }
+    }
+
+    /// Attaches `token` to every request, or none when it is `None`.
+    ///
+    /// The token is not checked until the server receives a request.
+    pub fn with_token(mut self, token: Option<BearerToken>) -> Self {
+        self.token = ClientToken(token);
+        self
+    }
+
+    /// The channel shared by the service clients, without the token.
+    pub fn channel(&self) -> &Channel {
+        &self.channel
+    }
+
+    /// A client for the accounts service.
+    pub fn accounts(&self) -> AccountsServiceClient<LedgerChannel> {
+        AccountsServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the attachments service.
+    pub fn attachments(&self) -> AttachmentsServiceClient<LedgerChannel> {
+        AttachmentsServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the audit service.
+    pub fn audit(&self) -> AuditServiceClient<LedgerChannel> {
+        AuditServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the budgets service.
+    pub fn budgets(&self) -> BudgetsServiceClient<LedgerChannel> {
+        BudgetsServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the categories service.
+    pub fn categories(&self) -> CategoriesServiceClient<LedgerChannel> {
+        CategoriesServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the export service.
+    pub fn export(&self) -> ExportServiceClient<LedgerChannel> {
+        ExportServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the goals service.
+    pub fn goals(&self) -> GoalsServiceClient<LedgerChannel> {
+        GoalsServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the payees service.
+    pub fn payees(&self) -> PayeesServiceClient<LedgerChannel> {
+        PayeesServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the periods service.
+    pub fn periods(&self) -> PeriodsServiceClient<LedgerChannel> {
+        PeriodsServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the reconciliations service.
+    pub fn reconciliations(&self) -> ReconciliationsServiceClient<LedgerChannel> {
+        ReconciliationsServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the standard `grpc.health.v1.Health` service.
+    pub fn health(&self) -> HealthClient<LedgerChannel> {
+        HealthClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the reports service.
+    pub fn reports(&self) -> ReportsServiceClient<LedgerChannel> {
+        ReportsServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the search service.
+    pub fn search(&self) -> SearchServiceClient<LedgerChannel> {
+        SearchServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the transactions service.
+    pub fn transactions(&self) -> TransactionsServiceClient<LedgerChannel> {
+        TransactionsServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// A client for the utilities service.
+    pub fn utilities(&self) -> UtilitiesServiceClient<LedgerChannel> {
+        UtilitiesServiceClient::with_interceptor(self.channel.clone(), self.token.clone())
+    }
+
+    /// Every category, in the server's default order.
+    pub fn list_all_categories(
+        &self,
+    ) -> impl Stream<Item = Result<Category, Status>> + Send + 'static {
+        self.iter_categories(CategoriesListRequest::default())
+    }
+
+    /// Every category matching `filter`, following page tokens from its
+    /// `page_token`. The `limit` sets the page size.
+    pub fn iter_categories(
+        &self,
+        filter: CategoriesListRequest,
+    ) -> impl Stream<Item = Result<Category, Status>> + Send + 'static {
+        let client = self.categories();
+
+        paginate(filter, move |request| {
+            let mut client = client.clone();
+            async move {
+                let response = client.categories_list(request).await?.into_inner();
+                Ok((response.categories, response.next_page_token))
+            }
+        })
+    }
+
+    /// Every transaction matching `filter`, following page tokens from its
+    /// `page_token`. The `limit` sets the page size.
+    pub fn iter_transactions(
+        &self,
+        filter: TransactionsListRequest,
+    ) -> impl Stream<Item = Result<Transaction, Status>> + Send + 'static {
+        let client = self.transactions();
+
+        paginate(filter, move |request| {
+            let mut client = client.clone();
+            async move {
+                let response = client.transactions_list(request).await?.into_inner();
+                Ok((response.transactions, response.next_page_token))
+            }
+        })
+    }
+
+    /// Every account, following page tokens from the request's
+    /// `page_token`. The `limit` sets the page size.
+    pub fn iter_accounts(
+        &self,
+        request: AccountsListRequest,
+    ) -> impl Stream<Item = Result<Account, Status>> + Send + 'static {
+        let client = self.accounts();
+
+        paginate(request, move |request| {
+            let mut client = client.clone();
+            async move {
+                let response = client.accounts_list(request).await?.into_inner();
+                Ok((response.accounts, response.next_page_token))
+            }
+        })
+    }
+
+    /// Every budget, following page tokens from the request's `page_token`.
+    /// The `limit` sets the page size.
+    pub fn iter_budgets(
+        &self,
+        request: BudgetsListRequest,
+    ) -> impl Stream<Item = Result<Budget, Status>> + Send + 'static {
+        let client = self.budgets();
+
+        paginate(request, move |request| {
+            let mut client = client.clone();
+            async move {
+                let response = client.budgets_list(request).await?.into_inner();
+                Ok((response.budgets, response.next_page_token))
+            }
+        })
+    }
+}
+
+/// A list request that is paged with a page token.
+trait PageRequest: Clone {
+    /// Sets the token of the page to request.
+    fn set_page_token(&mut self, page_token: String);
+}
+
+macro_rules! impl_page_request {
+    ($($request:ty),*) => {
+        $(
+            impl PageRequest for $request {
+                fn set_page_token(&mut self, page_token: String) {
+                    self.page_token = page_token;
+                }
+            }
+        )*
+    };
+}
+
+impl_page_request!(
+    AccountsListRequest,
+    BudgetsListRequest,
+    CategoriesListRequest,
+    TransactionsListRequest
+);
+
+/// Streams the items of every page, from `request` on. `fetch` requests one
+/// page and returns its items and the next page token, empty on the last page.
+fn paginate<R, T, F, Fut>(
+    request: R,
+    fetch: F,
+) -> impl Stream<Item = Result<T, Status>> + Send + 'static
+where
+    R: PageRequest + Send + 'static,
+    T: Send + 'static,
+    F: Fn(R) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<(Vec<T>, String), Status>> + Send + 'static,
+{
+    stream::try_unfold((Some(request), fetch), |(request, fetch)| async move {
+        let Some(request) = request else {
+            return Ok::<_, Status>(None);
+        };
+
+        let (items, next_page_token) = fetch(request.clone()).await?;
+        let next = (!next_page_token.is_empty()).then(|| {
+            let mut next = request;
+            next.set_page_token(next_page_token);
+            next
+        });
+
+        Ok(Some((
+            stream::iter(items.into_iter().map(Ok)),
+            (next, fetch),
+        )))
+    })
+    .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Fetches pages of `pages`, where each page token is the page's index,
+    /// recording the tokens requested.
+    fn fake_pages(
+        pages: Vec<Vec<i32>>,
+        requested: Arc<Mutex<Vec<String>>>,
+    ) -> impl Stream<Item = Result<i32, Status>> {
+        let request = AccountsListRequest {
+            limit: 2,
+            ..Default::default()
+        };
+
+        paginate(request, move |request: AccountsListRequest| {
+            requested.lock().unwrap().push(request.page_token.clone());
+            assert_eq!(request.limit, 2);
+
+            let index: usize = request.page_token.parse().unwrap_or_default();
+            let items = pages.get(index).cloned();
+            let next = if index + 1 < pages.len() {
+                (index + 1).to_string()
+            } else {
+                String::new()
+            };
+
+            async move {
+                items
+                    .map(|items| (items, next))
+                    .ok_or_else(|| Status::not_found("page"))
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn paginate_follows_page_tokens() {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+
+        let items: Vec<i32> = fake_pages(vec![vec![1, 2], vec![3, 4], vec![5]], requested.clone())
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+        assert_eq!(*requested.lock().unwrap(), ["", "1", "2"]);
+    }
+
+    #[tokio::test]
+    async fn paginate_stops_after_empty_page() {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+
+        let items: Vec<i32> = fake_pages(vec![vec![]], requested.clone())
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert!(items.is_empty());
+        assert_eq!(requested.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn client_token_is_attached_when_set() {
+        let mut token = ClientToken(Some(BearerToken::new("key-one").unwrap()));
+        let request = token.call(Request::new(())).unwrap();
+        assert_eq!(
+            request.metadata().get(crate::AUTHORIZATION_HEADER).unwrap(),
+            "Bearer key-one"
+        );
+
+        let request = ClientToken::default().call(Request::new(())).unwrap();
+        assert!(
+            request
+                .metadata()
+                .get(crate::AUTHORIZATION_HEADER)
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn paginate_ends_on_first_error() {
+        let request = AccountsListRequest::default();
+        let stream = paginate(request, |_request: AccountsListRequest| async {
+            Err::<(Vec<i32>, String), _>(Status::unavailable("server down"))
+        });
+        futures_util::pin_mut!(stream);
+
+        let error = stream.try_next().await.unwrap_err();
+        assert_eq!(error.code(), tonic::Code::Unavailable);
+        assert!(stream.try_next().await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,875 @@
+// -- ./src/compatibility.rs --
+
+//! Compatibility module - checks proto changes for changes that break existing clients.
+//!
+//! The compiled protos are summarised as an [`ApiSurface`]: every message field
+//! by number, name, label and type, every enum value, and every RPC with its
+//! request and response types. The surface has a plain text form, one item per
+//! line, that is committed as `snapshots/personal-ledger-api.txt`.
+//!
+//! A test compares the current protos with that snapshot. Adding messages,
+//! fields, enum values and RPCs is always allowed. These are breaking changes:
+//!
+//! - Removing a message, enum, service or RPC
+//! - Removing a field, unless its number is reserved
+//! - Renumbering, renaming or changing the type of a field, or making it
+//!   repeated or no longer repeated
+//! - Removing, renumbering or renaming an enum value
+//! - Changing an RPC's request or response type, or whether either streams
+//!
+//! When the protos change without breaking anything, regenerate the snapshot
+//! with `UPDATE_API_SNAPSHOT=1 cargo test -p lib_rpc` and commit it with the
+//! change.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use lib_rpc::ApiSurface;
+//!
+//! let current = ApiSurface::current();
+//! let snapshot = ApiSurface::parse(&current.to_string()).unwrap();
+//!
+//! assert!(snapshot.breaking_changes(&current).is_empty());
+//! ```
+
+// ---------------------------- [ COMPATIBILITY ] ----------------------------------
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use prost::Message;
+use prost_types::field_descriptor_proto::{Label, Type};
+
+/// The compiled `FileDescriptorSet` of every proto in the crate, and the
+/// protos they import, for gRPC reflection and [`ApiSurface`].
+pub use crate::generated::FILE_DESCRIPTOR_SET;
+
+/// Package prefix of the protos that make up the API.
+const PACKAGE_PREFIX: &str = "personal_ledger.";
+
+/// Whether a field holds one value, one value with presence, or many.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldLabels {
+    /// A plain proto3 field.
+    Singular,
+    /// A proto3 `optional` field, with presence.
+    Optional,
+    /// A `repeated` or `map` field.
+    Repeated,
+}
+
+impl FieldLabels {
+    fn as_str(self) -> &'static str {
+        match self {
+            FieldLabels::Singular => "singular",
+            FieldLabels::Optional => "optional",
+            FieldLabels::Repeated => "repeated",
+        }
+    }
+
+    fn parse(label: &str) -> Option<Self> {
+        match label {
+            "singular" => Some(FieldLabels::Singular),
+            "optional" => Some(FieldLabels::Optional),
+            "repeated" => Some(FieldLabels::Repeated),
+            _ => None,
+        }
+    }
+}
+
+/// A message field, as it is sent on the wire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FieldShape {
+    name: String,
+    label: FieldLabels,
+    type_name: String,
+}
+
+/// A message's fields by number, and the field numbers it reserves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct MessageShape {
+    fields: BTreeMap<i32, FieldShape>,
+    reserved: Vec<(i32, i32)>,
+}
+
+impl MessageShape {
+    fn is_reserved(&self, number: i32) -> bool {
+        self.reserved
+            .iter()
+            .any(|&(start, end)| (start..=end).contains(&number))
+    }
+}
+
+/// An RPC's request and response types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MethodShape {
+    input: String,
+    output: String,
+    client_streaming: bool,
+    server_streaming: bool,
+}
+
+impl fmt::Display for MethodShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stream = |streaming: bool| if streaming { "stream " } else { "" };
+        write!(
+            f,
+            "({}{}) returns ({}{})",
+            stream(self.client_streaming),
+            self.input,
+            stream(self.server_streaming),
+            self.output
+        )
+    }
+}
+
+/// The parts of the protos that existing clients depend on, keyed by fully
+/// qualified name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiSurface {
+    messages: BTreeMap<String, MessageShape>,
+    enums: BTreeMap<String, BTreeMap<i32, String>>,
+    services: BTreeMap<String, BTreeMap<String, MethodShape>>,
+}
+
+/// A change between two versions of the API that breaks existing clients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BreakingChange {
+    /// A message was removed.
+    MessageRemoved(String),
+    /// A field was removed without reserving its number.
+    FieldRemoved {
+        message: String,
+        number: i32,
+        name: String,
+    },
+    /// A field was moved to a different number.
+    FieldRenumbered {
+        message: String,
+        name: String,
+        from: i32,
+        to: i32,
+    },
+    /// A field number now has a different name.
+    FieldRenamed {
+        message: String,
+        number: i32,
+        from: String,
+        to: String,
+    },
+    /// A field's type changed.
+    FieldTypeChanged {
+        message: String,
+        name: String,
+        from: String,
+        to: String,
+    },
+    /// A field became repeated, or stopped being repeated.
+    FieldLabelChanged {
+        message: String,
+        name: String,
+        from: FieldLabels,
+        to: FieldLabels,
+    },
+    /// An enum was removed.
+    EnumRemoved(String),
+    /// An enum value was removed or renumbered.
+    EnumValueRemoved {
+        name: String,
+        value: String,
+        number: i32,
+    },
+    /// An enum number now has a different name.
+    EnumValueRenamed {
+        name: String,
+        number: i32,
+        from: String,
+        to: String,
+    },
+    /// A service was removed.
+    ServiceRemoved(String),
+    /// An RPC was removed.
+    MethodRemoved { service: String, method: String },
+    /// An RPC's request or response type, or streaming, changed.
+    MethodChanged {
+        service: String,
+        method: String,
+        from: String,
+        to: String,
+    },
+}
+
+impl fmt::Display for BreakingChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BreakingChange::MessageRemoved(name) => write!(f, "message {name} was removed"),
+            BreakingChange::FieldRemoved {
+                message,
+                number,
+                name,
+            } => write!(
+                f,
+                "field {message}.{name} = {number} was removed, reserve its number instead"
+            ),
+            BreakingChange::FieldRenumbered {
+                message,
+                name,
+                from,
+                to,
+            } => {
+                write!(
+                    f,
+                    "field {message}.{name} was renumbered from {from} to {to}"
+                )
+            }
+            BreakingChange::FieldRenamed {
+                message,
+                number,
+                from,
+                to,
+            } => {
+                write!(
+                    f,
+                    "field {message} = {number} was renamed from {from} to {to}"
+                )
+            }
+            BreakingChange::FieldTypeChanged {
+                message,
+                name,
+                from,
+                to,
+            } => {
+                write!(f, "field {message}.{name} changed type from {from} to {to}")
+            }
+            BreakingChange::FieldLabelChanged {
+                message,
+                name,
+                from,
+                to,
+            } => write!(
+                f,
+                "field {message}.{name} changed from {} to {}",
+                from.as_str(),
+                to.as_str()
+            ),
+            BreakingChange::EnumRemoved(name) => write!(f, "enum {name} was removed"),
+            BreakingChange::EnumValueRemoved {
+                name,
+                value,
+                number,
+            } => {
+                write!(f, "enum value {name}.{value} = {number} was removed")
+            }
+            BreakingChange::EnumValueRenamed {
+                name,
+                number,
+                from,
+                to,
+            } => {
+                write!(
+                    f,
+                    "enum value {name} = {number} was renamed from {from} to {to}"
+                )
+            }
+            BreakingChange::ServiceRemoved(name) => write!(f, "service {name} was removed"),
+            BreakingChange::MethodRemoved { service, method } => {
+                write!(f, "rpc {service}.{method} was removed")
+            }
+            BreakingChange::MethodChanged {
+                service,
+                method,
+                from,
+                to,
+            } => {
+                write!(f, "rpc {service}.{method} changed from {from} to {to}")
+            }
+        }
+    }
+}
+
+impl ApiSurface {
+    /// The API surface of the protos this crate was compiled from.
+    pub fn current() -> Self {
+        let descriptors = prost_types::FileDescriptorSet::decode(FILE_DESCRIPTOR_SET)
+            .expect("The descriptor set written by build.rs should always decode");
+
+        Self::from_descriptor_set(&descriptors)
+    }
+
+    /// Builds the API surface of the `personal_ledger` packages in a compiled
+    /// `FileDescriptorSet`. Other packages, such as the well-known types, are
+    /// left out.
+    pub fn from_descriptor_set(descriptors: &prost_types::FileDescriptorSet) -> Self {
+        let mut surface = Self::default();
+
+        for file in descriptors
+            .file
+            .iter()
+            .filter(|file| file.package().starts_with(PACKAGE_PREFIX))
+        {
+            let package = file.package();
+
+            for message in &file.message_type {
+                surface.add_message(package, message);
+            }
+            for enumeration in &file.enum_type {
+                surface.add_enum(package, enumeration);
+            }
+            for service in &file.service {
+                let methods = service
+                    .method
+                    .iter()
+                    .map(|method| {
+                        let shape = MethodShape {
+                            input: method.input_type().trim_start_matches('.').to_string(),
+                            output: method.output_type().trim_start_matches('.').to_string(),
+                            client_streaming: method.client_streaming(),
+                            server_streaming: method.server_streaming(),
+                        };
+                        (method.name().to_string(), shape)
+                    })
+                    .collect();
+
+                surface
+                    .services
+                    .insert(format!("{package}.{}", service.name()), methods);
+            }
+        }
+
+        surface
+    }
+
+    fn add_message(&mut self, scope: &str, message: &prost_types::DescriptorProto) {
+        let name = format!("{scope}.{}", message.name());
+
+        let fields = message
+            .field
+            .iter()
+            .map(|field| {
+                let label = if field.label() == Label::Repeated {
+                    FieldLabels::Repeated
+                } else if field.proto3_optional() {
+                    FieldLabels::Optional
+                } else {
+                    FieldLabels::Singular
+                };
+
+                let type_name = match field.r#type() {
+                    Type::Message | Type::Enum => {
+                        field.type_name().trim_start_matches('.').to_string()
+                    }
+                    scalar => scalar
+                        .as_str_name()
+                        .trim_start_matches("TYPE_")
+                        .to_lowercase(),
+                };
+
+                let shape = FieldShape {
+                    name: field.name().to_string(),
+                    label,
+                    type_name,
+                };
+                (field.number(), shape)
+            })
+            .collect();
+
+        // Descriptor ranges are end exclusive
+        let reserved = message
+            .reserved_range
+            .iter()
+            .map(|range| (range.start(), range.end() - 1))
+            .collect();
+
+        for nested in &message.nested_type {
+            self.add_message(&name, nested);
+        }
+        for enumeration in &message.enum_type {
+            self.add_enum(&name, enumeration);
+        }
+
+        self.messages
+            .insert(name, MessageShape { fields, reserved });
+    }
+
+    fn add_enum(&mut self, scope: &str, enumeration: &prost_types::EnumDescriptorProto) {
+        let values = enumeration
+            .value
+            .iter()
+            .map(|value| (value.number(), value.name().to_string()))
+            .collect();
+
+        self.enums
+            .insert(format!("{scope}.{}", enumeration.name()), values);
+    }
+
+    /// Parses the text form of an API surface, as written by its `Display`
+    /// implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the first line that cannot be parsed.
+    pub fn parse(snapshot: &str) -> Result<Self, String> {
+        let mut surface = Self::default();
+
+        for (index, line) in snapshot.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            surface
+                .parse_line(line)
+                .ok_or_else(|| format!("Invalid API snapshot line {}: {}", index + 1, line))?;
+        }
+
+        Ok(surface)
+    }
+
+    fn parse_line(&mut self, line: &str) -> Option<()> {
+        let (kind, rest) = line.split_once(' ')?;
+
+        match kind {
+            "message" => {
+                self.messages.entry(rest.to_string()).or_default();
+            }
+            "field" => {
+                // field <message>.<name> = <number> <label> <type>
+                let (path, rest) = rest.split_once(" = ")?;
+                let (message, name) = path.rsplit_once('.')?;
+                let mut parts = rest.split(' ');
+                let number = parts.next()?.parse().ok()?;
+                let label = FieldLabels::parse(parts.next()?)?;
+                let type_name = parts.next()?.to_string();
+
+                let shape = FieldShape {
+                    name: name.to_string(),
+                    label,
+                    type_name,
+                };
+                self.messages
+                    .entry(message.to_string())
+                    .or_default()
+                    .fields
+                    .insert(number, shape);
+            }
+            "reserved" => {
+                // reserved <message> <start>..<end>
+                let (message, range) = rest.split_once(' ')?;
+                let (start, end) = range.split_once("..")?;
+                let range = (start.parse().ok()?, end.parse().ok()?);
+                self.messages
+                    .entry(message.to_string())
+                    .or_default()
+                    .reserved
+                    .push(range);
+            }
+            "enum" => {
+                self.enums.entry(rest.to_string()).or_default();
+            }
+            "value" => {
+                // value <enum>.<NAME> = <number>
+                let (path, number) = rest.split_once(" = ")?;
+                let (enumeration, name) = path.rsplit_once('.')?;
+                self.enums
+                    .entry(enumeration.to_string())
+                    .or_default()
+                    .insert(number.parse().ok()?, name.to_string());
+            }
+            "service" => {
+                self.services.entry(rest.to_string()).or_default();
+            }
+            "rpc" => {
+                // rpc <service>.<Method>([stream ]<input>) returns ([stream ]<output>)
+                let (path, rest) = rest.split_once('(')?;
+                let (service, method) = path.rsplit_once('.')?;
+                let (input, output) = rest.split_once(") returns (")?;
+                let output = output.strip_suffix(')')?;
+
+                let shape = MethodShape {
+                    input: input.trim_start_matches("stream ").to_string(),
+                    output: output.trim_start_matches("stream ").to_string(),
+                    client_streaming: input.starts_with("stream "),
+                    server_streaming: output.starts_with("stream "),
+                };
+                self.services
+                    .entry(service.to_string())
+                    .or_default()
+                    .insert(method.to_string(), shape);
+            }
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    /// Every RPC as its fully qualified service name and method name.
+    pub fn methods(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.services.iter().flat_map(|(service, methods)| {
+            methods
+                .keys()
+                .map(move |method| (service.as_str(), method.as_str()))
+        })
+    }
+
+    /// Lists the changes from this API surface to `newer` that break clients
+    /// built against this one. Additions are not listed.
+    pub fn breaking_changes(&self, newer: &ApiSurface) -> Vec<BreakingChange> {
+        let mut changes = Vec::new();
+
+        for (name, old) in &self.messages {
+            let Some(new) = newer.messages.get(name) else {
+                changes.push(BreakingChange::MessageRemoved(name.clone()));
+                continue;
+            };
+
+            for (&number, old_field) in &old.fields {
+                let Some(new_field) = new.fields.get(&number) else {
+                    let moved_to = new
+                        .fields
+                        .iter()
+                        .find(|(_, field)| field.name == old_field.name)
+                        .map(|(&to, _)| to);
+
+                    match moved_to {
+                        Some(to) => changes.push(BreakingChange::FieldRenumbered {
+                            message: name.clone(),
+                            name: old_field.name.clone(),
+                            from: number,
+                            to,
+                        }),
+                        None if new.is_reserved(number) => {}
+                        None => changes.push(BreakingChange::FieldRemoved {
+                            message: name.clone(),
+                            number,
+                            name: old_field.name.clone(),
+                        }),
+                    }
+                    continue;
+                };
+
+                if new_field.name != old_field.name {
+                    changes.push(BreakingChange::FieldRenamed {
+                        message: name.clone(),
+                        number,
+                        from: old_field.name.clone(),
+                        to: new_field.name.clone(),
+                    });
+                }
+                if new_field.type_name != old_field.type_name {
+                    changes.push(BreakingChange::FieldTypeChanged {
+                        message: name.clone(),
+                        name: new_field.name.clone(),
+                        from: old_field.type_name.clone(),
+                        to: new_field.type_name.clone(),
+                    });
+                }
+                // Adding or dropping presence keeps the wire format
+                if (new_field.label == FieldLabels::Repeated)
+                    != (old_field.label == FieldLabels::Repeated)
+                {
+                    changes.push(BreakingChange::FieldLabelChanged {
+                        message: name.clone(),
+                        name: new_field.name.clone(),
+                        from: old_field.label,
+                        to: new_field.label,
+                    });
+                }
+            }
+        }
+
+        for (name, old) in &self.enums {
+            let Some(new) = newer.enums.get(name) else {
+                changes.push(BreakingChange::EnumRemoved(name.clone()));
+                continue;
+            };
+
+            for (&number, old_value) in old {
+                match new.get(&number) {
+                    None => changes.push(BreakingChange::EnumValueRemoved {
+                        name: name.clone(),
+                        value: old_value.clone(),
+                        number,
+                    }),
+                    Some(new_value) if new_value != old_value => {
+                        changes.push(BreakingChange::EnumValueRenamed {
+                            name: name.clone(),
+                            number,
+                            from: old_value.clone(),
+                            to: new_value.clone(),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        for (name, old) in &self.services {
+            let Some(new) = newer.services.get(name) else {
+                changes.push(BreakingChange::ServiceRemoved(name.clone()));
+                continue;
+            };
+
+            for (method, old_shape) in old {
+                match new.get(method) {
+                    None => changes.push(BreakingChange::MethodRemoved {
+                        service: name.clone(),
+                        method: method.clone(),
+                    }),
+                    Some(new_shape) if new_shape != old_shape => {
+                        changes.push(BreakingChange::MethodChanged {
+                            service: name.clone(),
+                            method: method.clone(),
+                            from: old_shape.to_string(),
+                            to: new_shape.to_string(),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        changes
+    }
+}
+
+impl fmt::Display for ApiSurface {
+    /// Writes the text form of the surface, one item per line in name order,
+    /// so changes show up as line diffs.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, message) in &self.messages {
+            writeln!(f, "message {name}")?;
+            for (number, field) in &message.fields {
+                writeln!(
+                    f,
+                    "field {name}.{} = {number} {} {}",
+                    field.name,
+                    field.label.as_str(),
+                    field.type_name
+                )?;
+            }
+            for (start, end) in &message.reserved {
+                writeln!(f, "reserved {name} {start}..{end}")?;
+            }
+        }
+
+        for (name, values) in &self.enums {
+            writeln!(f, "enum {name}")?;
+            for (number, value) in values {
+                writeln!(f, "value {name}.{value} = {number}")?;
+            }
+        }
+
+        for (name, methods) in &self.services {
+            writeln!(f, "service {name}")?;
+            for (method, shape) in methods {
+                writeln!(f, "rpc {name}.{method}{shape}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SNAPSHOT_PATH: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/snapshots/personal-ledger-api.txt"
+    );
+
+    const SNAPSHOT_HEADER: &str = "# Personal Ledger API snapshot, checked by lib-rpc's compatibility tests.\n\
+        # Regenerate with `UPDATE_API_SNAPSHOT=1 cargo test -p lib_rpc`, never edit by hand.\n";
+
+    const OLD: &str = "\
+message personal_ledger.test.v001.Item
+field personal_ledger.test.v001.Item.id = 1 singular string
+field personal_ledger.test.v001.Item.name = 2 singular string
+field personal_ledger.test.v001.Item.tags = 3 repeated string
+field personal_ledger.test.v001.Item.kind = 4 singular personal_ledger.test.v001.Kinds
+enum personal_ledger.test.v001.Kinds
+value personal_ledger.test.v001.Kinds.KINDS_UNSPECIFIED = 0
+value personal_ledger.test.v001.Kinds.KINDS_BOOK = 1
+service personal_ledger.test.v001.ItemsService
+rpc personal_ledger.test.v001.ItemsService.ItemGet(personal_ledger.test.v001.Item) returns (personal_ledger.test.v001.Item)
+";
+
+    #[test]
+    fn snapshot_matches_current_protos() {
+        let current = ApiSurface::current();
+        let rendered = format!("{SNAPSHOT_HEADER}{current}");
+
+        if std::env::var_os("UPDATE_API_SNAPSHOT").is_some() {
+            std::fs::write(SNAPSHOT_PATH, &rendered).expect("API snapshot should be writable");
+            return;
+        }
+
+        let committed = std::fs::read_to_string(SNAPSHOT_PATH).expect("API snapshot should exist");
+        let snapshot = ApiSurface::parse(&committed).expect("API snapshot should parse");
+
+        let breaking = snapshot.breaking_changes(&current);
+        assert!(
+            breaking.is_empty(),
+            "Proto changes break existing clients:\n{}",
+            breaking
+                .iter()
+                .map(|change| format!("- {change}\n"))
+                .collect::<String>()
+        );
+
+        assert_eq!(
+            committed, rendered,
+            "Proto API changed compatibly, regenerate the snapshot with `UPDATE_API_SNAPSHOT=1 cargo test -p lib_rpc`"
+        );
+    }
+
+    #[test]
+    fn current_surface_round_trips_through_text() {
+        let current = ApiSurface::current();
+
+        assert!(
+            current
+                .services
+                .contains_key("personal_ledger.categories.v001.CategoriesService")
+        );
+        assert!(
+            !current
+                .messages
+                .keys()
+                .any(|name| name.starts_with("google."))
+        );
+        assert_eq!(ApiSurface::parse(&current.to_string()), Ok(current));
+    }
+
+    #[test]
+    fn additions_are_compatible() {
+        let old = ApiSurface::parse(OLD).unwrap();
+        let new = ApiSurface::parse(&format!(
+            "{OLD}\
+field personal_ledger.test.v001.Item.note = 5 optional string
+value personal_ledger.test.v001.Kinds.KINDS_FILM = 2
+rpc personal_ledger.test.v001.ItemsService.ItemsStream(personal_ledger.test.v001.Item) returns (stream personal_ledger.test.v001.Item)
+message personal_ledger.test.v001.Shelf
+"
+        ))
+        .unwrap();
+
+        assert_eq!(old.breaking_changes(&new), vec![]);
+        assert_eq!(new.breaking_changes(&new), vec![]);
+    }
+
+    #[test]
+    fn removed_fields_must_be_reserved() {
+        let old = ApiSurface::parse(OLD).unwrap();
+        let removed = OLD.replace(
+            "field personal_ledger.test.v001.Item.name = 2 singular string\n",
+            "",
+        );
+
+        let new = ApiSurface::parse(&removed).unwrap();
+        assert_eq!(
+            old.breaking_changes(&new),
+            vec![BreakingChange::FieldRemoved {
+                message: "personal_ledger.test.v001.Item".to_string(),
+                number: 2,
+                name: "name".to_string(),
+            }]
+        );
+
+        let new = ApiSurface::parse(&format!(
+            "{removed}reserved personal_ledger.test.v001.Item 2..2\n"
+        ))
+        .unwrap();
+        assert_eq!(old.breaking_changes(&new), vec![]);
+    }
+
+    #[test]
+    fn field_changes_are_breaking() {
+        let old = ApiSurface::parse(OLD).unwrap();
+        let new = ApiSurface::parse(
+            &OLD.replace("Item.name = 2 singular", "Item.name = 6 singular")
+                .replace(
+                    "Item.tags = 3 repeated string",
+                    "Item.tags = 3 singular string",
+                )
+                .replace(
+                    "Item.kind = 4 singular personal_ledger.test.v001.Kinds",
+                    "Item.category = 4 singular int32",
+                ),
+        )
+        .unwrap();
+
+        let changes: Vec<String> = old
+            .breaking_changes(&new)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                "field personal_ledger.test.v001.Item.name was renumbered from 2 to 6",
+                "field personal_ledger.test.v001.Item.tags changed from repeated to singular",
+                "field personal_ledger.test.v001.Item = 4 was renamed from kind to category",
+                "field personal_ledger.test.v001.Item.category changed type from personal_ledger.test.v001.Kinds to int32",
+            ]
+        );
+
+        // Presence does not change the wire format
+        let new =
+            ApiSurface::parse(&OLD.replace("Item.name = 2 singular", "Item.name = 2 optional"))
+                .unwrap();
+        assert_eq!(old.breaking_changes(&new), vec![]);
+    }
+
+    #[test]
+    fn removed_enums_services_and_rpcs_are_breaking() {
+        let old = ApiSurface::parse(OLD).unwrap();
+        let new = ApiSurface::parse(
+            &OLD.replace("value personal_ledger.test.v001.Kinds.KINDS_BOOK = 1\n", "")
+                .replace(
+                    "returns (personal_ledger.test.v001.Item)",
+                    "returns (stream personal_ledger.test.v001.Item)",
+                ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            old.breaking_changes(&new),
+            vec![
+                BreakingChange::EnumValueRemoved {
+                    name: "personal_ledger.test.v001.Kinds".to_string(),
+                    value: "KINDS_BOOK".to_string(),
+                    number: 1,
+                },
+                BreakingChange::MethodChanged {
+                    service: "personal_ledger.test.v001.ItemsService".to_string(),
+                    method: "ItemGet".to_string(),
+                    from: "(personal_ledger.test.v001.Item) returns (personal_ledger.test.v001.Item)".to_string(),
+                    to: "(personal_ledger.test.v001.Item) returns (stream personal_ledger.test.v001.Item)".to_string(),
+                },
+            ]
+        );
+
+        let changes = old.breaking_changes(&ApiSurface::default());
+        assert!(changes.contains(&BreakingChange::MessageRemoved(
+            "personal_ledger.test.v001.Item".to_string()
+        )));
+        assert!(changes.contains(&BreakingChange::EnumRemoved(
+            "personal_ledger.test.v001.Kinds".to_string()
+        )));
+        assert!(changes.contains(&BreakingChange::ServiceRemoved(
+            "personal_ledger.test.v001.ItemsService".to_string()
+        )));
+    }
+
+    #[test]
+    fn parse_rejects_invalid_lines() {
+        let result = ApiSurface::parse("message personal_ledger.test.v001.Item\nfield nonsense\n");
+
+        assert_eq!(
+            result,
+            Err("Invalid API snapshot line 2: field nonsense".to_string())
+        );
+    }
+}
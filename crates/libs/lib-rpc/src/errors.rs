@@ -0,0 +1,268 @@
+// -- ./src/errors.rs --
+
+//! Errors module - why a request failed, in a form a client can act on.
+//!
+//! Every error the services return carries a `google.rpc.ErrorInfo` in its
+//! status details alongside the canonical code. Its reason is one of the
+//! [`ErrorReason`]s in the [`ERROR_DOMAIN`], and its metadata names the
+//! `entity` and `field` the error is about, when known. A client can then
+//! branch on the reason rather than parsing the message, which is written for
+//! people and may change:
+//!
+//! | Reason              | Code                  | When                                         |
+//! |---------------------|-----------------------|----------------------------------------------|
+//! | `NOT_FOUND`         | `NOT_FOUND`           | The record does not exist                    |
+//! | `VALIDATION_FAILED` | `INVALID_ARGUMENT`    | A field of the request is not valid          |
+//! | `ALREADY_EXISTS`    | `ALREADY_EXISTS`      | A record with the same unique field exists   |
+//! | `IN_USE`            | `FAILED_PRECONDITION` | Other records still refer to the record      |
+//! | `MISSING_REFERENCE` | `FAILED_PRECONDITION` | A record it refers to does not exist         |
+//! | `PERIOD_CLOSED`     | `FAILED_PRECONDITION` | The change falls in a closed period          |
+//! | `CONFLICT`          | `ABORTED`             | The record was changed by someone else       |
+//! | `BUSY`              | `UNAVAILABLE`         | The database was busy, retry shortly         |
+//! | `INTERNAL`          | `INTERNAL`            | The server failed                            |
+//!
+//! ```rust
+//! use lib_rpc::{ErrorReason, error_info, error_status};
+//! use tonic::Code;
+//!
+//! let status = error_status(
+//!     Code::AlreadyExists,
+//!     "UNIQUE constraint failed: categories.code",
+//!     ErrorReason::AlreadyExists,
+//!     Some("categories"),
+//!     Some("code"),
+//! );
+//!
+//! let info = error_info(&status).unwrap();
+//! assert_eq!(info.reason(), Some(ErrorReason::AlreadyExists));
+//! assert_eq!(info.field(), Some("code"));
+//! ```
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use tonic::{Code, Status};
+use tonic_types::{ErrorDetails, StatusExt};
+
+/// The domain of every `google.rpc.ErrorInfo` the services return.
+pub const ERROR_DOMAIN: &str = "personal-ledger";
+
+/// `google.rpc.ErrorInfo` metadata key naming the kind of record an error is
+/// about, such as `categories`.
+pub const ERROR_ENTITY_KEY: &str = "entity";
+
+/// `google.rpc.ErrorInfo` metadata key naming the field an error is about,
+/// such as `code`.
+pub const ERROR_FIELD_KEY: &str = "field";
+
+/// Why a request failed, the reason of its `google.rpc.ErrorInfo`. See the
+/// [module docs](self) for the code each is returned with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorReason {
+    /// The record does not exist.
+    NotFound,
+    /// A field of the request is not valid.
+    ValidationFailed,
+    /// A record with the same unique field already exists.
+    AlreadyExists,
+    /// Other records still refer to the record.
+    InUse,
+    /// A record the request refers to does not exist.
+    MissingReference,
+    /// The change falls in a closed period.
+    PeriodClosed,
+    /// The record was changed since it was read.
+    Conflict,
+    /// The database was busy, the request can be retried.
+    Busy,
+    /// The server failed.
+    Internal,
+}
+
+impl ErrorReason {
+    /// Every reason, in the order of the [module docs](self).
+    pub const ALL: [Self; 9] = [
+        Self::NotFound,
+        Self::ValidationFailed,
+        Self::AlreadyExists,
+        Self::InUse,
+        Self::MissingReference,
+        Self::PeriodClosed,
+        Self::Conflict,
+        Self::Busy,
+        Self::Internal,
+    ];
+
+    /// The reason as sent in `google.rpc.ErrorInfo`, such as `NOT_FOUND`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NotFound => "NOT_FOUND",
+            Self::ValidationFailed => "VALIDATION_FAILED",
+            Self::AlreadyExists => "ALREADY_EXISTS",
+            Self::InUse => "IN_USE",
+            Self::MissingReference => "MISSING_REFERENCE",
+            Self::PeriodClosed => "PERIOD_CLOSED",
+            Self::Conflict => "CONFLICT",
+            Self::Busy => "BUSY",
+            Self::Internal => "INTERNAL",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ErrorReason {
+    type Err = String;
+
+    fn from_str(reason: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|known| known.as_str() == reason)
+            .ok_or_else(|| format!("Unknown error reason: {reason}"))
+    }
+}
+
+/// The metadata of a `google.rpc.ErrorInfo`, naming the `entity` and
+/// `field` it is about when they are known.
+pub fn error_metadata(entity: Option<&str>, field: Option<&str>) -> HashMap<String, String> {
+    [(ERROR_ENTITY_KEY, entity), (ERROR_FIELD_KEY, field)]
+        .into_iter()
+        .filter_map(|(key, value)| Some((key.to_string(), value?.to_string())))
+        .collect()
+}
+
+/// Makes a status with `code` and `message`, carrying a `google.rpc.ErrorInfo`
+/// with `reason` about the `entity` and `field`, when known.
+pub fn error_status(
+    code: Code,
+    message: impl Into<String>,
+    reason: ErrorReason,
+    entity: Option<&str>,
+    field: Option<&str>,
+) -> Status {
+    Status::with_error_details(
+        code,
+        message,
+        ErrorDetails::with_error_info(reason.as_str(), ERROR_DOMAIN, error_metadata(entity, field)),
+    )
+}
+
+/// The `google.rpc.ErrorInfo` of a status returned by the services.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerErrorInfo {
+    reason: String,
+    metadata: HashMap<String, String>,
+}
+
+impl LedgerErrorInfo {
+    /// Why the request failed, or `None` if the server sent a reason this
+    /// client does not know, such as one added in a later version.
+    pub fn reason(&self) -> Option<ErrorReason> {
+        self.reason.parse().ok()
+    }
+
+    /// The reason as sent, including ones this client does not know.
+    pub fn raw_reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// The kind of record the error is about, such as `categories`.
+    pub fn entity(&self) -> Option<&str> {
+        self.metadata.get(ERROR_ENTITY_KEY).map(String::as_str)
+    }
+
+    /// The field the error is about, such as `code`.
+    pub fn field(&self) -> Option<&str> {
+        self.metadata.get(ERROR_FIELD_KEY).map(String::as_str)
+    }
+
+    /// Every metadata value sent with the error.
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+}
+
+/// Reads the `google.rpc.ErrorInfo` of a status, if it has one in the
+/// [`ERROR_DOMAIN`].
+pub fn error_info(status: &Status) -> Option<LedgerErrorInfo> {
+    status
+        .get_details_error_info()
+        .filter(|info| info.domain == ERROR_DOMAIN)
+        .map(|info| LedgerErrorInfo {
+            reason: info.reason,
+            metadata: info.metadata,
+        })
+}
+
+/// Why a request failed, from its status's `google.rpc.ErrorInfo`.
+pub fn error_reason(status: &Status) -> Option<ErrorReason> {
+    error_info(status)?.reason()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reasons_round_trip_through_their_names() {
+        for reason in ErrorReason::ALL {
+            assert_eq!(reason.as_str().parse::<ErrorReason>(), Ok(reason));
+            assert!(
+                reason
+                    .as_str()
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c == '_')
+            );
+        }
+        assert!("TEAPOT".parse::<ErrorReason>().is_err());
+    }
+
+    #[test]
+    fn error_info_is_read_back_from_the_status() {
+        let status = error_status(
+            Code::NotFound,
+            "Category with id 7 not found",
+            ErrorReason::NotFound,
+            Some("category"),
+            None,
+        );
+
+        let info = error_info(&status).expect("error info");
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(status.message(), "Category with id 7 not found");
+        assert_eq!(info.reason(), Some(ErrorReason::NotFound));
+        assert_eq!(info.entity(), Some("category"));
+        assert_eq!(info.field(), None);
+        assert_eq!(error_reason(&status), Some(ErrorReason::NotFound));
+    }
+
+    #[test]
+    fn statuses_without_ledger_error_info_have_no_reason() {
+        let plain = Status::not_found("gone");
+        let foreign = Status::with_error_details(
+            Code::NotFound,
+            "gone",
+            ErrorDetails::with_error_info("NOT_FOUND", "example.com", HashMap::new()),
+        );
+
+        assert_eq!(error_info(&plain), None);
+        assert_eq!(error_info(&foreign), None);
+    }
+
+    #[test]
+    fn unknown_reasons_are_kept_raw() {
+        let status = Status::with_error_details(
+            Code::Internal,
+            "new",
+            ErrorDetails::with_error_info("SOMETHING_NEW", ERROR_DOMAIN, HashMap::new()),
+        );
+
+        let info = error_info(&status).expect("error info");
+        assert_eq!(info.reason(), None);
+        assert_eq!(info.raw_reason(), "SOMETHING_NEW");
+    }
+}
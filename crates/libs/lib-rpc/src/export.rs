@@ -0,0 +1,41 @@
+// -- ./src/export.rs --
+
+//! Export module - gRPC services and types for exporting the ledger.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the export service. Exports stream the categories, accounts and transactions
+//! of the ledger as CSV or JSON Lines, for spreadsheets or moving to another program.
+//!
+//! ## Services
+//!
+//! - **ExportService**: Streams the records of the ledger, one kind after another, or
+//!   the whole ledger as a book for another accounting program.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `ExportFormats`: Whether records are exported as CSV or JSON Lines
+//! - `ExportEntities`: The kinds of record that can be exported
+//! - `BookFormats`: The accounting programs a book can be exported for
+//! - Request/Response types for all operations (Ledger, Book)
+//! - `ExportServiceClient`: gRPC client for connecting to export service
+//! - `ExportService`: Server trait for implementing export service
+//! - `ExportServiceServer`: Server implementation for export service
+
+// ---------------------------- [ EXPORT ] -----------------------------------
+
+/// gRPC client for the ExportService.
+/// Provides methods for streaming the records of the ledger.
+pub use crate::generated::export::export_service_client::ExportServiceClient;
+
+/// gRPC server trait and implementation for the ExportService.
+/// Implement the `ExportService` trait to handle incoming gRPC requests for exports.
+pub use crate::generated::export::export_service_server::{ExportService, ExportServiceServer};
+
+/// Export-related message types.
+/// Includes the formats, kinds of record, requests and responses used in the ExportService.
+/// These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::export::{
+    BookFormats, ExportBookRequest, ExportBookResponse, ExportEntities, ExportFormats,
+    ExportLedgerRequest, ExportLedgerResponse,
+};
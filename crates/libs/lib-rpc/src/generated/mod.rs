@@ -2,8 +2,74 @@
 
 // #![allow(unused_imports)]
 
+// Each API version is a set of proto packages ending in the version name. The
+// generated files are included once per package here, then grouped by version
+// below, so versions can be served side by side. The flat modules are the
+// current version.
+
 #[path = "personal_ledger.categories.v001.rs"]
-pub mod categories;
+pub mod categories_v001;
 
 #[path = "personal_ledger.utilities.v001.rs"]
-pub mod utilities;
\ No newline at end of file
+pub mod utilities_v001;
+
+#[path = "personal_ledger.transactions.v001.rs"]
+pub mod transactions_v001;
+
+#[path = "personal_ledger.accounts.v001.rs"]
+pub mod accounts_v001;
+
+#[path = "personal_ledger.periods.v001.rs"]
+pub mod periods_v001;
+
+#[path = "personal_ledger.budgets.v001.rs"]
+pub mod budgets_v001;
+
+#[path = "personal_ledger.goals.v001.rs"]
+pub mod goals_v001;
+
+#[path = "personal_ledger.reports.v001.rs"]
+pub mod reports_v001;
+
+#[path = "personal_ledger.payees.v001.rs"]
+pub mod payees_v001;
+
+#[path = "personal_ledger.search.v001.rs"]
+pub mod search_v001;
+
+#[path = "personal_ledger.attachments.v001.rs"]
+pub mod attachments_v001;
+
+#[path = "personal_ledger.reconciliations.v001.rs"]
+pub mod reconciliations_v001;
+
+#[path = "personal_ledger.audit.v001.rs"]
+pub mod audit_v001;
+
+#[path = "personal_ledger.export.v001.rs"]
+pub mod export_v001;
+
+/// The compiled `FileDescriptorSet` of every proto in the crate, and the
+/// protos they import. Served by gRPC reflection, and read by the
+/// compatibility module to snapshot the API.
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/personal_ledger_descriptor.bin"));
+
+/// Version 001 of the API, the `personal_ledger.*.v001` proto packages.
+pub mod v001 {
+    pub use super::accounts_v001 as accounts;
+    pub use super::attachments_v001 as attachments;
+    pub use super::audit_v001 as audit;
+    pub use super::budgets_v001 as budgets;
+    pub use super::categories_v001 as categories;
+    pub use super::export_v001 as export;
+    pub use super::goals_v001 as goals;
+    pub use super::payees_v001 as payees;
+    pub use super::periods_v001 as periods;
+    pub use super::reconciliations_v001 as reconciliations;
+    pub use super::reports_v001 as reports;
+    pub use super::search_v001 as search;
+    pub use super::transactions_v001 as transactions;
+    pub use super::utilities_v001 as utilities;
+}
+
+pub use v001::*;
@@ -0,0 +1,1851 @@
+// This file is @generated by prost-build.
+/// Represents an account, such as a bank account or credit card, that
+/// transactions are posted to.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Account {
+    /// Unique identifier (UUID) for the account.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Unique human-readable name of the account (e.g., "Everyday Savings").
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// Optional description of the account.
+    #[prost(string, optional, tag = "3")]
+    pub description: ::core::option::Option<::prost::alloc::string::String>,
+    /// Balance before any recorded transactions, in minor units (cents).
+    /// Negative for money owed.
+    #[prost(int64, tag = "4")]
+    pub opening_balance: i64,
+    /// Whether the account is active and available for use.
+    #[prost(bool, tag = "5")]
+    pub is_active: bool,
+    /// Timestamp when the account was created (UTC).
+    #[prost(message, optional, tag = "6")]
+    pub created_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Timestamp when the account was last updated (UTC).
+    #[prost(message, optional, tag = "7")]
+    pub updated_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// ISO 4217 code of the currency the account is held in (e.g., "AUD"). The
+    /// opening balance and the transactions posted to the account are in this
+    /// currency. Defaults to "AUD" when empty.
+    #[prost(string, tag = "8")]
+    pub currency: ::prost::alloc::string::String,
+    /// Optional ID of the category balancing entries are posted to when the
+    /// balance is adjusted to a counted amount. Required to adjust the balance.
+    #[prost(string, optional, tag = "9")]
+    pub adjustments_category_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Request to create a new account.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountCreateRequest {
+    /// The account to create (id and timestamps are ignored).
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<Account>,
+    /// If true, validate the account and return it as it would be created,
+    /// without saving it (AIP-163 dry run).
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response containing the created account.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountCreateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<Account>,
+}
+/// Request to fetch an account by its unique ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountGetRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Response containing the requested account.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountGetResponse {
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<Account>,
+}
+/// Request to list accounts with pagination, ordered by name.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountsListRequest {
+    /// The page token from the previous page's response, or empty for the
+    /// first page.
+    #[prost(string, tag = "3")]
+    pub page_token: ::prost::alloc::string::String,
+    /// The maximum number of accounts to return.
+    #[prost(int32, tag = "2")]
+    pub limit: i32,
+}
+/// Response containing a list of accounts and pagination info.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountsListResponse {
+    /// The list of accounts for the requested page.
+    #[prost(message, repeated, tag = "1")]
+    pub accounts: ::prost::alloc::vec::Vec<Account>,
+    /// Total number of accounts (for pagination).
+    #[prost(int32, tag = "2")]
+    pub total_count: i32,
+    /// The token to request the next page with, or empty if this is the last
+    /// page.
+    #[prost(string, tag = "5")]
+    pub next_page_token: ::prost::alloc::string::String,
+    /// The limit used in the request.
+    #[prost(int32, tag = "4")]
+    pub limit: i32,
+}
+/// Request to update an existing account.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountUpdateRequest {
+    /// The ID of the account to update.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The new account data (id and timestamps are ignored).
+    #[prost(message, optional, tag = "2")]
+    pub account: ::core::option::Option<Account>,
+    /// If true, validate the update and return the account as it would be
+    /// updated, without saving it.
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+}
+/// Response containing the updated account.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountUpdateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<Account>,
+}
+/// Request to delete an account by ID. Accounts with transactions posted to
+/// them cannot be deleted.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountDeleteRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// If true, check the account can be deleted and return the would-be
+    /// row count, without deleting it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response indicating how many rows were deleted (should be 0 or 1).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountDeleteResponse {
+    #[prost(int32, tag = "1")]
+    pub rows_deleted: i32,
+}
+/// Request for the computed balance of an account.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountGetBalanceRequest {
+    /// The ID of the account.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Optional date in ISO 8601 format (YYYY-MM-DD). Only transactions on or
+    /// before this date are included. Defaults to all transactions.
+    #[prost(string, optional, tag = "2")]
+    pub as_of: ::core::option::Option<::prost::alloc::string::String>,
+    /// If true, return the balance as it was recorded at the end of `as_of`,
+    /// ignoring transactions entered, edited or deleted since. Requires `as_of`.
+    #[prost(bool, tag = "3")]
+    pub as_recorded: bool,
+}
+/// Response containing the computed account balance.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountGetBalanceResponse {
+    /// The ID of the account.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Opening balance plus the sum of included transactions, in minor units.
+    #[prost(int64, tag = "2")]
+    pub balance: i64,
+    /// The as-of date used, if one was requested.
+    #[prost(string, optional, tag = "3")]
+    pub as_of: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Request to adjust an account's balance to a counted amount, such as the
+/// cash in a wallet, by posting a balancing entry for the difference to the
+/// account's adjustments category.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountAdjustBalanceRequest {
+    /// The ID of the account.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The counted balance, in minor units (cents).
+    #[prost(int64, tag = "2")]
+    pub counted_balance: i64,
+    /// Optional date of the count in ISO 8601 format (YYYY-MM-DD). The
+    /// balancing entry is dated on it. Defaults to today.
+    #[prost(string, optional, tag = "3")]
+    pub on: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional reason for the adjustment, recorded in the entry's description.
+    #[prost(string, optional, tag = "4")]
+    pub note: ::core::option::Option<::prost::alloc::string::String>,
+    /// If true, return the adjustment that would be made without posting it.
+    #[prost(bool, tag = "5")]
+    pub validate_only: bool,
+}
+/// Response describing the adjustment made.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountAdjustBalanceResponse {
+    /// The ID of the account.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The ID of the balancing transaction, unset if the balance already matched.
+    #[prost(string, optional, tag = "2")]
+    pub transaction_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// The amount of the balancing entry in minor units, zero if none was needed.
+    #[prost(int64, tag = "3")]
+    pub adjustment: i64,
+    /// The balance on the date of the count after the adjustment.
+    #[prost(int64, tag = "4")]
+    pub balance: i64,
+    /// The date of the count.
+    #[prost(string, tag = "5")]
+    pub on: ::prost::alloc::string::String,
+}
+/// A balance recorded by hand for an account whose transactions are not kept
+/// in the ledger, such as a superannuation fund or crypto wallet. Once an
+/// account has a snapshot, net worth reports value it from its snapshots,
+/// interpolating between them.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BalanceSnapshot {
+    /// Unique identifier (UUID) for the snapshot.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The ID of the account the balance is for.
+    #[prost(string, tag = "2")]
+    pub account_id: ::prost::alloc::string::String,
+    /// The date the balance was read, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "3")]
+    pub snapshot_date: ::prost::alloc::string::String,
+    /// The balance on the date, in minor units of the account's currency.
+    #[prost(int64, tag = "4")]
+    pub balance: i64,
+    /// Optional note on where the balance came from (e.g., "Annual statement").
+    #[prost(string, optional, tag = "5")]
+    pub note: ::core::option::Option<::prost::alloc::string::String>,
+    /// Timestamp when the snapshot was created (UTC).
+    #[prost(message, optional, tag = "6")]
+    pub created_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Timestamp when the snapshot was last updated (UTC).
+    #[prost(message, optional, tag = "7")]
+    pub updated_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Where the balance came from. Snapshots recorded through
+    /// `AccountSnapshotRecord` are always manual.
+    #[prost(enumeration = "SnapshotSources", tag = "8")]
+    pub source: i32,
+}
+/// Request to record an account's balance on a day. A snapshot already
+/// recorded for the account on that day is replaced.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountSnapshotRecordRequest {
+    /// The snapshot to record (id and timestamps are ignored).
+    #[prost(message, optional, tag = "1")]
+    pub snapshot: ::core::option::Option<BalanceSnapshot>,
+    /// If true, validate the snapshot and return it as it would be recorded,
+    /// without saving it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response containing the recorded snapshot.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountSnapshotRecordResponse {
+    #[prost(message, optional, tag = "1")]
+    pub snapshot: ::core::option::Option<BalanceSnapshot>,
+}
+/// Request to list the balance snapshots of an account, oldest first.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountSnapshotsListRequest {
+    /// The ID of the account.
+    #[prost(string, tag = "1")]
+    pub account_id: ::prost::alloc::string::String,
+}
+/// Response containing the account's balance snapshots.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountSnapshotsListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub snapshots: ::prost::alloc::vec::Vec<BalanceSnapshot>,
+}
+/// Request to delete a balance snapshot by ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountSnapshotDeleteRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// If true, check the snapshot can be deleted and return the would-be row
+    /// count, without deleting it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response indicating how many rows were deleted (should be 0 or 1).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountSnapshotDeleteResponse {
+    #[prost(int32, tag = "1")]
+    pub rows_deleted: i32,
+}
+/// A balance a bank reported for an account at the end of a day, used to
+/// check the ledger. Unlike a snapshot, an assertion never changes how the
+/// account is valued.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BalanceAssertion {
+    /// Unique identifier (UUID) for the assertion.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The ID of the account the balance is for.
+    #[prost(string, tag = "2")]
+    pub account_id: ::prost::alloc::string::String,
+    /// The date the balance was reported for, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "3")]
+    pub assertion_date: ::prost::alloc::string::String,
+    /// The balance at the end of the date, in minor units of the account's
+    /// currency.
+    #[prost(int64, tag = "4")]
+    pub balance: i64,
+    /// Optional note on where the balance came from (e.g., "March statement").
+    #[prost(string, optional, tag = "5")]
+    pub note: ::core::option::Option<::prost::alloc::string::String>,
+    /// Timestamp when the assertion was created (UTC).
+    #[prost(message, optional, tag = "6")]
+    pub created_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Timestamp when the assertion was last updated (UTC).
+    #[prost(message, optional, tag = "7")]
+    pub updated_on: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Request to assert an account's balance at the end of a day. An assertion
+/// already recorded for the account on that day is replaced.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountAssertionRecordRequest {
+    /// The assertion to record (id and timestamps are ignored).
+    #[prost(message, optional, tag = "1")]
+    pub assertion: ::core::option::Option<BalanceAssertion>,
+    /// If true, validate the assertion and return it as it would be recorded,
+    /// without saving it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response containing the recorded assertion.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountAssertionRecordResponse {
+    #[prost(message, optional, tag = "1")]
+    pub assertion: ::core::option::Option<BalanceAssertion>,
+}
+/// Request to list the balance assertions of an account, oldest first.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountAssertionsListRequest {
+    /// The ID of the account.
+    #[prost(string, tag = "1")]
+    pub account_id: ::prost::alloc::string::String,
+}
+/// Response containing the account's balance assertions.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountAssertionsListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub assertions: ::prost::alloc::vec::Vec<BalanceAssertion>,
+}
+/// Request to delete a balance assertion by ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountAssertionDeleteRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// If true, check the assertion can be deleted and return the would-be row
+    /// count, without deleting it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response indicating how many rows were deleted (should be 0 or 1).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountAssertionDeleteResponse {
+    #[prost(int32, tag = "1")]
+    pub rows_deleted: i32,
+}
+/// Request to compare balance assertions with the balances computed from the
+/// ledger.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountAssertionsCheckRequest {
+    /// The ID of the account to check. If not set, every account is checked.
+    #[prost(string, optional, tag = "1")]
+    pub account_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// If true, only assertions that do not hold are returned.
+    #[prost(bool, tag = "2")]
+    pub failing_only: bool,
+}
+/// A balance assertion compared with the ledger.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BalanceAssertionResult {
+    /// The assertion checked.
+    #[prost(message, optional, tag = "1")]
+    pub assertion: ::core::option::Option<BalanceAssertion>,
+    /// The account's opening balance plus its posted transactions up to and
+    /// including the assertion date.
+    #[prost(int64, tag = "2")]
+    pub computed_balance: i64,
+    /// The computed balance less the asserted balance. Positive when the ledger
+    /// holds more than the bank reports, as after a doubled import.
+    #[prost(int64, tag = "3")]
+    pub difference: i64,
+    /// Whether the ledger agrees with the asserted balance.
+    #[prost(bool, tag = "4")]
+    pub holds: bool,
+}
+/// Response containing the checked assertions by date, oldest first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountAssertionsCheckResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<BalanceAssertionResult>,
+}
+/// Enum representing where a balance snapshot's balance came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SnapshotSources {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// Read off a statement and recorded by hand. The account is valued from
+    /// its manual snapshots instead of its transactions.
+    Manual = 1,
+    /// Computed from the account's opening balance and posted transactions when
+    /// a period ending on the day was closed.
+    Computed = 2,
+}
+impl SnapshotSources {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SNAPSHOT_SOURCES_UNSPECIFIED",
+            Self::Manual => "SNAPSHOT_SOURCES_MANUAL",
+            Self::Computed => "SNAPSHOT_SOURCES_COMPUTED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SNAPSHOT_SOURCES_UNSPECIFIED" => Some(Self::Unspecified),
+            "SNAPSHOT_SOURCES_MANUAL" => Some(Self::Manual),
+            "SNAPSHOT_SOURCES_COMPUTED" => Some(Self::Computed),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod accounts_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// gRPC service for managing accounts.
+    /// Provides CRUD, listing, computed balance, balance adjustment, balance
+    /// snapshot and balance assertion operations.
+    #[derive(Debug, Clone)]
+    pub struct AccountsServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl AccountsServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> AccountsServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> AccountsServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            AccountsServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Create a new account.
+        pub async fn account_create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountCreateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountCreate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountCreate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get an account by its unique ID.
+        pub async fn account_get(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountGetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountGetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountGet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountGet",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List accounts with pagination, ordered by name.
+        pub async fn accounts_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountsListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountsList",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountsList",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Update an existing account.
+        pub async fn account_update(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountUpdateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountUpdate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountUpdate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Delete an account by ID.
+        pub async fn account_delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountDeleteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountDelete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountDelete",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the balance of an account, optionally as of a past date.
+        pub async fn account_get_balance(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountGetBalanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountGetBalanceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountGetBalance",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountGetBalance",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Adjust the balance of an account to a counted amount.
+        pub async fn account_adjust_balance(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountAdjustBalanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountAdjustBalanceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountAdjustBalance",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountAdjustBalance",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Record the balance of an account on a day.
+        pub async fn account_snapshot_record(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountSnapshotRecordRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountSnapshotRecordResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountSnapshotRecord",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountSnapshotRecord",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List the balance snapshots of an account, oldest first.
+        pub async fn account_snapshots_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountSnapshotsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountSnapshotsListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountSnapshotsList",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountSnapshotsList",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Delete a balance snapshot by ID.
+        pub async fn account_snapshot_delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountSnapshotDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountSnapshotDeleteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountSnapshotDelete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountSnapshotDelete",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Assert the balance of an account at the end of a day.
+        pub async fn account_assertion_record(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountAssertionRecordRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountAssertionRecordResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountAssertionRecord",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountAssertionRecord",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List the balance assertions of an account, oldest first.
+        pub async fn account_assertions_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountAssertionsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountAssertionsListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountAssertionsList",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountAssertionsList",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Delete a balance assertion by ID.
+        pub async fn account_assertion_delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountAssertionDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountAssertionDeleteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountAssertionDelete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountAssertionDelete",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Compare balance assertions with the balances computed from the ledger.
+        pub async fn account_assertions_check(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountAssertionsCheckRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountAssertionsCheckResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.accounts.v001.AccountsService/AccountAssertionsCheck",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.accounts.v001.AccountsService",
+                        "AccountAssertionsCheck",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod accounts_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with AccountsServiceServer.
+    #[async_trait]
+    pub trait AccountsService: std::marker::Send + std::marker::Sync + 'static {
+        /// Create a new account.
+        async fn account_create(
+            &self,
+            request: tonic::Request<super::AccountCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountCreateResponse>,
+            tonic::Status,
+        >;
+        /// Get an account by its unique ID.
+        async fn account_get(
+            &self,
+            request: tonic::Request<super::AccountGetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountGetResponse>,
+            tonic::Status,
+        >;
+        /// List accounts with pagination, ordered by name.
+        async fn accounts_list(
+            &self,
+            request: tonic::Request<super::AccountsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountsListResponse>,
+            tonic::Status,
+        >;
+        /// Update an existing account.
+        async fn account_update(
+            &self,
+            request: tonic::Request<super::AccountUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountUpdateResponse>,
+            tonic::Status,
+        >;
+        /// Delete an account by ID.
+        async fn account_delete(
+            &self,
+            request: tonic::Request<super::AccountDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountDeleteResponse>,
+            tonic::Status,
+        >;
+        /// Get the balance of an account, optionally as of a past date.
+        async fn account_get_balance(
+            &self,
+            request: tonic::Request<super::AccountGetBalanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountGetBalanceResponse>,
+            tonic::Status,
+        >;
+        /// Adjust the balance of an account to a counted amount.
+        async fn account_adjust_balance(
+            &self,
+            request: tonic::Request<super::AccountAdjustBalanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountAdjustBalanceResponse>,
+            tonic::Status,
+        >;
+        /// Record the balance of an account on a day.
+        async fn account_snapshot_record(
+            &self,
+            request: tonic::Request<super::AccountSnapshotRecordRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountSnapshotRecordResponse>,
+            tonic::Status,
+        >;
+        /// List the balance snapshots of an account, oldest first.
+        async fn account_snapshots_list(
+            &self,
+            request: tonic::Request<super::AccountSnapshotsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountSnapshotsListResponse>,
+            tonic::Status,
+        >;
+        /// Delete a balance snapshot by ID.
+        async fn account_snapshot_delete(
+            &self,
+            request: tonic::Request<super::AccountSnapshotDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountSnapshotDeleteResponse>,
+            tonic::Status,
+        >;
+        /// Assert the balance of an account at the end of a day.
+        async fn account_assertion_record(
+            &self,
+            request: tonic::Request<super::AccountAssertionRecordRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountAssertionRecordResponse>,
+            tonic::Status,
+        >;
+        /// List the balance assertions of an account, oldest first.
+        async fn account_assertions_list(
+            &self,
+            request: tonic::Request<super::AccountAssertionsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountAssertionsListResponse>,
+            tonic::Status,
+        >;
+        /// Delete a balance assertion by ID.
+        async fn account_assertion_delete(
+            &self,
+            request: tonic::Request<super::AccountAssertionDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountAssertionDeleteResponse>,
+            tonic::Status,
+        >;
+        /// Compare balance assertions with the balances computed from the ledger.
+        async fn account_assertions_check(
+            &self,
+            request: tonic::Request<super::AccountAssertionsCheckRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AccountAssertionsCheckResponse>,
+            tonic::Status,
+        >;
+    }
+    /// gRPC service for managing accounts.
+    /// Provides CRUD, listing, computed balance, balance adjustment, balance
+    /// snapshot and balance assertion operations.
+    #[derive(Debug)]
+    pub struct AccountsServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> AccountsServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for AccountsServiceServer<T>
+    where
+        T: AccountsService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/personal_ledger.accounts.v001.AccountsService/AccountCreate" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountCreateSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountCreateRequest>
+                    for AccountCreateSvc<T> {
+                        type Response = super::AccountCreateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountCreateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_create(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountCreateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountGet" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountGetSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountGetRequest>
+                    for AccountGetSvc<T> {
+                        type Response = super::AccountGetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountGetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_get(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountGetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountsList" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountsListSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountsListRequest>
+                    for AccountsListSvc<T> {
+                        type Response = super::AccountsListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountsListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::accounts_list(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountsListSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountUpdate" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountUpdateSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountUpdateRequest>
+                    for AccountUpdateSvc<T> {
+                        type Response = super::AccountUpdateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountUpdateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_update(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountUpdateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountDelete" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountDeleteSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountDeleteRequest>
+                    for AccountDeleteSvc<T> {
+                        type Response = super::AccountDeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountDeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_delete(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountDeleteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountGetBalance" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountGetBalanceSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountGetBalanceRequest>
+                    for AccountGetBalanceSvc<T> {
+                        type Response = super::AccountGetBalanceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountGetBalanceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_get_balance(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountGetBalanceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountAdjustBalance" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountAdjustBalanceSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountAdjustBalanceRequest>
+                    for AccountAdjustBalanceSvc<T> {
+                        type Response = super::AccountAdjustBalanceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountAdjustBalanceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_adjust_balance(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountAdjustBalanceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountSnapshotRecord" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountSnapshotRecordSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountSnapshotRecordRequest>
+                    for AccountSnapshotRecordSvc<T> {
+                        type Response = super::AccountSnapshotRecordResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountSnapshotRecordRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_snapshot_record(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountSnapshotRecordSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountSnapshotsList" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountSnapshotsListSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountSnapshotsListRequest>
+                    for AccountSnapshotsListSvc<T> {
+                        type Response = super::AccountSnapshotsListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountSnapshotsListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_snapshots_list(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountSnapshotsListSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountSnapshotDelete" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountSnapshotDeleteSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountSnapshotDeleteRequest>
+                    for AccountSnapshotDeleteSvc<T> {
+                        type Response = super::AccountSnapshotDeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountSnapshotDeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_snapshot_delete(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountSnapshotDeleteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountAssertionRecord" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountAssertionRecordSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountAssertionRecordRequest>
+                    for AccountAssertionRecordSvc<T> {
+                        type Response = super::AccountAssertionRecordResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountAssertionRecordRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_assertion_record(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountAssertionRecordSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountAssertionsList" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountAssertionsListSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountAssertionsListRequest>
+                    for AccountAssertionsListSvc<T> {
+                        type Response = super::AccountAssertionsListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountAssertionsListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_assertions_list(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountAssertionsListSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountAssertionDelete" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountAssertionDeleteSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountAssertionDeleteRequest>
+                    for AccountAssertionDeleteSvc<T> {
+                        type Response = super::AccountAssertionDeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountAssertionDeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_assertion_delete(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountAssertionDeleteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.accounts.v001.AccountsService/AccountAssertionsCheck" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountAssertionsCheckSvc<T: AccountsService>(pub Arc<T>);
+                    impl<
+                        T: AccountsService,
+                    > tonic::server::UnaryService<super::AccountAssertionsCheckRequest>
+                    for AccountAssertionsCheckSvc<T> {
+                        type Response = super::AccountAssertionsCheckResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AccountAssertionsCheckRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccountsService>::account_assertions_check(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountAssertionsCheckSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for AccountsServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "personal_ledger.accounts.v001.AccountsService";
+    impl<T> tonic::server::NamedService for AccountsServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -0,0 +1,701 @@
+// This file is @generated by prost-build.
+/// A file attached to a transaction.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Attachment {
+    /// Unique identifier (UUID) for the attachment.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The ID (UUID) of the transaction the file is attached to.
+    #[prost(string, tag = "2")]
+    pub transaction_id: ::prost::alloc::string::String,
+    /// The file's name, e.g. "invoice.pdf".
+    #[prost(string, tag = "3")]
+    pub file_name: ::prost::alloc::string::String,
+    /// The file's MIME type, e.g. "application/pdf".
+    #[prost(string, tag = "4")]
+    pub content_type: ::prost::alloc::string::String,
+    /// The file's size in bytes.
+    #[prost(int64, tag = "5")]
+    pub size_bytes: i64,
+    /// Lowercase hex SHA-256 hash of the file, to check a download against.
+    #[prost(string, tag = "6")]
+    pub sha256: ::prost::alloc::string::String,
+    /// Timestamp when the file was attached (UTC).
+    #[prost(message, optional, tag = "7")]
+    pub created_on: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// What a file being uploaded is, sent before its content.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AttachmentUploadMetadata {
+    /// The ID (UUID) of the transaction to attach the file to.
+    #[prost(string, tag = "1")]
+    pub transaction_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub file_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub content_type: ::prost::alloc::string::String,
+    /// If true, check the attachment can be stored and return it as it would
+    /// be stored, without storing it.
+    #[prost(bool, tag = "4")]
+    pub validate_only: bool,
+}
+/// One message of an upload. The first message is the metadata, and every
+/// message after it a chunk of the content, in order.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AttachmentUploadRequest {
+    #[prost(oneof = "attachment_upload_request::Part", tags = "1, 2")]
+    pub part: ::core::option::Option<attachment_upload_request::Part>,
+}
+/// Nested message and enum types in `AttachmentUploadRequest`.
+pub mod attachment_upload_request {
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Part {
+        #[prost(message, tag = "1")]
+        Metadata(super::AttachmentUploadMetadata),
+        #[prost(bytes, tag = "2")]
+        Chunk(::prost::alloc::vec::Vec<u8>),
+    }
+}
+/// Response containing the stored attachment.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AttachmentUploadResponse {
+    #[prost(message, optional, tag = "1")]
+    pub attachment: ::core::option::Option<Attachment>,
+}
+/// Request to download an attachment by its unique ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AttachmentDownloadRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// One message of a download. The first message is the attachment, and every
+/// message after it a chunk of the content, in order.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AttachmentDownloadResponse {
+    #[prost(oneof = "attachment_download_response::Part", tags = "1, 2")]
+    pub part: ::core::option::Option<attachment_download_response::Part>,
+}
+/// Nested message and enum types in `AttachmentDownloadResponse`.
+pub mod attachment_download_response {
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Part {
+        #[prost(message, tag = "1")]
+        Attachment(super::Attachment),
+        #[prost(bytes, tag = "2")]
+        Chunk(::prost::alloc::vec::Vec<u8>),
+    }
+}
+/// Request to list the attachments of a transaction.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AttachmentsListRequest {
+    #[prost(string, tag = "1")]
+    pub transaction_id: ::prost::alloc::string::String,
+}
+/// Response containing the transaction's attachments, oldest first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachmentsListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub attachments: ::prost::alloc::vec::Vec<Attachment>,
+}
+/// Request to delete an attachment by ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AttachmentDeleteRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// If true, check the attachment can be deleted and return the would-be
+    /// row count, without deleting it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response indicating how many rows were deleted (should be 0 or 1).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AttachmentDeleteResponse {
+    #[prost(int32, tag = "1")]
+    pub rows_deleted: i32,
+}
+/// Generated client implementations.
+pub mod attachments_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// gRPC service for receipts and invoices attached to transactions.
+    #[derive(Debug, Clone)]
+    pub struct AttachmentsServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl AttachmentsServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> AttachmentsServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> AttachmentsServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            AttachmentsServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Attach a file to a transaction, streaming its content.
+        pub async fn attachment_upload(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::AttachmentUploadRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::AttachmentUploadResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.attachments.v001.AttachmentsService/AttachmentUpload",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.attachments.v001.AttachmentsService",
+                        "AttachmentUpload",
+                    ),
+                );
+            self.inner.client_streaming(req, path, codec).await
+        }
+        /// Download an attachment, streaming its content.
+        pub async fn attachment_download(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AttachmentDownloadRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::AttachmentDownloadResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.attachments.v001.AttachmentsService/AttachmentDownload",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.attachments.v001.AttachmentsService",
+                        "AttachmentDownload",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// List the attachments of a transaction.
+        pub async fn attachments_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AttachmentsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AttachmentsListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.attachments.v001.AttachmentsService/AttachmentsList",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.attachments.v001.AttachmentsService",
+                        "AttachmentsList",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Delete an attachment by ID.
+        pub async fn attachment_delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AttachmentDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AttachmentDeleteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.attachments.v001.AttachmentsService/AttachmentDelete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.attachments.v001.AttachmentsService",
+                        "AttachmentDelete",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod attachments_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with AttachmentsServiceServer.
+    #[async_trait]
+    pub trait AttachmentsService: std::marker::Send + std::marker::Sync + 'static {
+        /// Attach a file to a transaction, streaming its content.
+        async fn attachment_upload(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::AttachmentUploadRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<super::AttachmentUploadResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the AttachmentDownload method.
+        type AttachmentDownloadStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::AttachmentDownloadResponse,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Download an attachment, streaming its content.
+        async fn attachment_download(
+            &self,
+            request: tonic::Request<super::AttachmentDownloadRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::AttachmentDownloadStream>,
+            tonic::Status,
+        >;
+        /// List the attachments of a transaction.
+        async fn attachments_list(
+            &self,
+            request: tonic::Request<super::AttachmentsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AttachmentsListResponse>,
+            tonic::Status,
+        >;
+        /// Delete an attachment by ID.
+        async fn attachment_delete(
+            &self,
+            request: tonic::Request<super::AttachmentDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AttachmentDeleteResponse>,
+            tonic::Status,
+        >;
+    }
+    /// gRPC service for receipts and invoices attached to transactions.
+    #[derive(Debug)]
+    pub struct AttachmentsServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> AttachmentsServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for AttachmentsServiceServer<T>
+    where
+        T: AttachmentsService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/personal_ledger.attachments.v001.AttachmentsService/AttachmentUpload" => {
+                    #[allow(non_camel_case_types)]
+                    struct AttachmentUploadSvc<T: AttachmentsService>(pub Arc<T>);
+                    impl<
+                        T: AttachmentsService,
+                    > tonic::server::ClientStreamingService<
+                        super::AttachmentUploadRequest,
+                    > for AttachmentUploadSvc<T> {
+                        type Response = super::AttachmentUploadResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::AttachmentUploadRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AttachmentsService>::attachment_upload(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AttachmentUploadSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.attachments.v001.AttachmentsService/AttachmentDownload" => {
+                    #[allow(non_camel_case_types)]
+                    struct AttachmentDownloadSvc<T: AttachmentsService>(pub Arc<T>);
+                    impl<
+                        T: AttachmentsService,
+                    > tonic::server::ServerStreamingService<
+                        super::AttachmentDownloadRequest,
+                    > for AttachmentDownloadSvc<T> {
+                        type Response = super::AttachmentDownloadResponse;
+                        type ResponseStream = T::AttachmentDownloadStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AttachmentDownloadRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AttachmentsService>::attachment_download(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AttachmentDownloadSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.attachments.v001.AttachmentsService/AttachmentsList" => {
+                    #[allow(non_camel_case_types)]
+                    struct AttachmentsListSvc<T: AttachmentsService>(pub Arc<T>);
+                    impl<
+                        T: AttachmentsService,
+                    > tonic::server::UnaryService<super::AttachmentsListRequest>
+                    for AttachmentsListSvc<T> {
+                        type Response = super::AttachmentsListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AttachmentsListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AttachmentsService>::attachments_list(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AttachmentsListSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.attachments.v001.AttachmentsService/AttachmentDelete" => {
+                    #[allow(non_camel_case_types)]
+                    struct AttachmentDeleteSvc<T: AttachmentsService>(pub Arc<T>);
+                    impl<
+                        T: AttachmentsService,
+                    > tonic::server::UnaryService<super::AttachmentDeleteRequest>
+                    for AttachmentDeleteSvc<T> {
+                        type Response = super::AttachmentDeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AttachmentDeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AttachmentsService>::attachment_delete(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AttachmentDeleteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for AttachmentsServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "personal_ledger.attachments.v001.AttachmentsService";
+    impl<T> tonic::server::NamedService for AttachmentsServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -0,0 +1,1061 @@
+// This file is @generated by prost-build.
+/// A spending budget for a category. Spending in the category's
+/// subcategories counts towards it. A category has at most one budget.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Budget {
+    /// Unique identifier (UUID) for the budget.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The ID of the category the budget is for.
+    #[prost(string, tag = "2")]
+    pub category_id: ::prost::alloc::string::String,
+    /// Whether the amount is per month or per financial year.
+    #[prost(enumeration = "BudgetPeriods", tag = "3")]
+    pub period: i32,
+    /// The amount that may be spent each period, in minor units (cents).
+    /// Must be positive.
+    #[prost(int64, tag = "4")]
+    pub amount: i64,
+    /// Timestamp when the budget was created (UTC).
+    #[prost(message, optional, tag = "5")]
+    pub created_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Timestamp when the budget was last updated (UTC).
+    #[prost(message, optional, tag = "6")]
+    pub updated_on: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Budgeted and actual spending for one budget over a period.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetComparison {
+    #[prost(message, optional, tag = "1")]
+    pub budget: ::core::option::Option<Budget>,
+    /// First day of the period compared, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "2")]
+    pub start: ::prost::alloc::string::String,
+    /// Last day of the period compared, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "3")]
+    pub end: ::prost::alloc::string::String,
+    /// The budget scaled to the period, in minor units. A monthly budget is
+    /// multiplied by twelve for a year, and an annual budget divided by twelve
+    /// for a month.
+    #[prost(int64, tag = "4")]
+    pub budgeted: i64,
+    /// Posted spending in the category and its subcategories, in minor units.
+    /// Positive for money spent; refunds reduce it.
+    #[prost(int64, tag = "5")]
+    pub actual: i64,
+    /// Budgeted less actual. Negative when over budget.
+    #[prost(int64, tag = "6")]
+    pub remaining: i64,
+}
+/// Budgeted and actual spending for a category and its subcategories over a
+/// period, a node of the tree returned by BudgetsVariance.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BudgetVariance {
+    #[prost(string, tag = "1")]
+    pub category_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub category_name: ::prost::alloc::string::String,
+    /// The category's own budget. Unset if it has none.
+    #[prost(message, optional, tag = "3")]
+    pub budget: ::core::option::Option<Budget>,
+    /// The category's budget scaled to the period or, when it has none, the
+    /// sum of its subcategories' budgeted amounts, in minor units.
+    #[prost(int64, tag = "4")]
+    pub budgeted: i64,
+    /// Posted spending in the category and every subcategory, in minor units.
+    /// Positive for money spent; refunds reduce it.
+    #[prost(int64, tag = "5")]
+    pub actual: i64,
+    /// Budgeted less actual. Negative when over budget.
+    #[prost(int64, tag = "6")]
+    pub remaining: i64,
+    /// Whether the category or one of its subcategories has a budget, and more
+    /// was spent than budgeted.
+    #[prost(bool, tag = "7")]
+    pub is_over_budget: bool,
+    /// Subcategories with a budget or spending, ordered by name.
+    #[prost(message, repeated, tag = "8")]
+    pub children: ::prost::alloc::vec::Vec<BudgetVariance>,
+}
+/// Request to create a new budget.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetCreateRequest {
+    /// The budget to create (id and timestamps are ignored).
+    #[prost(message, optional, tag = "1")]
+    pub budget: ::core::option::Option<Budget>,
+    /// If true, validate the budget and return it as it would be created,
+    /// without saving it (AIP-163 dry run).
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response containing the created budget.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetCreateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub budget: ::core::option::Option<Budget>,
+}
+/// Request to fetch a budget by its unique ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetGetRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Response containing the requested budget.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetGetResponse {
+    #[prost(message, optional, tag = "1")]
+    pub budget: ::core::option::Option<Budget>,
+}
+/// Request to list budgets with pagination, ordered by category name.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetsListRequest {
+    /// The page token from the previous page's response, or empty for the
+    /// first page.
+    #[prost(string, tag = "3")]
+    pub page_token: ::prost::alloc::string::String,
+    /// The maximum number of budgets to return.
+    #[prost(int32, tag = "2")]
+    pub limit: i32,
+}
+/// Response containing a list of budgets and pagination info.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BudgetsListResponse {
+    /// The list of budgets for the requested page.
+    #[prost(message, repeated, tag = "1")]
+    pub budgets: ::prost::alloc::vec::Vec<Budget>,
+    /// Total number of budgets (for pagination).
+    #[prost(int32, tag = "2")]
+    pub total_count: i32,
+    /// The token to request the next page with, or empty if this is the last
+    /// page.
+    #[prost(string, tag = "5")]
+    pub next_page_token: ::prost::alloc::string::String,
+    /// The limit used in the request.
+    #[prost(int32, tag = "4")]
+    pub limit: i32,
+}
+/// Request to update an existing budget.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetUpdateRequest {
+    /// The ID of the budget to update.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The new budget data (id and timestamps are ignored).
+    #[prost(message, optional, tag = "2")]
+    pub budget: ::core::option::Option<Budget>,
+    /// If true, validate the update and return the budget as it would be
+    /// updated, without saving it.
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+}
+/// Response containing the updated budget.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetUpdateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub budget: ::core::option::Option<Budget>,
+}
+/// Request to delete a budget by ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetDeleteRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// If true, check the budget can be deleted and return the would-be
+    /// row count, without deleting it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response indicating how many rows were deleted (should be 0 or 1).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetDeleteResponse {
+    #[prost(int32, tag = "1")]
+    pub rows_deleted: i32,
+}
+/// Request to compare budgets with actual spending.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetsCompareRequest {
+    /// Whether to compare over a calendar month or a financial year.
+    #[prost(enumeration = "BudgetPeriods", tag = "1")]
+    pub period: i32,
+    /// A date in the period to compare, in ISO 8601 format (YYYY-MM-DD).
+    /// Defaults to today.
+    #[prost(string, optional, tag = "2")]
+    pub on: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response containing a comparison for every budget, ordered by category
+/// name.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BudgetsCompareResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub comparisons: ::prost::alloc::vec::Vec<BudgetComparison>,
+}
+/// Request to compare budgets with actual spending as a tree of categories.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BudgetsVarianceRequest {
+    /// Whether to compare over a calendar month or a financial year.
+    #[prost(enumeration = "BudgetPeriods", tag = "1")]
+    pub period: i32,
+    /// A date in the period to compare, in ISO 8601 format (YYYY-MM-DD).
+    /// Defaults to today.
+    #[prost(string, optional, tag = "2")]
+    pub on: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response containing the top level categories with a budget or spending,
+/// ordered by name, each with its subcategories.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BudgetsVarianceResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub categories: ::prost::alloc::vec::Vec<BudgetVariance>,
+    /// First day of the period compared, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "2")]
+    pub start: ::prost::alloc::string::String,
+    /// Last day of the period compared, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "3")]
+    pub end: ::prost::alloc::string::String,
+}
+/// Enum representing the period a budget amount is set for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BudgetPeriods {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// A calendar month.
+    Monthly = 1,
+    /// An Australian financial year, 1 July to 30 June.
+    Annual = 2,
+}
+impl BudgetPeriods {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "BUDGET_PERIODS_UNSPECIFIED",
+            Self::Monthly => "BUDGET_PERIODS_MONTHLY",
+            Self::Annual => "BUDGET_PERIODS_ANNUAL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BUDGET_PERIODS_UNSPECIFIED" => Some(Self::Unspecified),
+            "BUDGET_PERIODS_MONTHLY" => Some(Self::Monthly),
+            "BUDGET_PERIODS_ANNUAL" => Some(Self::Annual),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod budgets_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// gRPC service for managing budgets.
+    /// Provides CRUD, listing, and budgeted versus actual spending.
+    #[derive(Debug, Clone)]
+    pub struct BudgetsServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl BudgetsServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> BudgetsServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> BudgetsServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            BudgetsServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Create a new budget.
+        pub async fn budget_create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BudgetCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetCreateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetCreate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.budgets.v001.BudgetsService",
+                        "BudgetCreate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get a budget by its unique ID.
+        pub async fn budget_get(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BudgetGetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetGetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetGet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.budgets.v001.BudgetsService",
+                        "BudgetGet",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List budgets with pagination, ordered by category name.
+        pub async fn budgets_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BudgetsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetsListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetsList",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.budgets.v001.BudgetsService",
+                        "BudgetsList",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Update an existing budget.
+        pub async fn budget_update(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BudgetUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetUpdateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetUpdate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.budgets.v001.BudgetsService",
+                        "BudgetUpdate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Delete a budget by ID.
+        pub async fn budget_delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BudgetDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetDeleteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetDelete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.budgets.v001.BudgetsService",
+                        "BudgetDelete",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Compare every budget with the spending in a month or financial year.
+        pub async fn budgets_compare(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BudgetsCompareRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetsCompareResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetsCompare",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.budgets.v001.BudgetsService",
+                        "BudgetsCompare",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Compare budgets with the spending in a month or financial year as a
+        /// tree of categories, with subcategories rolled up into their parents and
+        /// the categories over budget flagged.
+        pub async fn budgets_variance(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BudgetsVarianceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetsVarianceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetsVariance",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.budgets.v001.BudgetsService",
+                        "BudgetsVariance",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod budgets_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with BudgetsServiceServer.
+    #[async_trait]
+    pub trait BudgetsService: std::marker::Send + std::marker::Sync + 'static {
+        /// Create a new budget.
+        async fn budget_create(
+            &self,
+            request: tonic::Request<super::BudgetCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetCreateResponse>,
+            tonic::Status,
+        >;
+        /// Get a budget by its unique ID.
+        async fn budget_get(
+            &self,
+            request: tonic::Request<super::BudgetGetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetGetResponse>,
+            tonic::Status,
+        >;
+        /// List budgets with pagination, ordered by category name.
+        async fn budgets_list(
+            &self,
+            request: tonic::Request<super::BudgetsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetsListResponse>,
+            tonic::Status,
+        >;
+        /// Update an existing budget.
+        async fn budget_update(
+            &self,
+            request: tonic::Request<super::BudgetUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetUpdateResponse>,
+            tonic::Status,
+        >;
+        /// Delete a budget by ID.
+        async fn budget_delete(
+            &self,
+            request: tonic::Request<super::BudgetDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetDeleteResponse>,
+            tonic::Status,
+        >;
+        /// Compare every budget with the spending in a month or financial year.
+        async fn budgets_compare(
+            &self,
+            request: tonic::Request<super::BudgetsCompareRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetsCompareResponse>,
+            tonic::Status,
+        >;
+        /// Compare budgets with the spending in a month or financial year as a
+        /// tree of categories, with subcategories rolled up into their parents and
+        /// the categories over budget flagged.
+        async fn budgets_variance(
+            &self,
+            request: tonic::Request<super::BudgetsVarianceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BudgetsVarianceResponse>,
+            tonic::Status,
+        >;
+    }
+    /// gRPC service for managing budgets.
+    /// Provides CRUD, listing, and budgeted versus actual spending.
+    #[derive(Debug)]
+    pub struct BudgetsServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> BudgetsServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for BudgetsServiceServer<T>
+    where
+        T: BudgetsService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetCreate" => {
+                    #[allow(non_camel_case_types)]
+                    struct BudgetCreateSvc<T: BudgetsService>(pub Arc<T>);
+                    impl<
+                        T: BudgetsService,
+                    > tonic::server::UnaryService<super::BudgetCreateRequest>
+                    for BudgetCreateSvc<T> {
+                        type Response = super::BudgetCreateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BudgetCreateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BudgetsService>::budget_create(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BudgetCreateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetGet" => {
+                    #[allow(non_camel_case_types)]
+                    struct BudgetGetSvc<T: BudgetsService>(pub Arc<T>);
+                    impl<
+                        T: BudgetsService,
+                    > tonic::server::UnaryService<super::BudgetGetRequest>
+                    for BudgetGetSvc<T> {
+                        type Response = super::BudgetGetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BudgetGetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BudgetsService>::budget_get(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BudgetGetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetsList" => {
+                    #[allow(non_camel_case_types)]
+                    struct BudgetsListSvc<T: BudgetsService>(pub Arc<T>);
+                    impl<
+                        T: BudgetsService,
+                    > tonic::server::UnaryService<super::BudgetsListRequest>
+                    for BudgetsListSvc<T> {
+                        type Response = super::BudgetsListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BudgetsListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BudgetsService>::budgets_list(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BudgetsListSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetUpdate" => {
+                    #[allow(non_camel_case_types)]
+                    struct BudgetUpdateSvc<T: BudgetsService>(pub Arc<T>);
+                    impl<
+                        T: BudgetsService,
+                    > tonic::server::UnaryService<super::BudgetUpdateRequest>
+                    for BudgetUpdateSvc<T> {
+                        type Response = super::BudgetUpdateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BudgetUpdateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BudgetsService>::budget_update(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BudgetUpdateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetDelete" => {
+                    #[allow(non_camel_case_types)]
+                    struct BudgetDeleteSvc<T: BudgetsService>(pub Arc<T>);
+                    impl<
+                        T: BudgetsService,
+                    > tonic::server::UnaryService<super::BudgetDeleteRequest>
+                    for BudgetDeleteSvc<T> {
+                        type Response = super::BudgetDeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BudgetDeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BudgetsService>::budget_delete(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BudgetDeleteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetsCompare" => {
+                    #[allow(non_camel_case_types)]
+                    struct BudgetsCompareSvc<T: BudgetsService>(pub Arc<T>);
+                    impl<
+                        T: BudgetsService,
+                    > tonic::server::UnaryService<super::BudgetsCompareRequest>
+                    for BudgetsCompareSvc<T> {
+                        type Response = super::BudgetsCompareResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BudgetsCompareRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BudgetsService>::budgets_compare(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BudgetsCompareSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.budgets.v001.BudgetsService/BudgetsVariance" => {
+                    #[allow(non_camel_case_types)]
+                    struct BudgetsVarianceSvc<T: BudgetsService>(pub Arc<T>);
+                    impl<
+                        T: BudgetsService,
+                    > tonic::server::UnaryService<super::BudgetsVarianceRequest>
+                    for BudgetsVarianceSvc<T> {
+                        type Response = super::BudgetsVarianceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BudgetsVarianceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BudgetsService>::budgets_variance(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BudgetsVarianceSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for BudgetsServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "personal_ledger.budgets.v001.BudgetsService";
+    impl<T> tonic::server::NamedService for BudgetsServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -12,7 +12,8 @@ pub struct Category {
     /// Human-readable name of the category.
     #[prost(string, tag = "3")]
     pub name: ::prost::alloc::string::String,
-    /// Optional description of the category's purpose.
+    /// Optional description of the category's purpose, as markdown of at most
+    /// 2,000 characters without raw HTML or images.
     #[prost(string, optional, tag = "4")]
     pub description: ::core::option::Option<::prost::alloc::string::String>,
     /// Optional URL-friendly slug for the category (e.g., "groceries").
@@ -36,6 +37,18 @@ pub struct Category {
     /// Timestamp when the category was last updated (UTC).
     #[prost(message, optional, tag = "11")]
     pub updated_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Optional ID (UUID) of the parent category, unset for a root category.
+    #[prost(string, optional, tag = "12")]
+    pub parent_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Timestamp when the category was soft-deleted (UTC), unset if it is not
+    /// deleted. Output only.
+    #[prost(message, optional, tag = "13")]
+    pub deleted_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Number of times the category has been written. Send back the version
+    /// read with an update, which fails with ABORTED if the category was
+    /// changed since. Zero updates whatever the stored version.
+    #[prost(int64, tag = "14")]
+    pub version: i64,
 }
 /// Request to create a new category.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -43,6 +56,10 @@ pub struct CategoryCreateRequest {
     /// The category to create (id is ignored).
     #[prost(message, optional, tag = "1")]
     pub category: ::core::option::Option<Category>,
+    /// If true, validate the category and return it as it would be created,
+    /// without saving it (AIP-163 dry run).
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
 }
 /// Response containing the created category.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -86,12 +103,14 @@ pub struct CategoryGetBySlugResponse {
     #[prost(message, optional, tag = "1")]
     pub category: ::core::option::Option<Category>,
 }
-/// Request to list categories with optional pagination, filtering, and sorting.
+/// Request to list categories with optional pagination and filtering, newest
+/// first.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CategoriesListRequest {
-    /// The number of categories to skip (for pagination).
-    #[prost(int32, tag = "1")]
-    pub offset: i32,
+    /// The page token from the previous page's response, or empty for the
+    /// first page.
+    #[prost(string, tag = "8")]
+    pub page_token: ::prost::alloc::string::String,
     /// The maximum number of categories to return.
     #[prost(int32, tag = "2")]
     pub limit: i32,
@@ -101,12 +120,31 @@ pub struct CategoriesListRequest {
     /// Optional filter by active status.
     #[prost(bool, optional, tag = "4")]
     pub is_active: ::core::option::Option<bool>,
-    /// Optional field to sort by (e.g., "created_on").
+    /// Optional column to sort by: created_on (the default), updated_on, name
+    /// or code.
     #[prost(string, optional, tag = "5")]
     pub sort_by: ::core::option::Option<::prost::alloc::string::String>,
-    /// Whether to sort in descending order.
+    /// Whether to sort in descending order. Defaults to descending for
+    /// created_on and updated_on, and ascending for name and code.
     #[prost(bool, optional, tag = "6")]
     pub sort_desc: ::core::option::Option<bool>,
+    /// Optional date in ISO 8601 format (YYYY-MM-DD). If set, categories are
+    /// returned as they were recorded at the end of this date, ignoring later
+    /// edits and including categories deleted since.
+    #[prost(string, optional, tag = "7")]
+    pub as_of: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional first day of creation to include, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, optional, tag = "9")]
+    pub created_from: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional last day of creation to include, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, optional, tag = "10")]
+    pub created_to: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional text the category name must contain, ignoring case.
+    #[prost(string, optional, tag = "11")]
+    pub name_contains: ::core::option::Option<::prost::alloc::string::String>,
+    /// If true, soft-deleted categories are listed too.
+    #[prost(bool, tag = "12")]
+    pub include_deleted: bool,
 }
 /// Response containing a list of categories and pagination info.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -117,13 +155,51 @@ pub struct CategoriesListResponse {
     /// Total number of categories matching the filter (for pagination).
     #[prost(int32, tag = "2")]
     pub total_count: i32,
-    /// The offset used in the request.
-    #[prost(int32, tag = "3")]
-    pub offset: i32,
+    /// The token to request the next page with, or empty if this is the last
+    /// page.
+    #[prost(string, tag = "5")]
+    pub next_page_token: ::prost::alloc::string::String,
     /// The limit used in the request.
     #[prost(int32, tag = "4")]
     pub limit: i32,
 }
+/// Request to stream every category in pages, newest first.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CategoriesStreamRequest {
+    /// The maximum number of categories in each page. Defaults to 100 when zero
+    /// and is capped at 1000.
+    #[prost(int32, tag = "1")]
+    pub page_size: i32,
+}
+/// A single page of a category stream.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CategoriesStreamResponse {
+    /// The categories in this page.
+    #[prost(message, repeated, tag = "1")]
+    pub categories: ::prost::alloc::vec::Vec<Category>,
+}
+/// Request to watch for changes to categories as they are made.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CategoriesWatchRequest {}
+/// A change to a category, sent once it is committed.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CategoriesWatchResponse {
+    /// Unique ID of the change, so a client can tell repeats apart.
+    #[prost(string, tag = "1")]
+    pub event_id: ::prost::alloc::string::String,
+    /// The kind of change.
+    #[prost(enumeration = "CategoryChangeKinds", tag = "2")]
+    pub kind: i32,
+    /// The ID of the category that changed.
+    #[prost(string, tag = "3")]
+    pub category_id: ::prost::alloc::string::String,
+    /// The category as it was written, unset when it was deleted.
+    #[prost(message, optional, tag = "4")]
+    pub category: ::core::option::Option<Category>,
+    /// When the change was made.
+    #[prost(message, optional, tag = "5")]
+    pub occurred_on: ::core::option::Option<::prost_types::Timestamp>,
+}
 /// Request to update an existing category.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CategoryUpdateRequest {
@@ -133,9 +209,16 @@ pub struct CategoryUpdateRequest {
     /// The new category data (id is ignored).
     #[prost(message, optional, tag = "2")]
     pub category: ::core::option::Option<Category>,
-    /// Optional field mask to specify which fields to update.
+    /// Optional field mask naming the fields to update, such as "name" or
+    /// "parent_id", leaving the others as they are stored. Every field is
+    /// updated when the mask is unset or empty. Only client editable fields
+    /// may be named, others fail with INVALID_ARGUMENT.
     #[prost(message, optional, tag = "3")]
     pub update_mask: ::core::option::Option<::prost_types::FieldMask>,
+    /// If true, validate the update and return the category as it would be
+    /// updated, without saving it.
+    #[prost(bool, tag = "4")]
+    pub validate_only: bool,
 }
 /// Response containing the updated category.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -149,6 +232,10 @@ pub struct CategoriesCreateBatchRequest {
     /// The categories to create (ids are ignored).
     #[prost(message, repeated, tag = "1")]
     pub categories: ::prost::alloc::vec::Vec<Category>,
+    /// If true, validate every category and return them as they would be
+    /// created, without saving any of them.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
 }
 /// Response containing the created categories and count.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -165,18 +252,40 @@ pub struct CategoriesCreateBatchResponse {
 pub struct CategoryDeleteRequest {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// If true, check the category can be deleted and return the would-be
+    /// row count, without deleting it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+    /// If true, soft-delete the category so it can be restored with
+    /// CategoryRestore. Otherwise the category is deleted for good, which also
+    /// purges a category that was soft-deleted before.
+    #[prost(bool, tag = "3")]
+    pub soft: bool,
+    /// The ID of a category to move the category's transactions, splits,
+    /// recurring transactions, account adjustments and child categories to
+    /// before it is deleted for good. Without it, deleting a category that is
+    /// still in use fails with FAILED_PRECONDITION. Not allowed with soft.
+    #[prost(string, optional, tag = "4")]
+    pub reassign_to_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Response indicating how many rows were deleted (should be 0 or 1).
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CategoryDeleteResponse {
     #[prost(int32, tag = "1")]
     pub rows_deleted: i32,
+    /// Rows moved to the reassign_to_id category before the delete.
+    #[prost(int64, tag = "2")]
+    pub rows_reassigned: i64,
 }
 /// Request to delete multiple categories by their IDs.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CategoriesDeleteBatchRequest {
     #[prost(string, repeated, tag = "1")]
     pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// If true, check the categories can be deleted and return the would-be
+    /// row count, without deleting them.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
 }
 /// Response indicating how many categories were deleted in the batch.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
@@ -189,6 +298,9 @@ pub struct CategoriesDeleteBatchResponse {
 pub struct CategoryActivateRequest {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// If true, return the category as it would be activated, without saving.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
 }
 /// Response containing the activated category.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -201,6 +313,9 @@ pub struct CategoryActivateResponse {
 pub struct CategoryDeactivateRequest {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// If true, return the category as it would be deactivated, without saving.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
 }
 /// Response containing the deactivated category.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -208,6 +323,104 @@ pub struct CategoryDeactivateResponse {
     #[prost(message, optional, tag = "1")]
     pub category: ::core::option::Option<Category>,
 }
+/// Request to archive a category that is no longer wanted. Archiving
+/// deactivates the category, but is refused while transactions classified
+/// under it, directly or through a split, are recent, unless forced.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CategoryArchiveRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// How many months back a transaction counts as recent. Defaults to 6, and
+    /// must be between 1 and 120.
+    #[prost(int32, optional, tag = "2")]
+    pub months: ::core::option::Option<i32>,
+    /// If true, archive the category even if it has recent transactions.
+    #[prost(bool, tag = "3")]
+    pub force: bool,
+    /// If true, check the category can be archived and return it as it would
+    /// be, without saving.
+    #[prost(bool, tag = "4")]
+    pub validate_only: bool,
+}
+/// Response containing the archived category.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CategoryArchiveResponse {
+    #[prost(message, optional, tag = "1")]
+    pub category: ::core::option::Option<Category>,
+    /// Transactions that used the category in the recent months. Only above
+    /// zero when the archive was forced.
+    #[prost(int64, tag = "2")]
+    pub recent_transaction_count: i64,
+}
+/// Request to restore a soft-deleted category.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CategoryRestoreRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// If true, return the category as it would be restored, without saving.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response containing the restored category.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CategoryRestoreResponse {
+    #[prost(message, optional, tag = "1")]
+    pub category: ::core::option::Option<Category>,
+}
+/// Request for a summary of the categories and how much they are used.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetCategoryStatisticsRequest {
+    /// The most recently used categories to return. Defaults to 5, and is
+    /// capped at 100.
+    #[prost(int32, optional, tag = "1")]
+    pub recent_limit: ::core::option::Option<i32>,
+}
+/// The number of categories of one type.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CategoryTypeCount {
+    #[prost(enumeration = "CategoryTypes", tag = "1")]
+    pub category_type: i32,
+    /// Categories of the type, active or not.
+    #[prost(int64, tag = "2")]
+    pub count: i64,
+}
+/// How much a category is used.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CategoryUsage {
+    #[prost(string, tag = "1")]
+    pub category_id: ::prost::alloc::string::String,
+    /// Transactions classified under the category, drafts included.
+    #[prost(int64, tag = "2")]
+    pub transaction_count: i64,
+    /// The date of the latest transaction classified under the category, in
+    /// ISO 8601 format (YYYY-MM-DD). Unset if it has never been used.
+    #[prost(string, optional, tag = "3")]
+    pub last_used_on: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response summarising the categories. Soft-deleted categories are left
+/// out.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCategoryStatisticsResponse {
+    /// Every category, active or not.
+    #[prost(int64, tag = "1")]
+    pub total_count: i64,
+    /// Categories available for use.
+    #[prost(int64, tag = "2")]
+    pub active_count: i64,
+    /// Categories hidden from use.
+    #[prost(int64, tag = "3")]
+    pub inactive_count: i64,
+    /// Categories by type, one for every type, including types without any.
+    #[prost(message, repeated, tag = "4")]
+    pub type_counts: ::prost::alloc::vec::Vec<CategoryTypeCount>,
+    /// Every category, most used first.
+    #[prost(message, repeated, tag = "5")]
+    pub usage: ::prost::alloc::vec::Vec<CategoryUsage>,
+    /// The categories used most recently, latest first. Categories never used
+    /// are left out.
+    #[prost(message, repeated, tag = "6")]
+    pub recently_used: ::prost::alloc::vec::Vec<CategoryUsage>,
+}
 /// Enum representing the type of financial category.
 /// Used to classify categories as assets, liabilities, etc.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -254,6 +467,47 @@ impl CategoryTypes {
         }
     }
 }
+/// Enum representing the kind of change a watched category went through.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CategoryChangeKinds {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// The category was created.
+    Created = 1,
+    /// The category was updated, including being activated or deactivated.
+    Updated = 2,
+    /// The category was deleted, or soft deleted.
+    Deleted = 3,
+    /// The soft deleted category was restored.
+    Restored = 4,
+}
+impl CategoryChangeKinds {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "CATEGORY_CHANGE_KINDS_UNSPECIFIED",
+            Self::Created => "CATEGORY_CHANGE_KINDS_CREATED",
+            Self::Updated => "CATEGORY_CHANGE_KINDS_UPDATED",
+            Self::Deleted => "CATEGORY_CHANGE_KINDS_DELETED",
+            Self::Restored => "CATEGORY_CHANGE_KINDS_RESTORED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CATEGORY_CHANGE_KINDS_UNSPECIFIED" => Some(Self::Unspecified),
+            "CATEGORY_CHANGE_KINDS_CREATED" => Some(Self::Created),
+            "CATEGORY_CHANGE_KINDS_UPDATED" => Some(Self::Updated),
+            "CATEGORY_CHANGE_KINDS_DELETED" => Some(Self::Deleted),
+            "CATEGORY_CHANGE_KINDS_RESTORED" => Some(Self::Restored),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod categories_service_client {
     #![allow(
@@ -527,6 +781,69 @@ pub mod categories_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Stream every category in pages, for lists too large for one response.
+        pub async fn categories_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CategoriesStreamRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::CategoriesStreamResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.categories.v001.CategoriesService/CategoriesStream",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.categories.v001.CategoriesService",
+                        "CategoriesStream",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Watch for categories being created, updated, deleted and restored,
+        /// pushed as each change is committed. Changes made before the call are not
+        /// sent, so list first and then apply the changes. A client that falls too
+        /// far behind has the stream ended with ABORTED and should list again.
+        pub async fn categories_watch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CategoriesWatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::CategoriesWatchResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.categories.v001.CategoriesService/CategoriesWatch",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.categories.v001.CategoriesService",
+                        "CategoriesWatch",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Update an existing category (partial updates supported).
         pub async fn category_update(
             &mut self,
@@ -677,6 +994,97 @@ pub mod categories_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Archive a category, refusing one with recent transactions unless forced.
+        pub async fn category_archive(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CategoryArchiveRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CategoryArchiveResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.categories.v001.CategoriesService/CategoryArchive",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.categories.v001.CategoriesService",
+                        "CategoryArchive",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Restore a soft-deleted category.
+        pub async fn category_restore(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CategoryRestoreRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CategoryRestoreResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.categories.v001.CategoriesService/CategoryRestore",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.categories.v001.CategoriesService",
+                        "CategoryRestore",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the number of categories by type and activity, and how many
+        /// transactions each is used by, in one request.
+        pub async fn get_category_statistics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCategoryStatisticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCategoryStatisticsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.categories.v001.CategoriesService/GetCategoryStatistics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.categories.v001.CategoriesService",
+                        "GetCategoryStatistics",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -740,6 +1148,40 @@ pub mod categories_service_server {
             tonic::Response<super::CategoriesListResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the CategoriesStream method.
+        type CategoriesStreamStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::CategoriesStreamResponse,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Stream every category in pages, for lists too large for one response.
+        async fn categories_stream(
+            &self,
+            request: tonic::Request<super::CategoriesStreamRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::CategoriesStreamStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the CategoriesWatch method.
+        type CategoriesWatchStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::CategoriesWatchResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Watch for categories being created, updated, deleted and restored,
+        /// pushed as each change is committed. Changes made before the call are not
+        /// sent, so list first and then apply the changes. A client that falls too
+        /// far behind has the stream ended with ABORTED and should list again.
+        async fn categories_watch(
+            &self,
+            request: tonic::Request<super::CategoriesWatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::CategoriesWatchStream>,
+            tonic::Status,
+        >;
         /// Update an existing category (partial updates supported).
         async fn category_update(
             &self,
@@ -780,6 +1222,31 @@ pub mod categories_service_server {
             tonic::Response<super::CategoryDeactivateResponse>,
             tonic::Status,
         >;
+        /// Archive a category, refusing one with recent transactions unless forced.
+        async fn category_archive(
+            &self,
+            request: tonic::Request<super::CategoryArchiveRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CategoryArchiveResponse>,
+            tonic::Status,
+        >;
+        /// Restore a soft-deleted category.
+        async fn category_restore(
+            &self,
+            request: tonic::Request<super::CategoryRestoreRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CategoryRestoreResponse>,
+            tonic::Status,
+        >;
+        /// Get the number of categories by type and activity, and how many
+        /// transactions each is used by, in one request.
+        async fn get_category_statistics(
+            &self,
+            request: tonic::Request<super::GetCategoryStatisticsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCategoryStatisticsResponse>,
+            tonic::Status,
+        >;
     }
     /// gRPC service for managing financial categories.
     /// Provides CRUD, batch, lookup, filtering, and activation operations.
@@ -1144,6 +1611,102 @@ pub mod categories_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/personal_ledger.categories.v001.CategoriesService/CategoriesStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct CategoriesStreamSvc<T: CategoriesService>(pub Arc<T>);
+                    impl<
+                        T: CategoriesService,
+                    > tonic::server::ServerStreamingService<
+                        super::CategoriesStreamRequest,
+                    > for CategoriesStreamSvc<T> {
+                        type Response = super::CategoriesStreamResponse;
+                        type ResponseStream = T::CategoriesStreamStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CategoriesStreamRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoriesService>::categories_stream(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CategoriesStreamSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.categories.v001.CategoriesService/CategoriesWatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct CategoriesWatchSvc<T: CategoriesService>(pub Arc<T>);
+                    impl<
+                        T: CategoriesService,
+                    > tonic::server::ServerStreamingService<
+                        super::CategoriesWatchRequest,
+                    > for CategoriesWatchSvc<T> {
+                        type Response = super::CategoriesWatchResponse;
+                        type ResponseStream = T::CategoriesWatchStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CategoriesWatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoriesService>::categories_watch(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CategoriesWatchSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/personal_ledger.categories.v001.CategoriesService/CategoryUpdate" => {
                     #[allow(non_camel_case_types)]
                     struct CategoryUpdateSvc<T: CategoriesService>(pub Arc<T>);
@@ -1380,6 +1943,147 @@ pub mod categories_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/personal_ledger.categories.v001.CategoriesService/CategoryArchive" => {
+                    #[allow(non_camel_case_types)]
+                    struct CategoryArchiveSvc<T: CategoriesService>(pub Arc<T>);
+                    impl<
+                        T: CategoriesService,
+                    > tonic::server::UnaryService<super::CategoryArchiveRequest>
+                    for CategoryArchiveSvc<T> {
+                        type Response = super::CategoryArchiveResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CategoryArchiveRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoriesService>::category_archive(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CategoryArchiveSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.categories.v001.CategoriesService/CategoryRestore" => {
+                    #[allow(non_camel_case_types)]
+                    struct CategoryRestoreSvc<T: CategoriesService>(pub Arc<T>);
+                    impl<
+                        T: CategoriesService,
+                    > tonic::server::UnaryService<super::CategoryRestoreRequest>
+                    for CategoryRestoreSvc<T> {
+                        type Response = super::CategoryRestoreResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CategoryRestoreRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoriesService>::category_restore(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CategoryRestoreSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.categories.v001.CategoriesService/GetCategoryStatistics" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCategoryStatisticsSvc<T: CategoriesService>(pub Arc<T>);
+                    impl<
+                        T: CategoriesService,
+                    > tonic::server::UnaryService<super::GetCategoryStatisticsRequest>
+                    for GetCategoryStatisticsSvc<T> {
+                        type Response = super::GetCategoryStatisticsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCategoryStatisticsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoriesService>::get_category_statistics(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetCategoryStatisticsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
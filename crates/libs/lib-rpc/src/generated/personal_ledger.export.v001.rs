@@ -0,0 +1,570 @@
+// This file is @generated by prost-build.
+/// Request to export records from the ledger.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ExportLedgerRequest {
+    /// The format to export in. Defaults to CSV.
+    #[prost(enumeration = "ExportFormats", tag = "1")]
+    pub format: i32,
+    /// The kinds of record to export, in the order given. Defaults to
+    /// categories, accounts and transactions.
+    #[prost(enumeration = "ExportEntities", repeated, tag = "2")]
+    pub entities: ::prost::alloc::vec::Vec<i32>,
+    /// Optional first transaction date to export, in ISO 8601 format
+    /// (YYYY-MM-DD). Categories and accounts are always exported whole.
+    #[prost(string, optional, tag = "3")]
+    pub from: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional last transaction date to export, in ISO 8601 format
+    /// (YYYY-MM-DD).
+    #[prost(string, optional, tag = "4")]
+    pub to: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// A run of records of one kind. The contents of every response for a kind,
+/// joined in the order received, make up the file for that kind.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ExportLedgerResponse {
+    /// The kind of record in this response.
+    #[prost(enumeration = "ExportEntities", tag = "1")]
+    pub entity: i32,
+    /// Whole records, each ending in a newline. For CSV the first response of a
+    /// kind starts with the header row.
+    #[prost(string, tag = "2")]
+    pub content: ::prost::alloc::string::String,
+}
+/// Request to export the ledger as a book for another accounting program.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ExportBookRequest {
+    /// The format of the book.
+    #[prost(enumeration = "BookFormats", tag = "1")]
+    pub format: i32,
+    /// Optional first transaction date to export, in ISO 8601 format
+    /// (YYYY-MM-DD). Every category and account is exported.
+    #[prost(string, optional, tag = "2")]
+    pub from: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional last transaction date to export, in ISO 8601 format
+    /// (YYYY-MM-DD).
+    #[prost(string, optional, tag = "3")]
+    pub to: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// A part of the book. The contents of every response, joined in the order
+/// received, make up the file.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ExportBookResponse {
+    /// Whole lines of the file, each ending in a newline.
+    #[prost(string, tag = "1")]
+    pub content: ::prost::alloc::string::String,
+}
+/// Enum representing the format records are exported in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ExportFormats {
+    /// Default value. Exports CSV.
+    Unspecified = 0,
+    /// Comma separated values, starting with a header row naming the columns.
+    Csv = 1,
+    /// One JSON object per line, keyed by column.
+    JsonLines = 2,
+}
+impl ExportFormats {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "EXPORT_FORMATS_UNSPECIFIED",
+            Self::Csv => "EXPORT_FORMATS_CSV",
+            Self::JsonLines => "EXPORT_FORMATS_JSON_LINES",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EXPORT_FORMATS_UNSPECIFIED" => Some(Self::Unspecified),
+            "EXPORT_FORMATS_CSV" => Some(Self::Csv),
+            "EXPORT_FORMATS_JSON_LINES" => Some(Self::JsonLines),
+            _ => None,
+        }
+    }
+}
+/// Enum representing the formats of another accounting program a book can be
+/// exported in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BookFormats {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// An uncompressed GnuCash XML book. Categories become accounts of the
+    /// matching GnuCash type, and ledger accounts become bank or credit accounts.
+    GnucashXml = 1,
+    /// A Beancount journal. Categories become accounts named by their code
+    /// under the root account of their type, e.g. `Expenses:FOD:GRO`.
+    Beancount = 2,
+    /// An hledger journal, with accounts named as for Beancount.
+    Hledger = 3,
+}
+impl BookFormats {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "BOOK_FORMATS_UNSPECIFIED",
+            Self::GnucashXml => "BOOK_FORMATS_GNUCASH_XML",
+            Self::Beancount => "BOOK_FORMATS_BEANCOUNT",
+            Self::Hledger => "BOOK_FORMATS_HLEDGER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BOOK_FORMATS_UNSPECIFIED" => Some(Self::Unspecified),
+            "BOOK_FORMATS_GNUCASH_XML" => Some(Self::GnucashXml),
+            "BOOK_FORMATS_BEANCOUNT" => Some(Self::Beancount),
+            "BOOK_FORMATS_HLEDGER" => Some(Self::Hledger),
+            _ => None,
+        }
+    }
+}
+/// Enum representing the kinds of record that can be exported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ExportEntities {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// Categories, including soft-deleted categories, which have `deleted_on`
+    /// set.
+    Categories = 1,
+    /// Accounts.
+    Accounts = 2,
+    /// Transactions, oldest first.
+    Transactions = 3,
+}
+impl ExportEntities {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "EXPORT_ENTITIES_UNSPECIFIED",
+            Self::Categories => "EXPORT_ENTITIES_CATEGORIES",
+            Self::Accounts => "EXPORT_ENTITIES_ACCOUNTS",
+            Self::Transactions => "EXPORT_ENTITIES_TRANSACTIONS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EXPORT_ENTITIES_UNSPECIFIED" => Some(Self::Unspecified),
+            "EXPORT_ENTITIES_CATEGORIES" => Some(Self::Categories),
+            "EXPORT_ENTITIES_ACCOUNTS" => Some(Self::Accounts),
+            "EXPORT_ENTITIES_TRANSACTIONS" => Some(Self::Transactions),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod export_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// gRPC service for exporting the ledger.
+    #[derive(Debug, Clone)]
+    pub struct ExportServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ExportServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ExportServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ExportServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            ExportServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Stream the records of the ledger, one kind after another.
+        pub async fn export_ledger(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportLedgerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ExportLedgerResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.export.v001.ExportService/ExportLedger",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.export.v001.ExportService",
+                        "ExportLedger",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Stream the ledger as a book for another accounting program. Draft
+        /// transactions are left out.
+        pub async fn export_book(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportBookRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ExportBookResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.export.v001.ExportService/ExportBook",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.export.v001.ExportService",
+                        "ExportBook",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod export_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ExportServiceServer.
+    #[async_trait]
+    pub trait ExportService: std::marker::Send + std::marker::Sync + 'static {
+        /// Server streaming response type for the ExportLedger method.
+        type ExportLedgerStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ExportLedgerResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Stream the records of the ledger, one kind after another.
+        async fn export_ledger(
+            &self,
+            request: tonic::Request<super::ExportLedgerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ExportLedgerStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ExportBook method.
+        type ExportBookStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ExportBookResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Stream the ledger as a book for another accounting program. Draft
+        /// transactions are left out.
+        async fn export_book(
+            &self,
+            request: tonic::Request<super::ExportBookRequest>,
+        ) -> std::result::Result<tonic::Response<Self::ExportBookStream>, tonic::Status>;
+    }
+    /// gRPC service for exporting the ledger.
+    #[derive(Debug)]
+    pub struct ExportServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ExportServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ExportServiceServer<T>
+    where
+        T: ExportService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/personal_ledger.export.v001.ExportService/ExportLedger" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportLedgerSvc<T: ExportService>(pub Arc<T>);
+                    impl<
+                        T: ExportService,
+                    > tonic::server::ServerStreamingService<super::ExportLedgerRequest>
+                    for ExportLedgerSvc<T> {
+                        type Response = super::ExportLedgerResponse;
+                        type ResponseStream = T::ExportLedgerStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportLedgerRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExportService>::export_ledger(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExportLedgerSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.export.v001.ExportService/ExportBook" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportBookSvc<T: ExportService>(pub Arc<T>);
+                    impl<
+                        T: ExportService,
+                    > tonic::server::ServerStreamingService<super::ExportBookRequest>
+                    for ExportBookSvc<T> {
+                        type Response = super::ExportBookResponse;
+                        type ResponseStream = T::ExportBookStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportBookRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExportService>::export_book(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExportBookSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for ExportServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "personal_ledger.export.v001.ExportService";
+    impl<T> tonic::server::NamedService for ExportServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
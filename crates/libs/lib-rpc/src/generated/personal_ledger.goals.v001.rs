@@ -0,0 +1,864 @@
+// This file is @generated by prost-build.
+/// A savings goal, linked to exactly one account or category.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Goal {
+    /// Unique identifier (UUID) for the goal.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The name of the goal, such as "Emergency fund". Must not be blank.
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// The amount to save, in minor units (cents). Must be positive.
+    #[prost(int64, tag = "3")]
+    pub target_amount: i64,
+    /// The date to reach the target by, in ISO 8601 format (YYYY-MM-DD).
+    /// Unset for a goal without a deadline.
+    #[prost(string, optional, tag = "4")]
+    pub target_date: ::core::option::Option<::prost::alloc::string::String>,
+    /// The ID of the account the money is saved in. Its balance is the amount
+    /// saved. Set either this or category_id.
+    #[prost(string, optional, tag = "5")]
+    pub account_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// The ID of the category money set aside is classified under. The posted
+    /// spending in it and its subcategories is the amount saved. Set either
+    /// this or account_id.
+    #[prost(string, optional, tag = "6")]
+    pub category_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Timestamp when the goal was created (UTC).
+    #[prost(message, optional, tag = "7")]
+    pub created_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Timestamp when the goal was last updated (UTC).
+    #[prost(message, optional, tag = "8")]
+    pub updated_on: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// How far a goal is towards its target on a date.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GoalProgress {
+    #[prost(message, optional, tag = "1")]
+    pub goal: ::core::option::Option<Goal>,
+    /// The date progress is measured on, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "2")]
+    pub on: ::prost::alloc::string::String,
+    /// The amount saved on that date, in minor units. Posted transactions only.
+    #[prost(int64, tag = "3")]
+    pub saved: i64,
+    /// What is left to save, in minor units. Zero once the target is reached.
+    #[prost(int64, tag = "4")]
+    pub remaining: i64,
+    /// The amount saved as a percentage of the target. Over 100 once more than
+    /// the target is saved.
+    #[prost(double, tag = "5")]
+    pub percent_complete: f64,
+    /// Whether the amount saved has reached the target.
+    #[prost(bool, tag = "6")]
+    pub is_reached: bool,
+    /// The amount to save each month, in minor units, to reach the target by
+    /// its date. Unset for a goal without a target date, or once it is reached.
+    #[prost(int64, optional, tag = "7")]
+    pub monthly_required: ::core::option::Option<i64>,
+}
+/// Request to create a new goal.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GoalCreateRequest {
+    /// The goal to create (id and timestamps are ignored).
+    #[prost(message, optional, tag = "1")]
+    pub goal: ::core::option::Option<Goal>,
+    /// If true, validate the goal and return it as it would be created,
+    /// without saving it (AIP-163 dry run).
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response containing the created goal.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GoalCreateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub goal: ::core::option::Option<Goal>,
+}
+/// Request to fetch a goal by its unique ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GoalGetRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Response containing the requested goal.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GoalGetResponse {
+    #[prost(message, optional, tag = "1")]
+    pub goal: ::core::option::Option<Goal>,
+}
+/// Request to list every goal.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GoalsListRequest {}
+/// Response containing every goal, those due soonest first and those
+/// without a target date last, then by name.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GoalsListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub goals: ::prost::alloc::vec::Vec<Goal>,
+}
+/// Request to update an existing goal.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GoalUpdateRequest {
+    /// The ID of the goal to update.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The new goal data (id and timestamps are ignored).
+    #[prost(message, optional, tag = "2")]
+    pub goal: ::core::option::Option<Goal>,
+    /// If true, validate the update and return the goal as it would be
+    /// updated, without saving it.
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+}
+/// Response containing the updated goal.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GoalUpdateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub goal: ::core::option::Option<Goal>,
+}
+/// Request to delete a goal by ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GoalDeleteRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// If true, check the goal can be deleted and return the would-be row
+    /// count, without deleting it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response indicating how many rows were deleted (should be 0 or 1).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GoalDeleteResponse {
+    #[prost(int32, tag = "1")]
+    pub rows_deleted: i32,
+}
+/// Request to measure the progress of every goal.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GoalsProgressRequest {
+    /// The date to measure progress on, in ISO 8601 format (YYYY-MM-DD).
+    /// Defaults to today.
+    #[prost(string, optional, tag = "1")]
+    pub on: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response containing the progress of every goal, in the order of
+/// GoalsList.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GoalsProgressResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub progress: ::prost::alloc::vec::Vec<GoalProgress>,
+}
+/// Generated client implementations.
+pub mod goals_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct GoalsServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl GoalsServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> GoalsServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> GoalsServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            GoalsServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Create a new goal.
+        pub async fn goal_create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GoalCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GoalCreateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.goals.v001.GoalsService/GoalCreate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.goals.v001.GoalsService",
+                        "GoalCreate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get a goal by its unique ID.
+        pub async fn goal_get(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GoalGetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GoalGetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.goals.v001.GoalsService/GoalGet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("personal_ledger.goals.v001.GoalsService", "GoalGet"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List every goal, soonest due first.
+        pub async fn goals_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GoalsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GoalsListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.goals.v001.GoalsService/GoalsList",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.goals.v001.GoalsService",
+                        "GoalsList",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Update an existing goal.
+        pub async fn goal_update(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GoalUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GoalUpdateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.goals.v001.GoalsService/GoalUpdate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.goals.v001.GoalsService",
+                        "GoalUpdate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Delete a goal by ID.
+        pub async fn goal_delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GoalDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GoalDeleteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.goals.v001.GoalsService/GoalDelete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.goals.v001.GoalsService",
+                        "GoalDelete",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Measure how far every goal is towards its target on a date.
+        pub async fn goals_progress(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GoalsProgressRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GoalsProgressResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.goals.v001.GoalsService/GoalsProgress",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.goals.v001.GoalsService",
+                        "GoalsProgress",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod goals_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with GoalsServiceServer.
+    #[async_trait]
+    pub trait GoalsService: std::marker::Send + std::marker::Sync + 'static {
+        /// Create a new goal.
+        async fn goal_create(
+            &self,
+            request: tonic::Request<super::GoalCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GoalCreateResponse>,
+            tonic::Status,
+        >;
+        /// Get a goal by its unique ID.
+        async fn goal_get(
+            &self,
+            request: tonic::Request<super::GoalGetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GoalGetResponse>, tonic::Status>;
+        /// List every goal, soonest due first.
+        async fn goals_list(
+            &self,
+            request: tonic::Request<super::GoalsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GoalsListResponse>,
+            tonic::Status,
+        >;
+        /// Update an existing goal.
+        async fn goal_update(
+            &self,
+            request: tonic::Request<super::GoalUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GoalUpdateResponse>,
+            tonic::Status,
+        >;
+        /// Delete a goal by ID.
+        async fn goal_delete(
+            &self,
+            request: tonic::Request<super::GoalDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GoalDeleteResponse>,
+            tonic::Status,
+        >;
+        /// Measure how far every goal is towards its target on a date.
+        async fn goals_progress(
+            &self,
+            request: tonic::Request<super::GoalsProgressRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GoalsProgressResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct GoalsServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> GoalsServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for GoalsServiceServer<T>
+    where
+        T: GoalsService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/personal_ledger.goals.v001.GoalsService/GoalCreate" => {
+                    #[allow(non_camel_case_types)]
+                    struct GoalCreateSvc<T: GoalsService>(pub Arc<T>);
+                    impl<
+                        T: GoalsService,
+                    > tonic::server::UnaryService<super::GoalCreateRequest>
+                    for GoalCreateSvc<T> {
+                        type Response = super::GoalCreateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GoalCreateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as GoalsService>::goal_create(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GoalCreateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.goals.v001.GoalsService/GoalGet" => {
+                    #[allow(non_camel_case_types)]
+                    struct GoalGetSvc<T: GoalsService>(pub Arc<T>);
+                    impl<
+                        T: GoalsService,
+                    > tonic::server::UnaryService<super::GoalGetRequest>
+                    for GoalGetSvc<T> {
+                        type Response = super::GoalGetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GoalGetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as GoalsService>::goal_get(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GoalGetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.goals.v001.GoalsService/GoalsList" => {
+                    #[allow(non_camel_case_types)]
+                    struct GoalsListSvc<T: GoalsService>(pub Arc<T>);
+                    impl<
+                        T: GoalsService,
+                    > tonic::server::UnaryService<super::GoalsListRequest>
+                    for GoalsListSvc<T> {
+                        type Response = super::GoalsListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GoalsListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as GoalsService>::goals_list(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GoalsListSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.goals.v001.GoalsService/GoalUpdate" => {
+                    #[allow(non_camel_case_types)]
+                    struct GoalUpdateSvc<T: GoalsService>(pub Arc<T>);
+                    impl<
+                        T: GoalsService,
+                    > tonic::server::UnaryService<super::GoalUpdateRequest>
+                    for GoalUpdateSvc<T> {
+                        type Response = super::GoalUpdateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GoalUpdateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as GoalsService>::goal_update(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GoalUpdateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.goals.v001.GoalsService/GoalDelete" => {
+                    #[allow(non_camel_case_types)]
+                    struct GoalDeleteSvc<T: GoalsService>(pub Arc<T>);
+                    impl<
+                        T: GoalsService,
+                    > tonic::server::UnaryService<super::GoalDeleteRequest>
+                    for GoalDeleteSvc<T> {
+                        type Response = super::GoalDeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GoalDeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as GoalsService>::goal_delete(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GoalDeleteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.goals.v001.GoalsService/GoalsProgress" => {
+                    #[allow(non_camel_case_types)]
+                    struct GoalsProgressSvc<T: GoalsService>(pub Arc<T>);
+                    impl<
+                        T: GoalsService,
+                    > tonic::server::UnaryService<super::GoalsProgressRequest>
+                    for GoalsProgressSvc<T> {
+                        type Response = super::GoalsProgressResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GoalsProgressRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as GoalsService>::goals_progress(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GoalsProgressSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for GoalsServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "personal_ledger.goals.v001.GoalsService";
+    impl<T> tonic::server::NamedService for GoalsServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -0,0 +1,873 @@
+// This file is @generated by prost-build.
+/// Maps raw payee strings to a clean payee.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeAlias {
+    /// Unique identifier (UUID) for the alias.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The raw payee, or regular expression, to match.
+    #[prost(string, tag = "2")]
+    pub pattern: ::prost::alloc::string::String,
+    /// How the pattern is matched. Exact aliases are tried before regular
+    /// expressions, which are tried in the order they were created.
+    #[prost(enumeration = "PayeeMatchKinds", tag = "3")]
+    pub match_kind: i32,
+    /// The clean payee matching raw payees are changed to.
+    #[prost(string, tag = "4")]
+    pub payee: ::prost::alloc::string::String,
+    /// Timestamp when the alias was created (UTC).
+    #[prost(message, optional, tag = "5")]
+    pub created_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Timestamp when the alias was last updated (UTC).
+    #[prost(message, optional, tag = "6")]
+    pub updated_on: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Request to create a new payee alias.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeAliasCreateRequest {
+    /// The alias to create (id and timestamps are ignored).
+    #[prost(message, optional, tag = "1")]
+    pub alias: ::core::option::Option<PayeeAlias>,
+    /// If true, validate the alias and return it as it would be created,
+    /// without saving it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response containing the created alias.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeAliasCreateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub alias: ::core::option::Option<PayeeAlias>,
+}
+/// Request to fetch a payee alias by its unique ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeAliasGetRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Response containing the requested alias.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeAliasGetResponse {
+    #[prost(message, optional, tag = "1")]
+    pub alias: ::core::option::Option<PayeeAlias>,
+}
+/// Request to list every payee alias.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeAliasesListRequest {}
+/// Response containing every payee alias, oldest first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayeeAliasesListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub aliases: ::prost::alloc::vec::Vec<PayeeAlias>,
+}
+/// Request to update an existing payee alias.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeAliasUpdateRequest {
+    /// The ID of the alias to update.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The new alias data (id and timestamps are ignored).
+    #[prost(message, optional, tag = "2")]
+    pub alias: ::core::option::Option<PayeeAlias>,
+    /// If true, validate the update and return the alias as it would be
+    /// updated, without saving it.
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+}
+/// Response containing the updated alias.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeAliasUpdateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub alias: ::core::option::Option<PayeeAlias>,
+}
+/// Request to delete a payee alias by ID. Transactions already imported keep
+/// the payee they were cleaned up to.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeAliasDeleteRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// If true, check the alias can be deleted and return the would-be row
+    /// count, without deleting it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response indicating how many rows were deleted (should be 0 or 1).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeAliasDeleteResponse {
+    #[prost(int32, tag = "1")]
+    pub rows_deleted: i32,
+}
+/// Request to clean up a raw payee with the stored aliases, as an import
+/// would.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeNormaliseRequest {
+    #[prost(string, tag = "1")]
+    pub raw_payee: ::prost::alloc::string::String,
+}
+/// Response containing the clean payee.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PayeeNormaliseResponse {
+    /// The clean payee, or the raw payee trimmed if no alias matches.
+    #[prost(string, tag = "1")]
+    pub payee: ::prost::alloc::string::String,
+}
+/// Enum representing how an alias pattern is matched against a raw payee.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PayeeMatchKinds {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// The whole raw payee, ignoring case and runs of whitespace.
+    Exact = 1,
+    /// A regular expression found anywhere in the raw payee, ignoring case.
+    Regex = 2,
+}
+impl PayeeMatchKinds {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "PAYEE_MATCH_KINDS_UNSPECIFIED",
+            Self::Exact => "PAYEE_MATCH_KINDS_EXACT",
+            Self::Regex => "PAYEE_MATCH_KINDS_REGEX",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PAYEE_MATCH_KINDS_UNSPECIFIED" => Some(Self::Unspecified),
+            "PAYEE_MATCH_KINDS_EXACT" => Some(Self::Exact),
+            "PAYEE_MATCH_KINDS_REGEX" => Some(Self::Regex),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod payees_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// gRPC service for managing payee aliases.
+    #[derive(Debug, Clone)]
+    pub struct PayeesServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl PayeesServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> PayeesServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> PayeesServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            PayeesServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Create a new payee alias.
+        pub async fn payee_alias_create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PayeeAliasCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeAliasCreateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.payees.v001.PayeesService/PayeeAliasCreate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.payees.v001.PayeesService",
+                        "PayeeAliasCreate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get a payee alias by its unique ID.
+        pub async fn payee_alias_get(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PayeeAliasGetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeAliasGetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.payees.v001.PayeesService/PayeeAliasGet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.payees.v001.PayeesService",
+                        "PayeeAliasGet",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List every payee alias, oldest first.
+        pub async fn payee_aliases_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PayeeAliasesListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeAliasesListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.payees.v001.PayeesService/PayeeAliasesList",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.payees.v001.PayeesService",
+                        "PayeeAliasesList",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Update an existing payee alias.
+        pub async fn payee_alias_update(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PayeeAliasUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeAliasUpdateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.payees.v001.PayeesService/PayeeAliasUpdate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.payees.v001.PayeesService",
+                        "PayeeAliasUpdate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Delete a payee alias by ID.
+        pub async fn payee_alias_delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PayeeAliasDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeAliasDeleteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.payees.v001.PayeesService/PayeeAliasDelete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.payees.v001.PayeesService",
+                        "PayeeAliasDelete",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Clean up a raw payee with the stored aliases, to try them out.
+        pub async fn payee_normalise(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PayeeNormaliseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeNormaliseResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.payees.v001.PayeesService/PayeeNormalise",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.payees.v001.PayeesService",
+                        "PayeeNormalise",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod payees_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with PayeesServiceServer.
+    #[async_trait]
+    pub trait PayeesService: std::marker::Send + std::marker::Sync + 'static {
+        /// Create a new payee alias.
+        async fn payee_alias_create(
+            &self,
+            request: tonic::Request<super::PayeeAliasCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeAliasCreateResponse>,
+            tonic::Status,
+        >;
+        /// Get a payee alias by its unique ID.
+        async fn payee_alias_get(
+            &self,
+            request: tonic::Request<super::PayeeAliasGetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeAliasGetResponse>,
+            tonic::Status,
+        >;
+        /// List every payee alias, oldest first.
+        async fn payee_aliases_list(
+            &self,
+            request: tonic::Request<super::PayeeAliasesListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeAliasesListResponse>,
+            tonic::Status,
+        >;
+        /// Update an existing payee alias.
+        async fn payee_alias_update(
+            &self,
+            request: tonic::Request<super::PayeeAliasUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeAliasUpdateResponse>,
+            tonic::Status,
+        >;
+        /// Delete a payee alias by ID.
+        async fn payee_alias_delete(
+            &self,
+            request: tonic::Request<super::PayeeAliasDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeAliasDeleteResponse>,
+            tonic::Status,
+        >;
+        /// Clean up a raw payee with the stored aliases, to try them out.
+        async fn payee_normalise(
+            &self,
+            request: tonic::Request<super::PayeeNormaliseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PayeeNormaliseResponse>,
+            tonic::Status,
+        >;
+    }
+    /// gRPC service for managing payee aliases.
+    #[derive(Debug)]
+    pub struct PayeesServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> PayeesServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for PayeesServiceServer<T>
+    where
+        T: PayeesService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/personal_ledger.payees.v001.PayeesService/PayeeAliasCreate" => {
+                    #[allow(non_camel_case_types)]
+                    struct PayeeAliasCreateSvc<T: PayeesService>(pub Arc<T>);
+                    impl<
+                        T: PayeesService,
+                    > tonic::server::UnaryService<super::PayeeAliasCreateRequest>
+                    for PayeeAliasCreateSvc<T> {
+                        type Response = super::PayeeAliasCreateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PayeeAliasCreateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PayeesService>::payee_alias_create(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PayeeAliasCreateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.payees.v001.PayeesService/PayeeAliasGet" => {
+                    #[allow(non_camel_case_types)]
+                    struct PayeeAliasGetSvc<T: PayeesService>(pub Arc<T>);
+                    impl<
+                        T: PayeesService,
+                    > tonic::server::UnaryService<super::PayeeAliasGetRequest>
+                    for PayeeAliasGetSvc<T> {
+                        type Response = super::PayeeAliasGetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PayeeAliasGetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PayeesService>::payee_alias_get(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PayeeAliasGetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.payees.v001.PayeesService/PayeeAliasesList" => {
+                    #[allow(non_camel_case_types)]
+                    struct PayeeAliasesListSvc<T: PayeesService>(pub Arc<T>);
+                    impl<
+                        T: PayeesService,
+                    > tonic::server::UnaryService<super::PayeeAliasesListRequest>
+                    for PayeeAliasesListSvc<T> {
+                        type Response = super::PayeeAliasesListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PayeeAliasesListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PayeesService>::payee_aliases_list(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PayeeAliasesListSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.payees.v001.PayeesService/PayeeAliasUpdate" => {
+                    #[allow(non_camel_case_types)]
+                    struct PayeeAliasUpdateSvc<T: PayeesService>(pub Arc<T>);
+                    impl<
+                        T: PayeesService,
+                    > tonic::server::UnaryService<super::PayeeAliasUpdateRequest>
+                    for PayeeAliasUpdateSvc<T> {
+                        type Response = super::PayeeAliasUpdateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PayeeAliasUpdateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PayeesService>::payee_alias_update(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PayeeAliasUpdateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.payees.v001.PayeesService/PayeeAliasDelete" => {
+                    #[allow(non_camel_case_types)]
+                    struct PayeeAliasDeleteSvc<T: PayeesService>(pub Arc<T>);
+                    impl<
+                        T: PayeesService,
+                    > tonic::server::UnaryService<super::PayeeAliasDeleteRequest>
+                    for PayeeAliasDeleteSvc<T> {
+                        type Response = super::PayeeAliasDeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PayeeAliasDeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PayeesService>::payee_alias_delete(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PayeeAliasDeleteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.payees.v001.PayeesService/PayeeNormalise" => {
+                    #[allow(non_camel_case_types)]
+                    struct PayeeNormaliseSvc<T: PayeesService>(pub Arc<T>);
+                    impl<
+                        T: PayeesService,
+                    > tonic::server::UnaryService<super::PayeeNormaliseRequest>
+                    for PayeeNormaliseSvc<T> {
+                        type Response = super::PayeeNormaliseResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PayeeNormaliseRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PayeesService>::payee_normalise(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PayeeNormaliseSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for PayeesServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "personal_ledger.payees.v001.PayeesService";
+    impl<T> tonic::server::NamedService for PayeesServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -0,0 +1,585 @@
+// This file is @generated by prost-build.
+/// A single close or reopen of the ledger, as recorded in the audit trail.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PeriodClosing {
+    /// Unique identifier (UUID) for the period closing.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Whether the ledger was closed or reopened.
+    #[prost(enumeration = "PeriodActions", tag = "2")]
+    pub action: i32,
+    /// The closing date in ISO 8601 format (YYYY-MM-DD) after this action.
+    /// Unset when a reopen removed the lock entirely.
+    #[prost(string, optional, tag = "3")]
+    pub closed_through: ::core::option::Option<::prost::alloc::string::String>,
+    /// Why the ledger was closed or reopened. Always set for reopens.
+    #[prost(string, optional, tag = "4")]
+    pub reason: ::core::option::Option<::prost::alloc::string::String>,
+    /// Timestamp when the action was recorded (UTC).
+    #[prost(message, optional, tag = "5")]
+    pub created_on: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Request to close the ledger through a date.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PeriodCloseRequest {
+    /// The last date of the closed period in ISO 8601 format (YYYY-MM-DD).
+    /// Must be later than the current closing date.
+    #[prost(string, tag = "1")]
+    pub closed_through: ::prost::alloc::string::String,
+    /// Optional note recorded with the close.
+    #[prost(string, optional, tag = "2")]
+    pub reason: ::core::option::Option<::prost::alloc::string::String>,
+    /// If true, validate the close and return it as it would be recorded,
+    /// without saving it (AIP-163 dry run).
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+}
+/// Response containing the recorded close.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PeriodCloseResponse {
+    #[prost(message, optional, tag = "1")]
+    pub closing: ::core::option::Option<PeriodClosing>,
+}
+/// Request to reopen a closed period (admin override).
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PeriodReopenRequest {
+    /// The new, earlier closing date in ISO 8601 format (YYYY-MM-DD). Leave
+    /// unset to reopen every period.
+    #[prost(string, optional, tag = "1")]
+    pub closed_through: ::core::option::Option<::prost::alloc::string::String>,
+    /// Why the period is being reopened. Required.
+    #[prost(string, tag = "2")]
+    pub reason: ::prost::alloc::string::String,
+    /// If true, validate the reopen and return it as it would be recorded,
+    /// without saving it.
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+}
+/// Response containing the recorded reopen.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PeriodReopenResponse {
+    #[prost(message, optional, tag = "1")]
+    pub closing: ::core::option::Option<PeriodClosing>,
+}
+/// Request for the current closing date and audit trail.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PeriodGetStatusRequest {}
+/// Response containing the current closing date and audit trail.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PeriodGetStatusResponse {
+    /// The current closing date in ISO 8601 format (YYYY-MM-DD). Unset when no
+    /// period is closed.
+    #[prost(string, optional, tag = "1")]
+    pub closed_through: ::core::option::Option<::prost::alloc::string::String>,
+    /// Every close and reopen, newest first.
+    #[prost(message, repeated, tag = "2")]
+    pub history: ::prost::alloc::vec::Vec<PeriodClosing>,
+}
+/// Enum representing whether a period closing closed or reopened the ledger.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PeriodActions {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// The ledger was closed through a date.
+    Close = 1,
+    /// The closing date was moved back or cleared (admin override).
+    Reopen = 2,
+}
+impl PeriodActions {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "PERIOD_ACTIONS_UNSPECIFIED",
+            Self::Close => "PERIOD_ACTIONS_CLOSE",
+            Self::Reopen => "PERIOD_ACTIONS_REOPEN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PERIOD_ACTIONS_UNSPECIFIED" => Some(Self::Unspecified),
+            "PERIOD_ACTIONS_CLOSE" => Some(Self::Close),
+            "PERIOD_ACTIONS_REOPEN" => Some(Self::Reopen),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod periods_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// gRPC service for closing and reopening ledger periods.
+    #[derive(Debug, Clone)]
+    pub struct PeriodsServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl PeriodsServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> PeriodsServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> PeriodsServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            PeriodsServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Close the ledger through a date, locking earlier transactions.
+        pub async fn period_close(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PeriodCloseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PeriodCloseResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.periods.v001.PeriodsService/PeriodClose",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.periods.v001.PeriodsService",
+                        "PeriodClose",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Reopen a closed period, recording the reason (admin override).
+        pub async fn period_reopen(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PeriodReopenRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PeriodReopenResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.periods.v001.PeriodsService/PeriodReopen",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.periods.v001.PeriodsService",
+                        "PeriodReopen",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the current closing date and the audit trail.
+        pub async fn period_get_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PeriodGetStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PeriodGetStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.periods.v001.PeriodsService/PeriodGetStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.periods.v001.PeriodsService",
+                        "PeriodGetStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod periods_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with PeriodsServiceServer.
+    #[async_trait]
+    pub trait PeriodsService: std::marker::Send + std::marker::Sync + 'static {
+        /// Close the ledger through a date, locking earlier transactions.
+        async fn period_close(
+            &self,
+            request: tonic::Request<super::PeriodCloseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PeriodCloseResponse>,
+            tonic::Status,
+        >;
+        /// Reopen a closed period, recording the reason (admin override).
+        async fn period_reopen(
+            &self,
+            request: tonic::Request<super::PeriodReopenRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PeriodReopenResponse>,
+            tonic::Status,
+        >;
+        /// Get the current closing date and the audit trail.
+        async fn period_get_status(
+            &self,
+            request: tonic::Request<super::PeriodGetStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PeriodGetStatusResponse>,
+            tonic::Status,
+        >;
+    }
+    /// gRPC service for closing and reopening ledger periods.
+    #[derive(Debug)]
+    pub struct PeriodsServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> PeriodsServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for PeriodsServiceServer<T>
+    where
+        T: PeriodsService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/personal_ledger.periods.v001.PeriodsService/PeriodClose" => {
+                    #[allow(non_camel_case_types)]
+                    struct PeriodCloseSvc<T: PeriodsService>(pub Arc<T>);
+                    impl<
+                        T: PeriodsService,
+                    > tonic::server::UnaryService<super::PeriodCloseRequest>
+                    for PeriodCloseSvc<T> {
+                        type Response = super::PeriodCloseResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PeriodCloseRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PeriodsService>::period_close(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PeriodCloseSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.periods.v001.PeriodsService/PeriodReopen" => {
+                    #[allow(non_camel_case_types)]
+                    struct PeriodReopenSvc<T: PeriodsService>(pub Arc<T>);
+                    impl<
+                        T: PeriodsService,
+                    > tonic::server::UnaryService<super::PeriodReopenRequest>
+                    for PeriodReopenSvc<T> {
+                        type Response = super::PeriodReopenResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PeriodReopenRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PeriodsService>::period_reopen(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PeriodReopenSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.periods.v001.PeriodsService/PeriodGetStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct PeriodGetStatusSvc<T: PeriodsService>(pub Arc<T>);
+                    impl<
+                        T: PeriodsService,
+                    > tonic::server::UnaryService<super::PeriodGetStatusRequest>
+                    for PeriodGetStatusSvc<T> {
+                        type Response = super::PeriodGetStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PeriodGetStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PeriodsService>::period_get_status(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PeriodGetStatusSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for PeriodsServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "personal_ledger.periods.v001.PeriodsService";
+    impl<T> tonic::server::NamedService for PeriodsServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
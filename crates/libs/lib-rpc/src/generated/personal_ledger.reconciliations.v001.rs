@@ -0,0 +1,584 @@
+// This file is @generated by prost-build.
+/// An account reconciled against one of its statements.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Reconciliation {
+    /// Unique identifier (UUID) for the reconciliation.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The ID (UUID) of the account that was reconciled.
+    #[prost(string, tag = "2")]
+    pub account_id: ::prost::alloc::string::String,
+    /// The statement's closing date in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "3")]
+    pub statement_date: ::prost::alloc::string::String,
+    /// The statement's starting balance in minor units (cents).
+    #[prost(int64, tag = "4")]
+    pub starting_balance: i64,
+    /// The statement's ending balance in minor units (cents).
+    #[prost(int64, tag = "5")]
+    pub ending_balance: i64,
+    /// Timestamp when the reconciliation was recorded (UTC).
+    #[prost(message, optional, tag = "6")]
+    pub created_on: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Request to reconcile an account against a statement.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReconciliationCreateRequest {
+    /// The ID (UUID) of the account to reconcile.
+    #[prost(string, tag = "1")]
+    pub account_id: ::prost::alloc::string::String,
+    /// The statement's closing date in ISO 8601 format (YYYY-MM-DD). Must be
+    /// after the account's latest reconciliation.
+    #[prost(string, tag = "2")]
+    pub statement_date: ::prost::alloc::string::String,
+    /// The statement's starting balance in minor units (cents). Must be the
+    /// ending balance of the account's latest reconciliation, if it has one.
+    #[prost(int64, tag = "3")]
+    pub starting_balance: i64,
+    /// The statement's ending balance in minor units (cents).
+    #[prost(int64, tag = "4")]
+    pub ending_balance: i64,
+    /// The IDs (UUID) of the transactions on the statement. They must be
+    /// posted to the account, dated on or before the statement, not yet
+    /// reconciled, and add up to the ending less the starting balance.
+    #[prost(string, repeated, tag = "5")]
+    pub transaction_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// If true, check the statement can be reconciled and return the
+    /// reconciliation as it would be recorded, without recording it.
+    #[prost(bool, tag = "6")]
+    pub validate_only: bool,
+}
+/// Response containing the recorded reconciliation.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReconciliationCreateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reconciliation: ::core::option::Option<Reconciliation>,
+}
+/// Request to list an account's reconciliations.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReconciliationsListRequest {
+    /// The ID (UUID) of the account.
+    #[prost(string, tag = "1")]
+    pub account_id: ::prost::alloc::string::String,
+}
+/// Response containing the account's reconciliations, latest statement first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReconciliationsListResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reconciliations: ::prost::alloc::vec::Vec<Reconciliation>,
+}
+/// Request to undo a reconciliation. Only an account's latest reconciliation
+/// can be undone.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReconciliationDeleteRequest {
+    /// The ID (UUID) of the reconciliation to undo.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// If true, check the reconciliation can be undone without undoing it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response to undoing a reconciliation.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReconciliationDeleteResponse {
+    /// How many transactions were set back to cleared.
+    #[prost(int32, tag = "1")]
+    pub transactions_cleared: i32,
+}
+/// Generated client implementations.
+pub mod reconciliations_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Service for reconciling accounts against their statements.
+    #[derive(Debug, Clone)]
+    pub struct ReconciliationsServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ReconciliationsServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ReconciliationsServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ReconciliationsServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            ReconciliationsServiceClient::new(
+                InterceptedService::new(inner, interceptor),
+            )
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// List an account's reconciliations.
+        pub async fn reconciliations_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReconciliationsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReconciliationsListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.reconciliations.v001.ReconciliationsService/ReconciliationsList",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.reconciliations.v001.ReconciliationsService",
+                        "ReconciliationsList",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Reconcile an account against a statement, marking its transactions
+        /// reconciled.
+        pub async fn reconciliation_create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReconciliationCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReconciliationCreateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.reconciliations.v001.ReconciliationsService/ReconciliationCreate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.reconciliations.v001.ReconciliationsService",
+                        "ReconciliationCreate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Undo an account's latest reconciliation, setting its transactions back
+        /// to cleared.
+        pub async fn reconciliation_delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReconciliationDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReconciliationDeleteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.reconciliations.v001.ReconciliationsService/ReconciliationDelete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.reconciliations.v001.ReconciliationsService",
+                        "ReconciliationDelete",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod reconciliations_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ReconciliationsServiceServer.
+    #[async_trait]
+    pub trait ReconciliationsService: std::marker::Send + std::marker::Sync + 'static {
+        /// List an account's reconciliations.
+        async fn reconciliations_list(
+            &self,
+            request: tonic::Request<super::ReconciliationsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReconciliationsListResponse>,
+            tonic::Status,
+        >;
+        /// Reconcile an account against a statement, marking its transactions
+        /// reconciled.
+        async fn reconciliation_create(
+            &self,
+            request: tonic::Request<super::ReconciliationCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReconciliationCreateResponse>,
+            tonic::Status,
+        >;
+        /// Undo an account's latest reconciliation, setting its transactions back
+        /// to cleared.
+        async fn reconciliation_delete(
+            &self,
+            request: tonic::Request<super::ReconciliationDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReconciliationDeleteResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Service for reconciling accounts against their statements.
+    #[derive(Debug)]
+    pub struct ReconciliationsServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ReconciliationsServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for ReconciliationsServiceServer<T>
+    where
+        T: ReconciliationsService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/personal_ledger.reconciliations.v001.ReconciliationsService/ReconciliationsList" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReconciliationsListSvc<T: ReconciliationsService>(pub Arc<T>);
+                    impl<
+                        T: ReconciliationsService,
+                    > tonic::server::UnaryService<super::ReconciliationsListRequest>
+                    for ReconciliationsListSvc<T> {
+                        type Response = super::ReconciliationsListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReconciliationsListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReconciliationsService>::reconciliations_list(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReconciliationsListSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.reconciliations.v001.ReconciliationsService/ReconciliationCreate" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReconciliationCreateSvc<T: ReconciliationsService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ReconciliationsService,
+                    > tonic::server::UnaryService<super::ReconciliationCreateRequest>
+                    for ReconciliationCreateSvc<T> {
+                        type Response = super::ReconciliationCreateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReconciliationCreateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReconciliationsService>::reconciliation_create(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReconciliationCreateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.reconciliations.v001.ReconciliationsService/ReconciliationDelete" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReconciliationDeleteSvc<T: ReconciliationsService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ReconciliationsService,
+                    > tonic::server::UnaryService<super::ReconciliationDeleteRequest>
+                    for ReconciliationDeleteSvc<T> {
+                        type Response = super::ReconciliationDeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReconciliationDeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReconciliationsService>::reconciliation_delete(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReconciliationDeleteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for ReconciliationsServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "personal_ledger.reconciliations.v001.ReconciliationsService";
+    impl<T> tonic::server::NamedService for ReconciliationsServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
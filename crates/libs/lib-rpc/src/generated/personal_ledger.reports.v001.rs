@@ -0,0 +1,944 @@
+// This file is @generated by prost-build.
+/// Spending in one category over a date range.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CategorySpending {
+    /// The ID (UUID) of the category, unset for uncategorised transactions.
+    #[prost(string, optional, tag = "1")]
+    pub category_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// The name of the category, unset for uncategorised transactions.
+    #[prost(string, optional, tag = "2")]
+    pub category_name: ::core::option::Option<::prost::alloc::string::String>,
+    /// Money spent in minor units (cents), positive. Refunds reduce it.
+    #[prost(int64, tag = "3")]
+    pub spent: i64,
+    /// Number of posted transactions in the category and range.
+    #[prost(int64, tag = "4")]
+    pub transaction_count: i64,
+}
+/// Income and expenses for one month.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MonthlyCashFlow {
+    /// The first day of the month in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "1")]
+    pub month: ::prost::alloc::string::String,
+    /// Money received in minor units (cents), positive.
+    #[prost(int64, tag = "2")]
+    pub income: i64,
+    /// Money spent in minor units (cents), positive.
+    #[prost(int64, tag = "3")]
+    pub expense: i64,
+    /// Income less expenses, negative when more was spent.
+    #[prost(int64, tag = "4")]
+    pub net: i64,
+}
+/// Net worth on one day.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct NetWorthPoint {
+    /// The day in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "1")]
+    pub on: ::prost::alloc::string::String,
+    /// The total of every account balance, in minor units of the requested
+    /// currency.
+    #[prost(int64, tag = "2")]
+    pub net_worth: i64,
+}
+/// What one account was worth on a day, for net worth history.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountWorth {
+    /// The ID (UUID) of the account.
+    #[prost(string, tag = "1")]
+    pub account_id: ::prost::alloc::string::String,
+    /// The name of the account.
+    #[prost(string, tag = "2")]
+    pub account_name: ::prost::alloc::string::String,
+    /// The account's balance in minor units of the requested currency.
+    #[prost(int64, tag = "3")]
+    pub worth: i64,
+    /// How the account's worth was worked out.
+    #[prost(enumeration = "ValuationSources", tag = "4")]
+    pub valued_from: i32,
+}
+/// Net worth on one day with what each account was worth, for charting.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NetWorthHistoryPoint {
+    /// The day in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "1")]
+    pub on: ::prost::alloc::string::String,
+    /// The total of every account's worth, in minor units of the requested
+    /// currency.
+    #[prost(int64, tag = "2")]
+    pub net_worth: i64,
+    /// Every account's worth on the day, by account name.
+    #[prost(message, repeated, tag = "3")]
+    pub accounts: ::prost::alloc::vec::Vec<AccountWorth>,
+}
+/// The balance of one active account on a day, for the dashboard.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DashboardAccount {
+    /// The ID (UUID) of the account.
+    #[prost(string, tag = "1")]
+    pub account_id: ::prost::alloc::string::String,
+    /// The name of the account.
+    #[prost(string, tag = "2")]
+    pub account_name: ::prost::alloc::string::String,
+    /// ISO 4217 code of the account's currency.
+    #[prost(string, tag = "3")]
+    pub currency: ::prost::alloc::string::String,
+    /// Balance in minor units of the account's currency.
+    #[prost(int64, tag = "4")]
+    pub balance: i64,
+}
+/// Budgeted against actual spending for one budget this month, for the
+/// dashboard.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DashboardBudget {
+    /// The ID (UUID) of the budget.
+    #[prost(string, tag = "1")]
+    pub budget_id: ::prost::alloc::string::String,
+    /// The ID (UUID) of the budgeted category.
+    #[prost(string, tag = "2")]
+    pub category_id: ::prost::alloc::string::String,
+    /// The budget scaled to the month, in minor units (cents).
+    #[prost(int64, tag = "3")]
+    pub budgeted: i64,
+    /// Money spent in the month, in minor units (cents), positive.
+    #[prost(int64, tag = "4")]
+    pub actual: i64,
+    /// Budgeted less actual, negative when over budget.
+    #[prost(int64, tag = "5")]
+    pub remaining: i64,
+}
+/// A recently recorded transaction, for the dashboard.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DashboardTransaction {
+    /// The ID (UUID) of the transaction.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The transaction date in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "2")]
+    pub transaction_date: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub payee: ::prost::alloc::string::String,
+    /// Amount in minor units (cents), negative for money spent.
+    #[prost(int64, tag = "4")]
+    pub amount: i64,
+    /// The ID (UUID) of the category, unset when uncategorised.
+    #[prost(string, optional, tag = "5")]
+    pub category_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// The ID (UUID) of the account, unset when not posted to one.
+    #[prost(string, optional, tag = "6")]
+    pub account_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether the transaction is a draft, left out of balances and budgets.
+    #[prost(bool, tag = "7")]
+    pub is_draft: bool,
+}
+/// Request for the spending in each category between two dates.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReportsSpendingByCategoryRequest {
+    /// The first transaction date to include, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "1")]
+    pub from: ::prost::alloc::string::String,
+    /// The last transaction date to include, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "2")]
+    pub to: ::prost::alloc::string::String,
+}
+/// Response containing the spending per category, largest first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReportsSpendingByCategoryResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub categories: ::prost::alloc::vec::Vec<CategorySpending>,
+    /// Total spending across the categories, in minor units (cents).
+    #[prost(int64, tag = "2")]
+    pub total_spent: i64,
+}
+/// Request for the income and expenses in each month between two dates.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReportsIncomeVsExpenseRequest {
+    /// The first transaction date to include, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "1")]
+    pub from: ::prost::alloc::string::String,
+    /// The last transaction date to include, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "2")]
+    pub to: ::prost::alloc::string::String,
+}
+/// Response containing every month in the range, oldest first. Months
+/// without transactions have zero totals.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReportsIncomeVsExpenseResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub months: ::prost::alloc::vec::Vec<MonthlyCashFlow>,
+}
+/// Request for net worth at each month end between two dates. Accounts with
+/// balance snapshots are valued by interpolating between their snapshots.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReportsNetWorthRequest {
+    /// A day in the first month to report on, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "1")]
+    pub from: ::prost::alloc::string::String,
+    /// The last day to report on, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "2")]
+    pub to: ::prost::alloc::string::String,
+    /// ISO 4217 code of the currency to report in. Defaults to "AUD".
+    #[prost(string, optional, tag = "3")]
+    pub currency: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response containing net worth at the end of each month, and on the last
+/// day, oldest first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReportsNetWorthResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub points: ::prost::alloc::vec::Vec<NetWorthPoint>,
+    /// ISO 4217 code of the currency the points are in.
+    #[prost(string, tag = "2")]
+    pub currency: ::prost::alloc::string::String,
+}
+/// Request for net worth history at each month end between two dates, with
+/// each account's part in it. Accounts take the balance computed when a period
+/// ending on the day was closed, if there is one.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReportsNetWorthHistoryRequest {
+    /// A day in the first month to report on, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "1")]
+    pub from: ::prost::alloc::string::String,
+    /// The last day to report on, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "2")]
+    pub to: ::prost::alloc::string::String,
+    /// ISO 4217 code of the currency to report in. Defaults to "AUD".
+    #[prost(string, optional, tag = "3")]
+    pub currency: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response containing net worth history at the end of each month, and on the
+/// last day, oldest first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReportsNetWorthHistoryResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub points: ::prost::alloc::vec::Vec<NetWorthHistoryPoint>,
+    /// ISO 4217 code of the currency the points are in.
+    #[prost(string, tag = "2")]
+    pub currency: ::prost::alloc::string::String,
+}
+/// Request for the dashboard summary on a day.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ReportsDashboardRequest {
+    /// The day to summarise in ISO 8601 format (YYYY-MM-DD). Defaults to today.
+    #[prost(string, optional, tag = "1")]
+    pub on: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response containing the dashboard summary, read from the database in
+/// parallel.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReportsDashboardResponse {
+    /// The day summarised in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "1")]
+    pub on: ::prost::alloc::string::String,
+    /// Balances of the active accounts at the end of the day, by account name.
+    #[prost(message, repeated, tag = "2")]
+    pub accounts: ::prost::alloc::vec::Vec<DashboardAccount>,
+    /// Every budget compared with the spending in the day's month, by category
+    /// name.
+    #[prost(message, repeated, tag = "3")]
+    pub budgets: ::prost::alloc::vec::Vec<DashboardBudget>,
+    /// The ten most recently recorded transactions, newest first.
+    #[prost(message, repeated, tag = "4")]
+    pub recent_transactions: ::prost::alloc::vec::Vec<DashboardTransaction>,
+}
+/// Enum representing how an account's worth on a day was worked out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ValuationSources {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// Its opening balance and posted transactions up to the day.
+    Transactions = 1,
+    /// Interpolated between its manual balance snapshots.
+    Manual = 2,
+    /// The balance computed when the period ending on the day was closed.
+    Computed = 3,
+}
+impl ValuationSources {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "VALUATION_SOURCES_UNSPECIFIED",
+            Self::Transactions => "VALUATION_SOURCES_TRANSACTIONS",
+            Self::Manual => "VALUATION_SOURCES_MANUAL",
+            Self::Computed => "VALUATION_SOURCES_COMPUTED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VALUATION_SOURCES_UNSPECIFIED" => Some(Self::Unspecified),
+            "VALUATION_SOURCES_TRANSACTIONS" => Some(Self::Transactions),
+            "VALUATION_SOURCES_MANUAL" => Some(Self::Manual),
+            "VALUATION_SOURCES_COMPUTED" => Some(Self::Computed),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod reports_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// gRPC service for aggregate reports over the ledger.
+    #[derive(Debug, Clone)]
+    pub struct ReportsServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ReportsServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ReportsServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ReportsServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            ReportsServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Total the spending in each category over a date range.
+        pub async fn reports_spending_by_category(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReportsSpendingByCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReportsSpendingByCategoryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.reports.v001.ReportsService/ReportsSpendingByCategory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.reports.v001.ReportsService",
+                        "ReportsSpendingByCategory",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Total the income and expenses in each month of a date range, with the
+        /// net cash flow of each month, so dashboards need not sum transactions.
+        pub async fn reports_income_vs_expense(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReportsIncomeVsExpenseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReportsIncomeVsExpenseResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.reports.v001.ReportsService/ReportsIncomeVsExpense",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.reports.v001.ReportsService",
+                        "ReportsIncomeVsExpense",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Work out net worth at the end of each month of a date range.
+        pub async fn reports_net_worth(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReportsNetWorthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReportsNetWorthResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.reports.v001.ReportsService/ReportsNetWorth",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.reports.v001.ReportsService",
+                        "ReportsNetWorth",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Work out net worth at the end of each month of a date range, with what
+        /// each account was worth, as a time series for charting.
+        pub async fn reports_net_worth_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReportsNetWorthHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReportsNetWorthHistoryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.reports.v001.ReportsService/ReportsNetWorthHistory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.reports.v001.ReportsService",
+                        "ReportsNetWorthHistory",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Summarise account balances, this month's budgets and recent
+        /// transactions in one call.
+        pub async fn reports_dashboard(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReportsDashboardRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReportsDashboardResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.reports.v001.ReportsService/ReportsDashboard",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.reports.v001.ReportsService",
+                        "ReportsDashboard",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod reports_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ReportsServiceServer.
+    #[async_trait]
+    pub trait ReportsService: std::marker::Send + std::marker::Sync + 'static {
+        /// Total the spending in each category over a date range.
+        async fn reports_spending_by_category(
+            &self,
+            request: tonic::Request<super::ReportsSpendingByCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReportsSpendingByCategoryResponse>,
+            tonic::Status,
+        >;
+        /// Total the income and expenses in each month of a date range, with the
+        /// net cash flow of each month, so dashboards need not sum transactions.
+        async fn reports_income_vs_expense(
+            &self,
+            request: tonic::Request<super::ReportsIncomeVsExpenseRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReportsIncomeVsExpenseResponse>,
+            tonic::Status,
+        >;
+        /// Work out net worth at the end of each month of a date range.
+        async fn reports_net_worth(
+            &self,
+            request: tonic::Request<super::ReportsNetWorthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReportsNetWorthResponse>,
+            tonic::Status,
+        >;
+        /// Work out net worth at the end of each month of a date range, with what
+        /// each account was worth, as a time series for charting.
+        async fn reports_net_worth_history(
+            &self,
+            request: tonic::Request<super::ReportsNetWorthHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReportsNetWorthHistoryResponse>,
+            tonic::Status,
+        >;
+        /// Summarise account balances, this month's budgets and recent
+        /// transactions in one call.
+        async fn reports_dashboard(
+            &self,
+            request: tonic::Request<super::ReportsDashboardRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReportsDashboardResponse>,
+            tonic::Status,
+        >;
+    }
+    /// gRPC service for aggregate reports over the ledger.
+    #[derive(Debug)]
+    pub struct ReportsServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ReportsServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ReportsServiceServer<T>
+    where
+        T: ReportsService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/personal_ledger.reports.v001.ReportsService/ReportsSpendingByCategory" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReportsSpendingByCategorySvc<T: ReportsService>(pub Arc<T>);
+                    impl<
+                        T: ReportsService,
+                    > tonic::server::UnaryService<
+                        super::ReportsSpendingByCategoryRequest,
+                    > for ReportsSpendingByCategorySvc<T> {
+                        type Response = super::ReportsSpendingByCategoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ReportsSpendingByCategoryRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReportsService>::reports_spending_by_category(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReportsSpendingByCategorySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.reports.v001.ReportsService/ReportsIncomeVsExpense" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReportsIncomeVsExpenseSvc<T: ReportsService>(pub Arc<T>);
+                    impl<
+                        T: ReportsService,
+                    > tonic::server::UnaryService<super::ReportsIncomeVsExpenseRequest>
+                    for ReportsIncomeVsExpenseSvc<T> {
+                        type Response = super::ReportsIncomeVsExpenseResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReportsIncomeVsExpenseRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReportsService>::reports_income_vs_expense(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReportsIncomeVsExpenseSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.reports.v001.ReportsService/ReportsNetWorth" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReportsNetWorthSvc<T: ReportsService>(pub Arc<T>);
+                    impl<
+                        T: ReportsService,
+                    > tonic::server::UnaryService<super::ReportsNetWorthRequest>
+                    for ReportsNetWorthSvc<T> {
+                        type Response = super::ReportsNetWorthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReportsNetWorthRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReportsService>::reports_net_worth(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReportsNetWorthSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.reports.v001.ReportsService/ReportsNetWorthHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReportsNetWorthHistorySvc<T: ReportsService>(pub Arc<T>);
+                    impl<
+                        T: ReportsService,
+                    > tonic::server::UnaryService<super::ReportsNetWorthHistoryRequest>
+                    for ReportsNetWorthHistorySvc<T> {
+                        type Response = super::ReportsNetWorthHistoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReportsNetWorthHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReportsService>::reports_net_worth_history(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReportsNetWorthHistorySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.reports.v001.ReportsService/ReportsDashboard" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReportsDashboardSvc<T: ReportsService>(pub Arc<T>);
+                    impl<
+                        T: ReportsService,
+                    > tonic::server::UnaryService<super::ReportsDashboardRequest>
+                    for ReportsDashboardSvc<T> {
+                        type Response = super::ReportsDashboardResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReportsDashboardRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReportsService>::reports_dashboard(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReportsDashboardSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for ReportsServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "personal_ledger.reports.v001.ReportsService";
+    impl<T> tonic::server::NamedService for ReportsServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -0,0 +1,2721 @@
+// This file is @generated by prost-build.
+/// One part of a split transaction, classifying part of its amount under a
+/// category.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Split {
+    /// Unique identifier (UUID) for the split. Output only: every split is given
+    /// a new ID when the splits of a transaction are set.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The ID (UUID) of the category this part of the amount is classified
+    /// under.
+    #[prost(string, tag = "2")]
+    pub category_id: ::prost::alloc::string::String,
+    /// Signed amount in minor units (cents), with the same sign convention as
+    /// the transaction, so the splits of a purchase are negative. Must not be
+    /// zero.
+    #[prost(int64, tag = "3")]
+    pub amount: i64,
+    /// Optional note on what this part of the amount was for.
+    #[prost(string, optional, tag = "4")]
+    pub memo: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Represents a financial transaction recorded in the ledger.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transaction {
+    /// Unique identifier (UUID) for the transaction.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The transaction date in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "2")]
+    pub transaction_date: ::prost::alloc::string::String,
+    /// Who the money was paid to or received from.
+    #[prost(string, tag = "3")]
+    pub payee: ::prost::alloc::string::String,
+    /// Optional description or note for the transaction, as markdown of at most
+    /// 2,000 characters without raw HTML or images.
+    #[prost(string, optional, tag = "4")]
+    pub description: ::core::option::Option<::prost::alloc::string::String>,
+    /// Signed amount in minor units (cents). Negative for money spent,
+    /// positive for money received. Must not be zero.
+    #[prost(int64, tag = "5")]
+    pub amount: i64,
+    /// Optional ID (UUID) of the category the transaction is classified under.
+    #[prost(string, optional, tag = "6")]
+    pub category_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Timestamp when the transaction was created (UTC).
+    #[prost(message, optional, tag = "7")]
+    pub created_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Timestamp when the transaction was last updated (UTC).
+    #[prost(message, optional, tag = "8")]
+    pub updated_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Optional ID (UUID) of the account the transaction is posted to.
+    #[prost(string, optional, tag = "9")]
+    pub account_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether the transaction is a draft. Drafts are left out of balances and
+    /// reports, and are not checked against closed periods, until posted.
+    #[prost(bool, tag = "10")]
+    pub is_draft: bool,
+    /// Optional latitude in WGS 84 decimal degrees, set together with longitude.
+    #[prost(double, optional, tag = "11")]
+    pub latitude: ::core::option::Option<f64>,
+    /// Optional longitude in WGS 84 decimal degrees, set together with latitude.
+    #[prost(double, optional, tag = "12")]
+    pub longitude: ::core::option::Option<f64>,
+    /// Optional name of the place the transaction was made, e.g. a shop or suburb.
+    #[prost(string, optional, tag = "13")]
+    pub place_name: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional last day the purchase can be returned, in ISO 8601 format
+    /// (YYYY-MM-DD). Must not be before the transaction date.
+    #[prost(string, optional, tag = "14")]
+    pub return_by: ::core::option::Option<::prost::alloc::string::String>,
+    /// Optional day the purchase's warranty expires, in ISO 8601 format
+    /// (YYYY-MM-DD). Must not be before the transaction date.
+    #[prost(string, optional, tag = "15")]
+    pub warranty_expires: ::core::option::Option<::prost::alloc::string::String>,
+    /// Lowercase tag names without the leading "#", sorted. Set on create and
+    /// replaced as a whole on update. Filled in by TransactionCreate,
+    /// TransactionGet, TransactionsList, TransactionUpdate,
+    /// TransactionsListByTag and the sample of TransactionsBulkEdit, and left
+    /// empty by the other RPCs.
+    #[prost(string, repeated, tag = "16")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// How far the transaction has been reconciled. Output only: ignored on
+    /// create and update, and changed with TransactionsMarkCleared and the
+    /// ReconciliationsService.
+    #[prost(enumeration = "ClearedStatus", tag = "17")]
+    pub cleared_status: i32,
+    /// Number of times the transaction has been written. Send back the version
+    /// read with an update, which fails with ABORTED if the transaction was
+    /// changed since. Zero updates whatever the stored version.
+    #[prost(int64, tag = "18")]
+    pub version: i64,
+    /// The parts the amount is split into across categories, in order. Either
+    /// empty for an unsplit transaction, or two or more splits whose amounts
+    /// sum to the transaction amount, which fails with INVALID_ARGUMENT
+    /// otherwise. Set on create and replaced as a whole on update. Filled in by
+    /// TransactionCreate, TransactionGet, TransactionsList, TransactionUpdate
+    /// and TransactionsListByTag, and left empty by the other RPCs.
+    #[prost(message, repeated, tag = "19")]
+    pub splits: ::prost::alloc::vec::Vec<Split>,
+}
+/// A transaction parsed from quick entry text, returned for confirmation
+/// before anything is saved.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionPreview {
+    /// Signed amount in minor units (cents). Negative for money spent,
+    /// positive for money received.
+    #[prost(int64, tag = "1")]
+    pub amount: i64,
+    /// The payee, built from the words that were not parsed as anything else.
+    #[prost(string, tag = "2")]
+    pub payee: ::prost::alloc::string::String,
+    /// The transaction date in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "3")]
+    pub date: ::prost::alloc::string::String,
+    /// Lowercase tag names without the leading "#".
+    #[prost(string, repeated, tag = "4")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Optional account name without the leading "@".
+    #[prost(string, optional, tag = "5")]
+    pub account: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Request to parse a line of quick entry text, e.g.
+/// "12.50 coffee yesterday #food @visa".
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionQuickAddRequest {
+    #[prost(string, tag = "1")]
+    pub text: ::prost::alloc::string::String,
+}
+/// Response containing the parsed transaction preview.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionQuickAddResponse {
+    #[prost(message, optional, tag = "1")]
+    pub preview: ::core::option::Option<TransactionPreview>,
+}
+/// Request to create a new transaction.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionCreateRequest {
+    /// The transaction to create (id and timestamps are ignored).
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::core::option::Option<Transaction>,
+    /// If true, validate the transaction and return it as it would be created,
+    /// without saving it (AIP-163 dry run).
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response containing the created transaction.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionCreateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::core::option::Option<Transaction>,
+}
+/// Request to fetch a transaction by its unique ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionGetRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Response containing the requested transaction.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionGetResponse {
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::core::option::Option<Transaction>,
+}
+/// Request to list transactions with pagination, newest first.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsListRequest {
+    /// The page token from the previous page's response, or empty for the
+    /// first page.
+    #[prost(string, tag = "4")]
+    pub page_token: ::prost::alloc::string::String,
+    /// The maximum number of transactions to return.
+    #[prost(int32, tag = "2")]
+    pub limit: i32,
+    /// If true, list only draft transactions, oldest first.
+    #[prost(bool, tag = "3")]
+    pub drafts_only: bool,
+}
+/// Response containing a list of transactions and pagination info.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsListResponse {
+    /// The list of transactions for the requested page.
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<Transaction>,
+    /// Total number of transactions (for pagination).
+    #[prost(int32, tag = "2")]
+    pub total_count: i32,
+    /// The token to request the next page with, or empty if this is the last
+    /// page.
+    #[prost(string, tag = "5")]
+    pub next_page_token: ::prost::alloc::string::String,
+    /// The limit used in the request.
+    #[prost(int32, tag = "4")]
+    pub limit: i32,
+}
+/// Request to stream every transaction in pages, newest first.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsStreamRequest {
+    /// The maximum number of transactions in each page. Defaults to 100 when
+    /// zero and is capped at 1000.
+    #[prost(int32, tag = "1")]
+    pub page_size: i32,
+}
+/// A single page of a transaction stream.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsStreamResponse {
+    /// The transactions in this page.
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<Transaction>,
+}
+/// Request to update an existing transaction.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionUpdateRequest {
+    /// The ID of the transaction to update.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The new transaction data (id and timestamps are ignored).
+    #[prost(message, optional, tag = "2")]
+    pub transaction: ::core::option::Option<Transaction>,
+    /// If true, validate the update and return the transaction as it would be
+    /// updated, without saving it.
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+    /// Optional field mask naming the fields to update, such as "payee" or
+    /// "splits", leaving the others as they are stored. Every field is updated
+    /// when the mask is unset or empty. Only client editable fields may be
+    /// named, others fail with INVALID_ARGUMENT. Stored splits must still sum
+    /// to the amount when only the amount is updated.
+    #[prost(message, optional, tag = "4")]
+    pub update_mask: ::core::option::Option<::prost_types::FieldMask>,
+}
+/// Response containing the updated transaction.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionUpdateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::core::option::Option<Transaction>,
+}
+/// Request to delete a transaction by ID.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionDeleteRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// If true, check the transaction can be deleted and return the would-be
+    /// row count, without deleting it.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response indicating how many rows were deleted: 1, or 2 when the
+/// transaction is part of a transfer, whose other transaction is deleted too.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionDeleteResponse {
+    #[prost(int32, tag = "1")]
+    pub rows_deleted: i32,
+}
+/// Request to post a batch of draft transactions to the ledger. Either every
+/// draft is posted or none are.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsPostDraftsRequest {
+    /// The IDs of the draft transactions to post.
+    #[prost(string, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// If true, check the drafts can be posted and return them as they would be
+    /// posted, without posting them.
+    #[prost(bool, tag = "2")]
+    pub validate_only: bool,
+}
+/// Response containing the posted transactions.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsPostDraftsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<Transaction>,
+}
+/// Request to tick off a batch of transactions as cleared against a
+/// statement, or set them back to uncleared. Either every transaction is
+/// marked or none are.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsMarkClearedRequest {
+    /// The IDs of the posted, unreconciled transactions to mark.
+    #[prost(string, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Whether the transactions are marked cleared, or uncleared.
+    #[prost(bool, tag = "2")]
+    pub cleared: bool,
+    /// If true, check the transactions can be marked and return them as they
+    /// would be marked, without marking them.
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+}
+/// Response containing the marked transactions.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsMarkClearedResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<Transaction>,
+}
+/// Request to roll back an import, deleting every transaction it created.
+/// Either all of them are deleted or none are.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsRollbackImportRequest {
+    /// The ID of the import batch to roll back.
+    #[prost(string, tag = "1")]
+    pub batch_id: ::prost::alloc::string::String,
+    /// If true, also delete transactions edited since they were imported.
+    /// Transactions linked to a reimbursement or receipt are never deleted.
+    #[prost(bool, tag = "2")]
+    pub force: bool,
+    /// If true, check the import can be rolled back and return the
+    /// transactions that would be deleted, without deleting them.
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+}
+/// Response containing the deleted transactions.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsRollbackImportResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<Transaction>,
+}
+/// Selects the transactions a bulk edit or reassignment applies to. Every
+/// field that is set must match, and at least one must be set.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionFilter {
+    /// Payee to match, ignoring case.
+    #[prost(string, optional, tag = "1")]
+    pub payee: ::core::option::Option<::prost::alloc::string::String>,
+    /// First transaction date to include in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, optional, tag = "2")]
+    pub from_date: ::core::option::Option<::prost::alloc::string::String>,
+    /// Last transaction date to include in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, optional, tag = "3")]
+    pub to_date: ::core::option::Option<::prost::alloc::string::String>,
+    /// ID (UUID) of the category the transactions are classified under.
+    #[prost(string, optional, tag = "4")]
+    pub category_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// ID (UUID) of the account the transactions are posted to.
+    #[prost(string, optional, tag = "5")]
+    pub account_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Payee pattern to match, ignoring case, where `*` matches any run of
+    /// characters (e.g., "AMZN\*").
+    #[prost(string, optional, tag = "6")]
+    pub payee_pattern: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// The changes a bulk edit makes to each matching transaction. At least one
+/// change must be set.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionChanges {
+    /// New payee for every transaction.
+    #[prost(string, optional, tag = "1")]
+    pub payee: ::core::option::Option<::prost::alloc::string::String>,
+    /// Number of days to move each transaction date, negative for earlier.
+    #[prost(int32, tag = "2")]
+    pub shift_days: i32,
+    /// Tags to give every transaction, keeping the tags it already has. Names
+    /// are lowercased without the leading "#", so "#Travel" and "travel" are
+    /// the same tag.
+    #[prost(string, repeated, tag = "3")]
+    pub add_tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Request to edit every transaction matching a filter.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsBulkEditRequest {
+    /// The transactions to edit.
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<TransactionFilter>,
+    /// The changes to make.
+    #[prost(message, optional, tag = "2")]
+    pub changes: ::core::option::Option<TransactionChanges>,
+    /// The number of edited transactions to return as a sample. Defaults to 10
+    /// when zero and is capped at 100.
+    #[prost(int32, tag = "3")]
+    pub sample_size: i32,
+    /// If true, return the number of matching transactions and a sample of them
+    /// as they would be edited, without changing anything.
+    #[prost(bool, tag = "4")]
+    pub validate_only: bool,
+}
+/// Response describing a bulk edit.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsBulkEditResponse {
+    /// The number of transactions matching the filter.
+    #[prost(int32, tag = "1")]
+    pub matched_count: i32,
+    /// The number of transactions edited, zero for a preview.
+    #[prost(int32, tag = "2")]
+    pub updated_count: i32,
+    /// The first matching transactions, oldest first, with the changes applied,
+    /// including their tags.
+    #[prost(message, repeated, tag = "3")]
+    pub sample: ::prost::alloc::vec::Vec<Transaction>,
+}
+/// Request to move every transaction matching a filter to another category.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsReassignRequest {
+    /// The transactions to move.
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<TransactionFilter>,
+    /// ID (UUID) of the category to move them to.
+    #[prost(string, tag = "2")]
+    pub category_id: ::prost::alloc::string::String,
+    /// If true, return the number of transactions that would be moved,
+    /// without moving them.
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+}
+/// Response describing a reassignment.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsReassignResponse {
+    /// The number of transactions moved to the category. Matching transactions
+    /// already in it are not counted.
+    #[prost(int32, tag = "1")]
+    pub reassigned_count: i32,
+}
+/// Request for the transactions in an area of the map, clustered for display.
+/// Areas crossing the antimeridian are not supported.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TransactionsMapDataRequest {
+    /// Southern edge of the area in decimal degrees.
+    #[prost(double, tag = "1")]
+    pub min_latitude: f64,
+    /// Western edge of the area in decimal degrees.
+    #[prost(double, tag = "2")]
+    pub min_longitude: f64,
+    /// Northern edge of the area in decimal degrees.
+    #[prost(double, tag = "3")]
+    pub max_latitude: f64,
+    /// Eastern edge of the area in decimal degrees.
+    #[prost(double, tag = "4")]
+    pub max_longitude: f64,
+    /// Width and height of a cluster cell in degrees. When zero, the area is
+    /// divided into 16 cells along its longer side.
+    #[prost(double, tag = "5")]
+    pub cell_size_degrees: f64,
+}
+/// The posted transactions within one cell of the map.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionMapCluster {
+    /// Mean latitude of the transactions in the cell.
+    #[prost(double, tag = "1")]
+    pub latitude: f64,
+    /// Mean longitude of the transactions in the cell.
+    #[prost(double, tag = "2")]
+    pub longitude: f64,
+    /// Number of transactions in the cell.
+    #[prost(int32, tag = "3")]
+    pub count: i32,
+    /// Sum of the transaction amounts in minor units (cents).
+    #[prost(int64, tag = "4")]
+    pub total_amount: i64,
+    /// The place name used most often in the cell, if any.
+    #[prost(string, optional, tag = "5")]
+    pub place_name: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Response containing the map clusters, ordered by cell.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsMapDataResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub clusters: ::prost::alloc::vec::Vec<TransactionMapCluster>,
+}
+/// Request for the purchases whose return window closes or warranty expires
+/// soon. Each kind has its own notice period.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsListExpiringRequest {
+    /// The day to remind from in ISO 8601 format (YYYY-MM-DD). Defaults to today.
+    #[prost(string, optional, tag = "1")]
+    pub on: ::core::option::Option<::prost::alloc::string::String>,
+    /// Days ahead to list closing return windows. Defaults to 7.
+    #[prost(uint32, optional, tag = "2")]
+    pub return_notice_days: ::core::option::Option<u32>,
+    /// Days ahead to list expiring warranties. Defaults to 30.
+    #[prost(uint32, optional, tag = "3")]
+    pub warranty_notice_days: ::core::option::Option<u32>,
+}
+/// A purchase whose return window or warranty ends soon.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionExpiry {
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::core::option::Option<Transaction>,
+    /// Whether the return window or the warranty is ending.
+    #[prost(enumeration = "ExpiryKinds", tag = "2")]
+    pub kind: i32,
+    /// The return by or warranty expiry date in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "3")]
+    pub due_on: ::prost::alloc::string::String,
+    /// Days from the requested day to `due_on`, zero on the day itself.
+    #[prost(int64, tag = "4")]
+    pub days_left: i64,
+}
+/// Response containing the expiring purchases, soonest first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsListExpiringResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reminders: ::prost::alloc::vec::Vec<TransactionExpiry>,
+}
+/// Request for every transaction with a tag.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsListByTagRequest {
+    /// The tag name, with or without the leading "#", in any case.
+    #[prost(string, tag = "1")]
+    pub tag: ::prost::alloc::string::String,
+}
+/// Response containing the tagged transactions, newest first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsListByTagResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<Transaction>,
+}
+/// Request to suggest tags as one is typed.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionTagsSuggestRequest {
+    /// The start of the tag typed so far, with or without the leading "#".
+    /// Empty suggests the most used tags.
+    #[prost(string, tag = "1")]
+    pub prefix: ::prost::alloc::string::String,
+    /// The most suggestions to return. Defaults to 10, and is capped at 50.
+    #[prost(uint32, optional, tag = "2")]
+    pub limit: ::core::option::Option<u32>,
+}
+/// A suggested tag and how many transactions use it.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TagSuggestion {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub transaction_count: i64,
+}
+/// Response containing the suggested tags, the most used first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionTagsSuggestResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub suggestions: ::prost::alloc::vec::Vec<TagSuggestion>,
+}
+/// Request to move money from one account to another.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsTransferRequest {
+    /// The ID (UUID) of the account the money leaves.
+    #[prost(string, tag = "1")]
+    pub from_account_id: ::prost::alloc::string::String,
+    /// The ID (UUID) of the account the money arrives in.
+    #[prost(string, tag = "2")]
+    pub to_account_id: ::prost::alloc::string::String,
+    /// The amount moved, in minor units (cents). Must be greater than zero.
+    #[prost(int64, tag = "3")]
+    pub amount: i64,
+    /// The date the money moves, in ISO 8601 format (YYYY-MM-DD).
+    #[prost(string, tag = "4")]
+    pub transaction_date: ::prost::alloc::string::String,
+    /// Optional markdown description given to both transactions.
+    #[prost(string, optional, tag = "5")]
+    pub description: ::core::option::Option<::prost::alloc::string::String>,
+    /// If true, validate the transfer and return its transactions as they would
+    /// be created, without saving them.
+    #[prost(bool, tag = "6")]
+    pub validate_only: bool,
+}
+/// Request to list probable duplicate transactions.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsListDuplicatesRequest {
+    /// The most days apart two transactions may be dated to be duplicates.
+    /// Defaults to 3 when unset, and is capped at 31.
+    #[prost(uint32, optional, tag = "1")]
+    pub window_days: ::core::option::Option<u32>,
+}
+/// Two transactions that are probably the same money movement recorded twice:
+/// the same account and amount on close dates, with similar payees.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DuplicatePair {
+    /// The transaction recorded first.
+    #[prost(message, optional, tag = "1")]
+    pub original: ::core::option::Option<Transaction>,
+    /// The transaction recorded later, probably a duplicate of the original.
+    #[prost(message, optional, tag = "2")]
+    pub duplicate: ::core::option::Option<Transaction>,
+    /// How alike the two payees are, from 0 to 1 for the same payee.
+    #[prost(double, tag = "3")]
+    pub similarity: f64,
+    /// Number of days between the two transaction dates.
+    #[prost(int32, tag = "4")]
+    pub days_apart: i32,
+}
+/// Response containing the probable duplicates, the most alike payees first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsListDuplicatesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub pairs: ::prost::alloc::vec::Vec<DuplicatePair>,
+}
+/// Request to merge a duplicate transaction into the one kept.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsMergeDuplicateRequest {
+    /// The ID (UUID) of the transaction to keep.
+    #[prost(string, tag = "1")]
+    pub keep_id: ::prost::alloc::string::String,
+    /// The ID (UUID) of the duplicate to merge into it and delete.
+    #[prost(string, tag = "2")]
+    pub duplicate_id: ::prost::alloc::string::String,
+    /// If true, validate the merge and return the kept transaction as it would
+    /// be merged, without saving it.
+    #[prost(bool, tag = "3")]
+    pub validate_only: bool,
+}
+/// Response containing the kept transaction as merged.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsMergeDuplicateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub transaction: ::core::option::Option<Transaction>,
+}
+/// Request to mark two transactions as not duplicates of each other.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsDismissDuplicateRequest {
+    /// The ID (UUID) of one of the transactions.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The ID (UUID) of the other transaction.
+    #[prost(string, tag = "2")]
+    pub other_id: ::prost::alloc::string::String,
+}
+/// Response to dismissing a pair of transactions as duplicates.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TransactionsDismissDuplicateResponse {}
+/// Response containing the two linked transactions of a transfer.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsTransferResponse {
+    /// The ID (UUID) of the transfer linking the transactions.
+    #[prost(string, tag = "1")]
+    pub transfer_id: ::prost::alloc::string::String,
+    /// The transaction taking the amount out of the from account, with a
+    /// negative amount.
+    #[prost(message, optional, tag = "2")]
+    pub from_transaction: ::core::option::Option<Transaction>,
+    /// The transaction paying the amount into the to account.
+    #[prost(message, optional, tag = "3")]
+    pub to_transaction: ::core::option::Option<Transaction>,
+}
+/// Enum representing what is ending for a purchase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ExpiryKinds {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// The last day the purchase can be returned.
+    Return = 1,
+    /// The day the purchase's warranty expires.
+    Warranty = 2,
+}
+impl ExpiryKinds {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "EXPIRY_KINDS_UNSPECIFIED",
+            Self::Return => "EXPIRY_KINDS_RETURN",
+            Self::Warranty => "EXPIRY_KINDS_WARRANTY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EXPIRY_KINDS_UNSPECIFIED" => Some(Self::Unspecified),
+            "EXPIRY_KINDS_RETURN" => Some(Self::Return),
+            "EXPIRY_KINDS_WARRANTY" => Some(Self::Warranty),
+            _ => None,
+        }
+    }
+}
+/// Enum representing how far a transaction has been checked against its
+/// account's statements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ClearedStatus {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// Not yet seen on a statement.
+    Uncleared = 1,
+    /// Seen on a statement, waiting to be reconciled.
+    Cleared = 2,
+    /// Included in a reconciliation. The amount, date and account cannot be
+    /// changed, nor the transaction deleted, until the reconciliation is undone.
+    Reconciled = 3,
+}
+impl ClearedStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "CLEARED_STATUS_UNSPECIFIED",
+            Self::Uncleared => "CLEARED_STATUS_UNCLEARED",
+            Self::Cleared => "CLEARED_STATUS_CLEARED",
+            Self::Reconciled => "CLEARED_STATUS_RECONCILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CLEARED_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "CLEARED_STATUS_UNCLEARED" => Some(Self::Uncleared),
+            "CLEARED_STATUS_CLEARED" => Some(Self::Cleared),
+            "CLEARED_STATUS_RECONCILED" => Some(Self::Reconciled),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod transactions_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// gRPC service for managing financial transactions.
+    /// Provides CRUD, listing, draft posting, import rollback, bulk editing,
+    /// reassignment, map, expiry, tagging, quick entry, transfer and duplicate
+    /// operations.
+    #[derive(Debug, Clone)]
+    pub struct TransactionsServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl TransactionsServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> TransactionsServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> TransactionsServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            TransactionsServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Create a new transaction.
+        pub async fn transaction_create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionCreateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionCreate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionCreate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get a transaction by its unique ID.
+        pub async fn transaction_get(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionGetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionGetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionGet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionGet",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List transactions with pagination, newest first.
+        pub async fn transactions_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsList",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsList",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Stream every transaction in pages, for lists too large for one response.
+        pub async fn transactions_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsStreamRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::TransactionsStreamResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsStream",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsStream",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Update an existing transaction.
+        pub async fn transaction_update(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionUpdateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionUpdate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionUpdate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Delete a transaction by ID.
+        pub async fn transaction_delete(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionDeleteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionDelete",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionDelete",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Post a batch of draft transactions to the ledger.
+        pub async fn transactions_post_drafts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsPostDraftsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsPostDraftsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsPostDrafts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsPostDrafts",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Tick off a batch of transactions as cleared, or set them back to
+        /// uncleared.
+        pub async fn transactions_mark_cleared(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsMarkClearedRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsMarkClearedResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsMarkCleared",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsMarkCleared",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Roll back an import, deleting the transactions it created.
+        pub async fn transactions_rollback_import(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsRollbackImportRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsRollbackImportResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsRollbackImport",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsRollbackImport",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Preview or apply the same change to every transaction matching a filter.
+        pub async fn transactions_bulk_edit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsBulkEditRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsBulkEditResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsBulkEdit",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsBulkEdit",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Move every transaction matching a filter to another category, all or
+        /// nothing.
+        pub async fn transactions_reassign(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsReassignRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsReassignResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsReassign",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsReassign",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Cluster the transactions in an area of the map.
+        pub async fn transactions_map_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsMapDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsMapDataResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsMapData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsMapData",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List purchases whose return window or warranty is about to end.
+        pub async fn transactions_list_expiring(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsListExpiringRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsListExpiringResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsListExpiring",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsListExpiring",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List every transaction with a tag.
+        pub async fn transactions_list_by_tag(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsListByTagRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsListByTagResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsListByTag",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsListByTag",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Suggest tags starting with what has been typed, the most used first.
+        pub async fn transaction_tags_suggest(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionTagsSuggestRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionTagsSuggestResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionTagsSuggest",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionTagsSuggest",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Parse quick entry text into a transaction preview for confirmation.
+        pub async fn transaction_quick_add(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionQuickAddRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionQuickAddResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionQuickAdd",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionQuickAdd",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Move money between two accounts as a linked pair of transactions, created
+        /// together. Updating the amount, date, description or draft flag of either
+        /// updates the other to match, and deleting either deletes both.
+        pub async fn transactions_transfer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsTransferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsTransferResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsTransfer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsTransfer",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// List probable duplicate transactions, left out once merged or dismissed.
+        pub async fn transactions_list_duplicates(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsListDuplicatesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsListDuplicatesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsListDuplicates",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsListDuplicates",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Merge a duplicate into the transaction kept, filling in the kept
+        /// transaction's empty fields and moving its tags and attachments, then
+        /// delete the duplicate.
+        pub async fn transactions_merge_duplicate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsMergeDuplicateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsMergeDuplicateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsMergeDuplicate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsMergeDuplicate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Mark two transactions as not duplicates, so they are no longer listed.
+        pub async fn transactions_dismiss_duplicate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionsDismissDuplicateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsDismissDuplicateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsDismissDuplicate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.transactions.v001.TransactionsService",
+                        "TransactionsDismissDuplicate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod transactions_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with TransactionsServiceServer.
+    #[async_trait]
+    pub trait TransactionsService: std::marker::Send + std::marker::Sync + 'static {
+        /// Create a new transaction.
+        async fn transaction_create(
+            &self,
+            request: tonic::Request<super::TransactionCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionCreateResponse>,
+            tonic::Status,
+        >;
+        /// Get a transaction by its unique ID.
+        async fn transaction_get(
+            &self,
+            request: tonic::Request<super::TransactionGetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionGetResponse>,
+            tonic::Status,
+        >;
+        /// List transactions with pagination, newest first.
+        async fn transactions_list(
+            &self,
+            request: tonic::Request<super::TransactionsListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsListResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the TransactionsStream method.
+        type TransactionsStreamStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::TransactionsStreamResponse,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Stream every transaction in pages, for lists too large for one response.
+        async fn transactions_stream(
+            &self,
+            request: tonic::Request<super::TransactionsStreamRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::TransactionsStreamStream>,
+            tonic::Status,
+        >;
+        /// Update an existing transaction.
+        async fn transaction_update(
+            &self,
+            request: tonic::Request<super::TransactionUpdateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionUpdateResponse>,
+            tonic::Status,
+        >;
+        /// Delete a transaction by ID.
+        async fn transaction_delete(
+            &self,
+            request: tonic::Request<super::TransactionDeleteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionDeleteResponse>,
+            tonic::Status,
+        >;
+        /// Post a batch of draft transactions to the ledger.
+        async fn transactions_post_drafts(
+            &self,
+            request: tonic::Request<super::TransactionsPostDraftsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsPostDraftsResponse>,
+            tonic::Status,
+        >;
+        /// Tick off a batch of transactions as cleared, or set them back to
+        /// uncleared.
+        async fn transactions_mark_cleared(
+            &self,
+            request: tonic::Request<super::TransactionsMarkClearedRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsMarkClearedResponse>,
+            tonic::Status,
+        >;
+        /// Roll back an import, deleting the transactions it created.
+        async fn transactions_rollback_import(
+            &self,
+            request: tonic::Request<super::TransactionsRollbackImportRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsRollbackImportResponse>,
+            tonic::Status,
+        >;
+        /// Preview or apply the same change to every transaction matching a filter.
+        async fn transactions_bulk_edit(
+            &self,
+            request: tonic::Request<super::TransactionsBulkEditRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsBulkEditResponse>,
+            tonic::Status,
+        >;
+        /// Move every transaction matching a filter to another category, all or
+        /// nothing.
+        async fn transactions_reassign(
+            &self,
+            request: tonic::Request<super::TransactionsReassignRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsReassignResponse>,
+            tonic::Status,
+        >;
+        /// Cluster the transactions in an area of the map.
+        async fn transactions_map_data(
+            &self,
+            request: tonic::Request<super::TransactionsMapDataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsMapDataResponse>,
+            tonic::Status,
+        >;
+        /// List purchases whose return window or warranty is about to end.
+        async fn transactions_list_expiring(
+            &self,
+            request: tonic::Request<super::TransactionsListExpiringRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsListExpiringResponse>,
+            tonic::Status,
+        >;
+        /// List every transaction with a tag.
+        async fn transactions_list_by_tag(
+            &self,
+            request: tonic::Request<super::TransactionsListByTagRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsListByTagResponse>,
+            tonic::Status,
+        >;
+        /// Suggest tags starting with what has been typed, the most used first.
+        async fn transaction_tags_suggest(
+            &self,
+            request: tonic::Request<super::TransactionTagsSuggestRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionTagsSuggestResponse>,
+            tonic::Status,
+        >;
+        /// Parse quick entry text into a transaction preview for confirmation.
+        async fn transaction_quick_add(
+            &self,
+            request: tonic::Request<super::TransactionQuickAddRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionQuickAddResponse>,
+            tonic::Status,
+        >;
+        /// Move money between two accounts as a linked pair of transactions, created
+        /// together. Updating the amount, date, description or draft flag of either
+        /// updates the other to match, and deleting either deletes both.
+        async fn transactions_transfer(
+            &self,
+            request: tonic::Request<super::TransactionsTransferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsTransferResponse>,
+            tonic::Status,
+        >;
+        /// List probable duplicate transactions, left out once merged or dismissed.
+        async fn transactions_list_duplicates(
+            &self,
+            request: tonic::Request<super::TransactionsListDuplicatesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsListDuplicatesResponse>,
+            tonic::Status,
+        >;
+        /// Merge a duplicate into the transaction kept, filling in the kept
+        /// transaction's empty fields and moving its tags and attachments, then
+        /// delete the duplicate.
+        async fn transactions_merge_duplicate(
+            &self,
+            request: tonic::Request<super::TransactionsMergeDuplicateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsMergeDuplicateResponse>,
+            tonic::Status,
+        >;
+        /// Mark two transactions as not duplicates, so they are no longer listed.
+        async fn transactions_dismiss_duplicate(
+            &self,
+            request: tonic::Request<super::TransactionsDismissDuplicateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionsDismissDuplicateResponse>,
+            tonic::Status,
+        >;
+    }
+    /// gRPC service for managing financial transactions.
+    /// Provides CRUD, listing, draft posting, import rollback, bulk editing,
+    /// reassignment, map, expiry, tagging, quick entry, transfer and duplicate
+    /// operations.
+    #[derive(Debug)]
+    pub struct TransactionsServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> TransactionsServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for TransactionsServiceServer<T>
+    where
+        T: TransactionsService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionCreate" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionCreateSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionCreateRequest>
+                    for TransactionCreateSvc<T> {
+                        type Response = super::TransactionCreateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionCreateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transaction_create(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionCreateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionGet" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionGetSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionGetRequest>
+                    for TransactionGetSvc<T> {
+                        type Response = super::TransactionGetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionGetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transaction_get(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionGetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsList" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsListSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionsListRequest>
+                    for TransactionsListSvc<T> {
+                        type Response = super::TransactionsListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionsListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_list(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsListSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsStreamSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::ServerStreamingService<
+                        super::TransactionsStreamRequest,
+                    > for TransactionsStreamSvc<T> {
+                        type Response = super::TransactionsStreamResponse;
+                        type ResponseStream = T::TransactionsStreamStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionsStreamRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_stream(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsStreamSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionUpdate" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionUpdateSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionUpdateRequest>
+                    for TransactionUpdateSvc<T> {
+                        type Response = super::TransactionUpdateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionUpdateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transaction_update(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionUpdateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionDelete" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionDeleteSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionDeleteRequest>
+                    for TransactionDeleteSvc<T> {
+                        type Response = super::TransactionDeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionDeleteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transaction_delete(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionDeleteSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsPostDrafts" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsPostDraftsSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionsPostDraftsRequest>
+                    for TransactionsPostDraftsSvc<T> {
+                        type Response = super::TransactionsPostDraftsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionsPostDraftsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_post_drafts(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsPostDraftsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsMarkCleared" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsMarkClearedSvc<T: TransactionsService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionsMarkClearedRequest>
+                    for TransactionsMarkClearedSvc<T> {
+                        type Response = super::TransactionsMarkClearedResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::TransactionsMarkClearedRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_mark_cleared(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsMarkClearedSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsRollbackImport" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsRollbackImportSvc<T: TransactionsService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<
+                        super::TransactionsRollbackImportRequest,
+                    > for TransactionsRollbackImportSvc<T> {
+                        type Response = super::TransactionsRollbackImportResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::TransactionsRollbackImportRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_rollback_import(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsRollbackImportSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsBulkEdit" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsBulkEditSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionsBulkEditRequest>
+                    for TransactionsBulkEditSvc<T> {
+                        type Response = super::TransactionsBulkEditResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionsBulkEditRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_bulk_edit(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsBulkEditSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsReassign" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsReassignSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionsReassignRequest>
+                    for TransactionsReassignSvc<T> {
+                        type Response = super::TransactionsReassignResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionsReassignRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_reassign(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsReassignSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsMapData" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsMapDataSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionsMapDataRequest>
+                    for TransactionsMapDataSvc<T> {
+                        type Response = super::TransactionsMapDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionsMapDataRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_map_data(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsMapDataSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsListExpiring" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsListExpiringSvc<T: TransactionsService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionsListExpiringRequest>
+                    for TransactionsListExpiringSvc<T> {
+                        type Response = super::TransactionsListExpiringResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::TransactionsListExpiringRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_list_expiring(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsListExpiringSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsListByTag" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsListByTagSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionsListByTagRequest>
+                    for TransactionsListByTagSvc<T> {
+                        type Response = super::TransactionsListByTagResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionsListByTagRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_list_by_tag(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsListByTagSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionTagsSuggest" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionTagsSuggestSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionTagsSuggestRequest>
+                    for TransactionTagsSuggestSvc<T> {
+                        type Response = super::TransactionTagsSuggestResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionTagsSuggestRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transaction_tags_suggest(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionTagsSuggestSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionQuickAdd" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionQuickAddSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionQuickAddRequest>
+                    for TransactionQuickAddSvc<T> {
+                        type Response = super::TransactionQuickAddResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionQuickAddRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transaction_quick_add(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionQuickAddSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsTransfer" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsTransferSvc<T: TransactionsService>(pub Arc<T>);
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<super::TransactionsTransferRequest>
+                    for TransactionsTransferSvc<T> {
+                        type Response = super::TransactionsTransferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionsTransferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_transfer(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsTransferSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsListDuplicates" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsListDuplicatesSvc<T: TransactionsService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<
+                        super::TransactionsListDuplicatesRequest,
+                    > for TransactionsListDuplicatesSvc<T> {
+                        type Response = super::TransactionsListDuplicatesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::TransactionsListDuplicatesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_list_duplicates(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsListDuplicatesSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsMergeDuplicate" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsMergeDuplicateSvc<T: TransactionsService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<
+                        super::TransactionsMergeDuplicateRequest,
+                    > for TransactionsMergeDuplicateSvc<T> {
+                        type Response = super::TransactionsMergeDuplicateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::TransactionsMergeDuplicateRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_merge_duplicate(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsMergeDuplicateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.transactions.v001.TransactionsService/TransactionsDismissDuplicate" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsDismissDuplicateSvc<T: TransactionsService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: TransactionsService,
+                    > tonic::server::UnaryService<
+                        super::TransactionsDismissDuplicateRequest,
+                    > for TransactionsDismissDuplicateSvc<T> {
+                        type Response = super::TransactionsDismissDuplicateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::TransactionsDismissDuplicateRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionsService>::transactions_dismiss_duplicate(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TransactionsDismissDuplicateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(
+                            tonic::body::Body::default(),
+                        );
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for TransactionsServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "personal_ledger.transactions.v001.TransactionsService";
+    impl<T> tonic::server::NamedService for TransactionsServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
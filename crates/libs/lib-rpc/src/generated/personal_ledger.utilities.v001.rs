@@ -9,6 +9,334 @@ pub struct PingResponse {
     #[prost(string, tag = "1")]
     pub message: ::prost::alloc::string::String,
 }
+/// The state of one database migration.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Migration {
+    /// The migration's version, in the order migrations are applied.
+    #[prost(int64, tag = "1")]
+    pub version: i64,
+    /// What the migration does.
+    #[prost(string, tag = "2")]
+    pub description: ::prost::alloc::string::String,
+    /// When the migration was applied (UTC). Unset while it is pending.
+    #[prost(message, optional, tag = "3")]
+    pub applied_on: ::core::option::Option<::prost_types::Timestamp>,
+    /// Whether the migration was changed after it was applied, so the database
+    /// no longer matches it.
+    #[prost(bool, tag = "4")]
+    pub is_modified: bool,
+}
+/// This message is intentionally left empty
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MigrationStatusRequest {}
+/// The response message containing the state of every migration.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MigrationStatusResponse {
+    /// Every migration, in version order.
+    #[prost(message, repeated, tag = "1")]
+    pub migrations: ::prost::alloc::vec::Vec<Migration>,
+    /// The version of the latest applied migration, 0 when none are applied.
+    #[prost(int64, tag = "2")]
+    pub current_version: i64,
+    /// The number of migrations not yet applied.
+    #[prost(int32, tag = "3")]
+    pub pending_count: i32,
+}
+/// A version of the API the server serves, the last part of the proto
+/// package names, e.g. "v001" for personal_ledger.categories.v001.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiVersionInfo {
+    /// The version name, e.g. "v001".
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Whether the version is current, supported or deprecated.
+    #[prost(enumeration = "ApiVersionStates", tag = "2")]
+    pub state: i32,
+    /// The date a deprecated version stops being served, in ISO 8601 format
+    /// (YYYY-MM-DD). Unset for versions that are not deprecated.
+    #[prost(string, optional, tag = "3")]
+    pub sunset_on: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ServerInfoRequest {
+    /// The API versions the client was built against, in any order. Empty to
+    /// only ask for the server's information.
+    #[prost(string, repeated, tag = "1")]
+    pub api_versions: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// The response message containing the server's version and API versions.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ServerInfoResponse {
+    /// The server's release version, e.g. "0.1.0".
+    #[prost(string, tag = "1")]
+    pub server_version: ::prost::alloc::string::String,
+    /// The newest API version both the client and server support, or the
+    /// server's current version when the client sent none.
+    #[prost(string, tag = "2")]
+    pub api_version: ::prost::alloc::string::String,
+    /// Every API version the server serves, newest first.
+    #[prost(message, repeated, tag = "3")]
+    pub api_versions: ::prost::alloc::vec::Vec<ApiVersionInfo>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BackupRequest {
+    /// The backup's file name in the server's backup directory, without any
+    /// directories. Defaults to "personal_ledger-<UTC timestamp>.db". The file
+    /// must not already exist.
+    #[prost(string, optional, tag = "1")]
+    pub file_name: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// The response message describing the backup written.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BackupResponse {
+    /// The backup's path on the server.
+    #[prost(string, tag = "1")]
+    pub path: ::prost::alloc::string::String,
+    /// Size of the backup file in bytes.
+    #[prost(int64, tag = "2")]
+    pub size_bytes: i64,
+    /// The newest migration applied to the backed up database.
+    #[prost(int64, tag = "3")]
+    pub migration_version: i64,
+    /// When the backup was taken (UTC).
+    #[prost(message, optional, tag = "4")]
+    pub created_on: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// This message is intentionally left empty
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetUsageRequest {}
+/// How much of one resource the ledger uses.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ResourceUsage {
+    /// The amount used.
+    #[prost(int64, tag = "1")]
+    pub used: i64,
+    /// The most the ledger may use, unset when there is no limit. Requests
+    /// adding more than this are refused with RESOURCE_EXHAUSTED.
+    #[prost(int64, optional, tag = "2")]
+    pub limit: ::core::option::Option<i64>,
+}
+/// The response message containing what the ledger holds.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetUsageResponse {
+    /// Transactions, drafts included.
+    #[prost(message, optional, tag = "1")]
+    pub transactions: ::core::option::Option<ResourceUsage>,
+    /// Categories, not counting soft-deleted ones.
+    #[prost(message, optional, tag = "2")]
+    pub categories: ::core::option::Option<ResourceUsage>,
+    /// Bytes of receipt attachments stored.
+    #[prost(message, optional, tag = "3")]
+    pub attachment_bytes: ::core::option::Option<ResourceUsage>,
+}
+/// This message is intentionally left empty
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct VerifyLedgerRequest {}
+/// One inconsistency found in the ledger.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LedgerFinding {
+    /// The check that found it.
+    #[prost(enumeration = "LedgerChecks", tag = "1")]
+    pub check: i32,
+    /// The ID of the journal entry, account, category, transaction or
+    /// attachment at fault.
+    #[prost(string, tag = "2")]
+    pub record_id: ::prost::alloc::string::String,
+    /// What is wrong with it.
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
+}
+/// The response message containing what checking the ledger found.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerifyLedgerResponse {
+    /// Every inconsistency found, grouped by check. Empty when the ledger is
+    /// consistent.
+    #[prost(message, repeated, tag = "1")]
+    pub findings: ::prost::alloc::vec::Vec<LedgerFinding>,
+    /// The checks that ran.
+    #[prost(enumeration = "LedgerChecks", repeated, tag = "2")]
+    pub checks: ::prost::alloc::vec::Vec<i32>,
+    /// When the checks ran (UTC).
+    #[prost(message, optional, tag = "3")]
+    pub checked_on: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// This message is intentionally left empty
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RoundTripExportRequest {}
+/// One figure, from the ledger and from the copy read back from its export.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RoundTripCheck {
+    /// What was compared.
+    #[prost(enumeration = "RoundTripChecks", tag = "1")]
+    pub check: i32,
+    /// The ID of the account a balance is for. Unset for figures of the whole
+    /// ledger.
+    #[prost(string, optional, tag = "2")]
+    pub record_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// The figure in the ledger.
+    #[prost(int64, tag = "3")]
+    pub exported: i64,
+    /// The figure in the copy read back from the export.
+    #[prost(int64, tag = "4")]
+    pub imported: i64,
+}
+/// The response message containing the figures compared.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RoundTripExportResponse {
+    /// Every figure compared, the counts and totals first, then the balance of
+    /// each account.
+    #[prost(message, repeated, tag = "1")]
+    pub checks: ::prost::alloc::vec::Vec<RoundTripCheck>,
+    /// Whether every figure in the copy matches the ledger.
+    #[prost(bool, tag = "2")]
+    pub is_intact: bool,
+    /// When the round trip ran (UTC).
+    #[prost(message, optional, tag = "3")]
+    pub checked_on: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetLogLevelRequest {
+    /// A level, one of "trace", "debug", "info", "warn", "error" or "off", or
+    /// tracing directives as RUST_LOG takes, such as
+    /// "info,lib_database=trace".
+    #[prost(string, tag = "1")]
+    pub filter: ::prost::alloc::string::String,
+}
+/// The response message containing the filter replaced.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetLogLevelResponse {
+    /// The filter in effect before, to send back to undo the change.
+    #[prost(string, tag = "1")]
+    pub previous_filter: ::prost::alloc::string::String,
+    /// The filter now in effect.
+    #[prost(string, tag = "2")]
+    pub filter: ::prost::alloc::string::String,
+}
+/// Whether a version of the API is current, or on its way out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ApiVersionStates {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// The newest version, new clients should use it.
+    Current = 1,
+    /// An older version that is still served and not yet scheduled for removal.
+    Supported = 2,
+    /// An older version that will be removed after its sunset date.
+    Deprecated = 3,
+}
+impl ApiVersionStates {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "API_VERSION_STATES_UNSPECIFIED",
+            Self::Current => "API_VERSION_STATES_CURRENT",
+            Self::Supported => "API_VERSION_STATES_SUPPORTED",
+            Self::Deprecated => "API_VERSION_STATES_DEPRECATED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "API_VERSION_STATES_UNSPECIFIED" => Some(Self::Unspecified),
+            "API_VERSION_STATES_CURRENT" => Some(Self::Current),
+            "API_VERSION_STATES_SUPPORTED" => Some(Self::Supported),
+            "API_VERSION_STATES_DEPRECATED" => Some(Self::Deprecated),
+            _ => None,
+        }
+    }
+}
+/// The invariants VerifyLedger checks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LedgerChecks {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// Every journal entry has at least two splits, and they sum to zero.
+    JournalBalance = 1,
+    /// Every account's balance matches the balance replayed from its history,
+    /// which reports as recorded rely on.
+    AccountBalance = 2,
+    /// Every category and transaction is in the search index with its current
+    /// text, and the index holds nothing else.
+    SearchIndex = 3,
+    /// No receipt attachment or email points at a row that no longer exists.
+    ReceiptAttachments = 4,
+}
+impl LedgerChecks {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "LEDGER_CHECKS_UNSPECIFIED",
+            Self::JournalBalance => "LEDGER_CHECKS_JOURNAL_BALANCE",
+            Self::AccountBalance => "LEDGER_CHECKS_ACCOUNT_BALANCE",
+            Self::SearchIndex => "LEDGER_CHECKS_SEARCH_INDEX",
+            Self::ReceiptAttachments => "LEDGER_CHECKS_RECEIPT_ATTACHMENTS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LEDGER_CHECKS_UNSPECIFIED" => Some(Self::Unspecified),
+            "LEDGER_CHECKS_JOURNAL_BALANCE" => Some(Self::JournalBalance),
+            "LEDGER_CHECKS_ACCOUNT_BALANCE" => Some(Self::AccountBalance),
+            "LEDGER_CHECKS_SEARCH_INDEX" => Some(Self::SearchIndex),
+            "LEDGER_CHECKS_RECEIPT_ATTACHMENTS" => Some(Self::ReceiptAttachments),
+            _ => None,
+        }
+    }
+}
+/// The figures RoundTripExport compares.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RoundTripChecks {
+    /// Default value. Should not be used.
+    Unspecified = 0,
+    /// The number of categories, soft-deleted categories included.
+    CategoryCount = 1,
+    /// The number of accounts.
+    AccountCount = 2,
+    /// The number of transactions, drafts included.
+    TransactionCount = 3,
+    /// The sum of every transaction amount, in cents.
+    TransactionTotal = 4,
+    /// The balance of one account, in cents.
+    AccountBalance = 5,
+}
+impl RoundTripChecks {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "ROUND_TRIP_CHECKS_UNSPECIFIED",
+            Self::CategoryCount => "ROUND_TRIP_CHECKS_CATEGORY_COUNT",
+            Self::AccountCount => "ROUND_TRIP_CHECKS_ACCOUNT_COUNT",
+            Self::TransactionCount => "ROUND_TRIP_CHECKS_TRANSACTION_COUNT",
+            Self::TransactionTotal => "ROUND_TRIP_CHECKS_TRANSACTION_TOTAL",
+            Self::AccountBalance => "ROUND_TRIP_CHECKS_ACCOUNT_BALANCE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ROUND_TRIP_CHECKS_UNSPECIFIED" => Some(Self::Unspecified),
+            "ROUND_TRIP_CHECKS_CATEGORY_COUNT" => Some(Self::CategoryCount),
+            "ROUND_TRIP_CHECKS_ACCOUNT_COUNT" => Some(Self::AccountCount),
+            "ROUND_TRIP_CHECKS_TRANSACTION_COUNT" => Some(Self::TransactionCount),
+            "ROUND_TRIP_CHECKS_TRANSACTION_TOTAL" => Some(Self::TransactionTotal),
+            "ROUND_TRIP_CHECKS_ACCOUNT_BALANCE" => Some(Self::AccountBalance),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod utilities_service_client {
     #![allow(
@@ -101,6 +429,10 @@ pub mod utilities_service_client {
             self.inner = self.inner.max_encoding_message_size(limit);
             self
         }
+        /// Deprecated: check the standard grpc.health.v1.Health service instead,
+        /// which reports whether the database is reachable and migrated. Kept so
+        /// existing v001 clients still get a pong.
+        #[deprecated]
         pub async fn ping(
             &mut self,
             request: impl tonic::IntoRequest<super::PingRequest>,
@@ -127,6 +459,222 @@ pub mod utilities_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Get the state of every database migration the server was built with.
+        pub async fn migration_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MigrationStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MigrationStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.utilities.v001.UtilitiesService/MigrationStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.utilities.v001.UtilitiesService",
+                        "MigrationStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the server's version and the API versions it serves, agreeing on the
+        /// newest version both the client and server support.
+        pub async fn server_info(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ServerInfoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ServerInfoResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.utilities.v001.UtilitiesService/ServerInfo",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.utilities.v001.UtilitiesService",
+                        "ServerInfo",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Write a consistent snapshot of the database to the server's backup
+        /// directory, while the server keeps running.
+        pub async fn backup(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BackupRequest>,
+        ) -> std::result::Result<tonic::Response<super::BackupResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.utilities.v001.UtilitiesService/Backup",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.utilities.v001.UtilitiesService",
+                        "Backup",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get what the ledger holds, against the server's configured quotas.
+        pub async fn get_usage(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetUsageRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUsageResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.utilities.v001.UtilitiesService/GetUsage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.utilities.v001.UtilitiesService",
+                        "GetUsage",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Check the whole ledger for inconsistencies the database cannot prevent,
+        /// such as an unbalanced journal entry or a stale search index. Nothing is
+        /// repaired. Every check runs before the report is sent, which can take a
+        /// while on a large ledger.
+        pub async fn verify_ledger(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VerifyLedgerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyLedgerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.utilities.v001.UtilitiesService/VerifyLedger",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.utilities.v001.UtilitiesService",
+                        "VerifyLedger",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Export every category, account and transaction, read the export back
+        /// into an empty database and compare record counts and balances, to check
+        /// an export holds the whole ledger. Nothing is written to the ledger.
+        pub async fn round_trip_export(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RoundTripExportRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RoundTripExportResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.utilities.v001.UtilitiesService/RoundTripExport",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.utilities.v001.UtilitiesService",
+                        "RoundTripExport",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Change which logs and spans the server records, without restarting it,
+        /// such as turning up to trace to look into an issue and back down again.
+        /// The change lasts until it is changed again or the server restarts.
+        pub async fn set_log_level(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetLogLevelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetLogLevelResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/personal_ledger.utilities.v001.UtilitiesService/SetLogLevel",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "personal_ledger.utilities.v001.UtilitiesService",
+                        "SetLogLevel",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -142,10 +690,75 @@ pub mod utilities_service_server {
     /// Generated trait containing gRPC methods that should be implemented for use with UtilitiesServiceServer.
     #[async_trait]
     pub trait UtilitiesService: std::marker::Send + std::marker::Sync + 'static {
+        /// Deprecated: check the standard grpc.health.v1.Health service instead,
+        /// which reports whether the database is reachable and migrated. Kept so
+        /// existing v001 clients still get a pong.
         async fn ping(
             &self,
             request: tonic::Request<super::PingRequest>,
         ) -> std::result::Result<tonic::Response<super::PingResponse>, tonic::Status>;
+        /// Get the state of every database migration the server was built with.
+        async fn migration_status(
+            &self,
+            request: tonic::Request<super::MigrationStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MigrationStatusResponse>,
+            tonic::Status,
+        >;
+        /// Get the server's version and the API versions it serves, agreeing on the
+        /// newest version both the client and server support.
+        async fn server_info(
+            &self,
+            request: tonic::Request<super::ServerInfoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ServerInfoResponse>,
+            tonic::Status,
+        >;
+        /// Write a consistent snapshot of the database to the server's backup
+        /// directory, while the server keeps running.
+        async fn backup(
+            &self,
+            request: tonic::Request<super::BackupRequest>,
+        ) -> std::result::Result<tonic::Response<super::BackupResponse>, tonic::Status>;
+        /// Get what the ledger holds, against the server's configured quotas.
+        async fn get_usage(
+            &self,
+            request: tonic::Request<super::GetUsageRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUsageResponse>,
+            tonic::Status,
+        >;
+        /// Check the whole ledger for inconsistencies the database cannot prevent,
+        /// such as an unbalanced journal entry or a stale search index. Nothing is
+        /// repaired. Every check runs before the report is sent, which can take a
+        /// while on a large ledger.
+        async fn verify_ledger(
+            &self,
+            request: tonic::Request<super::VerifyLedgerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyLedgerResponse>,
+            tonic::Status,
+        >;
+        /// Export every category, account and transaction, read the export back
+        /// into an empty database and compare record counts and balances, to check
+        /// an export holds the whole ledger. Nothing is written to the ledger.
+        async fn round_trip_export(
+            &self,
+            request: tonic::Request<super::RoundTripExportRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RoundTripExportResponse>,
+            tonic::Status,
+        >;
+        /// Change which logs and spans the server records, without restarting it,
+        /// such as turning up to trace to look into an issue and back down again.
+        /// The change lasts until it is changed again or the server restarts.
+        async fn set_log_level(
+            &self,
+            request: tonic::Request<super::SetLogLevelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetLogLevelResponse>,
+            tonic::Status,
+        >;
     }
     /// The Utilities service definition.
     #[derive(Debug)]
@@ -268,6 +881,325 @@ pub mod utilities_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/personal_ledger.utilities.v001.UtilitiesService/MigrationStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct MigrationStatusSvc<T: UtilitiesService>(pub Arc<T>);
+                    impl<
+                        T: UtilitiesService,
+                    > tonic::server::UnaryService<super::MigrationStatusRequest>
+                    for MigrationStatusSvc<T> {
+                        type Response = super::MigrationStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MigrationStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UtilitiesService>::migration_status(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MigrationStatusSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.utilities.v001.UtilitiesService/ServerInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct ServerInfoSvc<T: UtilitiesService>(pub Arc<T>);
+                    impl<
+                        T: UtilitiesService,
+                    > tonic::server::UnaryService<super::ServerInfoRequest>
+                    for ServerInfoSvc<T> {
+                        type Response = super::ServerInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ServerInfoRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UtilitiesService>::server_info(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ServerInfoSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.utilities.v001.UtilitiesService/Backup" => {
+                    #[allow(non_camel_case_types)]
+                    struct BackupSvc<T: UtilitiesService>(pub Arc<T>);
+                    impl<
+                        T: UtilitiesService,
+                    > tonic::server::UnaryService<super::BackupRequest>
+                    for BackupSvc<T> {
+                        type Response = super::BackupResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BackupRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UtilitiesService>::backup(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BackupSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.utilities.v001.UtilitiesService/GetUsage" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetUsageSvc<T: UtilitiesService>(pub Arc<T>);
+                    impl<
+                        T: UtilitiesService,
+                    > tonic::server::UnaryService<super::GetUsageRequest>
+                    for GetUsageSvc<T> {
+                        type Response = super::GetUsageResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetUsageRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UtilitiesService>::get_usage(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetUsageSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.utilities.v001.UtilitiesService/VerifyLedger" => {
+                    #[allow(non_camel_case_types)]
+                    struct VerifyLedgerSvc<T: UtilitiesService>(pub Arc<T>);
+                    impl<
+                        T: UtilitiesService,
+                    > tonic::server::UnaryService<super::VerifyLedgerRequest>
+                    for VerifyLedgerSvc<T> {
+                        type Response = super::VerifyLedgerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::VerifyLedgerRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UtilitiesService>::verify_ledger(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VerifyLedgerSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.utilities.v001.UtilitiesService/RoundTripExport" => {
+                    #[allow(non_camel_case_types)]
+                    struct RoundTripExportSvc<T: UtilitiesService>(pub Arc<T>);
+                    impl<
+                        T: UtilitiesService,
+                    > tonic::server::UnaryService<super::RoundTripExportRequest>
+                    for RoundTripExportSvc<T> {
+                        type Response = super::RoundTripExportResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RoundTripExportRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UtilitiesService>::round_trip_export(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RoundTripExportSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/personal_ledger.utilities.v001.UtilitiesService/SetLogLevel" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetLogLevelSvc<T: UtilitiesService>(pub Arc<T>);
+                    impl<
+                        T: UtilitiesService,
+                    > tonic::server::UnaryService<super::SetLogLevelRequest>
+                    for SetLogLevelSvc<T> {
+                        type Response = super::SetLogLevelResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetLogLevelRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UtilitiesService>::set_log_level(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetLogLevelSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
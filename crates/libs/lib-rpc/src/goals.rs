@@ -0,0 +1,41 @@
+// -- ./src/goals.rs --
+
+//! Goals module - gRPC services and types for savings goals.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the goals service. A goal has a target amount and optional date, and its
+//! progress is measured from a linked account or category.
+//!
+//! ## Services
+//!
+//! - **GoalsService**: Handles CRUD operations for goals and measures their progress
+//!   towards their targets.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `Goal`: A savings goal linked to an account or category
+//! - `GoalProgress`: How far a goal is towards its target on a date
+//! - Request/Response types for all operations (Create, Get, List, Update, Delete, Progress)
+//! - `GoalsServiceClient`: gRPC client for connecting to goals service
+//! - `GoalsService`: Server trait for implementing goals service
+//! - `GoalsServiceServer`: Server implementation for goals service
+
+// ---------------------------- [ GOALS ] -----------------------------------
+
+/// gRPC client for the GoalsService.
+/// Provides methods for managing goals and measuring their progress.
+pub use crate::generated::goals::goals_service_client::GoalsServiceClient;
+
+/// gRPC server trait and implementation for the GoalsService.
+/// Implement the `GoalsService` trait to handle incoming gRPC requests for goals.
+pub use crate::generated::goals::goals_service_server::{GoalsService, GoalsServiceServer};
+
+/// Goals-related message types.
+/// Includes structs for goals, their progress, requests, and responses used in the
+/// GoalsService. These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::goals::{
+    Goal, GoalCreateRequest, GoalCreateResponse, GoalDeleteRequest, GoalDeleteResponse,
+    GoalGetRequest, GoalGetResponse, GoalProgress, GoalUpdateRequest, GoalUpdateResponse,
+    GoalsListRequest, GoalsListResponse, GoalsProgressRequest, GoalsProgressResponse,
+};
@@ -0,0 +1,143 @@
+// -- ./src/health.rs --
+
+//! Health module - the standard gRPC health checking protocol.
+//!
+//! This module re-exports the `grpc.health.v1.Health` service from
+//! `tonic-health`, so Kubernetes probes, `grpc_health_probe` and grpcurl can
+//! check the server without knowing the ledger's own protos. It replaces the
+//! ad-hoc `Ping` RPC, which is kept in v001 for existing clients.
+//!
+//! Health is reported per service, by the fully qualified names in
+//! [`LEDGER_SERVICES`], and for the server as a whole under the empty name
+//! `""`. A [`HealthReporter`] sets the statuses that the [`HealthServer`]
+//! made with it serves.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_rpc::{HealthCheckRequest, LEDGER_SERVICES, LedgerClient, ServingStatus, health_reporter};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // Server side
+//! let (reporter, health) = health_reporter();
+//! for service in LEDGER_SERVICES {
+//!     reporter.set_service_status(service, ServingStatus::NotServing).await;
+//! }
+//!
+//! // Client side
+//! let client = LedgerClient::connect("http://127.0.0.1:50059").await?;
+//! let response = client
+//!     .health()
+//!     .check(HealthCheckRequest { service: String::new() })
+//!     .await?;
+//! println!("{:?}", response.into_inner().status());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::generated::{
+    accounts, attachments, audit, budgets, categories, export, goals, payees, periods,
+    reconciliations, reports, search, transactions, utilities,
+};
+
+/// gRPC client for the `grpc.health.v1.Health` service.
+pub use tonic_health::pb::health_client::HealthClient;
+
+/// gRPC server trait and implementation for the `grpc.health.v1.Health`
+/// service, made with [`health_reporter`].
+pub use tonic_health::pb::health_server::{Health, HealthServer};
+
+/// Health check message types, with the status on the wire as
+/// `HealthCheckStatus`.
+pub use tonic_health::pb::{
+    HealthCheckRequest, HealthCheckResponse,
+    health_check_response::ServingStatus as HealthCheckStatus,
+};
+
+/// Sets the statuses served by the [`HealthServer`] it was made with.
+pub use tonic_health::server::{HealthReporter, health_reporter};
+
+/// The status a [`HealthReporter`] sets for a service.
+pub use tonic_health::ServingStatus;
+
+/// The compiled `FileDescriptorSet` of the `grpc.health.v1` protos, for gRPC
+/// reflection.
+pub use tonic_health::pb::FILE_DESCRIPTOR_SET as HEALTH_FILE_DESCRIPTOR_SET;
+
+/// Fully qualified names of the ledger's services, as their health is
+/// reported and checked by.
+pub const LEDGER_SERVICES: &[&str] = &[
+    accounts::accounts_service_server::SERVICE_NAME,
+    attachments::attachments_service_server::SERVICE_NAME,
+    audit::audit_service_server::SERVICE_NAME,
+    budgets::budgets_service_server::SERVICE_NAME,
+    categories::categories_service_server::SERVICE_NAME,
+    export::export_service_server::SERVICE_NAME,
+    goals::goals_service_server::SERVICE_NAME,
+    payees::payees_service_server::SERVICE_NAME,
+    periods::periods_service_server::SERVICE_NAME,
+    reconciliations::reconciliations_service_server::SERVICE_NAME,
+    reports::reports_service_server::SERVICE_NAME,
+    search::search_service_server::SERVICE_NAME,
+    transactions::transactions_service_server::SERVICE_NAME,
+    utilities::utilities_service_server::SERVICE_NAME,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_service_is_named() {
+        let services: Vec<_> = crate::ApiSurface::current()
+            .methods()
+            .map(|(service, _)| service.to_string())
+            .collect();
+
+        for service in services {
+            assert!(
+                LEDGER_SERVICES.contains(&service.as_str()),
+                "{service} missing from LEDGER_SERVICES"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn checks_report_each_services_status() {
+        let (reporter, health) = health_reporter();
+        reporter
+            .set_service_status(LEDGER_SERVICES[0], ServingStatus::NotServing)
+            .await;
+
+        let (client, serving) = crate::serve_in_process(|incoming| {
+            tonic::transport::Server::builder()
+                .add_service(health)
+                .serve_with_incoming(incoming)
+        });
+
+        let overall = client
+            .health()
+            .check(HealthCheckRequest::default())
+            .await
+            .unwrap();
+        assert_eq!(overall.into_inner().status(), HealthCheckStatus::Serving);
+
+        let accounts = HealthCheckRequest {
+            service: LEDGER_SERVICES[0].to_string(),
+        };
+        let accounts = client.health().check(accounts).await.unwrap();
+        assert_eq!(
+            accounts.into_inner().status(),
+            HealthCheckStatus::NotServing
+        );
+
+        let unknown = HealthCheckRequest {
+            service: "personal_ledger.widgets.v001.WidgetsService".to_string(),
+        };
+        let status = client.health().check(unknown).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        drop(client);
+        serving.await.unwrap().unwrap();
+    }
+}
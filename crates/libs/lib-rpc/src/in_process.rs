@@ -0,0 +1,174 @@
+// -- ./src/in_process.rs --
+
+//! In-process module - serving and calling the services without a socket.
+//!
+//! [`LedgerClient::in_process`] returns a client and the [`InProcessIncoming`]
+//! stream of connections it makes. Each connection is one end of an in-memory
+//! duplex pipe, so a tonic server given the stream with
+//! `serve_with_incoming` answers the client without opening a port. This lets
+//! a desktop app embed the whole stack in one process.
+//!
+//! [`serve_in_process`] does both, spawning the server on the Tokio runtime.
+//! The stream ends once every clone of the client has been dropped, which
+//! stops the server after it finishes the requests in flight.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_rpc::{CategoriesServiceServer, PingRequest};
+//!
+//! # async fn example(categories: impl lib_rpc::CategoriesService) -> Result<(), Box<dyn std::error::Error>> {
+//! let (client, serving) = lib_rpc::serve_in_process(|incoming| {
+//!     tonic::transport::Server::builder()
+//!         .add_service(CategoriesServiceServer::new(categories))
+//!         .serve_with_incoming(incoming)
+//! });
+//!
+//! let categories = client.list_all_categories();
+//!
+//! drop(client);
+//! serving.await??;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::Stream;
+use hyper_util::rt::TokioIo;
+use tokio::io::DuplexStream;
+use tokio::sync::mpsc;
+use tonic::transport::Endpoint;
+
+use crate::LedgerClient;
+
+/// Bytes buffered in each direction of an in-process connection.
+const IN_PROCESS_BUFFER_SIZE: usize = 64 * 1024;
+
+/// The server ends of the connections made by an in-process client, to pass
+/// to a tonic server's `serve_with_incoming`.
+///
+/// The stream ends once every clone of the client has been dropped.
+#[derive(Debug)]
+pub struct InProcessIncoming {
+    connections: mpsc::UnboundedReceiver<DuplexStream>,
+}
+
+impl Stream for InProcessIncoming {
+    type Item = std::io::Result<DuplexStream>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.connections
+            .poll_recv(cx)
+            .map(|connection| connection.map(Ok))
+    }
+}
+
+impl LedgerClient {
+    /// A client whose connections are in-memory pipes, and the stream of
+    /// their server ends. Nothing is sent until a server is serving the
+    /// stream, see [`serve_in_process`].
+    pub fn in_process() -> (Self, InProcessIncoming) {
+        let (sender, connections) = mpsc::unbounded_channel();
+
+        // The URL is required but never resolved, the connector makes the pipe
+        let channel = Endpoint::from_static("http://in-process.personal-ledger")
+            .connect_with_connector_lazy(tower::service_fn(move |_uri| {
+                let (client, server) = tokio::io::duplex(IN_PROCESS_BUFFER_SIZE);
+                let sent = sender.send(server).map_err(|_| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::ConnectionRefused,
+                        "the in-process server has stopped",
+                    )
+                });
+
+                async move { sent.map(|()| TokioIo::new(client)) }
+            }));
+
+        (Self::new(channel), InProcessIncoming { connections })
+    }
+}
+
+/// Spawns the server returned by `serve` on an in-process connection stream,
+/// and returns a client connected to it with the server's task.
+///
+/// `serve` is given the [`InProcessIncoming`] stream, usually to pass to a
+/// tonic router's `serve_with_incoming`. The task completes once every clone
+/// of the client has been dropped and the requests in flight are finished.
+pub fn serve_in_process<F, Fut>(serve: F) -> (LedgerClient, tokio::task::JoinHandle<Fut::Output>)
+where
+    F: FnOnce(InProcessIncoming) -> Fut,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    let (client, incoming) = LedgerClient::in_process();
+    let serving = tokio::spawn(serve(incoming));
+
+    (client, serving)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HealthCheckRequest;
+
+    /// Serves no services, so every call is answered as unimplemented.
+    fn serve_nothing() -> (
+        LedgerClient,
+        tokio::task::JoinHandle<Result<(), tonic::transport::Error>>,
+    ) {
+        serve_in_process(|incoming| {
+            tonic::transport::Server::builder()
+                .add_routes(tonic::service::Routes::default())
+                .serve_with_incoming(incoming)
+        })
+    }
+
+    #[tokio::test]
+    async fn serve_in_process_answers_over_the_pipe() {
+        let (client, _serving) = serve_nothing();
+
+        let status = client
+            .health()
+            .check(HealthCheckRequest::default())
+            .await
+            .unwrap_err();
+
+        // Answered by the server, not a transport error
+        assert_eq!(status.code(), tonic::Code::Unimplemented);
+    }
+
+    #[tokio::test]
+    async fn server_stops_once_clients_are_dropped() {
+        let (client, serving) = serve_nothing();
+        let _ = client
+            .clone()
+            .health()
+            .check(HealthCheckRequest::default())
+            .await;
+
+        drop(client);
+
+        tokio::time::timeout(std::time::Duration::from_secs(5), serving)
+            .await
+            .expect("the server stops")
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn calls_fail_once_the_server_has_stopped() {
+        let (client, incoming) = LedgerClient::in_process();
+        drop(incoming);
+
+        let status = client
+            .health()
+            .check(HealthCheckRequest::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+    }
+}
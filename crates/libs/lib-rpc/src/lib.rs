@@ -1,35 +1,649 @@
 //! lib-rpc - gRPC services and types for the personal ledger.
 //!
 //! This crate provides re-exports of generated protobuf types and gRPC clients/servers
-//! for categories and utilities services. It serves as the main interface for interacting
+//! for accounts, attachments, audit, budgets, categories, export, goals, payees, periods, reconciliations, reports, search, transactions and utilities services. It serves as the main interface for interacting
 //! with the personal ledger's gRPC APIs.
 //!
 //! ## Services
 //!
+//! - **AccountsService**: Handles CRUD operations and computed balances for accounts.
+//! - **AttachmentsService**: Uploads and downloads receipt images and PDF invoices
+//!   attached to transactions, streamed in chunks.
+//! - **AuditService**: Reads the audit log of every change made to a record.
+//! - **BudgetsService**: Handles CRUD operations for category budgets and compares them
+//!   with actual spending.
 //! - **CategoriesService**: Handles CRUD operations for financial categories.
-//! - **UtilitiesService**: Provides utility operations like health checks.
+//! - **ExportService**: Streams categories, accounts and transactions out of the
+//!   ledger as CSV or JSON Lines.
+//! - **GoalsService**: Handles CRUD operations for savings goals and measures their
+//!   progress from a linked account or category.
+//! - **PayeesService**: Handles CRUD operations for payee aliases, which clean up the
+//!   payees of imported transactions.
+//! - **PeriodsService**: Closes and reopens ledger periods, locking past transactions.
+//! - **ReconciliationsService**: Reconciles accounts against their statements, and
+//!   undoes the latest reconciliation.
+//! - **ReportsService**: Aggregate reports: spending by category, income against expenses
+//!   by month, and net worth over time.
+//! - **SearchService**: Full-text search over categories and transactions.
+//! - **TransactionsService**: Handles CRUD operations for financial transactions, including
+//!   quick entry parsing.
+//! - **UtilitiesService**: Provides utility operations like the state of the
+//!   database migrations, the API versions served and database backups.
+//! - **Health**: The standard `grpc.health.v1.Health` service, reporting the
+//!   health of each of the services above, see [`LEDGER_SERVICES`].
 //!
 //! ## Usage
 //!
 //! Use the re-exported clients and servers to build gRPC clients or implement servers.
 //! Message types are available for constructing requests and handling responses.
+//!
+//! [`LedgerClient`] connects once and hands out every service's client over the
+//! same connection, with helpers such as [`LedgerClient::iter_transactions`]
+//! that stream every page of a list. [`RetryPolicy`] retries requests that failed
+//! for a reason that passes, and [`LedgerClient::retry_mutation`] does so for
+//! changes, with an idempotency key shared by every attempt.
+//!
+//! [`serve_in_process`] serves the services over in-memory pipes and returns a
+//! client for them, to embed the whole stack in one process without a socket.
+//! [`load_server_tls`] and [`LedgerClient::connect_tls`] encrypt connections,
+//! optionally with client certificates. [`AuthInterceptor`] authenticates
+//! every request with an API key or JWT, which clients attach with
+//! [`BearerToken`], and checks the principal's [`Role`] allows the RPC.
+//! [`Validate`] checks a request's fields before it is handled, returning
+//! every problem as a `google.rpc.BadRequest` field violation. Every error
+//! carries a `google.rpc.ErrorInfo`, read with [`error_info`], whose
+//! [`ErrorReason`] a client can branch on.
+//!
+//! ## Versions
+//!
+//! The protos are versioned by package, e.g. `personal_ledger.categories.v001`,
+//! and each version's generated code is in its own module, such as [`v001`].
+//! Breaking changes ship as a new version served alongside the old ones, see
+//! [`API_VERSIONS`] and the deprecation policy in [`ApiVersion`]'s module.
+//!
+//! ## Compatibility
+//!
+//! [`ApiSurface`] summarises the compiled protos and lists the
+//! [`BreakingChange`]s between two versions of them. A committed snapshot of the
+//! surface is checked in the tests, so a proto change that would break existing
+//! clients fails CI. The surface is read from [`FILE_DESCRIPTOR_SET`], the
+//! descriptors the crate was compiled from, which the server also serves over
+//! gRPC reflection.
 
 mod generated;
 
+mod accounts;
+
+mod attachments;
+
+mod audit;
+
+mod auth;
+
+mod budgets;
+
 mod categories;
 
+mod client;
+
+mod compatibility;
+
+mod errors;
+
+mod export;
+
+mod goals;
+
+mod health;
+
+mod in_process;
+
+mod payees;
+
+mod periods;
+
+mod reconciliations;
+
+mod reports;
+
+mod retry;
+
+mod roles;
+
+mod search;
+
+mod tls;
+
+mod transactions;
+
 mod utilities;
 
+mod validation;
+
+mod versions;
+
+// Re-export accounts module to maintain flat API
+pub use accounts::*;
+
+// Re-export attachments module to maintain flat API
+pub use attachments::*;
+
+// Re-export audit module to maintain flat API
+pub use audit::*;
+
+// Re-export auth module to maintain flat API
+pub use auth::*;
+
+// Re-export budgets module to maintain flat API
+pub use budgets::*;
+
 // Re-export categories module to maintain flat API
 pub use categories::*;
 
+// Re-export client module to maintain flat API
+pub use client::*;
+
+// Re-export compatibility module to maintain flat API
+pub use compatibility::*;
+
+// Re-export errors module to maintain flat API
+pub use errors::*;
+
+// Re-export export module to maintain flat API
+pub use export::*;
+
+// Re-export goals module to maintain flat API
+pub use goals::*;
+
+// Re-export health module to maintain flat API
+pub use health::*;
+
+// Re-export in_process module to maintain flat API
+pub use in_process::*;
+
+// Re-export payees module to maintain flat API
+pub use payees::*;
+
+// Re-export periods module to maintain flat API
+pub use periods::*;
+
+// Re-export reconciliations module to maintain flat API
+pub use reconciliations::*;
+
+// Re-export reports module to maintain flat API
+pub use reports::*;
+
+// Re-export retry module to maintain flat API
+pub use retry::*;
+
+// Re-export roles module to maintain flat API
+pub use roles::*;
+
+// Re-export search module to maintain flat API
+pub use search::*;
+
+// Re-export tls module to maintain flat API
+pub use tls::*;
+
+// Re-export transactions module to maintain flat API
+pub use transactions::*;
+
 // Re-export utilities module to maintain flat API
 pub use utilities::*;
 
+// Re-export validation module to maintain flat API
+pub use validation::*;
+
+// Re-export versions module to maintain flat API
+pub use versions::*;
+
+/// Every message, client and server of version 001 of the API, by service.
+/// The flat re-exports above are the current version.
+pub use generated::v001;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_accounts_reexports() {
+        // Test that accounts types can be instantiated
+        let account = Account {
+            id: "test-id".to_string(),
+            name: "Everyday Savings".to_string(),
+            description: None,
+            opening_balance: 150_000,
+            is_active: true,
+            created_on: None,
+            updated_on: None,
+            currency: "AUD".to_string(),
+            adjustments_category_id: None,
+        };
+
+        let request = AccountGetBalanceRequest {
+            id: account.id.clone(),
+            as_of: Some("2025-06-30".to_string()),
+            as_recorded: true,
+        };
+
+        let response = AccountGetBalanceResponse {
+            id: account.id.clone(),
+            balance: 140_000,
+            as_of: request.as_of.clone(),
+        };
+
+        let snapshot = BalanceSnapshot {
+            account_id: account.id.clone(),
+            snapshot_date: "2025-06-30".to_string(),
+            balance: 18_450_000,
+            source: SnapshotSources::Manual as i32,
+            ..Default::default()
+        };
+
+        let result = BalanceAssertionResult {
+            assertion: Some(BalanceAssertion {
+                account_id: account.id.clone(),
+                assertion_date: "2025-06-30".to_string(),
+                balance: 150_000,
+                ..Default::default()
+            }),
+            computed_balance: 140_000,
+            difference: -10_000,
+            holds: false,
+        };
+
+        // Basic assertions
+        assert_eq!(account.name, "Everyday Savings");
+        assert_eq!(response.balance, 140_000);
+        assert_eq!(response.as_of.as_deref(), Some("2025-06-30"));
+        assert_eq!(snapshot.account_id, account.id);
+        assert_eq!(snapshot.source(), SnapshotSources::Manual);
+        assert_eq!(result.assertion.unwrap().account_id, account.id);
+        assert!(!result.holds);
+    }
+
+    #[test]
+    fn test_budgets_reexports() {
+        // Test that budgets types can be instantiated
+        let budget = Budget {
+            id: "test-id".to_string(),
+            category_id: "category-id".to_string(),
+            period: BudgetPeriods::Monthly as i32,
+            amount: 80_000,
+            created_on: None,
+            updated_on: None,
+        };
+
+        let request = BudgetsCompareRequest {
+            period: BudgetPeriods::Annual as i32,
+            on: Some("2025-03-14".to_string()),
+        };
+
+        let response = BudgetsCompareResponse {
+            comparisons: vec![BudgetComparison {
+                budget: Some(budget.clone()),
+                start: "2024-07-01".to_string(),
+                end: "2025-06-30".to_string(),
+                budgeted: 960_000,
+                actual: 1_000_000,
+                remaining: -40_000,
+            }],
+        };
+
+        // Basic assertions
+        assert_eq!(budget.period(), BudgetPeriods::Monthly);
+        assert_eq!(request.period(), BudgetPeriods::Annual);
+        assert_eq!(response.comparisons[0].remaining, -40_000);
+
+        let variance = BudgetsVarianceResponse {
+            categories: vec![BudgetVariance {
+                category_name: "Food".to_string(),
+                budgeted: 50_000,
+                actual: 46_000,
+                remaining: 4_000,
+                children: vec![BudgetVariance {
+                    budget: Some(budget),
+                    is_over_budget: true,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert!(variance.categories[0].children[0].is_over_budget);
+        let _ = BudgetsVarianceRequest::default();
+    }
+
+    #[test]
+    fn test_goals_reexports() {
+        // Test that goals types can be instantiated
+        let goal = Goal {
+            id: "test-id".to_string(),
+            name: "Emergency fund".to_string(),
+            target_amount: 1_000_000,
+            target_date: Some("2026-06-30".to_string()),
+            account_id: Some("account-id".to_string()),
+            category_id: None,
+            created_on: None,
+            updated_on: None,
+        };
+
+        let request = GoalCreateRequest {
+            goal: Some(goal.clone()),
+            validate_only: true,
+        };
+
+        let response = GoalsProgressResponse {
+            progress: vec![GoalProgress {
+                goal: Some(goal),
+                on: "2025-06-30".to_string(),
+                saved: 250_000,
+                remaining: 750_000,
+                percent_complete: 25.0,
+                is_reached: false,
+                monthly_required: Some(62_500),
+            }],
+        };
+
+        // Basic assertions
+        assert!(request.validate_only);
+        assert_eq!(response.progress[0].remaining, 750_000);
+        assert_eq!(response.progress[0].monthly_required, Some(62_500));
+    }
+
+    #[test]
+    fn test_reports_reexports() {
+        // Test that reports types can be instantiated
+        let spending_request = ReportsSpendingByCategoryRequest {
+            from: "2025-03-01".to_string(),
+            to: "2025-03-31".to_string(),
+        };
+        let spending = ReportsSpendingByCategoryResponse {
+            categories: vec![CategorySpending {
+                category_id: Some("category-id".to_string()),
+                category_name: Some("Groceries".to_string()),
+                spent: 18_500,
+                transaction_count: 3,
+            }],
+            total_spent: 18_500,
+        };
+
+        let cash_flow = ReportsIncomeVsExpenseResponse {
+            months: vec![MonthlyCashFlow {
+                month: "2025-03-01".to_string(),
+                income: 5_000,
+                expense: 2_000,
+                net: 3_000,
+            }],
+        };
+
+        let net_worth_request = ReportsNetWorthRequest {
+            from: spending_request.from.clone(),
+            to: spending_request.to.clone(),
+            currency: None,
+        };
+        let net_worth = ReportsNetWorthResponse {
+            points: vec![NetWorthPoint {
+                on: "2025-03-31".to_string(),
+                net_worth: 110_000,
+            }],
+            currency: "AUD".to_string(),
+        };
+
+        let history = ReportsNetWorthHistoryResponse {
+            points: vec![NetWorthHistoryPoint {
+                on: "2025-03-31".to_string(),
+                net_worth: 110_000,
+                accounts: vec![AccountWorth {
+                    account_id: "account-id".to_string(),
+                    account_name: "Savings".to_string(),
+                    worth: 110_000,
+                    valued_from: ValuationSources::Computed as i32,
+                }],
+            }],
+            currency: "AUD".to_string(),
+        };
+
+        let dashboard_request = ReportsDashboardRequest { on: None };
+        let dashboard = ReportsDashboardResponse {
+            on: "2025-03-31".to_string(),
+            accounts: vec![DashboardAccount {
+                account_id: "account-id".to_string(),
+                account_name: "Savings".to_string(),
+                currency: "AUD".to_string(),
+                balance: 110_000,
+            }],
+            budgets: vec![DashboardBudget {
+                budget_id: "budget-id".to_string(),
+                category_id: "category-id".to_string(),
+                budgeted: 50_000,
+                actual: 18_500,
+                remaining: 31_500,
+            }],
+            recent_transactions: vec![DashboardTransaction {
+                id: "transaction-id".to_string(),
+                transaction_date: "2025-03-30".to_string(),
+                payee: "Grocer".to_string(),
+                amount: -18_500,
+                category_id: Some("category-id".to_string()),
+                account_id: None,
+                is_draft: false,
+            }],
+        };
+
+        // Basic assertions
+        assert_eq!(spending.categories[0].spent, spending.total_spent);
+        assert_eq!(cash_flow.months[0].net, 3_000);
+        assert!(net_worth_request.currency.is_none());
+        assert_eq!(net_worth.points.len(), 1);
+        assert_eq!(
+            history.points[0].accounts[0].valued_from(),
+            ValuationSources::Computed
+        );
+        assert!(dashboard_request.on.is_none());
+        assert_eq!(dashboard.budgets[0].remaining, 31_500);
+    }
+
+    #[test]
+    fn test_search_reexports() {
+        // Test that search types can be instantiated
+        let request = SearchRequest {
+            query: "gro*".to_string(),
+            limit: Some(5),
+            skip_categories: false,
+            skip_transactions: true,
+        };
+        let response = SearchResponse {
+            categories: vec![SearchCategoryHit {
+                id: "category-id".to_string(),
+                code: "FOOD.001".to_string(),
+                name: "Groceries".to_string(),
+                description: None,
+            }],
+            transactions: vec![],
+        };
+
+        // Basic assertions
+        assert_eq!(request.limit, Some(5));
+        assert_eq!(response.categories[0].name, "Groceries");
+        assert!(response.transactions.is_empty());
+    }
+
+    #[test]
+    fn test_attachments_reexports() {
+        // Test that attachments types can be instantiated
+        let attachment = Attachment {
+            id: "test-id".to_string(),
+            transaction_id: "transaction-id".to_string(),
+            file_name: "invoice.pdf".to_string(),
+            content_type: "application/pdf".to_string(),
+            size_bytes: 8,
+            sha256: "0".repeat(64),
+            created_on: None,
+        };
+
+        let upload = AttachmentUploadRequest {
+            part: Some(AttachmentUploadPart::Chunk(b"%PDF-1.7".to_vec())),
+        };
+
+        let download = AttachmentDownloadResponse {
+            part: Some(AttachmentDownloadPart::Attachment(attachment.clone())),
+        };
+
+        // Basic assertions
+        assert!(
+            matches!(upload.part, Some(AttachmentUploadPart::Chunk(ref chunk)) if chunk.len() == 8)
+        );
+        assert!(matches!(
+            download.part,
+            Some(AttachmentDownloadPart::Attachment(_))
+        ));
+        assert_eq!(attachment.sha256.len(), 64);
+    }
+
+    #[test]
+    fn test_audit_reexports() {
+        // Test that audit types can be instantiated
+        let entry = AuditEntry {
+            id: 1,
+            entity: "categories".to_string(),
+            entity_id: "test-id".to_string(),
+            action: AuditActions::Update as i32,
+            actor: Some("ian".to_string()),
+            old_values: Some(r#"{"name":"Groceries"}"#.to_string()),
+            new_values: Some(r#"{"name":"Food"}"#.to_string()),
+            changed_on: None,
+        };
+
+        let request = AuditHistoryRequest {
+            entity: entry.entity.clone(),
+            entity_id: entry.entity_id.clone(),
+        };
+
+        let response = AuditHistoryResponse {
+            entries: vec![entry.clone()],
+        };
+
+        // Basic assertions
+        assert_eq!(entry.action(), AuditActions::Update);
+        assert_eq!(request.entity, "categories");
+        assert_eq!(response.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_export_reexports() {
+        // Test that export types can be instantiated
+        let request = ExportLedgerRequest {
+            format: ExportFormats::JsonLines as i32,
+            entities: vec![ExportEntities::Transactions as i32],
+            from: Some("2025-07-01".to_string()),
+            to: None,
+        };
+
+        let response = ExportLedgerResponse {
+            entity: ExportEntities::Transactions as i32,
+            content: "{\"payee\":\"Fresh Market\"}\n".to_string(),
+        };
+
+        let book = ExportBookRequest {
+            format: BookFormats::GnucashXml as i32,
+            from: None,
+            to: Some("2025-12-31".to_string()),
+        };
+
+        // Basic assertions
+        assert_eq!(request.format(), ExportFormats::JsonLines);
+        assert_eq!(book.format(), BookFormats::GnucashXml);
+        assert_eq!(response.entity(), ExportEntities::Transactions);
+        assert!(response.content.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_periods_reexports() {
+        // Test that periods types can be instantiated
+        let closing = PeriodClosing {
+            id: "test-id".to_string(),
+            action: PeriodActions::Reopen as i32,
+            closed_through: Some("2025-03-31".to_string()),
+            reason: Some("Amended invoice".to_string()),
+            created_on: None,
+        };
+
+        let request = PeriodReopenRequest {
+            closed_through: closing.closed_through.clone(),
+            reason: "Amended invoice".to_string(),
+            validate_only: false,
+        };
+
+        let response = PeriodGetStatusResponse {
+            closed_through: request.closed_through.clone(),
+            history: vec![closing.clone()],
+        };
+
+        // Basic assertions
+        assert_eq!(closing.action(), PeriodActions::Reopen);
+        assert_eq!(response.history.len(), 1);
+        assert_eq!(response.closed_through.as_deref(), Some("2025-03-31"));
+    }
+
+    #[test]
+    fn test_reconciliations_reexports() {
+        // Test that reconciliations types can be instantiated
+        let reconciliation = Reconciliation {
+            id: "test-id".to_string(),
+            account_id: "account-id".to_string(),
+            statement_date: "2025-03-31".to_string(),
+            starting_balance: 120_000,
+            ending_balance: 95_500,
+            created_on: None,
+        };
+
+        let request = ReconciliationCreateRequest {
+            account_id: reconciliation.account_id.clone(),
+            statement_date: reconciliation.statement_date.clone(),
+            starting_balance: reconciliation.starting_balance,
+            ending_balance: reconciliation.ending_balance,
+            transaction_ids: vec!["transaction-id".to_string()],
+            validate_only: true,
+        };
+
+        let response = ReconciliationsListResponse {
+            reconciliations: vec![reconciliation.clone()],
+        };
+
+        // Basic assertions
+        assert_eq!(request.transaction_ids.len(), 1);
+        assert_eq!(response.reconciliations[0].ending_balance, 95_500);
+        assert!(request.validate_only);
+    }
+
+    #[test]
+    fn test_payees_reexports() {
+        // Test that payees types can be instantiated
+        let alias = PayeeAlias {
+            id: "test-id".to_string(),
+            pattern: "AMZN Mktp".to_string(),
+            match_kind: PayeeMatchKinds::Exact as i32,
+            payee: "Amazon".to_string(),
+            created_on: None,
+            updated_on: None,
+        };
+
+        let request = PayeeAliasCreateRequest {
+            alias: Some(alias.clone()),
+            validate_only: true,
+        };
+
+        let response = PayeeNormaliseResponse {
+            payee: alias.payee.clone(),
+        };
+
+        // Basic assertions
+        assert_eq!(alias.match_kind(), PayeeMatchKinds::Exact);
+        assert!(request.validate_only);
+        assert_eq!(response.payee, "Amazon");
+    }
+
     #[test]
     fn test_categories_reexports() {
         // Test that categories types can be instantiated
@@ -45,16 +659,275 @@ mod tests {
             is_active: true,
             created_on: None,
             updated_on: None,
+            parent_id: None,
+            deleted_on: None,
+            version: 1,
         };
 
         let request = CategoryCreateRequest {
             category: Some(category.clone()),
+            validate_only: false,
         };
 
         // Basic assertions
         assert_eq!(category.code, "TEST");
         assert!(category.is_active);
         assert!(request.category.is_some());
+        assert!(!request.validate_only);
+
+        let archive = CategoryArchiveRequest {
+            id: category.id.clone(),
+            months: Some(6),
+            force: true,
+            validate_only: false,
+        };
+        assert_eq!(archive.months, Some(6));
+
+        let statistics = GetCategoryStatisticsResponse {
+            total_count: 1,
+            active_count: 1,
+            type_counts: vec![CategoryTypeCount {
+                category_type: CategoryTypes::Expense as i32,
+                count: 1,
+            }],
+            usage: vec![CategoryUsage {
+                category_id: "test-id".to_string(),
+                transaction_count: 3,
+                last_used_on: Some("2025-03-13".to_string()),
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            statistics.type_counts[0].category_type(),
+            CategoryTypes::Expense
+        );
+        let _ = GetCategoryStatisticsRequest { recent_limit: None };
+    }
+
+    #[test]
+    fn test_transactions_reexports() {
+        // Test that transactions types can be instantiated
+        let request = TransactionQuickAddRequest {
+            text: "12.50 coffee yesterday #food @visa".to_string(),
+        };
+
+        let response = TransactionQuickAddResponse {
+            preview: Some(TransactionPreview {
+                amount: -1250,
+                payee: "coffee".to_string(),
+                date: "2025-03-13".to_string(),
+                tags: vec!["food".to_string()],
+                account: Some("visa".to_string()),
+            }),
+        };
+
+        let transaction = Transaction {
+            id: "test-id".to_string(),
+            transaction_date: "2025-03-13".to_string(),
+            payee: "Corner Cafe".to_string(),
+            description: None,
+            amount: -1250,
+            category_id: Some("category-id".to_string()),
+            created_on: None,
+            updated_on: None,
+            account_id: None,
+            is_draft: true,
+            latitude: Some(-27.4698),
+            longitude: Some(153.0251),
+            place_name: Some("West End".to_string()),
+            return_by: Some("2025-04-12".to_string()),
+            warranty_expires: None,
+            tags: vec!["coffee".to_string()],
+            cleared_status: ClearedStatus::Cleared as i32,
+            version: 1,
+            splits: vec![
+                Split {
+                    id: "split-1".to_string(),
+                    category_id: "category-id".to_string(),
+                    amount: -1000,
+                    memo: None,
+                },
+                Split {
+                    id: "split-2".to_string(),
+                    category_id: "other-category-id".to_string(),
+                    amount: -250,
+                    memo: Some("Muffin".to_string()),
+                },
+            ],
+        };
+
+        let suggest_response = TransactionTagsSuggestResponse {
+            suggestions: vec![TagSuggestion {
+                name: transaction.tags[0].clone(),
+                transaction_count: 1,
+            }],
+        };
+
+        let map_request = TransactionsMapDataRequest {
+            min_latitude: -28.0,
+            min_longitude: 152.5,
+            max_latitude: -27.0,
+            max_longitude: 153.5,
+            cell_size_degrees: 0.0,
+        };
+        let cluster = TransactionMapCluster {
+            latitude: -27.4698,
+            longitude: 153.0251,
+            count: 1,
+            total_amount: -1250,
+            place_name: transaction.place_name.clone(),
+        };
+
+        let create_request = TransactionCreateRequest {
+            transaction: Some(transaction.clone()),
+            validate_only: true,
+        };
+
+        let update_request = TransactionUpdateRequest {
+            id: transaction.id.clone(),
+            transaction: Some(transaction.clone()),
+            validate_only: false,
+            update_mask: Some(prost_types::FieldMask {
+                paths: vec!["splits".to_string()],
+            }),
+        };
+
+        let post_request = TransactionsPostDraftsRequest {
+            ids: vec![transaction.id.clone()],
+            validate_only: false,
+        };
+
+        let bulk_edit_request = TransactionsBulkEditRequest {
+            filter: Some(TransactionFilter {
+                payee: Some("CORNER CAFE PTY".to_string()),
+                ..Default::default()
+            }),
+            changes: Some(TransactionChanges {
+                payee: Some("Corner Cafe".to_string()),
+                shift_days: 0,
+                add_tags: vec!["coffee".to_string()],
+            }),
+            sample_size: 5,
+            validate_only: true,
+        };
+
+        let reassign_request = TransactionsReassignRequest {
+            filter: Some(TransactionFilter {
+                payee_pattern: Some("AMZN*".to_string()),
+                ..Default::default()
+            }),
+            category_id: "cat_1".to_string(),
+            validate_only: false,
+        };
+
+        let expiring_request = TransactionsListExpiringRequest {
+            on: Some("2025-04-07".to_string()),
+            return_notice_days: Some(7),
+            warranty_notice_days: None,
+        };
+        let expiry = TransactionExpiry {
+            transaction: Some(transaction.clone()),
+            kind: ExpiryKinds::Return as i32,
+            due_on: "2025-04-12".to_string(),
+            days_left: 5,
+        };
+
+        let transfer_request = TransactionsTransferRequest {
+            from_account_id: "everyday".to_string(),
+            to_account_id: "savings".to_string(),
+            amount: 50_000,
+            transaction_date: "2025-03-14".to_string(),
+            description: None,
+            validate_only: true,
+        };
+        let transfer_response = TransactionsTransferResponse {
+            transfer_id: "transfer-id".to_string(),
+            from_transaction: Some(Transaction {
+                amount: -50_000,
+                ..Default::default()
+            }),
+            to_transaction: Some(Transaction {
+                amount: 50_000,
+                ..Default::default()
+            }),
+        };
+
+        let duplicates_request = TransactionsListDuplicatesRequest {
+            window_days: Some(3),
+        };
+        let duplicates = TransactionsListDuplicatesResponse {
+            pairs: vec![DuplicatePair {
+                original: Some(transaction.clone()),
+                duplicate: Some(Transaction {
+                    id: "duplicate-id".to_string(),
+                    ..transaction.clone()
+                }),
+                similarity: 1.0,
+                days_apart: 0,
+            }],
+        };
+        let merge_request = TransactionsMergeDuplicateRequest {
+            keep_id: transaction.id.clone(),
+            duplicate_id: "duplicate-id".to_string(),
+            validate_only: true,
+        };
+        let merged = TransactionsMergeDuplicateResponse {
+            transaction: Some(transaction.clone()),
+        };
+        let dismiss_request = TransactionsDismissDuplicateRequest {
+            id: transaction.id.clone(),
+            other_id: "duplicate-id".to_string(),
+        };
+        let _ = TransactionsDismissDuplicateResponse {};
+
+        let stream_request = TransactionsStreamRequest { page_size: 500 };
+        let page = TransactionsStreamResponse {
+            transactions: vec![transaction.clone()],
+        };
+
+        // Basic assertions
+        assert!(!request.text.is_empty());
+        assert_eq!(response.preview.unwrap().amount, -1250);
+        assert_eq!(transaction.payee, "Corner Cafe");
+        assert_eq!(transaction.cleared_status(), ClearedStatus::Cleared);
+        assert!(create_request.validate_only);
+        assert_eq!(
+            transaction
+                .splits
+                .iter()
+                .map(|split| split.amount)
+                .sum::<i64>(),
+            transaction.amount
+        );
+        assert_eq!(
+            update_request.update_mask.unwrap().paths,
+            vec!["splits".to_string()]
+        );
+        assert_eq!(post_request.ids, vec!["test-id".to_string()]);
+        assert!(bulk_edit_request.validate_only);
+        assert_eq!(
+            reassign_request
+                .filter
+                .and_then(|filter| filter.payee_pattern)
+                .as_deref(),
+            Some("AMZN*")
+        );
+        assert!(map_request.min_latitude < map_request.max_latitude);
+        assert_eq!(cluster.place_name.as_deref(), Some("West End"));
+        assert_eq!(suggest_response.suggestions[0].name, "coffee");
+        assert_eq!(expiring_request.return_notice_days, Some(7));
+        assert_eq!(expiry.kind(), ExpiryKinds::Return);
+        assert_eq!(stream_request.page_size, 500);
+        assert_eq!(page.transactions.len(), 1);
+        assert!(transfer_request.validate_only);
+        assert_eq!(duplicates_request.window_days, Some(3));
+        assert_eq!(duplicates.pairs[0].similarity, 1.0);
+        assert!(merge_request.validate_only);
+        assert_eq!(merged.transaction.unwrap().id, dismiss_request.id);
+        assert_eq!(
+            transfer_response.to_transaction.unwrap().amount,
+            transfer_request.amount
+        );
     }
 
     #[test]
@@ -66,9 +939,58 @@ mod tests {
             message: "pong".to_string(),
         };
 
+        let migrations = MigrationStatusResponse {
+            migrations: vec![Migration {
+                version: 1,
+                description: "categories".to_string(),
+                applied_on: None,
+                is_modified: false,
+            }],
+            current_version: 0,
+            pending_count: 1,
+        };
+
         // Basic assertions
         assert_eq!(ping_response.message, "pong");
+        assert_eq!(migrations.migrations[0].version, 1);
         // PingRequest is empty, so just check it exists
         let _ = ping_request;
+        let _ = MigrationStatusRequest {};
+
+        let server_info = ServerInfoResponse {
+            server_version: "0.1.0".to_string(),
+            api_version: CURRENT_API_VERSION.to_string(),
+            api_versions: API_VERSIONS.iter().map(ApiVersionInfo::from).collect(),
+        };
+        assert_eq!(
+            server_info.api_versions[0].state,
+            ApiVersionStates::Current as i32
+        );
+        let _ = ServerInfoRequest {
+            api_versions: vec![],
+        };
+        let _ = v001::utilities::PingRequest {};
+
+        let backup = BackupResponse {
+            path: "backups/ledger.db".to_string(),
+            size_bytes: 4096,
+            migration_version: 26,
+            created_on: None,
+        };
+        assert_eq!(backup.size_bytes, 4096);
+        let _ = BackupRequest { file_name: None };
+
+        let round_trip = RoundTripExportResponse {
+            checks: vec![RoundTripCheck {
+                check: RoundTripChecks::AccountCount as i32,
+                record_id: None,
+                exported: 2,
+                imported: 2,
+            }],
+            is_intact: true,
+            checked_on: None,
+        };
+        assert_eq!(round_trip.checks[0].check(), RoundTripChecks::AccountCount);
+        let _ = RoundTripExportRequest {};
     }
 }
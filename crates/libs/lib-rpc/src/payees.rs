@@ -0,0 +1,43 @@
+// -- ./src/payees.rs --
+
+//! Payees module - gRPC services and types for payee aliases.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the payees service. A payee alias maps the raw payee strings banks put in their
+//! exports to a clean payee, and every alias is applied when transactions are imported.
+//!
+//! ## Services
+//!
+//! - **PayeesService**: Handles CRUD operations for payee aliases, and cleans up a raw
+//!   payee with them to try them out.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `PayeeAlias`: A raw payee pattern and the clean payee it maps to
+//! - `PayeeMatchKinds`: Whether a pattern is matched exactly or as a regular expression
+//! - Request/Response types for all operations (Create, Get, List, Update, Delete, Normalise)
+//! - `PayeesServiceClient`: gRPC client for connecting to payees service
+//! - `PayeesService`: Server trait for implementing payees service
+//! - `PayeesServiceServer`: Server implementation for payees service
+
+// ----------------------------- [ PAYEES ] -----------------------------------
+
+/// gRPC client for the PayeesService.
+/// Provides methods for creating, reading, updating, deleting and trying out payee
+/// aliases.
+pub use crate::generated::payees::payees_service_client::PayeesServiceClient;
+
+/// gRPC server trait and implementation for the PayeesService.
+/// Implement the `PayeesService` trait to handle incoming gRPC requests for payees.
+pub use crate::generated::payees::payees_service_server::{PayeesService, PayeesServiceServer};
+
+/// Payees-related message types.
+/// Includes structs for aliases, requests, and responses used in the PayeesService.
+/// These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::payees::{
+    PayeeAlias, PayeeAliasCreateRequest, PayeeAliasCreateResponse, PayeeAliasDeleteRequest,
+    PayeeAliasDeleteResponse, PayeeAliasGetRequest, PayeeAliasGetResponse, PayeeAliasUpdateRequest,
+    PayeeAliasUpdateResponse, PayeeAliasesListRequest, PayeeAliasesListResponse, PayeeMatchKinds,
+    PayeeNormaliseRequest, PayeeNormaliseResponse,
+};
@@ -0,0 +1,40 @@
+// -- ./src/periods.rs --
+
+//! Periods module - gRPC services and types for closing ledger periods.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the periods service. Closing a period locks every transaction dated on or before
+//! the closing date, so finished months and tax years stay immutable.
+//!
+//! ## Services
+//!
+//! - **PeriodsService**: Closes the ledger through a date, reopens it as an admin override
+//!   with a reason, and returns the current closing date with its audit trail.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `PeriodClosing`: A single close or reopen recorded in the audit trail
+//! - `PeriodActions`: Whether a period closing closed or reopened the ledger
+//! - Request/Response types for all operations (Close, Reopen, GetStatus)
+//! - `PeriodsServiceClient`: gRPC client for connecting to periods service
+//! - `PeriodsService`: Server trait for implementing periods service
+//! - `PeriodsServiceServer`: Server implementation for periods service
+
+// --------------------------- [ PERIODS ] ----------------------------------
+
+/// gRPC client for the PeriodsService.
+/// Provides methods for closing and reopening periods and reading the audit trail.
+pub use crate::generated::periods::periods_service_client::PeriodsServiceClient;
+
+/// gRPC server trait and implementation for the PeriodsService.
+/// Implement the `PeriodsService` trait to handle incoming gRPC requests for periods.
+pub use crate::generated::periods::periods_service_server::{PeriodsService, PeriodsServiceServer};
+
+/// Periods-related message types.
+/// Includes structs for period closings, requests, and responses used in the PeriodsService.
+/// These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::periods::{
+    PeriodActions, PeriodCloseRequest, PeriodCloseResponse, PeriodClosing, PeriodGetStatusRequest,
+    PeriodGetStatusResponse, PeriodReopenRequest, PeriodReopenResponse,
+};
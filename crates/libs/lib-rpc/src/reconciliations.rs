@@ -0,0 +1,42 @@
+// -- ./src/reconciliations.rs --
+
+//! Reconciliations module - gRPC services and types for reconciling accounts.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the reconciliations service. An account is reconciled against each of its
+//! statements in turn, marking the transactions on the statement reconciled.
+//!
+//! ## Services
+//!
+//! - **ReconciliationsService**: Lists an account's reconciliations, reconciles it
+//!   against a statement, and undoes its latest reconciliation.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `Reconciliation`: A statement's date and balances, reconciled for an account
+//! - Request/Response types for all operations (Create, List, Delete)
+//! - `ReconciliationsServiceClient`: gRPC client for connecting to reconciliations service
+//! - `ReconciliationsService`: Server trait for implementing reconciliations service
+//! - `ReconciliationsServiceServer`: Server implementation for reconciliations service
+
+// ----------------------- [ RECONCILIATIONS ] ------------------------------
+
+/// gRPC client for the ReconciliationsService.
+/// Provides methods for listing, creating and undoing reconciliations.
+pub use crate::generated::reconciliations::reconciliations_service_client::ReconciliationsServiceClient;
+
+/// gRPC server trait and implementation for the ReconciliationsService.
+/// Implement the `ReconciliationsService` trait to handle incoming gRPC requests for reconciliations.
+pub use crate::generated::reconciliations::reconciliations_service_server::{
+    ReconciliationsService, ReconciliationsServiceServer,
+};
+
+/// Reconciliations-related message types.
+/// Includes structs for reconciliations, requests, and responses used in the ReconciliationsService.
+/// These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::reconciliations::{
+    Reconciliation, ReconciliationCreateRequest, ReconciliationCreateResponse,
+    ReconciliationDeleteRequest, ReconciliationDeleteResponse, ReconciliationsListRequest,
+    ReconciliationsListResponse,
+};
@@ -0,0 +1,51 @@
+// -- ./src/reports.rs --
+
+//! Reports module - gRPC services and types for aggregate ledger reports.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the reports service. Reports are totalled by the database, so only the report
+//! rows are sent to the client.
+//!
+//! ## Services
+//!
+//! - **ReportsService**: Reports spending by category over a date range, income against
+//!   expenses for each month, net worth over time, net worth history broken down by
+//!   account for charting, and a dashboard summary of account balances, this month's
+//!   budgets and recent transactions in one call.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `CategorySpending`: Spending in one category over a date range
+//! - `MonthlyCashFlow`: Income and expenses for one month
+//! - `NetWorthPoint`: Net worth on one day
+//! - `NetWorthHistoryPoint`, `AccountWorth`: Net worth on one day and what each account was worth
+//! - `ValuationSources`: Enum of how an account's worth was worked out
+//! - `DashboardAccount`, `DashboardBudget`, `DashboardTransaction`: Rows of the dashboard summary
+//! - Request/Response types for all operations (SpendingByCategory, IncomeVsExpense, NetWorth,
+//!   NetWorthHistory, Dashboard)
+//! - `ReportsServiceClient`: gRPC client for connecting to reports service
+//! - `ReportsService`: Server trait for implementing reports service
+//! - `ReportsServiceServer`: Server implementation for reports service
+
+// --------------------------- [ REPORTS ] ----------------------------------
+
+/// gRPC client for the ReportsService.
+/// Provides methods for fetching aggregate reports over the ledger.
+pub use crate::generated::reports::reports_service_client::ReportsServiceClient;
+
+/// gRPC server trait and implementation for the ReportsService.
+/// Implement the `ReportsService` trait to handle incoming gRPC requests for reports.
+pub use crate::generated::reports::reports_service_server::{ReportsService, ReportsServiceServer};
+
+/// Reports-related message types.
+/// Includes structs for report rows, requests, and responses used in the ReportsService.
+/// These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::reports::{
+    AccountWorth, CategorySpending, DashboardAccount, DashboardBudget, DashboardTransaction,
+    MonthlyCashFlow, NetWorthHistoryPoint, NetWorthPoint, ReportsDashboardRequest,
+    ReportsDashboardResponse, ReportsIncomeVsExpenseRequest, ReportsIncomeVsExpenseResponse,
+    ReportsNetWorthHistoryRequest, ReportsNetWorthHistoryResponse, ReportsNetWorthRequest,
+    ReportsNetWorthResponse, ReportsSpendingByCategoryRequest, ReportsSpendingByCategoryResponse,
+    ValuationSources,
+};
@@ -0,0 +1,335 @@
+// -- ./src/retry.rs --
+
+//! Retry module - retrying requests that failed for a reason that passes.
+//!
+//! A failed request is only retried when its status says the server did not
+//! act on it, so retrying a mutation never applies it twice:
+//!
+//! - **Retryable**: `UNAVAILABLE` (the server could not be reached, or the
+//!   database was busy and the change rolled back), and `RESOURCE_EXHAUSTED`
+//!   when the server says how long to wait
+//! - **Terminal**: every other code. Some, such as `INVALID_ARGUMENT`, a
+//!   quota that is used up, or `ABORTED` for a stale version, will fail the
+//!   same way again. Others, such as `DEADLINE_EXCEEDED` and `INTERNAL`, leave
+//!   it unknown whether the change was made, so they are returned for the
+//!   caller to check rather than retried blindly.
+//!
+//! The server may say how long to wait in the `retry-after-ms` metadata,
+//! which is used in place of the backoff when it is longer.
+//!
+//! Mutations carry an `idempotency-key` metadata value that stays the same on
+//! every attempt, for logs and proxies to tie the attempts together. The
+//! server does not deduplicate on it, so only the codes above, where the
+//! change was not made, are retried.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_rpc::{CategoryCreateRequest, LedgerClient, RetryPolicy};
+//!
+//! # async fn example(category: lib_rpc::Category) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = LedgerClient::connect("http://127.0.0.1:50059").await?;
+//!
+//! let request = CategoryCreateRequest {
+//!     category: Some(category),
+//!     validate_only: false,
+//! };
+//! let created = client
+//!     .retry_mutation(&RetryPolicy::default(), request, |request| {
+//!         let mut categories = client.categories();
+//!         async move { categories.category_create(request).await }
+//!     })
+//!     .await?;
+//! println!("Created {:?}", created.into_inner().category);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::time::Duration;
+
+use tonic::{Code, Request, Response, Status};
+
+use crate::LedgerClient;
+
+/// Metadata key carrying the key that identifies every attempt of one mutation.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Metadata key carrying how long the client should wait before retrying,
+/// in milliseconds.
+pub const RETRY_AFTER_HEADER: &str = "retry-after-ms";
+
+/// Whether a request that failed with `status` can be retried, as the server
+/// did not act on it. See the [module docs](self) for the codes.
+pub fn is_retryable(status: &Status) -> bool {
+    match status.code() {
+        Code::Unavailable => true,
+        Code::ResourceExhausted => retry_after(status).is_some(),
+        _ => false,
+    }
+}
+
+/// How long the server asked the client to wait before retrying, from the
+/// status's `retry-after-ms` metadata.
+pub fn retry_after(status: &Status) -> Option<Duration> {
+    status
+        .metadata()
+        .get(RETRY_AFTER_HEADER)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+        .map(Duration::from_millis)
+}
+
+/// Wraps `message` in a request carrying the idempotency key.
+pub fn with_idempotency_key<T>(message: T, key: &str) -> Request<T> {
+    let mut request = Request::new(message);
+    if let Ok(value) = key.parse() {
+        request.metadata_mut().insert(IDEMPOTENCY_KEY_HEADER, value);
+    }
+    request
+}
+
+/// How many times, and how often, a failed request is tried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The most times the request is sent, including the first.
+    pub max_attempts: u32,
+
+    /// The wait before the first retry, doubled for each retry after it.
+    pub initial_backoff: Duration,
+
+    /// The longest wait between attempts, including one asked for by the
+    /// server.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts, waiting 100ms then 200ms, and never more than 5s.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends the request once.
+    pub fn no_retry() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// How long to wait after failed attempt number `attempt`, counting
+    /// from 1, given the status it failed with.
+    pub fn backoff(&self, attempt: u32, status: &Status) -> Duration {
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+        let wait = match retry_after(status) {
+            Some(asked) => asked.max(exponential),
+            None => exponential,
+        };
+
+        wait.min(self.max_backoff)
+    }
+
+    /// Calls `call` until it succeeds, fails with a terminal status, or
+    /// `max_attempts` is reached, waiting between attempts. `call` is given
+    /// the attempt number, counting from 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns the status of the last attempt.
+    pub async fn run<T, F, Fut>(&self, mut call: F) -> Result<T, Status>
+    where
+        F: FnMut(u32) -> Fut,
+        Fut: Future<Output = Result<T, Status>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match call(attempt).await {
+                Err(status) if attempt < self.max_attempts && is_retryable(&status) => {
+                    tokio::time::sleep(self.backoff(attempt, &status)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl LedgerClient {
+    /// Sends a mutation with `call`, retrying it under `policy`. Every
+    /// attempt carries the same new idempotency key.
+    ///
+    /// # Errors
+    ///
+    /// Returns the status of the last attempt.
+    pub async fn retry_mutation<M, T, F, Fut>(
+        &self,
+        policy: &RetryPolicy,
+        message: M,
+        mut call: F,
+    ) -> Result<Response<T>, Status>
+    where
+        M: Clone,
+        F: FnMut(Request<M>) -> Fut,
+        Fut: Future<Output = Result<Response<T>, Status>>,
+    {
+        let key = uuid::Uuid::now_v7().to_string();
+
+        policy
+            .run(|_attempt| call(with_idempotency_key(message.clone(), &key)))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn busy(retry_after_ms: Option<&str>) -> Status {
+        let mut status = Status::unavailable("database is busy");
+        if let Some(value) = retry_after_ms {
+            status
+                .metadata_mut()
+                .insert(RETRY_AFTER_HEADER, value.parse().unwrap());
+        }
+        status
+    }
+
+    /// The default policy without the waits.
+    fn fast() -> RetryPolicy {
+        RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[test]
+    fn classifies_status_codes() {
+        assert!(is_retryable(&Status::unavailable("")));
+        let mut throttled = Status::resource_exhausted("slow down");
+        throttled
+            .metadata_mut()
+            .insert(RETRY_AFTER_HEADER, "250".parse().unwrap());
+        assert!(is_retryable(&throttled));
+
+        for code in [
+            Code::ResourceExhausted,
+            Code::Aborted,
+            Code::InvalidArgument,
+            Code::NotFound,
+            Code::AlreadyExists,
+            Code::FailedPrecondition,
+            Code::DeadlineExceeded,
+            Code::Internal,
+            Code::Unknown,
+        ] {
+            assert!(!is_retryable(&Status::new(code, "")), "{code:?}");
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_and_honours_retry_after() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(policy.backoff(1, &busy(None)), Duration::from_millis(100));
+        assert_eq!(policy.backoff(3, &busy(None)), Duration::from_millis(400));
+        assert_eq!(
+            policy.backoff(1, &busy(Some("750"))),
+            Duration::from_millis(750)
+        );
+        assert_eq!(
+            policy.backoff(1, &busy(Some("60000"))),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            policy.backoff(1, &busy(Some("soon"))),
+            Duration::from_millis(100)
+        );
+        assert_eq!(policy.backoff(40, &busy(None)), Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn run_retries_until_success() {
+        let attempts = Arc::new(Mutex::new(Vec::new()));
+
+        let result = fast()
+            .run(|attempt| {
+                attempts.lock().unwrap().push(attempt);
+                async move {
+                    if attempt < 3 {
+                        Err(busy(Some("10")))
+                    } else {
+                        Ok(attempt)
+                    }
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(*attempts.lock().unwrap(), [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn run_stops_on_terminal_status_and_after_max_attempts() {
+        let mut calls = 0;
+        let terminal = fast()
+            .run(|_| {
+                calls += 1;
+                async { Err::<(), _>(Status::invalid_argument("bad")) }
+            })
+            .await;
+        assert_eq!(terminal.unwrap_err().code(), Code::InvalidArgument);
+        assert_eq!(calls, 1);
+
+        let mut calls = 0;
+        let exhausted = fast()
+            .run(|_| {
+                calls += 1;
+                async { Err::<(), _>(busy(None)) }
+            })
+            .await;
+        assert_eq!(exhausted.unwrap_err().code(), Code::Unavailable);
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn retry_mutation_reuses_idempotency_key() {
+        let channel = tonic::transport::Endpoint::from_static("http://127.0.0.1:1").connect_lazy();
+        let client = LedgerClient::new(channel);
+        let keys = Arc::new(Mutex::new(Vec::new()));
+
+        let response = client
+            .retry_mutation(&fast(), "create", |request: Request<&str>| {
+                let key = request.metadata().get(IDEMPOTENCY_KEY_HEADER).cloned();
+                let mut keys = keys.lock().unwrap();
+                keys.push(key);
+                let attempt = keys.len();
+                async move {
+                    if attempt < 2 {
+                        Err(busy(None))
+                    } else {
+                        Ok(Response::new(*request.get_ref()))
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(*response.get_ref(), "create");
+        let keys = keys.lock().unwrap();
+        assert_eq!(keys.len(), 2);
+        assert!(keys[0].is_some());
+        assert_eq!(keys[0], keys[1]);
+    }
+}
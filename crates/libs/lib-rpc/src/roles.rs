@@ -0,0 +1,355 @@
+// -- ./src/roles.rs --
+
+//! Roles module - which RPCs each authenticated principal may call.
+//!
+//! Every [`Principal`](crate::Principal) has a [`Role`], and every RPC
+//! requires one. The roles are ordered, each allowing everything the one
+//! below it does:
+//!
+//! - **read-only**: reads, lists, reports and search
+//! - **editor**: also creates, updates and posts records
+//! - **admin**: also deletes records, rolls back imports, closes and
+//!   reopens periods and backs up the database
+//!
+//! The role an RPC requires is looked up by its method name in
+//! [`METHOD_ROLES`], with overrides from
+//! [`AuthInterceptor::with_method_role`](crate::AuthInterceptor::with_method_role).
+//! RPCs that are not listed require admin, so a new RPC is refused to
+//! everyone else until it is given a role.
+//!
+//! A tonic interceptor does not see the request path, so [`RpcMethodLayer`]
+//! reads it and puts the [`RpcMethod`] in the request extensions for the
+//! [`AuthInterceptor`](crate::AuthInterceptor) to check. Without the layer
+//! every RPC requires admin.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_rpc::{AuthInterceptor, CategoriesServiceServer, Role, RpcMethodLayer};
+//!
+//! # async fn example(categories: impl lib_rpc::CategoriesService) -> Result<(), Box<dyn std::error::Error>> {
+//! let auth = AuthInterceptor::new()
+//!     .with_api_key("a-long-random-key", "ian", Role::Admin)
+//!     .with_api_key("another-random-key", "accountant", Role::ReadOnly);
+//!
+//! tonic::transport::Server::builder()
+//!     .layer(RpcMethodLayer)
+//!     .add_service(CategoriesServiceServer::with_interceptor(categories, auth))
+//!     .serve("127.0.0.1:50059".parse()?)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::task::{Context, Poll};
+
+use tonic::codegen::http;
+
+/// What an authenticated principal may do, from least to most.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Role {
+    /// Read records and reports.
+    #[default]
+    ReadOnly,
+    /// Read, create and update records.
+    Editor,
+    /// Everything, including deleting records.
+    Admin,
+}
+
+impl Role {
+    /// The role's name, as used in the `role` claim of a JWT.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::ReadOnly => "read-only",
+            Role::Editor => "editor",
+            Role::Admin => "admin",
+        }
+    }
+
+    /// Whether this role may call an RPC requiring `required`.
+    pub fn allows(&self, required: Role) -> bool {
+        *self >= required
+    }
+
+    /// The role the RPC named `method` requires by default, admin if it is
+    /// not in [`METHOD_ROLES`].
+    pub fn required_for(method: &str) -> Role {
+        METHOD_ROLES
+            .iter()
+            .find(|(name, _)| *name == method)
+            .map_or(Role::Admin, |(_, role)| *role)
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Role {
+    type Err = String;
+
+    /// Parses a role's [name](Role::as_str), such as `read-only`.
+    fn from_str(role: &str) -> Result<Self, Self::Err> {
+        [Role::ReadOnly, Role::Editor, Role::Admin]
+            .into_iter()
+            .find(|known| known.as_str() == role)
+            .ok_or_else(|| format!("Unknown role: {role}"))
+    }
+}
+
+/// The role each RPC requires by default, by method name. Method names are
+/// unique across the services and kept between API versions.
+pub const METHOD_ROLES: &[(&str, Role)] = &[
+    // AccountsService
+    ("AccountsList", Role::ReadOnly),
+    ("AccountGet", Role::ReadOnly),
+    ("AccountGetBalance", Role::ReadOnly),
+    ("AccountSnapshotsList", Role::ReadOnly),
+    ("AccountAssertionsList", Role::ReadOnly),
+    ("AccountAssertionsCheck", Role::ReadOnly),
+    ("AccountCreate", Role::Editor),
+    ("AccountUpdate", Role::Editor),
+    ("AccountAdjustBalance", Role::Editor),
+    ("AccountSnapshotRecord", Role::Editor),
+    ("AccountAssertionRecord", Role::Editor),
+    ("AccountDelete", Role::Admin),
+    ("AccountSnapshotDelete", Role::Admin),
+    ("AccountAssertionDelete", Role::Admin),
+    // AttachmentsService
+    ("AttachmentsList", Role::ReadOnly),
+    ("AttachmentDownload", Role::ReadOnly),
+    ("AttachmentUpload", Role::Editor),
+    ("AttachmentDelete", Role::Admin),
+    // AuditService
+    ("AuditHistory", Role::ReadOnly),
+    // BudgetsService
+    ("BudgetsList", Role::ReadOnly),
+    ("BudgetGet", Role::ReadOnly),
+    ("BudgetsCompare", Role::ReadOnly),
+    ("BudgetsVariance", Role::ReadOnly),
+    ("BudgetCreate", Role::Editor),
+    ("BudgetUpdate", Role::Editor),
+    ("BudgetDelete", Role::Admin),
+    // CategoriesService
+    ("CategoriesList", Role::ReadOnly),
+    ("CategoriesStream", Role::ReadOnly),
+    ("CategoriesWatch", Role::ReadOnly),
+    ("CategoryGet", Role::ReadOnly),
+    ("CategoryGetByCode", Role::ReadOnly),
+    ("CategoryGetBySlug", Role::ReadOnly),
+    ("GetCategoryStatistics", Role::ReadOnly),
+    ("CategoryCreate", Role::Editor),
+    ("CategoriesCreateBatch", Role::Editor),
+    ("CategoryUpdate", Role::Editor),
+    ("CategoryActivate", Role::Editor),
+    ("CategoryDeactivate", Role::Editor),
+    ("CategoryArchive", Role::Editor),
+    ("CategoryRestore", Role::Editor),
+    ("CategoryDelete", Role::Admin),
+    ("CategoriesDeleteBatch", Role::Admin),
+    // ExportService
+    ("ExportBook", Role::ReadOnly),
+    ("ExportLedger", Role::ReadOnly),
+    // GoalsService
+    ("GoalsList", Role::ReadOnly),
+    ("GoalGet", Role::ReadOnly),
+    ("GoalsProgress", Role::ReadOnly),
+    ("GoalCreate", Role::Editor),
+    ("GoalUpdate", Role::Editor),
+    ("GoalDelete", Role::Admin),
+    // Health, the standard grpc.health.v1.Health service
+    ("Check", Role::ReadOnly),
+    ("Watch", Role::ReadOnly),
+    // ServerReflection, the standard grpc.reflection services
+    ("ServerReflectionInfo", Role::ReadOnly),
+    // PayeesService
+    ("PayeeAliasGet", Role::ReadOnly),
+    ("PayeeAliasesList", Role::ReadOnly),
+    ("PayeeNormalise", Role::ReadOnly),
+    ("PayeeAliasCreate", Role::Editor),
+    ("PayeeAliasUpdate", Role::Editor),
+    ("PayeeAliasDelete", Role::Admin),
+    // PeriodsService
+    ("PeriodGetStatus", Role::ReadOnly),
+    ("PeriodClose", Role::Admin),
+    ("PeriodReopen", Role::Admin),
+    // ReconciliationsService
+    ("ReconciliationsList", Role::ReadOnly),
+    ("ReconciliationCreate", Role::Editor),
+    ("ReconciliationDelete", Role::Admin),
+    // ReportsService
+    ("ReportsDashboard", Role::ReadOnly),
+    ("ReportsIncomeVsExpense", Role::ReadOnly),
+    ("ReportsNetWorth", Role::ReadOnly),
+    ("ReportsNetWorthHistory", Role::ReadOnly),
+    ("ReportsSpendingByCategory", Role::ReadOnly),
+    // SearchService
+    ("Search", Role::ReadOnly),
+    // TransactionsService
+    ("TransactionsList", Role::ReadOnly),
+    ("TransactionsStream", Role::ReadOnly),
+    ("TransactionsListExpiring", Role::ReadOnly),
+    ("TransactionsMapData", Role::ReadOnly),
+    ("TransactionsListByTag", Role::ReadOnly),
+    ("TransactionsListDuplicates", Role::ReadOnly),
+    ("TransactionTagsSuggest", Role::ReadOnly),
+    ("TransactionGet", Role::ReadOnly),
+    ("TransactionCreate", Role::Editor),
+    ("TransactionQuickAdd", Role::Editor),
+    ("TransactionUpdate", Role::Editor),
+    ("TransactionsBulkEdit", Role::Editor),
+    ("TransactionsReassign", Role::Editor),
+    ("TransactionsPostDrafts", Role::Editor),
+    ("TransactionsMarkCleared", Role::Editor),
+    ("TransactionsTransfer", Role::Editor),
+    ("TransactionsDismissDuplicate", Role::Editor),
+    ("TransactionDelete", Role::Admin),
+    ("TransactionsMergeDuplicate", Role::Admin),
+    ("TransactionsRollbackImport", Role::Admin),
+    // UtilitiesService
+    ("Ping", Role::ReadOnly),
+    ("ServerInfo", Role::ReadOnly),
+    ("MigrationStatus", Role::ReadOnly),
+    ("GetUsage", Role::ReadOnly),
+    ("Backup", Role::Admin),
+    ("VerifyLedger", Role::Admin),
+    ("RoundTripExport", Role::Admin),
+    ("SetLogLevel", Role::Admin),
+];
+
+/// The RPC a request is for, put in the request extensions by
+/// [`RpcMethodLayer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcMethod {
+    path: String,
+}
+
+impl RpcMethod {
+    /// The RPC at a request path such as
+    /// `/personal_ledger.categories.v001.CategoriesService/CategoryDelete`.
+    pub fn from_path(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+        }
+    }
+
+    /// The full request path.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The method name, the last segment of the path, e.g. `CategoryDelete`.
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or_default()
+    }
+}
+
+/// Layer that wraps services in [`RpcMethodExtension`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RpcMethodLayer;
+
+impl<S> tower::Layer<S> for RpcMethodLayer {
+    type Service = RpcMethodExtension<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcMethodExtension { inner }
+    }
+}
+
+/// Service that puts the [`RpcMethod`] of each request in its extensions.
+#[derive(Debug, Clone)]
+pub struct RpcMethodExtension<S> {
+    inner: S,
+}
+
+impl<S, ReqBody> tower::Service<http::Request<ReqBody>> for RpcMethodExtension<S>
+where
+    S: tower::Service<http::Request<ReqBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<ReqBody>) -> Self::Future {
+        let method = RpcMethod::from_path(request.uri().path());
+        request.extensions_mut().insert(method);
+
+        self.inner.call(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::{Layer, ServiceExt};
+
+    #[test]
+    fn roles_allow_everything_below_them() {
+        assert!(Role::Admin.allows(Role::Editor));
+        assert!(Role::Editor.allows(Role::Editor));
+        assert!(!Role::ReadOnly.allows(Role::Editor));
+        assert_eq!(Role::Editor.to_string(), "editor");
+    }
+
+    #[test]
+    fn roles_parse_from_their_names() {
+        for role in [Role::ReadOnly, Role::Editor, Role::Admin] {
+            assert_eq!(role.as_str().parse::<Role>(), Ok(role));
+        }
+        assert!("owner".parse::<Role>().is_err());
+    }
+
+    #[test]
+    fn unlisted_methods_require_admin() {
+        assert_eq!(Role::required_for("CategoryGet"), Role::ReadOnly);
+        assert_eq!(Role::required_for("CategoryDelete"), Role::Admin);
+        assert_eq!(Role::required_for("SomethingNew"), Role::Admin);
+    }
+
+    #[test]
+    fn every_rpc_has_a_role() {
+        let unlisted: Vec<_> = crate::ApiSurface::current()
+            .methods()
+            .filter(|(_, method)| !METHOD_ROLES.iter().any(|(name, _)| name == method))
+            .map(|(service, method)| format!("{service}.{method}"))
+            .collect();
+
+        assert!(
+            unlisted.is_empty(),
+            "RPCs missing from METHOD_ROLES: {unlisted:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn layer_puts_the_method_in_the_extensions() {
+        let handler = tower::service_fn(|request: http::Request<()>| async move {
+            Ok::<_, std::convert::Infallible>(request.extensions().get::<RpcMethod>().cloned())
+        });
+        let request = http::Request::builder()
+            .uri("/personal_ledger.categories.v001.CategoriesService/CategoryDelete")
+            .body(())
+            .unwrap();
+
+        let method = RpcMethodLayer
+            .layer(handler)
+            .oneshot(request)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(method.name(), "CategoryDelete");
+        assert_eq!(
+            method.path(),
+            "/personal_ledger.categories.v001.CategoriesService/CategoryDelete"
+        );
+    }
+}
@@ -0,0 +1,38 @@
+// -- ./src/search.rs --
+
+//! Search module - gRPC services and types for full-text search.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the search service. Matches are ranked by the database, best match first.
+//!
+//! ## Services
+//!
+//! - **SearchService**: Searches categories and transactions, with prefix and phrase
+//!   matching.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `SearchCategoryHit`: A category that matched a search
+//! - `SearchTransactionHit`: A transaction that matched a search
+//! - `SearchRequest` and `SearchResponse`
+//! - `SearchServiceClient`: gRPC client for connecting to search service
+//! - `SearchService`: Server trait for implementing search service
+//! - `SearchServiceServer`: Server implementation for search service
+
+// ---------------------------- [ SEARCH ] ----------------------------------
+
+/// gRPC client for the SearchService.
+/// Provides methods for searching categories and transactions.
+pub use crate::generated::search::search_service_client::SearchServiceClient;
+
+/// gRPC server trait and implementation for the SearchService.
+/// Implement the `SearchService` trait to handle incoming gRPC requests for search.
+pub use crate::generated::search::search_service_server::{SearchService, SearchServiceServer};
+
+/// Search-related message types.
+/// Includes structs for matches, requests, and responses used in the SearchService.
+/// These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::search::{
+    SearchCategoryHit, SearchRequest, SearchResponse, SearchTransactionHit,
+};
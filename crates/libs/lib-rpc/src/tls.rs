@@ -0,0 +1,132 @@
+// -- ./src/tls.rs --
+
+//! TLS module - encrypting connections between clients and the server.
+//!
+//! Certificates and keys are read from PEM files into tonic's
+//! [`ServerTlsConfig`] and [`ClientTlsConfig`]:
+//!
+//! - **TLS**: the server presents its certificate, and clients check it was
+//!   signed by the authority they were given, or a public one
+//! - **Mutual TLS**: the server is also given a client authority, and turns
+//!   away clients without a certificate it signed
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use std::path::Path;
+//! use lib_rpc::LedgerClient;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let tls = lib_rpc::load_client_tls(
+//!     Some(Path::new("ca.pem")),
+//!     Some((Path::new("client.pem"), Path::new("client.key"))),
+//! )?
+//! .domain_name("ledger.example.com");
+//!
+//! let client = LedgerClient::connect_tls("https://ledger.example.com:50059", tls).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::Path;
+
+use tonic::transport::Endpoint;
+pub use tonic::transport::{Certificate, ClientTlsConfig, Identity, ServerTlsConfig};
+
+use crate::LedgerClient;
+
+/// Reads a PEM file, naming the file in any error.
+fn read_pem(path: &Path) -> std::io::Result<Vec<u8>> {
+    std::fs::read(path)
+        .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {e}", path.display())))
+}
+
+/// Reads the server's certificate chain and private key, and the authority
+/// client certificates must be signed by when `client_ca` is given.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read. The PEM is only parsed when the
+/// server starts.
+pub fn load_server_tls(
+    cert: &Path,
+    key: &Path,
+    client_ca: Option<&Path>,
+) -> std::io::Result<ServerTlsConfig> {
+    let mut tls =
+        ServerTlsConfig::new().identity(Identity::from_pem(read_pem(cert)?, read_pem(key)?));
+    if let Some(client_ca) = client_ca {
+        tls = tls.client_ca_root(Certificate::from_pem(read_pem(client_ca)?));
+    }
+
+    Ok(tls)
+}
+
+/// Reads the authority the server's certificate must be signed by, or trusts
+/// the public web authorities when `ca` is `None`, and the client's
+/// certificate chain and private key for mutual TLS.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read.
+pub fn load_client_tls(
+    ca: Option<&Path>,
+    identity: Option<(&Path, &Path)>,
+) -> std::io::Result<ClientTlsConfig> {
+    let mut tls = match ca {
+        Some(ca) => ClientTlsConfig::new().ca_certificate(Certificate::from_pem(read_pem(ca)?)),
+        None => ClientTlsConfig::new().with_webpki_roots(),
+    };
+    if let Some((cert, key)) = identity {
+        tls = tls.identity(Identity::from_pem(read_pem(cert)?, read_pem(key)?));
+    }
+
+    Ok(tls)
+}
+
+impl LedgerClient {
+    /// Connects to the server at an `https://` `url` over TLS.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL or TLS settings are invalid, or the
+    /// server cannot be reached or its certificate is not trusted.
+    pub async fn connect_tls(
+        url: impl Into<String>,
+        tls: ClientTlsConfig,
+    ) -> Result<Self, tonic::transport::Error> {
+        let channel = Endpoint::from_shared(url.into())?
+            .tls_config(tls)?
+            .connect()
+            .await?;
+
+        Ok(Self::new(channel))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_files_are_named_in_errors() {
+        let missing = Path::new("/nonexistent/personal-ledger/server.pem");
+
+        let server = load_server_tls(missing, missing, None).unwrap_err();
+        let client = load_client_tls(Some(missing), None).unwrap_err();
+
+        for error in [server, client] {
+            assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+            assert!(
+                error
+                    .to_string()
+                    .contains("/nonexistent/personal-ledger/server.pem")
+            );
+        }
+    }
+
+    #[test]
+    fn client_without_authority_trusts_public_roots() {
+        assert!(load_client_tls(None, None).is_ok());
+    }
+}
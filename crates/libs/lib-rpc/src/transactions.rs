@@ -0,0 +1,69 @@
+// -- ./src/transactions.rs --
+
+//! Transactions module - gRPC services and types for financial transaction operations.
+//!
+//! This module provides re-exports of generated protobuf types and gRPC clients/servers
+//! for the transactions service. It handles recording the financial transactions that
+//! make up the personal ledger.
+//!
+//! ## Services
+//!
+//! - **TransactionsService**: Handles CRUD operations for financial transactions,
+//!   streaming large lists in pages, posting drafts in bulk, previewed bulk edits,
+//!   moving matching transactions to another category, clustering located transactions for a map, listing purchases whose return
+//!   window or warranty is ending, listing transactions by tag and suggesting tags,
+//!   ticking transactions off as cleared against a statement, and quick entry parsing of free text into a transaction preview.
+//!
+//! ## Types
+//!
+//! Core message types include:
+//! - `Transaction`: The main transaction struct with all fields
+//! - `TransactionPreview`: A transaction parsed from quick entry text
+//! - `TransactionFilter`, `TransactionChanges`: What a bulk edit or reassignment selects, and
+//!   what a bulk edit changes
+//! - `TransactionMapCluster`: The transactions within one cell of a map
+//! - `TransactionExpiry`, `ExpiryKinds`: A purchase whose return window or warranty ends soon
+//! - `ClearedStatus`: How far a transaction has been reconciled
+//! - `TagSuggestion`: A tag suggested as it is typed, with how many transactions use it
+//! - Request/Response types for all operations (Create, Get, Update, Delete, List, Stream,
+//!   PostDrafts, MarkCleared, BulkEdit, Reassign, MapData, ListExpiring, ListByTag, TagsSuggest, QuickAdd)
+//! - `TransactionsServiceClient`: gRPC client for connecting to transactions service
+//! - `TransactionsService`: Server trait for implementing transactions service
+//! - `TransactionsServiceServer`: Server implementation for transactions service
+
+// -------------------------- [ TRANSACTIONS ] ---------------------------------
+
+/// gRPC client for the TransactionsService.
+/// Provides methods for creating, reading, updating, deleting, listing and previewing
+/// financial transactions.
+pub use crate::generated::transactions::transactions_service_client::TransactionsServiceClient;
+
+/// gRPC server trait and implementation for the TransactionsService.
+/// Implement the `TransactionsService` trait to handle incoming gRPC requests for transactions.
+pub use crate::generated::transactions::transactions_service_server::{
+    TransactionsService, TransactionsServiceServer,
+};
+
+/// Transactions-related message types.
+/// Includes structs for transactions, requests, and responses used in the TransactionsService.
+/// These are protobuf-generated types for serialization and deserialization.
+pub use crate::generated::transactions::{
+    ClearedStatus, DuplicatePair, ExpiryKinds, Split, TagSuggestion, Transaction,
+    TransactionChanges, TransactionCreateRequest, TransactionCreateResponse,
+    TransactionDeleteRequest, TransactionDeleteResponse, TransactionExpiry, TransactionFilter,
+    TransactionGetRequest, TransactionGetResponse, TransactionMapCluster, TransactionPreview,
+    TransactionQuickAddRequest, TransactionQuickAddResponse, TransactionTagsSuggestRequest,
+    TransactionTagsSuggestResponse, TransactionUpdateRequest, TransactionUpdateResponse,
+    TransactionsBulkEditRequest, TransactionsBulkEditResponse, TransactionsDismissDuplicateRequest,
+    TransactionsDismissDuplicateResponse, TransactionsListByTagRequest,
+    TransactionsListByTagResponse, TransactionsListDuplicatesRequest,
+    TransactionsListDuplicatesResponse, TransactionsListExpiringRequest,
+    TransactionsListExpiringResponse, TransactionsListRequest, TransactionsListResponse,
+    TransactionsMapDataRequest, TransactionsMapDataResponse, TransactionsMarkClearedRequest,
+    TransactionsMarkClearedResponse, TransactionsMergeDuplicateRequest,
+    TransactionsMergeDuplicateResponse, TransactionsPostDraftsRequest,
+    TransactionsPostDraftsResponse, TransactionsReassignRequest, TransactionsReassignResponse,
+    TransactionsRollbackImportRequest, TransactionsRollbackImportResponse,
+    TransactionsStreamRequest, TransactionsStreamResponse, TransactionsTransferRequest,
+    TransactionsTransferResponse,
+};
@@ -3,37 +3,60 @@
 //! Utilities module - gRPC services and types for utility operations.
 //!
 //! This module provides re-exports of generated protobuf types and gRPC clients/servers
-//! for the utilities service. It includes health check functionality and other utility
-//! operations for the personal ledger system.
+//! for the utilities service. It includes the migration status, server information and
+//! backups of the personal ledger system. Health checks are served by the standard
+//! `grpc.health.v1.Health` service, see the health module.
 //!
 //! ## Services
 //!
-//! - **UtilitiesService**: Provides utility operations like health checks via ping.
+//! - **UtilitiesService**: Provides utility operations like the state of the database
+//!   migrations, the API versions served, database backups, usage against quotas and
+//!   verifying the ledger, and changing the log level at runtime. Its `Ping` is
+//!   deprecated in favour of the standard health service.
 //!
 //! ## Types
 //!
-//! - `PingRequest`: Empty request for ping operations
+//! - `PingRequest`: Empty request for the deprecated ping
 //! - `PingResponse`: Response containing a pong message
+//! - `Migration`: The state of one database migration
+//! - `MigrationStatusRequest`/`MigrationStatusResponse`: Request and response for the
+//!   migration status
+//! - `ServerInfoRequest`/`ServerInfoResponse`: Request and response for the server
+//!   version and the API version agreed with the client
+//! - `BackupRequest`/`BackupResponse`: Request and response for backing up the database
+//! - `ApiVersionInfo`: One API version the server serves, and its `ApiVersionStates`
+//! - `GetUsageRequest`/`GetUsageResponse`: Request and response for what the ledger
+//!   holds, each `ResourceUsage` with its quota
+//! - `VerifyLedgerRequest`/`VerifyLedgerResponse`: Request and response for checking the
+//!   ledger is consistent, each `LedgerFinding` from one of the `LedgerChecks`
+//! - `RoundTripExportRequest`/`RoundTripExportResponse`: Request and response for
+//!   reading an export back into an empty database, each `RoundTripCheck` comparing
+//!   one of the `RoundTripChecks`
+//! - `SetLogLevelRequest`/`SetLogLevelResponse`: Request and response for changing the
+//!   server's log filter
 //! - `UtilitiesServiceClient`: gRPC client for connecting to utilities service
 //! - `UtilitiesService`: Server trait for implementing utilities service
 //! - `UtilitiesServiceServer`: Server implementation for utilities service
 
-
-
 /// gRPC client for the UtilitiesService.
-/// Provides methods for utility operations, such as health checks via ping.
+/// Provides methods for utility operations, such as reporting the database
+/// migrations and backing up the database.
 pub use crate::generated::utilities::utilities_service_client::UtilitiesServiceClient;
 
 /// gRPC server trait and implementation for the UtilitiesService.
-/// Implement the `UtilitiesService` trait to handle utility requests like ping.
+/// Implement the `UtilitiesService` trait to handle utility requests like backups.
 pub use crate::generated::utilities::utilities_service_server::{
     UtilitiesService, UtilitiesServiceServer,
 };
 
 /// Utilities-related message types.
-/// Includes structs for ping requests and responses used in the UtilitiesService.
+/// Includes structs for ping and migration status requests and responses used in the UtilitiesService.
 /// These are protobuf-generated types for serialization and deserialization.
 pub use crate::generated::utilities::{
-    PingRequest,
-    PingResponse,
+    ApiVersionInfo, ApiVersionStates, BackupRequest, BackupResponse, GetUsageRequest,
+    GetUsageResponse, LedgerChecks, LedgerFinding, Migration, MigrationStatusRequest,
+    MigrationStatusResponse, PingRequest, PingResponse, ResourceUsage, RoundTripCheck,
+    RoundTripChecks, RoundTripExportRequest, RoundTripExportResponse, ServerInfoRequest,
+    ServerInfoResponse, SetLogLevelRequest, SetLogLevelResponse, VerifyLedgerRequest,
+    VerifyLedgerResponse,
 };
@@ -0,0 +1,355 @@
+// -- ./src/validation.rs --
+
+//! Validation module - checking request messages before they reach a handler.
+//!
+//! Handlers validate a request first with [`Validate::validate`], which checks
+//! every field and returns `INVALID_ARGUMENT` listing all the fields that are
+//! wrong at once, rather than failing on the first. Each problem is a
+//! `google.rpc.BadRequest` field violation in the status details, naming the
+//! field by its path in the request (e.g. `categories[1].color`), so a client
+//! can show the error beside the field it came from:
+//!
+//! ```rust
+//! use lib_rpc::{Category, CategoryCreateRequest, StatusExt, Validate};
+//!
+//! let request = CategoryCreateRequest {
+//!     category: Some(Category {
+//!         code: "food".to_string(),
+//!         name: "Food".to_string(),
+//!         color: Some("orange".to_string()),
+//!         ..Default::default()
+//!     }),
+//!     validate_only: false,
+//! };
+//!
+//! let status = request.validate().unwrap_err();
+//! let fields: Vec<_> = status
+//!     .get_details_bad_request()
+//!     .unwrap()
+//!     .field_violations
+//!     .into_iter()
+//!     .map(|violation| violation.field)
+//!     .collect();
+//! assert_eq!(fields, ["category.code", "category.color"]);
+//! ```
+//!
+//! The rules are the same for every service:
+//!
+//! - **Codes**: one to three groups of uppercase letters, digits and
+//!   underscores separated by dots, such as `DINING_OUT` or `EXP.GRO.001`
+//! - **Names**: not blank, and at most [`MAX_NAME_LENGTH`] characters
+//! - **Colours**: six hex digits, optionally after a `#`, such as `#FF8800`
+//! - **Pagination**: page sizes and limits are not negative, zero asks for
+//!   the default and larger requests are capped by the server
+
+use tonic::{Code, Status};
+use tonic_types::ErrorDetails;
+
+/// Field violations listed in a `google.rpc.BadRequest`.
+pub use tonic_types::{BadRequest, FieldViolation};
+
+/// Reads and writes the `google.rpc` details of a status.
+pub use tonic_types::StatusExt;
+
+use crate::{
+    CategoriesCreateBatchRequest, CategoriesListRequest, CategoriesStreamRequest, Category,
+    CategoryCreateRequest, CategoryUpdateRequest, ERROR_DOMAIN, ErrorReason, error_metadata,
+};
+
+/// Longest name, in characters, a category or other named record may have.
+pub const MAX_NAME_LENGTH: usize = 100;
+
+/// Most dot-separated groups a code may have.
+pub const MAX_CODE_GROUPS: usize = 3;
+
+/// The field violations found while validating a message.
+#[derive(Debug, Clone, Default)]
+pub struct Violations {
+    violations: Vec<FieldViolation>,
+}
+
+impl Violations {
+    /// Records that `field` is wrong, and why.
+    pub fn add(&mut self, field: impl Into<String>, description: impl Into<String>) {
+        self.violations
+            .push(FieldViolation::new(field, description));
+    }
+
+    /// Checks `code` is one to [`MAX_CODE_GROUPS`] dot-separated groups of
+    /// uppercase letters, digits and underscores.
+    pub fn code(&mut self, field: &str, code: &str) {
+        if code.trim().is_empty() {
+            return self.add(field, "must not be empty");
+        }
+
+        let groups: Vec<&str> = code.split('.').collect();
+        let valid_group = |group: &&str| {
+            !group.is_empty()
+                && group
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        };
+        if groups.len() > MAX_CODE_GROUPS || !groups.iter().all(valid_group) {
+            self.add(
+                field,
+                format!(
+                    "must be up to {MAX_CODE_GROUPS} groups of uppercase letters, digits and underscores separated by dots (e.g. EXP.GRO.001): {code}"
+                ),
+            );
+        }
+    }
+
+    /// Checks `name` is not blank and at most [`MAX_NAME_LENGTH`] characters.
+    pub fn name(&mut self, field: &str, name: &str) {
+        if name.trim().is_empty() {
+            self.add(field, "must not be empty");
+        } else if name.chars().count() > MAX_NAME_LENGTH {
+            self.add(
+                field,
+                format!("must be at most {MAX_NAME_LENGTH} characters"),
+            );
+        }
+    }
+
+    /// Checks `color`, if set, is six hex digits, optionally after a `#`.
+    pub fn hex_color(&mut self, field: &str, color: Option<&str>) {
+        let Some(color) = color else {
+            return;
+        };
+
+        let digits = color.trim();
+        let digits = digits.strip_prefix('#').unwrap_or(digits);
+        if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            self.add(
+                field,
+                format!("must be six hex digits (e.g. #FF8800): {color}"),
+            );
+        }
+    }
+
+    /// Checks a page size or limit is not negative.
+    pub fn page_size(&mut self, field: &str, size: i32) {
+        if size < 0 {
+            self.add(field, format!("must not be negative: {size}"));
+        }
+    }
+
+    /// Whether no violations were found.
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns `Ok` if no violations were found, otherwise an
+    /// `INVALID_ARGUMENT` status listing them in a `google.rpc.BadRequest`,
+    /// with a `VALIDATION_FAILED` [`ErrorReason`] naming the first field.
+    pub fn into_result(self) -> Result<(), Status> {
+        if self.violations.is_empty() {
+            return Ok(());
+        }
+
+        let message = self
+            .violations
+            .iter()
+            .map(|violation| format!("Invalid {}: {}", violation.field, violation.description))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        let first = self.violations[0].field.clone();
+        let mut details = ErrorDetails::with_bad_request(self.violations);
+        details.set_error_info(
+            ErrorReason::ValidationFailed.as_str(),
+            ERROR_DOMAIN,
+            error_metadata(None, Some(&first)),
+        );
+
+        Err(Status::with_error_details(
+            Code::InvalidArgument,
+            message,
+            details,
+        ))
+    }
+}
+
+/// A request or message whose fields can be checked before it is handled.
+pub trait Validate {
+    /// Adds the violations of the message's fields to `violations`, naming
+    /// each field under `prefix`, or at the top level if `prefix` is empty.
+    fn validate_fields(&self, prefix: &str, violations: &mut Violations);
+
+    /// Validates the message, see the [module docs](self).
+    fn validate(&self) -> Result<(), Status> {
+        self.validate_as("")
+    }
+
+    /// Validates the message as the field `prefix` of a request, such as the
+    /// merged category of an update.
+    fn validate_as(&self, prefix: &str) -> Result<(), Status> {
+        let mut violations = Violations::default();
+        self.validate_fields(prefix, &mut violations);
+        violations.into_result()
+    }
+}
+
+/// Joins a field name onto the path of the message it is in.
+fn path(prefix: &str, field: &str) -> String {
+    match prefix {
+        "" => field.to_string(),
+        prefix => format!("{prefix}.{field}"),
+    }
+}
+
+impl Validate for Category {
+    fn validate_fields(&self, prefix: &str, violations: &mut Violations) {
+        violations.code(&path(prefix, "code"), &self.code);
+        violations.name(&path(prefix, "name"), &self.name);
+        violations.hex_color(&path(prefix, "color"), self.color.as_deref());
+    }
+}
+
+impl Validate for CategoryCreateRequest {
+    fn validate_fields(&self, prefix: &str, violations: &mut Violations) {
+        match &self.category {
+            Some(category) => category.validate_fields(&path(prefix, "category"), violations),
+            None => violations.add(path(prefix, "category"), "is required"),
+        }
+    }
+}
+
+impl Validate for CategoriesCreateBatchRequest {
+    fn validate_fields(&self, prefix: &str, violations: &mut Violations) {
+        for (index, category) in self.categories.iter().enumerate() {
+            category.validate_fields(&path(prefix, &format!("categories[{index}]")), violations);
+        }
+    }
+}
+
+impl Validate for CategoryUpdateRequest {
+    /// Only checks a category is given, as an update with a mask carries just
+    /// the masked fields. Validate the category merged with the stored one
+    /// with [`Validate::validate_as`].
+    fn validate_fields(&self, prefix: &str, violations: &mut Violations) {
+        if self.category.is_none() {
+            violations.add(path(prefix, "category"), "is required");
+        }
+    }
+}
+
+impl Validate for CategoriesListRequest {
+    fn validate_fields(&self, prefix: &str, violations: &mut Violations) {
+        violations.page_size(&path(prefix, "limit"), self.limit);
+    }
+}
+
+impl Validate for CategoriesStreamRequest {
+    fn validate_fields(&self, prefix: &str, violations: &mut Violations) {
+        violations.page_size(&path(prefix, "page_size"), self.page_size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(code: &str, name: &str, color: Option<&str>) -> Category {
+        Category {
+            code: code.to_string(),
+            name: name.to_string(),
+            color: color.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn fields(status: &Status) -> Vec<String> {
+        status
+            .get_details_bad_request()
+            .expect("bad request details")
+            .field_violations
+            .into_iter()
+            .map(|violation| violation.field)
+            .collect()
+    }
+
+    #[test]
+    fn valid_category_passes() {
+        for code in ["FOOD", "DINING_OUT", "EXP.GRO", "ABC.DEF.GHI", "EXP.001"] {
+            assert!(
+                category(code, "Groceries", Some("#ff8800"))
+                    .validate()
+                    .is_ok(),
+                "{code}"
+            );
+        }
+        assert!(category("FOOD", "Food", None).validate().is_ok());
+    }
+
+    #[test]
+    fn codes_must_be_dotted_uppercase_groups() {
+        for code in ["", "food", "FOOD.", ".FOOD", "A.B.C.D", "FO OD", "FOOD-1"] {
+            let status = category(code, "Food", None).validate().unwrap_err();
+            assert_eq!(fields(&status), ["code"], "{code}");
+        }
+    }
+
+    #[test]
+    fn names_must_not_be_blank_or_too_long() {
+        let long = "a".repeat(MAX_NAME_LENGTH + 1);
+        for name in [" ", long.as_str()] {
+            let status = category("FOOD", name, None).validate().unwrap_err();
+            assert_eq!(fields(&status), ["name"]);
+        }
+        assert!(
+            category("FOOD", &"é".repeat(MAX_NAME_LENGTH), None)
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn every_violation_is_reported_with_its_path() {
+        let request = CategoriesCreateBatchRequest {
+            categories: vec![
+                category("FOOD", "Food", None),
+                category("rent", "", Some("#12345")),
+            ],
+            validate_only: false,
+        };
+
+        let status = request.validate().unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(
+            fields(&status),
+            [
+                "categories[1].code",
+                "categories[1].name",
+                "categories[1].color"
+            ]
+        );
+        assert!(status.message().starts_with("Invalid categories[1].code: "));
+        let info = crate::error_info(&status).expect("error info");
+        assert_eq!(info.reason(), Some(ErrorReason::ValidationFailed));
+        assert_eq!(info.field(), Some("categories[1].code"));
+    }
+
+    #[test]
+    fn pagination_must_not_be_negative() {
+        let list = CategoriesListRequest {
+            limit: -1,
+            ..Default::default()
+        };
+        assert_eq!(fields(&list.validate().unwrap_err()), ["limit"]);
+
+        let stream = CategoriesStreamRequest { page_size: 0 };
+        assert!(stream.validate().is_ok());
+    }
+
+    #[test]
+    fn missing_category_is_reported() {
+        let request = CategoryCreateRequest {
+            category: None,
+            validate_only: false,
+        };
+
+        assert_eq!(fields(&request.validate().unwrap_err()), ["category"]);
+    }
+}
@@ -0,0 +1,207 @@
+// -- ./src/versions.rs --
+
+//! Versions module - the API versions served, and agreeing on one with a client.
+//!
+//! Each API version is a set of proto packages ending in the version name, e.g.
+//! `personal_ledger.categories.v001`. gRPC routes requests by package, so a
+//! breaking change ships as a new version alongside the old one, and clients
+//! built against the old version keep working until it is removed.
+//!
+//! ## Deprecation Policy
+//!
+//! - The newest version is [`ApiVersionStates::Current`].
+//! - When a new version ships, the previous one stays
+//!   [`ApiVersionStates::Supported`] for at least one release.
+//! - A version is then [`ApiVersionStates::Deprecated`] with a sunset date at
+//!   least six months out. Responses to its RPCs carry `deprecation` and
+//!   `sunset` metadata, so clients can warn before it goes.
+//! - After the sunset date the version's packages are removed.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use lib_rpc::{ApiVersion, CURRENT_API_VERSION};
+//!
+//! // A client built against v001 and a future v002
+//! let agreed = ApiVersion::negotiate(&["v001".to_string(), "v002".to_string()]);
+//! assert_eq!(agreed.map(|version| version.name), Some(CURRENT_API_VERSION));
+//! ```
+
+// ---------------------------- [ VERSIONS ] -----------------------------------
+
+use crate::{ApiVersionInfo, ApiVersionStates};
+
+/// Package prefix shared by every API version's protos.
+const PACKAGE_PREFIX: &str = "personal_ledger.";
+
+/// The API version new clients should use.
+pub const CURRENT_API_VERSION: &str = "v001";
+
+/// A version of the API the server serves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApiVersion {
+    /// The version name, the last part of its proto package names.
+    pub name: &'static str,
+
+    /// Whether the version is current, supported or deprecated.
+    pub state: ApiVersionStates,
+
+    /// The date a deprecated version stops being served, in ISO 8601 format.
+    pub sunset_on: Option<&'static str>,
+}
+
+/// Every API version served, newest first.
+pub const API_VERSIONS: &[ApiVersion] = &[ApiVersion {
+    name: CURRENT_API_VERSION,
+    state: ApiVersionStates::Current,
+    sunset_on: None,
+}];
+
+impl ApiVersion {
+    /// Finds a served API version by name.
+    pub fn find(name: &str) -> Option<&'static ApiVersion> {
+        Self::find_in(API_VERSIONS, name)
+    }
+
+    /// Finds an API version by name in the given versions.
+    pub fn find_in<'v>(versions: &'v [ApiVersion], name: &str) -> Option<&'v ApiVersion> {
+        versions.iter().find(|version| version.name == name)
+    }
+
+    /// Agrees on the newest served version the client also supports. A
+    /// client that names no versions gets the current version.
+    ///
+    /// Returns `None` when none of the client's versions are served.
+    pub fn negotiate(client_versions: &[String]) -> Option<&'static ApiVersion> {
+        Self::negotiate_in(API_VERSIONS, client_versions)
+    }
+
+    /// Agrees on the newest of the given versions the client also supports.
+    /// `versions` must be newest first, as [`API_VERSIONS`] is.
+    pub fn negotiate_in<'v>(
+        versions: &'v [ApiVersion],
+        client_versions: &[String],
+    ) -> Option<&'v ApiVersion> {
+        if client_versions.is_empty() {
+            return versions
+                .iter()
+                .find(|version| version.state == ApiVersionStates::Current);
+        }
+
+        versions.iter().find(|version| {
+            client_versions
+                .iter()
+                .any(|name| name.trim() == version.name)
+        })
+    }
+
+    /// The API version of a gRPC request path, e.g. `"v001"` for
+    /// `/personal_ledger.categories.v001.CategoriesService/CategoryGet`.
+    ///
+    /// Returns `None` for paths outside the Personal Ledger packages, such
+    /// as the gRPC health and reflection services.
+    pub fn name_of_path(path: &str) -> Option<&str> {
+        let service = path.strip_prefix('/')?.split('/').next()?;
+        let package = service.strip_prefix(PACKAGE_PREFIX)?.rsplit_once('.')?.0;
+
+        package.rsplit_once('.').map(|(_, version)| version)
+    }
+
+    /// Whether the version will be removed.
+    pub fn is_deprecated(&self) -> bool {
+        self.state == ApiVersionStates::Deprecated
+    }
+}
+
+impl From<&ApiVersion> for ApiVersionInfo {
+    fn from(version: &ApiVersion) -> Self {
+        ApiVersionInfo {
+            name: version.name.to_string(),
+            state: version.state as i32,
+            sunset_on: version.sunset_on.map(str::to_string),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERSIONS: &[ApiVersion] = &[
+        ApiVersion {
+            name: "v003",
+            state: ApiVersionStates::Current,
+            sunset_on: None,
+        },
+        ApiVersion {
+            name: "v002",
+            state: ApiVersionStates::Supported,
+            sunset_on: None,
+        },
+        ApiVersion {
+            name: "v001",
+            state: ApiVersionStates::Deprecated,
+            sunset_on: Some("2026-06-30"),
+        },
+    ];
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn served_versions_have_one_current_version() {
+        let current: Vec<_> = API_VERSIONS
+            .iter()
+            .filter(|version| version.state == ApiVersionStates::Current)
+            .collect();
+
+        assert_eq!(current.len(), 1);
+        assert_eq!(current[0].name, CURRENT_API_VERSION);
+        assert_eq!(ApiVersion::find(CURRENT_API_VERSION), Some(current[0]));
+        assert!(
+            API_VERSIONS
+                .iter()
+                .all(|version| version.is_deprecated() == version.sunset_on.is_some())
+        );
+    }
+
+    #[test]
+    fn negotiate_picks_newest_shared_version() {
+        let agreed = |client: &[&str]| {
+            ApiVersion::negotiate_in(VERSIONS, &names(client)).map(|version| version.name)
+        };
+
+        assert_eq!(agreed(&["v001", "v002"]), Some("v002"));
+        assert_eq!(agreed(&["v001", "v004"]), Some("v001"));
+        assert_eq!(agreed(&[]), Some("v003"));
+        assert_eq!(agreed(&["v004"]), None);
+    }
+
+    #[test]
+    fn name_of_path_reads_the_package_version() {
+        assert_eq!(
+            ApiVersion::name_of_path(
+                "/personal_ledger.categories.v001.CategoriesService/CategoryGet"
+            ),
+            Some("v001")
+        );
+        assert_eq!(
+            ApiVersion::name_of_path("/grpc.health.v1.Health/Check"),
+            None
+        );
+        assert_eq!(
+            ApiVersion::name_of_path("personal_ledger.categories.v001.CategoriesService"),
+            None
+        );
+    }
+
+    #[test]
+    fn converts_to_rpc_message() {
+        let info = ApiVersionInfo::from(&VERSIONS[2]);
+
+        assert_eq!(info.name, "v001");
+        assert_eq!(info.state, ApiVersionStates::Deprecated as i32);
+        assert_eq!(info.sunset_on.as_deref(), Some("2026-06-30"));
+    }
+}
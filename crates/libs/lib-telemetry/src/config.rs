@@ -1,4 +1,3 @@
-
 //! # Telemetry Configuration
 //!
 //! This module provides configuration structures for the telemetry system in the Personal Ledger application.
@@ -11,6 +10,9 @@
 //!
 //! The `TelemetryConfig` struct encapsulates all telemetry-related settings:
 //! - **Log Level**: Controls the verbosity of telemetry output (OFF, ERROR, WARN, INFO, DEBUG, TRACE)
+//! - **Response Metadata**: Debug flag that adds request ID and server timing metadata to RPC responses
+//! - **OTLP Export**: Optional OpenTelemetry collector endpoint spans are exported to, with the
+//!   service name they are exported under and the fraction of traces sampled
 //! - **Default Behavior**: Provides sensible defaults for production use
 //!
 //! ## Usage
@@ -35,7 +37,11 @@
 //! ```json
 //! {
 //!   "telemetry": {
-//!     "telemetry_level": "debug"
+//!     "telemetry_level": "debug",
+//!     "response_metadata": true,
+//!     "otlp_endpoint": "http://localhost:4318",
+//!     "service_name": "personal-ledger",
+//!     "sampling_ratio": 0.25
 //!   }
 //! }
 //! ```
@@ -56,6 +62,23 @@
 /// for production deployments.
 const DEFAULT_TELEMETRY_LEVEL: super::TelemetryLevels = super::TelemetryLevels::INFO;
 
+/// Default service name spans are exported under.
+const DEFAULT_SERVICE_NAME: &str = "personal-ledger";
+
+/// Default fraction of traces exported, every one of them.
+const DEFAULT_SAMPLING_RATIO: f64 = 1.0;
+
+/// The OTLP/HTTP path traces are sent to on a collector.
+const OTLP_TRACES_PATH: &str = "/v1/traces";
+
+fn default_service_name() -> String {
+    DEFAULT_SERVICE_NAME.to_string()
+}
+
+fn default_sampling_ratio() -> f64 {
+    DEFAULT_SAMPLING_RATIO
+}
+
 /// Configuration structure for telemetry settings.
 ///
 /// This struct encapsulates all configurable aspects of the telemetry system,
@@ -85,9 +108,11 @@ const DEFAULT_TELEMETRY_LEVEL: super::TelemetryLevels = super::TelemetryLevels::
 /// // Custom configuration
 /// let custom_config = TelemetryConfig {
 ///     telemetry_level: lib_telemetry::TelemetryLevels::DEBUG,
+///     response_metadata: true,
+///     ..TelemetryConfig::default()
 /// };
 /// ```
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, PartialEq)]
 pub struct TelemetryConfig {
     /// The telemetry logging level for the application.
     ///
@@ -104,6 +129,35 @@ pub struct TelemetryConfig {
     /// - `DEBUG`: Detailed debugging information
     /// - `TRACE`: Very detailed execution tracing
     pub telemetry_level: super::TelemetryLevels,
+
+    /// Whether RPC responses carry debug metadata.
+    ///
+    /// When enabled the server echoes the request ID and reports the total
+    /// server processing time and database time for every RPC as response
+    /// metadata, so performance can be investigated from the client side
+    /// without access to server logs. Disabled by default.
+    #[serde(default)]
+    pub response_metadata: bool,
+
+    /// The OpenTelemetry collector spans are exported to over OTLP/HTTP.
+    ///
+    /// The collector's address, e.g. `http://localhost:4318` for Jaeger or
+    /// Tempo, or the full traces URL ending in `/v1/traces`. Spans pass the
+    /// same level filter as console output. Unset by default, which keeps
+    /// tracing local.
+    #[serde(default)]
+    pub otlp_endpoint: Option<String>,
+
+    /// The service name exported spans are grouped under. Defaults to
+    /// `personal-ledger`.
+    #[serde(default = "default_service_name")]
+    pub service_name: String,
+
+    /// The fraction of traces exported, from `0.0` for none to `1.0` for all
+    /// of them (the default). A trace started by a client that sent its own
+    /// sampling decision follows the client's decision instead.
+    #[serde(default = "default_sampling_ratio")]
+    pub sampling_ratio: f64,
 }
 
 impl Default for TelemetryConfig {
@@ -125,6 +179,10 @@ impl Default for TelemetryConfig {
     fn default() -> Self {
         Self {
             telemetry_level: DEFAULT_TELEMETRY_LEVEL,
+            response_metadata: false,
+            otlp_endpoint: None,
+            service_name: default_service_name(),
+            sampling_ratio: DEFAULT_SAMPLING_RATIO,
         }
     }
 }
@@ -157,4 +215,58 @@ impl TelemetryConfig {
     pub fn telemetry_level(&self) -> super::TelemetryLevels {
         self.telemetry_level
     }
-}
\ No newline at end of file
+
+    /// Whether request ID and timing metadata should be added to RPC responses.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_telemetry::TelemetryConfig;
+    ///
+    /// let config = TelemetryConfig::default();
+    /// assert!(!config.response_metadata());
+    /// ```
+    pub fn response_metadata(&self) -> bool {
+        self.response_metadata
+    }
+
+    /// The URL spans are exported to, or `None` when OTLP export is off.
+    ///
+    /// `/v1/traces` is added to a collector address that does not already
+    /// end in it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib_telemetry::TelemetryConfig;
+    ///
+    /// let config = TelemetryConfig {
+    ///     otlp_endpoint: Some("http://localhost:4318".to_string()),
+    ///     ..TelemetryConfig::default()
+    /// };
+    /// assert_eq!(config.otlp_traces_endpoint().as_deref(), Some("http://localhost:4318/v1/traces"));
+    /// assert_eq!(TelemetryConfig::default().otlp_traces_endpoint(), None);
+    /// ```
+    pub fn otlp_traces_endpoint(&self) -> Option<String> {
+        let endpoint = self.otlp_endpoint.as_deref()?.trim().trim_end_matches('/');
+        if endpoint.is_empty() {
+            return None;
+        }
+
+        if endpoint.ends_with(OTLP_TRACES_PATH) {
+            Some(endpoint.to_string())
+        } else {
+            Some(format!("{endpoint}{OTLP_TRACES_PATH}"))
+        }
+    }
+
+    /// The service name exported spans are grouped under.
+    pub fn service_name(&self) -> &str {
+        &self.service_name
+    }
+
+    /// The fraction of traces exported, from `0.0` to `1.0`.
+    pub fn sampling_ratio(&self) -> f64 {
+        self.sampling_ratio
+    }
+}
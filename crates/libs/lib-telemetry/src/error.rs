@@ -1,4 +1,3 @@
-
 #![allow(unused)] // Allow unused code during development
 
 /// Result type alias for telemetry operations.
@@ -14,7 +13,7 @@ pub type TelemetryResult<T> = std::result::Result<T, TelemetryError>;
 pub enum TelemetryError {
     /// Generic telemetry errors.
     ///
-    /// This covers generic telemetry issues that don't fit into the more specific 
+    /// This covers generic telemetry issues that don't fit into the more specific
     /// error categories above.
     #[error("Telemetry generic error: {0}")]
     Generic(String),
@@ -44,14 +43,14 @@ impl TelemetryError {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use fake::faker::lorem::en::Sentence;
     use fake::Fake;
+    use fake::faker::lorem::en::Sentence;
 
     #[test]
     fn test_generic_error_with_string() {
         let message = Sentence(3..8).fake::<String>();
         let error = TelemetryError::generic(message.clone());
-        
+
         match error {
             TelemetryError::Generic(msg) => assert_eq!(msg, message),
         }
@@ -61,7 +60,7 @@ mod tests {
     fn test_generic_error_with_str() {
         let message = Sentence(3..8).fake::<String>();
         let error = TelemetryError::generic(message.as_str());
-        
+
         match error {
             TelemetryError::Generic(msg) => assert_eq!(msg, message),
         }
@@ -72,7 +71,7 @@ mod tests {
         let message = Sentence(3..8).fake::<String>();
         let error = TelemetryError::generic(message.clone());
         let display_msg = format!("{}", error);
-        
+
         assert_eq!(display_msg, format!("Telemetry generic error: {}", message));
     }
 
@@ -81,7 +80,7 @@ mod tests {
         let message = Sentence(3..8).fake::<String>();
         let error = TelemetryError::generic(message.clone());
         let debug_msg = format!("{:?}", error);
-        
+
         // The debug output should contain the variant name and message
         assert!(debug_msg.contains("Generic"));
         assert!(debug_msg.contains(&message));
@@ -100,4 +99,4 @@ mod tests {
         let error_result: TelemetryResult<i32> = Err(TelemetryError::generic(error_message));
         assert!(error_result.is_err());
     }
-}
\ No newline at end of file
+}
@@ -12,7 +12,8 @@
 //! The initialisation process follows these steps:
 //!
 //! 1. **Event Filtering**: Configure which log levels and targets to include/exclude
-//! 2. **Collector Setup**: Create formatter and output destinations for log events
+//! 2. **Collector Setup**: Create formatter and output destinations for log events, and
+//!    an OpenTelemetry exporter when an OTLP endpoint is configured
 //! 3. **Registry Building**: Combine filters and collectors into a subscriber registry
 //! 4. **Integration**: Bridge with the standard `log` crate for compatibility
 //! 5. **Activation**: Set the global default subscriber to start collecting telemetry
@@ -31,11 +32,36 @@
 //!
 //! # Ok::<(), lib_telemetry::TelemetryError>(())
 //! ```
+//!
+//! ## OpenTelemetry Export
+//!
+//! [`init_with_config`] also exports spans to an OpenTelemetry collector such as
+//! Jaeger or Tempo over OTLP/HTTP, when the configuration sets `otlp_endpoint`. Spans
+//! are batched and sent from a background thread, so call [`shutdown`] before the
+//! process exits to send the last of them.
+//!
+//! ## Changing the Level at Runtime
+//!
+//! The level filter is installed behind a reload handle, so [`set_filter`] can turn
+//! logging up to `trace` on a live server to look into an issue, and back down again,
+//! without a restart.
 
+use std::sync::OnceLock;
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
 use tracing::subscriber::set_global_default;
-use tracing_subscriber::{EnvFilter, prelude::*};
+use tracing_subscriber::{EnvFilter, Registry, prelude::*, reload};
+
+use crate::{TelemetryConfig, TelemetryError, TelemetryLevels, TelemetryResult};
+
+/// The tracer provider exporting spans over OTLP, kept so [`shutdown`] can
+/// flush it.
+static TRACER_PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
 
-use crate::{TelemetryError, TelemetryLevels, TelemetryResult};
+/// Handle to the installed level filter, kept so [`set_filter`] can replace it.
+static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
 
 /// Initialises the telemetry system for the Personal Ledger application.
 ///
@@ -89,11 +115,46 @@ use crate::{TelemetryError, TelemetryLevels, TelemetryResult};
 /// // The function will return an error if telemetry is already initialised
 /// // or if there are conflicts with existing loggers
 /// # Ok::<(), lib_telemetry::TelemetryError>(())
-/// ``` 
-pub fn init(
-    telemetry_level: Option<&TelemetryLevels>,
-) -> TelemetryResult<()> {
+/// ```
+pub fn init(telemetry_level: Option<&TelemetryLevels>) -> TelemetryResult<()> {
+    let mut config = TelemetryConfig::default();
+    if let Some(&level) = telemetry_level {
+        config.telemetry_level = level;
+    }
+
+    init_with_config(&config)
+}
+
+/// Initialises the telemetry system from the full telemetry configuration.
+///
+/// Works as [`init`] with the configured level, and also exports spans over OTLP when
+/// `otlp_endpoint` is set, under the configured service name and sampling ratio.
+///
+/// # Errors
+///
+/// Returns a `TelemetryError` as [`init`] does, or if:
+/// - The sampling ratio is not between 0.0 and 1.0
+/// - The OTLP endpoint is not a valid URL
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use lib_telemetry::{init_with_config, shutdown, TelemetryConfig};
+///
+/// let config = TelemetryConfig {
+///     otlp_endpoint: Some("http://localhost:4318".to_string()),
+///     ..TelemetryConfig::default()
+/// };
+/// init_with_config(&config)?;
+///
+/// // ... run the application ...
+///
+/// shutdown();
+/// # Ok::<(), lib_telemetry::TelemetryError>(())
+/// ```
+pub fn init_with_config(config: &TelemetryConfig) -> TelemetryResult<()> {
     // TODO: Add log file functionality
+    let telemetry_level = Some(&config.telemetry_level);
 
     // ============================================================================
     // Phase 1: Configure Event Filtering (Tracing/Log Level)
@@ -114,25 +175,36 @@ pub fn init(
     // Try to use runtime level from RUST_LOG env var, fallback to configured default
     let env_filter = EnvFilter::try_from_default_env().unwrap_or(default_env_filter);
 
+    // Install the filter behind a reload handle, so it can be changed at runtime
+    let (env_filter, filter_handle) = reload::Layer::new(env_filter);
+
     // ============================================================================
     // Phase 2: Configure Event Collection
     // ============================================================================
     // Build event collector for console output with default formatting
     let console_collector = tracing_subscriber::fmt::layer();
 
+    // Build span collector for OTLP export, when an endpoint is configured
+    let tracer_provider = otlp_tracer_provider(config)?;
+    let otlp_collector = tracer_provider.as_ref().map(|provider| {
+        tracing_opentelemetry::layer().with_tracer(provider.tracer("personal-ledger"))
+    });
+
     // ============================================================================
     // Phase 3: Build Subscriber Registry
     // ============================================================================
     // Combine filters and collectors into a complete subscriber registry
     let registry = tracing_subscriber::registry()
         .with(env_filter)
-        .with(console_collector);
+        .with(console_collector)
+        .with(otlp_collector);
 
     // ============================================================================
     // Phase 4: Integrate with Standard Log Crate
     // ============================================================================
     // Convert all log records into tracing events for unified processing
-    tracing_log::LogTracer::init().map_err(|e| TelemetryError::generic(format!("Log tracer initialisation failed: {}", e)))?;
+    tracing_log::LogTracer::init()
+        .map_err(|e| TelemetryError::generic(format!("Log tracer initialisation failed: {}", e)))?;
 
     // ============================================================================
     // Phase 5: Activate Global Subscriber
@@ -142,9 +214,119 @@ pub fn init(
         TelemetryError::generic(format!("Failed to set global default subscriber: {}", e))
     })?;
 
+    let _ = FILTER_HANDLE.set(filter_handle);
+    if let Some(provider) = tracer_provider {
+        let _ = TRACER_PROVIDER.set(provider);
+    }
+
     Ok(())
 }
 
+/// Flushes the spans waiting to be exported over OTLP and stops the exporter.
+///
+/// Does nothing when telemetry was initialised without an OTLP endpoint. Spans recorded
+/// afterwards are still logged to the console, but no longer exported.
+pub fn shutdown() {
+    if let Some(provider) = TRACER_PROVIDER.get()
+        && let Err(e) = provider.shutdown()
+    {
+        tracing::warn!("OTLP exporter shutdown failed: {}", e);
+    }
+}
+
+/// Returns the filter events and spans currently pass, or `None` before telemetry is
+/// initialised.
+///
+/// The filter is shown as `tracing` directives, e.g. `info` or
+/// `info,lib_database=trace`.
+pub fn current_filter() -> Option<String> {
+    FILTER_HANDLE
+        .get()?
+        .with_current(|filter| filter.to_string())
+        .ok()
+}
+
+/// Replaces the filter events and spans pass, returning the filter it replaced.
+///
+/// The filter is a level such as `trace`, or `tracing` directives such as
+/// `info,lib_database=trace`, as `RUST_LOG` takes. It applies to console output and
+/// OTLP export alike, until it is replaced again or the process exits.
+///
+/// # Errors
+///
+/// Returns a `TelemetryError` if:
+/// - Telemetry has not been initialised
+/// - The filter is not valid `tracing` directives
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use lib_telemetry::set_filter;
+///
+/// let previous = set_filter("trace")?;
+///
+/// // ... reproduce the issue ...
+///
+/// set_filter(&previous)?;
+/// # Ok::<(), lib_telemetry::TelemetryError>(())
+/// ```
+pub fn set_filter(directives: &str) -> TelemetryResult<String> {
+    let handle = FILTER_HANDLE
+        .get()
+        .ok_or_else(|| TelemetryError::generic("Telemetry is not initialised"))?;
+
+    let filter = EnvFilter::try_new(directives)
+        .map_err(|e| TelemetryError::generic(format!("Invalid filter {}: {}", directives, e)))?;
+
+    let mut previous = None;
+    handle
+        .modify(|current| previous = Some(std::mem::replace(current, filter).to_string()))
+        .map_err(|e| TelemetryError::generic(format!("Filter reload failed: {}", e)))?;
+
+    tracing::info!("Telemetry filter changed to {}", directives);
+
+    Ok(previous.unwrap_or_default())
+}
+
+/// Builds the tracer provider exporting spans to the configured OTLP endpoint, or `None`
+/// when there is no endpoint.
+fn otlp_tracer_provider(config: &TelemetryConfig) -> TelemetryResult<Option<SdkTracerProvider>> {
+    let Some(endpoint) = config.otlp_traces_endpoint() else {
+        return Ok(None);
+    };
+
+    let sampling_ratio = config.sampling_ratio();
+    if !(0.0..=1.0).contains(&sampling_ratio) {
+        return Err(TelemetryError::generic(format!(
+            "Sampling ratio must be between 0.0 and 1.0, not {}",
+            sampling_ratio
+        )));
+    }
+
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(endpoint.as_str())
+        .build()
+        .map_err(|e| {
+            TelemetryError::generic(format!("OTLP exporter for {} failed: {}", endpoint, e))
+        })?;
+
+    // Follow the sampling decision of a trace started elsewhere, so a trace is
+    // never exported in pieces
+    let sampler = Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(sampling_ratio)));
+    let resource = opentelemetry_sdk::Resource::builder()
+        .with_service_name(config.service_name().to_string())
+        .build();
+
+    Ok(Some(
+        SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_sampler(sampler)
+            .with_resource(resource)
+            .build(),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,7 +336,7 @@ mod tests {
         // This test may fail if telemetry is already initialised
         // In a real scenario, this would be the first call during app startup
         let result = init(None);
-        
+
         // If it succeeds, telemetry was initialised
         // If it fails, it might be because telemetry is already initialised
         match result {
@@ -163,10 +345,13 @@ mod tests {
             }
             Err(TelemetryError::Generic(msg)) => {
                 // Check if it's the expected "already initialised" error
-                assert!(msg.contains("already initialised") || 
-                       msg.contains("tracer") || 
-                       msg.contains("subscriber"),
-                       "Unexpected error message: {}", msg);
+                assert!(
+                    msg.contains("already initialised")
+                        || msg.contains("tracer")
+                        || msg.contains("subscriber"),
+                    "Unexpected error message: {}",
+                    msg
+                );
             }
         }
     }
@@ -175,17 +360,20 @@ mod tests {
     fn test_init_with_debug_level() {
         let debug_level = TelemetryLevels::DEBUG;
         let result = init(Some(&debug_level));
-        
+
         match result {
             Ok(()) => {
                 // Successfully initialised with DEBUG level
             }
             Err(TelemetryError::Generic(msg)) => {
                 // Expected if already initialised
-                assert!(msg.contains("already initialised") || 
-                       msg.contains("tracer") || 
-                       msg.contains("subscriber"),
-                       "Unexpected error message: {}", msg);
+                assert!(
+                    msg.contains("already initialised")
+                        || msg.contains("tracer")
+                        || msg.contains("subscriber"),
+                    "Unexpected error message: {}",
+                    msg
+                );
             }
         }
     }
@@ -210,10 +398,14 @@ mod tests {
                 }
                 Err(TelemetryError::Generic(msg)) => {
                     // Continue if already initialised
-                    assert!(msg.contains("already initialised") || 
-                           msg.contains("tracer") || 
-                           msg.contains("subscriber"),
-                           "Unexpected error for level {:?}: {}", level, msg);
+                    assert!(
+                        msg.contains("already initialised")
+                            || msg.contains("tracer")
+                            || msg.contains("subscriber"),
+                        "Unexpected error for level {:?}: {}",
+                        level,
+                        msg
+                    );
                 }
             }
         }
@@ -222,13 +414,13 @@ mod tests {
     #[test]
     fn test_init_error_handling() {
         // Test that init returns appropriate errors
-        
+
         // First, try to initialize (might succeed or fail)
         let _ = init(None);
-        
+
         // Second call should definitely fail
         let result = init(Some(&TelemetryLevels::DEBUG));
-        
+
         // This should fail because telemetry is already initialised
         match result {
             Ok(()) => {
@@ -237,8 +429,11 @@ mod tests {
             }
             Err(TelemetryError::Generic(msg)) => {
                 // Expected error for double initialisation
-                assert!(msg.contains("tracer") || msg.contains("subscriber") || msg.contains("already"),
-                       "Expected initialisation conflict error, got: {}", msg);
+                assert!(
+                    msg.contains("tracer") || msg.contains("subscriber") || msg.contains("already"),
+                    "Expected initialisation conflict error, got: {}",
+                    msg
+                );
             }
         }
     }
@@ -247,21 +442,41 @@ mod tests {
     fn test_telemetry_levels_conversion() {
         // Test that TelemetryLevels convert correctly to tracing levels
         // This is more of an integration test but validates the conversion logic
-        
+
         let test_cases = vec![
-            (TelemetryLevels::OFF, tracing::level_filters::LevelFilter::OFF),
-            (TelemetryLevels::ERROR, tracing::level_filters::LevelFilter::ERROR),
-            (TelemetryLevels::WARN, tracing::level_filters::LevelFilter::WARN),
-            (TelemetryLevels::INFO, tracing::level_filters::LevelFilter::INFO),
-            (TelemetryLevels::DEBUG, tracing::level_filters::LevelFilter::DEBUG),
-            (TelemetryLevels::TRACE, tracing::level_filters::LevelFilter::TRACE),
+            (
+                TelemetryLevels::OFF,
+                tracing::level_filters::LevelFilter::OFF,
+            ),
+            (
+                TelemetryLevels::ERROR,
+                tracing::level_filters::LevelFilter::ERROR,
+            ),
+            (
+                TelemetryLevels::WARN,
+                tracing::level_filters::LevelFilter::WARN,
+            ),
+            (
+                TelemetryLevels::INFO,
+                tracing::level_filters::LevelFilter::INFO,
+            ),
+            (
+                TelemetryLevels::DEBUG,
+                tracing::level_filters::LevelFilter::DEBUG,
+            ),
+            (
+                TelemetryLevels::TRACE,
+                tracing::level_filters::LevelFilter::TRACE,
+            ),
         ];
 
         for (telemetry_level, expected_tracing_level) in test_cases {
             let actual_tracing_level: tracing::level_filters::LevelFilter = telemetry_level.into();
-            assert_eq!(actual_tracing_level, expected_tracing_level, 
-                      "Telemetry level {:?} should convert to tracing level {:?}", 
-                      telemetry_level, expected_tracing_level);
+            assert_eq!(
+                actual_tracing_level, expected_tracing_level,
+                "Telemetry level {:?} should convert to tracing level {:?}",
+                telemetry_level, expected_tracing_level
+            );
         }
     }
 
@@ -271,25 +486,29 @@ mod tests {
         let none_result: tracing::level_filters::LevelFilter = None
             .map(|&level: &TelemetryLevels| level.into())
             .unwrap_or(tracing::level_filters::LevelFilter::INFO);
-        
+
         assert_eq!(none_result, tracing::level_filters::LevelFilter::INFO);
     }
 
     #[test]
     fn test_env_filter_creation() {
         // Test that env filter can be created with different levels
-        let levels = [TelemetryLevels::DEBUG, TelemetryLevels::INFO, TelemetryLevels::WARN];
-        
+        let levels = [
+            TelemetryLevels::DEBUG,
+            TelemetryLevels::INFO,
+            TelemetryLevels::WARN,
+        ];
+
         for level in &levels {
             // Convert TelemetryLevels to LevelFilter first, then to Directive
             let level_filter: tracing::level_filters::LevelFilter = (*level).into();
             let directive = level_filter.into();
-            
+
             // Create env filter with this directive
             let env_filter = EnvFilter::builder()
                 .with_default_directive(directive)
                 .from_env_lossy();
-            
+
             // The filter should be created successfully
             assert!(!env_filter.to_string().is_empty());
         }
@@ -301,26 +520,92 @@ mod tests {
         let env_filter = EnvFilter::builder()
             .with_default_directive(tracing::level_filters::LevelFilter::INFO.into())
             .from_env_lossy();
-        
+
         let console_collector = tracing_subscriber::fmt::layer();
-        
+
         // This should not panic
         let _registry = tracing_subscriber::registry()
             .with(env_filter)
             .with(console_collector);
     }
 
+    #[test]
+    fn test_otlp_tracer_provider_only_with_an_endpoint() {
+        assert!(
+            otlp_tracer_provider(&TelemetryConfig::default())
+                .unwrap()
+                .is_none()
+        );
+
+        let config = TelemetryConfig {
+            otlp_endpoint: Some("http://localhost:4318".to_string()),
+            sampling_ratio: 0.5,
+            ..TelemetryConfig::default()
+        };
+        let provider = otlp_tracer_provider(&config)
+            .unwrap()
+            .expect("an OTLP tracer provider");
+        provider.shutdown().unwrap();
+    }
+
+    #[test]
+    fn test_otlp_tracer_provider_rejects_bad_config() {
+        let out_of_range = TelemetryConfig {
+            otlp_endpoint: Some("http://localhost:4318".to_string()),
+            sampling_ratio: 1.5,
+            ..TelemetryConfig::default()
+        };
+        let not_a_url = TelemetryConfig {
+            otlp_endpoint: Some("not a url".to_string()),
+            ..TelemetryConfig::default()
+        };
+
+        for config in [out_of_range, not_a_url] {
+            match otlp_tracer_provider(&config) {
+                Err(TelemetryError::Generic(_)) => {}
+                Ok(_) => panic!("Expected an error for {:?}", config),
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_filter_replaces_the_filter() {
+        // Initialise if no other test has yet, so there is a filter to replace
+        let _ = init(None);
+
+        let previous = set_filter("warn,lib_telemetry=trace").unwrap();
+        assert_eq!(
+            current_filter().as_deref(),
+            Some("lib_telemetry=trace,warn")
+        );
+
+        // Invalid directives leave the filter as it was
+        match set_filter("lib_telemetry=loud") {
+            Err(TelemetryError::Generic(msg)) => {
+                assert!(msg.contains("Invalid filter"), "Unexpected error: {}", msg)
+            }
+            Ok(_) => panic!("Expected an invalid filter error"),
+        }
+        assert_eq!(
+            current_filter().as_deref(),
+            Some("lib_telemetry=trace,warn")
+        );
+
+        set_filter(&previous).unwrap();
+        assert_eq!(current_filter(), Some(previous));
+    }
+
     #[test]
     fn test_error_message_formatting() {
         // Test that error messages are properly formatted
         let test_error = TelemetryError::generic("test message");
-        
+
         match &test_error {
             TelemetryError::Generic(msg) => {
                 assert_eq!(msg, "test message");
             }
         }
-        
+
         // Test Display implementation
         let display_msg = format!("{}", test_error);
         assert_eq!(display_msg, "Telemetry generic error: test message");
@@ -334,10 +619,10 @@ mod tests {
             Ok(value) => assert_eq!(value, 42),
             Err(_) => panic!("Expected Ok(42)"),
         }
-        
+
         let err_result: TelemetryResult<i32> = Err(TelemetryError::generic("test error"));
         assert!(err_result.is_err());
-        
+
         if let Err(TelemetryError::Generic(msg)) = err_result {
             assert_eq!(msg, "test error");
         } else {
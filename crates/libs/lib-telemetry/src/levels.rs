@@ -205,12 +205,30 @@ mod tests {
     #[test]
     fn test_deserialization() {
         // Test that each lowercase string deserializes to the correct variant
-        assert_eq!(from_str::<TelemetryLevels>("\"off\"").unwrap(), TelemetryLevels::OFF);
-        assert_eq!(from_str::<TelemetryLevels>("\"error\"").unwrap(), TelemetryLevels::ERROR);
-        assert_eq!(from_str::<TelemetryLevels>("\"warn\"").unwrap(), TelemetryLevels::WARN);
-        assert_eq!(from_str::<TelemetryLevels>("\"info\"").unwrap(), TelemetryLevels::INFO);
-        assert_eq!(from_str::<TelemetryLevels>("\"debug\"").unwrap(), TelemetryLevels::DEBUG);
-        assert_eq!(from_str::<TelemetryLevels>("\"trace\"").unwrap(), TelemetryLevels::TRACE);
+        assert_eq!(
+            from_str::<TelemetryLevels>("\"off\"").unwrap(),
+            TelemetryLevels::OFF
+        );
+        assert_eq!(
+            from_str::<TelemetryLevels>("\"error\"").unwrap(),
+            TelemetryLevels::ERROR
+        );
+        assert_eq!(
+            from_str::<TelemetryLevels>("\"warn\"").unwrap(),
+            TelemetryLevels::WARN
+        );
+        assert_eq!(
+            from_str::<TelemetryLevels>("\"info\"").unwrap(),
+            TelemetryLevels::INFO
+        );
+        assert_eq!(
+            from_str::<TelemetryLevels>("\"debug\"").unwrap(),
+            TelemetryLevels::DEBUG
+        );
+        assert_eq!(
+            from_str::<TelemetryLevels>("\"trace\"").unwrap(),
+            TelemetryLevels::TRACE
+        );
     }
 
     #[test]
@@ -271,7 +289,11 @@ mod tests {
         for (i, &variant1) in variants.iter().enumerate() {
             for (j, &variant2) in variants.iter().enumerate() {
                 if i != j {
-                    assert_ne!(variant1, variant2, "Variants at indices {} and {} should be different", i, j);
+                    assert_ne!(
+                        variant1, variant2,
+                        "Variants at indices {} and {} should be different",
+                        i, j
+                    );
                 }
             }
         }
@@ -287,4 +309,4 @@ mod tests {
         assert_eq!(format!("{}", TelemetryLevels::DEBUG), "debug");
         assert_eq!(format!("{}", TelemetryLevels::TRACE), "trace");
     }
-}
\ No newline at end of file
+}
@@ -12,4 +12,4 @@ pub use error::{TelemetryError, TelemetryResult};
 pub use levels::TelemetryLevels;
 
 // Reexport init module
-pub use init::init;
\ No newline at end of file
+pub use init::{current_filter, init, init_with_config, set_filter, shutdown};
@@ -0,0 +1,119 @@
+//! # Fixtures
+//!
+//! Builders for valid RPC messages, and [`TestServer`] helpers that create
+//! them through the API and return what the server stored.
+//!
+//! Builders set only the required fields, so tests change what they care
+//! about with struct update syntax:
+//!
+//! ```rust
+//! use lib_testkit::fixtures;
+//!
+//! let rent = lib_rpc::Category {
+//!     description: Some("Monthly rent".to_string()),
+//!     ..fixtures::category("RENT", "Rent")
+//! };
+//! assert_eq!(rent.code, "RENT");
+//! ```
+
+use lib_rpc as rpc;
+
+use crate::TestServer;
+
+/// An active expense category.
+pub fn category(code: &str, name: &str) -> rpc::Category {
+    rpc::Category {
+        code: code.to_string(),
+        name: name.to_string(),
+        category_type: rpc::CategoryTypes::Expense as i32,
+        is_active: true,
+        ..Default::default()
+    }
+}
+
+/// An active Australian dollar account with the given opening balance, in cents.
+pub fn account(name: &str, opening_balance: i64) -> rpc::Account {
+    rpc::Account {
+        name: name.to_string(),
+        opening_balance,
+        is_active: true,
+        currency: "AUD".to_string(),
+        ..Default::default()
+    }
+}
+
+/// A posted transaction, `date` in ISO 8601 format and `amount` in cents,
+/// negative for money spent.
+pub fn transaction(date: &str, payee: &str, amount: i64) -> rpc::Transaction {
+    rpc::Transaction {
+        transaction_date: date.to_string(),
+        payee: payee.to_string(),
+        amount,
+        ..Default::default()
+    }
+}
+
+impl TestServer {
+    /// Creates a category and returns it as stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns the server's status if the category is rejected.
+    pub async fn create_category(&self, category: rpc::Category) -> crate::Result<rpc::Category> {
+        let response = self
+            .categories()
+            .category_create(rpc::CategoryCreateRequest {
+                category: Some(category),
+                validate_only: false,
+            })
+            .await?;
+
+        response
+            .into_inner()
+            .category
+            .ok_or_else(|| "Server returned no category".into())
+    }
+
+    /// Creates an account and returns it as stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns the server's status if the account is rejected.
+    pub async fn create_account(&self, account: rpc::Account) -> crate::Result<rpc::Account> {
+        let response = self
+            .accounts()
+            .account_create(rpc::AccountCreateRequest {
+                account: Some(account),
+                validate_only: false,
+            })
+            .await?;
+
+        response
+            .into_inner()
+            .account
+            .ok_or_else(|| "Server returned no account".into())
+    }
+
+    /// Creates a transaction and returns it as stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns the server's status if the transaction is rejected.
+    pub async fn create_transaction(
+        &self,
+        transaction: rpc::Transaction,
+    ) -> crate::Result<rpc::Transaction> {
+        let response = self
+            .transactions()
+            .transaction_create(rpc::TransactionCreateRequest {
+                transaction: Some(transaction),
+                validate_only: false,
+            })
+            .await?;
+
+        response
+            .into_inner()
+            .transaction
+            .ok_or_else(|| "Server returned no transaction".into())
+    }
+}
@@ -0,0 +1,49 @@
+//! # Personal Ledger Test Kit
+//!
+//! End-to-end test harness for the Personal Ledger. [`TestServer`] boots the
+//! real server, the same [`Server`](server::Server) the binary runs, on an
+//! ephemeral localhost port with a fresh in-memory database, and hands out
+//! lib-rpc clients connected to it. [`TestServer::start_in_process`] serves
+//! it over in-memory pipes instead, so no port is opened at all.
+//!
+//! Every test gets its own server and database, so tests run in parallel
+//! without seeing each other's data. The server is shut down when the
+//! `TestServer` is dropped, or with [`TestServer::stop`] to check it stopped
+//! cleanly.
+//!
+//! ## Modules
+//!
+//! - [`TestServer`] - Starting, connecting to and stopping a test server
+//! - [`fixtures`] - RPC messages and helpers to seed the ledger through the API
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_testkit::{TestServer, fixtures};
+//!
+//! # async fn example() -> lib_testkit::Result<()> {
+//! let server = TestServer::start().await?;
+//! let groceries = server.create_category(fixtures::category("GROCERY", "Groceries")).await?;
+//!
+//! let response = server
+//!     .categories()
+//!     .category_get(lib_rpc::CategoryGetRequest { id: groceries.id })
+//!     .await?;
+//! assert_eq!(response.into_inner().category.unwrap().name, "Groceries");
+//!
+//! server.stop().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod fixtures;
+mod test_server;
+
+/// A running server for end-to-end tests.
+pub use test_server::TestServer;
+
+/// Result type for test kit functions and the tests using them.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error type for test kit functions, any error a test can fail with.
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
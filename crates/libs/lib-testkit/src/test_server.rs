@@ -0,0 +1,286 @@
+use std::net::SocketAddr;
+
+use tonic::transport::{Channel, Endpoint};
+
+use lib_config as config;
+use lib_rpc as rpc;
+
+/// The real Personal Ledger server, running in the background on an
+/// ephemeral port or over in-memory pipes, and a channel connected to it.
+///
+/// Dropping the server asks it to shut down without waiting for it. Use
+/// [`stop`](Self::stop) to wait for it and check it stopped cleanly.
+#[derive(Debug)]
+pub struct TestServer {
+    addr: Option<SocketAddr>,
+    pool: sqlx::SqlitePool,
+    channel: Channel,
+    shutdown: server::ShutdownHandle,
+    running: Option<tokio::task::JoinHandle<server::ServerResult<()>>>,
+}
+
+impl TestServer {
+    /// Starts a server on an ephemeral localhost port with an in-memory
+    /// database, migrated to the latest schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot be built or connected to.
+    pub async fn start() -> crate::Result<Self> {
+        let config = config::LedgerConfig {
+            server: config::ServerConfig::ephemeral(),
+            ..Default::default()
+        };
+
+        Self::start_with(config).await
+    }
+
+    /// Starts a server from the given configuration. Telemetry is never
+    /// initialised, as tests in the same process start many servers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot be built or connected to.
+    pub async fn start_with(config: config::LedgerConfig) -> crate::Result<Self> {
+        let built = server::Server::builder(config)
+            .with_telemetry(false)
+            .build()
+            .await?;
+
+        let addr = built.local_addr().expect("built on a socket");
+        let pool = built.pool().clone();
+        let shutdown = built.shutdown_handle();
+        let running = tokio::spawn(built.run());
+
+        // The listener is already bound, so the server accepts as soon as
+        // its task is polled
+        let channel = Endpoint::from_shared(format!("http://{addr}"))?
+            .connect()
+            .await?;
+
+        Ok(Self {
+            addr: Some(addr),
+            pool,
+            channel,
+            shutdown,
+            running: Some(running),
+        })
+    }
+
+    /// Starts a server with an in-memory database like [`start`](Self::start),
+    /// but served over in-memory pipes rather than a port, so a request goes
+    /// through the handlers to the database and back without a socket.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot be built.
+    pub async fn start_in_process() -> crate::Result<Self> {
+        let config = config::LedgerConfig {
+            server: config::ServerConfig::ephemeral(),
+            ..Default::default()
+        };
+
+        Self::start_in_process_with(config).await
+    }
+
+    /// Starts a server from the given configuration over in-memory pipes.
+    /// The configured address is not bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot be built.
+    pub async fn start_in_process_with(config: config::LedgerConfig) -> crate::Result<Self> {
+        let (built, client) = server::Server::builder(config)
+            .with_telemetry(false)
+            .build_in_process()
+            .await?;
+        let pool = built.pool().clone();
+        let shutdown = built.shutdown_handle();
+        let running = tokio::spawn(built.run());
+
+        Ok(Self {
+            addr: None,
+            pool,
+            channel: client.channel().clone(),
+            shutdown,
+            running: Some(running),
+        })
+    }
+
+    /// The address the server is listening on, or `None` for a server
+    /// started in process.
+    pub fn addr(&self) -> Option<SocketAddr> {
+        self.addr
+    }
+
+    /// The URL clients connect to, e.g. `http://127.0.0.1:41234`, or `None`
+    /// for a server started in process.
+    pub fn url(&self) -> Option<String> {
+        self.addr.map(|addr| format!("http://{addr}"))
+    }
+
+    /// The server's database pool, to seed or inspect the database directly.
+    pub fn pool(&self) -> &sqlx::SqlitePool {
+        &self.pool
+    }
+
+    /// The channel connected to the server, for clients not covered below.
+    /// Clones share the one connection.
+    pub fn channel(&self) -> Channel {
+        self.channel.clone()
+    }
+
+    /// A client facade over the channel, with every service's client and
+    /// the pagination helpers.
+    pub fn client(&self) -> rpc::LedgerClient {
+        rpc::LedgerClient::new(self.channel())
+    }
+
+    /// A client for the `AccountsService`.
+    pub fn accounts(&self) -> rpc::AccountsServiceClient<Channel> {
+        rpc::AccountsServiceClient::new(self.channel())
+    }
+
+    /// A client for the `AttachmentsService`.
+    pub fn attachments(&self) -> rpc::AttachmentsServiceClient<Channel> {
+        rpc::AttachmentsServiceClient::new(self.channel())
+    }
+
+    /// A client for the `AuditService`.
+    pub fn audit(&self) -> rpc::AuditServiceClient<Channel> {
+        rpc::AuditServiceClient::new(self.channel())
+    }
+
+    /// A client for the `BudgetsService`.
+    pub fn budgets(&self) -> rpc::BudgetsServiceClient<Channel> {
+        rpc::BudgetsServiceClient::new(self.channel())
+    }
+
+    /// A client for the `CategoriesService`.
+    pub fn categories(&self) -> rpc::CategoriesServiceClient<Channel> {
+        rpc::CategoriesServiceClient::new(self.channel())
+    }
+
+    /// A client for the `ExportService`.
+    pub fn export(&self) -> rpc::ExportServiceClient<Channel> {
+        rpc::ExportServiceClient::new(self.channel())
+    }
+
+    /// A client for the `GoalsService`.
+    pub fn goals(&self) -> rpc::GoalsServiceClient<Channel> {
+        rpc::GoalsServiceClient::new(self.channel())
+    }
+
+    /// A client for the standard `grpc.health.v1.Health` service.
+    pub fn health(&self) -> rpc::HealthClient<Channel> {
+        rpc::HealthClient::new(self.channel())
+    }
+
+    /// A client for the `PayeesService`.
+    pub fn payees(&self) -> rpc::PayeesServiceClient<Channel> {
+        rpc::PayeesServiceClient::new(self.channel())
+    }
+
+    /// A client for the `PeriodsService`.
+    pub fn periods(&self) -> rpc::PeriodsServiceClient<Channel> {
+        rpc::PeriodsServiceClient::new(self.channel())
+    }
+
+    /// A client for the `ReconciliationsService`.
+    pub fn reconciliations(&self) -> rpc::ReconciliationsServiceClient<Channel> {
+        rpc::ReconciliationsServiceClient::new(self.channel())
+    }
+
+    /// A client for the `ReportsService`.
+    pub fn reports(&self) -> rpc::ReportsServiceClient<Channel> {
+        rpc::ReportsServiceClient::new(self.channel())
+    }
+
+    /// A client for the `SearchService`.
+    pub fn search(&self) -> rpc::SearchServiceClient<Channel> {
+        rpc::SearchServiceClient::new(self.channel())
+    }
+
+    /// A client for the `TransactionsService`.
+    pub fn transactions(&self) -> rpc::TransactionsServiceClient<Channel> {
+        rpc::TransactionsServiceClient::new(self.channel())
+    }
+
+    /// A client for the `UtilitiesService`.
+    pub fn utilities(&self) -> rpc::UtilitiesServiceClient<Channel> {
+        rpc::UtilitiesServiceClient::new(self.channel())
+    }
+
+    /// Shuts the server down and waits for it to stop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server stopped with an error or its task
+    /// panicked.
+    pub async fn stop(mut self) -> crate::Result<()> {
+        self.shutdown.shutdown();
+
+        if let Some(running) = self.running.take() {
+            running.await??;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.shutdown.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn servers_have_separate_databases() -> crate::Result<()> {
+        let first = TestServer::start().await?;
+        let second = TestServer::start().await?;
+        assert_ne!(first.addr(), second.addr());
+
+        first
+            .create_category(crate::fixtures::category("FOOD", "Food"))
+            .await?;
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM categories")
+            .fetch_one(second.pool())
+            .await?;
+        assert_eq!(count, 0);
+
+        first.stop().await?;
+        second.stop().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn in_process_server_has_no_address() -> crate::Result<()> {
+        let server = TestServer::start_in_process().await?;
+
+        let category = server
+            .create_category(crate::fixtures::category("FOOD", "Food"))
+            .await?;
+
+        assert_eq!((server.addr(), server.url()), (None, None));
+        assert_eq!(category.name, "Food");
+        server.stop().await
+    }
+
+    #[tokio::test]
+    async fn stop_closes_the_server() -> crate::Result<()> {
+        let server = TestServer::start().await?;
+        let url = server.url().expect("started on a port");
+
+        server.stop().await?;
+
+        assert!(rpc::UtilitiesServiceClient::connect(url).await.is_err());
+
+        Ok(())
+    }
+}
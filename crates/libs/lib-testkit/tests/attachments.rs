@@ -0,0 +1,158 @@
+//! End-to-end tests of the `AttachmentsService`.
+
+use lib_rpc as rpc;
+use lib_testkit::{Result, TestServer, fixtures};
+use tokio_stream::StreamExt;
+
+/// The upload stream for `content`, the metadata then the content in
+/// chunks of `chunk_bytes`.
+fn upload(
+    transaction_id: &str,
+    content: &[u8],
+    chunk_bytes: usize,
+) -> Vec<rpc::AttachmentUploadRequest> {
+    let metadata = rpc::AttachmentUploadRequest {
+        part: Some(rpc::AttachmentUploadPart::Metadata(
+            rpc::AttachmentUploadMetadata {
+                transaction_id: transaction_id.to_string(),
+                file_name: "receipt.png".to_string(),
+                content_type: "image/png".to_string(),
+                validate_only: false,
+            },
+        )),
+    };
+    let chunks = content
+        .chunks(chunk_bytes)
+        .map(|chunk| rpc::AttachmentUploadRequest {
+            part: Some(rpc::AttachmentUploadPart::Chunk(chunk.to_vec())),
+        });
+
+    std::iter::once(metadata).chain(chunks).collect()
+}
+
+#[tokio::test]
+async fn uploaded_attachments_download_unchanged() -> Result<()> {
+    let server = TestServer::start().await?;
+    let transaction = server
+        .create_transaction(fixtures::transaction(
+            "2025-03-14",
+            "Hardware Store",
+            -12900,
+        ))
+        .await?;
+    let mut content = b"\x89PNG\r\n\x1a\n".to_vec();
+    content.extend((0..100_000u32).map(|byte| byte as u8));
+
+    let uploaded = server
+        .attachments()
+        .attachment_upload(tokio_stream::iter(upload(
+            &transaction.id,
+            &content,
+            16 * 1024,
+        )))
+        .await?
+        .into_inner()
+        .attachment
+        .expect("uploaded attachment");
+    assert_eq!(uploaded.size_bytes, content.len() as i64);
+
+    let listed = server
+        .attachments()
+        .attachments_list(rpc::AttachmentsListRequest {
+            transaction_id: transaction.id,
+        })
+        .await?
+        .into_inner()
+        .attachments;
+    assert_eq!(listed, vec![uploaded.clone()]);
+
+    let mut download = server
+        .attachments()
+        .attachment_download(rpc::AttachmentDownloadRequest {
+            id: uploaded.id.clone(),
+        })
+        .await?
+        .into_inner();
+    let mut downloaded = Vec::new();
+    while let Some(message) = download.next().await {
+        match message?.part {
+            Some(rpc::AttachmentDownloadPart::Attachment(attachment)) => {
+                assert_eq!(attachment, uploaded)
+            }
+            Some(rpc::AttachmentDownloadPart::Chunk(chunk)) => downloaded.extend(chunk),
+            None => panic!("empty download message"),
+        }
+    }
+    assert_eq!(downloaded, content);
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn uploads_that_are_not_the_declared_type_are_refused() -> Result<()> {
+    let server = TestServer::start().await?;
+    let transaction = server
+        .create_transaction(fixtures::transaction(
+            "2025-03-14",
+            "Hardware Store",
+            -12900,
+        ))
+        .await?;
+
+    let refused = server
+        .attachments()
+        .attachment_upload(tokio_stream::iter(upload(
+            &transaction.id,
+            b"MZ not a picture",
+            1024,
+        )))
+        .await;
+    assert_eq!(refused.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+    let headless = server
+        .attachments()
+        .attachment_upload(tokio_stream::iter(
+            upload(&transaction.id, b"\x89PNG\r\n\x1a\n", 1024).split_off(1),
+        ))
+        .await;
+    assert_eq!(headless.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn validated_uploads_are_not_stored() -> Result<()> {
+    let server = TestServer::start().await?;
+    let transaction = server
+        .create_transaction(fixtures::transaction(
+            "2025-03-14",
+            "Hardware Store",
+            -12900,
+        ))
+        .await?;
+    let mut parts = upload(&transaction.id, b"\x89PNG\r\n\x1a\n", 1024);
+    if let Some(rpc::AttachmentUploadPart::Metadata(metadata)) = &mut parts[0].part {
+        metadata.validate_only = true;
+    }
+
+    let validated = server
+        .attachments()
+        .attachment_upload(tokio_stream::iter(parts))
+        .await?
+        .into_inner()
+        .attachment
+        .expect("validated attachment");
+    assert_eq!(validated.size_bytes, 8);
+
+    let listed = server
+        .attachments()
+        .attachments_list(rpc::AttachmentsListRequest {
+            transaction_id: transaction.id,
+        })
+        .await?
+        .into_inner()
+        .attachments;
+    assert!(listed.is_empty());
+
+    server.stop().await
+}
@@ -0,0 +1,98 @@
+//! End-to-end tests of the `CategoriesService` and `AuditService`.
+
+use lib_rpc as rpc;
+use lib_testkit::{Result, TestServer, fixtures};
+
+#[tokio::test]
+async fn create_get_and_list_categories() -> Result<()> {
+    let server = TestServer::start_in_process().await?;
+    let food = server
+        .create_category(fixtures::category("FOOD", "Food"))
+        .await?;
+    server
+        .create_category(fixtures::category("RENT", "Rent"))
+        .await?;
+
+    let fetched = server
+        .categories()
+        .category_get(rpc::CategoryGetRequest {
+            id: food.id.clone(),
+        })
+        .await?
+        .into_inner()
+        .category;
+    assert_eq!(fetched, Some(food));
+
+    let listed = server
+        .categories()
+        .categories_list(rpc::CategoriesListRequest {
+            limit: 1,
+            ..Default::default()
+        })
+        .await?
+        .into_inner();
+    assert_eq!(listed.categories.len(), 1);
+    assert_eq!(listed.total_count, 2);
+    assert!(!listed.next_page_token.is_empty());
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn errors_are_returned_as_status_codes() -> Result<()> {
+    let server = TestServer::start_in_process().await?;
+    server
+        .create_category(fixtures::category("FOOD", "Food"))
+        .await?;
+
+    let duplicate = server
+        .create_category(fixtures::category("FOOD", "Food again"))
+        .await;
+    let status = duplicate.unwrap_err().downcast::<tonic::Status>()?;
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+
+    let missing = server
+        .categories()
+        .category_get(rpc::CategoryGetRequest {
+            id: "not-a-uuid".to_string(),
+        })
+        .await;
+    assert_eq!(missing.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn changes_are_audited_against_the_actor() -> Result<()> {
+    let server = TestServer::start_in_process().await?;
+
+    let mut request = tonic::Request::new(rpc::CategoryCreateRequest {
+        category: Some(fixtures::category("FOOD", "Food")),
+        validate_only: false,
+    });
+    request
+        .metadata_mut()
+        .insert(server::ACTOR_HEADER, "household-ipad".parse()?);
+    let created = server
+        .categories()
+        .category_create(request)
+        .await?
+        .into_inner()
+        .category
+        .unwrap();
+
+    let history = server
+        .audit()
+        .audit_history(rpc::AuditHistoryRequest {
+            entity: "categories".to_string(),
+            entity_id: created.id,
+        })
+        .await?
+        .into_inner();
+
+    assert_eq!(history.entries.len(), 1);
+    assert_eq!(history.entries[0].action, rpc::AuditActions::Insert as i32);
+    assert_eq!(history.entries[0].actor.as_deref(), Some("household-ipad"));
+
+    server.stop().await
+}
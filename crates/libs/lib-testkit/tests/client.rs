@@ -0,0 +1,112 @@
+//! End-to-end tests of the `LedgerClient` pagination and retry helpers.
+
+use lib_rpc as rpc;
+use lib_testkit::{Result, TestServer, fixtures};
+use tokio_stream::StreamExt;
+
+#[tokio::test]
+async fn iter_transactions_follows_every_page() -> Result<()> {
+    let server = TestServer::start().await?;
+    for day in 1..=5 {
+        server
+            .create_transaction(fixtures::transaction(
+                &format!("2025-04-0{day}"),
+                "Cafe",
+                -450,
+            ))
+            .await?;
+    }
+
+    let transactions: Vec<rpc::Transaction> = server
+        .client()
+        .iter_transactions(rpc::TransactionsListRequest {
+            limit: 2,
+            ..Default::default()
+        })
+        .collect::<core::result::Result<_, _>>()
+        .await?;
+
+    assert_eq!(transactions.len(), 5);
+    let mut ids: Vec<_> = transactions
+        .iter()
+        .map(|transaction| &transaction.id)
+        .collect();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 5);
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn list_all_categories_includes_every_category() -> Result<()> {
+    let server = TestServer::start().await?;
+    for code in ["FOOD", "RENT", "TRAVEL"] {
+        server
+            .create_category(fixtures::category(code, code))
+            .await?;
+    }
+
+    let mut codes: Vec<String> = server
+        .client()
+        .list_all_categories()
+        .map(|category| category.map(|category| category.code))
+        .collect::<core::result::Result<_, _>>()
+        .await?;
+    codes.sort();
+
+    assert_eq!(codes, ["FOOD", "RENT", "TRAVEL"]);
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn iter_categories_stops_on_error() -> Result<()> {
+    let server = TestServer::start().await?;
+
+    let mut categories = Box::pin(server.client().iter_categories(rpc::CategoriesListRequest {
+        page_token: "not a page token".to_string(),
+        ..Default::default()
+    }));
+
+    let error = categories.next().await.expect("an error").unwrap_err();
+    assert_eq!(error.code(), tonic::Code::InvalidArgument);
+    assert!(categories.next().await.is_none());
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn retry_mutation_creates_once() -> Result<()> {
+    let server = TestServer::start().await?;
+    let client = server.client();
+
+    let request = rpc::CategoryCreateRequest {
+        category: Some(fixtures::category("FOOD", "Food")),
+        validate_only: false,
+    };
+    let created = client
+        .retry_mutation(&rpc::RetryPolicy::default(), request.clone(), |request| {
+            let mut categories = client.categories();
+            async move { categories.category_create(request).await }
+        })
+        .await?
+        .into_inner();
+    assert_eq!(
+        created.category.map(|category| category.code).as_deref(),
+        Some("FOOD")
+    );
+
+    // A terminal status is returned straight away, not retried
+    let duplicate = client
+        .retry_mutation(&rpc::RetryPolicy::default(), request, |request| {
+            let mut categories = client.categories();
+            async move { categories.category_create(request).await }
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(duplicate.code(), tonic::Code::AlreadyExists);
+    assert!(!rpc::is_retryable(&duplicate));
+
+    server.stop().await
+}
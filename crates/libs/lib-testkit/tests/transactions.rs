@@ -0,0 +1,95 @@
+//! End-to-end tests of the `TransactionsService` and `AccountsService`.
+
+use lib_rpc as rpc;
+use lib_testkit::{Result, TestServer, fixtures};
+use tokio_stream::StreamExt;
+
+#[tokio::test]
+async fn transactions_move_the_account_balance() -> Result<()> {
+    let server = TestServer::start_in_process().await?;
+    let account = server
+        .create_account(fixtures::account("Everyday", 10000))
+        .await?;
+    let groceries = server
+        .create_category(fixtures::category("GROCERY", "Groceries"))
+        .await?;
+
+    for (date, amount) in [("2025-03-14", -4250), ("2025-03-15", 250000)] {
+        server
+            .create_transaction(rpc::Transaction {
+                account_id: Some(account.id.clone()),
+                category_id: Some(groceries.id.clone()),
+                ..fixtures::transaction(date, "Corner Store", amount)
+            })
+            .await?;
+    }
+
+    let balance = server
+        .accounts()
+        .account_get_balance(rpc::AccountGetBalanceRequest {
+            id: account.id,
+            ..Default::default()
+        })
+        .await?
+        .into_inner();
+    assert_eq!(balance.balance, 10000 - 4250 + 250000);
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn stream_returns_every_transaction_in_pages() -> Result<()> {
+    let server = TestServer::start_in_process().await?;
+    for day in 1..=5 {
+        server
+            .create_transaction(fixtures::transaction(
+                &format!("2025-04-0{day}"),
+                "Cafe",
+                -450,
+            ))
+            .await?;
+    }
+
+    let pages: Vec<rpc::TransactionsStreamResponse> = server
+        .transactions()
+        .transactions_stream(rpc::TransactionsStreamRequest { page_size: 2 })
+        .await?
+        .into_inner()
+        .collect::<core::result::Result<_, _>>()
+        .await?;
+
+    assert_eq!(pages.len(), 3);
+    assert_eq!(
+        pages
+            .iter()
+            .map(|page| page.transactions.len())
+            .sum::<usize>(),
+        5
+    );
+
+    server.stop().await
+}
+
+#[tokio::test]
+async fn validate_only_does_not_save() -> Result<()> {
+    let server = TestServer::start_in_process().await?;
+
+    let validated = server
+        .transactions()
+        .transaction_create(rpc::TransactionCreateRequest {
+            transaction: Some(fixtures::transaction("2025-03-14", "Corner Store", -1200)),
+            validate_only: true,
+        })
+        .await?
+        .into_inner();
+    assert!(validated.transaction.is_some());
+
+    let listed = server
+        .transactions()
+        .transactions_list(rpc::TransactionsListRequest::default())
+        .await?
+        .into_inner();
+    assert_eq!(listed.total_count, 0);
+
+    server.stop().await
+}
@@ -0,0 +1,41 @@
+//! End-to-end tests of the `UtilitiesService` and server startup.
+
+use lib_rpc as rpc;
+use lib_testkit::{Result, TestServer};
+
+#[tokio::test]
+async fn server_starts_with_a_migrated_database() -> Result<()> {
+    let server = TestServer::start().await?;
+
+    let health = server
+        .health()
+        .check(rpc::HealthCheckRequest::default())
+        .await?
+        .into_inner();
+    assert_eq!(health.status(), rpc::HealthCheckStatus::Serving);
+
+    let status = server
+        .utilities()
+        .migration_status(rpc::MigrationStatusRequest {})
+        .await?
+        .into_inner();
+    assert_eq!(status.pending_count, 0);
+    assert!(status.current_version > 0);
+
+    server.stop().await
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn deprecated_ping_still_answers() -> Result<()> {
+    let server = TestServer::start().await?;
+
+    let pong = server
+        .utilities()
+        .ping(rpc::PingRequest {})
+        .await?
+        .into_inner();
+    assert_eq!(pong.message, "Pong...");
+
+    server.stop().await
+}
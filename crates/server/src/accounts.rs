@@ -0,0 +1,1230 @@
+//! # Accounts Service
+//!
+//! gRPC handlers for the `AccountsService`, backed by the lib-database
+//! [`Accounts`](database::Accounts) model.
+//!
+//! Balances are computed in the database from the account's opening balance
+//! and the transactions posted to it, so clients never need to sum
+//! transactions themselves. Accounts kept outside the ledger, such as a
+//! superannuation fund, have their balance recorded by hand as
+//! [`BalanceSnapshots`](database::BalanceSnapshots) instead. Balances a bank
+//! reports for any other account can be recorded as
+//! [`BalanceAssertions`](database::BalanceAssertions) and checked against the
+//! computed balance.
+
+use tonic::{Request, Response, Status};
+
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{auth, convert, metadata};
+
+/// Server implementation of the `AccountsService`.
+#[derive(Debug, Clone)]
+pub struct MyAccountsService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+    events: database::EventBus,
+}
+
+impl MyAccountsService {
+    /// Creates the service using the given database pool, public ID key and
+    /// event bus.
+    pub fn new(
+        pool: sqlx::SqlitePool,
+        public_ids: domain::PublicIdKey,
+        events: database::EventBus,
+    ) -> Self {
+        Self {
+            pool,
+            public_ids,
+            events,
+        }
+    }
+}
+
+/// Maps a database error to the gRPC status returned to the client.
+fn database_status(error: database::DatabaseError) -> Status {
+    if convert::is_foreign_key_violation(&error) {
+        return convert::foreign_key_status(
+            rpc::ErrorReason::InUse,
+            "account",
+            "Account still has transactions posted to it, or its adjustments category does not exist",
+        );
+    }
+
+    convert::database_status(error)
+}
+
+/// Checks the account `id` is in `scope` before its balance, snapshots or
+/// assertions are read, or one is recorded against it.
+async fn ensure_account<'c, A>(
+    id: domain::RowID,
+    scope: database::UserScope,
+    conn: A,
+) -> database::DatabaseResult<()>
+where
+    A: sqlx::Acquire<'c, Database = sqlx::Sqlite> + Send + 'c,
+{
+    database::Accounts::find_by_id(id, scope, conn)
+        .await?
+        .map(|_| ())
+        .ok_or_else(|| database::DatabaseError::NotFound(format!("Account with id {id} not found")))
+}
+
+/// Converts a database account into its RPC message.
+fn to_rpc(public_ids: &domain::PublicIdKey, account: database::Accounts) -> rpc::Account {
+    rpc::Account {
+        id: convert::format_id(public_ids, account.id),
+        name: account.name,
+        description: account.description,
+        opening_balance: account.opening_balance,
+        is_active: account.is_active,
+        created_on: Some(convert::to_timestamp(account.created_on)),
+        updated_on: Some(convert::to_timestamp(account.updated_on)),
+        currency: account.currency.to_string(),
+        adjustments_category_id: account
+            .adjustments_category_id
+            .map(|id| convert::format_id(public_ids, id)),
+    }
+}
+
+/// Applies the client editable fields of an RPC account to a database
+/// account. The id and timestamps are left untouched. The currency and
+/// adjustments category are parsed by [`parse_rpc`], before any database work
+/// starts.
+fn apply_rpc(
+    mut account: database::Accounts,
+    message: rpc::Account,
+    (currency, adjustments_category_id): (lib_domain::Currency, Option<lib_domain::RowID>),
+) -> database::Accounts {
+    account.name = message.name;
+    account.description = message.description;
+    account.opening_balance = message.opening_balance;
+    account.currency = currency;
+    account.adjustments_category_id = adjustments_category_id;
+    account.is_active = message.is_active;
+
+    account
+}
+
+/// Parses the fields of an RPC account that [`apply_rpc`] needs as domain
+/// types.
+fn parse_rpc(
+    public_ids: &domain::PublicIdKey,
+    message: &rpc::Account,
+) -> Result<(lib_domain::Currency, Option<lib_domain::RowID>), Status> {
+    let currency = convert::parse_currency("account.currency", &message.currency)?;
+    let adjustments_category_id = message
+        .adjustments_category_id
+        .as_deref()
+        .map(|id| convert::parse_id(public_ids, "account.adjustments_category_id", id))
+        .transpose()?;
+
+    Ok((currency, adjustments_category_id))
+}
+
+/// Converts a database balance snapshot into its RPC message.
+fn snapshot_to_rpc(
+    public_ids: &domain::PublicIdKey,
+    snapshot: database::BalanceSnapshots,
+) -> rpc::BalanceSnapshot {
+    rpc::BalanceSnapshot {
+        id: convert::format_id(public_ids, snapshot.id),
+        account_id: convert::format_id(public_ids, snapshot.account_id),
+        snapshot_date: convert::format_date(snapshot.snapshot_date),
+        balance: snapshot.balance,
+        note: snapshot.note,
+        source: match snapshot.source {
+            database::SnapshotSources::Manual => rpc::SnapshotSources::Manual,
+            database::SnapshotSources::Computed => rpc::SnapshotSources::Computed,
+        } as i32,
+        created_on: Some(convert::to_timestamp(snapshot.created_on)),
+        updated_on: Some(convert::to_timestamp(snapshot.updated_on)),
+    }
+}
+
+/// Converts a database balance assertion into its RPC message.
+fn assertion_to_rpc(
+    public_ids: &domain::PublicIdKey,
+    assertion: database::BalanceAssertions,
+) -> rpc::BalanceAssertion {
+    rpc::BalanceAssertion {
+        id: convert::format_id(public_ids, assertion.id),
+        account_id: convert::format_id(public_ids, assertion.account_id),
+        assertion_date: convert::format_date(assertion.assertion_date),
+        balance: assertion.balance,
+        note: assertion.note,
+        created_on: Some(convert::to_timestamp(assertion.created_on)),
+        updated_on: Some(convert::to_timestamp(assertion.updated_on)),
+    }
+}
+
+#[tonic::async_trait]
+impl rpc::AccountsService for MyAccountsService {
+    async fn account_create(
+        &self,
+        request: Request<rpc::AccountCreateRequest>,
+    ) -> Result<Response<rpc::AccountCreateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let message = request
+            .account
+            .ok_or_else(|| Status::invalid_argument("Missing account"))?;
+
+        let parsed = parse_rpc(&self.public_ids, &message)?;
+        let mut account = apply_rpc(database::Accounts::new(""), message, parsed);
+        account.user_id = scope.user_id();
+
+        let created = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| account.insert(conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::AccountCreateResponse {
+            account: Some(to_rpc(&self.public_ids, created)),
+        }))
+    }
+
+    async fn account_get(
+        &self,
+        request: Request<rpc::AccountGetRequest>,
+    ) -> Result<Response<rpc::AccountGetResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let public_id = request.into_inner().id;
+        let id = convert::parse_id(&self.public_ids, "id", &public_id)?;
+
+        let account = metadata::time_db(database::Accounts::find_by_id(id, scope, &self.pool))
+            .await
+            .map_err(database_status)?
+            .ok_or_else(|| {
+                convert::not_found(
+                    "account",
+                    "id",
+                    format!("Account with id {public_id} not found"),
+                )
+            })?;
+
+        Ok(Response::new(rpc::AccountGetResponse {
+            account: Some(to_rpc(&self.public_ids, account)),
+        }))
+    }
+
+    async fn accounts_list(
+        &self,
+        request: Request<rpc::AccountsListRequest>,
+    ) -> Result<Response<rpc::AccountsListResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+
+        let after = convert::parse_page_token(&request.page_token)?;
+
+        let page = metadata::time_db(database::Accounts::find_all_with_pagination(
+            after,
+            request.limit,
+            scope,
+            &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::AccountsListResponse {
+            accounts: page
+                .items
+                .into_iter()
+                .map(|account| to_rpc(&self.public_ids, account))
+                .collect(),
+            total_count: page.total_count,
+            next_page_token: convert::format_page_token(page.next_cursor),
+            limit: request.limit,
+        }))
+    }
+
+    async fn account_update(
+        &self,
+        request: Request<rpc::AccountUpdateRequest>,
+    ) -> Result<Response<rpc::AccountUpdateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        let message = request
+            .account
+            .ok_or_else(|| Status::invalid_argument("Missing account"))?;
+
+        let parsed = parse_rpc(&self.public_ids, &message)?;
+
+        let updated = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                let existing = database::Accounts::find_by_id(id, scope, &mut *conn)
+                    .await?
+                    .ok_or_else(|| {
+                        database::DatabaseError::NotFound(format!(
+                            "Account with id {} not found",
+                            request.id
+                        ))
+                    })?;
+
+                apply_rpc(existing, message, parsed)
+                    .update(scope, conn)
+                    .await
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::AccountUpdateResponse {
+            account: Some(to_rpc(&self.public_ids, updated)),
+        }))
+    }
+
+    async fn account_delete(
+        &self,
+        request: Request<rpc::AccountDeleteRequest>,
+    ) -> Result<Response<rpc::AccountDeleteResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+
+        metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Accounts::delete_by_id(id, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::AccountDeleteResponse {
+            rows_deleted: 1,
+        }))
+    }
+
+    async fn account_get_balance(
+        &self,
+        request: Request<rpc::AccountGetBalanceRequest>,
+    ) -> Result<Response<rpc::AccountGetBalanceResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        let as_of = request
+            .as_of
+            .as_deref()
+            .map(|as_of| convert::parse_date("as_of", as_of))
+            .transpose()?;
+        metadata::time_db(ensure_account(id, scope, &self.pool))
+            .await
+            .map_err(database_status)?;
+
+        let balance = match (as_of, request.as_recorded) {
+            (Some(as_of), true) => {
+                metadata::time_db(database::Accounts::balance_as_recorded(
+                    id, as_of, &self.pool,
+                ))
+                .await
+            }
+            (None, true) => return Err(Status::invalid_argument("as_recorded requires as_of")),
+            (as_of, false) => {
+                metadata::time_db(database::Accounts::balance(id, as_of, &self.pool)).await
+            }
+        }
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::AccountGetBalanceResponse {
+            id: convert::format_id(&self.public_ids, id),
+            balance,
+            as_of: as_of.map(convert::format_date),
+        }))
+    }
+
+    async fn account_adjust_balance(
+        &self,
+        request: Request<rpc::AccountAdjustBalanceRequest>,
+    ) -> Result<Response<rpc::AccountAdjustBalanceResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        let on = match request.on.as_deref() {
+            Some(on) => convert::parse_date("on", on)?,
+            None => chrono::Utc::now().date_naive(),
+        };
+        let counted = request.counted_balance;
+
+        let (adjustment, balance) = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                let adjustment = database::Accounts::adjust_balance(
+                    id,
+                    counted,
+                    on,
+                    request.note,
+                    scope,
+                    &mut *conn,
+                )
+                .await?;
+                let balance = database::Accounts::balance(id, Some(on), conn).await?;
+
+                Ok((adjustment, balance))
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::AccountAdjustBalanceResponse {
+            id: convert::format_id(&self.public_ids, id),
+            transaction_id: adjustment
+                .as_ref()
+                .map(|transaction| convert::format_id(&self.public_ids, transaction.id)),
+            adjustment: adjustment.map_or(0, |transaction| transaction.amount),
+            balance,
+            on: convert::format_date(on),
+        }))
+    }
+
+    async fn account_snapshot_record(
+        &self,
+        request: Request<rpc::AccountSnapshotRecordRequest>,
+    ) -> Result<Response<rpc::AccountSnapshotRecordResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let message = request
+            .snapshot
+            .ok_or_else(|| Status::invalid_argument("Missing snapshot"))?;
+        let account_id =
+            convert::parse_id(&self.public_ids, "snapshot.account_id", &message.account_id)?;
+        let snapshot_date = convert::parse_date("snapshot.snapshot_date", &message.snapshot_date)?;
+
+        let mut snapshot =
+            database::BalanceSnapshots::new(account_id, snapshot_date, message.balance);
+        snapshot.note = message.note;
+
+        let recorded = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                ensure_account(account_id, scope, &mut *conn).await?;
+                snapshot.insert(conn).await
+            },
+        ))
+        .await
+        .map_err(|error| {
+            if convert::is_foreign_key_violation(&error) {
+                return convert::not_found(
+                    "account",
+                    "id",
+                    format!("Account with id {} not found", message.account_id),
+                );
+            }
+            database_status(error)
+        })?;
+
+        Ok(Response::new(rpc::AccountSnapshotRecordResponse {
+            snapshot: Some(snapshot_to_rpc(&self.public_ids, recorded)),
+        }))
+    }
+
+    async fn account_snapshots_list(
+        &self,
+        request: Request<rpc::AccountSnapshotsListRequest>,
+    ) -> Result<Response<rpc::AccountSnapshotsListResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let account_id = convert::parse_id(
+            &self.public_ids,
+            "account_id",
+            &request.into_inner().account_id,
+        )?;
+        metadata::time_db(ensure_account(account_id, scope, &self.pool))
+            .await
+            .map_err(database_status)?;
+
+        let snapshots = metadata::time_db(database::BalanceSnapshots::find_by_account(
+            account_id, &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::AccountSnapshotsListResponse {
+            snapshots: snapshots
+                .into_iter()
+                .map(|snapshot| snapshot_to_rpc(&self.public_ids, snapshot))
+                .collect(),
+        }))
+    }
+
+    async fn account_snapshot_delete(
+        &self,
+        request: Request<rpc::AccountSnapshotDeleteRequest>,
+    ) -> Result<Response<rpc::AccountSnapshotDeleteResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+
+        metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::BalanceSnapshots::delete_by_id(id, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::AccountSnapshotDeleteResponse {
+            rows_deleted: 1,
+        }))
+    }
+
+    async fn account_assertion_record(
+        &self,
+        request: Request<rpc::AccountAssertionRecordRequest>,
+    ) -> Result<Response<rpc::AccountAssertionRecordResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let message = request
+            .assertion
+            .ok_or_else(|| Status::invalid_argument("Missing assertion"))?;
+        let account_id = convert::parse_id(
+            &self.public_ids,
+            "assertion.account_id",
+            &message.account_id,
+        )?;
+        let assertion_date =
+            convert::parse_date("assertion.assertion_date", &message.assertion_date)?;
+
+        let mut assertion =
+            database::BalanceAssertions::new(account_id, assertion_date, message.balance);
+        assertion.note = message.note;
+
+        let recorded = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                ensure_account(account_id, scope, &mut *conn).await?;
+                assertion.insert(conn).await
+            },
+        ))
+        .await
+        .map_err(|error| {
+            if convert::is_foreign_key_violation(&error) {
+                return convert::not_found(
+                    "account",
+                    "id",
+                    format!("Account with id {} not found", message.account_id),
+                );
+            }
+            database_status(error)
+        })?;
+
+        Ok(Response::new(rpc::AccountAssertionRecordResponse {
+            assertion: Some(assertion_to_rpc(&self.public_ids, recorded)),
+        }))
+    }
+
+    async fn account_assertions_list(
+        &self,
+        request: Request<rpc::AccountAssertionsListRequest>,
+    ) -> Result<Response<rpc::AccountAssertionsListResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let account_id = convert::parse_id(
+            &self.public_ids,
+            "account_id",
+            &request.into_inner().account_id,
+        )?;
+        metadata::time_db(ensure_account(account_id, scope, &self.pool))
+            .await
+            .map_err(database_status)?;
+
+        let assertions = metadata::time_db(database::BalanceAssertions::find_by_account(
+            account_id, &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::AccountAssertionsListResponse {
+            assertions: assertions
+                .into_iter()
+                .map(|assertion| assertion_to_rpc(&self.public_ids, assertion))
+                .collect(),
+        }))
+    }
+
+    async fn account_assertion_delete(
+        &self,
+        request: Request<rpc::AccountAssertionDeleteRequest>,
+    ) -> Result<Response<rpc::AccountAssertionDeleteResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+
+        metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::BalanceAssertions::delete_by_id(id, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::AccountAssertionDeleteResponse {
+            rows_deleted: 1,
+        }))
+    }
+
+    async fn account_assertions_check(
+        &self,
+        request: Request<rpc::AccountAssertionsCheckRequest>,
+    ) -> Result<Response<rpc::AccountAssertionsCheckResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let account_id = request
+            .account_id
+            .as_deref()
+            .map(|id| convert::parse_id(&self.public_ids, "account_id", id))
+            .transpose()?;
+
+        let checks = metadata::time_db(database::BalanceAssertions::check(
+            account_id, scope, &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        let results = checks
+            .into_iter()
+            .filter(|check| !request.failing_only || !check.holds())
+            .map(|check| rpc::BalanceAssertionResult {
+                computed_balance: check.computed_balance,
+                difference: check.difference(),
+                holds: check.holds(),
+                assertion: Some(assertion_to_rpc(&self.public_ids, check.assertion)),
+            })
+            .collect();
+
+        Ok(Response::new(rpc::AccountAssertionsCheckResponse {
+            results,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::{AccountsService, CategoriesService, TransactionsService};
+
+    use crate::auth::as_user;
+    use crate::categories::MyCategoriesService;
+    use crate::transactions::MyTransactionsService;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn create(
+        service: &MyAccountsService,
+        name: &str,
+        opening_balance: i64,
+    ) -> core::result::Result<rpc::Account, Status> {
+        let request = Request::new(rpc::AccountCreateRequest {
+            account: Some(rpc::Account {
+                name: name.to_string(),
+                opening_balance,
+                is_active: true,
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+
+        Ok(service
+            .account_create(request)
+            .await?
+            .into_inner()
+            .account
+            .unwrap())
+    }
+
+    async fn post(
+        pool: &sqlx::SqlitePool,
+        account_id: &str,
+        date: &str,
+        amount: i64,
+    ) -> Result<()> {
+        let request = Request::new(rpc::TransactionCreateRequest {
+            transaction: Some(rpc::Transaction {
+                transaction_date: date.to_string(),
+                payee: "Payee".to_string(),
+                amount,
+                account_id: Some(account_id.to_string()),
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+        MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .transaction_create(request)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn balance(service: &MyAccountsService, id: &str, as_of: Option<&str>) -> Result<i64> {
+        let request = Request::new(rpc::AccountGetBalanceRequest {
+            id: id.to_string(),
+            as_of: as_of.map(str::to_string),
+            as_recorded: false,
+        });
+
+        Ok(service
+            .account_get_balance(request)
+            .await?
+            .into_inner()
+            .balance)
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn get_balance_sums_transactions(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyAccountsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let account = create(&service, "Everyday", 100_000).await?;
+
+        post(&pool, &account.id, "2025-03-01", -2_500).await?;
+        post(&pool, &account.id, "2025-03-20", -7_500).await?;
+
+        assert_eq!(balance(&service, &account.id, None).await?, 90_000);
+        assert_eq!(
+            balance(&service, &account.id, Some("2025-03-10")).await?,
+            97_500
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn users_only_see_and_change_their_own_accounts(pool: sqlx::SqlitePool) -> Result<()> {
+        database::Users::new("ian").insert(&pool).await?;
+        database::Users::new("sam").insert(&pool).await?;
+        let service =
+            MyAccountsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let create_as = |subject| {
+            as_user(
+                subject,
+                Request::new(rpc::AccountCreateRequest {
+                    account: Some(rpc::Account {
+                        name: "Everyday".to_string(),
+                        is_active: true,
+                        ..Default::default()
+                    }),
+                    validate_only: false,
+                }),
+            )
+        };
+
+        let ians = service
+            .account_create(create_as("ian"))
+            .await?
+            .into_inner()
+            .account
+            .unwrap();
+        service.account_create(create_as("sam")).await?;
+
+        let listed = service
+            .accounts_list(as_user("sam", Request::new(Default::default())))
+            .await?
+            .into_inner();
+        assert_eq!(listed.total_count, 1);
+        assert_ne!(listed.accounts[0].id, ians.id);
+
+        let get = rpc::AccountGetRequest {
+            id: ians.id.clone(),
+        };
+        let status = service
+            .account_get(as_user("sam", Request::new(get)))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        let balance = rpc::AccountGetBalanceRequest {
+            id: ians.id.clone(),
+            ..Default::default()
+        };
+        let status = service
+            .account_get_balance(as_user("sam", Request::new(balance)))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        let snapshot = rpc::AccountSnapshotRecordRequest {
+            snapshot: Some(rpc::BalanceSnapshot {
+                account_id: ians.id.clone(),
+                snapshot_date: "2025-03-31".to_string(),
+                ..Default::default()
+            }),
+            validate_only: false,
+        };
+        let status = service
+            .account_snapshot_record(as_user("sam", Request::new(snapshot)))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        let delete = rpc::AccountDeleteRequest {
+            id: ians.id.clone(),
+            validate_only: false,
+        };
+        let status = service
+            .account_delete(as_user("sam", Request::new(delete)))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        let status = service
+            .accounts_list(as_user("alex", Request::new(Default::default())))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn get_balance_as_recorded(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyAccountsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let account = create(&service, "Everyday", 100_000).await?;
+        post(&pool, &account.id, "2025-03-01", -2_500).await?;
+
+        let request = Request::new(rpc::AccountGetBalanceRequest {
+            id: account.id.clone(),
+            as_of: Some(convert::format_date(chrono::Utc::now().date_naive())),
+            as_recorded: true,
+        });
+        let response = service.account_get_balance(request).await?.into_inner();
+
+        assert_eq!(response.balance, 97_500);
+        assert!(response.as_of.is_some());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn get_balance_rejects_bad_input(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyAccountsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let account = create(&service, "Everyday", 0).await?;
+
+        let bad_date = service
+            .account_get_balance(Request::new(rpc::AccountGetBalanceRequest {
+                id: account.id.clone(),
+                as_of: Some("30/06/2025".to_string()),
+                as_recorded: false,
+            }))
+            .await;
+        let unknown_account = service
+            .account_get_balance(Request::new(rpc::AccountGetBalanceRequest {
+                id: convert::format_id(&convert::TEST_PUBLIC_IDS, lib_domain::RowID::new()),
+                as_of: None,
+                as_recorded: false,
+            }))
+            .await;
+        let recorded_without_date = service
+            .account_get_balance(Request::new(rpc::AccountGetBalanceRequest {
+                id: account.id.clone(),
+                as_of: None,
+                as_recorded: true,
+            }))
+            .await;
+
+        assert_eq!(bad_date.unwrap_err().code(), tonic::Code::InvalidArgument);
+        assert_eq!(unknown_account.unwrap_err().code(), tonic::Code::NotFound);
+        assert_eq!(
+            recorded_without_date.unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn create_duplicate_name_already_exists(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyAccountsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        create(&service, "Everyday", 0).await?;
+
+        let duplicate = create(&service, "Everyday", 0).await;
+
+        assert_eq!(duplicate.unwrap_err().code(), tonic::Code::AlreadyExists);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn update_and_list_accounts(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyAccountsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let account = create(&service, "Everyday", 0).await?;
+        create(&service, "Visa", -5_000).await?;
+
+        let request = Request::new(rpc::AccountUpdateRequest {
+            id: account.id.clone(),
+            account: Some(rpc::Account {
+                name: "Everyday Savings".to_string(),
+                is_active: false,
+                currency: "usd".to_string(),
+                ..account.clone()
+            }),
+            validate_only: false,
+        });
+        let updated = service
+            .account_update(request)
+            .await?
+            .into_inner()
+            .account
+            .unwrap();
+
+        let request = Request::new(rpc::AccountsListRequest {
+            page_token: String::new(),
+            limit: 10,
+        });
+        let list = service.accounts_list(request).await?.into_inner();
+
+        assert_eq!(updated.name, "Everyday Savings");
+        assert!(!updated.is_active);
+        assert_eq!(account.currency, "AUD");
+        assert_eq!(updated.currency, "USD");
+        assert_eq!(list.total_count, 2);
+        assert_eq!(list.accounts[0].name, "Everyday Savings");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn list_accounts_by_page_token(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyAccountsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        for name in ["Everyday", "Visa", "Savings"] {
+            create(&service, name, 0).await?;
+        }
+        let list = |page_token: String| {
+            Request::new(rpc::AccountsListRequest {
+                page_token,
+                limit: 2,
+            })
+        };
+
+        let first = service
+            .accounts_list(list(String::new()))
+            .await?
+            .into_inner();
+        let second = service
+            .accounts_list(list(first.next_page_token.clone()))
+            .await?
+            .into_inner();
+        let names = |list: &rpc::AccountsListResponse| {
+            list.accounts
+                .iter()
+                .map(|a| a.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(&first), ["Everyday", "Savings"]);
+        assert_eq!(names(&second), ["Visa"]);
+        assert_eq!((first.total_count, second.total_count), (3, 3));
+        assert!(second.next_page_token.is_empty());
+
+        let invalid = service.accounts_list(list("page-2".to_string())).await;
+        assert_eq!(invalid.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn create_rejects_unknown_currency(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyAccountsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+
+        let request = Request::new(rpc::AccountCreateRequest {
+            account: Some(rpc::Account {
+                name: "Travel".to_string(),
+                currency: "XYZ".to_string(),
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+        let result = service.account_create(request).await;
+
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn delete_account_with_transactions_fails(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyAccountsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let account = create(&service, "Everyday", 0).await?;
+        post(&pool, &account.id, "2025-03-01", -2_500).await?;
+
+        let result = service
+            .account_delete(Request::new(rpc::AccountDeleteRequest {
+                id: account.id,
+                validate_only: false,
+            }))
+            .await;
+
+        assert_eq!(result.unwrap_err().code(), tonic::Code::FailedPrecondition);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn adjust_balance_to_counted_cash(pool: sqlx::SqlitePool) -> Result<()> {
+        let category = MyCategoriesService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .category_create(Request::new(rpc::CategoryCreateRequest {
+            category: Some(rpc::Category {
+                code: "CASH".to_string(),
+                name: "Cash adjustments".to_string(),
+                category_type: lib_domain::CategoryTypes::Expense.to_rpc_i32(),
+                is_active: true,
+                ..Default::default()
+            }),
+            validate_only: false,
+        }))
+        .await?
+        .into_inner()
+        .category
+        .unwrap();
+        let service = MyAccountsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let mut wallet = create(&service, "Wallet", 5_000).await?;
+        post(&pool, &wallet.id, "2025-03-03", -550).await?;
+        let adjust = |validate_only| {
+            Request::new(rpc::AccountAdjustBalanceRequest {
+                id: wallet.id.clone(),
+                counted_balance: 4_260,
+                on: Some("2025-03-10".to_string()),
+                note: Some("Counted after the markets".to_string()),
+                validate_only,
+            })
+        };
+
+        let unconfigured = service.account_adjust_balance(adjust(false)).await;
+        assert_eq!(
+            unconfigured.unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+
+        wallet.adjustments_category_id = Some(category.id);
+        service
+            .account_update(Request::new(rpc::AccountUpdateRequest {
+                id: wallet.id.clone(),
+                account: Some(wallet.clone()),
+                validate_only: false,
+            }))
+            .await?;
+
+        let preview = service
+            .account_adjust_balance(adjust(true))
+            .await?
+            .into_inner();
+        assert_eq!((preview.adjustment, preview.balance), (-190, 4_260));
+        assert_eq!(balance(&service, &wallet.id, None).await?, 4_450);
+
+        let adjusted = service
+            .account_adjust_balance(adjust(false))
+            .await?
+            .into_inner();
+        assert!(adjusted.transaction_id.is_some());
+        assert_eq!(balance(&service, &wallet.id, None).await?, 4_260);
+
+        let matched = service
+            .account_adjust_balance(adjust(false))
+            .await?
+            .into_inner();
+        assert_eq!((matched.transaction_id, matched.adjustment), (None, 0));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn record_list_and_delete_snapshots(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyAccountsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let super_fund = create(&service, "Super", 0).await?;
+        let record = |snapshot_date: &str, balance| {
+            Request::new(rpc::AccountSnapshotRecordRequest {
+                snapshot: Some(rpc::BalanceSnapshot {
+                    account_id: super_fund.id.clone(),
+                    snapshot_date: snapshot_date.to_string(),
+                    balance,
+                    ..Default::default()
+                }),
+                validate_only: false,
+            })
+        };
+        let list = || {
+            Request::new(rpc::AccountSnapshotsListRequest {
+                account_id: super_fund.id.clone(),
+            })
+        };
+
+        let june = service
+            .account_snapshot_record(record("2025-06-30", 1_000_000))
+            .await?
+            .into_inner();
+        service
+            .account_snapshot_record(record("2025-03-31", 950_000))
+            .await?;
+        service
+            .account_snapshot_record(record("2025-06-30", 1_050_000))
+            .await?;
+
+        let snapshots = service
+            .account_snapshots_list(list())
+            .await?
+            .into_inner()
+            .snapshots;
+        let found: Vec<_> = snapshots
+            .iter()
+            .map(|s| (s.snapshot_date.as_str(), s.balance))
+            .collect();
+        assert_eq!(found, [("2025-03-31", 950_000), ("2025-06-30", 1_050_000)]);
+
+        let june_id = june.snapshot.unwrap().id;
+        let delete = Request::new(rpc::AccountSnapshotDeleteRequest {
+            id: june_id,
+            validate_only: false,
+        });
+        service.account_snapshot_delete(delete).await?;
+        assert_eq!(
+            service
+                .account_snapshots_list(list())
+                .await?
+                .into_inner()
+                .snapshots
+                .len(),
+            1
+        );
+
+        let mut unknown = record("2025-06-30", 0);
+        unknown.get_mut().snapshot.as_mut().unwrap().account_id =
+            convert::format_id(&convert::TEST_PUBLIC_IDS, lib_domain::RowID::new());
+        let unknown = service.account_snapshot_record(unknown).await;
+        assert_eq!(unknown.unwrap_err().code(), tonic::Code::NotFound);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn assertions_are_checked_against_the_ledger(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyAccountsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let everyday = create(&service, "Everyday", 10_000).await?;
+        post(&pool, &everyday.id, "2025-03-01", 250_000).await?;
+        post(&pool, &everyday.id, "2025-03-20", -15_000).await?;
+        post(&pool, &everyday.id, "2025-03-20", -15_000).await?;
+        let record = |assertion_date: &str, balance| {
+            Request::new(rpc::AccountAssertionRecordRequest {
+                assertion: Some(rpc::BalanceAssertion {
+                    account_id: everyday.id.clone(),
+                    assertion_date: assertion_date.to_string(),
+                    balance,
+                    ..Default::default()
+                }),
+                validate_only: false,
+            })
+        };
+        let check = |failing_only| {
+            Request::new(rpc::AccountAssertionsCheckRequest {
+                account_id: None,
+                failing_only,
+            })
+        };
+
+        service
+            .account_assertion_record(record("2025-03-10", 260_000))
+            .await?;
+        let march = service
+            .account_assertion_record(record("2025-03-31", 245_000))
+            .await?
+            .into_inner();
+
+        let results = service
+            .account_assertions_check(check(false))
+            .await?
+            .into_inner()
+            .results;
+        let found: Vec<_> = results
+            .iter()
+            .map(|r| (r.computed_balance, r.difference, r.holds))
+            .collect();
+        assert_eq!(found, [(260_000, 0, true), (230_000, -15_000, false)]);
+
+        let failing = service
+            .account_assertions_check(check(true))
+            .await?
+            .into_inner()
+            .results;
+        assert_eq!(failing.len(), 1);
+        assert_eq!(
+            failing[0].assertion.as_ref().unwrap().assertion_date,
+            "2025-03-31"
+        );
+
+        let march_id = march.assertion.unwrap().id;
+        let delete = Request::new(rpc::AccountAssertionDeleteRequest {
+            id: march_id,
+            validate_only: false,
+        });
+        service.account_assertion_delete(delete).await?;
+        let list = Request::new(rpc::AccountAssertionsListRequest {
+            account_id: everyday.id.clone(),
+        });
+        assert_eq!(
+            service
+                .account_assertions_list(list)
+                .await?
+                .into_inner()
+                .assertions
+                .len(),
+            1
+        );
+        assert!(
+            service
+                .account_assertions_check(check(true))
+                .await?
+                .into_inner()
+                .results
+                .is_empty()
+        );
+
+        Ok(())
+    }
+}
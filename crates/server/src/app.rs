@@ -0,0 +1,1156 @@
+//! # Server Application
+//!
+//! [`Server`] wires the Personal Ledger server together from a
+//! [`LedgerConfig`](config::LedgerConfig), so the binary and integration tests
+//! start exactly the same server.
+//!
+//! Each step of startup depends on the ones before it:
+//!
+//! 1. **Telemetry**, so everything after it is logged
+//! 2. **Database**, opened and then migrated, so nothing runs against an old
+//!    schema, and its public ID key read so IDs go on the wire as public IDs.
+//!    In sandbox mode it is then reset to demo data, otherwise a ledger
+//!    without categories is given the standard chart of them, and with
+//!    [demo seeding](ServerBuilder::with_seed_demo) a ledger without
+//!    transactions is given demo transactions
+//! 3. **Listener**, bound before serving so the address is known, even for an
+//!    ephemeral port. A server [built in process](ServerBuilder::build_in_process)
+//!    listens on in-memory pipes instead
+//! 4. **Background jobs**, the health monitor, recurring transaction
+//!    scheduler, scheduled backups, bill reminders, webhook publisher,
+//!    sandbox resets, receipt inbox and REST gateway. The health monitor
+//!    checks the database once before anything is served
+//! 5. **gRPC services**, with the standard health and reflection services,
+//!    served until the server is shut down
+//!
+//! Background jobs are owned by the server. When it stops they are aborted
+//! and waited for before the database is closed, and if a job stops on its
+//! own the server shuts down rather than carry on without it.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use lib_config::LedgerConfig;
+//! use server::Server;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = LedgerConfig::parse(None)?;
+//! let server = Server::builder(config).build().await?;
+//!
+//! let shutdown = server.shutdown_handle();
+//! tokio::spawn(async move {
+//!     let _ = server::shutdown_signal().await;
+//!     shutdown.shutdown();
+//! });
+//!
+//! server.run().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::server::TcpIncoming;
+
+use lib_config as config;
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+use lib_telemetry as telemetry;
+
+use crate::{ServerError, ServerResult};
+use crate::{
+    accounts, attachments, audit, auth, backups, budgets, categories, export, goals, health,
+    metadata, notifications, payees, periods, reconciliations, recurring, reflection, reports,
+    sandbox, search, transactions, utilities, versions,
+};
+
+/// Builds a [`Server`] from its configuration.
+#[derive(Debug, Clone)]
+pub struct ServerBuilder {
+    config: config::LedgerConfig,
+    telemetry: bool,
+    recurring_interval: Option<Duration>,
+    seed_demo: bool,
+}
+
+impl ServerBuilder {
+    /// Sets whether the global telemetry subscriber is initialised, on by
+    /// default. It can only be set once per process, so tests that start
+    /// more than one server turn it off.
+    pub fn with_telemetry(mut self, telemetry: bool) -> Self {
+        self.telemetry = telemetry;
+        self
+    }
+
+    /// Sets how often the recurring transaction scheduler runs, instead of
+    /// its default of once an hour.
+    pub fn with_recurring_interval(mut self, interval: Duration) -> Self {
+        self.recurring_interval = Some(interval);
+        self
+    }
+
+    /// Sets whether a ledger without transactions is given a few months of
+    /// demo transactions when the server starts, off by default.
+    pub fn with_seed_demo(mut self, seed_demo: bool) -> Self {
+        self.seed_demo = seed_demo;
+        self
+    }
+
+    /// Initialises telemetry, opens and migrates the database and binds the
+    /// server address, with TLS when a certificate is configured, or Unix
+    /// socket when one is configured, ready to [`run`](Server::run).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if telemetry cannot be initialised, the database
+    /// cannot be opened or migrated, the TLS files cannot be loaded, or the
+    /// address cannot be bound.
+    pub async fn build(self) -> ServerResult<Server> {
+        let (config, pool, public_ids) = self.open().await?;
+        let auth = auth::interceptor(
+            config.auth_config(),
+            config.server_config().socket_path.is_some(),
+        )?;
+
+        let mut transport = tonic::transport::Server::builder();
+        let listener = match &config.server_config().socket_path {
+            Some(socket_path) => Listener::bind_unix(socket_path)?,
+            None => {
+                if let Some(tls) = load_tls(config.server_config())? {
+                    transport = transport
+                        .tls_config(tls)
+                        .map_err(|e| ServerError::Tls(e.to_string()))?;
+                }
+                let incoming = TcpIncoming::bind(config.server_config().socket_address()?)?;
+                let local_addr = incoming.local_addr()?;
+                Listener::Tcp(incoming, local_addr)
+            }
+        };
+
+        Ok(Server {
+            config,
+            pool,
+            public_ids,
+            events: database::EventBus::new(),
+            auth,
+            transport,
+            listener,
+            recurring_interval: self.recurring_interval,
+            shutdown: ShutdownHandle::new(),
+        })
+    }
+
+    /// Initialises telemetry and opens and migrates the database like
+    /// [`build`](Self::build), but serves over in-memory pipes rather than a
+    /// socket. Returns the server, ready to [`run`](Server::run), and the
+    /// only client that can reach it.
+    ///
+    /// The configured address is not bound. Once every clone of the client
+    /// has been dropped the server shuts down.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if telemetry cannot be initialised or the database
+    /// cannot be opened or migrated.
+    pub async fn build_in_process(self) -> ServerResult<(Server, rpc::LedgerClient)> {
+        let (config, pool, public_ids) = self.open().await?;
+        // Only the embedding process holds the client, so it is trusted
+        let auth = auth::interceptor(config.auth_config(), true)?;
+        let (client, incoming) = rpc::LedgerClient::in_process();
+
+        let server = Server {
+            config,
+            pool,
+            public_ids,
+            events: database::EventBus::new(),
+            auth,
+            transport: tonic::transport::Server::builder(),
+            listener: Listener::InProcess(incoming),
+            recurring_interval: self.recurring_interval,
+            shutdown: ShutdownHandle::new(),
+        };
+
+        Ok((server, client))
+    }
+
+    /// Initialises telemetry, opens and migrates the database, and reads the
+    /// ledger's public ID key, which the migrations create.
+    async fn open(
+        &self,
+    ) -> ServerResult<(config::LedgerConfig, sqlx::SqlitePool, domain::PublicIdKey)> {
+        let config = self.config.clone();
+
+        if self.telemetry {
+            telemetry::init_with_config(config.telemetry_config())?;
+        }
+
+        let database_url = config.server_config().database_url()?;
+        if !config.server_config().is_in_memory()
+            && let Some(directory) = config.server_config().database_path.parent()
+        {
+            std::fs::create_dir_all(directory)?;
+        }
+        let database = database::DatabasePool::new(&database_url)
+            .with_config(config.database_config().clone())
+            .connect()
+            .await?;
+        let applied = database.migrate().await?;
+        tracing::info!("Database ready, applied {} migrations", applied.len());
+        let pool = database.into_pool()?;
+
+        let public_ids = database::public_id_key(&pool).await?;
+
+        if config.sandbox_config().enabled {
+            tracing::warn!("Sandbox mode on, resetting the database to demo data");
+            database::DemoData::reset(&pool).await?;
+        } else {
+            if config.server_config().seed_categories {
+                database::SeedData::categories(&pool).await?;
+            }
+            if self.seed_demo {
+                database::SeedData::demo(chrono::Utc::now().date_naive(), &pool).await?;
+            }
+        }
+
+        Ok((config, pool, public_ids))
+    }
+}
+
+/// Loads the TLS certificate, key and client authority, if TLS is turned on.
+fn load_tls(server: &config::ServerConfig) -> ServerResult<Option<rpc::ServerTlsConfig>> {
+    let Some((cert, key)) = server.tls_identity()? else {
+        return Ok(None);
+    };
+
+    let tls = rpc::load_server_tls(cert, key, server.client_ca.as_deref())
+        .map_err(|e| ServerError::Tls(e.to_string()))?;
+    tracing::info!(mutual = server.client_ca.is_some(), "TLS turned on");
+
+    Ok(Some(tls))
+}
+
+/// Signals a running [`Server`] to shut down. Cloned handles all signal the
+/// same server.
+#[derive(Debug, Clone)]
+pub struct ShutdownHandle {
+    sender: Arc<tokio::sync::watch::Sender<bool>>,
+}
+
+impl ShutdownHandle {
+    fn new() -> Self {
+        Self {
+            sender: Arc::new(tokio::sync::watch::Sender::new(false)),
+        }
+    }
+
+    /// Asks the server to stop. Requests in flight are finished first. A
+    /// server that has not started running yet stops as soon as it starts.
+    pub fn shutdown(&self) {
+        self.sender.send_replace(true);
+    }
+
+    /// Whether shutdown has been asked for.
+    pub fn is_shutdown(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    /// Completes once shutdown has been asked for.
+    pub async fn wait(&self) {
+        let mut receiver = self.sender.subscribe();
+        // The sender is held by `self`, so this cannot fail
+        let _ = receiver.wait_for(|stop| *stop).await;
+    }
+}
+
+/// Where a [`Server`] accepts connections from.
+#[derive(Debug)]
+enum Listener {
+    /// A bound TCP listener and its address.
+    Tcp(TcpIncoming, SocketAddr),
+
+    /// A bound Unix domain socket and its path.
+    #[cfg(unix)]
+    Unix(tokio::net::UnixListener, std::path::PathBuf),
+
+    /// The in-memory pipes of an in-process client.
+    InProcess(rpc::InProcessIncoming),
+}
+
+impl Listener {
+    /// Binds the Unix socket at `path`, replacing one left behind by a
+    /// server that did not stop cleanly. Only the current user can connect.
+    ///
+    /// The socket is bound in a directory only the current user can enter,
+    /// narrowed to `0600` and then renamed onto `path`, so there is no moment
+    /// where it can be reached with the permissions the umask gave it. A file
+    /// at `path` that is not a socket is left alone and refused.
+    #[cfg(unix)]
+    fn bind_unix(path: &std::path::Path) -> ServerResult<Self> {
+        use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+
+        let directory = match path.parent() {
+            Some(directory) if !directory.as_os_str().is_empty() => directory,
+            _ => std::path::Path::new("."),
+        };
+        std::fs::create_dir_all(directory)?;
+
+        match std::fs::symlink_metadata(path) {
+            Ok(metadata) if !metadata.file_type().is_socket() => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::AlreadyExists,
+                    format!("{} exists and is not a socket", path.display()),
+                )
+                .into());
+            }
+            Ok(_) if std::os::unix::net::UnixStream::connect(path).is_ok() => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::AddrInUse,
+                    format!("another server is listening on {}", path.display()),
+                )
+                .into());
+            }
+            Ok(_) => {
+                tracing::info!(path = %path.display(), "Replacing the socket of a server that did not stop cleanly");
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
+
+        // Kept short, as socket paths are limited to about 100 bytes
+        let staging = directory.join(format!(".ledger-{}", std::process::id()));
+        std::fs::DirBuilder::new().mode(0o700).create(&staging)?;
+        let staged = staging.join("s");
+        let bound = tokio::net::UnixListener::bind(&staged).and_then(|listener| {
+            std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+            // Replaces a stale socket at `path` in the same step
+            std::fs::rename(&staged, path)?;
+            Ok(listener)
+        });
+        let _ = std::fs::remove_file(&staged);
+        let _ = std::fs::remove_dir(&staging);
+
+        Ok(Self::Unix(bound?, path.to_path_buf()))
+    }
+
+    #[cfg(not(unix))]
+    fn bind_unix(_path: &std::path::Path) -> ServerResult<Self> {
+        Err(config::ConfigError::Validation(
+            "server.socket_path is only supported on Unix".to_string(),
+        )
+        .into())
+    }
+
+    /// Whether only the user the server runs as can connect, so that user
+    /// is trusted as the actor of requests.
+    fn is_local_user_only(&self) -> bool {
+        match self {
+            #[cfg(unix)]
+            Self::Unix(..) => true,
+            _ => false,
+        }
+    }
+}
+
+/// The Personal Ledger server, built and bound but not yet serving.
+#[derive(Debug)]
+pub struct Server {
+    config: config::LedgerConfig,
+    pool: sqlx::SqlitePool,
+    /// The ledger's key its records' public IDs are made with.
+    public_ids: domain::PublicIdKey,
+    /// The bus the ledger's committed changes are published on, for
+    /// webhooks and watch streams.
+    events: database::EventBus,
+    auth: rpc::AuthInterceptor,
+    transport: tonic::transport::Server,
+    listener: Listener,
+    recurring_interval: Option<Duration>,
+    shutdown: ShutdownHandle,
+}
+
+impl Server {
+    /// Starts building a server from the given configuration.
+    pub fn builder(config: config::LedgerConfig) -> ServerBuilder {
+        ServerBuilder {
+            config,
+            telemetry: true,
+            recurring_interval: None,
+            seed_demo: false,
+        }
+    }
+
+    /// The address the server is bound to, with the port the operating
+    /// system chose when the configured port is `0`. `None` when it listens
+    /// on a Unix socket or was [built in process](ServerBuilder::build_in_process).
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        match &self.listener {
+            Listener::Tcp(_, local_addr) => Some(*local_addr),
+            _ => None,
+        }
+    }
+
+    /// The configuration the server was built from.
+    pub fn config(&self) -> &config::LedgerConfig {
+        &self.config
+    }
+
+    /// The server's database pool, to seed or inspect the database directly.
+    pub fn pool(&self) -> &sqlx::SqlitePool {
+        &self.pool
+    }
+
+    /// Returns a handle that shuts the server down from another task.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    /// Asks the server to shut down, see [`ShutdownHandle::shutdown`].
+    pub fn shutdown(&self) {
+        self.shutdown.shutdown();
+    }
+
+    /// Starts the background jobs and serves the gRPC services until the
+    /// server is shut down, then stops the jobs and closes the database.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the REST gateway's port cannot be bound, the gRPC
+    /// transport fails, or a background job stops while the server is running.
+    pub async fn run(self) -> ServerResult<()> {
+        let mut jobs = tokio::task::JoinSet::new();
+
+        let mut scheduler =
+            recurring::RecurringScheduler::new(self.pool.clone(), self.events.clone());
+        if let Some(interval) = self.recurring_interval {
+            scheduler = scheduler.with_interval(interval);
+        }
+        jobs.spawn(async move {
+            scheduler.run().await;
+            "recurring transaction scheduler"
+        });
+
+        if let Some(backups) =
+            backups::BackupScheduler::new(self.pool.clone(), self.config.backup_config())
+        {
+            jobs.spawn(async move {
+                backups.run().await;
+                "database backup scheduler"
+            });
+        }
+
+        if let Some(reminders) = notifications::NotificationScheduler::new(
+            self.pool.clone(),
+            self.config.notifications_config(),
+        )? {
+            jobs.spawn(async move {
+                reminders.run().await;
+                "bill reminder notifications"
+            });
+        }
+
+        #[cfg(feature = "webhooks")]
+        if let Some(publisher) = crate::webhooks::WebhookPublisher::new(
+            self.pool.clone(),
+            self.public_ids.clone(),
+            self.events.clone(),
+            self.config.webhooks_config(),
+        )? {
+            jobs.spawn(async move {
+                publisher.run().await;
+                "webhook publisher"
+            });
+        }
+        #[cfg(not(feature = "webhooks"))]
+        if self.config.webhooks_config().is_enabled() {
+            tracing::warn!(
+                "Not posting events to webhooks, the server was built without the webhooks feature"
+            );
+        }
+
+        #[cfg(feature = "receipt-email")]
+        if let Some(maildir) = &self.config.server_config().receipt_maildir {
+            let inbox = crate::receipts::ReceiptInbox::new(
+                maildir.clone(),
+                self.pool.clone(),
+                self.events.clone(),
+            )
+            .with_quotas(self.config.quota_config().clone());
+            jobs.spawn(async move {
+                inbox.run().await;
+                "receipt inbox"
+            });
+        }
+
+        #[cfg(feature = "rest-gateway")]
+        if let Some(port) = self.config.server_config().gateway_port {
+            let address = SocketAddr::new(self.config.server_config().socket_address()?.ip(), port);
+            let listener = tokio::net::TcpListener::bind(address).await?;
+            let router = crate::gateway::router(
+                categories::MyCategoriesService::new(
+                    self.pool.clone(),
+                    self.public_ids.clone(),
+                    self.events.clone(),
+                )
+                .with_quotas(self.config.quota_config().clone()),
+                sandbox::SandboxLayer::new(self.config.sandbox_config().enabled),
+                self.auth.clone(),
+            );
+            jobs.spawn(async move {
+                crate::gateway::serve(listener, router).await;
+                "REST gateway"
+            });
+        }
+
+        if let Some(reset) = sandbox::SandboxReset::new(
+            self.pool.clone(),
+            self.events.clone(),
+            self.config.sandbox_config(),
+        ) {
+            jobs.spawn(async move {
+                reset.run().await;
+                "sandbox reset"
+            });
+        }
+
+        // Checked before serving, so probes never see the default healthy status
+        let (reporter, health_service) = rpc::health_reporter();
+        let monitor = health::HealthMonitor::new(self.pool.clone(), reporter);
+        monitor.run_once().await;
+        jobs.spawn(async move {
+            monitor.run().await;
+            "health monitor"
+        });
+
+        let default_actor = self
+            .listener
+            .is_local_user_only()
+            .then(audit::local_user)
+            .flatten();
+        let router = self
+            .transport
+            .layer(metadata::ResponseMetadataLayer::new(
+                self.config.telemetry_config().response_metadata(),
+            ))
+            .layer(audit::AuditActorLayer::default().with_default_actor(default_actor))
+            .layer(versions::ApiVersionLayer::default())
+            .layer(sandbox::SandboxLayer::new(
+                self.config.sandbox_config().enabled,
+            ))
+            // Names the RPC for the auth interceptor to check the caller's role
+            .layer(rpc::RpcMethodLayer)
+            // Left open, so probes can check the server without a token
+            .add_service(health_service)
+            .add_service(InterceptedService::new(
+                reflection::service_v1()?,
+                self.auth.clone(),
+            ))
+            .add_service(InterceptedService::new(
+                reflection::service_v1alpha()?,
+                self.auth.clone(),
+            ))
+            .add_service(rpc::AccountsServiceServer::with_interceptor(
+                accounts::MyAccountsService::new(
+                    self.pool.clone(),
+                    self.public_ids.clone(),
+                    self.events.clone(),
+                ),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::AuditServiceServer::with_interceptor(
+                audit::MyAuditService::new(self.pool.clone(), self.public_ids.clone()),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::AttachmentsServiceServer::with_interceptor(
+                attachments::MyAttachmentsService::new(
+                    self.pool.clone(),
+                    self.public_ids.clone(),
+                    self.events.clone(),
+                )
+                .with_quotas(self.config.quota_config().clone()),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::BudgetsServiceServer::with_interceptor(
+                budgets::MyBudgetsService::new(
+                    self.pool.clone(),
+                    self.public_ids.clone(),
+                    self.events.clone(),
+                ),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::CategoriesServiceServer::with_interceptor(
+                categories::MyCategoriesService::new(
+                    self.pool.clone(),
+                    self.public_ids.clone(),
+                    self.events.clone(),
+                )
+                .with_quotas(self.config.quota_config().clone()),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::ExportServiceServer::with_interceptor(
+                export::MyExportService::new(self.pool.clone(), self.public_ids.clone()),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::GoalsServiceServer::with_interceptor(
+                goals::MyGoalsService::new(
+                    self.pool.clone(),
+                    self.public_ids.clone(),
+                    self.events.clone(),
+                ),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::PayeesServiceServer::with_interceptor(
+                payees::MyPayeesService::new(
+                    self.pool.clone(),
+                    self.public_ids.clone(),
+                    self.events.clone(),
+                ),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::PeriodsServiceServer::with_interceptor(
+                periods::MyPeriodsService::new(
+                    self.pool.clone(),
+                    self.public_ids.clone(),
+                    self.events.clone(),
+                ),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::ReconciliationsServiceServer::with_interceptor(
+                reconciliations::MyReconciliationsService::new(
+                    self.pool.clone(),
+                    self.public_ids.clone(),
+                    self.events.clone(),
+                ),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::ReportsServiceServer::with_interceptor(
+                reports::MyReportsService::new(self.pool.clone(), self.public_ids.clone()),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::SearchServiceServer::with_interceptor(
+                search::MySearchService::new(self.pool.clone(), self.public_ids.clone()),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::TransactionsServiceServer::with_interceptor(
+                transactions::MyTransactionsService::new(
+                    self.pool.clone(),
+                    self.public_ids.clone(),
+                    self.events.clone(),
+                )
+                .with_quotas(self.config.quota_config().clone()),
+                self.auth.clone(),
+            ))
+            .add_service(rpc::UtilitiesServiceServer::with_interceptor(
+                utilities::MyUtilitiesService::new(self.pool.clone(), self.public_ids.clone())
+                    .with_backup_directory(self.config.backup_config().directory.clone())
+                    .with_quotas(self.config.quota_config().clone()),
+                self.auth.clone(),
+            ));
+
+        let signal = {
+            let shutdown = self.shutdown.clone();
+            async move { shutdown.wait().await }
+        };
+        let mut socket_path = None;
+        let mut serve: Pin<Box<dyn Future<Output = Result<(), tonic::transport::Error>> + Send>> =
+            match self.listener {
+                Listener::Tcp(incoming, local_addr) => {
+                    tracing::info!("Server listening on {local_addr}");
+                    Box::pin(router.serve_with_incoming_shutdown(incoming, signal))
+                }
+                #[cfg(unix)]
+                Listener::Unix(listener, path) => {
+                    tracing::info!("Server listening on {}", path.display());
+                    socket_path = Some(path);
+                    let incoming = futures_util::stream::unfold(listener, |listener| async move {
+                        let accepted = listener.accept().await.map(|(stream, _)| stream);
+                        Some((accepted, listener))
+                    });
+                    Box::pin(router.serve_with_incoming_shutdown(incoming, signal))
+                }
+                Listener::InProcess(incoming) => {
+                    tracing::info!("Server serving in process");
+                    Box::pin(router.serve_with_incoming_shutdown(incoming, signal))
+                }
+            };
+
+        let mut stopped_job = None;
+        let served = tokio::select! {
+            served = &mut serve => served,
+            Some(job) = jobs.join_next() => {
+                let job = match job {
+                    Ok(name) => name.to_string(),
+                    Err(error) => error.to_string(),
+                };
+                tracing::error!("Background job stopped, shutting down: {job}");
+                stopped_job = Some(job);
+
+                self.shutdown.shutdown();
+                serve.await
+            }
+        };
+
+        jobs.shutdown().await;
+        self.pool.close().await;
+        if let Some(path) = socket_path
+            && let Err(error) = std::fs::remove_file(&path)
+        {
+            tracing::warn!(path = %path.display(), "Could not remove the server socket: {error}");
+        }
+        tracing::info!("Server stopped");
+
+        served?;
+        match stopped_job {
+            Some(job) => Err(ServerError::Job(job)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn ephemeral() -> ServerBuilder {
+        let config = config::LedgerConfig {
+            server: config::ServerConfig::ephemeral(),
+            ..Default::default()
+        };
+
+        Server::builder(config).with_telemetry(false)
+    }
+
+    #[tokio::test]
+    async fn build_migrates_database_and_binds_ephemeral_port() -> Result<()> {
+        let server = ephemeral().build().await?;
+
+        let local_addr = server.local_addr().expect("a bound address");
+        assert_ne!(local_addr.port(), 0);
+        assert!(local_addr.ip().is_loopback());
+
+        let migrations = database::MigrationStatus::find_all(server.pool()).await?;
+        assert!(migrations.iter().all(|migration| migration.is_applied()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn run_serves_until_shutdown() -> Result<()> {
+        let server = ephemeral().build().await?;
+        let url = format!("http://{}", server.local_addr().expect("a bound address"));
+        let shutdown = server.shutdown_handle();
+        let running = tokio::spawn(server.run());
+
+        let mut client = rpc::UtilitiesServiceClient::connect(url.clone()).await?;
+        let status = client
+            .migration_status(rpc::MigrationStatusRequest {})
+            .await?
+            .into_inner();
+        assert_eq!(status.pending_count, 0);
+
+        let mut client = rpc::CategoriesServiceClient::connect(url.clone()).await?;
+        assert!(
+            client
+                .category_get(rpc::CategoryGetRequest::default())
+                .await
+                .is_err()
+        );
+
+        let client = rpc::LedgerClient::connect(url.clone()).await?;
+        let health = client
+            .health()
+            .check(rpc::HealthCheckRequest::default())
+            .await?
+            .into_inner();
+        assert_eq!(health.status(), rpc::HealthCheckStatus::Serving);
+
+        shutdown.shutdown();
+        assert!(shutdown.is_shutdown());
+        tokio::time::timeout(Duration::from_secs(5), running).await???;
+
+        assert!(rpc::UtilitiesServiceClient::connect(url).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn requests_without_a_token_are_unauthenticated_once_auth_is_on() -> Result<()> {
+        let directory = std::env::temp_dir().join(format!("ledger-auth-{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(&directory)?;
+        let api_keys_file = directory.join("api-keys");
+        // SHA-256 of "test"
+        std::fs::write(
+            &api_keys_file,
+            "ian admin 9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08\n",
+        )?;
+        let mut builder = ephemeral();
+        builder.config.auth.api_keys_file = Some(api_keys_file);
+
+        let server = builder.build().await?;
+        let url = format!("http://{}", server.local_addr().expect("a bound address"));
+        let shutdown = server.shutdown_handle();
+        let running = tokio::spawn(server.run());
+        let client = rpc::LedgerClient::connect(url).await?;
+
+        let status = client
+            .utilities()
+            .migration_status(rpc::MigrationStatusRequest {})
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        let migrations = client
+            .clone()
+            .with_token(Some(rpc::BearerToken::new("test")?))
+            .utilities()
+            .migration_status(rpc::MigrationStatusRequest {})
+            .await?
+            .into_inner();
+        assert_eq!(migrations.pending_count, 0);
+
+        let health = client
+            .health()
+            .check(rpc::HealthCheckRequest::default())
+            .await?
+            .into_inner();
+        assert_eq!(health.status(), rpc::HealthCheckStatus::Serving);
+
+        shutdown.shutdown();
+        tokio::time::timeout(Duration::from_secs(5), running).await???;
+        std::fs::remove_dir_all(&directory)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_only_keys_are_permission_denied_mutating_rpcs() -> Result<()> {
+        let directory = std::env::temp_dir().join(format!("ledger-auth-{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(&directory)?;
+        let api_keys_file = directory.join("api-keys");
+        // SHA-256 of "test2"
+        std::fs::write(
+            &api_keys_file,
+            "sam read-only 60303ae22b998861bce3b28f33eec1be758a213c86c93c076dbe9f558c11c752\n",
+        )?;
+        let mut builder = ephemeral();
+        builder.config.auth.api_keys_file = Some(api_keys_file);
+
+        let server = builder.build().await?;
+        let url = format!("http://{}", server.local_addr().expect("a bound address"));
+        let shutdown = server.shutdown_handle();
+        let running = tokio::spawn(server.run());
+        let client = rpc::LedgerClient::connect(url).await?;
+        let mut categories = rpc::CategoriesServiceClient::with_interceptor(
+            client.channel().clone(),
+            rpc::BearerToken::new("test2")?,
+        );
+
+        let listed = categories
+            .categories_list(rpc::CategoriesListRequest::default())
+            .await?
+            .into_inner();
+        assert_eq!(listed.total_count, 0);
+
+        let status = categories
+            .category_create(rpc::CategoryCreateRequest::default())
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert!(status.message().contains("CategoryCreate"), "{status:?}");
+
+        shutdown.shutdown();
+        tokio::time::timeout(Duration::from_secs(5), running).await???;
+        std::fs::remove_dir_all(&directory)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_fails_for_a_ledger_without_a_public_id_key() -> Result<()> {
+        let directory = std::env::temp_dir().join(format!("ledger-app-{}", uuid::Uuid::now_v7()));
+        let mut builder = ephemeral();
+        builder.config.server.database_path = directory.join("ledger.db");
+        let server = builder.clone().build().await?;
+        sqlx::query("DELETE FROM public_id_key")
+            .execute(server.pool())
+            .await?;
+        server.pool().close().await;
+
+        let result = builder.build().await;
+
+        assert!(
+            matches!(result, Err(ServerError::Database(_))),
+            "{result:?}"
+        );
+        std::fs::remove_dir_all(&directory)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_fails_for_an_unreadable_api_key_file() {
+        let mut builder = ephemeral();
+        builder.config.auth.api_keys_file = Some("/nonexistent/api-keys".into());
+
+        let result = builder.build().await;
+
+        assert!(matches!(result, Err(ServerError::Auth(_))));
+    }
+
+    #[tokio::test]
+    async fn build_in_process_serves_without_a_socket() -> Result<()> {
+        let (server, client) = ephemeral().build_in_process().await?;
+        assert!(server.local_addr().is_none());
+        let running = tokio::spawn(server.run());
+
+        let status = client
+            .utilities()
+            .migration_status(rpc::MigrationStatusRequest {})
+            .await?
+            .into_inner();
+        assert_eq!(status.pending_count, 0);
+
+        // Dropping the only client stops the server
+        drop(client);
+        tokio::time::timeout(Duration::from_secs(5), running).await???;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sandbox_serves_demo_data_without_admin_rpcs() -> Result<()> {
+        let mut builder = ephemeral();
+        builder.config.sandbox.enabled = true;
+        let (server, client) = builder.build_in_process().await?;
+
+        let usage = database::Usage::find(server.pool()).await?;
+        assert!(usage.categories > 0 && usage.transactions > 0);
+        let running = tokio::spawn(server.run());
+
+        let status = client
+            .categories()
+            .category_delete(rpc::CategoryDeleteRequest::default())
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        drop(client);
+        tokio::time::timeout(Duration::from_secs(5), running).await???;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_seeds_the_chart_and_demo_transactions() -> Result<()> {
+        let mut builder = ephemeral();
+        builder.config.server.seed_categories = true;
+
+        let (plain, _) = ephemeral().build_in_process().await?;
+        let (seeded, _) = builder.with_seed_demo(true).build_in_process().await?;
+
+        assert_eq!(database::Usage::find(plain.pool()).await?.categories, 0);
+        let usage = database::Usage::find(seeded.pool()).await?;
+        assert!(usage.categories > 0 && usage.transactions > 0);
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn build_listens_on_unix_socket() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let directory =
+            std::env::temp_dir().join(format!("ledger-socket-{}", uuid::Uuid::now_v7()));
+        let socket_path = directory.join("personal-ledger.sock");
+        let mut config = config::LedgerConfig {
+            server: config::ServerConfig::ephemeral(),
+            ..Default::default()
+        };
+        config.server.socket_path = Some(socket_path.clone());
+
+        let server = Server::builder(config.clone())
+            .with_telemetry(false)
+            .build()
+            .await?;
+        assert!(server.local_addr().is_none());
+        assert_eq!(
+            std::fs::metadata(&socket_path)?.permissions().mode() & 0o777,
+            0o600
+        );
+        let shutdown = server.shutdown_handle();
+        let running = tokio::spawn(server.run());
+
+        let client = rpc::LedgerClient::connect_unix(&socket_path).await?;
+        let status = client
+            .utilities()
+            .migration_status(rpc::MigrationStatusRequest {})
+            .await?
+            .into_inner();
+        assert_eq!(status.pending_count, 0);
+
+        // The socket is in use, so a second server cannot take it over
+        let second = Server::builder(config).with_telemetry(false).build().await;
+        assert!(matches!(second, Err(ServerError::Bind(_))));
+
+        shutdown.shutdown();
+        tokio::time::timeout(Duration::from_secs(5), running).await???;
+        assert!(!socket_path.exists());
+
+        std::fs::remove_dir_all(&directory)?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn build_replaces_stale_sockets_but_not_other_files() -> Result<()> {
+        let directory =
+            std::env::temp_dir().join(format!("ledger-socket-{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(&directory)?;
+        let socket_path = directory.join("personal-ledger.sock");
+        let mut config = config::LedgerConfig {
+            server: config::ServerConfig::ephemeral(),
+            ..Default::default()
+        };
+        config.server.socket_path = Some(socket_path.clone());
+
+        // A socket nothing listens on any more, as a crashed server leaves
+        drop(std::os::unix::net::UnixListener::bind(&socket_path)?);
+        let server = Server::builder(config.clone())
+            .with_telemetry(false)
+            .build()
+            .await?;
+        assert!(std::os::unix::net::UnixStream::connect(&socket_path).is_ok());
+        drop(server);
+        assert_eq!(std::fs::read_dir(&directory)?.count(), 1);
+
+        std::fs::remove_file(&socket_path)?;
+        std::fs::write(&socket_path, "not a socket")?;
+        let refused = Server::builder(config).with_telemetry(false).build().await;
+        assert!(matches!(refused, Err(ServerError::Bind(_))));
+        assert_eq!(std::fs::read_to_string(&socket_path)?, "not a socket");
+
+        std::fs::remove_dir_all(&directory)?;
+        Ok(())
+    }
+
+    /// Writes a certificate authority, and a server and client certificate
+    /// signed by it, to `directory` as PEM files.
+    fn write_certificates(directory: &std::path::Path) -> Result<()> {
+        let mut ca_params = rcgen::CertificateParams::new(Vec::<String>::new())?;
+        ca_params.is_ca = rcgen::IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+        let ca = rcgen::CertifiedIssuer::self_signed(ca_params, rcgen::KeyPair::generate()?)?;
+        std::fs::write(directory.join("ca.pem"), ca.pem())?;
+
+        for (name, subject) in [("server", "localhost"), ("client", "ian")] {
+            let key = rcgen::KeyPair::generate()?;
+            let cert =
+                rcgen::CertificateParams::new(vec![subject.to_string()])?.signed_by(&key, &ca)?;
+            std::fs::write(directory.join(format!("{name}.pem")), cert.pem())?;
+            std::fs::write(directory.join(format!("{name}.key")), key.serialize_pem())?;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_serves_mutual_tls() -> Result<()> {
+        let directory = std::env::temp_dir().join(format!("ledger-tls-{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(&directory)?;
+        write_certificates(&directory)?;
+        let mut config = config::LedgerConfig {
+            server: config::ServerConfig::ephemeral(),
+            ..Default::default()
+        };
+        config.server.tls_cert = Some(directory.join("server.pem"));
+        config.server.tls_key = Some(directory.join("server.key"));
+        config.server.client_ca = Some(directory.join("ca.pem"));
+
+        let server = Server::builder(config)
+            .with_telemetry(false)
+            .build()
+            .await?;
+        let url = format!(
+            "https://localhost:{}",
+            server.local_addr().expect("a bound address").port()
+        );
+        let shutdown = server.shutdown_handle();
+        let running = tokio::spawn(server.run());
+
+        let ca = directory.join("ca.pem");
+        let identity = (directory.join("client.pem"), directory.join("client.key"));
+        let tls = rpc::load_client_tls(Some(&ca), Some((&identity.0, &identity.1)))?;
+        let client = rpc::LedgerClient::connect_tls(url.clone(), tls).await?;
+        let status = client
+            .utilities()
+            .migration_status(rpc::MigrationStatusRequest {})
+            .await?
+            .into_inner();
+        assert_eq!(status.pending_count, 0);
+
+        // Without a client certificate the server turns the client away
+        let anonymous =
+            match rpc::LedgerClient::connect_tls(url, rpc::load_client_tls(Some(&ca), None)?).await
+            {
+                Ok(client) => client
+                    .health()
+                    .check(rpc::HealthCheckRequest::default())
+                    .await
+                    .is_err(),
+                Err(_) => true,
+            };
+        assert!(anonymous);
+
+        shutdown.shutdown();
+        tokio::time::timeout(Duration::from_secs(5), running).await???;
+
+        std::fs::remove_dir_all(&directory)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_fails_for_missing_tls_files() {
+        let mut config = config::LedgerConfig {
+            server: config::ServerConfig::ephemeral(),
+            ..Default::default()
+        };
+        config.server.tls_cert = Some("/nonexistent/server.pem".into());
+        config.server.tls_key = Some("/nonexistent/server.key".into());
+
+        let result = Server::builder(config).with_telemetry(false).build().await;
+
+        assert!(
+            matches!(result, Err(ServerError::Tls(message)) if message.contains("/nonexistent/server.pem"))
+        );
+    }
+
+    #[tokio::test]
+    async fn shutdown_before_run_stops_straight_away() -> Result<()> {
+        let server = ephemeral().build().await?;
+        server.shutdown();
+
+        tokio::time::timeout(Duration::from_secs(5), server.run()).await??;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_fails_for_invalid_address() {
+        let mut config = config::LedgerConfig::default();
+        config.server = config::ServerConfig {
+            address: "not_an_ip".to_string(),
+            ..config::ServerConfig::ephemeral()
+        };
+
+        let result = Server::builder(config).with_telemetry(false).build().await;
+
+        assert!(matches!(result, Err(ServerError::Config(_))));
+    }
+}
@@ -0,0 +1,436 @@
+//! # Attachments Service
+//!
+//! gRPC handlers for the `AttachmentsService`, backed by the lib-database
+//! [`Attachments`](database::Attachments) model.
+//!
+//! Uploads arrive as a stream of messages, the metadata then the content in
+//! chunks, and are refused as soon as they pass
+//! [`MAX_ATTACHMENT_BYTES`](database::MAX_ATTACHMENT_BYTES), before the rest
+//! is read. The whole file is checked and stored once the stream ends, within
+//! the attachment bytes quota. Downloads are sent back the same way, in
+//! chunks of [`DOWNLOAD_CHUNK_BYTES`].
+
+use tokio_stream::StreamExt;
+use tonic::{Request, Response, Status, Streaming};
+
+use lib_config as config;
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::quota::{Quotas, Resource};
+use crate::{auth, convert, metadata};
+
+/// Size of the content chunks a download is sent in.
+pub const DOWNLOAD_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Server implementation of the `AttachmentsService`.
+#[derive(Debug, Clone)]
+pub struct MyAttachmentsService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+    events: database::EventBus,
+    quotas: Quotas,
+}
+
+impl MyAttachmentsService {
+    /// Creates the service using the given database pool, public ID key and
+    /// event bus, with no quota.
+    pub fn new(
+        pool: sqlx::SqlitePool,
+        public_ids: domain::PublicIdKey,
+        events: database::EventBus,
+    ) -> Self {
+        Self {
+            pool,
+            public_ids,
+            events,
+            quotas: Quotas::default(),
+        }
+    }
+
+    /// Sets the quotas checked before attachments are stored.
+    pub fn with_quotas(mut self, quotas: config::QuotaConfig) -> Self {
+        self.quotas = Quotas::new(quotas);
+        self
+    }
+}
+
+/// Converts a database attachment into its RPC message.
+fn to_rpc(public_ids: &domain::PublicIdKey, attachment: database::Attachments) -> rpc::Attachment {
+    rpc::Attachment {
+        id: convert::format_id(public_ids, attachment.id),
+        transaction_id: convert::format_id(public_ids, attachment.transaction_id),
+        file_name: attachment.file_name,
+        content_type: attachment.content_type,
+        size_bytes: attachment.size_bytes,
+        sha256: attachment.sha256,
+        created_on: Some(convert::to_timestamp(attachment.created_on)),
+    }
+}
+
+/// Reads an upload stream into its metadata and content.
+///
+/// # Errors
+///
+/// Returns `INVALID_ARGUMENT` if the stream does not start with the metadata,
+/// sends it twice, or carries more than `MAX_ATTACHMENT_BYTES` of content.
+async fn read_upload(
+    mut parts: Streaming<rpc::AttachmentUploadRequest>,
+) -> Result<(rpc::AttachmentUploadMetadata, Vec<u8>), Status> {
+    let mut metadata = None;
+    let mut content = Vec::new();
+
+    while let Some(message) = parts.next().await {
+        match (message?.part, &metadata) {
+            (Some(rpc::AttachmentUploadPart::Metadata(sent)), None) => metadata = Some(sent),
+            (Some(rpc::AttachmentUploadPart::Metadata(_)), Some(_)) => {
+                return Err(Status::invalid_argument(
+                    "Attachment metadata sent more than once",
+                ));
+            }
+            (Some(rpc::AttachmentUploadPart::Chunk(chunk)), Some(_)) => {
+                if content.len() + chunk.len() > database::MAX_ATTACHMENT_BYTES {
+                    return Err(Status::invalid_argument(format!(
+                        "Attachment is larger than the {} bytes allowed",
+                        database::MAX_ATTACHMENT_BYTES
+                    )));
+                }
+                content.extend_from_slice(&chunk);
+            }
+            (Some(rpc::AttachmentUploadPart::Chunk(_)), None) => {
+                return Err(Status::invalid_argument(
+                    "Attachment content sent before its metadata",
+                ));
+            }
+            (None, _) => return Err(Status::invalid_argument("Empty attachment upload message")),
+        }
+    }
+
+    let metadata =
+        metadata.ok_or_else(|| Status::invalid_argument("Missing attachment metadata"))?;
+
+    Ok((metadata, content))
+}
+
+#[tonic::async_trait]
+impl rpc::AttachmentsService for MyAttachmentsService {
+    type AttachmentDownloadStream =
+        tokio_stream::Iter<std::vec::IntoIter<Result<rpc::AttachmentDownloadResponse, Status>>>;
+
+    async fn attachment_upload(
+        &self,
+        request: Request<Streaming<rpc::AttachmentUploadRequest>>,
+    ) -> Result<Response<rpc::AttachmentUploadResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let (upload, content) = read_upload(request.into_inner()).await?;
+
+        let transaction_id = convert::parse_id(
+            &self.public_ids,
+            "metadata.transaction_id",
+            &upload.transaction_id,
+        )?;
+        // Attachments belong to the owner of their transaction
+        metadata::time_db(database::Transactions::find_by_id(
+            transaction_id,
+            scope,
+            &self.pool,
+        ))
+        .await
+        .map_err(convert::database_status)?
+        .ok_or_else(|| {
+            convert::not_found(
+                "transaction",
+                "metadata.transaction_id",
+                format!("Transaction with id {} not found", upload.transaction_id),
+            )
+        })?;
+        let attachment = database::Attachments::new(
+            transaction_id,
+            upload.file_name,
+            upload.content_type,
+            &content,
+        );
+        self.quotas
+            .check(Resource::AttachmentBytes, content.len() as u64, &self.pool)
+            .await?;
+
+        let stored = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            upload.validate_only,
+            async |conn| attachment.insert(&content, conn).await,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::AttachmentUploadResponse {
+            attachment: Some(to_rpc(&self.public_ids, stored)),
+        }))
+    }
+
+    async fn attachment_download(
+        &self,
+        request: Request<rpc::AttachmentDownloadRequest>,
+    ) -> Result<Response<Self::AttachmentDownloadStream>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let public_id = request.into_inner().id;
+        let id = convert::parse_id(&self.public_ids, "id", &public_id)?;
+        let not_found = || {
+            convert::not_found(
+                "attachment",
+                "id",
+                format!("Attachment with id {public_id} not found"),
+            )
+        };
+
+        let attachment =
+            metadata::time_db(database::Attachments::find_by_id(id, scope, &self.pool))
+                .await
+                .map_err(convert::database_status)?
+                .ok_or_else(not_found)?;
+        let content = metadata::time_db(database::Attachments::find_content(id, scope, &self.pool))
+            .await
+            .map_err(convert::database_status)?
+            .ok_or_else(not_found)?;
+
+        let first = rpc::AttachmentDownloadResponse {
+            part: Some(rpc::AttachmentDownloadPart::Attachment(to_rpc(
+                &self.public_ids,
+                attachment,
+            ))),
+        };
+        let chunks =
+            content
+                .chunks(DOWNLOAD_CHUNK_BYTES)
+                .map(|chunk| rpc::AttachmentDownloadResponse {
+                    part: Some(rpc::AttachmentDownloadPart::Chunk(chunk.to_vec())),
+                });
+        let messages: Vec<_> = std::iter::once(first).chain(chunks).map(Ok).collect();
+
+        Ok(Response::new(tokio_stream::iter(messages)))
+    }
+
+    async fn attachments_list(
+        &self,
+        request: Request<rpc::AttachmentsListRequest>,
+    ) -> Result<Response<rpc::AttachmentsListResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let transaction_id = convert::parse_id(
+            &self.public_ids,
+            "transaction_id",
+            &request.into_inner().transaction_id,
+        )?;
+
+        let attachments = metadata::time_db(database::Attachments::find_by_transaction(
+            transaction_id,
+            scope,
+            &self.pool,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::AttachmentsListResponse {
+            attachments: attachments
+                .into_iter()
+                .map(|attachment| to_rpc(&self.public_ids, attachment))
+                .collect(),
+        }))
+    }
+
+    async fn attachment_delete(
+        &self,
+        request: Request<rpc::AttachmentDeleteRequest>,
+    ) -> Result<Response<rpc::AttachmentDeleteResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+
+        metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Attachments::delete_by_id(id, scope, conn).await,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::AttachmentDeleteResponse {
+            rows_deleted: 1,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::AttachmentsService;
+
+    use crate::auth::as_user;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn attach(pool: &sqlx::SqlitePool, content: &[u8]) -> Result<database::Attachments> {
+        let transaction =
+            database::Transactions::new(chrono::Utc::now().date_naive(), "Officeworks", -4_500)
+                .insert(pool)
+                .await?;
+
+        Ok(
+            database::Attachments::new(transaction.id, "invoice.pdf", "application/pdf", content)
+                .insert(content, pool)
+                .await?,
+        )
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn download_sends_the_attachment_then_its_content_in_chunks(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let mut content = b"%PDF-1.7".to_vec();
+        content.resize(DOWNLOAD_CHUNK_BYTES + 10, b' ');
+        let attachment = attach(&pool, &content).await?;
+        let service =
+            MyAttachmentsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+
+        let request = Request::new(rpc::AttachmentDownloadRequest {
+            id: convert::format_id(&convert::TEST_PUBLIC_IDS, attachment.id),
+        });
+        let messages: Vec<_> = service
+            .attachment_download(request)
+            .await?
+            .into_inner()
+            .collect()
+            .await;
+
+        assert_eq!(messages.len(), 3);
+        let mut downloaded = Vec::new();
+        for message in messages {
+            match message?.part {
+                Some(rpc::AttachmentDownloadPart::Attachment(sent)) => {
+                    assert_eq!(sent.sha256, attachment.sha256)
+                }
+                Some(rpc::AttachmentDownloadPart::Chunk(chunk)) => downloaded.extend(chunk),
+                None => panic!("empty download message"),
+            }
+        }
+        assert_eq!(downloaded, content);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn delete_removes_it_from_the_list(pool: sqlx::SqlitePool) -> Result<()> {
+        let attachment = attach(&pool, b"%PDF-1.7").await?;
+        let service =
+            MyAttachmentsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let list = || {
+            Request::new(rpc::AttachmentsListRequest {
+                transaction_id: convert::format_id(
+                    &convert::TEST_PUBLIC_IDS,
+                    attachment.transaction_id,
+                ),
+            })
+        };
+
+        assert_eq!(
+            service
+                .attachments_list(list())
+                .await?
+                .into_inner()
+                .attachments
+                .len(),
+            1
+        );
+
+        let request = Request::new(rpc::AttachmentDeleteRequest {
+            id: convert::format_id(&convert::TEST_PUBLIC_IDS, attachment.id),
+            validate_only: false,
+        });
+        service.attachment_delete(request).await?;
+
+        assert!(
+            service
+                .attachments_list(list())
+                .await?
+                .into_inner()
+                .attachments
+                .is_empty()
+        );
+        let request = Request::new(rpc::AttachmentDownloadRequest {
+            id: convert::format_id(&convert::TEST_PUBLIC_IDS, attachment.id),
+        });
+        assert_eq!(
+            service
+                .attachment_download(request)
+                .await
+                .unwrap_err()
+                .code(),
+            tonic::Code::NotFound
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn users_only_see_and_delete_attachments_of_their_own_transactions(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let ian = database::Users::new("ian").insert(&pool).await?;
+        database::Users::new("sam").insert(&pool).await?;
+        let mut transaction =
+            database::Transactions::new(chrono::Utc::now().date_naive(), "Officeworks", -4_500);
+        transaction.user_id = Some(ian.id);
+        let transaction = transaction.insert(&pool).await?;
+        let attachment = database::Attachments::new(
+            transaction.id,
+            "invoice.pdf",
+            "application/pdf",
+            b"%PDF-1.7",
+        )
+        .insert(b"%PDF-1.7", &pool)
+        .await?;
+        let service =
+            MyAttachmentsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let id = convert::format_id(&convert::TEST_PUBLIC_IDS, attachment.id);
+        let list_as = |subject| {
+            as_user(
+                subject,
+                Request::new(rpc::AttachmentsListRequest {
+                    transaction_id: convert::format_id(&convert::TEST_PUBLIC_IDS, transaction.id),
+                }),
+            )
+        };
+        let download_as = |subject| {
+            as_user(
+                subject,
+                Request::new(rpc::AttachmentDownloadRequest { id: id.clone() }),
+            )
+        };
+
+        let listed = service.attachments_list(list_as("sam")).await?.into_inner();
+        assert!(listed.attachments.is_empty());
+        let status = service
+            .attachment_download(download_as("sam"))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        let status = service
+            .attachment_delete(as_user(
+                "sam",
+                Request::new(rpc::AttachmentDeleteRequest {
+                    id: id.clone(),
+                    validate_only: false,
+                }),
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        let listed = service.attachments_list(list_as("ian")).await?.into_inner();
+        assert_eq!(listed.attachments.len(), 1);
+        service.attachment_download(download_as("ian")).await?;
+
+        Ok(())
+    }
+}
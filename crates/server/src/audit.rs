@@ -0,0 +1,365 @@
+//! # Audit Service
+//!
+//! gRPC handlers for the `AuditService`, backed by the lib-database
+//! [`AuditLog`](database::AuditLog), and the tower layer that names the actor
+//! changes are recorded against.
+//!
+//! There are no user accounts yet, so the actor is whoever the client says it
+//! is in the `x-actor` request metadata. [`AuditActorLayer`] runs the request
+//! with that actor set, and lib-database records it against every change the
+//! handler makes in a transaction.
+//!
+//! On a Unix socket only the user the server runs as can connect, so that
+//! user is trusted as the actor of requests that do not name one.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tonic::codegen::http;
+use tonic::{Request, Response, Status};
+
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{auth, convert, metadata};
+
+/// Metadata key naming the actor of a request.
+pub const ACTOR_HEADER: &str = "x-actor";
+
+/// Server implementation of the `AuditService`.
+#[derive(Debug, Clone)]
+pub struct MyAuditService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+}
+
+impl MyAuditService {
+    /// Creates the service using the given database pool and public ID key.
+    pub fn new(pool: sqlx::SqlitePool, public_ids: domain::PublicIdKey) -> Self {
+        Self { pool, public_ids }
+    }
+}
+
+/// A row snapshot with every row ID in it, such as `id` or `category_id`,
+/// replaced by its public ID.
+fn public_snapshot(public_ids: &domain::PublicIdKey, snapshot: String) -> String {
+    let Ok(serde_json::Value::Object(mut row)) = serde_json::from_str(&snapshot) else {
+        return snapshot;
+    };
+
+    for value in row.values_mut() {
+        if let Some(id) = value
+            .as_str()
+            .and_then(|text| text.parse::<domain::RowID>().ok())
+        {
+            *value = convert::format_id(public_ids, id).into();
+        }
+    }
+
+    serde_json::Value::Object(row).to_string()
+}
+
+/// Converts a database audit log entry into its RPC message, naming rows by
+/// their public IDs.
+fn to_rpc(public_ids: &domain::PublicIdKey, entry: database::AuditLog) -> rpc::AuditEntry {
+    let action = match entry.action {
+        database::AuditActions::Insert => rpc::AuditActions::Insert,
+        database::AuditActions::Update => rpc::AuditActions::Update,
+        database::AuditActions::Delete => rpc::AuditActions::Delete,
+    };
+
+    rpc::AuditEntry {
+        id: entry.id,
+        entity: entry.entity,
+        entity_id: entry
+            .entity_id
+            .parse()
+            .map(|id| convert::format_id(public_ids, id))
+            .unwrap_or(entry.entity_id),
+        action: action as i32,
+        actor: entry.actor,
+        old_values: entry
+            .old_values
+            .map(|snapshot| public_snapshot(public_ids, snapshot)),
+        new_values: entry
+            .new_values
+            .map(|snapshot| public_snapshot(public_ids, snapshot)),
+        changed_on: Some(convert::to_timestamp(entry.changed_on)),
+    }
+}
+
+#[tonic::async_trait]
+impl rpc::AuditService for MyAuditService {
+    async fn audit_history(
+        &self,
+        request: Request<rpc::AuditHistoryRequest>,
+    ) -> Result<Response<rpc::AuditHistoryResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let entity = request.entity.trim();
+        let entity_id = request.entity_id.trim();
+
+        if entity_id.is_empty() {
+            return Err(Status::invalid_argument("entity_id is required"));
+        }
+        let entity_id = convert::parse_id(&self.public_ids, "entity_id", entity_id)?.to_string();
+
+        let entries = metadata::time_db(database::AuditLog::find_by_entity(
+            entity, &entity_id, scope, &self.pool,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::AuditHistoryResponse {
+            entries: entries
+                .into_iter()
+                .map(|entry| to_rpc(&self.public_ids, entry))
+                .collect(),
+        }))
+    }
+}
+
+/// Layer that wraps services in [`AuditActor`].
+#[derive(Debug, Clone, Default)]
+pub struct AuditActorLayer {
+    default_actor: Option<String>,
+}
+
+impl AuditActorLayer {
+    /// Sets the actor of requests that do not name one in their metadata.
+    pub fn with_default_actor(mut self, default_actor: Option<String>) -> Self {
+        self.default_actor = default_actor;
+        self
+    }
+}
+
+impl<S> tower::Layer<S> for AuditActorLayer {
+    type Service = AuditActor<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuditActor {
+            inner,
+            default_actor: self.default_actor.clone(),
+        }
+    }
+}
+
+/// Service that runs each request with the actor named in its `x-actor`
+/// metadata, or the layer's default actor, if any.
+#[derive(Debug, Clone)]
+pub struct AuditActor<S> {
+    inner: S,
+    default_actor: Option<String>,
+}
+
+/// The name of the operating system user the server runs as, if known.
+pub fn local_user() -> Option<String> {
+    ["USER", "USERNAME"]
+        .into_iter()
+        .filter_map(|key| std::env::var(key).ok())
+        .map(|user| user.trim().to_string())
+        .find(|user| !user.is_empty())
+}
+
+impl<S, ReqBody, ResBody> tower::Service<http::Request<ReqBody>> for AuditActor<S>
+where
+    S: tower::Service<http::Request<ReqBody>, Response = http::Response<ResBody>>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        // Take the service that was driven to readiness and leave a clone behind
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let actor = request
+            .headers()
+            .get(ACTOR_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+            .or_else(|| self.default_actor.clone());
+
+        match actor {
+            Some(actor) => Box::pin(database::AuditLog::with_actor(actor, inner.call(request))),
+            None => Box::pin(inner.call(request)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::{AuditService, CategoriesService};
+    use tower::{Layer, ServiceExt};
+
+    use crate::auth::as_user;
+    use crate::categories::MyCategoriesService;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn history(entity: &str, entity_id: &str) -> Request<rpc::AuditHistoryRequest> {
+        Request::new(rpc::AuditHistoryRequest {
+            entity: entity.to_string(),
+            entity_id: entity_id.to_string(),
+        })
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn history_lists_changes_with_actor(pool: sqlx::SqlitePool) -> Result<()> {
+        let categories = MyCategoriesService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let create = Request::new(rpc::CategoryCreateRequest {
+            category: Some(rpc::Category {
+                code: "FOOD".to_string(),
+                name: "Food".to_string(),
+                category_type: lib_domain::CategoryTypes::Expense.to_rpc_i32(),
+                is_active: true,
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+
+        let created = database::AuditLog::with_actor("ian", categories.category_create(create))
+            .await?
+            .into_inner()
+            .category
+            .unwrap();
+
+        let service = MyAuditService::new(pool, convert::TEST_PUBLIC_IDS);
+        let response = service
+            .audit_history(history("categories", &created.id))
+            .await?
+            .into_inner();
+
+        assert_eq!(response.entries.len(), 1);
+        assert_eq!(response.entries[0].action(), rpc::AuditActions::Insert);
+        assert_eq!(response.entries[0].actor.as_deref(), Some("ian"));
+        assert!(
+            response.entries[0]
+                .new_values
+                .as_deref()
+                .unwrap()
+                .contains(r#""code":"FOOD""#)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn users_only_see_the_history_of_their_own_rows(pool: sqlx::SqlitePool) -> Result<()> {
+        database::Users::new("ian").insert(&pool).await?;
+        database::Users::new("sam").insert(&pool).await?;
+        let created = MyCategoriesService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .category_create(as_user(
+            "ian",
+            Request::new(rpc::CategoryCreateRequest {
+                category: Some(rpc::Category {
+                    code: "FOOD".to_string(),
+                    name: "Food".to_string(),
+                    category_type: lib_domain::CategoryTypes::Expense.to_rpc_i32(),
+                    is_active: true,
+                    ..Default::default()
+                }),
+                validate_only: false,
+            }),
+        ))
+        .await?
+        .into_inner()
+        .category
+        .unwrap();
+        let service = MyAuditService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let owned = service
+            .audit_history(as_user("ian", history("categories", &created.id)))
+            .await?
+            .into_inner();
+        let not_owned = service
+            .audit_history(as_user("sam", history("categories", &created.id)))
+            .await?
+            .into_inner();
+
+        assert_eq!(owned.entries.len(), 1);
+        assert!(not_owned.entries.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn history_rejects_unknown_entity(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyAuditService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let unknown = service.audit_history(history("widgets", "1")).await;
+        let missing_id = service.audit_history(history("categories", " ")).await;
+
+        assert_eq!(unknown.unwrap_err().code(), tonic::Code::InvalidArgument);
+        assert_eq!(missing_id.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn layer_sets_actor_from_metadata() -> Result<()> {
+        let handler = tower::service_fn(|_request: http::Request<()>| async {
+            Ok::<_, std::convert::Infallible>(http::Response::new(
+                database::AuditLog::current_actor(),
+            ))
+        });
+        let service = AuditActorLayer::default().layer(handler);
+
+        let request = http::Request::builder()
+            .header(ACTOR_HEADER, " ian ")
+            .body(())?;
+        let named = service.clone().oneshot(request).await?;
+        let anonymous = service.oneshot(http::Request::new(())).await?;
+
+        assert_eq!(named.body().as_deref(), Some("ian"));
+        assert_eq!(anonymous.body(), &None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn layer_falls_back_to_default_actor() -> Result<()> {
+        let handler = tower::service_fn(|_request: http::Request<()>| async {
+            Ok::<_, std::convert::Infallible>(http::Response::new(
+                database::AuditLog::current_actor(),
+            ))
+        });
+        let service = AuditActorLayer::default()
+            .with_default_actor(Some("ian".to_string()))
+            .layer(handler);
+
+        let request = http::Request::builder()
+            .header(ACTOR_HEADER, "accountant")
+            .body(())?;
+        let named = service.clone().oneshot(request).await?;
+        let unnamed = service.oneshot(http::Request::new(())).await?;
+
+        assert_eq!(named.body().as_deref(), Some("accountant"));
+        assert_eq!(unnamed.body().as_deref(), Some("ian"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+//! # Authentication
+//!
+//! Builds the [`AuthInterceptor`](rpc::AuthInterceptor) every service is
+//! wrapped in from the `[Auth]` config section, see
+//! [`AuthConfig`](config::AuthConfig):
+//!
+//! - With authentication off, requests without a token are trusted as an
+//!   admin, as the server has always done
+//! - With it on, API keys are loaded from the key file and JWTs checked with
+//!   the secret, and requests without a token are refused, unless they come
+//!   from the only user who can connect
+//!
+//! Requests with a token that is not valid are always refused. Trusted
+//! requests without a token are authenticated as the
+//! [local user](crate::audit::local_user) the server runs as.
+//!
+//! Handlers limit what a caller sees and changes to their own rows with
+//! [`user_scope`], which matches the caller to the [user](database::Users)
+//! of the same username.
+
+use std::path::Path;
+
+use tonic::{Request, Status};
+
+use lib_config as config;
+use lib_database as database;
+use lib_rpc as rpc;
+
+use crate::{ServerError, ServerResult, audit, convert};
+
+/// Subject of requests without a token while authentication is off.
+pub const ANONYMOUS_SUBJECT: &str = "anonymous";
+
+/// Subject of trusted requests without a token when the local user's name is
+/// not known.
+pub const LOCAL_SUBJECT: &str = "local";
+
+/// Builds the interceptor from the auth config. `trust_local_user` trusts
+/// requests without a token as the local user, for listeners only they can
+/// connect to, such as a Unix socket.
+///
+/// # Errors
+///
+/// Returns [`ServerError::Auth`] if the API key file cannot be read or has a
+/// line that is not valid.
+pub fn interceptor(
+    auth: &config::AuthConfig,
+    trust_local_user: bool,
+) -> ServerResult<rpc::AuthInterceptor> {
+    let mut interceptor = rpc::AuthInterceptor::new();
+
+    if let Some(path) = &auth.api_keys_file {
+        for (digest, subject, role) in read_api_keys(path)? {
+            interceptor = interceptor.with_api_key_digest(digest, subject, role);
+        }
+    }
+    if let Some(secret) = &auth.jwt_secret {
+        interceptor = interceptor.with_jwt_secret(secret);
+    }
+
+    let anonymous = if trust_local_user {
+        Some(audit::local_user().unwrap_or_else(|| LOCAL_SUBJECT.to_string()))
+    } else if !auth.is_enabled() {
+        tracing::warn!("Authentication is off, trusting every request as an admin");
+        Some(ANONYMOUS_SUBJECT.to_string())
+    } else {
+        None
+    };
+    if let Some(subject) = anonymous {
+        interceptor = interceptor.with_anonymous(rpc::Principal {
+            subject,
+            role: rpc::Role::Admin,
+        });
+    }
+
+    Ok(interceptor)
+}
+
+/// Whose rows the caller of `request` may see and change:
+///
+/// - a caller with the username of an active user, only theirs
+/// - an admin who is not a user, and anyone while the ledger has no users,
+///   every row, as on a server with one user
+///
+/// Requests that did not pass the interceptor, such as a handler called
+/// directly, are not limited.
+///
+/// # Errors
+///
+/// Returns `PERMISSION_DENIED` if the caller's user is deactivated, or they
+/// are not an admin and not a user of a ledger that has users.
+pub async fn user_scope<T>(
+    request: &Request<T>,
+    pool: &sqlx::SqlitePool,
+) -> Result<database::UserScope, Status> {
+    let Some(principal) = rpc::principal(request) else {
+        return Ok(database::UserScope::All);
+    };
+
+    let user = database::Users::find_by_username(&principal.subject, pool)
+        .await
+        .map_err(convert::database_status)?;
+    match user {
+        Some(user) if user.is_active => Ok(database::UserScope::User(user.id)),
+        Some(_) => Err(Status::permission_denied(format!(
+            "User {} has been deactivated",
+            principal.subject
+        ))),
+        None if principal.role == rpc::Role::Admin => Ok(database::UserScope::All),
+        None => {
+            let users = database::Users::find_all(pool)
+                .await
+                .map_err(convert::database_status)?;
+            if users.is_empty() {
+                Ok(database::UserScope::All)
+            } else {
+                Err(Status::permission_denied(format!(
+                    "{} is not a user of this ledger",
+                    principal.subject
+                )))
+            }
+        }
+    }
+}
+
+/// Authenticates `request` as the editor `subject`, as the interceptor
+/// would, for tests calling handlers directly.
+#[cfg(test)]
+pub fn as_user<T>(subject: &str, mut request: Request<T>) -> Request<T> {
+    request.extensions_mut().insert(rpc::Principal {
+        subject: subject.to_string(),
+        role: rpc::Role::Editor,
+    });
+    request
+}
+
+/// Reads the API keys from `path`, one `subject role sha256-hex` per line.
+fn read_api_keys(path: &Path) -> ServerResult<Vec<([u8; 32], String, rpc::Role)>> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        ServerError::Auth(format!(
+            "Could not read the API key file {}: {e}",
+            path.display()
+        ))
+    })?;
+
+    contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(number, line)| {
+            parse_api_key(line).map_err(|e| {
+                ServerError::Auth(format!(
+                    "{} line {number} is not valid: {e}",
+                    path.display()
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Parses one line of the API key file.
+fn parse_api_key(line: &str) -> Result<([u8; 32], String, rpc::Role), String> {
+    let fields: Vec<_> = line.split_whitespace().collect();
+    let [subject, role, digest] = fields[..] else {
+        return Err("expected a subject, role and SHA-256 digest".to_string());
+    };
+
+    let role = role.parse()?;
+    let mut bytes = [0; 32];
+    hex::decode_to_slice(digest, &mut bytes)
+        .map_err(|_| "the digest is not 64 hex digits".to_string())?;
+
+    Ok((bytes, subject.to_string(), role))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // SHA-256 of "test"
+    const TEST_DIGEST: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    #[test]
+    fn api_key_lines_name_subject_role_and_digest() {
+        let (digest, subject, role) =
+            parse_api_key(&format!("sam  read-only  {TEST_DIGEST}")).unwrap();
+
+        assert_eq!(subject, "sam");
+        assert_eq!(role, rpc::Role::ReadOnly);
+        assert_eq!(hex::encode(digest), TEST_DIGEST);
+
+        assert!(parse_api_key(&format!("sam owner {TEST_DIGEST}")).is_err());
+        assert!(parse_api_key("sam admin abc").is_err());
+        assert!(parse_api_key(TEST_DIGEST).is_err());
+    }
+
+    #[test]
+    fn api_key_file_skips_comments_and_reports_bad_lines() {
+        let directory = std::env::temp_dir().join(format!("ledger-auth-{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("api-keys");
+
+        std::fs::write(
+            &path,
+            format!("# subject role digest\n\nian admin {TEST_DIGEST}\n"),
+        )
+        .unwrap();
+        assert_eq!(read_api_keys(&path).unwrap().len(), 1);
+
+        std::fs::write(&path, format!("ian admin {TEST_DIGEST}\nsam\n")).unwrap();
+        let error = read_api_keys(&path).unwrap_err().to_string();
+        assert!(error.contains("line 2"), "{error}");
+
+        assert!(read_api_keys(&directory.join("missing")).is_err());
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
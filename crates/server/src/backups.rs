@@ -0,0 +1,311 @@
+//! # Scheduled Database Backups
+//!
+//! Backs the database up to the configured directory with
+//! [`DatabaseBackup`](database::DatabaseBackup), and thins out older backups
+//! using the retention policy of the [`BackupConfig`].
+//!
+//! The scheduler runs once as soon as it starts and then on every tick of its
+//! interval. Backups it writes are named `personal_ledger-<UTC time>.db`,
+//! the same as a `Backup` RPC without a file name, and only backups named
+//! like that are removed by retention. Backups given a file name are kept
+//! until removed by hand.
+//!
+//! Retention keeps the newest backup of each of the `keep_daily` most recent
+//! days that have one, and the newest of each of the `keep_weekly` most recent
+//! weeks, starting on Monday. The newest backup is always kept.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use chrono::{Datelike, NaiveDateTime};
+use lib_config::BackupConfig;
+use lib_database as database;
+
+/// File name prefix of backups named by the server.
+const BACKUP_FILE_PREFIX: &str = "personal_ledger-";
+
+/// File name suffix of backups named by the server.
+const BACKUP_FILE_SUFFIX: &str = ".db";
+
+/// Format of the time in backups named by the server.
+const BACKUP_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// The file name of a backup taken at `at`.
+pub fn backup_file_name(at: chrono::DateTime<chrono::Utc>) -> String {
+    format!(
+        "{BACKUP_FILE_PREFIX}{}{BACKUP_FILE_SUFFIX}",
+        at.format(BACKUP_TIME_FORMAT)
+    )
+}
+
+/// The time a backup was taken, if `file_name` was named by the server.
+fn backup_time(file_name: &str) -> Option<NaiveDateTime> {
+    let time = file_name
+        .strip_prefix(BACKUP_FILE_PREFIX)?
+        .strip_suffix(BACKUP_FILE_SUFFIX)?;
+    NaiveDateTime::parse_from_str(time, BACKUP_TIME_FORMAT).ok()
+}
+
+/// Backs the database up on a schedule, removing backups that are no longer
+/// kept.
+#[derive(Debug, Clone)]
+pub struct BackupScheduler {
+    pool: sqlx::SqlitePool,
+    directory: PathBuf,
+    interval: std::time::Duration,
+    keep_daily: u32,
+    keep_weekly: u32,
+}
+
+/// What a scheduler run backed up and removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupRun {
+    /// The backup written.
+    pub backup: database::DatabaseBackup,
+
+    /// Backups removed by retention.
+    pub removed: Vec<PathBuf>,
+}
+
+impl BackupScheduler {
+    /// Creates a scheduler from the backup configuration, or `None` when
+    /// scheduled backups are off.
+    pub fn new(pool: sqlx::SqlitePool, config: &BackupConfig) -> Option<Self> {
+        Some(Self {
+            pool,
+            directory: config.directory.clone()?,
+            interval: config.interval()?,
+            keep_daily: config.keep_daily,
+            keep_weekly: config.keep_weekly,
+        })
+    }
+
+    /// Runs straight away and then every interval until the task is dropped.
+    /// Errors are logged and the next run carries on.
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(self.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            // The first tick completes immediately, so this runs on startup
+            interval.tick().await;
+
+            if let Err(error) = self.run_once(chrono::Utc::now()).await {
+                tracing::error!("Database backup scheduler error: {error}");
+            }
+        }
+    }
+
+    /// Backs the database up as taken at `now`, then removes the backups
+    /// retention no longer keeps.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backup directory cannot be created or the
+    /// backup fails. Backups that cannot be removed are logged instead.
+    #[tracing::instrument(name = "Run database backup scheduler", skip(self), err)]
+    pub async fn run_once(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> database::DatabaseResult<BackupRun> {
+        tokio::fs::create_dir_all(&self.directory)
+            .await
+            .map_err(|e| {
+                database::DatabaseError::Other(format!(
+                    "cannot create backup directory {}: {e}",
+                    self.directory.display()
+                ))
+            })?;
+
+        let path = self.directory.join(backup_file_name(now));
+        let backup = database::DatabaseBackup::backup_to(&self.pool, path).await?;
+        tracing::info!(path = %backup.path.display(), size_bytes = backup.size_bytes, "Backed up the database");
+
+        let mut removed = Vec::new();
+        for path in expired(
+            backups_in(&self.directory).await,
+            self.keep_daily,
+            self.keep_weekly,
+        ) {
+            match tokio::fs::remove_file(&path).await {
+                Ok(()) => removed.push(path),
+                Err(error) => {
+                    tracing::warn!(path = %path.display(), "Could not remove old backup: {error}")
+                }
+            }
+        }
+
+        Ok(BackupRun { backup, removed })
+    }
+}
+
+/// Backups named by the server in `directory`, with the time each was taken.
+async fn backups_in(directory: &Path) -> Vec<(PathBuf, NaiveDateTime)> {
+    let mut backups = Vec::new();
+
+    let mut entries = match tokio::fs::read_dir(directory).await {
+        Ok(entries) => entries,
+        Err(error) => {
+            tracing::warn!(directory = %directory.display(), "Could not list backups: {error}");
+            return backups;
+        }
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        if let Some(taken) = entry.file_name().to_str().and_then(backup_time) {
+            backups.push((entry.path(), taken));
+        }
+    }
+
+    backups
+}
+
+/// The backups retention no longer keeps, out of `backups` and the times they
+/// were taken.
+fn expired(
+    mut backups: Vec<(PathBuf, NaiveDateTime)>,
+    keep_daily: u32,
+    keep_weekly: u32,
+) -> Vec<PathBuf> {
+    backups.sort_by_key(|(_, taken)| std::cmp::Reverse(*taken));
+
+    let mut days = HashSet::new();
+    let mut weeks = HashSet::new();
+    let mut expired = Vec::new();
+
+    for (index, (path, taken)) in backups.into_iter().enumerate() {
+        let day = taken.date();
+        let week = day.iso_week();
+
+        // Newest first, so the first backup seen of a day or week is its newest
+        let keep_day = days.len() < keep_daily as usize && !days.contains(&day);
+        let keep_week = weeks.len() < keep_weekly as usize && !weeks.contains(&week);
+        if keep_day {
+            days.insert(day);
+        }
+        if keep_week {
+            weeks.insert(week);
+        }
+
+        if index > 0 && !keep_day && !keep_week {
+            expired.push(path);
+        }
+    }
+
+    expired
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn backup(taken: &str) -> (PathBuf, NaiveDateTime) {
+        let taken = NaiveDateTime::parse_from_str(taken, "%Y-%m-%d %H:%M").unwrap();
+        (PathBuf::from(backup_file_name(taken.and_utc())), taken)
+    }
+
+    fn names(paths: Vec<PathBuf>) -> Vec<String> {
+        let mut names: Vec<_> = paths
+            .iter()
+            .map(|path| {
+                backup_time(path.to_str().unwrap())
+                    .unwrap()
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn backup_file_names_round_trip() {
+        let (path, taken) = backup("2025-06-01 03:30");
+
+        assert_eq!(path, PathBuf::from("personal_ledger-20250601T033000Z.db"));
+        assert_eq!(
+            backup_time("personal_ledger-20250601T033000Z.db"),
+            Some(taken)
+        );
+        assert_eq!(backup_time("before-upgrade.db"), None);
+        assert_eq!(backup_time("personal_ledger-latest.db"), None);
+    }
+
+    #[test]
+    fn expired_keeps_newest_of_recent_days_and_weeks() {
+        // Monday 2 June to Wednesday 11 June 2025, twice on 11 June, and a
+        // backup from the week starting 19 May
+        let mut backups: Vec<_> = (2..=11)
+            .map(|day| backup(&format!("2025-06-{day:02} 03:00")))
+            .collect();
+        backups.push(backup("2025-06-11 15:00"));
+        backups.push(backup("2025-05-20 03:00"));
+
+        let expired = expired(backups, 3, 3);
+
+        // Kept: 11 June 15:00, 10 June and 9 June (daily), 8 June (newest of
+        // the week of 2 June) and 20 May (third week)
+        assert_eq!(
+            names(expired),
+            [
+                "2025-06-02 03:00",
+                "2025-06-03 03:00",
+                "2025-06-04 03:00",
+                "2025-06-05 03:00",
+                "2025-06-06 03:00",
+                "2025-06-07 03:00",
+                "2025-06-11 03:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn expired_always_keeps_the_newest_backup() {
+        let backups = vec![backup("2025-06-01 03:00"), backup("2025-06-02 03:00")];
+
+        assert_eq!(names(expired(backups, 0, 0)), ["2025-06-01 03:00"]);
+        assert!(expired(Vec::new(), 0, 0).is_empty());
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn run_once_backs_up_and_removes_expired_backups(pool: sqlx::SqlitePool) -> Result<()> {
+        let directory =
+            std::env::temp_dir().join(format!("ledger-backups-{}", uuid::Uuid::now_v7()));
+        let config = BackupConfig {
+            directory: Some(directory.clone()),
+            keep_daily: 1,
+            keep_weekly: 0,
+            ..BackupConfig::default()
+        };
+        let scheduler = BackupScheduler::new(pool, &config).expect("backups are scheduled");
+
+        std::fs::create_dir_all(&directory)?;
+        std::fs::write(directory.join("before-upgrade.db"), b"kept")?;
+
+        let first = scheduler.run_once("2025-06-01T03:00:00Z".parse()?).await?;
+        assert!(first.removed.is_empty());
+
+        let second = scheduler.run_once("2025-06-02T03:00:00Z".parse()?).await?;
+        assert_eq!(second.removed, std::slice::from_ref(&first.backup.path));
+        assert!(second.backup.path.exists());
+        assert!(!first.backup.path.exists());
+        assert!(directory.join("before-upgrade.db").exists());
+
+        std::fs::remove_dir_all(&directory)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn new_is_none_when_backups_are_not_scheduled() {
+        let pool = sqlx::SqlitePool::connect_lazy("sqlite::memory:").unwrap();
+
+        assert!(BackupScheduler::new(pool.clone(), &BackupConfig::default()).is_none());
+        let manual_only = BackupConfig {
+            directory: Some(PathBuf::from("backups")),
+            interval_hours: 0,
+            ..BackupConfig::default()
+        };
+        assert!(BackupScheduler::new(pool, &manual_only).is_none());
+    }
+}
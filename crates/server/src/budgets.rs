@@ -0,0 +1,732 @@
+//! # Budgets Service
+//!
+//! gRPC handlers for the `BudgetsService`, backed by the lib-database
+//! [`Budgets`](database::Budgets) model.
+//!
+//! Budgeted versus actual spending is computed in the database over a
+//! calendar month or financial year, including spending in subcategories,
+//! per budget with `BudgetsCompare` or as a tree of categories with
+//! `BudgetsVariance`.
+
+use tonic::{Request, Response, Status};
+
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{auth, convert, metadata};
+
+/// Server implementation of the `BudgetsService`.
+#[derive(Debug, Clone)]
+pub struct MyBudgetsService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+    events: database::EventBus,
+}
+
+impl MyBudgetsService {
+    /// Creates the service using the given database pool, public ID key and
+    /// event bus.
+    pub fn new(
+        pool: sqlx::SqlitePool,
+        public_ids: domain::PublicIdKey,
+        events: database::EventBus,
+    ) -> Self {
+        Self {
+            pool,
+            public_ids,
+            events,
+        }
+    }
+}
+
+/// Maps a database error to the gRPC status returned to the client.
+fn database_status(error: database::DatabaseError) -> Status {
+    if convert::is_foreign_key_violation(&error) {
+        return convert::foreign_key_status(
+            rpc::ErrorReason::MissingReference,
+            "budget",
+            "Budget category does not exist",
+        );
+    }
+
+    convert::database_status(error)
+}
+
+/// Checks that a budget's category is one the caller can see, so a budget
+/// is never set against another user's category. A category outside the
+/// scope is reported as missing, as one that does not exist is.
+async fn ensure_category(
+    pool: &sqlx::SqlitePool,
+    category_id: domain::RowID,
+    scope: database::UserScope,
+) -> Result<(), Status> {
+    metadata::time_db(database::Categories::find_by_id(category_id, scope, pool))
+        .await
+        .map_err(database_status)?
+        .map(|_| ())
+        .ok_or_else(|| {
+            convert::foreign_key_status(
+                rpc::ErrorReason::MissingReference,
+                "budget",
+                "Budget category does not exist",
+            )
+        })
+}
+
+/// Parses a budget period sent by the client. The period must be set.
+fn parse_period(field: &str, period: i32) -> Result<database::BudgetPeriods, Status> {
+    match rpc::BudgetPeriods::try_from(period) {
+        Ok(rpc::BudgetPeriods::Monthly) => Ok(database::BudgetPeriods::Monthly),
+        Ok(rpc::BudgetPeriods::Annual) => Ok(database::BudgetPeriods::Annual),
+        _ => Err(Status::invalid_argument(format!(
+            "Invalid {field}: {period}"
+        ))),
+    }
+}
+
+/// Converts a database budget into its RPC message.
+fn to_rpc(public_ids: &domain::PublicIdKey, budget: database::Budgets) -> rpc::Budget {
+    let period = match budget.period {
+        database::BudgetPeriods::Monthly => rpc::BudgetPeriods::Monthly,
+        database::BudgetPeriods::Annual => rpc::BudgetPeriods::Annual,
+    };
+
+    rpc::Budget {
+        id: convert::format_id(public_ids, budget.id),
+        category_id: convert::format_id(public_ids, budget.category_id),
+        period: period as i32,
+        amount: budget.amount,
+        created_on: Some(convert::to_timestamp(budget.created_on)),
+        updated_on: Some(convert::to_timestamp(budget.updated_on)),
+    }
+}
+
+/// Converts a database budget comparison into its RPC message.
+fn comparison_to_rpc(
+    public_ids: &domain::PublicIdKey,
+    comparison: database::BudgetComparison,
+) -> rpc::BudgetComparison {
+    rpc::BudgetComparison {
+        start: convert::format_date(comparison.start),
+        end: convert::format_date(comparison.end),
+        budgeted: comparison.budgeted,
+        actual: comparison.actual,
+        remaining: comparison.remaining(),
+        budget: Some(to_rpc(public_ids, comparison.budget)),
+    }
+}
+
+/// Converts a budget variance tree node, and its children, into its RPC
+/// message.
+fn variance_to_rpc(
+    public_ids: &domain::PublicIdKey,
+    variance: database::BudgetVariance,
+) -> rpc::BudgetVariance {
+    rpc::BudgetVariance {
+        category_id: convert::format_id(public_ids, variance.category_id),
+        remaining: variance.remaining(),
+        is_over_budget: variance.is_over_budget(),
+        category_name: variance.category_name,
+        budget: variance.budget.map(|budget| to_rpc(public_ids, budget)),
+        budgeted: variance.budgeted,
+        actual: variance.actual,
+        children: variance
+            .children
+            .into_iter()
+            .map(|variance| variance_to_rpc(public_ids, variance))
+            .collect(),
+    }
+}
+
+/// Applies the client editable fields of an RPC budget to a database budget.
+/// The id and timestamps are left untouched. The category and period are
+/// parsed by the caller, before any database work starts.
+fn apply_rpc(
+    mut budget: database::Budgets,
+    message: rpc::Budget,
+    category_id: lib_domain::RowID,
+    period: database::BudgetPeriods,
+) -> database::Budgets {
+    budget.category_id = category_id;
+    budget.period = period;
+    budget.amount = message.amount;
+
+    budget
+}
+
+/// Parses the category and period of an RPC budget.
+fn parse_rpc(
+    public_ids: &domain::PublicIdKey,
+    message: &rpc::Budget,
+) -> Result<(lib_domain::RowID, database::BudgetPeriods), Status> {
+    Ok((
+        convert::parse_id(public_ids, "budget.category_id", &message.category_id)?,
+        parse_period("budget.period", message.period)?,
+    ))
+}
+
+#[tonic::async_trait]
+impl rpc::BudgetsService for MyBudgetsService {
+    async fn budget_create(
+        &self,
+        request: Request<rpc::BudgetCreateRequest>,
+    ) -> Result<Response<rpc::BudgetCreateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let message = request
+            .budget
+            .ok_or_else(|| Status::invalid_argument("Missing budget"))?;
+
+        let (category_id, period) = parse_rpc(&self.public_ids, &message)?;
+        ensure_category(&self.pool, category_id, scope).await?;
+        let budget = database::Budgets::new(category_id, period, message.amount);
+
+        let created = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| budget.insert(conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::BudgetCreateResponse {
+            budget: Some(to_rpc(&self.public_ids, created)),
+        }))
+    }
+
+    async fn budget_get(
+        &self,
+        request: Request<rpc::BudgetGetRequest>,
+    ) -> Result<Response<rpc::BudgetGetResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let public_id = request.into_inner().id;
+        let id = convert::parse_id(&self.public_ids, "id", &public_id)?;
+
+        let budget = metadata::time_db(database::Budgets::find_by_id(id, scope, &self.pool))
+            .await
+            .map_err(database_status)?
+            .ok_or_else(|| {
+                convert::not_found(
+                    "budget",
+                    "id",
+                    format!("Budget with id {public_id} not found"),
+                )
+            })?;
+
+        Ok(Response::new(rpc::BudgetGetResponse {
+            budget: Some(to_rpc(&self.public_ids, budget)),
+        }))
+    }
+
+    async fn budgets_list(
+        &self,
+        request: Request<rpc::BudgetsListRequest>,
+    ) -> Result<Response<rpc::BudgetsListResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+
+        let after = convert::parse_page_token(&request.page_token)?;
+
+        let page = metadata::time_db(database::Budgets::find_all_with_pagination(
+            after,
+            request.limit,
+            scope,
+            &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::BudgetsListResponse {
+            budgets: page
+                .items
+                .into_iter()
+                .map(|budget| to_rpc(&self.public_ids, budget))
+                .collect(),
+            total_count: page.total_count,
+            next_page_token: convert::format_page_token(page.next_cursor),
+            limit: request.limit,
+        }))
+    }
+
+    async fn budget_update(
+        &self,
+        request: Request<rpc::BudgetUpdateRequest>,
+    ) -> Result<Response<rpc::BudgetUpdateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        let message = request
+            .budget
+            .ok_or_else(|| Status::invalid_argument("Missing budget"))?;
+
+        let (category_id, period) = parse_rpc(&self.public_ids, &message)?;
+        ensure_category(&self.pool, category_id, scope).await?;
+
+        let updated = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                let existing = database::Budgets::find_by_id(id, scope, &mut *conn)
+                    .await?
+                    .ok_or_else(|| {
+                        database::DatabaseError::NotFound(format!(
+                            "Budget with id {} not found",
+                            request.id
+                        ))
+                    })?;
+
+                apply_rpc(existing, message, category_id, period)
+                    .update(scope, conn)
+                    .await
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::BudgetUpdateResponse {
+            budget: Some(to_rpc(&self.public_ids, updated)),
+        }))
+    }
+
+    async fn budget_delete(
+        &self,
+        request: Request<rpc::BudgetDeleteRequest>,
+    ) -> Result<Response<rpc::BudgetDeleteResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+
+        metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Budgets::delete_by_id(id, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::BudgetDeleteResponse { rows_deleted: 1 }))
+    }
+
+    async fn budgets_compare(
+        &self,
+        request: Request<rpc::BudgetsCompareRequest>,
+    ) -> Result<Response<rpc::BudgetsCompareResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let period = parse_period("period", request.period)?;
+        let on = match request.on.as_deref() {
+            Some(on) => convert::parse_date("on", on)?,
+            None => chrono::Utc::now().date_naive(),
+        };
+
+        let comparisons =
+            metadata::time_db(database::Budgets::compare(period, on, scope, &self.pool))
+                .await
+                .map_err(database_status)?;
+
+        Ok(Response::new(rpc::BudgetsCompareResponse {
+            comparisons: comparisons
+                .into_iter()
+                .map(|comparison| comparison_to_rpc(&self.public_ids, comparison))
+                .collect(),
+        }))
+    }
+
+    async fn budgets_variance(
+        &self,
+        request: Request<rpc::BudgetsVarianceRequest>,
+    ) -> Result<Response<rpc::BudgetsVarianceResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let period = parse_period("period", request.period)?;
+        let on = match request.on.as_deref() {
+            Some(on) => convert::parse_date("on", on)?,
+            None => chrono::Utc::now().date_naive(),
+        };
+        let (start, end) = period.range(on);
+
+        let categories =
+            metadata::time_db(database::Budgets::variance(period, on, scope, &self.pool))
+                .await
+                .map_err(database_status)?;
+
+        Ok(Response::new(rpc::BudgetsVarianceResponse {
+            categories: categories
+                .into_iter()
+                .map(|variance| variance_to_rpc(&self.public_ids, variance))
+                .collect(),
+            start: convert::format_date(start),
+            end: convert::format_date(end),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::{BudgetsService, CategoriesService, TransactionsService};
+
+    use crate::auth::as_user;
+    use crate::categories::MyCategoriesService;
+    use crate::transactions::MyTransactionsService;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn category(pool: &sqlx::SqlitePool, code: &str) -> Result<String> {
+        let request = Request::new(rpc::CategoryCreateRequest {
+            category: Some(rpc::Category {
+                code: code.to_string(),
+                name: code.to_string(),
+                category_type: lib_domain::CategoryTypes::Expense.to_rpc_i32(),
+                is_active: true,
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+        let response = MyCategoriesService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .category_create(request)
+        .await?;
+
+        Ok(response.into_inner().category.unwrap().id)
+    }
+
+    async fn create(
+        service: &MyBudgetsService,
+        category_id: &str,
+        period: rpc::BudgetPeriods,
+        amount: i64,
+    ) -> core::result::Result<rpc::Budget, Status> {
+        let request = Request::new(rpc::BudgetCreateRequest {
+            budget: Some(rpc::Budget {
+                category_id: category_id.to_string(),
+                period: period as i32,
+                amount,
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+
+        Ok(service
+            .budget_create(request)
+            .await?
+            .into_inner()
+            .budget
+            .unwrap())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn compare_reports_budgeted_and_actual(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyBudgetsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let category_id = category(&pool, "FOOD").await?;
+        let budget = create(&service, &category_id, rpc::BudgetPeriods::Monthly, 50_000).await?;
+
+        let request = Request::new(rpc::TransactionCreateRequest {
+            transaction: Some(rpc::Transaction {
+                transaction_date: "2025-03-14".to_string(),
+                payee: "Grocer".to_string(),
+                amount: -20_000,
+                category_id: Some(category_id.clone()),
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+        MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .transaction_create(request)
+        .await?;
+
+        let response = service
+            .budgets_compare(Request::new(rpc::BudgetsCompareRequest {
+                period: rpc::BudgetPeriods::Annual as i32,
+                on: Some("2025-03-01".to_string()),
+            }))
+            .await?
+            .into_inner();
+
+        let comparison = &response.comparisons[0];
+        assert_eq!(
+            comparison.budget.as_ref().map(|b| b.id.as_str()),
+            Some(budget.id.as_str())
+        );
+        assert_eq!(
+            (comparison.start.as_str(), comparison.end.as_str()),
+            ("2024-07-01", "2025-06-30")
+        );
+        assert_eq!(comparison.budgeted, 600_000);
+        assert_eq!(comparison.actual, 20_000);
+        assert_eq!(comparison.remaining, 580_000);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn variance_returns_a_tree_flagging_overspending(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyBudgetsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let food_id = category(&pool, "FOOD").await?;
+        let request = Request::new(rpc::CategoryCreateRequest {
+            category: Some(rpc::Category {
+                code: "DINING".to_string(),
+                name: "Dining".to_string(),
+                category_type: lib_domain::CategoryTypes::Expense.to_rpc_i32(),
+                is_active: true,
+                parent_id: Some(food_id.clone()),
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+        let dining_id = MyCategoriesService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .category_create(request)
+        .await?
+        .into_inner()
+        .category
+        .unwrap()
+        .id;
+        create(&service, &dining_id, rpc::BudgetPeriods::Monthly, 10_000).await?;
+
+        let request = Request::new(rpc::TransactionCreateRequest {
+            transaction: Some(rpc::Transaction {
+                transaction_date: "2025-03-14".to_string(),
+                payee: "Bistro".to_string(),
+                amount: -12_000,
+                category_id: Some(dining_id.clone()),
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+        MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .transaction_create(request)
+        .await?;
+
+        let response = service
+            .budgets_variance(Request::new(rpc::BudgetsVarianceRequest {
+                period: rpc::BudgetPeriods::Monthly as i32,
+                on: Some("2025-03-01".to_string()),
+            }))
+            .await?
+            .into_inner();
+
+        assert_eq!(
+            (response.start.as_str(), response.end.as_str()),
+            ("2025-03-01", "2025-03-31")
+        );
+        let food = &response.categories[0];
+        assert_eq!(food.category_id, food_id);
+        assert!(food.budget.is_none());
+        assert_eq!(
+            (food.budgeted, food.actual, food.remaining),
+            (10_000, 12_000, -2_000)
+        );
+        assert!(food.is_over_budget);
+        assert_eq!(food.children[0].category_id, dining_id);
+        assert!(food.children[0].is_over_budget);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn update_changes_period_and_amount(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyBudgetsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let category_id = category(&pool, "FOOD").await?;
+        let budget = create(&service, &category_id, rpc::BudgetPeriods::Monthly, 50_000).await?;
+
+        let request = Request::new(rpc::BudgetUpdateRequest {
+            id: budget.id.clone(),
+            budget: Some(rpc::Budget {
+                period: rpc::BudgetPeriods::Annual as i32,
+                amount: 500_000,
+                ..budget.clone()
+            }),
+            validate_only: false,
+        });
+        let updated = service
+            .budget_update(request)
+            .await?
+            .into_inner()
+            .budget
+            .unwrap();
+
+        assert_eq!(updated.period(), rpc::BudgetPeriods::Annual);
+        assert_eq!(updated.amount, 500_000);
+        assert_eq!(updated.created_on, budget.created_on);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn create_rejects_bad_input(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyBudgetsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let category_id = category(&pool, "FOOD").await?;
+
+        let unspecified = create(
+            &service,
+            &category_id,
+            rpc::BudgetPeriods::Unspecified,
+            50_000,
+        )
+        .await;
+        let negative = create(&service, &category_id, rpc::BudgetPeriods::Monthly, -1).await;
+        let unknown_category = create(
+            &service,
+            &convert::format_id(&convert::TEST_PUBLIC_IDS, lib_domain::RowID::new()),
+            rpc::BudgetPeriods::Monthly,
+            50_000,
+        )
+        .await;
+        create(&service, &category_id, rpc::BudgetPeriods::Monthly, 50_000).await?;
+        let duplicate = create(&service, &category_id, rpc::BudgetPeriods::Annual, 50_000).await;
+
+        assert_eq!(
+            unspecified.unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+        assert_eq!(negative.unwrap_err().code(), tonic::Code::InvalidArgument);
+        assert_eq!(
+            unknown_category.unwrap_err().code(),
+            tonic::Code::FailedPrecondition
+        );
+        assert_eq!(duplicate.unwrap_err().code(), tonic::Code::AlreadyExists);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn users_only_see_and_change_budgets_on_their_own_categories(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        database::Users::new("ian").insert(&pool).await?;
+        database::Users::new("sam").insert(&pool).await?;
+        let service = MyBudgetsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let category_id = MyCategoriesService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .category_create(as_user(
+            "ian",
+            Request::new(rpc::CategoryCreateRequest {
+                category: Some(rpc::Category {
+                    code: "FOOD".to_string(),
+                    name: "Food".to_string(),
+                    category_type: lib_domain::CategoryTypes::Expense.to_rpc_i32(),
+                    is_active: true,
+                    ..Default::default()
+                }),
+                validate_only: false,
+            }),
+        ))
+        .await?
+        .into_inner()
+        .category
+        .unwrap()
+        .id;
+        let create_as = |subject| {
+            as_user(
+                subject,
+                Request::new(rpc::BudgetCreateRequest {
+                    budget: Some(rpc::Budget {
+                        category_id: category_id.clone(),
+                        period: rpc::BudgetPeriods::Monthly as i32,
+                        amount: 50_000,
+                        ..Default::default()
+                    }),
+                    validate_only: false,
+                }),
+            )
+        };
+
+        let status = service.budget_create(create_as("sam")).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition, "{status:?}");
+        let budget = service
+            .budget_create(create_as("ian"))
+            .await?
+            .into_inner()
+            .budget
+            .unwrap();
+
+        let listed = service
+            .budgets_list(as_user("sam", Request::new(Default::default())))
+            .await?
+            .into_inner();
+        assert_eq!(listed.total_count, 0);
+        let compared = service
+            .budgets_compare(as_user(
+                "sam",
+                Request::new(rpc::BudgetsCompareRequest {
+                    period: rpc::BudgetPeriods::Monthly as i32,
+                    on: None,
+                }),
+            ))
+            .await?
+            .into_inner();
+        assert!(compared.comparisons.is_empty());
+
+        let status = service
+            .budget_get(as_user(
+                "sam",
+                Request::new(rpc::BudgetGetRequest {
+                    id: budget.id.clone(),
+                }),
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        let status = service
+            .budget_delete(as_user(
+                "sam",
+                Request::new(rpc::BudgetDeleteRequest {
+                    id: budget.id.clone(),
+                    validate_only: false,
+                }),
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        let listed = service
+            .budgets_list(as_user("ian", Request::new(Default::default())))
+            .await?
+            .into_inner();
+        assert_eq!(listed.total_count, 1);
+
+        Ok(())
+    }
+}
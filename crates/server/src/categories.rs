@@ -0,0 +1,1474 @@
+//! # Categories Service
+//!
+//! gRPC handlers for the `CategoriesService`, backed by the lib-database
+//! [`Categories`](database::Categories) model.
+//!
+//! Mutating RPCs run inside [`database::with_transaction`] so `validate_only`
+//! requests are checked against the database and then rolled back. Large lists
+//! can be fetched page by page with `CategoriesStream`, and changes pushed as
+//! they are committed with `CategoriesWatch`, see [`stream`].
+//! `GetCategoryStatistics` answers a dashboard's counts with the one query of
+//! [`Categories::statistics`](database::Categories::statistics).
+//! Requests are checked with [`rpc::Validate`] before they are handled, so
+//! every bad field is reported at once as a `google.rpc.BadRequest`.
+//! `CategoryArchive` deactivates a category only once it has gone unused for
+//! a number of months, unless forced.
+
+use tonic::{Request, Response, Status};
+
+use lib_config as config;
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+use rpc::Validate;
+
+use crate::quota::{Quotas, Resource};
+use crate::{auth, convert, metadata, stream};
+
+/// Recently used categories returned when a statistics request does not ask
+/// for a number.
+const DEFAULT_RECENT_LIMIT: usize = 5;
+
+/// Most recently used categories returned, larger requests are capped.
+const MAX_RECENT_LIMIT: usize = 100;
+
+/// Months a transaction counts as recent use when an archive request does
+/// not ask for a number.
+const DEFAULT_ARCHIVE_MONTHS: i32 = 6;
+
+/// Most months back an archive request may look for recent use.
+const MAX_ARCHIVE_MONTHS: i32 = 120;
+
+/// Server implementation of the `CategoriesService`.
+#[derive(Debug, Clone)]
+pub struct MyCategoriesService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+    events: database::EventBus,
+    quotas: Quotas,
+}
+
+impl MyCategoriesService {
+    /// Creates the service using the given database pool, public ID key and
+    /// event bus, with no quota.
+    pub fn new(
+        pool: sqlx::SqlitePool,
+        public_ids: domain::PublicIdKey,
+        events: database::EventBus,
+    ) -> Self {
+        Self {
+            pool,
+            public_ids,
+            events,
+            quotas: Quotas::default(),
+        }
+    }
+
+    /// Sets the quotas checked before categories are added.
+    pub fn with_quotas(mut self, quotas: config::QuotaConfig) -> Self {
+        self.quotas = Quotas::new(quotas);
+        self
+    }
+}
+
+/// Maps a database error to the gRPC status returned to the client.
+fn database_status(error: database::DatabaseError) -> Status {
+    if convert::is_foreign_key_violation(&error) {
+        return convert::foreign_key_status(
+            rpc::ErrorReason::InUse,
+            "category",
+            "Category is still in use",
+        );
+    }
+
+    convert::database_status(error)
+}
+
+/// Converts a category's usage into its RPC message.
+fn usage_to_rpc(
+    public_ids: &domain::PublicIdKey,
+    usage: database::CategoryUsage,
+) -> rpc::CategoryUsage {
+    rpc::CategoryUsage {
+        category_id: convert::format_id(public_ids, usage.category_id),
+        transaction_count: usage.transaction_count,
+        last_used_on: usage.last_used_on.map(convert::format_date),
+    }
+}
+
+/// Converts a database category into its RPC message.
+fn to_rpc(public_ids: &domain::PublicIdKey, category: database::Categories) -> rpc::Category {
+    rpc::Category {
+        id: convert::format_id(public_ids, category.id),
+        code: category.code,
+        name: category.name,
+        description: category.description,
+        url_slug: category.url_slug.map(domain::UrlSlug::into_string),
+        category_type: category.category_type.to_rpc_i32(),
+        color: category.color.map(domain::HexColor::into_string),
+        icon: category.icon,
+        is_active: category.is_active,
+        parent_id: category
+            .parent_id
+            .map(|id| convert::format_id(public_ids, id)),
+        created_on: Some(convert::to_timestamp(category.created_on)),
+        updated_on: Some(convert::to_timestamp(category.updated_on)),
+        deleted_on: category.deleted_on.map(convert::to_timestamp),
+        version: category.version,
+    }
+}
+
+/// Builds a new database category from the client editable fields of an RPC
+/// category. The id and timestamps of the message are ignored.
+fn from_rpc(
+    public_ids: &domain::PublicIdKey,
+    message: rpc::Category,
+) -> Result<database::Categories, Status> {
+    let non_empty = |value: String| Some(value).filter(|value| !value.trim().is_empty());
+
+    let category_type =
+        domain::CategoryTypes::from_rpc_i32(message.category_type).map_err(|_| {
+            Status::invalid_argument(format!("Invalid category_type: {}", message.category_type))
+        })?;
+    let url_slug = message
+        .url_slug
+        .map(domain::UrlSlug::parse)
+        .transpose()
+        .map_err(|e| Status::invalid_argument(format!("Invalid url_slug: {e}")))?;
+    let color = message
+        .color
+        .map(domain::HexColor::parse)
+        .transpose()
+        .map_err(|e| Status::invalid_argument(format!("Invalid color: {e}")))?;
+    let parent_id = message
+        .parent_id
+        .as_deref()
+        .map(|id| convert::parse_id(public_ids, "parent_id", id))
+        .transpose()?;
+
+    database::CategoriesBuilder::new()
+        .with_code_opt(non_empty(message.code))
+        .with_name_opt(non_empty(message.name))
+        .with_description_opt(message.description)
+        .with_url_slug_opt(url_slug)
+        .with_category_type(category_type)
+        .with_color_opt(color)
+        .with_icon_opt(message.icon)
+        .with_is_active(message.is_active)
+        .with_parent_id_opt(parent_id)
+        .build()
+        .map_err(|e| Status::invalid_argument(e.to_string()))
+}
+
+/// Copies the `fields` of `message` onto the `stored` category, or returns
+/// `message` whole when no fields are given, as an empty mask updates every
+/// field.
+fn apply_mask(
+    mut stored: rpc::Category,
+    message: rpc::Category,
+    fields: &[database::CategoryFields],
+) -> rpc::Category {
+    if fields.is_empty() {
+        return message;
+    }
+
+    for field in fields {
+        match field {
+            database::CategoryFields::Code => stored.code = message.code.clone(),
+            database::CategoryFields::Name => stored.name = message.name.clone(),
+            database::CategoryFields::Description => {
+                stored.description = message.description.clone()
+            }
+            database::CategoryFields::UrlSlug => stored.url_slug = message.url_slug.clone(),
+            database::CategoryFields::CategoryType => stored.category_type = message.category_type,
+            database::CategoryFields::Color => stored.color = message.color.clone(),
+            database::CategoryFields::Icon => stored.icon = message.icon.clone(),
+            database::CategoryFields::IsActive => stored.is_active = message.is_active,
+            database::CategoryFields::ParentId => stored.parent_id = message.parent_id.clone(),
+        }
+    }
+
+    stored
+}
+
+/// Builds a page of a category stream.
+fn to_page(
+    public_ids: &domain::PublicIdKey,
+    categories: Vec<database::Categories>,
+) -> rpc::CategoriesStreamResponse {
+    rpc::CategoriesStreamResponse {
+        categories: categories
+            .into_iter()
+            .map(|category| to_rpc(public_ids, category))
+            .collect(),
+    }
+}
+
+/// Builds the watch message for a change to a category, or `None` for events
+/// about other records or categories outside `scope`. Deletions carry only
+/// the category's ID, so are sent whatever the scope.
+fn to_change(
+    public_ids: &domain::PublicIdKey,
+    event: database::DataEvent,
+    scope: database::UserScope,
+) -> Option<rpc::CategoriesWatchResponse> {
+    let category_id = convert::format_id(public_ids, event.id());
+    let (kind, category) = match event.change {
+        database::DataChanges::CategoryInserted(category) => {
+            (rpc::CategoryChangeKinds::Created, Some(category))
+        }
+        database::DataChanges::CategoryUpdated(category) => {
+            (rpc::CategoryChangeKinds::Updated, Some(category))
+        }
+        database::DataChanges::CategoryDeleted(_) => (rpc::CategoryChangeKinds::Deleted, None),
+        database::DataChanges::CategoryRestored(category) => {
+            (rpc::CategoryChangeKinds::Restored, Some(category))
+        }
+        _ => return None,
+    };
+    if let Some(category) = &category
+        && !scope.includes(category.user_id)
+    {
+        return None;
+    }
+
+    Some(rpc::CategoriesWatchResponse {
+        event_id: convert::format_id(public_ids, event.event_id),
+        kind: kind.into(),
+        category_id,
+        category: category.map(|category| to_rpc(public_ids, category)),
+        occurred_on: Some(convert::to_timestamp(event.occurred_on)),
+    })
+}
+
+#[tonic::async_trait]
+impl rpc::CategoriesService for MyCategoriesService {
+    type CategoriesStreamStream = stream::PageStream<rpc::CategoriesStreamResponse>;
+    type CategoriesWatchStream = stream::PageStream<rpc::CategoriesWatchResponse>;
+
+    async fn category_create(
+        &self,
+        request: Request<rpc::CategoryCreateRequest>,
+    ) -> Result<Response<rpc::CategoryCreateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        request.validate()?;
+        let message = request
+            .category
+            .ok_or_else(|| Status::invalid_argument("Missing category"))?;
+        let mut category = from_rpc(&self.public_ids, message)?;
+        category.user_id = scope.user_id();
+        self.quotas
+            .check(Resource::Categories, 1, &self.pool)
+            .await?;
+
+        let created = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| category.insert(conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::CategoryCreateResponse {
+            category: Some(to_rpc(&self.public_ids, created)),
+        }))
+    }
+
+    async fn categories_create_batch(
+        &self,
+        request: Request<rpc::CategoriesCreateBatchRequest>,
+    ) -> Result<Response<rpc::CategoriesCreateBatchResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        request.validate()?;
+        let categories = request
+            .categories
+            .into_iter()
+            .map(|message| {
+                let mut category = from_rpc(&self.public_ids, message)?;
+                category.user_id = scope.user_id();
+                Ok(category)
+            })
+            .collect::<Result<Vec<_>, Status>>()?;
+        self.quotas
+            .check(Resource::Categories, categories.len() as u64, &self.pool)
+            .await?;
+
+        let created = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Categories::insert_many(&categories, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::CategoriesCreateBatchResponse {
+            created_count: created.len() as i32,
+            categories: created
+                .into_iter()
+                .map(|category| to_rpc(&self.public_ids, category))
+                .collect(),
+        }))
+    }
+
+    async fn category_get(
+        &self,
+        request: Request<rpc::CategoryGetRequest>,
+    ) -> Result<Response<rpc::CategoryGetResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let public_id = request.into_inner().id;
+        let id = convert::parse_id(&self.public_ids, "id", &public_id)?;
+
+        let category = metadata::time_db(database::Categories::find_by_id(id, scope, &self.pool))
+            .await
+            .map_err(database_status)?
+            .ok_or_else(|| {
+                convert::not_found(
+                    "category",
+                    "id",
+                    format!("Category with id {public_id} not found"),
+                )
+            })?;
+
+        Ok(Response::new(rpc::CategoryGetResponse {
+            category: Some(to_rpc(&self.public_ids, category)),
+        }))
+    }
+
+    async fn category_get_by_code(
+        &self,
+        request: Request<rpc::CategoryGetByCodeRequest>,
+    ) -> Result<Response<rpc::CategoryGetByCodeResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let code = request.into_inner().code;
+
+        let category =
+            metadata::time_db(database::Categories::find_by_code(&code, scope, &self.pool))
+                .await
+                .map_err(database_status)?
+                .ok_or_else(|| {
+                    convert::not_found(
+                        "category",
+                        "code",
+                        format!("Category with code {code} not found"),
+                    )
+                })?;
+
+        Ok(Response::new(rpc::CategoryGetByCodeResponse {
+            category: Some(to_rpc(&self.public_ids, category)),
+        }))
+    }
+
+    async fn category_get_by_slug(
+        &self,
+        request: Request<rpc::CategoryGetBySlugRequest>,
+    ) -> Result<Response<rpc::CategoryGetBySlugResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let url_slug = request.into_inner().url_slug;
+        let slug = domain::UrlSlug::parse(url_slug.clone())
+            .map_err(|e| Status::invalid_argument(format!("Invalid url_slug: {e}")))?;
+
+        let category = metadata::time_db(database::Categories::find_by_url_slug(
+            &slug, scope, &self.pool,
+        ))
+        .await
+        .map_err(database_status)?
+        .ok_or_else(|| {
+            convert::not_found(
+                "category",
+                "slug",
+                format!("Category with slug {url_slug} not found"),
+            )
+        })?;
+
+        Ok(Response::new(rpc::CategoryGetBySlugResponse {
+            category: Some(to_rpc(&self.public_ids, category)),
+        }))
+    }
+
+    async fn categories_list(
+        &self,
+        request: Request<rpc::CategoriesListRequest>,
+    ) -> Result<Response<rpc::CategoriesListResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        request.validate()?;
+        let category_type = request
+            .category_type
+            .map(domain::CategoryTypes::from_rpc_i32)
+            .transpose()
+            .map_err(|_| Status::invalid_argument("Invalid category_type"))?;
+        let as_of = request
+            .as_of
+            .as_deref()
+            .map(|as_of| convert::parse_date("as_of", as_of))
+            .transpose()?;
+        let filter = database::CategoryFilter {
+            category_type,
+            is_active: request.is_active,
+            created_from: request
+                .created_from
+                .as_deref()
+                .map(|date| convert::parse_date("created_from", date))
+                .transpose()?,
+            created_to: request
+                .created_to
+                .as_deref()
+                .map(|date| convert::parse_date("created_to", date))
+                .transpose()?,
+            name_contains: request.name_contains.filter(|text| !text.trim().is_empty()),
+            sort_by: request
+                .sort_by
+                .as_deref()
+                .map(str::parse)
+                .transpose()
+                .map_err(database_status)?
+                .unwrap_or_default(),
+            sort_desc: request.sort_desc,
+            include_deleted: request.include_deleted,
+        };
+
+        let after = convert::parse_page_token(&request.page_token)?;
+
+        let page = match as_of {
+            // History is small enough to filter and page in memory
+            Some(as_of) => {
+                if filter.sort_by != database::CategorySortColumns::CreatedOn
+                    || !filter.is_descending()
+                {
+                    return Err(Status::invalid_argument(
+                        "Categories as of a date are listed newest first, and cannot be sorted",
+                    ));
+                }
+
+                let categories = metadata::time_db(database::Categories::find_all_as_of(
+                    as_of, scope, &self.pool,
+                ))
+                .await
+                .map_err(database_status)?
+                .into_iter()
+                .filter(|category| filter.matches(category))
+                .collect::<Vec<_>>();
+
+                database::Page::from_all(categories, after, request.limit, |category| {
+                    database::PageCursor::new(category.created_on, category.id)
+                })
+            }
+            None => metadata::time_db(database::Categories::find_with_filters(
+                &filter,
+                after,
+                request.limit,
+                scope,
+                &self.pool,
+            ))
+            .await
+            .map_err(database_status)?,
+        };
+
+        Ok(Response::new(rpc::CategoriesListResponse {
+            categories: page
+                .items
+                .into_iter()
+                .map(|category| to_rpc(&self.public_ids, category))
+                .collect(),
+            total_count: page.total_count,
+            next_page_token: convert::format_page_token(page.next_cursor),
+            limit: request.limit,
+        }))
+    }
+
+    async fn categories_stream(
+        &self,
+        request: Request<rpc::CategoriesStreamRequest>,
+    ) -> Result<Response<Self::CategoriesStreamStream>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        request.validate()?;
+        let page_size = stream::page_size(request.page_size)?;
+        let public_ids = self.public_ids.clone();
+
+        Ok(Response::new(stream::stream_pages(
+            self.pool.clone(),
+            scope,
+            page_size,
+            database::Categories::stream_all,
+            move |categories| to_page(&public_ids, categories),
+        )))
+    }
+
+    async fn categories_watch(
+        &self,
+        request: Request<rpc::CategoriesWatchRequest>,
+    ) -> Result<Response<Self::CategoriesWatchStream>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let public_ids = self.public_ids.clone();
+
+        Ok(Response::new(stream::watch_events(
+            &self.events,
+            move |event| to_change(&public_ids, event, scope),
+        )))
+    }
+
+    async fn category_update(
+        &self,
+        request: Request<rpc::CategoryUpdateRequest>,
+    ) -> Result<Response<rpc::CategoryUpdateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        request.validate()?;
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        let message = request
+            .category
+            .ok_or_else(|| Status::invalid_argument("Missing category"))?;
+        let existing = metadata::time_db(database::Categories::find_by_id(id, scope, &self.pool))
+            .await
+            .map_err(database_status)?
+            .ok_or_else(|| {
+                convert::not_found(
+                    "category",
+                    "id",
+                    format!("Category with id {} not found", request.id),
+                )
+            })?;
+
+        let fields = request
+            .update_mask
+            .map(|mask| mask.paths)
+            .unwrap_or_default()
+            .iter()
+            .map(|path| path.parse::<database::CategoryFields>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(database_status)?;
+
+        // Zero is a client that does not track versions, so nothing is checked
+        let version = match message.version {
+            0 => existing.version,
+            version => version,
+        };
+        let (created_on, stored) = (existing.created_on, to_rpc(&self.public_ids, existing));
+        let merged = apply_mask(stored, message, &fields);
+        merged.validate_as("category")?;
+        let mut category = from_rpc(&self.public_ids, merged)?;
+        category.id = id;
+        category.created_on = created_on;
+        category.version = version;
+
+        let updated = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| match fields.is_empty() {
+                true => category.update(scope, conn).await,
+                false => category.update_partial(&fields, scope, conn).await,
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::CategoryUpdateResponse {
+            category: Some(to_rpc(&self.public_ids, updated)),
+        }))
+    }
+
+    async fn category_delete(
+        &self,
+        request: Request<rpc::CategoryDeleteRequest>,
+    ) -> Result<Response<rpc::CategoryDeleteResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        let reassign_to_id = request
+            .reassign_to_id
+            .as_deref()
+            .map(|id| convert::parse_id(&self.public_ids, "reassign_to_id", id))
+            .transpose()?;
+        if request.soft && reassign_to_id.is_some() {
+            return Err(Status::invalid_argument(
+                "reassign_to_id cannot be used with soft, a soft-deleted category keeps its uses",
+            ));
+        }
+
+        let rows_reassigned = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| match reassign_to_id {
+                Some(target_id) => {
+                    database::Categories::delete_with_reassign(id, target_id, scope, conn).await
+                }
+                None if request.soft => database::Categories::delete_soft(id, scope, conn)
+                    .await
+                    .map(|_| 0),
+                None => database::Categories::delete_by_id(id, scope, conn)
+                    .await
+                    .map(|_| 0),
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::CategoryDeleteResponse {
+            rows_deleted: 1,
+            rows_reassigned: rows_reassigned as i64,
+        }))
+    }
+
+    async fn categories_delete_batch(
+        &self,
+        request: Request<rpc::CategoriesDeleteBatchRequest>,
+    ) -> Result<Response<rpc::CategoriesDeleteBatchResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let ids = request
+            .ids
+            .iter()
+            .map(|id| convert::parse_id(&self.public_ids, "ids", id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Categories::delete_many_by_id(&ids, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::CategoriesDeleteBatchResponse {
+            rows_deleted: ids.len() as i32,
+        }))
+    }
+
+    async fn category_activate(
+        &self,
+        request: Request<rpc::CategoryActivateRequest>,
+    ) -> Result<Response<rpc::CategoryActivateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+
+        let category = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Categories::update_active_status(id, true, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::CategoryActivateResponse {
+            category: Some(to_rpc(&self.public_ids, category)),
+        }))
+    }
+
+    async fn category_deactivate(
+        &self,
+        request: Request<rpc::CategoryDeactivateRequest>,
+    ) -> Result<Response<rpc::CategoryDeactivateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+
+        let category = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Categories::update_active_status(id, false, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::CategoryDeactivateResponse {
+            category: Some(to_rpc(&self.public_ids, category)),
+        }))
+    }
+
+    async fn category_archive(
+        &self,
+        request: Request<rpc::CategoryArchiveRequest>,
+    ) -> Result<Response<rpc::CategoryArchiveResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        let months = request.months.unwrap_or(DEFAULT_ARCHIVE_MONTHS);
+        if !(1..=MAX_ARCHIVE_MONTHS).contains(&months) {
+            return Err(Status::invalid_argument(format!(
+                "Invalid months, must be between 1 and {MAX_ARCHIVE_MONTHS}: {months}"
+            )));
+        }
+        let since = chrono::Utc::now()
+            .date_naive()
+            .checked_sub_months(chrono::Months::new(months as u32))
+            .ok_or_else(|| Status::invalid_argument(format!("Invalid months: {months}")))?;
+
+        let (category, recent) = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Categories::archive(id, since, request.force, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::CategoryArchiveResponse {
+            category: Some(to_rpc(&self.public_ids, category)),
+            recent_transaction_count: recent,
+        }))
+    }
+
+    async fn category_restore(
+        &self,
+        request: Request<rpc::CategoryRestoreRequest>,
+    ) -> Result<Response<rpc::CategoryRestoreResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        self.quotas
+            .check(Resource::Categories, 1, &self.pool)
+            .await?;
+
+        let category = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Categories::restore(id, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::CategoryRestoreResponse {
+            category: Some(to_rpc(&self.public_ids, category)),
+        }))
+    }
+
+    async fn get_category_statistics(
+        &self,
+        request: Request<rpc::GetCategoryStatisticsRequest>,
+    ) -> Result<Response<rpc::GetCategoryStatisticsResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let recent_limit = match request.into_inner().recent_limit {
+            None => DEFAULT_RECENT_LIMIT,
+            Some(limit) if limit < 0 => {
+                return Err(Status::invalid_argument(format!(
+                    "Invalid recent_limit, must not be negative: {limit}"
+                )));
+            }
+            Some(limit) => (limit as usize).min(MAX_RECENT_LIMIT),
+        };
+
+        let statistics = metadata::time_db(database::Categories::statistics(
+            recent_limit,
+            scope,
+            &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::GetCategoryStatisticsResponse {
+            total_count: statistics.total_count,
+            active_count: statistics.active_count,
+            inactive_count: statistics.inactive_count,
+            type_counts: statistics
+                .type_counts
+                .into_iter()
+                .map(|count| rpc::CategoryTypeCount {
+                    category_type: count.category_type.to_rpc_i32(),
+                    count: count.count,
+                })
+                .collect(),
+            usage: statistics
+                .usage
+                .into_iter()
+                .map(|usage| usage_to_rpc(&self.public_ids, usage))
+                .collect(),
+            recently_used: statistics
+                .recently_used
+                .into_iter()
+                .map(|usage| usage_to_rpc(&self.public_ids, usage))
+                .collect(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::CategoriesService;
+
+    use crate::auth::as_user;
+    use tokio_stream::StreamExt;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn message(code: &str, name: &str) -> rpc::Category {
+        rpc::Category {
+            code: code.to_string(),
+            name: name.to_string(),
+            category_type: domain::CategoryTypes::Expense.to_rpc_i32(),
+            is_active: true,
+            ..Default::default()
+        }
+    }
+
+    async fn create(
+        service: &MyCategoriesService,
+        category: rpc::Category,
+    ) -> Result<rpc::Category> {
+        let request = Request::new(rpc::CategoryCreateRequest {
+            category: Some(category),
+            validate_only: false,
+        });
+
+        Ok(service
+            .category_create(request)
+            .await?
+            .into_inner()
+            .category
+            .unwrap())
+    }
+
+    async fn stream(
+        service: &MyCategoriesService,
+        page_size: i32,
+    ) -> core::result::Result<Vec<rpc::CategoriesStreamResponse>, Status> {
+        let request = Request::new(rpc::CategoriesStreamRequest { page_size });
+
+        service
+            .categories_stream(request)
+            .await?
+            .into_inner()
+            .collect::<core::result::Result<Vec<_>, _>>()
+            .await
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn create_update_and_get_round_trip(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let created = create(&service, message("FOOD", "Food")).await?;
+
+        let request = Request::new(rpc::CategoryUpdateRequest {
+            id: created.id.clone(),
+            category: Some(message("FOOD", "Groceries")),
+            validate_only: false,
+            ..Default::default()
+        });
+        service.category_update(request).await?;
+
+        let request = Request::new(rpc::CategoryGetRequest {
+            id: created.id.clone(),
+        });
+        let fetched = service
+            .category_get(request)
+            .await?
+            .into_inner()
+            .category
+            .unwrap();
+
+        assert_eq!(fetched.name, "Groceries");
+        assert_eq!(fetched.created_on, created.created_on);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn users_only_see_and_reuse_codes_of_their_own_categories(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        database::Users::new("ian").insert(&pool).await?;
+        database::Users::new("sam").insert(&pool).await?;
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let create_as = |subject| {
+            as_user(
+                subject,
+                Request::new(rpc::CategoryCreateRequest {
+                    category: Some(message("FOOD", "Food")),
+                    validate_only: false,
+                }),
+            )
+        };
+
+        service.category_create(create_as("ian")).await?;
+        service.category_create(create_as("sam")).await?;
+        let status = service.category_create(create_as("ian")).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::AlreadyExists, "{status:?}");
+
+        let listed = service
+            .categories_list(as_user("ian", Request::new(Default::default())))
+            .await?
+            .into_inner();
+        assert_eq!(listed.total_count, 1);
+
+        let status = service
+            .categories_list(as_user("alex", Request::new(Default::default())))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn not_found_names_the_public_id_not_the_row_id(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let id = domain::RowID::new();
+        let public_id = convert::format_id(&convert::TEST_PUBLIC_IDS, id);
+
+        let request = Request::new(rpc::CategoryGetRequest {
+            id: public_id.clone(),
+        });
+        let status = service.category_get(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert_eq!(
+            status.message(),
+            format!("Category with id {public_id} not found")
+        );
+
+        let request = Request::new(rpc::CategoryDeleteRequest {
+            id: public_id,
+            ..Default::default()
+        });
+        let status = service.category_delete(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert!(!status.message().contains(&id.to_string()), "{status:?}");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn update_of_a_stale_version_is_aborted(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let created = create(&service, message("FOOD", "Food")).await?;
+        let update = |name| {
+            Request::new(rpc::CategoryUpdateRequest {
+                id: created.id.clone(),
+                category: Some(rpc::Category {
+                    version: created.version,
+                    ..message("FOOD", name)
+                }),
+                ..Default::default()
+            })
+        };
+
+        let updated = service
+            .category_update(update("Groceries"))
+            .await?
+            .into_inner()
+            .category
+            .unwrap();
+        let stale = service.category_update(update("Supermarket")).await;
+
+        assert_eq!(updated.version, created.version + 1);
+        assert_eq!(stale.unwrap_err().code(), tonic::Code::Aborted);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn update_writes_only_the_masked_fields(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let created = create(&service, message("FOOD", "Food")).await?;
+        let update = |paths: &[&str]| {
+            Request::new(rpc::CategoryUpdateRequest {
+                id: created.id.clone(),
+                category: Some(message("RENT", "Groceries")),
+                update_mask: Some(prost_types::FieldMask {
+                    paths: paths.iter().map(|path| path.to_string()).collect(),
+                }),
+                ..Default::default()
+            })
+        };
+
+        let updated = service
+            .category_update(update(&["name"]))
+            .await?
+            .into_inner()
+            .category
+            .unwrap();
+        let unknown = service.category_update(update(&["created_on"])).await;
+
+        assert_eq!(
+            (updated.code.as_str(), updated.name.as_str()),
+            ("FOOD", "Groceries")
+        );
+        assert_eq!(unknown.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn create_refuses_categories_past_the_quota(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new())
+                .with_quotas(config::QuotaConfig {
+                    max_categories: Some(1),
+                    ..Default::default()
+                });
+        create(&service, message("FOOD", "Food")).await?;
+
+        let result = create(&service, message("RENT", "Rent")).await;
+
+        let status = result.unwrap_err().downcast::<Status>().unwrap();
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn create_rejects_missing_name(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+
+        let result = create(&service, message("FOOD", " ")).await;
+
+        let status = result.unwrap_err().downcast::<Status>().unwrap();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn invalid_fields_are_reported_as_bad_request(pool: sqlx::SqlitePool) -> Result<()> {
+        use rpc::StatusExt;
+
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let food = create(&service, message("FOOD", "Food")).await?;
+        let fields = |status: Status| -> Vec<String> {
+            let details = status
+                .get_details_bad_request()
+                .expect("bad request details");
+            details
+                .field_violations
+                .into_iter()
+                .map(|violation| violation.field)
+                .collect()
+        };
+
+        let mut invalid = message("food", "Food");
+        invalid.color = Some("orange".to_string());
+        let request = Request::new(rpc::CategoryCreateRequest {
+            category: Some(invalid),
+            validate_only: false,
+        });
+        let status = service.category_create(request).await.unwrap_err();
+        assert_eq!(fields(status), ["category.code", "category.color"]);
+
+        // A masked update is checked once merged with the stored category
+        let request = Request::new(rpc::CategoryUpdateRequest {
+            id: food.id.clone(),
+            category: Some(rpc::Category {
+                name: "x".repeat(rpc::MAX_NAME_LENGTH + 1),
+                ..Default::default()
+            }),
+            update_mask: Some(prost_types::FieldMask {
+                paths: vec!["name".to_string()],
+            }),
+            ..Default::default()
+        });
+        let status = service.category_update(request).await.unwrap_err();
+        assert_eq!(fields(status), ["category.name"]);
+
+        let request = Request::new(rpc::CategoriesListRequest {
+            limit: -1,
+            ..Default::default()
+        });
+        let status = service.categories_list(request).await.unwrap_err();
+        assert_eq!(fields(status), ["limit"]);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn list_filters_and_sorts_by_name(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        for (code, name) in [
+            ("TAKEAWAY", "Takeaway food"),
+            ("FOOD", "Food"),
+            ("FUEL", "Fuel"),
+        ] {
+            create(&service, message(code, name)).await?;
+        }
+
+        let request = Request::new(rpc::CategoriesListRequest {
+            name_contains: Some("food".to_string()),
+            sort_by: Some("name".to_string()),
+            ..Default::default()
+        });
+        let response = service.categories_list(request).await?.into_inner();
+        let names: Vec<_> = response
+            .categories
+            .iter()
+            .map(|category| category.name.as_str())
+            .collect();
+
+        assert_eq!(names, ["Food", "Takeaway food"]);
+        assert_eq!(response.total_count, 2);
+
+        let request = Request::new(rpc::CategoriesListRequest {
+            sort_by: Some("name; DROP TABLE categories".to_string()),
+            ..Default::default()
+        });
+        let result = service.categories_list(request).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn soft_deleted_category_is_listed_only_when_asked(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let food = create(&service, message("FOOD", "Food")).await?;
+        let list = async |include_deleted| {
+            let request = Request::new(rpc::CategoriesListRequest {
+                include_deleted,
+                ..Default::default()
+            });
+            service
+                .categories_list(request)
+                .await
+                .map(|response| response.into_inner().categories)
+        };
+
+        let request = Request::new(rpc::CategoryDeleteRequest {
+            id: food.id.clone(),
+            validate_only: false,
+            soft: true,
+            reassign_to_id: None,
+        });
+        service.category_delete(request).await?;
+
+        assert!(list(false).await?.is_empty());
+        let deleted = list(true).await?;
+        assert_eq!(deleted.len(), 1);
+        assert!(deleted[0].deleted_on.is_some());
+
+        let request = Request::new(rpc::CategoryGetRequest {
+            id: food.id.clone(),
+        });
+        assert_eq!(
+            service.category_get(request).await.unwrap_err().code(),
+            tonic::Code::NotFound
+        );
+
+        let request = Request::new(rpc::CategoryRestoreRequest {
+            id: food.id.clone(),
+            validate_only: false,
+        });
+        let restored = service
+            .category_restore(request)
+            .await?
+            .into_inner()
+            .category
+            .unwrap();
+
+        assert_eq!(restored.deleted_on, None);
+        assert_eq!(list(false).await?.len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn parent_with_children_cannot_be_deleted(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let food = create(&service, message("FOOD", "Food")).await?;
+        let mut groceries = message("GROCERIES", "Groceries");
+        groceries.parent_id = Some(food.id.clone());
+        let groceries = create(&service, groceries).await?;
+
+        let request = Request::new(rpc::CategoryDeleteRequest {
+            id: food.id.clone(),
+            validate_only: false,
+            soft: false,
+            reassign_to_id: None,
+        });
+        let result = service.category_delete(request).await;
+
+        assert_eq!(groceries.parent_id, Some(food.id));
+        assert_eq!(result.unwrap_err().code(), tonic::Code::FailedPrecondition);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn category_in_use_is_deleted_only_with_reassign(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyCategoriesService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let dining = create(&service, message("DINING", "Dining")).await?;
+        let food = create(&service, message("FOOD", "Food")).await?;
+        let mut transaction = database::Transactions::new(
+            chrono::NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+            "Noodle Bar",
+            -2_400,
+        );
+        transaction.category_id = Some(convert::parse_id(
+            &convert::TEST_PUBLIC_IDS,
+            "id",
+            &dining.id,
+        )?);
+        let transaction = transaction.insert(&pool).await?;
+        let delete = |reassign_to_id: Option<&str>, soft| {
+            Request::new(rpc::CategoryDeleteRequest {
+                id: dining.id.clone(),
+                validate_only: false,
+                soft,
+                reassign_to_id: reassign_to_id.map(str::to_string),
+            })
+        };
+
+        let in_use = service
+            .category_delete(delete(None, false))
+            .await
+            .unwrap_err();
+        assert_eq!(in_use.code(), tonic::Code::FailedPrecondition);
+        let soft = service
+            .category_delete(delete(Some(&food.id), true))
+            .await
+            .unwrap_err();
+        assert_eq!(soft.code(), tonic::Code::InvalidArgument);
+
+        let response = service
+            .category_delete(delete(Some(&food.id), false))
+            .await?
+            .into_inner();
+        assert_eq!(response.rows_reassigned, 1);
+        let moved =
+            database::Transactions::find_by_id(transaction.id, database::UserScope::All, &pool)
+                .await?
+                .unwrap();
+        assert_eq!(
+            moved
+                .category_id
+                .map(|id| convert::format_id(&convert::TEST_PUBLIC_IDS, id)),
+            Some(food.id)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn statistics_count_categories_by_type_and_use(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyCategoriesService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let groceries = create(&service, message("GRO", "Groceries")).await?;
+        let salary = rpc::Category {
+            category_type: rpc::CategoryTypes::Income as i32,
+            ..message("PAY", "Salary")
+        };
+        create(&service, salary).await?;
+        let mut transaction = database::Transactions::new(
+            chrono::NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+            "Fresh Market",
+            -1_000,
+        );
+        transaction.category_id = Some(convert::parse_id(
+            &convert::TEST_PUBLIC_IDS,
+            "id",
+            &groceries.id,
+        )?);
+        transaction.insert(&pool).await?;
+
+        let statistics = service
+            .get_category_statistics(Request::new(rpc::GetCategoryStatisticsRequest {
+                recent_limit: Some(1),
+            }))
+            .await?
+            .into_inner();
+
+        assert_eq!((statistics.total_count, statistics.active_count), (2, 2));
+        let expense = statistics
+            .type_counts
+            .iter()
+            .find(|count| count.category_type() == rpc::CategoryTypes::Expense)
+            .unwrap();
+        assert_eq!(expense.count, 1);
+        assert_eq!(statistics.usage[0].category_id, groceries.id);
+        assert_eq!(statistics.usage[0].transaction_count, 1);
+        assert_eq!(statistics.recently_used.len(), 1);
+        assert_eq!(
+            statistics.recently_used[0].last_used_on.as_deref(),
+            Some("2025-04-01")
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn statistics_reject_a_negative_limit(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+
+        let status = service
+            .get_category_statistics(Request::new(rpc::GetCategoryStatisticsRequest {
+                recent_limit: Some(-1),
+            }))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn archive_needs_force_for_recently_used_categories(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let service = MyCategoriesService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let groceries = create(&service, message("GRO", "Groceries")).await?;
+        let last_month = chrono::Utc::now().date_naive() - chrono::Days::new(30);
+        let mut transaction = database::Transactions::new(last_month, "Fresh Market", -1_000);
+        transaction.category_id = Some(convert::parse_id(
+            &convert::TEST_PUBLIC_IDS,
+            "id",
+            &groceries.id,
+        )?);
+        transaction.insert(&pool).await?;
+        let archive = |months, force| {
+            Request::new(rpc::CategoryArchiveRequest {
+                id: groceries.id.clone(),
+                months,
+                force,
+                validate_only: false,
+            })
+        };
+
+        let refused = service
+            .category_archive(archive(None, false))
+            .await
+            .unwrap_err();
+        assert_eq!(refused.code(), tonic::Code::InvalidArgument);
+        let invalid = service
+            .category_archive(archive(Some(0), true))
+            .await
+            .unwrap_err();
+        assert_eq!(invalid.code(), tonic::Code::InvalidArgument);
+
+        let forced = service
+            .category_archive(archive(None, true))
+            .await?
+            .into_inner();
+        assert!(!forced.category.unwrap().is_active);
+        assert_eq!(forced.recent_transaction_count, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn stream_sends_every_category_in_pages(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        for index in 1..=5 {
+            create(
+                &service,
+                message(&format!("C{index}"), &format!("Category {index}")),
+            )
+            .await?;
+        }
+
+        let pages = stream(&service, 2).await?;
+        let sizes: Vec<_> = pages.iter().map(|page| page.categories.len()).collect();
+        let whole = stream(&service, 0).await?;
+
+        assert_eq!(sizes, vec![2, 2, 1]);
+        assert_eq!(whole.len(), 1);
+        assert_eq!(whole[0].categories.len(), 5);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn stream_of_empty_table_sends_no_pages(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+
+        let pages = stream(&service, 10).await?;
+        let negative = stream(&service, -1).await;
+
+        assert!(pages.is_empty());
+        assert_eq!(negative.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    /// Waits for the next change to the category `id`, skipping changes made
+    /// by other tests running at the same time, or `None` if there is none.
+    async fn next_change_for(
+        changes: &mut stream::PageStream<rpc::CategoriesWatchResponse>,
+        id: &str,
+    ) -> Option<rpc::CategoriesWatchResponse> {
+        loop {
+            let change =
+                tokio::time::timeout(std::time::Duration::from_millis(500), changes.next())
+                    .await
+                    .ok()??
+                    .expect("the watch should not fail");
+            if change.category_id == id {
+                return Some(change);
+            }
+        }
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn watch_pushes_committed_changes(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyCategoriesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let mut changes = service
+            .categories_watch(Request::new(rpc::CategoriesWatchRequest {}))
+            .await?
+            .into_inner();
+
+        let food = create(&service, message("FOOD", "Food")).await?;
+        let request = Request::new(rpc::CategoryCreateRequest {
+            category: Some(message("RENT", "Rent")),
+            validate_only: true,
+        });
+        let rent = service
+            .category_create(request)
+            .await?
+            .into_inner()
+            .category
+            .unwrap();
+        let request = Request::new(rpc::CategoryDeleteRequest {
+            id: food.id.clone(),
+            validate_only: false,
+            soft: false,
+            reassign_to_id: None,
+        });
+        service.category_delete(request).await?;
+
+        let created = next_change_for(&mut changes, &food.id)
+            .await
+            .expect("a created change");
+        assert_eq!(created.kind(), rpc::CategoryChangeKinds::Created);
+        assert_eq!(created.category, Some(food.clone()));
+        let deleted = next_change_for(&mut changes, &food.id)
+            .await
+            .expect("a deleted change");
+        assert_eq!(deleted.kind(), rpc::CategoryChangeKinds::Deleted);
+        assert_eq!(deleted.category, None);
+        assert_eq!(next_change_for(&mut changes, &rent.id).await, None);
+
+        Ok(())
+    }
+}
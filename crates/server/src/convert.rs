@@ -0,0 +1,583 @@
+//! # RPC Conversions
+//!
+//! Helpers shared by the gRPC handlers for converting between wire values and
+//! database values, and for mapping database errors to gRPC statuses.
+//!
+//! Every error carries a `google.rpc.ErrorInfo` with its
+//! [reason](rpc::ErrorReason), and the entity and field it is about when they
+//! are known, see [`database_status`].
+//!
+//! Records are named on the wire by their [public ID](domain::PublicID),
+//! never their RowID. IDs are formatted with [`format_id`] and parsed with
+//! [`parse_id`], under the ledger's key, which each service is given when
+//! the server starts.
+
+use tonic::{Code, Status};
+
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+/// Date format used for dates on the wire (ISO 8601, `YYYY-MM-DD`).
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Maps a database error to the gRPC status returned to the client.
+///
+/// | Error                | Code                  | Reason              |
+/// |----------------------|-----------------------|---------------------|
+/// | `Validation`         | `INVALID_ARGUMENT`    | `VALIDATION_FAILED` |
+/// | `NotFound`           | `NOT_FOUND`           | `NOT_FOUND`         |
+/// | `PeriodClosed`       | `FAILED_PRECONDITION` | `PERIOD_CLOSED`     |
+/// | `Conflict`           | `ABORTED`             | `CONFLICT`          |
+/// | `InUse`              | `FAILED_PRECONDITION` | `IN_USE`            |
+/// | `UNIQUE` violation   | `ALREADY_EXISTS`      | `ALREADY_EXISTS`    |
+/// | `CHECK` violation    | `INVALID_ARGUMENT`    | `VALIDATION_FAILED` |
+/// | Busy or locked       | `UNAVAILABLE`         | `BUSY`              |
+/// | Anything else        | `INTERNAL`            | `INTERNAL`          |
+///
+/// The entity and field are read from the error where it names them, such
+/// as the table and column of a unique constraint. RowIDs are left out of the
+/// messages of database errors, as clients only know records by their public
+/// IDs. Services handle foreign key
+/// violations themselves before falling back to this, as the right status
+/// depends on which relationship was violated.
+pub fn database_status(error: database::DatabaseError) -> Status {
+    match error {
+        database::DatabaseError::Validation(message) => {
+            let reason = rpc::ErrorReason::ValidationFailed;
+            let message = without_row_ids(&message);
+            rpc::error_status(Code::InvalidArgument, message, reason, None, None)
+        }
+        database::DatabaseError::NotFound(message) => {
+            let (entity, field) = not_found_subject(&message).unzip();
+            let (entity, field) = (entity.as_deref(), field.as_deref());
+            rpc::error_status(
+                Code::NotFound,
+                without_row_ids(&message),
+                rpc::ErrorReason::NotFound,
+                entity,
+                field,
+            )
+        }
+        database::DatabaseError::PeriodClosed(message) => rpc::error_status(
+            Code::FailedPrecondition,
+            format!("Period closed: {}", without_row_ids(&message)),
+            rpc::ErrorReason::PeriodClosed,
+            None,
+            None,
+        ),
+        database::DatabaseError::Conflict(message) => rpc::error_status(
+            Code::Aborted,
+            without_row_ids(&message),
+            rpc::ErrorReason::Conflict,
+            None,
+            None,
+        ),
+        database::DatabaseError::InUse(message) => rpc::error_status(
+            Code::FailedPrecondition,
+            without_row_ids(&message),
+            rpc::ErrorReason::InUse,
+            None,
+            None,
+        ),
+        database::DatabaseError::Sqlx(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+            let (entity, field) = unique_constraint(e.message()).unzip();
+            let (entity, field) = (entity.as_deref(), field.as_deref());
+            rpc::error_status(
+                Code::AlreadyExists,
+                e.message(),
+                rpc::ErrorReason::AlreadyExists,
+                entity,
+                field,
+            )
+        }
+        database::DatabaseError::Sqlx(sqlx::Error::Database(e)) if e.is_check_violation() => {
+            let reason = rpc::ErrorReason::ValidationFailed;
+            rpc::error_status(Code::InvalidArgument, e.message(), reason, None, None)
+        }
+        error if is_busy(&error) => {
+            tracing::warn!(error = %error, "Database busy, asking the client to retry");
+            let mut status = rpc::error_status(
+                Code::Unavailable,
+                "Database is busy, retry shortly",
+                rpc::ErrorReason::Busy,
+                None,
+                None,
+            );
+            status
+                .metadata_mut()
+                .insert(rpc::RETRY_AFTER_HEADER, BUSY_RETRY_AFTER_MS.into());
+            status
+        }
+        error => {
+            tracing::error!(error = %error, "Database operation failed");
+            rpc::error_status(
+                Code::Internal,
+                "Database error",
+                rpc::ErrorReason::Internal,
+                None,
+                None,
+            )
+        }
+    }
+}
+
+/// A `NOT_FOUND` status for a record the handler looked up itself, naming
+/// the `entity` and the `field` it was looked up by.
+pub fn not_found(entity: &str, field: &str, message: impl Into<String>) -> Status {
+    rpc::error_status(
+        Code::NotFound,
+        message,
+        rpc::ErrorReason::NotFound,
+        Some(entity),
+        Some(field),
+    )
+}
+
+/// A `FAILED_PRECONDITION` status for a foreign key violation, which a
+/// service words for the relationship that was violated.
+pub fn foreign_key_status(
+    reason: rpc::ErrorReason,
+    entity: &str,
+    message: impl Into<String>,
+) -> Status {
+    rpc::error_status(
+        Code::FailedPrecondition,
+        message,
+        reason,
+        Some(entity),
+        None,
+    )
+}
+
+/// Reads the entity and field from a not found message worded like
+/// `Payee alias with id 7 not found`, as `payee_alias` and `id`.
+fn not_found_subject(message: &str) -> Option<(String, String)> {
+    let (entity, rest) = message.split_once(" with ")?;
+    let entity = entity.strip_prefix("Deleted ").unwrap_or(entity);
+    let field = rest.split_whitespace().next()?;
+
+    Some((entity.to_lowercase().replace(' ', "_"), field.to_string()))
+}
+
+/// The `message` of a database error without the RowIDs in it, and the
+/// `with id` naming one, so `Category with id <RowID> not found` is sent as
+/// `Category not found`.
+fn without_row_ids(message: &str) -> String {
+    let mut words: Vec<&str> = Vec::new();
+    for word in message.split(' ') {
+        let bare = word.trim_matches(|c: char| !c.is_ascii_hexdigit() && c != '-');
+        if uuid::Uuid::try_parse(bare).is_err() {
+            words.push(word);
+        } else if words.ends_with(&["with", "id"]) {
+            words.truncate(words.len() - 2);
+        }
+    }
+
+    words.join(" ")
+}
+
+/// Reads the entity and fields from a SQLite unique constraint message such
+/// as `UNIQUE constraint failed: categories.user_id, categories.code`, as
+/// `category` and `user_id,code`.
+fn unique_constraint(message: &str) -> Option<(String, String)> {
+    let columns = message.strip_prefix("UNIQUE constraint failed: ")?;
+    let mut table = None;
+    let mut fields = Vec::new();
+    for column in columns.split(", ") {
+        let (column_table, field) = column.split_once('.')?;
+        table.get_or_insert(column_table);
+        fields.push(field);
+    }
+
+    Some((singular(table?), fields.join(",")))
+}
+
+/// The entity a table holds, such as `category` for `categories`.
+fn singular(table: &str) -> String {
+    if let Some(stem) = table.strip_suffix("ies") {
+        format!("{stem}y")
+    } else if ["ches", "shes", "ses", "xes"]
+        .iter()
+        .any(|suffix| table.ends_with(suffix))
+    {
+        table[..table.len() - 2].to_string()
+    } else {
+        table.strip_suffix('s').unwrap_or(table).to_string()
+    }
+}
+
+/// How long a client is asked to wait after the database was busy, in
+/// milliseconds.
+const BUSY_RETRY_AFTER_MS: u64 = 250;
+
+/// Returns `true` when the database was locked by another connection or no
+/// connection was free. The transaction was rolled back, so the request can
+/// be retried as is.
+fn is_busy(error: &database::DatabaseError) -> bool {
+    match error {
+        database::DatabaseError::Sqlx(sqlx::Error::PoolTimedOut) => true,
+        // SQLITE_BUSY and SQLITE_LOCKED, and their extended codes
+        database::DatabaseError::Sqlx(sqlx::Error::Database(e)) => e
+            .code()
+            .and_then(|code| code.parse::<i32>().ok())
+            .is_some_and(|code| matches!(code & 0xff, 5 | 6)),
+        _ => false,
+    }
+}
+
+/// Returns `true` when the error is a foreign key constraint violation.
+pub fn is_foreign_key_violation(error: &database::DatabaseError) -> bool {
+    matches!(
+        error,
+        database::DatabaseError::Sqlx(sqlx::Error::Database(e)) if e.is_foreign_key_violation()
+    )
+}
+
+/// A fixed key that is not secret, for tests calling handlers directly.
+#[cfg(test)]
+pub const TEST_PUBLIC_IDS: domain::PublicIdKey = domain::PublicIdKey::new([7; 16]);
+
+/// Formats a row ID as the public ID sent to the client, under the ledger's
+/// `public_ids` key.
+pub fn format_id(public_ids: &domain::PublicIdKey, id: domain::RowID) -> String {
+    public_ids.encode(id).to_string()
+}
+
+/// Parses a public ID sent by the client into its row ID, under the
+/// ledger's `public_ids` key.
+pub fn parse_id(
+    public_ids: &domain::PublicIdKey,
+    field: &str,
+    id: &str,
+) -> Result<domain::RowID, Status> {
+    id.parse()
+        .ok()
+        .and_then(|public_id| public_ids.decode(public_id).ok())
+        .ok_or_else(|| invalid_field(field, format!("Invalid {field}: {id}")))
+}
+
+/// Parses a `YYYY-MM-DD` date sent by the client.
+pub fn parse_date(field: &str, date: &str) -> Result<chrono::NaiveDate, Status> {
+    chrono::NaiveDate::parse_from_str(date, DATE_FORMAT).map_err(|_| {
+        invalid_field(
+            field,
+            format!("Invalid {field}, expected YYYY-MM-DD: {date}"),
+        )
+    })
+}
+
+/// Parses an ISO 4217 currency code sent by the client. An empty code is the
+/// default currency.
+pub fn parse_currency(field: &str, code: &str) -> Result<domain::Currency, Status> {
+    if code.trim().is_empty() {
+        return Ok(domain::Currency::default());
+    }
+
+    code.parse()
+        .map_err(|_| invalid_field(field, format!("Invalid {field}: {code}")))
+}
+
+/// An `INVALID_ARGUMENT` status for a request `field` that could not be
+/// parsed.
+pub fn invalid_field(field: &str, message: impl Into<String>) -> Status {
+    rpc::error_status(
+        Code::InvalidArgument,
+        message,
+        rpc::ErrorReason::ValidationFailed,
+        None,
+        Some(field),
+    )
+}
+
+/// Parses a page token sent by the client. An empty token is the first page.
+pub fn parse_page_token(token: &str) -> Result<Option<database::PageCursor>, Status> {
+    if token.is_empty() {
+        return Ok(None);
+    }
+
+    database::PageCursor::decode(token)
+        .map(Some)
+        .map_err(database_status)
+}
+
+/// Formats the cursor of the next page as a page token, empty on the last
+/// page.
+pub fn format_page_token(cursor: Option<database::PageCursor>) -> String {
+    cursor.map(|cursor| cursor.encode()).unwrap_or_default()
+}
+
+/// Formats a date for the wire.
+pub fn format_date(date: chrono::NaiveDate) -> String {
+    date.format(DATE_FORMAT).to_string()
+}
+
+/// Converts a UTC timestamp into a protobuf timestamp.
+pub fn to_timestamp(date_time: chrono::DateTime<chrono::Utc>) -> prost_types::Timestamp {
+    prost_types::Timestamp {
+        seconds: date_time.timestamp(),
+        nanos: date_time.timestamp_subsec_nanos() as i32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_date_round_trips() {
+        let date = parse_date("as_of", "2025-06-30").unwrap();
+
+        assert_eq!(format_date(date), "2025-06-30");
+        assert_eq!(
+            parse_date("as_of", "30/06/2025").unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn parse_id_rejects_invalid_id() {
+        let id = domain::RowID::new();
+
+        assert_eq!(
+            parse_id(&TEST_PUBLIC_IDS, "id", &format_id(&TEST_PUBLIC_IDS, id)).unwrap(),
+            id
+        );
+        assert_eq!(
+            parse_id(&TEST_PUBLIC_IDS, "id", "not-an-id")
+                .unwrap_err()
+                .code(),
+            tonic::Code::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn ids_go_on_the_wire_as_public_ids() {
+        let id = domain::RowID::new();
+        let public_id = format_id(&TEST_PUBLIC_IDS, id);
+
+        assert_eq!(public_id.len(), domain::PUBLIC_ID_LENGTH);
+        assert!(!public_id.contains(&id.to_string()[..8]));
+        // RowIDs are not accepted in place of public IDs
+        assert!(parse_id(&TEST_PUBLIC_IDS, "id", &id.to_string()).is_err());
+        // Nor are another ledger's public IDs
+        let other_ledger = domain::PublicIdKey::new([8; 16]);
+        assert_ne!(parse_id(&other_ledger, "id", &public_id).ok(), Some(id));
+    }
+
+    #[test]
+    fn parse_currency_defaults_when_empty() {
+        assert_eq!(
+            parse_currency("currency", "usd").unwrap(),
+            domain::Currency::USD
+        );
+        assert_eq!(
+            parse_currency("currency", "").unwrap(),
+            domain::Currency::AUD
+        );
+        assert_eq!(
+            parse_currency("currency", "XYZ").unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn page_token_round_trips() {
+        let cursor = database::PageCursor::new(chrono::Utc::now(), domain::RowID::new());
+
+        assert_eq!(
+            parse_page_token(&format_page_token(Some(cursor))).unwrap(),
+            Some(cursor)
+        );
+        assert_eq!(parse_page_token(&format_page_token(None)).unwrap(), None);
+        assert_eq!(
+            parse_page_token("page-2").unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn to_timestamp_keeps_sub_second_precision() {
+        let date_time = chrono::DateTime::from_timestamp(1_741_910_400, 123_456_789).unwrap();
+
+        let timestamp = to_timestamp(date_time);
+
+        assert_eq!(timestamp.seconds, 1_741_910_400);
+        assert_eq!(timestamp.nanos, 123_456_789);
+    }
+
+    #[test]
+    fn database_status_maps_error_kinds() {
+        let validation = database_status(database::DatabaseError::Validation("bad".into()));
+        let not_found = database_status(database::DatabaseError::NotFound("gone".into()));
+        let conflict = database_status(database::DatabaseError::Conflict("stale".into()));
+        let in_use = database_status(database::DatabaseError::InUse("used".into()));
+        let other = database_status(database::DatabaseError::Other("boom".into()));
+
+        assert_eq!(validation.code(), tonic::Code::InvalidArgument);
+        assert_eq!(not_found.code(), tonic::Code::NotFound);
+        assert_eq!(conflict.code(), tonic::Code::Aborted);
+        assert_eq!(in_use.code(), tonic::Code::FailedPrecondition);
+        assert_eq!(other.code(), tonic::Code::Internal);
+
+        let reasons = [&validation, &not_found, &conflict, &in_use, &other].map(rpc::error_reason);
+        assert_eq!(
+            reasons,
+            [
+                rpc::ErrorReason::ValidationFailed,
+                rpc::ErrorReason::NotFound,
+                rpc::ErrorReason::Conflict,
+                rpc::ErrorReason::InUse,
+                rpc::ErrorReason::Internal,
+            ]
+            .map(Some)
+        );
+    }
+
+    #[test]
+    fn not_found_names_the_entity_and_field() {
+        let message = "Payee alias with id 7 not found";
+        let status = database_status(database::DatabaseError::NotFound(message.into()));
+
+        let info = rpc::error_info(&status).expect("error info");
+        assert_eq!(status.message(), message);
+        assert_eq!(info.entity(), Some("payee_alias"));
+        assert_eq!(info.field(), Some("id"));
+
+        let id = domain::RowID::new();
+        let message = format!("Category with id {id} not found after update");
+        let status = database_status(database::DatabaseError::NotFound(message));
+        assert_eq!(status.message(), "Category not found after update");
+        assert_eq!(
+            rpc::error_info(&status).expect("error info").entity(),
+            Some("category")
+        );
+
+        let message = format!("Category {id} is still used by 2 budgets");
+        let status = database_status(database::DatabaseError::InUse(message));
+        assert_eq!(status.message(), "Category is still used by 2 budgets");
+
+        let unnamed = database_status(database::DatabaseError::NotFound("record not found".into()));
+        assert_eq!(
+            rpc::error_info(&unnamed).expect("error info").entity(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn constraint_violations_name_the_entity_and_field()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await?;
+        sqlx::query("CREATE TABLE payee_aliases (user_id INTEGER, code TEXT, amount INTEGER CHECK (amount > 0))")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE UNIQUE INDEX payee_aliases_code ON payee_aliases (user_id, code)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("INSERT INTO payee_aliases VALUES (1, 'CAFE', 1)")
+            .execute(&pool)
+            .await?;
+
+        let duplicate: database::DatabaseError =
+            sqlx::query("INSERT INTO payee_aliases VALUES (1, 'CAFE', 1)")
+                .execute(&pool)
+                .await
+                .unwrap_err()
+                .into();
+        let negative: database::DatabaseError =
+            sqlx::query("INSERT INTO payee_aliases VALUES (1, 'BAR', -1)")
+                .execute(&pool)
+                .await
+                .unwrap_err()
+                .into();
+
+        let duplicate = database_status(duplicate);
+        let info = rpc::error_info(&duplicate).expect("error info");
+        assert_eq!(duplicate.code(), tonic::Code::AlreadyExists);
+        assert_eq!(info.reason(), Some(rpc::ErrorReason::AlreadyExists));
+        assert_eq!(info.entity(), Some("payee_alias"));
+        assert_eq!(info.field(), Some("user_id,code"));
+
+        let negative = database_status(negative);
+        assert_eq!(negative.code(), tonic::Code::InvalidArgument);
+        assert_eq!(
+            rpc::error_reason(&negative),
+            Some(rpc::ErrorReason::ValidationFailed)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn tables_are_named_as_their_entity() {
+        for (table, entity) in [
+            ("categories", "category"),
+            ("payee_aliases", "payee_alias"),
+            ("import_batches", "import_batch"),
+            ("transactions", "transaction"),
+            ("audit_log", "audit_log"),
+        ] {
+            assert_eq!(singular(table), entity);
+        }
+    }
+
+    #[test]
+    fn parse_errors_name_the_field() {
+        let status = parse_date("start_date", "yesterday").unwrap_err();
+
+        let info = rpc::error_info(&status).expect("error info");
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(info.reason(), Some(rpc::ErrorReason::ValidationFailed));
+        assert_eq!(info.field(), Some("start_date"));
+    }
+
+    #[test]
+    fn database_status_asks_client_to_retry_when_busy() {
+        let status = database_status(database::DatabaseError::Sqlx(sqlx::Error::PoolTimedOut));
+
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        assert!(rpc::is_retryable(&status));
+        assert_eq!(
+            rpc::retry_after(&status),
+            Some(std::time::Duration::from_millis(BUSY_RETRY_AFTER_MS))
+        );
+    }
+
+    #[tokio::test]
+    async fn locked_database_is_busy() -> Result<(), Box<dyn std::error::Error>> {
+        let path = std::env::temp_dir().join(format!("ledger-busy-{}.db", uuid::Uuid::now_v7()));
+        let url = format!("sqlite://{}?mode=rwc", path.display());
+        let config = lib_config::DatabaseConfig {
+            busy_timeout_ms: 0,
+            ..Default::default()
+        };
+        let first = database::DatabasePool::new(&url)
+            .with_config(config.clone())
+            .connect()
+            .await?;
+        let second = database::DatabasePool::new(&url)
+            .with_config(config)
+            .connect()
+            .await?;
+        sqlx::query("CREATE TABLE t (id INTEGER)")
+            .execute(first.get_pool()?)
+            .await?;
+
+        let mut writer = first.get_pool()?.begin().await?;
+        sqlx::query("INSERT INTO t VALUES (1)")
+            .execute(&mut *writer)
+            .await?;
+        let blocked: database::DatabaseError = sqlx::query("INSERT INTO t VALUES (2)")
+            .execute(second.get_pool()?)
+            .await
+            .unwrap_err()
+            .into();
+        writer.rollback().await?;
+
+        assert!(is_busy(&blocked), "{blocked}");
+        assert!(!is_busy(&database::DatabaseError::NotFound("gone".into())));
+
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+//! Server startup and run errors.
+//!
+//! `ServerError` wraps the errors of each library the server is built from,
+//! so startup can bail out with `?` at whichever step fails.
+
+use lib_config as config;
+use lib_database as database;
+use lib_telemetry as telemetry;
+
+/// Result type alias used by the server application.
+pub type ServerResult<T> = std::result::Result<T, ServerError>;
+
+/// Errors produced while starting or running the server.
+#[derive(thiserror::Error, Debug)]
+pub enum ServerError {
+    /// The configuration could not be loaded or is invalid.
+    #[error("Server configuration error: {0}")]
+    Config(#[from] config::ConfigError),
+
+    /// Telemetry could not be initialised.
+    #[error("Server telemetry error: {0}")]
+    Telemetry(#[from] telemetry::TelemetryError),
+
+    /// The database could not be opened or migrated.
+    #[error("Server database error: {0}")]
+    Database(#[from] database::DatabaseError),
+
+    /// The server address could not be bound.
+    #[error("Server bind error: {0}")]
+    Bind(#[from] std::io::Error),
+
+    /// The TLS certificate, key or client authority could not be loaded.
+    #[error("Server TLS error: {0}")]
+    Tls(String),
+
+    /// The API key file could not be read or has a line that is not valid.
+    #[error("Server auth error: {0}")]
+    Auth(String),
+
+    /// The gRPC reflection service could not be built from the compiled
+    /// protos.
+    #[error("Server reflection error: {0}")]
+    Reflection(#[from] tonic_reflection::server::Error),
+
+    /// The gRPC transport failed while serving.
+    #[error("Server transport error: {0}")]
+    Transport(#[from] tonic::transport::Error),
+
+    /// A background job stopped while the server was running.
+    #[error("Server background job stopped: {0}")]
+    Job(String),
+}
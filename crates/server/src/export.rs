@@ -0,0 +1,387 @@
+//! # Export Service
+//!
+//! gRPC handlers for the `ExportService`, backed by the lib-database
+//! [`LedgerExport`](database::LedgerExport).
+//!
+//! Records are read in a spawned task and sent in runs of
+//! [`DEFAULT_PAGE_SIZE`](stream::DEFAULT_PAGE_SIZE), through the same bounded
+//! channel as the other streaming RPCs, so a slow client pauses the export
+//! rather than the server buffering the ledger. Row IDs are written as public
+//! IDs, the same as every other response.
+//!
+//! A book for another accounting program is written whole by lib-database,
+//! then sent in runs of lines up to [`BOOK_CHUNK_BYTES`].
+
+use futures_util::TryStreamExt;
+use tonic::{Request, Response, Status};
+
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{auth, convert, metadata, stream};
+
+/// Largest content sent in one book response, unless a single line is
+/// longer.
+pub const BOOK_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Server implementation of the `ExportService`.
+#[derive(Debug, Clone)]
+pub struct MyExportService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+}
+
+impl MyExportService {
+    /// Creates the service using the given database pool and public ID key.
+    pub fn new(pool: sqlx::SqlitePool, public_ids: domain::PublicIdKey) -> Self {
+        Self { pool, public_ids }
+    }
+}
+
+/// Parses the optional date range of a request, checking it is in order.
+fn date_range(
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Result<(Option<chrono::NaiveDate>, Option<chrono::NaiveDate>), Status> {
+    let from = from
+        .map(|from| convert::parse_date("from", from))
+        .transpose()?;
+    let to = to.map(|to| convert::parse_date("to", to)).transpose()?;
+
+    if let (Some(from), Some(to)) = (from, to)
+        && from > to
+    {
+        return Err(Status::invalid_argument(format!(
+            "Invalid range, from {from} is after to {to}"
+        )));
+    }
+
+    Ok((from, to))
+}
+
+/// Splits `content` into runs of whole lines of up to `max_bytes`.
+fn chunk_lines(content: &str, max_bytes: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut chunk = String::new();
+
+    for line in content.split_inclusive('\n') {
+        if !chunk.is_empty() && chunk.len() + line.len() > max_bytes {
+            chunks.push(std::mem::take(&mut chunk));
+        }
+        chunk.push_str(line);
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+
+    chunks
+}
+
+/// Converts an RPC export format into its database format, CSV when unset.
+fn to_format(format: rpc::ExportFormats) -> database::ExportFormats {
+    match format {
+        rpc::ExportFormats::Unspecified | rpc::ExportFormats::Csv => database::ExportFormats::Csv,
+        rpc::ExportFormats::JsonLines => database::ExportFormats::JsonLines,
+    }
+}
+
+/// Converts an RPC entity into its database entity.
+fn to_entity(entity: i32) -> Result<database::ExportEntities, Status> {
+    match rpc::ExportEntities::try_from(entity) {
+        Ok(rpc::ExportEntities::Categories) => Ok(database::ExportEntities::Categories),
+        Ok(rpc::ExportEntities::Accounts) => Ok(database::ExportEntities::Accounts),
+        Ok(rpc::ExportEntities::Transactions) => Ok(database::ExportEntities::Transactions),
+        _ => Err(Status::invalid_argument(format!(
+            "Invalid entity: {entity}"
+        ))),
+    }
+}
+
+/// Converts a database entity into its RPC entity.
+fn to_rpc(entity: database::ExportEntities) -> rpc::ExportEntities {
+    match entity {
+        database::ExportEntities::Categories => rpc::ExportEntities::Categories,
+        database::ExportEntities::Accounts => rpc::ExportEntities::Accounts,
+        database::ExportEntities::Transactions => rpc::ExportEntities::Transactions,
+    }
+}
+
+#[tonic::async_trait]
+impl rpc::ExportService for MyExportService {
+    type ExportLedgerStream = stream::PageStream<rpc::ExportLedgerResponse>;
+
+    async fn export_ledger(
+        &self,
+        request: Request<rpc::ExportLedgerRequest>,
+    ) -> Result<Response<Self::ExportLedgerStream>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+
+        let (from, to) = date_range(request.from.as_deref(), request.to.as_deref())?;
+
+        let entities = if request.entities.is_empty() {
+            database::ExportEntities::ALL.to_vec()
+        } else {
+            request
+                .entities
+                .iter()
+                .map(|entity| to_entity(*entity))
+                .collect::<Result<_, _>>()?
+        };
+
+        let export = database::LedgerExport::new(to_format(request.format()))
+            .with_dates(from, to)
+            .with_scope(scope)
+            .with_public_ids(self.public_ids.clone());
+
+        let pool = self.pool.clone();
+        let (sender, receiver) = tokio::sync::mpsc::channel(stream::CHANNEL_PAGES);
+
+        tokio::spawn(async move {
+            for entity in entities {
+                let mut records = export.stream(entity, &pool);
+                let mut content = String::new();
+                let mut count = 0;
+
+                loop {
+                    let record = match records.try_next().await {
+                        Ok(record) => record,
+                        Err(error) => {
+                            let _ = sender.send(Err(convert::database_status(error))).await;
+                            return;
+                        }
+                    };
+
+                    let ends = record.is_none();
+                    if let Some(record) = record {
+                        content.push_str(&record);
+                        count += 1;
+                    }
+
+                    if (ends && !content.is_empty()) || count == stream::DEFAULT_PAGE_SIZE {
+                        let response = rpc::ExportLedgerResponse {
+                            entity: to_rpc(entity) as i32,
+                            content: std::mem::take(&mut content),
+                        };
+                        count = 0;
+
+                        // Waits here while the client is behind, pausing the export
+                        if sender.send(Ok(response)).await.is_err() {
+                            tracing::debug!("Client disconnected, stopping export");
+                            return;
+                        }
+                    }
+
+                    if ends {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Response::new(stream::PageStream::new(receiver)))
+    }
+
+    type ExportBookStream =
+        tokio_stream::Iter<std::vec::IntoIter<Result<rpc::ExportBookResponse, Status>>>;
+
+    async fn export_book(
+        &self,
+        request: Request<rpc::ExportBookRequest>,
+    ) -> Result<Response<Self::ExportBookStream>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let (from, to) = date_range(request.from.as_deref(), request.to.as_deref())?;
+
+        let book = match request.format() {
+            rpc::BookFormats::GnucashXml => {
+                let export = database::GnuCashExport::new()
+                    .with_dates(from, to)
+                    .with_scope(scope);
+                metadata::time_db(export.write(&self.pool))
+                    .await
+                    .map_err(convert::database_status)?
+            }
+            format @ (rpc::BookFormats::Beancount | rpc::BookFormats::Hledger) => {
+                let format = match format {
+                    rpc::BookFormats::Hledger => database::PlainTextFormats::Hledger,
+                    _ => database::PlainTextFormats::Beancount,
+                };
+                let export = database::PlainTextExport::new(format)
+                    .with_dates(from, to)
+                    .with_scope(scope);
+                metadata::time_db(export.write(&self.pool))
+                    .await
+                    .map_err(convert::database_status)?
+            }
+            rpc::BookFormats::Unspecified => {
+                return Err(Status::invalid_argument("format is required"));
+            }
+        };
+
+        let messages: Vec<_> = chunk_lines(&book, BOOK_CHUNK_BYTES)
+            .into_iter()
+            .map(|content| Ok(rpc::ExportBookResponse { content }))
+            .collect();
+
+        Ok(Response::new(tokio_stream::iter(messages)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::ExportService;
+    use tokio_stream::StreamExt;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn export(
+        service: &MyExportService,
+        request: rpc::ExportLedgerRequest,
+    ) -> core::result::Result<Vec<rpc::ExportLedgerResponse>, Status> {
+        service
+            .export_ledger(Request::new(request))
+            .await?
+            .into_inner()
+            .collect::<core::result::Result<Vec<_>, _>>()
+            .await
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn export_streams_each_entity_in_turn(pool: sqlx::SqlitePool) -> Result<()> {
+        let account = database::Accounts::new("Everyday").insert(&pool).await?;
+        let date = |day| chrono::NaiveDate::from_ymd_opt(2025, 5, day).unwrap();
+        for day in [1, 20] {
+            let mut transaction = database::Transactions::new(date(day), "Fresh Market", -5_000);
+            transaction.account_id = Some(account.id);
+            transaction.insert(&pool).await?;
+        }
+        let service = MyExportService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let responses = export(
+            &service,
+            rpc::ExportLedgerRequest {
+                format: rpc::ExportFormats::JsonLines as i32,
+                from: Some("2025-05-10".to_string()),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        let entities: Vec<rpc::ExportEntities> =
+            responses.iter().map(|response| response.entity()).collect();
+        assert_eq!(
+            entities,
+            [
+                rpc::ExportEntities::Accounts,
+                rpc::ExportEntities::Transactions
+            ]
+        );
+
+        let transaction: serde_json::Value = serde_json::from_str(responses[1].content.trim_end())?;
+        assert_eq!(transaction["transaction_date"], "2025-05-20");
+        assert_eq!(
+            transaction["account_id"],
+            convert::format_id(&convert::TEST_PUBLIC_IDS, account.id)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn csv_export_starts_with_the_header(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyExportService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let responses = export(
+            &service,
+            rpc::ExportLedgerRequest {
+                entities: vec![rpc::ExportEntities::Categories as i32],
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        assert_eq!(responses.len(), 1);
+        assert!(responses[0].content.starts_with("id,code,name,"));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn gnucash_book_is_sent_in_whole_lines(pool: sqlx::SqlitePool) -> Result<()> {
+        database::Accounts::new("Everyday").insert(&pool).await?;
+        let service = MyExportService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let request = Request::new(rpc::ExportBookRequest {
+            format: rpc::BookFormats::GnucashXml as i32,
+            ..Default::default()
+        });
+        let book: String = service
+            .export_book(request)
+            .await?
+            .into_inner()
+            .map(|response| response.map(|response| response.content))
+            .collect::<core::result::Result<_, _>>()
+            .await?;
+
+        assert!(book.starts_with("<?xml"));
+        assert!(book.contains("<act:name>Everyday</act:name>"));
+        assert!(book.ends_with("</gnc-v2>\n"));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn hledger_book_declares_the_accounts(pool: sqlx::SqlitePool) -> Result<()> {
+        let mut account = database::Accounts::new("Everyday");
+        account.opening_balance = 10_000;
+        account.insert(&pool).await?;
+        let service = MyExportService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let request = Request::new(rpc::ExportBookRequest {
+            format: rpc::BookFormats::Hledger as i32,
+            ..Default::default()
+        });
+        let book: String = service
+            .export_book(request)
+            .await?
+            .into_inner()
+            .map(|response| response.map(|response| response.content))
+            .collect::<core::result::Result<_, _>>()
+            .await?;
+
+        assert!(book.contains("account Assets:Everyday\n"));
+        assert!(book.contains("account Equity:Opening-Balances\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn chunk_lines_keeps_lines_whole() {
+        let chunks = chunk_lines("one\ntwo\nthree\n", 8);
+
+        assert_eq!(chunks, ["one\ntwo\n", "three\n"]);
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn export_rejects_a_reversed_range(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyExportService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let status = export(
+            &service,
+            rpc::ExportLedgerRequest {
+                from: Some("2025-06-01".to_string()),
+                to: Some("2025-05-01".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,627 @@
+//! # REST Gateway
+//!
+//! Serves the categories service as REST/JSON over HTTP/1.1, for browser
+//! frontends and curl users that cannot speak gRPC. Built with the
+//! `rest-gateway` feature and started when `gateway_port` is set in the
+//! `[Server]` section, on the same address as the gRPC server.
+//!
+//! Each route calls the same [`CategoriesService`](rpc::CategoriesService)
+//! implementation as the gRPC server, so validation, quotas and errors are
+//! the same:
+//!
+//! | Route | RPC |
+//! |-------|-----|
+//! | `GET /v1/categories` | `CategoriesList` |
+//! | `POST /v1/categories` | `CategoryCreate` |
+//! | `GET /v1/categories/{id}` | `CategoryGet` |
+//! | `PUT /v1/categories/{id}` | `CategoryUpdate` |
+//! | `DELETE /v1/categories/{id}` | `CategoryDelete` |
+//!
+//! Categories are JSON objects with the fields of the proto message, the
+//! category type by name, e.g. `"expense"`, and timestamps in RFC 3339.
+//! Descriptions are markdown, and are also answered rendered to sanitised
+//! HTML in `description_html`, see [`Markdown`](domain::Markdown).
+//! Listing takes `limit`, `page_token`, `name_contains` and `is_active` query
+//! parameters, and deleting takes `soft=true` to soft-delete. A failed
+//! request is answered with the HTTP status matching its gRPC status code,
+//! and a body of `{"code": "<gRPC code>", "message": "..."}`.
+//!
+//! ```text
+//! curl http://127.0.0.1:8080/v1/categories?limit=10
+//! curl -X POST http://127.0.0.1:8080/v1/categories \
+//!     -d '{"code": "EXP.001", "name": "Groceries", "category_type": "expense"}' \
+//!     -H 'content-type: application/json'
+//! ```
+//!
+//! The routes do not pass through the gRPC server's tower layers, so the
+//! gateway checks [sandbox mode](crate::sandbox) itself, and authenticates
+//! the `authorization: Bearer <token>` header with the same
+//! [`AuthInterceptor`](rpc::AuthInterceptor) as the gRPC services.
+
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use tonic::server::NamedService;
+use tonic::service::Interceptor;
+use tonic::{Code, Request, Status};
+
+use lib_domain as domain;
+use lib_rpc as rpc;
+use rpc::CategoriesService;
+
+use crate::{categories, sandbox};
+
+/// A category as sent and received by the gateway.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct CategoryJson {
+    /// Ignored when creating or updating.
+    pub id: String,
+    pub code: String,
+    pub name: String,
+
+    /// The markdown description as written.
+    pub description: Option<String>,
+
+    /// The description rendered to sanitised HTML. Set by the server,
+    /// ignored when creating or updating.
+    pub description_html: Option<String>,
+    pub url_slug: Option<String>,
+
+    /// The category type by name, e.g. `"expense"`.
+    pub category_type: String,
+    pub color: Option<String>,
+    pub icon: Option<String>,
+    pub is_active: bool,
+    pub parent_id: Option<String>,
+
+    /// Set by the server, ignored when creating or updating.
+    pub created_on: Option<String>,
+    pub updated_on: Option<String>,
+    pub deleted_on: Option<String>,
+}
+
+impl Default for CategoryJson {
+    /// An empty, active category, so `is_active` may be left out.
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            code: String::new(),
+            name: String::new(),
+            description: None,
+            description_html: None,
+            url_slug: None,
+            category_type: String::new(),
+            color: None,
+            icon: None,
+            is_active: true,
+            parent_id: None,
+            created_on: None,
+            updated_on: None,
+            deleted_on: None,
+        }
+    }
+}
+
+/// A page of categories, as listed by the gateway.
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CategoriesPageJson {
+    pub categories: Vec<CategoryJson>,
+    pub total_count: i32,
+
+    /// Token of the next page, empty on the last page.
+    pub next_page_token: String,
+}
+
+/// Query parameters of `GET /v1/categories`.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+struct ListQuery {
+    limit: i32,
+    page_token: String,
+    name_contains: Option<String>,
+    is_active: Option<bool>,
+}
+
+/// Query parameters of `DELETE /v1/categories/{id}`.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+struct DeleteQuery {
+    soft: bool,
+    reassign_to: Option<String>,
+}
+
+/// The body of a failed request.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ErrorJson {
+    /// The gRPC status code, e.g. `"NotFound"`.
+    pub code: String,
+    pub message: String,
+}
+
+/// A gRPC status answered as JSON with the matching HTTP status.
+struct GatewayError(Status);
+
+impl From<Status> for GatewayError {
+    fn from(status: Status) -> Self {
+        Self(status)
+    }
+}
+
+impl IntoResponse for GatewayError {
+    fn into_response(self) -> Response {
+        let body = ErrorJson {
+            code: format!("{:?}", self.0.code()),
+            message: self.0.message().to_string(),
+        };
+
+        (http_status(self.0.code()), Json(body)).into_response()
+    }
+}
+
+/// The HTTP status of a gRPC status code, as mapped by Google's API gateways.
+fn http_status(code: Code) -> StatusCode {
+    match code {
+        Code::Ok => StatusCode::OK,
+        Code::InvalidArgument | Code::OutOfRange => StatusCode::BAD_REQUEST,
+        Code::FailedPrecondition => StatusCode::BAD_REQUEST,
+        Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+        Code::PermissionDenied => StatusCode::FORBIDDEN,
+        Code::NotFound => StatusCode::NOT_FOUND,
+        Code::AlreadyExists | Code::Aborted => StatusCode::CONFLICT,
+        Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+        Code::Cancelled => StatusCode::from_u16(499).unwrap_or(StatusCode::BAD_REQUEST),
+        Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+        Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+        Code::Unknown | Code::Internal | Code::DataLoss => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// Formats a timestamp set by the server as RFC 3339.
+fn format_timestamp(timestamp: Option<prost_types::Timestamp>) -> Option<String> {
+    let timestamp = timestamp?;
+    chrono::DateTime::from_timestamp(timestamp.seconds, timestamp.nanos.max(0) as u32)
+        .map(|date_time| date_time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+}
+
+impl CategoryJson {
+    /// The JSON of an RPC category.
+    fn from_rpc(category: rpc::Category) -> Self {
+        let category_type = domain::CategoryTypes::from_rpc_i32(category.category_type)
+            .map(|category_type| category_type.as_str().to_string())
+            .unwrap_or_default();
+
+        Self {
+            id: category.id,
+            code: category.code,
+            name: category.name,
+            description_html: category
+                .description
+                .as_deref()
+                .map(domain::Markdown::sanitised_html),
+            description: category.description,
+            url_slug: category.url_slug,
+            category_type,
+            color: category.color,
+            icon: category.icon,
+            is_active: category.is_active,
+            parent_id: category.parent_id,
+            created_on: format_timestamp(category.created_on),
+            updated_on: format_timestamp(category.updated_on),
+            deleted_on: format_timestamp(category.deleted_on),
+        }
+    }
+
+    /// The RPC category of the client editable fields.
+    fn into_rpc(self) -> Result<rpc::Category, Status> {
+        let category_type = self
+            .category_type
+            .parse::<domain::CategoryTypes>()
+            .map_err(|_| {
+                Status::invalid_argument(format!("Invalid category_type: {}", self.category_type))
+            })?;
+
+        Ok(rpc::Category {
+            code: self.code,
+            name: self.name,
+            description: self.description,
+            url_slug: self.url_slug,
+            category_type: category_type.to_rpc_i32(),
+            color: self.color,
+            icon: self.icon,
+            is_active: self.is_active,
+            parent_id: self.parent_id,
+            ..Default::default()
+        })
+    }
+}
+
+/// What every route shares.
+#[derive(Debug)]
+struct Gateway {
+    categories: categories::MyCategoriesService,
+    sandbox: sandbox::SandboxLayer,
+    auth: rpc::AuthInterceptor,
+}
+
+impl Gateway {
+    /// An empty request for the RPC named `method`, once sandbox mode allows
+    /// it and the caller's bearer token is authenticated as the gRPC server
+    /// would, to [`map`](Request::map) the RPC's request into.
+    fn authorize(&self, headers: &HeaderMap, method: &str) -> Result<Request<()>, Status> {
+        self.sandbox.check(method)?;
+
+        let mut request = Request::new(());
+        if let Some(authorization) = headers
+            .get(rpc::AUTHORIZATION_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+        {
+            request
+                .metadata_mut()
+                .insert(rpc::AUTHORIZATION_HEADER, authorization);
+        }
+        let service = rpc::CategoriesServiceServer::<categories::MyCategoriesService>::NAME;
+        request
+            .extensions_mut()
+            .insert(rpc::RpcMethod::from_path(&format!("/{service}/{method}")));
+
+        self.auth.clone().call(request)
+    }
+}
+
+type GatewayState = State<Arc<Gateway>>;
+
+/// The gateway's routes, calling `categories`, refusing admin RPCs as
+/// `sandbox` does and authenticating callers with `auth`.
+pub fn router(
+    categories: categories::MyCategoriesService,
+    sandbox: sandbox::SandboxLayer,
+    auth: rpc::AuthInterceptor,
+) -> axum::Router {
+    axum::Router::new()
+        .route(
+            "/v1/categories",
+            axum::routing::get(list_categories).post(create_category),
+        )
+        .route(
+            "/v1/categories/{id}",
+            axum::routing::get(get_category)
+                .put(update_category)
+                .delete(delete_category),
+        )
+        .with_state(Arc::new(Gateway {
+            categories,
+            sandbox,
+            auth,
+        }))
+}
+
+/// Serves the gateway on `listener` until the task is dropped.
+pub async fn serve(listener: tokio::net::TcpListener, router: axum::Router) {
+    if let Ok(local_addr) = listener.local_addr() {
+        tracing::info!("REST gateway listening on {local_addr}");
+    }
+
+    if let Err(error) = axum::serve(listener, router).await {
+        tracing::error!("REST gateway error: {error}");
+    }
+}
+
+async fn list_categories(
+    State(gateway): GatewayState,
+    headers: HeaderMap,
+    Query(query): Query<ListQuery>,
+) -> Result<Json<CategoriesPageJson>, GatewayError> {
+    let caller = gateway.authorize(&headers, "CategoriesList")?;
+    let request = rpc::CategoriesListRequest {
+        limit: query.limit,
+        page_token: query.page_token,
+        name_contains: query.name_contains,
+        is_active: query.is_active,
+        ..Default::default()
+    };
+
+    let response = gateway
+        .categories
+        .categories_list(caller.map(|()| request))
+        .await?
+        .into_inner();
+
+    Ok(Json(CategoriesPageJson {
+        categories: response
+            .categories
+            .into_iter()
+            .map(CategoryJson::from_rpc)
+            .collect(),
+        total_count: response.total_count,
+        next_page_token: response.next_page_token,
+    }))
+}
+
+async fn create_category(
+    State(gateway): GatewayState,
+    headers: HeaderMap,
+    Json(category): Json<CategoryJson>,
+) -> Result<(StatusCode, Json<CategoryJson>), GatewayError> {
+    let caller = gateway.authorize(&headers, "CategoryCreate")?;
+    let request = rpc::CategoryCreateRequest {
+        category: Some(category.into_rpc()?),
+        validate_only: false,
+    };
+
+    let response = gateway
+        .categories
+        .category_create(caller.map(|()| request))
+        .await?
+        .into_inner();
+    let created = response
+        .category
+        .map(CategoryJson::from_rpc)
+        .unwrap_or_default();
+
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+async fn get_category(
+    State(gateway): GatewayState,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+) -> Result<Json<CategoryJson>, GatewayError> {
+    let caller = gateway.authorize(&headers, "CategoryGet")?;
+    let request = rpc::CategoryGetRequest { id };
+
+    let response = gateway
+        .categories
+        .category_get(caller.map(|()| request))
+        .await?
+        .into_inner();
+
+    Ok(Json(
+        response
+            .category
+            .map(CategoryJson::from_rpc)
+            .unwrap_or_default(),
+    ))
+}
+
+async fn update_category(
+    State(gateway): GatewayState,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Json(category): Json<CategoryJson>,
+) -> Result<Json<CategoryJson>, GatewayError> {
+    let caller = gateway.authorize(&headers, "CategoryUpdate")?;
+    let request = rpc::CategoryUpdateRequest {
+        id,
+        category: Some(category.into_rpc()?),
+        ..Default::default()
+    };
+
+    let response = gateway
+        .categories
+        .category_update(caller.map(|()| request))
+        .await?
+        .into_inner();
+
+    Ok(Json(
+        response
+            .category
+            .map(CategoryJson::from_rpc)
+            .unwrap_or_default(),
+    ))
+}
+
+async fn delete_category(
+    State(gateway): GatewayState,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Query(query): Query<DeleteQuery>,
+) -> Result<StatusCode, GatewayError> {
+    let caller = gateway.authorize(&headers, "CategoryDelete")?;
+    let request = rpc::CategoryDeleteRequest {
+        id,
+        soft: query.soft,
+        validate_only: false,
+        reassign_to_id: query.reassign_to,
+    };
+
+    gateway
+        .categories
+        .category_delete(caller.map(|()| request))
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::ServiceExt;
+
+    use axum::body::Body;
+    use axum::http;
+
+    use crate::convert;
+    use lib_database as database;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// Auth trusting every caller as an admin, as with authentication off.
+    fn trusting() -> rpc::AuthInterceptor {
+        rpc::AuthInterceptor::new().with_anonymous(rpc::Principal {
+            subject: "anonymous".to_string(),
+            role: rpc::Role::Admin,
+        })
+    }
+
+    /// Sends `body` to the gateway and returns the status and JSON answered.
+    async fn send(
+        router: &axum::Router,
+        method: &str,
+        uri: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<(StatusCode, serde_json::Value)> {
+        let request = http::Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(
+                body.map(|body| Body::from(body.to_string()))
+                    .unwrap_or_default(),
+            )?;
+
+        let response = router.clone().oneshot(request).await?;
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        let json = if bytes.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&bytes)?
+        };
+
+        Ok((status, json))
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn routes_map_to_the_categories_service(pool: sqlx::SqlitePool) -> Result<()> {
+        let router = router(
+            categories::MyCategoriesService::new(
+                pool,
+                convert::TEST_PUBLIC_IDS,
+                database::EventBus::new(),
+            ),
+            sandbox::SandboxLayer::default(),
+            trusting(),
+        );
+
+        let groceries = serde_json::json!({
+            "code": "EXP.001",
+            "name": "Groceries",
+            "description": "The *weekly* shop",
+            "category_type": "expense"
+        });
+        let (status, created) = send(&router, "POST", "/v1/categories", Some(groceries)).await?;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["category_type"], "expense");
+        assert_eq!(created["is_active"], true);
+        assert_eq!(created["description"], "The *weekly* shop");
+        assert_eq!(
+            created["description_html"],
+            "<p>The <em>weekly</em> shop</p>\n"
+        );
+        let id = created["id"].as_str().expect("an id").to_string();
+
+        let (status, listed) = send(&router, "GET", "/v1/categories?limit=10", None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(listed["categories"][0]["id"], id.as_str());
+
+        let renamed =
+            serde_json::json!({"code": "EXP.001", "name": "Food", "category_type": "expense"});
+        let (status, updated) = send(
+            &router,
+            "PUT",
+            &format!("/v1/categories/{id}"),
+            Some(renamed),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(updated["name"], "Food");
+
+        let (status, _) = send(&router, "DELETE", &format!("/v1/categories/{id}"), None).await?;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, error) = send(&router, "GET", &format!("/v1/categories/{id}"), None).await?;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(error["code"], "NotFound");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn invalid_category_is_a_bad_request(pool: sqlx::SqlitePool) -> Result<()> {
+        let router = router(
+            categories::MyCategoriesService::new(
+                pool,
+                convert::TEST_PUBLIC_IDS,
+                database::EventBus::new(),
+            ),
+            sandbox::SandboxLayer::default(),
+            trusting(),
+        );
+
+        let widget =
+            serde_json::json!({"code": "W.001", "name": "Widgets", "category_type": "widget"});
+        let (status, error) = send(&router, "POST", "/v1/categories", Some(widget)).await?;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(error["code"], "InvalidArgument");
+
+        let scripted = serde_json::json!({
+            "code": "W.001",
+            "name": "Widgets",
+            "description": "<script>alert(1)</script>",
+            "category_type": "expense"
+        });
+        let (status, _) = send(&router, "POST", "/v1/categories", Some(scripted)).await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn sandbox_refuses_deletes(pool: sqlx::SqlitePool) -> Result<()> {
+        let router = router(
+            categories::MyCategoriesService::new(
+                pool,
+                convert::TEST_PUBLIC_IDS,
+                database::EventBus::new(),
+            ),
+            sandbox::SandboxLayer::new(true),
+            trusting(),
+        );
+
+        let (status, error) = send(&router, "DELETE", "/v1/categories/anything", None).await?;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(error["code"], "PermissionDenied");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn bearer_tokens_are_authenticated(pool: sqlx::SqlitePool) -> Result<()> {
+        let router = router(
+            categories::MyCategoriesService::new(
+                pool,
+                convert::TEST_PUBLIC_IDS,
+                database::EventBus::new(),
+            ),
+            sandbox::SandboxLayer::default(),
+            rpc::AuthInterceptor::new().with_api_key("key-one", "sam", rpc::Role::ReadOnly),
+        );
+
+        let (status, error) = send(&router, "GET", "/v1/categories", None).await?;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(error["code"], "Unauthenticated");
+
+        let authorized = |method: &str| {
+            http::Request::builder()
+                .method(method)
+                .uri("/v1/categories")
+                .header("authorization", "Bearer key-one")
+                .header("content-type", "application/json")
+                .body(Body::from("{}"))
+        };
+        let listed = router.clone().oneshot(authorized("GET")?).await?;
+        assert_eq!(listed.status(), StatusCode::OK);
+        let created = router.clone().oneshot(authorized("POST")?).await?;
+        assert_eq!(created.status(), StatusCode::FORBIDDEN);
+
+        Ok(())
+    }
+}
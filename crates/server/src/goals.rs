@@ -0,0 +1,624 @@
+//! # Goals Service
+//!
+//! gRPC handlers for the `GoalsService`, backed by the lib-database
+//! [`Goals`](database::Goals) model.
+//!
+//! Progress is measured in the database from the balance of a goal's
+//! account, or the posted spending in its category and subcategories, on the
+//! date asked for.
+
+use tonic::{Request, Response, Status};
+
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{auth, convert, metadata};
+
+/// Server implementation of the `GoalsService`.
+#[derive(Debug, Clone)]
+pub struct MyGoalsService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+    events: database::EventBus,
+}
+
+impl MyGoalsService {
+    /// Creates the service using the given database pool, public ID key and
+    /// event bus.
+    pub fn new(
+        pool: sqlx::SqlitePool,
+        public_ids: domain::PublicIdKey,
+        events: database::EventBus,
+    ) -> Self {
+        Self {
+            pool,
+            public_ids,
+            events,
+        }
+    }
+}
+
+/// Maps a database error to the gRPC status returned to the client.
+fn database_status(error: database::DatabaseError) -> Status {
+    if convert::is_foreign_key_violation(&error) {
+        return convert::foreign_key_status(
+            rpc::ErrorReason::MissingReference,
+            "goal",
+            "Goal account or category does not exist",
+        );
+    }
+
+    convert::database_status(error)
+}
+
+/// Converts a database goal into its RPC message.
+fn to_rpc(public_ids: &domain::PublicIdKey, goal: database::Goals) -> rpc::Goal {
+    rpc::Goal {
+        id: convert::format_id(public_ids, goal.id),
+        name: goal.name,
+        target_amount: goal.target_amount,
+        target_date: goal.target_date.map(convert::format_date),
+        account_id: goal.account_id.map(|id| convert::format_id(public_ids, id)),
+        category_id: goal
+            .category_id
+            .map(|id| convert::format_id(public_ids, id)),
+        created_on: Some(convert::to_timestamp(goal.created_on)),
+        updated_on: Some(convert::to_timestamp(goal.updated_on)),
+    }
+}
+
+/// Converts a goal's progress into its RPC message.
+fn progress_to_rpc(
+    public_ids: &domain::PublicIdKey,
+    progress: database::GoalProgress,
+) -> rpc::GoalProgress {
+    rpc::GoalProgress {
+        on: convert::format_date(progress.on),
+        saved: progress.saved,
+        remaining: progress.remaining(),
+        percent_complete: progress.percent_complete(),
+        is_reached: progress.is_reached(),
+        monthly_required: progress.monthly_required(),
+        goal: Some(to_rpc(public_ids, progress.goal)),
+    }
+}
+
+/// The target date and links of an RPC goal, parsed before any database
+/// work starts.
+struct ParsedGoal {
+    target_date: Option<chrono::NaiveDate>,
+    account_id: Option<lib_domain::RowID>,
+    category_id: Option<lib_domain::RowID>,
+}
+
+/// Parses the target date and links of an RPC goal.
+fn parse_rpc(public_ids: &domain::PublicIdKey, message: &rpc::Goal) -> Result<ParsedGoal, Status> {
+    Ok(ParsedGoal {
+        target_date: message
+            .target_date
+            .as_deref()
+            .map(|date| convert::parse_date("goal.target_date", date))
+            .transpose()?,
+        account_id: message
+            .account_id
+            .as_deref()
+            .map(|id| convert::parse_id(public_ids, "goal.account_id", id))
+            .transpose()?,
+        category_id: message
+            .category_id
+            .as_deref()
+            .map(|id| convert::parse_id(public_ids, "goal.category_id", id))
+            .transpose()?,
+    })
+}
+
+/// Checks that a goal's account and category are ones the caller can see, so
+/// a goal never tracks another user's money. A link outside the scope is
+/// reported as missing, as one that does not exist is.
+async fn ensure_links(
+    pool: &sqlx::SqlitePool,
+    parsed: &ParsedGoal,
+    scope: database::UserScope,
+) -> Result<(), Status> {
+    let missing = || {
+        convert::foreign_key_status(
+            rpc::ErrorReason::MissingReference,
+            "goal",
+            "Goal account or category does not exist",
+        )
+    };
+
+    if let Some(account_id) = parsed.account_id {
+        metadata::time_db(database::Accounts::find_by_id(account_id, scope, pool))
+            .await
+            .map_err(database_status)?
+            .ok_or_else(missing)?;
+    }
+    if let Some(category_id) = parsed.category_id {
+        metadata::time_db(database::Categories::find_by_id(category_id, scope, pool))
+            .await
+            .map_err(database_status)?
+            .ok_or_else(missing)?;
+    }
+
+    Ok(())
+}
+
+/// Applies the client editable fields of an RPC goal to a database goal.
+/// The id and timestamps are left untouched.
+fn apply_rpc(mut goal: database::Goals, message: rpc::Goal, parsed: ParsedGoal) -> database::Goals {
+    goal.name = message.name;
+    goal.target_amount = message.target_amount;
+    goal.target_date = parsed.target_date;
+    goal.account_id = parsed.account_id;
+    goal.category_id = parsed.category_id;
+
+    goal
+}
+
+#[tonic::async_trait]
+impl rpc::GoalsService for MyGoalsService {
+    async fn goal_create(
+        &self,
+        request: Request<rpc::GoalCreateRequest>,
+    ) -> Result<Response<rpc::GoalCreateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let message = request
+            .goal
+            .ok_or_else(|| Status::invalid_argument("Missing goal"))?;
+
+        let parsed = parse_rpc(&self.public_ids, &message)?;
+        ensure_links(&self.pool, &parsed, scope).await?;
+        let goal = apply_rpc(
+            database::Goals::new(message.name.clone(), message.target_amount),
+            message,
+            parsed,
+        );
+
+        let created = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| goal.insert(conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::GoalCreateResponse {
+            goal: Some(to_rpc(&self.public_ids, created)),
+        }))
+    }
+
+    async fn goal_get(
+        &self,
+        request: Request<rpc::GoalGetRequest>,
+    ) -> Result<Response<rpc::GoalGetResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let public_id = request.into_inner().id;
+        let id = convert::parse_id(&self.public_ids, "id", &public_id)?;
+
+        let goal = metadata::time_db(database::Goals::find_by_id(id, scope, &self.pool))
+            .await
+            .map_err(database_status)?
+            .ok_or_else(|| {
+                convert::not_found("goal", "id", format!("Goal with id {public_id} not found"))
+            })?;
+
+        Ok(Response::new(rpc::GoalGetResponse {
+            goal: Some(to_rpc(&self.public_ids, goal)),
+        }))
+    }
+
+    async fn goals_list(
+        &self,
+        request: Request<rpc::GoalsListRequest>,
+    ) -> Result<Response<rpc::GoalsListResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let goals = metadata::time_db(database::Goals::find_all(scope, &self.pool))
+            .await
+            .map_err(database_status)?;
+
+        Ok(Response::new(rpc::GoalsListResponse {
+            goals: goals
+                .into_iter()
+                .map(|goal| to_rpc(&self.public_ids, goal))
+                .collect(),
+        }))
+    }
+
+    async fn goal_update(
+        &self,
+        request: Request<rpc::GoalUpdateRequest>,
+    ) -> Result<Response<rpc::GoalUpdateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        let message = request
+            .goal
+            .ok_or_else(|| Status::invalid_argument("Missing goal"))?;
+
+        let parsed = parse_rpc(&self.public_ids, &message)?;
+        ensure_links(&self.pool, &parsed, scope).await?;
+
+        let updated = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                let existing = database::Goals::find_by_id(id, scope, &mut *conn)
+                    .await?
+                    .ok_or_else(|| {
+                        database::DatabaseError::NotFound(format!(
+                            "Goal with id {} not found",
+                            request.id
+                        ))
+                    })?;
+
+                apply_rpc(existing, message, parsed)
+                    .update(scope, conn)
+                    .await
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::GoalUpdateResponse {
+            goal: Some(to_rpc(&self.public_ids, updated)),
+        }))
+    }
+
+    async fn goal_delete(
+        &self,
+        request: Request<rpc::GoalDeleteRequest>,
+    ) -> Result<Response<rpc::GoalDeleteResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+
+        metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Goals::delete_by_id(id, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::GoalDeleteResponse { rows_deleted: 1 }))
+    }
+
+    async fn goals_progress(
+        &self,
+        request: Request<rpc::GoalsProgressRequest>,
+    ) -> Result<Response<rpc::GoalsProgressResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let on = match request.on.as_deref() {
+            Some(on) => convert::parse_date("on", on)?,
+            None => chrono::Utc::now().date_naive(),
+        };
+
+        let progress = metadata::time_db(async {
+            let mut conn = self.pool.acquire().await?;
+            let mut progress = Vec::new();
+            for goal in database::Goals::find_all(scope, &mut *conn).await? {
+                progress.push(goal.progress(on, &mut *conn).await?);
+            }
+
+            Ok::<_, database::DatabaseError>(progress)
+        })
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::GoalsProgressResponse {
+            progress: progress
+                .into_iter()
+                .map(|progress| progress_to_rpc(&self.public_ids, progress))
+                .collect(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::{AccountsService, GoalsService, TransactionsService};
+
+    use crate::accounts::MyAccountsService;
+    use crate::auth::as_user;
+    use crate::transactions::MyTransactionsService;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn account(pool: &sqlx::SqlitePool, opening_balance: i64) -> Result<String> {
+        let request = Request::new(rpc::AccountCreateRequest {
+            account: Some(rpc::Account {
+                name: "Savings".to_string(),
+                opening_balance,
+                is_active: true,
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+        let response = MyAccountsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .account_create(request)
+        .await?;
+
+        Ok(response.into_inner().account.unwrap().id)
+    }
+
+    async fn create(
+        service: &MyGoalsService,
+        goal: rpc::Goal,
+    ) -> core::result::Result<rpc::Goal, Status> {
+        let request = Request::new(rpc::GoalCreateRequest {
+            goal: Some(goal),
+            validate_only: false,
+        });
+
+        Ok(service
+            .goal_create(request)
+            .await?
+            .into_inner()
+            .goal
+            .unwrap())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn progress_measures_the_linked_account(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyGoalsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let account_id = account(&pool, 100_000).await?;
+        let goal = create(
+            &service,
+            rpc::Goal {
+                name: "Emergency fund".to_string(),
+                target_amount: 1_000_000,
+                target_date: Some("2025-12-31".to_string()),
+                account_id: Some(account_id.clone()),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        let request = Request::new(rpc::TransactionCreateRequest {
+            transaction: Some(rpc::Transaction {
+                transaction_date: "2025-03-14".to_string(),
+                payee: "Transfer".to_string(),
+                amount: 150_000,
+                account_id: Some(account_id),
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+        MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .transaction_create(request)
+        .await?;
+
+        let response = service
+            .goals_progress(Request::new(rpc::GoalsProgressRequest {
+                on: Some("2025-07-01".to_string()),
+            }))
+            .await?
+            .into_inner();
+
+        let progress = &response.progress[0];
+        assert_eq!(progress.goal.as_ref().unwrap().id, goal.id);
+        assert_eq!((progress.saved, progress.remaining), (250_000, 750_000));
+        assert_eq!(progress.percent_complete, 25.0);
+        assert!(!progress.is_reached);
+        assert_eq!(progress.monthly_required, Some(125_000));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn create_rejects_bad_input(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyGoalsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let account_id = account(&pool, 0).await?;
+        let goal = rpc::Goal {
+            name: "Holiday".to_string(),
+            target_amount: 300_000,
+            account_id: Some(account_id),
+            ..Default::default()
+        };
+
+        let unlinked = rpc::Goal {
+            account_id: None,
+            ..goal.clone()
+        };
+        let bad_date = rpc::Goal {
+            target_date: Some("soon".to_string()),
+            ..goal.clone()
+        };
+        for goal in [unlinked, bad_date] {
+            let status = create(&service, goal).await.unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        }
+
+        let unknown_category = rpc::Goal {
+            account_id: None,
+            category_id: Some(convert::format_id(
+                &convert::TEST_PUBLIC_IDS,
+                lib_domain::RowID::new(),
+            )),
+            ..goal
+        };
+        let status = create(&service, unknown_category).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn update_and_delete_a_goal(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyGoalsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let account_id = account(&pool, 0).await?;
+        let goal = create(
+            &service,
+            rpc::Goal {
+                name: "Car".to_string(),
+                target_amount: 2_000_000,
+                account_id: Some(account_id),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        let request = Request::new(rpc::GoalUpdateRequest {
+            id: goal.id.clone(),
+            goal: Some(rpc::Goal {
+                target_amount: 2_500_000,
+                target_date: Some("2026-06-30".to_string()),
+                ..goal.clone()
+            }),
+            validate_only: false,
+        });
+        let updated = service
+            .goal_update(request)
+            .await?
+            .into_inner()
+            .goal
+            .unwrap();
+        assert_eq!(updated.target_amount, 2_500_000);
+        assert_eq!(updated.target_date.as_deref(), Some("2026-06-30"));
+
+        let request = Request::new(rpc::GoalDeleteRequest {
+            id: goal.id.clone(),
+            validate_only: false,
+        });
+        service.goal_delete(request).await?;
+
+        let listed = service
+            .goals_list(Request::new(rpc::GoalsListRequest {}))
+            .await?
+            .into_inner();
+        assert!(listed.goals.is_empty());
+        let status = service
+            .goal_get(Request::new(rpc::GoalGetRequest { id: goal.id }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn users_only_see_and_change_goals_on_their_own_accounts(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        database::Users::new("ian").insert(&pool).await?;
+        database::Users::new("sam").insert(&pool).await?;
+        let service = MyGoalsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let account_id = MyAccountsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .account_create(as_user(
+            "ian",
+            Request::new(rpc::AccountCreateRequest {
+                account: Some(rpc::Account {
+                    name: "Savings".to_string(),
+                    is_active: true,
+                    ..Default::default()
+                }),
+                validate_only: false,
+            }),
+        ))
+        .await?
+        .into_inner()
+        .account
+        .unwrap()
+        .id;
+        let create_as = |subject| {
+            as_user(
+                subject,
+                Request::new(rpc::GoalCreateRequest {
+                    goal: Some(rpc::Goal {
+                        name: "Emergency fund".to_string(),
+                        target_amount: 1_000_000,
+                        account_id: Some(account_id.clone()),
+                        ..Default::default()
+                    }),
+                    validate_only: false,
+                }),
+            )
+        };
+
+        let status = service.goal_create(create_as("sam")).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition, "{status:?}");
+        let goal = service
+            .goal_create(create_as("ian"))
+            .await?
+            .into_inner()
+            .goal
+            .unwrap();
+
+        let listed = service
+            .goals_list(as_user("sam", Request::new(Default::default())))
+            .await?
+            .into_inner();
+        assert!(listed.goals.is_empty());
+        let progress = service
+            .goals_progress(as_user("sam", Request::new(Default::default())))
+            .await?
+            .into_inner();
+        assert!(progress.progress.is_empty());
+
+        let status = service
+            .goal_get(as_user(
+                "sam",
+                Request::new(rpc::GoalGetRequest {
+                    id: goal.id.clone(),
+                }),
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        let status = service
+            .goal_delete(as_user(
+                "sam",
+                Request::new(rpc::GoalDeleteRequest {
+                    id: goal.id.clone(),
+                    validate_only: false,
+                }),
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        let listed = service
+            .goals_list(as_user("ian", Request::new(Default::default())))
+            .await?
+            .into_inner();
+        assert_eq!(listed.goals.len(), 1);
+
+        Ok(())
+    }
+}
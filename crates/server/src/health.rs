@@ -0,0 +1,231 @@
+//! # Health Monitor
+//!
+//! Reports the health of each gRPC service over the standard
+//! `grpc.health.v1.Health` service, so Kubernetes probes and
+//! `grpc_health_probe` work without knowing the ledger's protos. Health is
+//! worked out from the database every service is backed by:
+//!
+//! - **Reachable**: the migration table can be read
+//! - **Migrated**: every migration the server was built with is applied, and
+//!   none has been modified since
+//!
+//! The utilities service only needs the database to be reachable, as it is
+//! how clients find out which migrations are pending. Every other service
+//! needs both, and the server as a whole, the empty service name, is serving
+//! only when every service is.
+//!
+//! The monitor checks on every tick of its interval, logging when the health
+//! changes, so a database that goes away takes the services out of rotation
+//! until it comes back.
+
+use lib_database as database;
+use lib_rpc as rpc;
+
+/// Name of the utilities service, which stays up while migrations are pending.
+const UTILITIES_SERVICE: &str = rpc::v001::utilities::utilities_service_server::SERVICE_NAME;
+
+/// How often the database is checked.
+const CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// The state of the database, as the services' health depends on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatabaseHealth {
+    /// Whether the database could be queried.
+    pub reachable: bool,
+
+    /// Whether every migration is applied and unmodified.
+    pub migrated: bool,
+}
+
+impl DatabaseHealth {
+    /// The status of the service named `service`, or the whole server for `""`.
+    pub fn status(&self, service: &str) -> rpc::ServingStatus {
+        let serving = match service {
+            UTILITIES_SERVICE => self.reachable,
+            _ => self.reachable && self.migrated,
+        };
+
+        if serving {
+            rpc::ServingStatus::Serving
+        } else {
+            rpc::ServingStatus::NotServing
+        }
+    }
+}
+
+/// Checks the database and reports the health of every service.
+#[derive(Debug, Clone)]
+pub struct HealthMonitor {
+    pool: sqlx::SqlitePool,
+    reporter: rpc::HealthReporter,
+}
+
+impl HealthMonitor {
+    /// Creates a monitor of the given database pool, reporting to `reporter`
+    /// every ten seconds.
+    pub fn new(pool: sqlx::SqlitePool, reporter: rpc::HealthReporter) -> Self {
+        Self { pool, reporter }
+    }
+
+    /// Checks straight away and then every interval until the task is
+    /// dropped, logging each change in health.
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut last = None;
+
+        loop {
+            // The first tick completes immediately, so this runs on startup
+            interval.tick().await;
+
+            let health = self.run_once().await;
+            if last.is_some_and(|last| last != health) {
+                if health.reachable && health.migrated {
+                    tracing::info!("Database healthy again, every service is serving");
+                } else {
+                    tracing::warn!(
+                        reachable = health.reachable,
+                        migrated = health.migrated,
+                        "Database unhealthy"
+                    );
+                }
+            }
+            last = Some(health);
+        }
+    }
+
+    /// Checks the database once and reports every service's status.
+    pub async fn run_once(&self) -> DatabaseHealth {
+        let health = match database::MigrationStatus::find_all(&self.pool).await {
+            Ok(migrations) => DatabaseHealth {
+                reachable: true,
+                migrated: migrations
+                    .iter()
+                    .all(|migration| migration.is_applied() && !migration.is_modified),
+            },
+            Err(error) => {
+                tracing::debug!("Health check could not read the database: {error}");
+                DatabaseHealth {
+                    reachable: false,
+                    migrated: false,
+                }
+            }
+        };
+
+        for service in std::iter::once("").chain(rpc::LEDGER_SERVICES.iter().copied()) {
+            self.reporter
+                .set_service_status(service, health.status(service))
+                .await;
+        }
+
+        health
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    const CATEGORIES_SERVICE: &str = rpc::v001::categories::categories_service_server::SERVICE_NAME;
+
+    /// The status the health server reports for `service`.
+    async fn check(client: &rpc::LedgerClient, service: &str) -> Result<rpc::HealthCheckStatus> {
+        let request = rpc::HealthCheckRequest {
+            service: service.to_string(),
+        };
+
+        Ok(client.health().check(request).await?.into_inner().status())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn reports_every_service_serving_when_migrated(pool: sqlx::SqlitePool) -> Result<()> {
+        let (reporter, health) = rpc::health_reporter();
+        let (client, _serving) = rpc::serve_in_process(|incoming| {
+            tonic::transport::Server::builder()
+                .add_service(health)
+                .serve_with_incoming(incoming)
+        });
+
+        let checked = HealthMonitor::new(pool, reporter).run_once().await;
+
+        assert_eq!(
+            checked,
+            DatabaseHealth {
+                reachable: true,
+                migrated: true
+            }
+        );
+        assert_eq!(check(&client, "").await?, rpc::HealthCheckStatus::Serving);
+        for service in rpc::LEDGER_SERVICES {
+            assert_eq!(
+                check(&client, service).await?,
+                rpc::HealthCheckStatus::Serving
+            );
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn pending_migrations_leave_only_utilities_serving(pool: sqlx::SqlitePool) -> Result<()> {
+        sqlx::query("DELETE FROM _sqlx_migrations WHERE version = (SELECT MAX(version) FROM _sqlx_migrations)")
+            .execute(&pool)
+            .await?;
+        let (reporter, health) = rpc::health_reporter();
+        let (client, _serving) = rpc::serve_in_process(|incoming| {
+            tonic::transport::Server::builder()
+                .add_service(health)
+                .serve_with_incoming(incoming)
+        });
+
+        let checked = HealthMonitor::new(pool, reporter).run_once().await;
+
+        assert_eq!(
+            checked,
+            DatabaseHealth {
+                reachable: true,
+                migrated: false
+            }
+        );
+        assert_eq!(
+            check(&client, "").await?,
+            rpc::HealthCheckStatus::NotServing
+        );
+        assert_eq!(
+            check(&client, UTILITIES_SERVICE).await?,
+            rpc::HealthCheckStatus::Serving
+        );
+        assert_eq!(
+            check(&client, CATEGORIES_SERVICE).await?,
+            rpc::HealthCheckStatus::NotServing
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn unreachable_database_stops_every_service(pool: sqlx::SqlitePool) -> Result<()> {
+        let (reporter, _health) = rpc::health_reporter();
+        let monitor = HealthMonitor::new(pool.clone(), reporter);
+        pool.close().await;
+
+        let checked = monitor.run_once().await;
+
+        assert_eq!(
+            checked,
+            DatabaseHealth {
+                reachable: false,
+                migrated: false
+            }
+        );
+        assert_eq!(
+            checked.status(UTILITIES_SERVICE),
+            rpc::ServingStatus::NotServing
+        );
+        assert_eq!(checked.status(""), rpc::ServingStatus::NotServing);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+//! # Personal Ledger Server
+//!
+//! The gRPC server for the Personal Ledger, serving the lib-rpc services
+//! from a lib-database SQLite database.
+//!
+//! The server is a library so integration tests can start the same server as
+//! the binary. [`Server`] builds it from a
+//! [`LedgerConfig`](lib_config::LedgerConfig) and runs it until it is shut
+//! down.
+//!
+//! ## Modules
+//!
+//! - [`Server`] - Startup, serving and shutdown of the whole server
+//! - `accounts`, `attachments`, `audit`, `budgets`, `categories`, `export`, `goals`, `payees`,
+//!   `periods`, `reconciliations`, `reports`, `search`, `transactions`, `utilities` - gRPC service
+//!   handlers
+//! - `auth` - Bearer token authentication of every service, from the `[Auth]` config
+//! - `backups` - Scheduled database backups and their retention
+//! - `health` - Per-service health from the database, for the gRPC health service
+//! - `reflection` - gRPC server reflection of the compiled protos
+//! - `recurring` - Scheduler creating transactions from recurring templates
+//! - `notifications` - Bill reminders sent through the log, a webhook or email
+//! - `webhooks` - Signed data events posted to webhooks, with the `webhooks`
+//!   feature
+//! - `receipts` - Receipt email inbox, with the `receipt-email` feature
+//! - `gateway` - REST/JSON gateway to the categories service, with the
+//!   `rest-gateway` feature
+//! - `sandbox` - Public demo mode: demo data reset on a schedule, admin RPCs refused
+//! - `metadata` - Response metadata tower layer
+//! - `quota` - Soft limits on what the ledger holds, checked by the handlers
+//! - [`shutdown_signal`] - Waiting for `SIGINT` or `SIGTERM` to shut down
+
+mod accounts;
+mod app;
+mod attachments;
+mod audit;
+mod auth;
+mod backups;
+mod budgets;
+mod categories;
+mod convert;
+mod error;
+mod export;
+#[cfg(feature = "rest-gateway")]
+mod gateway;
+mod goals;
+mod health;
+mod metadata;
+mod notifications;
+mod payees;
+mod periods;
+mod quota;
+#[cfg(feature = "receipt-email")]
+pub mod receipts;
+mod reconciliations;
+mod recurring;
+mod reflection;
+mod reports;
+mod sandbox;
+mod search;
+mod signal;
+mod stream;
+mod transactions;
+mod utilities;
+mod versions;
+#[cfg(feature = "webhooks")]
+mod webhooks;
+
+/// The server application, its builder and shutdown handle.
+pub use app::{Server, ServerBuilder, ShutdownHandle};
+
+/// Completes when the operating system asks the server to stop.
+pub use signal::shutdown_signal;
+
+/// Server startup and run errors.
+pub use error::{ServerError, ServerResult};
+
+/// Metadata key naming the actor of a request.
+pub use audit::ACTOR_HEADER;
+
+/// Metadata keys marking a response from a deprecated API version.
+pub use versions::{DEPRECATION_HEADER, SUNSET_HEADER};
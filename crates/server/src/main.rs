@@ -1,58 +1,56 @@
-#![allow(dead_code)]
-#![allow(unused_imports)]
-
-use tonic::{transport::Server, Request, Response, Status};
-
-use clap::{Arg, command};
-
-use lib_rpc::{UtilitiesService, UtilitiesServiceServer, PingRequest, PingResponse};
-use lib_telemetry as telemetry;
+use clap::Parser;
 use lib_config as config;
-
-#[derive(Default)]
-pub struct MyUtilitiesService {}
-
-#[tonic::async_trait]
-impl UtilitiesService for MyUtilitiesService {
-    async fn ping(
-        &self,
-        request: Request<PingRequest>,
-    ) -> Result<Response<PingResponse>, Status> {
-        println!("Got a request from {:?}", request.remote_addr());
-
-        let reply: PingResponse = PingResponse {
-            message: "Pong...".to_string(),
-        };
-
-        Ok(Response::new(reply)) // Send back ping response
-    }
+use lib_telemetry as telemetry;
+use server::Server;
+
+/// The Personal Ledger gRPC server.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    /// Run as the backend of a desktop app: keep the database in the user's
+    /// data directory, listen on a Unix socket trusting the local user and
+    /// back up automatically
+    #[arg(long)]
+    standalone: bool,
+
+    /// Fill a ledger that has no transactions yet with a few months of demo
+    /// transactions, to try the server out
+    #[arg(long)]
+    seed_demo: bool,
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
 
-    let config = config::LedgerConfig::parse(None)?;
-
-    let telemetry_level = Some(&config.telemetry_config().telemetry_level());
-    telemetry::init(telemetry_level)?;
-    tracing::info!("Starting server with config: {:#?}", config);
-
-    // let matched_results = command!().arg(
-    //     Arg::new("firstname")
-    // ).get_matches();
-
-    // let addr = "0.0.0.0:50051".parse().unwrap();
-    // let utility_server = MyUtilitiesService::default();
-
-    // let tracing_level = Some(telemetry::TelemetryLevels::DEBUG);
-    // telemetry::init(tracing_level.as_ref())?;
-
-    // tracing::info!("UtilitiesServiceServer listening on {addr}");
-
-    // Server::builder()
-    //     .add_service(UtilitiesServiceServer::new(utility_server))
-    //     .serve(addr)
-    //     .await?;
+    let mut config = config::LedgerConfig::parse(None)?;
+    if cli.standalone {
+        config = config.standalone()?;
+    }
 
+    let server = Server::builder(config)
+        .with_seed_demo(cli.seed_demo)
+        .build()
+        .await?;
+    tracing::info!("Starting server with config: {:#?}", server.config());
+
+    // Finish requests in flight and close the database on SIGINT or SIGTERM
+    let shutdown = server.shutdown_handle();
+    tokio::spawn(async move {
+        match server::shutdown_signal().await {
+            Ok(signal) => {
+                tracing::info!("Shutdown signal received: {signal}");
+                shutdown.shutdown();
+            }
+            Err(error) => tracing::error!("Could not listen for the shutdown signal: {error}"),
+        }
+    });
+
+    let served = server.run().await;
+
+    // Send the spans still waiting to be exported before the process exits
+    telemetry::shutdown();
+
+    served?;
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,263 @@
+//! # Response Metadata
+//!
+//! Tower layer that adds debugging metadata to every RPC response:
+//!
+//! - `x-request-id`: the request ID sent by the client, or a new UUID v7 when
+//!   the client did not send one
+//! - `server-timing`: total server processing time and time spent in the
+//!   database, in milliseconds, using the HTTP `Server-Timing` format, e.g.
+//!   `total;dur=12.804, db;dur=3.117`
+//!
+//! The layer is enabled by the `telemetry.response_metadata` configuration
+//! flag. When disabled it passes requests straight through.
+//!
+//! Database time is collected per request. Handlers wrap database calls in
+//! [`time_db`] and the elapsed time is added to the request's total.
+
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use tonic::codegen::http;
+use tracing::Instrument;
+
+/// Metadata key used to send and echo the request ID.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Metadata key carrying the server and database timings.
+pub const SERVER_TIMING_HEADER: &str = "server-timing";
+
+tokio::task_local! {
+    /// Database time accumulated by [`time_db`] for the request being handled.
+    static DB_TIME: Cell<Duration>;
+}
+
+/// Runs a database future and adds its elapsed time to the current request's
+/// database time.
+///
+/// Outside a request handled by [`ResponseMetadata`] (or when the layer is
+/// disabled) the future is simply awaited.
+pub async fn time_db<F: Future>(future: F) -> F::Output {
+    let started = Instant::now();
+    let output = future.await;
+    let elapsed = started.elapsed();
+
+    // Not inside a metadata scope, nothing to record
+    let _ = DB_TIME.try_with(|db_time| db_time.set(db_time.get() + elapsed));
+
+    output
+}
+
+/// Formats the `server-timing` value in milliseconds.
+fn server_timing(total: Duration, db: Duration) -> String {
+    format!(
+        "total;dur={:.3}, db;dur={:.3}",
+        total.as_secs_f64() * 1_000.0,
+        db.as_secs_f64() * 1_000.0
+    )
+}
+
+/// Layer that wraps services in [`ResponseMetadata`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResponseMetadataLayer {
+    enabled: bool,
+}
+
+impl ResponseMetadataLayer {
+    /// Creates the layer, adding metadata only when `enabled` is true.
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+impl<S> tower::Layer<S> for ResponseMetadataLayer {
+    type Service = ResponseMetadata<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ResponseMetadata {
+            inner,
+            enabled: self.enabled,
+        }
+    }
+}
+
+/// Service that echoes the request ID and reports server timing metadata.
+#[derive(Debug, Clone)]
+pub struct ResponseMetadata<S> {
+    inner: S,
+    enabled: bool,
+}
+
+impl<S, ReqBody, ResBody> tower::Service<http::Request<ReqBody>> for ResponseMetadata<S>
+where
+    S: tower::Service<http::Request<ReqBody>, Response = http::Response<ResBody>>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        // Take the service that was driven to readiness and leave a clone behind
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        if !self.enabled {
+            return Box::pin(inner.call(request));
+        }
+
+        let request_id = request
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| uuid::Uuid::now_v7().to_string());
+
+        let span =
+            tracing::info_span!("rpc", request_id = %request_id, path = %request.uri().path());
+
+        Box::pin(
+            async move {
+                let started = Instant::now();
+
+                let (result, db_time) = DB_TIME
+                    .scope(Cell::new(Duration::ZERO), async move {
+                        let result = inner.call(request).await;
+                        (result, DB_TIME.with(Cell::get))
+                    })
+                    .await;
+
+                let mut response = result?;
+                let total_time = started.elapsed();
+
+                tracing::debug!(?total_time, ?db_time, "RPC handled");
+
+                let headers = response.headers_mut();
+                if let Ok(value) = http::HeaderValue::from_str(&request_id) {
+                    headers.insert(REQUEST_ID_HEADER, value);
+                }
+                if let Ok(value) = http::HeaderValue::from_str(&server_timing(total_time, db_time))
+                {
+                    headers.insert(SERVER_TIMING_HEADER, value);
+                }
+
+                Ok(response)
+            }
+            .instrument(span),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::{Layer, ServiceExt};
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    type Handler = tower::util::BoxCloneService<
+        http::Request<()>,
+        http::Response<()>,
+        std::convert::Infallible,
+    >;
+
+    /// Service standing in for a tonic handler that spends `db` in the database.
+    fn handler(db: Duration) -> Handler {
+        Handler::new(tower::service_fn(
+            move |_request: http::Request<()>| async move {
+                time_db(tokio::time::sleep(db)).await;
+                Ok(http::Response::new(()))
+            },
+        ))
+    }
+
+    /// Parses `total;dur=X, db;dur=Y` into `(X, Y)`.
+    fn parse_timing(value: &str) -> (f64, f64) {
+        let durations: Vec<f64> = value
+            .split(", ")
+            .map(|part| part.split_once(";dur=").unwrap().1.parse().unwrap())
+            .collect();
+        (durations[0], durations[1])
+    }
+
+    #[tokio::test]
+    async fn disabled_layer_adds_no_metadata() -> Result<()> {
+        let service = ResponseMetadataLayer::new(false).layer(handler(Duration::ZERO));
+
+        let response = service.oneshot(http::Request::new(())).await?;
+
+        assert!(response.headers().get(REQUEST_ID_HEADER).is_none());
+        assert!(response.headers().get(SERVER_TIMING_HEADER).is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn echoes_client_request_id() -> Result<()> {
+        let service = ResponseMetadataLayer::new(true).layer(handler(Duration::ZERO));
+        let request = http::Request::builder()
+            .header(REQUEST_ID_HEADER, "client-request-42")
+            .body(())?;
+
+        let response = service.oneshot(request).await?;
+
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "client-request-42");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn generates_request_id_when_missing() -> Result<()> {
+        let service = ResponseMetadataLayer::new(true).layer(handler(Duration::ZERO));
+
+        let response = service.oneshot(http::Request::new(())).await?;
+
+        let request_id = response.headers()[REQUEST_ID_HEADER].to_str()?;
+        assert!(uuid::Uuid::parse_str(request_id).is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_server_and_database_time() -> Result<()> {
+        let service = ResponseMetadataLayer::new(true).layer(handler(Duration::from_millis(20)));
+
+        let response = service.oneshot(http::Request::new(())).await?;
+
+        let (total, db) = parse_timing(response.headers()[SERVER_TIMING_HEADER].to_str()?);
+        assert!(
+            db >= 20.0,
+            "db time {db} should include the handler's database call"
+        );
+        assert!(
+            total >= db,
+            "total time {total} should include db time {db}"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn time_db_outside_request_is_passthrough() {
+        assert_eq!(time_db(async { 42 }).await, 42);
+    }
+
+    #[test]
+    fn server_timing_formats_milliseconds() {
+        assert_eq!(
+            server_timing(Duration::from_micros(12_804), Duration::from_micros(3_117)),
+            "total;dur=12.804, db;dur=3.117"
+        );
+    }
+}
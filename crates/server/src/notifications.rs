@@ -0,0 +1,472 @@
+//! # Bill Reminder Notifications
+//!
+//! Reminds about recurring transactions coming up within the configured
+//! number of days ([`RecurringTransactions::find_reminders`](database::RecurringTransactions::find_reminders)),
+//! sending each reminder through every channel set up in the
+//! [`NotificationsConfig`]:
+//!
+//! - the server log
+//! - a webhook the reminder is posted to as JSON, with the
+//!   `webhook-notifications` feature
+//! - email sent over SMTP, with the `email-notifications` feature
+//!
+//! The scheduler runs once as soon as it starts and then on every tick of its
+//! interval. A reminder is marked sent once every channel has taken it, so a
+//! webhook or mail server that is down is tried again on the next run, and
+//! each occurrence is only reminded about once after that.
+
+use lib_config::NotificationsConfig;
+use lib_database as database;
+
+use crate::error::ServerResult;
+
+/// Why a reminder could not be sent through a channel.
+#[derive(thiserror::Error, Debug)]
+pub enum NotificationError {
+    /// The webhook could not be reached or did not accept the reminder.
+    #[cfg(feature = "webhook-notifications")]
+    #[error("Webhook notification error: {0}")]
+    Webhook(String),
+
+    /// The reminder could not be emailed.
+    #[cfg(feature = "email-notifications")]
+    #[error("Email notification error: {0}")]
+    Email(String),
+}
+
+/// A reminder ready to send, worded for people and described for machines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// One line summary, used as the email subject.
+    pub subject: String,
+
+    /// The reminder in full, used as the email body.
+    pub body: String,
+
+    /// The reminder the notification is for.
+    pub reminder: database::BillReminder,
+}
+
+impl Notification {
+    /// Words a notification for a bill reminder.
+    pub fn new(reminder: database::BillReminder) -> Self {
+        let template = &reminder.template;
+        let amount = lib_domain::Money::new(template.amount, lib_domain::Currency::default());
+        let when = match reminder.days_left {
+            0 => "today".to_string(),
+            1 => "tomorrow".to_string(),
+            days => format!("in {days} days"),
+        };
+
+        let subject = format!("{} {amount} due {when}", template.payee);
+        let mut body = format!(
+            "{} of {amount} is due on {}.",
+            template.payee, reminder.due_on
+        );
+        if let Some(description) = &template.description {
+            body.push_str(&format!("\n\n{description}"));
+        }
+        if !template.auto_post {
+            body.push_str("\n\nA draft transaction will be created for it to review.");
+        }
+
+        Self {
+            subject,
+            body,
+            reminder,
+        }
+    }
+
+    /// The notification as the JSON posted to webhooks.
+    #[cfg(feature = "webhook-notifications")]
+    pub fn to_json(&self) -> serde_json::Value {
+        let template = &self.reminder.template;
+
+        serde_json::json!({
+            "event": "bill.reminder",
+            "subject": self.subject,
+            "recurring_id": template.id.to_string(),
+            "payee": template.payee,
+            "amount": template.amount,
+            "due_on": self.reminder.due_on.format("%Y-%m-%d").to_string(),
+            "days_left": self.reminder.days_left,
+        })
+    }
+}
+
+/// Somewhere reminders are sent.
+#[derive(Debug, Clone)]
+pub enum NotificationChannel {
+    /// Written to the server log at info level.
+    Log,
+
+    /// Posted as JSON to a URL.
+    #[cfg(feature = "webhook-notifications")]
+    Webhook {
+        client: reqwest::Client,
+        url: String,
+    },
+
+    /// Emailed over SMTP.
+    #[cfg(feature = "email-notifications")]
+    Email {
+        mailer: Box<lettre::AsyncSmtpTransport<lettre::Tokio1Executor>>,
+        from: lettre::message::Mailbox,
+        to: lettre::message::Mailbox,
+    },
+}
+
+impl NotificationChannel {
+    /// Builds every channel set up in the configuration. Channels whose
+    /// feature the server was built without are warned about and left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ServerError::Config`](crate::error::ServerError::Config) if the webhook client cannot be built,
+    /// or an email address or the SMTP server is not valid.
+    pub fn from_config(config: &NotificationsConfig) -> ServerResult<Vec<Self>> {
+        let mut channels = Vec::new();
+
+        if config.log {
+            channels.push(Self::Log);
+        }
+
+        if let Some(url) = &config.webhook_url {
+            #[cfg(feature = "webhook-notifications")]
+            channels.push(Self::webhook(url)?);
+            #[cfg(not(feature = "webhook-notifications"))]
+            tracing::warn!(
+                "Not posting bill reminders to {url}, the server was built without the webhook-notifications feature"
+            );
+        }
+
+        if config.is_email_configured() {
+            #[cfg(feature = "email-notifications")]
+            channels.push(Self::email(config)?);
+            #[cfg(not(feature = "email-notifications"))]
+            tracing::warn!(
+                "Not emailing bill reminders, the server was built without the email-notifications feature"
+            );
+        }
+
+        Ok(channels)
+    }
+
+    /// A channel posting to the webhook at `url`, giving up after ten seconds.
+    #[cfg(feature = "webhook-notifications")]
+    fn webhook(url: &str) -> ServerResult<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .map_err(|error| invalid_config(format!("cannot build the webhook client: {error}")))?;
+
+        Ok(Self::Webhook {
+            client,
+            url: url.to_string(),
+        })
+    }
+
+    /// A channel emailing through the configured SMTP server, upgraded to TLS
+    /// with `STARTTLS`.
+    #[cfg(feature = "email-notifications")]
+    fn email(config: &NotificationsConfig) -> ServerResult<Self> {
+        let mailbox = |field: &str, address: &Option<String>| {
+            address
+                .as_deref()
+                .unwrap_or_default()
+                .parse::<lettre::message::Mailbox>()
+                .map_err(|error| {
+                    invalid_config(format!(
+                        "notifications {field} is not an email address: {error}"
+                    ))
+                })
+        };
+        let host = config.smtp_host.as_deref().unwrap_or_default();
+
+        let mut mailer = lettre::AsyncSmtpTransport::<lettre::Tokio1Executor>::starttls_relay(host)
+            .map_err(|error| {
+                invalid_config(format!(
+                    "notifications smtp_host {host} is not valid: {error}"
+                ))
+            })?
+            .port(config.smtp_port);
+        if let (Some(username), Some(password)) = (&config.smtp_username, &config.smtp_password) {
+            mailer = mailer.credentials(lettre::transport::smtp::authentication::Credentials::new(
+                username.clone(),
+                password.clone(),
+            ));
+        }
+
+        Ok(Self::Email {
+            mailer: Box::new(mailer.build()),
+            from: mailbox("email_from", &config.email_from)?,
+            to: mailbox("email_to", &config.email_to)?,
+        })
+    }
+
+    /// Sends a notification through the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the webhook or mail server could not be reached or
+    /// did not accept the notification.
+    pub async fn send(&self, notification: &Notification) -> Result<(), NotificationError> {
+        match self {
+            Self::Log => {
+                tracing::info!(
+                    recurring_id = %notification.reminder.template.id,
+                    due_on = %notification.reminder.due_on,
+                    "Bill reminder: {}",
+                    notification.subject
+                );
+                Ok(())
+            }
+
+            #[cfg(feature = "webhook-notifications")]
+            Self::Webhook { client, url } => {
+                client
+                    .post(url)
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(notification.to_json().to_string())
+                    .send()
+                    .await
+                    .and_then(reqwest::Response::error_for_status)
+                    .map_err(|error| NotificationError::Webhook(error.to_string()))?;
+                Ok(())
+            }
+
+            #[cfg(feature = "email-notifications")]
+            Self::Email { mailer, from, to } => {
+                use lettre::AsyncTransport;
+
+                let message = lettre::Message::builder()
+                    .from(from.clone())
+                    .to(to.clone())
+                    .subject(&notification.subject)
+                    .header(lettre::message::header::ContentType::TEXT_PLAIN)
+                    .body(notification.body.clone())
+                    .map_err(|error| NotificationError::Email(error.to_string()))?;
+                mailer
+                    .send(message)
+                    .await
+                    .map_err(|error| NotificationError::Email(error.to_string()))?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A configuration error for a notification channel.
+#[cfg(any(feature = "webhook-notifications", feature = "email-notifications"))]
+fn invalid_config(message: String) -> crate::error::ServerError {
+    crate::error::ServerError::Config(lib_config::ConfigError::Validation(message))
+}
+
+/// Sends bill reminders on a schedule.
+#[derive(Debug, Clone)]
+pub struct NotificationScheduler {
+    pool: sqlx::SqlitePool,
+    days_ahead: u32,
+    interval: Option<std::time::Duration>,
+    channels: Vec<NotificationChannel>,
+}
+
+/// What a scheduler run sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NotificationRun {
+    /// The number of reminders sent through every channel.
+    pub sent: usize,
+
+    /// The number of reminders a channel failed to send, to try again on
+    /// the next run.
+    pub failed: usize,
+}
+
+impl NotificationScheduler {
+    /// Creates a scheduler from the notifications configuration, or `None`
+    /// when bill reminders are off.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a channel cannot be built, see
+    /// [`NotificationChannel::from_config`].
+    pub fn new(pool: sqlx::SqlitePool, config: &NotificationsConfig) -> ServerResult<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            pool,
+            days_ahead: config.days_ahead,
+            interval: config.interval(),
+            channels: NotificationChannel::from_config(config)?,
+        }))
+    }
+
+    /// Runs straight away and then every interval until the task is dropped,
+    /// or only straight away without an interval. Errors are logged and the
+    /// next run carries on.
+    pub async fn run(self) {
+        let Some(period) = self.interval else {
+            self.run_logged().await;
+            // Stay running, as the server treats a finished job as stopped
+            return std::future::pending().await;
+        };
+
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            // The first tick completes immediately, so this runs on startup
+            interval.tick().await;
+
+            self.run_logged().await;
+        }
+    }
+
+    /// Runs for today, logging rather than returning an error.
+    async fn run_logged(&self) {
+        let today = chrono::Utc::now().date_naive();
+        if let Err(error) = self.run_once(today).await {
+            tracing::error!("Bill reminder scheduler error: {error}");
+        }
+    }
+
+    /// Sends a reminder for every bill due within the configured days of
+    /// `on` that has not been reminded about.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reminders cannot be read. Reminders a channel
+    /// fails to send are logged and counted instead.
+    #[tracing::instrument(name = "Run bill reminder scheduler", skip(self), err)]
+    pub async fn run_once(
+        &self,
+        on: chrono::NaiveDate,
+    ) -> database::DatabaseResult<NotificationRun> {
+        let mut run = NotificationRun::default();
+
+        for reminder in
+            database::RecurringTransactions::find_reminders(on, self.days_ahead, &self.pool).await?
+        {
+            let notification = Notification::new(reminder);
+
+            let mut delivered = true;
+            for channel in &self.channels {
+                if let Err(error) = channel.send(&notification).await {
+                    tracing::warn!(id = %notification.reminder.template.id, "Could not send bill reminder: {error}");
+                    delivered = false;
+                }
+            }
+
+            if delivered {
+                notification.reminder.mark_sent(&self.pool).await?;
+                run.sent += 1;
+            } else {
+                run.failed += 1;
+            }
+        }
+
+        if run.sent > 0 {
+            tracing::info!("Sent {} bill reminders", run.sent);
+        }
+
+        Ok(run)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    fn enabled() -> NotificationsConfig {
+        NotificationsConfig {
+            enabled: true,
+            ..NotificationsConfig::default()
+        }
+    }
+
+    #[test]
+    fn notification_words_the_reminder() {
+        let mut template = database::RecurringTransactions::new(
+            "Landlord",
+            -200_000,
+            database::RecurringFrequencies::Monthly,
+            date(3, 4),
+        );
+        template.auto_post = true;
+        let reminder = database::BillReminder {
+            template,
+            due_on: date(3, 4),
+            days_left: 1,
+        };
+
+        let notification = Notification::new(reminder);
+
+        assert_eq!(notification.subject, "Landlord -$2,000.00 due tomorrow");
+        assert_eq!(
+            notification.body,
+            "Landlord of -$2,000.00 is due on 2025-03-04."
+        );
+    }
+
+    #[cfg(feature = "webhook-notifications")]
+    #[test]
+    fn notification_json_describes_the_reminder() {
+        let template = database::RecurringTransactions::new(
+            "Landlord",
+            -200_000,
+            database::RecurringFrequencies::Monthly,
+            date(3, 4),
+        );
+        let notification = Notification::new(database::BillReminder {
+            template,
+            due_on: date(3, 4),
+            days_left: 3,
+        });
+
+        let json = notification.to_json();
+
+        assert_eq!(json["event"], "bill.reminder");
+        assert_eq!(json["due_on"], "2025-03-04");
+        assert_eq!(json["amount"], -200_000);
+        assert_eq!(json["days_left"], 3);
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn run_once_reminds_about_each_bill_once(pool: sqlx::SqlitePool) -> Result<()> {
+        let monthly = database::RecurringFrequencies::Monthly;
+        database::RecurringTransactions::new("Landlord", -200_000, monthly, date(3, 4))
+            .insert(&pool)
+            .await?;
+        database::RecurringTransactions::new("Insurance", -12_000, monthly, date(3, 20))
+            .insert(&pool)
+            .await?;
+        let scheduler =
+            NotificationScheduler::new(pool, &enabled())?.expect("reminders are enabled");
+
+        assert_eq!(
+            scheduler.run_once(date(3, 1)).await?,
+            NotificationRun { sent: 1, failed: 0 }
+        );
+        assert_eq!(
+            scheduler.run_once(date(3, 2)).await?,
+            NotificationRun::default()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn scheduler_is_off_unless_enabled(pool: sqlx::SqlitePool) -> Result<()> {
+        assert!(NotificationScheduler::new(pool, &NotificationsConfig::default())?.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,353 @@
+//! # Payees Service
+//!
+//! gRPC handlers for the `PayeesService`, backed by the lib-database
+//! [`PayeeAliases`](database::PayeeAliases) model.
+//!
+//! Aliases clean up the payees of imported transactions. The normalise RPC
+//! applies them to a single raw payee, exactly as an import would, so an
+//! alias can be tried out before importing with it.
+
+use tonic::{Request, Response, Status};
+
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{convert, metadata};
+
+/// Server implementation of the `PayeesService`.
+#[derive(Debug, Clone)]
+pub struct MyPayeesService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+    events: database::EventBus,
+}
+
+impl MyPayeesService {
+    /// Creates the service using the given database pool, public ID key and
+    /// event bus.
+    pub fn new(
+        pool: sqlx::SqlitePool,
+        public_ids: domain::PublicIdKey,
+        events: database::EventBus,
+    ) -> Self {
+        Self {
+            pool,
+            public_ids,
+            events,
+        }
+    }
+}
+
+/// Parses the match kind sent by the client. The match kind must be set.
+fn parse_match_kind(field: &str, match_kind: i32) -> Result<database::PayeeMatchKinds, Status> {
+    match rpc::PayeeMatchKinds::try_from(match_kind) {
+        Ok(rpc::PayeeMatchKinds::Exact) => Ok(database::PayeeMatchKinds::Exact),
+        Ok(rpc::PayeeMatchKinds::Regex) => Ok(database::PayeeMatchKinds::Regex),
+        _ => Err(Status::invalid_argument(format!(
+            "Invalid {field}: {match_kind}"
+        ))),
+    }
+}
+
+/// Converts a database payee alias into its RPC message.
+fn to_rpc(public_ids: &domain::PublicIdKey, alias: database::PayeeAliases) -> rpc::PayeeAlias {
+    let match_kind = match alias.match_kind {
+        database::PayeeMatchKinds::Exact => rpc::PayeeMatchKinds::Exact,
+        database::PayeeMatchKinds::Regex => rpc::PayeeMatchKinds::Regex,
+    };
+
+    rpc::PayeeAlias {
+        id: convert::format_id(public_ids, alias.id),
+        pattern: alias.pattern,
+        match_kind: match_kind as i32,
+        payee: alias.payee,
+        created_on: Some(convert::to_timestamp(alias.created_on)),
+        updated_on: Some(convert::to_timestamp(alias.updated_on)),
+    }
+}
+
+#[tonic::async_trait]
+impl rpc::PayeesService for MyPayeesService {
+    async fn payee_alias_create(
+        &self,
+        request: Request<rpc::PayeeAliasCreateRequest>,
+    ) -> Result<Response<rpc::PayeeAliasCreateResponse>, Status> {
+        let request = request.into_inner();
+        let message = request
+            .alias
+            .ok_or_else(|| Status::invalid_argument("Missing alias"))?;
+
+        let match_kind = parse_match_kind("alias.match_kind", message.match_kind)?;
+        let alias = database::PayeeAliases::new(message.pattern, match_kind, message.payee);
+
+        let created = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| alias.insert(conn).await,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::PayeeAliasCreateResponse {
+            alias: Some(to_rpc(&self.public_ids, created)),
+        }))
+    }
+
+    async fn payee_alias_get(
+        &self,
+        request: Request<rpc::PayeeAliasGetRequest>,
+    ) -> Result<Response<rpc::PayeeAliasGetResponse>, Status> {
+        let public_id = request.into_inner().id;
+        let id = convert::parse_id(&self.public_ids, "id", &public_id)?;
+
+        let alias = metadata::time_db(database::PayeeAliases::find_by_id(id, &self.pool))
+            .await
+            .map_err(convert::database_status)?
+            .ok_or_else(|| {
+                convert::not_found(
+                    "payee_alias",
+                    "id",
+                    format!("Payee alias with id {public_id} not found"),
+                )
+            })?;
+
+        Ok(Response::new(rpc::PayeeAliasGetResponse {
+            alias: Some(to_rpc(&self.public_ids, alias)),
+        }))
+    }
+
+    async fn payee_aliases_list(
+        &self,
+        _request: Request<rpc::PayeeAliasesListRequest>,
+    ) -> Result<Response<rpc::PayeeAliasesListResponse>, Status> {
+        let aliases = metadata::time_db(database::PayeeAliases::find_all(&self.pool))
+            .await
+            .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::PayeeAliasesListResponse {
+            aliases: aliases
+                .into_iter()
+                .map(|alias| to_rpc(&self.public_ids, alias))
+                .collect(),
+        }))
+    }
+
+    async fn payee_alias_update(
+        &self,
+        request: Request<rpc::PayeeAliasUpdateRequest>,
+    ) -> Result<Response<rpc::PayeeAliasUpdateResponse>, Status> {
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        let message = request
+            .alias
+            .ok_or_else(|| Status::invalid_argument("Missing alias"))?;
+
+        let match_kind = parse_match_kind("alias.match_kind", message.match_kind)?;
+
+        let updated = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                let mut alias = database::PayeeAliases::find_by_id(id, &mut *conn)
+                    .await?
+                    .ok_or_else(|| {
+                        database::DatabaseError::NotFound(format!(
+                            "Payee alias with id {} not found",
+                            request.id
+                        ))
+                    })?;
+
+                alias.pattern = message.pattern;
+                alias.match_kind = match_kind;
+                alias.payee = message.payee;
+                alias.update(conn).await
+            },
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::PayeeAliasUpdateResponse {
+            alias: Some(to_rpc(&self.public_ids, updated)),
+        }))
+    }
+
+    async fn payee_alias_delete(
+        &self,
+        request: Request<rpc::PayeeAliasDeleteRequest>,
+    ) -> Result<Response<rpc::PayeeAliasDeleteResponse>, Status> {
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+
+        metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::PayeeAliases::delete_by_id(id, conn).await,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::PayeeAliasDeleteResponse {
+            rows_deleted: 1,
+        }))
+    }
+
+    async fn payee_normalise(
+        &self,
+        request: Request<rpc::PayeeNormaliseRequest>,
+    ) -> Result<Response<rpc::PayeeNormaliseResponse>, Status> {
+        let raw_payee = request.into_inner().raw_payee;
+
+        let normaliser = metadata::time_db(database::PayeeNormaliser::load(&self.pool))
+            .await
+            .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::PayeeNormaliseResponse {
+            payee: normaliser.normalise(&raw_payee).to_string(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::PayeesService;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn create(
+        service: &MyPayeesService,
+        pattern: &str,
+        match_kind: rpc::PayeeMatchKinds,
+        payee: &str,
+    ) -> core::result::Result<rpc::PayeeAlias, Status> {
+        let request = Request::new(rpc::PayeeAliasCreateRequest {
+            alias: Some(rpc::PayeeAlias {
+                pattern: pattern.to_string(),
+                match_kind: match_kind as i32,
+                payee: payee.to_string(),
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+
+        Ok(service
+            .payee_alias_create(request)
+            .await?
+            .into_inner()
+            .alias
+            .unwrap())
+    }
+
+    async fn normalise(service: &MyPayeesService, raw_payee: &str) -> Result<String> {
+        let request = Request::new(rpc::PayeeNormaliseRequest {
+            raw_payee: raw_payee.to_string(),
+        });
+
+        Ok(service.payee_normalise(request).await?.into_inner().payee)
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn normalise_applies_created_aliases(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyPayeesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        create(
+            &service,
+            r"^AMZN Mktp",
+            rpc::PayeeMatchKinds::Regex,
+            "Amazon",
+        )
+        .await?;
+
+        assert_eq!(normalise(&service, "AMZN Mktp US*1234").await?, "Amazon");
+        assert_eq!(normalise(&service, "  Corner Cafe ").await?, "Corner Cafe");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn update_get_and_delete_an_alias(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyPayeesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let alias = create(
+            &service,
+            "WOOLWORTHS 1234",
+            rpc::PayeeMatchKinds::Exact,
+            "Woolies",
+        )
+        .await?;
+
+        let request = Request::new(rpc::PayeeAliasUpdateRequest {
+            id: alias.id.clone(),
+            alias: Some(rpc::PayeeAlias {
+                payee: "Woolworths".to_string(),
+                ..alias.clone()
+            }),
+            validate_only: false,
+        });
+        service.payee_alias_update(request).await?;
+
+        let request = Request::new(rpc::PayeeAliasGetRequest {
+            id: alias.id.clone(),
+        });
+        let fetched = service
+            .payee_alias_get(request)
+            .await?
+            .into_inner()
+            .alias
+            .unwrap();
+        assert_eq!(fetched.payee, "Woolworths");
+        assert_eq!(fetched.created_on, alias.created_on);
+
+        let request = Request::new(rpc::PayeeAliasDeleteRequest {
+            id: alias.id.clone(),
+            validate_only: false,
+        });
+        service.payee_alias_delete(request).await?;
+
+        let request = Request::new(rpc::PayeeAliasGetRequest { id: alias.id });
+        let missing = service.payee_alias_get(request).await;
+        assert_eq!(missing.unwrap_err().code(), tonic::Code::NotFound);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn create_rejects_bad_input(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyPayeesService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+
+        let unspecified = create(
+            &service,
+            "AMZN",
+            rpc::PayeeMatchKinds::Unspecified,
+            "Amazon",
+        )
+        .await;
+        let bad_regex = create(&service, "AMZN(", rpc::PayeeMatchKinds::Regex, "Amazon").await;
+        let blank_payee = create(&service, "AMZN", rpc::PayeeMatchKinds::Exact, " ").await;
+
+        assert_eq!(
+            unspecified.unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+        assert_eq!(bad_regex.unwrap_err().code(), tonic::Code::InvalidArgument);
+        assert_eq!(
+            blank_payee.unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+
+        let request = Request::new(rpc::PayeeAliasesListRequest {});
+        let listed = service
+            .payee_aliases_list(request)
+            .await?
+            .into_inner()
+            .aliases;
+        assert!(listed.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,264 @@
+//! # Periods Service
+//!
+//! gRPC handlers for the `PeriodsService`, backed by the lib-database
+//! [`PeriodClosings`](database::PeriodClosings) model.
+//!
+//! The lock itself is enforced by lib-database on every transaction write, so
+//! these handlers only move the closing date and report the audit trail.
+//! Writes into a closed period surface as `FAILED_PRECONDITION`.
+
+use tonic::{Request, Response, Status};
+
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{convert, metadata};
+
+/// Server implementation of the `PeriodsService`.
+#[derive(Debug, Clone)]
+pub struct MyPeriodsService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+    events: database::EventBus,
+}
+
+impl MyPeriodsService {
+    /// Creates the service using the given database pool, public ID key and
+    /// event bus.
+    pub fn new(
+        pool: sqlx::SqlitePool,
+        public_ids: domain::PublicIdKey,
+        events: database::EventBus,
+    ) -> Self {
+        Self {
+            pool,
+            public_ids,
+            events,
+        }
+    }
+}
+
+/// Converts a database period closing into its RPC message.
+fn to_rpc(
+    public_ids: &domain::PublicIdKey,
+    closing: database::PeriodClosings,
+) -> rpc::PeriodClosing {
+    let action = match closing.action {
+        database::PeriodActions::Close => rpc::PeriodActions::Close,
+        database::PeriodActions::Reopen => rpc::PeriodActions::Reopen,
+    };
+
+    rpc::PeriodClosing {
+        id: convert::format_id(public_ids, closing.id),
+        action: action as i32,
+        closed_through: closing.closed_through.map(convert::format_date),
+        reason: closing.reason,
+        created_on: Some(convert::to_timestamp(closing.created_on)),
+    }
+}
+
+#[tonic::async_trait]
+impl rpc::PeriodsService for MyPeriodsService {
+    async fn period_close(
+        &self,
+        request: Request<rpc::PeriodCloseRequest>,
+    ) -> Result<Response<rpc::PeriodCloseResponse>, Status> {
+        let request = request.into_inner();
+        let closed_through = convert::parse_date("closed_through", &request.closed_through)?;
+        let reason = request.reason;
+
+        let closing = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::PeriodClosings::close(closed_through, reason, conn).await,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::PeriodCloseResponse {
+            closing: Some(to_rpc(&self.public_ids, closing)),
+        }))
+    }
+
+    async fn period_reopen(
+        &self,
+        request: Request<rpc::PeriodReopenRequest>,
+    ) -> Result<Response<rpc::PeriodReopenResponse>, Status> {
+        let request = request.into_inner();
+        let closed_through = request
+            .closed_through
+            .as_deref()
+            .map(|date| convert::parse_date("closed_through", date))
+            .transpose()?;
+        let reason = request.reason;
+
+        let closing = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::PeriodClosings::reopen(closed_through, reason, conn).await,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        tracing::warn!(
+            closed_through = ?closing.closed_through,
+            reason = ?closing.reason,
+            validate_only = request.validate_only,
+            "Closed period reopened"
+        );
+
+        Ok(Response::new(rpc::PeriodReopenResponse {
+            closing: Some(to_rpc(&self.public_ids, closing)),
+        }))
+    }
+
+    async fn period_get_status(
+        &self,
+        _request: Request<rpc::PeriodGetStatusRequest>,
+    ) -> Result<Response<rpc::PeriodGetStatusResponse>, Status> {
+        let history = metadata::time_db(database::PeriodClosings::find_all(&self.pool))
+            .await
+            .map_err(convert::database_status)?;
+
+        // The newest row is the current lock
+        let closed_through = history
+            .first()
+            .and_then(|closing| closing.closed_through)
+            .map(convert::format_date);
+
+        Ok(Response::new(rpc::PeriodGetStatusResponse {
+            closed_through,
+            history: history
+                .into_iter()
+                .map(|closing| to_rpc(&self.public_ids, closing))
+                .collect(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::{PeriodsService, TransactionsService};
+
+    use crate::transactions::MyTransactionsService;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn close(closed_through: &str, validate_only: bool) -> Request<rpc::PeriodCloseRequest> {
+        Request::new(rpc::PeriodCloseRequest {
+            closed_through: closed_through.to_string(),
+            reason: Some("FY2025 lodged".to_string()),
+            validate_only,
+        })
+    }
+
+    async fn create_transaction(
+        pool: &sqlx::SqlitePool,
+        date: &str,
+    ) -> core::result::Result<rpc::Transaction, Status> {
+        let request = Request::new(rpc::TransactionCreateRequest {
+            transaction: Some(rpc::Transaction {
+                transaction_date: date.to_string(),
+                payee: "Corner Cafe".to_string(),
+                amount: -1_250,
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+
+        let response = MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .transaction_create(request)
+        .await?;
+
+        Ok(response.into_inner().transaction.unwrap())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn close_locks_transactions(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyPeriodsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+
+        let closing = service
+            .period_close(close("2025-06-30", false))
+            .await?
+            .into_inner();
+        let locked = create_transaction(&pool, "2025-06-30").await;
+        let open = create_transaction(&pool, "2025-07-01").await;
+
+        assert_eq!(closing.closing.unwrap().action(), rpc::PeriodActions::Close);
+        assert_eq!(locked.unwrap_err().code(), tonic::Code::FailedPrecondition);
+        assert!(open.is_ok());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn close_validate_only_does_not_lock(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyPeriodsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+
+        service.period_close(close("2025-06-30", true)).await?;
+        let status = service
+            .period_get_status(Request::new(rpc::PeriodGetStatusRequest {}))
+            .await?
+            .into_inner();
+
+        assert!(status.closed_through.is_none());
+        assert!(create_transaction(&pool, "2025-06-30").await.is_ok());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn reopen_requires_reason_and_is_audited(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyPeriodsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        service.period_close(close("2025-06-30", false)).await?;
+
+        let reopen = |reason: &str| {
+            Request::new(rpc::PeriodReopenRequest {
+                closed_through: Some("2025-03-31".to_string()),
+                reason: reason.to_string(),
+                validate_only: false,
+            })
+        };
+
+        let without_reason = service.period_reopen(reopen("")).await;
+        service.period_reopen(reopen("Amended Q4 invoice")).await?;
+        let status = service
+            .period_get_status(Request::new(rpc::PeriodGetStatusRequest {}))
+            .await?
+            .into_inner();
+
+        assert_eq!(
+            without_reason.unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+        assert_eq!(status.closed_through.as_deref(), Some("2025-03-31"));
+        assert_eq!(status.history.len(), 2);
+        assert_eq!(
+            status.history[0].reason.as_deref(),
+            Some("Amended Q4 invoice")
+        );
+        assert!(create_transaction(&pool, "2025-06-30").await.is_ok());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+//! # Quotas
+//!
+//! Checks the soft limits of the `[Quota]` config section, see
+//! [`QuotaConfig`](config::QuotaConfig), before handlers add records. A
+//! request that would take the ledger past a limit is refused with
+//! `RESOURCE_EXHAUSTED` before anything is written, naming the resource, what
+//! the ledger holds and the limit.
+//!
+//! What the ledger holds is counted with [`Usage`](database::Usage) when a
+//! limit is set, and not at all otherwise, so an unlimited server does no
+//! extra work. The same counts answer `GetUsage`.
+
+use tonic::Status;
+
+use lib_config as config;
+use lib_database as database;
+use lib_rpc as rpc;
+
+use crate::{convert, metadata};
+
+/// A resource a quota limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    Transactions,
+    Categories,
+    AttachmentBytes,
+}
+
+impl Resource {
+    /// The resource's name in error messages.
+    fn name(self) -> &'static str {
+        match self {
+            Resource::Transactions => "transactions",
+            Resource::Categories => "categories",
+            Resource::AttachmentBytes => "bytes of attachments",
+        }
+    }
+
+    /// How much of the resource `usage` counts.
+    fn used(self, usage: &database::Usage) -> u64 {
+        let used = match self {
+            Resource::Transactions => usage.transactions,
+            Resource::Categories => usage.categories,
+            Resource::AttachmentBytes => usage.attachment_bytes,
+        };
+
+        used.max(0) as u64
+    }
+}
+
+/// The configured limits, checked against the ledger's usage.
+#[derive(Debug, Clone, Default)]
+pub struct Quotas {
+    config: config::QuotaConfig,
+}
+
+impl Quotas {
+    /// Quotas with the configured limits.
+    pub fn new(config: config::QuotaConfig) -> Self {
+        Self { config }
+    }
+
+    /// The limit on `resource`, or `None` when it is unlimited.
+    fn limit(&self, resource: Resource) -> Option<u64> {
+        match resource {
+            Resource::Transactions => self.config.max_transactions,
+            Resource::Categories => self.config.max_categories,
+            Resource::AttachmentBytes => self.config.max_attachment_bytes,
+        }
+    }
+
+    /// Checks the ledger has room to add `adding` of `resource`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RESOURCE_EXHAUSTED` if adding would pass the limit, or the
+    /// status of the database error if the usage cannot be counted.
+    pub async fn check(
+        &self,
+        resource: Resource,
+        adding: u64,
+        pool: &sqlx::SqlitePool,
+    ) -> Result<(), Status> {
+        let Some(limit) = self.limit(resource) else {
+            return Ok(());
+        };
+
+        let usage = metadata::time_db(database::Usage::find(pool))
+            .await
+            .map_err(convert::database_status)?;
+        let used = resource.used(&usage);
+
+        if self.config.allows(Some(limit), used, adding) {
+            Ok(())
+        } else {
+            Err(Status::resource_exhausted(format!(
+                "Quota exceeded: the ledger holds {used} of at most {limit} {}, and cannot add {adding} more",
+                resource.name()
+            )))
+        }
+    }
+
+    /// The ledger's `usage` of `resource` against its limit, as sent by
+    /// `GetUsage`.
+    pub fn to_rpc(&self, resource: Resource, usage: &database::Usage) -> rpc::ResourceUsage {
+        rpc::ResourceUsage {
+            used: resource.used(usage) as i64,
+            limit: self
+                .limit(resource)
+                .map(|limit| limit.min(i64::MAX as u64) as i64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn check_refuses_past_the_limit(pool: sqlx::SqlitePool) -> Result<()> {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        database::Transactions::new(date, "Cafe", -550)
+            .insert(&pool)
+            .await?;
+        let quotas = Quotas::new(config::QuotaConfig {
+            max_transactions: Some(2),
+            ..Default::default()
+        });
+
+        quotas.check(Resource::Transactions, 1, &pool).await?;
+        let status = quotas
+            .check(Resource::Transactions, 2, &pool)
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+        assert!(status.message().contains("1 of at most 2 transactions"));
+        // Unlimited resources are not counted
+        quotas.check(Resource::Categories, u64::MAX, &pool).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,353 @@
+//! # Receipt Email Inbox
+//!
+//! Watches a maildir that forwarded receipts are delivered to, and hands each
+//! new email to lib-database, which creates a draft transaction for review
+//! ([`ReceiptEmails::ingest`](database::ReceiptEmails::ingest)).
+//!
+//! Only built with the `receipt-email` feature. Delivery into the maildir is
+//! left to the mail server, or to a fetcher such as `fdm` or `mbsync` for an
+//! IMAP folder. Processed emails are moved from `new/` to `cur/` and marked
+//! seen, as a mail client would. Emails that cannot be parsed are moved to
+//! `cur/` unseen, so they stand out when the maildir is opened in a client.
+//! Emails whose attachments would pass the attachment storage quota are left
+//! in `new/`, to be ingested once there is room.
+
+use std::path::PathBuf;
+
+use mail_parser::{MessageParser, MimeHeaders};
+
+use lib_config as config;
+use lib_database as database;
+
+use crate::quota::{Quotas, Resource};
+
+/// Reads forwarded receipts from a maildir into the ledger.
+#[derive(Debug, Clone)]
+pub struct ReceiptInbox {
+    maildir: PathBuf,
+    pool: sqlx::SqlitePool,
+    events: database::EventBus,
+    poll_interval: std::time::Duration,
+    quotas: Quotas,
+}
+
+/// Error type for reading the receipt inbox.
+#[derive(Debug)]
+pub enum InboxError {
+    /// The maildir could not be read or an email could not be moved.
+    Io(std::io::Error),
+
+    /// An email could not be stored. It is left in `new/` to be tried again.
+    Database(database::DatabaseError),
+}
+
+impl std::fmt::Display for InboxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InboxError::Io(error) => write!(f, "Receipt inbox error: {error}"),
+            InboxError::Database(error) => write!(f, "Receipt inbox error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for InboxError {}
+
+impl From<std::io::Error> for InboxError {
+    fn from(error: std::io::Error) -> Self {
+        InboxError::Io(error)
+    }
+}
+
+impl From<database::DatabaseError> for InboxError {
+    fn from(error: database::DatabaseError) -> Self {
+        InboxError::Database(error)
+    }
+}
+
+impl ReceiptInbox {
+    /// Creates an inbox for a maildir, the directory holding `new/`, `cur/`
+    /// and `tmp/`, checked once a minute. The draft transactions it creates
+    /// are published on `events`.
+    pub fn new(
+        maildir: impl Into<PathBuf>,
+        pool: sqlx::SqlitePool,
+        events: database::EventBus,
+    ) -> Self {
+        Self {
+            maildir: maildir.into(),
+            pool,
+            events,
+            poll_interval: std::time::Duration::from_secs(60),
+            quotas: Quotas::default(),
+        }
+    }
+
+    /// Sets the quotas checked before attachments are stored.
+    pub fn with_quotas(mut self, quotas: config::QuotaConfig) -> Self {
+        self.quotas = Quotas::new(quotas);
+        self
+    }
+
+    /// Sets how often the maildir is checked for new emails.
+    pub fn with_poll_interval(mut self, poll_interval: std::time::Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Checks the maildir every poll interval until the task is dropped.
+    /// Errors are logged and the next check carries on.
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(self.poll_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(error) = self.process_once().await {
+                tracing::error!("{error}");
+            }
+        }
+    }
+
+    /// Ingests every email waiting in `new/`, oldest file name first.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of receipt emails stored. Emails already ingested
+    /// and emails that cannot be parsed are moved to `cur/` but not counted,
+    /// and emails over the attachment quota are left in `new/`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the maildir cannot be read, or an email cannot be
+    /// stored or moved. Emails before it have already been processed.
+    #[tracing::instrument(name = "Process receipt inbox", skip(self), fields(maildir = %self.maildir.display()), err)]
+    pub async fn process_once(&self) -> Result<usize, InboxError> {
+        let new = self.maildir.join("new");
+        let cur = self.maildir.join("cur");
+        tokio::fs::create_dir_all(&cur).await?;
+
+        let mut entries = tokio::fs::read_dir(&new).await?;
+        let mut files = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_type().await?.is_file() {
+                files.push(entry.path());
+            }
+        }
+        files.sort();
+
+        let mut ingested = 0;
+        for path in files {
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            // Maildir info comes after a colon; the unique name is before it
+            let unique = name.split(':').next().unwrap_or(name).to_string();
+
+            let bytes = tokio::fs::read(&path).await?;
+            let flags = match parse(&bytes, &unique) {
+                Some(receipt) => {
+                    let size = receipt
+                        .attachments
+                        .iter()
+                        .map(|attachment| attachment.content.len() as u64)
+                        .sum();
+                    if let Err(status) = self
+                        .quotas
+                        .check(Resource::AttachmentBytes, size, &self.pool)
+                        .await
+                    {
+                        tracing::warn!("{}, leaving {} in new/", status.message(), path.display());
+                        continue;
+                    }
+
+                    let ingest = database::ReceiptEmails::ingest(&receipt, &self.pool);
+                    if self.events.publish_committed(ingest).await?.is_some() {
+                        ingested += 1;
+                    }
+                    "S"
+                }
+                None => {
+                    tracing::warn!("Could not parse {}, leaving it unseen", path.display());
+                    ""
+                }
+            };
+
+            tokio::fs::rename(&path, cur.join(format!("{unique}:2,{flags}"))).await?;
+        }
+
+        if ingested > 0 {
+            tracing::info!("Ingested {ingested} receipt emails");
+        }
+
+        Ok(ingested)
+    }
+}
+
+/// Parses a raw email into a receipt. The maildir file name stands in for a
+/// missing Message-ID, and the current time for a missing date.
+///
+/// A receipt forwarded as an attachment (`message/rfc822`) has its text added
+/// to the body and its attachments kept with the email's own.
+fn parse(bytes: &[u8], file_name: &str) -> Option<database::InboundReceipt> {
+    let message = MessageParser::default().parse(bytes)?;
+
+    let message_id = message
+        .message_id()
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("maildir:{file_name}"));
+    let received_on = message
+        .date()
+        .and_then(|date| chrono::DateTime::from_timestamp(date.to_timestamp(), 0))
+        .unwrap_or_else(chrono::Utc::now);
+
+    let mut body = message
+        .body_text(0)
+        .map(|text| text.into_owned())
+        .unwrap_or_default();
+    let mut attachments = Vec::new();
+    let mut parts: Vec<_> = message.attachments().collect();
+
+    while let Some(part) = parts.pop() {
+        if let Some(forwarded) = part.message() {
+            if let Some(subject) = forwarded.subject() {
+                body.push_str(&format!("\nSubject: {subject}"));
+            }
+            if let Some(from) = forwarded.from().and_then(|from| from.first()) {
+                let name = from.name().unwrap_or_default();
+                let address = from.address().unwrap_or_default();
+                body.push_str(&format!("\nFrom: {name} <{address}>"));
+            }
+            if let Some(text) = forwarded.body_text(0) {
+                body.push('\n');
+                body.push_str(&text);
+            }
+            parts.extend(forwarded.attachments());
+            continue;
+        }
+
+        let content_type = part
+            .content_type()
+            .map(|content_type| match content_type.subtype() {
+                Some(subtype) => format!("{}/{subtype}", content_type.ctype()),
+                None => content_type.ctype().to_string(),
+            })
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+
+        attachments.push(database::InboundAttachment {
+            file_name: part.attachment_name().unwrap_or("attachment").to_string(),
+            content_type,
+            content: part.contents().to_vec(),
+        });
+    }
+
+    Some(database::InboundReceipt {
+        message_id,
+        sender: message
+            .from()
+            .and_then(|from| from.first())
+            .and_then(|from| from.address())
+            .map(str::to_string),
+        subject: message.subject().map(str::to_string),
+        received_on,
+        body,
+        attachments,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    const RECEIPT: &str = "Message-ID: <4521@cornercafe.com.au>\r\n\
+        From: Me <me@example.com>\r\n\
+        Subject: Fwd: Your receipt from Corner Cafe\r\n\
+        Date: Fri, 14 Mar 2025 09:30:00 +1100\r\n\
+        MIME-Version: 1.0\r\n\
+        Content-Type: multipart/mixed; boundary=\"b\"\r\n\
+        \r\n\
+        --b\r\n\
+        Content-Type: text/plain\r\n\
+        \r\n\
+        Flat white $5.50\r\nTotal $5.50\r\n\
+        --b\r\n\
+        Content-Type: application/pdf\r\n\
+        Content-Disposition: attachment; filename=\"receipt.pdf\"\r\n\
+        \r\n\
+        %PDF-1.4\r\n\
+        --b--\r\n";
+
+    fn maildir() -> PathBuf {
+        let maildir = std::env::temp_dir().join(format!("receipts-{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(maildir.join("new")).unwrap();
+        maildir
+    }
+
+    #[test]
+    fn parse_reads_headers_body_and_attachments() {
+        let receipt = parse(RECEIPT.as_bytes(), "1.host").unwrap();
+
+        assert_eq!(receipt.message_id, "4521@cornercafe.com.au");
+        assert_eq!(receipt.sender.as_deref(), Some("me@example.com"));
+        assert_eq!(
+            receipt.received_on.to_rfc3339(),
+            "2025-03-13T22:30:00+00:00"
+        );
+        assert!(receipt.body.contains("Total $5.50"));
+        assert_eq!(receipt.attachments.len(), 1);
+        assert_eq!(receipt.attachments[0].file_name, "receipt.pdf");
+        assert_eq!(receipt.attachments[0].content_type, "application/pdf");
+    }
+
+    #[test]
+    fn parse_falls_back_to_file_name_for_message_id() {
+        let receipt = parse(b"Subject: Receipt\r\n\r\nTotal $1.00\r\n", "2.host").unwrap();
+
+        assert_eq!(receipt.message_id, "maildir:2.host");
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn process_once_ingests_and_moves_emails(pool: sqlx::SqlitePool) -> Result<()> {
+        let maildir = maildir();
+        std::fs::write(maildir.join("new/1.host"), RECEIPT)?;
+        std::fs::write(maildir.join("new/2.host"), RECEIPT)?;
+        let inbox = ReceiptInbox::new(&maildir, pool.clone(), database::EventBus::new());
+
+        let ingested = inbox.process_once().await?;
+
+        assert_eq!(ingested, 1);
+        assert!(maildir.join("cur/1.host:2,S").exists());
+        assert!(maildir.join("cur/2.host:2,S").exists());
+        assert_eq!(std::fs::read_dir(maildir.join("new"))?.count(), 0);
+
+        let drafts = database::ReceiptEmails::find_unmatched(&pool).await?;
+        assert!(drafts.is_empty());
+
+        std::fs::remove_dir_all(&maildir)?;
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn process_once_leaves_emails_over_the_attachment_quota(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let maildir = maildir();
+        std::fs::write(maildir.join("new/1.host"), RECEIPT)?;
+        let quotas = config::QuotaConfig {
+            max_attachment_bytes: Some(4),
+            ..Default::default()
+        };
+        let inbox = ReceiptInbox::new(&maildir, pool.clone(), database::EventBus::new())
+            .with_quotas(quotas);
+
+        let ingested = inbox.process_once().await?;
+
+        assert_eq!(ingested, 0);
+        assert!(maildir.join("new/1.host").exists());
+
+        std::fs::remove_dir_all(&maildir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,262 @@
+//! # Reconciliations Service
+//!
+//! gRPC handlers for the `ReconciliationsService`, backed by the lib-database
+//! [`Reconciliations`](database::Reconciliations) model.
+//!
+//! lib-database checks the statement balances and its transactions, and
+//! records the reconciliation all or nothing, so a statement that does not
+//! check out surfaces as `INVALID_ARGUMENT` with nothing changed. Ticking
+//! transactions off as cleared beforehand is `TransactionsMarkCleared`, on
+//! the transactions service.
+
+use tonic::{Request, Response, Status};
+
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{auth, convert, metadata};
+
+/// Server implementation of the `ReconciliationsService`.
+#[derive(Debug, Clone)]
+pub struct MyReconciliationsService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+    events: database::EventBus,
+}
+
+impl MyReconciliationsService {
+    /// Creates the service using the given database pool, public ID key and
+    /// event bus.
+    pub fn new(
+        pool: sqlx::SqlitePool,
+        public_ids: domain::PublicIdKey,
+        events: database::EventBus,
+    ) -> Self {
+        Self {
+            pool,
+            public_ids,
+            events,
+        }
+    }
+}
+
+/// Converts a database reconciliation into its RPC message.
+fn to_rpc(
+    public_ids: &domain::PublicIdKey,
+    reconciliation: database::Reconciliations,
+) -> rpc::Reconciliation {
+    rpc::Reconciliation {
+        id: convert::format_id(public_ids, reconciliation.id),
+        account_id: convert::format_id(public_ids, reconciliation.account_id),
+        statement_date: convert::format_date(reconciliation.statement_date),
+        starting_balance: reconciliation.starting_balance,
+        ending_balance: reconciliation.ending_balance,
+        created_on: Some(convert::to_timestamp(reconciliation.created_on)),
+    }
+}
+
+#[tonic::async_trait]
+impl rpc::ReconciliationsService for MyReconciliationsService {
+    async fn reconciliations_list(
+        &self,
+        request: Request<rpc::ReconciliationsListRequest>,
+    ) -> Result<Response<rpc::ReconciliationsListResponse>, Status> {
+        let account_id = convert::parse_id(
+            &self.public_ids,
+            "account_id",
+            &request.into_inner().account_id,
+        )?;
+
+        let reconciliations = metadata::time_db(database::Reconciliations::find_by_account(
+            account_id, &self.pool,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::ReconciliationsListResponse {
+            reconciliations: reconciliations
+                .into_iter()
+                .map(|reconciliation| to_rpc(&self.public_ids, reconciliation))
+                .collect(),
+        }))
+    }
+
+    async fn reconciliation_create(
+        &self,
+        request: Request<rpc::ReconciliationCreateRequest>,
+    ) -> Result<Response<rpc::ReconciliationCreateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let account_id = convert::parse_id(&self.public_ids, "account_id", &request.account_id)?;
+        let statement_date = convert::parse_date("statement_date", &request.statement_date)?;
+        let transaction_ids = request
+            .transaction_ids
+            .iter()
+            .map(|id| convert::parse_id(&self.public_ids, "transaction_ids", id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let reconciliation = database::Reconciliations::new(
+            account_id,
+            statement_date,
+            request.starting_balance,
+            request.ending_balance,
+        );
+        let reconciled = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                reconciliation
+                    .reconcile(&transaction_ids, scope, conn)
+                    .await
+            },
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::ReconciliationCreateResponse {
+            reconciliation: Some(to_rpc(&self.public_ids, reconciled)),
+        }))
+    }
+
+    async fn reconciliation_delete(
+        &self,
+        request: Request<rpc::ReconciliationDeleteRequest>,
+    ) -> Result<Response<rpc::ReconciliationDeleteResponse>, Status> {
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+
+        let cleared = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Reconciliations::delete_by_id(id, conn).await,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::ReconciliationDeleteResponse {
+            transactions_cleared: cleared as i32,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::{ReconciliationsService, TransactionsService};
+
+    use crate::transactions::MyTransactionsService;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn posted(
+        pool: &sqlx::SqlitePool,
+        account_id: lib_domain::RowID,
+        amount: i64,
+    ) -> Result<String> {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        let mut transaction = database::Transactions::new(date, "Corner Store", amount);
+        transaction.account_id = Some(account_id);
+
+        Ok(convert::format_id(
+            &convert::TEST_PUBLIC_IDS,
+            transaction.insert(pool).await?.id,
+        ))
+    }
+
+    fn create(
+        account_id: &str,
+        ending_balance: i64,
+        transaction_ids: Vec<String>,
+    ) -> Request<rpc::ReconciliationCreateRequest> {
+        Request::new(rpc::ReconciliationCreateRequest {
+            account_id: account_id.to_string(),
+            statement_date: "2025-03-31".to_string(),
+            starting_balance: 0,
+            ending_balance,
+            transaction_ids,
+            validate_only: false,
+        })
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn cleared_transactions_are_reconciled_and_undone(pool: sqlx::SqlitePool) -> Result<()> {
+        let account = database::Accounts::new("Everyday").insert(&pool).await?;
+        let account_id = convert::format_id(&convert::TEST_PUBLIC_IDS, account.id);
+        let wages = posted(&pool, account.id, 250_000).await?;
+        let rent = posted(&pool, account.id, -180_000).await?;
+        let transactions = MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let service = MyReconciliationsService::new(
+            pool,
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+
+        let request = Request::new(rpc::TransactionsMarkClearedRequest {
+            ids: vec![wages.clone(), rent.clone()],
+            cleared: true,
+            validate_only: false,
+        });
+        let marked = transactions
+            .transactions_mark_cleared(request)
+            .await?
+            .into_inner()
+            .transactions;
+        assert!(
+            marked
+                .iter()
+                .all(|transaction| transaction.cleared_status() == rpc::ClearedStatus::Cleared)
+        );
+
+        let unbalanced = service
+            .reconciliation_create(create(&account_id, 80_000, vec![wages.clone()]))
+            .await;
+        assert_eq!(unbalanced.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        let reconciled = service
+            .reconciliation_create(create(&account_id, 70_000, vec![wages.clone(), rent]))
+            .await?
+            .into_inner()
+            .reconciliation
+            .expect("reconciliation");
+        let request = Request::new(rpc::TransactionGetRequest { id: wages.clone() });
+        let transaction = transactions
+            .transaction_get(request)
+            .await?
+            .into_inner()
+            .transaction;
+        assert_eq!(
+            transaction.map(|t| t.cleared_status()),
+            Some(rpc::ClearedStatus::Reconciled)
+        );
+
+        let request = Request::new(rpc::ReconciliationsListRequest { account_id });
+        let listed = service
+            .reconciliations_list(request)
+            .await?
+            .into_inner()
+            .reconciliations;
+        assert_eq!(listed, vec![reconciled.clone()]);
+
+        let request = Request::new(rpc::ReconciliationDeleteRequest {
+            id: reconciled.id,
+            validate_only: false,
+        });
+        assert_eq!(
+            service
+                .reconciliation_delete(request)
+                .await?
+                .into_inner()
+                .transactions_cleared,
+            2
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+//! # Recurring Transactions Scheduler
+//!
+//! Turns recurring transaction templates into transactions as they fall due
+//! ([`RecurringTransactions::materialise`](database::RecurringTransactions::materialise)).
+//!
+//! The scheduler runs once as soon as it starts, catching up anything missed
+//! while the server was down, and then on every tick of its interval. Each
+//! template is materialised in its own database transaction, so a template
+//! that fails, for example because an occurrence falls in a closed period, is
+//! logged and retried on the next run without holding up the others.
+
+use lib_database as database;
+
+/// Creates transactions from recurring templates when they are due.
+#[derive(Debug, Clone)]
+pub struct RecurringScheduler {
+    pool: sqlx::SqlitePool,
+    events: database::EventBus,
+    interval: std::time::Duration,
+}
+
+/// What a scheduler run created.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchedulerRun {
+    /// The number of transactions created.
+    pub created: usize,
+
+    /// The number of due templates that could not be materialised.
+    pub failed: usize,
+}
+
+impl RecurringScheduler {
+    /// Creates a scheduler for the given database pool, publishing the
+    /// transactions it creates on `events`, run once an hour.
+    pub fn new(pool: sqlx::SqlitePool, events: database::EventBus) -> Self {
+        Self {
+            pool,
+            events,
+            interval: std::time::Duration::from_secs(60 * 60),
+        }
+    }
+
+    /// Sets how often due templates are checked for.
+    pub fn with_interval(mut self, interval: std::time::Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Runs straight away and then every interval until the task is dropped.
+    /// Errors are logged and the next run carries on.
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(self.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            // The first tick completes immediately, so this runs on startup
+            interval.tick().await;
+
+            let today = chrono::Utc::now().date_naive();
+            if let Err(error) = self.run_once(today).await {
+                tracing::error!("Recurring transaction scheduler error: {error}");
+            }
+        }
+    }
+
+    /// Materialises every template due on or before `on`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the due templates cannot be read. Templates that
+    /// fail to materialise are logged and counted instead.
+    #[tracing::instrument(name = "Run recurring transaction scheduler", skip(self), err)]
+    pub async fn run_once(&self, on: chrono::NaiveDate) -> database::DatabaseResult<SchedulerRun> {
+        let mut run = SchedulerRun::default();
+
+        for template in database::RecurringTransactions::find_due(on, &self.pool).await? {
+            let materialise =
+                database::RecurringTransactions::materialise(template.id, on, &self.pool);
+            match self.events.publish_committed(materialise).await {
+                Ok(created) => run.created += created.len(),
+                Err(error) => {
+                    tracing::warn!(id = %template.id, payee = %template.payee, "Could not materialise recurring transaction: {error}");
+                    run.failed += 1;
+                }
+            }
+        }
+
+        if run.created > 0 {
+            tracing::info!(
+                "Created {} transactions from recurring templates",
+                run.created
+            );
+        }
+
+        Ok(run)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn date(month: u32, day: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn run_once_skips_failing_templates(pool: sqlx::SqlitePool) -> Result<()> {
+        let weekly = database::RecurringFrequencies::Weekly;
+        let mut posted =
+            database::RecurringTransactions::new("Employer", 90_000, weekly, date(2, 20));
+        posted.auto_post = true;
+        posted.insert(&pool).await?;
+        database::RecurringTransactions::new("Gym", -2_500, weekly, date(2, 20))
+            .insert(&pool)
+            .await?;
+        database::PeriodClosings::close(date(2, 28), None, &pool).await?;
+        let scheduler = RecurringScheduler::new(pool.clone(), database::EventBus::new());
+
+        let run = scheduler.run_once(date(3, 6)).await?;
+
+        assert_eq!(
+            run,
+            SchedulerRun {
+                created: 3,
+                failed: 1
+            }
+        );
+        assert_eq!(
+            scheduler.run_once(date(3, 6)).await?,
+            SchedulerRun {
+                created: 0,
+                failed: 1
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+//! # Server Reflection
+//!
+//! Serves the standard gRPC reflection services, so grpcurl, grpcui and
+//! Postman can list the services and describe their messages without a copy
+//! of the proto files. The descriptors are the ones lib-rpc was compiled
+//! from, see [`FILE_DESCRIPTOR_SET`](rpc::FILE_DESCRIPTOR_SET), and the
+//! health service's.
+//!
+//! Both versions of the protocol are served, `grpc.reflection.v1` and the
+//! older `grpc.reflection.v1alpha` that some tools still ask for first.
+//!
+//! ```text
+//! grpcurl -plaintext 127.0.0.1:50059 list
+//! grpcurl -plaintext 127.0.0.1:50059 describe personal_ledger.categories.v001.CategoriesService
+//! ```
+
+use tonic_reflection::server::v1::{ServerReflection, ServerReflectionServer};
+use tonic_reflection::server::v1alpha::{
+    ServerReflection as ServerReflectionV1Alpha,
+    ServerReflectionServer as ServerReflectionServerV1Alpha,
+};
+
+use lib_rpc as rpc;
+
+use crate::ServerResult;
+
+/// Builds the `grpc.reflection.v1` service.
+///
+/// # Errors
+///
+/// Returns `ServerError::Reflection` if the compiled descriptors cannot be
+/// decoded.
+pub fn service_v1() -> ServerResult<ServerReflectionServer<impl ServerReflection>> {
+    Ok(builder().build_v1()?)
+}
+
+/// Builds the `grpc.reflection.v1alpha` service.
+///
+/// # Errors
+///
+/// Returns `ServerError::Reflection` if the compiled descriptors cannot be
+/// decoded.
+pub fn service_v1alpha() -> ServerResult<ServerReflectionServerV1Alpha<impl ServerReflectionV1Alpha>>
+{
+    Ok(builder().build_v1alpha()?)
+}
+
+/// A reflection builder with every descriptor the server serves.
+fn builder() -> tonic_reflection::server::Builder<'static> {
+    tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(rpc::FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(rpc::HEALTH_FILE_DESCRIPTOR_SET)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio_stream::StreamExt;
+    use tonic_reflection::pb::v1::ServerReflectionRequest;
+    use tonic_reflection::pb::v1::server_reflection_client::ServerReflectionClient;
+    use tonic_reflection::pb::v1::server_reflection_request::MessageRequest;
+    use tonic_reflection::pb::v1::server_reflection_response::MessageResponse;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn lists_every_service() -> Result<()> {
+        let reflection = service_v1()?;
+        let (client, _serving) = rpc::serve_in_process(|incoming| {
+            tonic::transport::Server::builder()
+                .add_service(reflection)
+                .serve_with_incoming(incoming)
+        });
+        let mut client = ServerReflectionClient::new(client.channel().clone());
+
+        let request = ServerReflectionRequest {
+            host: String::new(),
+            message_request: Some(MessageRequest::ListServices(String::new())),
+        };
+        let mut responses = client
+            .server_reflection_info(tokio_stream::once(request))
+            .await?
+            .into_inner();
+        let response = responses
+            .next()
+            .await
+            .transpose()?
+            .and_then(|response| response.message_response);
+        let Some(MessageResponse::ListServicesResponse(listed)) = response else {
+            panic!("expected a list of services");
+        };
+        let listed: Vec<_> = listed
+            .service
+            .into_iter()
+            .map(|service| service.name)
+            .collect();
+
+        for service in rpc::LEDGER_SERVICES {
+            assert!(
+                listed.iter().any(|name| name == service),
+                "{service} not listed"
+            );
+        }
+        assert!(listed.iter().any(|name| name == "grpc.health.v1.Health"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn builds_the_older_protocol() {
+        assert!(service_v1alpha().is_ok());
+    }
+}
@@ -0,0 +1,522 @@
+//! # Reports Service
+//!
+//! gRPC handlers for the `ReportsService`, backed by the lib-database
+//! [`Reports`](database::Reports) aggregate queries.
+//!
+//! Reports are read only, so there is no `validate_only` flag. The dashboard
+//! runs its queries in parallel on separate pool connections. Database
+//! calls are wrapped in [`metadata::time_db`] so their time is reported in
+//! the response metadata.
+
+use tonic::{Request, Response, Status};
+
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{auth, convert, metadata};
+
+/// Server implementation of the `ReportsService`.
+#[derive(Debug, Clone)]
+pub struct MyReportsService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+}
+
+impl MyReportsService {
+    /// Creates the service using the given database pool and public ID key.
+    pub fn new(pool: sqlx::SqlitePool, public_ids: domain::PublicIdKey) -> Self {
+        Self { pool, public_ids }
+    }
+}
+
+/// Number of recently recorded transactions on the dashboard.
+const DASHBOARD_RECENT_TRANSACTIONS: i32 = 10;
+
+/// Parses the `from` and `to` dates of a report request.
+fn parse_range(from: &str, to: &str) -> Result<(chrono::NaiveDate, chrono::NaiveDate), Status> {
+    Ok((
+        convert::parse_date("from", from)?,
+        convert::parse_date("to", to)?,
+    ))
+}
+
+#[tonic::async_trait]
+impl rpc::ReportsService for MyReportsService {
+    async fn reports_spending_by_category(
+        &self,
+        request: Request<rpc::ReportsSpendingByCategoryRequest>,
+    ) -> Result<Response<rpc::ReportsSpendingByCategoryResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let (from, to) = parse_range(&request.from, &request.to)?;
+
+        let spending = metadata::time_db(database::Reports::spending_by_category(
+            from, to, scope, &self.pool,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::ReportsSpendingByCategoryResponse {
+            total_spent: spending.iter().map(|category| category.spent).sum(),
+            categories: spending
+                .into_iter()
+                .map(|category| rpc::CategorySpending {
+                    category_id: category
+                        .category_id
+                        .map(|id| convert::format_id(&self.public_ids, id)),
+                    category_name: category.category_name,
+                    spent: category.spent,
+                    transaction_count: category.transaction_count,
+                })
+                .collect(),
+        }))
+    }
+
+    async fn reports_income_vs_expense(
+        &self,
+        request: Request<rpc::ReportsIncomeVsExpenseRequest>,
+    ) -> Result<Response<rpc::ReportsIncomeVsExpenseResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let (from, to) = parse_range(&request.from, &request.to)?;
+
+        let months = metadata::time_db(database::Reports::income_vs_expense(
+            from, to, scope, &self.pool,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::ReportsIncomeVsExpenseResponse {
+            months: months
+                .into_iter()
+                .map(|month| rpc::MonthlyCashFlow {
+                    month: convert::format_date(month.month),
+                    income: month.income,
+                    expense: month.expense,
+                    net: month.net(),
+                })
+                .collect(),
+        }))
+    }
+
+    async fn reports_net_worth(
+        &self,
+        request: Request<rpc::ReportsNetWorthRequest>,
+    ) -> Result<Response<rpc::ReportsNetWorthResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let (from, to) = parse_range(&request.from, &request.to)?;
+        let currency =
+            convert::parse_currency("currency", request.currency.as_deref().unwrap_or_default())?;
+
+        let points = metadata::time_db(database::Reports::net_worth(
+            currency, from, to, scope, &self.pool,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::ReportsNetWorthResponse {
+            points: points
+                .into_iter()
+                .map(|point| rpc::NetWorthPoint {
+                    on: convert::format_date(point.on),
+                    net_worth: point.net_worth.amount(),
+                })
+                .collect(),
+            currency: currency.to_string(),
+        }))
+    }
+
+    async fn reports_net_worth_history(
+        &self,
+        request: Request<rpc::ReportsNetWorthHistoryRequest>,
+    ) -> Result<Response<rpc::ReportsNetWorthHistoryResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let (from, to) = parse_range(&request.from, &request.to)?;
+        let currency =
+            convert::parse_currency("currency", request.currency.as_deref().unwrap_or_default())?;
+
+        let points = metadata::time_db(database::Reports::net_worth_history(
+            currency, from, to, scope, &self.pool,
+        ))
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::ReportsNetWorthHistoryResponse {
+            points: points
+                .into_iter()
+                .map(|point| rpc::NetWorthHistoryPoint {
+                    on: convert::format_date(point.on),
+                    net_worth: point.net_worth.amount(),
+                    accounts: point
+                        .accounts
+                        .into_iter()
+                        .map(|account| rpc::AccountWorth {
+                            account_id: convert::format_id(&self.public_ids, account.account_id),
+                            account_name: account.account_name,
+                            worth: account.worth.amount(),
+                            valued_from: match account.valued_from {
+                                database::ValuationSources::Transactions => {
+                                    rpc::ValuationSources::Transactions
+                                }
+                                database::ValuationSources::Manual => rpc::ValuationSources::Manual,
+                                database::ValuationSources::Computed => {
+                                    rpc::ValuationSources::Computed
+                                }
+                            } as i32,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            currency: currency.to_string(),
+        }))
+    }
+
+    async fn reports_dashboard(
+        &self,
+        request: Request<rpc::ReportsDashboardRequest>,
+    ) -> Result<Response<rpc::ReportsDashboardResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let on = match request.on.as_deref() {
+            Some(on) => convert::parse_date("on", on)?,
+            None => chrono::Utc::now().date_naive(),
+        };
+
+        let (accounts, budgets, recent) = metadata::time_db(async {
+            tokio::try_join!(
+                database::Reports::account_balances(on, scope, &self.pool),
+                database::Budgets::compare(database::BudgetPeriods::Monthly, on, scope, &self.pool),
+                database::Transactions::find_all_with_pagination(
+                    None,
+                    DASHBOARD_RECENT_TRANSACTIONS,
+                    scope,
+                    &self.pool
+                ),
+            )
+        })
+        .await
+        .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::ReportsDashboardResponse {
+            on: convert::format_date(on),
+            accounts: accounts
+                .into_iter()
+                .map(|account| rpc::DashboardAccount {
+                    account_id: convert::format_id(&self.public_ids, account.account_id),
+                    account_name: account.account_name,
+                    currency: account.currency.to_string(),
+                    balance: account.balance,
+                })
+                .collect(),
+            budgets: budgets
+                .into_iter()
+                .map(|comparison| rpc::DashboardBudget {
+                    budget_id: convert::format_id(&self.public_ids, comparison.budget.id),
+                    category_id: convert::format_id(
+                        &self.public_ids,
+                        comparison.budget.category_id,
+                    ),
+                    budgeted: comparison.budgeted,
+                    actual: comparison.actual,
+                    remaining: comparison.remaining(),
+                })
+                .collect(),
+            recent_transactions: recent
+                .items
+                .into_iter()
+                .map(|transaction| rpc::DashboardTransaction {
+                    id: convert::format_id(&self.public_ids, transaction.id),
+                    transaction_date: convert::format_date(transaction.transaction_date),
+                    payee: transaction.payee,
+                    amount: transaction.amount,
+                    category_id: transaction
+                        .category_id
+                        .map(|id| convert::format_id(&self.public_ids, id)),
+                    account_id: transaction
+                        .account_id
+                        .map(|id| convert::format_id(&self.public_ids, id)),
+                    is_draft: transaction.is_draft,
+                })
+                .collect(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::{CategoriesService, ReportsService, TransactionsService};
+
+    use crate::auth::as_user;
+    use crate::categories::MyCategoriesService;
+    use crate::transactions::MyTransactionsService;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    async fn post(pool: &sqlx::SqlitePool, date: &str, amount: i64) -> Result<()> {
+        let request = Request::new(rpc::TransactionCreateRequest {
+            transaction: Some(rpc::Transaction {
+                transaction_date: date.to_string(),
+                payee: "Payee".to_string(),
+                amount,
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+        MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .transaction_create(request)
+        .await?;
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn reports_total_posted_transactions(pool: sqlx::SqlitePool) -> Result<()> {
+        post(&pool, "2025-01-10", -3_000).await?;
+        post(&pool, "2025-01-12", -1_000).await?;
+        post(&pool, "2025-03-01", 1_500).await?;
+        let service = MyReportsService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let spending = service
+            .reports_spending_by_category(Request::new(rpc::ReportsSpendingByCategoryRequest {
+                from: "2025-01-01".to_string(),
+                to: "2025-03-31".to_string(),
+            }))
+            .await?
+            .into_inner();
+        // Uncategorised money received offsets the uncategorised spending
+        assert_eq!(spending.total_spent, 2_500);
+        assert_eq!(spending.categories[0].category_id, None);
+
+        let cash_flow = service
+            .reports_income_vs_expense(Request::new(rpc::ReportsIncomeVsExpenseRequest {
+                from: "2025-01-01".to_string(),
+                to: "2025-03-31".to_string(),
+            }))
+            .await?
+            .into_inner();
+        let net: Vec<_> = cash_flow.months.iter().map(|month| month.net).collect();
+        assert_eq!(net, [-4_000, 0, 1_500]);
+
+        let backwards = service
+            .reports_net_worth(Request::new(rpc::ReportsNetWorthRequest {
+                from: "2025-03-31".to_string(),
+                to: "2025-01-01".to_string(),
+                currency: None,
+            }))
+            .await;
+        assert_eq!(backwards.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn net_worth_history_breaks_down_each_account(pool: sqlx::SqlitePool) -> Result<()> {
+        let mut savings = database::Accounts::new("Savings");
+        savings.opening_balance = 100_000;
+        let savings = savings.insert(&pool).await?;
+        database::PeriodClosings::close(
+            chrono::NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+            None,
+            &pool,
+        )
+        .await?;
+        let service = MyReportsService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let history = service
+            .reports_net_worth_history(Request::new(rpc::ReportsNetWorthHistoryRequest {
+                from: "2025-01-01".to_string(),
+                to: "2025-02-28".to_string(),
+                currency: None,
+            }))
+            .await?
+            .into_inner();
+
+        assert_eq!(history.currency, "AUD");
+        let found: Vec<_> = history
+            .points
+            .iter()
+            .map(|point| {
+                (
+                    point.on.as_str(),
+                    point.net_worth,
+                    point.accounts[0].valued_from(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("2025-01-31", 100_000, rpc::ValuationSources::Computed),
+                ("2025-02-28", 100_000, rpc::ValuationSources::Transactions),
+            ]
+        );
+        assert_eq!(
+            history.points[0].accounts[0].account_id,
+            convert::format_id(&convert::TEST_PUBLIC_IDS, savings.id)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn dashboard_summarises_the_ledger(pool: sqlx::SqlitePool) -> Result<()> {
+        let mut savings = database::Accounts::new("Savings");
+        savings.opening_balance = 100_000;
+        let savings = savings.insert(&pool).await?;
+        let request = Request::new(rpc::CategoryCreateRequest {
+            category: Some(rpc::Category {
+                code: "FOOD".to_string(),
+                name: "Food".to_string(),
+                category_type: lib_domain::CategoryTypes::Expense.to_rpc_i32(),
+                is_active: true,
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+        let food = MyCategoriesService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .category_create(request)
+        .await?;
+        let food = convert::parse_id(
+            &convert::TEST_PUBLIC_IDS,
+            "id",
+            &food.into_inner().category.unwrap().id,
+        )?;
+        database::Budgets::new(food, database::BudgetPeriods::Monthly, 50_000)
+            .insert(&pool)
+            .await?;
+        for day in 1..=12 {
+            let mut transaction = database::Transactions::new(
+                chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap(),
+                "Grocer",
+                -1_000,
+            );
+            transaction.account_id = Some(savings.id);
+            transaction.category_id = Some(food);
+            transaction.insert(&pool).await?;
+        }
+        let service = MyReportsService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let dashboard = service
+            .reports_dashboard(Request::new(rpc::ReportsDashboardRequest {
+                on: Some("2025-03-10".to_string()),
+            }))
+            .await?
+            .into_inner();
+
+        assert_eq!(dashboard.on, "2025-03-10");
+        assert_eq!(dashboard.accounts.len(), 1);
+        assert_eq!(dashboard.accounts[0].balance, 90_000);
+        // The budget covers the whole month, the balance only up to the day
+        assert_eq!(dashboard.budgets[0].actual, 12_000);
+        assert_eq!(dashboard.budgets[0].remaining, 38_000);
+        // Newest recorded first, whatever the transaction date
+        assert_eq!(dashboard.recent_transactions.len(), 10);
+        assert_eq!(
+            dashboard.recent_transactions[0].transaction_date,
+            "2025-03-12"
+        );
+
+        let bad_date = service
+            .reports_dashboard(Request::new(rpc::ReportsDashboardRequest {
+                on: Some("10/03/2025".to_string()),
+            }))
+            .await;
+        assert_eq!(bad_date.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn users_only_see_reports_of_their_own_ledger(pool: sqlx::SqlitePool) -> Result<()> {
+        let ian = database::Users::new("ian").insert(&pool).await?;
+        database::Users::new("sam").insert(&pool).await?;
+        let mut savings = database::Accounts::new("Savings");
+        savings.opening_balance = 100_000;
+        savings.user_id = Some(ian.id);
+        let savings = savings.insert(&pool).await?;
+        let mut transaction = database::Transactions::new(
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+            "Grocer",
+            -1_000,
+        );
+        transaction.account_id = Some(savings.id);
+        transaction.user_id = Some(ian.id);
+        transaction.insert(&pool).await?;
+        let service = MyReportsService::new(pool, convert::TEST_PUBLIC_IDS);
+        let spending_as = |subject| {
+            as_user(
+                subject,
+                Request::new(rpc::ReportsSpendingByCategoryRequest {
+                    from: "2025-03-01".to_string(),
+                    to: "2025-03-31".to_string(),
+                }),
+            )
+        };
+        let net_worth_as = |subject| {
+            as_user(
+                subject,
+                Request::new(rpc::ReportsNetWorthRequest {
+                    from: "2025-03-01".to_string(),
+                    to: "2025-03-31".to_string(),
+                    currency: None,
+                }),
+            )
+        };
+        let dashboard_as = |subject| {
+            as_user(
+                subject,
+                Request::new(rpc::ReportsDashboardRequest {
+                    on: Some("2025-03-10".to_string()),
+                }),
+            )
+        };
+
+        let spending = service
+            .reports_spending_by_category(spending_as("ian"))
+            .await?
+            .into_inner();
+        assert_eq!(spending.total_spent, 1_000);
+        let net_worth = service
+            .reports_net_worth(net_worth_as("ian"))
+            .await?
+            .into_inner();
+        assert_eq!(net_worth.points[0].net_worth, 99_000);
+        let dashboard = service
+            .reports_dashboard(dashboard_as("ian"))
+            .await?
+            .into_inner();
+        assert_eq!(dashboard.accounts.len(), 1);
+        assert_eq!(dashboard.recent_transactions.len(), 1);
+
+        let spending = service
+            .reports_spending_by_category(spending_as("sam"))
+            .await?
+            .into_inner();
+        assert_eq!(spending.total_spent, 0);
+        let net_worth = service
+            .reports_net_worth(net_worth_as("sam"))
+            .await?
+            .into_inner();
+        assert!(net_worth.points.iter().all(|point| point.net_worth == 0));
+        let dashboard = service
+            .reports_dashboard(dashboard_as("sam"))
+            .await?
+            .into_inner();
+        assert!(dashboard.accounts.is_empty());
+        assert!(dashboard.recent_transactions.is_empty());
+
+        Ok(())
+    }
+}
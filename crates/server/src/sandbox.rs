@@ -0,0 +1,218 @@
+//! # Sandbox Mode
+//!
+//! Runs the server as a public demo when the `[Sandbox]` config section
+//! enables it, see [`SandboxConfig`](config::SandboxConfig):
+//!
+//! - The database is reset to the demo ledger of
+//!   [`DemoData`](database::DemoData) on startup, before anything is served
+//! - [`SandboxReset`] resets it again on every tick of its interval,
+//!   throwing away whatever visitors changed
+//! - [`SandboxLayer`] refuses every RPC that needs the
+//!   [`Admin`](rpc::Role::Admin) role, such as deletes, period closing and
+//!   backups, with `PERMISSION_DENIED`, so visitors cannot spoil the demo for
+//!   each other between resets
+//!
+//! Services outside the ledger's packages, such as health and reflection,
+//! pass straight through. The REST gateway checks its routes with
+//! [`SandboxLayer::check`], as they do not pass through the layer.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tonic::codegen::http;
+
+use lib_config as config;
+use lib_database as database;
+use lib_rpc as rpc;
+
+/// Resets the database to the demo ledger on a schedule.
+#[derive(Debug, Clone)]
+pub struct SandboxReset {
+    pool: sqlx::SqlitePool,
+    events: database::EventBus,
+    interval: std::time::Duration,
+}
+
+impl SandboxReset {
+    /// Creates the reset job from the sandbox configuration, or `None` when
+    /// sandbox mode is off or only resets on startup. The demo data each
+    /// reset writes is published on `events`.
+    pub fn new(
+        pool: sqlx::SqlitePool,
+        events: database::EventBus,
+        config: &config::SandboxConfig,
+    ) -> Option<Self> {
+        Some(Self {
+            pool,
+            events,
+            interval: config.reset_interval()?,
+        })
+    }
+
+    /// Resets every interval until the task is dropped. The first reset is
+    /// one interval in, as the database was reset on startup. Errors are
+    /// logged and the next reset carries on.
+    pub async fn run(self) {
+        let start = tokio::time::Instant::now() + self.interval;
+        let mut interval = tokio::time::interval_at(start, self.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            let reset = database::DemoData::reset(&self.pool);
+            if let Err(error) = self.events.publish_committed(reset).await {
+                tracing::error!("Sandbox reset error: {error}");
+            }
+        }
+    }
+}
+
+/// Layer that wraps services in [`SandboxGuard`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SandboxLayer {
+    enabled: bool,
+}
+
+impl SandboxLayer {
+    /// Creates the layer, refusing admin RPCs when sandbox mode is `enabled`
+    /// and passing everything through otherwise.
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    /// Checks the RPC named `method`, e.g. `CategoryDelete`, may be called.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PERMISSION_DENIED` in sandbox mode if the RPC needs the
+    /// admin role.
+    pub fn check(&self, method: &str) -> Result<(), tonic::Status> {
+        if self.enabled && rpc::Role::required_for(method) == rpc::Role::Admin {
+            return Err(tonic::Status::permission_denied(format!(
+                "{method} is turned off on this demo server"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl<S> tower::Layer<S> for SandboxLayer {
+    type Service = SandboxGuard<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        SandboxGuard {
+            inner,
+            enabled: self.enabled,
+        }
+    }
+}
+
+/// Service that refuses admin RPCs in sandbox mode.
+#[derive(Debug, Clone)]
+pub struct SandboxGuard<S> {
+    inner: S,
+    enabled: bool,
+}
+
+impl<S, ReqBody, ResBody> tower::Service<http::Request<ReqBody>> for SandboxGuard<S>
+where
+    S: tower::Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+    ResBody: Default + Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        let path = request.uri().path();
+
+        if rpc::ApiVersion::name_of_path(path).is_some()
+            && let Err(status) =
+                SandboxLayer::new(self.enabled).check(rpc::RpcMethod::from_path(path).name())
+        {
+            return Box::pin(async move { Ok(status.into_http()) });
+        }
+
+        Box::pin(self.inner.call(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::{Layer, ServiceExt};
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    type Handler = tower::util::BoxCloneService<
+        http::Request<()>,
+        http::Response<()>,
+        std::convert::Infallible,
+    >;
+
+    fn handler() -> Handler {
+        tower::util::BoxCloneService::new(tower::service_fn(|_request: http::Request<()>| async {
+            Ok(http::Response::new(()))
+        }))
+    }
+
+    /// The gRPC status code a request to `path` is answered with, `0` if it
+    /// reached the service.
+    async fn code_for(layer: SandboxLayer, path: &str) -> Result<String> {
+        let request = http::Request::builder().uri(path).body(())?;
+        let response = layer.layer(handler()).oneshot(request).await?;
+
+        Ok(response
+            .headers()
+            .get("grpc-status")
+            .map(|code| code.to_str().unwrap_or_default().to_string())
+            .unwrap_or_else(|| "0".to_string()))
+    }
+
+    #[tokio::test]
+    async fn refuses_admin_rpcs_when_enabled() -> Result<()> {
+        let layer = SandboxLayer::new(true);
+        let denied = (tonic::Code::PermissionDenied as i32).to_string();
+
+        let delete = "/personal_ledger.categories.v001.CategoriesService/CategoryDelete";
+        assert_eq!(code_for(layer, delete).await?, denied);
+        let backup = "/personal_ledger.utilities.v001.UtilitiesService/Backup";
+        assert_eq!(code_for(layer, backup).await?, denied);
+
+        let create = "/personal_ledger.categories.v001.CategoriesService/CategoryCreate";
+        assert_eq!(code_for(layer, create).await?, "0");
+        assert_eq!(code_for(layer, "/grpc.health.v1.Health/Check").await?, "0");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn passes_everything_through_when_disabled() -> Result<()> {
+        let delete = "/personal_ledger.categories.v001.CategoriesService/CategoryDelete";
+
+        assert_eq!(code_for(SandboxLayer::default(), delete).await?, "0");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn reset_only_scheduled_when_enabled(pool: sqlx::SqlitePool) -> Result<()> {
+        let mut config = config::SandboxConfig::default();
+        assert!(SandboxReset::new(pool.clone(), database::EventBus::new(), &config).is_none());
+
+        config.enabled = true;
+        let reset =
+            SandboxReset::new(pool, database::EventBus::new(), &config).expect("a scheduled reset");
+        assert_eq!(reset.interval, std::time::Duration::from_secs(60 * 60));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+//! # Search Service
+//!
+//! gRPC handlers for the `SearchService`, backed by the lib-database
+//! full-text search over [`Categories`](database::Categories) and
+//! [`Transactions`](database::Transactions).
+//!
+//! The search text is read by lib-database, which rejects text with no words
+//! in it as an invalid argument.
+
+use tonic::{Request, Response, Status};
+
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{auth, convert, metadata};
+
+/// Server implementation of the `SearchService`.
+#[derive(Debug, Clone)]
+pub struct MySearchService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+}
+
+impl MySearchService {
+    /// Creates the service using the given database pool and public ID key.
+    pub fn new(pool: sqlx::SqlitePool, public_ids: domain::PublicIdKey) -> Self {
+        Self { pool, public_ids }
+    }
+}
+
+#[tonic::async_trait]
+impl rpc::SearchService for MySearchService {
+    async fn search(
+        &self,
+        request: Request<rpc::SearchRequest>,
+    ) -> Result<Response<rpc::SearchResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+
+        let categories = if request.skip_categories {
+            Vec::new()
+        } else {
+            metadata::time_db(database::Categories::search(
+                &request.query,
+                request.limit,
+                scope,
+                &self.pool,
+            ))
+            .await
+            .map_err(convert::database_status)?
+        };
+
+        let transactions = if request.skip_transactions {
+            Vec::new()
+        } else {
+            metadata::time_db(database::Transactions::search(
+                &request.query,
+                request.limit,
+                scope,
+                &self.pool,
+            ))
+            .await
+            .map_err(convert::database_status)?
+        };
+
+        Ok(Response::new(rpc::SearchResponse {
+            categories: categories
+                .into_iter()
+                .map(|category| rpc::SearchCategoryHit {
+                    id: convert::format_id(&self.public_ids, category.id),
+                    code: category.code,
+                    name: category.name,
+                    description: category.description,
+                })
+                .collect(),
+            transactions: transactions
+                .into_iter()
+                .map(|transaction| rpc::SearchTransactionHit {
+                    id: convert::format_id(&self.public_ids, transaction.id),
+                    transaction_date: convert::format_date(transaction.transaction_date),
+                    payee: transaction.payee,
+                    description: transaction.description,
+                    amount: transaction.amount,
+                    is_draft: transaction.is_draft,
+                })
+                .collect(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::{SearchService, TransactionsService};
+
+    use crate::transactions::MyTransactionsService;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn search(query: &str, skip_categories: bool) -> Request<rpc::SearchRequest> {
+        Request::new(rpc::SearchRequest {
+            query: query.to_string(),
+            limit: None,
+            skip_categories,
+            skip_transactions: false,
+        })
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn search_finds_created_transactions(pool: sqlx::SqlitePool) -> Result<()> {
+        MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .transaction_create(Request::new(rpc::TransactionCreateRequest {
+            transaction: Some(rpc::Transaction {
+                transaction_date: "2025-03-14".to_string(),
+                payee: "Corner Café".to_string(),
+                amount: -1_850,
+                ..Default::default()
+            }),
+            validate_only: false,
+        }))
+        .await?;
+        let service = MySearchService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let response = service
+            .search(search("corner caf*", true))
+            .await?
+            .into_inner();
+        assert!(response.categories.is_empty());
+        assert_eq!(response.transactions.len(), 1);
+        assert_eq!(response.transactions[0].transaction_date, "2025-03-14");
+
+        let empty = service.search(search(r#""" *"#, false)).await;
+        assert_eq!(empty.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+//! # Shutdown Signals
+//!
+//! Waits for the operating system to ask the server to stop: `SIGINT` (Ctrl+C)
+//! or `SIGTERM`, which service managers such as systemd and container runtimes
+//! send. Other platforms only have Ctrl+C.
+//!
+//! The server is not stopped here. The binary passes the signal on to a
+//! [`ShutdownHandle`](crate::ShutdownHandle), so requests in flight are
+//! finished and the database is closed before the process exits.
+
+/// Completes when a shutdown signal is received, with the signal's name.
+///
+/// # Errors
+///
+/// Returns an error if the signal handlers cannot be installed.
+pub async fn shutdown_signal() -> std::io::Result<&'static str> {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        let mut interrupt = signal(SignalKind::interrupt())?;
+        let mut terminate = signal(SignalKind::terminate())?;
+
+        tokio::select! {
+            _ = interrupt.recv() => Ok("SIGINT"),
+            _ = terminate.recv() => Ok("SIGTERM"),
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        tokio::signal::ctrl_c().await?;
+        Ok("Ctrl+C")
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn shutdown_signal_completes_on_sigterm() {
+        // Installed before the signal is sent, otherwise SIGTERM would end
+        // the test process. Tokio's handler stays for the life of the process
+        let _installed =
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()).unwrap();
+        let signal = tokio::spawn(shutdown_signal());
+        tokio::task::yield_now().await;
+
+        let killed = std::process::Command::new("kill")
+            .args(["-TERM", &std::process::id().to_string()])
+            .status()
+            .expect("kill runs");
+        assert!(killed.success());
+
+        let name = tokio::time::timeout(std::time::Duration::from_secs(5), signal)
+            .await
+            .expect("the signal is received")
+            .unwrap()
+            .unwrap();
+        assert_eq!(name, "SIGTERM");
+    }
+}
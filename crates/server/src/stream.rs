@@ -0,0 +1,169 @@
+//! # Paged Streaming
+//!
+//! Shared plumbing for the server-streaming list RPCs. Rows are read from a
+//! lib-database cursor in a spawned task, grouped into pages and sent to the
+//! client through a bounded channel.
+//!
+//! The channel is what provides backpressure: once [`CHANNEL_PAGES`] pages
+//! are waiting to be sent, the task waits for the client to take one before
+//! reading any more rows, so a slow client never causes the table to be
+//! buffered in memory. If the client disconnects the send fails and the task
+//! stops, dropping the cursor and returning its connection to the pool.
+//!
+//! The watch RPCs use the same channel to push changes from the lib-database
+//! event bus, see [`watch_events`]. A client too slow to keep up has its
+//! stream ended with `ABORTED` rather than the server buffering changes for it.
+
+use futures_util::stream::BoxStream;
+use tokio_stream::{StreamExt, wrappers::ReceiverStream};
+use tonic::Status;
+
+use lib_database as database;
+
+use crate::convert;
+
+/// Page size used when the client does not ask for one.
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// Largest page size a client may ask for, larger requests are capped.
+pub const MAX_PAGE_SIZE: usize = 1_000;
+
+/// Number of pages buffered ahead of the client before reading pauses.
+pub const CHANNEL_PAGES: usize = 2;
+
+/// Number of changes buffered ahead of a watching client.
+pub const CHANNEL_CHANGES: usize = 16;
+
+/// Stream of response pages returned by a streaming RPC handler.
+pub type PageStream<M> = ReceiverStream<Result<M, Status>>;
+
+/// Checks the requested page size, applying the default and the cap.
+pub fn page_size(requested: i32) -> Result<usize, Status> {
+    match requested {
+        0 => Ok(DEFAULT_PAGE_SIZE),
+        size if size < 0 => Err(Status::invalid_argument(format!(
+            "Invalid page_size, must not be negative: {size}"
+        ))),
+        size => Ok((size as usize).min(MAX_PAGE_SIZE)),
+    }
+}
+
+/// Streams the rows returned by `rows` to the client in pages of `page_size`.
+///
+/// `rows` opens the cursor on the pool over the rows in `scope`, and `to_page` builds a response
+/// message from a page of rows. A database error is sent as the final item and
+/// ends the stream. An empty table ends the stream without sending a page.
+pub fn stream_pages<T, M>(
+    pool: sqlx::SqlitePool,
+    scope: database::UserScope,
+    page_size: usize,
+    rows: for<'p> fn(
+        database::UserScope,
+        &'p sqlx::SqlitePool,
+    ) -> BoxStream<'p, database::DatabaseResult<T>>,
+    to_page: impl Fn(Vec<T>) -> M + Send + 'static,
+) -> PageStream<M>
+where
+    T: Send + 'static,
+    M: Send + 'static,
+{
+    let (sender, receiver) = tokio::sync::mpsc::channel(CHANNEL_PAGES);
+
+    tokio::spawn(async move {
+        let mut rows = rows(scope, &pool);
+        let mut page = Vec::with_capacity(page_size);
+
+        while let Some(row) = rows.next().await {
+            match row {
+                Ok(row) => page.push(row),
+                Err(error) => {
+                    let _ = sender.send(Err(convert::database_status(error))).await;
+                    return;
+                }
+            }
+
+            if page.len() == page_size {
+                let full_page = std::mem::replace(&mut page, Vec::with_capacity(page_size));
+
+                // Waits here while the client is behind, pausing the cursor
+                if sender.send(Ok(to_page(full_page))).await.is_err() {
+                    tracing::debug!("Client disconnected, stopping stream");
+                    return;
+                }
+            }
+        }
+
+        if !page.is_empty() {
+            let _ = sender.send(Ok(to_page(page))).await;
+        }
+    });
+
+    ReceiverStream::new(receiver)
+}
+
+/// Streams the changes published on `events` from now on to the client.
+///
+/// `to_change` builds a response message from an event, or `None` for events
+/// the watch is not about. If the client falls more than
+/// [`database::EVENT_CAPACITY`] events behind, the changes it missed are
+/// reported with `ABORTED`, ending the stream. The task stops as soon as the
+/// client disconnects, without waiting for another event.
+pub fn watch_events<M>(
+    events: &database::EventBus,
+    to_change: impl Fn(database::DataEvent) -> Option<M> + Send + 'static,
+) -> PageStream<M>
+where
+    M: Send + 'static,
+{
+    // Subscribed before returning, so no change made after the call is missed
+    let mut events = events.subscribe();
+    let (sender, receiver) = tokio::sync::mpsc::channel(CHANNEL_CHANGES);
+
+    tokio::spawn(async move {
+        loop {
+            let event = tokio::select! {
+                () = sender.closed() => {
+                    tracing::debug!("Client disconnected, stopping watch");
+                    return;
+                }
+                event = events.recv() => event,
+            };
+
+            let change = match event {
+                Ok(event) => match to_change(event) {
+                    Some(change) => Ok(change),
+                    None => continue,
+                },
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(missed)) => {
+                    Err(Status::aborted(format!(
+                        "Missed {missed} changes while behind, list again and watch from there"
+                    )))
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => return,
+            };
+
+            let ends = change.is_err();
+            if sender.send(change).await.is_err() || ends {
+                return;
+            }
+        }
+    });
+
+    ReceiverStream::new(receiver)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_size_defaults_and_caps() {
+        assert_eq!(page_size(0).unwrap(), DEFAULT_PAGE_SIZE);
+        assert_eq!(page_size(25).unwrap(), 25);
+        assert_eq!(page_size(1_000_000).unwrap(), MAX_PAGE_SIZE);
+        assert_eq!(
+            page_size(-1).unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+    }
+}
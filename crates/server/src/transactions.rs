@@ -0,0 +1,2048 @@
+//! # Transactions Service
+//!
+//! gRPC handlers for the `TransactionsService`, backed by the lib-database
+//! [`Transactions`](database::Transactions) model.
+//!
+//! Mutating RPCs run inside [`database::with_transaction`] so `validate_only`
+//! requests execute the same statements as a real write and are then rolled
+//! back. Database calls are wrapped in [`metadata::time_db`] so their time is
+//! reported in the response metadata. Large lists can be fetched page by page
+//! with `TransactionsStream`, see [`stream`]. A transaction's tags and splits
+//! are written with it on create and update, and read back for single
+//! transactions and lists, but not for streams or bulk changes. Updates can
+//! name the fields to change with an update mask, see [`TransactionFields`].
+//! Both transactions of a transfer between accounts are created together,
+//! and updating or deleting either applies to the other, see
+//! [`database::Transfers`]. Probable duplicates are listed by amount, date and
+//! payee similarity, then merged into the transaction kept or dismissed.
+
+use tonic::{Request, Response, Status};
+
+use lib_config as config;
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::quota::{Quotas, Resource};
+use crate::{auth, convert, metadata, stream};
+
+/// Server implementation of the `TransactionsService`.
+#[derive(Debug, Clone)]
+pub struct MyTransactionsService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+    events: database::EventBus,
+    quotas: Quotas,
+}
+
+impl MyTransactionsService {
+    /// Creates the service using the given database pool, public ID key and
+    /// event bus, with no quota.
+    pub fn new(
+        pool: sqlx::SqlitePool,
+        public_ids: domain::PublicIdKey,
+        events: database::EventBus,
+    ) -> Self {
+        Self {
+            pool,
+            public_ids,
+            events,
+            quotas: Quotas::default(),
+        }
+    }
+
+    /// Sets the quotas checked before transactions are added.
+    pub fn with_quotas(mut self, quotas: config::QuotaConfig) -> Self {
+        self.quotas = Quotas::new(quotas);
+        self
+    }
+}
+
+/// Number of transactions written in each database transaction of a bulk edit.
+const BULK_EDIT_CHUNK_SIZE: usize = 500;
+
+/// Sample size used when a bulk edit request does not ask for one.
+const DEFAULT_SAMPLE_SIZE: usize = 10;
+
+/// Largest sample a bulk edit returns, larger requests are capped.
+const MAX_SAMPLE_SIZE: usize = 100;
+
+/// Number of map cells along the longer side of an area, when a map data
+/// request does not give a cell size.
+const DEFAULT_MAP_CELLS: f64 = 16.0;
+
+/// Number of tags suggested when a request does not ask for a number.
+const DEFAULT_TAG_SUGGESTIONS: u32 = 10;
+
+/// Most tags suggested, larger requests are capped.
+const MAX_TAG_SUGGESTIONS: u32 = 50;
+
+/// Days apart duplicates may be dated when a request does not give a window.
+const DEFAULT_DUPLICATE_WINDOW_DAYS: u32 = 3;
+
+/// Widest duplicate window, larger requests are capped.
+const MAX_DUPLICATE_WINDOW_DAYS: u32 = 31;
+
+/// Maps a database error to the gRPC status returned to the client.
+fn database_status(error: database::DatabaseError) -> Status {
+    if convert::is_foreign_key_violation(&error) {
+        return Status::invalid_argument(
+            "Transaction references a category or account that does not exist",
+        );
+    }
+
+    convert::database_status(error)
+}
+
+/// The client editable fields of a transaction, as named in the update mask
+/// of a `TransactionUpdate` request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TransactionFields {
+    TransactionDate,
+    Payee,
+    Description,
+    Amount,
+    CategoryId,
+    AccountId,
+    IsDraft,
+    Latitude,
+    Longitude,
+    PlaceName,
+    ReturnBy,
+    WarrantyExpires,
+    Tags,
+    Splits,
+}
+
+impl std::str::FromStr for TransactionFields {
+    type Err = Status;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "transaction_date" => Ok(TransactionFields::TransactionDate),
+            "payee" => Ok(TransactionFields::Payee),
+            "description" => Ok(TransactionFields::Description),
+            "amount" => Ok(TransactionFields::Amount),
+            "category_id" => Ok(TransactionFields::CategoryId),
+            "account_id" => Ok(TransactionFields::AccountId),
+            "is_draft" => Ok(TransactionFields::IsDraft),
+            "latitude" => Ok(TransactionFields::Latitude),
+            "longitude" => Ok(TransactionFields::Longitude),
+            "place_name" => Ok(TransactionFields::PlaceName),
+            "return_by" => Ok(TransactionFields::ReturnBy),
+            "warranty_expires" => Ok(TransactionFields::WarrantyExpires),
+            "tags" => Ok(TransactionFields::Tags),
+            "splits" => Ok(TransactionFields::Splits),
+            _ => Err(Status::invalid_argument(format!(
+                "Cannot update transaction field '{s}', expected transaction_date, payee, \
+                 description, amount, category_id, account_id, is_draft, latitude, longitude, \
+                 place_name, return_by, warranty_expires, tags or splits"
+            ))),
+        }
+    }
+}
+
+/// Converts a database transaction into its RPC message, without its tags or
+/// splits.
+fn to_rpc(
+    public_ids: &domain::PublicIdKey,
+    transaction: database::Transactions,
+) -> rpc::Transaction {
+    rpc::Transaction {
+        id: convert::format_id(public_ids, transaction.id),
+        transaction_date: convert::format_date(transaction.transaction_date),
+        payee: transaction.payee,
+        description: transaction.description,
+        amount: transaction.amount,
+        category_id: transaction
+            .category_id
+            .map(|id| convert::format_id(public_ids, id)),
+        account_id: transaction
+            .account_id
+            .map(|id| convert::format_id(public_ids, id)),
+        is_draft: transaction.is_draft,
+        latitude: transaction.latitude,
+        longitude: transaction.longitude,
+        place_name: transaction.place_name,
+        return_by: transaction.return_by.map(convert::format_date),
+        warranty_expires: transaction.warranty_expires.map(convert::format_date),
+        version: transaction.version,
+        cleared_status: match transaction.cleared_status {
+            database::ClearedStatus::Uncleared => rpc::ClearedStatus::Uncleared,
+            database::ClearedStatus::Cleared => rpc::ClearedStatus::Cleared,
+            database::ClearedStatus::Reconciled => rpc::ClearedStatus::Reconciled,
+        } as i32,
+        created_on: Some(convert::to_timestamp(transaction.created_on)),
+        updated_on: Some(convert::to_timestamp(transaction.updated_on)),
+        tags: Vec::new(),
+        splits: Vec::new(),
+    }
+}
+
+/// Converts a database split into its RPC message.
+fn split_to_rpc(
+    public_ids: &domain::PublicIdKey,
+    split: database::TransactionSplits,
+) -> rpc::Split {
+    rpc::Split {
+        id: convert::format_id(public_ids, split.id),
+        category_id: convert::format_id(public_ids, split.category_id),
+        amount: split.amount,
+        memo: split.memo,
+    }
+}
+
+/// Converts a database transaction and its splits into its RPC message.
+fn to_rpc_with_splits(
+    public_ids: &domain::PublicIdKey,
+    transaction: database::Transactions,
+    tags: Vec<String>,
+    splits: Vec<database::TransactionSplits>,
+) -> rpc::Transaction {
+    rpc::Transaction {
+        tags,
+        splits: splits
+            .into_iter()
+            .map(|split| split_to_rpc(public_ids, split))
+            .collect(),
+        ..to_rpc(public_ids, transaction)
+    }
+}
+
+/// Converts database transactions into their RPC messages with their tags
+/// and splits, each read in one query.
+async fn to_rpc_with_details(
+    public_ids: &domain::PublicIdKey,
+    transactions: Vec<database::Transactions>,
+    pool: &sqlx::SqlitePool,
+) -> Result<Vec<rpc::Transaction>, Status> {
+    let ids: Vec<_> = transactions
+        .iter()
+        .map(|transaction| transaction.id)
+        .collect();
+    let mut tags = metadata::time_db(database::Tags::find_for_transactions(&ids, pool))
+        .await
+        .map_err(database_status)?;
+    let mut splits = metadata::time_db(database::TransactionSplits::find_for_transactions(
+        &ids, pool,
+    ))
+    .await
+    .map_err(database_status)?;
+
+    Ok(transactions
+        .into_iter()
+        .map(|transaction| {
+            let tags = tags.remove(&transaction.id).unwrap_or_default();
+            let splits = splits.remove(&transaction.id).unwrap_or_default();
+            to_rpc_with_splits(public_ids, transaction, tags, splits)
+        })
+        .collect())
+}
+
+/// Converts the RPC splits of a transaction into unsaved database splits,
+/// each with a new id. Whether they balance is checked when they are set.
+fn splits_from_rpc(
+    public_ids: &domain::PublicIdKey,
+    transaction_id: domain::RowID,
+    splits: &[rpc::Split],
+) -> Result<Vec<database::TransactionSplits>, Status> {
+    splits
+        .iter()
+        .map(|split| {
+            let category_id =
+                convert::parse_id(public_ids, "splits.category_id", &split.category_id)?;
+            Ok(database::TransactionSplits::new(
+                transaction_id,
+                category_id,
+                split.amount,
+                split.memo.clone(),
+            ))
+        })
+        .collect()
+}
+
+/// Copies the `fields` of `message` onto the `stored` transaction, or returns
+/// `message` whole when no fields are given, as an empty mask updates every
+/// field. The version is always taken from `message`.
+fn apply_mask(
+    mut stored: rpc::Transaction,
+    message: rpc::Transaction,
+    fields: &[TransactionFields],
+) -> rpc::Transaction {
+    if fields.is_empty() {
+        return message;
+    }
+
+    for field in fields {
+        match field {
+            TransactionFields::TransactionDate => {
+                stored.transaction_date = message.transaction_date.clone()
+            }
+            TransactionFields::Payee => stored.payee = message.payee.clone(),
+            TransactionFields::Description => stored.description = message.description.clone(),
+            TransactionFields::Amount => stored.amount = message.amount,
+            TransactionFields::CategoryId => stored.category_id = message.category_id.clone(),
+            TransactionFields::AccountId => stored.account_id = message.account_id.clone(),
+            TransactionFields::IsDraft => stored.is_draft = message.is_draft,
+            TransactionFields::Latitude => stored.latitude = message.latitude,
+            TransactionFields::Longitude => stored.longitude = message.longitude,
+            TransactionFields::PlaceName => stored.place_name = message.place_name.clone(),
+            TransactionFields::ReturnBy => stored.return_by = message.return_by.clone(),
+            TransactionFields::WarrantyExpires => {
+                stored.warranty_expires = message.warranty_expires.clone()
+            }
+            TransactionFields::Tags => stored.tags = message.tags.clone(),
+            TransactionFields::Splits => stored.splits = message.splits.clone(),
+        }
+    }
+    stored.version = message.version;
+
+    stored
+}
+
+/// Applies the client editable fields of an RPC transaction to a database
+/// transaction. The id and timestamps are left untouched.
+fn apply_rpc(
+    public_ids: &domain::PublicIdKey,
+    mut transaction: database::Transactions,
+    message: rpc::Transaction,
+) -> Result<database::Transactions, Status> {
+    transaction.transaction_date =
+        convert::parse_date("transaction_date", &message.transaction_date)?;
+    transaction.payee = message.payee;
+    transaction.description = message.description;
+    transaction.amount = message.amount;
+    transaction.category_id = message
+        .category_id
+        .as_deref()
+        .map(|id| convert::parse_id(public_ids, "category_id", id))
+        .transpose()?;
+    transaction.account_id = message
+        .account_id
+        .as_deref()
+        .map(|id| convert::parse_id(public_ids, "account_id", id))
+        .transpose()?;
+    transaction.is_draft = message.is_draft;
+    transaction.latitude = message.latitude;
+    transaction.longitude = message.longitude;
+    transaction.place_name = message.place_name;
+    transaction.return_by = message
+        .return_by
+        .as_deref()
+        .map(|date| convert::parse_date("return_by", date))
+        .transpose()?;
+    transaction.warranty_expires = message
+        .warranty_expires
+        .as_deref()
+        .map(|date| convert::parse_date("warranty_expires", date))
+        .transpose()?;
+    // Zero is a client that does not track versions, so nothing is checked
+    if message.version != 0 {
+        transaction.version = message.version;
+    }
+
+    Ok(transaction)
+}
+
+/// Converts an RPC bulk edit or reassignment filter into a database filter.
+fn filter_from_rpc(
+    public_ids: &domain::PublicIdKey,
+    message: rpc::TransactionFilter,
+) -> Result<database::TransactionFilter, Status> {
+    let parse_date = |field, date: Option<String>| {
+        date.as_deref()
+            .map(|date| convert::parse_date(field, date))
+            .transpose()
+    };
+    let parse_id = |field, id: Option<String>| {
+        id.as_deref()
+            .map(|id| convert::parse_id(public_ids, field, id))
+            .transpose()
+    };
+
+    Ok(database::TransactionFilter {
+        payee: message.payee,
+        payee_pattern: message.payee_pattern,
+        from_date: parse_date("from_date", message.from_date)?,
+        to_date: parse_date("to_date", message.to_date)?,
+        category_id: parse_id("category_id", message.category_id)?,
+        account_id: parse_id("account_id", message.account_id)?,
+    })
+}
+
+#[tonic::async_trait]
+impl rpc::TransactionsService for MyTransactionsService {
+    type TransactionsStreamStream = stream::PageStream<rpc::TransactionsStreamResponse>;
+
+    async fn transaction_create(
+        &self,
+        request: Request<rpc::TransactionCreateRequest>,
+    ) -> Result<Response<rpc::TransactionCreateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let message = request
+            .transaction
+            .ok_or_else(|| Status::invalid_argument("Missing transaction"))?;
+
+        let today = chrono::Utc::now().date_naive();
+        let tags = message.tags.clone();
+        let splits = message.splits.clone();
+        let mut transaction = apply_rpc(
+            &self.public_ids,
+            database::Transactions::new(today, "", 0),
+            message,
+        )?;
+        transaction.user_id = scope.user_id();
+        let splits = splits_from_rpc(&self.public_ids, transaction.id, &splits)?;
+        self.quotas
+            .check(Resource::Transactions, 1, &self.pool)
+            .await?;
+
+        let (created, tags, splits) = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                let created = transaction.insert(&mut *conn).await?;
+                let splits =
+                    database::TransactionSplits::set_for_transaction(&created, &splits, &mut *conn)
+                        .await?;
+                let tags = database::Tags::set_for_transaction(created.id, &tags, conn).await?;
+
+                Ok((created, tags, splits))
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionCreateResponse {
+            transaction: Some(to_rpc_with_splits(&self.public_ids, created, tags, splits)),
+        }))
+    }
+
+    async fn transaction_get(
+        &self,
+        request: Request<rpc::TransactionGetRequest>,
+    ) -> Result<Response<rpc::TransactionGetResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let public_id = request.into_inner().id;
+        let id = convert::parse_id(&self.public_ids, "id", &public_id)?;
+
+        let transaction =
+            metadata::time_db(database::Transactions::find_by_id(id, scope, &self.pool))
+                .await
+                .map_err(database_status)?
+                .ok_or_else(|| {
+                    convert::not_found(
+                        "transaction",
+                        "id",
+                        format!("Transaction with id {public_id} not found"),
+                    )
+                })?;
+
+        let tags = metadata::time_db(database::Tags::find_for_transaction(id, &self.pool))
+            .await
+            .map_err(database_status)?;
+        let splits = metadata::time_db(database::TransactionSplits::find_for_transaction(
+            id, &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionGetResponse {
+            transaction: Some(to_rpc_with_splits(
+                &self.public_ids,
+                transaction,
+                tags,
+                splits,
+            )),
+        }))
+    }
+
+    async fn transactions_list(
+        &self,
+        request: Request<rpc::TransactionsListRequest>,
+    ) -> Result<Response<rpc::TransactionsListResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+
+        let after = convert::parse_page_token(&request.page_token)?;
+
+        let page = if request.drafts_only {
+            // The drafts workspace is small enough to page in memory
+            let drafts = metadata::time_db(database::Transactions::find_drafts(scope, &self.pool))
+                .await
+                .map_err(database_status)?;
+
+            database::Page::from_all(drafts, after, request.limit, |draft| {
+                database::PageCursor::new(draft.created_on, draft.id)
+            })
+        } else {
+            metadata::time_db(database::Transactions::find_all_with_pagination(
+                after,
+                request.limit,
+                scope,
+                &self.pool,
+            ))
+            .await
+            .map_err(database_status)?
+        };
+
+        Ok(Response::new(rpc::TransactionsListResponse {
+            transactions: to_rpc_with_details(&self.public_ids, page.items, &self.pool).await?,
+            total_count: page.total_count,
+            next_page_token: convert::format_page_token(page.next_cursor),
+            limit: request.limit,
+        }))
+    }
+
+    async fn transactions_stream(
+        &self,
+        request: Request<rpc::TransactionsStreamRequest>,
+    ) -> Result<Response<Self::TransactionsStreamStream>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let page_size = stream::page_size(request.into_inner().page_size)?;
+        let public_ids = self.public_ids.clone();
+
+        Ok(Response::new(stream::stream_pages(
+            self.pool.clone(),
+            scope,
+            page_size,
+            database::Transactions::stream_all,
+            move |transactions| rpc::TransactionsStreamResponse {
+                transactions: transactions
+                    .into_iter()
+                    .map(|transaction| to_rpc(&public_ids, transaction))
+                    .collect(),
+            },
+        )))
+    }
+
+    async fn transaction_update(
+        &self,
+        request: Request<rpc::TransactionUpdateRequest>,
+    ) -> Result<Response<rpc::TransactionUpdateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        let message = request
+            .transaction
+            .ok_or_else(|| Status::invalid_argument("Missing transaction"))?;
+        let fields = request
+            .update_mask
+            .map(|mask| mask.paths)
+            .unwrap_or_default()
+            .iter()
+            .map(|path| path.parse::<TransactionFields>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (updated, tags, splits) = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                let existing = database::Transactions::find_by_id(id, scope, &mut *conn)
+                    .await?
+                    .ok_or_else(|| {
+                        database::DatabaseError::NotFound(format!(
+                            "Transaction with id {} not found",
+                            request.id
+                        ))
+                    })?;
+                let as_validation = |status: Status| {
+                    database::DatabaseError::Validation(status.message().to_string())
+                };
+
+                let message = match fields.is_empty() {
+                    true => message,
+                    false => {
+                        let tags = database::Tags::find_for_transaction(id, &mut *conn).await?;
+                        let stored = rpc::Transaction {
+                            tags,
+                            ..to_rpc(&self.public_ids, existing.clone())
+                        };
+                        apply_mask(stored, message, &fields)
+                    }
+                };
+
+                // Splits left out of the mask are written back as stored, so
+                // they are checked against a changed amount and keep their ids
+                let splits = match fields.is_empty() || fields.contains(&TransactionFields::Splits)
+                {
+                    true => splits_from_rpc(&self.public_ids, id, &message.splits)
+                        .map_err(as_validation)?,
+                    false => {
+                        database::TransactionSplits::find_for_transaction(id, &mut *conn).await?
+                    }
+                };
+                let tags = message.tags.clone();
+                let transaction =
+                    apply_rpc(&self.public_ids, existing, message).map_err(as_validation)?;
+
+                let updated = transaction.update(scope, &mut *conn).await?;
+                let splits =
+                    database::TransactionSplits::set_for_transaction(&updated, &splits, &mut *conn)
+                        .await?;
+                let tags = database::Tags::set_for_transaction(id, &tags, conn).await?;
+
+                Ok((updated, tags, splits))
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionUpdateResponse {
+            transaction: Some(to_rpc_with_splits(&self.public_ids, updated, tags, splits)),
+        }))
+    }
+
+    async fn transaction_delete(
+        &self,
+        request: Request<rpc::TransactionDeleteRequest>,
+    ) -> Result<Response<rpc::TransactionDeleteResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+
+        let transfer = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                let transfer = database::Transfers::find_by_transaction(id, &mut *conn).await?;
+                database::Transactions::delete_by_id(id, scope, conn).await?;
+
+                Ok(transfer)
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionDeleteResponse {
+            rows_deleted: if transfer.is_some() { 2 } else { 1 },
+        }))
+    }
+
+    async fn transactions_post_drafts(
+        &self,
+        request: Request<rpc::TransactionsPostDraftsRequest>,
+    ) -> Result<Response<rpc::TransactionsPostDraftsResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let ids = request
+            .ids
+            .iter()
+            .map(|id| convert::parse_id(&self.public_ids, "ids", id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let posted = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Transactions::post_drafts(&ids, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionsPostDraftsResponse {
+            transactions: posted
+                .into_iter()
+                .map(|transaction| to_rpc(&self.public_ids, transaction))
+                .collect(),
+        }))
+    }
+
+    async fn transactions_mark_cleared(
+        &self,
+        request: Request<rpc::TransactionsMarkClearedRequest>,
+    ) -> Result<Response<rpc::TransactionsMarkClearedResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let ids = request
+            .ids
+            .iter()
+            .map(|id| convert::parse_id(&self.public_ids, "ids", id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let marked = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                database::Transactions::mark_cleared(&ids, request.cleared, scope, conn).await
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionsMarkClearedResponse {
+            transactions: marked
+                .into_iter()
+                .map(|transaction| to_rpc(&self.public_ids, transaction))
+                .collect(),
+        }))
+    }
+
+    async fn transactions_rollback_import(
+        &self,
+        request: Request<rpc::TransactionsRollbackImportRequest>,
+    ) -> Result<Response<rpc::TransactionsRollbackImportResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let batch_id = convert::parse_id(&self.public_ids, "batch_id", &request.batch_id)?;
+
+        let deleted = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                database::ImportBatches::rollback(batch_id, request.force, scope, conn).await
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionsRollbackImportResponse {
+            transactions: deleted
+                .into_iter()
+                .map(|transaction| to_rpc(&self.public_ids, transaction))
+                .collect(),
+        }))
+    }
+
+    async fn transactions_bulk_edit(
+        &self,
+        request: Request<rpc::TransactionsBulkEditRequest>,
+    ) -> Result<Response<rpc::TransactionsBulkEditResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let filter = filter_from_rpc(&self.public_ids, request.filter.unwrap_or_default())?;
+        let changes = request.changes.unwrap_or_default();
+        let changes = database::TransactionChanges {
+            payee: changes.payee,
+            shift_days: changes.shift_days.into(),
+            add_tags: changes.add_tags,
+        };
+        let sample_size = match request.sample_size {
+            0 => DEFAULT_SAMPLE_SIZE,
+            size if size < 0 => {
+                return Err(Status::invalid_argument(format!(
+                    "Invalid sample_size, must not be negative: {size}"
+                )));
+            }
+            size => (size as usize).min(MAX_SAMPLE_SIZE),
+        };
+
+        let preview = metadata::time_db(database::Transactions::bulk_edit_preview(
+            &filter,
+            &changes,
+            sample_size,
+            scope,
+            &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        let updated_count = if request.validate_only {
+            0
+        } else {
+            metadata::time_db(database::Transactions::bulk_edit(
+                &filter,
+                &changes,
+                BULK_EDIT_CHUNK_SIZE,
+                scope,
+                &self.pool,
+            ))
+            .await
+            .map_err(database_status)?
+        };
+
+        Ok(Response::new(rpc::TransactionsBulkEditResponse {
+            matched_count: preview.count as i32,
+            updated_count: updated_count as i32,
+            sample: preview
+                .sample
+                .into_iter()
+                .zip(preview.sample_tags)
+                .map(|(transaction, tags)| rpc::Transaction {
+                    tags,
+                    ..to_rpc(&self.public_ids, transaction)
+                })
+                .collect(),
+        }))
+    }
+
+    async fn transactions_reassign(
+        &self,
+        request: Request<rpc::TransactionsReassignRequest>,
+    ) -> Result<Response<rpc::TransactionsReassignResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let filter = filter_from_rpc(&self.public_ids, request.filter.unwrap_or_default())?;
+        let category_id = convert::parse_id(&self.public_ids, "category_id", &request.category_id)?;
+
+        let reassigned = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| database::Transactions::reassign(&filter, category_id, scope, conn).await,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionsReassignResponse {
+            reassigned_count: reassigned as i32,
+        }))
+    }
+
+    async fn transactions_map_data(
+        &self,
+        request: Request<rpc::TransactionsMapDataRequest>,
+    ) -> Result<Response<rpc::TransactionsMapDataResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let bounds = database::MapBounds {
+            min_latitude: request.min_latitude,
+            min_longitude: request.min_longitude,
+            max_latitude: request.max_latitude,
+            max_longitude: request.max_longitude,
+        };
+        let cell_degrees = if request.cell_size_degrees == 0.0 {
+            let latitude_span = bounds.max_latitude - bounds.min_latitude;
+            let longitude_span = bounds.max_longitude - bounds.min_longitude;
+            latitude_span.max(longitude_span) / DEFAULT_MAP_CELLS
+        } else {
+            request.cell_size_degrees
+        };
+
+        let clusters = metadata::time_db(database::Transactions::map_clusters(
+            bounds,
+            cell_degrees,
+            scope,
+            &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionsMapDataResponse {
+            clusters: clusters
+                .into_iter()
+                .map(|cluster| rpc::TransactionMapCluster {
+                    latitude: cluster.latitude,
+                    longitude: cluster.longitude,
+                    count: cluster.count as i32,
+                    total_amount: cluster.total_amount,
+                    place_name: cluster.place_name,
+                })
+                .collect(),
+        }))
+    }
+
+    async fn transactions_list_expiring(
+        &self,
+        request: Request<rpc::TransactionsListExpiringRequest>,
+    ) -> Result<Response<rpc::TransactionsListExpiringResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let on = match request.on.as_deref() {
+            Some(on) => convert::parse_date("on", on)?,
+            None => chrono::Utc::now().date_naive(),
+        };
+        let default = database::ExpiryRule::default();
+        let rule = database::ExpiryRule {
+            return_notice_days: request
+                .return_notice_days
+                .unwrap_or(default.return_notice_days),
+            warranty_notice_days: request
+                .warranty_notice_days
+                .unwrap_or(default.warranty_notice_days),
+        };
+
+        let reminders = metadata::time_db(rule.reminders(on, scope, &self.pool))
+            .await
+            .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionsListExpiringResponse {
+            reminders: reminders
+                .into_iter()
+                .map(|reminder| rpc::TransactionExpiry {
+                    kind: match reminder.kind {
+                        database::ExpiryKinds::Return => rpc::ExpiryKinds::Return,
+                        database::ExpiryKinds::Warranty => rpc::ExpiryKinds::Warranty,
+                    } as i32,
+                    due_on: convert::format_date(reminder.due_on),
+                    days_left: reminder.days_left,
+                    transaction: Some(to_rpc(&self.public_ids, reminder.transaction)),
+                })
+                .collect(),
+        }))
+    }
+
+    async fn transactions_list_by_tag(
+        &self,
+        request: Request<rpc::TransactionsListByTagRequest>,
+    ) -> Result<Response<rpc::TransactionsListByTagResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let tag = request.into_inner().tag;
+
+        let transactions =
+            metadata::time_db(database::Transactions::find_by_tag(&tag, scope, &self.pool))
+                .await
+                .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionsListByTagResponse {
+            transactions: to_rpc_with_details(&self.public_ids, transactions, &self.pool).await?,
+        }))
+    }
+
+    async fn transaction_tags_suggest(
+        &self,
+        request: Request<rpc::TransactionTagsSuggestRequest>,
+    ) -> Result<Response<rpc::TransactionTagsSuggestResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let limit = request
+            .limit
+            .unwrap_or(DEFAULT_TAG_SUGGESTIONS)
+            .min(MAX_TAG_SUGGESTIONS);
+
+        let suggestions = metadata::time_db(database::Tags::suggest(
+            &request.prefix,
+            limit,
+            scope,
+            &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionTagsSuggestResponse {
+            suggestions: suggestions
+                .into_iter()
+                .map(|suggestion| rpc::TagSuggestion {
+                    name: suggestion.name,
+                    transaction_count: suggestion.transaction_count,
+                })
+                .collect(),
+        }))
+    }
+
+    async fn transaction_quick_add(
+        &self,
+        request: Request<rpc::TransactionQuickAddRequest>,
+    ) -> Result<Response<rpc::TransactionQuickAddResponse>, Status> {
+        let text = request.into_inner().text;
+
+        // Parse only, the client confirms the preview before anything is saved
+        let entry = domain::QuickEntry::parse(&text).map_err(|e| {
+            tracing::debug!(error = %e, text = %text, "Quick entry text could not be parsed");
+            Status::invalid_argument(e.to_string())
+        })?;
+
+        let reply = rpc::TransactionQuickAddResponse {
+            preview: Some(entry.into()),
+        };
+
+        Ok(Response::new(reply)) // Send back parsed preview
+    }
+
+    async fn transactions_transfer(
+        &self,
+        request: Request<rpc::TransactionsTransferRequest>,
+    ) -> Result<Response<rpc::TransactionsTransferResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let from_account_id = convert::parse_id(
+            &self.public_ids,
+            "from_account_id",
+            &request.from_account_id,
+        )?;
+        let to_account_id =
+            convert::parse_id(&self.public_ids, "to_account_id", &request.to_account_id)?;
+        let transaction_date = convert::parse_date("transaction_date", &request.transaction_date)?;
+        self.quotas
+            .check(Resource::Transactions, 2, &self.pool)
+            .await?;
+
+        let (transfer, from, to) = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                database::Transfers::create(
+                    transaction_date,
+                    request.amount,
+                    from_account_id,
+                    to_account_id,
+                    request.description,
+                    scope.user_id(),
+                    conn,
+                )
+                .await
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionsTransferResponse {
+            transfer_id: convert::format_id(&self.public_ids, transfer.id),
+            from_transaction: Some(to_rpc(&self.public_ids, from)),
+            to_transaction: Some(to_rpc(&self.public_ids, to)),
+        }))
+    }
+
+    async fn transactions_list_duplicates(
+        &self,
+        request: Request<rpc::TransactionsListDuplicatesRequest>,
+    ) -> Result<Response<rpc::TransactionsListDuplicatesResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let window_days = request
+            .into_inner()
+            .window_days
+            .unwrap_or(DEFAULT_DUPLICATE_WINDOW_DAYS)
+            .min(MAX_DUPLICATE_WINDOW_DAYS);
+
+        let pairs = metadata::time_db(database::Transactions::find_duplicates(
+            window_days,
+            scope,
+            &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionsListDuplicatesResponse {
+            pairs: pairs
+                .into_iter()
+                .map(|pair| rpc::DuplicatePair {
+                    original: Some(to_rpc(&self.public_ids, pair.original)),
+                    duplicate: Some(to_rpc(&self.public_ids, pair.duplicate)),
+                    similarity: pair.similarity,
+                    days_apart: pair.days_apart as i32,
+                })
+                .collect(),
+        }))
+    }
+
+    async fn transactions_merge_duplicate(
+        &self,
+        request: Request<rpc::TransactionsMergeDuplicateRequest>,
+    ) -> Result<Response<rpc::TransactionsMergeDuplicateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let keep_id = convert::parse_id(&self.public_ids, "keep_id", &request.keep_id)?;
+        let duplicate_id =
+            convert::parse_id(&self.public_ids, "duplicate_id", &request.duplicate_id)?;
+
+        let (merged, tags, splits) = metadata::time_db(database::with_transaction(
+            &self.pool,
+            &self.events,
+            request.validate_only,
+            async |conn| {
+                let merged = database::Transactions::merge_duplicate(
+                    keep_id,
+                    duplicate_id,
+                    scope,
+                    &mut *conn,
+                )
+                .await?;
+                let tags = database::Tags::find_for_transaction(keep_id, &mut *conn).await?;
+                let splits =
+                    database::TransactionSplits::find_for_transaction(keep_id, conn).await?;
+
+                Ok((merged, tags, splits))
+            },
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionsMergeDuplicateResponse {
+            transaction: Some(to_rpc_with_splits(&self.public_ids, merged, tags, splits)),
+        }))
+    }
+
+    async fn transactions_dismiss_duplicate(
+        &self,
+        request: Request<rpc::TransactionsDismissDuplicateRequest>,
+    ) -> Result<Response<rpc::TransactionsDismissDuplicateResponse>, Status> {
+        let scope = auth::user_scope(&request, &self.pool).await?;
+        let request = request.into_inner();
+        let id = convert::parse_id(&self.public_ids, "id", &request.id)?;
+        let other_id = convert::parse_id(&self.public_ids, "other_id", &request.other_id)?;
+
+        metadata::time_db(database::Transactions::dismiss_duplicate(
+            id, other_id, scope, &self.pool,
+        ))
+        .await
+        .map_err(database_status)?;
+
+        Ok(Response::new(rpc::TransactionsDismissDuplicateResponse {}))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::TransactionsService;
+
+    use crate::auth::as_user;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn message(payee: &str, amount: i64) -> rpc::Transaction {
+        rpc::Transaction {
+            transaction_date: "2025-03-14".to_string(),
+            payee: payee.to_string(),
+            amount,
+            ..Default::default()
+        }
+    }
+
+    async fn create(
+        service: &MyTransactionsService,
+        transaction: rpc::Transaction,
+        validate_only: bool,
+    ) -> core::result::Result<rpc::Transaction, Status> {
+        let request = Request::new(rpc::TransactionCreateRequest {
+            transaction: Some(transaction),
+            validate_only,
+        });
+
+        Ok(service
+            .transaction_create(request)
+            .await?
+            .into_inner()
+            .transaction
+            .unwrap())
+    }
+
+    async fn count(service: &MyTransactionsService) -> Result<i32> {
+        let request = Request::new(rpc::TransactionsListRequest {
+            page_token: String::new(),
+            limit: 100,
+            drafts_only: false,
+        });
+
+        Ok(service
+            .transactions_list(request)
+            .await?
+            .into_inner()
+            .total_count)
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn create_and_get_round_trip(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+
+        let created = create(&service, message("Corner Cafe", -1_250), false).await?;
+        let request = Request::new(rpc::TransactionGetRequest {
+            id: created.id.clone(),
+        });
+        let fetched = service
+            .transaction_get(request)
+            .await?
+            .into_inner()
+            .transaction
+            .unwrap();
+
+        assert_eq!(fetched, created);
+        assert_eq!(fetched.transaction_date, "2025-03-14");
+        assert_eq!(fetched.amount, -1_250);
+        assert!(fetched.created_on.is_some());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn create_validate_only_does_not_persist(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+
+        let preview = create(&service, message("Corner Cafe", -1_250), true).await?;
+
+        assert_eq!(preview.payee, "Corner Cafe");
+        assert_eq!(count(&service).await?, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn create_rejects_invalid_input(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+
+        let zero_amount = create(&service, message("Corner Cafe", 0), false).await;
+        let mut bad_date = message("Corner Cafe", -1_250);
+        bad_date.transaction_date = "14/03/2025".to_string();
+        let bad_date = create(&service, bad_date, false).await;
+        let mut unknown_category = message("Corner Cafe", -1_250);
+        unknown_category.category_id = Some(convert::format_id(
+            &convert::TEST_PUBLIC_IDS,
+            domain::RowID::new(),
+        ));
+        let unknown_category = create(&service, unknown_category, false).await;
+
+        assert_eq!(
+            zero_amount.unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+        assert_eq!(bad_date.unwrap_err().code(), tonic::Code::InvalidArgument);
+        assert_eq!(
+            unknown_category.unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+        assert_eq!(count(&service).await?, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn update_changes_fields(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let created = create(&service, message("Corner Cafe", -1_250), false).await?;
+
+        let request = Request::new(rpc::TransactionUpdateRequest {
+            id: created.id.clone(),
+            transaction: Some(message("Corner Cafe", -1_450)),
+            validate_only: false,
+            update_mask: None,
+        });
+        let updated = service
+            .transaction_update(request)
+            .await?
+            .into_inner()
+            .transaction
+            .unwrap();
+
+        assert_eq!(updated.id, created.id);
+        assert_eq!(updated.amount, -1_450);
+        assert_eq!(updated.created_on, created.created_on);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn delete_validate_only_keeps_transaction(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let created = create(&service, message("Corner Cafe", -1_250), false).await?;
+
+        let delete = |validate_only| {
+            Request::new(rpc::TransactionDeleteRequest {
+                id: created.id.clone(),
+                validate_only,
+            })
+        };
+
+        service.transaction_delete(delete(true)).await?;
+        assert_eq!(count(&service).await?, 1);
+
+        let response = service
+            .transaction_delete(delete(false))
+            .await?
+            .into_inner();
+        assert_eq!(response.rows_deleted, 1);
+        assert_eq!(count(&service).await?, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn stream_sends_every_transaction_in_pages(pool: sqlx::SqlitePool) -> Result<()> {
+        use tokio_stream::StreamExt;
+
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        for amount in 1..=5 {
+            create(&service, message("Corner Cafe", -amount), false).await?;
+        }
+
+        let request = Request::new(rpc::TransactionsStreamRequest { page_size: 2 });
+        let pages: Vec<_> = service
+            .transactions_stream(request)
+            .await?
+            .into_inner()
+            .collect::<core::result::Result<_, _>>()
+            .await?;
+        let sizes: Vec<_> = pages.iter().map(|page| page.transactions.len()).collect();
+
+        assert_eq!(sizes, vec![2, 2, 1]);
+
+        let negative = service
+            .transactions_stream(Request::new(rpc::TransactionsStreamRequest {
+                page_size: -1,
+            }))
+            .await;
+        assert_eq!(negative.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn drafts_are_listed_and_posted(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        create(&service, message("Corner Cafe", -1_250), false).await?;
+        let mut draft = message("Cash Lunch", -1_800);
+        draft.is_draft = true;
+        let draft = create(&service, draft, false).await?;
+
+        let list_drafts = || {
+            Request::new(rpc::TransactionsListRequest {
+                page_token: String::new(),
+                limit: 100,
+                drafts_only: true,
+            })
+        };
+        let drafts = service.transactions_list(list_drafts()).await?.into_inner();
+        assert_eq!(drafts.total_count, 1);
+        assert_eq!(drafts.transactions[0].id, draft.id);
+
+        let post = |validate_only| {
+            Request::new(rpc::TransactionsPostDraftsRequest {
+                ids: vec![draft.id.clone()],
+                validate_only,
+            })
+        };
+        let preview = service
+            .transactions_post_drafts(post(true))
+            .await?
+            .into_inner();
+        assert!(!preview.transactions[0].is_draft);
+        assert_eq!(
+            service
+                .transactions_list(list_drafts())
+                .await?
+                .into_inner()
+                .total_count,
+            1
+        );
+
+        service.transactions_post_drafts(post(false)).await?;
+        assert_eq!(
+            service
+                .transactions_list(list_drafts())
+                .await?
+                .into_inner()
+                .total_count,
+            0
+        );
+
+        let again = service.transactions_post_drafts(post(false)).await;
+        assert_eq!(again.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn rollback_import_deletes_imported_transactions(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        create(&service, message("Corner Cafe", -1_250), false).await?;
+
+        let export = "Date,Payee,Amount\n2025-03-15,Employer,2500\n2025-03-16,Bakery,-6\n";
+        let mapping = database::CsvMapping::new(
+            database::CsvColumn::Name("Date".to_string()),
+            database::CsvColumn::Name("Payee".to_string()),
+            database::CsvAmount::Signed(database::CsvColumn::Name("Amount".to_string())),
+        );
+        let batch = database::ImportBatches::new(export.as_bytes(), Some("march.csv"), None);
+        database::ImportSummary::import(&batch, &mapping.parse(export.as_bytes())?, false, &pool)
+            .await?;
+
+        let rollback = |validate_only| {
+            Request::new(rpc::TransactionsRollbackImportRequest {
+                batch_id: convert::format_id(&convert::TEST_PUBLIC_IDS, batch.id),
+                force: false,
+                validate_only,
+            })
+        };
+        let preview = service
+            .transactions_rollback_import(rollback(true))
+            .await?
+            .into_inner();
+        assert_eq!(preview.transactions.len(), 2);
+        assert_eq!(count(&service).await?, 3);
+
+        service
+            .transactions_rollback_import(rollback(false))
+            .await?;
+        assert_eq!(count(&service).await?, 1);
+
+        let again = service.transactions_rollback_import(rollback(false)).await;
+        assert_eq!(again.unwrap_err().code(), tonic::Code::NotFound);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn users_only_roll_back_imports_of_their_own_transactions(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let ian = database::Users::new("ian").insert(&pool).await?;
+        database::Users::new("sam").insert(&pool).await?;
+        let export = "Date,Payee,Amount\n2025-03-16,Bakery,-6\n";
+        let mapping = database::CsvMapping::new(
+            database::CsvColumn::Name("Date".to_string()),
+            database::CsvColumn::Name("Payee".to_string()),
+            database::CsvAmount::Signed(database::CsvColumn::Name("Amount".to_string())),
+        );
+        let batch = database::ImportBatches::new(export.as_bytes(), Some("march.csv"), None);
+        database::ImportSummary::import(&batch, &mapping.parse(export.as_bytes())?, false, &pool)
+            .await?;
+        sqlx::query("UPDATE transactions SET user_id = ?")
+            .bind(ian.id)
+            .execute(&pool)
+            .await?;
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let rollback_as = |subject| {
+            as_user(
+                subject,
+                Request::new(rpc::TransactionsRollbackImportRequest {
+                    batch_id: convert::format_id(&convert::TEST_PUBLIC_IDS, batch.id),
+                    force: false,
+                    validate_only: false,
+                }),
+            )
+        };
+
+        let status = service
+            .transactions_rollback_import(rollback_as("sam"))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        let rolled_back = service
+            .transactions_rollback_import(rollback_as("ian"))
+            .await?
+            .into_inner();
+        assert_eq!(rolled_back.transactions.len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn bulk_edit_previews_then_applies(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        for _ in 0..3 {
+            create(&service, message("CORNER CAFE PTY", -1_250), false).await?;
+        }
+        create(&service, message("Bakery", -600), false).await?;
+
+        let bulk_edit = |payee: &str, validate_only| {
+            Request::new(rpc::TransactionsBulkEditRequest {
+                filter: Some(rpc::TransactionFilter {
+                    payee: Some(payee.to_string()),
+                    ..Default::default()
+                }),
+                changes: Some(rpc::TransactionChanges {
+                    payee: Some("Corner Cafe".to_string()),
+                    shift_days: 1,
+                    add_tags: vec!["#Coffee".to_string()],
+                }),
+                sample_size: 2,
+                validate_only,
+            })
+        };
+
+        let preview = service
+            .transactions_bulk_edit(bulk_edit("corner cafe pty", true))
+            .await?
+            .into_inner();
+        assert_eq!(preview.matched_count, 3);
+        assert_eq!(preview.updated_count, 0);
+        assert_eq!(preview.sample.len(), 2);
+        assert_eq!(preview.sample[0].payee, "Corner Cafe");
+        assert_eq!(preview.sample[0].transaction_date, "2025-03-15");
+        assert_eq!(preview.sample[0].tags, ["coffee"]);
+
+        let applied = service
+            .transactions_bulk_edit(bulk_edit("corner cafe pty", false))
+            .await?
+            .into_inner();
+        assert_eq!(applied.updated_count, 3);
+        let listed = service
+            .transactions_list(Request::new(rpc::TransactionsListRequest::default()))
+            .await?
+            .into_inner();
+        let tagged = listed
+            .transactions
+            .iter()
+            .filter(|transaction| transaction.tags == ["coffee"])
+            .count();
+        assert_eq!(tagged, 3);
+
+        let renamed = service
+            .transactions_bulk_edit(bulk_edit("Corner Cafe", true))
+            .await?
+            .into_inner();
+        assert_eq!(renamed.matched_count, 3);
+
+        let request = Request::new(rpc::TransactionsBulkEditRequest::default());
+        let empty = service.transactions_bulk_edit(request).await;
+        assert_eq!(empty.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn reassign_previews_then_moves(pool: sqlx::SqlitePool) -> Result<()> {
+        let shopping = database::CategoriesBuilder::new()
+            .with_code("SHOPPING")
+            .with_name("Shopping")
+            .with_category_type(domain::CategoryTypes::Expense)
+            .build()?
+            .insert(&pool)
+            .await?;
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        for payee in ["AMZN Mktp AU", "amzn mktp us", "Bakery"] {
+            create(&service, message(payee, -2_000), false).await?;
+        }
+
+        let reassign = |category_id: String, validate_only| {
+            Request::new(rpc::TransactionsReassignRequest {
+                filter: Some(rpc::TransactionFilter {
+                    payee_pattern: Some("AMZN*".to_string()),
+                    ..Default::default()
+                }),
+                category_id,
+                validate_only,
+            })
+        };
+        let shopping_id = convert::format_id(&convert::TEST_PUBLIC_IDS, shopping.id);
+
+        let preview = service
+            .transactions_reassign(reassign(shopping_id.clone(), true))
+            .await?
+            .into_inner();
+        let applied = service
+            .transactions_reassign(reassign(shopping_id.clone(), false))
+            .await?
+            .into_inner();
+        let again = service
+            .transactions_reassign(reassign(shopping_id, false))
+            .await?
+            .into_inner();
+        assert_eq!(preview.reassigned_count, 2);
+        assert_eq!(applied.reassigned_count, 2);
+        assert_eq!(again.reassigned_count, 0);
+
+        let unknown = service
+            .transactions_reassign(reassign(
+                convert::format_id(&convert::TEST_PUBLIC_IDS, domain::RowID::new()),
+                false,
+            ))
+            .await;
+        assert_eq!(unknown.unwrap_err().code(), tonic::Code::NotFound);
+        let invalid = service
+            .transactions_reassign(reassign("not-an-id".to_string(), false))
+            .await;
+        assert_eq!(invalid.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn map_data_clusters_located_transactions(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        for (latitude, place_name) in [
+            (-27.47, "West End"),
+            (-27.48, "West End"),
+            (-27.90, "Logan"),
+        ] {
+            let transaction = rpc::Transaction {
+                latitude: Some(latitude),
+                longitude: Some(153.02),
+                place_name: Some(place_name.to_string()),
+                ..message("Cafe", -500)
+            };
+            create(&service, transaction, false).await?;
+        }
+        create(&service, message("Online", -900), false).await?;
+
+        let request = |min_latitude| {
+            Request::new(rpc::TransactionsMapDataRequest {
+                min_latitude,
+                min_longitude: 152.5,
+                max_latitude: -27.0,
+                max_longitude: 153.5,
+                cell_size_degrees: 0.0,
+            })
+        };
+
+        let response = service
+            .transactions_map_data(request(-28.0))
+            .await?
+            .into_inner();
+        let counts: Vec<i32> = response
+            .clusters
+            .iter()
+            .map(|cluster| cluster.count)
+            .collect();
+        assert_eq!(counts, vec![1, 2]);
+        assert_eq!(response.clusters[1].place_name.as_deref(), Some("West End"));
+
+        let half_located = rpc::Transaction {
+            latitude: Some(-27.47),
+            ..message("Cafe", -500)
+        };
+        let created = create(&service, half_located, false).await;
+        assert_eq!(created.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        let inverted = service.transactions_map_data(request(-26.0)).await;
+        assert_eq!(inverted.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn list_expiring_uses_notice_days(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let kettle = rpc::Transaction {
+            return_by: Some("2025-03-28".to_string()),
+            warranty_expires: Some("2026-03-14".to_string()),
+            ..message("Kettle", -4_900)
+        };
+        let created = create(&service, kettle, false).await?;
+        assert_eq!(created.return_by.as_deref(), Some("2025-03-28"));
+
+        let request = |on: &str, return_notice_days| {
+            Request::new(rpc::TransactionsListExpiringRequest {
+                on: Some(on.to_string()),
+                return_notice_days,
+                warranty_notice_days: None,
+            })
+        };
+
+        let response = service
+            .transactions_list_expiring(request("2025-03-20", None))
+            .await?
+            .into_inner();
+        assert!(response.reminders.is_empty());
+
+        let response = service
+            .transactions_list_expiring(request("2025-03-20", Some(10)))
+            .await?
+            .into_inner();
+        assert_eq!(response.reminders.len(), 1);
+        assert_eq!(response.reminders[0].kind(), rpc::ExpiryKinds::Return);
+        assert_eq!(response.reminders[0].days_left, 8);
+
+        let response = service
+            .transactions_list_expiring(request("2026-03-01", None))
+            .await?
+            .into_inner();
+        assert_eq!(response.reminders[0].kind(), rpc::ExpiryKinds::Warranty);
+
+        let early = rpc::Transaction {
+            return_by: Some("2025-03-01".to_string()),
+            ..message("Toaster", -2_900)
+        };
+        let created = create(&service, early, false).await;
+        assert_eq!(created.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn tags_are_saved_listed_and_suggested(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let flight = rpc::Transaction {
+            tags: vec!["#Travel".to_string(), "work".to_string()],
+            ..message("Qantas", -45_000)
+        };
+        let flight = create(&service, flight, false).await?;
+        let hotel = rpc::Transaction {
+            tags: vec!["travel".to_string()],
+            ..message("Hotel", -30_000)
+        };
+        create(&service, hotel, false).await?;
+        create(&service, message("Grocer", -6_000), false).await?;
+
+        assert_eq!(flight.tags, ["travel", "work"]);
+
+        let request = Request::new(rpc::TransactionUpdateRequest {
+            id: flight.id.clone(),
+            transaction: Some(rpc::Transaction {
+                tags: vec!["travel".to_string()],
+                ..flight.clone()
+            }),
+            validate_only: false,
+            update_mask: None,
+        });
+        service.transaction_update(request).await?;
+
+        let request = Request::new(rpc::TransactionGetRequest {
+            id: flight.id.clone(),
+        });
+        let fetched = service
+            .transaction_get(request)
+            .await?
+            .into_inner()
+            .transaction
+            .unwrap();
+        assert_eq!(fetched.tags, ["travel"]);
+
+        let request = Request::new(rpc::TransactionsListByTagRequest {
+            tag: "TRAVEL".to_string(),
+        });
+        let travel = service
+            .transactions_list_by_tag(request)
+            .await?
+            .into_inner()
+            .transactions;
+        assert_eq!(travel.len(), 2);
+        assert!(
+            travel
+                .iter()
+                .all(|transaction| transaction.tags == ["travel"])
+        );
+
+        let request = Request::new(rpc::TransactionTagsSuggestRequest {
+            prefix: "t".to_string(),
+            limit: None,
+        });
+        let suggestions = service
+            .transaction_tags_suggest(request)
+            .await?
+            .into_inner()
+            .suggestions;
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].transaction_count, 2);
+
+        let spaced = rpc::Transaction {
+            tags: vec!["road trip".to_string()],
+            ..message("Servo", -8_000)
+        };
+        let result = create(&service, spaced, false).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        Ok(())
+    }
+
+    async fn category(pool: &sqlx::SqlitePool, code: &str) -> Result<String> {
+        use rpc::CategoriesService;
+
+        let request = Request::new(rpc::CategoryCreateRequest {
+            category: Some(rpc::Category {
+                code: code.to_string(),
+                name: code.to_string(),
+                category_type: domain::CategoryTypes::Expense.to_rpc_i32(),
+                is_active: true,
+                ..Default::default()
+            }),
+            validate_only: false,
+        });
+        let response = crate::categories::MyCategoriesService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        )
+        .category_create(request)
+        .await?;
+
+        Ok(response.into_inner().category.unwrap().id)
+    }
+
+    fn split(category_id: &str, amount: i64) -> rpc::Split {
+        rpc::Split {
+            category_id: category_id.to_string(),
+            amount,
+            ..Default::default()
+        }
+    }
+
+    fn update(
+        id: &str,
+        transaction: rpc::Transaction,
+        paths: &[&str],
+    ) -> Request<rpc::TransactionUpdateRequest> {
+        Request::new(rpc::TransactionUpdateRequest {
+            id: id.to_string(),
+            transaction: Some(transaction),
+            validate_only: false,
+            update_mask: Some(prost_types::FieldMask {
+                paths: paths.iter().map(|path| path.to_string()).collect(),
+            }),
+        })
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn splits_are_saved_and_kept_by_masked_updates(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let groceries = category(&pool, "GRO").await?;
+        let household = category(&pool, "HOU").await?;
+        let receipt = rpc::Transaction {
+            splits: vec![split(&groceries, -9_000), split(&household, -3_000)],
+            ..message("Supermarket", -12_000)
+        };
+
+        let created = create(&service, receipt, false).await?;
+        let request = Request::new(rpc::TransactionGetRequest {
+            id: created.id.clone(),
+        });
+        let fetched = service
+            .transaction_get(request)
+            .await?
+            .into_inner()
+            .transaction
+            .unwrap();
+
+        assert_eq!(fetched, created);
+        assert_eq!(fetched.splits.len(), 2);
+        assert_eq!(fetched.splits[0].category_id, groceries);
+        assert!(fetched.splits.iter().all(|split| !split.id.is_empty()));
+
+        // Only the payee is named, so the empty splits in the message are ignored
+        let renamed = rpc::Transaction {
+            payee: "Fresh Market".to_string(),
+            ..Default::default()
+        };
+        let updated = service
+            .transaction_update(update(&created.id, renamed, &["payee"]))
+            .await?
+            .into_inner()
+            .transaction
+            .unwrap();
+
+        assert_eq!(updated.payee, "Fresh Market");
+        assert_eq!(updated.amount, -12_000);
+        assert_eq!(updated.transaction_date, "2025-03-14");
+        assert_eq!(updated.splits, created.splits);
+
+        let resplit = rpc::Transaction {
+            splits: vec![split(&groceries, -10_000), split(&household, -2_000)],
+            ..Default::default()
+        };
+        let updated = service
+            .transaction_update(update(&created.id, resplit, &["splits"]))
+            .await?
+            .into_inner()
+            .transaction
+            .unwrap();
+
+        assert_eq!(updated.payee, "Fresh Market");
+        assert_eq!(updated.splits[0].amount, -10_000);
+        assert_ne!(updated.splits[0].id, created.splits[0].id);
+
+        let request = Request::new(rpc::TransactionsListRequest {
+            page_token: String::new(),
+            limit: 10,
+            drafts_only: false,
+        });
+        let listed = service
+            .transactions_list(request)
+            .await?
+            .into_inner()
+            .transactions;
+        assert_eq!(listed[0].splits, updated.splits);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn unbalanced_splits_are_rejected(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let groceries = category(&pool, "GRO").await?;
+        let household = category(&pool, "HOU").await?;
+
+        let unbalanced = rpc::Transaction {
+            splits: vec![split(&groceries, -9_000), split(&household, -2_000)],
+            ..message("Supermarket", -12_000)
+        };
+        let result = create(&service, unbalanced, false).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+        assert_eq!(count(&service).await?, 0);
+
+        let receipt = rpc::Transaction {
+            splits: vec![split(&groceries, -9_000), split(&household, -3_000)],
+            ..message("Supermarket", -12_000)
+        };
+        let created = create(&service, receipt, false).await?;
+
+        // The stored splits no longer sum to a changed amount
+        let repriced = rpc::Transaction {
+            amount: -13_000,
+            ..Default::default()
+        };
+        let result = service
+            .transaction_update(update(&created.id, repriced, &["amount"]))
+            .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        let result = service
+            .transaction_update(update(&created.id, created.clone(), &["version"]))
+            .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        let request = Request::new(rpc::TransactionGetRequest {
+            id: created.id.clone(),
+        });
+        let fetched = service
+            .transaction_get(request)
+            .await?
+            .into_inner()
+            .transaction
+            .unwrap();
+        assert_eq!(fetched, created);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn transfers_are_created_updated_and_deleted_together(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let service = MyTransactionsService::new(
+            pool.clone(),
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+        );
+        let everyday = database::Accounts::new("Everyday").insert(&pool).await?;
+        let savings = database::Accounts::new("Savings").insert(&pool).await?;
+        let transfer = |amount| {
+            Request::new(rpc::TransactionsTransferRequest {
+                from_account_id: convert::format_id(&convert::TEST_PUBLIC_IDS, everyday.id),
+                to_account_id: convert::format_id(&convert::TEST_PUBLIC_IDS, savings.id),
+                amount,
+                transaction_date: "2025-03-14".to_string(),
+                description: None,
+                validate_only: false,
+            })
+        };
+
+        let result = service.transactions_transfer(transfer(0)).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        let response = service
+            .transactions_transfer(transfer(50_000))
+            .await?
+            .into_inner();
+        let (from, to) = (
+            response.from_transaction.unwrap(),
+            response.to_transaction.unwrap(),
+        );
+        assert_eq!((from.amount, to.amount), (-50_000, 50_000));
+        assert_eq!(to.payee, "Transfer from Everyday");
+
+        let repriced = rpc::Transaction {
+            amount: 60_000,
+            ..Default::default()
+        };
+        service
+            .transaction_update(update(&to.id, repriced, &["amount"]))
+            .await?;
+        let request = Request::new(rpc::TransactionGetRequest {
+            id: from.id.clone(),
+        });
+        let fetched = service
+            .transaction_get(request)
+            .await?
+            .into_inner()
+            .transaction
+            .unwrap();
+        assert_eq!(fetched.amount, -60_000);
+
+        let request = Request::new(rpc::TransactionDeleteRequest {
+            id: from.id.clone(),
+            validate_only: false,
+        });
+        let deleted = service.transaction_delete(request).await?.into_inner();
+        assert_eq!(deleted.rows_deleted, 2);
+        assert_eq!(count(&service).await?, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn duplicates_are_listed_then_merged_or_dismissed(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let typed = create(&service, message("Corner Cafe", -1_250), false).await?;
+        let imported = rpc::Transaction {
+            place_name: Some("West End".to_string()),
+            ..message("CORNER CAFE PTY LTD", -1_250)
+        };
+        let imported = create(&service, imported, false).await?;
+        let grocer = create(&service, message("Grocer", -6_000), false).await?;
+        let grocer_again = create(&service, message("GROCER", -6_000), false).await?;
+
+        let list = async || -> core::result::Result<Vec<rpc::DuplicatePair>, Status> {
+            let request =
+                Request::new(rpc::TransactionsListDuplicatesRequest { window_days: None });
+            Ok(service
+                .transactions_list_duplicates(request)
+                .await?
+                .into_inner()
+                .pairs)
+        };
+        let pairs = list().await?;
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[0].original.as_ref().unwrap().id, grocer.id);
+        assert_eq!(pairs[1].duplicate.as_ref().unwrap().id, imported.id);
+
+        let request = Request::new(rpc::TransactionsDismissDuplicateRequest {
+            id: grocer.id.clone(),
+            other_id: grocer_again.id.clone(),
+        });
+        service.transactions_dismiss_duplicate(request).await?;
+
+        let request = Request::new(rpc::TransactionsMergeDuplicateRequest {
+            keep_id: typed.id.clone(),
+            duplicate_id: imported.id.clone(),
+            validate_only: false,
+        });
+        let merged = service
+            .transactions_merge_duplicate(request)
+            .await?
+            .into_inner()
+            .transaction
+            .unwrap();
+
+        assert_eq!(merged.payee, "Corner Cafe");
+        assert_eq!(merged.place_name.as_deref(), Some("West End"));
+        assert!(list().await?.is_empty());
+        assert_eq!(count(&service).await?, 3);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn missing_transaction_is_not_found(pool: sqlx::SqlitePool) -> Result<()> {
+        let service =
+            MyTransactionsService::new(pool, convert::TEST_PUBLIC_IDS, database::EventBus::new());
+        let id = convert::format_id(&convert::TEST_PUBLIC_IDS, domain::RowID::new());
+
+        let get = service
+            .transaction_get(Request::new(rpc::TransactionGetRequest { id: id.clone() }))
+            .await;
+        let delete = service
+            .transaction_delete(Request::new(rpc::TransactionDeleteRequest {
+                id,
+                validate_only: false,
+            }))
+            .await;
+
+        assert_eq!(get.unwrap_err().code(), tonic::Code::NotFound);
+        assert_eq!(delete.unwrap_err().code(), tonic::Code::NotFound);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,610 @@
+//! # Utilities Service
+//!
+//! gRPC handlers for the `UtilitiesService`: the deprecated ping, kept for
+//! v001 clients now health is checked with the standard health service, the
+//! state of the database migrations the server was built with, from
+//! [`MigrationStatus`](database::MigrationStatus), and the server version with
+//! the API version agreed with the client, from [`API_VERSIONS`](rpc::API_VERSIONS).
+//!
+//! `GetUsage` reports what the ledger holds, counted with
+//! [`Usage`](database::Usage), against the configured quotas.
+//!
+//! `VerifyLedger` runs the consistency checks of
+//! [`LedgerVerification`](database::LedgerVerification) and reports what they
+//! found. The checks finish before the response is sent, as there is nowhere
+//! to keep a report for the client to collect later.
+//!
+//! `RoundTripExport` exports the ledger and reads it back into an empty
+//! in-memory database with [`RoundTrip`](database::RoundTrip), comparing
+//! record counts and balances to check an export would restore the ledger.
+//!
+//! `SetLogLevel` replaces the log filter with
+//! [`set_filter`](telemetry::set_filter), so a live server can be turned up
+//! to trace and back down again without a restart.
+//!
+//! Backups are written with [`DatabaseBackup`](database::DatabaseBackup) to
+//! the configured backup directory. Clients only name the file, so they
+//! cannot write anywhere else on the server.
+
+use std::path::PathBuf;
+
+use tonic::{Request, Response, Status};
+
+use lib_config as config;
+use lib_database as database;
+use lib_domain as domain;
+use lib_rpc as rpc;
+use lib_telemetry as telemetry;
+
+use crate::quota::{Quotas, Resource};
+use crate::{backups, convert, metadata};
+
+/// Server implementation of the `UtilitiesService`.
+#[derive(Debug, Clone)]
+pub struct MyUtilitiesService {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+    backup_directory: Option<PathBuf>,
+    quotas: Quotas,
+}
+
+impl MyUtilitiesService {
+    /// Creates the service using the given database pool and public ID key,
+    /// with backups turned off.
+    pub fn new(pool: sqlx::SqlitePool, public_ids: domain::PublicIdKey) -> Self {
+        Self {
+            pool,
+            public_ids,
+            backup_directory: None,
+            quotas: Quotas::default(),
+        }
+    }
+
+    /// Sets the directory backups are written to, turning backups on when
+    /// it is `Some`.
+    pub fn with_backup_directory(mut self, backup_directory: Option<PathBuf>) -> Self {
+        self.backup_directory = backup_directory;
+        self
+    }
+
+    /// Sets the quotas usage is reported against.
+    pub fn with_quotas(mut self, quotas: config::QuotaConfig) -> Self {
+        self.quotas = Quotas::new(quotas);
+        self
+    }
+}
+
+/// The RPC enum of a ledger check.
+fn check_to_rpc(check: database::LedgerChecks) -> rpc::LedgerChecks {
+    match check {
+        database::LedgerChecks::JournalBalance => rpc::LedgerChecks::JournalBalance,
+        database::LedgerChecks::AccountBalance => rpc::LedgerChecks::AccountBalance,
+        database::LedgerChecks::SearchIndex => rpc::LedgerChecks::SearchIndex,
+        database::LedgerChecks::ReceiptAttachments => rpc::LedgerChecks::ReceiptAttachments,
+    }
+}
+
+/// Converts a round trip check for the client, with the account as a public
+/// ID.
+fn round_trip_check_to_rpc(
+    public_ids: &domain::PublicIdKey,
+    check: database::RoundTripCheck,
+) -> rpc::RoundTripCheck {
+    let kind = match check.check {
+        database::RoundTripChecks::CategoryCount => rpc::RoundTripChecks::CategoryCount,
+        database::RoundTripChecks::AccountCount => rpc::RoundTripChecks::AccountCount,
+        database::RoundTripChecks::TransactionCount => rpc::RoundTripChecks::TransactionCount,
+        database::RoundTripChecks::TransactionTotal => rpc::RoundTripChecks::TransactionTotal,
+        database::RoundTripChecks::AccountBalance => rpc::RoundTripChecks::AccountBalance,
+    };
+
+    rpc::RoundTripCheck {
+        check: kind.into(),
+        record_id: check.record_id.map(|id| convert::format_id(public_ids, id)),
+        exported: check.exported,
+        imported: check.imported,
+    }
+}
+
+/// Converts a finding for the client. Record IDs are sent as public IDs,
+/// except for search index rows whose ID is not a row ID at all.
+fn finding_to_rpc(
+    public_ids: &domain::PublicIdKey,
+    finding: database::LedgerFinding,
+) -> rpc::LedgerFinding {
+    let record_id = match finding.record_id.parse::<domain::RowID>() {
+        Ok(id) => convert::format_id(public_ids, id),
+        Err(_) => finding.record_id,
+    };
+
+    rpc::LedgerFinding {
+        check: check_to_rpc(finding.check).into(),
+        record_id,
+        message: finding.message,
+    }
+}
+
+/// Checks a backup file name sent by the client is a plain file name, with
+/// no directories that could point outside the backup directory.
+fn parse_backup_file_name(file_name: Option<String>) -> Result<String, Status> {
+    let Some(file_name) = file_name else {
+        return Ok(backups::backup_file_name(chrono::Utc::now()));
+    };
+
+    // Leading dots also rule out `.` and `..`
+    let is_plain =
+        !file_name.is_empty() && !file_name.starts_with('.') && !file_name.contains(['/', '\\']);
+    if !is_plain {
+        return Err(Status::invalid_argument(format!(
+            "file_name: {file_name:?} must be a file name without directories"
+        )));
+    }
+
+    Ok(file_name)
+}
+
+/// Converts a database migration status into its RPC message.
+fn to_rpc(status: database::MigrationStatus) -> rpc::Migration {
+    rpc::Migration {
+        version: status.version,
+        description: status.description,
+        applied_on: status.applied_on.map(convert::to_timestamp),
+        is_modified: status.is_modified,
+    }
+}
+
+#[tonic::async_trait]
+impl rpc::UtilitiesService for MyUtilitiesService {
+    async fn ping(
+        &self,
+        request: Request<rpc::PingRequest>,
+    ) -> Result<Response<rpc::PingResponse>, Status> {
+        println!("Got a request from {:?}", request.remote_addr());
+
+        let reply = rpc::PingResponse {
+            message: "Pong...".to_string(),
+        };
+
+        Ok(Response::new(reply)) // Send back ping response
+    }
+
+    async fn migration_status(
+        &self,
+        _request: Request<rpc::MigrationStatusRequest>,
+    ) -> Result<Response<rpc::MigrationStatusResponse>, Status> {
+        let migrations = metadata::time_db(database::MigrationStatus::find_all(&self.pool))
+            .await
+            .map_err(convert::database_status)?;
+
+        let current_version = migrations
+            .iter()
+            .filter(|status| status.is_applied())
+            .map(|status| status.version)
+            .max()
+            .unwrap_or_default();
+        let pending_count = migrations
+            .iter()
+            .filter(|status| !status.is_applied())
+            .count() as i32;
+
+        Ok(Response::new(rpc::MigrationStatusResponse {
+            migrations: migrations.into_iter().map(to_rpc).collect(),
+            current_version,
+            pending_count,
+        }))
+    }
+
+    async fn get_usage(
+        &self,
+        _request: Request<rpc::GetUsageRequest>,
+    ) -> Result<Response<rpc::GetUsageResponse>, Status> {
+        let usage = metadata::time_db(database::Usage::find(&self.pool))
+            .await
+            .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::GetUsageResponse {
+            transactions: Some(self.quotas.to_rpc(Resource::Transactions, &usage)),
+            categories: Some(self.quotas.to_rpc(Resource::Categories, &usage)),
+            attachment_bytes: Some(self.quotas.to_rpc(Resource::AttachmentBytes, &usage)),
+        }))
+    }
+
+    async fn verify_ledger(
+        &self,
+        _request: Request<rpc::VerifyLedgerRequest>,
+    ) -> Result<Response<rpc::VerifyLedgerResponse>, Status> {
+        let verification = metadata::time_db(database::LedgerVerification::run(&self.pool))
+            .await
+            .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::VerifyLedgerResponse {
+            findings: verification
+                .findings
+                .into_iter()
+                .map(|finding| finding_to_rpc(&self.public_ids, finding))
+                .collect(),
+            checks: database::LedgerChecks::ALL
+                .into_iter()
+                .map(|check| check_to_rpc(check).into())
+                .collect(),
+            checked_on: Some(convert::to_timestamp(verification.checked_on)),
+        }))
+    }
+
+    async fn round_trip_export(
+        &self,
+        _request: Request<rpc::RoundTripExportRequest>,
+    ) -> Result<Response<rpc::RoundTripExportResponse>, Status> {
+        let round_trip = metadata::time_db(database::RoundTrip::run(&self.pool))
+            .await
+            .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::RoundTripExportResponse {
+            is_intact: round_trip.is_intact(),
+            checks: round_trip
+                .checks
+                .into_iter()
+                .map(|check| round_trip_check_to_rpc(&self.public_ids, check))
+                .collect(),
+            checked_on: Some(convert::to_timestamp(round_trip.checked_on)),
+        }))
+    }
+
+    async fn set_log_level(
+        &self,
+        request: Request<rpc::SetLogLevelRequest>,
+    ) -> Result<Response<rpc::SetLogLevelResponse>, Status> {
+        if telemetry::current_filter().is_none() {
+            return Err(Status::failed_precondition(
+                "Telemetry is turned off on this server",
+            ));
+        }
+
+        let filter = request.into_inner().filter;
+        let previous_filter =
+            telemetry::set_filter(&filter).map_err(|e| Status::invalid_argument(e.to_string()))?;
+        tracing::warn!(previous_filter = %previous_filter, "Log filter changed to {filter}");
+
+        Ok(Response::new(rpc::SetLogLevelResponse {
+            previous_filter,
+            filter: telemetry::current_filter().unwrap_or(filter),
+        }))
+    }
+
+    async fn server_info(
+        &self,
+        request: Request<rpc::ServerInfoRequest>,
+    ) -> Result<Response<rpc::ServerInfoResponse>, Status> {
+        let client_versions = request.into_inner().api_versions;
+
+        let Some(agreed) = rpc::ApiVersion::negotiate(&client_versions) else {
+            let served: Vec<_> = rpc::API_VERSIONS
+                .iter()
+                .map(|version| version.name)
+                .collect();
+            return Err(Status::failed_precondition(format!(
+                "No supported API version in {}, the server supports {}",
+                client_versions.join(", "),
+                served.join(", ")
+            )));
+        };
+
+        Ok(Response::new(rpc::ServerInfoResponse {
+            server_version: env!("CARGO_PKG_VERSION").to_string(),
+            api_version: agreed.name.to_string(),
+            api_versions: rpc::API_VERSIONS
+                .iter()
+                .map(rpc::ApiVersionInfo::from)
+                .collect(),
+        }))
+    }
+
+    async fn backup(
+        &self,
+        request: Request<rpc::BackupRequest>,
+    ) -> Result<Response<rpc::BackupResponse>, Status> {
+        let Some(backup_directory) = &self.backup_directory else {
+            return Err(Status::failed_precondition(
+                "Backups are turned off, set backup.directory to turn them on",
+            ));
+        };
+
+        let file_name = parse_backup_file_name(request.into_inner().file_name)?;
+        let path = backup_directory.join(file_name);
+        if path.exists() {
+            return Err(Status::already_exists(format!(
+                "Backup {} already exists",
+                path.display()
+            )));
+        }
+
+        tokio::fs::create_dir_all(backup_directory).await.map_err(|e| {
+            tracing::error!(error = %e, directory = %backup_directory.display(), "Cannot create backup directory");
+            Status::internal("Cannot create backup directory")
+        })?;
+
+        let backup = metadata::time_db(database::DatabaseBackup::backup_to(&self.pool, path))
+            .await
+            .map_err(convert::database_status)?;
+
+        Ok(Response::new(rpc::BackupResponse {
+            path: backup.path.display().to_string(),
+            size_bytes: backup.size_bytes as i64,
+            migration_version: backup.migration_version,
+            created_on: Some(convert::to_timestamp(backup.created_on)),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::UtilitiesService;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn migration_status_reports_applied_migrations(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyUtilitiesService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let status = service
+            .migration_status(Request::new(rpc::MigrationStatusRequest {}))
+            .await?
+            .into_inner();
+
+        assert_eq!(status.pending_count, 0);
+        assert_eq!(
+            status.current_version,
+            status.migrations.last().unwrap().version
+        );
+        assert!(
+            status
+                .migrations
+                .iter()
+                .all(|migration| migration.applied_on.is_some())
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn get_usage_reports_counts_against_quotas(pool: sqlx::SqlitePool) -> Result<()> {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        database::Transactions::new(date, "Cafe", -550)
+            .insert(&pool)
+            .await?;
+        let service = MyUtilitiesService::new(pool, convert::TEST_PUBLIC_IDS).with_quotas(
+            config::QuotaConfig {
+                max_transactions: Some(500),
+                ..Default::default()
+            },
+        );
+
+        let usage = service
+            .get_usage(Request::new(rpc::GetUsageRequest {}))
+            .await?
+            .into_inner();
+
+        let transactions = usage.transactions.unwrap();
+        assert_eq!((transactions.used, transactions.limit), (1, Some(500)));
+        assert_eq!(usage.categories.unwrap().limit, None);
+        assert_eq!(usage.attachment_bytes.unwrap().used, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn verify_ledger_reports_findings_with_public_ids(pool: sqlx::SqlitePool) -> Result<()> {
+        let category = database::CategoriesBuilder::new()
+            .with_code("EXP.001")
+            .with_name("Groceries")
+            .with_category_type(domain::CategoryTypes::Expense)
+            .build()?
+            .insert(&pool)
+            .await?;
+        sqlx::query("DELETE FROM categories_fts WHERE id = ?")
+            .bind(category.id)
+            .execute(&pool)
+            .await?;
+        let service = MyUtilitiesService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let report = service
+            .verify_ledger(Request::new(rpc::VerifyLedgerRequest {}))
+            .await?
+            .into_inner();
+
+        assert_eq!(report.checks.len(), database::LedgerChecks::ALL.len());
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].check(), rpc::LedgerChecks::SearchIndex);
+        assert_eq!(
+            report.findings[0].record_id,
+            convert::format_id(&convert::TEST_PUBLIC_IDS, category.id)
+        );
+        assert!(report.checked_on.is_some());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn round_trip_export_reports_balances_with_public_ids(
+        pool: sqlx::SqlitePool,
+    ) -> Result<()> {
+        let mut account = database::Accounts::new("Everyday");
+        account.opening_balance = 25_000;
+        let account = account.insert(&pool).await?;
+        let service = MyUtilitiesService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let report = service
+            .round_trip_export(Request::new(rpc::RoundTripExportRequest {}))
+            .await?
+            .into_inner();
+
+        assert!(report.is_intact);
+        let balance = report.checks.last().unwrap();
+        assert_eq!(balance.check(), rpc::RoundTripChecks::AccountBalance);
+        assert_eq!(
+            balance.record_id,
+            Some(convert::format_id(&convert::TEST_PUBLIC_IDS, account.id))
+        );
+        assert_eq!((balance.exported, balance.imported), (25_000, 25_000));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn set_log_level_replaces_the_filter(pool: sqlx::SqlitePool) -> Result<()> {
+        // Initialise if no other test has yet, so there is a filter to replace
+        let _ = telemetry::init(None);
+        let service = MyUtilitiesService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let changed = service
+            .set_log_level(Request::new(rpc::SetLogLevelRequest {
+                filter: "info,lib_database=trace".to_string(),
+            }))
+            .await?
+            .into_inner();
+        assert_eq!(changed.filter, "lib_database=trace,info");
+
+        let invalid = service
+            .set_log_level(Request::new(rpc::SetLogLevelRequest {
+                filter: "lib_database=loud".to_string(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(invalid.code(), tonic::Code::InvalidArgument);
+
+        let restored = service
+            .set_log_level(Request::new(rpc::SetLogLevelRequest {
+                filter: changed.previous_filter.clone(),
+            }))
+            .await?
+            .into_inner();
+        assert_eq!(restored.previous_filter, changed.filter);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn server_info_agrees_on_api_version(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyUtilitiesService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let info = service
+            .server_info(Request::new(rpc::ServerInfoRequest {
+                api_versions: vec!["v000".to_string(), rpc::CURRENT_API_VERSION.to_string()],
+            }))
+            .await?
+            .into_inner();
+
+        assert_eq!(info.server_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.api_version, rpc::CURRENT_API_VERSION);
+        assert_eq!(info.api_versions.len(), rpc::API_VERSIONS.len());
+
+        let info = service
+            .server_info(Request::new(rpc::ServerInfoRequest::default()))
+            .await?
+            .into_inner();
+        assert_eq!(info.api_version, rpc::CURRENT_API_VERSION);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn server_info_rejects_unsupported_api_versions(pool: sqlx::SqlitePool) {
+        let service = MyUtilitiesService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let status = service
+            .server_info(Request::new(rpc::ServerInfoRequest {
+                api_versions: vec!["v000".to_string()],
+            }))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        assert!(status.message().contains(rpc::CURRENT_API_VERSION));
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn backup_writes_to_backup_directory(pool: sqlx::SqlitePool) -> Result<()> {
+        let directory =
+            std::env::temp_dir().join(format!("ledger-backups-{}", uuid::Uuid::now_v7()));
+        let service = MyUtilitiesService::new(pool, convert::TEST_PUBLIC_IDS)
+            .with_backup_directory(Some(directory.clone()));
+
+        let backup = service
+            .backup(Request::new(rpc::BackupRequest {
+                file_name: Some("nightly.db".to_string()),
+            }))
+            .await?
+            .into_inner();
+
+        assert_eq!(
+            backup.path,
+            directory.join("nightly.db").display().to_string()
+        );
+        assert_eq!(
+            backup.size_bytes as u64,
+            std::fs::metadata(&backup.path)?.len()
+        );
+        assert!(backup.migration_version > 0);
+
+        let again = service
+            .backup(Request::new(rpc::BackupRequest {
+                file_name: Some("nightly.db".to_string()),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(again.code(), tonic::Code::AlreadyExists);
+
+        let named = service
+            .backup(Request::new(rpc::BackupRequest::default()))
+            .await?
+            .into_inner();
+        assert!(named.path.ends_with(".db"));
+
+        std::fs::remove_dir_all(directory)?;
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn backup_rejects_paths_and_disabled_backups(pool: sqlx::SqlitePool) {
+        let disabled = MyUtilitiesService::new(pool.clone(), convert::TEST_PUBLIC_IDS);
+        let status = disabled
+            .backup(Request::new(rpc::BackupRequest::default()))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+        let service = MyUtilitiesService::new(pool, convert::TEST_PUBLIC_IDS)
+            .with_backup_directory(Some(std::env::temp_dir()));
+        for file_name in [
+            "../escape.db",
+            "/etc/ledger.db",
+            "..",
+            "",
+            "nested/ledger.db",
+        ] {
+            let status = service
+                .backup(Request::new(rpc::BackupRequest {
+                    file_name: Some(file_name.to_string()),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument, "{file_name}");
+        }
+    }
+
+    #[sqlx::test(migrations = false)]
+    async fn migration_status_reports_pending_migrations(pool: sqlx::SqlitePool) -> Result<()> {
+        let service = MyUtilitiesService::new(pool, convert::TEST_PUBLIC_IDS);
+
+        let status = service
+            .migration_status(Request::new(rpc::MigrationStatusRequest {}))
+            .await?
+            .into_inner();
+
+        assert_eq!(status.current_version, 0);
+        assert_eq!(status.pending_count as usize, status.migrations.len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+//! # API Versions
+//!
+//! Tower layer that marks responses from deprecated API versions, so clients
+//! can warn before the version is removed:
+//!
+//! - `deprecation`: `true` for every RPC in a deprecated version
+//! - `sunset`: the date the version stops being served, in ISO 8601 format
+//!
+//! The version is read from the request path, e.g. `v001` in
+//! `/personal_ledger.categories.v001.CategoriesService/CategoryGet`. Requests
+//! to current and supported versions, and to services outside the ledger's
+//! packages, pass straight through.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tonic::codegen::http;
+
+use lib_rpc as rpc;
+
+/// Metadata key marking a response from a deprecated API version.
+pub const DEPRECATION_HEADER: &str = "deprecation";
+
+/// Metadata key carrying the date a deprecated API version is removed.
+pub const SUNSET_HEADER: &str = "sunset";
+
+/// Layer that wraps services in [`ApiVersionMetadata`].
+#[derive(Debug, Clone, Copy)]
+pub struct ApiVersionLayer {
+    versions: &'static [rpc::ApiVersion],
+}
+
+impl ApiVersionLayer {
+    /// Creates the layer for the given API versions, normally
+    /// [`API_VERSIONS`](rpc::API_VERSIONS).
+    pub fn new(versions: &'static [rpc::ApiVersion]) -> Self {
+        Self { versions }
+    }
+}
+
+impl Default for ApiVersionLayer {
+    fn default() -> Self {
+        Self::new(rpc::API_VERSIONS)
+    }
+}
+
+impl<S> tower::Layer<S> for ApiVersionLayer {
+    type Service = ApiVersionMetadata<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiVersionMetadata {
+            inner,
+            versions: self.versions,
+        }
+    }
+}
+
+/// Service that adds deprecation metadata to responses from deprecated API
+/// versions.
+#[derive(Debug, Clone)]
+pub struct ApiVersionMetadata<S> {
+    inner: S,
+    versions: &'static [rpc::ApiVersion],
+}
+
+impl<S, ReqBody, ResBody> tower::Service<http::Request<ReqBody>> for ApiVersionMetadata<S>
+where
+    S: tower::Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        let deprecated = rpc::ApiVersion::name_of_path(request.uri().path())
+            .and_then(|name| rpc::ApiVersion::find_in(self.versions, name))
+            .filter(|version| version.is_deprecated())
+            .copied();
+
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            let mut response = future.await?;
+
+            if let Some(version) = deprecated {
+                tracing::warn!("Request to deprecated API version {}", version.name);
+
+                let headers = response.headers_mut();
+                headers.insert(DEPRECATION_HEADER, http::HeaderValue::from_static("true"));
+                if let Some(sunset_on) = version.sunset_on {
+                    headers.insert(SUNSET_HEADER, http::HeaderValue::from_static(sunset_on));
+                }
+            }
+
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::{Layer, ServiceExt};
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    type Handler = tower::util::BoxCloneService<
+        http::Request<()>,
+        http::Response<()>,
+        std::convert::Infallible,
+    >;
+
+    const VERSIONS: &[rpc::ApiVersion] = &[
+        rpc::ApiVersion {
+            name: "v002",
+            state: rpc::ApiVersionStates::Current,
+            sunset_on: None,
+        },
+        rpc::ApiVersion {
+            name: "v001",
+            state: rpc::ApiVersionStates::Deprecated,
+            sunset_on: Some("2026-06-30"),
+        },
+    ];
+
+    fn handler() -> Handler {
+        Handler::new(tower::service_fn(
+            |_request: http::Request<()>| async move { Ok(http::Response::new(())) },
+        ))
+    }
+
+    async fn call(path: &str) -> Result<http::Response<()>> {
+        let service = ApiVersionLayer::new(VERSIONS).layer(handler());
+        let request = http::Request::builder().uri(path).body(())?;
+
+        Ok(service.oneshot(request).await?)
+    }
+
+    #[tokio::test]
+    async fn marks_deprecated_version() -> Result<()> {
+        let response =
+            call("/personal_ledger.categories.v001.CategoriesService/CategoryGet").await?;
+
+        assert_eq!(response.headers()[DEPRECATION_HEADER], "true");
+        assert_eq!(response.headers()[SUNSET_HEADER], "2026-06-30");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn passes_current_and_unknown_versions_through() -> Result<()> {
+        for path in [
+            "/personal_ledger.categories.v002.CategoriesService/CategoryGet",
+            "/grpc.health.v1.Health/Check",
+        ] {
+            let response = call(path).await?;
+
+            assert!(response.headers().get(DEPRECATION_HEADER).is_none());
+            assert!(response.headers().get(SUNSET_HEADER).is_none());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,434 @@
+//! # Webhook Event Publishing
+//!
+//! Posts changes to the ledger to the endpoints in the [`WebhooksConfig`],
+//! as they are announced on the server's [event bus](database::EventBus).
+//! Built with the `webhooks` feature.
+//!
+//! Each event is posted to every endpoint as a JSON object naming the event
+//! and the public ID of the row it happened to:
+//!
+//! ```json
+//! {
+//!   "id": "0190f5c2-...",
+//!   "event": "category.created",
+//!   "occurred_on": "2025-03-01T09:30:00.000000Z",
+//!   "data": { "id": "AbC123..." }
+//! }
+//! ```
+//!
+//! The receiver reads the row through the API if it needs more than its ID.
+//! Deliveries are signed with HMAC-SHA256 over `<timestamp>.<body>`, keyed by
+//! the shared secret, and the signature sent as `sha256=<hex>` in the
+//! [`SIGNATURE_HEADER`], with the Unix timestamp in the [`TIMESTAMP_HEADER`].
+//! Signing the timestamp lets a receiver refuse old deliveries played back.
+//!
+//! A delivery that fails, with no answer or an answer other than 2xx, is
+//! tried again after a doubling backoff, until its attempts are used up.
+//! Every delivery is logged in
+//! [`WebhookDeliveries`](database::WebhookDeliveries) with its latest
+//! response. Deliveries run side by side, so a slow endpoint holds up only
+//! its own events.
+
+use hmac::Mac;
+use lib_config::WebhooksConfig;
+use lib_database as database;
+use lib_domain as domain;
+
+use crate::convert;
+use crate::error::{ServerError, ServerResult};
+
+/// Header carrying the delivery's signature, `sha256=<hex>`.
+pub const SIGNATURE_HEADER: &str = "X-Ledger-Signature";
+
+/// Header carrying the Unix timestamp the delivery was signed at.
+pub const TIMESTAMP_HEADER: &str = "X-Ledger-Timestamp";
+
+/// Header carrying the name of the event, such as `category.created`.
+pub const EVENT_HEADER: &str = "X-Ledger-Event";
+
+/// The JSON body posted for an event, naming records by their public IDs
+/// under the ledger's `public_ids` key.
+pub fn payload(public_ids: &domain::PublicIdKey, event: &database::DataEvent) -> String {
+    serde_json::json!({
+        "id": event.event_id.to_string(),
+        "event": event.name(),
+        "occurred_on": event.occurred_on.to_rfc3339_opts(chrono::SecondsFormat::Micros, true),
+        "data": { "id": convert::format_id(public_ids, event.id()) },
+    })
+    .to_string()
+}
+
+/// Signs a body posted at `timestamp` with the shared secret, returning the
+/// [`SIGNATURE_HEADER`] value.
+pub fn sign(secret: &str, timestamp: i64, payload: &str) -> String {
+    let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC takes a key of any length");
+    mac.update(format!("{timestamp}.{payload}").as_bytes());
+
+    let signature: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    format!("sha256={signature}")
+}
+
+/// Why an attempt at a delivery failed.
+#[derive(Debug)]
+struct AttemptFailure {
+    /// The HTTP status the endpoint answered with, if it answered.
+    response_status: Option<i64>,
+    message: String,
+}
+
+/// Posts data events to the configured webhooks.
+#[derive(Debug, Clone)]
+pub struct WebhookPublisher {
+    pool: sqlx::SqlitePool,
+    public_ids: domain::PublicIdKey,
+    events: database::EventBus,
+    client: reqwest::Client,
+    config: WebhooksConfig,
+    secret: String,
+}
+
+impl WebhookPublisher {
+    /// Creates a publisher of the events on `events` from the webhooks
+    /// configuration, or `None` when no endpoint is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ServerError::Config`] if endpoints are set without a secret,
+    /// or the HTTP client cannot be built.
+    pub fn new(
+        pool: sqlx::SqlitePool,
+        public_ids: domain::PublicIdKey,
+        events: database::EventBus,
+        config: &WebhooksConfig,
+    ) -> ServerResult<Option<Self>> {
+        if !config.is_enabled() {
+            return Ok(None);
+        }
+
+        let secret = config
+            .secret
+            .clone()
+            .filter(|secret| !secret.is_empty())
+            .ok_or_else(|| {
+                invalid_config("webhooks secret must be set to sign deliveries".to_string())
+            })?;
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout())
+            .build()
+            .map_err(|error| invalid_config(format!("cannot build the webhook client: {error}")))?;
+
+        Ok(Some(Self {
+            pool,
+            public_ids,
+            events,
+            client,
+            config: config.clone(),
+            secret,
+        }))
+    }
+
+    /// Posts events to every endpoint as they are published, until the task
+    /// is dropped, which stops any deliveries still being tried.
+    pub async fn run(self) {
+        let mut events = self.events.subscribe();
+        let mut deliveries = tokio::task::JoinSet::new();
+
+        loop {
+            tokio::select! {
+                received = events.recv() => match received {
+                    Ok(event) if self.config.sends(event.name()) => {
+                        for endpoint in self.config.endpoints() {
+                            let publisher = self.clone();
+                            let event = event.clone();
+                            let endpoint = endpoint.to_string();
+                            deliveries.spawn(async move {
+                                if let Err(error) = publisher.deliver(&event, &endpoint).await {
+                                    tracing::error!("Webhook delivery error: {error}");
+                                }
+                            });
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(missed)) => {
+                        tracing::warn!("Webhooks fell behind and missed {missed} events");
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => return,
+                },
+                Some(_) = deliveries.join_next() => {}
+            }
+        }
+    }
+
+    /// Delivers an event to one endpoint, trying until it is accepted or its
+    /// attempts are used up, and logging how it went.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delivery cannot be logged. A delivery the
+    /// endpoint never accepts is logged as failed rather than returned as an
+    /// error.
+    #[tracing::instrument(
+        name = "Deliver webhook",
+        skip(self, event),
+        fields(event_id = %event.event_id, event_name = %event.name()),
+        err
+    )]
+    pub async fn deliver(
+        &self,
+        event: &database::DataEvent,
+        endpoint: &str,
+    ) -> database::DatabaseResult<database::WebhookDeliveries> {
+        let body = payload(&self.public_ids, event);
+        let delivery =
+            database::WebhookDeliveries::new(event.event_id, event.name(), endpoint, &body)
+                .insert(&self.pool)
+                .await?;
+        let max_attempts = self.config.max_attempts.max(1);
+
+        for attempt in 1..=max_attempts {
+            let (status, response_status, error) = match self.attempt(&delivery).await {
+                Ok(response_status) => (
+                    database::WebhookDeliveryStatuses::Delivered,
+                    Some(response_status),
+                    None,
+                ),
+                Err(failure) if attempt == max_attempts => (
+                    database::WebhookDeliveryStatuses::Failed,
+                    failure.response_status,
+                    Some(failure.message),
+                ),
+                Err(failure) => (
+                    database::WebhookDeliveryStatuses::Pending,
+                    failure.response_status,
+                    Some(failure.message),
+                ),
+            };
+            let recorded = delivery
+                .record_attempt(status, response_status, error.as_deref(), &self.pool)
+                .await?;
+
+            if status != database::WebhookDeliveryStatuses::Pending {
+                if status == database::WebhookDeliveryStatuses::Failed {
+                    tracing::warn!(
+                        "Gave up delivering {} to {endpoint} after {attempt} attempts",
+                        event.name()
+                    );
+                }
+                return Ok(recorded);
+            }
+
+            tokio::time::sleep(self.config.retry_delay(attempt)).await;
+        }
+
+        unreachable!("the last attempt is recorded as delivered or failed")
+    }
+
+    /// Signs and posts a delivery once, returning the endpoint's 2xx status.
+    async fn attempt(&self, delivery: &database::WebhookDeliveries) -> Result<i64, AttemptFailure> {
+        let timestamp = chrono::Utc::now().timestamp();
+
+        let response = self
+            .client
+            .post(&delivery.endpoint)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, &delivery.event_name)
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(
+                SIGNATURE_HEADER,
+                sign(&self.secret, timestamp, &delivery.payload),
+            )
+            .body(delivery.payload.clone())
+            .send()
+            .await
+            .map_err(|error| AttemptFailure {
+                response_status: None,
+                message: error.to_string(),
+            })?;
+
+        let status = response.status();
+        let response_status = Some(i64::from(status.as_u16()));
+        match response.error_for_status() {
+            Ok(_) => Ok(i64::from(status.as_u16())),
+            Err(error) => Err(AttemptFailure {
+                response_status,
+                message: error.to_string(),
+            }),
+        }
+    }
+}
+
+/// A configuration error for webhooks.
+fn invalid_config(message: String) -> ServerError {
+    ServerError::Config(lib_config::ConfigError::Validation(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_domain as domain;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// Answers one request with each status in turn, returning the endpoint's
+    /// URL and the requests it received.
+    async fn endpoint(
+        statuses: Vec<u16>,
+    ) -> Result<(String, tokio::sync::mpsc::UnboundedReceiver<String>)> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/hooks", listener.local_addr()?);
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            for status in statuses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 4096];
+                // Read until the headers and the body they announce have arrived
+                loop {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    if let Some((headers, body)) = text.split_once("\r\n\r\n") {
+                        let length = headers
+                            .lines()
+                            .find_map(|line| {
+                                line.to_lowercase()
+                                    .strip_prefix("content-length:")
+                                    .map(|v| v.trim().parse::<usize>().unwrap())
+                            })
+                            .unwrap_or(0);
+                        if body.len() >= length {
+                            break;
+                        }
+                    }
+                }
+                sender
+                    .send(String::from_utf8_lossy(&request).to_string())
+                    .unwrap();
+                let response = format!(
+                    "HTTP/1.1 {status} Status\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        Ok((url, receiver))
+    }
+
+    fn config(endpoint: &str) -> WebhooksConfig {
+        WebhooksConfig {
+            endpoints: Some(endpoint.to_string()),
+            secret: Some("whsec_test".to_string()),
+            retry_backoff_seconds: 0,
+            max_attempts: 2,
+            ..WebhooksConfig::default()
+        }
+    }
+
+    fn event() -> database::DataEvent {
+        database::DataEvent {
+            event_id: domain::RowID::new(),
+            change: database::DataChanges::CategoryDeleted(domain::RowID::new()),
+            occurred_on: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn sign_uses_hmac_sha256_over_timestamp_and_body() {
+        assert_eq!(
+            sign(
+                "whsec_test",
+                1_700_000_000,
+                r#"{"event":"category.created"}"#
+            ),
+            "sha256=d3fec078eccb5b73114162189d2b1c4df9e1ba361c4a4ec42a9f4ce9239c55f4"
+        );
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn deliver_retries_until_accepted(pool: sqlx::SqlitePool) -> Result<()> {
+        let (url, mut requests) = endpoint(vec![503, 204]).await?;
+        let publisher = WebhookPublisher::new(
+            pool,
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+            &config(&url),
+        )?
+        .expect("an endpoint is set");
+        let event = event();
+
+        let delivery = publisher.deliver(&event, &url).await?;
+
+        assert_eq!(
+            delivery.status,
+            database::WebhookDeliveryStatuses::Delivered
+        );
+        assert_eq!(
+            (delivery.attempts, delivery.response_status),
+            (2, Some(204))
+        );
+        let request = requests.recv().await.expect("a first attempt");
+        let headers = request.to_lowercase();
+        let timestamp: i64 = headers
+            .lines()
+            .find_map(|line| line.strip_prefix("x-ledger-timestamp: "))
+            .expect("a timestamp header")
+            .trim()
+            .parse()?;
+        let signature = sign("whsec_test", timestamp, &delivery.payload);
+        assert!(headers.contains(&format!("x-ledger-signature: {signature}")));
+        assert!(request.ends_with(&delivery.payload));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn deliver_gives_up_after_max_attempts(pool: sqlx::SqlitePool) -> Result<()> {
+        let (url, _requests) = endpoint(vec![500, 500]).await?;
+        let publisher = WebhookPublisher::new(
+            pool,
+            convert::TEST_PUBLIC_IDS,
+            database::EventBus::new(),
+            &config(&url),
+        )?
+        .expect("an endpoint is set");
+
+        let delivery = publisher.deliver(&event(), &url).await?;
+
+        assert_eq!(delivery.status, database::WebhookDeliveryStatuses::Failed);
+        assert_eq!(
+            (delivery.attempts, delivery.response_status),
+            (2, Some(500))
+        );
+        assert!(delivery.last_error.is_some());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../libs/lib-database/migrations")]
+    async fn publisher_needs_a_secret(pool: sqlx::SqlitePool) -> Result<()> {
+        let config = WebhooksConfig {
+            secret: None,
+            ..config("https://hooks.example.com")
+        };
+
+        assert!(matches!(
+            WebhookPublisher::new(
+                pool,
+                convert::TEST_PUBLIC_IDS,
+                database::EventBus::new(),
+                &config
+            ),
+            Err(ServerError::Config(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,483 @@
+//! Application state and key handling.
+//!
+//! [`App`] holds what is on screen and turns key presses into [`Action`]s
+//! for the [`Ledger`](crate::Ledger) to carry out. It never talks to the
+//! server itself, so the whole of the keyboard handling can be tested
+//! without one.
+//!
+//! ## Keys
+//!
+//! | Mode   | Key                  | Does                                      |
+//! |--------|----------------------|-------------------------------------------|
+//! | Browse | `←` `→` `Tab` `h` `l`| Switch between accounts, transactions and categories |
+//! | Browse | `↑` `↓` `k` `j`      | Move the selection                        |
+//! | Browse | `g` `G` `Home` `End` | Jump to the first or last row             |
+//! | Browse | `/`                  | Type in the search box                    |
+//! | Browse | `e` `Enter`          | Edit the selected row's name or payee     |
+//! | Browse | `r`                  | Reload the tab, or run the search again   |
+//! | Browse | `Esc`                | Clear the search results                  |
+//! | Browse | `q`                  | Quit                                      |
+//! | Search | `Enter`              | Search, or clear the search if empty      |
+//! | Edit   | `Enter`              | Save the edit                             |
+//! | Search | `Esc`                | Stop typing, keeping the text             |
+//! | Edit   | `Esc`                | Cancel the edit                           |
+//!
+//! `Ctrl+C` quits from any mode.
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use lib_rpc as rpc;
+
+/// The lists the ledger can be browsed by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Tab {
+    #[default]
+    Accounts,
+    Transactions,
+    Categories,
+}
+
+impl Tab {
+    /// Every tab, in the order they are shown.
+    pub const ALL: [Tab; 3] = [Tab::Accounts, Tab::Transactions, Tab::Categories];
+
+    /// The tab's title.
+    pub fn title(self) -> &'static str {
+        match self {
+            Tab::Accounts => "Accounts",
+            Tab::Transactions => "Transactions",
+            Tab::Categories => "Categories",
+        }
+    }
+
+    /// The tab's position in [`Tab::ALL`].
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    fn previous(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// What key presses are for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Moving around the lists.
+    #[default]
+    Browse,
+
+    /// Typing in the search box.
+    Search,
+
+    /// Typing a new value for the selected row.
+    Edit,
+}
+
+/// A record with one field changed, to be saved.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Edit {
+    /// An account with a new name.
+    Account(Box<rpc::Account>),
+
+    /// A transaction with a new payee.
+    Transaction(Box<rpc::Transaction>),
+
+    /// A category with a new name.
+    Category(Box<rpc::Category>),
+}
+
+/// Work for the [`Ledger`](crate::Ledger) to do.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// Fetch the tab's list again.
+    Load(Tab),
+
+    /// Search categories and transactions for the text.
+    Search(String),
+
+    /// Save an edited record.
+    Save(Edit),
+}
+
+/// What the [`Ledger`](crate::Ledger) did, to show.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    /// The accounts were fetched.
+    Accounts(Vec<rpc::Account>),
+
+    /// The transactions were fetched.
+    Transactions(Vec<rpc::Transaction>),
+
+    /// The categories were fetched.
+    Categories(Vec<rpc::Category>),
+
+    /// The search was run.
+    Search(rpc::SearchResponse),
+
+    /// The record was saved, as the server stored it.
+    Saved(Edit),
+}
+
+/// Everything on screen.
+#[derive(Debug, Default)]
+pub struct App {
+    pub(crate) tab: Tab,
+    pub(crate) mode: Mode,
+    pub(crate) accounts: Vec<rpc::Account>,
+    pub(crate) transactions: Vec<rpc::Transaction>,
+    pub(crate) categories: Vec<rpc::Category>,
+    /// The results of the last search, shown instead of the tab's list.
+    pub(crate) search: Option<rpc::SearchResponse>,
+    /// The text in the search box.
+    pub(crate) query: String,
+    /// The new value being typed for the selected row.
+    pub(crate) input: String,
+    /// The last thing that happened, or went wrong.
+    pub(crate) status: String,
+    /// The selected row of each tab, then of the search results.
+    selected: [usize; 4],
+    quit: bool,
+}
+
+impl App {
+    /// Whether the user asked to quit.
+    pub fn should_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// The selected row of the list on screen.
+    pub fn selected(&self) -> usize {
+        self.selected[self.view()]
+    }
+
+    /// Shows what the ledger did, or what went wrong.
+    pub fn apply(&mut self, outcome: crate::TuiResult<Outcome>) {
+        let outcome = match outcome {
+            Ok(outcome) => outcome,
+            Err(error) => {
+                self.status = error.to_string();
+                return;
+            }
+        };
+
+        self.status = match outcome {
+            Outcome::Accounts(accounts) => {
+                self.accounts = accounts;
+                format!("Loaded {} accounts", self.accounts.len())
+            }
+            Outcome::Transactions(transactions) => {
+                self.transactions = transactions;
+                format!("Loaded {} transactions", self.transactions.len())
+            }
+            Outcome::Categories(categories) => {
+                self.categories = categories;
+                format!("Loaded {} categories", self.categories.len())
+            }
+            Outcome::Search(results) => {
+                let status = format!(
+                    "{} categories and {} transactions match",
+                    results.categories.len(),
+                    results.transactions.len()
+                );
+                self.search = Some(results);
+                self.selected[3] = 0;
+                status
+            }
+            Outcome::Saved(edit) => {
+                match edit {
+                    Edit::Account(account) => replace(&mut self.accounts, *account, |a| &a.id),
+                    Edit::Transaction(transaction) => {
+                        replace(&mut self.transactions, *transaction, |t| &t.id)
+                    }
+                    Edit::Category(category) => replace(&mut self.categories, *category, |c| &c.id),
+                }
+                "Saved".to_string()
+            }
+        };
+
+        // Lists can shrink when reloaded
+        for view in 0..self.selected.len() {
+            self.selected[view] = self.selected[view].min(self.len_of(view).saturating_sub(1));
+        }
+    }
+
+    /// Handles a key press, returning the work it needs done, if any.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return None;
+        }
+
+        match self.mode {
+            Mode::Browse => self.browse_key(key),
+            Mode::Search => self.search_key(key),
+            Mode::Edit => self.edit_key(key),
+        }
+    }
+
+    fn browse_key(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Char('q') => self.quit = true,
+            KeyCode::Right | KeyCode::Tab | KeyCode::Char('l') => self.switch_tab(self.tab.next()),
+            KeyCode::Left | KeyCode::BackTab | KeyCode::Char('h') => {
+                self.switch_tab(self.tab.previous())
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.select(self.selected().saturating_add(1)),
+            KeyCode::Up | KeyCode::Char('k') => self.select(self.selected().saturating_sub(1)),
+            KeyCode::Home | KeyCode::Char('g') => self.select(0),
+            KeyCode::End | KeyCode::Char('G') => self.select(usize::MAX),
+            KeyCode::Char('/') => self.mode = Mode::Search,
+            KeyCode::Esc => self.search = None,
+            KeyCode::Char('r') => {
+                return Some(match self.search {
+                    Some(_) => Action::Search(self.query.trim().to_string()),
+                    None => Action::Load(self.tab),
+                });
+            }
+            KeyCode::Char('e') | KeyCode::Enter => match self.edit_value() {
+                Some(value) => {
+                    self.input = value;
+                    self.mode = Mode::Edit;
+                }
+                None if self.search.is_some() => {
+                    self.status = "Clear the search (Esc) to edit".to_string()
+                }
+                None => {}
+            },
+            _ => {}
+        }
+
+        None
+    }
+
+    fn search_key(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Char(c) => self.query.push(c),
+            KeyCode::Backspace => _ = self.query.pop(),
+            KeyCode::Esc => self.mode = Mode::Browse,
+            KeyCode::Enter => {
+                self.mode = Mode::Browse;
+                let query = self.query.trim();
+                if query.is_empty() {
+                    self.search = None;
+                } else {
+                    return Some(Action::Search(query.to_string()));
+                }
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn edit_key(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Char(c) => self.input.push(c),
+            KeyCode::Backspace => _ = self.input.pop(),
+            KeyCode::Esc => {
+                self.mode = Mode::Browse;
+                self.status = "Edit cancelled".to_string();
+            }
+            KeyCode::Enter => {
+                self.mode = Mode::Browse;
+                let value = self.input.trim().to_string();
+                if value.is_empty() || Some(&value) == self.edit_value().as_ref() {
+                    return None;
+                }
+                return self.edited(value).map(Action::Save);
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    /// The selected row's editable value: an account or category name, or
+    /// a transaction payee. Search results cannot be edited.
+    pub(crate) fn edit_value(&self) -> Option<String> {
+        if self.search.is_some() {
+            return None;
+        }
+
+        let index = self.selected();
+        match self.tab {
+            Tab::Accounts => self.accounts.get(index).map(|a| a.name.clone()),
+            Tab::Transactions => self.transactions.get(index).map(|t| t.payee.clone()),
+            Tab::Categories => self.categories.get(index).map(|c| c.name.clone()),
+        }
+    }
+
+    /// The selected row with its editable value changed.
+    fn edited(&self, value: String) -> Option<Edit> {
+        let index = self.selected();
+        match self.tab {
+            Tab::Accounts => self.accounts.get(index).map(|account| {
+                Edit::Account(Box::new(rpc::Account {
+                    name: value,
+                    ..account.clone()
+                }))
+            }),
+            Tab::Transactions => self.transactions.get(index).map(|transaction| {
+                Edit::Transaction(Box::new(rpc::Transaction {
+                    payee: value,
+                    ..transaction.clone()
+                }))
+            }),
+            Tab::Categories => self.categories.get(index).map(|category| {
+                Edit::Category(Box::new(rpc::Category {
+                    name: value,
+                    ..category.clone()
+                }))
+            }),
+        }
+    }
+
+    fn switch_tab(&mut self, tab: Tab) {
+        self.tab = tab;
+        self.search = None;
+    }
+
+    fn select(&mut self, index: usize) {
+        let view = self.view();
+        self.selected[view] = index.min(self.len_of(view).saturating_sub(1));
+    }
+
+    /// The list on screen: a tab's index, or 3 for the search results.
+    fn view(&self) -> usize {
+        if self.search.is_some() {
+            3
+        } else {
+            self.tab.index()
+        }
+    }
+
+    fn len_of(&self, view: usize) -> usize {
+        match view {
+            0 => self.accounts.len(),
+            1 => self.transactions.len(),
+            2 => self.categories.len(),
+            _ => self.search.as_ref().map_or(0, |results| {
+                results.categories.len() + results.transactions.len()
+            }),
+        }
+    }
+}
+
+/// Replaces the item with the same ID as `item`.
+fn replace<T>(items: &mut [T], item: T, id: impl Fn(&T) -> &String) {
+    if let Some(existing) = items.iter_mut().find(|existing| id(existing) == id(&item)) {
+        *existing = item;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(app: &mut App, keys: &str) -> Option<Action> {
+        keys.chars().fold(None, |_, c| {
+            app.handle_key(KeyEvent::from(KeyCode::Char(c)))
+        })
+    }
+
+    fn key(app: &mut App, code: KeyCode) -> Option<Action> {
+        app.handle_key(KeyEvent::from(code))
+    }
+
+    fn categories() -> Vec<rpc::Category> {
+        ["Groceries", "Rent", "Salary"]
+            .iter()
+            .enumerate()
+            .map(|(id, name)| rpc::Category {
+                id: id.to_string(),
+                name: name.to_string(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn moves_between_tabs_and_rows() {
+        let mut app = App::default();
+        app.apply(Ok(Outcome::Categories(categories())));
+
+        key(&mut app, KeyCode::Left);
+        assert_eq!(app.tab, Tab::Categories);
+
+        press(&mut app, "jjjj");
+        assert_eq!(app.selected(), 2);
+        press(&mut app, "k");
+        assert_eq!(app.selected(), 1);
+
+        assert_eq!(press(&mut app, "r"), Some(Action::Load(Tab::Categories)));
+        press(&mut app, "q");
+        assert!(app.should_quit());
+    }
+
+    #[test]
+    fn search_box_runs_and_clears_searches() {
+        let mut app = App::default();
+
+        press(&mut app, "/");
+        assert_eq!(app.mode, Mode::Search);
+        press(&mut app, "grox");
+        key(&mut app, KeyCode::Backspace);
+        press(&mut app, "*");
+        assert_eq!(
+            key(&mut app, KeyCode::Enter),
+            Some(Action::Search("gro*".to_string()))
+        );
+        assert_eq!(app.mode, Mode::Browse);
+
+        app.apply(Ok(Outcome::Search(rpc::SearchResponse::default())));
+        assert!(app.search.is_some());
+        assert!(press(&mut app, "e").is_none());
+        assert_eq!(app.mode, Mode::Browse);
+
+        key(&mut app, KeyCode::Esc);
+        assert!(app.search.is_none());
+    }
+
+    #[test]
+    fn editing_saves_the_changed_record() {
+        let mut app = App {
+            tab: Tab::Categories,
+            ..Default::default()
+        };
+        app.apply(Ok(Outcome::Categories(categories())));
+
+        press(&mut app, "je");
+        assert_eq!(app.input, "Rent");
+        press(&mut app, " & Board");
+        let Some(Action::Save(Edit::Category(category))) = key(&mut app, KeyCode::Enter) else {
+            panic!("expected a save");
+        };
+        assert_eq!(category.id, "1");
+        assert_eq!(category.name, "Rent & Board");
+
+        app.apply(Ok(Outcome::Saved(Edit::Category(category))));
+        assert_eq!(app.categories[1].name, "Rent & Board");
+    }
+
+    #[test]
+    fn unchanged_or_cancelled_edits_save_nothing() {
+        let mut app = App {
+            tab: Tab::Categories,
+            ..Default::default()
+        };
+        app.apply(Ok(Outcome::Categories(categories())));
+
+        press(&mut app, "e");
+        assert!(key(&mut app, KeyCode::Enter).is_none());
+
+        press(&mut app, "ex");
+        assert!(key(&mut app, KeyCode::Esc).is_none());
+        assert_eq!(app.mode, Mode::Browse);
+        assert_eq!(app.categories[0].name, "Groceries");
+    }
+}
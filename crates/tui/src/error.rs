@@ -0,0 +1,24 @@
+//! Terminal UI errors.
+//!
+//! Errors from the server are shown in the status line and browsing carries
+//! on. Only failing to connect or to draw ends the program.
+
+/// Result type alias used by the terminal UI.
+pub type TuiResult<T> = std::result::Result<T, TuiError>;
+
+/// Errors produced while running the terminal UI.
+#[derive(thiserror::Error, Debug)]
+pub enum TuiError {
+    /// The server could not be connected to, or the connection options
+    /// could not be used, such as TLS files that cannot be read.
+    #[error(transparent)]
+    Client(#[from] client::ClientError),
+
+    /// The server refused or failed the request.
+    #[error("{}: {}", .0.code(), .0.message())]
+    Status(#[from] tonic::Status),
+
+    /// The terminal could not be read or drawn to.
+    #[error("Terminal error: {0}")]
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,228 @@
+//! The server side of the terminal UI.
+//!
+//! [`Ledger`] carries out the [`Action`]s the [`App`](crate::App) asks for
+//! over the gRPC API, and hands back the [`Outcome`] to show.
+
+use futures_util::{StreamExt, TryStreamExt};
+
+use lib_rpc as rpc;
+
+use crate::{Action, Edit, Outcome, Tab, TuiResult};
+
+/// The most rows fetched for a tab. Search finds anything further down.
+pub const MAX_ROWS: usize = 1_000;
+
+/// The most results of each kind a search shows.
+const SEARCH_LIMIT: u32 = 50;
+
+/// A connection to the server the terminal UI browses.
+#[derive(Debug, Clone)]
+pub struct Ledger {
+    client: rpc::LedgerClient,
+}
+
+impl Ledger {
+    /// Uses an existing client.
+    pub fn new(client: rpc::LedgerClient) -> Self {
+        Self { client }
+    }
+
+    /// Connects to the server `connection` names, as the `ledger` command
+    /// line client does.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TuiError::Client` if the server cannot be reached or the
+    /// options cannot be used, see [`client::connect`].
+    pub async fn connect(connection: &client::Connection) -> TuiResult<Self> {
+        Ok(Self::new(client::connect(connection).await?))
+    }
+
+    /// Carries out an action.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TuiError::Status` if the server refuses a request, such as
+    /// an edit that leaves a name empty.
+    pub async fn perform(&self, action: Action) -> TuiResult<Outcome> {
+        match action {
+            Action::Load(Tab::Accounts) => Ok(Outcome::Accounts(
+                self.client
+                    .iter_accounts(rpc::AccountsListRequest::default())
+                    .take(MAX_ROWS)
+                    .try_collect()
+                    .await?,
+            )),
+            Action::Load(Tab::Transactions) => Ok(Outcome::Transactions(
+                self.client
+                    .iter_transactions(rpc::TransactionsListRequest::default())
+                    .take(MAX_ROWS)
+                    .try_collect()
+                    .await?,
+            )),
+            Action::Load(Tab::Categories) => Ok(Outcome::Categories(
+                self.client
+                    .iter_categories(rpc::CategoriesListRequest::default())
+                    .take(MAX_ROWS)
+                    .try_collect()
+                    .await?,
+            )),
+            Action::Search(query) => {
+                let request = rpc::SearchRequest {
+                    query,
+                    limit: Some(SEARCH_LIMIT),
+                    ..Default::default()
+                };
+                Ok(Outcome::Search(
+                    self.client.search().search(request).await?.into_inner(),
+                ))
+            }
+            Action::Save(edit) => self.save(edit).await.map(Outcome::Saved),
+        }
+    }
+
+    async fn save(&self, edit: Edit) -> TuiResult<Edit> {
+        let saved = match edit {
+            Edit::Account(account) => {
+                let request = rpc::AccountUpdateRequest {
+                    id: account.id.clone(),
+                    account: Some(*account),
+                    validate_only: false,
+                };
+                self.client
+                    .accounts()
+                    .account_update(request)
+                    .await?
+                    .into_inner()
+                    .account
+                    .map(|account| Edit::Account(Box::new(account)))
+            }
+            Edit::Transaction(transaction) => {
+                let request = rpc::TransactionUpdateRequest {
+                    id: transaction.id.clone(),
+                    transaction: Some(*transaction),
+                    validate_only: false,
+                    update_mask: None,
+                };
+                self.client
+                    .transactions()
+                    .transaction_update(request)
+                    .await?
+                    .into_inner()
+                    .transaction
+                    .map(|transaction| Edit::Transaction(Box::new(transaction)))
+            }
+            Edit::Category(category) => {
+                let request = rpc::CategoryUpdateRequest {
+                    id: category.id.clone(),
+                    category: Some(*category),
+                    ..Default::default()
+                };
+                self.client
+                    .categories()
+                    .category_update(request)
+                    .await?
+                    .into_inner()
+                    .category
+                    .map(|category| Edit::Category(Box::new(category)))
+            }
+        };
+
+        saved.ok_or_else(|| {
+            tonic::Status::internal("The server did not return the saved record").into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lib_testkit::{TestServer, fixtures};
+
+    use super::*;
+
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+    #[tokio::test]
+    async fn connects_with_the_client_options() -> Result<()> {
+        let server = TestServer::start().await?;
+        server
+            .create_category(fixtures::category("GROCERIES", "Groceries"))
+            .await?;
+        let connection = client::Connection {
+            url: server.url().expect("a TCP server"),
+            socket: None,
+            standalone: false,
+            token: None,
+            tls_ca: None,
+            tls_cert: None,
+            tls_key: None,
+        };
+
+        let ledger = Ledger::connect(&connection).await?;
+
+        let Outcome::Categories(categories) = ledger.perform(Action::Load(Tab::Categories)).await?
+        else {
+            panic!("expected categories");
+        };
+        assert_eq!(categories.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn loads_searches_and_saves() -> Result<()> {
+        let server = TestServer::start().await?;
+        server
+            .create_category(fixtures::category("GROCERIES", "Groceries"))
+            .await?;
+        server
+            .create_transaction(fixtures::transaction("2026-10-01", "Fresh Market", -4_250))
+            .await?;
+        let ledger = Ledger::new(server.client());
+
+        let Outcome::Transactions(transactions) =
+            ledger.perform(Action::Load(Tab::Transactions)).await?
+        else {
+            panic!("expected transactions");
+        };
+        assert_eq!(transactions.len(), 1);
+
+        let Outcome::Search(results) = ledger.perform(Action::Search("fresh".to_string())).await?
+        else {
+            panic!("expected search results");
+        };
+        assert_eq!(results.transactions.len(), 1);
+        assert!(results.categories.is_empty());
+
+        let edit = Edit::Transaction(Box::new(rpc::Transaction {
+            payee: "Fresh Market Co-op".to_string(),
+            ..transactions[0].clone()
+        }));
+        let Outcome::Saved(Edit::Transaction(saved)) = ledger.perform(Action::Save(edit)).await?
+        else {
+            panic!("expected the saved transaction");
+        };
+        assert_eq!(saved.payee, "Fresh Market Co-op");
+        assert_eq!(saved.amount, -4_250);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refused_edits_are_errors() -> Result<()> {
+        let server = TestServer::start().await?;
+        let category = server
+            .create_category(fixtures::category("RENT", "Rent"))
+            .await?;
+        let ledger = Ledger::new(server.client());
+
+        let edit = Edit::Category(Box::new(rpc::Category {
+            name: String::new(),
+            ..category
+        }));
+
+        assert!(ledger.perform(Action::Save(edit)).await.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+//! # Personal Ledger Terminal UI
+//!
+//! An interactive terminal client for the Personal Ledger gRPC API, built
+//! with `ratatui` and installed as the `ledger-tui` binary. It browses
+//! accounts, transactions and categories, edits their names and payees in
+//! place, and searches the ledger with the `Search` RPC.
+//!
+//! The crate is split so most of it can be tested without a terminal:
+//!
+//! - [`App`] holds the state and turns key presses into [`Action`]s, see the
+//!   [`app`] module for the keys
+//! - [`Ledger`] carries out actions over the gRPC API
+//! - [`draw`] renders the state
+//! - [`run`] ties them to the terminal
+
+pub mod app;
+mod error;
+mod ledger;
+mod ui;
+
+pub use app::{Action, App, Edit, Mode, Outcome, Tab};
+pub use error::{TuiError, TuiResult};
+pub use ledger::{Ledger, MAX_ROWS};
+pub use ui::draw;
+
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+
+/// Connects to the server `connection` names, loads every tab and runs the
+/// terminal UI until the user quits. The terminal is restored however it
+/// ends.
+///
+/// # Errors
+///
+/// Returns `TuiError::Client` if the server cannot be reached, or
+/// `TuiError::Io` if the terminal cannot be read or drawn to. Errors from
+/// the server once running are shown in the status line instead.
+pub async fn run(connection: client::Connection) -> TuiResult<()> {
+    let ledger = Ledger::connect(&connection).await?;
+
+    let mut app = App::default();
+    for tab in Tab::ALL {
+        app.apply(ledger.perform(Action::Load(tab)).await);
+    }
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut app, &ledger).await;
+    ratatui::restore();
+
+    result
+}
+
+async fn event_loop(
+    terminal: &mut ratatui::DefaultTerminal,
+    app: &mut App,
+    ledger: &Ledger,
+) -> TuiResult<()> {
+    while !app.should_quit() {
+        terminal.draw(|frame| draw(frame, app))?;
+
+        // Key presses are read on this thread, so the runtime is told it blocks
+        if let Event::Key(key) = tokio::task::block_in_place(event::read)?
+            && key.kind == KeyEventKind::Press
+            && let Some(action) = app.handle_key(key)
+        {
+            app.apply(ledger.perform(action).await);
+        }
+    }
+
+    Ok(())
+}
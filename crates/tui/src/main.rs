@@ -0,0 +1,24 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+
+/// Interactive terminal client for the Personal Ledger gRPC API.
+#[derive(Debug, Parser)]
+#[command(name = "ledger-tui", version, about)]
+struct Cli {
+    #[command(flatten)]
+    connection: client::Connection,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match tui::run(cli.connection).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Drawing the screen.
+//!
+//! From the top: the tabs, the search box, the list on screen, then the
+//! status line and the keys of the current mode. While editing, the
+//! selected row shows the value being typed in place of the one it had.
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Position};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState, Tabs};
+
+use lib_domain as domain;
+use lib_rpc as rpc;
+
+use crate::{App, Mode, Tab};
+
+/// Draws the whole screen.
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [tabs_area, search_area, list_area, status_area, help_area] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Min(0),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let tabs = Tabs::new(Tab::ALL.map(Tab::title))
+        .select(app.search.is_none().then_some(app.tab.index()))
+        .highlight_style(Style::new().bold().reversed())
+        .block(Block::bordered().title(" Personal Ledger "));
+    frame.render_widget(tabs, tabs_area);
+
+    let searching = app.mode == Mode::Search;
+    let search_box = Block::bordered()
+        .title(" Search (/) ")
+        .border_style(if searching {
+            Style::new().yellow()
+        } else {
+            Style::new()
+        });
+    let query = if app.query.is_empty() && !searching {
+        Line::from("Press / to search categories and transactions").dark_gray()
+    } else {
+        Line::from(app.query.as_str())
+    };
+    frame.render_widget(Paragraph::new(query).block(search_box), search_area);
+    if searching {
+        let x = search_area.x + 1 + app.query.chars().count() as u16;
+        frame.set_cursor_position(Position::new(
+            x.min(search_area.right().saturating_sub(2)),
+            search_area.y + 1,
+        ));
+    }
+
+    let List {
+        title,
+        header,
+        mut rows,
+    } = list(app);
+    if app.mode == Mode::Edit
+        && let Some(row) = rows.get_mut(app.selected())
+    {
+        // The editable value is always the second column
+        row[1] = format!("{}▏", app.input);
+    }
+    let widths = [
+        Constraint::Length(12),
+        Constraint::Fill(2),
+        Constraint::Fill(1),
+        Constraint::Length(8),
+    ];
+    let table = Table::new(rows.into_iter().map(Row::new), widths)
+        .header(Row::new(header.to_vec()).bold())
+        .row_highlight_style(if app.mode == Mode::Edit {
+            Style::new().yellow().reversed()
+        } else {
+            Style::new().reversed()
+        })
+        .highlight_symbol("> ")
+        .block(Block::bordered().title(format!(" {title} ")));
+    let mut state = TableState::default().with_selected(Some(app.selected()));
+    frame.render_stateful_widget(table, list_area, &mut state);
+
+    frame.render_widget(Line::from(app.status.as_str()).italic(), status_area);
+    frame.render_widget(Line::from(help(app.mode)).dark_gray(), help_area);
+}
+
+/// The keys of a mode.
+fn help(mode: Mode) -> &'static str {
+    match mode {
+        Mode::Browse => "q quit  ←/→ tabs  ↑/↓ move  / search  e edit  r reload  esc clear search",
+        Mode::Search => "enter search  esc stop typing",
+        Mode::Edit => "enter save  esc cancel",
+    }
+}
+
+/// The list on screen, as four columns of text.
+struct List {
+    title: &'static str,
+    header: [&'static str; 4],
+    rows: Vec<Vec<String>>,
+}
+
+/// The list on screen: the search results if there are any, otherwise the
+/// tab's list.
+fn list(app: &App) -> List {
+    if let Some(results) = &app.search {
+        let categories = results.categories.iter().map(|hit| {
+            vec![
+                "category".to_string(),
+                hit.name.clone(),
+                hit.code.clone(),
+                String::new(),
+            ]
+        });
+        let transactions = results.transactions.iter().map(|hit| {
+            vec![
+                "transaction".to_string(),
+                hit.payee.clone(),
+                format!("{}  {}", hit.transaction_date, money(hit.amount, None)),
+                yes_no(hit.is_draft),
+            ]
+        });
+        return List {
+            title: "Search results",
+            header: ["KIND", "NAME", "DETAIL", "DRAFT"],
+            rows: categories.chain(transactions).collect(),
+        };
+    }
+
+    match app.tab {
+        Tab::Accounts => List {
+            title: "Accounts",
+            header: ["CURRENCY", "NAME", "OPENING BALANCE", "ACTIVE"],
+            rows: app.accounts.iter().map(account_cells).collect(),
+        },
+        Tab::Transactions => List {
+            title: "Transactions",
+            header: ["DATE", "PAYEE", "AMOUNT", "DRAFT"],
+            rows: app.transactions.iter().map(transaction_cells).collect(),
+        },
+        Tab::Categories => List {
+            title: "Categories",
+            header: ["CODE", "NAME", "TYPE", "ACTIVE"],
+            rows: app.categories.iter().map(category_cells).collect(),
+        },
+    }
+}
+
+fn account_cells(account: &rpc::Account) -> Vec<String> {
+    vec![
+        account.currency.clone(),
+        account.name.clone(),
+        money(account.opening_balance, Some(&account.currency)),
+        yes_no(account.is_active),
+    ]
+}
+
+fn transaction_cells(transaction: &rpc::Transaction) -> Vec<String> {
+    vec![
+        transaction.transaction_date.clone(),
+        transaction.payee.clone(),
+        money(transaction.amount, None),
+        yes_no(transaction.is_draft),
+    ]
+}
+
+fn category_cells(category: &rpc::Category) -> Vec<String> {
+    let category_type = domain::CategoryTypes::from_rpc_i32(category.category_type)
+        .map_or("unknown", |category_type| category_type.as_str());
+
+    vec![
+        category.code.clone(),
+        category.name.clone(),
+        category_type.to_string(),
+        yes_no(category.is_active),
+    ]
+}
+
+/// An amount in cents with its currency's symbol, in the default currency
+/// unless `currency` names another.
+fn money(amount: i64, currency: Option<&str>) -> String {
+    let currency = currency
+        .and_then(|code| code.parse().ok())
+        .unwrap_or_default();
+
+    domain::Money::new(amount, currency).to_string()
+}
+
+fn yes_no(value: bool) -> String {
+    if value { "yes" } else { "no" }.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+    use super::*;
+    use crate::Outcome;
+
+    fn screen(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 16)).unwrap();
+        terminal.draw(|frame| draw(frame, app)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        buffer.content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    fn fresh_market() -> rpc::Transaction {
+        rpc::Transaction {
+            id: "1".to_string(),
+            transaction_date: "2026-10-01".to_string(),
+            payee: "Fresh Market".to_string(),
+            amount: -4_250,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn draws_the_tab_on_screen() {
+        let mut app = App::default();
+        app.apply(Ok(Outcome::Transactions(vec![fresh_market()])));
+        app.handle_key(KeyEvent::from(KeyCode::Right));
+
+        let screen = screen(&app);
+
+        assert!(screen.contains("Accounts"));
+        assert!(screen.contains("Fresh Market"));
+        assert!(screen.contains("-$42.50"));
+        assert!(screen.contains("Loaded 1 transactions"));
+    }
+
+    #[test]
+    fn draws_the_value_being_edited() {
+        let mut app = App::default();
+        app.apply(Ok(Outcome::Transactions(vec![fresh_market()])));
+        app.handle_key(KeyEvent::from(KeyCode::Right));
+        app.handle_key(KeyEvent::from(KeyCode::Char('e')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('!')));
+
+        let screen = screen(&app);
+
+        assert!(screen.contains("Fresh Market!▏"));
+        assert!(screen.contains("enter save"));
+    }
+
+    #[test]
+    fn draws_search_results_instead_of_the_tab() {
+        let mut app = App::default();
+        for c in "/rent".chars() {
+            app.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        app.apply(Ok(Outcome::Search(rpc::SearchResponse {
+            categories: vec![rpc::SearchCategoryHit {
+                name: "Rent".to_string(),
+                code: "RENT".to_string(),
+                ..Default::default()
+            }],
+            transactions: Vec::new(),
+        })));
+
+        let screen = screen(&app);
+
+        assert!(screen.contains("Search results"));
+        assert!(screen.contains("category"));
+        assert!(screen.contains("RENT"));
+    }
+}